@@ -1,12 +1,16 @@
 pub mod analyzer;
 mod formatting;
 mod lexer;
+pub mod metadata_generator;
 pub mod naming;
+pub mod operands_generator;
 pub mod parser_generator;
+pub mod requirements_generator;
 mod spec_parser;
 pub mod r#type;
 pub mod type_generator;
 pub mod unparser_generator;
+pub mod visitor_generator;
 
 pub use lexer::{PtxSpecToken, Span, tokenize};
 pub use spec_parser::{SpecParseError, parse_spec, parse_spec_with_name};
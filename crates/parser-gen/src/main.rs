@@ -73,6 +73,34 @@ enum Command {
         #[arg(value_name = "OUTPUT_DIR")]
         output_dir: PathBuf,
     },
+    /// Generate Rust visitor walkers from PTX specification files.
+    GenerateVisitor {
+        #[arg(value_name = "INPUT_DIR")]
+        input_dir: PathBuf,
+        #[arg(value_name = "OUTPUT_DIR")]
+        output_dir: PathBuf,
+    },
+    /// Generate Rust operand accessors from PTX specification files.
+    GenerateOperands {
+        #[arg(value_name = "INPUT_DIR")]
+        input_dir: PathBuf,
+        #[arg(value_name = "OUTPUT_DIR")]
+        output_dir: PathBuf,
+    },
+    /// Generate Rust opcode and modifier metadata from PTX specification files.
+    GenerateMetadata {
+        #[arg(value_name = "INPUT_DIR")]
+        input_dir: PathBuf,
+        #[arg(value_name = "OUTPUT_DIR")]
+        output_dir: PathBuf,
+    },
+    /// Generate the instruction requirement table from `ptx_requirements.txt`.
+    GenerateRequirements {
+        #[arg(value_name = "INPUT_FILE")]
+        input_file: PathBuf,
+        #[arg(value_name = "OUTPUT_FILE")]
+        output_file: PathBuf,
+    },
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -101,6 +129,30 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         } => {
             generate_unparsers(&input_dir, &output_dir)?;
         }
+        Command::GenerateVisitor {
+            input_dir,
+            output_dir,
+        } => {
+            generate_visitors(&input_dir, &output_dir)?;
+        }
+        Command::GenerateOperands {
+            input_dir,
+            output_dir,
+        } => {
+            generate_operands(&input_dir, &output_dir)?;
+        }
+        Command::GenerateMetadata {
+            input_dir,
+            output_dir,
+        } => {
+            generate_metadata(&input_dir, &output_dir)?;
+        }
+        Command::GenerateRequirements {
+            input_file,
+            output_file,
+        } => {
+            generate_requirements(&input_file, &output_file)?;
+        }
     }
     Ok(())
 }
@@ -512,3 +564,272 @@ fn process_unparser_file(
         instruction_structs,
     })
 }
+
+fn generate_visitors(
+    input_dir: &Path,
+    output_dir: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    fs::create_dir_all(output_dir)?;
+
+    let mut entries: Vec<_> = fs::read_dir(input_dir)?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.path().is_file() && entry.path().extension().is_some_and(|ext| ext == "txt")
+        })
+        .collect();
+    entries.sort_unstable_by_key(|entry| entry.path());
+
+    let mut success_count = 0;
+    let mut error_count = 0;
+    let mut module_info = Vec::new();
+
+    for entry in entries {
+        let path = entry.path();
+        let file_name = path.file_stem().unwrap().to_string_lossy();
+
+        eprint!("Processing: {} ... ", file_name);
+
+        match process_visitor_file(&path, output_dir) {
+            Ok(info) => {
+                eprintln!("OK");
+                success_count += 1;
+                module_info.push(info);
+            }
+            Err(e) => {
+                eprintln!("ERROR");
+                eprintln!("  Error: {}", e);
+                error_count += 1;
+            }
+        }
+    }
+
+    eprintln!(
+        "\nSummary: {} succeeded, {} failed",
+        success_count, error_count
+    );
+
+    if error_count > 0 {
+        return Err(format!("Generation failed: {} errors", error_count).into());
+    }
+
+    eprintln!("Generating visitor mod.rs ...");
+    let modules: Vec<(String, Vec<(String, String)>)> = module_info
+        .iter()
+        .map(|info| (info.module_name.clone(), info.instruction_structs.clone()))
+        .collect();
+    let content = parser_gen::visitor_generator::generate_visitor_mod_rs_content(&modules);
+    let mod_path = output_dir.join("mod.rs");
+    fs::write(&mod_path, content)?;
+    eprintln!("visitor mod.rs generated successfully");
+
+    Ok(())
+}
+
+fn process_visitor_file(
+    input_path: &Path,
+    output_dir: &Path,
+) -> Result<ModuleInfo, Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(input_path)?;
+    let file_name = input_path.file_name().unwrap().to_string_lossy();
+
+    let module_name = input_path
+        .file_stem()
+        .unwrap()
+        .to_string_lossy()
+        .replace('.', "_");
+
+    let (output, (module_name, instruction_structs)) =
+        parser_gen::visitor_generator::generate_visitor_file(&content, &file_name, &module_name)?;
+
+    let output_path = output_dir.join(format!("{}.rs", module_name));
+    fs::write(&output_path, output)?;
+
+    Ok(ModuleInfo {
+        module_name,
+        instruction_structs,
+    })
+}
+fn generate_operands(
+    input_dir: &Path,
+    output_dir: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    fs::create_dir_all(output_dir)?;
+
+    let mut entries: Vec<_> = fs::read_dir(input_dir)?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.path().is_file() && entry.path().extension().is_some_and(|ext| ext == "txt")
+        })
+        .collect();
+    entries.sort_unstable_by_key(|entry| entry.path());
+
+    let mut success_count = 0;
+    let mut error_count = 0;
+    let mut module_info = Vec::new();
+
+    for entry in entries {
+        let path = entry.path();
+        let file_name = path.file_stem().unwrap().to_string_lossy();
+
+        eprint!("Processing: {} ... ", file_name);
+
+        match process_operands_file(&path, output_dir) {
+            Ok(info) => {
+                eprintln!("OK");
+                success_count += 1;
+                module_info.push(info);
+            }
+            Err(e) => {
+                eprintln!("ERROR");
+                eprintln!("  Error: {}", e);
+                error_count += 1;
+            }
+        }
+    }
+
+    eprintln!(
+        "\nSummary: {} succeeded, {} failed",
+        success_count, error_count
+    );
+
+    if error_count > 0 {
+        return Err(format!("Generation failed: {} errors", error_count).into());
+    }
+
+    eprintln!("Generating operands mod.rs ...");
+    let modules: Vec<(String, Vec<(String, String)>)> = module_info
+        .iter()
+        .map(|info| (info.module_name.clone(), info.instruction_structs.clone()))
+        .collect();
+    let content = parser_gen::operands_generator::generate_operands_mod_rs_content(&modules);
+    let mod_path = output_dir.join("mod.rs");
+    fs::write(&mod_path, content)?;
+    eprintln!("operands mod.rs generated successfully");
+
+    Ok(())
+}
+
+fn process_operands_file(
+    input_path: &Path,
+    output_dir: &Path,
+) -> Result<ModuleInfo, Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(input_path)?;
+    let file_name = input_path.file_name().unwrap().to_string_lossy();
+
+    let module_name = input_path
+        .file_stem()
+        .unwrap()
+        .to_string_lossy()
+        .replace('.', "_");
+
+    let (output, (module_name, instruction_structs)) =
+        parser_gen::operands_generator::generate_operands_file(&content, &file_name, &module_name)?;
+
+    let output_path = output_dir.join(format!("{}.rs", module_name));
+    fs::write(&output_path, output)?;
+
+    Ok(ModuleInfo {
+        module_name,
+        instruction_structs,
+    })
+}
+fn generate_metadata(
+    input_dir: &Path,
+    output_dir: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    fs::create_dir_all(output_dir)?;
+
+    let mut entries: Vec<_> = fs::read_dir(input_dir)?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry.path().is_file() && entry.path().extension().is_some_and(|ext| ext == "txt")
+        })
+        .collect();
+    entries.sort_unstable_by_key(|entry| entry.path());
+
+    let mut success_count = 0;
+    let mut error_count = 0;
+    let mut module_info = Vec::new();
+
+    for entry in entries {
+        let path = entry.path();
+        let file_name = path.file_stem().unwrap().to_string_lossy();
+
+        eprint!("Processing: {} ... ", file_name);
+
+        match process_metadata_file(&path, output_dir) {
+            Ok(info) => {
+                eprintln!("OK");
+                success_count += 1;
+                module_info.push(info);
+            }
+            Err(e) => {
+                eprintln!("ERROR");
+                eprintln!("  Error: {}", e);
+                error_count += 1;
+            }
+        }
+    }
+
+    eprintln!(
+        "\nSummary: {} succeeded, {} failed",
+        success_count, error_count
+    );
+
+    if error_count > 0 {
+        return Err(format!("Generation failed: {} errors", error_count).into());
+    }
+
+    eprintln!("Generating metadata mod.rs ...");
+    let modules: Vec<(String, Vec<(String, String)>)> = module_info
+        .iter()
+        .map(|info| (info.module_name.clone(), info.instruction_structs.clone()))
+        .collect();
+    let content = parser_gen::metadata_generator::generate_metadata_mod_rs_content(&modules);
+    let mod_path = output_dir.join("mod.rs");
+    fs::write(&mod_path, content)?;
+    eprintln!("metadata mod.rs generated successfully");
+
+    Ok(())
+}
+
+fn process_metadata_file(
+    input_path: &Path,
+    output_dir: &Path,
+) -> Result<ModuleInfo, Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(input_path)?;
+    let file_name = input_path.file_name().unwrap().to_string_lossy();
+
+    let module_name = input_path
+        .file_stem()
+        .unwrap()
+        .to_string_lossy()
+        .replace('.', "_");
+
+    let (output, (module_name, instruction_structs)) =
+        parser_gen::metadata_generator::generate_metadata_file(&content, &file_name, &module_name)?;
+
+    let output_path = output_dir.join(format!("{}.rs", module_name));
+    fs::write(&output_path, output)?;
+
+    Ok(ModuleInfo {
+        module_name,
+        instruction_structs,
+    })
+}
+
+fn generate_requirements(
+    input_file: &Path,
+    output_file: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(input_file)?;
+    let file_name = input_file.file_name().unwrap().to_string_lossy();
+
+    eprint!("Processing: {} ... ", file_name);
+    let output =
+        parser_gen::requirements_generator::generate_requirements_file(&content, &file_name)?;
+    fs::write(output_file, output)?;
+    eprintln!("OK");
+
+    Ok(())
+}
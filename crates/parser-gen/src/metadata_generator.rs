@@ -0,0 +1,487 @@
+use crate::analyzer::{AnalyzedInstruction, AnalyzedModifier, AnalyzedSection};
+
+/// Output produced when generating metadata accessors for a PTX section
+pub struct GeneratedMetadataOutput {
+    /// Generated Rust code containing all `Metadata` implementations for the section
+    pub code: String,
+    /// Struct names emitted for the instructions, used for dispatcher generation
+    pub instruction_structs: Vec<String>,
+    /// Module name for this section
+    pub module_name: String,
+}
+
+/// Generator that emits `Metadata` implementations describing the opcode,
+/// modifiers and originating rule of analyzed PTX instructions.
+pub struct MetadataGenerator {
+    /// Name of the specification file, e.g. `ld.txt`.
+    file_name: String,
+    /// Locates instruction rules in the specification file.
+    rules: RuleLocator,
+    /// Counter used to build unique binding names when destructuring enums.
+    tmp_counter: usize,
+}
+
+impl MetadataGenerator {
+    pub fn new(file_name: &str, spec_content: &str) -> Self {
+        Self {
+            file_name: file_name.to_string(),
+            rules: RuleLocator::new(spec_content),
+            tmp_counter: 0,
+        }
+    }
+
+    /// Generate metadata implementations for an analyzed section.
+    pub fn generate(
+        &mut self,
+        section: &AnalyzedSection,
+        section_idx: usize,
+        type_module_name: &str,
+    ) -> GeneratedMetadataOutput {
+        let mut struct_names = Vec::new();
+        let mut impl_output = String::new();
+
+        for instr in &section.instructions {
+            struct_names.push(instr.rust_name.clone());
+            impl_output.push_str(&self.generate_instruction_metadata(instr, section_idx));
+            impl_output.push('\n');
+        }
+
+        let module_name = format!("section_{}", section_idx);
+
+        let mut output = String::new();
+        output.push_str(&format!("pub mod {} {{\n", module_name));
+        output.push_str("    use super::*;\n");
+        output.push_str(&format!(
+            "    use crate::r#type::instruction::{}::{}::*;\n\n",
+            type_module_name, module_name
+        ));
+
+        for line in impl_output.lines() {
+            if !line.is_empty() {
+                output.push_str("    ");
+            }
+            output.push_str(line);
+            output.push('\n');
+        }
+
+        output.push_str("}\n");
+
+        GeneratedMetadataOutput {
+            code: output,
+            instruction_structs: struct_names,
+            module_name,
+        }
+    }
+
+    /// Generate helper imports used before section modules.
+    pub fn generate_imports() -> String {
+        "use crate::metadata::{Metadata, SpecSection};".to_string()
+    }
+
+    fn generate_instruction_metadata(
+        &mut self,
+        instr: &AnalyzedInstruction,
+        section_idx: usize,
+    ) -> String {
+        let mut output = String::new();
+        output.push_str(&format!("impl Metadata for {} {{\n", instr.rust_name));
+        output.push_str("    fn opcode(&self) -> &'static str {\n");
+        output.push_str(&format!("        {:?}\n", instr.head.opcode));
+        output.push_str("    }\n");
+        output.push_str("    fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {\n");
+        for (modifier, rust_name) in &instr.head.modifiers {
+            output.push_str(&self.generate_modifier_field(modifier, rust_name, 2));
+        }
+        output.push_str("    }\n");
+        let (line, rule) = self
+            .rules
+            .locate(&instr.raw)
+            .unwrap_or_else(|| (0, instr.raw.trim().to_string()));
+        output.push_str("    fn spec_section(&self) -> SpecSection {\n");
+        output.push_str("        SpecSection {\n");
+        output.push_str(&format!("            file: {:?},\n", self.file_name));
+        output.push_str(&format!("            section: {},\n", section_idx));
+        output.push_str(&format!("            line: {},\n", line));
+        output.push_str(&format!("            rule: {:?},\n", rule));
+        output.push_str("        }\n");
+        output.push_str("    }\n");
+        output.push_str("}\n");
+        output
+    }
+
+    /// Mirrors `UnparserGenerator::generate_modifier_field_unparse`, reporting
+    /// each modifier to `f` instead of pushing tokens.
+    fn generate_modifier_field(
+        &mut self,
+        modifier: &AnalyzedModifier,
+        field_name: &str,
+        indent: usize,
+    ) -> String {
+        match modifier {
+            AnalyzedModifier::Optional(inner) => {
+                let (inner_mod, _) = inner.as_ref();
+                let mut code = String::new();
+                if matches!(inner_mod, AnalyzedModifier::Atom(_)) {
+                    code.push_str(&format!(
+                        "{}if self.{} {{\n",
+                        self.indent(indent),
+                        field_name
+                    ));
+                    code.push_str(&self.generate_modifier_value(inner_mod, "", indent + 1));
+                } else {
+                    let binding = self.unique_binding(field_name);
+                    code.push_str(&format!(
+                        "{}if let Some({}) = self.{}.as_ref() {{\n",
+                        self.indent(indent),
+                        binding,
+                        field_name
+                    ));
+                    code.push_str(&self.generate_modifier_value(inner_mod, &binding, indent + 1));
+                }
+                code.push_str(&format!("{}}}\n", self.indent(indent)));
+                code
+            }
+            _ => self.generate_modifier_value(modifier, &format!("&self.{}", field_name), indent),
+        }
+    }
+
+    fn generate_modifier_value(
+        &mut self,
+        modifier: &AnalyzedModifier,
+        value_expr: &str,
+        indent: usize,
+    ) -> String {
+        match modifier {
+            AnalyzedModifier::Atom((ident, _)) => self.emit_literal(ident, indent),
+            AnalyzedModifier::Sequence(items) => items
+                .iter()
+                .map(|(item, _)| self.emit_literals(item, indent))
+                .collect(),
+            AnalyzedModifier::Choice { base, options } => {
+                let enum_name = &base.1;
+                let match_expr = if value_expr.is_empty() {
+                    format!("&self.{}", crate::naming::sanitize_field_name(&base.0))
+                } else {
+                    value_expr.to_string()
+                };
+                let mut code = String::new();
+                code.push_str(&format!("{}match {} {{\n", self.indent(indent), match_expr));
+                for (option, variant_name) in options {
+                    let (pattern, bindings) =
+                        Self::choice_variant_pattern(enum_name, variant_name, option);
+                    code.push_str(&format!("{}{} => {{\n", self.indent(indent + 1), pattern));
+                    code.push_str(&self.emit_literals_with_bindings(option, &bindings, indent + 2));
+                    code.push_str(&format!("{}}}\n", self.indent(indent + 1)));
+                }
+                code.push_str(&format!("{}}}\n", self.indent(indent)));
+                code
+            }
+            AnalyzedModifier::Optional(inner) => {
+                self.generate_modifier_value(&inner.0, value_expr, indent)
+            }
+        }
+    }
+
+    fn emit_literal(&self, ident: &str, indent: usize) -> String {
+        format!("{}f({:?});\n", self.indent(indent), ident)
+    }
+
+    fn emit_literals(&self, modifier: &AnalyzedModifier, indent: usize) -> String {
+        match modifier {
+            AnalyzedModifier::Atom((ident, _)) => self.emit_literal(ident, indent),
+            AnalyzedModifier::Sequence(items) => items
+                .iter()
+                .map(|(item, _)| self.emit_literals(item, indent))
+                .collect(),
+            AnalyzedModifier::Optional(inner) => self.emit_literals(&inner.0, indent),
+            AnalyzedModifier::Choice { options, .. } => options
+                .iter()
+                .map(|(option, _)| self.emit_literals(option, indent))
+                .collect(),
+        }
+    }
+
+    fn emit_literals_with_bindings(
+        &self,
+        modifier: &AnalyzedModifier,
+        bindings: &[String],
+        indent: usize,
+    ) -> String {
+        match modifier {
+            AnalyzedModifier::Sequence(items)
+                if Self::is_combinable_sequence(items) && !bindings.is_empty() =>
+            {
+                // `.b.n.n.n.n` selectors are written as a single modifier, e.g. `.b3210`.
+                let prefix = match &items[0].0 {
+                    AnalyzedModifier::Atom((ident, _)) => ident.clone(),
+                    _ => ".b".to_string(),
+                };
+                let indent_str = self.indent(indent);
+                let mut code = format!(
+                    "{}let mut combined = String::from({:?});\n",
+                    indent_str, prefix
+                );
+                for binding in &bindings[1..] {
+                    code.push_str(&format!(
+                        "{}combined.push_str(format!(\"{{:?}}\", {}).trim_start_matches('_'));\n",
+                        indent_str, binding
+                    ));
+                }
+                code.push_str(&format!("{}f(&combined);\n", indent_str));
+                code
+            }
+            AnalyzedModifier::Optional(inner) => {
+                self.emit_literals_with_bindings(&inner.0, bindings, indent)
+            }
+            AnalyzedModifier::Choice { options, .. } => options
+                .iter()
+                .map(|(option, _)| self.emit_literals_with_bindings(option, bindings, indent))
+                .collect(),
+            _ => self.emit_literals(modifier, indent),
+        }
+    }
+
+    /// Pattern matching `variant_name`, and the bindings it introduces.
+    /// Follows the variant shapes chosen by the type generator.
+    fn choice_variant_pattern(
+        enum_name: &str,
+        variant_name: &str,
+        option: &AnalyzedModifier,
+    ) -> (String, Vec<String>) {
+        match option {
+            AnalyzedModifier::Sequence(items) if items.is_empty() => {
+                (format!("{}::{}()", enum_name, variant_name), vec![])
+            }
+            AnalyzedModifier::Sequence(items) if Self::is_combinable_sequence(items) => {
+                let bindings: Vec<String> = (0..items.len())
+                    .map(|i| {
+                        if i == 0 {
+                            "_".to_string()
+                        } else {
+                            format!("n{}", i)
+                        }
+                    })
+                    .collect();
+                (
+                    format!("{}::{}({})", enum_name, variant_name, bindings.join(", ")),
+                    bindings,
+                )
+            }
+            AnalyzedModifier::Sequence(items) => {
+                let args = vec!["_"; items.len()].join(", ");
+                (format!("{}::{}({})", enum_name, variant_name, args), vec![])
+            }
+            AnalyzedModifier::Optional(inner) => {
+                let (pattern, bindings) =
+                    Self::choice_variant_pattern(enum_name, variant_name, &inner.0);
+                if pattern.contains('(') {
+                    (pattern, bindings)
+                } else {
+                    (format!("{}::{}(..)", enum_name, variant_name), vec![])
+                }
+            }
+            AnalyzedModifier::Choice { .. } => {
+                (format!("{}::{}(..)", enum_name, variant_name), vec![])
+            }
+            AnalyzedModifier::Atom(_) => (format!("{}::{}", enum_name, variant_name), vec![]),
+        }
+    }
+
+    /// Whether a sequence is a `.b.n.n.n.n` style selector: a directive
+    /// followed only by choices.
+    fn is_combinable_sequence(items: &[(AnalyzedModifier, String)]) -> bool {
+        items.len() >= 2
+            && matches!(&items[0].0, AnalyzedModifier::Atom((ident, _)) if ident.starts_with('.'))
+            && items[1..]
+                .iter()
+                .all(|(modifier, _)| matches!(modifier, AnalyzedModifier::Choice { .. }))
+    }
+
+    fn indent(&self, level: usize) -> String {
+        "        ".repeat(level)
+    }
+
+    fn unique_binding(&mut self, seed: &str) -> String {
+        let name = crate::naming::sanitize_field_name(seed);
+        let result = if name.is_empty() {
+            format!("tmp{}", self.tmp_counter)
+        } else {
+            format!("{}_{}", name, self.tmp_counter)
+        };
+        self.tmp_counter += 1;
+        result
+    }
+}
+
+/// Finds the source text of instruction rules in a specification file.
+///
+/// The analyzer only keeps a token-joined copy of each rule, so rules are
+/// matched against the file with all whitespace removed. Rules are searched
+/// in file order, so repeated rules resolve to successive occurrences.
+struct RuleLocator {
+    /// Non-whitespace characters of the file.
+    compact: String,
+    /// Byte offset in the file of each byte of `compact`.
+    offsets: Vec<usize>,
+    source: String,
+    /// Position in `compact` where the next search starts.
+    cursor: usize,
+}
+
+impl RuleLocator {
+    fn new(source: &str) -> Self {
+        let mut compact = String::new();
+        let mut offsets = Vec::new();
+        for (offset, ch) in source.char_indices() {
+            if !ch.is_whitespace() {
+                compact.push(ch);
+                offsets.extend((0..ch.len_utf8()).map(|i| offset + i));
+            }
+        }
+        Self {
+            compact,
+            offsets,
+            source: source.to_string(),
+            cursor: 0,
+        }
+    }
+
+    /// 1-based line and whitespace-normalized text of the rule `raw`,
+    /// including its terminating semicolon.
+    fn locate(&mut self, raw: &str) -> Option<(usize, String)> {
+        let needle: String = raw.chars().filter(|ch| !ch.is_whitespace()).collect();
+        if needle.is_empty() {
+            return None;
+        }
+        let found = self.compact[self.cursor..]
+            .find(&needle)
+            .map(|at| self.cursor + at)
+            .or_else(|| self.compact.find(&needle))?;
+        let mut last = found + needle.len() - 1;
+        if self.compact[last + 1..].starts_with(';') {
+            last += 1;
+        }
+        self.cursor = last + 1;
+
+        let start = self.offsets[found];
+        let end = self.offsets[last] + 1;
+        let line = self.source[..start].matches('\n').count() + 1;
+        let text = self.source[start..end]
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        Some((line, text))
+    }
+}
+
+/// Generate the content for `src/metadata/instruction/mod.rs`.
+pub fn generate_metadata_mod_rs_content(modules: &[(String, Vec<(String, String)>)]) -> String {
+    let mut output = String::new();
+    output.push_str("// Auto-generated module declarations\n");
+    output.push_str("// DO NOT EDIT MANUALLY\n");
+    output.push_str("#![allow(unused)]\n\n");
+    output.push_str("use crate::metadata::{Metadata, SpecSection};\n");
+    output.push_str("use crate::r#type::instruction::Inst;\n\n");
+
+    for (module_name, _) in modules {
+        output.push_str(&format!("pub mod {};\n", module_name));
+    }
+    output.push('\n');
+
+    let methods = [
+        ("fn opcode(&self) -> &'static str", "opcode()"),
+        (
+            "fn for_each_modifier(&self, f: &mut dyn FnMut(&str))",
+            "for_each_modifier(f)",
+        ),
+        ("fn spec_section(&self) -> SpecSection", "spec_section()"),
+    ];
+    output.push_str("impl Metadata for Inst {\n");
+    for (signature, call) in methods {
+        output.push_str(&format!("    {} {{\n", signature));
+        output.push_str("        match self {\n");
+        for (_module_name, structs) in modules {
+            for (_section_name, struct_name) in structs {
+                output.push_str(&format!(
+                    "            Inst::{}(value) => value.{},\n",
+                    struct_name, call
+                ));
+            }
+        }
+        output.push_str("        }\n");
+        output.push_str("    }\n");
+    }
+    output.push_str("}\n");
+
+    output
+}
+
+/// Generate complete metadata file from PTX specification content
+/// Returns (generated_code, module_info(module_name, instruction_structs))
+pub fn generate_metadata_file(
+    spec_content: &str,
+    file_name: &str,
+    module_name: &str,
+) -> Result<(String, (String, Vec<(String, String)>)), Box<dyn std::error::Error>> {
+    use crate::analyzer::Analyzer;
+
+    let sections = crate::parse_spec_with_name(spec_content, file_name)?;
+
+    if sections.is_empty() {
+        return Err("No sections found in file".into());
+    }
+
+    let mut analyzer = Analyzer::new();
+    let analyzed_sections = analyzer.analyze_sections(&sections);
+
+    if analyzed_sections.is_empty() {
+        return Err("No instructions found".into());
+    }
+
+    let mut all_outputs = Vec::new();
+    let mut metadata_gen = MetadataGenerator::new(file_name, spec_content);
+
+    for (section_idx, section) in analyzed_sections.iter().enumerate() {
+        let generated = metadata_gen.generate(section, section_idx, module_name);
+
+        if !generated.code.trim().is_empty() {
+            all_outputs.push(generated);
+        }
+    }
+
+    if all_outputs.is_empty() {
+        return Err("No instructions found".into());
+    }
+
+    let all_instruction_structs: Vec<(String, String)> = all_outputs
+        .iter()
+        .flat_map(|output| {
+            let section_name = output.module_name.clone();
+            output
+                .instruction_structs
+                .iter()
+                .map(move |struct_name| (section_name.clone(), struct_name.clone()))
+        })
+        .collect();
+
+    let mut output = String::new();
+    output.push_str("//! Original PTX specification:\n");
+    output.push_str("//!\n");
+    for line in spec_content.lines() {
+        output.push_str("//! ");
+        output.push_str(line);
+        output.push('\n');
+    }
+    output.push('\n');
+    output.push_str("#![allow(unused)]\n");
+    output.push('\n');
+    output.push_str(&MetadataGenerator::generate_imports());
+    output.push_str("\n\n");
+
+    for gen_output in all_outputs.iter() {
+        output.push_str(&gen_output.code);
+        output.push('\n');
+    }
+
+    Ok((output, (module_name.to_string(), all_instruction_structs)))
+}
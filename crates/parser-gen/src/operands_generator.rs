@@ -0,0 +1,436 @@
+use crate::analyzer::{AnalyzedInstruction, AnalyzedOperandElement, AnalyzedSection};
+
+/// Output produced when generating operand accessors for a PTX section
+pub struct GeneratedOperandsOutput {
+    /// Generated Rust code containing all `Operands` implementations for the section
+    pub code: String,
+    /// Struct names emitted for the instructions, used for dispatcher generation
+    pub instruction_structs: Vec<String>,
+    /// Module name for this section
+    pub module_name: String,
+}
+
+/// Generator that emits `Operands` implementations listing the operands of
+/// analyzed PTX instructions together with their roles.
+#[derive(Default)]
+pub struct OperandsGenerator;
+
+/// Role assigned to an operand field, named after the `OperandRole` variant.
+#[derive(Clone, Copy)]
+enum Role {
+    Destination,
+    ReadWrite,
+    Source,
+    Address,
+    PredicateOut,
+}
+
+impl Role {
+    fn path(self) -> &'static str {
+        match self {
+            Role::Destination => "OperandRole::Destination",
+            Role::ReadWrite => "OperandRole::ReadWrite",
+            Role::Source => "OperandRole::Source",
+            Role::Address => "OperandRole::Address",
+            Role::PredicateOut => "OperandRole::PredicateOut",
+        }
+    }
+}
+
+impl OperandsGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Generate operand accessor implementations for an analyzed section.
+    pub fn generate(
+        &mut self,
+        section: &AnalyzedSection,
+        section_idx: usize,
+        type_module_name: &str,
+    ) -> GeneratedOperandsOutput {
+        let mut struct_names = Vec::new();
+        let mut impl_output = String::new();
+
+        for instr in &section.instructions {
+            struct_names.push(instr.rust_name.clone());
+            impl_output.push_str(&self.generate_instruction_operands(instr));
+            impl_output.push('\n');
+        }
+
+        let module_name = format!("section_{}", section_idx);
+
+        let mut output = String::new();
+        output.push_str(&format!("pub mod {} {{\n", module_name));
+        output.push_str("    use super::*;\n");
+        output.push_str(&format!(
+            "    use crate::r#type::instruction::{}::{}::*;\n\n",
+            type_module_name, module_name
+        ));
+
+        for line in impl_output.lines() {
+            if !line.is_empty() {
+                output.push_str("    ");
+            }
+            output.push_str(line);
+            output.push('\n');
+        }
+
+        output.push_str("}\n");
+
+        GeneratedOperandsOutput {
+            code: output,
+            instruction_structs: struct_names,
+            module_name,
+        }
+    }
+
+    /// Generate helper imports used before section modules.
+    pub fn generate_imports() -> String {
+        "use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};".to_string()
+    }
+
+    fn generate_instruction_operands(&self, instr: &AnalyzedInstruction) -> String {
+        let first_role = first_operand_role(instr);
+        let mut shared = String::new();
+        let mut exclusive = String::new();
+        for (index, operand) in instr.operands.iter().enumerate() {
+            let leading = if index == 0 { first_role } else { Role::Source };
+            shared.push_str(&Self::operand_calls(&operand.operand, leading, false));
+            exclusive.push_str(&Self::operand_calls(&operand.operand, leading, true));
+        }
+
+        let mut output = String::new();
+        output.push_str(&format!("impl Operands for {} {{\n", instr.rust_name));
+        output.push_str(
+            "    fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {\n",
+        );
+        output.push_str(&shared);
+        output.push_str("    }\n");
+        output.push_str(
+            "    fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {\n",
+        );
+        output.push_str(&exclusive);
+        output.push_str("    }\n");
+        output.push_str("}\n");
+        output
+    }
+
+    /// Calls to `f` for the operand fields backing `element`, in source order.
+    ///
+    /// `leading` is the role of plain register operands: the role of the
+    /// first operand for the leading element, `Source` for the others.
+    fn operand_calls(element: &AnalyzedOperandElement, leading: Role, mutable: bool) -> String {
+        let (ty, borrow) = if mutable {
+            ("OperandMut", "&mut ")
+        } else {
+            ("OperandRef", "&")
+        };
+        let call = |kind: &str, name: &str, role: Role, expr: &str| {
+            format!(
+                "        f({}::{}({:?}, {}, {}{}));\n",
+                ty,
+                kind,
+                name,
+                role.path(),
+                borrow,
+                expr
+            )
+        };
+        // `binding` already holds a reference, bound by a preceding `let`.
+        let call_bound = |name: &str, role: Role, binding: &str| {
+            format!(
+                "        f({}::general({:?}, {}, {}));\n",
+                ty,
+                name,
+                role.path(),
+                binding
+            )
+        };
+        let call_optional = |name: &str, role: Role, expr: &str| {
+            format!(
+                "        if let Some(operand) = {}{} {{ f({}::general({:?}, {}, operand)); }}\n",
+                borrow,
+                expr,
+                ty,
+                name,
+                role.path()
+            )
+        };
+        match element {
+            AnalyzedOperandElement::Item((name, rust_name)) => {
+                call("general", name, leading, &format!("self.{}", rust_name))
+            }
+            // `(ret-param)` of `call` receives the return value.
+            AnalyzedOperandElement::ParenthesizedOperand((name, rust_name)) => call(
+                "general",
+                name,
+                Role::Destination,
+                &format!("self.{}", rust_name),
+            ),
+            AnalyzedOperandElement::Address((name, rust_name)) => call(
+                "address",
+                name,
+                Role::Address,
+                &format!("self.{}", rust_name),
+            ),
+            AnalyzedOperandElement::Optional((name, rust_name)) => {
+                call_optional(name, leading, &format!("self.{}", rust_name))
+            }
+            // `d|p`: the second half is the predicate written alongside `d`.
+            AnalyzedOperandElement::PipeChoice(((first, first_name), (second, second_name))) => {
+                let mut code = call("general", first, leading, &format!("self.{}", first_name));
+                code.push_str(&call(
+                    "general",
+                    second,
+                    Role::PredicateOut,
+                    &format!("self.{}", second_name),
+                ));
+                code
+            }
+            AnalyzedOperandElement::PipeOptionalChoice((
+                (first, first_name),
+                (second, second_name),
+            )) => {
+                let mut code = call("general", first, leading, &format!("self.{}", first_name));
+                code.push_str(&call_optional(
+                    second,
+                    Role::PredicateOut,
+                    &format!("self.{}", second_name),
+                ));
+                code
+            }
+            AnalyzedOperandElement::CurlyGroup(items) => {
+                let (name, field) = items
+                    .first()
+                    .map(|(name, rust_name)| (name.clone(), rust_name.clone()))
+                    .unwrap_or_else(|| ("group".to_string(), "group".to_string()));
+                call("vector", &name, leading, &format!("self.{}", field))
+            }
+            // Texture and surface handles are read, whatever the position.
+            AnalyzedOperandElement::SquareGroup(items) => {
+                let field = items
+                    .first()
+                    .map(|(_, name, _)| name.clone())
+                    .unwrap_or_else(|| "group".to_string());
+                let name = |index: usize| items[index].0.as_str();
+                // Mirrors the texture handler types chosen by the type generator.
+                match items.len() {
+                    // Destructured so that the mutable borrows stay disjoint.
+                    2 => {
+                        let mut code = format!(
+                            "        let [first, second] = {}self.{}.operands;\n",
+                            borrow, field
+                        );
+                        code.push_str(&call_bound(name(0), Role::Source, "first"));
+                        code.push_str(&call_bound(name(1), Role::Source, "second"));
+                        code
+                    }
+                    3 => {
+                        let mut code = call(
+                            "general",
+                            name(0),
+                            Role::Source,
+                            &format!("self.{}.handle", field),
+                        );
+                        if items.iter().any(|(_, _, optional)| *optional) {
+                            code.push_str(&call_optional(
+                                name(1),
+                                Role::Source,
+                                &format!("self.{}.sampler", field),
+                            ));
+                        } else {
+                            code.push_str(&call(
+                                "general",
+                                name(1),
+                                Role::Source,
+                                &format!("self.{}.sampler", field),
+                            ));
+                        }
+                        code.push_str(&call(
+                            "general",
+                            name(2),
+                            Role::Source,
+                            &format!("self.{}.coords", field),
+                        ));
+                        code
+                    }
+                    len => (0..len)
+                        .map(|index| {
+                            call(
+                                "general",
+                                name(index),
+                                Role::Source,
+                                &format!("self.{}.{}", field, index),
+                            )
+                        })
+                        .collect(),
+                }
+            }
+            AnalyzedOperandElement::ParamList(rust_name) => format!(
+                "        for operand in {}self.{} {{ f({}::general({:?}, {}, operand)); }}\n",
+                borrow,
+                rust_name,
+                ty,
+                rust_name,
+                Role::Source.path()
+            ),
+            // Literal operands and operand choices carry no operand values.
+            AnalyzedOperandElement::ImmediateNumber(_) | AnalyzedOperandElement::Choice { .. } => {
+                String::new()
+            }
+        }
+    }
+}
+
+/// Role of the leading operand of `instr`.
+///
+/// PTX puts the destination first, so this is `Destination` unless the
+/// instruction has no destination at all, or also reads it back as an
+/// accumulator. Leading addresses (`st [a], b`) are handled by the operand
+/// kind and do not need to be listed here.
+fn first_operand_role(instr: &AnalyzedInstruction) -> Role {
+    // `wgmma.mma_async d, ..., scale-d` computes `d = a*b + scale-d * d`.
+    if spelling(instr).starts_with("wgmma.mma_async") {
+        return Role::ReadWrite;
+    }
+    if first_operand_is_written(instr) {
+        Role::Destination
+    } else {
+        Role::Source
+    }
+}
+
+/// Whether the leading operand of `instr` is written by the instruction.
+fn first_operand_is_written(instr: &AnalyzedInstruction) -> bool {
+    let raw = spelling(instr);
+    match instr.head.opcode.as_str() {
+        "bra" | "brx" | "call" | "nanosleep" | "pmevent" | "setmaxnreg" | "stackrestore" => false,
+        // Only the reduction forms of `bar` and `barrier` produce a value.
+        "bar" | "barrier" => raw.contains(".red"),
+        "tcgen05" => !raw.starts_with("tcgen05.dealloc"),
+        _ => !raw.contains(".wait_group"),
+    }
+}
+
+/// The rule of `instr` without whitespace; `raw` separates the tokens of the
+/// instruction head with spaces (`wgmma . mma_async . sync`).
+fn spelling(instr: &AnalyzedInstruction) -> String {
+    instr.raw.chars().filter(|ch| !ch.is_whitespace()).collect()
+}
+
+/// Generate the content for `src/operands/instruction/mod.rs`.
+pub fn generate_operands_mod_rs_content(modules: &[(String, Vec<(String, String)>)]) -> String {
+    let mut output = String::new();
+    output.push_str("// Auto-generated module declarations\n");
+    output.push_str("// DO NOT EDIT MANUALLY\n");
+    output.push_str("#![allow(unused)]\n\n");
+    output.push_str("use crate::operands::{OperandMut, OperandRef, Operands};\n");
+    output.push_str("use crate::r#type::instruction::Inst;\n\n");
+
+    for (module_name, _) in modules {
+        output.push_str(&format!("pub mod {};\n", module_name));
+    }
+    output.push('\n');
+
+    output.push_str("impl Operands for Inst {\n");
+    output.push_str("    fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {\n");
+    output.push_str("        match self {\n");
+    for (_module_name, structs) in modules {
+        for (_section_name, struct_name) in structs {
+            output.push_str(&format!(
+                "            Inst::{}(value) => value.for_each_operand(f),\n",
+                struct_name
+            ));
+        }
+    }
+    output.push_str("        }\n");
+    output.push_str("    }\n");
+    output.push_str(
+        "    fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {\n",
+    );
+    output.push_str("        match self {\n");
+    for (_module_name, structs) in modules {
+        for (_section_name, struct_name) in structs {
+            output.push_str(&format!(
+                "            Inst::{}(value) => value.for_each_operand_mut(f),\n",
+                struct_name
+            ));
+        }
+    }
+    output.push_str("        }\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+
+    output
+}
+
+/// Generate complete operand accessor file from PTX specification content
+/// Returns (generated_code, module_info(module_name, instruction_structs))
+pub fn generate_operands_file(
+    spec_content: &str,
+    file_name: &str,
+    module_name: &str,
+) -> Result<(String, (String, Vec<(String, String)>)), Box<dyn std::error::Error>> {
+    use crate::analyzer::Analyzer;
+
+    let sections = crate::parse_spec_with_name(spec_content, file_name)?;
+
+    if sections.is_empty() {
+        return Err("No sections found in file".into());
+    }
+
+    let mut analyzer = Analyzer::new();
+    let analyzed_sections = analyzer.analyze_sections(&sections);
+
+    if analyzed_sections.is_empty() {
+        return Err("No instructions found".into());
+    }
+
+    let mut all_outputs = Vec::new();
+    let mut operands_gen = OperandsGenerator::new();
+
+    for (section_idx, section) in analyzed_sections.iter().enumerate() {
+        let generated = operands_gen.generate(section, section_idx, module_name);
+
+        if !generated.code.trim().is_empty() {
+            all_outputs.push(generated);
+        }
+    }
+
+    if all_outputs.is_empty() {
+        return Err("No instructions found".into());
+    }
+
+    let all_instruction_structs: Vec<(String, String)> = all_outputs
+        .iter()
+        .flat_map(|output| {
+            let section_name = output.module_name.clone();
+            output
+                .instruction_structs
+                .iter()
+                .map(move |struct_name| (section_name.clone(), struct_name.clone()))
+        })
+        .collect();
+
+    let mut output = String::new();
+    output.push_str("//! Original PTX specification:\n");
+    output.push_str("//!\n");
+    for line in spec_content.lines() {
+        output.push_str("//! ");
+        output.push_str(line);
+        output.push('\n');
+    }
+    output.push('\n');
+    output.push_str("#![allow(unused)]\n");
+    output.push('\n');
+    output.push_str(&OperandsGenerator::generate_imports());
+    output.push_str("\n\n");
+
+    for gen_output in all_outputs.iter() {
+        output.push_str(&gen_output.code);
+        output.push('\n');
+    }
+
+    Ok((output, (module_name.to_string(), all_instruction_structs)))
+}
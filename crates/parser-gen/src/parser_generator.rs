@@ -684,7 +684,9 @@ pub fn generate_parser_mod_rs_content(
     output.push_str("// DO NOT EDIT MANUALLY\n");
     output.push_str("#![allow(unused)]\n\n");
 
-    output.push_str("use crate::parser::{PtxParser, PtxParseError, PtxTokenStream, Span};\n");
+    output.push_str(
+        "use crate::parser::{ParseErrorKind, PtxParser, PtxParseError, PtxTokenStream, Span};\n",
+    );
     output.push_str("use crate::r#type::instruction::Inst;\n");
     output.push_str("use crate::lexer::PtxToken;\n\n");
 
@@ -712,6 +714,9 @@ pub fn generate_parser_mod_rs_content(
     );
     output.push_str("    };\n");
     output.push_str("    \n");
+    output.push_str("    // Error of the candidate format that got furthest before failing\n");
+    output.push_str("    let mut closest: Option<(&'static str, PtxParseError)> = None;\n");
+    output.push_str("    \n");
     output.push_str("    // Dispatch based on opcode\n");
     output.push_str("    match opcode {\n");
 
@@ -743,7 +748,10 @@ pub fn generate_parser_mod_rs_content(
                 "                Ok((inst, _)) => return Ok(Inst::{}(inst)),\n",
                 struct_name
             ));
-            output.push_str("                Err(_) => {}\n");
+            output.push_str(&format!(
+                "                Err(err) => keep_furthest(&mut closest, opcode, \"{}::{}::{}\", err),\n",
+                module_name, section_name, struct_name
+            ));
             output.push_str("            }\n");
         }
         output.push_str("        }\n");
@@ -752,7 +760,17 @@ pub fn generate_parser_mod_rs_content(
     output.push_str("        _ => {}\n");
     output.push_str("    }\n");
     output.push_str("    \n");
-    output.push_str("    // If no parser matched, return error\n");
+    output.push_str("    // If no parser matched, report the candidate that got furthest\n");
+    output.push_str("    if let Some((candidate, cause)) = closest {\n");
+    output.push_str("        return Err(PtxParseError {\n");
+    output.push_str("            kind: ParseErrorKind::NoMatchingInstruction {\n");
+    output.push_str("                opcode: opcode.to_string(),\n");
+    output.push_str("                candidate: candidate.to_string(),\n");
+    output.push_str("                cause: Box::new(cause.kind),\n");
+    output.push_str("            },\n");
+    output.push_str("            span: cause.span,\n");
+    output.push_str("        });\n");
+    output.push_str("    }\n");
     output.push_str(
         "    let span = stream.peek().map(|(_, s)| s.clone()).unwrap_or(Span::new(0, 0));\n",
     );
@@ -762,6 +780,30 @@ pub fn generate_parser_mod_rs_content(
     output.push_str("}\n");
     output.push_str("\n");
 
+    output.push_str(
+        "/// Keep `err` if its candidate got further into the instruction than the current best.\n",
+    );
+    output.push_str(
+        "///\n/// On a tie, a format of the opcode's own module wins over one of a sibling module\n",
+    );
+    output.push_str("/// such as `ld_global_nc` for `ld`.\n");
+    output.push_str(
+        "fn keep_furthest(closest: &mut Option<(&'static str, PtxParseError)>, opcode: &str, candidate: &'static str, err: PtxParseError) {\n",
+    );
+    output.push_str(
+        "    let primary = |candidate: &str| candidate.split(\"::\").next() == Some(opcode);\n",
+    );
+    output.push_str("    if closest.as_ref().is_none_or(|(best_candidate, best)| {\n");
+    output.push_str("        err.span.start > best.span.start\n");
+    output.push_str(
+        "            || (err.span.start == best.span.start && primary(candidate) && !primary(best_candidate))\n",
+    );
+    output.push_str("    }) {\n");
+    output.push_str("        *closest = Some((candidate, err));\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+    output.push_str("\n");
+
     output.push_str("impl PtxParser for Inst {\n");
     output.push_str(
         "    fn parse() -> impl Fn(&mut PtxTokenStream) -> Result<(Self, Span), PtxParseError> {\n",
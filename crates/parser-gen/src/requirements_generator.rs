@@ -0,0 +1,201 @@
+//! Generator for the instruction requirement table.
+//!
+//! Reads `ptx_requirements.txt`, which lists the minimum PTX ISA version and
+//! the supported targets of instruction patterns, and emits
+//! `src/metadata/requirements.rs`. See the header of the data file for the
+//! format.
+
+use std::fmt;
+
+/// How a target in the data file is matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TargetVariant {
+    /// `sm_80`: the target or any later one.
+    Base,
+    /// `sm_100f`: the family-specific target or a later one of its family.
+    Family,
+    /// `sm_90a`: exactly the architecture-specific target.
+    Arch,
+}
+
+/// A target listed in the data file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequiredTarget {
+    pub sm: u32,
+    pub variant: TargetVariant,
+}
+
+/// One line of the data file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequirementEntry {
+    /// Pattern as written, e.g. `cp.async.bulk`.
+    pub pattern: String,
+    /// Opcode of the pattern, or `None` for `*`.
+    pub opcode: Option<String>,
+    /// Modifiers of the pattern including the leading dot.
+    pub modifiers: Vec<String>,
+    /// Minimum PTX ISA version as `(major, minor)`.
+    pub ptx: (u32, u32),
+    /// Supported targets; empty when any target will do.
+    pub targets: Vec<RequiredTarget>,
+}
+
+/// Error in the data file, with its 1-based line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequirementsError {
+    pub file_name: String,
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for RequirementsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.file_name, self.line, self.message)
+    }
+}
+
+impl std::error::Error for RequirementsError {}
+
+/// Parse the content of a requirements data file.
+pub fn parse_requirements(
+    content: &str,
+    file_name: &str,
+) -> Result<Vec<RequirementEntry>, RequirementsError> {
+    let mut entries = Vec::new();
+    for (index, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let error = |message: String| RequirementsError {
+            file_name: file_name.to_string(),
+            line: index + 1,
+            message,
+        };
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let [pattern, ptx, targets] = fields[..] else {
+            return Err(error(format!(
+                "expected `pattern ptx targets`, found {} fields",
+                fields.len()
+            )));
+        };
+        let (opcode, modifiers) = parse_pattern(pattern).map_err(&error)?;
+        let ptx = parse_version(ptx).map_err(&error)?;
+        let targets = if targets == "-" {
+            Vec::new()
+        } else {
+            targets
+                .split('|')
+                .map(parse_target)
+                .collect::<Result<_, _>>()
+                .map_err(&error)?
+        };
+        entries.push(RequirementEntry {
+            pattern: pattern.to_string(),
+            opcode,
+            modifiers,
+            ptx,
+            targets,
+        });
+    }
+    Ok(entries)
+}
+
+fn parse_pattern(pattern: &str) -> Result<(Option<String>, Vec<String>), String> {
+    let mut segments = pattern.split('.');
+    let opcode = segments.next().unwrap_or_default();
+    if opcode.is_empty() {
+        return Err(format!("pattern `{pattern}` has no opcode"));
+    }
+    let modifiers: Vec<String> = segments.map(|segment| format!(".{segment}")).collect();
+    if modifiers.iter().any(|modifier| modifier == ".") {
+        return Err(format!("pattern `{pattern}` has an empty modifier"));
+    }
+    if opcode == "*" {
+        if modifiers.is_empty() {
+            return Err("pattern `*` needs at least one modifier".to_string());
+        }
+        return Ok((None, modifiers));
+    }
+    Ok((Some(opcode.to_string()), modifiers))
+}
+
+fn parse_version(version: &str) -> Result<(u32, u32), String> {
+    version
+        .split_once('.')
+        .and_then(|(major, minor)| Some((major.parse().ok()?, minor.parse().ok()?)))
+        .ok_or_else(|| format!("invalid PTX version `{version}`"))
+}
+
+fn parse_target(target: &str) -> Result<RequiredTarget, String> {
+    let invalid = || format!("invalid target `{target}`");
+    let digits = target.strip_prefix("sm_").ok_or_else(invalid)?;
+    let (digits, variant) = if let Some(digits) = digits.strip_suffix('a') {
+        (digits, TargetVariant::Arch)
+    } else if let Some(digits) = digits.strip_suffix('f') {
+        (digits, TargetVariant::Family)
+    } else {
+        (digits, TargetVariant::Base)
+    };
+    let sm = digits.parse().map_err(|_| invalid())?;
+    Ok(RequiredTarget { sm, variant })
+}
+
+/// Generate the content for `src/metadata/requirements.rs`.
+pub fn generate_requirements_rs_content(entries: &[RequirementEntry]) -> String {
+    let mut output = String::new();
+    output.push_str("// Auto-generated module declarations\n");
+    output.push_str("// DO NOT EDIT MANUALLY\n");
+    output.push_str("#![allow(unused)]\n\n");
+    output.push_str("use crate::metadata::{PtxVersion, Requirement, Target};\n\n");
+    output.push_str("pub(crate) const REQUIREMENTS: &[Requirement] = &[\n");
+    for entry in entries {
+        let opcode = match &entry.opcode {
+            Some(opcode) => format!("Some({opcode:?})"),
+            None => "None".to_string(),
+        };
+        let modifiers = entry
+            .modifiers
+            .iter()
+            .map(|modifier| format!("{modifier:?}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let targets = entry
+            .targets
+            .iter()
+            .map(|target| {
+                let constructor = match target.variant {
+                    TargetVariant::Base => "base",
+                    TargetVariant::Family => "family",
+                    TargetVariant::Arch => "arch",
+                };
+                format!("Target::{}({})", constructor, target.sm)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        output.push_str("    Requirement {\n");
+        output.push_str(&format!("        pattern: {:?},\n", entry.pattern));
+        output.push_str(&format!("        opcode: {},\n", opcode));
+        output.push_str(&format!("        modifiers: &[{}],\n", modifiers));
+        output.push_str(&format!(
+            "        ptx: PtxVersion::new({}, {}),\n",
+            entry.ptx.0, entry.ptx.1
+        ));
+        output.push_str(&format!("        targets: &[{}],\n", targets));
+        output.push_str("    },\n");
+    }
+    output.push_str("];\n");
+    output
+}
+
+/// Generate the complete requirement table from the content of a data file.
+pub fn generate_requirements_file(
+    content: &str,
+    file_name: &str,
+) -> Result<String, Box<dyn std::error::Error>> {
+    let entries = parse_requirements(content, file_name)?;
+    if entries.is_empty() {
+        return Err(format!("{file_name}: no requirements found").into());
+    }
+    Ok(generate_requirements_rs_content(&entries))
+}
@@ -0,0 +1,301 @@
+use crate::analyzer::{AnalyzedInstruction, AnalyzedOperandElement, AnalyzedSection};
+
+/// Output produced when generating walkers for a PTX section
+pub struct GeneratedVisitorOutput {
+    /// Generated Rust code containing all `Walk` implementations for the section
+    pub code: String,
+    /// Struct names emitted for the instructions, used for dispatcher generation
+    pub instruction_structs: Vec<String>,
+    /// Module name for this section
+    pub module_name: String,
+}
+
+/// Generator that emits `Walk` implementations visiting the operands of
+/// analyzed PTX instructions.
+#[derive(Default)]
+pub struct VisitorGenerator;
+
+impl VisitorGenerator {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Generate walker implementations for an analyzed section.
+    pub fn generate(
+        &mut self,
+        section: &AnalyzedSection,
+        section_idx: usize,
+        type_module_name: &str,
+    ) -> GeneratedVisitorOutput {
+        let mut struct_names = Vec::new();
+        let mut impl_output = String::new();
+
+        for instr in &section.instructions {
+            struct_names.push(instr.rust_name.clone());
+            impl_output.push_str(&self.generate_instruction_walk(instr));
+            impl_output.push('\n');
+        }
+
+        let module_name = format!("section_{}", section_idx);
+
+        let mut output = String::new();
+        output.push_str(&format!("pub mod {} {{\n", module_name));
+        output.push_str("    use super::*;\n");
+        output.push_str(&format!(
+            "    use crate::r#type::instruction::{}::{}::*;\n\n",
+            type_module_name, module_name
+        ));
+
+        for line in impl_output.lines() {
+            if !line.is_empty() {
+                output.push_str("    ");
+            }
+            output.push_str(line);
+            output.push('\n');
+        }
+
+        output.push_str("}\n");
+
+        GeneratedVisitorOutput {
+            code: output,
+            instruction_structs: struct_names,
+            module_name,
+        }
+    }
+
+    /// Generate helper imports used before section modules.
+    pub fn generate_imports() -> String {
+        "use crate::visit::{Visitor, VisitorMut, Walk};".to_string()
+    }
+
+    fn generate_instruction_walk(&self, instr: &AnalyzedInstruction) -> String {
+        let mut shared = String::new();
+        let mut exclusive = String::new();
+        for operand in &instr.operands {
+            shared.push_str(&Self::operand_walk(&operand.operand, false));
+            exclusive.push_str(&Self::operand_walk(&operand.operand, true));
+        }
+
+        let mut output = String::new();
+        output.push_str(&format!("impl Walk for {} {{\n", instr.rust_name));
+        output.push_str(
+            "    fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {\n",
+        );
+        output.push_str(&shared);
+        output.push_str("    }\n");
+        output.push_str("    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {\n");
+        output.push_str(&exclusive);
+        output.push_str("    }\n");
+        output.push_str("}\n");
+        output
+    }
+
+    /// Visit calls for the operand fields backing `element`, in source order.
+    fn operand_walk(element: &AnalyzedOperandElement, mutable: bool) -> String {
+        let (suffix, borrow) = if mutable {
+            ("_mut", "&mut ")
+        } else {
+            ("", "&")
+        };
+        let visit = |method: &str, expr: &str| {
+            format!(
+                "        visitor.visit_{}{}({}{});\n",
+                method, suffix, borrow, expr
+            )
+        };
+        let visit_optional = |method: &str, expr: &str| {
+            format!(
+                "        if let Some(operand) = {}{} {{ visitor.visit_{}{}(operand); }}\n",
+                borrow, expr, method, suffix
+            )
+        };
+
+        match element {
+            AnalyzedOperandElement::Item((_, rust_name))
+            | AnalyzedOperandElement::ParenthesizedOperand((_, rust_name)) => {
+                visit("general_operand", &format!("self.{}", rust_name))
+            }
+            AnalyzedOperandElement::Address((_, rust_name)) => {
+                visit("address_operand", &format!("self.{}", rust_name))
+            }
+            AnalyzedOperandElement::Optional((_, rust_name)) => {
+                visit_optional("general_operand", &format!("self.{}", rust_name))
+            }
+            AnalyzedOperandElement::PipeChoice(((_, first_name), (_, second_name))) => {
+                let mut code = visit("general_operand", &format!("self.{}", first_name));
+                code.push_str(&visit("general_operand", &format!("self.{}", second_name)));
+                code
+            }
+            AnalyzedOperandElement::PipeOptionalChoice(((_, first_name), (_, second_name))) => {
+                let mut code = visit("general_operand", &format!("self.{}", first_name));
+                code.push_str(&visit_optional(
+                    "general_operand",
+                    &format!("self.{}", second_name),
+                ));
+                code
+            }
+            AnalyzedOperandElement::CurlyGroup(items) => {
+                let field = items
+                    .first()
+                    .map(|(_, name)| name.clone())
+                    .unwrap_or_else(|| "group".to_string());
+                visit("vector_operand", &format!("self.{}", field))
+            }
+            AnalyzedOperandElement::SquareGroup(items) => {
+                let field = items
+                    .first()
+                    .map(|(_, name, _)| name.clone())
+                    .unwrap_or_else(|| "group".to_string());
+                // Mirrors the texture handler types chosen by the type generator.
+                match items.len() {
+                    2 => format!(
+                        "        for operand in {}self.{}.operands {{ visitor.visit_general_operand{}(operand); }}\n",
+                        borrow, field, suffix
+                    ),
+                    3 => {
+                        let mut code = visit("general_operand", &format!("self.{}.handle", field));
+                        if items.iter().any(|(_, _, optional)| *optional) {
+                            code.push_str(&visit_optional(
+                                "general_operand",
+                                &format!("self.{}.sampler", field),
+                            ));
+                        } else {
+                            code.push_str(&visit(
+                                "general_operand",
+                                &format!("self.{}.sampler", field),
+                            ));
+                        }
+                        code.push_str(&visit("general_operand", &format!("self.{}.coords", field)));
+                        code
+                    }
+                    len => (0..len)
+                        .map(|index| visit("general_operand", &format!("self.{}.{}", field, index)))
+                        .collect(),
+                }
+            }
+            AnalyzedOperandElement::ParamList(rust_name) => format!(
+                "        for operand in {}self.{} {{ visitor.visit_general_operand{}(operand); }}\n",
+                borrow, rust_name, suffix
+            ),
+            // Literal operands and operand choices carry no operand values.
+            AnalyzedOperandElement::ImmediateNumber(_) | AnalyzedOperandElement::Choice { .. } => {
+                String::new()
+            }
+        }
+    }
+}
+
+/// Generate the content for `src/visit/instruction/mod.rs`.
+pub fn generate_visitor_mod_rs_content(modules: &[(String, Vec<(String, String)>)]) -> String {
+    let mut output = String::new();
+    output.push_str("// Auto-generated module declarations\n");
+    output.push_str("// DO NOT EDIT MANUALLY\n");
+    output.push_str("#![allow(unused)]\n\n");
+    output.push_str("use crate::r#type::instruction::Inst;\n");
+    output.push_str("use crate::visit::{Visitor, VisitorMut, Walk};\n\n");
+
+    for (module_name, _) in modules {
+        output.push_str(&format!("pub mod {};\n", module_name));
+    }
+    output.push('\n');
+
+    output.push_str("impl Walk for Inst {\n");
+    output
+        .push_str("    fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {\n");
+    output.push_str("        match self {\n");
+    for (_module_name, structs) in modules {
+        for (_section_name, struct_name) in structs {
+            output.push_str(&format!(
+                "            Inst::{}(value) => value.walk(visitor),\n",
+                struct_name
+            ));
+        }
+    }
+    output.push_str("        }\n");
+    output.push_str("    }\n");
+    output.push_str("    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {\n");
+    output.push_str("        match self {\n");
+    for (_module_name, structs) in modules {
+        for (_section_name, struct_name) in structs {
+            output.push_str(&format!(
+                "            Inst::{}(value) => value.walk_mut(visitor),\n",
+                struct_name
+            ));
+        }
+    }
+    output.push_str("        }\n");
+    output.push_str("    }\n");
+    output.push_str("}\n");
+
+    output
+}
+
+/// Generate complete visitor file from PTX specification content
+/// Returns (generated_code, module_info(module_name, instruction_structs))
+pub fn generate_visitor_file(
+    spec_content: &str,
+    file_name: &str,
+    module_name: &str,
+) -> Result<(String, (String, Vec<(String, String)>)), Box<dyn std::error::Error>> {
+    use crate::analyzer::Analyzer;
+
+    let sections = crate::parse_spec_with_name(spec_content, file_name)?;
+
+    if sections.is_empty() {
+        return Err("No sections found in file".into());
+    }
+
+    let mut analyzer = Analyzer::new();
+    let analyzed_sections = analyzer.analyze_sections(&sections);
+
+    if analyzed_sections.is_empty() {
+        return Err("No instructions found".into());
+    }
+
+    let mut all_outputs = Vec::new();
+    let mut visitor_gen = VisitorGenerator::new();
+
+    for (section_idx, section) in analyzed_sections.iter().enumerate() {
+        let generated = visitor_gen.generate(section, section_idx, module_name);
+
+        if !generated.code.trim().is_empty() {
+            all_outputs.push(generated);
+        }
+    }
+
+    if all_outputs.is_empty() {
+        return Err("No instructions found".into());
+    }
+
+    let all_instruction_structs: Vec<(String, String)> = all_outputs
+        .iter()
+        .flat_map(|output| {
+            let section_name = output.module_name.clone();
+            output
+                .instruction_structs
+                .iter()
+                .map(move |struct_name| (section_name.clone(), struct_name.clone()))
+        })
+        .collect();
+
+    let mut output = String::new();
+    output.push_str("//! Original PTX specification:\n");
+    output.push_str("//!\n");
+    for line in spec_content.lines() {
+        output.push_str("//! ");
+        output.push_str(line);
+        output.push('\n');
+    }
+    output.push('\n');
+    output.push_str("#![allow(unused)]\n");
+    output.push('\n');
+    output.push_str(&VisitorGenerator::generate_imports());
+    output.push_str("\n\n");
+
+    for gen_output in all_outputs.iter() {
+        output.push_str(&gen_output.code);
+        output.push('\n');
+    }
+
+    Ok((output, (module_name.to_string(), all_instruction_structs)))
+}
@@ -0,0 +1,67 @@
+use ptx_parser_gen::requirements_generator::{
+    RequiredTarget, TargetVariant, generate_requirements_file, parse_requirements,
+};
+
+#[test]
+fn parses_patterns_versions_and_targets() {
+    let entries = parse_requirements(
+        "
+# comment
+tcgen05            8.6  sm_100f|sm_110f
+*.L2::256B         7.4  sm_80
+mma.sp             7.1  -
+",
+        "requirements.txt",
+    )
+    .unwrap();
+
+    assert_eq!(entries.len(), 3);
+    assert_eq!(entries[0].opcode.as_deref(), Some("tcgen05"));
+    assert_eq!(entries[0].ptx, (8, 6));
+    assert_eq!(
+        entries[0].targets,
+        [
+            RequiredTarget {
+                sm: 100,
+                variant: TargetVariant::Family
+            },
+            RequiredTarget {
+                sm: 110,
+                variant: TargetVariant::Family
+            }
+        ]
+    );
+    assert_eq!(entries[1].opcode, None);
+    assert_eq!(entries[1].modifiers, [".L2::256B"]);
+    assert_eq!(entries[2].modifiers, [".sp"]);
+    assert!(entries[2].targets.is_empty());
+}
+
+#[test]
+fn reports_the_line_of_malformed_entries() {
+    let error =
+        parse_requirements("mma 6.4 sm_70\nwgmma 8.0 sm90a\n", "requirements.txt").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "requirements.txt:2: invalid target `sm90a`"
+    );
+
+    let error = parse_requirements("* 7.0 sm_80\n", "requirements.txt").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "requirements.txt:1: pattern `*` needs at least one modifier"
+    );
+}
+
+#[test]
+fn generated_table_is_up_to_date() {
+    let root = std::path::Path::new(env!("CARGO_MANIFEST_DIR"));
+    let content = std::fs::read_to_string(root.join("ptx_requirements.txt")).unwrap();
+    let generated = generate_requirements_file(&content, "ptx_requirements.txt").unwrap();
+    let checked_in =
+        std::fs::read_to_string(root.join("../../src/metadata/requirements.rs")).unwrap();
+    assert!(
+        generated == checked_in,
+        "src/metadata/requirements.rs is stale; run `ptx-parser-gen generate-requirements`"
+    );
+}
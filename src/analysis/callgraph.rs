@@ -0,0 +1,645 @@
+//! Call graph of a module.
+//!
+//! [`CallGraph::build`] links every `call` to the functions it may reach. A
+//! direct call names its callee, possibly through `.alias`. A call through a
+//! pointer is bounded by the `.calltargets` list it names, or by its
+//! `.callprototype`: then any function whose address is taken and whose
+//! signature matches the prototype may be called.
+//!
+//! ```
+//! use ptx_parser::analysis::{CallGraph, CallKind};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".func f() { call f; ret; } .func g() { ret; } .alias h, f; .entry k() { call h; ret; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let graph = CallGraph::build(&module);
+//!
+//!     let k = graph.function("k").unwrap();
+//!     let f = graph.function("h").unwrap();
+//!     assert_eq!(graph.node(f).name, "f");
+//!     assert_eq!(graph.callees(k), [f]);
+//!     assert_eq!(graph.call_sites()[1].kind, CallKind::Direct);
+//!     assert_eq!(graph.recursive_cycles(), [vec![f]]);
+//!     assert_eq!(graph.unreachable(), [graph.function("g").unwrap()]);
+//! });
+//! ```
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Write;
+use std::mem::discriminant;
+
+use super::FunctionRef;
+use crate::operands::OperandValue;
+use crate::r#type::instruction::Inst;
+use crate::r#type::{
+    CallPrototypeDirective, CodeLinkage, FunctionStatement, GeneralOperand, GlobalInitializer,
+    InitializerValue, Instruction, Module, ModuleDirective, ModuleVariableDirective, Operand,
+    ParameterDirective, StatementDirective,
+};
+
+/// Index of a [`FunctionNode`] in its [`CallGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FunctionId(u32);
+
+impl FunctionId {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A function of the module, or a callee it never declares.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FunctionNode<'a> {
+    pub name: &'a str,
+    /// The definition, else the first declaration. `None` for a callee that
+    /// is not declared in the module.
+    pub function: Option<FunctionRef<'a>>,
+    /// Linkage of `function`.
+    pub linkage: Option<&'a CodeLinkage>,
+}
+
+impl FunctionNode<'_> {
+    pub fn is_entry(&self) -> bool {
+        self.function.is_some_and(|function| function.is_entry())
+    }
+
+    /// Whether the module holds the body of the function.
+    pub fn is_defined(&self) -> bool {
+        self.function
+            .is_some_and(|function| function.body().is_some())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CallKind {
+    /// `call f, ...` naming the callee or an alias of it.
+    Direct,
+    /// Call through a pointer to one of the functions of a `.calltargets`
+    /// list.
+    CallTargets,
+    /// Call through a pointer to any address-taken function matching a
+    /// `.callprototype`.
+    Prototype,
+    /// Call through a pointer whose list or prototype is not declared; any
+    /// address-taken function may be called.
+    Unknown,
+}
+
+/// A `call` instruction and the functions it may reach.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallSite<'a> {
+    pub caller: FunctionId,
+    pub kind: CallKind,
+    /// Possible callees in ascending order.
+    pub callees: Vec<FunctionId>,
+    pub instruction: &'a Instruction,
+    /// Path of the call in the body of the caller, as in
+    /// [`CfgStatement::path`](super::CfgStatement::path).
+    pub path: Vec<usize>,
+}
+
+/// Operands of a `call` instruction, whichever of its forms it takes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CallOperands<'a> {
+    pub return_param: Option<&'a GeneralOperand>,
+    /// Function name of a direct call, pointer of an indirect one.
+    pub callee: &'a GeneralOperand,
+    pub params: &'a [GeneralOperand],
+    /// `.calltargets` or `.callprototype` label of an indirect call.
+    pub targets: Option<&'a GeneralOperand>,
+}
+
+impl<'a> CallOperands<'a> {
+    /// Operands of `instruction`, or `None` if it is not a `call`.
+    pub fn of(instruction: &'a Instruction) -> Option<Self> {
+        let operands = |return_param, callee, params, targets| CallOperands {
+            return_param,
+            callee,
+            params,
+            targets,
+        };
+        Some(match &instruction.inst {
+            Inst::CallUni(call) => {
+                operands(Some(&call.ret_param), &call.func, &call.param_list, None)
+            }
+            Inst::CallUni1(call) => operands(None, &call.func, &call.param_list, None),
+            Inst::CallUni2(call) => operands(None, &call.func, &[], None),
+            Inst::CallUni3(call) => operands(
+                Some(&call.ret_param),
+                &call.fptr,
+                &call.param_list,
+                Some(&call.flist),
+            ),
+            Inst::CallUni4(call) => operands(None, &call.fptr, &call.param_list, Some(&call.flist)),
+            Inst::CallUni5(call) => operands(None, &call.fptr, &[], Some(&call.flist)),
+            Inst::CallUni6(call) => operands(
+                Some(&call.ret_param),
+                &call.fptr,
+                &call.param_list,
+                Some(&call.fproto),
+            ),
+            Inst::CallUni7(call) => {
+                operands(None, &call.fptr, &call.param_list, Some(&call.fproto))
+            }
+            Inst::CallUni8(call) => operands(None, &call.fptr, &[], Some(&call.fproto)),
+            _ => return None,
+        })
+    }
+}
+
+/// The functions of a module and the calls between them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CallGraph<'a> {
+    nodes: Vec<FunctionNode<'a>>,
+    by_name: HashMap<&'a str, FunctionId>,
+    /// `.alias` names and their targets.
+    aliases: HashMap<&'a str, &'a str>,
+    sites: Vec<CallSite<'a>>,
+    callees: Vec<Vec<FunctionId>>,
+    callers: Vec<Vec<FunctionId>>,
+    address_taken: Vec<FunctionId>,
+}
+
+impl<'a> CallGraph<'a> {
+    pub fn build(module: &'a Module) -> Self {
+        let mut graph = CallGraph {
+            nodes: Vec::new(),
+            by_name: HashMap::new(),
+            aliases: HashMap::new(),
+            sites: Vec::new(),
+            callees: Vec::new(),
+            callers: Vec::new(),
+            address_taken: Vec::new(),
+        };
+        let mut bodies = Vec::new();
+        for directive in &module.directives {
+            let (function, linkage) = match directive {
+                ModuleDirective::EntryFunction {
+                    directive, linkage, ..
+                } => (FunctionRef::Entry(directive), linkage.as_ref()),
+                ModuleDirective::FuncFunction {
+                    directive, linkage, ..
+                } => (FunctionRef::Func(directive), linkage.as_ref()),
+                ModuleDirective::AliasFunction { directive, .. } => {
+                    graph
+                        .aliases
+                        .insert(&directive.alias.val, &directive.target.val);
+                    continue;
+                }
+                _ => continue,
+            };
+            let id = graph.node_named(function.name());
+            let node = &mut graph.nodes[id.index()];
+            if node.function.is_none() || (!node.is_defined() && function.body().is_some()) {
+                node.function = Some(function);
+                node.linkage = linkage;
+            }
+            if let Some(body) = function.body() {
+                bodies.push((id, &body.statements));
+            }
+        }
+
+        // Functions whose address is taken by an initializer or an operand
+        // other than the target of a call.
+        let mut taken = Vec::new();
+        for directive in &module.directives {
+            if let ModuleDirective::ModuleVariable { directive, .. } = directive {
+                let (ModuleVariableDirective::Tex { directive, .. }
+                | ModuleVariableDirective::Shared { directive, .. }
+                | ModuleVariableDirective::Global { directive, .. }
+                | ModuleVariableDirective::Const { directive, .. }) = directive;
+                if let Some(initializer) = &directive.initializer {
+                    initializer_functions(initializer, &mut taken);
+                }
+            }
+        }
+        for (_, statements) in &bodies {
+            for (_, instruction) in instructions(statements) {
+                for operand in instruction.inst.operands() {
+                    if matches!(operand.name, "func" | "fptr" | "flist" | "fproto") {
+                        continue;
+                    }
+                    if let OperandValue::General(GeneralOperand::Single {
+                        operand:
+                            Operand::Symbol { name, .. } | Operand::SymbolOffset { symbol: name, .. },
+                        ..
+                    }) = operand.value
+                    {
+                        taken.push(name.as_str());
+                    }
+                }
+            }
+        }
+        let mut address_taken: Vec<FunctionId> = taken
+            .into_iter()
+            .filter_map(|name| graph.function(name))
+            .collect();
+        address_taken.sort_unstable();
+        address_taken.dedup();
+        graph.address_taken = address_taken;
+
+        for (caller, statements) in bodies {
+            let targets = target_directives(statements);
+            for (path, instruction) in instructions(statements) {
+                let Some(call) = CallOperands::of(instruction) else {
+                    continue;
+                };
+                let (kind, mut callees) = match call.targets.and_then(symbol_name) {
+                    None => match symbol_name(call.callee) {
+                        Some(name) => (CallKind::Direct, vec![graph.resolve_or_insert(name)]),
+                        None => (CallKind::Unknown, graph.address_taken.clone()),
+                    },
+                    Some(label) => match targets.get(label) {
+                        Some(StatementDirective::CallTargets { directive, .. }) => (
+                            CallKind::CallTargets,
+                            directive
+                                .targets
+                                .iter()
+                                .map(|target| graph.resolve_or_insert(&target.val))
+                                .collect(),
+                        ),
+                        Some(StatementDirective::CallPrototype { directive, .. }) => (
+                            CallKind::Prototype,
+                            graph
+                                .address_taken
+                                .iter()
+                                .copied()
+                                .filter(|&id| {
+                                    graph.nodes[id.index()].function.is_some_and(|function| {
+                                        matches_prototype(function, directive)
+                                    })
+                                })
+                                .collect(),
+                        ),
+                        _ => (CallKind::Unknown, graph.address_taken.clone()),
+                    },
+                };
+                callees.sort_unstable();
+                callees.dedup();
+                graph.sites.push(CallSite {
+                    caller,
+                    kind,
+                    callees,
+                    instruction,
+                    path,
+                });
+            }
+        }
+
+        graph.callees = vec![Vec::new(); graph.nodes.len()];
+        graph.callers = vec![Vec::new(); graph.nodes.len()];
+        for site in &graph.sites {
+            for &callee in &site.callees {
+                graph.callees[site.caller.index()].push(callee);
+                graph.callers[callee.index()].push(site.caller);
+            }
+        }
+        for edges in graph.callees.iter_mut().chain(&mut graph.callers) {
+            edges.sort_unstable();
+            edges.dedup();
+        }
+        graph
+    }
+
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn node(&self, id: FunctionId) -> &FunctionNode<'a> {
+        &self.nodes[id.index()]
+    }
+
+    /// Functions in order of first appearance, declarations before
+    /// undeclared callees.
+    pub fn nodes(&self) -> impl Iterator<Item = (FunctionId, &FunctionNode<'a>)> {
+        self.nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (FunctionId(index as u32), node))
+    }
+
+    /// The function called `name`, following `.alias` names.
+    pub fn function(&self, name: &str) -> Option<FunctionId> {
+        self.by_name
+            .get(resolve_alias(&self.aliases, name))
+            .copied()
+    }
+
+    /// Calls in program order.
+    pub fn call_sites(&self) -> &[CallSite<'a>] {
+        &self.sites
+    }
+
+    pub fn calls_from(&self, caller: FunctionId) -> impl Iterator<Item = &CallSite<'a>> {
+        self.sites.iter().filter(move |site| site.caller == caller)
+    }
+
+    /// Functions `id` may call, in ascending order.
+    pub fn callees(&self, id: FunctionId) -> &[FunctionId] {
+        &self.callees[id.index()]
+    }
+
+    /// Functions that may call `id`, in ascending order.
+    pub fn callers(&self, id: FunctionId) -> &[FunctionId] {
+        &self.callers[id.index()]
+    }
+
+    /// Functions whose address is taken, the candidates of calls through
+    /// `.callprototype`.
+    pub fn address_taken(&self) -> &[FunctionId] {
+        &self.address_taken
+    }
+
+    /// Groups of functions that call each other, including functions that
+    /// call themselves, each in ascending order.
+    pub fn recursive_cycles(&self) -> Vec<Vec<FunctionId>> {
+        // Tarjan's strongly connected components, without recursion.
+        let count = self.nodes.len();
+        let mut number = vec![usize::MAX; count];
+        let mut low = vec![0; count];
+        let mut on_stack = vec![false; count];
+        let mut stack = Vec::new();
+        let mut next_number = 0;
+        let mut cycles = Vec::new();
+        for start in 0..count {
+            if number[start] != usize::MAX {
+                continue;
+            }
+            let mut work = vec![(start, 0)];
+            number[start] = next_number;
+            low[start] = next_number;
+            next_number += 1;
+            stack.push(start);
+            on_stack[start] = true;
+            while let Some((node, next)) = work.last_mut() {
+                let node = *node;
+                if let Some(callee) = self.callees[node].get(*next) {
+                    *next += 1;
+                    let callee = callee.index();
+                    if number[callee] == usize::MAX {
+                        number[callee] = next_number;
+                        low[callee] = next_number;
+                        next_number += 1;
+                        stack.push(callee);
+                        on_stack[callee] = true;
+                        work.push((callee, 0));
+                    } else if on_stack[callee] {
+                        low[node] = low[node].min(number[callee]);
+                    }
+                    continue;
+                }
+                work.pop();
+                if let Some(&(parent, _)) = work.last() {
+                    low[parent] = low[parent].min(low[node]);
+                }
+                if low[node] != number[node] {
+                    continue;
+                }
+                let mut component = Vec::new();
+                while let Some(member) = stack.pop() {
+                    on_stack[member] = false;
+                    component.push(FunctionId(member as u32));
+                    if member == node {
+                        break;
+                    }
+                }
+                if component.len() > 1 || self.callees[node].contains(&FunctionId(node as u32)) {
+                    component.sort_unstable();
+                    cycles.push(component);
+                }
+            }
+        }
+        cycles.sort_unstable();
+        cycles
+    }
+
+    pub fn is_recursive(&self, id: FunctionId) -> bool {
+        self.recursive_cycles()
+            .iter()
+            .any(|cycle| cycle.contains(&id))
+    }
+
+    /// Defined `.func`s that no `.entry` reaches through calls.
+    pub fn unreachable(&self) -> Vec<FunctionId> {
+        let mut reached = vec![false; self.nodes.len()];
+        let mut queue: VecDeque<FunctionId> = self
+            .nodes()
+            .filter(|(_, node)| node.is_entry())
+            .map(|(id, _)| id)
+            .collect();
+        for id in &queue {
+            reached[id.index()] = true;
+        }
+        while let Some(id) = queue.pop_front() {
+            for &callee in self.callees(id) {
+                if !std::mem::replace(&mut reached[callee.index()], true) {
+                    queue.push_back(callee);
+                }
+            }
+        }
+        self.nodes()
+            .filter(|(id, node)| !reached[id.index()] && node.is_defined() && !node.is_entry())
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Called functions whose body is not in the module: `.extern`
+    /// declarations, prototypes and undeclared names.
+    pub fn extern_callees(&self) -> Vec<FunctionId> {
+        self.nodes()
+            .filter(|(id, node)| !node.is_defined() && !self.callers(*id).is_empty())
+            .map(|(id, _)| id)
+            .collect()
+    }
+
+    /// Render the graph in Graphviz DOT syntax.
+    ///
+    /// Kernels are boxes, functions without a body dashed and unreachable
+    /// functions grey. Calls through pointers are dotted.
+    pub fn to_dot(&self) -> String {
+        let unreachable = self.unreachable();
+        let mut dot = String::new();
+        dot.push_str("digraph callgraph {\n");
+        dot.push_str("    node [fontname=\"monospace\"];\n");
+        for (id, node) in self.nodes() {
+            let mut attributes = vec![format!("label=\"{}\"", node.name.replace('"', "\\\""))];
+            if node.is_entry() {
+                attributes.push("shape=box".into());
+            }
+            if !node.is_defined() {
+                attributes.push("style=dashed".into());
+            }
+            if unreachable.contains(&id) {
+                attributes.push("color=gray".into());
+            }
+            let _ = writeln!(dot, "    f{} [{}];", id.index(), attributes.join(", "));
+        }
+        let mut edges = Vec::new();
+        for site in &self.sites {
+            for callee in &site.callees {
+                let edge = (site.caller, *callee, site.kind == CallKind::Direct);
+                if !edges.contains(&edge) {
+                    edges.push(edge);
+                }
+            }
+        }
+        for (caller, callee, direct) in edges {
+            let style = if direct { "" } else { " [style=dotted]" };
+            let _ = writeln!(
+                dot,
+                "    f{} -> f{}{style};",
+                caller.index(),
+                callee.index()
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn resolve_or_insert(&mut self, name: &'a str) -> FunctionId {
+        let name = resolve_alias(&self.aliases, name);
+        self.node_named(name)
+    }
+
+    fn node_named(&mut self, name: &'a str) -> FunctionId {
+        *self.by_name.entry(name).or_insert_with(|| {
+            self.nodes.push(FunctionNode {
+                name,
+                function: None,
+                linkage: None,
+            });
+            FunctionId(self.nodes.len() as u32 - 1)
+        })
+    }
+}
+
+/// Follow `.alias` names to the function they stand for.
+fn resolve_alias<'n>(aliases: &HashMap<&'n str, &'n str>, mut name: &'n str) -> &'n str {
+    // Bounded, in case of a cycle of aliases.
+    for _ in 0..=aliases.len() {
+        match aliases.get(name) {
+            Some(target) => name = target,
+            None => break,
+        }
+    }
+    name
+}
+
+/// Name of a plain symbol operand.
+pub(super) fn symbol_name(operand: &GeneralOperand) -> Option<&str> {
+    match operand {
+        GeneralOperand::Single {
+            operand: Operand::Symbol { name, .. },
+            ..
+        } => Some(name),
+        _ => None,
+    }
+}
+
+/// Instructions of `statements` and nested blocks with their paths.
+fn instructions(statements: &[FunctionStatement]) -> Vec<(Vec<usize>, &Instruction)> {
+    let mut found = Vec::new();
+    let mut pending = vec![(Vec::new(), statements)];
+    while let Some((prefix, statements)) = pending.pop() {
+        for (index, statement) in statements.iter().enumerate() {
+            let mut path = prefix.clone();
+            path.push(index);
+            match statement {
+                FunctionStatement::Instruction { instruction, .. } => {
+                    found.push((path, instruction))
+                }
+                FunctionStatement::Block { statements, .. } => pending.push((path, statements)),
+                _ => {}
+            }
+        }
+    }
+    found.sort_by(|a, b| a.0.cmp(&b.0));
+    found
+}
+
+/// `.calltargets` and `.callprototype` directives by the label in front of
+/// them.
+pub(super) fn target_directives(
+    statements: &[FunctionStatement],
+) -> HashMap<&str, &StatementDirective> {
+    let mut targets = HashMap::new();
+    let mut pending = vec![statements];
+    while let Some(statements) = pending.pop() {
+        for pair in statements.windows(2) {
+            if let (
+                FunctionStatement::Label { label, .. },
+                FunctionStatement::Directive { directive, .. },
+            ) = (&pair[0], &pair[1])
+                && matches!(
+                    directive,
+                    StatementDirective::CallTargets { .. }
+                        | StatementDirective::CallPrototype { .. }
+                )
+            {
+                targets.insert(label.val.as_str(), directive);
+            }
+        }
+        for statement in statements {
+            if let FunctionStatement::Block { statements, .. } = statement {
+                pending.push(statements);
+            }
+        }
+    }
+    targets
+}
+
+fn initializer_functions<'a>(initializer: &'a GlobalInitializer, names: &mut Vec<&'a str>) {
+    match initializer {
+        GlobalInitializer::Scalar {
+            value: InitializerValue::FunctionSymbol { name, .. },
+            ..
+        } => names.push(&name.val),
+        GlobalInitializer::Scalar { .. } => {}
+        GlobalInitializer::Aggregate { values, .. } => {
+            for value in values {
+                initializer_functions(value, names);
+            }
+        }
+    }
+}
+
+/// Whether `function` has the parameters and return value of `prototype`.
+/// Parameters must agree in kind, type and array dimensions; names do not
+/// matter.
+fn matches_prototype(function: FunctionRef, prototype: &CallPrototypeDirective) -> bool {
+    let same = |a: &ParameterDirective, b: &ParameterDirective| match (a, b) {
+        (
+            ParameterDirective::Register { ty: a, .. },
+            ParameterDirective::Register { ty: b, .. },
+        ) => discriminant(a) == discriminant(b),
+        (
+            ParameterDirective::Parameter {
+                ty: a,
+                array: a_dims,
+                ..
+            },
+            ParameterDirective::Parameter {
+                ty: b,
+                array: b_dims,
+                ..
+            },
+        ) => discriminant(a) == discriminant(b) && a_dims == b_dims,
+        _ => false,
+    };
+    let returns = match (function.return_param(), &prototype.return_param) {
+        (None, None) => true,
+        (Some(a), Some(b)) => same(a, b),
+        _ => false,
+    };
+    returns
+        && function.params().len() == prototype.params.len()
+        && function
+            .params()
+            .iter()
+            .zip(&prototype.params)
+            .all(|(a, b)| same(a, b))
+}
@@ -0,0 +1,424 @@
+//! Call sites checked against the signature of their callee.
+//!
+//! The parser accepts any arguments in a `call`; mismatches only surface
+//! when `ptxas` compiles the module. [`check_calls`] compares every call with
+//! the parameters and return parameter of the function it names, following
+//! `.alias`, with every function of its `.calltargets` list, or with its
+//! `.callprototype`:
+//!
+//! - the call passes as many arguments as the callee takes;
+//! - the call receives a value exactly when the callee returns one;
+//! - every argument has the size of its parameter, and a type the parameter
+//!   accepts: `.bN` accepts any type of `N` bits, `.uN` and `.sN` accept
+//!   each other, and arrays compare their total size and element type.
+//!
+//! Arguments are `.param` variables declared in the caller, parameters of
+//! the caller, or registers. Immediates, and calls to functions the module
+//! does not declare, are not checked.
+//!
+//! ```
+//! use ptx_parser::analysis::check_calls;
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".func f(.param .b32 x) { ret; } .entry k() { .param .b64 a; call f, (a); ret; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let issues = check_calls(&module);
+//!
+//!     assert_eq!(
+//!         issues[0].to_string(),
+//!         "`a` is `.b64`, but parameter `x` of `f` is `.b32`"
+//!     );
+//!     assert_eq!(&source[issues[0].span().start..issues[0].span().end], "a");
+//! });
+//! ```
+
+use std::collections::HashMap;
+use std::fmt;
+
+use thiserror::Error;
+
+use super::callgraph::{CallGraph, CallOperands, symbol_name, target_directives};
+use super::frame::{param_slot, type_size, variable_layout};
+use super::registers::RegisterTypes;
+use super::{FunctionRef, functions};
+use crate::Span;
+use crate::diagnostic::Diagnostic;
+use crate::immediate::type_name;
+use crate::r#type::{
+    CallPrototypeDirective, DataType, FunctionStatement, GeneralOperand, Instruction, Module,
+    Operand, ParameterDirective, StatementDirective, VariableDirective, VariableModifier,
+};
+
+/// The signature a call is checked against.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Callee {
+    /// A function, by the name the call or its `.calltargets` list uses.
+    Function(String),
+    /// A `.callprototype`, by its label.
+    Prototype(String),
+}
+
+impl fmt::Display for Callee {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Callee::Function(name) => write!(f, "`{name}`"),
+            Callee::Prototype(label) => write!(f, "prototype `{label}`"),
+        }
+    }
+}
+
+/// A call that does not agree with the signature of its callee.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CallIssue {
+    #[error("`call` passes {} to {callee}, which takes {expected}", arguments(*found))]
+    ArgumentCount {
+        callee: Callee,
+        expected: usize,
+        found: usize,
+        /// Span of the `call`.
+        span: Span,
+    },
+    #[error("{}", return_message(callee, *returns))]
+    Return {
+        callee: Callee,
+        /// Whether the callee returns a value.
+        returns: bool,
+        /// Span of the `call`.
+        span: Span,
+    },
+    /// An argument, or the return value, whose type does not agree with the
+    /// parameter.
+    #[error("`{argument}` is `{found}`, but parameter `{parameter}` of {callee} is `{expected}`")]
+    Mismatch {
+        callee: Callee,
+        argument: String,
+        parameter: String,
+        expected: String,
+        found: String,
+        /// Span of the argument.
+        span: Span,
+    },
+}
+
+fn arguments(count: usize) -> String {
+    match count {
+        1 => "1 argument".to_string(),
+        _ => format!("{count} arguments"),
+    }
+}
+
+fn return_message(callee: &Callee, returns: bool) -> String {
+    if returns {
+        format!("{callee} returns a value, but the call does not receive it")
+    } else {
+        format!("{callee} does not return a value, but the call receives one")
+    }
+}
+
+impl CallIssue {
+    /// Span of the offending call or argument.
+    pub fn span(&self) -> Span {
+        match self {
+            CallIssue::ArgumentCount { span, .. }
+            | CallIssue::Return { span, .. }
+            | CallIssue::Mismatch { span, .. } => *span,
+        }
+    }
+
+    /// Convert this issue into a [`Diagnostic`].
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::error(self.to_string(), self.span());
+        match self {
+            CallIssue::ArgumentCount { expected, .. } => {
+                diagnostic.with_label(format!("expected {}", arguments(*expected)))
+            }
+            CallIssue::Return { returns: true, .. } => {
+                diagnostic.with_label("expected a return parameter")
+            }
+            CallIssue::Return { returns: false, .. } => {
+                diagnostic.with_label("unexpected return parameter")
+            }
+            CallIssue::Mismatch { expected, .. } => {
+                diagnostic.with_label(format!("expected `{expected}`"))
+            }
+        }
+    }
+}
+
+/// Check every call of `module` against the signature of its callee.
+pub fn check_calls(module: &Module) -> Vec<CallIssue> {
+    let graph = CallGraph::build(module);
+    let mut issues = Vec::new();
+    for function in functions(module) {
+        let Some(body) = function.body() else {
+            continue;
+        };
+        let mut checker = Checker {
+            graph: &graph,
+            registers: RegisterTypes::of(function),
+            targets: target_directives(&body.statements),
+            scopes: function
+                .return_param()
+                .into_iter()
+                .chain(function.params())
+                .filter_map(parameter_value)
+                .collect(),
+            issues: Vec::new(),
+        };
+        checker.statements(&body.statements);
+        issues.extend(checker.issues);
+    }
+    issues
+}
+
+/// Type and size of an argument or a parameter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Value<'a> {
+    name: &'a str,
+    /// Element type, e.g. `.b8`.
+    ty: &'static str,
+    size: u64,
+    /// Type as declared, e.g. `.b8[16]`.
+    display: String,
+}
+
+impl Value<'_> {
+    /// Whether an argument of this type may be passed as `parameter`.
+    fn fits(&self, parameter: &Value) -> bool {
+        let sized = |ty: &str, prefixes: &[&str]| {
+            prefixes.iter().any(|prefix| {
+                ty.strip_prefix(prefix)
+                    .is_some_and(|bits| bits.parse::<u32>().is_ok())
+            })
+        };
+        let bits = |ty: &str| sized(ty, &[".b"]);
+        let integer = |ty: &str| sized(ty, &[".u", ".s"]);
+        self.size == parameter.size
+            && (self.ty == parameter.ty
+                || bits(self.ty)
+                || bits(parameter.ty)
+                || (integer(self.ty) && integer(parameter.ty)))
+    }
+}
+
+fn parameter_value(parameter: &ParameterDirective) -> Option<Value<'_>> {
+    match parameter {
+        ParameterDirective::Register { ty, name, .. } => Some(register_value(&name.val, ty)),
+        ParameterDirective::Parameter {
+            ty, name, array, ..
+        } => Some(Value {
+            name: &name.val,
+            ty: type_name(ty),
+            size: param_slot(parameter)?.size,
+            display: format!("{}{}", type_name(ty), dims(array)),
+        }),
+    }
+}
+
+fn variable_value(variable: &VariableDirective) -> Value<'_> {
+    let vector = variable
+        .modifiers
+        .iter()
+        .find_map(|modifier| match modifier {
+            VariableModifier::Vector { value, .. } => Some(format!(".v{value}")),
+            _ => None,
+        });
+    Value {
+        name: &variable.name.val,
+        ty: type_name(&variable.ty),
+        size: variable_layout(variable).0,
+        display: format!(
+            "{}{}{}",
+            vector.unwrap_or_default(),
+            type_name(&variable.ty),
+            dims(&variable.array_dims)
+        ),
+    }
+}
+
+fn register_value<'a>(name: &'a str, ty: &DataType) -> Value<'a> {
+    Value {
+        name,
+        ty: type_name(ty),
+        size: type_size(ty),
+        display: type_name(ty).to_string(),
+    }
+}
+
+fn dims(dims: &[Option<u64>]) -> String {
+    dims.iter()
+        .map(|dim| match dim {
+            Some(dim) => format!("[{dim}]"),
+            None => "[]".to_string(),
+        })
+        .collect()
+}
+
+/// A signature to check a call against.
+enum Signature<'a> {
+    Function(FunctionRef<'a>),
+    Prototype(&'a CallPrototypeDirective),
+}
+
+impl<'a> Signature<'a> {
+    fn return_param(&self) -> Option<&'a ParameterDirective> {
+        match self {
+            Signature::Function(function) => function.return_param(),
+            Signature::Prototype(prototype) => prototype.return_param.as_ref(),
+        }
+    }
+
+    fn params(&self) -> &'a [ParameterDirective] {
+        match self {
+            Signature::Function(function) => function.params(),
+            Signature::Prototype(prototype) => &prototype.params,
+        }
+    }
+}
+
+struct Checker<'a, 'g> {
+    graph: &'g CallGraph<'a>,
+    registers: RegisterTypes,
+    /// `.calltargets` and `.callprototype` directives by label.
+    targets: HashMap<&'a str, &'a StatementDirective>,
+    /// Parameters of the function and `.param` variables in scope.
+    scopes: Vec<Value<'a>>,
+    issues: Vec<CallIssue>,
+}
+
+impl<'a> Checker<'a, '_> {
+    fn statements(&mut self, statements: &'a [FunctionStatement]) {
+        let depth = self.scopes.len();
+        for statement in statements {
+            match statement {
+                FunctionStatement::Directive {
+                    directive: StatementDirective::Param { directive, .. },
+                    ..
+                } => self.scopes.push(variable_value(directive)),
+                FunctionStatement::Instruction { instruction, .. } => self.instruction(instruction),
+                FunctionStatement::Block { statements, .. } => self.statements(statements),
+                _ => {}
+            }
+        }
+        self.scopes.truncate(depth);
+    }
+
+    fn instruction(&mut self, instruction: &'a Instruction) {
+        let Some(call) = CallOperands::of(instruction) else {
+            return;
+        };
+        for (callee, signature) in self.signatures(&call) {
+            self.check(instruction, &call, callee, signature);
+        }
+    }
+
+    /// Signatures `call` must agree with; empty when the callee is unknown.
+    fn signatures(&self, call: &CallOperands<'a>) -> Vec<(Callee, Signature<'a>)> {
+        let function = |name: &str| {
+            self.graph
+                .function(name)
+                .and_then(|id| self.graph.node(id).function)
+        };
+        let Some(label) = call.targets.and_then(symbol_name) else {
+            return symbol_name(call.callee)
+                .and_then(|name| Some((Callee::Function(name.to_string()), function(name)?)))
+                .map(|(callee, function)| (callee, Signature::Function(function)))
+                .into_iter()
+                .collect();
+        };
+        match self.targets.get(label) {
+            Some(StatementDirective::CallTargets { directive, .. }) => directive
+                .targets
+                .iter()
+                .filter_map(|target| {
+                    let function = function(&target.val)?;
+                    Some((
+                        Callee::Function(target.val.clone()),
+                        Signature::Function(function),
+                    ))
+                })
+                .collect(),
+            Some(StatementDirective::CallPrototype { directive, .. }) => vec![(
+                Callee::Prototype(label.to_string()),
+                Signature::Prototype(directive),
+            )],
+            _ => Vec::new(),
+        }
+    }
+
+    fn check(
+        &mut self,
+        instruction: &Instruction,
+        call: &CallOperands<'a>,
+        callee: Callee,
+        signature: Signature<'a>,
+    ) {
+        match (call.return_param, signature.return_param()) {
+            (Some(argument), Some(parameter)) => {
+                self.argument(&callee, argument, parameter);
+            }
+            (None, None) => {}
+            (found, _) => self.issues.push(CallIssue::Return {
+                callee: callee.clone(),
+                returns: found.is_none(),
+                span: instruction.span,
+            }),
+        }
+
+        let params = signature.params();
+        if call.params.len() != params.len() {
+            self.issues.push(CallIssue::ArgumentCount {
+                callee,
+                expected: params.len(),
+                found: call.params.len(),
+                span: instruction.span,
+            });
+            return;
+        }
+        for (argument, parameter) in call.params.iter().zip(params) {
+            self.argument(&callee, argument, parameter);
+        }
+    }
+
+    fn argument(
+        &mut self,
+        callee: &Callee,
+        argument: &GeneralOperand,
+        parameter: &ParameterDirective,
+    ) {
+        let (Some(found), Some(expected)) = (self.value(argument), parameter_value(parameter))
+        else {
+            return;
+        };
+        if !found.fits(&expected) {
+            self.issues.push(CallIssue::Mismatch {
+                callee: callee.clone(),
+                argument: found.name.to_string(),
+                parameter: expected.name.to_string(),
+                expected: expected.display,
+                found: found.display,
+                span: argument.span(),
+            });
+        }
+    }
+
+    /// Type of an argument, or `None` when it is unknown or an immediate.
+    fn value(&self, argument: &'a GeneralOperand) -> Option<Value<'a>> {
+        let GeneralOperand::Single { operand, .. } = argument else {
+            return None;
+        };
+        match operand {
+            Operand::Symbol { name, .. } => self
+                .scopes
+                .iter()
+                .rev()
+                .find(|value| value.name == name)
+                .cloned(),
+            Operand::Register { operand, .. } => {
+                let ty = self.registers.get(&operand.name)?;
+                Some(register_value(&operand.name, ty))
+            }
+            _ => None,
+        }
+    }
+}
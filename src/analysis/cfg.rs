@@ -0,0 +1,492 @@
+//! Control-flow graphs of function bodies.
+//!
+//! [`Cfg::build`] splits a [`FunctionBody`] into basic blocks. A block starts
+//! at a label that follows code, and ends after a branch (`bra`, `brx.idx`)
+//! or an instruction that leaves the function (`ret`, `exit`, `trap`).
+//! Statements of nested `{ ... }` blocks are laid out in source order, and
+//! each statement keeps its [`path`](CfgStatement::path) back into the body.
+//! Branch targets resolve like other names, to the label declared in the
+//! innermost enclosing block; targets that name no visible label get no edge
+//! and are listed by [`Cfg::unresolved`]:
+//!
+//! ```
+//! use ptx_parser::analysis::{BlockId, Cfg, EdgeKind};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".entry k() { @%p1 bra DONE; add.s32 %r1, %r1, 1; DONE: ret; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let function = ptx_parser::analysis::functions(&module).next().unwrap();
+//!     let cfg = Cfg::build(function.body().unwrap());
+//!     assert_eq!(cfg.len(), 3);
+//!
+//!     let kinds: Vec<_> = cfg.block(cfg.entry()).successors.iter().map(|edge| edge.kind).collect();
+//!     assert_eq!(kinds, [EdgeKind::Branch, EdgeKind::FallThrough]);
+//!     assert_eq!(cfg.block_of_label("DONE"), Some(BlockId::new(2)));
+//!     assert_eq!(cfg.exits(), [BlockId::new(2)]);
+//! });
+//! ```
+
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use crate::operands::OperandValue;
+use crate::r#type::{
+    FunctionBody, FunctionStatement, GeneralOperand, Instruction, Label, Operand,
+    StatementDirective,
+};
+use crate::{PtxUnlexer, PtxUnparser, Span};
+
+/// Index of a [`BasicBlock`] in its [`Cfg`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct BlockId(u32);
+
+impl BlockId {
+    pub fn new(index: usize) -> Self {
+        BlockId(index as u32)
+    }
+
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// How control reaches the target of an [`Edge`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EdgeKind {
+    /// Execution continues with the next block, after a predicated branch or
+    /// a block without a branch.
+    FallThrough,
+    /// Taken `bra`.
+    Branch,
+    /// `brx.idx` to one of the labels of its `.branchtargets` list.
+    Indirect,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge {
+    pub target: BlockId,
+    pub kind: EdgeKind,
+    /// Set for edges of `.uni` branches, which are known to be non-divergent.
+    pub uniform: bool,
+}
+
+/// A statement of the body together with its position.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CfgStatement<'a> {
+    /// Indices from the body through nested blocks, outermost first; `[4, 1]`
+    /// is the second statement of the block at `body.statements[4]`.
+    pub path: Vec<usize>,
+    pub statement: &'a FunctionStatement,
+}
+
+impl CfgStatement<'_> {
+    /// Path of the `{ ... }` block holding the statement; empty for a
+    /// statement of the body itself.
+    pub fn scope(&self) -> &[usize] {
+        &self.path[..self.path.len() - 1]
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BasicBlock<'a> {
+    /// Labels, directives and instructions of the block in source order.
+    pub statements: Vec<CfgStatement<'a>>,
+    pub successors: Vec<Edge>,
+    /// Blocks with an edge to this one, without duplicates.
+    pub predecessors: Vec<BlockId>,
+}
+
+impl<'a> BasicBlock<'a> {
+    pub fn labels(&self) -> impl Iterator<Item = &'a Label> + '_ {
+        self.statements
+            .iter()
+            .filter_map(|statement| match statement.statement {
+                FunctionStatement::Label { label, .. } => Some(label),
+                _ => None,
+            })
+    }
+
+    pub fn instructions(&self) -> impl DoubleEndedIterator<Item = &'a Instruction> + '_ {
+        self.statements
+            .iter()
+            .filter_map(|statement| match statement.statement {
+                FunctionStatement::Instruction { instruction, .. } => Some(instruction),
+                _ => None,
+            })
+    }
+
+    /// The branch or `ret`/`exit`/`trap` ending the block, if any.
+    pub fn terminator(&self) -> Option<&'a Instruction> {
+        match self.statements.last()?.statement {
+            FunctionStatement::Instruction { instruction, .. }
+                if Control::of(instruction).is_some() =>
+            {
+                Some(instruction)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// A branch target, or `.branchtargets` entry, that names no label visible
+/// from the branch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnresolvedTarget<'a> {
+    /// Block ending in the branch.
+    pub block: BlockId,
+    pub label: &'a str,
+    pub span: Span,
+}
+
+/// Declarations keyed by the block scope they appear in, given as the path
+/// of the `{ ... }` block (empty for the body itself), and by name.
+type Scoped<'a, T> = HashMap<(Vec<usize>, &'a str), T>;
+
+/// Declaration of `name` visible from `scope`: the one in the innermost
+/// enclosing block.
+fn lookup<'d, 'm, T>(
+    declarations: &'d Scoped<'m, T>,
+    scope: &[usize],
+    name: &'m str,
+) -> Option<&'d T> {
+    (0..=scope.len())
+        .rev()
+        .find_map(|depth| declarations.get(&(scope[..depth].to_vec(), name)))
+}
+
+/// Control-flow graph of one function body.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cfg<'a> {
+    blocks: Vec<BasicBlock<'a>>,
+    labels: HashMap<&'a str, BlockId>,
+    exits: Vec<BlockId>,
+    unresolved: Vec<UnresolvedTarget<'a>>,
+}
+
+impl<'a> Cfg<'a> {
+    pub fn build(body: &'a FunctionBody) -> Self {
+        let mut statements = Vec::new();
+        flatten(&body.statements, &mut Vec::new(), &mut statements);
+
+        // `.branchtargets` lists are named by the label in front of them.
+        let mut target_lists: Scoped<(Vec<usize>, &[Label])> = HashMap::new();
+        for pair in statements.windows(2) {
+            if let (
+                FunctionStatement::Label { label, .. },
+                FunctionStatement::Directive {
+                    directive: StatementDirective::BranchTargets { directive, .. },
+                    ..
+                },
+            ) = (pair[0].statement, pair[1].statement)
+            {
+                let scope = pair[1].scope();
+                target_lists
+                    .entry((scope.to_vec(), &label.val))
+                    .or_insert((scope.to_vec(), &directive.labels));
+            }
+        }
+
+        let mut blocks = vec![BasicBlock::default()];
+        let mut has_code = false;
+        let mut terminated = false;
+        for statement in statements {
+            let is_label = matches!(statement.statement, FunctionStatement::Label { .. });
+            if terminated || (is_label && has_code) {
+                blocks.push(BasicBlock::default());
+                has_code = false;
+                terminated = false;
+            }
+            if let FunctionStatement::Instruction { instruction, .. } = statement.statement {
+                has_code = true;
+                terminated = Control::of(instruction).is_some();
+            }
+            blocks.last_mut().unwrap().statements.push(statement);
+        }
+
+        let mut labels = HashMap::new();
+        let mut scoped_labels: Scoped<BlockId> = HashMap::new();
+        for (index, block) in blocks.iter().enumerate() {
+            for statement in &block.statements {
+                if let FunctionStatement::Label { label, .. } = statement.statement {
+                    let id = BlockId::new(index);
+                    labels.entry(label.val.as_str()).or_insert(id);
+                    scoped_labels
+                        .entry((statement.scope().to_vec(), &label.val))
+                        .or_insert(id);
+                }
+            }
+        }
+
+        let mut exits = Vec::new();
+        let mut unresolved = Vec::new();
+        let count = blocks.len();
+        for (index, block) in blocks.iter_mut().enumerate() {
+            let next = (index + 1 < count).then(|| BlockId::new(index + 1));
+            let fall_through = |successors: &mut Vec<Edge>, exits: &mut Vec<BlockId>| match next {
+                Some(target) => successors.push(Edge {
+                    target,
+                    kind: EdgeKind::FallThrough,
+                    uniform: false,
+                }),
+                None => exits.push(BlockId::new(index)),
+            };
+
+            let Some(instruction) = block.terminator() else {
+                fall_through(&mut block.successors, &mut exits);
+                continue;
+            };
+            let uniform = instruction
+                .inst
+                .modifiers()
+                .iter()
+                .any(|modifier| modifier == ".uni");
+            let edge = |target: BlockId, kind: EdgeKind| Edge {
+                target,
+                kind,
+                uniform,
+            };
+            let scope = block
+                .statements
+                .last()
+                .expect("blocks are not empty")
+                .scope();
+            let mut unresolved_target = |label: &'a str, span: Span| {
+                unresolved.push(UnresolvedTarget {
+                    block: BlockId::new(index),
+                    label,
+                    span,
+                })
+            };
+            match Control::of(instruction) {
+                Some(Control::Branch) => {
+                    if let Some((label, span)) = branch_target(instruction) {
+                        match lookup(&scoped_labels, scope, label) {
+                            Some(&target) => block.successors.push(edge(target, EdgeKind::Branch)),
+                            None => unresolved_target(label, span),
+                        }
+                    }
+                }
+                Some(Control::IndirectBranch) => {
+                    if let Some((name, span)) = branch_target(instruction) {
+                        match lookup(&target_lists, scope, name) {
+                            Some((list_scope, list)) => {
+                                for label in *list {
+                                    match lookup(&scoped_labels, list_scope, &label.val) {
+                                        Some(&target) => {
+                                            let edge = edge(target, EdgeKind::Indirect);
+                                            if !block.successors.contains(&edge) {
+                                                block.successors.push(edge);
+                                            }
+                                        }
+                                        None => unresolved_target(&label.val, label.span),
+                                    }
+                                }
+                            }
+                            None => unresolved_target(name, span),
+                        }
+                    }
+                }
+                Some(Control::Exit) | None => exits.push(BlockId::new(index)),
+            }
+            if instruction.predicate.is_some() {
+                fall_through(&mut block.successors, &mut exits);
+            }
+        }
+        exits.sort_unstable();
+        exits.dedup();
+
+        for index in 0..blocks.len() {
+            for edge in blocks[index].successors.clone() {
+                let predecessors = &mut blocks[edge.target.index()].predecessors;
+                if !predecessors.contains(&BlockId::new(index)) {
+                    predecessors.push(BlockId::new(index));
+                }
+            }
+        }
+
+        Cfg {
+            blocks,
+            labels,
+            exits,
+            unresolved,
+        }
+    }
+
+    /// The first block. It is never a branch target unless it is labelled.
+    pub fn entry(&self) -> BlockId {
+        BlockId(0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.blocks.len()
+    }
+
+    /// Always false; the entry block exists even for an empty body.
+    pub fn is_empty(&self) -> bool {
+        self.blocks.is_empty()
+    }
+
+    pub fn block(&self, id: BlockId) -> &BasicBlock<'a> {
+        &self.blocks[id.index()]
+    }
+
+    /// Blocks indexed by [`BlockId::index`].
+    pub fn blocks(&self) -> &[BasicBlock<'a>] {
+        &self.blocks
+    }
+
+    pub fn block_ids(&self) -> impl DoubleEndedIterator<Item = BlockId> + use<'a> {
+        (0..self.blocks.len()).map(BlockId::new)
+    }
+
+    /// Successor blocks of `id`, without duplicates.
+    pub fn successors(&self, id: BlockId) -> Vec<BlockId> {
+        let mut successors = Vec::new();
+        for edge in &self.block(id).successors {
+            if !successors.contains(&edge.target) {
+                successors.push(edge.target);
+            }
+        }
+        successors
+    }
+
+    pub fn predecessors(&self, id: BlockId) -> &[BlockId] {
+        &self.block(id).predecessors
+    }
+
+    /// Blocks that leave the function through `ret`, `exit` or `trap`, or by
+    /// running off the end of the body.
+    pub fn exits(&self) -> &[BlockId] {
+        &self.exits
+    }
+
+    /// Block of the first label named `label`, in any block scope.
+    pub fn block_of_label(&self, label: &str) -> Option<BlockId> {
+        self.labels.get(label).copied()
+    }
+
+    /// Branch targets that name no visible label, in block order. Their
+    /// branches have no edge to the missing target.
+    pub fn unresolved(&self) -> &[UnresolvedTarget<'a>] {
+        &self.unresolved
+    }
+
+    /// Block holding the statement at `path`.
+    pub fn block_of_statement(&self, path: &[usize]) -> Option<BlockId> {
+        self.blocks
+            .iter()
+            .position(|block| block.statements.iter().any(|s| s.path == path))
+            .map(BlockId::new)
+    }
+
+    /// Render the graph in Graphviz DOT syntax.
+    ///
+    /// Each node lists the labels and instructions of a block. Fall-through
+    /// edges are dashed, indirect edges dotted and uniform edges labelled
+    /// `uni`.
+    pub fn to_dot(&self, name: &str) -> String {
+        let mut dot = String::new();
+        let _ = writeln!(dot, "digraph \"{}\" {{", escape(name));
+        let _ = writeln!(dot, "    node [shape=box, fontname=\"monospace\"];");
+        for (index, block) in self.blocks.iter().enumerate() {
+            let mut label = format!("b{index}\\l");
+            for statement in &block.statements {
+                match statement.statement {
+                    FunctionStatement::Label { label: name, .. } => {
+                        let _ = write!(label, "{}:\\l", escape(&name.val));
+                    }
+                    FunctionStatement::Instruction { instruction, .. } => {
+                        let text = PtxUnlexer::to_string(&instruction.to_tokens_spaced())
+                            .unwrap_or_default();
+                        let _ = write!(label, "    {}\\l", escape(text.trim()));
+                    }
+                    _ => {}
+                }
+            }
+            let _ = writeln!(dot, "    b{index} [label=\"{label}\"];");
+        }
+        for (index, block) in self.blocks.iter().enumerate() {
+            for edge in &block.successors {
+                let mut attributes = Vec::new();
+                match edge.kind {
+                    EdgeKind::FallThrough => attributes.push("style=dashed"),
+                    EdgeKind::Branch => {}
+                    EdgeKind::Indirect => attributes.push("style=dotted"),
+                }
+                if edge.uniform {
+                    attributes.push("label=\"uni\"");
+                }
+                let target = edge.target.index();
+                if attributes.is_empty() {
+                    let _ = writeln!(dot, "    b{index} -> b{target};");
+                } else {
+                    let _ = writeln!(
+                        dot,
+                        "    b{index} -> b{target} [{}];",
+                        attributes.join(", ")
+                    );
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Instructions that end a basic block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Control {
+    Branch,
+    IndirectBranch,
+    Exit,
+}
+
+impl Control {
+    fn of(instruction: &Instruction) -> Option<Control> {
+        match instruction.inst.opcode() {
+            "bra" => Some(Control::Branch),
+            "brx" => Some(Control::IndirectBranch),
+            "ret" | "exit" | "trap" => Some(Control::Exit),
+            _ => None,
+        }
+    }
+}
+
+/// Label operand of `bra`, or the `.branchtargets` list of `brx.idx`, with
+/// its span.
+fn branch_target(instruction: &Instruction) -> Option<(&str, Span)> {
+    instruction
+        .inst
+        .operands()
+        .into_iter()
+        .rev()
+        .find_map(|operand| match operand.value {
+            OperandValue::General(GeneralOperand::Single {
+                operand: Operand::Symbol { name, span },
+                ..
+            }) => Some((name.as_str(), *span)),
+            _ => None,
+        })
+}
+
+fn flatten<'a>(
+    statements: &'a [FunctionStatement],
+    path: &mut Vec<usize>,
+    out: &mut Vec<CfgStatement<'a>>,
+) {
+    for (index, statement) in statements.iter().enumerate() {
+        path.push(index);
+        match statement {
+            FunctionStatement::Block { statements, .. } => flatten(statements, path, out),
+            _ => out.push(CfgStatement {
+                path: path.clone(),
+                statement,
+            }),
+        }
+        path.pop();
+    }
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,186 @@
+//! A generic dataflow solver over basic blocks.
+//!
+//! An [`Analysis`] describes facts of a [`Lattice`], the direction they flow
+//! in and how a block transforms them. [`solve`] runs a worklist to the least
+//! fixed point and returns the facts at the start and end of every block:
+//!
+//! ```
+//! use std::collections::BTreeSet;
+//!
+//! use ptx_parser::analysis::dataflow::{self, Analysis, Direction};
+//! use ptx_parser::analysis::{BlockId, Cfg};
+//!
+//! /// Blocks on some path from the entry to each block.
+//! struct Visited;
+//!
+//! impl Analysis for Visited {
+//!     type Fact = BTreeSet<BlockId>;
+//!     const DIRECTION: Direction = Direction::Forward;
+//!
+//!     fn bottom(&self) -> Self::Fact {
+//!         BTreeSet::new()
+//!     }
+//!
+//!     fn transfer(&self, block: BlockId, input: &Self::Fact) -> Self::Fact {
+//!         let mut output = input.clone();
+//!         output.insert(block);
+//!         output
+//!     }
+//! }
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".entry k() { @%p1 bra END; mov.u32 %r1, 1; END: ret; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let function = ptx_parser::analysis::functions(&module).next().unwrap();
+//!     let cfg = Cfg::build(function.body().unwrap());
+//!     let solution = dataflow::solve(&cfg, &Visited);
+//!     assert_eq!(solution.entry(BlockId::new(2)).len(), 2);
+//!     assert_eq!(solution.exit(BlockId::new(2)).len(), 3);
+//! });
+//! ```
+
+use std::collections::{BTreeSet, VecDeque};
+
+use super::cfg::{BlockId, Cfg};
+
+/// A join semilattice of dataflow facts.
+pub trait Lattice: Clone + PartialEq {
+    /// Merge `other` into `self`, returning whether `self` changed.
+    fn join(&mut self, other: &Self) -> bool;
+}
+
+/// Sets joined by union, the lattice of most bit-vector problems.
+impl<T: Ord + Clone> Lattice for BTreeSet<T> {
+    fn join(&mut self, other: &Self) -> bool {
+        let before = self.len();
+        self.extend(other.iter().cloned());
+        self.len() != before
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    /// Facts flow from the entry along edges, e.g. reaching definitions.
+    Forward,
+    /// Facts flow from the exits against edges, e.g. liveness.
+    Backward,
+}
+
+/// A dataflow problem over the blocks of a [`Cfg`].
+pub trait Analysis {
+    type Fact: Lattice;
+    const DIRECTION: Direction;
+
+    /// Initial fact of every block; the identity of [`Lattice::join`].
+    fn bottom(&self) -> Self::Fact;
+
+    /// Fact flowing into the entry block (forward) or out of the exit blocks
+    /// (backward). Defaults to [`Analysis::bottom`].
+    fn boundary(&self) -> Self::Fact {
+        self.bottom()
+    }
+
+    /// Fact after `block` given the fact before it, in the direction of the
+    /// analysis: for a backward analysis `input` holds at the end of the
+    /// block and the result at its start.
+    fn transfer(&self, block: BlockId, input: &Self::Fact) -> Self::Fact;
+}
+
+/// Facts at the boundaries of every block, in program order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Solution<F> {
+    entry: Vec<F>,
+    exit: Vec<F>,
+}
+
+impl<F> Solution<F> {
+    /// Fact at the start of `block`.
+    pub fn entry(&self, block: BlockId) -> &F {
+        &self.entry[block.index()]
+    }
+
+    /// Fact at the end of `block`.
+    pub fn exit(&self, block: BlockId) -> &F {
+        &self.exit[block.index()]
+    }
+}
+
+/// Solve `analysis` over `cfg` with a worklist seeded in block order
+/// (reversed for backward problems).
+///
+/// Terminates when the transfer functions are monotone and the lattice has
+/// finite height.
+pub fn solve<A: Analysis>(cfg: &Cfg, analysis: &A) -> Solution<A::Fact> {
+    let forward = A::DIRECTION == Direction::Forward;
+    let count = cfg.len();
+    // `input` and `output` follow the direction of the analysis.
+    let mut input = vec![analysis.bottom(); count];
+    let mut output = vec![analysis.bottom(); count];
+    let boundary = analysis.boundary();
+    let mut is_boundary = vec![false; count];
+    if forward {
+        is_boundary[cfg.entry().index()] = true;
+    } else {
+        for exit in cfg.exits() {
+            is_boundary[exit.index()] = true;
+        }
+    }
+
+    let mut queued = vec![true; count];
+    let mut worklist: VecDeque<BlockId> = if forward {
+        cfg.block_ids().collect()
+    } else {
+        cfg.block_ids().rev().collect()
+    };
+    // Blocks whose output has been computed at least once, so that a
+    // transfer that happens to return bottom still reaches its neighbours.
+    let mut visited = vec![false; count];
+    while let Some(block) = worklist.pop_front() {
+        let index = block.index();
+        queued[index] = false;
+
+        let mut fact = analysis.bottom();
+        if is_boundary[index] {
+            fact.join(&boundary);
+        }
+        let sources: Vec<BlockId> = if forward {
+            cfg.predecessors(block).to_vec()
+        } else {
+            cfg.successors(block)
+        };
+        for source in sources {
+            fact.join(&output[source.index()]);
+        }
+
+        let result = analysis.transfer(block, &fact);
+        input[index] = fact;
+        if visited[index] && result == output[index] {
+            continue;
+        }
+        visited[index] = true;
+        output[index] = result;
+
+        let targets: Vec<BlockId> = if forward {
+            cfg.successors(block)
+        } else {
+            cfg.predecessors(block).to_vec()
+        };
+        for target in targets {
+            if !std::mem::replace(&mut queued[target.index()], true) {
+                worklist.push_back(target);
+            }
+        }
+    }
+
+    if forward {
+        Solution {
+            entry: input,
+            exit: output,
+        }
+    } else {
+        Solution {
+            entry: output,
+            exit: input,
+        }
+    }
+}
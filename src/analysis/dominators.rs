@@ -0,0 +1,268 @@
+//! Dominator and post-dominator trees with dominance frontiers.
+//!
+//! Block `a` dominates `b` when every path from the entry to `b` passes
+//! through `a`, and post-dominates `b` when every path from `b` to an exit
+//! passes through `a`. The immediate post-dominator of a divergent branch is
+//! where its threads reconverge:
+//!
+//! ```
+//! use ptx_parser::analysis::{BlockId, Cfg, DominatorTree};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".entry k() { @%p1 bra ELSE; mov.u32 %r1, 1; bra.uni END; ELSE: mov.u32 %r1, 2; END: ret; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let function = ptx_parser::analysis::functions(&module).next().unwrap();
+//!     let cfg = Cfg::build(function.body().unwrap());
+//!
+//!     let dominators = DominatorTree::dominators(&cfg);
+//!     let end = cfg.block_of_label("END").unwrap();
+//!     assert_eq!(dominators.immediate(end), Some(cfg.entry()));
+//!     assert_eq!(dominators.frontier(BlockId::new(1)), [end]);
+//!
+//!     let post_dominators = DominatorTree::post_dominators(&cfg);
+//!     assert_eq!(post_dominators.immediate(cfg.entry()), Some(end));
+//! });
+//! ```
+
+use super::cfg::{BlockId, Cfg};
+
+/// Dominator tree of a [`Cfg`], or post-dominator tree when built by
+/// [`DominatorTree::post_dominators`].
+///
+/// Blocks that cannot be reached from the entry (or, for post-dominators,
+/// cannot reach an exit) are not part of the tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DominatorTree {
+    immediate: Vec<Option<BlockId>>,
+    children: Vec<Vec<BlockId>>,
+    roots: Vec<BlockId>,
+    frontiers: Vec<Vec<BlockId>>,
+    /// Preorder and postorder numbers in the tree, for constant-time
+    /// dominance queries. `None` outside the tree.
+    order: Vec<Option<(u32, u32)>>,
+}
+
+impl DominatorTree {
+    /// Dominators, rooted at the entry block.
+    pub fn dominators(cfg: &Cfg) -> Self {
+        let successors: Vec<Vec<usize>> = cfg
+            .block_ids()
+            .map(|id| cfg.successors(id).iter().map(|s| s.index()).collect())
+            .collect();
+        Self::compute(&successors, &[cfg.entry().index()])
+    }
+
+    /// Post-dominators, computed on the reversed graph from all exits.
+    ///
+    /// A function with several exits has several roots; their common
+    /// post-dominator is the (implicit) function exit.
+    pub fn post_dominators(cfg: &Cfg) -> Self {
+        let predecessors: Vec<Vec<usize>> = cfg
+            .block_ids()
+            .map(|id| cfg.predecessors(id).iter().map(|p| p.index()).collect())
+            .collect();
+        let exits: Vec<usize> = cfg.exits().iter().map(|id| id.index()).collect();
+        Self::compute(&predecessors, &exits)
+    }
+
+    /// Blocks without an immediate dominator: the entry block, or for
+    /// post-dominators the exits and blocks whose paths to different exits
+    /// share no block.
+    pub fn roots(&self) -> &[BlockId] {
+        &self.roots
+    }
+
+    /// Whether `block` is part of the tree.
+    pub fn contains(&self, block: BlockId) -> bool {
+        self.order[block.index()].is_some()
+    }
+
+    /// Closest strict dominator of `block`, `None` for roots and blocks
+    /// outside the tree.
+    pub fn immediate(&self, block: BlockId) -> Option<BlockId> {
+        self.immediate[block.index()]
+    }
+
+    /// Blocks immediately dominated by `block`, in ascending order.
+    pub fn children(&self, block: BlockId) -> &[BlockId] {
+        &self.children[block.index()]
+    }
+
+    /// Whether `a` dominates `b`. Every block in the tree dominates itself.
+    pub fn dominates(&self, a: BlockId, b: BlockId) -> bool {
+        match (self.order[a.index()], self.order[b.index()]) {
+            (Some((pre_a, post_a)), Some((pre_b, post_b))) => pre_a <= pre_b && post_b <= post_a,
+            _ => false,
+        }
+    }
+
+    pub fn strictly_dominates(&self, a: BlockId, b: BlockId) -> bool {
+        a != b && self.dominates(a, b)
+    }
+
+    /// Dominance frontier of `block`: blocks where its dominance ends, in
+    /// ascending order. For post-dominators this is the set of branches
+    /// `block` is control dependent on.
+    pub fn frontier(&self, block: BlockId) -> &[BlockId] {
+        &self.frontiers[block.index()]
+    }
+
+    /// `block` followed by its dominators up to the root.
+    pub fn ancestors(&self, block: BlockId) -> impl Iterator<Item = BlockId> + '_ {
+        let start = self.contains(block).then_some(block);
+        std::iter::successors(start, |&block| self.immediate(block))
+    }
+
+    /// Cooper, Harvey and Kennedy's iterative algorithm. Node `n` (one past
+    /// the blocks) is a virtual root with an edge to every real root, so
+    /// several roots share one tree.
+    fn compute(edges: &[Vec<usize>], roots: &[usize]) -> Self {
+        let n = edges.len();
+        let root = n;
+        let successors =
+            |node: usize| -> &[usize] { if node == root { roots } else { &edges[node] } };
+
+        // Postorder from the virtual root.
+        let mut postorder = Vec::with_capacity(n + 1);
+        let mut visited = vec![false; n + 1];
+        let mut stack = vec![(root, 0)];
+        visited[root] = true;
+        while let Some((node, next)) = stack.last_mut() {
+            let node = *node;
+            match successors(node).get(*next) {
+                Some(&successor) => {
+                    *next += 1;
+                    if !visited[successor] {
+                        visited[successor] = true;
+                        stack.push((successor, 0));
+                    }
+                }
+                None => {
+                    postorder.push(node);
+                    stack.pop();
+                }
+            }
+        }
+        let mut number = vec![usize::MAX; n + 1];
+        for (index, &node) in postorder.iter().enumerate() {
+            number[node] = index;
+        }
+
+        let mut predecessors = vec![Vec::new(); n + 1];
+        for &node in &postorder {
+            for &successor in successors(node) {
+                predecessors[successor].push(node);
+            }
+        }
+
+        let mut idom = vec![usize::MAX; n + 1];
+        idom[root] = root;
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &node in postorder.iter().rev().skip(1) {
+                let mut processed = predecessors[node]
+                    .iter()
+                    .copied()
+                    .filter(|&p| idom[p] != usize::MAX);
+                let Some(first) = processed.next() else {
+                    continue;
+                };
+                let new = processed.fold(first, |mut a, mut b| {
+                    while a != b {
+                        while number[a] < number[b] {
+                            a = idom[a];
+                        }
+                        while number[b] < number[a] {
+                            b = idom[b];
+                        }
+                    }
+                    a
+                });
+                if idom[node] != new {
+                    idom[node] = new;
+                    changed = true;
+                }
+            }
+        }
+
+        let mut frontiers = vec![Vec::new(); n];
+        for node in 0..n {
+            if idom[node] == usize::MAX || predecessors[node].len() < 2 {
+                continue;
+            }
+            for &predecessor in &predecessors[node] {
+                let mut runner = predecessor;
+                while runner != idom[node] && runner != root {
+                    let frontier: &mut Vec<BlockId> = &mut frontiers[runner];
+                    if !frontier.contains(&BlockId::new(node)) {
+                        frontier.push(BlockId::new(node));
+                    }
+                    runner = idom[runner];
+                }
+            }
+        }
+        for frontier in &mut frontiers {
+            frontier.sort_unstable();
+        }
+
+        let immediate: Vec<Option<BlockId>> = (0..n)
+            .map(|node| match idom[node] {
+                parent if parent == usize::MAX || parent == root => None,
+                parent => Some(BlockId::new(parent)),
+            })
+            .collect();
+        let mut children = vec![Vec::new(); n];
+        let mut tree_roots = Vec::new();
+        for node in 0..n {
+            match immediate[node] {
+                Some(parent) => children[parent.index()].push(BlockId::new(node)),
+                None if idom[node] == root => tree_roots.push(BlockId::new(node)),
+                None => {}
+            }
+        }
+
+        let mut order = vec![None; n];
+        let mut counter = (0, 0);
+        for &tree_root in &tree_roots {
+            number_subtree(tree_root, &children, &mut order, &mut counter);
+        }
+
+        DominatorTree {
+            immediate,
+            children,
+            roots: tree_roots,
+            frontiers,
+            order,
+        }
+    }
+}
+
+fn number_subtree(
+    root: BlockId,
+    children: &[Vec<BlockId>],
+    order: &mut [Option<(u32, u32)>],
+    counter: &mut (u32, u32),
+) {
+    let mut stack = vec![(root, 0)];
+    order[root.index()] = Some((counter.0, 0));
+    counter.0 += 1;
+    while let Some((node, next)) = stack.last_mut() {
+        let node = *node;
+        match children[node.index()].get(*next) {
+            Some(&child) => {
+                *next += 1;
+                order[child.index()] = Some((counter.0, 0));
+                counter.0 += 1;
+                stack.push((child, 0));
+            }
+            None => {
+                if let Some((_, post)) = &mut order[node.index()] {
+                    *post = counter.1;
+                }
+                counter.1 += 1;
+                stack.pop();
+            }
+        }
+    }
+}
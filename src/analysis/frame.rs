@@ -0,0 +1,350 @@
+//! Parameter, stack and shared memory usage of functions.
+//!
+//! [`FrameReport::of`] lays out the parameters of a function the way the ABI
+//! does: each one starts at a multiple of its alignment, which is the
+//! `.align` given in its declaration or else the size of its type, and takes
+//! its size times the product of its array dimensions. `.local` and `.shared`
+//! variables declared in the body are laid out the same way. Calls are
+//! measured by the `.param` variables they pass and receive.
+//!
+//! Kernel parameters are limited to [`PARAM_LIMIT`] bytes, or to
+//! [`LARGE_PARAM_LIMIT`] bytes when the module targets PTX ISA 8.1 or later
+//! and only `sm_70` or later; see [`param_limit`]. A kernel above the limit
+//! fails to load or launch, which [`FrameReport::to_diagnostic`] reports.
+//!
+//! ```
+//! use ptx_parser::analysis::{FrameReport, functions, param_limit};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".version 7.0 .target sm_80 .address_size 64 .entry k(.param .u8 a, .param .align 8 .b8 b[4096]) { .local .b32 x[4]; ret; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let report = FrameReport::of(functions(&module).next().unwrap());
+//!
+//!     assert_eq!(report.params[1].offset, 8);
+//!     assert_eq!(report.param_bytes, 4104);
+//!     assert_eq!(report.local_bytes, 16);
+//!     assert_eq!(param_limit(&module), 4096);
+//!     assert!(report.to_diagnostic(param_limit(&module)).is_some());
+//! });
+//! ```
+
+use super::FunctionRef;
+use super::callgraph::{CallOperands, symbol_name};
+use super::targets::{module_targets, module_version};
+use crate::Span;
+use crate::diagnostic::Diagnostic;
+use crate::metadata::PtxVersion;
+use crate::r#type::instruction::Inst;
+use crate::r#type::{
+    DataLinkage, DataType, FunctionStatement, Module, ModuleDirective, ModuleVariableDirective,
+    ParameterDirective, StatementDirective, VariableDirective, VariableModifier,
+};
+
+/// Bytes of kernel parameters accepted by every target.
+pub const PARAM_LIMIT: u64 = 4096;
+
+/// Bytes of kernel parameters accepted from PTX ISA 8.1 on `sm_70` and later.
+pub const LARGE_PARAM_LIMIT: u64 = 32764;
+
+/// A parameter of a function and where the ABI places it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamSlot<'a> {
+    pub name: &'a str,
+    pub offset: u64,
+    pub size: u64,
+    pub align: u64,
+    pub span: Span,
+}
+
+/// `.param` space passed to and returned from a `call`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallFrame<'a> {
+    /// Path of the instruction in the body, see
+    /// [`CfgStatement::path`](super::CfgStatement::path).
+    pub path: Vec<usize>,
+    pub span: Span,
+    /// Function named by a direct call.
+    pub callee: Option<&'a str>,
+    /// Bytes of the `.param` variables passed as arguments.
+    pub param_bytes: u64,
+    /// Bytes of the `.param` variable receiving the result.
+    pub return_bytes: u64,
+}
+
+/// Parameter, stack and shared memory usage of a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameReport<'a> {
+    pub function: &'a str,
+    pub is_entry: bool,
+    pub span: Span,
+    /// `.param` parameters in declaration order; `.reg` parameters are
+    /// passed in registers and left out.
+    pub params: Vec<ParamSlot<'a>>,
+    /// End of the last parameter.
+    pub param_bytes: u64,
+    /// Size of the `.param` return parameter of a `.func`.
+    pub return_bytes: u64,
+    /// `.local` variables of the body, nested blocks included.
+    pub local_bytes: u64,
+    /// `.shared` variables of the body; module-scope ones are counted by
+    /// [`module_shared_bytes`].
+    pub shared_bytes: u64,
+    /// `alloca` instructions, which grow the stack at run time.
+    pub alloca: Vec<Span>,
+    /// `stacksave` and `stackrestore` instructions.
+    pub stacksave: Vec<Span>,
+    pub calls: Vec<CallFrame<'a>>,
+}
+
+impl<'a> FrameReport<'a> {
+    /// Report for `function`; a prototype only has its parameters.
+    pub fn of(function: FunctionRef<'a>) -> Self {
+        let mut params = Vec::new();
+        let mut param_bytes = 0;
+        for parameter in function.params() {
+            if let Some(mut slot) = param_slot(parameter) {
+                slot.offset = align_to(param_bytes, slot.align);
+                param_bytes = slot.offset + slot.size;
+                params.push(slot);
+            }
+        }
+
+        let mut report = FrameReport {
+            function: function.name(),
+            is_entry: function.is_entry(),
+            span: function.span(),
+            params,
+            param_bytes,
+            return_bytes: function
+                .return_param()
+                .and_then(param_slot)
+                .map_or(0, |slot| slot.size),
+            local_bytes: 0,
+            shared_bytes: 0,
+            alloca: Vec::new(),
+            stacksave: Vec::new(),
+            calls: Vec::new(),
+        };
+        if let Some(body) = function.body() {
+            let mut scopes = Vec::new();
+            report.visit(&body.statements, &mut Vec::new(), &mut scopes);
+        }
+        report
+    }
+
+    pub fn uses_alloca(&self) -> bool {
+        !self.alloca.is_empty()
+    }
+
+    pub fn uses_stacksave(&self) -> bool {
+        !self.stacksave.is_empty()
+    }
+
+    /// Whether the parameters of a kernel take more than `limit` bytes.
+    pub fn exceeds_param_limit(&self, limit: u64) -> bool {
+        self.is_entry && self.param_bytes > limit
+    }
+
+    /// An error at the kernel when its parameters take more than `limit`
+    /// bytes, as from [`param_limit`].
+    pub fn to_diagnostic(&self, limit: u64) -> Option<Diagnostic> {
+        if !self.exceeds_param_limit(limit) {
+            return None;
+        }
+        let mut diagnostic = Diagnostic::error(
+            format!(
+                "parameters of kernel `{}` take {} bytes, above the limit of {limit} bytes",
+                self.function, self.param_bytes
+            ),
+            self.span,
+        )
+        .with_label("launching this kernel fails");
+        if let Some(slot) = self
+            .params
+            .iter()
+            .find(|slot| slot.offset + slot.size > limit)
+        {
+            diagnostic = diagnostic.with_note(format!(
+                "`{}` ends at byte {}",
+                slot.name,
+                slot.offset + slot.size
+            ));
+        }
+        if limit < LARGE_PARAM_LIMIT && self.param_bytes <= LARGE_PARAM_LIMIT {
+            diagnostic = diagnostic.with_note(format!(
+                "PTX ISA 8.1 raises the limit to {LARGE_PARAM_LIMIT} bytes on sm_70 and later"
+            ));
+        }
+        Some(diagnostic)
+    }
+
+    /// Walk `statements`, keeping the `.param` variables in scope by name
+    /// and size so that call arguments can be measured.
+    fn visit(
+        &mut self,
+        statements: &'a [FunctionStatement],
+        path: &mut Vec<usize>,
+        scopes: &mut Vec<(&'a str, u64)>,
+    ) {
+        let depth = scopes.len();
+        for (index, statement) in statements.iter().enumerate() {
+            path.push(index);
+            match statement {
+                FunctionStatement::Directive { directive, .. } => match directive {
+                    StatementDirective::Local { directive, .. } => {
+                        let (size, align) = variable_layout(directive);
+                        self.local_bytes = align_to(self.local_bytes, align) + size;
+                    }
+                    StatementDirective::Shared { directive, .. } => {
+                        let (size, align) = variable_layout(directive);
+                        self.shared_bytes = align_to(self.shared_bytes, align) + size;
+                    }
+                    StatementDirective::Param { directive, .. } => {
+                        scopes.push((&directive.name.val, variable_layout(directive).0));
+                    }
+                    _ => {}
+                },
+                FunctionStatement::Instruction { instruction, span } => {
+                    match &instruction.inst {
+                        Inst::AllocaType(_) => self.alloca.push(*span),
+                        Inst::StacksaveType(_) | Inst::StackrestoreType(_) => {
+                            self.stacksave.push(*span)
+                        }
+                        _ => {}
+                    }
+                    if let Some(call) = CallOperands::of(instruction) {
+                        let size = |operand| {
+                            symbol_name(operand)
+                                .and_then(|name| {
+                                    scopes.iter().rev().find(|(declared, _)| *declared == name)
+                                })
+                                .map_or(0, |(_, size)| *size)
+                        };
+                        self.calls.push(CallFrame {
+                            path: path.clone(),
+                            span: *span,
+                            callee: call.targets.map_or(symbol_name(call.callee), |_| None),
+                            param_bytes: call.params.iter().map(size).sum(),
+                            return_bytes: call.return_param.map_or(0, size),
+                        });
+                    }
+                }
+                FunctionStatement::Block { statements, .. } => self.visit(statements, path, scopes),
+                FunctionStatement::Label { .. } | FunctionStatement::Error { .. } => {}
+            }
+            path.pop();
+        }
+        scopes.truncate(depth);
+    }
+}
+
+/// Kernel parameter limit of `module`: [`LARGE_PARAM_LIMIT`] for PTX ISA 8.1
+/// or later when every target is `sm_70` or later, [`PARAM_LIMIT`]
+/// otherwise, including when `.version` or `.target` is missing.
+pub fn param_limit(module: &Module) -> u64 {
+    let targets = module_targets(module);
+    let large = module_version(module).is_some_and(|version| version >= PtxVersion::new(8, 1))
+        && !targets.is_empty()
+        && targets.iter().all(|(target, _)| target.sm >= 70);
+    if large {
+        LARGE_PARAM_LIMIT
+    } else {
+        PARAM_LIMIT
+    }
+}
+
+/// Bytes of `.shared` variables declared at module scope with a known size.
+/// An `.extern` array without a size stands for dynamic shared memory and
+/// takes none.
+pub fn module_shared_bytes(module: &Module) -> u64 {
+    let mut bytes = 0;
+    for directive in &module.directives {
+        if let ModuleDirective::ModuleVariable {
+            linkage,
+            directive: ModuleVariableDirective::Shared { directive, .. },
+            ..
+        } = directive
+        {
+            if matches!(linkage, Some(DataLinkage::Extern { .. }))
+                && directive.array_dims.contains(&None)
+            {
+                continue;
+            }
+            let (size, align) = variable_layout(directive);
+            bytes = align_to(bytes, align) + size;
+        }
+    }
+    bytes
+}
+
+/// Layout of a `.param` parameter with its offset left at zero, or `None`
+/// for a `.reg` parameter.
+pub(super) fn param_slot(parameter: &ParameterDirective) -> Option<ParamSlot<'_>> {
+    let ParameterDirective::Parameter {
+        align,
+        ptr,
+        ty,
+        name,
+        array,
+        span,
+        ..
+    } = parameter
+    else {
+        return None;
+    };
+    let size = type_size(ty);
+    // With `.ptr`, `.align` gives the alignment of the data pointed to.
+    let align = match align {
+        Some(align) if !ptr => u64::from(*align),
+        _ => size,
+    };
+    Some(ParamSlot {
+        name: &name.val,
+        offset: 0,
+        size: size * elements(array),
+        align,
+        span: *span,
+    })
+}
+
+/// Size and alignment of a variable.
+pub(super) fn variable_layout(variable: &VariableDirective) -> (u64, u64) {
+    let mut size = type_size(&variable.ty);
+    let mut align = None;
+    for modifier in &variable.modifiers {
+        match modifier {
+            VariableModifier::Vector { value, .. } => size *= u64::from(*value),
+            VariableModifier::Alignment { value, .. } => align = Some(u64::from(*value)),
+            VariableModifier::Ptr { .. } => {}
+        }
+    }
+    (size * elements(&variable.array_dims), align.unwrap_or(size))
+}
+
+/// Number of elements of an array with `dims`; a dimension without a size
+/// counts as empty.
+fn elements(dims: &[Option<u64>]) -> u64 {
+    dims.iter().map(|dim| dim.unwrap_or(0)).product()
+}
+
+fn align_to(offset: u64, align: u64) -> u64 {
+    offset.next_multiple_of(align.max(1))
+}
+
+/// Bytes taken by a value of type `ty`. `.pred` has no memory layout and
+/// counts as a byte; the opaque `.texref`, `.samplerref` and `.surfref`
+/// handles take eight.
+pub(super) fn type_size(ty: &DataType) -> u64 {
+    match ty {
+        DataType::U8 { .. } | DataType::S8 { .. } | DataType::B8 { .. } => 1,
+        DataType::U16 { .. } | DataType::S16 { .. } | DataType::B16 { .. } => 2,
+        DataType::F16 { .. } => 2,
+        DataType::U32 { .. } | DataType::S32 { .. } | DataType::B32 { .. } => 4,
+        DataType::F16x2 { .. } | DataType::F32 { .. } => 4,
+        DataType::U64 { .. } | DataType::S64 { .. } | DataType::B64 { .. } => 8,
+        DataType::F64 { .. } => 8,
+        DataType::B128 { .. } => 16,
+        DataType::Pred { .. } => 1,
+        DataType::TexRef { .. } | DataType::SamplerRef { .. } | DataType::SurfRef { .. } => 8,
+    }
+}
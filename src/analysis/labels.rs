@@ -0,0 +1,458 @@
+//! Labels and the branches that target them.
+//!
+//! A label is visible in the block it is declared in and in the blocks nested
+//! inside it, so a branch may leave a block but not enter one. The parser
+//! accepts any label name as a branch target; [`check_labels`] reports:
+//!
+//! - branches and `.branchtargets` entries naming a label the function does
+//!   not declare, or one declared in a block that does not enclose them;
+//! - labels declared twice in the same block;
+//! - `brx.idx` naming a label that is not a `.branchtargets` list;
+//! - labels that nothing refers to, as warnings. Labels referred to from
+//!   debug `.section` directives count as used, and the `$L__tmp`,
+//!   `$L__func_begin` and `$L__func_end` labels that `nvcc` emits for debug
+//!   information are never reported.
+//!
+//! ```
+//! use ptx_parser::analysis::check_labels;
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".entry k() { bra DONE; { DONE: ret; } }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let issues = check_labels(&module);
+//!
+//!     assert_eq!(
+//!         issues[0].to_string(),
+//!         "`DONE` is declared in a block that does not enclose the branch"
+//!     );
+//!     assert_eq!(&source[issues[0].span().start..issues[0].span().end], "DONE");
+//! });
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use thiserror::Error;
+
+use super::{FunctionRef, functions};
+use crate::Span;
+use crate::diagnostic::Diagnostic;
+use crate::operands::OperandValue;
+use crate::r#type::{
+    FunctionStatement, FunctionSymbol, GeneralOperand, Instruction, Label, Module,
+    ModuleDebugDirective, ModuleDirective, Operand, SectionDirective, SectionEntry,
+    StatementDirective, StatementSectionDirectiveLine,
+};
+use crate::visit::Visitor;
+
+/// A problem with a label or a branch target.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum LabelIssue {
+    /// A branch to a label the function does not declare.
+    #[error("undefined label `{label}`")]
+    Undefined { label: String, span: Span },
+    /// A `.branchtargets` entry the function does not declare.
+    #[error("`.branchtargets` names undefined label `{label}`")]
+    UndefinedTarget { label: String, span: Span },
+    /// A branch or `.branchtargets` entry naming a label declared in a block
+    /// that does not enclose it.
+    #[error("`{label}` is declared in a block that does not enclose the branch")]
+    IntoBlock {
+        label: String,
+        span: Span,
+        declared: Span,
+    },
+    #[error("label `{label}` is already declared in this block")]
+    Duplicate {
+        label: String,
+        span: Span,
+        previous: Span,
+    },
+    /// `brx.idx` naming a label that does not precede a `.branchtargets`
+    /// directive.
+    #[error("`brx.idx` needs a `.branchtargets` list, but `{label}` is a plain label")]
+    NotBranchTargets {
+        label: String,
+        span: Span,
+        declared: Span,
+    },
+    #[error("label `{label}` is never used")]
+    Unused { label: String, span: Span },
+}
+
+impl LabelIssue {
+    /// Span of the offending use or declaration.
+    pub fn span(&self) -> Span {
+        match self {
+            LabelIssue::Undefined { span, .. }
+            | LabelIssue::UndefinedTarget { span, .. }
+            | LabelIssue::IntoBlock { span, .. }
+            | LabelIssue::Duplicate { span, .. }
+            | LabelIssue::NotBranchTargets { span, .. }
+            | LabelIssue::Unused { span, .. } => *span,
+        }
+    }
+
+    /// Convert this issue into a [`Diagnostic`]; unused labels are warnings.
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        match self {
+            LabelIssue::Undefined { span, .. } | LabelIssue::UndefinedTarget { span, .. } => {
+                Diagnostic::error(self.to_string(), *span)
+                    .with_label("not declared in this function")
+            }
+            LabelIssue::IntoBlock { span, .. } => Diagnostic::error(self.to_string(), *span)
+                .with_label("branches into another block")
+                .with_note("a label is only visible in its block and the blocks nested in it"),
+            LabelIssue::Duplicate { span, .. } => {
+                Diagnostic::error(self.to_string(), *span).with_label("declared again here")
+            }
+            LabelIssue::NotBranchTargets { label, span, .. } => {
+                Diagnostic::error(self.to_string(), *span)
+                    .with_label("not a `.branchtargets` list")
+                    .with_note(format!(
+                        "declare the targets with `{label}: .branchtargets ...;`"
+                    ))
+            }
+            LabelIssue::Unused { span, .. } => {
+                Diagnostic::warning(self.to_string(), *span).with_label("declared here")
+            }
+        }
+    }
+}
+
+/// Check the labels and branch targets of every function in `module`.
+///
+/// Issues are reported per function in source order.
+pub fn check_labels(module: &Module) -> Vec<LabelIssue> {
+    let sections = section_labels(module);
+    let mut issues = Vec::new();
+    for function in functions(module) {
+        issues.extend(check_function(function, &sections));
+    }
+    issues
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum UseKind {
+    /// Target of `bra`.
+    Branch,
+    /// `.branchtargets` list of `brx.idx`.
+    Indirect,
+    /// Entry of a `.branchtargets` list.
+    ListEntry,
+    /// Any other symbol operand, such as the `.callprototype` of `call`.
+    Other,
+}
+
+struct LabelUse<'a> {
+    name: &'a str,
+    span: Span,
+    scope: usize,
+    kind: UseKind,
+}
+
+struct Declaration<'a> {
+    name: &'a str,
+    span: Span,
+    /// Whether the label names a `.branchtargets` list.
+    list: bool,
+    used: bool,
+}
+
+#[derive(Default)]
+struct Collector<'a> {
+    /// Parent of each block scope; the function body is scope 0.
+    parents: Vec<Option<usize>>,
+    declarations: Vec<Declaration<'a>>,
+    /// Declarations by scope and name.
+    scopes: HashMap<(usize, &'a str), usize>,
+    uses: Vec<LabelUse<'a>>,
+    issues: Vec<LabelIssue>,
+}
+
+impl<'a> Collector<'a> {
+    fn block(&mut self, statements: &'a [FunctionStatement], parent: Option<usize>) {
+        let scope = self.parents.len();
+        self.parents.push(parent);
+        for (index, statement) in statements.iter().enumerate() {
+            match statement {
+                FunctionStatement::Label { label, .. } => {
+                    let list = matches!(
+                        statements.get(index + 1),
+                        Some(FunctionStatement::Directive {
+                            directive: StatementDirective::BranchTargets { .. },
+                            ..
+                        })
+                    );
+                    self.declare(label, scope, list);
+                }
+                FunctionStatement::Directive {
+                    directive: StatementDirective::BranchTargets { directive, .. },
+                    ..
+                } => {
+                    self.uses
+                        .extend(directive.labels.iter().map(|label| LabelUse {
+                            name: &label.val,
+                            span: label.span,
+                            scope,
+                            kind: UseKind::ListEntry,
+                        }));
+                }
+                FunctionStatement::Instruction { instruction, .. } => {
+                    self.instruction(instruction, scope)
+                }
+                FunctionStatement::Block { statements, .. } => self.block(statements, Some(scope)),
+                _ => {}
+            }
+        }
+    }
+
+    fn declare(&mut self, label: &'a Label, scope: usize, list: bool) {
+        if let Some(&previous) = self.scopes.get(&(scope, label.val.as_str())) {
+            self.issues.push(LabelIssue::Duplicate {
+                label: label.val.clone(),
+                span: label.span,
+                previous: self.declarations[previous].span,
+            });
+            return;
+        }
+        self.scopes
+            .insert((scope, &label.val), self.declarations.len());
+        self.declarations.push(Declaration {
+            name: &label.val,
+            span: label.span,
+            list,
+            used: false,
+        });
+    }
+
+    fn instruction(&mut self, instruction: &'a Instruction, scope: usize) {
+        let target = match instruction.inst.opcode() {
+            "bra" => branch_target(instruction).map(|target| (target, UseKind::Branch)),
+            "brx" => branch_target(instruction).map(|target| (target, UseKind::Indirect)),
+            _ => None,
+        };
+        if let Some(((name, span), kind)) = target {
+            self.uses.push(LabelUse {
+                name,
+                span,
+                scope,
+                kind,
+            });
+        }
+
+        let mut symbols = Symbols::default();
+        symbols.visit_instruction(instruction);
+        for (name, span) in symbols.0 {
+            if target.is_none_or(|((_, target), _)| target != span) {
+                self.uses.push(LabelUse {
+                    name,
+                    span,
+                    scope,
+                    kind: UseKind::Other,
+                });
+            }
+        }
+    }
+
+    /// Declaration of `name` visible from `scope`.
+    fn visible(&self, name: &str, scope: usize) -> Option<usize> {
+        let mut scope = Some(scope);
+        while let Some(current) = scope {
+            if let Some(&declaration) = self.scopes.get(&(current, name)) {
+                return Some(declaration);
+            }
+            scope = self.parents[current];
+        }
+        None
+    }
+
+    fn resolve(&mut self) {
+        for index in 0..self.uses.len() {
+            let used = &self.uses[index];
+            let (name, span, kind) = (used.name, used.span, used.kind);
+            if let Some(declaration) = self.visible(name, used.scope) {
+                self.declarations[declaration].used = true;
+                if kind == UseKind::Indirect && !self.declarations[declaration].list {
+                    self.issues.push(LabelIssue::NotBranchTargets {
+                        label: name.to_string(),
+                        span,
+                        declared: self.declarations[declaration].span,
+                    });
+                }
+                continue;
+            }
+            let hidden = self
+                .declarations
+                .iter()
+                .position(|declaration| declaration.name == name);
+            let issue = match (hidden, kind) {
+                (_, UseKind::Other) => None,
+                (Some(declaration), _) => Some(LabelIssue::IntoBlock {
+                    label: name.to_string(),
+                    span,
+                    declared: self.declarations[declaration].span,
+                }),
+                (None, UseKind::ListEntry) => Some(LabelIssue::UndefinedTarget {
+                    label: name.to_string(),
+                    span,
+                }),
+                (None, _) => Some(LabelIssue::Undefined {
+                    label: name.to_string(),
+                    span,
+                }),
+            };
+            if let Some(declaration) = hidden {
+                self.declarations[declaration].used = true;
+            }
+            self.issues.extend(issue);
+        }
+    }
+}
+
+fn check_function(function: FunctionRef<'_>, sections: &HashSet<&str>) -> Vec<LabelIssue> {
+    let Some(body) = function.body() else {
+        return Vec::new();
+    };
+    let mut collector = Collector::default();
+    collector.block(&body.statements, None);
+    collector.resolve();
+
+    let mut issues = collector.issues;
+    issues.extend(
+        collector
+            .declarations
+            .iter()
+            .filter(|declaration| {
+                !declaration.used
+                    && !sections.contains(declaration.name)
+                    && !is_debug_label(declaration.name)
+            })
+            .map(|declaration| LabelIssue::Unused {
+                label: declaration.name.to_string(),
+                span: declaration.span,
+            }),
+    );
+    issues.sort_by_key(|issue| issue.span().start);
+    issues
+}
+
+/// Whether `name` is one of the labels `nvcc` emits to mark source
+/// locations for debug information.
+fn is_debug_label(name: &str) -> bool {
+    ["$L__tmp", "$L__func_begin", "$L__func_end"]
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+}
+
+/// Label operand of `bra`, or the `.branchtargets` list of `brx.idx`, with
+/// its span.
+fn branch_target(instruction: &Instruction) -> Option<(&str, Span)> {
+    instruction
+        .inst
+        .operands()
+        .into_iter()
+        .rev()
+        .find_map(|operand| match operand.value {
+            OperandValue::General(GeneralOperand::Single {
+                operand: Operand::Symbol { name, span },
+                ..
+            }) => Some((name.as_str(), *span)),
+            _ => None,
+        })
+}
+
+/// Symbol operands of an instruction that may name a label.
+#[derive(Default)]
+struct Symbols<'a>(Vec<(&'a str, Span)>);
+
+impl<'a> Visitor<'a> for Symbols<'a> {
+    fn visit_symbol(&mut self, name: &'a str, span: Span) {
+        self.0.push((name, span));
+    }
+
+    fn visit_function_symbol(&mut self, symbol: &'a FunctionSymbol) {
+        self.0.push((&symbol.val, symbol.span));
+    }
+
+    fn visit_label(&mut self, label: &'a Label) {
+        self.0.push((&label.val, label.span));
+    }
+}
+
+/// Labels referred to by the `.section` directives of `module`, such as the
+/// `$L__func_begin0` of a `.debug_info` entry.
+fn section_labels(module: &Module) -> HashSet<&str> {
+    let mut labels = HashSet::new();
+    for directive in &module.directives {
+        if let ModuleDirective::Debug {
+            directive: ModuleDebugDirective::Section { directive, .. },
+            ..
+        } = directive
+        {
+            add_section_labels(directive, &mut labels);
+        }
+    }
+    for function in functions(module) {
+        if let Some(body) = function.body() {
+            statement_section_labels(&body.statements, &mut labels);
+        }
+    }
+    labels
+}
+
+fn statement_section_labels<'a>(
+    statements: &'a [FunctionStatement],
+    labels: &mut HashSet<&'a str>,
+) {
+    for statement in statements {
+        match statement {
+            FunctionStatement::Directive {
+                directive: StatementDirective::Section { directive, .. },
+                ..
+            } => add_section_labels(directive, labels),
+            FunctionStatement::Block { statements, .. } => {
+                statement_section_labels(statements, labels)
+            }
+            _ => {}
+        }
+    }
+}
+
+fn add_section_labels<'a>(section: &'a SectionDirective, labels: &mut HashSet<&'a str>) {
+    for entry in &section.entries {
+        let SectionEntry::Directive(line) = entry else {
+            continue;
+        };
+        match line {
+            StatementSectionDirectiveLine::B32Label { labels: label, .. }
+            | StatementSectionDirectiveLine::B64Label { labels: label, .. }
+            | StatementSectionDirectiveLine::B32LabelPlusImm {
+                entries: (label, _),
+                ..
+            } => {
+                labels.insert(&label.val);
+            }
+            StatementSectionDirectiveLine::B64LabelPlusImm {
+                entries: (label, _),
+                ..
+            } => {
+                labels.insert(&label.val);
+            }
+            StatementSectionDirectiveLine::B32LabelDiff {
+                entries: (first, second),
+                ..
+            }
+            | StatementSectionDirectiveLine::B64LabelDiff {
+                entries: (first, second),
+                ..
+            } => {
+                labels.insert(&first.val);
+                labels.insert(&second.val);
+            }
+            StatementSectionDirectiveLine::B8 { .. }
+            | StatementSectionDirectiveLine::B16 { .. }
+            | StatementSectionDirectiveLine::B32Immediate { .. }
+            | StatementSectionDirectiveLine::B64Immediate { .. } => {}
+        }
+    }
+}
@@ -0,0 +1,384 @@
+//! Register liveness and register pressure.
+//!
+//! [`Liveness`] computes the declared registers live into and out of every
+//! basic block. [`PressureReport`] turns that into an estimate of how many
+//! hardware registers a function needs, counted in 32-bit units: 64-bit
+//! registers occupy a pair and `.b128` registers four. Predicates live in a
+//! register file of their own and are counted separately.
+//!
+//! ```
+//! use ptx_parser::analysis::{PressureReport, functions};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".entry k() .maxnreg 2 { .reg .b64 %rd<3>; mov.b64 %rd1, 1; mov.b64 %rd2, 2; add.s64 %rd1, %rd1, %rd2; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let report = PressureReport::of(functions(&module).next().unwrap()).unwrap();
+//!     assert_eq!(report.max.registers, 4);
+//!     assert_eq!(report.peak.as_ref().unwrap().live, ["%rd1", "%rd2"]);
+//!     assert!(report.exceeds_maxnreg());
+//! });
+//! ```
+
+use std::collections::BTreeSet;
+
+use super::FunctionRef;
+use super::cfg::{BlockId, Cfg};
+use super::dataflow::{self, Analysis, Direction};
+use super::registers::{RegisterTypes, register_accesses};
+use crate::r#type::{
+    DataType, EntryFunctionHeaderDirective, FunctionStatement, ParameterDirective,
+};
+use crate::{Diagnostic, Span};
+
+/// Live registers, ordered by name.
+pub type LiveSet<'a> = BTreeSet<&'a str>;
+
+/// Registers written and read by one statement of a block.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+struct Access<'a> {
+    defs: Vec<&'a str>,
+    uses: Vec<&'a str>,
+    /// A guarded instruction may leave its destinations unchanged, so its
+    /// definitions do not end the live ranges of earlier values.
+    predicated: bool,
+}
+
+/// Registers live at a statement of a basic block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LivePoint<'a> {
+    /// Index into [`BasicBlock::statements`](super::BasicBlock::statements).
+    pub index: usize,
+    pub live_before: LiveSet<'a>,
+    pub live_after: LiveSet<'a>,
+    /// Registers written by the statement.
+    pub defs: Vec<&'a str>,
+}
+
+/// Live registers at the boundaries of every basic block of a [`Cfg`].
+///
+/// Only registers declared by `.reg` directives or `.reg` parameters take
+/// part; special registers such as `%tid` are ignored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Liveness<'a> {
+    accesses: Vec<Vec<Access<'a>>>,
+    live_in: Vec<LiveSet<'a>>,
+    live_out: Vec<LiveSet<'a>>,
+}
+
+impl<'a> Liveness<'a> {
+    /// Liveness of `function`, whose body `cfg` was built from. The `.reg`
+    /// return parameter of a `.func` is read by the caller and so is live at
+    /// every exit.
+    pub fn of(function: FunctionRef<'a>, cfg: &Cfg<'a>) -> Self {
+        let types = RegisterTypes::of(function);
+        let mut exit = LiveSet::new();
+        if let Some(ParameterDirective::Register { name, .. }) = function.return_param() {
+            exit.insert(name.val.as_str());
+        }
+        Self::compute_with_exit(cfg, &types, exit)
+    }
+
+    /// Liveness of a body on its own, with nothing live after the exits.
+    pub fn compute(cfg: &Cfg<'a>, types: &RegisterTypes) -> Self {
+        Self::compute_with_exit(cfg, types, LiveSet::new())
+    }
+
+    fn compute_with_exit(cfg: &Cfg<'a>, types: &RegisterTypes, exit: LiveSet<'a>) -> Self {
+        let accesses: Vec<Vec<Access<'a>>> = cfg
+            .blocks()
+            .iter()
+            .map(|block| {
+                block
+                    .statements
+                    .iter()
+                    .map(|statement| match statement.statement {
+                        FunctionStatement::Instruction { instruction, .. } => {
+                            let (defs, uses) = register_accesses(instruction);
+                            let declared = |names: Vec<(&'a str, _)>| -> Vec<&'a str> {
+                                names
+                                    .into_iter()
+                                    .map(|(name, _)| name)
+                                    .filter(|name| types.get(name).is_some())
+                                    .collect()
+                            };
+                            Access {
+                                defs: declared(defs),
+                                uses: declared(uses),
+                                predicated: instruction.predicate.is_some(),
+                            }
+                        }
+                        _ => Access::default(),
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Upward-exposed uses and killed registers of each block.
+        let mut problem = LiveRegisters {
+            gen_sets: Vec::with_capacity(cfg.len()),
+            kill_sets: Vec::with_capacity(cfg.len()),
+            exit,
+        };
+        for block in &accesses {
+            let mut generated = LiveSet::new();
+            let mut killed = LiveSet::new();
+            for access in block {
+                generated.extend(access.uses.iter().filter(|name| !killed.contains(*name)));
+                if !access.predicated {
+                    killed.extend(&access.defs);
+                }
+            }
+            problem.gen_sets.push(generated);
+            problem.kill_sets.push(killed);
+        }
+
+        let solution = dataflow::solve(cfg, &problem);
+        let live_in = cfg
+            .block_ids()
+            .map(|id| solution.entry(id).clone())
+            .collect();
+        let live_out = cfg
+            .block_ids()
+            .map(|id| solution.exit(id).clone())
+            .collect();
+        Liveness {
+            accesses,
+            live_in,
+            live_out,
+        }
+    }
+
+    pub fn live_in(&self, block: BlockId) -> &LiveSet<'a> {
+        &self.live_in[block.index()]
+    }
+
+    pub fn live_out(&self, block: BlockId) -> &LiveSet<'a> {
+        &self.live_out[block.index()]
+    }
+
+    /// Live registers around each instruction of `block`, in source order.
+    pub fn points(&self, block: BlockId) -> Vec<LivePoint<'a>> {
+        let mut live = self.live_out[block.index()].clone();
+        let mut points = Vec::new();
+        for (index, access) in self.accesses[block.index()].iter().enumerate().rev() {
+            if access.defs.is_empty() && access.uses.is_empty() {
+                continue;
+            }
+            let live_after = live.clone();
+            if !access.predicated {
+                for def in &access.defs {
+                    live.remove(def);
+                }
+            }
+            live.extend(&access.uses);
+            points.push(LivePoint {
+                index,
+                live_before: live.clone(),
+                live_after,
+                defs: access.defs.clone(),
+            });
+        }
+        points.reverse();
+        points
+    }
+}
+
+/// Backward problem behind [`Liveness`].
+struct LiveRegisters<'a> {
+    gen_sets: Vec<LiveSet<'a>>,
+    kill_sets: Vec<LiveSet<'a>>,
+    exit: LiveSet<'a>,
+}
+
+impl<'a> Analysis for LiveRegisters<'a> {
+    type Fact = LiveSet<'a>;
+    const DIRECTION: Direction = Direction::Backward;
+
+    fn bottom(&self) -> Self::Fact {
+        LiveSet::new()
+    }
+
+    fn boundary(&self) -> Self::Fact {
+        self.exit.clone()
+    }
+
+    fn transfer(&self, block: BlockId, input: &Self::Fact) -> Self::Fact {
+        let kill = &self.kill_sets[block.index()];
+        let mut live = self.gen_sets[block.index()].clone();
+        live.extend(input.iter().filter(|name| !kill.contains(*name)));
+        live
+    }
+}
+
+/// Register demand, in 32-bit registers and predicate registers.
+///
+/// Orders by general registers first, which is what `.maxnreg` limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Pressure {
+    pub registers: u32,
+    pub predicates: u32,
+}
+
+impl Pressure {
+    /// Demand of the registers in `names`; undeclared names are skipped.
+    pub fn of<'b>(names: impl IntoIterator<Item = &'b str>, types: &RegisterTypes) -> Self {
+        let mut pressure = Pressure::default();
+        for ty in names.into_iter().filter_map(|name| types.get(name)) {
+            match ty {
+                DataType::Pred { .. } => pressure.predicates += 1,
+                DataType::U64 { .. }
+                | DataType::S64 { .. }
+                | DataType::B64 { .. }
+                | DataType::F64 { .. }
+                | DataType::TexRef { .. }
+                | DataType::SamplerRef { .. }
+                | DataType::SurfRef { .. } => pressure.registers += 2,
+                DataType::B128 { .. } => pressure.registers += 4,
+                _ => pressure.registers += 1,
+            }
+        }
+        pressure
+    }
+}
+
+/// The instruction where pressure is highest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PressurePeak {
+    pub block: BlockId,
+    /// Path of the instruction in the body, see
+    /// [`CfgStatement::path`](super::CfgStatement::path).
+    pub path: Vec<usize>,
+    pub span: Span,
+    pub pressure: Pressure,
+    /// Registers live across the instruction, including its destinations.
+    pub live: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockPressure {
+    pub block: BlockId,
+    pub max: Pressure,
+    /// `None` for blocks without instructions.
+    pub peak: Option<PressurePeak>,
+}
+
+/// Estimated register pressure of a function.
+///
+/// The pressure at an instruction is the larger of the registers live before
+/// it and the registers live after it together with its destinations, so a
+/// destination that is never read still takes a register. The estimate
+/// assumes no spilling or rematerialisation and is meant for comparing
+/// kernels and spotting peaks, not for predicting `ptxas` exactly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PressureReport {
+    pub function: String,
+    pub max: Pressure,
+    pub peak: Option<PressurePeak>,
+    pub blocks: Vec<BlockPressure>,
+    /// Value of the `.maxnreg` directive of an entry function.
+    pub maxnreg: Option<u32>,
+}
+
+impl PressureReport {
+    /// Report for `function`, or `None` for a prototype.
+    pub fn of(function: FunctionRef) -> Option<Self> {
+        let body = function.body()?;
+        let cfg = Cfg::build(body);
+        let types = RegisterTypes::of(function);
+        let liveness = Liveness::of(function, &cfg);
+
+        let mut blocks = Vec::with_capacity(cfg.len());
+        for id in cfg.block_ids() {
+            let block = cfg.block(id);
+            let mut max = Pressure::of(liveness.live_in(id).iter().copied(), &types);
+            let mut peak: Option<PressurePeak> = None;
+            for point in liveness.points(id) {
+                let mut across = point.live_after.clone();
+                across.extend(&point.defs);
+                let before = Pressure::of(point.live_before.iter().copied(), &types);
+                let after = Pressure::of(across.iter().copied(), &types);
+                let (pressure, live) = if before > after {
+                    (before, &point.live_before)
+                } else {
+                    (after, &across)
+                };
+                if peak.as_ref().is_some_and(|peak| peak.pressure >= pressure) {
+                    continue;
+                }
+                let statement = &block.statements[point.index];
+                peak = Some(PressurePeak {
+                    block: id,
+                    path: statement.path.clone(),
+                    span: statement.statement.span(),
+                    pressure,
+                    live: live.iter().map(|name| name.to_string()).collect(),
+                });
+            }
+            if let Some(peak) = &peak {
+                max = max.max(peak.pressure);
+            }
+            blocks.push(BlockPressure {
+                block: id,
+                max,
+                peak,
+            });
+        }
+
+        let mut peak: Option<&PressurePeak> = None;
+        for candidate in blocks.iter().filter_map(|block| block.peak.as_ref()) {
+            if peak.is_none_or(|peak| candidate.pressure > peak.pressure) {
+                peak = Some(candidate);
+            }
+        }
+        let maxnreg = match function {
+            FunctionRef::Entry(entry) => {
+                entry
+                    .directives
+                    .iter()
+                    .find_map(|directive| match directive {
+                        EntryFunctionHeaderDirective::MaxNReg { value, .. } => Some(*value),
+                        _ => None,
+                    })
+            }
+            FunctionRef::Func(_) => None,
+        };
+
+        Some(PressureReport {
+            function: function.name().to_string(),
+            max: blocks
+                .iter()
+                .map(|block| block.max)
+                .max()
+                .unwrap_or_default(),
+            peak: peak.cloned(),
+            blocks,
+            maxnreg,
+        })
+    }
+
+    /// Whether the estimated pressure is above the `.maxnreg` limit.
+    pub fn exceeds_maxnreg(&self) -> bool {
+        self.maxnreg.is_some_and(|limit| self.max.registers > limit)
+    }
+
+    /// A warning at the peak when the estimate exceeds `.maxnreg`.
+    pub fn to_diagnostic(&self) -> Option<Diagnostic> {
+        if !self.exceeds_maxnreg() {
+            return None;
+        }
+        let peak = self.peak.as_ref()?;
+        Some(
+            Diagnostic::warning(
+                format!(
+                    "estimated register pressure of `{}` ({} registers) exceeds `.maxnreg {}`",
+                    self.function,
+                    self.max.registers,
+                    self.maxnreg.unwrap_or_default()
+                ),
+                peak.span,
+            )
+            .with_label(format!("{} registers live here", peak.pressure.registers))
+            .with_note(format!("live: {}", peak.live.join(", "))),
+        )
+    }
+}
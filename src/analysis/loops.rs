@@ -0,0 +1,212 @@
+//! Natural loops of a control-flow graph.
+//!
+//! An edge `latch -> header` is a back edge when `header` dominates `latch`.
+//! The natural loop of a header is the header plus every block that reaches
+//! one of its latches without passing through the header. Loops either nest
+//! or are disjoint, forming a [`LoopNest`]:
+//!
+//! ```
+//! use ptx_parser::analysis::{Cfg, DominatorTree, LoopNest};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".entry k() { LOOP: .pragma \"nounroll\"; add.s32 %r1, %r1, 1; @%p1 bra LOOP; ret; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let function = ptx_parser::analysis::functions(&module).next().unwrap();
+//!     let cfg = Cfg::build(function.body().unwrap());
+//!     let loops = LoopNest::build(&cfg, &DominatorTree::dominators(&cfg));
+//!
+//!     assert_eq!(loops.len(), 1);
+//!     let (_, found) = loops.iter().next().unwrap();
+//!     assert_eq!(found.header, cfg.block_of_label("LOOP").unwrap());
+//!     assert!(found.nounroll);
+//! });
+//! ```
+
+use super::cfg::{BlockId, Cfg};
+use super::dominators::DominatorTree;
+use crate::r#type::{FunctionStatement, PragmaDirectiveKind, StatementDirective};
+
+/// Index of a [`Loop`] in its [`LoopNest`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LoopId(u32);
+
+impl LoopId {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Loop {
+    pub header: BlockId,
+    /// Sources of the back edges to the header.
+    pub latches: Vec<BlockId>,
+    /// Blocks of the loop including the header and nested loops, in
+    /// ascending order.
+    pub blocks: Vec<BlockId>,
+    /// Innermost enclosing loop.
+    pub parent: Option<LoopId>,
+    pub children: Vec<LoopId>,
+    /// Nesting depth; outermost loops have depth 1.
+    pub depth: u32,
+    /// Set when `.pragma "nounroll";` precedes the first instruction of the
+    /// header, inside the block or just before its label.
+    pub nounroll: bool,
+}
+
+impl Loop {
+    pub fn contains(&self, block: BlockId) -> bool {
+        self.blocks.binary_search(&block).is_ok()
+    }
+
+    /// Back edges as `(latch, header)` pairs.
+    pub fn back_edges(&self) -> impl Iterator<Item = (BlockId, BlockId)> + '_ {
+        self.latches.iter().map(|&latch| (latch, self.header))
+    }
+}
+
+/// The natural loops of a function.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct LoopNest {
+    /// Loops in order of their header blocks.
+    loops: Vec<Loop>,
+    /// Innermost loop of each block.
+    innermost: Vec<Option<LoopId>>,
+}
+
+impl LoopNest {
+    /// Find the loops of `cfg` given its dominators.
+    ///
+    /// Back edges to one header are merged into a single loop. Edges to a
+    /// block that does not dominate the source (irreducible control flow) do
+    /// not form loops.
+    pub fn build(cfg: &Cfg, dominators: &DominatorTree) -> Self {
+        let mut loops: Vec<Loop> = Vec::new();
+        for header in cfg.block_ids() {
+            let latches: Vec<BlockId> = cfg
+                .predecessors(header)
+                .iter()
+                .copied()
+                .filter(|&latch| dominators.dominates(header, latch))
+                .collect();
+            if latches.is_empty() {
+                continue;
+            }
+
+            let mut member = vec![false; cfg.len()];
+            member[header.index()] = true;
+            let mut stack = latches.clone();
+            while let Some(block) = stack.pop() {
+                if std::mem::replace(&mut member[block.index()], true) {
+                    continue;
+                }
+                stack.extend(
+                    cfg.predecessors(block)
+                        .iter()
+                        .filter(|p| dominators.contains(**p)),
+                );
+            }
+            loops.push(Loop {
+                header,
+                latches,
+                blocks: cfg.block_ids().filter(|id| member[id.index()]).collect(),
+                parent: None,
+                children: Vec::new(),
+                depth: 1,
+                nounroll: has_nounroll(cfg, header),
+            });
+        }
+
+        // The innermost loop containing a header is the smallest enclosing one.
+        let mut innermost: Vec<Option<LoopId>> = vec![None; cfg.len()];
+        let mut by_size: Vec<usize> = (0..loops.len()).collect();
+        by_size.sort_by_key(|&index| std::cmp::Reverse(loops[index].blocks.len()));
+        for &index in &by_size {
+            let id = LoopId(index as u32);
+            loops[index].parent = innermost[loops[index].header.index()];
+            for block in loops[index].blocks.clone() {
+                innermost[block.index()] = Some(id);
+            }
+        }
+        for &index in &by_size {
+            if let Some(parent) = loops[index].parent {
+                loops[index].depth = loops[parent.index()].depth + 1;
+            }
+        }
+        for index in 0..loops.len() {
+            if let Some(parent) = loops[index].parent {
+                loops[parent.index()].children.push(LoopId(index as u32));
+            }
+        }
+
+        LoopNest { loops, innermost }
+    }
+
+    pub fn len(&self) -> usize {
+        self.loops.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.loops.is_empty()
+    }
+
+    pub fn get(&self, id: LoopId) -> &Loop {
+        &self.loops[id.index()]
+    }
+
+    /// Loops in order of their header blocks.
+    pub fn iter(&self) -> impl Iterator<Item = (LoopId, &Loop)> {
+        self.loops
+            .iter()
+            .enumerate()
+            .map(|(index, l)| (LoopId(index as u32), l))
+    }
+
+    /// Loops not nested in another loop.
+    pub fn top_level(&self) -> impl Iterator<Item = LoopId> + '_ {
+        self.iter()
+            .filter(|(_, l)| l.parent.is_none())
+            .map(|(id, _)| id)
+    }
+
+    /// Innermost loop containing `block`.
+    pub fn innermost(&self, block: BlockId) -> Option<LoopId> {
+        self.innermost[block.index()]
+    }
+
+    /// Loop whose header is `block`.
+    pub fn loop_with_header(&self, block: BlockId) -> Option<LoopId> {
+        self.innermost(block)
+            .filter(|id| self.get(*id).header == block)
+    }
+
+    /// Number of loops containing `block`.
+    pub fn depth(&self, block: BlockId) -> u32 {
+        self.innermost(block).map_or(0, |id| self.get(id).depth)
+    }
+}
+
+/// Whether a `nounroll` pragma precedes the first instruction of `header`,
+/// either inside the block or directly in front of its label.
+fn has_nounroll(cfg: &Cfg, header: BlockId) -> bool {
+    let leading = cfg
+        .block(header)
+        .statements
+        .iter()
+        .take_while(|s| !matches!(s.statement, FunctionStatement::Instruction { .. }));
+    let trailing = header
+        .index()
+        .checked_sub(1)
+        .into_iter()
+        .flat_map(|previous| cfg.blocks()[previous].statements.iter().rev())
+        .take_while(|s| !matches!(s.statement, FunctionStatement::Instruction { .. }));
+    leading.chain(trailing).any(|s| {
+        matches!(
+            s.statement,
+            FunctionStatement::Directive {
+                directive: StatementDirective::Pragma { directive, .. },
+                ..
+            } if matches!(directive.kind, PragmaDirectiveKind::Nounroll)
+        )
+    })
+}
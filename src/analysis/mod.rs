@@ -0,0 +1,154 @@
+//! Semantic analyses over parsed modules.
+//!
+//! - [`symbols`]: name resolution from every use to its declaration.
+//! - [`registers`]: concrete registers declared by `.reg` ranges and their
+//!   types.
+//! - [`cfg`]: basic blocks and control-flow edges of function bodies.
+//! - [`labels`]: labels checked against the branches that target them.
+//! - [`dominators`]: dominator and post-dominator trees and dominance
+//!   frontiers.
+//! - [`loops`]: natural loops and their nesting.
+//! - [`dataflow`]: a generic worklist solver for dataflow problems.
+//! - [`liveness`]: live registers and estimated register pressure.
+//! - [`reaching`]: reaching definitions and def-use chains.
+//! - [`ssa`]: static single assignment form and its conversion back to PTX.
+//! - [`callgraph`]: calls between the functions of a module.
+//! - [`calls`]: call sites checked against the signature of their callee.
+//! - [`frame`]: parameter, stack and shared memory usage of functions.
+//! - [`typecheck`]: operand types checked against instruction types.
+//! - [`spaces`]: state spaces of memory accesses and the addresses they
+//!   use.
+//! - [`targets`]: instructions checked against the `.version` and `.target`
+//!   of a module.
+
+pub mod callgraph;
+pub mod calls;
+pub mod cfg;
+pub mod dataflow;
+pub mod dominators;
+pub mod frame;
+pub mod labels;
+pub mod liveness;
+pub mod loops;
+pub mod reaching;
+pub mod registers;
+pub mod spaces;
+pub mod ssa;
+pub mod symbols;
+pub mod targets;
+pub mod typecheck;
+
+pub use callgraph::{CallGraph, CallKind, CallOperands, CallSite, FunctionId, FunctionNode};
+pub use calls::{CallIssue, Callee, check_calls};
+pub use cfg::{BasicBlock, BlockId, Cfg, CfgStatement, Edge, EdgeKind, UnresolvedTarget};
+pub use dominators::DominatorTree;
+pub use frame::{
+    CallFrame, FrameReport, LARGE_PARAM_LIMIT, PARAM_LIMIT, ParamSlot, module_shared_bytes,
+    param_limit,
+};
+pub use labels::{LabelIssue, check_labels};
+pub use liveness::{
+    BlockPressure, LivePoint, LiveSet, Liveness, Pressure, PressurePeak, PressureReport,
+};
+pub use loops::{Loop, LoopId, LoopNest};
+pub use reaching::{DefId, Definition, Location, ReachingDefinitions, Use, UseId};
+pub use registers::{RegisterTypes, compact_registers};
+pub use spaces::{Address, AddressSpaces, SpaceIssue, check_state_spaces};
+pub use ssa::{GuardedDefinition, Phi, Ssa, SsaError};
+pub use symbols::{
+    DeclId, Declaration, Reference, Resolution, Scope, ScopeId, ScopeKind, StateSpace, Symbol,
+    SymbolIssue, SymbolKind, SymbolTable,
+};
+pub use targets::{
+    MinTarget, RequirementUse, TargetIssue, check_targets, min_target, module_targets,
+    module_version, requirement_uses,
+};
+pub use typecheck::{TypeIssue, typecheck, typecheck_function};
+
+use crate::Span;
+use crate::r#type::{
+    EntryFunctionDirective, FuncFunctionDirective, FunctionBody, Module, ModuleDirective,
+    ParameterDirective, StatementDirective,
+};
+
+/// An `.entry` or `.func` function, for analyses that treat both alike.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FunctionRef<'a> {
+    Entry(&'a EntryFunctionDirective),
+    Func(&'a FuncFunctionDirective),
+}
+
+impl<'a> FunctionRef<'a> {
+    pub fn name(&self) -> &'a str {
+        match self {
+            FunctionRef::Entry(function) => &function.name.val,
+            FunctionRef::Func(function) => &function.name.val,
+        }
+    }
+
+    pub fn is_entry(&self) -> bool {
+        matches!(self, FunctionRef::Entry(_))
+    }
+
+    pub fn params(&self) -> &'a [ParameterDirective] {
+        match self {
+            FunctionRef::Entry(function) => &function.params,
+            FunctionRef::Func(function) => &function.params,
+        }
+    }
+
+    /// Return parameter of a `.func`; kernels have none.
+    pub fn return_param(&self) -> Option<&'a ParameterDirective> {
+        match self {
+            FunctionRef::Entry(_) => None,
+            FunctionRef::Func(function) => function.return_param.as_ref(),
+        }
+    }
+
+    /// Declarations between the header and the body of a `.func`.
+    pub fn pre_body_declarations(&self) -> &'a [StatementDirective] {
+        match self {
+            FunctionRef::Entry(_) => &[],
+            FunctionRef::Func(function) => &function.pre_body_declarations,
+        }
+    }
+
+    /// Body, or `None` for a prototype.
+    pub fn body(&self) -> Option<&'a FunctionBody> {
+        match self {
+            FunctionRef::Entry(function) => function.body.as_ref(),
+            FunctionRef::Func(function) => function.body.as_ref(),
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            FunctionRef::Entry(function) => function.span,
+            FunctionRef::Func(function) => function.span,
+        }
+    }
+}
+
+impl<'a> From<&'a EntryFunctionDirective> for FunctionRef<'a> {
+    fn from(function: &'a EntryFunctionDirective) -> Self {
+        FunctionRef::Entry(function)
+    }
+}
+
+impl<'a> From<&'a FuncFunctionDirective> for FunctionRef<'a> {
+    fn from(function: &'a FuncFunctionDirective) -> Self {
+        FunctionRef::Func(function)
+    }
+}
+
+/// Functions of `module` in source order, including prototypes.
+pub fn functions(module: &Module) -> impl Iterator<Item = FunctionRef<'_>> {
+    module
+        .directives
+        .iter()
+        .filter_map(|directive| match directive {
+            ModuleDirective::EntryFunction { directive, .. } => Some(FunctionRef::Entry(directive)),
+            ModuleDirective::FuncFunction { directive, .. } => Some(FunctionRef::Func(directive)),
+            _ => None,
+        })
+}
@@ -0,0 +1,335 @@
+//! Reaching definitions with def-use and use-def chains.
+//!
+//! A definition of a register reaches a use when some path from the
+//! definition to the use does not redefine the register. A guarded
+//! instruction such as `@%p1 mov.u32 %r1, 0;` may leave its destination
+//! unchanged, so it is a partial definition: it reaches later uses alongside
+//! the definitions before it instead of replacing them.
+//!
+//! ```
+//! use ptx_parser::analysis::{Cfg, ReachingDefinitions, functions};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".entry k() { .reg .b32 %r1; mov.u32 %r1, 1; @%p1 mov.u32 %r1, 2; st.global.u32 [0], %r1; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let function = functions(&module).next().unwrap();
+//!     let cfg = Cfg::build(function.body().unwrap());
+//!     let reaching = ReachingDefinitions::of(function, &cfg);
+//!
+//!     let (store, _) = reaching.uses().last().unwrap();
+//!     let defs = reaching.definitions_of(store);
+//!     assert_eq!(defs.len(), 2);
+//!     assert!(reaching.definition(defs[1]).partial);
+//! });
+//! ```
+
+use std::collections::{BTreeSet, HashMap};
+
+use super::FunctionRef;
+use super::cfg::{BlockId, Cfg};
+use super::dataflow::{self, Analysis, Direction};
+use super::registers::{RegisterTypes, register_accesses};
+use crate::Span;
+use crate::r#type::{FunctionStatement, ParameterDirective};
+
+/// Index of a [`Definition`] in its [`ReachingDefinitions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DefId(u32);
+
+impl DefId {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Index of a [`Use`] in its [`ReachingDefinitions`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct UseId(u32);
+
+impl UseId {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A statement of a [`Cfg`]: the block and the index into its
+/// [`statements`](super::BasicBlock::statements).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Location {
+    pub block: BlockId,
+    pub index: usize,
+}
+
+/// A write to a register.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Definition<'a> {
+    pub register: &'a str,
+    /// Instruction writing the register, `None` for a `.reg` parameter,
+    /// which is defined on entry.
+    pub location: Option<Location>,
+    /// Span of the destination operand or parameter name.
+    pub span: Span,
+    /// Set for guarded instructions, which do not replace earlier
+    /// definitions.
+    pub partial: bool,
+}
+
+/// A read of a register.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Use<'a> {
+    pub register: &'a str,
+    pub location: Location,
+    /// Span of the source operand, address register or guard predicate.
+    pub span: Span,
+}
+
+/// Index of a statement in its block with its definitions and uses.
+type StatementAccess = (usize, Vec<DefId>, Vec<UseId>);
+
+/// Reaching definitions of a function with chains between definitions and
+/// uses of its declared registers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReachingDefinitions<'a> {
+    definitions: Vec<Definition<'a>>,
+    uses: Vec<Use<'a>>,
+    reach_in: Vec<BTreeSet<DefId>>,
+    reach_out: Vec<BTreeSet<DefId>>,
+    use_defs: Vec<Vec<DefId>>,
+    def_uses: Vec<Vec<UseId>>,
+    /// Definitions and uses in program order per block, by statement index.
+    statements: Vec<Vec<StatementAccess>>,
+    use_spans: HashMap<Span, UseId>,
+    /// Register returned by a `.func`, which the caller reads.
+    returned: Option<&'a str>,
+}
+
+impl<'a> ReachingDefinitions<'a> {
+    /// Compute the definitions of `function` reaching each use, where `cfg`
+    /// was built from its body.
+    pub fn of(function: FunctionRef<'a>, cfg: &Cfg<'a>) -> Self {
+        let types = RegisterTypes::of(function);
+        let declared = |name: &str| types.get(name).is_some();
+
+        let returned = match function.return_param() {
+            Some(ParameterDirective::Register { name, .. }) => Some(name.val.as_str()),
+            _ => None,
+        };
+        let mut definitions = Vec::new();
+        for parameter in function.return_param().into_iter().chain(function.params()) {
+            if let ParameterDirective::Register { name, .. } = parameter {
+                definitions.push(Definition {
+                    register: name.val.as_str(),
+                    location: None,
+                    span: name.span,
+                    partial: false,
+                });
+            }
+        }
+        let parameters: BTreeSet<DefId> = (0..definitions.len())
+            .map(|index| DefId(index as u32))
+            .collect();
+
+        let mut uses = Vec::new();
+        let mut statements = Vec::with_capacity(cfg.len());
+        for id in cfg.block_ids() {
+            let mut block = Vec::new();
+            for (index, statement) in cfg.block(id).statements.iter().enumerate() {
+                let FunctionStatement::Instruction { instruction, .. } = statement.statement else {
+                    continue;
+                };
+                let location = Location { block: id, index };
+                let (defs, reads) = register_accesses(instruction);
+                let use_ids: Vec<UseId> = reads
+                    .into_iter()
+                    .filter(|(name, _)| declared(name))
+                    .map(|(register, span)| {
+                        uses.push(Use {
+                            register,
+                            location,
+                            span,
+                        });
+                        UseId(uses.len() as u32 - 1)
+                    })
+                    .collect();
+                let def_ids: Vec<DefId> = defs
+                    .into_iter()
+                    .filter(|(name, _)| declared(name))
+                    .map(|(register, span)| {
+                        definitions.push(Definition {
+                            register,
+                            location: Some(location),
+                            span,
+                            partial: instruction.predicate.is_some(),
+                        });
+                        DefId(definitions.len() as u32 - 1)
+                    })
+                    .collect();
+                if !def_ids.is_empty() || !use_ids.is_empty() {
+                    block.push((index, def_ids, use_ids));
+                }
+            }
+            statements.push(block);
+        }
+
+        let problem = Reaching {
+            definitions: &definitions,
+            statements: &statements,
+            parameters,
+        };
+        let solution = dataflow::solve(cfg, &problem);
+        let reach_in: Vec<BTreeSet<DefId>> = cfg
+            .block_ids()
+            .map(|id| solution.entry(id).clone())
+            .collect();
+        let reach_out = cfg
+            .block_ids()
+            .map(|id| solution.exit(id).clone())
+            .collect();
+
+        let mut use_defs = vec![Vec::new(); uses.len()];
+        let mut def_uses = vec![Vec::new(); definitions.len()];
+        for (block, entries) in statements.iter().enumerate() {
+            let mut reaching = reach_in[block].clone();
+            for (_, defs, reads) in entries {
+                for &read in reads {
+                    let register = uses[read.index()].register;
+                    for &def in &reaching {
+                        if definitions[def.index()].register == register {
+                            use_defs[read.index()].push(def);
+                            def_uses[def.index()].push(read);
+                        }
+                    }
+                }
+                apply(&definitions, &mut reaching, defs);
+            }
+        }
+
+        let use_spans = uses
+            .iter()
+            .enumerate()
+            .map(|(index, read)| (read.span, UseId(index as u32)))
+            .collect();
+        ReachingDefinitions {
+            definitions,
+            uses,
+            reach_in,
+            reach_out,
+            use_defs,
+            def_uses,
+            statements,
+            use_spans,
+            returned,
+        }
+    }
+
+    pub fn definition(&self, id: DefId) -> &Definition<'a> {
+        &self.definitions[id.index()]
+    }
+
+    /// Every definition, parameters first and then in program order.
+    pub fn definitions(&self) -> impl Iterator<Item = (DefId, &Definition<'a>)> {
+        self.definitions
+            .iter()
+            .enumerate()
+            .map(|(index, definition)| (DefId(index as u32), definition))
+    }
+
+    pub fn get_use(&self, id: UseId) -> &Use<'a> {
+        &self.uses[id.index()]
+    }
+
+    /// Every use in program order.
+    pub fn uses(&self) -> impl Iterator<Item = (UseId, &Use<'a>)> {
+        self.uses
+            .iter()
+            .enumerate()
+            .map(|(index, read)| (UseId(index as u32), read))
+    }
+
+    /// The use whose operand spans exactly `span`.
+    pub fn use_at(&self, span: Span) -> Option<UseId> {
+        self.use_spans.get(&span).copied()
+    }
+
+    /// Use-def chain: definitions that may provide the value read by `id`.
+    /// Empty when the register is read before any write.
+    pub fn definitions_of(&self, id: UseId) -> &[DefId] {
+        &self.use_defs[id.index()]
+    }
+
+    /// Def-use chain: uses that may read the value written by `id`.
+    pub fn uses_of(&self, id: DefId) -> &[UseId] {
+        &self.def_uses[id.index()]
+    }
+
+    /// Definitions whose value is never read. Writes to the return register
+    /// of a `.func` are left out, as the caller reads them.
+    pub fn unused(&self) -> impl Iterator<Item = DefId> + '_ {
+        self.definitions()
+            .filter(|(_, definition)| Some(definition.register) != self.returned)
+            .map(|(id, _)| id)
+            .filter(|id| self.uses_of(*id).is_empty())
+    }
+
+    /// Definitions reaching the start of `block`.
+    pub fn reaching_in(&self, block: BlockId) -> &BTreeSet<DefId> {
+        &self.reach_in[block.index()]
+    }
+
+    /// Definitions reaching the end of `block`.
+    pub fn reaching_out(&self, block: BlockId) -> &BTreeSet<DefId> {
+        &self.reach_out[block.index()]
+    }
+
+    /// Definitions reaching the statement at `location`, before it executes.
+    pub fn reaching_at(&self, location: Location) -> BTreeSet<DefId> {
+        let mut reaching = self.reach_in[location.block.index()].clone();
+        for (index, defs, _) in &self.statements[location.block.index()] {
+            if *index >= location.index {
+                break;
+            }
+            apply(&self.definitions, &mut reaching, defs);
+        }
+        reaching
+    }
+}
+
+/// Forward problem behind [`ReachingDefinitions`].
+struct Reaching<'r, 'a> {
+    definitions: &'r [Definition<'a>],
+    statements: &'r [Vec<StatementAccess>],
+    parameters: BTreeSet<DefId>,
+}
+
+/// Apply the definitions of one statement: full definitions replace the
+/// others of their register, partial ones add to them.
+fn apply(definitions: &[Definition], reaching: &mut BTreeSet<DefId>, defs: &[DefId]) {
+    for &def in defs {
+        let register = definitions[def.index()].register;
+        if !definitions[def.index()].partial {
+            reaching.retain(|other| definitions[other.index()].register != register);
+        }
+    }
+    reaching.extend(defs);
+}
+
+impl Analysis for Reaching<'_, '_> {
+    type Fact = BTreeSet<DefId>;
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn bottom(&self) -> Self::Fact {
+        BTreeSet::new()
+    }
+
+    fn boundary(&self) -> Self::Fact {
+        self.parameters.clone()
+    }
+
+    fn transfer(&self, block: BlockId, input: &Self::Fact) -> Self::Fact {
+        let mut reaching = input.clone();
+        for (_, defs, _) in &self.statements[block.index()] {
+            apply(self.definitions, &mut reaching, defs);
+        }
+        reaching
+    }
+}
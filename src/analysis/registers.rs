@@ -0,0 +1,394 @@
+//! Concrete registers behind `.reg` declarations.
+//!
+//! `.reg .b32 %r<4>;` declares `%r0` to `%r3`. [`RegisterTarget::names`] and
+//! [`RegisterDirective::registers`] expand such ranges, [`RegisterTypes`] maps
+//! every register of a function to its type, and [`compact_registers`] turns
+//! a set of registers back into range declarations:
+//!
+//! ```
+//! use ptx_parser::analysis::{FunctionRef, RegisterTypes, functions};
+//! use ptx_parser::r#type::DataType;
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".entry k() { .reg .b32 %r<100>; .reg .pred %p1; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let function: FunctionRef = functions(&module).next().unwrap();
+//!     let types = RegisterTypes::of(function);
+//!     assert!(matches!(types.get("%r57"), Some(DataType::B32 { .. })));
+//!     assert!(types.get("%r100").is_none());
+//!     assert_eq!(types.len(), 101);
+//! });
+//! ```
+
+use std::collections::HashMap;
+use std::mem::{Discriminant, discriminant};
+
+use super::FunctionRef;
+use crate::Span;
+use crate::operands::{OperandMut, OperandRef, OperandValue, OperandValueMut};
+use crate::r#type::{
+    AddressBase, AddressOffset, AddressOperand, DataType, FunctionStatement, GeneralOperand,
+    Instruction, Operand, ParameterDirective, RegisterDirective, RegisterTarget,
+    StatementDirective, VariableSymbol, VectorOperand,
+};
+
+impl RegisterTarget {
+    /// Registers declared by this target: `%r0` to `%r{N-1}` for `%r<N>`,
+    /// otherwise the name itself.
+    pub fn names(&self) -> impl Iterator<Item = String> + '_ {
+        let (single, count) = match self.range {
+            Some(count) => (None, count),
+            None => (Some(self.name.val.clone()), 0),
+        };
+        single
+            .into_iter()
+            .chain((0..count).map(|index| format!("{}{index}", self.name.val)))
+    }
+}
+
+impl RegisterDirective {
+    /// Every register declared by the directive, in declaration order.
+    pub fn registers(&self) -> impl Iterator<Item = String> + '_ {
+        self.registers.iter().flat_map(RegisterTarget::names)
+    }
+}
+
+/// Types of the registers declared in a function.
+///
+/// Covers `.reg` parameters and `.reg` directives anywhere in the function,
+/// including nested blocks. When a name is declared more than once the first
+/// declaration wins; use [`SymbolTable`](super::SymbolTable) for resolution
+/// that follows block scoping.
+#[derive(Debug, Clone, Default)]
+pub struct RegisterTypes {
+    /// Declarations in order: name or range base, range size and type.
+    declared: Vec<(String, Option<u32>, DataType)>,
+    names: HashMap<String, usize>,
+    ranges: HashMap<String, usize>,
+}
+
+impl RegisterTypes {
+    pub fn of(function: FunctionRef) -> Self {
+        let mut types = RegisterTypes::default();
+        for parameter in function.return_param().into_iter().chain(function.params()) {
+            if let ParameterDirective::Register { ty, name, .. } = parameter {
+                types.declare(&name.val, None, ty);
+            }
+        }
+        for directive in function.pre_body_declarations() {
+            types.directive(directive);
+        }
+        if let Some(body) = function.body() {
+            types.statements(&body.statements);
+        }
+        types
+    }
+
+    /// Type of the register `name`, e.g. `%r57` declared by `%r<100>`.
+    pub fn get(&self, name: &str) -> Option<&DataType> {
+        if let Some(&index) = self.names.get(name) {
+            return Some(&self.declared[index].2);
+        }
+        let (base, index) = split_register_index(name)?;
+        let (_, count, ty) = &self.declared[*self.ranges.get(base)?];
+        (index < count.unwrap_or(0)).then_some(ty)
+    }
+
+    /// Number of concrete registers, counting each register of a range.
+    pub fn len(&self) -> usize {
+        self.declared
+            .iter()
+            .map(|(_, count, _)| count.map_or(1, |count| count as usize))
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Concrete registers and their types, in declaration order.
+    pub fn iter(&self) -> impl Iterator<Item = (String, &DataType)> + '_ {
+        self.declared.iter().flat_map(|(name, count, ty)| {
+            let single = count.is_none().then(|| name.clone());
+            single
+                .into_iter()
+                .chain((0..count.unwrap_or(0)).map(move |index| format!("{name}{index}")))
+                .map(move |name| (name, ty))
+        })
+    }
+
+    fn declare(&mut self, name: &str, range: Option<u32>, ty: &DataType) {
+        let map = if range.is_some() {
+            &mut self.ranges
+        } else {
+            &mut self.names
+        };
+        if map.contains_key(name) {
+            return;
+        }
+        map.insert(name.to_string(), self.declared.len());
+        self.declared.push((name.to_string(), range, ty.clone()));
+    }
+
+    fn directive(&mut self, directive: &StatementDirective) {
+        if let StatementDirective::Reg { directive, .. } = directive {
+            for target in &directive.registers {
+                self.declare(&target.name.val, target.range, &directive.ty);
+            }
+        }
+    }
+
+    fn statements(&mut self, statements: &[FunctionStatement]) {
+        for statement in statements {
+            match statement {
+                FunctionStatement::Directive { directive, .. } => self.directive(directive),
+                FunctionStatement::Block { statements, .. } => self.statements(statements),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Registers of one type: plain names (`None`) and range bases with their
+/// indices.
+type TypeGroup<'a> = (&'a DataType, Vec<(String, Option<Vec<u32>>)>);
+
+/// Declarations for a set of registers, one directive per type in order of
+/// first appearance.
+///
+/// Registers `%r0`..`%rN` or `%r1`..`%rN` of one type become the range
+/// `%r<N+1>`, the latter following the `nvcc` convention of leaving `%r0`
+/// unused. The range then also declares `%r0`, so callers must not declare
+/// it elsewhere; a `%r0` of another type in the set keeps the base from
+/// forming a range. Any other register is declared on its own. The
+/// directives have empty spans.
+pub fn compact_registers<'a, N: AsRef<str>>(
+    registers: impl IntoIterator<Item = (N, &'a DataType)>,
+) -> Vec<RegisterDirective> {
+    let mut groups: Vec<TypeGroup> = Vec::new();
+    let mut group_of: HashMap<Discriminant<DataType>, usize> = HashMap::new();
+    let mut bases: HashMap<String, Option<Discriminant<DataType>>> = HashMap::new();
+
+    let registers: Vec<(String, &DataType)> = registers
+        .into_iter()
+        .map(|(name, ty)| (name.as_ref().to_string(), ty))
+        .collect();
+    // A base used with several types cannot become a range.
+    for (name, ty) in &registers {
+        if let Some((base, _)) = split_register_index(name) {
+            let kind = discriminant(*ty);
+            bases
+                .entry(base.to_string())
+                .and_modify(|seen| {
+                    if *seen != Some(kind) {
+                        *seen = None;
+                    }
+                })
+                .or_insert(Some(kind));
+        }
+    }
+
+    for (name, ty) in registers {
+        let group = *group_of.entry(discriminant(ty)).or_insert_with(|| {
+            groups.push((ty, Vec::new()));
+            groups.len() - 1
+        });
+        let entries = &mut groups[group].1;
+        let (key, index) = match split_register_index(&name) {
+            Some((base, index)) if bases[base].is_some() => (base.to_string(), Some(index)),
+            _ => (name, None),
+        };
+        let position = entries.iter().position(|(existing, indices)| {
+            *existing == key && indices.is_some() == index.is_some()
+        });
+        match (position, index) {
+            (Some(position), Some(index)) => {
+                entries[position].1.get_or_insert_default().push(index)
+            }
+            (Some(_), None) => {}
+            (None, index) => entries.push((key, index.map(|index| vec![index]))),
+        }
+    }
+
+    groups
+        .into_iter()
+        .map(|(ty, entries)| {
+            let mut targets = Vec::new();
+            for (base, indices) in entries {
+                let Some(mut indices) = indices else {
+                    targets.push(target(base, None));
+                    continue;
+                };
+                indices.sort_unstable();
+                indices.dedup();
+                let max = *indices.last().unwrap();
+                let first = indices[0];
+                if first <= 1 && indices.len() as u32 == max - first + 1 {
+                    targets.push(target(base, Some(max + 1)));
+                } else {
+                    for index in indices {
+                        targets.push(target(format!("{base}{index}"), None));
+                    }
+                }
+            }
+            RegisterDirective {
+                ty: ty.clone(),
+                registers: targets,
+                span: Span::default(),
+            }
+        })
+        .collect()
+}
+
+fn target(name: String, range: Option<u32>) -> RegisterTarget {
+    RegisterTarget {
+        name: VariableSymbol {
+            val: name,
+            span: Span::default(),
+        },
+        range,
+        span: Span::default(),
+    }
+}
+
+/// Register names with the spans of the operands naming them.
+pub(crate) type Accesses<'a> = Vec<(&'a str, Span)>;
+
+/// Names written and read by `instruction` with the spans of their
+/// operands, in operand order. The guard predicate counts as a use, and
+/// read-write operands such as the accumulator of `wgmma.mma_async` are
+/// listed on both sides.
+///
+/// Besides register operands this lists plain symbols, since `.reg`
+/// parameters without a `%` are spelled like variables; callers filter the
+/// names against the declared registers.
+pub(crate) fn register_accesses(instruction: &Instruction) -> (Accesses<'_>, Accesses<'_>) {
+    let mut defs = Vec::new();
+    let mut uses = Vec::new();
+    if let Some(predicate) = &instruction.predicate {
+        push_name(&predicate.operand, &mut uses);
+    }
+    for operand in instruction.inst.operands() {
+        if operand.role.is_def() {
+            operand_names(&operand, &mut defs);
+        }
+        if operand.role.is_use() {
+            operand_names(&operand, &mut uses);
+        }
+    }
+    (defs, uses)
+}
+
+fn operand_names<'a>(operand: &OperandRef<'a>, names: &mut Vec<(&'a str, Span)>) {
+    match operand.value {
+        OperandValue::General(GeneralOperand::Single { operand, .. }) => push_name(operand, names),
+        OperandValue::General(GeneralOperand::Vec { operand, .. })
+        | OperandValue::Vector(operand) => {
+            let elements: &[Operand] = match operand {
+                VectorOperand::Vector1 { operand, .. } => std::slice::from_ref(operand),
+                VectorOperand::Vector2 { operands, .. } => operands,
+                VectorOperand::Vector3 { operands, .. } => operands,
+                VectorOperand::Vector4 { operands, .. } => operands,
+                VectorOperand::Vector8 { operands, .. } => operands,
+            };
+            for element in elements {
+                push_name(element, names);
+            }
+        }
+        OperandValue::Address(address) => {
+            if let AddressOperand::Offset {
+                base: AddressBase::Variable { symbol, .. },
+                ..
+            } = address
+            {
+                names.push((&symbol.val, symbol.span));
+            }
+            names.extend(
+                operand
+                    .registers()
+                    .iter()
+                    .map(|register| (register.name.as_str(), register.span)),
+            );
+        }
+    }
+}
+
+fn push_name<'a>(operand: &'a Operand, names: &mut Vec<(&'a str, Span)>) {
+    match operand {
+        Operand::Register { operand, .. } => names.push((&operand.name, operand.span)),
+        Operand::Symbol { name, span } => names.push((name, *span)),
+        _ => {}
+    }
+}
+
+/// Mutable counterpart of [`register_accesses`], listing the same names in
+/// the same order, except that read-write operands are only listed as defs.
+pub(crate) fn register_accesses_mut(
+    instruction: &mut Instruction,
+) -> (Vec<&mut String>, Vec<&mut String>) {
+    let mut defs = Vec::new();
+    let mut uses = Vec::new();
+    if let Some(predicate) = &mut instruction.predicate {
+        push_name_mut(&mut predicate.operand, &mut uses);
+    }
+    for operand in instruction.inst.operands_mut() {
+        if operand.role.is_def() {
+            operand_names_mut(operand, &mut defs);
+        } else if operand.role.is_use() {
+            operand_names_mut(operand, &mut uses);
+        }
+    }
+    (defs, uses)
+}
+
+fn operand_names_mut<'a>(operand: OperandMut<'a>, names: &mut Vec<&'a mut String>) {
+    match operand.value {
+        OperandValueMut::General(GeneralOperand::Single { operand, .. }) => {
+            push_name_mut(operand, names)
+        }
+        OperandValueMut::General(GeneralOperand::Vec { operand, .. })
+        | OperandValueMut::Vector(operand) => {
+            let elements: &mut [Operand] = match operand {
+                VectorOperand::Vector1 { operand, .. } => std::slice::from_mut(operand),
+                VectorOperand::Vector2 { operands, .. } => operands,
+                VectorOperand::Vector3 { operands, .. } => operands,
+                VectorOperand::Vector4 { operands, .. } => operands,
+                VectorOperand::Vector8 { operands, .. } => operands,
+            };
+            for element in elements {
+                push_name_mut(element, names);
+            }
+        }
+        OperandValueMut::Address(address) => match address {
+            AddressOperand::Offset { base, offset, .. } => {
+                match base {
+                    AddressBase::Variable { symbol, .. } => names.push(&mut symbol.val),
+                    AddressBase::Register { operand, .. } => names.push(&mut operand.name),
+                }
+                if let Some(AddressOffset::Register { operand, .. }) = offset {
+                    names.push(&mut operand.name);
+                }
+            }
+            AddressOperand::Array { .. } | AddressOperand::ImmediateAddress { .. } => {}
+        },
+    }
+}
+
+fn push_name_mut<'a>(operand: &'a mut Operand, names: &mut Vec<&'a mut String>) {
+    match operand {
+        Operand::Register { operand, .. } => names.push(&mut operand.name),
+        Operand::Symbol { name, .. } => names.push(name),
+        _ => {}
+    }
+}
+
+/// Split `%r12` into `("%r", 12)`. Indices with leading zeros are not
+/// produced by ranges and do not split.
+pub(crate) fn split_register_index(name: &str) -> Option<(&str, u32)> {
+    let base = name.trim_end_matches(|ch: char| ch.is_ascii_digit());
+    let digits = &name[base.len()..];
+    if base.is_empty() || digits.is_empty() || (digits.len() > 1 && digits.starts_with('0')) {
+        return None;
+    }
+    Some((base, digits.parse().ok()?))
+}
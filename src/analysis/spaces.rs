@@ -0,0 +1,537 @@
+//! State spaces of memory accesses and the addresses they use.
+//!
+//! A memory instruction names the state space it accesses, `ld.shared` or
+//! `st.global`, or uses generic addressing when it names none. The parser
+//! accepts any address with any state space; [`check_state_spaces`] compares
+//! the two:
+//!
+//! - a variable must be accessed in the state space it is declared in, and
+//!   `.shared::cluster` also reaches `.shared` variables;
+//! - `.const` variables and kernel parameters are read-only;
+//! - `.param::entry` is only valid in kernels;
+//! - generic addressing needs a generic address, so a variable or a register
+//!   holding the address of a variable must be converted with `cvta` first.
+//!
+//! Registers are followed through the instructions that compute addresses,
+//! see [`AddressSpaces`]. Only `ld`, `ldu`, `st`, `atom`, `red` and
+//! `prefetch` are checked, and addresses that cannot be traced are accepted.
+//!
+//! ```
+//! use ptx_parser::analysis::check_state_spaces;
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".global .u32 g; .entry k() { .reg .b32 %r1; ld.shared.u32 %r1, [g]; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let issues = check_state_spaces(&module);
+//!
+//!     assert_eq!(
+//!         issues[0].to_string(),
+//!         "`g` is declared in `.global`, but `ld.shared.u32` accesses `.shared`"
+//!     );
+//!     assert_eq!(&source[issues[0].span().start..issues[0].span().end], "[g]");
+//! });
+//! ```
+
+use std::collections::{HashMap, HashSet};
+
+use thiserror::Error;
+
+use super::symbols::{StateSpace, SymbolKind, SymbolTable};
+use super::{FunctionRef, functions};
+use crate::Span;
+use crate::diagnostic::Diagnostic;
+use crate::operands::{OperandRole, OperandValue};
+use crate::r#type::{
+    AddressBase, AddressOperand, FunctionStatement, GeneralOperand, Instruction, Module, Operand,
+    ParameterDirective,
+};
+
+/// What a register holding an address points into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Address {
+    /// An address within a state space, as `mov` of a variable or
+    /// `cvta.to` produce.
+    Space(StateSpace),
+    /// A generic address, as `cvta` produces.
+    Generic,
+}
+
+/// A memory access whose address does not belong to the state space it
+/// accesses.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SpaceIssue {
+    #[error(
+        "`{name}` is declared in `{}`, but `{instruction}` accesses `{access}`",
+        space_name(*found)
+    )]
+    Mismatch {
+        /// Mnemonic of the instruction, e.g. `ld.shared.u32`.
+        instruction: String,
+        /// Variable the address is based on.
+        name: String,
+        found: StateSpace,
+        /// State space modifier of the instruction, e.g. `.shared`.
+        access: String,
+        span: Span,
+    },
+    #[error("{}", register_message(register, *found, instruction, access))]
+    RegisterMismatch {
+        instruction: String,
+        register: String,
+        found: Address,
+        access: String,
+        span: Span,
+    },
+    #[error(
+        "`{instruction}` uses generic addressing, but `{name}` is an address in `{}`",
+        space_name(*space)
+    )]
+    Generic {
+        instruction: String,
+        name: String,
+        space: StateSpace,
+        span: Span,
+    },
+    #[error("`{instruction}` writes to `{name}`, which is read-only")]
+    ReadOnly {
+        instruction: String,
+        name: String,
+        space: StateSpace,
+        /// Whether `name` is a kernel parameter.
+        kernel_param: bool,
+        span: Span,
+    },
+    #[error("`{instruction}` accesses `.param::entry` in `.func` `{function}`")]
+    EntryParam {
+        instruction: String,
+        function: String,
+        span: Span,
+    },
+}
+
+fn register_message(register: &str, found: Address, instruction: &str, access: &str) -> String {
+    match found {
+        Address::Space(space) => format!(
+            "`{register}` holds an address in `{}`, but `{instruction}` accesses `{access}`",
+            space_name(space)
+        ),
+        Address::Generic => {
+            format!("`{register}` holds a generic address, but `{instruction}` accesses `{access}`")
+        }
+    }
+}
+
+fn space_name(space: StateSpace) -> &'static str {
+    match space {
+        StateSpace::Reg => ".reg",
+        StateSpace::Param => ".param",
+        StateSpace::Local => ".local",
+        StateSpace::Shared => ".shared",
+        StateSpace::Global => ".global",
+        StateSpace::Const => ".const",
+        StateSpace::Tex => ".tex",
+    }
+}
+
+impl SpaceIssue {
+    /// Span of the address operand.
+    pub fn span(&self) -> Span {
+        match self {
+            SpaceIssue::Mismatch { span, .. }
+            | SpaceIssue::RegisterMismatch { span, .. }
+            | SpaceIssue::Generic { span, .. }
+            | SpaceIssue::ReadOnly { span, .. }
+            | SpaceIssue::EntryParam { span, .. } => *span,
+        }
+    }
+
+    /// Convert this issue into a [`Diagnostic`].
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::error(self.to_string(), self.span());
+        match self {
+            SpaceIssue::RegisterMismatch {
+                found: Address::Generic,
+                access,
+                ..
+            } => diagnostic
+                .with_label(format!("expected an address in `{access}`"))
+                .with_note(format!("convert it with `cvta.to{access}` first")),
+            SpaceIssue::Mismatch { access, .. } | SpaceIssue::RegisterMismatch { access, .. } => {
+                diagnostic.with_label(format!("expected an address in `{access}`"))
+            }
+            SpaceIssue::Generic { space, .. } => diagnostic
+                .with_label("expected a generic address")
+                .with_note(format!(
+                    "convert it with `cvta{}` first",
+                    space_name(*space)
+                )),
+            SpaceIssue::ReadOnly {
+                kernel_param: true, ..
+            } => diagnostic
+                .with_label("kernel parameter")
+                .with_note("kernel parameters can only be read"),
+            SpaceIssue::ReadOnly { space, .. } => {
+                diagnostic.with_label(format!("`{}` is read-only", space_name(*space)))
+            }
+            SpaceIssue::EntryParam { .. } => {
+                diagnostic.with_label("only valid in `.entry` functions")
+            }
+        }
+    }
+}
+
+/// Addresses held by the registers of a function.
+///
+/// A register holds an address when every instruction writing it computes
+/// one from the same state space: `mov` of a variable, `cvta`, a copy, or
+/// `add`, `sub`, `mad` and `cvt` of an address and an offset. Registers that
+/// are also written otherwise, or from addresses in different state spaces,
+/// are left out; so are function parameters.
+#[derive(Debug, Clone, Default)]
+pub struct AddressSpaces {
+    registers: HashMap<String, Slot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Known(Address),
+    Unknown,
+}
+
+impl AddressSpaces {
+    /// Trace the registers of `function`; `table` resolves its variables.
+    pub fn of(function: FunctionRef, table: &SymbolTable) -> Self {
+        let mut spaces = AddressSpaces::default();
+        for parameter in function.return_param().into_iter().chain(function.params()) {
+            if let ParameterDirective::Register { name, .. } = parameter {
+                spaces.registers.insert(name.val.clone(), Slot::Unknown);
+            }
+        }
+        let Some(body) = function.body() else {
+            return spaces;
+        };
+        let mut instructions = Vec::new();
+        collect_instructions(&body.statements, &mut instructions);
+
+        // Registers start unset and only move to `Known` and then to
+        // `Unknown`, so this terminates.
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for instruction in &instructions {
+                changed |= spaces.define(instruction, table);
+            }
+        }
+        spaces
+    }
+
+    /// Address held by the register `name`, if it is known.
+    pub fn get(&self, name: &str) -> Option<Address> {
+        match self.registers.get(name)? {
+            Slot::Known(address) => Some(*address),
+            Slot::Unknown => None,
+        }
+    }
+
+    /// Record what `instruction` writes; returns whether anything changed.
+    fn define(&mut self, instruction: &Instruction, table: &SymbolTable) -> bool {
+        let mut destinations = Vec::new();
+        let mut sources = Vec::new();
+        for operand in instruction.inst.operands() {
+            match (operand.role, operand.value) {
+                (
+                    OperandRole::Destination,
+                    OperandValue::General(GeneralOperand::Single { operand, .. }),
+                ) => destinations.push(operand_name(operand)),
+                (OperandRole::Destination, _) => destinations.push(None),
+                (
+                    OperandRole::Source | OperandRole::Immediate,
+                    OperandValue::General(GeneralOperand::Single { operand: value, .. }),
+                ) => sources.push((operand.name, value)),
+                _ => {}
+            }
+        }
+        let Some(value) = self.value(instruction, &sources, table) else {
+            return false;
+        };
+        let mut changed = false;
+        for name in destinations.into_iter().flatten() {
+            let slot = match (self.registers.get(name), value) {
+                (None, value) => value,
+                (Some(&previous), value) if previous == value => continue,
+                (Some(_), _) => Slot::Unknown,
+            };
+            if self.registers.get(name) != Some(&slot) {
+                self.registers.insert(name.to_string(), slot);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// What `instruction` writes to its destination, or `None` when that
+    /// depends on registers not traced yet.
+    fn value(
+        &self,
+        instruction: &Instruction,
+        sources: &[(&str, &Operand)],
+        table: &SymbolTable,
+    ) -> Option<Slot> {
+        let modifiers = instruction.inst.modifiers();
+        let source = |name: &str| {
+            sources
+                .iter()
+                .find(|(operand, _)| *operand == name)
+                .map(|(_, value)| *value)
+        };
+        let known = match instruction.inst.opcode() {
+            "mov" => match source("a") {
+                Some(Operand::Symbol { span, .. } | Operand::SymbolOffset { span, .. }) => {
+                    match table.resolve(*span).map(|declaration| &declaration.kind) {
+                        Some(SymbolKind::Variable { space, .. }) => {
+                            return Some(Slot::Known(Address::Space(*space)));
+                        }
+                        Some(SymbolKind::Register { .. }) => {
+                            return self.copied(source("a")?);
+                        }
+                        _ => None,
+                    }
+                }
+                Some(register @ Operand::Register { .. }) => return self.copied(register),
+                _ => None,
+            },
+            "cvta" => modifiers.iter().find_map(|modifier| {
+                let space = access_space(modifier)?;
+                Some(if modifiers.iter().any(|modifier| modifier == ".to") {
+                    Address::Space(space)
+                } else {
+                    Address::Generic
+                })
+            }),
+            "cvt" => return self.copied(source("a")?),
+            "add" | "sub" => return self.offset(&[source("a"), source("b")]),
+            "mad" => return self.offset(&[source("c")]),
+            _ => None,
+        };
+        Some(known.map_or(Slot::Unknown, Slot::Known))
+    }
+
+    fn copied(&self, operand: &Operand) -> Option<Slot> {
+        match operand_name(operand) {
+            Some(name) => self.registers.get(name).copied(),
+            None => Some(Slot::Unknown),
+        }
+    }
+
+    /// An address plus offsets: known when exactly one of `bases` holds an
+    /// address and the others are immediates or unknown registers.
+    fn offset(&self, bases: &[Option<&Operand>]) -> Option<Slot> {
+        let mut address = None;
+        for operand in bases.iter().flatten() {
+            match self.copied(operand)? {
+                Slot::Known(found) if address.is_none() => address = Some(found),
+                Slot::Known(_) => return Some(Slot::Unknown),
+                Slot::Unknown => {}
+            }
+        }
+        Some(address.map_or(Slot::Unknown, Slot::Known))
+    }
+}
+
+fn operand_name(operand: &Operand) -> Option<&str> {
+    match operand {
+        Operand::Register { operand, .. } => Some(&operand.name),
+        Operand::Symbol { name, .. } => Some(name),
+        _ => None,
+    }
+}
+
+fn collect_instructions<'a>(
+    statements: &'a [FunctionStatement],
+    instructions: &mut Vec<&'a Instruction>,
+) {
+    for statement in statements {
+        match statement {
+            FunctionStatement::Instruction { instruction, .. } => instructions.push(instruction),
+            FunctionStatement::Block { statements, .. } => {
+                collect_instructions(statements, instructions)
+            }
+            _ => {}
+        }
+    }
+}
+
+/// State space named by a modifier such as `.shared::cta`.
+fn access_space(modifier: &str) -> Option<StateSpace> {
+    Some(match modifier {
+        ".global" => StateSpace::Global,
+        ".shared" | ".shared::cta" | ".shared::cluster" => StateSpace::Shared,
+        ".local" => StateSpace::Local,
+        ".const" => StateSpace::Const,
+        ".param" | ".param::entry" | ".param::func" => StateSpace::Param,
+        _ => return None,
+    })
+}
+
+/// Check the memory accesses of every function of `module`.
+pub fn check_state_spaces(module: &Module) -> Vec<SpaceIssue> {
+    let table = SymbolTable::build(module);
+    let kernel_params: HashSet<Span> = functions(module)
+        .filter(|function| function.is_entry())
+        .flat_map(|function| function.params())
+        .filter_map(|parameter| match parameter {
+            ParameterDirective::Parameter { name, .. } => Some(name.span),
+            ParameterDirective::Register { .. } => None,
+        })
+        .collect();
+
+    let mut issues = Vec::new();
+    for function in functions(module) {
+        let Some(body) = function.body() else {
+            continue;
+        };
+        let checker = Checker {
+            function,
+            table: &table,
+            kernel_params: &kernel_params,
+            addresses: AddressSpaces::of(function, &table),
+        };
+        let mut instructions = Vec::new();
+        collect_instructions(&body.statements, &mut instructions);
+        issues.extend(
+            instructions
+                .into_iter()
+                .filter_map(|instruction| checker.instruction(instruction)),
+        );
+    }
+    issues
+}
+
+struct Checker<'a> {
+    function: FunctionRef<'a>,
+    table: &'a SymbolTable,
+    kernel_params: &'a HashSet<Span>,
+    addresses: AddressSpaces,
+}
+
+impl Checker<'_> {
+    fn instruction(&self, instruction: &Instruction) -> Option<SpaceIssue> {
+        let opcode = instruction.inst.opcode();
+        if !matches!(opcode, "ld" | "ldu" | "st" | "atom" | "red" | "prefetch") {
+            return None;
+        }
+        let writes = matches!(opcode, "st" | "atom" | "red");
+        let address = instruction
+            .inst
+            .operands()
+            .into_iter()
+            .find_map(|operand| match operand.value {
+                OperandValue::Address(address) => Some(address),
+                _ => None,
+            })?;
+        let modifiers = instruction.inst.modifiers();
+        let access = modifiers
+            .iter()
+            .find(|modifier| access_space(modifier).is_some());
+        let mnemonic = || instruction.inst.mnemonic();
+        let span = address.span();
+
+        if access.is_some_and(|access| access == ".param::entry") && !self.function.is_entry() {
+            return Some(SpaceIssue::EntryParam {
+                instruction: mnemonic(),
+                function: self.function.name().to_string(),
+                span,
+            });
+        }
+
+        let base = self.base(address)?;
+        if let Address::Space(space) = base.found
+            && writes
+            && (space == StateSpace::Const || base.kernel_param)
+        {
+            return Some(SpaceIssue::ReadOnly {
+                instruction: mnemonic(),
+                name: base.name.to_string(),
+                space,
+                kernel_param: base.kernel_param,
+                span,
+            });
+        }
+        let Some(access) = access else {
+            return match base.found {
+                Address::Generic => None,
+                Address::Space(space) => Some(SpaceIssue::Generic {
+                    instruction: mnemonic(),
+                    name: base.name.to_string(),
+                    space,
+                    span,
+                }),
+            };
+        };
+        match base.found {
+            found if found == Address::Space(access_space(access)?) => None,
+            Address::Space(found) if !base.register => Some(SpaceIssue::Mismatch {
+                instruction: mnemonic(),
+                name: base.name.to_string(),
+                found,
+                access: access.clone(),
+                span,
+            }),
+            found => Some(SpaceIssue::RegisterMismatch {
+                instruction: mnemonic(),
+                register: base.name.to_string(),
+                found,
+                access: access.clone(),
+                span,
+            }),
+        }
+    }
+
+    /// What the address is based on; `None` when that is not known.
+    fn base<'a>(&self, address: &'a AddressOperand) -> Option<Base<'a>> {
+        let register = |name: &'a str| {
+            Some(Base {
+                name,
+                found: self.addresses.get(name)?,
+                register: true,
+                kernel_param: false,
+            })
+        };
+        let symbol = match address {
+            AddressOperand::Array { base, .. }
+            | AddressOperand::Offset {
+                base: AddressBase::Variable { symbol: base, .. },
+                ..
+            } => base,
+            AddressOperand::Offset {
+                base: AddressBase::Register { operand, .. },
+                ..
+            } => return register(&operand.name),
+            AddressOperand::ImmediateAddress { .. } => return None,
+        };
+        let declaration = self.table.resolve(symbol.span)?;
+        match declaration.kind {
+            // `.tex` variables are only used by texture instructions.
+            SymbolKind::Variable {
+                space: StateSpace::Tex,
+                ..
+            } => None,
+            SymbolKind::Variable { space, .. } => Some(Base {
+                name: &symbol.val,
+                found: Address::Space(space),
+                register: false,
+                kernel_param: self.kernel_params.contains(&declaration.span),
+            }),
+            SymbolKind::Register { .. } => register(&symbol.val),
+            _ => None,
+        }
+    }
+}
+
+/// The variable or register an address operand is based on.
+struct Base<'a> {
+    name: &'a str,
+    found: Address,
+    register: bool,
+    kernel_param: bool,
+}
@@ -0,0 +1,772 @@
+//! Static single assignment form of function bodies.
+//!
+//! [`Ssa::build`] renames every write to a register into a fresh version, so
+//! that each version is written exactly once, and records [`Phi`] nodes at
+//! the join points where versions meet. The phis are kept beside the renamed
+//! body rather than in it, since PTX has no phi instruction.
+//! [`Ssa::eliminate_phis`] turns them back into `mov` copies on the incoming
+//! edges, giving a body that is valid PTX again:
+//!
+//! ```
+//! use ptx_parser::analysis::{Ssa, functions};
+//! use ptx_parser::{PtxUnlexer, PtxUnparser};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".entry k() { .reg .b32 %r1; .reg .pred %p1; mov.u32 %r1, 0; LOOP: add.u32 %r1, %r1, 1; setp.lt.u32 %p1, %r1, 10; @%p1 bra LOOP; ret; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let function = functions(&module).next().unwrap();
+//!     let ssa = Ssa::build(function).unwrap();
+//!
+//!     let phi = &ssa.phis()[0];
+//!     assert_eq!((phi.register.as_str(), phi.result.as_str()), ("%r1", "%r1_2"));
+//!     assert_eq!(phi.incoming[0].1, "%r1_1");
+//!     assert_eq!(phi.incoming[1].1, "%r1_3");
+//!
+//!     let body = ssa.eliminate_phis().unwrap();
+//!     let text = PtxUnlexer::to_string(&body.to_tokens_spaced()).unwrap();
+//!     assert!(text.contains("mov.b32 %r1_2, %r1_1;"));
+//! });
+//! ```
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use thiserror::Error;
+
+use super::FunctionRef;
+use super::cfg::{BlockId, Cfg, EdgeKind};
+use super::dominators::DominatorTree;
+use super::liveness::Liveness;
+use super::registers::{
+    RegisterTypes, compact_registers, register_accesses, register_accesses_mut,
+    split_register_index,
+};
+use crate::immediate::type_name;
+use crate::operands::{OperandRole, OperandValueMut};
+use crate::r#type::{
+    DataType, FunctionBody, FunctionStatement, GeneralOperand, Instruction, Operand,
+    ParameterDirective, RegisterDirective, StatementDirective,
+};
+use crate::{PtxParser, PtxTokenStream, Span, tokenize};
+
+/// A join of the versions of one register at the start of a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Phi {
+    pub block: BlockId,
+    /// Register of the original body.
+    pub register: String,
+    /// Version written by the phi.
+    pub result: String,
+    /// Version arriving from each predecessor, in the order of
+    /// [`Cfg::predecessors`]. `None` stands for the function entry when the
+    /// entry block is itself a join point. A predecessor without a version
+    /// passes on the original register.
+    pub incoming: Vec<(Option<BlockId>, String)>,
+}
+
+/// A guarded write such as `@%p1 mov.u32 %r1_2, 0;`, which leaves the
+/// previous version in place when the guard is false.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GuardedDefinition {
+    /// Path of the instruction, as in [`CfgStatement::path`](super::CfgStatement::path).
+    pub path: Vec<usize>,
+    pub result: String,
+    /// Version the result holds when the instruction does not execute.
+    pub previous: String,
+}
+
+/// Error produced when leaving SSA form.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SsaError {
+    /// Copies cannot be placed on the edges of `brx.idx`, whose targets are
+    /// fixed by a `.branchtargets` list.
+    #[error("phi copies needed on the indirect branch from b{} to b{}", from.index(), to.index())]
+    IndirectEdge { from: BlockId, to: BlockId },
+}
+
+/// A function body in SSA form.
+///
+/// Registers that cannot be versioned keep their names: `.reg` parameters,
+/// registers declared more than once, 8-bit registers (which `mov` cannot
+/// copy), `.texref`/`.samplerref`/`.surfref` registers, registers that an
+/// instruction reads and writes through one operand (the accumulator of
+/// `wgmma.mma_async`), and every register in blocks unreachable from the
+/// entry. Reads before any write use the original register.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ssa {
+    /// The body with registers renamed; statements keep their paths.
+    body: FunctionBody,
+    phis: Vec<Phi>,
+    guarded: Vec<GuardedDefinition>,
+    /// Versions in order of creation with their original registers.
+    versions: Vec<(String, String)>,
+    types: HashMap<String, DataType>,
+    names: Names,
+}
+
+impl Ssa {
+    /// Convert the body of `function`, or `None` for a prototype.
+    pub fn build(function: FunctionRef) -> Option<Self> {
+        let body = function.body()?;
+        let cfg = Cfg::build(body);
+        let dominators = DominatorTree::dominators(&cfg);
+        let liveness = Liveness::of(function, &cfg);
+        let types = RegisterTypes::of(function);
+        let pinned = pinned_registers(function, &types);
+
+        // Registers written in reachable code, with the blocks writing them.
+        let mut registers: Vec<&str> = Vec::new();
+        let mut index_of: HashMap<&str, usize> = HashMap::new();
+        let mut def_blocks: Vec<Vec<BlockId>> = Vec::new();
+        for id in cfg.block_ids().filter(|&id| dominators.contains(id)) {
+            for instruction in cfg.block(id).instructions() {
+                for (name, _) in register_accesses(instruction).0 {
+                    if types.get(name).is_none() || pinned.contains(name) {
+                        continue;
+                    }
+                    let index = *index_of.entry(name).or_insert_with(|| {
+                        registers.push(name);
+                        def_blocks.push(Vec::new());
+                        registers.len() - 1
+                    });
+                    if def_blocks[index].last() != Some(&id) {
+                        def_blocks[index].push(id);
+                    }
+                }
+            }
+        }
+
+        // Pruned phi placement on iterated dominance frontiers.
+        let mut placed: Vec<(BlockId, usize)> = Vec::new();
+        for (index, blocks) in def_blocks.iter().enumerate() {
+            let mut has_phi = vec![false; cfg.len()];
+            let mut queued = vec![false; cfg.len()];
+            for block in blocks {
+                queued[block.index()] = true;
+            }
+            let mut worklist = blocks.clone();
+            while let Some(block) = worklist.pop() {
+                for &join in dominators.frontier(block) {
+                    if has_phi[join.index()] || !liveness.live_in(join).contains(&registers[index])
+                    {
+                        continue;
+                    }
+                    has_phi[join.index()] = true;
+                    placed.push((join, index));
+                    if !std::mem::replace(&mut queued[join.index()], true) {
+                        worklist.push(join);
+                    }
+                }
+            }
+        }
+        placed.sort_unstable();
+        let mut phis: Vec<Phi> = placed
+            .iter()
+            .map(|&(block, index)| {
+                let register = registers[index].to_string();
+                let entry = (block == cfg.entry()).then_some(None);
+                let predecessors = cfg.predecessors(block).iter().map(|&p| Some(p));
+                Phi {
+                    block,
+                    incoming: entry
+                        .into_iter()
+                        .chain(predecessors)
+                        .map(|source| (source, register.clone()))
+                        .collect(),
+                    result: String::new(),
+                    register,
+                }
+            })
+            .collect();
+        let mut phis_at: Vec<Vec<usize>> = vec![Vec::new(); cfg.len()];
+        for (phi, &(block, _)) in placed.iter().enumerate() {
+            phis_at[block.index()].push(phi);
+        }
+
+        // Rename along a preorder walk of the dominator tree, with a stack of
+        // versions per register.
+        let mut names = Names::new(&types);
+        let mut versions = Vec::new();
+        let mut stacks: Vec<Vec<String>> = vec![Vec::new(); registers.len()];
+        let mut guarded = Vec::new();
+        let mut renames: Vec<(Vec<usize>, Renaming)> = Vec::new();
+        let current = |stacks: &[Vec<String>], index: usize| {
+            stacks[index]
+                .last()
+                .cloned()
+                .unwrap_or_else(|| registers[index].to_string())
+        };
+        let mut steps: Vec<Step> = dominators
+            .roots()
+            .iter()
+            .map(|&root| Step::Enter(root))
+            .collect();
+        while let Some(step) = steps.pop() {
+            let block = match step {
+                Step::Enter(block) => block,
+                Step::Leave(pushed) => {
+                    for index in pushed {
+                        stacks[index].pop();
+                    }
+                    continue;
+                }
+            };
+            let mut pushed = Vec::new();
+            let mut define = |index: usize, stacks: &mut Vec<Vec<String>>| {
+                let version = names.fresh(registers[index]);
+                versions.push((version.clone(), registers[index].to_string()));
+                stacks[index].push(version.clone());
+                pushed.push(index);
+                version
+            };
+
+            for &phi in &phis_at[block.index()] {
+                let index = index_of[phis[phi].register.as_str()];
+                phis[phi].result = define(index, &mut stacks);
+            }
+            for statement in &cfg.block(block).statements {
+                let FunctionStatement::Instruction { instruction, .. } = statement.statement else {
+                    continue;
+                };
+                let (defs, uses) = register_accesses(instruction);
+                let uses: HashMap<String, String> = uses
+                    .iter()
+                    .filter_map(|(name, _)| {
+                        let index = *index_of.get(name)?;
+                        Some((name.to_string(), current(&stacks, index)))
+                    })
+                    .collect();
+                let defs: Vec<Option<String>> = defs
+                    .iter()
+                    .map(|(name, _)| {
+                        let index = *index_of.get(name)?;
+                        let previous = current(&stacks, index);
+                        let result = define(index, &mut stacks);
+                        if instruction.predicate.is_some() {
+                            guarded.push(GuardedDefinition {
+                                path: statement.path.clone(),
+                                result: result.clone(),
+                                previous,
+                            });
+                        }
+                        Some(result)
+                    })
+                    .collect();
+                if defs.iter().any(Option::is_some) || !uses.is_empty() {
+                    renames.push((statement.path.clone(), Renaming { defs, uses }));
+                }
+            }
+            for successor in cfg.successors(block) {
+                for &phi in &phis_at[successor.index()] {
+                    let index = index_of[phis[phi].register.as_str()];
+                    let version = current(&stacks, index);
+                    if let Some(slot) = phis[phi]
+                        .incoming
+                        .iter_mut()
+                        .find(|(source, _)| *source == Some(block))
+                    {
+                        slot.1 = version;
+                    }
+                }
+            }
+
+            steps.push(Step::Leave(pushed));
+            steps.extend(
+                dominators
+                    .children(block)
+                    .iter()
+                    .rev()
+                    .map(|&c| Step::Enter(c)),
+            );
+        }
+
+        let mut renamed = body.clone();
+        for (path, renaming) in renames {
+            let FunctionStatement::Instruction { instruction, .. } =
+                statement_mut(&mut renamed.statements, &path)
+            else {
+                unreachable!("renamed statements are instructions");
+            };
+            let (defs, uses) = register_accesses_mut(instruction);
+            for (name, version) in defs.into_iter().zip(renaming.defs) {
+                if let Some(version) = version {
+                    *name = version;
+                }
+            }
+            for name in uses {
+                if let Some(version) = renaming.uses.get(name.as_str()) {
+                    *name = version.clone();
+                }
+            }
+        }
+
+        let types = versions
+            .iter()
+            .map(|(version, register)| (version.clone(), types.get(register).unwrap().clone()))
+            .collect();
+        Some(Ssa {
+            body: renamed,
+            phis,
+            guarded,
+            versions,
+            types,
+            names,
+        })
+    }
+
+    /// The renamed body, without phis. Its statements have the same paths
+    /// as in the original body.
+    pub fn body(&self) -> &FunctionBody {
+        &self.body
+    }
+
+    /// The renamed body, for transformations in SSA form. They must keep
+    /// the paths of existing statements and the control flow, on which
+    /// [`Ssa::eliminate_phis`] relies.
+    pub fn body_mut(&mut self) -> &mut FunctionBody {
+        &mut self.body
+    }
+
+    /// Phis in block order.
+    pub fn phis(&self) -> &[Phi] {
+        &self.phis
+    }
+
+    /// Phis, for transformations such as copy propagation that rewrite
+    /// their incoming versions.
+    pub fn phis_mut(&mut self) -> &mut [Phi] {
+        &mut self.phis
+    }
+
+    pub fn phis_at(&self, block: BlockId) -> impl Iterator<Item = &Phi> {
+        self.phis.iter().filter(move |phi| phi.block == block)
+    }
+
+    /// Guarded instructions with the versions they may leave unchanged, in
+    /// program order.
+    pub fn guarded(&self) -> &[GuardedDefinition] {
+        &self.guarded
+    }
+
+    /// Versions in order of creation with their original registers.
+    pub fn versions(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.versions
+            .iter()
+            .map(|(version, register)| (version.as_str(), register.as_str()))
+    }
+
+    /// Register that `name` is a version of; registers that were not
+    /// renamed map to themselves.
+    pub fn original<'s>(&'s self, name: &'s str) -> &'s str {
+        self.versions
+            .iter()
+            .find(|(version, _)| version == name)
+            .map_or(name, |(_, register)| register)
+    }
+
+    /// `.reg` declarations for every version.
+    pub fn declarations(&self) -> Vec<RegisterDirective> {
+        compact_registers(
+            self.versions
+                .iter()
+                .map(|(version, _)| (version, &self.types[version])),
+        )
+    }
+
+    /// Leave SSA form: replace each phi by `mov` copies on its incoming
+    /// edges and declare the versions, giving a body with the semantics of
+    /// the original.
+    ///
+    /// Copies go at the end of a predecessor with a single successor, or in
+    /// front of the label of a block reached by fall-through. A conditional
+    /// branch into a join point is redirected to a new block at the end of
+    /// the body holding the copies and a branch to the join point. A guarded
+    /// write is preceded by a copy of the previous version into its result.
+    /// Parallel copies are ordered so that no source is overwritten before
+    /// it is read, using a temporary to break cycles.
+    ///
+    /// Inserted statements have empty spans, or spans into their own text.
+    pub fn eliminate_phis(&self) -> Result<FunctionBody, SsaError> {
+        let cfg = Cfg::build(&self.body);
+        let dominators = DominatorTree::dominators(&cfg);
+        let mut names = self.names.clone();
+        let mut temporaries: Vec<(String, DataType)> = Vec::new();
+        let mut insertions: BTreeMap<Vec<usize>, Vec<FunctionStatement>> = BTreeMap::new();
+        let mut retargets: Vec<(Vec<usize>, String)> = Vec::new();
+        let mut split_blocks: Vec<FunctionStatement> = Vec::new();
+        // Copies from the function entry go after the leading declarations,
+        // which may declare the registers they read.
+        let entry = vec![
+            self.body
+                .statements
+                .iter()
+                .take_while(|statement| matches!(statement, FunctionStatement::Directive { .. }))
+                .count(),
+        ];
+
+        let mut phis = self.phis.iter().peekable();
+        while let Some(first) = phis.next() {
+            let mut group = vec![first];
+            while let Some(phi) = phis.next_if(|phi| phi.block == first.block) {
+                group.push(phi);
+            }
+            let join = first.block;
+            for (slot, &(source, _)) in first.incoming.iter().enumerate() {
+                let copies: Vec<(String, String)> = group
+                    .iter()
+                    .map(|phi| (phi.result.clone(), phi.incoming[slot].1.clone()))
+                    .collect();
+                let Some(source) = source else {
+                    let mut copies = self.sequentialize(copies, &mut names, &mut temporaries);
+                    insertions
+                        .entry(entry.clone())
+                        .or_default()
+                        .append(&mut copies);
+                    continue;
+                };
+                if !dominators.contains(source) {
+                    continue;
+                }
+
+                let block = cfg.block(source);
+                let last = &block.statements.last().expect("blocks are not empty").path;
+                let kinds: Vec<EdgeKind> = block
+                    .successors
+                    .iter()
+                    .filter(|edge| edge.target == join)
+                    .map(|edge| edge.kind)
+                    .collect();
+                if kinds.contains(&EdgeKind::Indirect) {
+                    return Err(SsaError::IndirectEdge {
+                        from: source,
+                        to: join,
+                    });
+                }
+                let mut copies = self.sequentialize(copies, &mut names, &mut temporaries);
+                let at = if cfg.successors(source).len() == 1 && block.terminator().is_some() {
+                    last.clone()
+                } else if cfg.successors(source).len() == 1 || kinds == [EdgeKind::FallThrough] {
+                    following(last)
+                } else {
+                    let label = names.label(&cfg);
+                    let mut jump = block.terminator().unwrap().clone();
+                    jump.predicate = None;
+                    split_blocks.push(parse_statement(&format!("{label}:")));
+                    split_blocks.append(&mut copies);
+                    split_blocks.push(FunctionStatement::Instruction {
+                        instruction: jump,
+                        span: Span::default(),
+                    });
+                    retargets.push((last.clone(), label));
+                    continue;
+                };
+                insertions.entry(at).or_default().append(&mut copies);
+            }
+        }
+        for definition in &self.guarded {
+            let copy = copy(&definition.result, &definition.previous, &self.types);
+            insertions
+                .entry(definition.path.clone())
+                .or_default()
+                .push(copy);
+        }
+
+        let mut body = self.body.clone();
+        for (path, label) in retargets {
+            if let FunctionStatement::Instruction { instruction, .. } =
+                statement_mut(&mut body.statements, &path)
+            {
+                retarget(instruction, &label);
+            }
+        }
+        // Later paths first, so that earlier ones stay valid.
+        for (path, statements) in insertions.into_iter().rev() {
+            let (&index, parents) = path.split_last().expect("paths are not empty");
+            statements_mut(&mut body.statements, parents).splice(index..index, statements);
+        }
+        if !split_blocks.is_empty() {
+            let falls_off = cfg.blocks().last().is_some_and(
+                |block| !matches!(block.terminator(), Some(t) if t.predicate.is_none()),
+            );
+            if falls_off {
+                body.statements.push(parse_statement("ret;"));
+            }
+            body.statements.append(&mut split_blocks);
+        }
+
+        let declarations = compact_registers(
+            self.versions
+                .iter()
+                .map(|(version, _)| (version.as_str(), &self.types[version]))
+                .chain(temporaries.iter().map(|(name, ty)| (name.as_str(), ty))),
+        );
+        body.statements.splice(
+            0..0,
+            declarations
+                .into_iter()
+                .map(|directive| FunctionStatement::Directive {
+                    directive: StatementDirective::Reg {
+                        directive,
+                        span: Span::default(),
+                    },
+                    span: Span::default(),
+                }),
+        );
+        Ok(body)
+    }
+
+    /// Order the parallel copies `(destination, source)` so that every source
+    /// is read before it is overwritten.
+    fn sequentialize(
+        &self,
+        mut pending: Vec<(String, String)>,
+        names: &mut Names,
+        temporaries: &mut Vec<(String, DataType)>,
+    ) -> Vec<FunctionStatement> {
+        pending.retain(|(destination, source)| destination != source);
+        let mut copies = Vec::new();
+        while !pending.is_empty() {
+            let ready = pending.iter().position(|(destination, _)| {
+                !pending.iter().any(|(_, source)| source == destination)
+            });
+            if let Some(ready) = ready {
+                let (destination, source) = pending.remove(ready);
+                copies.push(copy(&destination, &source, &self.types));
+                continue;
+            }
+            // Only cycles remain: save one destination before it is written.
+            let saved = pending[0].0.clone();
+            let ty = self.types[&saved].clone();
+            let temporary = names.fresh(self.original(&saved));
+            copies.push(copy(&temporary, &saved, &self.types));
+            temporaries.push((temporary.clone(), ty));
+            for (_, source) in &mut pending {
+                if *source == saved {
+                    *source = temporary.clone();
+                }
+            }
+        }
+        copies
+    }
+}
+
+/// Versions given to the defs of one instruction, in the order of
+/// `register_accesses`, where `None` keeps the name, and to the registers
+/// it reads, by name.
+struct Renaming {
+    defs: Vec<Option<String>>,
+    uses: HashMap<String, String>,
+}
+
+enum Step {
+    Enter(BlockId),
+    /// Pop the versions pushed by a block once its subtree is done.
+    Leave(Vec<usize>),
+}
+
+/// Source of fresh register and label names.
+///
+/// Versions of `%r1` are `%r1_1`, `%r1_2` and so on. When a declared
+/// register already has the form `%r1_N`, a longer prefix is used, so that
+/// ranges over the versions never cover a declared register.
+#[derive(Debug, Clone, PartialEq, Default)]
+struct Names {
+    taken_prefixes: HashSet<String>,
+    prefixes: HashMap<String, (String, u32)>,
+    labels: u32,
+}
+
+impl Names {
+    fn new(types: &RegisterTypes) -> Self {
+        let taken_prefixes = types
+            .iter()
+            .filter_map(|(name, _)| split_register_index(&name).map(|(base, _)| base.to_string()))
+            .collect();
+        Names {
+            taken_prefixes,
+            ..Names::default()
+        }
+    }
+
+    fn fresh(&mut self, register: &str) -> String {
+        let (prefix, count) = self
+            .prefixes
+            .entry(register.to_string())
+            .or_insert_with(|| {
+                let mut prefix = format!("{register}_");
+                while self.taken_prefixes.contains(&prefix) {
+                    prefix.push('_');
+                }
+                (prefix, 0)
+            });
+        *count += 1;
+        format!("{prefix}{count}")
+    }
+
+    fn label(&mut self, cfg: &Cfg) -> String {
+        loop {
+            let label = format!("$L__ssa_{}", self.labels);
+            self.labels += 1;
+            if cfg.block_of_label(&label).is_none() {
+                return label;
+            }
+        }
+    }
+}
+
+/// Registers that keep their names: `.reg` parameters, registers declared
+/// more than once, registers of read-write operands, and registers of types
+/// `mov` cannot copy.
+fn pinned_registers(function: FunctionRef, types: &RegisterTypes) -> HashSet<String> {
+    let mut pinned = HashSet::new();
+    for parameter in function.return_param().into_iter().chain(function.params()) {
+        if let ParameterDirective::Register { name, .. } = parameter {
+            pinned.insert(name.val.clone());
+        }
+    }
+
+    let mut declared = HashSet::new();
+    let mut declare = |directive: &StatementDirective| {
+        if let StatementDirective::Reg { directive, .. } = directive {
+            for name in directive.registers() {
+                if !declared.insert(name.clone()) {
+                    pinned.insert(name);
+                }
+            }
+        }
+    };
+    for directive in function.pre_body_declarations() {
+        declare(directive);
+    }
+    let mut read_write = Vec::new();
+    let mut pending: Vec<&[FunctionStatement]> = function
+        .body()
+        .map(|body| body.statements.as_slice())
+        .into_iter()
+        .collect();
+    while let Some(statements) = pending.pop() {
+        for statement in statements {
+            match statement {
+                FunctionStatement::Directive { directive, .. } => declare(directive),
+                FunctionStatement::Block { statements, .. } => pending.push(statements),
+                // A fresh version cannot be both read and written.
+                FunctionStatement::Instruction { instruction, .. } => read_write.extend(
+                    instruction
+                        .inst
+                        .operands()
+                        .iter()
+                        .filter(|operand| operand.role == OperandRole::ReadWrite)
+                        .flat_map(|operand| operand.registers())
+                        .map(|register| register.name.clone()),
+                ),
+                _ => {}
+            }
+        }
+    }
+
+    pinned.extend(read_write);
+    for (name, ty) in types.iter() {
+        if copy_type(ty).is_none() {
+            pinned.insert(name);
+        }
+    }
+    pinned
+}
+
+/// Type suffix of a `mov` copying a register of type `ty`.
+fn copy_type(ty: &DataType) -> Option<&'static str> {
+    match ty {
+        DataType::U8 { .. }
+        | DataType::S8 { .. }
+        | DataType::B8 { .. }
+        | DataType::TexRef { .. }
+        | DataType::SamplerRef { .. }
+        | DataType::SurfRef { .. } => None,
+        DataType::F16 { .. } => Some(".b16"),
+        DataType::F16x2 { .. } => Some(".b32"),
+        ty => Some(type_name(ty)),
+    }
+}
+
+/// `mov` from `source` to `destination`, typed by the version `destination`
+/// or, for a temporary, by the version it saves.
+fn copy(destination: &str, source: &str, types: &HashMap<String, DataType>) -> FunctionStatement {
+    let ty = types
+        .get(destination)
+        .or_else(|| types.get(source))
+        .expect("copies are between versions");
+    let suffix = copy_type(ty).expect("versioned registers can be copied");
+    parse_statement(&format!("mov{suffix} {destination}, {source};"))
+}
+
+fn parse_statement(text: &str) -> FunctionStatement {
+    let tokens = tokenize(text).expect("generated statements lex");
+    let mut stream = PtxTokenStream::new(&tokens);
+    let (statement, _) =
+        FunctionStatement::parse()(&mut stream).expect("generated statements parse");
+    match statement {
+        FunctionStatement::Instruction { instruction, .. } => FunctionStatement::Instruction {
+            instruction: Instruction {
+                span: Span::default(),
+                ..instruction
+            },
+            span: Span::default(),
+        },
+        FunctionStatement::Label { label, .. } => FunctionStatement::Label {
+            label,
+            span: Span::default(),
+        },
+        statement => statement,
+    }
+}
+
+/// Point the branch `instruction` at `label`.
+fn retarget(instruction: &mut Instruction, label: &str) {
+    let target =
+        instruction
+            .inst
+            .operands_mut()
+            .into_iter()
+            .rev()
+            .find_map(|operand| match operand.value {
+                OperandValueMut::General(GeneralOperand::Single {
+                    operand: Operand::Symbol { name, .. },
+                    ..
+                }) => Some(name),
+                _ => None,
+            });
+    if let Some(target) = target {
+        *target = label.to_string();
+    }
+}
+
+/// Path of the statement after the one at `path`, in the same block.
+fn following(path: &[usize]) -> Vec<usize> {
+    let mut next = path.to_vec();
+    *next.last_mut().expect("paths are not empty") += 1;
+    next
+}
+
+/// Statements of the nested block at `path`, the body itself when empty.
+fn statements_mut<'s>(
+    mut statements: &'s mut Vec<FunctionStatement>,
+    path: &[usize],
+) -> &'s mut Vec<FunctionStatement> {
+    for &index in path {
+        let FunctionStatement::Block {
+            statements: inner, ..
+        } = &mut statements[index]
+        else {
+            unreachable!("statement paths lead through blocks");
+        };
+        statements = inner;
+    }
+    statements
+}
+
+fn statement_mut<'s>(
+    statements: &'s mut Vec<FunctionStatement>,
+    path: &[usize],
+) -> &'s mut FunctionStatement {
+    let (&index, parents) = path.split_last().expect("paths are not empty");
+    &mut statements_mut(statements, parents)[index]
+}
@@ -0,0 +1,719 @@
+//! Name resolution for a whole module.
+//!
+//! [`SymbolTable::build`] interns every name in a [`Module`], records each
+//! declaration in the scope it belongs to and resolves every use — registers,
+//! variables in address operands, branch and call targets — to its
+//! declaration:
+//!
+//! ```
+//! use ptx_parser::analysis::{Resolution, SymbolKind, SymbolTable};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".entry k(.param .u64 p) { .reg .b64 %rd<2>; ld.param.u64 %rd1, [p]; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let table = SymbolTable::build(&module);
+//!     assert!(table.issues().is_empty());
+//!
+//!     let names: Vec<_> = table.references().iter().map(|r| table.name(r.name)).collect();
+//!     assert_eq!(names, ["%rd1", "p"]);
+//!     let Resolution::Declared(decl) = table.references()[0].resolution else {
+//!         unreachable!()
+//!     };
+//!     assert_eq!(table.name(table.declaration(decl).name), "%rd");
+//!     assert!(matches!(
+//!         table.declaration(decl).kind,
+//!         SymbolKind::Register { range: Some(2), .. }
+//!     ));
+//! });
+//! ```
+//!
+//! Scopes nest as in PTX: the module, one scope per function holding its
+//! parameters and top-level statements, and one per nested `{ ... }` block. A
+//! declaration is visible throughout the block it appears in, so forward
+//! branches resolve like any other use.
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use super::FunctionRef;
+use super::registers::split_register_index;
+use crate::diagnostic::Diagnostic;
+use crate::r#type::{
+    DataType, FunctionStatement, FunctionSymbol, GlobalInitializer, InitializerValue, Instruction,
+    Label, Module, ModuleDirective, ModuleVariableDirective, ParameterDirective, RegisterOperand,
+    SpecialRegister, StatementDirective, VariableSymbol,
+};
+use crate::visit::Visitor;
+use crate::{PtxParser, PtxTokenStream, Span, tokenize};
+
+/// Interned name. Only meaningful for the [`SymbolTable`] that produced it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Symbol(u32);
+
+/// Index of a [`Scope`] in its [`SymbolTable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ScopeId(u32);
+
+impl ScopeId {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Index of a [`Declaration`] in its [`SymbolTable`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DeclId(u32);
+
+impl DeclId {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// State space a variable is declared in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StateSpace {
+    Reg,
+    Param,
+    Local,
+    Shared,
+    Global,
+    Const,
+    /// Deprecated `.tex` declaration, equivalent to `.global .texref`.
+    Tex,
+}
+
+/// What a [`Declaration`] declares.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SymbolKind {
+    /// `.reg` variable or parameter. `range` is `N` for `%r<N>`, which
+    /// declares `%r0` to `%r{N-1}` under the base name `%r`.
+    Register {
+        ty: DataType,
+        range: Option<u32>,
+    },
+    /// Variable in any other state space, including `.param` parameters.
+    Variable {
+        space: StateSpace,
+        ty: DataType,
+    },
+    /// `.entry` (`entry`) or `.func` function; `defined` unless it is a
+    /// prototype without a body.
+    Function {
+        entry: bool,
+        defined: bool,
+    },
+    /// `.alias` name of another function.
+    Alias {
+        target: Symbol,
+    },
+    Label,
+}
+
+/// A name introduced by a directive, parameter or label.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Declaration {
+    pub name: Symbol,
+    pub kind: SymbolKind,
+    pub scope: ScopeId,
+    /// Span of the declared name.
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScopeKind {
+    Module,
+    /// Parameters and top-level statements of the named function.
+    Function {
+        name: Symbol,
+    },
+    /// Nested `{ ... }` block.
+    Block,
+}
+
+/// A set of declarations visible in one part of the module.
+#[derive(Debug, Clone)]
+pub struct Scope {
+    pub kind: ScopeKind,
+    /// Enclosing scope; `None` only for the module scope.
+    pub parent: Option<ScopeId>,
+    pub span: Span,
+    /// Declarations in source order.
+    pub declarations: Vec<DeclId>,
+    names: HashMap<Symbol, DeclId>,
+    /// Register ranges by base name.
+    ranges: HashMap<Symbol, DeclId>,
+}
+
+/// What a [`Reference`] resolved to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resolution {
+    Declared(DeclId),
+    /// Special register such as `%tid`, or the `WARP_SZ` constant.
+    Builtin,
+    Undefined,
+}
+
+/// A use of a name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+    pub name: Symbol,
+    pub span: Span,
+    /// Innermost scope the use appears in.
+    pub scope: ScopeId,
+    pub resolution: Resolution,
+}
+
+/// Problem found while resolving names.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum SymbolIssue {
+    #[error("`{name}` is not declared")]
+    Undefined { name: String, span: Span },
+    #[error("`{name}` shadows a declaration in an enclosing scope")]
+    Shadowed {
+        name: String,
+        span: Span,
+        previous: Span,
+    },
+    #[error("`{name}` is already declared in this scope")]
+    Redeclared {
+        name: String,
+        span: Span,
+        previous: Span,
+    },
+}
+
+impl SymbolIssue {
+    /// Span of the offending use or declaration.
+    pub fn span(&self) -> Span {
+        match self {
+            SymbolIssue::Undefined { span, .. }
+            | SymbolIssue::Shadowed { span, .. }
+            | SymbolIssue::Redeclared { span, .. } => *span,
+        }
+    }
+
+    /// Convert this issue into a [`Diagnostic`]; shadowing is a warning.
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        match self {
+            SymbolIssue::Undefined { span, .. } => {
+                Diagnostic::error(self.to_string(), *span).with_label("not found in this scope")
+            }
+            SymbolIssue::Shadowed { span, .. } => Diagnostic::warning(self.to_string(), *span)
+                .with_label("shadows an outer declaration"),
+            SymbolIssue::Redeclared { span, .. } => {
+                Diagnostic::error(self.to_string(), *span).with_label("declared again here")
+            }
+        }
+    }
+}
+
+/// Declarations, scopes and resolved uses of every name in a module.
+#[derive(Debug, Clone)]
+pub struct SymbolTable {
+    names: Vec<String>,
+    symbols: HashMap<String, Symbol>,
+    scopes: Vec<Scope>,
+    declarations: Vec<Declaration>,
+    references: Vec<Reference>,
+    reference_spans: HashMap<Span, usize>,
+    function_scopes: HashMap<Symbol, ScopeId>,
+    issues: Vec<SymbolIssue>,
+}
+
+impl SymbolTable {
+    pub fn build(module: &Module) -> Self {
+        let mut table = SymbolTable {
+            names: Vec::new(),
+            symbols: HashMap::new(),
+            scopes: Vec::new(),
+            declarations: Vec::new(),
+            references: Vec::new(),
+            reference_spans: HashMap::new(),
+            function_scopes: HashMap::new(),
+            issues: Vec::new(),
+        };
+        let root = table.push_scope(ScopeKind::Module, None, module.span);
+
+        // Module-level names are visible before their declaration, e.g. in
+        // calls to functions defined further down.
+        for directive in &module.directives {
+            table.declare_module_directive(root, directive);
+        }
+        for directive in &module.directives {
+            match directive {
+                ModuleDirective::ModuleVariable { directive, .. } => {
+                    let (ModuleVariableDirective::Tex { directive, .. }
+                    | ModuleVariableDirective::Shared { directive, .. }
+                    | ModuleVariableDirective::Global { directive, .. }
+                    | ModuleVariableDirective::Const { directive, .. }) = directive;
+                    if let Some(initializer) = &directive.initializer {
+                        table.initializer(root, initializer);
+                    }
+                }
+                ModuleDirective::EntryFunction { directive, .. } => {
+                    table.function(root, directive.into())
+                }
+                ModuleDirective::FuncFunction { directive, .. } => {
+                    table.function(root, directive.into())
+                }
+                ModuleDirective::AliasFunction { directive, .. } => {
+                    table.reference(root, &directive.target.val, directive.target.span)
+                }
+                ModuleDirective::ModuleInfo { .. } | ModuleDirective::Debug { .. } => {}
+            }
+        }
+        table
+    }
+
+    /// Text of an interned name.
+    pub fn name(&self, symbol: Symbol) -> &str {
+        &self.names[symbol.0 as usize]
+    }
+
+    /// Interned name for `name`, if it occurs in the module.
+    pub fn symbol(&self, name: &str) -> Option<Symbol> {
+        self.symbols.get(name).copied()
+    }
+
+    pub fn module_scope(&self) -> ScopeId {
+        ScopeId(0)
+    }
+
+    pub fn scope(&self, id: ScopeId) -> &Scope {
+        &self.scopes[id.index()]
+    }
+
+    /// All scopes; the module scope comes first and every scope follows its
+    /// parent.
+    pub fn scopes(&self) -> &[Scope] {
+        &self.scopes
+    }
+
+    /// Scope of the named function, preferring its definition over
+    /// prototypes.
+    pub fn function_scope(&self, name: &str) -> Option<ScopeId> {
+        self.function_scopes.get(&self.symbol(name)?).copied()
+    }
+
+    pub fn declaration(&self, id: DeclId) -> &Declaration {
+        &self.declarations[id.index()]
+    }
+
+    /// All declarations, indexed by [`DeclId::index`].
+    pub fn declarations(&self) -> &[Declaration] {
+        &self.declarations
+    }
+
+    /// Declaration `name` refers to when used in `scope`. Registers such as
+    /// `%r7` also resolve to a covering range declaration `%r<N>`.
+    pub fn lookup(&self, scope: ScopeId, name: &str) -> Option<DeclId> {
+        let mut current = Some(scope);
+        while let Some(id) = current {
+            if let Some(decl) = self.find_in_scope(id, name) {
+                return Some(decl);
+            }
+            current = self.scope(id).parent;
+        }
+        None
+    }
+
+    /// Every use of a name, in source order.
+    pub fn references(&self) -> &[Reference] {
+        &self.references
+    }
+
+    /// The use whose name spans exactly `span`.
+    pub fn reference_at(&self, span: Span) -> Option<&Reference> {
+        self.reference_spans
+            .get(&span)
+            .map(|&index| &self.references[index])
+    }
+
+    /// Declaration of the use at `span`.
+    pub fn resolve(&self, span: Span) -> Option<&Declaration> {
+        match self.reference_at(span)?.resolution {
+            Resolution::Declared(id) => Some(self.declaration(id)),
+            Resolution::Builtin | Resolution::Undefined => None,
+        }
+    }
+
+    /// Uses that resolve to `decl`.
+    pub fn references_to(&self, decl: DeclId) -> impl Iterator<Item = &Reference> + '_ {
+        self.references
+            .iter()
+            .filter(move |reference| reference.resolution == Resolution::Declared(decl))
+    }
+
+    /// Undefined, shadowed and redeclared names, in the order they were found.
+    pub fn issues(&self) -> &[SymbolIssue] {
+        &self.issues
+    }
+
+    fn intern(&mut self, name: &str) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(name) {
+            return symbol;
+        }
+        let symbol = Symbol(self.names.len() as u32);
+        self.names.push(name.to_string());
+        self.symbols.insert(name.to_string(), symbol);
+        symbol
+    }
+
+    fn push_scope(&mut self, kind: ScopeKind, parent: Option<ScopeId>, span: Span) -> ScopeId {
+        let id = ScopeId(self.scopes.len() as u32);
+        self.scopes.push(Scope {
+            kind,
+            parent,
+            span,
+            declarations: Vec::new(),
+            names: HashMap::new(),
+            ranges: HashMap::new(),
+        });
+        id
+    }
+
+    fn find_in_scope(&self, scope: ScopeId, name: &str) -> Option<DeclId> {
+        let scope = self.scope(scope);
+        if let Some(decl) = self
+            .symbol(name)
+            .and_then(|symbol| scope.names.get(&symbol))
+        {
+            return Some(*decl);
+        }
+        let (base, index) = split_register_index(name)?;
+        let decl = *scope.ranges.get(&self.symbol(base)?)?;
+        match self.declaration(decl).kind {
+            SymbolKind::Register {
+                range: Some(count), ..
+            } if index < count => Some(decl),
+            _ => None,
+        }
+    }
+
+    fn find_range(&self, scope: ScopeId, base: Symbol) -> Option<DeclId> {
+        self.scope(scope).ranges.get(&base).copied()
+    }
+
+    fn declare(&mut self, scope: ScopeId, name: &str, span: Span, kind: SymbolKind) -> DeclId {
+        let symbol = self.intern(name);
+        let is_range = matches!(kind, SymbolKind::Register { range: Some(_), .. });
+        let existing = if is_range {
+            self.find_range(scope, symbol)
+        } else {
+            self.find_in_scope(scope, name)
+        };
+
+        let id = DeclId(self.declarations.len() as u32);
+        let defined = matches!(kind, SymbolKind::Function { defined: true, .. });
+        self.declarations.push(Declaration {
+            name: symbol,
+            kind,
+            scope,
+            span,
+        });
+        self.scopes[scope.index()].declarations.push(id);
+
+        if let Some(previous) = existing {
+            // Prototypes may be repeated and followed by one definition.
+            let previous = self.declaration(previous);
+            match previous.kind {
+                SymbolKind::Function {
+                    defined: previous_defined,
+                    ..
+                } if matches!(self.declaration(id).kind, SymbolKind::Function { .. })
+                    && !(previous_defined && defined) =>
+                {
+                    if defined {
+                        self.scopes[scope.index()].names.insert(symbol, id);
+                    }
+                }
+                _ => self.issues.push(SymbolIssue::Redeclared {
+                    name: name.to_string(),
+                    span,
+                    previous: previous.span,
+                }),
+            }
+            return id;
+        }
+
+        let mut outer = self.scope(scope).parent;
+        while let Some(parent) = outer {
+            let shadowed = if is_range {
+                self.find_range(parent, symbol)
+            } else {
+                self.find_in_scope(parent, name)
+            };
+            if let Some(shadowed) = shadowed {
+                self.issues.push(SymbolIssue::Shadowed {
+                    name: name.to_string(),
+                    span,
+                    previous: self.declaration(shadowed).span,
+                });
+                break;
+            }
+            outer = self.scope(parent).parent;
+        }
+
+        let scope = &mut self.scopes[scope.index()];
+        if is_range {
+            scope.ranges.insert(symbol, id);
+        } else {
+            scope.names.insert(symbol, id);
+        }
+        id
+    }
+
+    fn reference(&mut self, scope: ScopeId, name: &str, span: Span) {
+        let symbol = self.intern(name);
+        let resolution = match self.lookup(scope, name) {
+            Some(decl) => Resolution::Declared(decl),
+            None if is_builtin(name) => Resolution::Builtin,
+            None => {
+                self.issues.push(SymbolIssue::Undefined {
+                    name: name.to_string(),
+                    span,
+                });
+                Resolution::Undefined
+            }
+        };
+        self.reference_spans.insert(span, self.references.len());
+        self.references.push(Reference {
+            name: symbol,
+            span,
+            scope,
+            resolution,
+        });
+    }
+
+    fn declare_module_directive(&mut self, root: ScopeId, directive: &ModuleDirective) {
+        match directive {
+            ModuleDirective::ModuleVariable { directive, .. } => {
+                let (space, variable) = match directive {
+                    ModuleVariableDirective::Tex { directive, .. } => (StateSpace::Tex, directive),
+                    ModuleVariableDirective::Shared { directive, .. } => {
+                        (StateSpace::Shared, directive)
+                    }
+                    ModuleVariableDirective::Global { directive, .. } => {
+                        (StateSpace::Global, directive)
+                    }
+                    ModuleVariableDirective::Const { directive, .. } => {
+                        (StateSpace::Const, directive)
+                    }
+                };
+                self.declare_variable(root, &variable.name, space, &variable.ty);
+            }
+            ModuleDirective::EntryFunction { directive, .. } => {
+                self.declare_function(root, &directive.name, true, directive.body.is_some());
+            }
+            ModuleDirective::FuncFunction { directive, .. } => {
+                self.declare_function(root, &directive.name, false, directive.body.is_some());
+            }
+            ModuleDirective::AliasFunction { directive, .. } => {
+                let target = self.intern(&directive.target.val);
+                self.declare(
+                    root,
+                    &directive.alias.val,
+                    directive.alias.span,
+                    SymbolKind::Alias { target },
+                );
+            }
+            ModuleDirective::ModuleInfo { .. } | ModuleDirective::Debug { .. } => {}
+        }
+    }
+
+    fn declare_function(
+        &mut self,
+        root: ScopeId,
+        name: &FunctionSymbol,
+        entry: bool,
+        defined: bool,
+    ) {
+        self.declare(
+            root,
+            &name.val,
+            name.span,
+            SymbolKind::Function { entry, defined },
+        );
+    }
+
+    fn declare_variable(
+        &mut self,
+        scope: ScopeId,
+        name: &VariableSymbol,
+        space: StateSpace,
+        ty: &DataType,
+    ) {
+        let kind = SymbolKind::Variable {
+            space,
+            ty: ty.clone(),
+        };
+        self.declare(scope, &name.val, name.span, kind);
+    }
+
+    fn declare_parameter(&mut self, scope: ScopeId, parameter: &ParameterDirective) {
+        match parameter {
+            ParameterDirective::Register { ty, name, .. } => {
+                let kind = SymbolKind::Register {
+                    ty: ty.clone(),
+                    range: None,
+                };
+                self.declare(scope, &name.val, name.span, kind);
+            }
+            ParameterDirective::Parameter { ty, name, .. } => {
+                self.declare_variable(scope, name, StateSpace::Param, ty);
+            }
+        }
+    }
+
+    fn declare_statement_directive(&mut self, scope: ScopeId, directive: &StatementDirective) {
+        match directive {
+            StatementDirective::Reg { directive, .. } => {
+                for register in &directive.registers {
+                    let kind = SymbolKind::Register {
+                        ty: directive.ty.clone(),
+                        range: register.range,
+                    };
+                    self.declare(scope, &register.name.val, register.name.span, kind);
+                }
+            }
+            StatementDirective::Local { directive, .. } => {
+                self.declare_variable(scope, &directive.name, StateSpace::Local, &directive.ty);
+            }
+            StatementDirective::Param { directive, .. } => {
+                self.declare_variable(scope, &directive.name, StateSpace::Param, &directive.ty);
+            }
+            StatementDirective::Shared { directive, .. } => {
+                self.declare_variable(scope, &directive.name, StateSpace::Shared, &directive.ty);
+            }
+            _ => {}
+        }
+    }
+
+    fn function(&mut self, root: ScopeId, function: FunctionRef) {
+        let symbol = self.intern(function.name());
+        let scope = self.push_scope(
+            ScopeKind::Function { name: symbol },
+            Some(root),
+            function.span(),
+        );
+        if function.body().is_some() || !self.function_scopes.contains_key(&symbol) {
+            self.function_scopes.insert(symbol, scope);
+        }
+        for parameter in function.return_param().into_iter().chain(function.params()) {
+            self.declare_parameter(scope, parameter);
+        }
+        for directive in function.pre_body_declarations() {
+            self.declare_statement_directive(scope, directive);
+        }
+        if let Some(body) = function.body() {
+            self.block(scope, &body.statements);
+        }
+    }
+
+    fn block(&mut self, scope: ScopeId, statements: &[FunctionStatement]) {
+        for statement in statements {
+            match statement {
+                FunctionStatement::Label { label, .. } => {
+                    self.declare(scope, &label.val, label.span, SymbolKind::Label);
+                }
+                FunctionStatement::Directive { directive, .. } => {
+                    self.declare_statement_directive(scope, directive);
+                }
+                _ => {}
+            }
+        }
+        for statement in statements {
+            match statement {
+                FunctionStatement::Instruction { instruction, .. } => {
+                    self.instruction(scope, instruction)
+                }
+                FunctionStatement::Block { statements, span } => {
+                    let inner = self.push_scope(ScopeKind::Block, Some(scope), *span);
+                    self.block(inner, statements);
+                }
+                FunctionStatement::Directive {
+                    directive: StatementDirective::BranchTargets { directive, .. },
+                    ..
+                } => {
+                    for label in &directive.labels {
+                        self.reference(scope, &label.val, label.span);
+                    }
+                }
+                FunctionStatement::Directive {
+                    directive: StatementDirective::CallTargets { directive, .. },
+                    ..
+                } => {
+                    for target in &directive.targets {
+                        self.reference(scope, &target.val, target.span);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn instruction(&mut self, scope: ScopeId, instruction: &Instruction) {
+        Uses { table: self, scope }.visit_instruction(instruction);
+    }
+
+    fn initializer(&mut self, scope: ScopeId, initializer: &GlobalInitializer) {
+        match initializer {
+            GlobalInitializer::Scalar {
+                value: InitializerValue::FunctionSymbol { name, .. },
+                ..
+            } => self.reference(scope, &name.val, name.span),
+            GlobalInitializer::Scalar { .. } => {}
+            GlobalInitializer::Aggregate { values, .. } => {
+                for value in values {
+                    self.initializer(scope, value);
+                }
+            }
+        }
+    }
+}
+
+/// Records the names used by one instruction.
+struct Uses<'t> {
+    table: &'t mut SymbolTable,
+    scope: ScopeId,
+}
+
+impl<'ast> Visitor<'ast> for Uses<'_> {
+    fn visit_symbol(&mut self, name: &'ast str, span: Span) {
+        self.table.reference(self.scope, name, span);
+    }
+
+    fn visit_register(&mut self, register: &'ast RegisterOperand) {
+        self.table
+            .reference(self.scope, &register.name, register.span);
+    }
+
+    fn visit_function_symbol(&mut self, symbol: &'ast FunctionSymbol) {
+        self.table.reference(self.scope, &symbol.val, symbol.span);
+    }
+
+    fn visit_variable_symbol(&mut self, symbol: &'ast VariableSymbol) {
+        self.table.reference(self.scope, &symbol.val, symbol.span);
+    }
+
+    fn visit_label(&mut self, label: &'ast Label) {
+        self.table.reference(self.scope, &label.val, label.span);
+    }
+}
+
+/// Whether `name` is a special register or predefined constant.
+fn is_builtin(name: &str) -> bool {
+    if name == "WARP_SZ" {
+        return true;
+    }
+    if !name.starts_with('%') {
+        return false;
+    }
+    let Ok(tokens) = tokenize(name) else {
+        return false;
+    };
+    let mut stream = PtxTokenStream::new(&tokens);
+    SpecialRegister::parse()(&mut stream).is_ok()
+}
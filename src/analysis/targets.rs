@@ -0,0 +1,373 @@
+//! Instructions checked against the `.version` and `.target` of a module.
+//!
+//! Many instructions and modifiers only exist from some PTX ISA version on,
+//! or only on some architectures: `wgmma` needs PTX ISA 8.0 and `sm_90a`, and
+//! `.L2::256B` needs PTX ISA 7.4 and `sm_80`. The parser accepts them
+//! everywhere; [`check_targets`] reports every instruction that the
+//! `.version` or `.target` of its module does not allow, and [`min_target`]
+//! finds the lowest PTX ISA version and target that allow every instruction.
+//!
+//! The requirements are listed in `crates/parser-gen/ptx_requirements.txt`,
+//! see [`Requirement`]. Instructions without a matching line are assumed to
+//! work everywhere.
+//!
+//! ```
+//! use ptx_parser::Target;
+//! use ptx_parser::analysis::{check_targets, min_target};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".version 7.8 .target sm_80 .entry k() { wgmma.fence.sync.aligned; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!
+//!     let issues = check_targets(&module);
+//!     assert_eq!(
+//!         issues[0].to_string(),
+//!         "`wgmma.fence.sync.aligned` requires PTX ISA 8.0, but the module is version 7.8"
+//!     );
+//!     assert_eq!(
+//!         issues[1].to_string(),
+//!         "`wgmma.fence.sync.aligned` is not supported on `sm_80`"
+//!     );
+//!
+//!     let (target, _) = min_target(&module).unwrap().target.unwrap();
+//!     assert_eq!(target, Target::arch(90));
+//! });
+//! ```
+
+use thiserror::Error;
+
+use super::functions;
+use crate::Span;
+use crate::diagnostic::Diagnostic;
+use crate::metadata::{PtxVersion, Requirement, Target, TargetVariant};
+use crate::r#type::{
+    FunctionStatement, Instruction, Module, ModuleDirective, ModuleInfoDirectiveKind,
+};
+
+/// An instruction that matches a [`Requirement`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RequirementUse {
+    /// Mnemonic of the instruction, e.g. `wgmma.fence.sync.aligned`.
+    pub mnemonic: String,
+    pub requirement: &'static Requirement,
+    /// Span of the instruction.
+    pub span: Span,
+}
+
+/// An instruction that the `.version` or `.target` of its module does not
+/// allow.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TargetIssue {
+    #[error(
+        "`{}` requires PTX ISA {}, but the module is version {found}",
+        used.mnemonic,
+        used.requirement.ptx
+    )]
+    Version {
+        used: RequirementUse,
+        found: PtxVersion,
+    },
+    #[error("`{}` is not supported on `{found}`", used.mnemonic)]
+    Target { used: RequirementUse, found: Target },
+    /// No target supports both instructions; reported by [`min_target`].
+    #[error("no target supports both `{}` and `{}`", first.mnemonic, second.mnemonic)]
+    Conflict {
+        first: RequirementUse,
+        second: RequirementUse,
+    },
+}
+
+impl TargetIssue {
+    /// Span of the offending instruction; the later one for a conflict.
+    pub fn span(&self) -> Span {
+        match self {
+            TargetIssue::Version { used, .. } | TargetIssue::Target { used, .. } => used.span,
+            TargetIssue::Conflict { second, .. } => second.span,
+        }
+    }
+
+    /// Convert this issue into a [`Diagnostic`].
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::error(self.to_string(), self.span());
+        match self {
+            TargetIssue::Version { used, .. } => diagnostic
+                .with_label(format!("requires `.version {}`", used.requirement.ptx))
+                .with_note(format!(
+                    "`{}` was introduced in PTX ISA {}",
+                    used.requirement.pattern, used.requirement.ptx
+                )),
+            TargetIssue::Target { used, .. } => diagnostic
+                .with_label(format!(
+                    "requires {}",
+                    target_list(used.requirement.targets)
+                ))
+                .with_note(needs(used.requirement)),
+            TargetIssue::Conflict { first, second } => diagnostic
+                .with_label(format!("conflicts with `{}`", first.mnemonic))
+                .with_note(needs(first.requirement))
+                .with_note(needs(second.requirement)),
+        }
+    }
+}
+
+fn target_list(targets: &[Target]) -> String {
+    targets
+        .iter()
+        .map(|target| format!("`{target}`"))
+        .collect::<Vec<_>>()
+        .join(" or ")
+}
+
+fn needs(requirement: &Requirement) -> String {
+    let targets = requirement
+        .targets
+        .iter()
+        .map(|target| match target.variant {
+            TargetVariant::Base => format!("`{target}` or later"),
+            TargetVariant::Family => format!("the `{target}` family"),
+            TargetVariant::Arch => format!("`{target}`"),
+        })
+        .collect::<Vec<_>>()
+        .join(" or ");
+    format!("`{}` needs {targets}", requirement.pattern)
+}
+
+/// Lowest PTX ISA version and target that allow every instruction of a
+/// module, see [`min_target`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinTarget {
+    /// Lowest PTX ISA version and the first instruction that needs it, or
+    /// `None` when no instruction has a requirement.
+    pub ptx: Option<(PtxVersion, RequirementUse)>,
+    /// Lowest target and the instruction that needs the highest one on its
+    /// own, or `None` when every target will do.
+    pub target: Option<(Target, RequirementUse)>,
+}
+
+/// Version given by the `.version` directive of `module`.
+pub fn module_version(module: &Module) -> Option<PtxVersion> {
+    module
+        .directives
+        .iter()
+        .find_map(|directive| match directive {
+            ModuleDirective::ModuleInfo {
+                directive: ModuleInfoDirectiveKind::Version { directive, .. },
+                ..
+            } => Some(PtxVersion::new(directive.major, directive.minor)),
+            _ => None,
+        })
+}
+
+/// Architectures named by the `.target` directives of `module`, with the
+/// span of each entry. Options such as `texmode_unified` are skipped.
+pub fn module_targets(module: &Module) -> Vec<(Target, Span)> {
+    let mut targets = Vec::new();
+    for directive in &module.directives {
+        if let ModuleDirective::ModuleInfo {
+            directive: ModuleInfoDirectiveKind::Target { directive, .. },
+            ..
+        } = directive
+        {
+            targets.extend(
+                directive
+                    .entries
+                    .iter()
+                    .filter_map(|entry| Some((Target::of(entry)?, entry.span()))),
+            );
+        }
+    }
+    targets
+}
+
+/// Every instruction of `module` that matches a requirement, once per
+/// matching requirement, in source order.
+pub fn requirement_uses(module: &Module) -> Vec<RequirementUse> {
+    let mut uses = Vec::new();
+    for function in functions(module) {
+        if let Some(body) = function.body() {
+            collect_uses(&body.statements, &mut uses);
+        }
+    }
+    uses
+}
+
+fn collect_uses(statements: &[FunctionStatement], uses: &mut Vec<RequirementUse>) {
+    for statement in statements {
+        match statement {
+            FunctionStatement::Instruction { instruction, .. } => {
+                uses.extend(instruction_uses(instruction))
+            }
+            FunctionStatement::Block { statements, .. } => collect_uses(statements, uses),
+            _ => {}
+        }
+    }
+}
+
+fn instruction_uses(instruction: &Instruction) -> impl Iterator<Item = RequirementUse> + '_ {
+    let mnemonic = instruction.inst.mnemonic();
+    instruction
+        .inst
+        .requirements()
+        .into_iter()
+        .map(move |requirement| RequirementUse {
+            mnemonic: mnemonic.clone(),
+            requirement,
+            span: instruction.span,
+        })
+}
+
+/// Check every instruction of `module` against its `.version` and `.target`.
+///
+/// An instruction is reported at most once for the version, naming the
+/// requirement with the highest version, and once per declared target,
+/// naming the failing requirement with the highest target. Without a
+/// `.version` directive no version is checked, and without an `sm_*` target
+/// no target is.
+pub fn check_targets(module: &Module) -> Vec<TargetIssue> {
+    let version = module_version(module);
+    let targets = module_targets(module);
+    let uses = requirement_uses(module);
+
+    let mut issues = Vec::new();
+    for instruction in uses.chunk_by(|a, b| a.span == b.span) {
+        if let Some(found) = version {
+            let newest = instruction
+                .iter()
+                .filter(|used| used.requirement.ptx > found)
+                .max_by_key(|used| used.requirement.ptx);
+            if let Some(used) = newest {
+                issues.push(TargetIssue::Version {
+                    used: used.clone(),
+                    found,
+                });
+            }
+        }
+        for &(found, _) in &targets {
+            let failing = instruction
+                .iter()
+                .filter(|used| !used.requirement.allows(&found))
+                .reduce(|highest, used| {
+                    if lowest_target(used.requirement) > lowest_target(highest.requirement) {
+                        used
+                    } else {
+                        highest
+                    }
+                });
+            if let Some(used) = failing {
+                issues.push(TargetIssue::Target {
+                    used: used.clone(),
+                    found,
+                });
+            }
+        }
+    }
+    issues
+}
+
+/// Find the lowest PTX ISA version and target that allow every instruction of
+/// `module`, ignoring its `.version` and `.target`. Targets are ordered by
+/// architecture, preferring a base target to a family-specific one and that
+/// to an architecture-specific one.
+///
+/// Returns [`TargetIssue::Conflict`] when no target supports two of the
+/// instructions, such as `wgmma`, which needs `sm_90a`, and `tcgen05`.
+pub fn min_target(module: &Module) -> Result<MinTarget, TargetIssue> {
+    let uses = requirement_uses(module);
+
+    let ptx = uses
+        .iter()
+        .map(|used| used.requirement.ptx)
+        .max()
+        .map(|version| {
+            let used = uses
+                .iter()
+                .find(|used| used.requirement.ptx == version)
+                .unwrap();
+            (version, used.clone())
+        });
+
+    // The first use of each requirement that restricts the target.
+    let mut restricting: Vec<&RequirementUse> = Vec::new();
+    for used in &uses {
+        if !used.requirement.targets.is_empty()
+            && !restricting
+                .iter()
+                .any(|seen| std::ptr::eq(seen.requirement, used.requirement))
+        {
+            restricting.push(used);
+        }
+    }
+    let Some(forcing) = restricting.iter().copied().reduce(|highest, used| {
+        if lowest_target(used.requirement) > lowest_target(highest.requirement) {
+            used
+        } else {
+            highest
+        }
+    }) else {
+        return Ok(MinTarget { ptx, target: None });
+    };
+
+    let allowed_by_all = |target: &Target| {
+        restricting
+            .iter()
+            .all(|used| used.requirement.allows(target))
+    };
+    if let Some(target) = candidates(&restricting)
+        .into_iter()
+        .find(|target| allowed_by_all(target))
+    {
+        return Ok(MinTarget {
+            ptx,
+            target: Some((target, forcing.clone())),
+        });
+    }
+
+    let mut conflict = (restricting[0], restricting[restricting.len() - 1]);
+    'search: for (index, first) in restricting.iter().enumerate() {
+        for second in &restricting[index + 1..] {
+            let pair = [*first, *second];
+            if !candidates(&pair)
+                .iter()
+                .any(|target| first.requirement.allows(target) && second.requirement.allows(target))
+            {
+                conflict = (first, second);
+                break 'search;
+            }
+        }
+    }
+    Err(TargetIssue::Conflict {
+        first: conflict.0.clone(),
+        second: conflict.1.clone(),
+    })
+}
+
+/// Targets that may be the lowest one allowing `uses`, in ascending order:
+/// every variant of every architecture the requirements name. Only `sm_90`
+/// and later have architecture-specific targets, and only `sm_100` and later
+/// family-specific ones.
+fn candidates(uses: &[&RequirementUse]) -> Vec<Target> {
+    let mut targets: Vec<Target> = uses
+        .iter()
+        .flat_map(|used| used.requirement.targets)
+        .flat_map(|target| {
+            [
+                Target::base(target.sm),
+                Target::family(target.sm),
+                Target::arch(target.sm),
+            ]
+        })
+        .filter(|target| match target.variant {
+            TargetVariant::Base => true,
+            TargetVariant::Family => target.sm >= 100,
+            TargetVariant::Arch => target.sm >= 90,
+        })
+        .collect();
+    targets.sort();
+    targets.dedup();
+    targets
+}
+
+/// Lowest target a requirement allows on its own.
+fn lowest_target(requirement: &Requirement) -> Option<Target> {
+    requirement.targets.iter().min().copied()
+}
@@ -0,0 +1,472 @@
+//! Operand types checked against instruction types.
+//!
+//! The parser only checks the shape of an instruction, so
+//! `add.s32 %rd1, %f2, 3.5` parses although none of its operands is a
+//! 32-bit integer. [`typecheck`] compares every register operand with the
+//! type its instruction gives it, following the type-checking rules of the
+//! PTX ISA:
+//!
+//! - a `.bN` instruction type accepts any register of `N` bits;
+//! - `.uN` and `.sN` accept `.bN`, `.uN` and `.sN` registers;
+//! - `.fN` accepts `.bN` and `.fN` registers;
+//! - `.f16x2` and `.bf16x2` accept `.b32` and `.f16x2` registers;
+//! - `.pred` accepts only predicates.
+//!
+//! `ld`, `ldu`, `st` and `cvt` relax the size rule so that narrow values can
+//! be moved through regular-width registers: their data operands may be wider
+//! than the instruction type, unless the instruction type is a float and the
+//! register is not a bit-size one.
+//!
+//! Immediates must fit the type, see [`ImmValue::fits`](crate::ImmValue::fits),
+//! and braced operands must have as many elements as the `.v2`, `.v4` or
+//! `.v8` modifier says. Only the common arithmetic, logic, data movement and
+//! comparison instructions have rules; the operands of other instructions,
+//! special registers and symbols are not checked.
+//!
+//! ```
+//! use ptx_parser::analysis::{TypeIssue, typecheck};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let source = ".entry k() { .reg .b64 %rd1; .reg .f32 %f2; add.s32 %rd1, %f2, 3.5; }";
+//!     let module = ptx_parser::parse_ptx(source).unwrap();
+//!     let issues = typecheck(&module);
+//!
+//!     assert_eq!(issues.len(), 3);
+//!     assert!(matches!(&issues[0], TypeIssue::Mismatch { found: ".b64", .. }));
+//!     assert_eq!(issues[2].to_string(), "`3.5` does not fit in `.s32`");
+//! });
+//! ```
+
+use thiserror::Error;
+
+use super::registers::RegisterTypes;
+use super::{FunctionRef, functions};
+use crate::Span;
+use crate::diagnostic::Diagnostic;
+use crate::immediate::type_name;
+use crate::operands::{OperandRole, OperandValue, vector_elements};
+use crate::r#type::{DataType, FunctionStatement, GeneralOperand, Instruction, Module, Operand};
+
+/// An operand whose type does not agree with its instruction.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum TypeIssue {
+    #[error("`{register}` is declared `{found}` but used as `{expected}`")]
+    Mismatch {
+        register: String,
+        expected: &'static str,
+        found: &'static str,
+        span: Span,
+    },
+    #[error("`{literal}` does not fit in `{expected}`")]
+    Immediate {
+        literal: String,
+        expected: &'static str,
+        span: Span,
+    },
+    #[error("{}", arity_message(*expected, *found))]
+    VectorArity {
+        expected: usize,
+        found: usize,
+        span: Span,
+    },
+}
+
+fn arity_message(expected: usize, found: usize) -> String {
+    match (expected, found) {
+        (1, _) => format!("expected a scalar operand, found a vector of {found}"),
+        (_, 1) => format!("expected a vector of {expected}, found a scalar operand"),
+        _ => format!("expected a vector of {expected}, found a vector of {found}"),
+    }
+}
+
+impl TypeIssue {
+    /// Span of the offending operand.
+    pub fn span(&self) -> Span {
+        match self {
+            TypeIssue::Mismatch { span, .. }
+            | TypeIssue::Immediate { span, .. }
+            | TypeIssue::VectorArity { span, .. } => *span,
+        }
+    }
+
+    /// Convert this issue into a [`Diagnostic`].
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        let label = match self {
+            TypeIssue::Mismatch { expected, .. } | TypeIssue::Immediate { expected, .. } => {
+                format!("expected `{expected}`")
+            }
+            TypeIssue::VectorArity { expected: 1, .. } => "expected one operand".to_string(),
+            TypeIssue::VectorArity { expected, .. } => format!("expected `.v{expected}`"),
+        };
+        Diagnostic::error(self.to_string(), self.span()).with_label(label)
+    }
+}
+
+/// Check the operands of every function of `module`.
+pub fn typecheck(module: &Module) -> Vec<TypeIssue> {
+    functions(module).flat_map(typecheck_function).collect()
+}
+
+/// Check the operands of `function`. Registers are looked up by name in the
+/// whole function, see [`RegisterTypes`].
+pub fn typecheck_function(function: FunctionRef) -> Vec<TypeIssue> {
+    let mut checker = Checker {
+        types: RegisterTypes::of(function),
+        issues: Vec::new(),
+    };
+    if let Some(body) = function.body() {
+        checker.statements(&body.statements);
+    }
+    checker.issues
+}
+
+/// How a value is interpreted, which decides the registers it may live in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Bit,
+    Unsigned,
+    Signed,
+    Float,
+    /// Two half-precision floats in 32 bits.
+    Packed,
+    Pred,
+}
+
+/// A scalar type: the instruction type of an operand or the type of a
+/// register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Scalar {
+    name: &'static str,
+    kind: Kind,
+    bits: u32,
+}
+
+const SCALARS: &[Scalar] = &[
+    scalar(".b8", Kind::Bit, 8),
+    scalar(".b16", Kind::Bit, 16),
+    scalar(".b32", Kind::Bit, 32),
+    scalar(".b64", Kind::Bit, 64),
+    scalar(".b128", Kind::Bit, 128),
+    scalar(".u8", Kind::Unsigned, 8),
+    scalar(".u16", Kind::Unsigned, 16),
+    scalar(".u32", Kind::Unsigned, 32),
+    scalar(".u64", Kind::Unsigned, 64),
+    scalar(".s8", Kind::Signed, 8),
+    scalar(".s16", Kind::Signed, 16),
+    scalar(".s32", Kind::Signed, 32),
+    scalar(".s64", Kind::Signed, 64),
+    scalar(".f16", Kind::Float, 16),
+    scalar(".bf16", Kind::Float, 16),
+    scalar(".f32", Kind::Float, 32),
+    scalar(".f64", Kind::Float, 64),
+    scalar(".f16x2", Kind::Packed, 32),
+    scalar(".bf16x2", Kind::Packed, 32),
+    scalar(".pred", Kind::Pred, 1),
+];
+
+const fn scalar(name: &'static str, kind: Kind, bits: u32) -> Scalar {
+    Scalar { name, kind, bits }
+}
+
+const U32: Scalar = scalar(".u32", Kind::Unsigned, 32);
+const PRED: Scalar = scalar(".pred", Kind::Pred, 1);
+
+impl Scalar {
+    fn named(name: &str) -> Option<Scalar> {
+        SCALARS.iter().copied().find(|scalar| scalar.name == name)
+    }
+
+    /// The type with this kind and size, preferring the first in
+    /// [`SCALARS`], e.g. `.f16` over `.bf16`.
+    fn sized(kind: Kind, bits: u32) -> Option<Scalar> {
+        SCALARS
+            .iter()
+            .copied()
+            .find(|scalar| scalar.kind == kind && scalar.bits == bits)
+    }
+
+    /// Type of a register declared `ty`; the opaque reference types have
+    /// none.
+    fn of(ty: &DataType) -> Option<Scalar> {
+        Scalar::named(type_name(ty))
+    }
+
+    /// Register type with the bit layout of this type, to check immediates.
+    fn data_type(self) -> DataType {
+        let span = Span::default();
+        match (self.kind, self.bits) {
+            (Kind::Float, 32) => DataType::F32 { span },
+            (Kind::Float, 64) => DataType::F64 { span },
+            (Kind::Float, _) => DataType::F16 { span },
+            (Kind::Pred, _) => DataType::Pred { span },
+            (_, 8) => DataType::B8 { span },
+            (_, 16) => DataType::B16 { span },
+            (_, 32) => DataType::B32 { span },
+            (_, 64) => DataType::B64 { span },
+            _ => DataType::B128 { span },
+        }
+    }
+
+    /// Whether a register of type `register` may hold an operand of this
+    /// instruction type. `relaxed` allows wider registers.
+    fn accepts(self, register: Scalar, relaxed: bool) -> bool {
+        let kinds = match self.kind {
+            Kind::Pred => return register.kind == Kind::Pred,
+            Kind::Bit => register.kind != Kind::Pred,
+            Kind::Unsigned | Kind::Signed => {
+                matches!(register.kind, Kind::Bit | Kind::Unsigned | Kind::Signed)
+            }
+            Kind::Float => matches!(register.kind, Kind::Bit | Kind::Float),
+            Kind::Packed => matches!(register.kind, Kind::Bit | Kind::Packed),
+        };
+        let wider = relaxed
+            && register.bits > self.bits
+            && (!matches!(self.kind, Kind::Float | Kind::Packed) || register.kind == Kind::Bit);
+        kinds && (register.bits == self.bits || wider)
+    }
+}
+
+/// Type of an operand in terms of the type modifiers of its instruction.
+#[derive(Debug, Clone, Copy)]
+enum Expected {
+    /// The type modifier at this index, e.g. 1 for `.s32` in
+    /// `cvt.rn.f32.s32`.
+    Type(usize),
+    /// Twice as wide as the type modifier at this index, for `.wide`.
+    Wide(usize),
+    Fixed(Scalar),
+}
+
+const T: Expected = Expected::Type(0);
+const A: Expected = Expected::Type(1);
+const WIDE: Expected = Expected::Wide(0);
+const U: Expected = Expected::Fixed(U32);
+const P: Expected = Expected::Fixed(PRED);
+
+/// Expected types of the operands of an instruction by their names in the
+/// PTX specification. Operands not listed are not checked.
+struct Rule {
+    operands: &'static [(&'static str, Expected)],
+    /// Number of type modifiers the instruction takes.
+    types: usize,
+    /// Data operands may be wider than the type and follow `.vN`.
+    relaxed: bool,
+    /// Braced operands pack or unpack the type, as in
+    /// `mov.b64 %rd1, {%r1, %r2}`.
+    pack: bool,
+}
+
+const fn rule(operands: &'static [(&'static str, Expected)]) -> Rule {
+    Rule {
+        operands,
+        types: 1,
+        relaxed: false,
+        pack: false,
+    }
+}
+
+fn rule_for(opcode: &str, modifiers: &[String]) -> Option<Rule> {
+    let wide = modifiers.iter().any(|modifier| modifier == ".wide");
+    Some(match opcode {
+        "mul" if wide => rule(&[("d", WIDE), ("a", T), ("b", T)]),
+        "mad" if wide => rule(&[("d", WIDE), ("a", T), ("b", T), ("c", WIDE)]),
+        "add" | "sub" | "mul" | "mad" | "fma" | "div" | "rem" | "min" | "max" | "abs" | "neg"
+        | "and" | "or" | "xor" | "not" | "cnot" | "copysign" | "rcp" | "sqrt" | "rsqrt" | "sin"
+        | "cos" | "lg2" | "ex2" | "tanh" | "addc" | "subc" | "madc" | "mul24" | "mad24" | "sad"
+        | "brev" | "lop3" | "prmt" | "activemask" => {
+            rule(&[("d", T), ("a", T), ("b", T), ("c", T)])
+        }
+        "shl" | "shr" => rule(&[("d", T), ("a", T), ("b", U)]),
+        "shf" => rule(&[("d", T), ("a", T), ("b", T), ("c", U)]),
+        "bfe" => rule(&[("d", T), ("a", T), ("b", U), ("c", U)]),
+        "bfi" => rule(&[("f", T), ("a", T), ("b", T), ("c", U), ("d", U)]),
+        "popc" | "clz" | "bfind" => rule(&[("d", U), ("a", T)]),
+        "cvta" => rule(&[("p", T), ("a", T)]),
+        "setp" => rule(&[("p", P), ("q", P), ("a", T), ("b", T), ("c", P)]),
+        "selp" => rule(&[("d", T), ("a", T), ("b", T), ("c", P)]),
+        "testp" => rule(&[("p", P), ("a", T)]),
+        "set" => Rule {
+            types: 2,
+            ..rule(&[("d", T), ("a", A), ("b", A), ("c", P)])
+        },
+        "slct" => Rule {
+            types: 2,
+            ..rule(&[("d", T), ("a", T), ("b", T), ("c", A)])
+        },
+        "mov" => Rule {
+            pack: true,
+            ..rule(&[("d", T), ("a", T)])
+        },
+        "ld" | "ldu" => Rule {
+            relaxed: true,
+            ..rule(&[("d", T)])
+        },
+        "st" => Rule {
+            relaxed: true,
+            ..rule(&[("b", T)])
+        },
+        "cvt" => Rule {
+            types: 2,
+            relaxed: true,
+            ..rule(&[("d", T), ("a", A), ("b", A)])
+        },
+        "atom" => rule(&[("d", T), ("b", T), ("c", T)]),
+        "red" => rule(&[("b", T)]),
+        "shfl" => rule(&[
+            ("d", T),
+            ("p", P),
+            ("a", T),
+            ("b", U),
+            ("c", U),
+            ("membermask", U),
+        ]),
+        "vote" => rule(&[("d", T), ("a", P), ("membermask", U)]),
+        _ => return None,
+    })
+}
+
+struct Checker {
+    types: RegisterTypes,
+    issues: Vec<TypeIssue>,
+}
+
+impl Checker {
+    fn statements(&mut self, statements: &[FunctionStatement]) {
+        for statement in statements {
+            match statement {
+                FunctionStatement::Instruction { instruction, .. } => self.instruction(instruction),
+                FunctionStatement::Block { statements, .. } => self.statements(statements),
+                _ => {}
+            }
+        }
+    }
+
+    fn instruction(&mut self, instruction: &Instruction) {
+        if let Some(guard) = &instruction.predicate {
+            self.operand(&guard.operand, PRED, false);
+        }
+
+        let modifiers = instruction.inst.modifiers();
+        let Some(rule) = rule_for(instruction.inst.opcode(), &modifiers) else {
+            return;
+        };
+        let types: Vec<Scalar> = modifiers
+            .iter()
+            .filter_map(|modifier| Scalar::named(modifier))
+            .collect();
+        // Types this checker does not model, such as `.e4m3x2`, leave too
+        // few modifiers; vector atomics are not modelled either.
+        let width = modifiers
+            .iter()
+            .find_map(|modifier| modifier.strip_prefix(".v")?.parse::<usize>().ok());
+        if types.len() != rule.types || (width.is_some() && !rule.relaxed) {
+            return;
+        }
+
+        for operand in instruction.inst.operands() {
+            let Some(&(_, expected)) = rule.operands.iter().find(|(name, _)| *name == operand.name)
+            else {
+                continue;
+            };
+            let expected = match expected {
+                Expected::Type(index) => types[index],
+                Expected::Wide(index) => {
+                    let ty = types[index];
+                    match Scalar::sized(ty.kind, ty.bits * 2) {
+                        Some(wide) => wide,
+                        None => continue,
+                    }
+                }
+                Expected::Fixed(ty) => ty,
+            };
+            let relaxed = rule.relaxed && operand.role != OperandRole::Address;
+            let vector = match operand.value {
+                OperandValue::General(GeneralOperand::Single {
+                    operand: single, ..
+                }) => {
+                    if relaxed && let Some(width) = width {
+                        self.issues.push(TypeIssue::VectorArity {
+                            expected: width,
+                            found: 1,
+                            span: single.span(),
+                        });
+                    } else {
+                        self.operand(single, expected, relaxed);
+                    }
+                    continue;
+                }
+                OperandValue::General(GeneralOperand::Vec { operand, .. })
+                | OperandValue::Vector(operand) => operand,
+                OperandValue::Address(_) => continue,
+            };
+
+            let elements = vector_elements(vector);
+            if rule.pack {
+                let element = (elements.len() > 1 && expected.bits % elements.len() as u32 == 0)
+                    .then(|| Scalar::sized(Kind::Bit, expected.bits / elements.len() as u32))
+                    .flatten();
+                match element {
+                    Some(element) => {
+                        for operand in elements {
+                            self.operand(operand, element, false);
+                        }
+                    }
+                    None => self.issues.push(TypeIssue::VectorArity {
+                        expected: 1,
+                        found: elements.len(),
+                        span: vector.span(),
+                    }),
+                }
+                continue;
+            }
+            let arity = if relaxed { width.unwrap_or(1) } else { 1 };
+            if elements.len() != arity {
+                self.issues.push(TypeIssue::VectorArity {
+                    expected: arity,
+                    found: elements.len(),
+                    span: vector.span(),
+                });
+                continue;
+            }
+            for operand in elements {
+                self.operand(operand, expected, relaxed);
+            }
+        }
+    }
+
+    /// Check one register or immediate against `expected`.
+    fn operand(&mut self, operand: &Operand, expected: Scalar, relaxed: bool) {
+        match operand {
+            Operand::Register { operand, span } => {
+                // Special registers such as `%tid.x` are not declared.
+                if operand.component.is_some() {
+                    return;
+                }
+                let Some(found) = self.types.get(&operand.name).and_then(Scalar::of) else {
+                    return;
+                };
+                if !expected.accepts(found, relaxed) {
+                    self.issues.push(TypeIssue::Mismatch {
+                        register: operand.name.clone(),
+                        expected: expected.name,
+                        found: found.name,
+                        span: *span,
+                    });
+                }
+            }
+            Operand::Immediate { operand, span } => {
+                let fits = operand
+                    .value()
+                    .is_ok_and(|value| value.fits(&expected.data_type()));
+                if !fits {
+                    self.issues.push(TypeIssue::Immediate {
+                        literal: operand.value.clone(),
+                        expected: expected.name,
+                        span: *span,
+                    });
+                }
+            }
+            Operand::Symbol { .. } | Operand::SymbolOffset { .. } => {}
+        }
+    }
+}
@@ -0,0 +1,212 @@
+//! Comments attached to the statements and directives of a parsed module.
+//!
+//! The AST itself carries no trivia. [`Comments`] is a side table, keyed by the
+//! spans of [`ModuleDirective`]s and [`FunctionStatement`]s, that records which
+//! comments precede or follow each of them, so that
+//! [`crate::unparse_with_comments`] can re-emit them next to the same nodes.
+//!
+//! Attachment rules:
+//! - A comment on the same line after the last token of a statement or
+//!   directive trails that node.
+//! - Any other comment leads the node that starts at the next token.
+//! - A comment before the closing `}` of a function body or block is kept
+//!   before that brace.
+//! - A comment inside a statement or directive header trails the innermost
+//!   statement or directive containing it.
+//! - A comment after the last token of the module is kept at the module end.
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::{
+    PtxToken, Span, TokenTrivia, Trivia, TriviaKind,
+    r#type::{FunctionBody, FunctionStatement, Module, ModuleDirective},
+};
+
+/// A `//` or `/* */` comment from the source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    pub kind: TriviaKind,
+    /// Full comment text including its delimiters
+    pub text: String,
+    pub span: Span,
+}
+
+/// Comments of a module, keyed by the node they are attached to.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Comments {
+    /// Keyed by the start offset of the following node or closing brace
+    leading: BTreeMap<usize, Vec<Comment>>,
+    /// Keyed by the end offset of the preceding node
+    trailing: BTreeMap<usize, Vec<Comment>>,
+    end: Vec<Comment>,
+}
+
+impl Comments {
+    /// Attach the comments found in `trivia` to the nodes of `module`.
+    ///
+    /// `tokens` and `trivia` must come from [`crate::tokenize_with_trivia`] on
+    /// the same `source` that `module` was parsed from.
+    pub fn collect(
+        source: &str,
+        module: &Module,
+        tokens: &[(PtxToken, Span)],
+        trivia: &[TokenTrivia],
+    ) -> Self {
+        let anchors = Anchors::new(module);
+        let mut comments = Comments::default();
+        let mut carried: Vec<Comment> = Vec::new();
+
+        for (index, token_trivia) in trivia.iter().enumerate() {
+            let leading: Vec<Comment> = carried
+                .drain(..)
+                .chain(comments_in(source, &token_trivia.leading))
+                .collect();
+            let Some((_, span)) = tokens.get(index) else {
+                comments.end.extend(leading);
+                continue;
+            };
+
+            if !leading.is_empty() {
+                if anchors.starts.contains(&span.start) || anchors.closes.contains(&span.start) {
+                    comments
+                        .leading
+                        .entry(span.start)
+                        .or_default()
+                        .extend(leading);
+                } else if let Some(anchor) = anchors.innermost(span.start) {
+                    comments
+                        .trailing
+                        .entry(anchor.end)
+                        .or_default()
+                        .extend(leading);
+                } else {
+                    comments.end.extend(leading);
+                }
+            }
+
+            let trailing = comments_in(source, &token_trivia.trailing);
+            if anchors.ends.contains(&span.end) {
+                let trailing: Vec<Comment> = trailing.collect();
+                if !trailing.is_empty() {
+                    comments
+                        .trailing
+                        .entry(span.end)
+                        .or_default()
+                        .extend(trailing);
+                }
+            } else {
+                // Not the end of a node: attach to whatever the next token starts.
+                carried.extend(trailing);
+            }
+        }
+
+        comments
+    }
+
+    /// Comments before the node or closing brace starting at `offset`.
+    pub fn leading(&self, offset: usize) -> &[Comment] {
+        self.leading.get(&offset).map_or(&[], Vec::as_slice)
+    }
+
+    /// Comments after the node ending at `offset`.
+    pub fn trailing(&self, offset: usize) -> &[Comment] {
+        self.trailing.get(&offset).map_or(&[], Vec::as_slice)
+    }
+
+    /// Comments after the last token of the module.
+    pub fn end(&self) -> &[Comment] {
+        &self.end
+    }
+
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.leading.is_empty() && self.trailing.is_empty() && self.end.is_empty()
+    }
+
+    /// All comments, in source order.
+    pub fn iter(&self) -> impl Iterator<Item = &Comment> {
+        let mut all: Vec<&Comment> = self
+            .leading
+            .values()
+            .chain(self.trailing.values())
+            .flatten()
+            .chain(&self.end)
+            .collect();
+        all.sort_by_key(|comment| comment.span.start);
+        all.into_iter()
+    }
+}
+
+fn comments_in<'a>(source: &'a str, trivia: &'a [Trivia]) -> impl Iterator<Item = Comment> + 'a {
+    trivia
+        .iter()
+        .filter(|piece| piece.is_comment())
+        .map(|piece| Comment {
+            kind: piece.kind,
+            text: source[piece.span.start..piece.span.end].to_string(),
+            span: piece.span,
+        })
+}
+
+/// Spans of the nodes comments can be attached to.
+struct Anchors {
+    starts: HashSet<usize>,
+    ends: HashSet<usize>,
+    /// Start offsets of the closing braces of function bodies and blocks
+    closes: HashSet<usize>,
+    /// All anchor spans, sorted by start
+    spans: Vec<Span>,
+}
+
+impl Anchors {
+    fn new(module: &Module) -> Self {
+        let mut anchors = Anchors {
+            starts: HashSet::new(),
+            ends: HashSet::new(),
+            closes: HashSet::new(),
+            spans: Vec::new(),
+        };
+        for directive in &module.directives {
+            anchors.add(directive.span());
+            let body = match directive {
+                ModuleDirective::EntryFunction { directive, .. } => directive.body.as_ref(),
+                ModuleDirective::FuncFunction { directive, .. } => directive.body.as_ref(),
+                _ => None,
+            };
+            if let Some(FunctionBody { statements, span }) = body {
+                anchors.add_statements(statements, *span);
+            }
+        }
+        anchors.spans.sort_by_key(|span| span.start);
+        anchors
+    }
+
+    fn add(&mut self, span: Span) {
+        self.starts.insert(span.start);
+        self.ends.insert(span.end);
+        self.spans.push(span);
+    }
+
+    fn add_statements(&mut self, statements: &[FunctionStatement], braces: Span) {
+        self.closes.insert(braces.end.saturating_sub(1));
+        for statement in statements {
+            self.add(statement.span());
+            if let FunctionStatement::Block { statements, span } = statement {
+                self.add_statements(statements, *span);
+            }
+        }
+    }
+
+    /// Innermost anchor containing `offset`.
+    fn innermost(&self, offset: usize) -> Option<Span> {
+        let candidates = self.spans.partition_point(|span| span.start <= offset);
+        self.spans[..candidates]
+            .iter()
+            .rev()
+            .find(|span| offset < span.end)
+            .copied()
+    }
+}
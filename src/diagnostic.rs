@@ -0,0 +1,339 @@
+//! Source locations and human-readable error reports.
+//!
+//! [`SourceMap`] converts byte [`Span`]s into line/column positions, and
+//! [`Diagnostic`] renders an error against the source in the style of rustc:
+//!
+//! ```text
+//! error: expected `Comma`, found Register("%r2")
+//!  --> kernel.ptx:7:17
+//!   |
+//! 7 |     mov.u32 %r1 %r2;
+//!   |                 ^^^ unexpected token
+//!   |
+//!   = note: closest candidate is `mov::section_0::MovType`
+//! ```
+
+use std::fmt::Write;
+
+use crate::{LexError, LexErrorKind, ParseErrorKind, PtxParseError, Span};
+
+/// Zero-based line and column of a position in the source.
+///
+/// The unit of `column` depends on the [`SourceMap`] method that produced it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct LineCol {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Line index over a source string.
+///
+/// Building the index is a single pass over the source; every lookup after
+/// that is a binary search over the line start offsets.
+#[derive(Debug, Clone)]
+pub struct SourceMap<'a> {
+    source: &'a str,
+    /// Byte offset of the first character of each line.
+    line_starts: Vec<usize>,
+}
+
+impl<'a> SourceMap<'a> {
+    pub fn new(source: &'a str) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(source.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+        Self {
+            source,
+            line_starts,
+        }
+    }
+
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    /// Line containing the byte `offset`. Offsets past the end map to the last line.
+    pub fn line_of(&self, offset: usize) -> usize {
+        let offset = offset.min(self.source.len());
+        self.line_starts.partition_point(|&start| start <= offset) - 1
+    }
+
+    /// Byte range of `line`, excluding the line terminator.
+    pub fn line_span(&self, line: usize) -> Option<Span> {
+        let start = *self.line_starts.get(line)?;
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .map_or(self.source.len(), |next| next - 1);
+        let end = if self.source[start..end].ends_with('\r') {
+            end - 1
+        } else {
+            end
+        };
+        Some(Span::new(start, end))
+    }
+
+    /// Text of `line`, excluding the line terminator.
+    pub fn line_text(&self, line: usize) -> Option<&'a str> {
+        self.line_span(line)
+            .map(|span| &self.source[span.start..span.end])
+    }
+
+    /// Line and UTF-8 byte column of `offset`.
+    pub fn line_col(&self, offset: usize) -> LineCol {
+        let offset = self.floor_char_boundary(offset);
+        let line = self.line_of(offset);
+        LineCol {
+            line,
+            column: offset - self.line_starts[line],
+        }
+    }
+
+    /// Line and UTF-16 code unit column of `offset`, as used by editors and LSP.
+    pub fn line_col_utf16(&self, offset: usize) -> LineCol {
+        let LineCol { line, column } = self.line_col(offset);
+        let start = self.line_starts[line];
+        LineCol {
+            line,
+            column: self.source[start..start + column]
+                .chars()
+                .map(char::len_utf16)
+                .sum(),
+        }
+    }
+
+    /// Byte offset of a line and UTF-8 byte column, if it lies within the source.
+    pub fn offset(&self, position: LineCol) -> Option<usize> {
+        let span = self.line_span(position.line)?;
+        let offset = span.start + position.column;
+        (offset <= span.end && self.source.is_char_boundary(offset)).then_some(offset)
+    }
+
+    /// Byte offset of a line and UTF-16 code unit column, if it lies within the source.
+    pub fn offset_utf16(&self, position: LineCol) -> Option<usize> {
+        let span = self.line_span(position.line)?;
+        let mut units = 0;
+        for (index, ch) in self.source[span.start..span.end].char_indices() {
+            if units == position.column {
+                return Some(span.start + index);
+            }
+            units += ch.len_utf16();
+        }
+        (units == position.column).then_some(span.end)
+    }
+
+    fn floor_char_boundary(&self, offset: usize) -> usize {
+        let mut offset = offset.min(self.source.len());
+        while !self.source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        offset
+    }
+}
+
+/// How serious a [`Diagnostic`] is.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Severity {
+    #[default]
+    Error,
+    Warning,
+}
+
+/// An error report that can be rendered against its source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub span: Span,
+    /// Text printed next to the caret underline.
+    pub label: Option<String>,
+    pub notes: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn error(message: impl Into<String>, span: Span) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+            span,
+            label: None,
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn warning(message: impl Into<String>, span: Span) -> Self {
+        Self {
+            severity: Severity::Warning,
+            ..Self::error(message, span)
+        }
+    }
+
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.notes.push(note.into());
+        self
+    }
+
+    /// Render the diagnostic with the offending line and a caret underline.
+    ///
+    /// `name` is shown in the location line and defaults to `<input>`. Spans
+    /// covering several lines are underlined up to the end of their first line.
+    pub fn render(&self, map: &SourceMap, name: Option<&str>) -> String {
+        let position = map.line_col(self.span.start);
+        let line_span = map
+            .line_span(position.line)
+            .unwrap_or(Span::new(self.span.start, self.span.start));
+        let text = &map.source()[line_span.start..line_span.end];
+
+        // A span starting on the line terminator is underlined just past the text.
+        let start = position.column.min(text.len());
+        let end = (self.span.end.saturating_sub(line_span.start)).clamp(start, text.len());
+        let end = (start..=end)
+            .rev()
+            .find(|&end| text.is_char_boundary(end))
+            .unwrap_or(start);
+        let indent = display_width(&text[..start]);
+        let carets = display_width(&text[start..end]).max(1);
+
+        let line_number = (position.line + 1).to_string();
+        let gutter = " ".repeat(line_number.len());
+        let column = text[..start].chars().count() + 1;
+
+        let mut output = String::new();
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        let _ = writeln!(output, "{severity}: {}", self.message);
+        let _ = writeln!(
+            output,
+            "{gutter}--> {}:{}:{column}",
+            name.unwrap_or("<input>"),
+            line_number
+        );
+        let _ = writeln!(output, "{gutter} |");
+        let _ = writeln!(output, "{line_number} | {}", text.replace('\t', "    "));
+        let _ = write!(
+            output,
+            "{gutter} | {}{}",
+            " ".repeat(indent),
+            "^".repeat(carets)
+        );
+        if let Some(label) = &self.label {
+            let _ = write!(output, " {label}");
+        }
+        output.push('\n');
+        if !self.notes.is_empty() {
+            let _ = writeln!(output, "{gutter} |");
+            for note in &self.notes {
+                let _ = writeln!(output, "{gutter} = note: {note}");
+            }
+        }
+        output
+    }
+}
+
+/// Width of `text` as printed by [`Diagnostic::render`], which expands tabs to
+/// four spaces.
+fn display_width(text: &str) -> usize {
+    text.chars().map(|ch| if ch == '\t' { 4 } else { 1 }).sum()
+}
+
+fn expected_list(expected: &[String]) -> String {
+    let quoted: Vec<_> = expected.iter().map(|item| format!("`{item}`")).collect();
+    match quoted.as_slice() {
+        [] => "something else".to_string(),
+        [single] => single.clone(),
+        _ => format!("one of {}", quoted.join(", ")),
+    }
+}
+
+impl ParseErrorKind {
+    /// Main message and underline label for this error kind.
+    fn message_and_label(&self) -> (String, String) {
+        match self {
+            ParseErrorKind::UnexpectedToken { expected, found } => (
+                format!("expected {}, found {found}", expected_list(expected)),
+                "unexpected token".into(),
+            ),
+            ParseErrorKind::UnexpectedEof => {
+                ("unexpected end of input".into(), "input ends here".into())
+            }
+            ParseErrorKind::InvalidLiteral(message) => (
+                format!("invalid literal: {message}"),
+                "invalid literal".into(),
+            ),
+            ParseErrorKind::InvalidModeForTokenMethod => (self.to_string(), "here".into()),
+            ParseErrorKind::Lex { kind, .. } => (kind.to_string(), "here".into()),
+            ParseErrorKind::NoMatchingInstruction { opcode, cause, .. } => (
+                format!("no matching format for `{opcode}`"),
+                cause.message_and_label().0,
+            ),
+        }
+    }
+}
+
+impl PtxParseError {
+    /// Convert this error into a [`Diagnostic`].
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        if let ParseErrorKind::Lex { kind, text } = &self.kind {
+            return lex_diagnostic(kind, text, self.span);
+        }
+        let (message, label) = self.kind.message_and_label();
+        let diagnostic = Diagnostic::error(message, self.span).with_label(label);
+        match &self.kind {
+            ParseErrorKind::NoMatchingInstruction { candidate, .. } => {
+                diagnostic.with_note(format!("closest candidate is `{candidate}`"))
+            }
+            _ => diagnostic,
+        }
+    }
+
+    /// Line and UTF-8 byte column where this error starts.
+    pub fn line_col(&self, map: &SourceMap) -> LineCol {
+        map.line_col(self.span.start)
+    }
+
+    /// Render this error against `source`; see [`Diagnostic::render`].
+    pub fn render(&self, source: &str, name: Option<&str>) -> String {
+        self.to_diagnostic().render(&SourceMap::new(source), name)
+    }
+}
+
+impl LexError {
+    /// Convert this error into a [`Diagnostic`].
+    pub fn to_diagnostic(&self) -> Diagnostic {
+        lex_diagnostic(&self.kind, &self.text, self.span)
+    }
+
+    /// Render this error against `source`; see [`Diagnostic::render`].
+    pub fn render(&self, source: &str, name: Option<&str>) -> String {
+        self.to_diagnostic().render(&SourceMap::new(source), name)
+    }
+}
+
+fn lex_diagnostic(kind: &LexErrorKind, text: &str, span: Span) -> Diagnostic {
+    match kind {
+        LexErrorKind::UnterminatedBlockComment => Diagnostic::error(kind.to_string(), span)
+            .with_label("comment is never closed")
+            .with_note("block comments end with `*/`"),
+        LexErrorKind::UnterminatedString => {
+            Diagnostic::error(kind.to_string(), span).with_label("string is never closed")
+        }
+        LexErrorKind::BadHexFloatWidth { .. } => Diagnostic::error(kind.to_string(), span)
+            .with_label("not a valid hex float")
+            .with_note("`0f` takes 8 hex digits (.f32) and `0d` takes 16 (.f64)"),
+        LexErrorKind::StrayCharacter => {
+            Diagnostic::error(format!("unexpected character `{text}`"), span)
+                .with_label("not a valid PTX token")
+        }
+    }
+}
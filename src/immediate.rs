@@ -0,0 +1,282 @@
+//! Decoding and construction of PTX literal values.
+//!
+//! [`Immediate`] keeps a literal exactly as written. [`Immediate::value`]
+//! decodes it into an [`ImmValue`], [`ImmValue::fits`] checks it against an
+//! operand type, and [`Immediate::from_value`] builds a literal with the
+//! canonical spelling of a value.
+
+use thiserror::Error;
+
+use crate::{
+    Span,
+    r#type::{DataType, Immediate},
+};
+
+/// Base an integer literal is written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Radix {
+    /// `0b1010`
+    Binary,
+    /// `0777`
+    Octal,
+    /// `42`
+    Decimal,
+    /// `0x2A`
+    Hexadecimal,
+}
+
+/// Decoded value of an [`Immediate`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ImmValue {
+    /// Integer literal. `unsigned` is set by the `U` suffix.
+    Int {
+        value: i128,
+        unsigned: bool,
+        radix: Radix,
+    },
+    /// Single-precision hex float, `0f3F800000`.
+    F32(f32),
+    /// Double-precision value: a hex float `0d3FF0000000000000` or a decimal
+    /// float such as `1.5e10`, which PTX reads as a double.
+    F64(f64),
+}
+
+/// Error produced when decoding or checking an immediate.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ImmediateError {
+    #[error("malformed literal `{0}`")]
+    Malformed(String),
+    /// Integer literals are at most 64 bits wide.
+    #[error("literal `{0}` does not fit in 64 bits")]
+    TooLarge(String),
+    #[error("literal `{literal}` is out of range for `{ty}`")]
+    OutOfRange { literal: String, ty: &'static str },
+}
+
+impl Immediate {
+    /// Decode the literal.
+    ///
+    /// Literals produced by the parser always decode, except integers wider
+    /// than 64 bits; an error is also returned if `value` was set to
+    /// something that is not a PTX literal.
+    pub fn value(&self) -> Result<ImmValue, ImmediateError> {
+        decode(&self.value)
+    }
+
+    /// Decode the literal and check that it is in range for `ty`.
+    pub fn value_for(&self, ty: &DataType) -> Result<ImmValue, ImmediateError> {
+        let value = self.value()?;
+        if value.fits(ty) {
+            Ok(value)
+        } else {
+            Err(ImmediateError::OutOfRange {
+                literal: self.value.clone(),
+                ty: type_name(ty),
+            })
+        }
+    }
+
+    /// Build an immediate spelling `value` canonically: integers keep their
+    /// radix and `U` suffix, floats are written as exact hex floats.
+    ///
+    /// The span is empty; the result is meant for synthesized code.
+    pub fn from_value(value: ImmValue) -> Self {
+        Immediate {
+            value: value.to_literal(),
+            span: Span::default(),
+        }
+    }
+}
+
+impl ImmValue {
+    /// Canonical PTX spelling of the value.
+    pub fn to_literal(&self) -> String {
+        match *self {
+            ImmValue::Int {
+                value,
+                unsigned,
+                radix,
+            } => {
+                let sign = if value < 0 { "-" } else { "" };
+                let magnitude = value.unsigned_abs();
+                let digits = match radix {
+                    Radix::Binary => format!("0b{:b}", magnitude),
+                    Radix::Octal if magnitude == 0 => "00".to_string(),
+                    Radix::Octal => format!("0{:o}", magnitude),
+                    Radix::Decimal => magnitude.to_string(),
+                    Radix::Hexadecimal => format!("0x{:X}", magnitude),
+                };
+                let suffix = if unsigned { "U" } else { "" };
+                format!("{sign}{digits}{suffix}")
+            }
+            ImmValue::F32(value) => format!("0f{:08X}", value.to_bits()),
+            ImmValue::F64(value) => format!("0d{:016X}", value.to_bits()),
+        }
+    }
+
+    /// Whether the value can be used as an operand of type `ty`.
+    ///
+    /// Integers fit an `N`-bit type if they are representable as `N` bits
+    /// read either signed or unsigned, as `ptxas` accepts `mov.u32 %r1, -1`.
+    /// Floats fit `.f32` and `.f64`; a double fits `.f32` if it does not
+    /// overflow it. Integers are accepted for float types and `.pred` takes
+    /// `0`, `1` and `-1`, which nvcc writes for true.
+    pub fn fits(&self, ty: &DataType) -> bool {
+        let bits = match ty {
+            DataType::U8 { .. } | DataType::S8 { .. } | DataType::B8 { .. } => 8,
+            DataType::U16 { .. }
+            | DataType::S16 { .. }
+            | DataType::B16 { .. }
+            | DataType::F16 { .. } => 16,
+            DataType::U32 { .. }
+            | DataType::S32 { .. }
+            | DataType::B32 { .. }
+            | DataType::F16x2 { .. } => 32,
+            DataType::U64 { .. } | DataType::S64 { .. } | DataType::B64 { .. } => 64,
+            DataType::B128 { .. } => 128,
+            DataType::F32 { .. } => {
+                return match *self {
+                    ImmValue::Int { value, .. } => int_fits(value, 64),
+                    ImmValue::F32(_) => true,
+                    ImmValue::F64(value) => {
+                        !value.is_finite() || value.abs() <= f64::from(f32::MAX)
+                    }
+                };
+            }
+            DataType::F64 { .. } => {
+                return match *self {
+                    ImmValue::Int { value, .. } => int_fits(value, 64),
+                    ImmValue::F32(_) | ImmValue::F64(_) => true,
+                };
+            }
+            DataType::Pred { .. } => {
+                return matches!(*self, ImmValue::Int { value: -1..=1, .. });
+            }
+            DataType::TexRef { .. } | DataType::SamplerRef { .. } | DataType::SurfRef { .. } => {
+                return false;
+            }
+        };
+        match *self {
+            ImmValue::Int { value, .. } => int_fits(value, bits),
+            ImmValue::F32(_) | ImmValue::F64(_) => false,
+        }
+    }
+}
+
+/// Whether `value` is representable in `bits` bits, signed or unsigned.
+fn int_fits(value: i128, bits: u32) -> bool {
+    if bits >= 128 {
+        return true;
+    }
+    let min = -(1i128 << (bits - 1));
+    let max = (1i128 << bits) - 1;
+    (min..=max).contains(&value)
+}
+
+fn decode(literal: &str) -> Result<ImmValue, ImmediateError> {
+    let malformed = || ImmediateError::Malformed(literal.to_string());
+    let (negative, body) = match literal.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, literal),
+    };
+
+    if let Some(digits) = strip_prefix_ci(body, "0f") {
+        if digits.len() != 8 {
+            return Err(malformed());
+        }
+        let value = f32::from_bits(u32::from_str_radix(digits, 16).map_err(|_| malformed())?);
+        return Ok(ImmValue::F32(if negative { -value } else { value }));
+    }
+    if let Some(digits) = strip_prefix_ci(body, "0d") {
+        if digits.len() != 16 {
+            return Err(malformed());
+        }
+        let value = f64::from_bits(u64::from_str_radix(digits, 16).map_err(|_| malformed())?);
+        return Ok(ImmValue::F64(if negative { -value } else { value }));
+    }
+
+    let (unsigned, body) = match body.strip_suffix(['U', 'u']) {
+        Some(rest) => (true, rest),
+        None => (false, body),
+    };
+    let (radix, digits) = if let Some(digits) = strip_prefix_ci(body, "0x") {
+        (Radix::Hexadecimal, digits)
+    } else if let Some(digits) = strip_prefix_ci(body, "0b") {
+        (Radix::Binary, digits)
+    } else if body.len() > 1 && body.starts_with('0') && body.bytes().all(|b| b.is_ascii_digit()) {
+        (Radix::Octal, &body[1..])
+    } else {
+        (Radix::Decimal, body)
+    };
+
+    let base = match radix {
+        Radix::Binary => 2,
+        Radix::Octal => 8,
+        Radix::Decimal => 10,
+        Radix::Hexadecimal => 16,
+    };
+    let is_digit = |ch: char| ch.is_digit(base);
+    if !digits.is_empty() && digits.chars().all(is_digit) {
+        let magnitude = u128::from_str_radix(digits, base)
+            .map_err(|_| ImmediateError::TooLarge(literal.into()))?;
+        if magnitude > u128::from(u64::MAX) {
+            return Err(ImmediateError::TooLarge(literal.to_string()));
+        }
+        let value = magnitude as i128;
+        return Ok(ImmValue::Int {
+            value: if negative { -value } else { value },
+            unsigned,
+            radix,
+        });
+    }
+
+    // Decimal floats; the `U` suffix is only valid on integers.
+    if unsigned || radix != Radix::Decimal {
+        return Err(malformed());
+    }
+    let is_float = body
+        .chars()
+        .next()
+        .is_some_and(|ch| ch.is_ascii_digit() || ch == '.')
+        && body
+            .chars()
+            .all(|ch| ch.is_ascii_digit() || matches!(ch, '.' | 'e' | 'E' | '+' | '-'));
+    if !is_float {
+        return Err(malformed());
+    }
+    let value: f64 = body.parse().map_err(|_| malformed())?;
+    Ok(ImmValue::F64(if negative { -value } else { value }))
+}
+
+/// `text` without `prefix`, compared ASCII case-insensitively.
+fn strip_prefix_ci<'a>(text: &'a str, prefix: &str) -> Option<&'a str> {
+    let head = text.get(..prefix.len())?;
+    head.eq_ignore_ascii_case(prefix)
+        .then(|| &text[prefix.len()..])
+}
+
+pub(crate) fn type_name(ty: &DataType) -> &'static str {
+    match ty {
+        DataType::U8 { .. } => ".u8",
+        DataType::U16 { .. } => ".u16",
+        DataType::U32 { .. } => ".u32",
+        DataType::U64 { .. } => ".u64",
+        DataType::S8 { .. } => ".s8",
+        DataType::S16 { .. } => ".s16",
+        DataType::S32 { .. } => ".s32",
+        DataType::S64 { .. } => ".s64",
+        DataType::F16 { .. } => ".f16",
+        DataType::F16x2 { .. } => ".f16x2",
+        DataType::F32 { .. } => ".f32",
+        DataType::F64 { .. } => ".f64",
+        DataType::B8 { .. } => ".b8",
+        DataType::B16 { .. } => ".b16",
+        DataType::B32 { .. } => ".b32",
+        DataType::B64 { .. } => ".b64",
+        DataType::B128 { .. } => ".b128",
+        DataType::Pred { .. } => ".pred",
+        DataType::TexRef { .. } => ".texref",
+        DataType::SamplerRef { .. } => ".samplerref",
+        DataType::SurfRef { .. } => ".surfref",
+    }
+}
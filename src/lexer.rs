@@ -1,5 +1,6 @@
 use crate::parser::Span;
 use logos::Logos;
+use thiserror::Error;
 
 /// PTX specification token types for lexical analysis.
 ///
@@ -62,10 +63,10 @@ pub enum PtxToken {
     #[token("@")]
     At,
     // Single-precision hex float: 0f12345678
-    #[regex(r"0[fF][0-9a-fA-F]{8}", |lex| lex.slice().to_string())]
+    #[regex(r"0[fF][0-9a-fA-F]*", |lex| hex_float(lex, 8))]
     HexFloatSingle(String),
     // Double-precision hex float: 0d1234567890abcdef
-    #[regex(r"0[dD][0-9a-fA-F]{16}", |lex| lex.slice().to_string())]
+    #[regex(r"0[dD][0-9a-fA-F]*", |lex| hex_float(lex, 16))]
     HexFloatDouble(String),
     #[regex(r"0[xX][0-9a-fA-F]+U?", |lex| lex.slice().to_string())]
     HexInteger(String),
@@ -91,11 +92,40 @@ pub enum PtxToken {
     Space,
     #[token("\u{0001}")]
     Newline,
+    /// Comment emitted by the unparser only, holding the full comment text
+    /// including its `//` or `/* */` delimiters.
+    Comment(String),
     #[regex(r#""([^"\\]|\\.)*""#, |lex| {
         let slice = lex.slice();
         slice[1..slice.len() - 1].to_string()
     })]
     StringLiteral(String),
+    /// Input that could not be lexed, emitted by [`tokenize_lossy`] only.
+    ///
+    /// The patterns below never produce a token; they exist so that unterminated
+    /// comments and strings are reported as such instead of as stray characters.
+    #[regex(r"/\*([^*]|\*+[^*/])*\**", |_| unterminated(LexErrorKind::UnterminatedBlockComment))]
+    #[regex(r#""([^"\\]|\\.)*\\?"#, |_| unterminated(LexErrorKind::UnterminatedString))]
+    Error(String),
+}
+
+/// Accept a hex float literal only if it has exactly `digits` hex digits.
+fn hex_float(lex: &logos::Lexer<PtxToken>, digits: usize) -> Result<String, LexError> {
+    let slice = lex.slice();
+    let found = slice.len() - 2;
+    if found == digits {
+        Ok(slice.to_string())
+    } else {
+        Err(LexErrorKind::BadHexFloatWidth {
+            expected: digits,
+            found,
+        }
+        .into())
+    }
+}
+
+fn unterminated(kind: LexErrorKind) -> Result<String, LexError> {
+    Err(kind.into())
 }
 
 impl PtxToken {
@@ -112,7 +142,9 @@ impl PtxToken {
             | PtxToken::HexFloatSingle(s)
             | PtxToken::HexFloatDouble(s)
             | PtxToken::Register(s)
-            | PtxToken::StringLiteral(s) => s.as_str(),
+            | PtxToken::StringLiteral(s)
+            | PtxToken::Error(s)
+            | PtxToken::Comment(s) => s.as_str(),
             PtxToken::DoubleColon => "::",
             PtxToken::Dot => ".",
             PtxToken::Comma => ",",
@@ -155,7 +187,9 @@ impl PtxToken {
             | PtxToken::HexFloatSingle(s)
             | PtxToken::HexFloatDouble(s)
             | PtxToken::Register(s)
-            | PtxToken::StringLiteral(s) => s.len(),
+            | PtxToken::StringLiteral(s)
+            | PtxToken::Error(s)
+            | PtxToken::Comment(s) => s.len(),
             PtxToken::DoubleColon => 2,
             PtxToken::Dot
             | PtxToken::Comma
@@ -187,16 +221,49 @@ impl PtxToken {
     }
 }
 
+/// The kind of problem found while lexing.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Error)]
+pub enum LexErrorKind {
+    #[error("unterminated block comment")]
+    UnterminatedBlockComment,
+    #[error("unterminated string literal")]
+    UnterminatedString,
+    #[error("hex float literal needs {expected} hex digits, found {found}")]
+    BadHexFloatWidth { expected: usize, found: usize },
+    #[default]
+    #[error("unexpected character")]
+    StrayCharacter,
+}
+
 /// Lexical analysis error type.
-#[derive(Debug, Clone, PartialEq, Default)]
+///
+/// Displays the kind and the offending text; [`LexError::render`] adds the
+/// location.
+#[derive(Debug, Clone, PartialEq, Default, Error)]
+#[error("{kind}: `{text}`")]
 pub struct LexError {
+    pub kind: LexErrorKind,
     /// The span in the source code where the error occurred
     pub span: Span,
+    /// The offending source text
+    pub text: String,
 }
 
 impl From<Span> for LexError {
     fn from(span: Span) -> Self {
-        LexError { span }
+        LexError {
+            span,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<LexErrorKind> for LexError {
+    fn from(kind: LexErrorKind) -> Self {
+        LexError {
+            kind,
+            ..Default::default()
+        }
     }
 }
 
@@ -229,13 +296,162 @@ pub fn tokenize(source: &str) -> Result<Vec<(PtxToken, Span)>, LexError> {
     while let Some(item) = lexer.next() {
         match item {
             Ok(token) => tokens.push((token, Span::from(lexer.span()))),
-            Err(_) => {
-                return Err(LexError {
-                    span: Span::from(lexer.span()),
-                });
-            }
+            Err(err) => return Err(locate(err, &lexer)),
         }
     }
 
     Ok(tokens)
 }
+
+/// Tokenize a PTX source string without stopping at lexing errors.
+///
+/// Every piece of input that cannot be lexed becomes a [`PtxToken::Error`]
+/// token holding its source text, and the corresponding [`LexError`] is
+/// returned alongside the tokens so that a recovering parser can skip the
+/// affected statements and still report the original problem.
+///
+/// # Example
+///
+/// ```
+/// use ptx_parser::{PtxToken, tokenize_lossy};
+///
+/// let (tokens, errors) = tokenize_lossy("mov.f32 %f1, 0f3F80;");
+/// assert_eq!(errors.len(), 1);
+/// assert!(matches!(tokens[5].0, PtxToken::Error(_)));
+/// ```
+pub fn tokenize_lossy(source: &str) -> (Vec<(PtxToken, Span)>, Vec<LexError>) {
+    let mut lexer = PtxToken::lexer(source);
+    let mut tokens = Vec::new();
+    let mut errors = Vec::new();
+
+    while let Some(item) = lexer.next() {
+        let span = Span::from(lexer.span());
+        match item {
+            Ok(token) => tokens.push((token, span)),
+            Err(err) => {
+                let err = locate(err, &lexer);
+                tokens.push((PtxToken::Error(err.text.clone()), span));
+                errors.push(err);
+            }
+        }
+    }
+
+    (tokens, errors)
+}
+
+/// Kind of a [`Trivia`] piece.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TriviaKind {
+    Whitespace,
+    LineComment,
+    BlockComment,
+}
+
+/// Source text between tokens that the lexer does not turn into tokens.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trivia {
+    pub kind: TriviaKind,
+    pub span: Span,
+}
+
+impl Trivia {
+    pub fn is_comment(&self) -> bool {
+        self.kind != TriviaKind::Whitespace
+    }
+}
+
+/// Trivia surrounding a single token.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TokenTrivia {
+    /// Trivia before the token that is not trailing trivia of the previous token.
+    pub leading: Vec<Trivia>,
+    /// Trivia after the token up to, but excluding, the next line break.
+    pub trailing: Vec<Trivia>,
+}
+
+/// Tokenize a PTX source string, keeping whitespace and comments as trivia.
+///
+/// The returned trivia vector has one entry per token plus a final entry whose
+/// `leading` trivia is everything after the last token. Concatenating, for
+/// each entry, the leading trivia, the token and the trailing trivia
+/// reproduces `source` exactly.
+///
+/// # Example
+///
+/// ```
+/// use ptx_parser::{TriviaKind, tokenize_with_trivia};
+///
+/// let (tokens, trivia) = tokenize_with_trivia("// header\nret; // done\n").unwrap();
+/// assert_eq!(trivia.len(), tokens.len() + 1);
+/// assert_eq!(trivia[0].leading[0].kind, TriviaKind::LineComment);
+/// assert_eq!(trivia[1].trailing[1].kind, TriviaKind::LineComment);
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn tokenize_with_trivia(
+    source: &str,
+) -> Result<(Vec<(PtxToken, Span)>, Vec<TokenTrivia>), LexError> {
+    let tokens = tokenize(source)?;
+    let mut trivia: Vec<TokenTrivia> = Vec::with_capacity(tokens.len() + 1);
+    let mut gap_start = 0;
+
+    let token_spans = tokens.iter().map(|(_, span)| *span);
+    for span in token_spans.chain(std::iter::once(Span::new(source.len(), source.len()))) {
+        let mut leading = split_trivia(source, gap_start, span.start);
+        if let Some(previous) = trivia.last_mut() {
+            // Trailing trivia of the previous token ends at the first line break.
+            let line_break = leading
+                .iter()
+                .position(|piece| {
+                    piece.kind == TriviaKind::Whitespace
+                        && source[piece.span.start..piece.span.end].contains('\n')
+                })
+                .unwrap_or(leading.len());
+            previous.trailing = leading.drain(..line_break).collect();
+        }
+        trivia.push(TokenTrivia {
+            leading,
+            trailing: Vec::new(),
+        });
+        gap_start = span.end;
+    }
+
+    Ok((tokens, trivia))
+}
+
+/// Split the text between two tokens into whitespace and comment pieces.
+fn split_trivia(source: &str, start: usize, end: usize) -> Vec<Trivia> {
+    let mut pieces = Vec::new();
+    let mut offset = start;
+    while offset < end {
+        let rest = &source[offset..end];
+        let (kind, len) = if rest.starts_with("//") {
+            (
+                TriviaKind::LineComment,
+                rest.find('\n').unwrap_or(rest.len()),
+            )
+        } else if let Some(body) = rest.strip_prefix("/*") {
+            let close = body.find("*/").map_or(rest.len(), |index| index + 4);
+            (TriviaKind::BlockComment, close)
+        } else {
+            let len = rest
+                .find(|ch: char| !ch.is_ascii_whitespace())
+                .unwrap_or(rest.len());
+            (TriviaKind::Whitespace, len)
+        };
+        pieces.push(Trivia {
+            kind,
+            span: Span::new(offset, offset + len),
+        });
+        offset += len;
+    }
+    pieces
+}
+
+/// Fill in the span and text of an error produced at the lexer's current token.
+fn locate(err: LexError, lexer: &logos::Lexer<PtxToken>) -> LexError {
+    LexError {
+        span: Span::from(lexer.span()),
+        text: lexer.slice().to_string(),
+        ..err
+    }
+}
@@ -63,6 +63,27 @@ pub mod r#type;
 // Pretty-print module - for displaying AST as tree (public)
 pub mod pretty_print;
 
+// Source locations and rendered error reports (public)
+pub mod diagnostic;
+
+// Comments attached to parsed nodes, for lossless rewriting (public)
+pub mod comments;
+
+// AST traversal (public)
+pub mod visit;
+
+// Operand roles, defs and uses of instructions (public)
+pub mod operands;
+
+// Opcode and modifier metadata of instructions (public)
+pub mod metadata;
+
+// Decoding and construction of literal values (public)
+pub mod immediate;
+
+// Semantic analyses over parsed modules (public)
+pub mod analysis;
+
 // Re-export derive macro for the `Spanned` trait so downstream crates can use it.
 pub use ptx_90_parser_span_derive::Spanned;
 
@@ -76,11 +97,15 @@ pub use ptx_90_parser_construct::{c, cclosure, err, func, ok, okmap};
 // Re-export commonly used items for convenience
 
 // Lexer exports
-pub use lexer::{LexError, PtxToken, tokenize};
+pub use lexer::{
+    LexError, LexErrorKind, PtxToken, TokenTrivia, Trivia, TriviaKind, tokenize, tokenize_lossy,
+    tokenize_with_trivia,
+};
 
 // Parser exports
 pub use parser::{
     ParseErrorKind, PtxParseError, PtxParser, PtxTokenStream, Span, StreamPosition, parse_ptx,
+    parse_ptx_recovering, parse_ptx_with_comments,
 };
 
 /// Execute `f` on a dedicated thread with a larger stack in debug builds to
@@ -109,8 +134,29 @@ where
     f()
 }
 
+// Diagnostic exports
+pub use diagnostic::{Diagnostic, LineCol, Severity, SourceMap};
+
+// Comment exports
+pub use comments::{Comment, Comments};
+
+// Visitor exports
+pub use visit::{Visitor, VisitorMut};
+
+// Operand exports
+pub use operands::{OperandRole, Operands};
+
+// Metadata exports
+pub use metadata::{Metadata, PtxVersion, Requirement, SpecSection, Target, TargetVariant};
+
+// Immediate exports
+pub use immediate::{ImmValue, ImmediateError, Radix};
+
+// Analysis exports
+pub use analysis::SymbolTable;
+
 // Unlexer exports
 pub use unlexer::PtxUnlexer;
 
 // Unparser exports
-pub use unparser::PtxUnparser;
+pub use unparser::{PtxUnparser, minimal::unparse_minimal, trivia::unparse_with_comments};
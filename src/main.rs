@@ -1,11 +1,14 @@
 use std::fs;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
 use clap::{Parser, Subcommand, ValueEnum};
 
+use ptx_parser::analysis::{self, CallGraph, CallKind, Cfg, FrameReport, PressureReport};
 use ptx_parser::pretty_print::{TreeDisplay, TreeFormatter, print_compact_module};
-use ptx_parser::{parse_ptx, run_with_large_stack};
+use ptx_parser::r#type::Module;
+use ptx_parser::{PtxUnlexer, PtxUnparser, SourceMap, parse_ptx, run_with_large_stack};
 
 #[derive(Parser)]
 #[command(name = "ptx-parser", about = "Utilities for parsing PTX assembly")]
@@ -40,6 +43,66 @@ enum Command {
         #[arg(long)]
         output: Option<PathBuf>,
     },
+    /// Read an AST emitted by `output-ast --format json` and print it as PTX.
+    FromJson {
+        /// Path to the JSON file holding the module AST.
+        input_file: PathBuf,
+        /// Optional path to write the PTX source; defaults to stdout.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Print the control-flow graph of each function body as Graphviz DOT.
+    Cfg {
+        /// Path to the PTX source file to analyze.
+        input_file: PathBuf,
+        /// Only print the graph of the function with this name.
+        #[arg(long)]
+        function: Option<String>,
+        /// Optional path to write the DOT output; defaults to stdout.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Estimate register pressure per function and warn above `.maxnreg`.
+    Pressure {
+        /// Path to the PTX source file to analyze.
+        input_file: PathBuf,
+        /// Only report the function with this name.
+        #[arg(long)]
+        function: Option<String>,
+        /// Also list the pressure of every basic block.
+        #[arg(long)]
+        blocks: bool,
+    },
+    /// Print the call graph of a module with recursion, unreachable
+    /// functions and external callees.
+    Callgraph {
+        /// Path to the PTX source file to analyze.
+        input_file: PathBuf,
+        /// Output format for the graph.
+        #[arg(long, value_enum, default_value_t = GraphOutputFormat::Dot)]
+        format: GraphOutputFormat,
+        /// Optional path to write output; defaults to stdout.
+        #[arg(long)]
+        output: Option<PathBuf>,
+    },
+    /// Summarize parameter, stack and shared memory usage per function and
+    /// fail when a kernel exceeds the parameter size limit.
+    Frame {
+        /// Path to the PTX source file to analyze.
+        input_file: PathBuf,
+        /// Only report the function with this name.
+        #[arg(long)]
+        function: Option<String>,
+    },
+    /// Check instructions against the `.version` and `.target` of a module.
+    Targets {
+        /// Path to the PTX source file to check.
+        input_file: PathBuf,
+        /// Also print the lowest PTX ISA version and target the module can
+        /// be compiled for, and the instructions that need them.
+        #[arg(long)]
+        min_target: bool,
+    },
 }
 
 #[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
@@ -48,9 +111,26 @@ enum AstOutputFormat {
     Tree,
 }
 
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+#[derive(Copy, Clone, Debug, PartialEq, Eq, ValueEnum)]
+enum GraphOutputFormat {
+    Dot,
+    Json,
+}
+
+fn main() -> ExitCode {
     let cli = Cli::parse();
 
+    // Print errors with `Display` so that rendered diagnostics keep their layout.
+    match run(cli) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err.to_string().trim_end());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(cli: Cli) -> Result<(), Box<dyn std::error::Error>> {
     match cli.command {
         Command::ParseFile { input_file } => parse_file(&input_file)?,
         Command::PrintAst {
@@ -62,6 +142,30 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             format,
             output,
         } => output_ast(&input_file, format, output.as_deref())?,
+        Command::FromJson { input_file, output } => from_json(&input_file, output.as_deref())?,
+        Command::Cfg {
+            input_file,
+            function,
+            output,
+        } => print_cfg(&input_file, function, output.as_deref())?,
+        Command::Pressure {
+            input_file,
+            function,
+            blocks,
+        } => print_pressure(&input_file, function, blocks)?,
+        Command::Callgraph {
+            input_file,
+            format,
+            output,
+        } => print_callgraph(&input_file, format, output.as_deref())?,
+        Command::Frame {
+            input_file,
+            function,
+        } => print_frame(&input_file, function)?,
+        Command::Targets {
+            input_file,
+            min_target,
+        } => check_targets(&input_file, min_target)?,
     }
 
     Ok(())
@@ -69,7 +173,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
 fn parse_file(path: &Path) -> Result<(), Box<dyn std::error::Error>> {
     let source = fs::read_to_string(path)?;
-    let module = parse_with_large_stack(source)?;
+    let module = parse_with_large_stack(path, source)?;
 
     println!(
         "✓ {}: Successfully parsed PTX module with {} directives",
@@ -86,7 +190,7 @@ fn output_ast(
     output: Option<&Path>,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let source = fs::read_to_string(path)?;
-    let module = parse_with_large_stack(source.clone())?;
+    let module = parse_with_large_stack(path, source.clone())?;
 
     match (format, output) {
         (AstOutputFormat::Json, Some(out_path)) => {
@@ -114,9 +218,331 @@ fn output_ast(
     Ok(())
 }
 
+fn from_json(path: &Path, output: Option<&Path>) -> Result<(), Box<dyn std::error::Error>> {
+    let json = fs::read_to_string(path)?;
+    let text = run_with_large_stack(move || -> Result<String, String> {
+        let module: Module = serde_json::from_str(&json).map_err(|err| err.to_string())?;
+        PtxUnlexer::to_string(&module.to_tokens_spaced()).map_err(|err| err.to_string())
+    })
+    .map_err(|err| format!("{}: {err}", path.display()))?;
+
+    match output {
+        Some(out_path) => fs::write(out_path, text)?,
+        None => io::stdout().lock().write_all(text.as_bytes())?,
+    }
+
+    Ok(())
+}
+
+fn print_cfg(
+    path: &Path,
+    function: Option<String>,
+    output: Option<&Path>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let source = fs::read_to_string(path)?;
+    let module = parse_with_large_stack(path, source)?;
+    let dot = run_with_large_stack(move || -> Result<String, String> {
+        let mut dot = String::new();
+        let mut found = false;
+        for candidate in analysis::functions(&module) {
+            let Some(body) = candidate.body() else {
+                continue;
+            };
+            if function
+                .as_deref()
+                .is_some_and(|name| name != candidate.name())
+            {
+                continue;
+            }
+            found = true;
+            dot.push_str(&Cfg::build(body).to_dot(candidate.name()));
+        }
+        match function {
+            Some(name) if !found => Err(format!("no function body named `{name}`")),
+            _ => Ok(dot),
+        }
+    })
+    .map_err(|err| format!("{}: {err}", path.display()))?;
+
+    match output {
+        Some(out_path) => fs::write(out_path, dot)?,
+        None => io::stdout().lock().write_all(dot.as_bytes())?,
+    }
+
+    Ok(())
+}
+
+fn print_pressure(
+    path: &Path,
+    function: Option<String>,
+    blocks: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let source = fs::read_to_string(path)?;
+    let module = parse_with_large_stack(path, source.clone())?;
+    let name = path.display().to_string();
+    let (report, warnings) = run_with_large_stack(move || -> Result<_, String> {
+        let map = SourceMap::new(&source);
+        let mut report = String::new();
+        let mut warnings = String::new();
+        let mut found = false;
+        for candidate in analysis::functions(&module) {
+            if function
+                .as_deref()
+                .is_some_and(|name| name != candidate.name())
+            {
+                continue;
+            }
+            let Some(pressure) = PressureReport::of(candidate) else {
+                continue;
+            };
+            found = true;
+
+            report.push_str(&format!(
+                "{}: {} registers, {} predicates",
+                pressure.function, pressure.max.registers, pressure.max.predicates
+            ));
+            if let Some(limit) = pressure.maxnreg {
+                report.push_str(&format!(" (.maxnreg {limit})"));
+            }
+            report.push('\n');
+            if let Some(peak) = &pressure.peak {
+                let line = map.line_col(peak.span.start).line + 1;
+                report.push_str(&format!(
+                    "  peak at line {line} in b{}: {}\n",
+                    peak.block.index(),
+                    peak.live.join(", ")
+                ));
+            }
+            if blocks {
+                for block in &pressure.blocks {
+                    report.push_str(&format!(
+                        "  b{}: {} registers, {} predicates\n",
+                        block.block.index(),
+                        block.max.registers,
+                        block.max.predicates
+                    ));
+                }
+            }
+            if let Some(diagnostic) = pressure.to_diagnostic() {
+                warnings.push_str(&diagnostic.render(&map, Some(&name)));
+            }
+        }
+        match function {
+            Some(name) if !found => Err(format!("no function body named `{name}`")),
+            _ => Ok((report, warnings)),
+        }
+    })
+    .map_err(|err| format!("{}: {err}", path.display()))?;
+
+    io::stdout().lock().write_all(report.as_bytes())?;
+    io::stderr().lock().write_all(warnings.as_bytes())?;
+
+    Ok(())
+}
+
+fn print_callgraph(
+    path: &Path,
+    format: GraphOutputFormat,
+    output: Option<&Path>,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let source = fs::read_to_string(path)?;
+    let module = parse_with_large_stack(path, source.clone())?;
+    let text = run_with_large_stack(move || -> Result<String, String> {
+        let graph = CallGraph::build(&module);
+        match format {
+            GraphOutputFormat::Dot => Ok(graph.to_dot()),
+            GraphOutputFormat::Json => {
+                let map = SourceMap::new(&source);
+                let json = callgraph_json(&graph, &map);
+                serde_json::to_string_pretty(&json)
+                    .map(|json| json + "\n")
+                    .map_err(|err| err.to_string())
+            }
+        }
+    })
+    .map_err(|err| format!("{}: {err}", path.display()))?;
+
+    match output {
+        Some(out_path) => fs::write(out_path, text)?,
+        None => io::stdout().lock().write_all(text.as_bytes())?,
+    }
+
+    Ok(())
+}
+
+fn print_frame(path: &Path, function: Option<String>) -> Result<(), Box<dyn std::error::Error>> {
+    let source = fs::read_to_string(path)?;
+    let module = parse_with_large_stack(path, source.clone())?;
+    let name = path.display().to_string();
+    let (report, errors) = run_with_large_stack(move || -> Result<_, String> {
+        let map = SourceMap::new(&source);
+        let line = |span: ptx_parser::Span| map.line_col(span.start).line + 1;
+        let limit = analysis::param_limit(&module);
+        let mut report = format!(
+            "module: {} bytes of shared memory, kernel parameter limit {limit} bytes\n",
+            analysis::module_shared_bytes(&module)
+        );
+        let mut errors = String::new();
+        let mut found = false;
+        for candidate in analysis::functions(&module) {
+            if function
+                .as_deref()
+                .is_some_and(|name| name != candidate.name())
+            {
+                continue;
+            }
+            found = true;
+            let frame = FrameReport::of(candidate);
+
+            report.push_str(&format!(
+                "{}: {} bytes of parameters",
+                frame.function, frame.param_bytes
+            ));
+            if !frame.is_entry {
+                report.push_str(&format!(", {} returned", frame.return_bytes));
+            }
+            report.push_str(&format!(
+                ", {} bytes local, {} bytes shared\n",
+                frame.local_bytes, frame.shared_bytes
+            ));
+            for span in &frame.alloca {
+                report.push_str(&format!("  alloca at line {}\n", line(*span)));
+            }
+            for span in &frame.stacksave {
+                report.push_str(&format!(
+                    "  stack save or restore at line {}\n",
+                    line(*span)
+                ));
+            }
+            for call in &frame.calls {
+                report.push_str(&format!(
+                    "  call {} at line {}: {} bytes passed, {} returned\n",
+                    call.callee.unwrap_or("<indirect>"),
+                    line(call.span),
+                    call.param_bytes,
+                    call.return_bytes
+                ));
+            }
+            if let Some(diagnostic) = frame.to_diagnostic(limit) {
+                errors.push_str(&diagnostic.render(&map, Some(&name)));
+            }
+        }
+        match function {
+            Some(name) if !found => Err(format!("no function named `{name}`")),
+            _ => Ok((report, errors)),
+        }
+    })
+    .map_err(|err| format!("{}: {err}", path.display()))?;
+
+    io::stdout().lock().write_all(report.as_bytes())?;
+    if !errors.is_empty() {
+        return Err(errors.into());
+    }
+
+    Ok(())
+}
+
+fn check_targets(path: &Path, min_target: bool) -> Result<(), Box<dyn std::error::Error>> {
+    let source = fs::read_to_string(path)?;
+    let module = parse_with_large_stack(path, source.clone())?;
+    let name = path.display().to_string();
+    let (report, errors) = run_with_large_stack(move || {
+        let map = SourceMap::new(&source);
+        let line = |span: ptx_parser::Span| map.line_col(span.start).line + 1;
+        let mut errors: String = analysis::check_targets(&module)
+            .iter()
+            .map(|issue| issue.to_diagnostic().render(&map, Some(&name)))
+            .collect();
+        let mut report = String::new();
+        if min_target {
+            match analysis::min_target(&module) {
+                Ok(min) => {
+                    let ptx = match &min.ptx {
+                        Some((version, used)) => format!(
+                            "{version}, for `{}` at line {}",
+                            used.mnemonic,
+                            line(used.span)
+                        ),
+                        None => "any".to_string(),
+                    };
+                    let target = match &min.target {
+                        Some((target, used)) => format!(
+                            "{target}, for `{}` at line {}",
+                            used.mnemonic,
+                            line(used.span)
+                        ),
+                        None => "any".to_string(),
+                    };
+                    report.push_str(&format!("minimum PTX ISA version: {ptx}\n"));
+                    report.push_str(&format!("minimum target: {target}\n"));
+                }
+                Err(issue) => errors.push_str(&issue.to_diagnostic().render(&map, Some(&name))),
+            }
+        }
+        (report, errors)
+    });
+
+    io::stdout().lock().write_all(report.as_bytes())?;
+    if !errors.is_empty() {
+        return Err(errors.into());
+    }
+
+    Ok(())
+}
+
+fn callgraph_json(graph: &CallGraph, map: &SourceMap) -> serde_json::Value {
+    let names = |ids: &[analysis::FunctionId]| -> Vec<&str> {
+        ids.iter().map(|&id| graph.node(id).name).collect()
+    };
+    let unreachable = graph.unreachable();
+    let functions: Vec<serde_json::Value> = graph
+        .nodes()
+        .map(|(id, node)| {
+            serde_json::json!({
+                "name": node.name,
+                "entry": node.is_entry(),
+                "defined": node.is_defined(),
+                "declared": node.function.is_some(),
+                "reachable": !unreachable.contains(&id),
+                "callees": names(graph.callees(id)),
+            })
+        })
+        .collect();
+    let calls: Vec<serde_json::Value> = graph
+        .call_sites()
+        .iter()
+        .map(|site| {
+            let kind = match site.kind {
+                CallKind::Direct => "direct",
+                CallKind::CallTargets => "calltargets",
+                CallKind::Prototype => "prototype",
+                CallKind::Unknown => "unknown",
+            };
+            serde_json::json!({
+                "caller": graph.node(site.caller).name,
+                "line": map.line_col(site.instruction.span.start).line + 1,
+                "kind": kind,
+                "callees": names(&site.callees),
+            })
+        })
+        .collect();
+    let recursion: Vec<Vec<&str>> = graph
+        .recursive_cycles()
+        .iter()
+        .map(|cycle| names(cycle))
+        .collect();
+    serde_json::json!({
+        "functions": functions,
+        "calls": calls,
+        "recursion": recursion,
+        "unreachable": names(&unreachable),
+        "extern_callees": names(&graph.extern_callees()),
+    })
+}
+
 fn print_ast(path: &Path, compact: bool) -> Result<(), Box<dyn std::error::Error>> {
     let source = fs::read_to_string(path)?;
-    let module = parse_with_large_stack(source.clone())?;
+    let module = parse_with_large_stack(path, source.clone())?;
 
     if compact {
         print_compact_module(&module);
@@ -141,8 +567,14 @@ fn print_ast_with_large_stack(
     Ok(())
 }
 
+/// Parse on a large stack, rendering any error against the source file.
 fn parse_with_large_stack(
+    path: &Path,
     source: String,
-) -> Result<ptx_parser::r#type::Module, ptx_parser::PtxParseError> {
-    run_with_large_stack(move || parse_ptx(&source))
+) -> Result<ptx_parser::r#type::Module, Box<dyn std::error::Error>> {
+    let name = path.display().to_string();
+    let result = run_with_large_stack(move || {
+        parse_ptx(&source).map_err(|err| err.render(&source, Some(&name)))
+    });
+    Ok(result?)
 }
@@ -0,0 +1,159 @@
+//! Original PTX specification:
+//!
+//! abs.type  d, a;
+//! .type = { .s16, .s32, .s64 };
+//!
+//! abs{.ftz}.f32  d, a;
+//! abs.f64        d, a;
+//!
+//! abs{.ftz}.f16    d, a;
+//! abs{.ftz}.f16x2  d, a;
+//! abs.bf16         d, a;
+//! abs.bf16x2       d, a;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::abs::section_0::*;
+
+    impl Metadata for AbsType {
+        fn opcode(&self) -> &'static str {
+            "abs"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "abs.txt",
+                section: 0,
+                line: 1,
+                rule: "abs.type d, a;",
+            }
+        }
+    }
+
+    impl Metadata for AbsFtzF32 {
+        fn opcode(&self) -> &'static str {
+            "abs"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "abs.txt",
+                section: 0,
+                line: 4,
+                rule: "abs{.ftz}.f32 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for AbsF64 {
+        fn opcode(&self) -> &'static str {
+            "abs"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "abs.txt",
+                section: 0,
+                line: 5,
+                rule: "abs.f64 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for AbsFtzF16 {
+        fn opcode(&self) -> &'static str {
+            "abs"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "abs.txt",
+                section: 0,
+                line: 7,
+                rule: "abs{.ftz}.f16 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for AbsFtzF16x2 {
+        fn opcode(&self) -> &'static str {
+            "abs"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "abs.txt",
+                section: 0,
+                line: 8,
+                rule: "abs{.ftz}.f16x2 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for AbsBf16 {
+        fn opcode(&self) -> &'static str {
+            "abs"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "abs.txt",
+                section: 0,
+                line: 9,
+                rule: "abs.bf16 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for AbsBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "abs"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "abs.txt",
+                section: 0,
+                line: 10,
+                rule: "abs.bf16x2 d, a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Original PTX specification:
+//!
+//! activemask.b32 d;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::activemask::section_0::*;
+
+    impl Metadata for ActivemaskB32 {
+        fn opcode(&self) -> &'static str {
+            "activemask"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "activemask.txt",
+                section: 0,
+                line: 1,
+                rule: "activemask.b32 d;",
+            }
+        }
+    }
+}
@@ -0,0 +1,368 @@
+//! Original PTX specification:
+//!
+//! add.type       d, a, b;
+//! add{.sat}.s32  d, a, b;     // .sat applies only to .s32
+//! .type = { .u16, .u32, .u64,
+//! .s16, .s32, .s64,
+//! .u16x2, .s16x2 };
+//! -------------------------------------------
+//! add{.rnd}{.ftz}{.sat}.f32  d, a, b;
+//! add{.rnd}{.ftz}.f32x2      d, a, b;
+//! add{.rnd}.f64              d, a, b;
+//! .rnd = { .rn, .rz, .rm, .rp };
+//! --------------------------------------------
+//! add{.rnd}{.ftz}{.sat}.f16   d, a, b;
+//! add{.rnd}{.ftz}{.sat}.f16x2 d, a, b;
+//! add{.rnd}.bf16   d, a, b;
+//! add{.rnd}.bf16x2 d, a, b;
+//! .rnd = { .rn };
+//! --------------------------------------------
+//! add{.rnd}{.sat}.f32.atype  d, a, c;
+//! .atype = { .f16, .bf16};
+//! .rnd   = { .rn, .rz, .rm, .rp };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::add::section_0::*;
+
+    impl Metadata for AddType {
+        fn opcode(&self) -> &'static str {
+            "add"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::U16x2 => {
+                    f(".u16x2");
+                }
+                Type::S16x2 => {
+                    f(".s16x2");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "add.txt",
+                section: 0,
+                line: 1,
+                rule: "add.type d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for AddSatS32 {
+        fn opcode(&self) -> &'static str {
+            "add"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.sat {
+                f(".sat");
+            }
+            f(".s32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "add.txt",
+                section: 0,
+                line: 2,
+                rule: "add{.sat}.s32 d, a, b;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::add::section_1::*;
+
+    impl Metadata for AddRndFtzSatF32 {
+        fn opcode(&self) -> &'static str {
+            "add"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_0) = self.rnd.as_ref() {
+                match rnd_0 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                    Rnd::Rz => {
+                        f(".rz");
+                    }
+                    Rnd::Rm => {
+                        f(".rm");
+                    }
+                    Rnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "add.txt",
+                section: 1,
+                line: 7,
+                rule: "add{.rnd}{.ftz}{.sat}.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for AddRndFtzF32x2 {
+        fn opcode(&self) -> &'static str {
+            "add"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_1) = self.rnd.as_ref() {
+                match rnd_1 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                    Rnd::Rz => {
+                        f(".rz");
+                    }
+                    Rnd::Rm => {
+                        f(".rm");
+                    }
+                    Rnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "add.txt",
+                section: 1,
+                line: 8,
+                rule: "add{.rnd}{.ftz}.f32x2 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for AddRndF64 {
+        fn opcode(&self) -> &'static str {
+            "add"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_2) = self.rnd.as_ref() {
+                match rnd_2 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                    Rnd::Rz => {
+                        f(".rz");
+                    }
+                    Rnd::Rm => {
+                        f(".rm");
+                    }
+                    Rnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "add.txt",
+                section: 1,
+                line: 9,
+                rule: "add{.rnd}.f64 d, a, b;",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::add::section_2::*;
+
+    impl Metadata for AddRndFtzSatF16 {
+        fn opcode(&self) -> &'static str {
+            "add"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_3) = self.rnd.as_ref() {
+                match rnd_3 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "add.txt",
+                section: 2,
+                line: 12,
+                rule: "add{.rnd}{.ftz}{.sat}.f16 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for AddRndFtzSatF16x2 {
+        fn opcode(&self) -> &'static str {
+            "add"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_4) = self.rnd.as_ref() {
+                match rnd_4 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "add.txt",
+                section: 2,
+                line: 13,
+                rule: "add{.rnd}{.ftz}{.sat}.f16x2 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for AddRndBf16 {
+        fn opcode(&self) -> &'static str {
+            "add"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_5) = self.rnd.as_ref() {
+                match rnd_5 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                }
+            }
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "add.txt",
+                section: 2,
+                line: 14,
+                rule: "add{.rnd}.bf16 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for AddRndBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "add"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_6) = self.rnd.as_ref() {
+                match rnd_6 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                }
+            }
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "add.txt",
+                section: 2,
+                line: 15,
+                rule: "add{.rnd}.bf16x2 d, a, b;",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::add::section_3::*;
+
+    impl Metadata for AddRndSatF32Atype {
+        fn opcode(&self) -> &'static str {
+            "add"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_7) = self.rnd.as_ref() {
+                match rnd_7 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                    Rnd::Rz => {
+                        f(".rz");
+                    }
+                    Rnd::Rm => {
+                        f(".rm");
+                    }
+                    Rnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f32");
+            match &self.atype {
+                Atype::Bf16 => {
+                    f(".bf16");
+                }
+                Atype::F16 => {
+                    f(".f16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "add.txt",
+                section: 3,
+                line: 18,
+                rule: "add{.rnd}{.sat}.f32.atype d, a, c;",
+            }
+        }
+    }
+}
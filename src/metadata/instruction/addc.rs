@@ -0,0 +1,46 @@
+//! Original PTX specification:
+//!
+//! addc{.cc}.type  d, a, b;
+//! .type = { .u32, .s32, .u64, .s64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::addc::section_0::*;
+
+    impl Metadata for AddcCcType {
+        fn opcode(&self) -> &'static str {
+            "addc"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.cc {
+                f(".cc");
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "addc.txt",
+                section: 0,
+                line: 1,
+                rule: "addc{.cc}.type d, a, b;",
+            }
+        }
+    }
+}
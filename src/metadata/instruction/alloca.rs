@@ -0,0 +1,37 @@
+//! Original PTX specification:
+//!
+//! alloca.type  ptr, size{, immAlign};
+//! .type = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::alloca::section_0::*;
+
+    impl Metadata for AllocaType {
+        fn opcode(&self) -> &'static str {
+            "alloca"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "alloca.txt",
+                section: 0,
+                line: 1,
+                rule: "alloca.type ptr, size{, immAlign};",
+            }
+        }
+    }
+}
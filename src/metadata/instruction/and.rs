@@ -0,0 +1,43 @@
+//! Original PTX specification:
+//!
+//! and.type d, a, b;
+//! .type = { .pred, .b16, .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::and::section_0::*;
+
+    impl Metadata for AndType {
+        fn opcode(&self) -> &'static str {
+            "and"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::Pred => {
+                    f(".pred");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "and.txt",
+                section: 0,
+                line: 1,
+                rule: "and.type d, a, b;",
+            }
+        }
+    }
+}
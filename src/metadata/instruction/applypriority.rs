@@ -0,0 +1,37 @@
+//! Original PTX specification:
+//!
+//! applypriority{.global}.level::eviction_priority  [a], size;
+//! .level::eviction_priority = { .L2::evict_normal };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::applypriority::section_0::*;
+
+    impl Metadata for ApplypriorityGlobalLevelEvictionPriority {
+        fn opcode(&self) -> &'static str {
+            "applypriority"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.global {
+                f(".global");
+            }
+            match &self.level_eviction_priority {
+                LevelEvictionPriority::L2EvictNormal => {
+                    f(".L2::evict_normal");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "applypriority.txt",
+                section: 0,
+                line: 1,
+                rule: "applypriority{.global}.level::eviction_priority [a], size;",
+            }
+        }
+    }
+}
@@ -0,0 +1,1040 @@
+//! Original PTX specification:
+//!
+//! // Atomic operation with scalar type:
+//! atom{.sem}{.scope}{.space}.op{.level::cache_hint}.type d, [a], b{, cache-policy};
+//! atom{.sem}{.scope}{.space}.op.type d, [a], b, c;
+//! atom{.sem}{.scope}{.space}.cas.b16 d, [a], b, c;
+//! atom{.sem}{.scope}{.space}.cas.b128 d, [a], b, c;
+//! atom{.sem}{.scope}{.space}.exch{.level::cache_hint}.b128 d, [a], b {, cache-policy};
+//! atom{.sem}{.scope}{.space}.add.noftz{.level::cache_hint}.f16     d, [a], b{, cache-policy};
+//! atom{.sem}{.scope}{.space}.add.noftz{.level::cache_hint}.f16x2   d, [a], b{, cache-policy};
+//! atom{.sem}{.scope}{.space}.add.noftz{.level::cache_hint}.bf16    d, [a], b{, cache-policy};
+//! atom{.sem}{.scope}{.space}.add.noftz{.level::cache_hint}.bf16x2  d, [a], b{, cache-policy};
+//! .space =              { .global, .shared, .shared::cta, .shared::cluster};
+//! .sem =                { .relaxed, .acquire, .release, .acq_rel };
+//! .scope =              { .cta, .cluster, .gpu, .sys };
+//! .op =                 { .and, .or, .xor, .cas, .exch, .add, .inc, .dec, .min, .max };
+//! .level::cache_hint =  { .L2::cache_hint };
+//! .type =               { .b32, .b64, .u32, .u64, .s32, .s64, .f32, .f64 };
+//! -------------------------------------------------------------
+//! // Atomic operation with vector type:
+//! atom{.sem}{.scope}{.global}.add{.level::cache_hint}.vec_32_bit.f32                  d, [a], b{, cache-policy};
+//! atom{.sem}{.scope}{.global}.op.noftz{.level::cache_hint}.vec_16_bit.half_word_type  d, [a], b{, cache-policy};
+//! atom{.sem}{.scope}{.global}.op.noftz{.level::cache_hint}.vec_32_bit.packed_type     d, [a], b{, cache-policy};
+//! .sem =               { .relaxed, .acquire, .release, .acq_rel };
+//! .scope =             { .cta, .cluster, .gpu, .sys };
+//! .op =                { .add, .min, .max };
+//! .half_word_type =    { .f16, .bf16 };
+//! .packed_type =       { .f16x2, .bf16x2 };
+//! .vec_16_bit =        { .v2, .v4, .v8 };
+//! .vec_32_bit =        { .v2, .v4 };
+//! .level::cache_hint = { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::atom::section_0::*;
+
+    impl Metadata for AtomSemScopeSpaceOpLevelCacheHintType {
+        fn opcode(&self) -> &'static str {
+            "atom"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_0) = self.sem.as_ref() {
+                match sem_0 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                }
+            }
+            if let Some(scope_1) = self.scope.as_ref() {
+                match scope_1 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(space_2) = self.space.as_ref() {
+                match space_2 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.op {
+                Op::Exch => {
+                    f(".exch");
+                }
+                Op::And => {
+                    f(".and");
+                }
+                Op::Xor => {
+                    f(".xor");
+                }
+                Op::Cas => {
+                    f(".cas");
+                }
+                Op::Add => {
+                    f(".add");
+                }
+                Op::Inc => {
+                    f(".inc");
+                }
+                Op::Dec => {
+                    f(".dec");
+                }
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+                Op::Or => {
+                    f(".or");
+                }
+            }
+            if let Some(level_cache_hint_3) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_3 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "atom.txt",
+                section: 0,
+                line: 2,
+                rule: "atom{.sem}{.scope}{.space}.op{.level::cache_hint}.type d, [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for AtomSemScopeSpaceOpType {
+        fn opcode(&self) -> &'static str {
+            "atom"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_4) = self.sem.as_ref() {
+                match sem_4 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                }
+            }
+            if let Some(scope_5) = self.scope.as_ref() {
+                match scope_5 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(space_6) = self.space.as_ref() {
+                match space_6 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.op {
+                Op::Exch => {
+                    f(".exch");
+                }
+                Op::And => {
+                    f(".and");
+                }
+                Op::Xor => {
+                    f(".xor");
+                }
+                Op::Cas => {
+                    f(".cas");
+                }
+                Op::Add => {
+                    f(".add");
+                }
+                Op::Inc => {
+                    f(".inc");
+                }
+                Op::Dec => {
+                    f(".dec");
+                }
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+                Op::Or => {
+                    f(".or");
+                }
+            }
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "atom.txt",
+                section: 0,
+                line: 3,
+                rule: "atom{.sem}{.scope}{.space}.op.type d, [a], b, c;",
+            }
+        }
+    }
+
+    impl Metadata for AtomSemScopeSpaceCasB16 {
+        fn opcode(&self) -> &'static str {
+            "atom"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_7) = self.sem.as_ref() {
+                match sem_7 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                }
+            }
+            if let Some(scope_8) = self.scope.as_ref() {
+                match scope_8 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(space_9) = self.space.as_ref() {
+                match space_9 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".cas");
+            f(".b16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "atom.txt",
+                section: 0,
+                line: 4,
+                rule: "atom{.sem}{.scope}{.space}.cas.b16 d, [a], b, c;",
+            }
+        }
+    }
+
+    impl Metadata for AtomSemScopeSpaceCasB128 {
+        fn opcode(&self) -> &'static str {
+            "atom"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_10) = self.sem.as_ref() {
+                match sem_10 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                }
+            }
+            if let Some(scope_11) = self.scope.as_ref() {
+                match scope_11 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(space_12) = self.space.as_ref() {
+                match space_12 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".cas");
+            f(".b128");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "atom.txt",
+                section: 0,
+                line: 5,
+                rule: "atom{.sem}{.scope}{.space}.cas.b128 d, [a], b, c;",
+            }
+        }
+    }
+
+    impl Metadata for AtomSemScopeSpaceExchLevelCacheHintB128 {
+        fn opcode(&self) -> &'static str {
+            "atom"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_13) = self.sem.as_ref() {
+                match sem_13 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                }
+            }
+            if let Some(scope_14) = self.scope.as_ref() {
+                match scope_14 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(space_15) = self.space.as_ref() {
+                match space_15 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".exch");
+            if let Some(level_cache_hint_16) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_16 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            f(".b128");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "atom.txt",
+                section: 0,
+                line: 6,
+                rule: "atom{.sem}{.scope}{.space}.exch{.level::cache_hint}.b128 d, [a], b {, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for AtomSemScopeSpaceAddNoftzLevelCacheHintF16 {
+        fn opcode(&self) -> &'static str {
+            "atom"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_17) = self.sem.as_ref() {
+                match sem_17 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                }
+            }
+            if let Some(scope_18) = self.scope.as_ref() {
+                match scope_18 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(space_19) = self.space.as_ref() {
+                match space_19 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".add");
+            f(".noftz");
+            if let Some(level_cache_hint_20) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_20 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "atom.txt",
+                section: 0,
+                line: 7,
+                rule: "atom{.sem}{.scope}{.space}.add.noftz{.level::cache_hint}.f16 d, [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2 {
+        fn opcode(&self) -> &'static str {
+            "atom"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_21) = self.sem.as_ref() {
+                match sem_21 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                }
+            }
+            if let Some(scope_22) = self.scope.as_ref() {
+                match scope_22 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(space_23) = self.space.as_ref() {
+                match space_23 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".add");
+            f(".noftz");
+            if let Some(level_cache_hint_24) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_24 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "atom.txt",
+                section: 0,
+                line: 8,
+                rule: "atom{.sem}{.scope}{.space}.add.noftz{.level::cache_hint}.f16x2 d, [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for AtomSemScopeSpaceAddNoftzLevelCacheHintBf16 {
+        fn opcode(&self) -> &'static str {
+            "atom"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_25) = self.sem.as_ref() {
+                match sem_25 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                }
+            }
+            if let Some(scope_26) = self.scope.as_ref() {
+                match scope_26 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(space_27) = self.space.as_ref() {
+                match space_27 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".add");
+            f(".noftz");
+            if let Some(level_cache_hint_28) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_28 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "atom.txt",
+                section: 0,
+                line: 9,
+                rule: "atom{.sem}{.scope}{.space}.add.noftz{.level::cache_hint}.bf16 d, [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "atom"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_29) = self.sem.as_ref() {
+                match sem_29 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                }
+            }
+            if let Some(scope_30) = self.scope.as_ref() {
+                match scope_30 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(space_31) = self.space.as_ref() {
+                match space_31 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".add");
+            f(".noftz");
+            if let Some(level_cache_hint_32) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_32 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "atom.txt",
+                section: 0,
+                line: 10,
+                rule: "atom{.sem}{.scope}{.space}.add.noftz{.level::cache_hint}.bf16x2 d, [a], b{, cache-policy};",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::atom::section_1::*;
+
+    impl Metadata for AtomSemScopeGlobalAddLevelCacheHintVec32BitF32 {
+        fn opcode(&self) -> &'static str {
+            "atom"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_33) = self.sem.as_ref() {
+                match sem_33 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                }
+            }
+            if let Some(scope_34) = self.scope.as_ref() {
+                match scope_34 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if self.global {
+                f(".global");
+            }
+            f(".add");
+            if let Some(level_cache_hint_35) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_35 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            match &self.vec_32_bit {
+                Vec32Bit::V2 => {
+                    f(".v2");
+                }
+                Vec32Bit::V4 => {
+                    f(".v4");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "atom.txt",
+                section: 1,
+                line: 19,
+                rule: "atom{.sem}{.scope}{.global}.add{.level::cache_hint}.vec_32_bit.f32 d, [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType {
+        fn opcode(&self) -> &'static str {
+            "atom"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_36) = self.sem.as_ref() {
+                match sem_36 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                }
+            }
+            if let Some(scope_37) = self.scope.as_ref() {
+                match scope_37 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if self.global {
+                f(".global");
+            }
+            match &self.op {
+                Op::Add => {
+                    f(".add");
+                }
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+            }
+            f(".noftz");
+            if let Some(level_cache_hint_38) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_38 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            match &self.vec_16_bit {
+                Vec16Bit::V2 => {
+                    f(".v2");
+                }
+                Vec16Bit::V4 => {
+                    f(".v4");
+                }
+                Vec16Bit::V8 => {
+                    f(".v8");
+                }
+            }
+            match &self.half_word_type {
+                HalfWordType::Bf16 => {
+                    f(".bf16");
+                }
+                HalfWordType::F16 => {
+                    f(".f16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "atom.txt",
+                section: 1,
+                line: 20,
+                rule: "atom{.sem}{.scope}{.global}.op.noftz{.level::cache_hint}.vec_16_bit.half_word_type d, [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType {
+        fn opcode(&self) -> &'static str {
+            "atom"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_39) = self.sem.as_ref() {
+                match sem_39 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                }
+            }
+            if let Some(scope_40) = self.scope.as_ref() {
+                match scope_40 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if self.global {
+                f(".global");
+            }
+            match &self.op {
+                Op::Add => {
+                    f(".add");
+                }
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+            }
+            f(".noftz");
+            if let Some(level_cache_hint_41) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_41 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            match &self.vec_32_bit {
+                Vec32Bit::V2 => {
+                    f(".v2");
+                }
+                Vec32Bit::V4 => {
+                    f(".v4");
+                }
+            }
+            match &self.packed_type {
+                PackedType::Bf16x2 => {
+                    f(".bf16x2");
+                }
+                PackedType::F16x2 => {
+                    f(".f16x2");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "atom.txt",
+                section: 1,
+                line: 21,
+                rule: "atom{.sem}{.scope}{.global}.op.noftz{.level::cache_hint}.vec_32_bit.packed_type d, [a], b{, cache-policy};",
+            }
+        }
+    }
+}
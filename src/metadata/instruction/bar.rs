@@ -0,0 +1,214 @@
+//! Original PTX specification:
+//!
+//! barrier{.cta}.sync{.aligned}      a{, b};
+//! barrier{.cta}.arrive{.aligned}    a, b;
+//! barrier{.cta}.red.popc{.aligned}.u32  d, a{, b}, {!}c;
+//! barrier{.cta}.red.op{.aligned}.pred   p, a{, b}, {!}c;
+//! bar{.cta}.sync      a{, b};
+//! bar{.cta}.arrive    a, b;
+//! bar{.cta}.red.popc.u32  d, a{, b}, {!}c;
+//! bar{.cta}.red.op.pred   p, a{, b}, {!}c;
+//! .op = { .and, .or };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bar::section_0::*;
+
+    impl Metadata for BarrierCtaSyncAligned {
+        fn opcode(&self) -> &'static str {
+            "barrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.cta {
+                f(".cta");
+            }
+            f(".sync");
+            if self.aligned {
+                f(".aligned");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bar.txt",
+                section: 0,
+                line: 1,
+                rule: "barrier{.cta}.sync{.aligned} a{, b};",
+            }
+        }
+    }
+
+    impl Metadata for BarrierCtaArriveAligned {
+        fn opcode(&self) -> &'static str {
+            "barrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.cta {
+                f(".cta");
+            }
+            f(".arrive");
+            if self.aligned {
+                f(".aligned");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bar.txt",
+                section: 0,
+                line: 2,
+                rule: "barrier{.cta}.arrive{.aligned} a, b;",
+            }
+        }
+    }
+
+    impl Metadata for BarrierCtaRedPopcAlignedU32 {
+        fn opcode(&self) -> &'static str {
+            "barrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.cta {
+                f(".cta");
+            }
+            f(".red");
+            f(".popc");
+            if self.aligned {
+                f(".aligned");
+            }
+            f(".u32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bar.txt",
+                section: 0,
+                line: 3,
+                rule: "barrier{.cta}.red.popc{.aligned}.u32 d, a{, b}, {!}c;",
+            }
+        }
+    }
+
+    impl Metadata for BarrierCtaRedOpAlignedPred {
+        fn opcode(&self) -> &'static str {
+            "barrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.cta {
+                f(".cta");
+            }
+            f(".red");
+            match &self.op {
+                Op::And => {
+                    f(".and");
+                }
+                Op::Or => {
+                    f(".or");
+                }
+            }
+            if self.aligned {
+                f(".aligned");
+            }
+            f(".pred");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bar.txt",
+                section: 0,
+                line: 4,
+                rule: "barrier{.cta}.red.op{.aligned}.pred p, a{, b}, {!}c;",
+            }
+        }
+    }
+
+    impl Metadata for BarCtaSync {
+        fn opcode(&self) -> &'static str {
+            "bar"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.cta {
+                f(".cta");
+            }
+            f(".sync");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bar.txt",
+                section: 0,
+                line: 5,
+                rule: "bar{.cta}.sync a{, b};",
+            }
+        }
+    }
+
+    impl Metadata for BarCtaArrive {
+        fn opcode(&self) -> &'static str {
+            "bar"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.cta {
+                f(".cta");
+            }
+            f(".arrive");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bar.txt",
+                section: 0,
+                line: 6,
+                rule: "bar{.cta}.arrive a, b;",
+            }
+        }
+    }
+
+    impl Metadata for BarCtaRedPopcU32 {
+        fn opcode(&self) -> &'static str {
+            "bar"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.cta {
+                f(".cta");
+            }
+            f(".red");
+            f(".popc");
+            f(".u32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bar.txt",
+                section: 0,
+                line: 7,
+                rule: "bar{.cta}.red.popc.u32 d, a{, b}, {!}c;",
+            }
+        }
+    }
+
+    impl Metadata for BarCtaRedOpPred {
+        fn opcode(&self) -> &'static str {
+            "bar"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.cta {
+                f(".cta");
+            }
+            f(".red");
+            match &self.op {
+                Op::And => {
+                    f(".and");
+                }
+                Op::Or => {
+                    f(".or");
+                }
+            }
+            f(".pred");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bar.txt",
+                section: 0,
+                line: 8,
+                rule: "bar{.cta}.red.op.pred p, a{, b}, {!}c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Original PTX specification:
+//!
+//! bar.warp.sync      membermask;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bar_warp_sync::section_0::*;
+
+    impl Metadata for BarWarpSync {
+        fn opcode(&self) -> &'static str {
+            "bar"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".warp");
+            f(".sync");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bar.warp.sync.txt",
+                section: 0,
+                line: 1,
+                rule: "bar.warp.sync membermask;",
+            }
+        }
+    }
+}
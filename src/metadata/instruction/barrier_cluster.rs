@@ -0,0 +1,69 @@
+//! Original PTX specification:
+//!
+//! barrier.cluster.arrive{.sem}{.aligned};
+//! barrier.cluster.wait{.acquire}{.aligned};
+//! .sem = {.release, .relaxed};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::barrier_cluster::section_0::*;
+
+    impl Metadata for BarrierClusterArriveSemAligned {
+        fn opcode(&self) -> &'static str {
+            "barrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".cluster");
+            f(".arrive");
+            if let Some(sem_0) = self.sem.as_ref() {
+                match sem_0 {
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                }
+            }
+            if self.aligned {
+                f(".aligned");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "barrier.cluster.txt",
+                section: 0,
+                line: 1,
+                rule: "barrier.cluster.arrive{.sem}{.aligned};",
+            }
+        }
+    }
+
+    impl Metadata for BarrierClusterWaitAcquireAligned {
+        fn opcode(&self) -> &'static str {
+            "barrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".cluster");
+            f(".wait");
+            if self.acquire {
+                f(".acquire");
+            }
+            if self.aligned {
+                f(".aligned");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "barrier.cluster.txt",
+                section: 0,
+                line: 2,
+                rule: "barrier.cluster.wait{.acquire}{.aligned};",
+            }
+        }
+    }
+}
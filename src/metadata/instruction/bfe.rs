@@ -0,0 +1,43 @@
+//! Original PTX specification:
+//!
+//! bfe.type  d, a, b, c;
+//! .type = { .u32, .u64, .s32, .s64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bfe::section_0::*;
+
+    impl Metadata for BfeType {
+        fn opcode(&self) -> &'static str {
+            "bfe"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bfe.txt",
+                section: 0,
+                line: 1,
+                rule: "bfe.type d, a, b, c;",
+            }
+        }
+    }
+}
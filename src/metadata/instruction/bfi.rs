@@ -0,0 +1,37 @@
+//! Original PTX specification:
+//!
+//! bfi.type  f, a, b, c, d;
+//! .type = { .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bfi::section_0::*;
+
+    impl Metadata for BfiType {
+        fn opcode(&self) -> &'static str {
+            "bfi"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bfi.txt",
+                section: 0,
+                line: 1,
+                rule: "bfi.type f, a, b, c, d;",
+            }
+        }
+    }
+}
@@ -0,0 +1,75 @@
+//! Original PTX specification:
+//!
+//! bfind.type           d, a;
+//! bfind.shiftamt.type  d, a;
+//! .type = { .u32, .u64, .s32, .s64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bfind::section_0::*;
+
+    impl Metadata for BfindType {
+        fn opcode(&self) -> &'static str {
+            "bfind"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bfind.txt",
+                section: 0,
+                line: 1,
+                rule: "bfind.type d, a;",
+            }
+        }
+    }
+
+    impl Metadata for BfindShiftamtType {
+        fn opcode(&self) -> &'static str {
+            "bfind"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".shiftamt");
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bfind.txt",
+                section: 0,
+                line: 2,
+                rule: "bfind.shiftamt.type d, a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//! Original PTX specification:
+//!
+//! bmsk.mode.b32  d, a, b;
+//! .mode = { .clamp, .wrap };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bmsk::section_0::*;
+
+    impl Metadata for BmskModeB32 {
+        fn opcode(&self) -> &'static str {
+            "bmsk"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.mode {
+                Mode::Clamp => {
+                    f(".clamp");
+                }
+                Mode::Wrap => {
+                    f(".wrap");
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bmsk.txt",
+                section: 0,
+                line: 1,
+                rule: "bmsk.mode.b32 d, a, b;",
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Original PTX specification:
+//!
+//! bra{.uni}  tgt;           // tgt is a label
+//! bra{.uni}  tgt;           // unconditional branch
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bra::section_0::*;
+
+    impl Metadata for BraUni {
+        fn opcode(&self) -> &'static str {
+            "bra"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bra.txt",
+                section: 0,
+                line: 1,
+                rule: "bra{.uni} tgt;",
+            }
+        }
+    }
+
+    impl Metadata for BraUni1 {
+        fn opcode(&self) -> &'static str {
+            "bra"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "bra.txt",
+                section: 0,
+                line: 2,
+                rule: "bra{.uni} tgt;",
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Original PTX specification:
+//!
+//! brkpt;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::brkpt::section_0::*;
+
+    impl Metadata for Brkpt {
+        fn opcode(&self) -> &'static str {
+            "brkpt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {}
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "brkpt.txt",
+                section: 0,
+                line: 1,
+                rule: "brkpt;",
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Original PTX specification:
+//!
+//! brx.idx{.uni} index, tlist;
+//! brx.idx{.uni} index, tlist;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::brx_idx::section_0::*;
+
+    impl Metadata for BrxIdxUni {
+        fn opcode(&self) -> &'static str {
+            "brx"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".idx");
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "brx.idx.txt",
+                section: 0,
+                line: 1,
+                rule: "brx.idx{.uni} index, tlist;",
+            }
+        }
+    }
+
+    impl Metadata for BrxIdxUni1 {
+        fn opcode(&self) -> &'static str {
+            "brx"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".idx");
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "brx.idx.txt",
+                section: 0,
+                line: 2,
+                rule: "brx.idx{.uni} index, tlist;",
+            }
+        }
+    }
+}
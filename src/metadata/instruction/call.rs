@@ -0,0 +1,194 @@
+//! Original PTX specification:
+//!
+//! // direct call to named function, func is a symbol
+//! call{.uni} (ret-param), func, (param-list);
+//! call{.uni} func, (param-list);
+//! call{.uni} func;
+//! // indirect call via pointer, with full list of call targets
+//! call{.uni} (ret-param), fptr, (param-list), flist;
+//! call{.uni} fptr, (param-list), flist;
+//! call{.uni} fptr, flist;
+//! // indirect call via pointer, with no knowledge of call targets
+//! call{.uni} (ret-param), fptr, (param-list), fproto;
+//! call{.uni} fptr, (param-list), fproto;
+//! call{.uni} fptr, fproto;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::call::section_0::*;
+
+    impl Metadata for CallUni {
+        fn opcode(&self) -> &'static str {
+            "call"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "call.txt",
+                section: 0,
+                line: 2,
+                rule: "call{.uni} (ret-param), func, (param-list);",
+            }
+        }
+    }
+
+    impl Metadata for CallUni1 {
+        fn opcode(&self) -> &'static str {
+            "call"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "call.txt",
+                section: 0,
+                line: 3,
+                rule: "call{.uni} func, (param-list);",
+            }
+        }
+    }
+
+    impl Metadata for CallUni2 {
+        fn opcode(&self) -> &'static str {
+            "call"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "call.txt",
+                section: 0,
+                line: 4,
+                rule: "call{.uni} func;",
+            }
+        }
+    }
+
+    impl Metadata for CallUni3 {
+        fn opcode(&self) -> &'static str {
+            "call"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "call.txt",
+                section: 0,
+                line: 6,
+                rule: "call{.uni} (ret-param), fptr, (param-list), flist;",
+            }
+        }
+    }
+
+    impl Metadata for CallUni4 {
+        fn opcode(&self) -> &'static str {
+            "call"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "call.txt",
+                section: 0,
+                line: 7,
+                rule: "call{.uni} fptr, (param-list), flist;",
+            }
+        }
+    }
+
+    impl Metadata for CallUni5 {
+        fn opcode(&self) -> &'static str {
+            "call"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "call.txt",
+                section: 0,
+                line: 8,
+                rule: "call{.uni} fptr, flist;",
+            }
+        }
+    }
+
+    impl Metadata for CallUni6 {
+        fn opcode(&self) -> &'static str {
+            "call"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "call.txt",
+                section: 0,
+                line: 10,
+                rule: "call{.uni} (ret-param), fptr, (param-list), fproto;",
+            }
+        }
+    }
+
+    impl Metadata for CallUni7 {
+        fn opcode(&self) -> &'static str {
+            "call"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "call.txt",
+                section: 0,
+                line: 11,
+                rule: "call{.uni} fptr, (param-list), fproto;",
+            }
+        }
+    }
+
+    impl Metadata for CallUni8 {
+        fn opcode(&self) -> &'static str {
+            "call"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "call.txt",
+                section: 0,
+                line: 12,
+                rule: "call{.uni} fptr, fproto;",
+            }
+        }
+    }
+}
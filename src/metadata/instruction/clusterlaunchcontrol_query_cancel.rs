@@ -0,0 +1,88 @@
+//! Original PTX specification:
+//!
+//! clusterlaunchcontrol.query_cancel.is_canceled.pred.b128 pred, try_cancel_response;
+//! clusterlaunchcontrol.query_cancel.get_first_ctaid.v4.b32.b128 {xdim, ydim, zdim, _},  try_cancel_response;
+//! clusterlaunchcontrol.query_cancel{.get_first_ctaid::dimension}.b32.b128 reg, try_cancel_response;
+//! .get_first_ctaid::dimension = { .get_first_ctaid::x, .get_first_ctaid::y, .get_first_ctaid::z };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::clusterlaunchcontrol_query_cancel::section_0::*;
+
+    impl Metadata for ClusterlaunchcontrolQueryCancelIsCanceledPredB128 {
+        fn opcode(&self) -> &'static str {
+            "clusterlaunchcontrol"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".query_cancel");
+            f(".is_canceled");
+            f(".pred");
+            f(".b128");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "clusterlaunchcontrol.query_cancel.txt",
+                section: 0,
+                line: 1,
+                rule: "clusterlaunchcontrol.query_cancel.is_canceled.pred.b128 pred, try_cancel_response;",
+            }
+        }
+    }
+
+    impl Metadata for ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128 {
+        fn opcode(&self) -> &'static str {
+            "clusterlaunchcontrol"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".query_cancel");
+            f(".get_first_ctaid");
+            f(".v4");
+            f(".b32");
+            f(".b128");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "clusterlaunchcontrol.query_cancel.txt",
+                section: 0,
+                line: 2,
+                rule: "clusterlaunchcontrol.query_cancel.get_first_ctaid.v4.b32.b128 {xdim, ydim, zdim, _}, try_cancel_response;",
+            }
+        }
+    }
+
+    impl Metadata for ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128 {
+        fn opcode(&self) -> &'static str {
+            "clusterlaunchcontrol"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".query_cancel");
+            if let Some(get_first_ctaid_dimension_0) = self.get_first_ctaid_dimension.as_ref() {
+                match get_first_ctaid_dimension_0 {
+                    GetFirstCtaidDimension::GetFirstCtaidX => {
+                        f(".get_first_ctaid::x");
+                    }
+                    GetFirstCtaidDimension::GetFirstCtaidY => {
+                        f(".get_first_ctaid::y");
+                    }
+                    GetFirstCtaidDimension::GetFirstCtaidZ => {
+                        f(".get_first_ctaid::z");
+                    }
+                }
+            }
+            f(".b32");
+            f(".b128");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "clusterlaunchcontrol.query_cancel.txt",
+                section: 0,
+                line: 3,
+                rule: "clusterlaunchcontrol.query_cancel{.get_first_ctaid::dimension}.b32.b128 reg, try_cancel_response;",
+            }
+        }
+    }
+}
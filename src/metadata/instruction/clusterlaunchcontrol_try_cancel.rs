@@ -0,0 +1,50 @@
+//! Original PTX specification:
+//!
+//! clusterlaunchcontrol.try_cancel.async{.space}.completion_mechanism{.multicast::cluster::all}.b128 [addr], [mbar];
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .space = { .shared::cta };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::clusterlaunchcontrol_try_cancel::section_0::*;
+
+    impl Metadata
+        for ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128
+    {
+        fn opcode(&self) -> &'static str {
+            "clusterlaunchcontrol"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".try_cancel");
+            f(".async");
+            if let Some(space_0) = self.space.as_ref() {
+                match space_0 {
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierCompleteTxBytes => {
+                    f(".mbarrier::complete_tx::bytes");
+                }
+            }
+            if self.multicast_cluster_all {
+                f(".multicast::cluster::all");
+            }
+            f(".b128");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "clusterlaunchcontrol.try_cancel.txt",
+                section: 0,
+                line: 1,
+                rule: "clusterlaunchcontrol.try_cancel.async{.space}.completion_mechanism{.multicast::cluster::all}.b128 [addr], [mbar];",
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Original PTX specification:
+//!
+//! clz.type  d, a;
+//! .type = { .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::clz::section_0::*;
+
+    impl Metadata for ClzType {
+        fn opcode(&self) -> &'static str {
+            "clz"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "clz.txt",
+                section: 0,
+                line: 1,
+                rule: "clz.type d, a;",
+            }
+        }
+    }
+}
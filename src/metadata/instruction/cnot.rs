@@ -0,0 +1,40 @@
+//! Original PTX specification:
+//!
+//! cnot.type d, a;
+//! .type = { .b16, .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cnot::section_0::*;
+
+    impl Metadata for CnotType {
+        fn opcode(&self) -> &'static str {
+            "cnot"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cnot.txt",
+                section: 0,
+                line: 1,
+                rule: "cnot.type d, a;",
+            }
+        }
+    }
+}
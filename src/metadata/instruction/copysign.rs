@@ -0,0 +1,37 @@
+//! Original PTX specification:
+//!
+//! copysign.type  d, a, b;
+//! .type = { .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::copysign::section_0::*;
+
+    impl Metadata for CopysignType {
+        fn opcode(&self) -> &'static str {
+            "copysign"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "copysign.txt",
+                section: 0,
+                line: 1,
+                rule: "copysign.type d, a, b;",
+            }
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Original PTX specification:
+//!
+//! cp.async.ca.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], cp-size{, src-size}{, cache-policy};
+//! cp.async.cg.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], 16{, src-size}{, cache-policy};
+//! cp.async.ca.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], cp-size{, ignore-src}{, cache-policy} ;
+//! cp.async.cg.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], 16{, ignore-src}{, cache-policy} ;
+//! .level::cache_hint =     { .L2::cache_hint };
+//! .level::prefetch_size =  { .L2::64B, .L2::128B, .L2::256B };
+//! cp-size = { 4, 8, 16 };
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async::section_0::*;
+
+    impl Metadata for CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".ca");
+            match &self.state {
+                State::SharedCta => {
+                    f(".shared::cta");
+                }
+                State::Shared => {
+                    f(".shared");
+                }
+            }
+            f(".global");
+            if let Some(level_cache_hint_0) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_0 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            if let Some(level_prefetch_size_1) = self.level_prefetch_size.as_ref() {
+                match level_prefetch_size_1 {
+                    LevelPrefetchSize::L2128b => {
+                        f(".L2::128B");
+                    }
+                    LevelPrefetchSize::L2256b => {
+                        f(".L2::256B");
+                    }
+                    LevelPrefetchSize::L264b => {
+                        f(".L2::64B");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.txt",
+                section: 0,
+                line: 1,
+                rule: "cp.async.ca.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], cp-size{, src-size}{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".cg");
+            match &self.state {
+                State::SharedCta => {
+                    f(".shared::cta");
+                }
+                State::Shared => {
+                    f(".shared");
+                }
+            }
+            f(".global");
+            if let Some(level_cache_hint_2) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_2 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            if let Some(level_prefetch_size_3) = self.level_prefetch_size.as_ref() {
+                match level_prefetch_size_3 {
+                    LevelPrefetchSize::L2128b => {
+                        f(".L2::128B");
+                    }
+                    LevelPrefetchSize::L2256b => {
+                        f(".L2::256B");
+                    }
+                    LevelPrefetchSize::L264b => {
+                        f(".L2::64B");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.txt",
+                section: 0,
+                line: 2,
+                rule: "cp.async.cg.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], 16{, src-size}{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1 {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".ca");
+            match &self.state {
+                State::SharedCta => {
+                    f(".shared::cta");
+                }
+                State::Shared => {
+                    f(".shared");
+                }
+            }
+            f(".global");
+            if let Some(level_cache_hint_4) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_4 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            if let Some(level_prefetch_size_5) = self.level_prefetch_size.as_ref() {
+                match level_prefetch_size_5 {
+                    LevelPrefetchSize::L2128b => {
+                        f(".L2::128B");
+                    }
+                    LevelPrefetchSize::L2256b => {
+                        f(".L2::256B");
+                    }
+                    LevelPrefetchSize::L264b => {
+                        f(".L2::64B");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.txt",
+                section: 0,
+                line: 3,
+                rule: "cp.async.ca.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], cp-size{, ignore-src}{, cache-policy} ;",
+            }
+        }
+    }
+
+    impl Metadata for CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1 {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".cg");
+            match &self.state {
+                State::SharedCta => {
+                    f(".shared::cta");
+                }
+                State::Shared => {
+                    f(".shared");
+                }
+            }
+            f(".global");
+            if let Some(level_cache_hint_6) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_6 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            if let Some(level_prefetch_size_7) = self.level_prefetch_size.as_ref() {
+                match level_prefetch_size_7 {
+                    LevelPrefetchSize::L2128b => {
+                        f(".L2::128B");
+                    }
+                    LevelPrefetchSize::L2256b => {
+                        f(".L2::256B");
+                    }
+                    LevelPrefetchSize::L264b => {
+                        f(".L2::64B");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.txt",
+                section: 0,
+                line: 4,
+                rule: "cp.async.cg.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], 16{, ignore-src}{, cache-policy} ;",
+            }
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Original PTX specification:
+//!
+//! // global -> shared::cta
+//! cp.async.bulk.dst.src.completion_mechanism{.level::cache_hint} [dstMem], [srcMem], size, [mbar] {, cache-policy};
+//! .dst =                  { .shared::cta };
+//! .src =                  { .global };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .level::cache_hint =    { .L2::cache_hint };
+//! ----------------------------------------------------------------
+//! // global -> shared::cluster;
+//! cp.async.bulk.dst.src.completion_mechanism{.multicast}{.level::cache_hint} [dstMem], [srcMem], size, [mbar] {, ctaMask} {, cache-policy};
+//! .dst =                  { .shared::cluster };
+//! .src =                  { .global };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .level::cache_hint =    { .L2::cache_hint };
+//! .multicast =            { .multicast::cluster  };
+//! ----------------------------------------------------------------
+//! // shared::cta -> shared::cluster
+//! cp.async.bulk.dst.src.completion_mechanism [dstMem], [srcMem], size, [mbar];
+//! .dst =                  { .shared::cluster };
+//! .src =                  { .shared::cta };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ----------------------------------------------------------------
+//! // shared::cta -> global
+//! cp.async.bulk.dst.src.completion_mechanism{.level::cache_hint}{.cp_mask} [dstMem], [srcMem], size {, cache-policy} {, byteMask};
+//! .dst =                  { .global };
+//! .src =                  { .shared::cta };
+//! .completion_mechanism = { .bulk_group };
+//! .level::cache_hint =    { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk::section_0::*;
+
+    impl Metadata for CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".bulk");
+            match &self.dst {
+                Dst::SharedCta => {
+                    f(".shared::cta");
+                }
+            }
+            match &self.src {
+                Src::Global => {
+                    f(".global");
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierCompleteTxBytes => {
+                    f(".mbarrier::complete_tx::bytes");
+                }
+            }
+            if let Some(level_cache_hint_0) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_0 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.bulk.txt",
+                section: 0,
+                line: 2,
+                rule: "cp.async.bulk.dst.src.completion_mechanism{.level::cache_hint} [dstMem], [srcMem], size, [mbar] {, cache-policy};",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk::section_1::*;
+
+    impl Metadata for CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".bulk");
+            match &self.dst {
+                Dst::SharedCluster => {
+                    f(".shared::cluster");
+                }
+            }
+            match &self.src {
+                Src::Global => {
+                    f(".global");
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierCompleteTxBytes => {
+                    f(".mbarrier::complete_tx::bytes");
+                }
+            }
+            if let Some(multicast_1) = self.multicast.as_ref() {
+                match multicast_1 {
+                    Multicast::MulticastCluster => {
+                        f(".multicast::cluster");
+                    }
+                }
+            }
+            if let Some(level_cache_hint_2) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_2 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.bulk.txt",
+                section: 1,
+                line: 9,
+                rule: "cp.async.bulk.dst.src.completion_mechanism{.multicast}{.level::cache_hint} [dstMem], [srcMem], size, [mbar] {, ctaMask} {, cache-policy};",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk::section_2::*;
+
+    impl Metadata for CpAsyncBulkDstSrcCompletionMechanism {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".bulk");
+            match &self.dst {
+                Dst::SharedCluster => {
+                    f(".shared::cluster");
+                }
+            }
+            match &self.src {
+                Src::SharedCta => {
+                    f(".shared::cta");
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierCompleteTxBytes => {
+                    f(".mbarrier::complete_tx::bytes");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.bulk.txt",
+                section: 2,
+                line: 17,
+                rule: "cp.async.bulk.dst.src.completion_mechanism [dstMem], [srcMem], size, [mbar];",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk::section_3::*;
+
+    impl Metadata for CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".bulk");
+            match &self.dst {
+                Dst::Global => {
+                    f(".global");
+                }
+            }
+            match &self.src {
+                Src::SharedCta => {
+                    f(".shared::cta");
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::BulkGroup => {
+                    f(".bulk_group");
+                }
+            }
+            if let Some(level_cache_hint_3) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_3 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            if self.cp_mask {
+                f(".cp_mask");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.bulk.txt",
+                section: 3,
+                line: 23,
+                rule: "cp.async.bulk.dst.src.completion_mechanism{.level::cache_hint}{.cp_mask} [dstMem], [srcMem], size {, cache-policy} {, byteMask};",
+            }
+        }
+    }
+}
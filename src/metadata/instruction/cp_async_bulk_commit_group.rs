@@ -0,0 +1,31 @@
+//! Original PTX specification:
+//!
+//! cp.async.bulk.commit_group;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_commit_group::section_0::*;
+
+    impl Metadata for CpAsyncBulkCommitGroup {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".bulk");
+            f(".commit_group");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.bulk.commit_group.txt",
+                section: 0,
+                line: 1,
+                rule: "cp.async.bulk.commit_group;",
+            }
+        }
+    }
+}
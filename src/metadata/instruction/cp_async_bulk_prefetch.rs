@@ -0,0 +1,46 @@
+//! Original PTX specification:
+//!
+//! cp.async.bulk.prefetch.L2.src{.level::cache_hint}   [srcMem], size {, cache-policy};
+//! .src =                { .global };
+//! .level::cache_hint =  { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_prefetch::section_0::*;
+
+    impl Metadata for CpAsyncBulkPrefetchL2SrcLevelCacheHint {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".bulk");
+            f(".prefetch");
+            f(".L2");
+            match &self.src {
+                Src::Global => {
+                    f(".global");
+                }
+            }
+            if let Some(level_cache_hint_0) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_0 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.bulk.prefetch.txt",
+                section: 0,
+                line: 1,
+                rule: "cp.async.bulk.prefetch.L2.src{.level::cache_hint} [srcMem], size {, cache-policy};",
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+//! Original PTX specification:
+//!
+//! // global -> shared::cluster:
+//! cp.async.bulk.prefetch.tensor.dim.L2.src{.load_mode}{.level::cache_hint} [tensorMap, tensorCoords] {, im2colInfo } {, cache-policy};
+//! .src =                { .global };
+//! .dim =                { .1d, .2d, .3d, .4d, .5d };
+//! .load_mode =          { .tile, .tile::gather4, .im2col, .im2col::w, .im2col::w::128 };
+//! .level::cache_hint =  { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_prefetch_tensor::section_0::*;
+
+    impl Metadata for CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".bulk");
+            f(".prefetch");
+            f(".tensor");
+            match &self.dim {
+                Dim::_1d => {
+                    f(".1d");
+                }
+                Dim::_2d => {
+                    f(".2d");
+                }
+                Dim::_3d => {
+                    f(".3d");
+                }
+                Dim::_4d => {
+                    f(".4d");
+                }
+                Dim::_5d => {
+                    f(".5d");
+                }
+            }
+            f(".L2");
+            match &self.src {
+                Src::Global => {
+                    f(".global");
+                }
+            }
+            if let Some(load_mode_0) = self.load_mode.as_ref() {
+                match load_mode_0 {
+                    LoadMode::Im2colW128 => {
+                        f(".im2col::w::128");
+                    }
+                    LoadMode::TileGather4 => {
+                        f(".tile::gather4");
+                    }
+                    LoadMode::Im2colW => {
+                        f(".im2col::w");
+                    }
+                    LoadMode::Im2col => {
+                        f(".im2col");
+                    }
+                    LoadMode::Tile => {
+                        f(".tile");
+                    }
+                }
+            }
+            if let Some(level_cache_hint_1) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_1 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.bulk.prefetch.tensor.txt",
+                section: 0,
+                line: 2,
+                rule: "cp.async.bulk.prefetch.tensor.dim.L2.src{.load_mode}{.level::cache_hint} [tensorMap, tensorCoords] {, im2colInfo } {, cache-policy};",
+            }
+        }
+    }
+}
@@ -0,0 +1,304 @@
+//! Original PTX specification:
+//!
+//! // global -> shared::cta
+//! cp.async.bulk.tensor.dim.dst.src{.load_mode}.completion_mechanism{.cta_group}{.level::cache_hint} [dstMem], [tensorMap, tensorCoords], [mbar]{, im2colInfo} {, cache-policy};
+//! .dst =                  { .shared::cta };
+//! .src =                  { .global };
+//! .dim =                  { .1d, .2d, .3d, .4d, .5d };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .cta_group =            { .cta_group::1, .cta_group::2 };
+//! .load_mode =            { .tile, .tile::gather4, .im2col, .im2col::w, .im2col::w::128 };
+//! .level::cache_hint =    { .L2::cache_hint };
+//! ----------------------------------------------------------------
+//! // global -> shared::cluster
+//! cp.async.bulk.tensor.dim.dst.src{.load_mode}.completion_mechanism{.multicast}{.cta_group}{.level::cache_hint} [dstMem], [tensorMap, tensorCoords], [mbar]{, im2colInfo} {, ctaMask} {, cache-policy};
+//! .dst =                  { .shared::cluster };
+//! .src =                  { .global };
+//! .dim =                  { .1d, .2d, .3d, .4d, .5d };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .cta_group =            { .cta_group::1, .cta_group::2 };
+//! .load_mode =            { .tile, .tile::gather4, .im2col, .im2col::w, .im2col::w::128 };
+//! .level::cache_hint =    { .L2::cache_hint };
+//! .multicast =            { .multicast::cluster  };
+//! ----------------------------------------------------------------
+//! // shared::cta -> global;
+//! cp.async.bulk.tensor.dim.dst.src{.load_mode}.completion_mechanism{.level::cache_hint} [tensorMap, tensorCoords], [srcMem] {, cache-policy};
+//! .dst =                  { .global };
+//! .src =                  { .shared::cta };
+//! .dim =                  { .1d, .2d, .3d, .4d, .5d };
+//! .completion_mechanism = { .bulk_group };
+//! .load_mode =            { .tile, .tile::scatter4, .im2col_no_offs };
+//! .level::cache_hint =    { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_tensor::section_0::*;
+
+    impl Metadata for CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".bulk");
+            f(".tensor");
+            match &self.dim {
+                Dim::_1d => {
+                    f(".1d");
+                }
+                Dim::_2d => {
+                    f(".2d");
+                }
+                Dim::_3d => {
+                    f(".3d");
+                }
+                Dim::_4d => {
+                    f(".4d");
+                }
+                Dim::_5d => {
+                    f(".5d");
+                }
+            }
+            match &self.dst {
+                Dst::SharedCta => {
+                    f(".shared::cta");
+                }
+            }
+            match &self.src {
+                Src::Global => {
+                    f(".global");
+                }
+            }
+            if let Some(load_mode_0) = self.load_mode.as_ref() {
+                match load_mode_0 {
+                    LoadMode::Im2colW128 => {
+                        f(".im2col::w::128");
+                    }
+                    LoadMode::TileGather4 => {
+                        f(".tile::gather4");
+                    }
+                    LoadMode::Im2colW => {
+                        f(".im2col::w");
+                    }
+                    LoadMode::Im2col => {
+                        f(".im2col");
+                    }
+                    LoadMode::Tile => {
+                        f(".tile");
+                    }
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierCompleteTxBytes => {
+                    f(".mbarrier::complete_tx::bytes");
+                }
+            }
+            if let Some(cta_group_1) = self.cta_group.as_ref() {
+                match cta_group_1 {
+                    CtaGroup::CtaGroup1 => {
+                        f(".cta_group::1");
+                    }
+                    CtaGroup::CtaGroup2 => {
+                        f(".cta_group::2");
+                    }
+                }
+            }
+            if let Some(level_cache_hint_2) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_2 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.bulk.tensor.txt",
+                section: 0,
+                line: 2,
+                rule: "cp.async.bulk.tensor.dim.dst.src{.load_mode}.completion_mechanism{.cta_group}{.level::cache_hint} [dstMem], [tensorMap, tensorCoords], [mbar]{, im2colInfo} {, cache-policy};",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_tensor::section_1::*;
+
+    impl Metadata
+        for CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint
+    {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".bulk");
+            f(".tensor");
+            match &self.dim {
+                Dim::_1d => {
+                    f(".1d");
+                }
+                Dim::_2d => {
+                    f(".2d");
+                }
+                Dim::_3d => {
+                    f(".3d");
+                }
+                Dim::_4d => {
+                    f(".4d");
+                }
+                Dim::_5d => {
+                    f(".5d");
+                }
+            }
+            match &self.dst {
+                Dst::SharedCluster => {
+                    f(".shared::cluster");
+                }
+            }
+            match &self.src {
+                Src::Global => {
+                    f(".global");
+                }
+            }
+            if let Some(load_mode_3) = self.load_mode.as_ref() {
+                match load_mode_3 {
+                    LoadMode::Im2colW128 => {
+                        f(".im2col::w::128");
+                    }
+                    LoadMode::TileGather4 => {
+                        f(".tile::gather4");
+                    }
+                    LoadMode::Im2colW => {
+                        f(".im2col::w");
+                    }
+                    LoadMode::Im2col => {
+                        f(".im2col");
+                    }
+                    LoadMode::Tile => {
+                        f(".tile");
+                    }
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierCompleteTxBytes => {
+                    f(".mbarrier::complete_tx::bytes");
+                }
+            }
+            if let Some(multicast_4) = self.multicast.as_ref() {
+                match multicast_4 {
+                    Multicast::MulticastCluster => {
+                        f(".multicast::cluster");
+                    }
+                }
+            }
+            if let Some(cta_group_5) = self.cta_group.as_ref() {
+                match cta_group_5 {
+                    CtaGroup::CtaGroup1 => {
+                        f(".cta_group::1");
+                    }
+                    CtaGroup::CtaGroup2 => {
+                        f(".cta_group::2");
+                    }
+                }
+            }
+            if let Some(level_cache_hint_6) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_6 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.bulk.tensor.txt",
+                section: 1,
+                line: 12,
+                rule: "cp.async.bulk.tensor.dim.dst.src{.load_mode}.completion_mechanism{.multicast}{.cta_group}{.level::cache_hint} [dstMem], [tensorMap, tensorCoords], [mbar]{, im2colInfo} {, ctaMask} {, cache-policy};",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_tensor::section_2::*;
+
+    impl Metadata for CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".bulk");
+            f(".tensor");
+            match &self.dim {
+                Dim::_1d => {
+                    f(".1d");
+                }
+                Dim::_2d => {
+                    f(".2d");
+                }
+                Dim::_3d => {
+                    f(".3d");
+                }
+                Dim::_4d => {
+                    f(".4d");
+                }
+                Dim::_5d => {
+                    f(".5d");
+                }
+            }
+            match &self.dst {
+                Dst::Global => {
+                    f(".global");
+                }
+            }
+            match &self.src {
+                Src::SharedCta => {
+                    f(".shared::cta");
+                }
+            }
+            if let Some(load_mode_7) = self.load_mode.as_ref() {
+                match load_mode_7 {
+                    LoadMode::TileScatter4 => {
+                        f(".tile::scatter4");
+                    }
+                    LoadMode::Im2colNoOffs => {
+                        f(".im2col_no_offs");
+                    }
+                    LoadMode::Tile => {
+                        f(".tile");
+                    }
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::BulkGroup => {
+                    f(".bulk_group");
+                }
+            }
+            if let Some(level_cache_hint_8) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_8 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.bulk.tensor.txt",
+                section: 2,
+                line: 23,
+                rule: "cp.async.bulk.tensor.dim.dst.src{.load_mode}.completion_mechanism{.level::cache_hint} [tensorMap, tensorCoords], [srcMem] {, cache-policy};",
+            }
+        }
+    }
+}
@@ -0,0 +1,34 @@
+//! Original PTX specification:
+//!
+//! cp.async.bulk.wait_group{.read} N;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_wait_group::section_0::*;
+
+    impl Metadata for CpAsyncBulkWaitGroupRead {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".bulk");
+            f(".wait_group");
+            if self.read {
+                f(".read");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.bulk.wait_group.txt",
+                section: 0,
+                line: 1,
+                rule: "cp.async.bulk.wait_group{.read} N;",
+            }
+        }
+    }
+}
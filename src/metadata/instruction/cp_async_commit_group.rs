@@ -0,0 +1,30 @@
+//! Original PTX specification:
+//!
+//! cp.async.commit_group ;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_commit_group::section_0::*;
+
+    impl Metadata for CpAsyncCommitGroup {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".commit_group");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.commit_group.txt",
+                section: 0,
+                line: 1,
+                rule: "cp.async.commit_group ;",
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Original PTX specification:
+//!
+//! cp.async.mbarrier.arrive{.noinc}{.state}.b64 [addr];
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_mbarrier_arrive::section_0::*;
+
+    impl Metadata for CpAsyncMbarrierArriveNoincStateB64 {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".mbarrier");
+            f(".arrive");
+            if self.noinc {
+                f(".noinc");
+            }
+            if let Some(state_0) = self.state.as_ref() {
+                match state_0 {
+                    State::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    State::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.mbarrier.arrive.txt",
+                section: 0,
+                line: 1,
+                rule: "cp.async.mbarrier.arrive{.noinc}{.state}.b64 [addr];",
+            }
+        }
+    }
+}
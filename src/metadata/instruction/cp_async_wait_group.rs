@@ -0,0 +1,49 @@
+//! Original PTX specification:
+//!
+//! cp.async.wait_group N;
+//! cp.async.wait_all ;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_wait_group::section_0::*;
+
+    impl Metadata for CpAsyncWaitGroup {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".wait_group");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.wait_group.txt",
+                section: 0,
+                line: 1,
+                rule: "cp.async.wait_group N;",
+            }
+        }
+    }
+
+    impl Metadata for CpAsyncWaitAll {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            f(".wait_all");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.async.wait_group.txt",
+                section: 0,
+                line: 2,
+                rule: "cp.async.wait_all ;",
+            }
+        }
+    }
+}
@@ -0,0 +1,250 @@
+//! Original PTX specification:
+//!
+//! cp.reduce.async.bulk.dst.src.completion_mechanism.redOp.type [dstMem], [srcMem], size, [mbar];
+//! .dst =                  { .shared::cluster };
+//! .src =                  { .shared::cta };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .redOp=                 { .and, .or, .xor, .add, .inc, .dec, .min, .max };
+//! .type =                 { .b32, .u32, .s32, .b64, .u64 };
+//! ----------------------------------------------------------------
+//! cp.reduce.async.bulk.dst.src.completion_mechanism{.level::cache_hint}.redOp.type [dstMem], [srcMem], size{, cache-policy};
+//! .dst =                  { .global      };
+//! .src =                  { .shared::cta };
+//! ----------------------------------------------------------------
+//! .completion_mechanism = { .bulk_group };
+//! .level::cache_hint    = { .L2::cache_hint };
+//! .redOp=                 { .and, .or, .xor, .add, .inc, .dec, .min, .max };
+//! .type =                 { .f16, .bf16, .b32, .u32, .s32, .b64, .u64, .s64, .f32, .f64 };
+//! ----------------------------------------------------------------
+//! cp.reduce.async.bulk.dst.src.completion_mechanism{.level::cache_hint}.add.noftz.type [dstMem], [srcMem], size{, cache-policy};
+//! .dst  =                 { .global };
+//! .src  =                 { .shared::cta };
+//! .completion_mechanism = { .bulk_group };
+//! .type =                 { .f16, .bf16 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_reduce_async_bulk::section_0::*;
+
+    impl Metadata for CpReduceAsyncBulkDstSrcCompletionMechanismRedopType {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".reduce");
+            f(".async");
+            f(".bulk");
+            match &self.dst {
+                Dst::SharedCluster => {
+                    f(".shared::cluster");
+                }
+            }
+            match &self.src {
+                Src::SharedCta => {
+                    f(".shared::cta");
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierCompleteTxBytes => {
+                    f(".mbarrier::complete_tx::bytes");
+                }
+            }
+            match &self.redop {
+                Redop::And => {
+                    f(".and");
+                }
+                Redop::Xor => {
+                    f(".xor");
+                }
+                Redop::Add => {
+                    f(".add");
+                }
+                Redop::Inc => {
+                    f(".inc");
+                }
+                Redop::Dec => {
+                    f(".dec");
+                }
+                Redop::Min => {
+                    f(".min");
+                }
+                Redop::Max => {
+                    f(".max");
+                }
+                Redop::Or => {
+                    f(".or");
+                }
+            }
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.reduce.async.bulk.txt",
+                section: 0,
+                line: 1,
+                rule: "cp.reduce.async.bulk.dst.src.completion_mechanism.redOp.type [dstMem], [srcMem], size, [mbar];",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::cp_reduce_async_bulk::section_1::*;
+
+    impl Metadata for CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".reduce");
+            f(".async");
+            f(".bulk");
+            match &self.dst {
+                Dst::Global => {
+                    f(".global");
+                }
+            }
+            match &self.src {
+                Src::SharedCta => {
+                    f(".shared::cta");
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierCompleteTxBytes => {
+                    f(".mbarrier::complete_tx::bytes");
+                }
+            }
+            if self.level_cache_hint {
+                f(".level::cache_hint");
+            }
+            match &self.redop {
+                Redop::And => {
+                    f(".and");
+                }
+                Redop::Xor => {
+                    f(".xor");
+                }
+                Redop::Add => {
+                    f(".add");
+                }
+                Redop::Inc => {
+                    f(".inc");
+                }
+                Redop::Dec => {
+                    f(".dec");
+                }
+                Redop::Min => {
+                    f(".min");
+                }
+                Redop::Max => {
+                    f(".max");
+                }
+                Redop::Or => {
+                    f(".or");
+                }
+            }
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.reduce.async.bulk.txt",
+                section: 1,
+                line: 8,
+                rule: "cp.reduce.async.bulk.dst.src.completion_mechanism{.level::cache_hint}.redOp.type [dstMem], [srcMem], size{, cache-policy};",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::cp_reduce_async_bulk::section_2::*;
+
+    impl Metadata for CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".reduce");
+            f(".async");
+            f(".bulk");
+            match &self.dst {
+                Dst::Global => {
+                    f(".global");
+                }
+            }
+            match &self.src {
+                Src::SharedCta => {
+                    f(".shared::cta");
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::BulkGroup => {
+                    f(".bulk_group");
+                }
+            }
+            if let Some(level_cache_hint_0) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_0 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            f(".add");
+            f(".noftz");
+            match &self.type_ {
+                Type::Bf16 => {
+                    f(".bf16");
+                }
+                Type::F16 => {
+                    f(".f16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.reduce.async.bulk.txt",
+                section: 2,
+                line: 17,
+                rule: "cp.reduce.async.bulk.dst.src.completion_mechanism{.level::cache_hint}.add.noftz.type [dstMem], [srcMem], size{, cache-policy};",
+            }
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! Original PTX specification:
+//!
+//! // shared::cta -> global
+//! cp.reduce.async.bulk.tensor.dim.dst.src.redOp{.load_mode}.completion_mechanism{.level::cache_hint} [tensorMap, tensorCoords], [srcMem] {,cache-policy};
+//! .dst =                  { .global };
+//! .src =                  { .shared::cta };
+//! .dim =                  { .1d, .2d, .3d, .4d, .5d };
+//! .completion_mechanism = { .bulk_group };
+//! .load_mode =            { .tile, .im2col_no_offs };
+//! .redOp =                { .add, .min, .max, .inc, .dec, .and, .or, .xor};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_reduce_async_bulk_tensor::section_0::*;
+
+    impl Metadata for CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint {
+        fn opcode(&self) -> &'static str {
+            "cp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".reduce");
+            f(".async");
+            f(".bulk");
+            f(".tensor");
+            match &self.dim {
+                Dim::_1d => {
+                    f(".1d");
+                }
+                Dim::_2d => {
+                    f(".2d");
+                }
+                Dim::_3d => {
+                    f(".3d");
+                }
+                Dim::_4d => {
+                    f(".4d");
+                }
+                Dim::_5d => {
+                    f(".5d");
+                }
+            }
+            match &self.dst {
+                Dst::Global => {
+                    f(".global");
+                }
+            }
+            match &self.src {
+                Src::SharedCta => {
+                    f(".shared::cta");
+                }
+            }
+            match &self.redop {
+                Redop::Add => {
+                    f(".add");
+                }
+                Redop::Min => {
+                    f(".min");
+                }
+                Redop::Max => {
+                    f(".max");
+                }
+                Redop::Inc => {
+                    f(".inc");
+                }
+                Redop::Dec => {
+                    f(".dec");
+                }
+                Redop::And => {
+                    f(".and");
+                }
+                Redop::Xor => {
+                    f(".xor");
+                }
+                Redop::Or => {
+                    f(".or");
+                }
+            }
+            if let Some(load_mode_0) = self.load_mode.as_ref() {
+                match load_mode_0 {
+                    LoadMode::Im2colNoOffs => {
+                        f(".im2col_no_offs");
+                    }
+                    LoadMode::Tile => {
+                        f(".tile");
+                    }
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::BulkGroup => {
+                    f(".bulk_group");
+                }
+            }
+            if self.level_cache_hint {
+                f(".level::cache_hint");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cp.reduce.async.bulk.tensor.txt",
+                section: 0,
+                line: 2,
+                rule: "cp.reduce.async.bulk.tensor.dim.dst.src.redOp{.load_mode}.completion_mechanism{.level::cache_hint} [tensorMap, tensorCoords], [srcMem] {,cache-policy};",
+            }
+        }
+    }
+}
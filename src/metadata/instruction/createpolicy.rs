@@ -0,0 +1,128 @@
+//! Original PTX specification:
+//!
+//! // Range-based policy
+//! createpolicy.range{.global}.level::primary_priority{.level::secondary_priority}.b64
+//! cache-policy, [a], primary-size, total-size;
+//! // Fraction-based policy
+//! createpolicy.fractional.level::primary_priority{.level::secondary_priority}.b64
+//! cache-policy{, fraction};
+//! // Converting the access property from CUDA APIs
+//! createpolicy.cvt.L2.b64            cache-policy, access-property;
+//! .level::primary_priority =   { .L2::evict_last, .L2::evict_normal,
+//! .L2::evict_first, .L2::evict_unchanged };
+//! .level::secondary_priority = { .L2::evict_first, .L2::evict_unchanged };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::createpolicy::section_0::*;
+
+    impl Metadata for CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64 {
+        fn opcode(&self) -> &'static str {
+            "createpolicy"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".range");
+            if self.global {
+                f(".global");
+            }
+            match &self.level_primary_priority {
+                LevelPrimaryPriority::L2EvictUnchanged => {
+                    f(".L2::evict_unchanged");
+                }
+                LevelPrimaryPriority::L2EvictNormal => {
+                    f(".L2::evict_normal");
+                }
+                LevelPrimaryPriority::L2EvictFirst => {
+                    f(".L2::evict_first");
+                }
+                LevelPrimaryPriority::L2EvictLast => {
+                    f(".L2::evict_last");
+                }
+            }
+            if let Some(level_secondary_priority_0) = self.level_secondary_priority.as_ref() {
+                match level_secondary_priority_0 {
+                    LevelSecondaryPriority::L2EvictUnchanged => {
+                        f(".L2::evict_unchanged");
+                    }
+                    LevelSecondaryPriority::L2EvictFirst => {
+                        f(".L2::evict_first");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "createpolicy.txt",
+                section: 0,
+                line: 2,
+                rule: "createpolicy.range{.global}.level::primary_priority{.level::secondary_priority}.b64 cache-policy, [a], primary-size, total-size;",
+            }
+        }
+    }
+
+    impl Metadata for CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64 {
+        fn opcode(&self) -> &'static str {
+            "createpolicy"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".fractional");
+            match &self.level_primary_priority {
+                LevelPrimaryPriority::L2EvictUnchanged => {
+                    f(".L2::evict_unchanged");
+                }
+                LevelPrimaryPriority::L2EvictNormal => {
+                    f(".L2::evict_normal");
+                }
+                LevelPrimaryPriority::L2EvictFirst => {
+                    f(".L2::evict_first");
+                }
+                LevelPrimaryPriority::L2EvictLast => {
+                    f(".L2::evict_last");
+                }
+            }
+            if let Some(level_secondary_priority_1) = self.level_secondary_priority.as_ref() {
+                match level_secondary_priority_1 {
+                    LevelSecondaryPriority::L2EvictUnchanged => {
+                        f(".L2::evict_unchanged");
+                    }
+                    LevelSecondaryPriority::L2EvictFirst => {
+                        f(".L2::evict_first");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "createpolicy.txt",
+                section: 0,
+                line: 5,
+                rule: "createpolicy.fractional.level::primary_priority{.level::secondary_priority}.b64 cache-policy{, fraction};",
+            }
+        }
+    }
+
+    impl Metadata for CreatepolicyCvtL2B64 {
+        fn opcode(&self) -> &'static str {
+            "createpolicy"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".cvt");
+            f(".L2");
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "createpolicy.txt",
+                section: 0,
+                line: 8,
+                rule: "createpolicy.cvt.L2.b64 cache-policy, access-property;",
+            }
+        }
+    }
+}
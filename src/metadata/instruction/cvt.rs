@@ -0,0 +1,872 @@
+//! Original PTX specification:
+//!
+//! cvt{.irnd}{.ftz}{.sat}.dtype.atype         d, a;  // integer rounding
+//! cvt{.frnd}{.ftz}{.sat}.dtype.atype         d, a;  // fp rounding
+//! cvt.frnd2{.relu}{.satfinite}.f16.f32       d, a;
+//! cvt.frnd2{.relu}{.satfinite}.f16x2.f32     d, a, b;
+//! cvt.rs{.relu}{.satfinite}.f16x2.f32        d, a, b, rbits;
+//! cvt.frnd2{.relu}{.satfinite}.bf16.f32      d, a;
+//! cvt.frnd2{.relu}{.satfinite}.bf16x2.f32    d, a, b;
+//! cvt.rs{.relu}{.satfinite}.bf16x2.f32       d, a, b, rbits;
+//! cvt.rna{.satfinite}.tf32.f32               d, a;
+//! cvt.frnd2{.satfinite}{.relu}.tf32.f32      d, a;
+//! cvt.rn.satfinite{.relu}.f8x2type.f32       d, a, b;
+//! cvt.rn.satfinite{.relu}.f8x2type.f16x2     d, a;
+//! cvt.rn{.relu}.f16x2.f8x2type              d, a;
+//! cvt.rs{.relu}.satfinite.f8x4type.f32       d, {a, b, e, f}, rbits;
+//! cvt.rn.satfinite{.relu}.f4x2type.f32       d, a, b;
+//! cvt.rn{.relu}.f16x2.f4x2type               d, a;
+//! cvt.rs{.relu}.satfinite.f4x4type.f32       d, {a, b, e, f}, rbits;
+//! cvt.rn.satfinite{.relu}.f6x2type.f32       d, a, b;
+//! cvt.rn{.relu}.f16x2.f6x2type               d, a;
+//! cvt.rs{.relu}.satfinite.f6x4type.f32       d, {a, b, e, f}, rbits;
+//! cvt.frnd3{.satfinite}.ue8m0x2.f32          d, a, b;
+//! cvt.frnd3{.satfinite}.ue8m0x2.bf16x2       d, a;
+//! cvt.rn.bf16x2.ue8m0x2                      d, a;
+//! .irnd   = { .rni, .rzi, .rmi, .rpi };
+//! .frnd   = { .rn,  .rz,  .rm,  .rp  };
+//! .frnd2  = { .rn,  .rz };
+//! .frnd3  = { .rz,  .rp };
+//! .dtype = .atype = { .u8,   .u16, .u32, .u64,
+//! .s8,   .s16, .s32, .s64,
+//! .bf16, .f16, .f32, .f64 };
+//! .f8x2type = { .e4m3x2, .e5m2x2 };
+//! .f4x2type = { .e2m1x2 };
+//! .f6x2type = { .e2m3x2, .e3m2x2 };
+//! .f4x4type = { .e2m1x4 };
+//! .f8x4type = { .e4m3x4, .e5m2x4 };
+//! .f6x4type = { .e2m3x4, .e3m2x4 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cvt::section_0::*;
+
+    impl Metadata for CvtIrndFtzSatDtypeAtype {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(irnd_0) = self.irnd.as_ref() {
+                match irnd_0 {
+                    Irnd::Rni => {
+                        f(".rni");
+                    }
+                    Irnd::Rzi => {
+                        f(".rzi");
+                    }
+                    Irnd::Rmi => {
+                        f(".rmi");
+                    }
+                    Irnd::Rpi => {
+                        f(".rpi");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            match &self.dtype {
+                Dtype::Bf16 => {
+                    f(".bf16");
+                }
+                Dtype::U16 => {
+                    f(".u16");
+                }
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::U64 => {
+                    f(".u64");
+                }
+                Dtype::S16 => {
+                    f(".s16");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::S64 => {
+                    f(".s64");
+                }
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+                Dtype::F64 => {
+                    f(".f64");
+                }
+                Dtype::U8 => {
+                    f(".u8");
+                }
+                Dtype::S8 => {
+                    f(".s8");
+                }
+            }
+            match &self.atype {
+                Atype::Bf16 => {
+                    f(".bf16");
+                }
+                Atype::U16 => {
+                    f(".u16");
+                }
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::U64 => {
+                    f(".u64");
+                }
+                Atype::S16 => {
+                    f(".s16");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+                Atype::S64 => {
+                    f(".s64");
+                }
+                Atype::F16 => {
+                    f(".f16");
+                }
+                Atype::F32 => {
+                    f(".f32");
+                }
+                Atype::F64 => {
+                    f(".f64");
+                }
+                Atype::U8 => {
+                    f(".u8");
+                }
+                Atype::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 1,
+                rule: "cvt{.irnd}{.ftz}{.sat}.dtype.atype d, a;",
+            }
+        }
+    }
+
+    impl Metadata for CvtFrndFtzSatDtypeAtype {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(frnd_1) = self.frnd.as_ref() {
+                match frnd_1 {
+                    Frnd::Rn => {
+                        f(".rn");
+                    }
+                    Frnd::Rz => {
+                        f(".rz");
+                    }
+                    Frnd::Rm => {
+                        f(".rm");
+                    }
+                    Frnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            match &self.dtype {
+                Dtype::Bf16 => {
+                    f(".bf16");
+                }
+                Dtype::U16 => {
+                    f(".u16");
+                }
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::U64 => {
+                    f(".u64");
+                }
+                Dtype::S16 => {
+                    f(".s16");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::S64 => {
+                    f(".s64");
+                }
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+                Dtype::F64 => {
+                    f(".f64");
+                }
+                Dtype::U8 => {
+                    f(".u8");
+                }
+                Dtype::S8 => {
+                    f(".s8");
+                }
+            }
+            match &self.atype {
+                Atype::Bf16 => {
+                    f(".bf16");
+                }
+                Atype::U16 => {
+                    f(".u16");
+                }
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::U64 => {
+                    f(".u64");
+                }
+                Atype::S16 => {
+                    f(".s16");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+                Atype::S64 => {
+                    f(".s64");
+                }
+                Atype::F16 => {
+                    f(".f16");
+                }
+                Atype::F32 => {
+                    f(".f32");
+                }
+                Atype::F64 => {
+                    f(".f64");
+                }
+                Atype::U8 => {
+                    f(".u8");
+                }
+                Atype::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 2,
+                rule: "cvt{.frnd}{.ftz}{.sat}.dtype.atype d, a;",
+            }
+        }
+    }
+
+    impl Metadata for CvtFrnd2ReluSatfiniteF16F32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.frnd2 {
+                Frnd2::Rn => {
+                    f(".rn");
+                }
+                Frnd2::Rz => {
+                    f(".rz");
+                }
+            }
+            if self.relu {
+                f(".relu");
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".f16");
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 3,
+                rule: "cvt.frnd2{.relu}{.satfinite}.f16.f32 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for CvtFrnd2ReluSatfiniteF16x2F32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.frnd2 {
+                Frnd2::Rn => {
+                    f(".rn");
+                }
+                Frnd2::Rz => {
+                    f(".rz");
+                }
+            }
+            if self.relu {
+                f(".relu");
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".f16x2");
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 4,
+                rule: "cvt.frnd2{.relu}{.satfinite}.f16x2.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRsReluSatfiniteF16x2F32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rs");
+            if self.relu {
+                f(".relu");
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".f16x2");
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 5,
+                rule: "cvt.rs{.relu}{.satfinite}.f16x2.f32 d, a, b, rbits;",
+            }
+        }
+    }
+
+    impl Metadata for CvtFrnd2ReluSatfiniteBf16F32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.frnd2 {
+                Frnd2::Rn => {
+                    f(".rn");
+                }
+                Frnd2::Rz => {
+                    f(".rz");
+                }
+            }
+            if self.relu {
+                f(".relu");
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".bf16");
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 6,
+                rule: "cvt.frnd2{.relu}{.satfinite}.bf16.f32 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for CvtFrnd2ReluSatfiniteBf16x2F32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.frnd2 {
+                Frnd2::Rn => {
+                    f(".rn");
+                }
+                Frnd2::Rz => {
+                    f(".rz");
+                }
+            }
+            if self.relu {
+                f(".relu");
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".bf16x2");
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 7,
+                rule: "cvt.frnd2{.relu}{.satfinite}.bf16x2.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRsReluSatfiniteBf16x2F32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rs");
+            if self.relu {
+                f(".relu");
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".bf16x2");
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 8,
+                rule: "cvt.rs{.relu}{.satfinite}.bf16x2.f32 d, a, b, rbits;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRnaSatfiniteTf32F32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rna");
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".tf32");
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 9,
+                rule: "cvt.rna{.satfinite}.tf32.f32 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for CvtFrnd2SatfiniteReluTf32F32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.frnd2 {
+                Frnd2::Rn => {
+                    f(".rn");
+                }
+                Frnd2::Rz => {
+                    f(".rz");
+                }
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            if self.relu {
+                f(".relu");
+            }
+            f(".tf32");
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 10,
+                rule: "cvt.frnd2{.satfinite}{.relu}.tf32.f32 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRnSatfiniteReluF8x2typeF32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rn");
+            f(".satfinite");
+            if self.relu {
+                f(".relu");
+            }
+            match &self.f8x2type {
+                F8x2type::E4m3x2 => {
+                    f(".e4m3x2");
+                }
+                F8x2type::E5m2x2 => {
+                    f(".e5m2x2");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 11,
+                rule: "cvt.rn.satfinite{.relu}.f8x2type.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRnSatfiniteReluF8x2typeF16x2 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rn");
+            f(".satfinite");
+            if self.relu {
+                f(".relu");
+            }
+            match &self.f8x2type {
+                F8x2type::E4m3x2 => {
+                    f(".e4m3x2");
+                }
+                F8x2type::E5m2x2 => {
+                    f(".e5m2x2");
+                }
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 12,
+                rule: "cvt.rn.satfinite{.relu}.f8x2type.f16x2 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRnReluF16x2F8x2type {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rn");
+            if self.relu {
+                f(".relu");
+            }
+            f(".f16x2");
+            match &self.f8x2type {
+                F8x2type::E4m3x2 => {
+                    f(".e4m3x2");
+                }
+                F8x2type::E5m2x2 => {
+                    f(".e5m2x2");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 13,
+                rule: "cvt.rn{.relu}.f16x2.f8x2type d, a;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRsReluSatfiniteF8x4typeF32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rs");
+            if self.relu {
+                f(".relu");
+            }
+            f(".satfinite");
+            match &self.f8x4type {
+                F8x4type::E4m3x4 => {
+                    f(".e4m3x4");
+                }
+                F8x4type::E5m2x4 => {
+                    f(".e5m2x4");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 14,
+                rule: "cvt.rs{.relu}.satfinite.f8x4type.f32 d, {a, b, e, f}, rbits;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRnSatfiniteReluF4x2typeF32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rn");
+            f(".satfinite");
+            if self.relu {
+                f(".relu");
+            }
+            match &self.f4x2type {
+                F4x2type::E2m1x2 => {
+                    f(".e2m1x2");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 15,
+                rule: "cvt.rn.satfinite{.relu}.f4x2type.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRnReluF16x2F4x2type {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rn");
+            if self.relu {
+                f(".relu");
+            }
+            f(".f16x2");
+            match &self.f4x2type {
+                F4x2type::E2m1x2 => {
+                    f(".e2m1x2");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 16,
+                rule: "cvt.rn{.relu}.f16x2.f4x2type d, a;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRsReluSatfiniteF4x4typeF32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rs");
+            if self.relu {
+                f(".relu");
+            }
+            f(".satfinite");
+            match &self.f4x4type {
+                F4x4type::E2m1x4 => {
+                    f(".e2m1x4");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 17,
+                rule: "cvt.rs{.relu}.satfinite.f4x4type.f32 d, {a, b, e, f}, rbits;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRnSatfiniteReluF6x2typeF32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rn");
+            f(".satfinite");
+            if self.relu {
+                f(".relu");
+            }
+            match &self.f6x2type {
+                F6x2type::E2m3x2 => {
+                    f(".e2m3x2");
+                }
+                F6x2type::E3m2x2 => {
+                    f(".e3m2x2");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 18,
+                rule: "cvt.rn.satfinite{.relu}.f6x2type.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRnReluF16x2F6x2type {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rn");
+            if self.relu {
+                f(".relu");
+            }
+            f(".f16x2");
+            match &self.f6x2type {
+                F6x2type::E2m3x2 => {
+                    f(".e2m3x2");
+                }
+                F6x2type::E3m2x2 => {
+                    f(".e3m2x2");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 19,
+                rule: "cvt.rn{.relu}.f16x2.f6x2type d, a;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRsReluSatfiniteF6x4typeF32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rs");
+            if self.relu {
+                f(".relu");
+            }
+            f(".satfinite");
+            match &self.f6x4type {
+                F6x4type::E2m3x4 => {
+                    f(".e2m3x4");
+                }
+                F6x4type::E3m2x4 => {
+                    f(".e3m2x4");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 20,
+                rule: "cvt.rs{.relu}.satfinite.f6x4type.f32 d, {a, b, e, f}, rbits;",
+            }
+        }
+    }
+
+    impl Metadata for CvtFrnd3SatfiniteUe8m0x2F32 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.frnd3 {
+                Frnd3::Rz => {
+                    f(".rz");
+                }
+                Frnd3::Rp => {
+                    f(".rp");
+                }
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".ue8m0x2");
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 21,
+                rule: "cvt.frnd3{.satfinite}.ue8m0x2.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for CvtFrnd3SatfiniteUe8m0x2Bf16x2 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.frnd3 {
+                Frnd3::Rz => {
+                    f(".rz");
+                }
+                Frnd3::Rp => {
+                    f(".rp");
+                }
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".ue8m0x2");
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 22,
+                rule: "cvt.frnd3{.satfinite}.ue8m0x2.bf16x2 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for CvtRnBf16x2Ue8m0x2 {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".rn");
+            f(".bf16x2");
+            f(".ue8m0x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.txt",
+                section: 0,
+                line: 23,
+                rule: "cvt.rn.bf16x2.ue8m0x2 d, a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Original PTX specification:
+//!
+//! cvt.pack.sat.convertType.abType  d, a, b;
+//! .convertType  = { .u16, .s16 };
+//! .abType       = { .s32 };
+//! ----------------------------------------------------------------
+//! cvt.pack.sat.convertType.abType.cType  d, a, b, c;
+//! .convertType  = { .u2, .s2, .u4, .s4, .u8, .s8 };
+//! .abType       = { .s32 };
+//! .cType        = { .b32 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cvt_pack::section_0::*;
+
+    impl Metadata for CvtPackSatConverttypeAbtype {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".pack");
+            f(".sat");
+            match &self.converttype {
+                Converttype::U16 => {
+                    f(".u16");
+                }
+                Converttype::S16 => {
+                    f(".s16");
+                }
+            }
+            match &self.abtype {
+                Abtype::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.pack.txt",
+                section: 0,
+                line: 1,
+                rule: "cvt.pack.sat.convertType.abType d, a, b;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::cvt_pack::section_1::*;
+
+    impl Metadata for CvtPackSatConverttypeAbtypeCtype {
+        fn opcode(&self) -> &'static str {
+            "cvt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".pack");
+            f(".sat");
+            match &self.converttype {
+                Converttype::U2 => {
+                    f(".u2");
+                }
+                Converttype::S2 => {
+                    f(".s2");
+                }
+                Converttype::U4 => {
+                    f(".u4");
+                }
+                Converttype::S4 => {
+                    f(".s4");
+                }
+                Converttype::U8 => {
+                    f(".u8");
+                }
+                Converttype::S8 => {
+                    f(".s8");
+                }
+            }
+            match &self.abtype {
+                Abtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.ctype {
+                Ctype::B32 => {
+                    f(".b32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvt.pack.txt",
+                section: 1,
+                line: 5,
+                rule: "cvt.pack.sat.convertType.abType.cType d, a, b, c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,120 @@
+//! Original PTX specification:
+//!
+//! // convert const, global, local, or shared address to generic address
+//! cvta.space.size  p, a;        // source address in register a
+//! // cvta.space.size  p, var;      // get generic address of var
+//! // cvta.space.size  p, var+imm;  // generic address of var+offset
+//! // convert generic address to const, global, local, or shared address
+//! cvta.to.space.size  p, a;
+//! .space = { .const, .global, .local, .shared, .shared::cta, .shared::cluster, .param, .param::entry };
+//! .size  = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cvta::section_0::*;
+
+    impl Metadata for CvtaSpaceSize {
+        fn opcode(&self) -> &'static str {
+            "cvta"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.space {
+                Space::SharedCluster => {
+                    f(".shared::cluster");
+                }
+                Space::ParamEntry => {
+                    f(".param::entry");
+                }
+                Space::SharedCta => {
+                    f(".shared::cta");
+                }
+                Space::Global => {
+                    f(".global");
+                }
+                Space::Shared => {
+                    f(".shared");
+                }
+                Space::Const => {
+                    f(".const");
+                }
+                Space::Local => {
+                    f(".local");
+                }
+                Space::Param => {
+                    f(".param");
+                }
+            }
+            match &self.size {
+                Size::U32 => {
+                    f(".u32");
+                }
+                Size::U64 => {
+                    f(".u64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvta.txt",
+                section: 0,
+                line: 2,
+                rule: "cvta.space.size p, a;",
+            }
+        }
+    }
+
+    impl Metadata for CvtaToSpaceSize {
+        fn opcode(&self) -> &'static str {
+            "cvta"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".to");
+            match &self.space {
+                Space::SharedCluster => {
+                    f(".shared::cluster");
+                }
+                Space::ParamEntry => {
+                    f(".param::entry");
+                }
+                Space::SharedCta => {
+                    f(".shared::cta");
+                }
+                Space::Global => {
+                    f(".global");
+                }
+                Space::Shared => {
+                    f(".shared");
+                }
+                Space::Const => {
+                    f(".const");
+                }
+                Space::Local => {
+                    f(".local");
+                }
+                Space::Param => {
+                    f(".param");
+                }
+            }
+            match &self.size {
+                Size::U32 => {
+                    f(".u32");
+                }
+                Size::U64 => {
+                    f(".u64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "cvta.txt",
+                section: 0,
+                line: 6,
+                rule: "cvta.to.space.size p, a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Original PTX specification:
+//!
+//! discard{.global}.level  [a], size;
+//! .level = { .L2 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::discard::section_0::*;
+
+    impl Metadata for DiscardGlobalLevel {
+        fn opcode(&self) -> &'static str {
+            "discard"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.global {
+                f(".global");
+            }
+            match &self.level {
+                Level::L2 => {
+                    f(".L2");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "discard.txt",
+                section: 0,
+                line: 1,
+                rule: "discard{.global}.level [a], size;",
+            }
+        }
+    }
+}
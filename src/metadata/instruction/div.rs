@@ -0,0 +1,163 @@
+//! Original PTX specification:
+//!
+//! div.type  d, a, b;
+//! .type = { .u16, .u32, .u64,
+//! .s16, .s32, .s64 };
+//!
+//! div.approx{.ftz}.f32  d, a, b;  // fast, approximate divide
+//! div.full{.ftz}.f32    d, a, b;  // full-range approximate divide
+//! div.rnd{.ftz}.f32     d, a, b;  // IEEE 754 compliant rounding
+//! div.rnd.f64           d, a, b;  // IEEE 754 compliant rounding
+//! .rnd = { .rn, .rz, .rm, .rp };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::div::section_0::*;
+
+    impl Metadata for DivType {
+        fn opcode(&self) -> &'static str {
+            "div"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "div.txt",
+                section: 0,
+                line: 1,
+                rule: "div.type d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for DivApproxFtzF32 {
+        fn opcode(&self) -> &'static str {
+            "div"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".approx");
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "div.txt",
+                section: 0,
+                line: 5,
+                rule: "div.approx{.ftz}.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for DivFullFtzF32 {
+        fn opcode(&self) -> &'static str {
+            "div"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".full");
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "div.txt",
+                section: 0,
+                line: 6,
+                rule: "div.full{.ftz}.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for DivRndFtzF32 {
+        fn opcode(&self) -> &'static str {
+            "div"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+                Rnd::Rz => {
+                    f(".rz");
+                }
+                Rnd::Rm => {
+                    f(".rm");
+                }
+                Rnd::Rp => {
+                    f(".rp");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "div.txt",
+                section: 0,
+                line: 7,
+                rule: "div.rnd{.ftz}.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for DivRndF64 {
+        fn opcode(&self) -> &'static str {
+            "div"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+                Rnd::Rz => {
+                    f(".rz");
+                }
+                Rnd::Rm => {
+                    f(".rm");
+                }
+                Rnd::Rp => {
+                    f(".rp");
+                }
+            }
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "div.txt",
+                section: 0,
+                line: 8,
+                rule: "div.rnd.f64 d, a, b;",
+            }
+        }
+    }
+}
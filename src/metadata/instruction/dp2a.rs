@@ -0,0 +1,54 @@
+//! Original PTX specification:
+//!
+//! dp2a.mode.atype.btype  d, a, b, c;
+//! .atype = .btype = { .u32, .s32 };
+//! .mode = { .lo, .hi };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::dp2a::section_0::*;
+
+    impl Metadata for Dp2aModeAtypeBtype {
+        fn opcode(&self) -> &'static str {
+            "dp2a"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.mode {
+                Mode::Lo => {
+                    f(".lo");
+                }
+                Mode::Hi => {
+                    f(".hi");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "dp2a.txt",
+                section: 0,
+                line: 1,
+                rule: "dp2a.mode.atype.btype d, a, b, c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Original PTX specification:
+//!
+//! dp4a.atype.btype  d, a, b, c;
+//! .atype = .btype = { .u32, .s32 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::dp4a::section_0::*;
+
+    impl Metadata for Dp4aAtypeBtype {
+        fn opcode(&self) -> &'static str {
+            "dp4a"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "dp4a.txt",
+                section: 0,
+                line: 1,
+                rule: "dp4a.atype.btype d, a, b, c;",
+            }
+        }
+    }
+}
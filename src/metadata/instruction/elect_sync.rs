@@ -0,0 +1,29 @@
+//! Original PTX specification:
+//!
+//! elect.sync d|p, membermask;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::elect_sync::section_0::*;
+
+    impl Metadata for ElectSync {
+        fn opcode(&self) -> &'static str {
+            "elect"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "elect.sync.txt",
+                section: 0,
+                line: 1,
+                rule: "elect.sync d|p, membermask;",
+            }
+        }
+    }
+}
@@ -0,0 +1,89 @@
+//! Original PTX specification:
+//!
+//! ex2.approx{.ftz}.f32  d, a;
+//!
+//! ex2.approx.atype     d, a;
+//! ex2.approx.ftz.btype d, a;
+//! .atype = { .f16,  .f16x2};
+//! .btype = { .bf16, .bf16x2};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::ex2::section_0::*;
+
+    impl Metadata for Ex2ApproxFtzF32 {
+        fn opcode(&self) -> &'static str {
+            "ex2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".approx");
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ex2.txt",
+                section: 0,
+                line: 1,
+                rule: "ex2.approx{.ftz}.f32 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for Ex2ApproxAtype {
+        fn opcode(&self) -> &'static str {
+            "ex2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".approx");
+            match &self.atype {
+                Atype::F16x2 => {
+                    f(".f16x2");
+                }
+                Atype::F16 => {
+                    f(".f16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ex2.txt",
+                section: 0,
+                line: 3,
+                rule: "ex2.approx.atype d, a;",
+            }
+        }
+    }
+
+    impl Metadata for Ex2ApproxFtzBtype {
+        fn opcode(&self) -> &'static str {
+            "ex2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".approx");
+            f(".ftz");
+            match &self.btype {
+                Btype::Bf16x2 => {
+                    f(".bf16x2");
+                }
+                Btype::Bf16 => {
+                    f(".bf16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ex2.txt",
+                section: 0,
+                line: 4,
+                rule: "ex2.approx.ftz.btype d, a;",
+            }
+        }
+    }
+}
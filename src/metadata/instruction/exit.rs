@@ -0,0 +1,27 @@
+//! Original PTX specification:
+//!
+//! exit;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::exit::section_0::*;
+
+    impl Metadata for Exit {
+        fn opcode(&self) -> &'static str {
+            "exit"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {}
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "exit.txt",
+                section: 0,
+                line: 1,
+                rule: "exit;",
+            }
+        }
+    }
+}
@@ -0,0 +1,366 @@
+//! Original PTX specification:
+//!
+//! fma.rnd{.ftz}{.sat}.f32  d, a, b, c;
+//! fma.rnd{.ftz}.f32x2      d, a, b, c;
+//! fma.rnd.f64              d, a, b, c;
+//! .rnd = { .rn, .rz, .rm, .rp };
+//! ---------------------------------------------
+//! fma.rnd{.ftz}{.sat}.f16     d, a, b, c;
+//! fma.rnd{.ftz}{.sat}.f16x2   d, a, b, c;
+//! fma.rnd{.ftz}.relu.f16      d, a, b, c;
+//! fma.rnd{.ftz}.relu.f16x2    d, a, b, c;
+//! fma.rnd{.relu}.bf16         d, a, b, c;
+//! fma.rnd{.relu}.bf16x2       d, a, b, c;
+//! fma.rnd.oob{.relu}.type     d, a, b, c;
+//! .rnd = { .rn };
+//! ---------------------------------------------
+//! fma.rnd{.sat}.f32.abtype  d, a, b, c;
+//! .abtype = { .f16, .bf16};
+//! .rnd    = { .rn, .rz, .rm, .rp };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::fma::section_0::*;
+
+    impl Metadata for FmaRndFtzSatF32 {
+        fn opcode(&self) -> &'static str {
+            "fma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+                Rnd::Rz => {
+                    f(".rz");
+                }
+                Rnd::Rm => {
+                    f(".rm");
+                }
+                Rnd::Rp => {
+                    f(".rp");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "fma.txt",
+                section: 0,
+                line: 1,
+                rule: "fma.rnd{.ftz}{.sat}.f32 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for FmaRndFtzF32x2 {
+        fn opcode(&self) -> &'static str {
+            "fma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+                Rnd::Rz => {
+                    f(".rz");
+                }
+                Rnd::Rm => {
+                    f(".rm");
+                }
+                Rnd::Rp => {
+                    f(".rp");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "fma.txt",
+                section: 0,
+                line: 2,
+                rule: "fma.rnd{.ftz}.f32x2 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for FmaRndF64 {
+        fn opcode(&self) -> &'static str {
+            "fma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+                Rnd::Rz => {
+                    f(".rz");
+                }
+                Rnd::Rm => {
+                    f(".rm");
+                }
+                Rnd::Rp => {
+                    f(".rp");
+                }
+            }
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "fma.txt",
+                section: 0,
+                line: 3,
+                rule: "fma.rnd.f64 d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::fma::section_1::*;
+
+    impl Metadata for FmaRndFtzSatF16 {
+        fn opcode(&self) -> &'static str {
+            "fma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "fma.txt",
+                section: 1,
+                line: 6,
+                rule: "fma.rnd{.ftz}{.sat}.f16 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for FmaRndFtzSatF16x2 {
+        fn opcode(&self) -> &'static str {
+            "fma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "fma.txt",
+                section: 1,
+                line: 7,
+                rule: "fma.rnd{.ftz}{.sat}.f16x2 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for FmaRndFtzReluF16 {
+        fn opcode(&self) -> &'static str {
+            "fma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".relu");
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "fma.txt",
+                section: 1,
+                line: 8,
+                rule: "fma.rnd{.ftz}.relu.f16 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for FmaRndFtzReluF16x2 {
+        fn opcode(&self) -> &'static str {
+            "fma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".relu");
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "fma.txt",
+                section: 1,
+                line: 9,
+                rule: "fma.rnd{.ftz}.relu.f16x2 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for FmaRndReluBf16 {
+        fn opcode(&self) -> &'static str {
+            "fma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+            }
+            if self.relu {
+                f(".relu");
+            }
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "fma.txt",
+                section: 1,
+                line: 10,
+                rule: "fma.rnd{.relu}.bf16 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for FmaRndReluBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "fma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+            }
+            if self.relu {
+                f(".relu");
+            }
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "fma.txt",
+                section: 1,
+                line: 11,
+                rule: "fma.rnd{.relu}.bf16x2 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for FmaRndOobReluType {
+        fn opcode(&self) -> &'static str {
+            "fma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+            }
+            f(".oob");
+            if self.relu {
+                f(".relu");
+            }
+            f(".type");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "fma.txt",
+                section: 1,
+                line: 12,
+                rule: "fma.rnd.oob{.relu}.type d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::fma::section_2::*;
+
+    impl Metadata for FmaRndSatF32Abtype {
+        fn opcode(&self) -> &'static str {
+            "fma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+                Rnd::Rz => {
+                    f(".rz");
+                }
+                Rnd::Rm => {
+                    f(".rm");
+                }
+                Rnd::Rp => {
+                    f(".rp");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f32");
+            match &self.abtype {
+                Abtype::Bf16 => {
+                    f(".bf16");
+                }
+                Abtype::F16 => {
+                    f(".f16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "fma.txt",
+                section: 2,
+                line: 15,
+                rule: "fma.rnd{.sat}.f32.abtype d, a, b, c;",
+            }
+        }
+    }
+}
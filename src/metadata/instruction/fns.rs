@@ -0,0 +1,29 @@
+//! Original PTX specification:
+//!
+//! fns.b32 d, mask, base, offset;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::fns::section_0::*;
+
+    impl Metadata for FnsB32 {
+        fn opcode(&self) -> &'static str {
+            "fns"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "fns.txt",
+                section: 0,
+                line: 1,
+                rule: "fns.b32 d, mask, base, offset;",
+            }
+        }
+    }
+}
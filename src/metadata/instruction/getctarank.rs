@@ -0,0 +1,102 @@
+//! Original PTX specification:
+//!
+//! getctarank{.space}.type d, a;
+//! // Get cta rank from source shared memory address in register a.
+//! getctarank.shared::cluster.type d, a;
+//! // // Get cta rank from shared memory variable.
+//! // getctarank.shared::cluster.type d, var;
+//! // // Get cta rank from shared memory variable+offset.
+//! // getctarank.shared::cluster.type d, var + imm;
+//! // Get cta rank from generic address of shared memory variable in register a.
+//! getctarank.type d, a;
+//! .space = { .shared::cluster };
+//! .type  = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::getctarank::section_0::*;
+
+    impl Metadata for GetctarankSpaceType {
+        fn opcode(&self) -> &'static str {
+            "getctarank"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(space_0) = self.space.as_ref() {
+                match space_0 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "getctarank.txt",
+                section: 0,
+                line: 1,
+                rule: "getctarank{.space}.type d, a;",
+            }
+        }
+    }
+
+    impl Metadata for GetctarankSharedClusterType {
+        fn opcode(&self) -> &'static str {
+            "getctarank"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".shared::cluster");
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "getctarank.txt",
+                section: 0,
+                line: 3,
+                rule: "getctarank.shared::cluster.type d, a;",
+            }
+        }
+    }
+
+    impl Metadata for GetctarankType {
+        fn opcode(&self) -> &'static str {
+            "getctarank"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "getctarank.txt",
+                section: 0,
+                line: 9,
+                rule: "getctarank.type d, a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Original PTX specification:
+//!
+//! griddepcontrol.action;
+//! .action   = { .launch_dependents, .wait };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::griddepcontrol::section_0::*;
+
+    impl Metadata for GriddepcontrolAction {
+        fn opcode(&self) -> &'static str {
+            "griddepcontrol"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.action {
+                Action::LaunchDependents => {
+                    f(".launch_dependents");
+                }
+                Action::Wait => {
+                    f(".wait");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "griddepcontrol.txt",
+                section: 0,
+                line: 1,
+                rule: "griddepcontrol.action;",
+            }
+        }
+    }
+}
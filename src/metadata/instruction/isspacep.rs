@@ -0,0 +1,55 @@
+//! Original PTX specification:
+//!
+//! isspacep.space  p, a;    // result is .pred
+//! .space = { .const, .global, .local, .shared, .shared::cta, .shared::cluster, .param, .param::entry };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::isspacep::section_0::*;
+
+    impl Metadata for IsspacepSpace {
+        fn opcode(&self) -> &'static str {
+            "isspacep"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.space {
+                Space::SharedCluster => {
+                    f(".shared::cluster");
+                }
+                Space::ParamEntry => {
+                    f(".param::entry");
+                }
+                Space::SharedCta => {
+                    f(".shared::cta");
+                }
+                Space::Global => {
+                    f(".global");
+                }
+                Space::Shared => {
+                    f(".shared");
+                }
+                Space::Const => {
+                    f(".const");
+                }
+                Space::Local => {
+                    f(".local");
+                }
+                Space::Param => {
+                    f(".param");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "isspacep.txt",
+                section: 0,
+                line: 1,
+                rule: "isspacep.space p, a;",
+            }
+        }
+    }
+}
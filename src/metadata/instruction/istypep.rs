@@ -0,0 +1,40 @@
+//! Original PTX specification:
+//!
+//! istypep.type   p, a;  // result is .pred
+//! .type = { .texref, .samplerref, .surfref };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::istypep::section_0::*;
+
+    impl Metadata for IstypepType {
+        fn opcode(&self) -> &'static str {
+            "istypep"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::Samplerref => {
+                    f(".samplerref");
+                }
+                Type::Surfref => {
+                    f(".surfref");
+                }
+                Type::Texref => {
+                    f(".texref");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "istypep.txt",
+                section: 0,
+                line: 1,
+                rule: "istypep.type p, a;",
+            }
+        }
+    }
+}
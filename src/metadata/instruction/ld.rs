@@ -0,0 +1,878 @@
+//! Original PTX specification:
+//!
+//! ld{.weak}{.ss}{.cop}{.level::cache_hint}{.level::prefetch_size}{.vec}.type  d, [a]{.unified}{, cache-policy};
+//! ld{.weak}{.ss}{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.level::prefetch_size}{.vec}.type  d, [a]{.unified}{, cache-policy};
+//! ld.volatile{.ss}{.level::prefetch_size}{.vec}.type  d, [a];
+//! ld.relaxed.scope{.ss}{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.level::prefetch_size}{.vec}.type  d, [a]{, cache-policy};
+//! ld.acquire.scope{.ss}{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.level::prefetch_size}{.vec}.type  d, [a]{, cache-policy};
+//! ld.mmio.relaxed.sys{.global}.type  d, [a];
+//! .ss =                       { .const, .global, .local, .param::entry, .param::func, .param, .shared, .shared::cta, .shared::cluster};
+//! .cop =                      { .ca, .cg, .cs, .lu, .cv };
+//! .level1::eviction_priority = { .L1::evict_normal, .L1::evict_unchanged, .L1::evict_first, .L1::evict_last, .L1::no_allocate };
+//! .level2::eviction_priority = {.L2::evict_normal, .L2::evict_first, .L2::evict_last};
+//! .level::cache_hint =        { .L2::cache_hint };
+//! .level::prefetch_size =     { .L2::64B, .L2::128B, .L2::256B };
+//! .scope =                    { .cta, .cluster, .gpu, .sys };
+//! .vec =                      { .v2, .v4, .v8 };
+//! .type =                     { .b8, .b16, .b32, .b64, .b128,
+//! .u8, .u16, .u32, .u64,
+//! .s8, .s16, .s32, .s64,
+//! .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::ld::section_0::*;
+
+    impl Metadata for LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType {
+        fn opcode(&self) -> &'static str {
+            "ld"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.weak {
+                f(".weak");
+            }
+            if let Some(ss_0) = self.ss.as_ref() {
+                match ss_0 {
+                    Ss::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Ss::ParamEntry => {
+                        f(".param::entry");
+                    }
+                    Ss::ParamFunc => {
+                        f(".param::func");
+                    }
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                    Ss::Const => {
+                        f(".const");
+                    }
+                    Ss::Local => {
+                        f(".local");
+                    }
+                    Ss::Param => {
+                        f(".param");
+                    }
+                }
+            }
+            if let Some(cop_1) = self.cop.as_ref() {
+                match cop_1 {
+                    Cop::Ca => {
+                        f(".ca");
+                    }
+                    Cop::Cg => {
+                        f(".cg");
+                    }
+                    Cop::Cs => {
+                        f(".cs");
+                    }
+                    Cop::Lu => {
+                        f(".lu");
+                    }
+                    Cop::Cv => {
+                        f(".cv");
+                    }
+                }
+            }
+            if let Some(level_cache_hint_2) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_2 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            if let Some(level_prefetch_size_3) = self.level_prefetch_size.as_ref() {
+                match level_prefetch_size_3 {
+                    LevelPrefetchSize::L2128b => {
+                        f(".L2::128B");
+                    }
+                    LevelPrefetchSize::L2256b => {
+                        f(".L2::256B");
+                    }
+                    LevelPrefetchSize::L264b => {
+                        f(".L2::64B");
+                    }
+                }
+            }
+            if let Some(vec_4) = self.vec.as_ref() {
+                match vec_4 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                    Vec::V8 => {
+                        f(".v8");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ld.txt",
+                section: 0,
+                line: 1,
+                rule: "ld{.weak}{.ss}{.cop}{.level::cache_hint}{.level::prefetch_size}{.vec}.type d, [a]{.unified}{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for LdWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType {
+        fn opcode(&self) -> &'static str {
+            "ld"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+                    if self.weak {
+                            f(".weak");
+                    }
+                    if let Some(ss_5) = self.ss.as_ref() {
+                            match ss_5 {
+                                    Ss::SharedCluster => {
+                                            f(".shared::cluster");
+                                    }
+                                    Ss::ParamEntry => {
+                                            f(".param::entry");
+                                    }
+                                    Ss::ParamFunc => {
+                                            f(".param::func");
+                                    }
+                                    Ss::SharedCta => {
+                                            f(".shared::cta");
+                                    }
+                                    Ss::Global => {
+                                            f(".global");
+                                    }
+                                    Ss::Shared => {
+                                            f(".shared");
+                                    }
+                                    Ss::Const => {
+                                            f(".const");
+                                    }
+                                    Ss::Local => {
+                                            f(".local");
+                                    }
+                                    Ss::Param => {
+                                            f(".param");
+                                    }
+                            }
+                    }
+                    if let Some(level1_eviction_priority_6) = self.level1_eviction_priority.as_ref() {
+                            match level1_eviction_priority_6 {
+                                    Level1EvictionPriority::L1EvictUnchanged => {
+                                            f(".L1::evict_unchanged");
+                                    }
+                                    Level1EvictionPriority::L1EvictNormal => {
+                                            f(".L1::evict_normal");
+                                    }
+                                    Level1EvictionPriority::L1EvictFirst => {
+                                            f(".L1::evict_first");
+                                    }
+                                    Level1EvictionPriority::L1NoAllocate => {
+                                            f(".L1::no_allocate");
+                                    }
+                                    Level1EvictionPriority::L1EvictLast => {
+                                            f(".L1::evict_last");
+                                    }
+                            }
+                    }
+                    if let Some(level2_eviction_priority_7) = self.level2_eviction_priority.as_ref() {
+                            match level2_eviction_priority_7 {
+                                    Level2EvictionPriority::L2EvictNormal => {
+                                            f(".L2::evict_normal");
+                                    }
+                                    Level2EvictionPriority::L2EvictFirst => {
+                                            f(".L2::evict_first");
+                                    }
+                                    Level2EvictionPriority::L2EvictLast => {
+                                            f(".L2::evict_last");
+                                    }
+                            }
+                    }
+                    if let Some(level_cache_hint_8) = self.level_cache_hint.as_ref() {
+                            match level_cache_hint_8 {
+                                    LevelCacheHint::L2CacheHint => {
+                                            f(".L2::cache_hint");
+                                    }
+                            }
+                    }
+                    if let Some(level_prefetch_size_9) = self.level_prefetch_size.as_ref() {
+                            match level_prefetch_size_9 {
+                                    LevelPrefetchSize::L2128b => {
+                                            f(".L2::128B");
+                                    }
+                                    LevelPrefetchSize::L2256b => {
+                                            f(".L2::256B");
+                                    }
+                                    LevelPrefetchSize::L264b => {
+                                            f(".L2::64B");
+                                    }
+                            }
+                    }
+                    if let Some(vec_10) = self.vec.as_ref() {
+                            match vec_10 {
+                                    Vec::V2 => {
+                                            f(".v2");
+                                    }
+                                    Vec::V4 => {
+                                            f(".v4");
+                                    }
+                                    Vec::V8 => {
+                                            f(".v8");
+                                    }
+                            }
+                    }
+                    match &self.type_ {
+                            Type::B128 => {
+                                    f(".b128");
+                            }
+                            Type::B16 => {
+                                    f(".b16");
+                            }
+                            Type::B32 => {
+                                    f(".b32");
+                            }
+                            Type::B64 => {
+                                    f(".b64");
+                            }
+                            Type::U16 => {
+                                    f(".u16");
+                            }
+                            Type::U32 => {
+                                    f(".u32");
+                            }
+                            Type::U64 => {
+                                    f(".u64");
+                            }
+                            Type::S16 => {
+                                    f(".s16");
+                            }
+                            Type::S32 => {
+                                    f(".s32");
+                            }
+                            Type::S64 => {
+                                    f(".s64");
+                            }
+                            Type::F32 => {
+                                    f(".f32");
+                            }
+                            Type::F64 => {
+                                    f(".f64");
+                            }
+                            Type::B8 => {
+                                    f(".b8");
+                            }
+                            Type::U8 => {
+                                    f(".u8");
+                            }
+                            Type::S8 => {
+                                    f(".s8");
+                            }
+                    }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ld.txt",
+                section: 0,
+                line: 2,
+                rule: "ld{.weak}{.ss}{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.level::prefetch_size}{.vec}.type d, [a]{.unified}{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for LdVolatileSsLevelPrefetchSizeVecType {
+        fn opcode(&self) -> &'static str {
+            "ld"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".volatile");
+            if let Some(ss_11) = self.ss.as_ref() {
+                match ss_11 {
+                    Ss::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Ss::ParamEntry => {
+                        f(".param::entry");
+                    }
+                    Ss::ParamFunc => {
+                        f(".param::func");
+                    }
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                    Ss::Const => {
+                        f(".const");
+                    }
+                    Ss::Local => {
+                        f(".local");
+                    }
+                    Ss::Param => {
+                        f(".param");
+                    }
+                }
+            }
+            if let Some(level_prefetch_size_12) = self.level_prefetch_size.as_ref() {
+                match level_prefetch_size_12 {
+                    LevelPrefetchSize::L2128b => {
+                        f(".L2::128B");
+                    }
+                    LevelPrefetchSize::L2256b => {
+                        f(".L2::256B");
+                    }
+                    LevelPrefetchSize::L264b => {
+                        f(".L2::64B");
+                    }
+                }
+            }
+            if let Some(vec_13) = self.vec.as_ref() {
+                match vec_13 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                    Vec::V8 => {
+                        f(".v8");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ld.txt",
+                section: 0,
+                line: 3,
+                rule: "ld.volatile{.ss}{.level::prefetch_size}{.vec}.type d, [a];",
+            }
+        }
+    }
+
+    impl Metadata for LdRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType {
+        fn opcode(&self) -> &'static str {
+            "ld"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+                    f(".relaxed");
+                    match &self.scope {
+                            Scope::Cluster => {
+                                    f(".cluster");
+                            }
+                            Scope::Cta => {
+                                    f(".cta");
+                            }
+                            Scope::Gpu => {
+                                    f(".gpu");
+                            }
+                            Scope::Sys => {
+                                    f(".sys");
+                            }
+                    }
+                    if let Some(ss_14) = self.ss.as_ref() {
+                            match ss_14 {
+                                    Ss::SharedCluster => {
+                                            f(".shared::cluster");
+                                    }
+                                    Ss::ParamEntry => {
+                                            f(".param::entry");
+                                    }
+                                    Ss::ParamFunc => {
+                                            f(".param::func");
+                                    }
+                                    Ss::SharedCta => {
+                                            f(".shared::cta");
+                                    }
+                                    Ss::Global => {
+                                            f(".global");
+                                    }
+                                    Ss::Shared => {
+                                            f(".shared");
+                                    }
+                                    Ss::Const => {
+                                            f(".const");
+                                    }
+                                    Ss::Local => {
+                                            f(".local");
+                                    }
+                                    Ss::Param => {
+                                            f(".param");
+                                    }
+                            }
+                    }
+                    if let Some(level1_eviction_priority_15) = self.level1_eviction_priority.as_ref() {
+                            match level1_eviction_priority_15 {
+                                    Level1EvictionPriority::L1EvictUnchanged => {
+                                            f(".L1::evict_unchanged");
+                                    }
+                                    Level1EvictionPriority::L1EvictNormal => {
+                                            f(".L1::evict_normal");
+                                    }
+                                    Level1EvictionPriority::L1EvictFirst => {
+                                            f(".L1::evict_first");
+                                    }
+                                    Level1EvictionPriority::L1NoAllocate => {
+                                            f(".L1::no_allocate");
+                                    }
+                                    Level1EvictionPriority::L1EvictLast => {
+                                            f(".L1::evict_last");
+                                    }
+                            }
+                    }
+                    if let Some(level2_eviction_priority_16) = self.level2_eviction_priority.as_ref() {
+                            match level2_eviction_priority_16 {
+                                    Level2EvictionPriority::L2EvictNormal => {
+                                            f(".L2::evict_normal");
+                                    }
+                                    Level2EvictionPriority::L2EvictFirst => {
+                                            f(".L2::evict_first");
+                                    }
+                                    Level2EvictionPriority::L2EvictLast => {
+                                            f(".L2::evict_last");
+                                    }
+                            }
+                    }
+                    if let Some(level_cache_hint_17) = self.level_cache_hint.as_ref() {
+                            match level_cache_hint_17 {
+                                    LevelCacheHint::L2CacheHint => {
+                                            f(".L2::cache_hint");
+                                    }
+                            }
+                    }
+                    if let Some(level_prefetch_size_18) = self.level_prefetch_size.as_ref() {
+                            match level_prefetch_size_18 {
+                                    LevelPrefetchSize::L2128b => {
+                                            f(".L2::128B");
+                                    }
+                                    LevelPrefetchSize::L2256b => {
+                                            f(".L2::256B");
+                                    }
+                                    LevelPrefetchSize::L264b => {
+                                            f(".L2::64B");
+                                    }
+                            }
+                    }
+                    if let Some(vec_19) = self.vec.as_ref() {
+                            match vec_19 {
+                                    Vec::V2 => {
+                                            f(".v2");
+                                    }
+                                    Vec::V4 => {
+                                            f(".v4");
+                                    }
+                                    Vec::V8 => {
+                                            f(".v8");
+                                    }
+                            }
+                    }
+                    match &self.type_ {
+                            Type::B128 => {
+                                    f(".b128");
+                            }
+                            Type::B16 => {
+                                    f(".b16");
+                            }
+                            Type::B32 => {
+                                    f(".b32");
+                            }
+                            Type::B64 => {
+                                    f(".b64");
+                            }
+                            Type::U16 => {
+                                    f(".u16");
+                            }
+                            Type::U32 => {
+                                    f(".u32");
+                            }
+                            Type::U64 => {
+                                    f(".u64");
+                            }
+                            Type::S16 => {
+                                    f(".s16");
+                            }
+                            Type::S32 => {
+                                    f(".s32");
+                            }
+                            Type::S64 => {
+                                    f(".s64");
+                            }
+                            Type::F32 => {
+                                    f(".f32");
+                            }
+                            Type::F64 => {
+                                    f(".f64");
+                            }
+                            Type::B8 => {
+                                    f(".b8");
+                            }
+                            Type::U8 => {
+                                    f(".u8");
+                            }
+                            Type::S8 => {
+                                    f(".s8");
+                            }
+                    }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ld.txt",
+                section: 0,
+                line: 4,
+                rule: "ld.relaxed.scope{.ss}{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.level::prefetch_size}{.vec}.type d, [a]{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for LdAcquireScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType {
+        fn opcode(&self) -> &'static str {
+            "ld"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+                    f(".acquire");
+                    match &self.scope {
+                            Scope::Cluster => {
+                                    f(".cluster");
+                            }
+                            Scope::Cta => {
+                                    f(".cta");
+                            }
+                            Scope::Gpu => {
+                                    f(".gpu");
+                            }
+                            Scope::Sys => {
+                                    f(".sys");
+                            }
+                    }
+                    if let Some(ss_20) = self.ss.as_ref() {
+                            match ss_20 {
+                                    Ss::SharedCluster => {
+                                            f(".shared::cluster");
+                                    }
+                                    Ss::ParamEntry => {
+                                            f(".param::entry");
+                                    }
+                                    Ss::ParamFunc => {
+                                            f(".param::func");
+                                    }
+                                    Ss::SharedCta => {
+                                            f(".shared::cta");
+                                    }
+                                    Ss::Global => {
+                                            f(".global");
+                                    }
+                                    Ss::Shared => {
+                                            f(".shared");
+                                    }
+                                    Ss::Const => {
+                                            f(".const");
+                                    }
+                                    Ss::Local => {
+                                            f(".local");
+                                    }
+                                    Ss::Param => {
+                                            f(".param");
+                                    }
+                            }
+                    }
+                    if let Some(level1_eviction_priority_21) = self.level1_eviction_priority.as_ref() {
+                            match level1_eviction_priority_21 {
+                                    Level1EvictionPriority::L1EvictUnchanged => {
+                                            f(".L1::evict_unchanged");
+                                    }
+                                    Level1EvictionPriority::L1EvictNormal => {
+                                            f(".L1::evict_normal");
+                                    }
+                                    Level1EvictionPriority::L1EvictFirst => {
+                                            f(".L1::evict_first");
+                                    }
+                                    Level1EvictionPriority::L1NoAllocate => {
+                                            f(".L1::no_allocate");
+                                    }
+                                    Level1EvictionPriority::L1EvictLast => {
+                                            f(".L1::evict_last");
+                                    }
+                            }
+                    }
+                    if let Some(level2_eviction_priority_22) = self.level2_eviction_priority.as_ref() {
+                            match level2_eviction_priority_22 {
+                                    Level2EvictionPriority::L2EvictNormal => {
+                                            f(".L2::evict_normal");
+                                    }
+                                    Level2EvictionPriority::L2EvictFirst => {
+                                            f(".L2::evict_first");
+                                    }
+                                    Level2EvictionPriority::L2EvictLast => {
+                                            f(".L2::evict_last");
+                                    }
+                            }
+                    }
+                    if let Some(level_cache_hint_23) = self.level_cache_hint.as_ref() {
+                            match level_cache_hint_23 {
+                                    LevelCacheHint::L2CacheHint => {
+                                            f(".L2::cache_hint");
+                                    }
+                            }
+                    }
+                    if let Some(level_prefetch_size_24) = self.level_prefetch_size.as_ref() {
+                            match level_prefetch_size_24 {
+                                    LevelPrefetchSize::L2128b => {
+                                            f(".L2::128B");
+                                    }
+                                    LevelPrefetchSize::L2256b => {
+                                            f(".L2::256B");
+                                    }
+                                    LevelPrefetchSize::L264b => {
+                                            f(".L2::64B");
+                                    }
+                            }
+                    }
+                    if let Some(vec_25) = self.vec.as_ref() {
+                            match vec_25 {
+                                    Vec::V2 => {
+                                            f(".v2");
+                                    }
+                                    Vec::V4 => {
+                                            f(".v4");
+                                    }
+                                    Vec::V8 => {
+                                            f(".v8");
+                                    }
+                            }
+                    }
+                    match &self.type_ {
+                            Type::B128 => {
+                                    f(".b128");
+                            }
+                            Type::B16 => {
+                                    f(".b16");
+                            }
+                            Type::B32 => {
+                                    f(".b32");
+                            }
+                            Type::B64 => {
+                                    f(".b64");
+                            }
+                            Type::U16 => {
+                                    f(".u16");
+                            }
+                            Type::U32 => {
+                                    f(".u32");
+                            }
+                            Type::U64 => {
+                                    f(".u64");
+                            }
+                            Type::S16 => {
+                                    f(".s16");
+                            }
+                            Type::S32 => {
+                                    f(".s32");
+                            }
+                            Type::S64 => {
+                                    f(".s64");
+                            }
+                            Type::F32 => {
+                                    f(".f32");
+                            }
+                            Type::F64 => {
+                                    f(".f64");
+                            }
+                            Type::B8 => {
+                                    f(".b8");
+                            }
+                            Type::U8 => {
+                                    f(".u8");
+                            }
+                            Type::S8 => {
+                                    f(".s8");
+                            }
+                    }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ld.txt",
+                section: 0,
+                line: 5,
+                rule: "ld.acquire.scope{.ss}{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.level::prefetch_size}{.vec}.type d, [a]{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for LdMmioRelaxedSysGlobalType {
+        fn opcode(&self) -> &'static str {
+            "ld"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mmio");
+            f(".relaxed");
+            f(".sys");
+            if self.global {
+                f(".global");
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ld.txt",
+                section: 0,
+                line: 6,
+                rule: "ld.mmio.relaxed.sys{.global}.type d, [a];",
+            }
+        }
+    }
+}
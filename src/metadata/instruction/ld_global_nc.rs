@@ -0,0 +1,477 @@
+//! Original PTX specification:
+//!
+//! ld.global{.cop}.nc{.level::cache_hint}{.level::prefetch_size}.type                 d, [a]{, cache-policy};
+//! ld.global{.cop}.nc{.level::cache_hint}{.level::prefetch_size}.vec.type             d, [a]{, cache-policy};
+//! ld.global.nc{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.level::prefetch_size}.type      d, [a]{, cache-policy};
+//! ld.global.nc{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.level::prefetch_size}.vec.type  d, [a]{, cache-policy};
+//! .cop  =                     { .ca, .cg, .cs };     // cache operation
+//! .level1::eviction_priority = { .L1::evict_normal, .L1::evict_unchanged, .L1::evict_first, .L1::evict_last, .L1::no_allocate};
+//! .level2::eviction_priority = {.L2::evict_normal, .L2::evict_first, .L2::evict_last};
+//! .level::cache_hint =        { .L2::cache_hint };
+//! .level::prefetch_size =     { .L2::64B, .L2::128B, .L2::256B };
+//! .vec  =                     { .v2, .v4, .v8 };
+//! .type =                     { .b8, .b16, .b32, .b64, .b128,
+//! .u8, .u16, .u32, .u64,
+//! .s8, .s16, .s32, .s64,
+//! .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::ld_global_nc::section_0::*;
+
+    impl Metadata for LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType {
+        fn opcode(&self) -> &'static str {
+            "ld"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".global");
+            if let Some(cop_0) = self.cop.as_ref() {
+                match cop_0 {
+                    Cop::Ca => {
+                        f(".ca");
+                    }
+                    Cop::Cg => {
+                        f(".cg");
+                    }
+                    Cop::Cs => {
+                        f(".cs");
+                    }
+                }
+            }
+            f(".nc");
+            if let Some(level_cache_hint_1) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_1 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            if let Some(level_prefetch_size_2) = self.level_prefetch_size.as_ref() {
+                match level_prefetch_size_2 {
+                    LevelPrefetchSize::L2128b => {
+                        f(".L2::128B");
+                    }
+                    LevelPrefetchSize::L2256b => {
+                        f(".L2::256B");
+                    }
+                    LevelPrefetchSize::L264b => {
+                        f(".L2::64B");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ld.global.nc.txt",
+                section: 0,
+                line: 1,
+                rule: "ld.global{.cop}.nc{.level::cache_hint}{.level::prefetch_size}.type d, [a]{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType {
+        fn opcode(&self) -> &'static str {
+            "ld"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".global");
+            if let Some(cop_3) = self.cop.as_ref() {
+                match cop_3 {
+                    Cop::Ca => {
+                        f(".ca");
+                    }
+                    Cop::Cg => {
+                        f(".cg");
+                    }
+                    Cop::Cs => {
+                        f(".cs");
+                    }
+                }
+            }
+            f(".nc");
+            if let Some(level_cache_hint_4) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_4 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            if let Some(level_prefetch_size_5) = self.level_prefetch_size.as_ref() {
+                match level_prefetch_size_5 {
+                    LevelPrefetchSize::L2128b => {
+                        f(".L2::128B");
+                    }
+                    LevelPrefetchSize::L2256b => {
+                        f(".L2::256B");
+                    }
+                    LevelPrefetchSize::L264b => {
+                        f(".L2::64B");
+                    }
+                }
+            }
+            match &self.vec {
+                Vec::V2 => {
+                    f(".v2");
+                }
+                Vec::V4 => {
+                    f(".v4");
+                }
+                Vec::V8 => {
+                    f(".v8");
+                }
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ld.global.nc.txt",
+                section: 0,
+                line: 2,
+                rule: "ld.global{.cop}.nc{.level::cache_hint}{.level::prefetch_size}.vec.type d, [a]{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType {
+        fn opcode(&self) -> &'static str {
+            "ld"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+                    f(".global");
+                    f(".nc");
+                    if let Some(level1_eviction_priority_6) = self.level1_eviction_priority.as_ref() {
+                            match level1_eviction_priority_6 {
+                                    Level1EvictionPriority::L1EvictUnchanged => {
+                                            f(".L1::evict_unchanged");
+                                    }
+                                    Level1EvictionPriority::L1EvictNormal => {
+                                            f(".L1::evict_normal");
+                                    }
+                                    Level1EvictionPriority::L1EvictFirst => {
+                                            f(".L1::evict_first");
+                                    }
+                                    Level1EvictionPriority::L1NoAllocate => {
+                                            f(".L1::no_allocate");
+                                    }
+                                    Level1EvictionPriority::L1EvictLast => {
+                                            f(".L1::evict_last");
+                                    }
+                            }
+                    }
+                    if let Some(level2_eviction_priority_7) = self.level2_eviction_priority.as_ref() {
+                            match level2_eviction_priority_7 {
+                                    Level2EvictionPriority::L2EvictNormal => {
+                                            f(".L2::evict_normal");
+                                    }
+                                    Level2EvictionPriority::L2EvictFirst => {
+                                            f(".L2::evict_first");
+                                    }
+                                    Level2EvictionPriority::L2EvictLast => {
+                                            f(".L2::evict_last");
+                                    }
+                            }
+                    }
+                    if let Some(level_cache_hint_8) = self.level_cache_hint.as_ref() {
+                            match level_cache_hint_8 {
+                                    LevelCacheHint::L2CacheHint => {
+                                            f(".L2::cache_hint");
+                                    }
+                            }
+                    }
+                    if let Some(level_prefetch_size_9) = self.level_prefetch_size.as_ref() {
+                            match level_prefetch_size_9 {
+                                    LevelPrefetchSize::L2128b => {
+                                            f(".L2::128B");
+                                    }
+                                    LevelPrefetchSize::L2256b => {
+                                            f(".L2::256B");
+                                    }
+                                    LevelPrefetchSize::L264b => {
+                                            f(".L2::64B");
+                                    }
+                            }
+                    }
+                    match &self.type_ {
+                            Type::B128 => {
+                                    f(".b128");
+                            }
+                            Type::B16 => {
+                                    f(".b16");
+                            }
+                            Type::B32 => {
+                                    f(".b32");
+                            }
+                            Type::B64 => {
+                                    f(".b64");
+                            }
+                            Type::U16 => {
+                                    f(".u16");
+                            }
+                            Type::U32 => {
+                                    f(".u32");
+                            }
+                            Type::U64 => {
+                                    f(".u64");
+                            }
+                            Type::S16 => {
+                                    f(".s16");
+                            }
+                            Type::S32 => {
+                                    f(".s32");
+                            }
+                            Type::S64 => {
+                                    f(".s64");
+                            }
+                            Type::F32 => {
+                                    f(".f32");
+                            }
+                            Type::F64 => {
+                                    f(".f64");
+                            }
+                            Type::B8 => {
+                                    f(".b8");
+                            }
+                            Type::U8 => {
+                                    f(".u8");
+                            }
+                            Type::S8 => {
+                                    f(".s8");
+                            }
+                    }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ld.global.nc.txt",
+                section: 0,
+                line: 3,
+                rule: "ld.global.nc{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.level::prefetch_size}.type d, [a]{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType {
+        fn opcode(&self) -> &'static str {
+            "ld"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+                    f(".global");
+                    f(".nc");
+                    if let Some(level1_eviction_priority_10) = self.level1_eviction_priority.as_ref() {
+                            match level1_eviction_priority_10 {
+                                    Level1EvictionPriority::L1EvictUnchanged => {
+                                            f(".L1::evict_unchanged");
+                                    }
+                                    Level1EvictionPriority::L1EvictNormal => {
+                                            f(".L1::evict_normal");
+                                    }
+                                    Level1EvictionPriority::L1EvictFirst => {
+                                            f(".L1::evict_first");
+                                    }
+                                    Level1EvictionPriority::L1NoAllocate => {
+                                            f(".L1::no_allocate");
+                                    }
+                                    Level1EvictionPriority::L1EvictLast => {
+                                            f(".L1::evict_last");
+                                    }
+                            }
+                    }
+                    if let Some(level2_eviction_priority_11) = self.level2_eviction_priority.as_ref() {
+                            match level2_eviction_priority_11 {
+                                    Level2EvictionPriority::L2EvictNormal => {
+                                            f(".L2::evict_normal");
+                                    }
+                                    Level2EvictionPriority::L2EvictFirst => {
+                                            f(".L2::evict_first");
+                                    }
+                                    Level2EvictionPriority::L2EvictLast => {
+                                            f(".L2::evict_last");
+                                    }
+                            }
+                    }
+                    if let Some(level_cache_hint_12) = self.level_cache_hint.as_ref() {
+                            match level_cache_hint_12 {
+                                    LevelCacheHint::L2CacheHint => {
+                                            f(".L2::cache_hint");
+                                    }
+                            }
+                    }
+                    if let Some(level_prefetch_size_13) = self.level_prefetch_size.as_ref() {
+                            match level_prefetch_size_13 {
+                                    LevelPrefetchSize::L2128b => {
+                                            f(".L2::128B");
+                                    }
+                                    LevelPrefetchSize::L2256b => {
+                                            f(".L2::256B");
+                                    }
+                                    LevelPrefetchSize::L264b => {
+                                            f(".L2::64B");
+                                    }
+                            }
+                    }
+                    match &self.vec {
+                            Vec::V2 => {
+                                    f(".v2");
+                            }
+                            Vec::V4 => {
+                                    f(".v4");
+                            }
+                            Vec::V8 => {
+                                    f(".v8");
+                            }
+                    }
+                    match &self.type_ {
+                            Type::B128 => {
+                                    f(".b128");
+                            }
+                            Type::B16 => {
+                                    f(".b16");
+                            }
+                            Type::B32 => {
+                                    f(".b32");
+                            }
+                            Type::B64 => {
+                                    f(".b64");
+                            }
+                            Type::U16 => {
+                                    f(".u16");
+                            }
+                            Type::U32 => {
+                                    f(".u32");
+                            }
+                            Type::U64 => {
+                                    f(".u64");
+                            }
+                            Type::S16 => {
+                                    f(".s16");
+                            }
+                            Type::S32 => {
+                                    f(".s32");
+                            }
+                            Type::S64 => {
+                                    f(".s64");
+                            }
+                            Type::F32 => {
+                                    f(".f32");
+                            }
+                            Type::F64 => {
+                                    f(".f64");
+                            }
+                            Type::B8 => {
+                                    f(".b8");
+                            }
+                            Type::U8 => {
+                                    f(".u8");
+                            }
+                            Type::S8 => {
+                                    f(".s8");
+                            }
+                    }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ld.global.nc.txt",
+                section: 0,
+                line: 4,
+                rule: "ld.global.nc{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.level::prefetch_size}.vec.type d, [a]{, cache-policy};",
+            }
+        }
+    }
+}
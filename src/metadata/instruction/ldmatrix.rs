@@ -0,0 +1,185 @@
+//! Original PTX specification:
+//!
+//! ldmatrix.sync.aligned.shape.num{.trans}{.ss}.type r, [p];
+//! ldmatrix.sync.aligned.m8n16.num{.ss}.dst_fmt.src_fmt        r, [p];
+//! ldmatrix.sync.aligned.m16n16.num.trans{.ss}.dst_fmt.src_fmt r, [p];
+//! .shape   = {.m8n8, .m16n16};
+//! .num     = {.x1, .x2, .x4};
+//! .ss      = {.shared, .shared::cta};
+//! .type    = {.b16, .b8};
+//! .dst_fmt = { .b8x16 };
+//! .src_fmt = { .b6x16_p32, .b4x16_p64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::ldmatrix::section_0::*;
+
+    impl Metadata for LdmatrixSyncAlignedShapeNumTransSsType {
+        fn opcode(&self) -> &'static str {
+            "ldmatrix"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M16n16 => {
+                    f(".m16n16");
+                }
+                Shape::M8n8 => {
+                    f(".m8n8");
+                }
+            }
+            match &self.num {
+                Num::X1 => {
+                    f(".x1");
+                }
+                Num::X2 => {
+                    f(".x2");
+                }
+                Num::X4 => {
+                    f(".x4");
+                }
+            }
+            if self.trans {
+                f(".trans");
+            }
+            if let Some(ss_0) = self.ss.as_ref() {
+                match ss_0 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ldmatrix.txt",
+                section: 0,
+                line: 1,
+                rule: "ldmatrix.sync.aligned.shape.num{.trans}{.ss}.type r, [p];",
+            }
+        }
+    }
+
+    impl Metadata for LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt {
+        fn opcode(&self) -> &'static str {
+            "ldmatrix"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            f(".m8n16");
+            match &self.num {
+                Num::X1 => {
+                    f(".x1");
+                }
+                Num::X2 => {
+                    f(".x2");
+                }
+                Num::X4 => {
+                    f(".x4");
+                }
+            }
+            if let Some(ss_1) = self.ss.as_ref() {
+                match ss_1 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.dst_fmt {
+                DstFmt::B8x16 => {
+                    f(".b8x16");
+                }
+            }
+            match &self.src_fmt {
+                SrcFmt::B6x16P32 => {
+                    f(".b6x16_p32");
+                }
+                SrcFmt::B4x16P64 => {
+                    f(".b4x16_p64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ldmatrix.txt",
+                section: 0,
+                line: 2,
+                rule: "ldmatrix.sync.aligned.m8n16.num{.ss}.dst_fmt.src_fmt r, [p];",
+            }
+        }
+    }
+
+    impl Metadata for LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt {
+        fn opcode(&self) -> &'static str {
+            "ldmatrix"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            f(".m16n16");
+            match &self.num {
+                Num::X1 => {
+                    f(".x1");
+                }
+                Num::X2 => {
+                    f(".x2");
+                }
+                Num::X4 => {
+                    f(".x4");
+                }
+            }
+            f(".trans");
+            if let Some(ss_2) = self.ss.as_ref() {
+                match ss_2 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.dst_fmt {
+                DstFmt::B8x16 => {
+                    f(".b8x16");
+                }
+            }
+            match &self.src_fmt {
+                SrcFmt::B6x16P32 => {
+                    f(".b6x16_p32");
+                }
+                SrcFmt::B4x16P64 => {
+                    f(".b4x16_p64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ldmatrix.txt",
+                section: 0,
+                line: 3,
+                rule: "ldmatrix.sync.aligned.m16n16.num.trans{.ss}.dst_fmt.src_fmt r, [p];",
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Original PTX specification:
+//!
+//! ldu{.ss}.type      d, [a];       // load from address
+//! ldu{.ss}.vec.type  d, [a];       // vec load from address
+//! .ss   = { .global };             // state space
+//! .vec  = { .v2, .v4 };
+//! .type = { .b8, .b16, .b32, .b64, .b128,
+//! .u8, .u16, .u32, .u64,
+//! .s8, .s16, .s32, .s64,
+//! .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::ldu::section_0::*;
+
+    impl Metadata for LduSsType {
+        fn opcode(&self) -> &'static str {
+            "ldu"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(ss_0) = self.ss.as_ref() {
+                match ss_0 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ldu.txt",
+                section: 0,
+                line: 1,
+                rule: "ldu{.ss}.type d, [a];",
+            }
+        }
+    }
+
+    impl Metadata for LduSsVecType {
+        fn opcode(&self) -> &'static str {
+            "ldu"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(ss_1) = self.ss.as_ref() {
+                match ss_1 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            match &self.vec {
+                Vec::V2 => {
+                    f(".v2");
+                }
+                Vec::V4 => {
+                    f(".v4");
+                }
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ldu.txt",
+                section: 0,
+                line: 2,
+                rule: "ldu{.ss}.vec.type d, [a];",
+            }
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Original PTX specification:
+//!
+//! lg2.approx{.ftz}.f32  d, a;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::lg2::section_0::*;
+
+    impl Metadata for Lg2ApproxFtzF32 {
+        fn opcode(&self) -> &'static str {
+            "lg2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".approx");
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "lg2.txt",
+                section: 0,
+                line: 1,
+                rule: "lg2.approx{.ftz}.f32 d, a;",
+            }
+        }
+    }
+}
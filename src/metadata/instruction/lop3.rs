@@ -0,0 +1,56 @@
+//! Original PTX specification:
+//!
+//! lop3.b32 d, a, b, c, immLut;
+//! lop3.BoolOp.b32 d|p, a, b, c, immLut, q;
+//! .BoolOp   = { .or , .and };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::lop3::section_0::*;
+
+    impl Metadata for Lop3B32 {
+        fn opcode(&self) -> &'static str {
+            "lop3"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "lop3.txt",
+                section: 0,
+                line: 1,
+                rule: "lop3.b32 d, a, b, c, immLut;",
+            }
+        }
+    }
+
+    impl Metadata for Lop3BoolopB32 {
+        fn opcode(&self) -> &'static str {
+            "lop3"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "lop3.txt",
+                section: 0,
+                line: 2,
+                rule: "lop3.BoolOp.b32 d|p, a, b, c, immLut, q;",
+            }
+        }
+    }
+}
@@ -0,0 +1,178 @@
+//! Original PTX specification:
+//!
+//! mad.mode.type  d, a, b, c;
+//! mad.hi.sat.s32 d, a, b, c;
+//! .mode = { .hi, .lo, .wide };
+//! .type = { .u16, .u32, .u64,
+//! .s16, .s32, .s64 };
+//!
+//! mad{.ftz}{.sat}.f32      d, a, b, c;    // .target sm_1x
+//! mad.rnd{.ftz}{.sat}.f32  d, a, b, c;    // .target sm_20
+//! mad.rnd.f64              d, a, b, c;    // .target sm_13 and higher
+//! .rnd = { .rn, .rz, .rm, .rp };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mad::section_0::*;
+
+    impl Metadata for MadModeType {
+        fn opcode(&self) -> &'static str {
+            "mad"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.mode {
+                Mode::Wide => {
+                    f(".wide");
+                }
+                Mode::Hi => {
+                    f(".hi");
+                }
+                Mode::Lo => {
+                    f(".lo");
+                }
+            }
+            match &self.type_ {
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mad.txt",
+                section: 0,
+                line: 1,
+                rule: "mad.mode.type d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for MadHiSatS32 {
+        fn opcode(&self) -> &'static str {
+            "mad"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".hi");
+            f(".sat");
+            f(".s32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mad.txt",
+                section: 0,
+                line: 2,
+                rule: "mad.hi.sat.s32 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for MadFtzSatF32 {
+        fn opcode(&self) -> &'static str {
+            "mad"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mad.txt",
+                section: 0,
+                line: 7,
+                rule: "mad{.ftz}{.sat}.f32 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for MadRndFtzSatF32 {
+        fn opcode(&self) -> &'static str {
+            "mad"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+                Rnd::Rz => {
+                    f(".rz");
+                }
+                Rnd::Rm => {
+                    f(".rm");
+                }
+                Rnd::Rp => {
+                    f(".rp");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mad.txt",
+                section: 0,
+                line: 8,
+                rule: "mad.rnd{.ftz}{.sat}.f32 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for MadRndF64 {
+        fn opcode(&self) -> &'static str {
+            "mad"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+                Rnd::Rz => {
+                    f(".rz");
+                }
+                Rnd::Rm => {
+                    f(".rm");
+                }
+                Rnd::Rp => {
+                    f(".rp");
+                }
+            }
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mad.txt",
+                section: 0,
+                line: 9,
+                rule: "mad.rnd.f64 d, a, b, c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Original PTX specification:
+//!
+//! mad24.mode.type  d, a, b, c;
+//! mad24.hi.sat.s32 d, a, b, c;
+//! .mode = { .hi, .lo };
+//! .type = { .u32, .s32 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mad24::section_0::*;
+
+    impl Metadata for Mad24ModeType {
+        fn opcode(&self) -> &'static str {
+            "mad24"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.mode {
+                Mode::Hi => {
+                    f(".hi");
+                }
+                Mode::Lo => {
+                    f(".lo");
+                }
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mad24.txt",
+                section: 0,
+                line: 1,
+                rule: "mad24.mode.type d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for Mad24HiSatS32 {
+        fn opcode(&self) -> &'static str {
+            "mad24"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".hi");
+            f(".sat");
+            f(".s32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mad24.txt",
+                section: 0,
+                line: 2,
+                rule: "mad24.hi.sat.s32 d, a, b, c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Original PTX specification:
+//!
+//! madc.hilo{.cc}.type  d, a, b, c;
+//! .type = { .u32, .s32, .u64, .s64 };
+//! .hilo = { .hi, .lo };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::madc::section_0::*;
+
+    impl Metadata for MadcHiloCcType {
+        fn opcode(&self) -> &'static str {
+            "madc"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.hilo {
+                Hilo::Hi => {
+                    f(".hi");
+                }
+                Hilo::Lo => {
+                    f(".lo");
+                }
+            }
+            if self.cc {
+                f(".cc");
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "madc.txt",
+                section: 0,
+                line: 1,
+                rule: "madc.hilo{.cc}.type d, a, b, c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Original PTX specification:
+//!
+//! mapa{.space}.type          d, a, b;
+//! // Maps shared memory address in register a into CTA b.
+//! // mapa.shared::cluster.type  d, a, b;
+//! // Maps shared memory variable into CTA b.
+//! // mapa.shared::cluster.type  d, sh, b;
+//! // Maps shared memory variable into CTA b.
+//! // mapa.shared::cluster.type  d, sh + imm, b;
+//! // Maps generic address in register a into CTA b.
+//! // mapa.type                  d, a, b;
+//! .space = { .shared::cluster };
+//! .type  = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mapa::section_0::*;
+
+    impl Metadata for MapaSpaceType {
+        fn opcode(&self) -> &'static str {
+            "mapa"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(space_0) = self.space.as_ref() {
+                match space_0 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mapa.txt",
+                section: 0,
+                line: 1,
+                rule: "mapa{.space}.type d, a, b;",
+            }
+        }
+    }
+}
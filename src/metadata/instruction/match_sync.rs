@@ -0,0 +1,66 @@
+//! Original PTX specification:
+//!
+//! match.any.sync.type  d, a, membermask;
+//! match.all.sync.type  d{|p}, a, membermask;
+//! .type = { .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::match_sync::section_0::*;
+
+    impl Metadata for MatchAnySyncType {
+        fn opcode(&self) -> &'static str {
+            "match"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".any");
+            f(".sync");
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "match.sync.txt",
+                section: 0,
+                line: 1,
+                rule: "match.any.sync.type d, a, membermask;",
+            }
+        }
+    }
+
+    impl Metadata for MatchAllSyncType {
+        fn opcode(&self) -> &'static str {
+            "match"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".all");
+            f(".sync");
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "match.sync.txt",
+                section: 0,
+                line: 2,
+                rule: "match.all.sync.type d{|p}, a, membermask;",
+            }
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Original PTX specification:
+//!
+//! max.atype         d, a, b;
+//! max{.relu}.btype  d, a, b;
+//! .atype = { .u16, .u32, .u64,
+//! .u16x2, .s16, .s64 };
+//! .btype = { .s16x2, .s32 };
+//!
+//! max{.ftz}{.NaN}{.xorsign.abs}.f32  d, a, b;
+//! max{.ftz}{.NaN}{.abs}.f32          d, a, b, c;
+//! max.f64                            d, a, b;
+//!
+//! max{.ftz}{.NaN}{.xorsign.abs}.f16      d, a, b;
+//! max{.ftz}{.NaN}{.xorsign.abs}.f16x2    d, a, b;
+//! max{.NaN}{.xorsign.abs}.bf16           d, a, b;
+//! max{.NaN}{.xorsign.abs}.bf16x2         d, a, b;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::max::section_0::*;
+
+    impl Metadata for MaxAtype {
+        fn opcode(&self) -> &'static str {
+            "max"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.atype {
+                Atype::U16x2 => {
+                    f(".u16x2");
+                }
+                Atype::U16 => {
+                    f(".u16");
+                }
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::U64 => {
+                    f(".u64");
+                }
+                Atype::S16 => {
+                    f(".s16");
+                }
+                Atype::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "max.txt",
+                section: 0,
+                line: 1,
+                rule: "max.atype d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for MaxReluBtype {
+        fn opcode(&self) -> &'static str {
+            "max"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.relu {
+                f(".relu");
+            }
+            match &self.btype {
+                Btype::S16x2 => {
+                    f(".s16x2");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "max.txt",
+                section: 0,
+                line: 2,
+                rule: "max{.relu}.btype d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for MaxFtzNanXorsignAbsF32 {
+        fn opcode(&self) -> &'static str {
+            "max"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.nan {
+                f(".NaN");
+            }
+            if self.xorsign_abs {
+                f(".xorsign.abs");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "max.txt",
+                section: 0,
+                line: 7,
+                rule: "max{.ftz}{.NaN}{.xorsign.abs}.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for MaxFtzNanAbsF32 {
+        fn opcode(&self) -> &'static str {
+            "max"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.nan {
+                f(".NaN");
+            }
+            if self.abs {
+                f(".abs");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "max.txt",
+                section: 0,
+                line: 8,
+                rule: "max{.ftz}{.NaN}{.abs}.f32 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for MaxF64 {
+        fn opcode(&self) -> &'static str {
+            "max"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "max.txt",
+                section: 0,
+                line: 9,
+                rule: "max.f64 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for MaxFtzNanXorsignAbsF16 {
+        fn opcode(&self) -> &'static str {
+            "max"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.nan {
+                f(".NaN");
+            }
+            if self.xorsign_abs {
+                f(".xorsign.abs");
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "max.txt",
+                section: 0,
+                line: 11,
+                rule: "max{.ftz}{.NaN}{.xorsign.abs}.f16 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for MaxFtzNanXorsignAbsF16x2 {
+        fn opcode(&self) -> &'static str {
+            "max"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.nan {
+                f(".NaN");
+            }
+            if self.xorsign_abs {
+                f(".xorsign.abs");
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "max.txt",
+                section: 0,
+                line: 12,
+                rule: "max{.ftz}{.NaN}{.xorsign.abs}.f16x2 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for MaxNanXorsignAbsBf16 {
+        fn opcode(&self) -> &'static str {
+            "max"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.nan {
+                f(".NaN");
+            }
+            if self.xorsign_abs {
+                f(".xorsign.abs");
+            }
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "max.txt",
+                section: 0,
+                line: 13,
+                rule: "max{.NaN}{.xorsign.abs}.bf16 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for MaxNanXorsignAbsBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "max"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.nan {
+                f(".NaN");
+            }
+            if self.xorsign_abs {
+                f(".xorsign.abs");
+            }
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "max.txt",
+                section: 0,
+                line: 14,
+                rule: "max{.NaN}{.xorsign.abs}.bf16x2 d, a, b;",
+            }
+        }
+    }
+}
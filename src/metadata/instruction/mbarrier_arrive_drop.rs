@@ -0,0 +1,234 @@
+//! Original PTX specification:
+//!
+//! mbarrier.arrive_drop{.sem}{.scope}{.state}.b64 state,           [addr]{, count};
+//! mbarrier.arrive_drop{.sem}{.scope}{.shared::cluster}.b64           _,   [addr] {,count};
+//! mbarrier.arrive_drop.expect_tx{.state}{.sem}{.scope}.b64 state, [addr], tx_count;
+//! mbarrier.arrive_drop.expect_tx{.shared::cluster}{.sem}{.scope}.b64   _, [addr], tx_count;
+//! mbarrier.arrive_drop.noComplete{.release}{.cta}{.state}.b64 state,  [addr], count;
+//! .sem   = { .release, .relaxed };
+//! .scope = { .cta, .cluster };
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_arrive_drop::section_0::*;
+
+    impl Metadata for MbarrierArriveDropSemScopeStateB64 {
+        fn opcode(&self) -> &'static str {
+            "mbarrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".arrive_drop");
+            if let Some(sem_0) = self.sem.as_ref() {
+                match sem_0 {
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                }
+            }
+            if let Some(scope_1) = self.scope.as_ref() {
+                match scope_1 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                }
+            }
+            if let Some(state_2) = self.state.as_ref() {
+                match state_2 {
+                    State::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    State::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mbarrier.arrive_drop.txt",
+                section: 0,
+                line: 1,
+                rule: "mbarrier.arrive_drop{.sem}{.scope}{.state}.b64 state, [addr]{, count};",
+            }
+        }
+    }
+
+    impl Metadata for MbarrierArriveDropSemScopeSharedClusterB64 {
+        fn opcode(&self) -> &'static str {
+            "mbarrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".arrive_drop");
+            if let Some(sem_3) = self.sem.as_ref() {
+                match sem_3 {
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                }
+            }
+            if let Some(scope_4) = self.scope.as_ref() {
+                match scope_4 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                }
+            }
+            if self.shared_cluster {
+                f(".shared::cluster");
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mbarrier.arrive_drop.txt",
+                section: 0,
+                line: 2,
+                rule: "mbarrier.arrive_drop{.sem}{.scope}{.shared::cluster}.b64 _, [addr] {,count};",
+            }
+        }
+    }
+
+    impl Metadata for MbarrierArriveDropExpectTxStateSemScopeB64 {
+        fn opcode(&self) -> &'static str {
+            "mbarrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".arrive_drop");
+            f(".expect_tx");
+            if let Some(state_5) = self.state.as_ref() {
+                match state_5 {
+                    State::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    State::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            if let Some(sem_6) = self.sem.as_ref() {
+                match sem_6 {
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                }
+            }
+            if let Some(scope_7) = self.scope.as_ref() {
+                match scope_7 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mbarrier.arrive_drop.txt",
+                section: 0,
+                line: 3,
+                rule: "mbarrier.arrive_drop.expect_tx{.state}{.sem}{.scope}.b64 state, [addr], tx_count;",
+            }
+        }
+    }
+
+    impl Metadata for MbarrierArriveDropExpectTxSharedClusterSemScopeB64 {
+        fn opcode(&self) -> &'static str {
+            "mbarrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".arrive_drop");
+            f(".expect_tx");
+            if self.shared_cluster {
+                f(".shared::cluster");
+            }
+            if let Some(sem_8) = self.sem.as_ref() {
+                match sem_8 {
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                }
+            }
+            if let Some(scope_9) = self.scope.as_ref() {
+                match scope_9 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mbarrier.arrive_drop.txt",
+                section: 0,
+                line: 4,
+                rule: "mbarrier.arrive_drop.expect_tx{.shared::cluster}{.sem}{.scope}.b64 _, [addr], tx_count;",
+            }
+        }
+    }
+
+    impl Metadata for MbarrierArriveDropNocompleteReleaseCtaStateB64 {
+        fn opcode(&self) -> &'static str {
+            "mbarrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".arrive_drop");
+            f(".noComplete");
+            if self.release {
+                f(".release");
+            }
+            if self.cta {
+                f(".cta");
+            }
+            if let Some(state_10) = self.state.as_ref() {
+                match state_10 {
+                    State::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    State::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mbarrier.arrive_drop.txt",
+                section: 0,
+                line: 5,
+                rule: "mbarrier.arrive_drop.noComplete{.release}{.cta}{.state}.b64 state, [addr], count;",
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Original PTX specification:
+//!
+//! mbarrier.complete_tx{.sem}{.scope}{.space}.b64 [addr], txCount;
+//! .sem   = { .relaxed };
+//! .scope = { .cta, .cluster };
+//! .space = { .shared, .shared::cta, .shared::cluster };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_complete_tx::section_0::*;
+
+    impl Metadata for MbarrierCompleteTxSemScopeSpaceB64 {
+        fn opcode(&self) -> &'static str {
+            "mbarrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".complete_tx");
+            if let Some(sem_0) = self.sem.as_ref() {
+                match sem_0 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                }
+            }
+            if let Some(scope_1) = self.scope.as_ref() {
+                match scope_1 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                }
+            }
+            if let Some(space_2) = self.space.as_ref() {
+                match space_2 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mbarrier.complete_tx.txt",
+                section: 0,
+                line: 1,
+                rule: "mbarrier.complete_tx{.sem}{.scope}{.space}.b64 [addr], txCount;",
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Original PTX specification:
+//!
+//! mbarrier.inval{.state}.b64 [addr];
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_inval::section_0::*;
+
+    impl Metadata for MbarrierInvalStateB64 {
+        fn opcode(&self) -> &'static str {
+            "mbarrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".inval");
+            if let Some(state_0) = self.state.as_ref() {
+                match state_0 {
+                    State::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    State::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mbarrier.inval.txt",
+                section: 0,
+                line: 1,
+                rule: "mbarrier.inval{.state}.b64 [addr];",
+            }
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Original PTX specification:
+//!
+//! mbarrier.pending_count.b64 count, state;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_pending_count::section_0::*;
+
+    impl Metadata for MbarrierPendingCountB64 {
+        fn opcode(&self) -> &'static str {
+            "mbarrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".pending_count");
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mbarrier.pending_count.txt",
+                section: 0,
+                line: 1,
+                rule: "mbarrier.pending_count.b64 count, state;",
+            }
+        }
+    }
+}
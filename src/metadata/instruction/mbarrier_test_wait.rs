@@ -0,0 +1,212 @@
+//! Original PTX specification:
+//!
+//! mbarrier.test_wait{.sem}{.scope}{.state}.b64        waitComplete, [addr], state;
+//! mbarrier.test_wait.parity{.sem}{.scope}{.state}.b64 waitComplete, [addr], phaseParity;
+//! mbarrier.try_wait{.sem}{.scope}{.state}.b64         waitComplete, [addr], state {, suspendTimeHint};
+//! mbarrier.try_wait.parity{.sem}{.scope}{.state}.b64  waitComplete, [addr], phaseParity {, suspendTimeHint};
+//! .sem   = { .acquire, .relaxed };
+//! .scope = { .cta, .cluster };
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_test_wait::section_0::*;
+
+    impl Metadata for MbarrierTestWaitSemScopeStateB64 {
+        fn opcode(&self) -> &'static str {
+            "mbarrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".test_wait");
+            if let Some(sem_0) = self.sem.as_ref() {
+                match sem_0 {
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                }
+            }
+            if let Some(scope_1) = self.scope.as_ref() {
+                match scope_1 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                }
+            }
+            if let Some(state_2) = self.state.as_ref() {
+                match state_2 {
+                    State::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    State::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mbarrier.test_wait.txt",
+                section: 0,
+                line: 1,
+                rule: "mbarrier.test_wait{.sem}{.scope}{.state}.b64 waitComplete, [addr], state;",
+            }
+        }
+    }
+
+    impl Metadata for MbarrierTestWaitParitySemScopeStateB64 {
+        fn opcode(&self) -> &'static str {
+            "mbarrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".test_wait");
+            f(".parity");
+            if let Some(sem_3) = self.sem.as_ref() {
+                match sem_3 {
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                }
+            }
+            if let Some(scope_4) = self.scope.as_ref() {
+                match scope_4 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                }
+            }
+            if let Some(state_5) = self.state.as_ref() {
+                match state_5 {
+                    State::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    State::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mbarrier.test_wait.txt",
+                section: 0,
+                line: 2,
+                rule: "mbarrier.test_wait.parity{.sem}{.scope}{.state}.b64 waitComplete, [addr], phaseParity;",
+            }
+        }
+    }
+
+    impl Metadata for MbarrierTryWaitSemScopeStateB64 {
+        fn opcode(&self) -> &'static str {
+            "mbarrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".try_wait");
+            if let Some(sem_6) = self.sem.as_ref() {
+                match sem_6 {
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                }
+            }
+            if let Some(scope_7) = self.scope.as_ref() {
+                match scope_7 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                }
+            }
+            if let Some(state_8) = self.state.as_ref() {
+                match state_8 {
+                    State::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    State::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mbarrier.test_wait.txt",
+                section: 0,
+                line: 3,
+                rule: "mbarrier.try_wait{.sem}{.scope}{.state}.b64 waitComplete, [addr], state {, suspendTimeHint};",
+            }
+        }
+    }
+
+    impl Metadata for MbarrierTryWaitParitySemScopeStateB64 {
+        fn opcode(&self) -> &'static str {
+            "mbarrier"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".try_wait");
+            f(".parity");
+            if let Some(sem_9) = self.sem.as_ref() {
+                match sem_9 {
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                }
+            }
+            if let Some(scope_10) = self.scope.as_ref() {
+                match scope_10 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                }
+            }
+            if let Some(state_11) = self.state.as_ref() {
+                match state_11 {
+                    State::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    State::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mbarrier.test_wait.txt",
+                section: 0,
+                line: 4,
+                rule: "mbarrier.try_wait.parity{.sem}{.scope}{.state}.b64 waitComplete, [addr], phaseParity {, suspendTimeHint};",
+            }
+        }
+    }
+}
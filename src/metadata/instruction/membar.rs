@@ -0,0 +1,352 @@
+//! Original PTX specification:
+//!
+//! // Thread fence:
+//! fence{.sem}.scope;
+//! // Thread fence (uni-directional):
+//! fence.acquire.sync_restrict::shared::cluster.cluster;
+//! fence.release.sync_restrict::shared::cta.cluster;
+//! // Operation fence (uni-directional):
+//! fence.op_restrict.release.cluster;
+//! // Proxy fence (bi-directional):
+//! fence.proxy.proxykind;
+//! // Proxy fence (uni-directional):
+//! fence.proxy.to_proxykind::from_proxykind.release.scope;
+//! fence.proxy.to_proxykind::from_proxykind.acquire.scope  [addr], size;
+//! fence.proxy.async::generic.acquire.sync_restrict::shared::cluster.cluster;
+//! fence.proxy.async::generic.release.sync_restrict::shared::cta.cluster;
+//! // Old style membar:
+//! membar.level;
+//! membar.proxy.proxykind;
+//! .sem       = { .sc, .acq_rel, .acquire, .release };
+//! .scope     = { .cta, .cluster, .gpu, .sys };
+//! .level     = { .cta, .gl, .sys };
+//! .proxykind = { .alias, .async, .async.global, .async.shared::cta, .async.shared::cluster};
+//! .op_restrict = { .mbarrier_init };
+//! .to_proxykind::from_proxykind = {.tensormap::generic};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::membar::section_0::*;
+
+    impl Metadata for FenceSemScope {
+        fn opcode(&self) -> &'static str {
+            "fence"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(sem_0) = self.sem.as_ref() {
+                match sem_0 {
+                    Sem::AcqRel => {
+                        f(".acq_rel");
+                    }
+                    Sem::Acquire => {
+                        f(".acquire");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                    Sem::Sc => {
+                        f(".sc");
+                    }
+                }
+            }
+            match &self.scope {
+                Scope::Cluster => {
+                    f(".cluster");
+                }
+                Scope::Cta => {
+                    f(".cta");
+                }
+                Scope::Gpu => {
+                    f(".gpu");
+                }
+                Scope::Sys => {
+                    f(".sys");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "membar.txt",
+                section: 0,
+                line: 2,
+                rule: "fence{.sem}.scope;",
+            }
+        }
+    }
+
+    impl Metadata for FenceAcquireSyncRestrictSharedClusterCluster {
+        fn opcode(&self) -> &'static str {
+            "fence"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".acquire");
+            f(".sync_restrict::shared::cluster");
+            f(".cluster");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "membar.txt",
+                section: 0,
+                line: 4,
+                rule: "fence.acquire.sync_restrict::shared::cluster.cluster;",
+            }
+        }
+    }
+
+    impl Metadata for FenceReleaseSyncRestrictSharedCtaCluster {
+        fn opcode(&self) -> &'static str {
+            "fence"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".release");
+            f(".sync_restrict::shared::cta");
+            f(".cluster");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "membar.txt",
+                section: 0,
+                line: 5,
+                rule: "fence.release.sync_restrict::shared::cta.cluster;",
+            }
+        }
+    }
+
+    impl Metadata for FenceOpRestrictReleaseCluster {
+        fn opcode(&self) -> &'static str {
+            "fence"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.op_restrict {
+                OpRestrict::MbarrierInit => {
+                    f(".mbarrier_init");
+                }
+            }
+            f(".release");
+            f(".cluster");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "membar.txt",
+                section: 0,
+                line: 7,
+                rule: "fence.op_restrict.release.cluster;",
+            }
+        }
+    }
+
+    impl Metadata for FenceProxyProxykind {
+        fn opcode(&self) -> &'static str {
+            "fence"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".proxy");
+            match &self.proxykind {
+                Proxykind::AsyncSharedCluster => {
+                    f(".async.shared::cluster");
+                }
+                Proxykind::AsyncSharedCta => {
+                    f(".async.shared::cta");
+                }
+                Proxykind::AsyncGlobal => {
+                    f(".async.global");
+                }
+                Proxykind::Alias => {
+                    f(".alias");
+                }
+                Proxykind::Async => {
+                    f(".async");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "membar.txt",
+                section: 0,
+                line: 9,
+                rule: "fence.proxy.proxykind;",
+            }
+        }
+    }
+
+    impl Metadata for FenceProxyToProxykindFromProxykindReleaseScope {
+        fn opcode(&self) -> &'static str {
+            "fence"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".proxy");
+            match &self.to_proxykind_from_proxykind {
+                ToProxykindFromProxykind::TensormapGeneric => {
+                    f(".tensormap::generic");
+                }
+            }
+            f(".release");
+            match &self.scope {
+                Scope::Cluster => {
+                    f(".cluster");
+                }
+                Scope::Cta => {
+                    f(".cta");
+                }
+                Scope::Gpu => {
+                    f(".gpu");
+                }
+                Scope::Sys => {
+                    f(".sys");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "membar.txt",
+                section: 0,
+                line: 11,
+                rule: "fence.proxy.to_proxykind::from_proxykind.release.scope;",
+            }
+        }
+    }
+
+    impl Metadata for FenceProxyToProxykindFromProxykindAcquireScope {
+        fn opcode(&self) -> &'static str {
+            "fence"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".proxy");
+            match &self.to_proxykind_from_proxykind {
+                ToProxykindFromProxykind::TensormapGeneric => {
+                    f(".tensormap::generic");
+                }
+            }
+            f(".acquire");
+            match &self.scope {
+                Scope::Cluster => {
+                    f(".cluster");
+                }
+                Scope::Cta => {
+                    f(".cta");
+                }
+                Scope::Gpu => {
+                    f(".gpu");
+                }
+                Scope::Sys => {
+                    f(".sys");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "membar.txt",
+                section: 0,
+                line: 12,
+                rule: "fence.proxy.to_proxykind::from_proxykind.acquire.scope [addr], size;",
+            }
+        }
+    }
+
+    impl Metadata for FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster {
+        fn opcode(&self) -> &'static str {
+            "fence"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".proxy");
+            f(".async::generic");
+            f(".acquire");
+            f(".sync_restrict::shared::cluster");
+            f(".cluster");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "membar.txt",
+                section: 0,
+                line: 13,
+                rule: "fence.proxy.async::generic.acquire.sync_restrict::shared::cluster.cluster;",
+            }
+        }
+    }
+
+    impl Metadata for FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster {
+        fn opcode(&self) -> &'static str {
+            "fence"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".proxy");
+            f(".async::generic");
+            f(".release");
+            f(".sync_restrict::shared::cta");
+            f(".cluster");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "membar.txt",
+                section: 0,
+                line: 14,
+                rule: "fence.proxy.async::generic.release.sync_restrict::shared::cta.cluster;",
+            }
+        }
+    }
+
+    impl Metadata for MembarLevel {
+        fn opcode(&self) -> &'static str {
+            "membar"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.level {
+                Level::Cta => {
+                    f(".cta");
+                }
+                Level::Sys => {
+                    f(".sys");
+                }
+                Level::Gl => {
+                    f(".gl");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "membar.txt",
+                section: 0,
+                line: 16,
+                rule: "membar.level;",
+            }
+        }
+    }
+
+    impl Metadata for MembarProxyProxykind {
+        fn opcode(&self) -> &'static str {
+            "membar"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".proxy");
+            match &self.proxykind {
+                Proxykind::AsyncSharedCluster => {
+                    f(".async.shared::cluster");
+                }
+                Proxykind::AsyncSharedCta => {
+                    f(".async.shared::cta");
+                }
+                Proxykind::AsyncGlobal => {
+                    f(".async.global");
+                }
+                Proxykind::Alias => {
+                    f(".alias");
+                }
+                Proxykind::Async => {
+                    f(".async");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "membar.txt",
+                section: 0,
+                line: 17,
+                rule: "membar.proxy.proxykind;",
+            }
+        }
+    }
+}
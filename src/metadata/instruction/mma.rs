@@ -0,0 +1,828 @@
+//! Original PTX specification:
+//!
+//! // Half precision floating point type:
+//! mma.sync.aligned.m8n8k4.alayout.blayout.dtype.f16.f16.ctype  d, a, b, c;
+//! mma.sync.aligned.m16n8k8.row.col.dtype.f16.f16.ctype  d, a, b, c;
+//! mma.sync.aligned.m16n8k16.row.col.dtype.f16.f16.ctype d, a, b, c;
+//! .alayout = {.row, .col};
+//! .blayout = {.row, .col};
+//! .ctype   = {.f16, .f32};
+//! .dtype   = {.f16, .f32};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! // Alternate floating point type:
+//! mma.sync.aligned.m16n8k4.row.col.f32.tf32.tf32.f32        d, a, b, c;
+//! mma.sync.aligned.m16n8k8.row.col.f32.atype.btype.f32      d, a, b, c;
+//! mma.sync.aligned.m16n8k16.row.col.f32.bf16.bf16.f32       d, a, b, c;
+//! mma.sync.aligned.shape.row.col.dtype.f8type.f8type.ctype  d, a, b, c;
+//! mma.sync.aligned.m16n8k32.row.col.kind.dtype.f8f6f4type.f8f6f4type.ctype d, a, b, c;
+//! .atype      = {.bf16, .tf32};
+//! .btype      = {.bf16, .tf32};
+//! .f8type     = {.e4m3, .e5m2};
+//! .f8f6f4type = {.e4m3, .e5m2, .e3m2, .e2m3, .e2m1};
+//! .ctype      = {.f16, .f32};
+//! .dtype      = {.f16, .f32};
+//! .shape      = {.m16n8k16, .m16n8k32};
+//! .kind       = {.kind::f8f6f4};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! // Alternate floating point type with block scaling:
+//! mma.sync.aligned.m16n8k64.row.col.kind.block_scale{.scale_vec_size}.f32.e2m1.e2m1.f32.stype d, a, b, c, scale-a-data, {byte-id-a, thread-id-a}, scale-b-data, {byte-id-b, thread-id-b};
+//! .kind           = {.kind::mxf4};
+//! .scale_vec_size = {.scale_vec::2X};
+//! .stype          = {.ue8m0};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! mma.sync.aligned.m16n8k64.row.col.kind.block_scale.scale_vec_size.f32.e2m1.e2m1.f32.stype d, a, b, c, scale-a-data, {byte-id-a, thread-id-a}, scale-b-data, {byte-id-b, thread-id-b};
+//! .kind           = {.kind::mxf4nvf4};
+//! .scale_vec_size = {.scale_vec::2X, .scale_vec::4X};
+//! .stype          = {.ue8m0, .ue4m3};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! mma.sync.aligned.m16n8k32.row.col.kind.block_scale{.scale_vec_size}.f32.f8f6f4type.f8f6f4type.f32.stype d, a, b, c, scale-a-data, {byte-id-a, thread-id-a}, scale-b-data, {byte-id-b, thread-id-b};
+//! .kind           = {.kind::mxf8f6f4};
+//! .scale_vec_size = {.scale_vec::1X};
+//! .f8f6f4type     = {.e4m3, .e5m2, .e3m2, .e2m3, .e2m1};
+//! .stype          = {.ue8m0};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! // Double precision floating point type:
+//! mma.sync.aligned.shape.row.col.f64.f64.f64.f64 d, a, b, c;
+//! .shape   = {.m8n84, .m16n8k4, .m16n8k8, .m16n8k16};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! // Integer type:
+//! mma.sync.aligned.shape.row.col{.satfinite}.s32.atype.btype.s32 d, a, b, c;
+//! .shape   = {.m8n8k16, .m16n8k16, .m16n8k32};
+//! .atype   = {.u8, .s8};
+//! .btype   = {.u8, .s8};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! mma.sync.aligned.shape.row.col{.satfinite}.s32.atype.btype.s32 d, a, b, c;
+//! .shape   = {.m8n8k32, .m16n8k32, .m16n8k64};
+//! .atype   = {.u4, .s4};
+//! .btype   = {.u4, .s4};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! // Single bit:
+//! mma.sync.aligned.shape.row.col.s32.b1.b1.s32.bitOp.popc d, a, b, c;
+//! .bitOp = {.xor, .and};
+//! .shape = {.m8n8k128, .m16n8k128, .m16n8k256};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mma::section_0::*;
+
+    impl Metadata for MmaSyncAlignedM8n8k4AlayoutBlayoutDtypeF16F16Ctype {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            f(".m8n8k4");
+            match &self.alayout {
+                Alayout::Row => {
+                    f(".row");
+                }
+                Alayout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.blayout {
+                Blayout::Row => {
+                    f(".row");
+                }
+                Blayout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".f16");
+            f(".f16");
+            match &self.ctype {
+                Ctype::F16 => {
+                    f(".f16");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 0,
+                line: 2,
+                rule: "mma.sync.aligned.m8n8k4.alayout.blayout.dtype.f16.f16.ctype d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for MmaSyncAlignedM16n8k8RowColDtypeF16F16Ctype {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            f(".m16n8k8");
+            f(".row");
+            f(".col");
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".f16");
+            f(".f16");
+            match &self.ctype {
+                Ctype::F16 => {
+                    f(".f16");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 0,
+                line: 3,
+                rule: "mma.sync.aligned.m16n8k8.row.col.dtype.f16.f16.ctype d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for MmaSyncAlignedM16n8k16RowColDtypeF16F16Ctype {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            f(".m16n8k16");
+            f(".row");
+            f(".col");
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".f16");
+            f(".f16");
+            match &self.ctype {
+                Ctype::F16 => {
+                    f(".f16");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 0,
+                line: 4,
+                rule: "mma.sync.aligned.m16n8k16.row.col.dtype.f16.f16.ctype d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::mma::section_1::*;
+
+    impl Metadata for MmaSyncAlignedM16n8k4RowColF32Tf32Tf32F32 {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            f(".m16n8k4");
+            f(".row");
+            f(".col");
+            f(".f32");
+            f(".tf32");
+            f(".tf32");
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 1,
+                line: 12,
+                rule: "mma.sync.aligned.m16n8k4.row.col.f32.tf32.tf32.f32 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for MmaSyncAlignedM16n8k8RowColF32AtypeBtypeF32 {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            f(".m16n8k8");
+            f(".row");
+            f(".col");
+            f(".f32");
+            match &self.atype {
+                Atype::Bf16 => {
+                    f(".bf16");
+                }
+                Atype::Tf32 => {
+                    f(".tf32");
+                }
+            }
+            match &self.btype {
+                Btype::Bf16 => {
+                    f(".bf16");
+                }
+                Btype::Tf32 => {
+                    f(".tf32");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 1,
+                line: 13,
+                rule: "mma.sync.aligned.m16n8k8.row.col.f32.atype.btype.f32 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for MmaSyncAlignedM16n8k16RowColF32Bf16Bf16F32 {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            f(".m16n8k16");
+            f(".row");
+            f(".col");
+            f(".f32");
+            f(".bf16");
+            f(".bf16");
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 1,
+                line: 14,
+                rule: "mma.sync.aligned.m16n8k16.row.col.f32.bf16.bf16.f32 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for MmaSyncAlignedShapeRowColDtypeF8typeF8typeCtype {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M16n8k16 => {
+                    f(".m16n8k16");
+                }
+                Shape::M16n8k32 => {
+                    f(".m16n8k32");
+                }
+            }
+            f(".row");
+            f(".col");
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.f8type {
+                F8type::E4m3 => {
+                    f(".e4m3");
+                }
+                F8type::E5m2 => {
+                    f(".e5m2");
+                }
+            }
+            match &self.f8type1 {
+                F8type::E4m3 => {
+                    f(".e4m3");
+                }
+                F8type::E5m2 => {
+                    f(".e5m2");
+                }
+            }
+            match &self.ctype {
+                Ctype::F16 => {
+                    f(".f16");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 1,
+                line: 15,
+                rule: "mma.sync.aligned.shape.row.col.dtype.f8type.f8type.ctype d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for MmaSyncAlignedM16n8k32RowColKindDtypeF8f6f4typeF8f6f4typeCtype {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            f(".m16n8k32");
+            f(".row");
+            f(".col");
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+            }
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.f8f6f4type {
+                F8f6f4type::E4m3 => {
+                    f(".e4m3");
+                }
+                F8f6f4type::E5m2 => {
+                    f(".e5m2");
+                }
+                F8f6f4type::E3m2 => {
+                    f(".e3m2");
+                }
+                F8f6f4type::E2m3 => {
+                    f(".e2m3");
+                }
+                F8f6f4type::E2m1 => {
+                    f(".e2m1");
+                }
+            }
+            match &self.f8f6f4type1 {
+                F8f6f4type::E4m3 => {
+                    f(".e4m3");
+                }
+                F8f6f4type::E5m2 => {
+                    f(".e5m2");
+                }
+                F8f6f4type::E3m2 => {
+                    f(".e3m2");
+                }
+                F8f6f4type::E2m3 => {
+                    f(".e2m3");
+                }
+                F8f6f4type::E2m1 => {
+                    f(".e2m1");
+                }
+            }
+            match &self.ctype {
+                Ctype::F16 => {
+                    f(".f16");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 1,
+                line: 16,
+                rule: "mma.sync.aligned.m16n8k32.row.col.kind.dtype.f8f6f4type.f8f6f4type.ctype d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::mma::section_2::*;
+
+    impl Metadata for MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            f(".m16n8k64");
+            f(".row");
+            f(".col");
+            match &self.kind {
+                Kind::KindMxf4 => {
+                    f(".kind::mxf4");
+                }
+            }
+            f(".block_scale");
+            if let Some(scale_vec_size_0) = self.scale_vec_size.as_ref() {
+                match scale_vec_size_0 {
+                    ScaleVecSize::ScaleVec2x => {
+                        f(".scale_vec::2X");
+                    }
+                }
+            }
+            f(".f32");
+            f(".e2m1");
+            f(".e2m1");
+            f(".f32");
+            match &self.stype {
+                Stype::Ue8m0 => {
+                    f(".ue8m0");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 2,
+                line: 28,
+                rule: "mma.sync.aligned.m16n8k64.row.col.kind.block_scale{.scale_vec_size}.f32.e2m1.e2m1.f32.stype d, a, b, c, scale-a-data, {byte-id-a, thread-id-a}, scale-b-data, {byte-id-b, thread-id-b};",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::mma::section_3::*;
+
+    impl Metadata for MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1 {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            f(".m16n8k64");
+            f(".row");
+            f(".col");
+            match &self.kind {
+                Kind::KindMxf4nvf4 => {
+                    f(".kind::mxf4nvf4");
+                }
+            }
+            f(".block_scale");
+            match &self.scale_vec_size {
+                ScaleVecSize::ScaleVec2x => {
+                    f(".scale_vec::2X");
+                }
+                ScaleVecSize::ScaleVec4x => {
+                    f(".scale_vec::4X");
+                }
+            }
+            f(".f32");
+            f(".e2m1");
+            f(".e2m1");
+            f(".f32");
+            match &self.stype {
+                Stype::Ue8m0 => {
+                    f(".ue8m0");
+                }
+                Stype::Ue4m3 => {
+                    f(".ue4m3");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 3,
+                line: 34,
+                rule: "mma.sync.aligned.m16n8k64.row.col.kind.block_scale.scale_vec_size.f32.e2m1.e2m1.f32.stype d, a, b, c, scale-a-data, {byte-id-a, thread-id-a}, scale-b-data, {byte-id-b, thread-id-b};",
+            }
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::mma::section_4::*;
+
+    impl Metadata
+        for MmaSyncAlignedM16n8k32RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype
+    {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            f(".m16n8k32");
+            f(".row");
+            f(".col");
+            match &self.kind {
+                Kind::KindMxf8f6f4 => {
+                    f(".kind::mxf8f6f4");
+                }
+            }
+            f(".block_scale");
+            if let Some(scale_vec_size_1) = self.scale_vec_size.as_ref() {
+                match scale_vec_size_1 {
+                    ScaleVecSize::ScaleVec1x => {
+                        f(".scale_vec::1X");
+                    }
+                }
+            }
+            f(".f32");
+            match &self.f8f6f4type {
+                F8f6f4type::E4m3 => {
+                    f(".e4m3");
+                }
+                F8f6f4type::E5m2 => {
+                    f(".e5m2");
+                }
+                F8f6f4type::E3m2 => {
+                    f(".e3m2");
+                }
+                F8f6f4type::E2m3 => {
+                    f(".e2m3");
+                }
+                F8f6f4type::E2m1 => {
+                    f(".e2m1");
+                }
+            }
+            match &self.f8f6f4type1 {
+                F8f6f4type::E4m3 => {
+                    f(".e4m3");
+                }
+                F8f6f4type::E5m2 => {
+                    f(".e5m2");
+                }
+                F8f6f4type::E3m2 => {
+                    f(".e3m2");
+                }
+                F8f6f4type::E2m3 => {
+                    f(".e2m3");
+                }
+                F8f6f4type::E2m1 => {
+                    f(".e2m1");
+                }
+            }
+            f(".f32");
+            match &self.stype {
+                Stype::Ue8m0 => {
+                    f(".ue8m0");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 4,
+                line: 40,
+                rule: "mma.sync.aligned.m16n8k32.row.col.kind.block_scale{.scale_vec_size}.f32.f8f6f4type.f8f6f4type.f32.stype d, a, b, c, scale-a-data, {byte-id-a, thread-id-a}, scale-b-data, {byte-id-b, thread-id-b};",
+            }
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::mma::section_5::*;
+
+    impl Metadata for MmaSyncAlignedShapeRowColF64F64F64F64 {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M16n8k16 => {
+                    f(".m16n8k16");
+                }
+                Shape::M16n8k4 => {
+                    f(".m16n8k4");
+                }
+                Shape::M16n8k8 => {
+                    f(".m16n8k8");
+                }
+                Shape::M8n84 => {
+                    f(".m8n84");
+                }
+            }
+            f(".row");
+            f(".col");
+            f(".f64");
+            f(".f64");
+            f(".f64");
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 5,
+                line: 48,
+                rule: "mma.sync.aligned.shape.row.col.f64.f64.f64.f64 d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_6 {
+    use super::*;
+    use crate::r#type::instruction::mma::section_6::*;
+
+    impl Metadata for MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32 {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M16n8k16 => {
+                    f(".m16n8k16");
+                }
+                Shape::M16n8k32 => {
+                    f(".m16n8k32");
+                }
+                Shape::M8n8k16 => {
+                    f(".m8n8k16");
+                }
+            }
+            f(".row");
+            f(".col");
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".s32");
+            match &self.atype {
+                Atype::U8 => {
+                    f(".u8");
+                }
+                Atype::S8 => {
+                    f(".s8");
+                }
+            }
+            match &self.btype {
+                Btype::U8 => {
+                    f(".u8");
+                }
+                Btype::S8 => {
+                    f(".s8");
+                }
+            }
+            f(".s32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 6,
+                line: 53,
+                rule: "mma.sync.aligned.shape.row.col{.satfinite}.s32.atype.btype.s32 d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_7 {
+    use super::*;
+    use crate::r#type::instruction::mma::section_7::*;
+
+    impl Metadata for MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321 {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M16n8k32 => {
+                    f(".m16n8k32");
+                }
+                Shape::M16n8k64 => {
+                    f(".m16n8k64");
+                }
+                Shape::M8n8k32 => {
+                    f(".m8n8k32");
+                }
+            }
+            f(".row");
+            f(".col");
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".s32");
+            match &self.atype {
+                Atype::U4 => {
+                    f(".u4");
+                }
+                Atype::S4 => {
+                    f(".s4");
+                }
+            }
+            match &self.btype {
+                Btype::U4 => {
+                    f(".u4");
+                }
+                Btype::S4 => {
+                    f(".s4");
+                }
+            }
+            f(".s32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 7,
+                line: 59,
+                rule: "mma.sync.aligned.shape.row.col{.satfinite}.s32.atype.btype.s32 d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_8 {
+    use super::*;
+    use crate::r#type::instruction::mma::section_8::*;
+
+    impl Metadata for MmaSyncAlignedShapeRowColS32B1B1S32BitopPopc {
+        fn opcode(&self) -> &'static str {
+            "mma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M16n8k128 => {
+                    f(".m16n8k128");
+                }
+                Shape::M16n8k256 => {
+                    f(".m16n8k256");
+                }
+                Shape::M8n8k128 => {
+                    f(".m8n8k128");
+                }
+            }
+            f(".row");
+            f(".col");
+            f(".s32");
+            f(".b1");
+            f(".b1");
+            f(".s32");
+            match &self.bitop {
+                Bitop::Xor => {
+                    f(".xor");
+                }
+                Bitop::And => {
+                    f(".and");
+                }
+            }
+            f(".popc");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mma.txt",
+                section: 8,
+                line: 66,
+                rule: "mma.sync.aligned.shape.row.col.s32.b1.b1.s32.bitOp.popc d, a, b, c;",
+            }
+        }
+    }
+}
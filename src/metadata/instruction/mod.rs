@@ -0,0 +1,1967 @@
+// Auto-generated module declarations
+// DO NOT EDIT MANUALLY
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+use crate::r#type::instruction::Inst;
+
+pub mod abs;
+pub mod activemask;
+pub mod add;
+pub mod add_cc;
+pub mod addc;
+pub mod alloca;
+pub mod and;
+pub mod applypriority;
+pub mod atom;
+pub mod bar;
+pub mod bar_warp_sync;
+pub mod barrier_cluster;
+pub mod bfe;
+pub mod bfi;
+pub mod bfind;
+pub mod bmsk;
+pub mod bra;
+pub mod brev;
+pub mod brkpt;
+pub mod brx_idx;
+pub mod call;
+pub mod clusterlaunchcontrol_query_cancel;
+pub mod clusterlaunchcontrol_try_cancel;
+pub mod clz;
+pub mod cnot;
+pub mod copysign;
+pub mod cos;
+pub mod cp_async;
+pub mod cp_async_bulk;
+pub mod cp_async_bulk_commit_group;
+pub mod cp_async_bulk_prefetch;
+pub mod cp_async_bulk_prefetch_tensor;
+pub mod cp_async_bulk_tensor;
+pub mod cp_async_bulk_wait_group;
+pub mod cp_async_commit_group;
+pub mod cp_async_mbarrier_arrive;
+pub mod cp_async_wait_group;
+pub mod cp_reduce_async_bulk;
+pub mod cp_reduce_async_bulk_tensor;
+pub mod createpolicy;
+pub mod cvt;
+pub mod cvt_pack;
+pub mod cvta;
+pub mod discard;
+pub mod div;
+pub mod dp2a;
+pub mod dp4a;
+pub mod elect_sync;
+pub mod ex2;
+pub mod exit;
+pub mod fma;
+pub mod fns;
+pub mod getctarank;
+pub mod griddepcontrol;
+pub mod isspacep;
+pub mod istypep;
+pub mod ld;
+pub mod ld_global_nc;
+pub mod ldmatrix;
+pub mod ldu;
+pub mod lg2;
+pub mod lop3;
+pub mod mad;
+pub mod mad24;
+pub mod mad_cc;
+pub mod madc;
+pub mod mapa;
+pub mod match_sync;
+pub mod max;
+pub mod mbarrier_arrive;
+pub mod mbarrier_arrive_drop;
+pub mod mbarrier_complete_tx;
+pub mod mbarrier_expect_tx;
+pub mod mbarrier_init;
+pub mod mbarrier_inval;
+pub mod mbarrier_pending_count;
+pub mod mbarrier_test_wait;
+pub mod membar;
+pub mod min;
+pub mod mma;
+pub mod mma_sp;
+pub mod mov;
+pub mod movmatrix;
+pub mod mul;
+pub mod mul24;
+pub mod multimem_ld_reduce;
+pub mod nanosleep;
+pub mod neg;
+pub mod not;
+pub mod or;
+pub mod pmevent;
+pub mod popc;
+pub mod prefetch;
+pub mod prmt;
+pub mod rcp;
+pub mod rcp_approx_ftz_f64;
+pub mod red;
+pub mod red_async;
+pub mod redux_sync;
+pub mod rem;
+pub mod ret;
+pub mod rsqrt;
+pub mod rsqrt_approx_ftz_f64;
+pub mod sad;
+pub mod selp;
+pub mod set;
+pub mod setmaxnreg;
+pub mod setp;
+pub mod shf;
+pub mod shfl;
+pub mod shfl_sync;
+pub mod shl;
+pub mod shr;
+pub mod sin;
+pub mod slct;
+pub mod sqrt;
+pub mod st;
+pub mod st_async;
+pub mod st_bulk;
+pub mod stackrestore;
+pub mod stacksave;
+pub mod stmatrix;
+pub mod sub;
+pub mod sub_cc;
+pub mod subc;
+pub mod suld;
+pub mod suq;
+pub mod sured;
+pub mod sust;
+pub mod szext;
+pub mod tanh;
+pub mod tcgen05_alloc;
+pub mod tcgen05_commit;
+pub mod tcgen05_cp;
+pub mod tcgen05_fence;
+pub mod tcgen05_ld;
+pub mod tcgen05_mma;
+pub mod tcgen05_mma_sp;
+pub mod tcgen05_mma_ws;
+pub mod tcgen05_mma_ws_sp;
+pub mod tcgen05_shift;
+pub mod tcgen05_st;
+pub mod tcgen05_wait;
+pub mod tensormap_cp_fenceproxy;
+pub mod tensormap_replace;
+pub mod testp;
+pub mod tex;
+pub mod tld4;
+pub mod trap;
+pub mod txq;
+pub mod vmad;
+pub mod vop;
+pub mod vop2;
+pub mod vop4;
+pub mod vote;
+pub mod vote_sync;
+pub mod vset;
+pub mod vset2;
+pub mod vset4;
+pub mod vsh;
+pub mod wgmma_commit_group;
+pub mod wgmma_fence;
+pub mod wgmma_mma_async;
+pub mod wgmma_mma_async_sp;
+pub mod wgmma_wait_group;
+pub mod wmma_load;
+pub mod wmma_mma;
+pub mod wmma_store;
+pub mod xor;
+
+impl Metadata for Inst {
+    fn opcode(&self) -> &'static str {
+        match self {
+            Inst::AbsType(value) => value.opcode(),
+            Inst::AbsFtzF32(value) => value.opcode(),
+            Inst::AbsF64(value) => value.opcode(),
+            Inst::AbsFtzF16(value) => value.opcode(),
+            Inst::AbsFtzF16x2(value) => value.opcode(),
+            Inst::AbsBf16(value) => value.opcode(),
+            Inst::AbsBf16x2(value) => value.opcode(),
+            Inst::ActivemaskB32(value) => value.opcode(),
+            Inst::AddCcType(value) => value.opcode(),
+            Inst::AddType(value) => value.opcode(),
+            Inst::AddSatS32(value) => value.opcode(),
+            Inst::AddRndFtzSatF32(value) => value.opcode(),
+            Inst::AddRndFtzF32x2(value) => value.opcode(),
+            Inst::AddRndF64(value) => value.opcode(),
+            Inst::AddRndFtzSatF16(value) => value.opcode(),
+            Inst::AddRndFtzSatF16x2(value) => value.opcode(),
+            Inst::AddRndBf16(value) => value.opcode(),
+            Inst::AddRndBf16x2(value) => value.opcode(),
+            Inst::AddRndSatF32Atype(value) => value.opcode(),
+            Inst::AddcCcType(value) => value.opcode(),
+            Inst::AllocaType(value) => value.opcode(),
+            Inst::AndType(value) => value.opcode(),
+            Inst::ApplypriorityGlobalLevelEvictionPriority(value) => value.opcode(),
+            Inst::AtomSemScopeSpaceOpLevelCacheHintType(value) => value.opcode(),
+            Inst::AtomSemScopeSpaceOpType(value) => value.opcode(),
+            Inst::AtomSemScopeSpaceCasB16(value) => value.opcode(),
+            Inst::AtomSemScopeSpaceCasB128(value) => value.opcode(),
+            Inst::AtomSemScopeSpaceExchLevelCacheHintB128(value) => value.opcode(),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16(value) => value.opcode(),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2(value) => value.opcode(),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16(value) => value.opcode(),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2(value) => value.opcode(),
+            Inst::AtomSemScopeGlobalAddLevelCacheHintVec32BitF32(value) => value.opcode(),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType(value) => value.opcode(),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType(value) => value.opcode(),
+            Inst::BarrierCtaSyncAligned(value) => value.opcode(),
+            Inst::BarrierCtaArriveAligned(value) => value.opcode(),
+            Inst::BarrierCtaRedPopcAlignedU32(value) => value.opcode(),
+            Inst::BarrierCtaRedOpAlignedPred(value) => value.opcode(),
+            Inst::BarCtaSync(value) => value.opcode(),
+            Inst::BarCtaArrive(value) => value.opcode(),
+            Inst::BarCtaRedPopcU32(value) => value.opcode(),
+            Inst::BarCtaRedOpPred(value) => value.opcode(),
+            Inst::BarWarpSync(value) => value.opcode(),
+            Inst::BarrierClusterArriveSemAligned(value) => value.opcode(),
+            Inst::BarrierClusterWaitAcquireAligned(value) => value.opcode(),
+            Inst::BfeType(value) => value.opcode(),
+            Inst::BfiType(value) => value.opcode(),
+            Inst::BfindType(value) => value.opcode(),
+            Inst::BfindShiftamtType(value) => value.opcode(),
+            Inst::BmskModeB32(value) => value.opcode(),
+            Inst::BraUni(value) => value.opcode(),
+            Inst::BraUni1(value) => value.opcode(),
+            Inst::BrevType(value) => value.opcode(),
+            Inst::Brkpt(value) => value.opcode(),
+            Inst::BrxIdxUni(value) => value.opcode(),
+            Inst::BrxIdxUni1(value) => value.opcode(),
+            Inst::CallUni(value) => value.opcode(),
+            Inst::CallUni1(value) => value.opcode(),
+            Inst::CallUni2(value) => value.opcode(),
+            Inst::CallUni3(value) => value.opcode(),
+            Inst::CallUni4(value) => value.opcode(),
+            Inst::CallUni5(value) => value.opcode(),
+            Inst::CallUni6(value) => value.opcode(),
+            Inst::CallUni7(value) => value.opcode(),
+            Inst::CallUni8(value) => value.opcode(),
+            Inst::ClusterlaunchcontrolQueryCancelIsCanceledPredB128(value) => value.opcode(),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128(value) => value.opcode(),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128(value) => value.opcode(),
+            Inst::ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128(value) => value.opcode(),
+            Inst::ClzType(value) => value.opcode(),
+            Inst::CnotType(value) => value.opcode(),
+            Inst::CopysignType(value) => value.opcode(),
+            Inst::CosApproxFtzF32(value) => value.opcode(),
+            Inst::CpAsyncBulkCommitGroup(value) => value.opcode(),
+            Inst::CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint(value) => value.opcode(),
+            Inst::CpAsyncBulkPrefetchL2SrcLevelCacheHint(value) => value.opcode(),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint(value) => value.opcode(),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint(value) => value.opcode(),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint(value) => value.opcode(),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint(value) => value.opcode(),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint(value) => value.opcode(),
+            Inst::CpAsyncBulkDstSrcCompletionMechanism(value) => value.opcode(),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask(value) => value.opcode(),
+            Inst::CpAsyncBulkWaitGroupRead(value) => value.opcode(),
+            Inst::CpAsyncCommitGroup(value) => value.opcode(),
+            Inst::CpAsyncMbarrierArriveNoincStateB64(value) => value.opcode(),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.opcode(),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.opcode(),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.opcode(),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.opcode(),
+            Inst::CpAsyncWaitGroup(value) => value.opcode(),
+            Inst::CpAsyncWaitAll(value) => value.opcode(),
+            Inst::CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint(value) => value.opcode(),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismRedopType(value) => value.opcode(),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType(value) => value.opcode(),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType(value) => value.opcode(),
+            Inst::CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.opcode(),
+            Inst::CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.opcode(),
+            Inst::CreatepolicyCvtL2B64(value) => value.opcode(),
+            Inst::CvtPackSatConverttypeAbtype(value) => value.opcode(),
+            Inst::CvtPackSatConverttypeAbtypeCtype(value) => value.opcode(),
+            Inst::CvtIrndFtzSatDtypeAtype(value) => value.opcode(),
+            Inst::CvtFrndFtzSatDtypeAtype(value) => value.opcode(),
+            Inst::CvtFrnd2ReluSatfiniteF16F32(value) => value.opcode(),
+            Inst::CvtFrnd2ReluSatfiniteF16x2F32(value) => value.opcode(),
+            Inst::CvtRsReluSatfiniteF16x2F32(value) => value.opcode(),
+            Inst::CvtFrnd2ReluSatfiniteBf16F32(value) => value.opcode(),
+            Inst::CvtFrnd2ReluSatfiniteBf16x2F32(value) => value.opcode(),
+            Inst::CvtRsReluSatfiniteBf16x2F32(value) => value.opcode(),
+            Inst::CvtRnaSatfiniteTf32F32(value) => value.opcode(),
+            Inst::CvtFrnd2SatfiniteReluTf32F32(value) => value.opcode(),
+            Inst::CvtRnSatfiniteReluF8x2typeF32(value) => value.opcode(),
+            Inst::CvtRnSatfiniteReluF8x2typeF16x2(value) => value.opcode(),
+            Inst::CvtRnReluF16x2F8x2type(value) => value.opcode(),
+            Inst::CvtRsReluSatfiniteF8x4typeF32(value) => value.opcode(),
+            Inst::CvtRnSatfiniteReluF4x2typeF32(value) => value.opcode(),
+            Inst::CvtRnReluF16x2F4x2type(value) => value.opcode(),
+            Inst::CvtRsReluSatfiniteF4x4typeF32(value) => value.opcode(),
+            Inst::CvtRnSatfiniteReluF6x2typeF32(value) => value.opcode(),
+            Inst::CvtRnReluF16x2F6x2type(value) => value.opcode(),
+            Inst::CvtRsReluSatfiniteF6x4typeF32(value) => value.opcode(),
+            Inst::CvtFrnd3SatfiniteUe8m0x2F32(value) => value.opcode(),
+            Inst::CvtFrnd3SatfiniteUe8m0x2Bf16x2(value) => value.opcode(),
+            Inst::CvtRnBf16x2Ue8m0x2(value) => value.opcode(),
+            Inst::CvtaSpaceSize(value) => value.opcode(),
+            Inst::CvtaToSpaceSize(value) => value.opcode(),
+            Inst::DiscardGlobalLevel(value) => value.opcode(),
+            Inst::DivType(value) => value.opcode(),
+            Inst::DivApproxFtzF32(value) => value.opcode(),
+            Inst::DivFullFtzF32(value) => value.opcode(),
+            Inst::DivRndFtzF32(value) => value.opcode(),
+            Inst::DivRndF64(value) => value.opcode(),
+            Inst::Dp2aModeAtypeBtype(value) => value.opcode(),
+            Inst::Dp4aAtypeBtype(value) => value.opcode(),
+            Inst::ElectSync(value) => value.opcode(),
+            Inst::Ex2ApproxFtzF32(value) => value.opcode(),
+            Inst::Ex2ApproxAtype(value) => value.opcode(),
+            Inst::Ex2ApproxFtzBtype(value) => value.opcode(),
+            Inst::Exit(value) => value.opcode(),
+            Inst::FmaRndFtzSatF32(value) => value.opcode(),
+            Inst::FmaRndFtzF32x2(value) => value.opcode(),
+            Inst::FmaRndF64(value) => value.opcode(),
+            Inst::FmaRndFtzSatF16(value) => value.opcode(),
+            Inst::FmaRndFtzSatF16x2(value) => value.opcode(),
+            Inst::FmaRndFtzReluF16(value) => value.opcode(),
+            Inst::FmaRndFtzReluF16x2(value) => value.opcode(),
+            Inst::FmaRndReluBf16(value) => value.opcode(),
+            Inst::FmaRndReluBf16x2(value) => value.opcode(),
+            Inst::FmaRndOobReluType(value) => value.opcode(),
+            Inst::FmaRndSatF32Abtype(value) => value.opcode(),
+            Inst::FnsB32(value) => value.opcode(),
+            Inst::GetctarankSpaceType(value) => value.opcode(),
+            Inst::GetctarankSharedClusterType(value) => value.opcode(),
+            Inst::GetctarankType(value) => value.opcode(),
+            Inst::GriddepcontrolAction(value) => value.opcode(),
+            Inst::IsspacepSpace(value) => value.opcode(),
+            Inst::IstypepType(value) => value.opcode(),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType(value) => value.opcode(),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType(value) => value.opcode(),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType(value) => value.opcode(),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.opcode(),
+            Inst::LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType(value) => value.opcode(),
+            Inst::LdWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.opcode(),
+            Inst::LdVolatileSsLevelPrefetchSizeVecType(value) => value.opcode(),
+            Inst::LdRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.opcode(),
+            Inst::LdAcquireScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.opcode(),
+            Inst::LdMmioRelaxedSysGlobalType(value) => value.opcode(),
+            Inst::LdmatrixSyncAlignedShapeNumTransSsType(value) => value.opcode(),
+            Inst::LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt(value) => value.opcode(),
+            Inst::LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt(value) => value.opcode(),
+            Inst::LduSsType(value) => value.opcode(),
+            Inst::LduSsVecType(value) => value.opcode(),
+            Inst::Lg2ApproxFtzF32(value) => value.opcode(),
+            Inst::Lop3B32(value) => value.opcode(),
+            Inst::Lop3BoolopB32(value) => value.opcode(),
+            Inst::MadHiloCcType(value) => value.opcode(),
+            Inst::MadModeType(value) => value.opcode(),
+            Inst::MadHiSatS32(value) => value.opcode(),
+            Inst::MadFtzSatF32(value) => value.opcode(),
+            Inst::MadRndFtzSatF32(value) => value.opcode(),
+            Inst::MadRndF64(value) => value.opcode(),
+            Inst::Mad24ModeType(value) => value.opcode(),
+            Inst::Mad24HiSatS32(value) => value.opcode(),
+            Inst::MadcHiloCcType(value) => value.opcode(),
+            Inst::MapaSpaceType(value) => value.opcode(),
+            Inst::MatchAnySyncType(value) => value.opcode(),
+            Inst::MatchAllSyncType(value) => value.opcode(),
+            Inst::MaxAtype(value) => value.opcode(),
+            Inst::MaxReluBtype(value) => value.opcode(),
+            Inst::MaxFtzNanXorsignAbsF32(value) => value.opcode(),
+            Inst::MaxFtzNanAbsF32(value) => value.opcode(),
+            Inst::MaxF64(value) => value.opcode(),
+            Inst::MaxFtzNanXorsignAbsF16(value) => value.opcode(),
+            Inst::MaxFtzNanXorsignAbsF16x2(value) => value.opcode(),
+            Inst::MaxNanXorsignAbsBf16(value) => value.opcode(),
+            Inst::MaxNanXorsignAbsBf16x2(value) => value.opcode(),
+            Inst::MbarrierArriveSemScopeStateB64(value) => value.opcode(),
+            Inst::MbarrierArriveSemScopeSharedClusterB64(value) => value.opcode(),
+            Inst::MbarrierArriveExpectTxSemScopeStateB64(value) => value.opcode(),
+            Inst::MbarrierArriveExpectTxSemScopeSharedClusterB64(value) => value.opcode(),
+            Inst::MbarrierArriveNocompleteReleaseCtaStateB64(value) => value.opcode(),
+            Inst::MbarrierArriveDropSemScopeStateB64(value) => value.opcode(),
+            Inst::MbarrierArriveDropSemScopeSharedClusterB64(value) => value.opcode(),
+            Inst::MbarrierArriveDropExpectTxStateSemScopeB64(value) => value.opcode(),
+            Inst::MbarrierArriveDropExpectTxSharedClusterSemScopeB64(value) => value.opcode(),
+            Inst::MbarrierArriveDropNocompleteReleaseCtaStateB64(value) => value.opcode(),
+            Inst::MbarrierCompleteTxSemScopeSpaceB64(value) => value.opcode(),
+            Inst::MbarrierExpectTxSemScopeSpaceB64(value) => value.opcode(),
+            Inst::MbarrierInitStateB64(value) => value.opcode(),
+            Inst::MbarrierInvalStateB64(value) => value.opcode(),
+            Inst::MbarrierPendingCountB64(value) => value.opcode(),
+            Inst::MbarrierTestWaitSemScopeStateB64(value) => value.opcode(),
+            Inst::MbarrierTestWaitParitySemScopeStateB64(value) => value.opcode(),
+            Inst::MbarrierTryWaitSemScopeStateB64(value) => value.opcode(),
+            Inst::MbarrierTryWaitParitySemScopeStateB64(value) => value.opcode(),
+            Inst::FenceSemScope(value) => value.opcode(),
+            Inst::FenceAcquireSyncRestrictSharedClusterCluster(value) => value.opcode(),
+            Inst::FenceReleaseSyncRestrictSharedCtaCluster(value) => value.opcode(),
+            Inst::FenceOpRestrictReleaseCluster(value) => value.opcode(),
+            Inst::FenceProxyProxykind(value) => value.opcode(),
+            Inst::FenceProxyToProxykindFromProxykindReleaseScope(value) => value.opcode(),
+            Inst::FenceProxyToProxykindFromProxykindAcquireScope(value) => value.opcode(),
+            Inst::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster(value) => value.opcode(),
+            Inst::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster(value) => value.opcode(),
+            Inst::MembarLevel(value) => value.opcode(),
+            Inst::MembarProxyProxykind(value) => value.opcode(),
+            Inst::MinAtype(value) => value.opcode(),
+            Inst::MinReluBtype(value) => value.opcode(),
+            Inst::MinFtzNanXorsignAbsF32(value) => value.opcode(),
+            Inst::MinFtzNanAbsF32(value) => value.opcode(),
+            Inst::MinF64(value) => value.opcode(),
+            Inst::MinFtzNanXorsignAbsF16(value) => value.opcode(),
+            Inst::MinFtzNanXorsignAbsF16x2(value) => value.opcode(),
+            Inst::MinNanXorsignAbsBf16(value) => value.opcode(),
+            Inst::MinNanXorsignAbsBf16x2(value) => value.opcode(),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.opcode(),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype(value) => value.opcode(),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.opcode(),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32(value) => value.opcode(),
+            Inst::MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32(value) => value.opcode(),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32(value) => value.opcode(),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32(value) => value.opcode(),
+            Inst::MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.opcode(),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.opcode(),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.opcode(),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.opcode(),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.opcode(),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.opcode(),
+            Inst::MmaSyncAlignedM8n8k4AlayoutBlayoutDtypeF16F16Ctype(value) => value.opcode(),
+            Inst::MmaSyncAlignedM16n8k8RowColDtypeF16F16Ctype(value) => value.opcode(),
+            Inst::MmaSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.opcode(),
+            Inst::MmaSyncAlignedM16n8k4RowColF32Tf32Tf32F32(value) => value.opcode(),
+            Inst::MmaSyncAlignedM16n8k8RowColF32AtypeBtypeF32(value) => value.opcode(),
+            Inst::MmaSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.opcode(),
+            Inst::MmaSyncAlignedShapeRowColDtypeF8typeF8typeCtype(value) => value.opcode(),
+            Inst::MmaSyncAlignedM16n8k32RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.opcode(),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.opcode(),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.opcode(),
+            Inst::MmaSyncAlignedM16n8k32RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.opcode(),
+            Inst::MmaSyncAlignedShapeRowColF64F64F64F64(value) => value.opcode(),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.opcode(),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.opcode(),
+            Inst::MmaSyncAlignedShapeRowColS32B1B1S32BitopPopc(value) => value.opcode(),
+            Inst::MovType(value) => value.opcode(),
+            Inst::MovU32(value) => value.opcode(),
+            Inst::MovU64(value) => value.opcode(),
+            Inst::MovU321(value) => value.opcode(),
+            Inst::MovU641(value) => value.opcode(),
+            Inst::MovType1(value) => value.opcode(),
+            Inst::MovmatrixSyncAlignedShapeTransType(value) => value.opcode(),
+            Inst::MulModeType(value) => value.opcode(),
+            Inst::MulRndFtzSatF32(value) => value.opcode(),
+            Inst::MulRndFtzF32x2(value) => value.opcode(),
+            Inst::MulRndF64(value) => value.opcode(),
+            Inst::MulRndFtzSatF16(value) => value.opcode(),
+            Inst::MulRndFtzSatF16x2(value) => value.opcode(),
+            Inst::MulRndBf16(value) => value.opcode(),
+            Inst::MulRndBf16x2(value) => value.opcode(),
+            Inst::Mul24ModeType(value) => value.opcode(),
+            Inst::MultimemLdReduceLdsemScopeSsOpType(value) => value.opcode(),
+            Inst::MultimemLdReduceWeakSsOpType(value) => value.opcode(),
+            Inst::MultimemStStsemScopeSsType(value) => value.opcode(),
+            Inst::MultimemStWeakSsType(value) => value.opcode(),
+            Inst::MultimemRedRedsemScopeSsOpType(value) => value.opcode(),
+            Inst::MultimemLdReduceLdsemScopeSsOpAccPrecVecType(value) => value.opcode(),
+            Inst::MultimemLdReduceWeakSsOpAccPrecVecType(value) => value.opcode(),
+            Inst::MultimemStStsemScopeSsVecType(value) => value.opcode(),
+            Inst::MultimemStWeakSsVecType(value) => value.opcode(),
+            Inst::MultimemRedRedsemScopeSsRedopVecRedtype(value) => value.opcode(),
+            Inst::NanosleepU32(value) => value.opcode(),
+            Inst::NegType(value) => value.opcode(),
+            Inst::NegFtzF32(value) => value.opcode(),
+            Inst::NegF64(value) => value.opcode(),
+            Inst::NegFtzF16(value) => value.opcode(),
+            Inst::NegFtzF16x2(value) => value.opcode(),
+            Inst::NegBf16(value) => value.opcode(),
+            Inst::NegBf16x2(value) => value.opcode(),
+            Inst::NotType(value) => value.opcode(),
+            Inst::OrType(value) => value.opcode(),
+            Inst::Pmevent(value) => value.opcode(),
+            Inst::PmeventMask(value) => value.opcode(),
+            Inst::PopcType(value) => value.opcode(),
+            Inst::PrefetchSpaceLevel(value) => value.opcode(),
+            Inst::PrefetchGlobalLevelEvictionPriority(value) => value.opcode(),
+            Inst::PrefetchuL1(value) => value.opcode(),
+            Inst::PrefetchTensormapSpaceTensormap(value) => value.opcode(),
+            Inst::PrmtB32Mode(value) => value.opcode(),
+            Inst::RcpApproxFtzF64(value) => value.opcode(),
+            Inst::RcpApproxFtzF32(value) => value.opcode(),
+            Inst::RcpRndFtzF32(value) => value.opcode(),
+            Inst::RcpRndF64(value) => value.opcode(),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType(value) => value.opcode(),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType1(value) => value.opcode(),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType2(value) => value.opcode(),
+            Inst::RedAsyncSemScopeSsCompletionMechanismAddType(value) => value.opcode(),
+            Inst::RedAsyncMmioSemScopeSsAddType(value) => value.opcode(),
+            Inst::RedOpSpaceSemScopeLevelCacheHintType(value) => value.opcode(),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16(value) => value.opcode(),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16x2(value) => value.opcode(),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16(value) => value.opcode(),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16x2(value) => value.opcode(),
+            Inst::RedAddSpaceSemScopeLevelCacheHintVec32BitF32(value) => value.opcode(),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec16BitHalfWordType(value) => value.opcode(),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec32BitPackedType(value) => value.opcode(),
+            Inst::ReduxSyncOpType(value) => value.opcode(),
+            Inst::ReduxSyncOpB32(value) => value.opcode(),
+            Inst::ReduxSyncOpAbsNanF32(value) => value.opcode(),
+            Inst::RemType(value) => value.opcode(),
+            Inst::RetUni(value) => value.opcode(),
+            Inst::RsqrtApproxFtzF64(value) => value.opcode(),
+            Inst::RsqrtApproxFtzF32(value) => value.opcode(),
+            Inst::RsqrtApproxF64(value) => value.opcode(),
+            Inst::SadType(value) => value.opcode(),
+            Inst::SelpType(value) => value.opcode(),
+            Inst::SetCmpopFtzDtypeStype(value) => value.opcode(),
+            Inst::SetCmpopBoolopFtzDtypeStype(value) => value.opcode(),
+            Inst::SetCmpopFtzF16Stype(value) => value.opcode(),
+            Inst::SetCmpopBoolopFtzF16Stype(value) => value.opcode(),
+            Inst::SetCmpopBf16Stype(value) => value.opcode(),
+            Inst::SetCmpopBoolopBf16Stype(value) => value.opcode(),
+            Inst::SetCmpopFtzDtypeF16(value) => value.opcode(),
+            Inst::SetCmpopBoolopFtzDtypeF16(value) => value.opcode(),
+            Inst::SetCmpopDtypeBf16(value) => value.opcode(),
+            Inst::SetCmpopBoolopDtypeBf16(value) => value.opcode(),
+            Inst::SetCmpopFtzDtypeF16x2(value) => value.opcode(),
+            Inst::SetCmpopBoolopFtzDtypeF16x2(value) => value.opcode(),
+            Inst::SetCmpopDtypeBf16x2(value) => value.opcode(),
+            Inst::SetCmpopBoolopDtypeBf16x2(value) => value.opcode(),
+            Inst::SetmaxnregActionSyncAlignedU32(value) => value.opcode(),
+            Inst::SetpCmpopFtzType(value) => value.opcode(),
+            Inst::SetpCmpopBoolopFtzType(value) => value.opcode(),
+            Inst::SetpCmpopFtzF16(value) => value.opcode(),
+            Inst::SetpCmpopBoolopFtzF16(value) => value.opcode(),
+            Inst::SetpCmpopFtzF16x2(value) => value.opcode(),
+            Inst::SetpCmpopBoolopFtzF16x2(value) => value.opcode(),
+            Inst::SetpCmpopBf16(value) => value.opcode(),
+            Inst::SetpCmpopBoolopBf16(value) => value.opcode(),
+            Inst::SetpCmpopBf16x2(value) => value.opcode(),
+            Inst::SetpCmpopBoolopBf16x2(value) => value.opcode(),
+            Inst::ShfLModeB32(value) => value.opcode(),
+            Inst::ShfRModeB32(value) => value.opcode(),
+            Inst::ShflSyncModeB32(value) => value.opcode(),
+            Inst::ShflModeB32(value) => value.opcode(),
+            Inst::ShlType(value) => value.opcode(),
+            Inst::ShrType(value) => value.opcode(),
+            Inst::SinApproxFtzF32(value) => value.opcode(),
+            Inst::SlctDtypeS32(value) => value.opcode(),
+            Inst::SlctFtzDtypeF32(value) => value.opcode(),
+            Inst::SqrtApproxFtzF32(value) => value.opcode(),
+            Inst::SqrtRndFtzF32(value) => value.opcode(),
+            Inst::SqrtRndF64(value) => value.opcode(),
+            Inst::StAsyncSemScopeSsCompletionMechanismVecType(value) => value.opcode(),
+            Inst::StAsyncMmioSemScopeSsType(value) => value.opcode(),
+            Inst::StBulkWeakSharedCta(value) => value.opcode(),
+            Inst::StWeakSsCopLevelCacheHintVecType(value) => value.opcode(),
+            Inst::StWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.opcode(),
+            Inst::StVolatileSsVecType(value) => value.opcode(),
+            Inst::StRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.opcode(),
+            Inst::StReleaseScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.opcode(),
+            Inst::StMmioRelaxedSysGlobalType(value) => value.opcode(),
+            Inst::StackrestoreType(value) => value.opcode(),
+            Inst::StacksaveType(value) => value.opcode(),
+            Inst::StmatrixSyncAlignedShapeNumTransSsType(value) => value.opcode(),
+            Inst::SubCcType(value) => value.opcode(),
+            Inst::SubType(value) => value.opcode(),
+            Inst::SubSatS32(value) => value.opcode(),
+            Inst::SubRndFtzSatF32(value) => value.opcode(),
+            Inst::SubRndFtzF32x2(value) => value.opcode(),
+            Inst::SubRndF64(value) => value.opcode(),
+            Inst::SubRndFtzSatF16(value) => value.opcode(),
+            Inst::SubRndFtzSatF16x2(value) => value.opcode(),
+            Inst::SubRndBf16(value) => value.opcode(),
+            Inst::SubRndBf16x2(value) => value.opcode(),
+            Inst::SubRndSatF32Atype(value) => value.opcode(),
+            Inst::SubcCcType(value) => value.opcode(),
+            Inst::SuldBGeomCopVecDtypeMode(value) => value.opcode(),
+            Inst::SuqQueryB32(value) => value.opcode(),
+            Inst::SuredBOpGeomCtypeMode(value) => value.opcode(),
+            Inst::SuredPOpGeomCtypeMode(value) => value.opcode(),
+            Inst::SustBDimCopVecCtypeMode(value) => value.opcode(),
+            Inst::SustPDimVecB32Mode(value) => value.opcode(),
+            Inst::SustBAdimCopVecCtypeMode(value) => value.opcode(),
+            Inst::SzextModeType(value) => value.opcode(),
+            Inst::TanhApproxType(value) => value.opcode(),
+            Inst::Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32(value) => value.opcode(),
+            Inst::Tcgen05DeallocCtaGroupSyncAlignedB32(value) => value.opcode(),
+            Inst::Tcgen05RelinquishAllocPermitCtaGroupSyncAligned(value) => value.opcode(),
+            Inst::Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64(value) => value.opcode(),
+            Inst::Tcgen05CpCtaGroupShapeMulticastDstSrcFmt(value) => value.opcode(),
+            Inst::Tcgen05FenceBeforeThreadSync(value) => value.opcode(),
+            Inst::Tcgen05FenceAfterThreadSync(value) => value.opcode(),
+            Inst::Tcgen05LdSyncAlignedShape1NumPackB32(value) => value.opcode(),
+            Inst::Tcgen05LdSyncAlignedShape2NumPackB32(value) => value.opcode(),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32(value) => value.opcode(),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32(value) => value.opcode(),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopType(value) => value.opcode(),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopType(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKind(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKind1(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKindCollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKindI8(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKindI81(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKindI8CollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKind(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKind1(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKindCollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage1(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKindI8(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKindI81(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKindI8CollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1(value) => value.opcode(),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage1(value) => value.opcode(),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage1(value) => value.opcode(),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage1(value) => value.opcode(),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage(value) => value.opcode(),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1(value) => value.opcode(),
+            Inst::Tcgen05ShiftCtaGroupDown(value) => value.opcode(),
+            Inst::Tcgen05StSyncAlignedShape1NumUnpackB32(value) => value.opcode(),
+            Inst::Tcgen05StSyncAlignedShape2NumUnpackB32(value) => value.opcode(),
+            Inst::Tcgen05WaitOperationSyncAligned(value) => value.opcode(),
+            Inst::TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned(value) => value.opcode(),
+            Inst::TensormapReplaceModeField1SsB1024Type(value) => value.opcode(),
+            Inst::TensormapReplaceModeField2SsB1024Type(value) => value.opcode(),
+            Inst::TensormapReplaceModeField3SsB1024Type(value) => value.opcode(),
+            Inst::TestpOpType(value) => value.opcode(),
+            Inst::TexGeomV4DtypeCtype(value) => value.opcode(),
+            Inst::TexGeomV4DtypeCtype1(value) => value.opcode(),
+            Inst::TexGeomV2F16x2Ctype(value) => value.opcode(),
+            Inst::TexGeomV2F16x2Ctype1(value) => value.opcode(),
+            Inst::TexBaseGeomV4DtypeCtype(value) => value.opcode(),
+            Inst::TexLevelGeomV4DtypeCtype(value) => value.opcode(),
+            Inst::TexGradGeomV4DtypeCtype(value) => value.opcode(),
+            Inst::TexBaseGeomV2F16x2Ctype(value) => value.opcode(),
+            Inst::TexLevelGeomV2F16x2Ctype(value) => value.opcode(),
+            Inst::TexGradGeomV2F16x2Ctype(value) => value.opcode(),
+            Inst::Tld4Comp2dV4DtypeF32(value) => value.opcode(),
+            Inst::Tld4CompGeomV4DtypeF32(value) => value.opcode(),
+            Inst::Trap(value) => value.opcode(),
+            Inst::TxqTqueryB32(value) => value.opcode(),
+            Inst::TxqLevelTlqueryB32(value) => value.opcode(),
+            Inst::TxqSqueryB32(value) => value.opcode(),
+            Inst::VmadDtypeAtypeBtypeSatScale(value) => value.opcode(),
+            Inst::VmadDtypeAtypeBtypePoSatScale(value) => value.opcode(),
+            Inst::VaddDtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::VsubDtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::VabsdiffDtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::VminDtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::VmaxDtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::VaddDtypeAtypeBtypeSatOp2(value) => value.opcode(),
+            Inst::VsubDtypeAtypeBtypeSatOp2(value) => value.opcode(),
+            Inst::VabsdiffDtypeAtypeBtypeSatOp2(value) => value.opcode(),
+            Inst::VminDtypeAtypeBtypeSatOp2(value) => value.opcode(),
+            Inst::VmaxDtypeAtypeBtypeSatOp2(value) => value.opcode(),
+            Inst::VaddDtypeAtypeBtypeSat1(value) => value.opcode(),
+            Inst::VsubDtypeAtypeBtypeSat1(value) => value.opcode(),
+            Inst::VabsdiffDtypeAtypeBtypeSat1(value) => value.opcode(),
+            Inst::VminDtypeAtypeBtypeSat1(value) => value.opcode(),
+            Inst::VmaxDtypeAtypeBtypeSat1(value) => value.opcode(),
+            Inst::Vadd2DtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::Vsub2DtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::Vavrg2DtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::Vabsdiff2DtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::Vmin2DtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::Vmax2DtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::Vadd2DtypeAtypeBtypeAdd(value) => value.opcode(),
+            Inst::Vsub2DtypeAtypeBtypeAdd(value) => value.opcode(),
+            Inst::Vavrg2DtypeAtypeBtypeAdd(value) => value.opcode(),
+            Inst::Vabsdiff2DtypeAtypeBtypeAdd(value) => value.opcode(),
+            Inst::Vmin2DtypeAtypeBtypeAdd(value) => value.opcode(),
+            Inst::Vmax2DtypeAtypeBtypeAdd(value) => value.opcode(),
+            Inst::Vadd4DtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::Vsub4DtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::Vavrg4DtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::Vabsdiff4DtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::Vmin4DtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::Vmax4DtypeAtypeBtypeSat(value) => value.opcode(),
+            Inst::Vadd4DtypeAtypeBtypeAdd(value) => value.opcode(),
+            Inst::Vsub4DtypeAtypeBtypeAdd(value) => value.opcode(),
+            Inst::Vavrg4DtypeAtypeBtypeAdd(value) => value.opcode(),
+            Inst::Vabsdiff4DtypeAtypeBtypeAdd(value) => value.opcode(),
+            Inst::Vmin4DtypeAtypeBtypeAdd(value) => value.opcode(),
+            Inst::Vmax4DtypeAtypeBtypeAdd(value) => value.opcode(),
+            Inst::VoteSyncModePred(value) => value.opcode(),
+            Inst::VoteSyncBallotB32(value) => value.opcode(),
+            Inst::VoteModePred(value) => value.opcode(),
+            Inst::VoteBallotB32(value) => value.opcode(),
+            Inst::VsetAtypeBtypeCmp(value) => value.opcode(),
+            Inst::VsetAtypeBtypeCmpOp2(value) => value.opcode(),
+            Inst::VsetAtypeBtypeCmp1(value) => value.opcode(),
+            Inst::Vset2AtypeBtypeCmp(value) => value.opcode(),
+            Inst::Vset2AtypeBtypeCmpAdd(value) => value.opcode(),
+            Inst::Vset4AtypeBtypeCmp(value) => value.opcode(),
+            Inst::Vset4AtypeBtypeCmpAdd(value) => value.opcode(),
+            Inst::VshlDtypeAtypeU32SatMode(value) => value.opcode(),
+            Inst::VshrDtypeAtypeU32SatMode(value) => value.opcode(),
+            Inst::VshlDtypeAtypeU32SatModeOp2(value) => value.opcode(),
+            Inst::VshrDtypeAtypeU32SatModeOp2(value) => value.opcode(),
+            Inst::VshlDtypeAtypeU32SatMode1(value) => value.opcode(),
+            Inst::VshrDtypeAtypeU32SatMode1(value) => value.opcode(),
+            Inst::WgmmaCommitGroupSyncAligned(value) => value.opcode(),
+            Inst::WgmmaFenceSyncAligned(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc(value) => value.opcode(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1(value) => value.opcode(),
+            Inst::WgmmaWaitGroupSyncAligned(value) => value.opcode(),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype(value) => value.opcode(),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype(value) => value.opcode(),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype(value) => value.opcode(),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype1(value) => value.opcode(),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype1(value) => value.opcode(),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype1(value) => value.opcode(),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype2(value) => value.opcode(),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype2(value) => value.opcode(),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype2(value) => value.opcode(),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype3(value) => value.opcode(),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype3(value) => value.opcode(),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype3(value) => value.opcode(),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype(value) => value.opcode(),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype(value) => value.opcode(),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype4(value) => value.opcode(),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype1(value) => value.opcode(),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype1(value) => value.opcode(),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype5(value) => value.opcode(),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeDtypeCtype(value) => value.opcode(),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeS32AtypeBtypeS32Satfinite(value) => value.opcode(),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF32(value) => value.opcode(),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF321(value) => value.opcode(),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeRndF64F64F64F64(value) => value.opcode(),
+            Inst::WmmaMmaSyncAlignedRowColShapeS32AtypeBtypeS32Satfinite(value) => value.opcode(),
+            Inst::WmmaMmaOpPopcSyncAlignedRowColShapeS32AtypeBtypeS32(value) => value.opcode(),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType(value) => value.opcode(),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType1(value) => value.opcode(),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType2(value) => value.opcode(),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType3(value) => value.opcode(),
+            Inst::XorType(value) => value.opcode(),
+        }
+    }
+    fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+        match self {
+            Inst::AbsType(value) => value.for_each_modifier(f),
+            Inst::AbsFtzF32(value) => value.for_each_modifier(f),
+            Inst::AbsF64(value) => value.for_each_modifier(f),
+            Inst::AbsFtzF16(value) => value.for_each_modifier(f),
+            Inst::AbsFtzF16x2(value) => value.for_each_modifier(f),
+            Inst::AbsBf16(value) => value.for_each_modifier(f),
+            Inst::AbsBf16x2(value) => value.for_each_modifier(f),
+            Inst::ActivemaskB32(value) => value.for_each_modifier(f),
+            Inst::AddCcType(value) => value.for_each_modifier(f),
+            Inst::AddType(value) => value.for_each_modifier(f),
+            Inst::AddSatS32(value) => value.for_each_modifier(f),
+            Inst::AddRndFtzSatF32(value) => value.for_each_modifier(f),
+            Inst::AddRndFtzF32x2(value) => value.for_each_modifier(f),
+            Inst::AddRndF64(value) => value.for_each_modifier(f),
+            Inst::AddRndFtzSatF16(value) => value.for_each_modifier(f),
+            Inst::AddRndFtzSatF16x2(value) => value.for_each_modifier(f),
+            Inst::AddRndBf16(value) => value.for_each_modifier(f),
+            Inst::AddRndBf16x2(value) => value.for_each_modifier(f),
+            Inst::AddRndSatF32Atype(value) => value.for_each_modifier(f),
+            Inst::AddcCcType(value) => value.for_each_modifier(f),
+            Inst::AllocaType(value) => value.for_each_modifier(f),
+            Inst::AndType(value) => value.for_each_modifier(f),
+            Inst::ApplypriorityGlobalLevelEvictionPriority(value) => value.for_each_modifier(f),
+            Inst::AtomSemScopeSpaceOpLevelCacheHintType(value) => value.for_each_modifier(f),
+            Inst::AtomSemScopeSpaceOpType(value) => value.for_each_modifier(f),
+            Inst::AtomSemScopeSpaceCasB16(value) => value.for_each_modifier(f),
+            Inst::AtomSemScopeSpaceCasB128(value) => value.for_each_modifier(f),
+            Inst::AtomSemScopeSpaceExchLevelCacheHintB128(value) => value.for_each_modifier(f),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16(value) => value.for_each_modifier(f),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2(value) => value.for_each_modifier(f),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16(value) => value.for_each_modifier(f),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2(value) => value.for_each_modifier(f),
+            Inst::AtomSemScopeGlobalAddLevelCacheHintVec32BitF32(value) => value.for_each_modifier(f),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType(value) => value.for_each_modifier(f),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType(value) => value.for_each_modifier(f),
+            Inst::BarrierCtaSyncAligned(value) => value.for_each_modifier(f),
+            Inst::BarrierCtaArriveAligned(value) => value.for_each_modifier(f),
+            Inst::BarrierCtaRedPopcAlignedU32(value) => value.for_each_modifier(f),
+            Inst::BarrierCtaRedOpAlignedPred(value) => value.for_each_modifier(f),
+            Inst::BarCtaSync(value) => value.for_each_modifier(f),
+            Inst::BarCtaArrive(value) => value.for_each_modifier(f),
+            Inst::BarCtaRedPopcU32(value) => value.for_each_modifier(f),
+            Inst::BarCtaRedOpPred(value) => value.for_each_modifier(f),
+            Inst::BarWarpSync(value) => value.for_each_modifier(f),
+            Inst::BarrierClusterArriveSemAligned(value) => value.for_each_modifier(f),
+            Inst::BarrierClusterWaitAcquireAligned(value) => value.for_each_modifier(f),
+            Inst::BfeType(value) => value.for_each_modifier(f),
+            Inst::BfiType(value) => value.for_each_modifier(f),
+            Inst::BfindType(value) => value.for_each_modifier(f),
+            Inst::BfindShiftamtType(value) => value.for_each_modifier(f),
+            Inst::BmskModeB32(value) => value.for_each_modifier(f),
+            Inst::BraUni(value) => value.for_each_modifier(f),
+            Inst::BraUni1(value) => value.for_each_modifier(f),
+            Inst::BrevType(value) => value.for_each_modifier(f),
+            Inst::Brkpt(value) => value.for_each_modifier(f),
+            Inst::BrxIdxUni(value) => value.for_each_modifier(f),
+            Inst::BrxIdxUni1(value) => value.for_each_modifier(f),
+            Inst::CallUni(value) => value.for_each_modifier(f),
+            Inst::CallUni1(value) => value.for_each_modifier(f),
+            Inst::CallUni2(value) => value.for_each_modifier(f),
+            Inst::CallUni3(value) => value.for_each_modifier(f),
+            Inst::CallUni4(value) => value.for_each_modifier(f),
+            Inst::CallUni5(value) => value.for_each_modifier(f),
+            Inst::CallUni6(value) => value.for_each_modifier(f),
+            Inst::CallUni7(value) => value.for_each_modifier(f),
+            Inst::CallUni8(value) => value.for_each_modifier(f),
+            Inst::ClusterlaunchcontrolQueryCancelIsCanceledPredB128(value) => value.for_each_modifier(f),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128(value) => value.for_each_modifier(f),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128(value) => value.for_each_modifier(f),
+            Inst::ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128(value) => value.for_each_modifier(f),
+            Inst::ClzType(value) => value.for_each_modifier(f),
+            Inst::CnotType(value) => value.for_each_modifier(f),
+            Inst::CopysignType(value) => value.for_each_modifier(f),
+            Inst::CosApproxFtzF32(value) => value.for_each_modifier(f),
+            Inst::CpAsyncBulkCommitGroup(value) => value.for_each_modifier(f),
+            Inst::CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint(value) => value.for_each_modifier(f),
+            Inst::CpAsyncBulkPrefetchL2SrcLevelCacheHint(value) => value.for_each_modifier(f),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint(value) => value.for_each_modifier(f),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint(value) => value.for_each_modifier(f),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint(value) => value.for_each_modifier(f),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint(value) => value.for_each_modifier(f),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint(value) => value.for_each_modifier(f),
+            Inst::CpAsyncBulkDstSrcCompletionMechanism(value) => value.for_each_modifier(f),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask(value) => value.for_each_modifier(f),
+            Inst::CpAsyncBulkWaitGroupRead(value) => value.for_each_modifier(f),
+            Inst::CpAsyncCommitGroup(value) => value.for_each_modifier(f),
+            Inst::CpAsyncMbarrierArriveNoincStateB64(value) => value.for_each_modifier(f),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.for_each_modifier(f),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.for_each_modifier(f),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.for_each_modifier(f),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.for_each_modifier(f),
+            Inst::CpAsyncWaitGroup(value) => value.for_each_modifier(f),
+            Inst::CpAsyncWaitAll(value) => value.for_each_modifier(f),
+            Inst::CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint(value) => value.for_each_modifier(f),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismRedopType(value) => value.for_each_modifier(f),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType(value) => value.for_each_modifier(f),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType(value) => value.for_each_modifier(f),
+            Inst::CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.for_each_modifier(f),
+            Inst::CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.for_each_modifier(f),
+            Inst::CreatepolicyCvtL2B64(value) => value.for_each_modifier(f),
+            Inst::CvtPackSatConverttypeAbtype(value) => value.for_each_modifier(f),
+            Inst::CvtPackSatConverttypeAbtypeCtype(value) => value.for_each_modifier(f),
+            Inst::CvtIrndFtzSatDtypeAtype(value) => value.for_each_modifier(f),
+            Inst::CvtFrndFtzSatDtypeAtype(value) => value.for_each_modifier(f),
+            Inst::CvtFrnd2ReluSatfiniteF16F32(value) => value.for_each_modifier(f),
+            Inst::CvtFrnd2ReluSatfiniteF16x2F32(value) => value.for_each_modifier(f),
+            Inst::CvtRsReluSatfiniteF16x2F32(value) => value.for_each_modifier(f),
+            Inst::CvtFrnd2ReluSatfiniteBf16F32(value) => value.for_each_modifier(f),
+            Inst::CvtFrnd2ReluSatfiniteBf16x2F32(value) => value.for_each_modifier(f),
+            Inst::CvtRsReluSatfiniteBf16x2F32(value) => value.for_each_modifier(f),
+            Inst::CvtRnaSatfiniteTf32F32(value) => value.for_each_modifier(f),
+            Inst::CvtFrnd2SatfiniteReluTf32F32(value) => value.for_each_modifier(f),
+            Inst::CvtRnSatfiniteReluF8x2typeF32(value) => value.for_each_modifier(f),
+            Inst::CvtRnSatfiniteReluF8x2typeF16x2(value) => value.for_each_modifier(f),
+            Inst::CvtRnReluF16x2F8x2type(value) => value.for_each_modifier(f),
+            Inst::CvtRsReluSatfiniteF8x4typeF32(value) => value.for_each_modifier(f),
+            Inst::CvtRnSatfiniteReluF4x2typeF32(value) => value.for_each_modifier(f),
+            Inst::CvtRnReluF16x2F4x2type(value) => value.for_each_modifier(f),
+            Inst::CvtRsReluSatfiniteF4x4typeF32(value) => value.for_each_modifier(f),
+            Inst::CvtRnSatfiniteReluF6x2typeF32(value) => value.for_each_modifier(f),
+            Inst::CvtRnReluF16x2F6x2type(value) => value.for_each_modifier(f),
+            Inst::CvtRsReluSatfiniteF6x4typeF32(value) => value.for_each_modifier(f),
+            Inst::CvtFrnd3SatfiniteUe8m0x2F32(value) => value.for_each_modifier(f),
+            Inst::CvtFrnd3SatfiniteUe8m0x2Bf16x2(value) => value.for_each_modifier(f),
+            Inst::CvtRnBf16x2Ue8m0x2(value) => value.for_each_modifier(f),
+            Inst::CvtaSpaceSize(value) => value.for_each_modifier(f),
+            Inst::CvtaToSpaceSize(value) => value.for_each_modifier(f),
+            Inst::DiscardGlobalLevel(value) => value.for_each_modifier(f),
+            Inst::DivType(value) => value.for_each_modifier(f),
+            Inst::DivApproxFtzF32(value) => value.for_each_modifier(f),
+            Inst::DivFullFtzF32(value) => value.for_each_modifier(f),
+            Inst::DivRndFtzF32(value) => value.for_each_modifier(f),
+            Inst::DivRndF64(value) => value.for_each_modifier(f),
+            Inst::Dp2aModeAtypeBtype(value) => value.for_each_modifier(f),
+            Inst::Dp4aAtypeBtype(value) => value.for_each_modifier(f),
+            Inst::ElectSync(value) => value.for_each_modifier(f),
+            Inst::Ex2ApproxFtzF32(value) => value.for_each_modifier(f),
+            Inst::Ex2ApproxAtype(value) => value.for_each_modifier(f),
+            Inst::Ex2ApproxFtzBtype(value) => value.for_each_modifier(f),
+            Inst::Exit(value) => value.for_each_modifier(f),
+            Inst::FmaRndFtzSatF32(value) => value.for_each_modifier(f),
+            Inst::FmaRndFtzF32x2(value) => value.for_each_modifier(f),
+            Inst::FmaRndF64(value) => value.for_each_modifier(f),
+            Inst::FmaRndFtzSatF16(value) => value.for_each_modifier(f),
+            Inst::FmaRndFtzSatF16x2(value) => value.for_each_modifier(f),
+            Inst::FmaRndFtzReluF16(value) => value.for_each_modifier(f),
+            Inst::FmaRndFtzReluF16x2(value) => value.for_each_modifier(f),
+            Inst::FmaRndReluBf16(value) => value.for_each_modifier(f),
+            Inst::FmaRndReluBf16x2(value) => value.for_each_modifier(f),
+            Inst::FmaRndOobReluType(value) => value.for_each_modifier(f),
+            Inst::FmaRndSatF32Abtype(value) => value.for_each_modifier(f),
+            Inst::FnsB32(value) => value.for_each_modifier(f),
+            Inst::GetctarankSpaceType(value) => value.for_each_modifier(f),
+            Inst::GetctarankSharedClusterType(value) => value.for_each_modifier(f),
+            Inst::GetctarankType(value) => value.for_each_modifier(f),
+            Inst::GriddepcontrolAction(value) => value.for_each_modifier(f),
+            Inst::IsspacepSpace(value) => value.for_each_modifier(f),
+            Inst::IstypepType(value) => value.for_each_modifier(f),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType(value) => value.for_each_modifier(f),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_modifier(f),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType(value) => value.for_each_modifier(f),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_modifier(f),
+            Inst::LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_modifier(f),
+            Inst::LdWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_modifier(f),
+            Inst::LdVolatileSsLevelPrefetchSizeVecType(value) => value.for_each_modifier(f),
+            Inst::LdRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_modifier(f),
+            Inst::LdAcquireScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_modifier(f),
+            Inst::LdMmioRelaxedSysGlobalType(value) => value.for_each_modifier(f),
+            Inst::LdmatrixSyncAlignedShapeNumTransSsType(value) => value.for_each_modifier(f),
+            Inst::LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt(value) => value.for_each_modifier(f),
+            Inst::LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt(value) => value.for_each_modifier(f),
+            Inst::LduSsType(value) => value.for_each_modifier(f),
+            Inst::LduSsVecType(value) => value.for_each_modifier(f),
+            Inst::Lg2ApproxFtzF32(value) => value.for_each_modifier(f),
+            Inst::Lop3B32(value) => value.for_each_modifier(f),
+            Inst::Lop3BoolopB32(value) => value.for_each_modifier(f),
+            Inst::MadHiloCcType(value) => value.for_each_modifier(f),
+            Inst::MadModeType(value) => value.for_each_modifier(f),
+            Inst::MadHiSatS32(value) => value.for_each_modifier(f),
+            Inst::MadFtzSatF32(value) => value.for_each_modifier(f),
+            Inst::MadRndFtzSatF32(value) => value.for_each_modifier(f),
+            Inst::MadRndF64(value) => value.for_each_modifier(f),
+            Inst::Mad24ModeType(value) => value.for_each_modifier(f),
+            Inst::Mad24HiSatS32(value) => value.for_each_modifier(f),
+            Inst::MadcHiloCcType(value) => value.for_each_modifier(f),
+            Inst::MapaSpaceType(value) => value.for_each_modifier(f),
+            Inst::MatchAnySyncType(value) => value.for_each_modifier(f),
+            Inst::MatchAllSyncType(value) => value.for_each_modifier(f),
+            Inst::MaxAtype(value) => value.for_each_modifier(f),
+            Inst::MaxReluBtype(value) => value.for_each_modifier(f),
+            Inst::MaxFtzNanXorsignAbsF32(value) => value.for_each_modifier(f),
+            Inst::MaxFtzNanAbsF32(value) => value.for_each_modifier(f),
+            Inst::MaxF64(value) => value.for_each_modifier(f),
+            Inst::MaxFtzNanXorsignAbsF16(value) => value.for_each_modifier(f),
+            Inst::MaxFtzNanXorsignAbsF16x2(value) => value.for_each_modifier(f),
+            Inst::MaxNanXorsignAbsBf16(value) => value.for_each_modifier(f),
+            Inst::MaxNanXorsignAbsBf16x2(value) => value.for_each_modifier(f),
+            Inst::MbarrierArriveSemScopeStateB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierArriveSemScopeSharedClusterB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierArriveExpectTxSemScopeStateB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierArriveExpectTxSemScopeSharedClusterB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierArriveNocompleteReleaseCtaStateB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierArriveDropSemScopeStateB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierArriveDropSemScopeSharedClusterB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierArriveDropExpectTxStateSemScopeB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierArriveDropExpectTxSharedClusterSemScopeB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierArriveDropNocompleteReleaseCtaStateB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierCompleteTxSemScopeSpaceB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierExpectTxSemScopeSpaceB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierInitStateB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierInvalStateB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierPendingCountB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierTestWaitSemScopeStateB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierTestWaitParitySemScopeStateB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierTryWaitSemScopeStateB64(value) => value.for_each_modifier(f),
+            Inst::MbarrierTryWaitParitySemScopeStateB64(value) => value.for_each_modifier(f),
+            Inst::FenceSemScope(value) => value.for_each_modifier(f),
+            Inst::FenceAcquireSyncRestrictSharedClusterCluster(value) => value.for_each_modifier(f),
+            Inst::FenceReleaseSyncRestrictSharedCtaCluster(value) => value.for_each_modifier(f),
+            Inst::FenceOpRestrictReleaseCluster(value) => value.for_each_modifier(f),
+            Inst::FenceProxyProxykind(value) => value.for_each_modifier(f),
+            Inst::FenceProxyToProxykindFromProxykindReleaseScope(value) => value.for_each_modifier(f),
+            Inst::FenceProxyToProxykindFromProxykindAcquireScope(value) => value.for_each_modifier(f),
+            Inst::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster(value) => value.for_each_modifier(f),
+            Inst::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster(value) => value.for_each_modifier(f),
+            Inst::MembarLevel(value) => value.for_each_modifier(f),
+            Inst::MembarProxyProxykind(value) => value.for_each_modifier(f),
+            Inst::MinAtype(value) => value.for_each_modifier(f),
+            Inst::MinReluBtype(value) => value.for_each_modifier(f),
+            Inst::MinFtzNanXorsignAbsF32(value) => value.for_each_modifier(f),
+            Inst::MinFtzNanAbsF32(value) => value.for_each_modifier(f),
+            Inst::MinF64(value) => value.for_each_modifier(f),
+            Inst::MinFtzNanXorsignAbsF16(value) => value.for_each_modifier(f),
+            Inst::MinFtzNanXorsignAbsF16x2(value) => value.for_each_modifier(f),
+            Inst::MinNanXorsignAbsBf16(value) => value.for_each_modifier(f),
+            Inst::MinNanXorsignAbsBf16x2(value) => value.for_each_modifier(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.for_each_modifier(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype(value) => value.for_each_modifier(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.for_each_modifier(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32(value) => value.for_each_modifier(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32(value) => value.for_each_modifier(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32(value) => value.for_each_modifier(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32(value) => value.for_each_modifier(f),
+            Inst::MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.for_each_modifier(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.for_each_modifier(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.for_each_modifier(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.for_each_modifier(f),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.for_each_modifier(f),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedM8n8k4AlayoutBlayoutDtypeF16F16Ctype(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedM16n8k8RowColDtypeF16F16Ctype(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedM16n8k4RowColF32Tf32Tf32F32(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedM16n8k8RowColF32AtypeBtypeF32(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedShapeRowColDtypeF8typeF8typeCtype(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedM16n8k32RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedM16n8k32RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedShapeRowColF64F64F64F64(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.for_each_modifier(f),
+            Inst::MmaSyncAlignedShapeRowColS32B1B1S32BitopPopc(value) => value.for_each_modifier(f),
+            Inst::MovType(value) => value.for_each_modifier(f),
+            Inst::MovU32(value) => value.for_each_modifier(f),
+            Inst::MovU64(value) => value.for_each_modifier(f),
+            Inst::MovU321(value) => value.for_each_modifier(f),
+            Inst::MovU641(value) => value.for_each_modifier(f),
+            Inst::MovType1(value) => value.for_each_modifier(f),
+            Inst::MovmatrixSyncAlignedShapeTransType(value) => value.for_each_modifier(f),
+            Inst::MulModeType(value) => value.for_each_modifier(f),
+            Inst::MulRndFtzSatF32(value) => value.for_each_modifier(f),
+            Inst::MulRndFtzF32x2(value) => value.for_each_modifier(f),
+            Inst::MulRndF64(value) => value.for_each_modifier(f),
+            Inst::MulRndFtzSatF16(value) => value.for_each_modifier(f),
+            Inst::MulRndFtzSatF16x2(value) => value.for_each_modifier(f),
+            Inst::MulRndBf16(value) => value.for_each_modifier(f),
+            Inst::MulRndBf16x2(value) => value.for_each_modifier(f),
+            Inst::Mul24ModeType(value) => value.for_each_modifier(f),
+            Inst::MultimemLdReduceLdsemScopeSsOpType(value) => value.for_each_modifier(f),
+            Inst::MultimemLdReduceWeakSsOpType(value) => value.for_each_modifier(f),
+            Inst::MultimemStStsemScopeSsType(value) => value.for_each_modifier(f),
+            Inst::MultimemStWeakSsType(value) => value.for_each_modifier(f),
+            Inst::MultimemRedRedsemScopeSsOpType(value) => value.for_each_modifier(f),
+            Inst::MultimemLdReduceLdsemScopeSsOpAccPrecVecType(value) => value.for_each_modifier(f),
+            Inst::MultimemLdReduceWeakSsOpAccPrecVecType(value) => value.for_each_modifier(f),
+            Inst::MultimemStStsemScopeSsVecType(value) => value.for_each_modifier(f),
+            Inst::MultimemStWeakSsVecType(value) => value.for_each_modifier(f),
+            Inst::MultimemRedRedsemScopeSsRedopVecRedtype(value) => value.for_each_modifier(f),
+            Inst::NanosleepU32(value) => value.for_each_modifier(f),
+            Inst::NegType(value) => value.for_each_modifier(f),
+            Inst::NegFtzF32(value) => value.for_each_modifier(f),
+            Inst::NegF64(value) => value.for_each_modifier(f),
+            Inst::NegFtzF16(value) => value.for_each_modifier(f),
+            Inst::NegFtzF16x2(value) => value.for_each_modifier(f),
+            Inst::NegBf16(value) => value.for_each_modifier(f),
+            Inst::NegBf16x2(value) => value.for_each_modifier(f),
+            Inst::NotType(value) => value.for_each_modifier(f),
+            Inst::OrType(value) => value.for_each_modifier(f),
+            Inst::Pmevent(value) => value.for_each_modifier(f),
+            Inst::PmeventMask(value) => value.for_each_modifier(f),
+            Inst::PopcType(value) => value.for_each_modifier(f),
+            Inst::PrefetchSpaceLevel(value) => value.for_each_modifier(f),
+            Inst::PrefetchGlobalLevelEvictionPriority(value) => value.for_each_modifier(f),
+            Inst::PrefetchuL1(value) => value.for_each_modifier(f),
+            Inst::PrefetchTensormapSpaceTensormap(value) => value.for_each_modifier(f),
+            Inst::PrmtB32Mode(value) => value.for_each_modifier(f),
+            Inst::RcpApproxFtzF64(value) => value.for_each_modifier(f),
+            Inst::RcpApproxFtzF32(value) => value.for_each_modifier(f),
+            Inst::RcpRndFtzF32(value) => value.for_each_modifier(f),
+            Inst::RcpRndF64(value) => value.for_each_modifier(f),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType(value) => value.for_each_modifier(f),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType1(value) => value.for_each_modifier(f),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType2(value) => value.for_each_modifier(f),
+            Inst::RedAsyncSemScopeSsCompletionMechanismAddType(value) => value.for_each_modifier(f),
+            Inst::RedAsyncMmioSemScopeSsAddType(value) => value.for_each_modifier(f),
+            Inst::RedOpSpaceSemScopeLevelCacheHintType(value) => value.for_each_modifier(f),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16(value) => value.for_each_modifier(f),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16x2(value) => value.for_each_modifier(f),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16(value) => value.for_each_modifier(f),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16x2(value) => value.for_each_modifier(f),
+            Inst::RedAddSpaceSemScopeLevelCacheHintVec32BitF32(value) => value.for_each_modifier(f),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec16BitHalfWordType(value) => value.for_each_modifier(f),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec32BitPackedType(value) => value.for_each_modifier(f),
+            Inst::ReduxSyncOpType(value) => value.for_each_modifier(f),
+            Inst::ReduxSyncOpB32(value) => value.for_each_modifier(f),
+            Inst::ReduxSyncOpAbsNanF32(value) => value.for_each_modifier(f),
+            Inst::RemType(value) => value.for_each_modifier(f),
+            Inst::RetUni(value) => value.for_each_modifier(f),
+            Inst::RsqrtApproxFtzF64(value) => value.for_each_modifier(f),
+            Inst::RsqrtApproxFtzF32(value) => value.for_each_modifier(f),
+            Inst::RsqrtApproxF64(value) => value.for_each_modifier(f),
+            Inst::SadType(value) => value.for_each_modifier(f),
+            Inst::SelpType(value) => value.for_each_modifier(f),
+            Inst::SetCmpopFtzDtypeStype(value) => value.for_each_modifier(f),
+            Inst::SetCmpopBoolopFtzDtypeStype(value) => value.for_each_modifier(f),
+            Inst::SetCmpopFtzF16Stype(value) => value.for_each_modifier(f),
+            Inst::SetCmpopBoolopFtzF16Stype(value) => value.for_each_modifier(f),
+            Inst::SetCmpopBf16Stype(value) => value.for_each_modifier(f),
+            Inst::SetCmpopBoolopBf16Stype(value) => value.for_each_modifier(f),
+            Inst::SetCmpopFtzDtypeF16(value) => value.for_each_modifier(f),
+            Inst::SetCmpopBoolopFtzDtypeF16(value) => value.for_each_modifier(f),
+            Inst::SetCmpopDtypeBf16(value) => value.for_each_modifier(f),
+            Inst::SetCmpopBoolopDtypeBf16(value) => value.for_each_modifier(f),
+            Inst::SetCmpopFtzDtypeF16x2(value) => value.for_each_modifier(f),
+            Inst::SetCmpopBoolopFtzDtypeF16x2(value) => value.for_each_modifier(f),
+            Inst::SetCmpopDtypeBf16x2(value) => value.for_each_modifier(f),
+            Inst::SetCmpopBoolopDtypeBf16x2(value) => value.for_each_modifier(f),
+            Inst::SetmaxnregActionSyncAlignedU32(value) => value.for_each_modifier(f),
+            Inst::SetpCmpopFtzType(value) => value.for_each_modifier(f),
+            Inst::SetpCmpopBoolopFtzType(value) => value.for_each_modifier(f),
+            Inst::SetpCmpopFtzF16(value) => value.for_each_modifier(f),
+            Inst::SetpCmpopBoolopFtzF16(value) => value.for_each_modifier(f),
+            Inst::SetpCmpopFtzF16x2(value) => value.for_each_modifier(f),
+            Inst::SetpCmpopBoolopFtzF16x2(value) => value.for_each_modifier(f),
+            Inst::SetpCmpopBf16(value) => value.for_each_modifier(f),
+            Inst::SetpCmpopBoolopBf16(value) => value.for_each_modifier(f),
+            Inst::SetpCmpopBf16x2(value) => value.for_each_modifier(f),
+            Inst::SetpCmpopBoolopBf16x2(value) => value.for_each_modifier(f),
+            Inst::ShfLModeB32(value) => value.for_each_modifier(f),
+            Inst::ShfRModeB32(value) => value.for_each_modifier(f),
+            Inst::ShflSyncModeB32(value) => value.for_each_modifier(f),
+            Inst::ShflModeB32(value) => value.for_each_modifier(f),
+            Inst::ShlType(value) => value.for_each_modifier(f),
+            Inst::ShrType(value) => value.for_each_modifier(f),
+            Inst::SinApproxFtzF32(value) => value.for_each_modifier(f),
+            Inst::SlctDtypeS32(value) => value.for_each_modifier(f),
+            Inst::SlctFtzDtypeF32(value) => value.for_each_modifier(f),
+            Inst::SqrtApproxFtzF32(value) => value.for_each_modifier(f),
+            Inst::SqrtRndFtzF32(value) => value.for_each_modifier(f),
+            Inst::SqrtRndF64(value) => value.for_each_modifier(f),
+            Inst::StAsyncSemScopeSsCompletionMechanismVecType(value) => value.for_each_modifier(f),
+            Inst::StAsyncMmioSemScopeSsType(value) => value.for_each_modifier(f),
+            Inst::StBulkWeakSharedCta(value) => value.for_each_modifier(f),
+            Inst::StWeakSsCopLevelCacheHintVecType(value) => value.for_each_modifier(f),
+            Inst::StWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.for_each_modifier(f),
+            Inst::StVolatileSsVecType(value) => value.for_each_modifier(f),
+            Inst::StRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.for_each_modifier(f),
+            Inst::StReleaseScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.for_each_modifier(f),
+            Inst::StMmioRelaxedSysGlobalType(value) => value.for_each_modifier(f),
+            Inst::StackrestoreType(value) => value.for_each_modifier(f),
+            Inst::StacksaveType(value) => value.for_each_modifier(f),
+            Inst::StmatrixSyncAlignedShapeNumTransSsType(value) => value.for_each_modifier(f),
+            Inst::SubCcType(value) => value.for_each_modifier(f),
+            Inst::SubType(value) => value.for_each_modifier(f),
+            Inst::SubSatS32(value) => value.for_each_modifier(f),
+            Inst::SubRndFtzSatF32(value) => value.for_each_modifier(f),
+            Inst::SubRndFtzF32x2(value) => value.for_each_modifier(f),
+            Inst::SubRndF64(value) => value.for_each_modifier(f),
+            Inst::SubRndFtzSatF16(value) => value.for_each_modifier(f),
+            Inst::SubRndFtzSatF16x2(value) => value.for_each_modifier(f),
+            Inst::SubRndBf16(value) => value.for_each_modifier(f),
+            Inst::SubRndBf16x2(value) => value.for_each_modifier(f),
+            Inst::SubRndSatF32Atype(value) => value.for_each_modifier(f),
+            Inst::SubcCcType(value) => value.for_each_modifier(f),
+            Inst::SuldBGeomCopVecDtypeMode(value) => value.for_each_modifier(f),
+            Inst::SuqQueryB32(value) => value.for_each_modifier(f),
+            Inst::SuredBOpGeomCtypeMode(value) => value.for_each_modifier(f),
+            Inst::SuredPOpGeomCtypeMode(value) => value.for_each_modifier(f),
+            Inst::SustBDimCopVecCtypeMode(value) => value.for_each_modifier(f),
+            Inst::SustPDimVecB32Mode(value) => value.for_each_modifier(f),
+            Inst::SustBAdimCopVecCtypeMode(value) => value.for_each_modifier(f),
+            Inst::SzextModeType(value) => value.for_each_modifier(f),
+            Inst::TanhApproxType(value) => value.for_each_modifier(f),
+            Inst::Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32(value) => value.for_each_modifier(f),
+            Inst::Tcgen05DeallocCtaGroupSyncAlignedB32(value) => value.for_each_modifier(f),
+            Inst::Tcgen05RelinquishAllocPermitCtaGroupSyncAligned(value) => value.for_each_modifier(f),
+            Inst::Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64(value) => value.for_each_modifier(f),
+            Inst::Tcgen05CpCtaGroupShapeMulticastDstSrcFmt(value) => value.for_each_modifier(f),
+            Inst::Tcgen05FenceBeforeThreadSync(value) => value.for_each_modifier(f),
+            Inst::Tcgen05FenceAfterThreadSync(value) => value.for_each_modifier(f),
+            Inst::Tcgen05LdSyncAlignedShape1NumPackB32(value) => value.for_each_modifier(f),
+            Inst::Tcgen05LdSyncAlignedShape2NumPackB32(value) => value.for_each_modifier(f),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32(value) => value.for_each_modifier(f),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32(value) => value.for_each_modifier(f),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopType(value) => value.for_each_modifier(f),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopType(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKind(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKind1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKindCollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI8(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI81(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI8CollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKind(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKind1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKindCollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKindI8(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKindI81(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKindI8CollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage(value) => value.for_each_modifier(f),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1(value) => value.for_each_modifier(f),
+            Inst::Tcgen05ShiftCtaGroupDown(value) => value.for_each_modifier(f),
+            Inst::Tcgen05StSyncAlignedShape1NumUnpackB32(value) => value.for_each_modifier(f),
+            Inst::Tcgen05StSyncAlignedShape2NumUnpackB32(value) => value.for_each_modifier(f),
+            Inst::Tcgen05WaitOperationSyncAligned(value) => value.for_each_modifier(f),
+            Inst::TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned(value) => value.for_each_modifier(f),
+            Inst::TensormapReplaceModeField1SsB1024Type(value) => value.for_each_modifier(f),
+            Inst::TensormapReplaceModeField2SsB1024Type(value) => value.for_each_modifier(f),
+            Inst::TensormapReplaceModeField3SsB1024Type(value) => value.for_each_modifier(f),
+            Inst::TestpOpType(value) => value.for_each_modifier(f),
+            Inst::TexGeomV4DtypeCtype(value) => value.for_each_modifier(f),
+            Inst::TexGeomV4DtypeCtype1(value) => value.for_each_modifier(f),
+            Inst::TexGeomV2F16x2Ctype(value) => value.for_each_modifier(f),
+            Inst::TexGeomV2F16x2Ctype1(value) => value.for_each_modifier(f),
+            Inst::TexBaseGeomV4DtypeCtype(value) => value.for_each_modifier(f),
+            Inst::TexLevelGeomV4DtypeCtype(value) => value.for_each_modifier(f),
+            Inst::TexGradGeomV4DtypeCtype(value) => value.for_each_modifier(f),
+            Inst::TexBaseGeomV2F16x2Ctype(value) => value.for_each_modifier(f),
+            Inst::TexLevelGeomV2F16x2Ctype(value) => value.for_each_modifier(f),
+            Inst::TexGradGeomV2F16x2Ctype(value) => value.for_each_modifier(f),
+            Inst::Tld4Comp2dV4DtypeF32(value) => value.for_each_modifier(f),
+            Inst::Tld4CompGeomV4DtypeF32(value) => value.for_each_modifier(f),
+            Inst::Trap(value) => value.for_each_modifier(f),
+            Inst::TxqTqueryB32(value) => value.for_each_modifier(f),
+            Inst::TxqLevelTlqueryB32(value) => value.for_each_modifier(f),
+            Inst::TxqSqueryB32(value) => value.for_each_modifier(f),
+            Inst::VmadDtypeAtypeBtypeSatScale(value) => value.for_each_modifier(f),
+            Inst::VmadDtypeAtypeBtypePoSatScale(value) => value.for_each_modifier(f),
+            Inst::VaddDtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::VsubDtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::VabsdiffDtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::VminDtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::VmaxDtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::VaddDtypeAtypeBtypeSatOp2(value) => value.for_each_modifier(f),
+            Inst::VsubDtypeAtypeBtypeSatOp2(value) => value.for_each_modifier(f),
+            Inst::VabsdiffDtypeAtypeBtypeSatOp2(value) => value.for_each_modifier(f),
+            Inst::VminDtypeAtypeBtypeSatOp2(value) => value.for_each_modifier(f),
+            Inst::VmaxDtypeAtypeBtypeSatOp2(value) => value.for_each_modifier(f),
+            Inst::VaddDtypeAtypeBtypeSat1(value) => value.for_each_modifier(f),
+            Inst::VsubDtypeAtypeBtypeSat1(value) => value.for_each_modifier(f),
+            Inst::VabsdiffDtypeAtypeBtypeSat1(value) => value.for_each_modifier(f),
+            Inst::VminDtypeAtypeBtypeSat1(value) => value.for_each_modifier(f),
+            Inst::VmaxDtypeAtypeBtypeSat1(value) => value.for_each_modifier(f),
+            Inst::Vadd2DtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::Vsub2DtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::Vavrg2DtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::Vabsdiff2DtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::Vmin2DtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::Vmax2DtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::Vadd2DtypeAtypeBtypeAdd(value) => value.for_each_modifier(f),
+            Inst::Vsub2DtypeAtypeBtypeAdd(value) => value.for_each_modifier(f),
+            Inst::Vavrg2DtypeAtypeBtypeAdd(value) => value.for_each_modifier(f),
+            Inst::Vabsdiff2DtypeAtypeBtypeAdd(value) => value.for_each_modifier(f),
+            Inst::Vmin2DtypeAtypeBtypeAdd(value) => value.for_each_modifier(f),
+            Inst::Vmax2DtypeAtypeBtypeAdd(value) => value.for_each_modifier(f),
+            Inst::Vadd4DtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::Vsub4DtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::Vavrg4DtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::Vabsdiff4DtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::Vmin4DtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::Vmax4DtypeAtypeBtypeSat(value) => value.for_each_modifier(f),
+            Inst::Vadd4DtypeAtypeBtypeAdd(value) => value.for_each_modifier(f),
+            Inst::Vsub4DtypeAtypeBtypeAdd(value) => value.for_each_modifier(f),
+            Inst::Vavrg4DtypeAtypeBtypeAdd(value) => value.for_each_modifier(f),
+            Inst::Vabsdiff4DtypeAtypeBtypeAdd(value) => value.for_each_modifier(f),
+            Inst::Vmin4DtypeAtypeBtypeAdd(value) => value.for_each_modifier(f),
+            Inst::Vmax4DtypeAtypeBtypeAdd(value) => value.for_each_modifier(f),
+            Inst::VoteSyncModePred(value) => value.for_each_modifier(f),
+            Inst::VoteSyncBallotB32(value) => value.for_each_modifier(f),
+            Inst::VoteModePred(value) => value.for_each_modifier(f),
+            Inst::VoteBallotB32(value) => value.for_each_modifier(f),
+            Inst::VsetAtypeBtypeCmp(value) => value.for_each_modifier(f),
+            Inst::VsetAtypeBtypeCmpOp2(value) => value.for_each_modifier(f),
+            Inst::VsetAtypeBtypeCmp1(value) => value.for_each_modifier(f),
+            Inst::Vset2AtypeBtypeCmp(value) => value.for_each_modifier(f),
+            Inst::Vset2AtypeBtypeCmpAdd(value) => value.for_each_modifier(f),
+            Inst::Vset4AtypeBtypeCmp(value) => value.for_each_modifier(f),
+            Inst::Vset4AtypeBtypeCmpAdd(value) => value.for_each_modifier(f),
+            Inst::VshlDtypeAtypeU32SatMode(value) => value.for_each_modifier(f),
+            Inst::VshrDtypeAtypeU32SatMode(value) => value.for_each_modifier(f),
+            Inst::VshlDtypeAtypeU32SatModeOp2(value) => value.for_each_modifier(f),
+            Inst::VshrDtypeAtypeU32SatModeOp2(value) => value.for_each_modifier(f),
+            Inst::VshlDtypeAtypeU32SatMode1(value) => value.for_each_modifier(f),
+            Inst::VshrDtypeAtypeU32SatMode1(value) => value.for_each_modifier(f),
+            Inst::WgmmaCommitGroupSyncAligned(value) => value.for_each_modifier(f),
+            Inst::WgmmaFenceSyncAligned(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc(value) => value.for_each_modifier(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1(value) => value.for_each_modifier(f),
+            Inst::WgmmaWaitGroupSyncAligned(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype1(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype1(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype1(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype2(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype2(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype2(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype3(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype3(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype3(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype4(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype1(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype1(value) => value.for_each_modifier(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype5(value) => value.for_each_modifier(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeDtypeCtype(value) => value.for_each_modifier(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeS32AtypeBtypeS32Satfinite(value) => value.for_each_modifier(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF32(value) => value.for_each_modifier(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF321(value) => value.for_each_modifier(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeRndF64F64F64F64(value) => value.for_each_modifier(f),
+            Inst::WmmaMmaSyncAlignedRowColShapeS32AtypeBtypeS32Satfinite(value) => value.for_each_modifier(f),
+            Inst::WmmaMmaOpPopcSyncAlignedRowColShapeS32AtypeBtypeS32(value) => value.for_each_modifier(f),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType(value) => value.for_each_modifier(f),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType1(value) => value.for_each_modifier(f),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType2(value) => value.for_each_modifier(f),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType3(value) => value.for_each_modifier(f),
+            Inst::XorType(value) => value.for_each_modifier(f),
+        }
+    }
+    fn spec_section(&self) -> SpecSection {
+        match self {
+            Inst::AbsType(value) => value.spec_section(),
+            Inst::AbsFtzF32(value) => value.spec_section(),
+            Inst::AbsF64(value) => value.spec_section(),
+            Inst::AbsFtzF16(value) => value.spec_section(),
+            Inst::AbsFtzF16x2(value) => value.spec_section(),
+            Inst::AbsBf16(value) => value.spec_section(),
+            Inst::AbsBf16x2(value) => value.spec_section(),
+            Inst::ActivemaskB32(value) => value.spec_section(),
+            Inst::AddCcType(value) => value.spec_section(),
+            Inst::AddType(value) => value.spec_section(),
+            Inst::AddSatS32(value) => value.spec_section(),
+            Inst::AddRndFtzSatF32(value) => value.spec_section(),
+            Inst::AddRndFtzF32x2(value) => value.spec_section(),
+            Inst::AddRndF64(value) => value.spec_section(),
+            Inst::AddRndFtzSatF16(value) => value.spec_section(),
+            Inst::AddRndFtzSatF16x2(value) => value.spec_section(),
+            Inst::AddRndBf16(value) => value.spec_section(),
+            Inst::AddRndBf16x2(value) => value.spec_section(),
+            Inst::AddRndSatF32Atype(value) => value.spec_section(),
+            Inst::AddcCcType(value) => value.spec_section(),
+            Inst::AllocaType(value) => value.spec_section(),
+            Inst::AndType(value) => value.spec_section(),
+            Inst::ApplypriorityGlobalLevelEvictionPriority(value) => value.spec_section(),
+            Inst::AtomSemScopeSpaceOpLevelCacheHintType(value) => value.spec_section(),
+            Inst::AtomSemScopeSpaceOpType(value) => value.spec_section(),
+            Inst::AtomSemScopeSpaceCasB16(value) => value.spec_section(),
+            Inst::AtomSemScopeSpaceCasB128(value) => value.spec_section(),
+            Inst::AtomSemScopeSpaceExchLevelCacheHintB128(value) => value.spec_section(),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16(value) => value.spec_section(),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2(value) => value.spec_section(),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16(value) => value.spec_section(),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2(value) => value.spec_section(),
+            Inst::AtomSemScopeGlobalAddLevelCacheHintVec32BitF32(value) => value.spec_section(),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType(value) => value.spec_section(),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType(value) => value.spec_section(),
+            Inst::BarrierCtaSyncAligned(value) => value.spec_section(),
+            Inst::BarrierCtaArriveAligned(value) => value.spec_section(),
+            Inst::BarrierCtaRedPopcAlignedU32(value) => value.spec_section(),
+            Inst::BarrierCtaRedOpAlignedPred(value) => value.spec_section(),
+            Inst::BarCtaSync(value) => value.spec_section(),
+            Inst::BarCtaArrive(value) => value.spec_section(),
+            Inst::BarCtaRedPopcU32(value) => value.spec_section(),
+            Inst::BarCtaRedOpPred(value) => value.spec_section(),
+            Inst::BarWarpSync(value) => value.spec_section(),
+            Inst::BarrierClusterArriveSemAligned(value) => value.spec_section(),
+            Inst::BarrierClusterWaitAcquireAligned(value) => value.spec_section(),
+            Inst::BfeType(value) => value.spec_section(),
+            Inst::BfiType(value) => value.spec_section(),
+            Inst::BfindType(value) => value.spec_section(),
+            Inst::BfindShiftamtType(value) => value.spec_section(),
+            Inst::BmskModeB32(value) => value.spec_section(),
+            Inst::BraUni(value) => value.spec_section(),
+            Inst::BraUni1(value) => value.spec_section(),
+            Inst::BrevType(value) => value.spec_section(),
+            Inst::Brkpt(value) => value.spec_section(),
+            Inst::BrxIdxUni(value) => value.spec_section(),
+            Inst::BrxIdxUni1(value) => value.spec_section(),
+            Inst::CallUni(value) => value.spec_section(),
+            Inst::CallUni1(value) => value.spec_section(),
+            Inst::CallUni2(value) => value.spec_section(),
+            Inst::CallUni3(value) => value.spec_section(),
+            Inst::CallUni4(value) => value.spec_section(),
+            Inst::CallUni5(value) => value.spec_section(),
+            Inst::CallUni6(value) => value.spec_section(),
+            Inst::CallUni7(value) => value.spec_section(),
+            Inst::CallUni8(value) => value.spec_section(),
+            Inst::ClusterlaunchcontrolQueryCancelIsCanceledPredB128(value) => value.spec_section(),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128(value) => value.spec_section(),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128(value) => value.spec_section(),
+            Inst::ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128(value) => value.spec_section(),
+            Inst::ClzType(value) => value.spec_section(),
+            Inst::CnotType(value) => value.spec_section(),
+            Inst::CopysignType(value) => value.spec_section(),
+            Inst::CosApproxFtzF32(value) => value.spec_section(),
+            Inst::CpAsyncBulkCommitGroup(value) => value.spec_section(),
+            Inst::CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint(value) => value.spec_section(),
+            Inst::CpAsyncBulkPrefetchL2SrcLevelCacheHint(value) => value.spec_section(),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint(value) => value.spec_section(),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint(value) => value.spec_section(),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint(value) => value.spec_section(),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint(value) => value.spec_section(),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint(value) => value.spec_section(),
+            Inst::CpAsyncBulkDstSrcCompletionMechanism(value) => value.spec_section(),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask(value) => value.spec_section(),
+            Inst::CpAsyncBulkWaitGroupRead(value) => value.spec_section(),
+            Inst::CpAsyncCommitGroup(value) => value.spec_section(),
+            Inst::CpAsyncMbarrierArriveNoincStateB64(value) => value.spec_section(),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.spec_section(),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.spec_section(),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.spec_section(),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.spec_section(),
+            Inst::CpAsyncWaitGroup(value) => value.spec_section(),
+            Inst::CpAsyncWaitAll(value) => value.spec_section(),
+            Inst::CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint(value) => value.spec_section(),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismRedopType(value) => value.spec_section(),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType(value) => value.spec_section(),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType(value) => value.spec_section(),
+            Inst::CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.spec_section(),
+            Inst::CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.spec_section(),
+            Inst::CreatepolicyCvtL2B64(value) => value.spec_section(),
+            Inst::CvtPackSatConverttypeAbtype(value) => value.spec_section(),
+            Inst::CvtPackSatConverttypeAbtypeCtype(value) => value.spec_section(),
+            Inst::CvtIrndFtzSatDtypeAtype(value) => value.spec_section(),
+            Inst::CvtFrndFtzSatDtypeAtype(value) => value.spec_section(),
+            Inst::CvtFrnd2ReluSatfiniteF16F32(value) => value.spec_section(),
+            Inst::CvtFrnd2ReluSatfiniteF16x2F32(value) => value.spec_section(),
+            Inst::CvtRsReluSatfiniteF16x2F32(value) => value.spec_section(),
+            Inst::CvtFrnd2ReluSatfiniteBf16F32(value) => value.spec_section(),
+            Inst::CvtFrnd2ReluSatfiniteBf16x2F32(value) => value.spec_section(),
+            Inst::CvtRsReluSatfiniteBf16x2F32(value) => value.spec_section(),
+            Inst::CvtRnaSatfiniteTf32F32(value) => value.spec_section(),
+            Inst::CvtFrnd2SatfiniteReluTf32F32(value) => value.spec_section(),
+            Inst::CvtRnSatfiniteReluF8x2typeF32(value) => value.spec_section(),
+            Inst::CvtRnSatfiniteReluF8x2typeF16x2(value) => value.spec_section(),
+            Inst::CvtRnReluF16x2F8x2type(value) => value.spec_section(),
+            Inst::CvtRsReluSatfiniteF8x4typeF32(value) => value.spec_section(),
+            Inst::CvtRnSatfiniteReluF4x2typeF32(value) => value.spec_section(),
+            Inst::CvtRnReluF16x2F4x2type(value) => value.spec_section(),
+            Inst::CvtRsReluSatfiniteF4x4typeF32(value) => value.spec_section(),
+            Inst::CvtRnSatfiniteReluF6x2typeF32(value) => value.spec_section(),
+            Inst::CvtRnReluF16x2F6x2type(value) => value.spec_section(),
+            Inst::CvtRsReluSatfiniteF6x4typeF32(value) => value.spec_section(),
+            Inst::CvtFrnd3SatfiniteUe8m0x2F32(value) => value.spec_section(),
+            Inst::CvtFrnd3SatfiniteUe8m0x2Bf16x2(value) => value.spec_section(),
+            Inst::CvtRnBf16x2Ue8m0x2(value) => value.spec_section(),
+            Inst::CvtaSpaceSize(value) => value.spec_section(),
+            Inst::CvtaToSpaceSize(value) => value.spec_section(),
+            Inst::DiscardGlobalLevel(value) => value.spec_section(),
+            Inst::DivType(value) => value.spec_section(),
+            Inst::DivApproxFtzF32(value) => value.spec_section(),
+            Inst::DivFullFtzF32(value) => value.spec_section(),
+            Inst::DivRndFtzF32(value) => value.spec_section(),
+            Inst::DivRndF64(value) => value.spec_section(),
+            Inst::Dp2aModeAtypeBtype(value) => value.spec_section(),
+            Inst::Dp4aAtypeBtype(value) => value.spec_section(),
+            Inst::ElectSync(value) => value.spec_section(),
+            Inst::Ex2ApproxFtzF32(value) => value.spec_section(),
+            Inst::Ex2ApproxAtype(value) => value.spec_section(),
+            Inst::Ex2ApproxFtzBtype(value) => value.spec_section(),
+            Inst::Exit(value) => value.spec_section(),
+            Inst::FmaRndFtzSatF32(value) => value.spec_section(),
+            Inst::FmaRndFtzF32x2(value) => value.spec_section(),
+            Inst::FmaRndF64(value) => value.spec_section(),
+            Inst::FmaRndFtzSatF16(value) => value.spec_section(),
+            Inst::FmaRndFtzSatF16x2(value) => value.spec_section(),
+            Inst::FmaRndFtzReluF16(value) => value.spec_section(),
+            Inst::FmaRndFtzReluF16x2(value) => value.spec_section(),
+            Inst::FmaRndReluBf16(value) => value.spec_section(),
+            Inst::FmaRndReluBf16x2(value) => value.spec_section(),
+            Inst::FmaRndOobReluType(value) => value.spec_section(),
+            Inst::FmaRndSatF32Abtype(value) => value.spec_section(),
+            Inst::FnsB32(value) => value.spec_section(),
+            Inst::GetctarankSpaceType(value) => value.spec_section(),
+            Inst::GetctarankSharedClusterType(value) => value.spec_section(),
+            Inst::GetctarankType(value) => value.spec_section(),
+            Inst::GriddepcontrolAction(value) => value.spec_section(),
+            Inst::IsspacepSpace(value) => value.spec_section(),
+            Inst::IstypepType(value) => value.spec_section(),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType(value) => value.spec_section(),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType(value) => value.spec_section(),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType(value) => value.spec_section(),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.spec_section(),
+            Inst::LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType(value) => value.spec_section(),
+            Inst::LdWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.spec_section(),
+            Inst::LdVolatileSsLevelPrefetchSizeVecType(value) => value.spec_section(),
+            Inst::LdRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.spec_section(),
+            Inst::LdAcquireScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.spec_section(),
+            Inst::LdMmioRelaxedSysGlobalType(value) => value.spec_section(),
+            Inst::LdmatrixSyncAlignedShapeNumTransSsType(value) => value.spec_section(),
+            Inst::LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt(value) => value.spec_section(),
+            Inst::LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt(value) => value.spec_section(),
+            Inst::LduSsType(value) => value.spec_section(),
+            Inst::LduSsVecType(value) => value.spec_section(),
+            Inst::Lg2ApproxFtzF32(value) => value.spec_section(),
+            Inst::Lop3B32(value) => value.spec_section(),
+            Inst::Lop3BoolopB32(value) => value.spec_section(),
+            Inst::MadHiloCcType(value) => value.spec_section(),
+            Inst::MadModeType(value) => value.spec_section(),
+            Inst::MadHiSatS32(value) => value.spec_section(),
+            Inst::MadFtzSatF32(value) => value.spec_section(),
+            Inst::MadRndFtzSatF32(value) => value.spec_section(),
+            Inst::MadRndF64(value) => value.spec_section(),
+            Inst::Mad24ModeType(value) => value.spec_section(),
+            Inst::Mad24HiSatS32(value) => value.spec_section(),
+            Inst::MadcHiloCcType(value) => value.spec_section(),
+            Inst::MapaSpaceType(value) => value.spec_section(),
+            Inst::MatchAnySyncType(value) => value.spec_section(),
+            Inst::MatchAllSyncType(value) => value.spec_section(),
+            Inst::MaxAtype(value) => value.spec_section(),
+            Inst::MaxReluBtype(value) => value.spec_section(),
+            Inst::MaxFtzNanXorsignAbsF32(value) => value.spec_section(),
+            Inst::MaxFtzNanAbsF32(value) => value.spec_section(),
+            Inst::MaxF64(value) => value.spec_section(),
+            Inst::MaxFtzNanXorsignAbsF16(value) => value.spec_section(),
+            Inst::MaxFtzNanXorsignAbsF16x2(value) => value.spec_section(),
+            Inst::MaxNanXorsignAbsBf16(value) => value.spec_section(),
+            Inst::MaxNanXorsignAbsBf16x2(value) => value.spec_section(),
+            Inst::MbarrierArriveSemScopeStateB64(value) => value.spec_section(),
+            Inst::MbarrierArriveSemScopeSharedClusterB64(value) => value.spec_section(),
+            Inst::MbarrierArriveExpectTxSemScopeStateB64(value) => value.spec_section(),
+            Inst::MbarrierArriveExpectTxSemScopeSharedClusterB64(value) => value.spec_section(),
+            Inst::MbarrierArriveNocompleteReleaseCtaStateB64(value) => value.spec_section(),
+            Inst::MbarrierArriveDropSemScopeStateB64(value) => value.spec_section(),
+            Inst::MbarrierArriveDropSemScopeSharedClusterB64(value) => value.spec_section(),
+            Inst::MbarrierArriveDropExpectTxStateSemScopeB64(value) => value.spec_section(),
+            Inst::MbarrierArriveDropExpectTxSharedClusterSemScopeB64(value) => value.spec_section(),
+            Inst::MbarrierArriveDropNocompleteReleaseCtaStateB64(value) => value.spec_section(),
+            Inst::MbarrierCompleteTxSemScopeSpaceB64(value) => value.spec_section(),
+            Inst::MbarrierExpectTxSemScopeSpaceB64(value) => value.spec_section(),
+            Inst::MbarrierInitStateB64(value) => value.spec_section(),
+            Inst::MbarrierInvalStateB64(value) => value.spec_section(),
+            Inst::MbarrierPendingCountB64(value) => value.spec_section(),
+            Inst::MbarrierTestWaitSemScopeStateB64(value) => value.spec_section(),
+            Inst::MbarrierTestWaitParitySemScopeStateB64(value) => value.spec_section(),
+            Inst::MbarrierTryWaitSemScopeStateB64(value) => value.spec_section(),
+            Inst::MbarrierTryWaitParitySemScopeStateB64(value) => value.spec_section(),
+            Inst::FenceSemScope(value) => value.spec_section(),
+            Inst::FenceAcquireSyncRestrictSharedClusterCluster(value) => value.spec_section(),
+            Inst::FenceReleaseSyncRestrictSharedCtaCluster(value) => value.spec_section(),
+            Inst::FenceOpRestrictReleaseCluster(value) => value.spec_section(),
+            Inst::FenceProxyProxykind(value) => value.spec_section(),
+            Inst::FenceProxyToProxykindFromProxykindReleaseScope(value) => value.spec_section(),
+            Inst::FenceProxyToProxykindFromProxykindAcquireScope(value) => value.spec_section(),
+            Inst::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster(value) => value.spec_section(),
+            Inst::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster(value) => value.spec_section(),
+            Inst::MembarLevel(value) => value.spec_section(),
+            Inst::MembarProxyProxykind(value) => value.spec_section(),
+            Inst::MinAtype(value) => value.spec_section(),
+            Inst::MinReluBtype(value) => value.spec_section(),
+            Inst::MinFtzNanXorsignAbsF32(value) => value.spec_section(),
+            Inst::MinFtzNanAbsF32(value) => value.spec_section(),
+            Inst::MinF64(value) => value.spec_section(),
+            Inst::MinFtzNanXorsignAbsF16(value) => value.spec_section(),
+            Inst::MinFtzNanXorsignAbsF16x2(value) => value.spec_section(),
+            Inst::MinNanXorsignAbsBf16(value) => value.spec_section(),
+            Inst::MinNanXorsignAbsBf16x2(value) => value.spec_section(),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.spec_section(),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype(value) => value.spec_section(),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.spec_section(),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32(value) => value.spec_section(),
+            Inst::MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32(value) => value.spec_section(),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32(value) => value.spec_section(),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32(value) => value.spec_section(),
+            Inst::MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.spec_section(),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.spec_section(),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.spec_section(),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.spec_section(),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.spec_section(),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.spec_section(),
+            Inst::MmaSyncAlignedM8n8k4AlayoutBlayoutDtypeF16F16Ctype(value) => value.spec_section(),
+            Inst::MmaSyncAlignedM16n8k8RowColDtypeF16F16Ctype(value) => value.spec_section(),
+            Inst::MmaSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.spec_section(),
+            Inst::MmaSyncAlignedM16n8k4RowColF32Tf32Tf32F32(value) => value.spec_section(),
+            Inst::MmaSyncAlignedM16n8k8RowColF32AtypeBtypeF32(value) => value.spec_section(),
+            Inst::MmaSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.spec_section(),
+            Inst::MmaSyncAlignedShapeRowColDtypeF8typeF8typeCtype(value) => value.spec_section(),
+            Inst::MmaSyncAlignedM16n8k32RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.spec_section(),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.spec_section(),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.spec_section(),
+            Inst::MmaSyncAlignedM16n8k32RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.spec_section(),
+            Inst::MmaSyncAlignedShapeRowColF64F64F64F64(value) => value.spec_section(),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.spec_section(),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.spec_section(),
+            Inst::MmaSyncAlignedShapeRowColS32B1B1S32BitopPopc(value) => value.spec_section(),
+            Inst::MovType(value) => value.spec_section(),
+            Inst::MovU32(value) => value.spec_section(),
+            Inst::MovU64(value) => value.spec_section(),
+            Inst::MovU321(value) => value.spec_section(),
+            Inst::MovU641(value) => value.spec_section(),
+            Inst::MovType1(value) => value.spec_section(),
+            Inst::MovmatrixSyncAlignedShapeTransType(value) => value.spec_section(),
+            Inst::MulModeType(value) => value.spec_section(),
+            Inst::MulRndFtzSatF32(value) => value.spec_section(),
+            Inst::MulRndFtzF32x2(value) => value.spec_section(),
+            Inst::MulRndF64(value) => value.spec_section(),
+            Inst::MulRndFtzSatF16(value) => value.spec_section(),
+            Inst::MulRndFtzSatF16x2(value) => value.spec_section(),
+            Inst::MulRndBf16(value) => value.spec_section(),
+            Inst::MulRndBf16x2(value) => value.spec_section(),
+            Inst::Mul24ModeType(value) => value.spec_section(),
+            Inst::MultimemLdReduceLdsemScopeSsOpType(value) => value.spec_section(),
+            Inst::MultimemLdReduceWeakSsOpType(value) => value.spec_section(),
+            Inst::MultimemStStsemScopeSsType(value) => value.spec_section(),
+            Inst::MultimemStWeakSsType(value) => value.spec_section(),
+            Inst::MultimemRedRedsemScopeSsOpType(value) => value.spec_section(),
+            Inst::MultimemLdReduceLdsemScopeSsOpAccPrecVecType(value) => value.spec_section(),
+            Inst::MultimemLdReduceWeakSsOpAccPrecVecType(value) => value.spec_section(),
+            Inst::MultimemStStsemScopeSsVecType(value) => value.spec_section(),
+            Inst::MultimemStWeakSsVecType(value) => value.spec_section(),
+            Inst::MultimemRedRedsemScopeSsRedopVecRedtype(value) => value.spec_section(),
+            Inst::NanosleepU32(value) => value.spec_section(),
+            Inst::NegType(value) => value.spec_section(),
+            Inst::NegFtzF32(value) => value.spec_section(),
+            Inst::NegF64(value) => value.spec_section(),
+            Inst::NegFtzF16(value) => value.spec_section(),
+            Inst::NegFtzF16x2(value) => value.spec_section(),
+            Inst::NegBf16(value) => value.spec_section(),
+            Inst::NegBf16x2(value) => value.spec_section(),
+            Inst::NotType(value) => value.spec_section(),
+            Inst::OrType(value) => value.spec_section(),
+            Inst::Pmevent(value) => value.spec_section(),
+            Inst::PmeventMask(value) => value.spec_section(),
+            Inst::PopcType(value) => value.spec_section(),
+            Inst::PrefetchSpaceLevel(value) => value.spec_section(),
+            Inst::PrefetchGlobalLevelEvictionPriority(value) => value.spec_section(),
+            Inst::PrefetchuL1(value) => value.spec_section(),
+            Inst::PrefetchTensormapSpaceTensormap(value) => value.spec_section(),
+            Inst::PrmtB32Mode(value) => value.spec_section(),
+            Inst::RcpApproxFtzF64(value) => value.spec_section(),
+            Inst::RcpApproxFtzF32(value) => value.spec_section(),
+            Inst::RcpRndFtzF32(value) => value.spec_section(),
+            Inst::RcpRndF64(value) => value.spec_section(),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType(value) => value.spec_section(),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType1(value) => value.spec_section(),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType2(value) => value.spec_section(),
+            Inst::RedAsyncSemScopeSsCompletionMechanismAddType(value) => value.spec_section(),
+            Inst::RedAsyncMmioSemScopeSsAddType(value) => value.spec_section(),
+            Inst::RedOpSpaceSemScopeLevelCacheHintType(value) => value.spec_section(),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16(value) => value.spec_section(),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16x2(value) => value.spec_section(),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16(value) => value.spec_section(),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16x2(value) => value.spec_section(),
+            Inst::RedAddSpaceSemScopeLevelCacheHintVec32BitF32(value) => value.spec_section(),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec16BitHalfWordType(value) => value.spec_section(),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec32BitPackedType(value) => value.spec_section(),
+            Inst::ReduxSyncOpType(value) => value.spec_section(),
+            Inst::ReduxSyncOpB32(value) => value.spec_section(),
+            Inst::ReduxSyncOpAbsNanF32(value) => value.spec_section(),
+            Inst::RemType(value) => value.spec_section(),
+            Inst::RetUni(value) => value.spec_section(),
+            Inst::RsqrtApproxFtzF64(value) => value.spec_section(),
+            Inst::RsqrtApproxFtzF32(value) => value.spec_section(),
+            Inst::RsqrtApproxF64(value) => value.spec_section(),
+            Inst::SadType(value) => value.spec_section(),
+            Inst::SelpType(value) => value.spec_section(),
+            Inst::SetCmpopFtzDtypeStype(value) => value.spec_section(),
+            Inst::SetCmpopBoolopFtzDtypeStype(value) => value.spec_section(),
+            Inst::SetCmpopFtzF16Stype(value) => value.spec_section(),
+            Inst::SetCmpopBoolopFtzF16Stype(value) => value.spec_section(),
+            Inst::SetCmpopBf16Stype(value) => value.spec_section(),
+            Inst::SetCmpopBoolopBf16Stype(value) => value.spec_section(),
+            Inst::SetCmpopFtzDtypeF16(value) => value.spec_section(),
+            Inst::SetCmpopBoolopFtzDtypeF16(value) => value.spec_section(),
+            Inst::SetCmpopDtypeBf16(value) => value.spec_section(),
+            Inst::SetCmpopBoolopDtypeBf16(value) => value.spec_section(),
+            Inst::SetCmpopFtzDtypeF16x2(value) => value.spec_section(),
+            Inst::SetCmpopBoolopFtzDtypeF16x2(value) => value.spec_section(),
+            Inst::SetCmpopDtypeBf16x2(value) => value.spec_section(),
+            Inst::SetCmpopBoolopDtypeBf16x2(value) => value.spec_section(),
+            Inst::SetmaxnregActionSyncAlignedU32(value) => value.spec_section(),
+            Inst::SetpCmpopFtzType(value) => value.spec_section(),
+            Inst::SetpCmpopBoolopFtzType(value) => value.spec_section(),
+            Inst::SetpCmpopFtzF16(value) => value.spec_section(),
+            Inst::SetpCmpopBoolopFtzF16(value) => value.spec_section(),
+            Inst::SetpCmpopFtzF16x2(value) => value.spec_section(),
+            Inst::SetpCmpopBoolopFtzF16x2(value) => value.spec_section(),
+            Inst::SetpCmpopBf16(value) => value.spec_section(),
+            Inst::SetpCmpopBoolopBf16(value) => value.spec_section(),
+            Inst::SetpCmpopBf16x2(value) => value.spec_section(),
+            Inst::SetpCmpopBoolopBf16x2(value) => value.spec_section(),
+            Inst::ShfLModeB32(value) => value.spec_section(),
+            Inst::ShfRModeB32(value) => value.spec_section(),
+            Inst::ShflSyncModeB32(value) => value.spec_section(),
+            Inst::ShflModeB32(value) => value.spec_section(),
+            Inst::ShlType(value) => value.spec_section(),
+            Inst::ShrType(value) => value.spec_section(),
+            Inst::SinApproxFtzF32(value) => value.spec_section(),
+            Inst::SlctDtypeS32(value) => value.spec_section(),
+            Inst::SlctFtzDtypeF32(value) => value.spec_section(),
+            Inst::SqrtApproxFtzF32(value) => value.spec_section(),
+            Inst::SqrtRndFtzF32(value) => value.spec_section(),
+            Inst::SqrtRndF64(value) => value.spec_section(),
+            Inst::StAsyncSemScopeSsCompletionMechanismVecType(value) => value.spec_section(),
+            Inst::StAsyncMmioSemScopeSsType(value) => value.spec_section(),
+            Inst::StBulkWeakSharedCta(value) => value.spec_section(),
+            Inst::StWeakSsCopLevelCacheHintVecType(value) => value.spec_section(),
+            Inst::StWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.spec_section(),
+            Inst::StVolatileSsVecType(value) => value.spec_section(),
+            Inst::StRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.spec_section(),
+            Inst::StReleaseScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.spec_section(),
+            Inst::StMmioRelaxedSysGlobalType(value) => value.spec_section(),
+            Inst::StackrestoreType(value) => value.spec_section(),
+            Inst::StacksaveType(value) => value.spec_section(),
+            Inst::StmatrixSyncAlignedShapeNumTransSsType(value) => value.spec_section(),
+            Inst::SubCcType(value) => value.spec_section(),
+            Inst::SubType(value) => value.spec_section(),
+            Inst::SubSatS32(value) => value.spec_section(),
+            Inst::SubRndFtzSatF32(value) => value.spec_section(),
+            Inst::SubRndFtzF32x2(value) => value.spec_section(),
+            Inst::SubRndF64(value) => value.spec_section(),
+            Inst::SubRndFtzSatF16(value) => value.spec_section(),
+            Inst::SubRndFtzSatF16x2(value) => value.spec_section(),
+            Inst::SubRndBf16(value) => value.spec_section(),
+            Inst::SubRndBf16x2(value) => value.spec_section(),
+            Inst::SubRndSatF32Atype(value) => value.spec_section(),
+            Inst::SubcCcType(value) => value.spec_section(),
+            Inst::SuldBGeomCopVecDtypeMode(value) => value.spec_section(),
+            Inst::SuqQueryB32(value) => value.spec_section(),
+            Inst::SuredBOpGeomCtypeMode(value) => value.spec_section(),
+            Inst::SuredPOpGeomCtypeMode(value) => value.spec_section(),
+            Inst::SustBDimCopVecCtypeMode(value) => value.spec_section(),
+            Inst::SustPDimVecB32Mode(value) => value.spec_section(),
+            Inst::SustBAdimCopVecCtypeMode(value) => value.spec_section(),
+            Inst::SzextModeType(value) => value.spec_section(),
+            Inst::TanhApproxType(value) => value.spec_section(),
+            Inst::Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32(value) => value.spec_section(),
+            Inst::Tcgen05DeallocCtaGroupSyncAlignedB32(value) => value.spec_section(),
+            Inst::Tcgen05RelinquishAllocPermitCtaGroupSyncAligned(value) => value.spec_section(),
+            Inst::Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64(value) => value.spec_section(),
+            Inst::Tcgen05CpCtaGroupShapeMulticastDstSrcFmt(value) => value.spec_section(),
+            Inst::Tcgen05FenceBeforeThreadSync(value) => value.spec_section(),
+            Inst::Tcgen05FenceAfterThreadSync(value) => value.spec_section(),
+            Inst::Tcgen05LdSyncAlignedShape1NumPackB32(value) => value.spec_section(),
+            Inst::Tcgen05LdSyncAlignedShape2NumPackB32(value) => value.spec_section(),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32(value) => value.spec_section(),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32(value) => value.spec_section(),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopType(value) => value.spec_section(),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopType(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKind(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKind1(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKindCollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKindI8(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKindI81(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKindI8CollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKind(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKind1(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKindCollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage1(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKindI8(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKindI81(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKindI8CollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1(value) => value.spec_section(),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage1(value) => value.spec_section(),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage1(value) => value.spec_section(),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage1(value) => value.spec_section(),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage(value) => value.spec_section(),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1(value) => value.spec_section(),
+            Inst::Tcgen05ShiftCtaGroupDown(value) => value.spec_section(),
+            Inst::Tcgen05StSyncAlignedShape1NumUnpackB32(value) => value.spec_section(),
+            Inst::Tcgen05StSyncAlignedShape2NumUnpackB32(value) => value.spec_section(),
+            Inst::Tcgen05WaitOperationSyncAligned(value) => value.spec_section(),
+            Inst::TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned(value) => value.spec_section(),
+            Inst::TensormapReplaceModeField1SsB1024Type(value) => value.spec_section(),
+            Inst::TensormapReplaceModeField2SsB1024Type(value) => value.spec_section(),
+            Inst::TensormapReplaceModeField3SsB1024Type(value) => value.spec_section(),
+            Inst::TestpOpType(value) => value.spec_section(),
+            Inst::TexGeomV4DtypeCtype(value) => value.spec_section(),
+            Inst::TexGeomV4DtypeCtype1(value) => value.spec_section(),
+            Inst::TexGeomV2F16x2Ctype(value) => value.spec_section(),
+            Inst::TexGeomV2F16x2Ctype1(value) => value.spec_section(),
+            Inst::TexBaseGeomV4DtypeCtype(value) => value.spec_section(),
+            Inst::TexLevelGeomV4DtypeCtype(value) => value.spec_section(),
+            Inst::TexGradGeomV4DtypeCtype(value) => value.spec_section(),
+            Inst::TexBaseGeomV2F16x2Ctype(value) => value.spec_section(),
+            Inst::TexLevelGeomV2F16x2Ctype(value) => value.spec_section(),
+            Inst::TexGradGeomV2F16x2Ctype(value) => value.spec_section(),
+            Inst::Tld4Comp2dV4DtypeF32(value) => value.spec_section(),
+            Inst::Tld4CompGeomV4DtypeF32(value) => value.spec_section(),
+            Inst::Trap(value) => value.spec_section(),
+            Inst::TxqTqueryB32(value) => value.spec_section(),
+            Inst::TxqLevelTlqueryB32(value) => value.spec_section(),
+            Inst::TxqSqueryB32(value) => value.spec_section(),
+            Inst::VmadDtypeAtypeBtypeSatScale(value) => value.spec_section(),
+            Inst::VmadDtypeAtypeBtypePoSatScale(value) => value.spec_section(),
+            Inst::VaddDtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::VsubDtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::VabsdiffDtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::VminDtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::VmaxDtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::VaddDtypeAtypeBtypeSatOp2(value) => value.spec_section(),
+            Inst::VsubDtypeAtypeBtypeSatOp2(value) => value.spec_section(),
+            Inst::VabsdiffDtypeAtypeBtypeSatOp2(value) => value.spec_section(),
+            Inst::VminDtypeAtypeBtypeSatOp2(value) => value.spec_section(),
+            Inst::VmaxDtypeAtypeBtypeSatOp2(value) => value.spec_section(),
+            Inst::VaddDtypeAtypeBtypeSat1(value) => value.spec_section(),
+            Inst::VsubDtypeAtypeBtypeSat1(value) => value.spec_section(),
+            Inst::VabsdiffDtypeAtypeBtypeSat1(value) => value.spec_section(),
+            Inst::VminDtypeAtypeBtypeSat1(value) => value.spec_section(),
+            Inst::VmaxDtypeAtypeBtypeSat1(value) => value.spec_section(),
+            Inst::Vadd2DtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::Vsub2DtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::Vavrg2DtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::Vabsdiff2DtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::Vmin2DtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::Vmax2DtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::Vadd2DtypeAtypeBtypeAdd(value) => value.spec_section(),
+            Inst::Vsub2DtypeAtypeBtypeAdd(value) => value.spec_section(),
+            Inst::Vavrg2DtypeAtypeBtypeAdd(value) => value.spec_section(),
+            Inst::Vabsdiff2DtypeAtypeBtypeAdd(value) => value.spec_section(),
+            Inst::Vmin2DtypeAtypeBtypeAdd(value) => value.spec_section(),
+            Inst::Vmax2DtypeAtypeBtypeAdd(value) => value.spec_section(),
+            Inst::Vadd4DtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::Vsub4DtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::Vavrg4DtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::Vabsdiff4DtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::Vmin4DtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::Vmax4DtypeAtypeBtypeSat(value) => value.spec_section(),
+            Inst::Vadd4DtypeAtypeBtypeAdd(value) => value.spec_section(),
+            Inst::Vsub4DtypeAtypeBtypeAdd(value) => value.spec_section(),
+            Inst::Vavrg4DtypeAtypeBtypeAdd(value) => value.spec_section(),
+            Inst::Vabsdiff4DtypeAtypeBtypeAdd(value) => value.spec_section(),
+            Inst::Vmin4DtypeAtypeBtypeAdd(value) => value.spec_section(),
+            Inst::Vmax4DtypeAtypeBtypeAdd(value) => value.spec_section(),
+            Inst::VoteSyncModePred(value) => value.spec_section(),
+            Inst::VoteSyncBallotB32(value) => value.spec_section(),
+            Inst::VoteModePred(value) => value.spec_section(),
+            Inst::VoteBallotB32(value) => value.spec_section(),
+            Inst::VsetAtypeBtypeCmp(value) => value.spec_section(),
+            Inst::VsetAtypeBtypeCmpOp2(value) => value.spec_section(),
+            Inst::VsetAtypeBtypeCmp1(value) => value.spec_section(),
+            Inst::Vset2AtypeBtypeCmp(value) => value.spec_section(),
+            Inst::Vset2AtypeBtypeCmpAdd(value) => value.spec_section(),
+            Inst::Vset4AtypeBtypeCmp(value) => value.spec_section(),
+            Inst::Vset4AtypeBtypeCmpAdd(value) => value.spec_section(),
+            Inst::VshlDtypeAtypeU32SatMode(value) => value.spec_section(),
+            Inst::VshrDtypeAtypeU32SatMode(value) => value.spec_section(),
+            Inst::VshlDtypeAtypeU32SatModeOp2(value) => value.spec_section(),
+            Inst::VshrDtypeAtypeU32SatModeOp2(value) => value.spec_section(),
+            Inst::VshlDtypeAtypeU32SatMode1(value) => value.spec_section(),
+            Inst::VshrDtypeAtypeU32SatMode1(value) => value.spec_section(),
+            Inst::WgmmaCommitGroupSyncAligned(value) => value.spec_section(),
+            Inst::WgmmaFenceSyncAligned(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc(value) => value.spec_section(),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1(value) => value.spec_section(),
+            Inst::WgmmaWaitGroupSyncAligned(value) => value.spec_section(),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype(value) => value.spec_section(),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype(value) => value.spec_section(),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype(value) => value.spec_section(),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype1(value) => value.spec_section(),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype1(value) => value.spec_section(),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype1(value) => value.spec_section(),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype2(value) => value.spec_section(),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype2(value) => value.spec_section(),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype2(value) => value.spec_section(),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype3(value) => value.spec_section(),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype3(value) => value.spec_section(),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype3(value) => value.spec_section(),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype(value) => value.spec_section(),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype(value) => value.spec_section(),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype4(value) => value.spec_section(),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype1(value) => value.spec_section(),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype1(value) => value.spec_section(),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype5(value) => value.spec_section(),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeDtypeCtype(value) => value.spec_section(),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeS32AtypeBtypeS32Satfinite(value) => value.spec_section(),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF32(value) => value.spec_section(),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF321(value) => value.spec_section(),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeRndF64F64F64F64(value) => value.spec_section(),
+            Inst::WmmaMmaSyncAlignedRowColShapeS32AtypeBtypeS32Satfinite(value) => value.spec_section(),
+            Inst::WmmaMmaOpPopcSyncAlignedRowColShapeS32AtypeBtypeS32(value) => value.spec_section(),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType(value) => value.spec_section(),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType1(value) => value.spec_section(),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType2(value) => value.spec_section(),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType3(value) => value.spec_section(),
+            Inst::XorType(value) => value.spec_section(),
+        }
+    }
+}
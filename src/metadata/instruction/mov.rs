@@ -0,0 +1,184 @@
+//! Original PTX specification:
+//!
+//! mov.type  d, a;
+//! // mov.type  d, sreg;
+//! // mov.type  d, avar;       // get address of variable
+//! // mov.type  d, avar+imm;   // get address of variable with offset
+//! mov.u32   d, fname;      // get address of device function
+//! mov.u64   d, fname;      // get address of device function
+//! mov.u32   d, kernel;     // get address of entry function
+//! mov.u64   d, kernel;     // get address of entry function
+//! .type = { .pred,
+//! .b16, .b32, .b64,
+//! .u16, .u32, .u64,
+//! .s16, .s32, .s64,
+//! .f32, .f64 };
+//! ----------------------------------------------
+//! mov.type  d, a;
+//! .type = { .b16, .b32, .b64, .b128 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mov::section_0::*;
+
+    impl Metadata for MovType {
+        fn opcode(&self) -> &'static str {
+            "mov"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::Pred => {
+                    f(".pred");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mov.txt",
+                section: 0,
+                line: 1,
+                rule: "mov.type d, a;",
+            }
+        }
+    }
+
+    impl Metadata for MovU32 {
+        fn opcode(&self) -> &'static str {
+            "mov"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".u32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mov.txt",
+                section: 0,
+                line: 5,
+                rule: "mov.u32 d, fname;",
+            }
+        }
+    }
+
+    impl Metadata for MovU64 {
+        fn opcode(&self) -> &'static str {
+            "mov"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".u64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mov.txt",
+                section: 0,
+                line: 6,
+                rule: "mov.u64 d, fname;",
+            }
+        }
+    }
+
+    impl Metadata for MovU321 {
+        fn opcode(&self) -> &'static str {
+            "mov"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".u32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mov.txt",
+                section: 0,
+                line: 7,
+                rule: "mov.u32 d, kernel;",
+            }
+        }
+    }
+
+    impl Metadata for MovU641 {
+        fn opcode(&self) -> &'static str {
+            "mov"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".u64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mov.txt",
+                section: 0,
+                line: 8,
+                rule: "mov.u64 d, kernel;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::mov::section_1::*;
+
+    impl Metadata for MovType1 {
+        fn opcode(&self) -> &'static str {
+            "mov"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mov.txt",
+                section: 1,
+                line: 15,
+                rule: "mov.type d, a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Original PTX specification:
+//!
+//! movmatrix.sync.aligned.shape.trans.type d, a;
+//! .shape  = {.m8n8};
+//! .type   = {.b16};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::movmatrix::section_0::*;
+
+    impl Metadata for MovmatrixSyncAlignedShapeTransType {
+        fn opcode(&self) -> &'static str {
+            "movmatrix"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M8n8 => {
+                    f(".m8n8");
+                }
+            }
+            f(".trans");
+            match &self.type_ {
+                Type::B16 => {
+                    f(".b16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "movmatrix.txt",
+                section: 0,
+                line: 1,
+                rule: "movmatrix.sync.aligned.shape.trans.type d, a;",
+            }
+        }
+    }
+}
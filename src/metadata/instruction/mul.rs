@@ -0,0 +1,299 @@
+//! Original PTX specification:
+//!
+//! mul.mode.type  d, a, b;
+//! .mode = { .hi, .lo, .wide };
+//! .type = { .u16, .u32, .u64,
+//! .s16, .s32, .s64 };
+//! --------------------------------------------
+//! mul{.rnd}{.ftz}{.sat}.f32  d, a, b;
+//! mul{.rnd}{.ftz}.f32x2      d, a, b;
+//! mul{.rnd}.f64              d, a, b;
+//! .rnd = { .rn, .rz, .rm, .rp };
+//! --------------------------------------------
+//! mul{.rnd}{.ftz}{.sat}.f16   d, a, b;
+//! mul{.rnd}{.ftz}{.sat}.f16x2 d, a, b;
+//! mul{.rnd}.bf16   d, a, b;
+//! mul{.rnd}.bf16x2 d, a, b;
+//! .rnd = { .rn };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mul::section_0::*;
+
+    impl Metadata for MulModeType {
+        fn opcode(&self) -> &'static str {
+            "mul"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.mode {
+                Mode::Wide => {
+                    f(".wide");
+                }
+                Mode::Hi => {
+                    f(".hi");
+                }
+                Mode::Lo => {
+                    f(".lo");
+                }
+            }
+            match &self.type_ {
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mul.txt",
+                section: 0,
+                line: 1,
+                rule: "mul.mode.type d, a, b;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::mul::section_1::*;
+
+    impl Metadata for MulRndFtzSatF32 {
+        fn opcode(&self) -> &'static str {
+            "mul"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_0) = self.rnd.as_ref() {
+                match rnd_0 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                    Rnd::Rz => {
+                        f(".rz");
+                    }
+                    Rnd::Rm => {
+                        f(".rm");
+                    }
+                    Rnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mul.txt",
+                section: 1,
+                line: 6,
+                rule: "mul{.rnd}{.ftz}{.sat}.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for MulRndFtzF32x2 {
+        fn opcode(&self) -> &'static str {
+            "mul"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_1) = self.rnd.as_ref() {
+                match rnd_1 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                    Rnd::Rz => {
+                        f(".rz");
+                    }
+                    Rnd::Rm => {
+                        f(".rm");
+                    }
+                    Rnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mul.txt",
+                section: 1,
+                line: 7,
+                rule: "mul{.rnd}{.ftz}.f32x2 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for MulRndF64 {
+        fn opcode(&self) -> &'static str {
+            "mul"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_2) = self.rnd.as_ref() {
+                match rnd_2 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                    Rnd::Rz => {
+                        f(".rz");
+                    }
+                    Rnd::Rm => {
+                        f(".rm");
+                    }
+                    Rnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mul.txt",
+                section: 1,
+                line: 8,
+                rule: "mul{.rnd}.f64 d, a, b;",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::mul::section_2::*;
+
+    impl Metadata for MulRndFtzSatF16 {
+        fn opcode(&self) -> &'static str {
+            "mul"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_3) = self.rnd.as_ref() {
+                match rnd_3 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mul.txt",
+                section: 2,
+                line: 11,
+                rule: "mul{.rnd}{.ftz}{.sat}.f16 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for MulRndFtzSatF16x2 {
+        fn opcode(&self) -> &'static str {
+            "mul"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_4) = self.rnd.as_ref() {
+                match rnd_4 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mul.txt",
+                section: 2,
+                line: 12,
+                rule: "mul{.rnd}{.ftz}{.sat}.f16x2 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for MulRndBf16 {
+        fn opcode(&self) -> &'static str {
+            "mul"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_5) = self.rnd.as_ref() {
+                match rnd_5 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                }
+            }
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mul.txt",
+                section: 2,
+                line: 13,
+                rule: "mul{.rnd}.bf16 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for MulRndBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "mul"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_6) = self.rnd.as_ref() {
+                match rnd_6 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                }
+            }
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mul.txt",
+                section: 2,
+                line: 14,
+                rule: "mul{.rnd}.bf16x2 d, a, b;",
+            }
+        }
+    }
+}
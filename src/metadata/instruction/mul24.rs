@@ -0,0 +1,46 @@
+//! Original PTX specification:
+//!
+//! mul24.mode.type  d, a, b;
+//! .mode = { .hi, .lo };
+//! .type = { .u32, .s32 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mul24::section_0::*;
+
+    impl Metadata for Mul24ModeType {
+        fn opcode(&self) -> &'static str {
+            "mul24"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.mode {
+                Mode::Hi => {
+                    f(".hi");
+                }
+                Mode::Lo => {
+                    f(".lo");
+                }
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "mul24.txt",
+                section: 0,
+                line: 1,
+                rule: "mul24.mode.type d, a, b;",
+            }
+        }
+    }
+}
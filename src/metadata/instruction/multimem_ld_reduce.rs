@@ -0,0 +1,891 @@
+//! Original PTX specification:
+//!
+//! // Integer type:
+//! multimem.ld_reduce{.ldsem}{.scope}{.ss}.op.type      d, [a];
+//! multimem.ld_reduce.weak{.ss}.op.type                 d, [a];
+//! multimem.st{.stsem}{.scope}{.ss}.type                [a], b;
+//! multimem.st.weak{.ss}.type                           [a], b;
+//! multimem.red{.redsem}{.scope}{.ss}.op.type           [a], b;
+//! .ss =       { .global };
+//! .ldsem =    { .relaxed, .acquire };
+//! .stsem =    { .relaxed, .release };
+//! .redsem =   { .relaxed, .release };
+//! .scope =    { .cta, .cluster, .gpu, .sys };
+//! .op  =      { .min, .max, .add, .and, .or, .xor };
+//! .type =     { .b32, .b64,  .u32, .u64, .s32, .s64 };
+//! ------------------------------------------------------------------
+//! // Floating point type:
+//! multimem.ld_reduce{.ldsem}{.scope}{.ss}.op{.acc_prec}{.vec}.type    d, [a];
+//! multimem.ld_reduce.weak{.ss}.op{.acc_prec}{.vec}.type               d, [a];
+//! multimem.st{.stsem}{.scope}{.ss}{.vec}.type                         [a], b;
+//! multimem.st.weak{.ss}{.vec}.type                                    [a], b;
+//! multimem.red{.redsem}{.scope}{.ss}.redop{.vec}.redtype              [a], b;
+//! .ss =       { .global };
+//! .ldsem =    { .relaxed, .acquire };
+//! .stsem =    { .relaxed, .release };
+//! .redsem =   { .relaxed, .release };
+//! .scope =    { .cta, .cluster, .gpu, .sys };
+//! .op  =      { .min, .max, .add };
+//! .redop  =   { .add };
+//! .acc_prec = { .acc::f32, .acc::f16 };
+//! .vec =      { .v2, .v4, .v8 };
+//! .type=      { .f16, .f16x2, .bf16, .bf16x2, .f32, .f64, .e5m2, .e5m2x2, .e5m2x4, .e4m3, .e4m3x2, .e4m3x4 };
+//! .redtype =  { .f16, .f16x2, .bf16, .bf16x2, .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::multimem_ld_reduce::section_0::*;
+
+    impl Metadata for MultimemLdReduceLdsemScopeSsOpType {
+        fn opcode(&self) -> &'static str {
+            "multimem"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".ld_reduce");
+            if let Some(ldsem_0) = self.ldsem.as_ref() {
+                match ldsem_0 {
+                    Ldsem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Ldsem::Acquire => {
+                        f(".acquire");
+                    }
+                }
+            }
+            if let Some(scope_1) = self.scope.as_ref() {
+                match scope_1 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(ss_2) = self.ss.as_ref() {
+                match ss_2 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            match &self.op {
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+                Op::Add => {
+                    f(".add");
+                }
+                Op::And => {
+                    f(".and");
+                }
+                Op::Xor => {
+                    f(".xor");
+                }
+                Op::Or => {
+                    f(".or");
+                }
+            }
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "multimem.ld_reduce.txt",
+                section: 0,
+                line: 2,
+                rule: "multimem.ld_reduce{.ldsem}{.scope}{.ss}.op.type d, [a];",
+            }
+        }
+    }
+
+    impl Metadata for MultimemLdReduceWeakSsOpType {
+        fn opcode(&self) -> &'static str {
+            "multimem"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".ld_reduce");
+            f(".weak");
+            if let Some(ss_3) = self.ss.as_ref() {
+                match ss_3 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            match &self.op {
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+                Op::Add => {
+                    f(".add");
+                }
+                Op::And => {
+                    f(".and");
+                }
+                Op::Xor => {
+                    f(".xor");
+                }
+                Op::Or => {
+                    f(".or");
+                }
+            }
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "multimem.ld_reduce.txt",
+                section: 0,
+                line: 3,
+                rule: "multimem.ld_reduce.weak{.ss}.op.type d, [a];",
+            }
+        }
+    }
+
+    impl Metadata for MultimemStStsemScopeSsType {
+        fn opcode(&self) -> &'static str {
+            "multimem"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".st");
+            if let Some(stsem_4) = self.stsem.as_ref() {
+                match stsem_4 {
+                    Stsem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Stsem::Release => {
+                        f(".release");
+                    }
+                }
+            }
+            if let Some(scope_5) = self.scope.as_ref() {
+                match scope_5 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(ss_6) = self.ss.as_ref() {
+                match ss_6 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "multimem.ld_reduce.txt",
+                section: 0,
+                line: 4,
+                rule: "multimem.st{.stsem}{.scope}{.ss}.type [a], b;",
+            }
+        }
+    }
+
+    impl Metadata for MultimemStWeakSsType {
+        fn opcode(&self) -> &'static str {
+            "multimem"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".st");
+            f(".weak");
+            if let Some(ss_7) = self.ss.as_ref() {
+                match ss_7 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "multimem.ld_reduce.txt",
+                section: 0,
+                line: 5,
+                rule: "multimem.st.weak{.ss}.type [a], b;",
+            }
+        }
+    }
+
+    impl Metadata for MultimemRedRedsemScopeSsOpType {
+        fn opcode(&self) -> &'static str {
+            "multimem"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".red");
+            if let Some(redsem_8) = self.redsem.as_ref() {
+                match redsem_8 {
+                    Redsem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Redsem::Release => {
+                        f(".release");
+                    }
+                }
+            }
+            if let Some(scope_9) = self.scope.as_ref() {
+                match scope_9 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(ss_10) = self.ss.as_ref() {
+                match ss_10 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            match &self.op {
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+                Op::Add => {
+                    f(".add");
+                }
+                Op::And => {
+                    f(".and");
+                }
+                Op::Xor => {
+                    f(".xor");
+                }
+                Op::Or => {
+                    f(".or");
+                }
+            }
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "multimem.ld_reduce.txt",
+                section: 0,
+                line: 6,
+                rule: "multimem.red{.redsem}{.scope}{.ss}.op.type [a], b;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::multimem_ld_reduce::section_1::*;
+
+    impl Metadata for MultimemLdReduceLdsemScopeSsOpAccPrecVecType {
+        fn opcode(&self) -> &'static str {
+            "multimem"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".ld_reduce");
+            if let Some(ldsem_11) = self.ldsem.as_ref() {
+                match ldsem_11 {
+                    Ldsem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Ldsem::Acquire => {
+                        f(".acquire");
+                    }
+                }
+            }
+            if let Some(scope_12) = self.scope.as_ref() {
+                match scope_12 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(ss_13) = self.ss.as_ref() {
+                match ss_13 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            match &self.op {
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+                Op::Add => {
+                    f(".add");
+                }
+            }
+            if let Some(acc_prec_14) = self.acc_prec.as_ref() {
+                match acc_prec_14 {
+                    AccPrec::AccF32 => {
+                        f(".acc::f32");
+                    }
+                    AccPrec::AccF16 => {
+                        f(".acc::f16");
+                    }
+                }
+            }
+            if let Some(vec_15) = self.vec.as_ref() {
+                match vec_15 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                    Vec::V8 => {
+                        f(".v8");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::Bf16x2 => {
+                    f(".bf16x2");
+                }
+                Type::E5m2x2 => {
+                    f(".e5m2x2");
+                }
+                Type::E5m2x4 => {
+                    f(".e5m2x4");
+                }
+                Type::E4m3x2 => {
+                    f(".e4m3x2");
+                }
+                Type::E4m3x4 => {
+                    f(".e4m3x4");
+                }
+                Type::F16x2 => {
+                    f(".f16x2");
+                }
+                Type::Bf16 => {
+                    f(".bf16");
+                }
+                Type::E5m2 => {
+                    f(".e5m2");
+                }
+                Type::E4m3 => {
+                    f(".e4m3");
+                }
+                Type::F16 => {
+                    f(".f16");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "multimem.ld_reduce.txt",
+                section: 1,
+                line: 16,
+                rule: "multimem.ld_reduce{.ldsem}{.scope}{.ss}.op{.acc_prec}{.vec}.type d, [a];",
+            }
+        }
+    }
+
+    impl Metadata for MultimemLdReduceWeakSsOpAccPrecVecType {
+        fn opcode(&self) -> &'static str {
+            "multimem"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".ld_reduce");
+            f(".weak");
+            if let Some(ss_16) = self.ss.as_ref() {
+                match ss_16 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            match &self.op {
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+                Op::Add => {
+                    f(".add");
+                }
+            }
+            if let Some(acc_prec_17) = self.acc_prec.as_ref() {
+                match acc_prec_17 {
+                    AccPrec::AccF32 => {
+                        f(".acc::f32");
+                    }
+                    AccPrec::AccF16 => {
+                        f(".acc::f16");
+                    }
+                }
+            }
+            if let Some(vec_18) = self.vec.as_ref() {
+                match vec_18 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                    Vec::V8 => {
+                        f(".v8");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::Bf16x2 => {
+                    f(".bf16x2");
+                }
+                Type::E5m2x2 => {
+                    f(".e5m2x2");
+                }
+                Type::E5m2x4 => {
+                    f(".e5m2x4");
+                }
+                Type::E4m3x2 => {
+                    f(".e4m3x2");
+                }
+                Type::E4m3x4 => {
+                    f(".e4m3x4");
+                }
+                Type::F16x2 => {
+                    f(".f16x2");
+                }
+                Type::Bf16 => {
+                    f(".bf16");
+                }
+                Type::E5m2 => {
+                    f(".e5m2");
+                }
+                Type::E4m3 => {
+                    f(".e4m3");
+                }
+                Type::F16 => {
+                    f(".f16");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "multimem.ld_reduce.txt",
+                section: 1,
+                line: 17,
+                rule: "multimem.ld_reduce.weak{.ss}.op{.acc_prec}{.vec}.type d, [a];",
+            }
+        }
+    }
+
+    impl Metadata for MultimemStStsemScopeSsVecType {
+        fn opcode(&self) -> &'static str {
+            "multimem"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".st");
+            if let Some(stsem_19) = self.stsem.as_ref() {
+                match stsem_19 {
+                    Stsem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Stsem::Release => {
+                        f(".release");
+                    }
+                }
+            }
+            if let Some(scope_20) = self.scope.as_ref() {
+                match scope_20 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(ss_21) = self.ss.as_ref() {
+                match ss_21 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            if let Some(vec_22) = self.vec.as_ref() {
+                match vec_22 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                    Vec::V8 => {
+                        f(".v8");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::Bf16x2 => {
+                    f(".bf16x2");
+                }
+                Type::E5m2x2 => {
+                    f(".e5m2x2");
+                }
+                Type::E5m2x4 => {
+                    f(".e5m2x4");
+                }
+                Type::E4m3x2 => {
+                    f(".e4m3x2");
+                }
+                Type::E4m3x4 => {
+                    f(".e4m3x4");
+                }
+                Type::F16x2 => {
+                    f(".f16x2");
+                }
+                Type::Bf16 => {
+                    f(".bf16");
+                }
+                Type::E5m2 => {
+                    f(".e5m2");
+                }
+                Type::E4m3 => {
+                    f(".e4m3");
+                }
+                Type::F16 => {
+                    f(".f16");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "multimem.ld_reduce.txt",
+                section: 1,
+                line: 18,
+                rule: "multimem.st{.stsem}{.scope}{.ss}{.vec}.type [a], b;",
+            }
+        }
+    }
+
+    impl Metadata for MultimemStWeakSsVecType {
+        fn opcode(&self) -> &'static str {
+            "multimem"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".st");
+            f(".weak");
+            if let Some(ss_23) = self.ss.as_ref() {
+                match ss_23 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            if let Some(vec_24) = self.vec.as_ref() {
+                match vec_24 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                    Vec::V8 => {
+                        f(".v8");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::Bf16x2 => {
+                    f(".bf16x2");
+                }
+                Type::E5m2x2 => {
+                    f(".e5m2x2");
+                }
+                Type::E5m2x4 => {
+                    f(".e5m2x4");
+                }
+                Type::E4m3x2 => {
+                    f(".e4m3x2");
+                }
+                Type::E4m3x4 => {
+                    f(".e4m3x4");
+                }
+                Type::F16x2 => {
+                    f(".f16x2");
+                }
+                Type::Bf16 => {
+                    f(".bf16");
+                }
+                Type::E5m2 => {
+                    f(".e5m2");
+                }
+                Type::E4m3 => {
+                    f(".e4m3");
+                }
+                Type::F16 => {
+                    f(".f16");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "multimem.ld_reduce.txt",
+                section: 1,
+                line: 19,
+                rule: "multimem.st.weak{.ss}{.vec}.type [a], b;",
+            }
+        }
+    }
+
+    impl Metadata for MultimemRedRedsemScopeSsRedopVecRedtype {
+        fn opcode(&self) -> &'static str {
+            "multimem"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".red");
+            if let Some(redsem_25) = self.redsem.as_ref() {
+                match redsem_25 {
+                    Redsem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Redsem::Release => {
+                        f(".release");
+                    }
+                }
+            }
+            if let Some(scope_26) = self.scope.as_ref() {
+                match scope_26 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(ss_27) = self.ss.as_ref() {
+                match ss_27 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            match &self.redop {
+                Redop::Add => {
+                    f(".add");
+                }
+            }
+            if let Some(vec_28) = self.vec.as_ref() {
+                match vec_28 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                    Vec::V8 => {
+                        f(".v8");
+                    }
+                }
+            }
+            match &self.redtype {
+                Redtype::Bf16x2 => {
+                    f(".bf16x2");
+                }
+                Redtype::F16x2 => {
+                    f(".f16x2");
+                }
+                Redtype::Bf16 => {
+                    f(".bf16");
+                }
+                Redtype::F16 => {
+                    f(".f16");
+                }
+                Redtype::F32 => {
+                    f(".f32");
+                }
+                Redtype::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "multimem.ld_reduce.txt",
+                section: 1,
+                line: 20,
+                rule: "multimem.red{.redsem}{.scope}{.ss}.redop{.vec}.redtype [a], b;",
+            }
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Original PTX specification:
+//!
+//! nanosleep.u32 t;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::nanosleep::section_0::*;
+
+    impl Metadata for NanosleepU32 {
+        fn opcode(&self) -> &'static str {
+            "nanosleep"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".u32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "nanosleep.txt",
+                section: 0,
+                line: 1,
+                rule: "nanosleep.u32 t;",
+            }
+        }
+    }
+}
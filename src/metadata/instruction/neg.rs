@@ -0,0 +1,159 @@
+//! Original PTX specification:
+//!
+//! neg.type  d, a;
+//! .type = { .s16, .s32, .s64 };
+//!
+//! neg{.ftz}.f32  d, a;
+//! neg.f64        d, a;
+//!
+//! neg{.ftz}.f16    d, a;
+//! neg{.ftz}.f16x2  d, a;
+//! neg.bf16         d, a;
+//! neg.bf16x2       d, a;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::neg::section_0::*;
+
+    impl Metadata for NegType {
+        fn opcode(&self) -> &'static str {
+            "neg"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "neg.txt",
+                section: 0,
+                line: 1,
+                rule: "neg.type d, a;",
+            }
+        }
+    }
+
+    impl Metadata for NegFtzF32 {
+        fn opcode(&self) -> &'static str {
+            "neg"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "neg.txt",
+                section: 0,
+                line: 4,
+                rule: "neg{.ftz}.f32 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for NegF64 {
+        fn opcode(&self) -> &'static str {
+            "neg"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "neg.txt",
+                section: 0,
+                line: 5,
+                rule: "neg.f64 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for NegFtzF16 {
+        fn opcode(&self) -> &'static str {
+            "neg"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "neg.txt",
+                section: 0,
+                line: 7,
+                rule: "neg{.ftz}.f16 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for NegFtzF16x2 {
+        fn opcode(&self) -> &'static str {
+            "neg"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "neg.txt",
+                section: 0,
+                line: 8,
+                rule: "neg{.ftz}.f16x2 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for NegBf16 {
+        fn opcode(&self) -> &'static str {
+            "neg"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "neg.txt",
+                section: 0,
+                line: 9,
+                rule: "neg.bf16 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for NegBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "neg"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "neg.txt",
+                section: 0,
+                line: 10,
+                rule: "neg.bf16x2 d, a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Original PTX specification:
+//!
+//! pmevent a;         // trigger a single performance monitor event
+//! pmevent.mask a;    // trigger one or more performance monitor events
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::pmevent::section_0::*;
+
+    impl Metadata for Pmevent {
+        fn opcode(&self) -> &'static str {
+            "pmevent"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {}
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "pmevent.txt",
+                section: 0,
+                line: 1,
+                rule: "pmevent a;",
+            }
+        }
+    }
+
+    impl Metadata for PmeventMask {
+        fn opcode(&self) -> &'static str {
+            "pmevent"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mask");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "pmevent.txt",
+                section: 0,
+                line: 2,
+                rule: "pmevent.mask a;",
+            }
+        }
+    }
+}
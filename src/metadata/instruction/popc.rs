@@ -0,0 +1,37 @@
+//! Original PTX specification:
+//!
+//! popc.type  d, a;
+//! .type = { .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::popc::section_0::*;
+
+    impl Metadata for PopcType {
+        fn opcode(&self) -> &'static str {
+            "popc"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "popc.txt",
+                section: 0,
+                line: 1,
+                rule: "popc.type d, a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,122 @@
+//! Original PTX specification:
+//!
+//! prefetch{.space}.level                    [a];   // prefetch to data cache
+//! prefetch.global.level::eviction_priority  [a];   // prefetch to data cache
+//! prefetchu.L1  [a];             // prefetch to uniform cache
+//! prefetch{.tensormap_space}.tensormap [a];  // prefetch the tensormap
+//! .space =                    { .global, .local };
+//! .level =                    { .L1, .L2 };
+//! .level::eviction_priority = { .L2::evict_last, .L2::evict_normal };
+//! .tensormap_space =          { .const, .param };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::prefetch::section_0::*;
+
+    impl Metadata for PrefetchSpaceLevel {
+        fn opcode(&self) -> &'static str {
+            "prefetch"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(space_0) = self.space.as_ref() {
+                match space_0 {
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Local => {
+                        f(".local");
+                    }
+                }
+            }
+            match &self.level {
+                Level::L1 => {
+                    f(".L1");
+                }
+                Level::L2 => {
+                    f(".L2");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "prefetch.txt",
+                section: 0,
+                line: 1,
+                rule: "prefetch{.space}.level [a];",
+            }
+        }
+    }
+
+    impl Metadata for PrefetchGlobalLevelEvictionPriority {
+        fn opcode(&self) -> &'static str {
+            "prefetch"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".global");
+            match &self.level_eviction_priority {
+                LevelEvictionPriority::L2EvictNormal => {
+                    f(".L2::evict_normal");
+                }
+                LevelEvictionPriority::L2EvictLast => {
+                    f(".L2::evict_last");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "prefetch.txt",
+                section: 0,
+                line: 2,
+                rule: "prefetch.global.level::eviction_priority [a];",
+            }
+        }
+    }
+
+    impl Metadata for PrefetchuL1 {
+        fn opcode(&self) -> &'static str {
+            "prefetchu"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".L1");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "prefetch.txt",
+                section: 0,
+                line: 3,
+                rule: "prefetchu.L1 [a];",
+            }
+        }
+    }
+
+    impl Metadata for PrefetchTensormapSpaceTensormap {
+        fn opcode(&self) -> &'static str {
+            "prefetch"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(tensormap_space_1) = self.tensormap_space.as_ref() {
+                match tensormap_space_1 {
+                    TensormapSpace::Const => {
+                        f(".const");
+                    }
+                    TensormapSpace::Param => {
+                        f(".param");
+                    }
+                }
+            }
+            f(".tensormap");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "prefetch.txt",
+                section: 0,
+                line: 4,
+                rule: "prefetch{.tensormap_space}.tensormap [a];",
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Original PTX specification:
+//!
+//! prmt.b32{.mode}  d, a, b, c;
+//! .mode = { .f4e, .b4e, .rc8, .ecl, .ecr, .rc16 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::prmt::section_0::*;
+
+    impl Metadata for PrmtB32Mode {
+        fn opcode(&self) -> &'static str {
+            "prmt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".b32");
+            if let Some(mode_0) = self.mode.as_ref() {
+                match mode_0 {
+                    Mode::Rc16 => {
+                        f(".rc16");
+                    }
+                    Mode::F4e => {
+                        f(".f4e");
+                    }
+                    Mode::B4e => {
+                        f(".b4e");
+                    }
+                    Mode::Rc8 => {
+                        f(".rc8");
+                    }
+                    Mode::Ecl => {
+                        f(".ecl");
+                    }
+                    Mode::Ecr => {
+                        f(".ecr");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "prmt.txt",
+                section: 0,
+                line: 1,
+                rule: "prmt.b32{.mode} d, a, b, c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,694 @@
+//! Original PTX specification:
+//!
+//! // Reduction operation with scalar type:
+//! red.op{.space}{.sem}{.scope}{.level::cache_hint}.type          [a], b{, cache-policy};
+//! red.add{.space}{.sem}{.scope}.noftz{.level::cache_hint}.f16    [a], b{, cache-policy};
+//! red.add{.space}{.sem}{.scope}.noftz{.level::cache_hint}.f16x2  [a], b{, cache-policy};
+//! red.add{.space}{.sem}{.scope}.noftz{.level::cache_hint}.bf16   [a], b{, cache-policy};
+//! red.add{.space}{.sem}{.scope}.noftz{.level::cache_hint}.bf16x2 [a], b{, cache-policy};
+//! .space =              { .global, .shared, .shared::cta, .shared::cluster};
+//! .sem =                {.relaxed, .release};
+//! .scope =              {.cta, .cluster, .gpu, .sys};
+//! .op =                 { .and, .or, .xor, .add, .inc, .dec, .min, .max };
+//! .level::cache_hint =  { .L2::cache_hint };
+//! .type =               { .b32, .b64, .u32, .u64, .s32, .s64, .f32, .f64 };
+//! ------------------------------------------------------------------
+//! // Reduction operation with vector type:
+//! red.add{.space}{.sem}{.scope}{.level::cache_hint}.vec_32_bit.f32 [a], b{, cache-policy};
+//! red.op{.space}{.sem}{.scope}.noftz{.level::cache_hint}. vec_16_bit.half_word_type [a], b{, cache-policy};
+//! red.op{.space}{.sem}{.scope}.noftz{.level::cache_hint}.vec_32_bit.packed_type [a], b {, cache-policy};
+//! .sem =                { .relaxed, .release };
+//! .scope =              { .cta, .cluster, .gpu, .sys };
+//! .op =                 { .add, .min, .max };
+//! .half_word_type =     { .f16, .bf16 };
+//! .packed_type =        { .f16x2,.bf16x2 };
+//! .vec_16_bit =         { .v2, .v4, .v8 };
+//! .vec_32_bit =         { .v2, .v4 };
+//! .level::cache_hint =  { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::red::section_0::*;
+
+    impl Metadata for RedOpSpaceSemScopeLevelCacheHintType {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.op {
+                Op::And => {
+                    f(".and");
+                }
+                Op::Xor => {
+                    f(".xor");
+                }
+                Op::Add => {
+                    f(".add");
+                }
+                Op::Inc => {
+                    f(".inc");
+                }
+                Op::Dec => {
+                    f(".dec");
+                }
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+                Op::Or => {
+                    f(".or");
+                }
+            }
+            if let Some(space_0) = self.space.as_ref() {
+                match space_0 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            if let Some(sem_1) = self.sem.as_ref() {
+                match sem_1 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                }
+            }
+            if let Some(scope_2) = self.scope.as_ref() {
+                match scope_2 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(level_cache_hint_3) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_3 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.txt",
+                section: 0,
+                line: 2,
+                rule: "red.op{.space}{.sem}{.scope}{.level::cache_hint}.type [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for RedAddSpaceSemScopeNoftzLevelCacheHintF16 {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".add");
+            if let Some(space_4) = self.space.as_ref() {
+                match space_4 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            if let Some(sem_5) = self.sem.as_ref() {
+                match sem_5 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                }
+            }
+            if let Some(scope_6) = self.scope.as_ref() {
+                match scope_6 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            f(".noftz");
+            if let Some(level_cache_hint_7) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_7 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.txt",
+                section: 0,
+                line: 3,
+                rule: "red.add{.space}{.sem}{.scope}.noftz{.level::cache_hint}.f16 [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for RedAddSpaceSemScopeNoftzLevelCacheHintF16x2 {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".add");
+            if let Some(space_8) = self.space.as_ref() {
+                match space_8 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            if let Some(sem_9) = self.sem.as_ref() {
+                match sem_9 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                }
+            }
+            if let Some(scope_10) = self.scope.as_ref() {
+                match scope_10 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            f(".noftz");
+            if let Some(level_cache_hint_11) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_11 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.txt",
+                section: 0,
+                line: 4,
+                rule: "red.add{.space}{.sem}{.scope}.noftz{.level::cache_hint}.f16x2 [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for RedAddSpaceSemScopeNoftzLevelCacheHintBf16 {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".add");
+            if let Some(space_12) = self.space.as_ref() {
+                match space_12 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            if let Some(sem_13) = self.sem.as_ref() {
+                match sem_13 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                }
+            }
+            if let Some(scope_14) = self.scope.as_ref() {
+                match scope_14 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            f(".noftz");
+            if let Some(level_cache_hint_15) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_15 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.txt",
+                section: 0,
+                line: 5,
+                rule: "red.add{.space}{.sem}{.scope}.noftz{.level::cache_hint}.bf16 [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for RedAddSpaceSemScopeNoftzLevelCacheHintBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".add");
+            if let Some(space_16) = self.space.as_ref() {
+                match space_16 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            if let Some(sem_17) = self.sem.as_ref() {
+                match sem_17 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                }
+            }
+            if let Some(scope_18) = self.scope.as_ref() {
+                match scope_18 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            f(".noftz");
+            if let Some(level_cache_hint_19) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_19 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.txt",
+                section: 0,
+                line: 6,
+                rule: "red.add{.space}{.sem}{.scope}.noftz{.level::cache_hint}.bf16x2 [a], b{, cache-policy};",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::red::section_1::*;
+
+    impl Metadata for RedAddSpaceSemScopeLevelCacheHintVec32BitF32 {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".add");
+            if let Some(space_20) = self.space.as_ref() {
+                match space_20 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            if let Some(sem_21) = self.sem.as_ref() {
+                match sem_21 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                }
+            }
+            if let Some(scope_22) = self.scope.as_ref() {
+                match scope_22 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            if let Some(level_cache_hint_23) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_23 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            match &self.vec_32_bit {
+                Vec32Bit::V2 => {
+                    f(".v2");
+                }
+                Vec32Bit::V4 => {
+                    f(".v4");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.txt",
+                section: 1,
+                line: 15,
+                rule: "red.add{.space}{.sem}{.scope}{.level::cache_hint}.vec_32_bit.f32 [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for RedOpSpaceSemScopeNoftzLevelCacheHintVec16BitHalfWordType {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.op {
+                Op::Add => {
+                    f(".add");
+                }
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+            }
+            if let Some(space_24) = self.space.as_ref() {
+                match space_24 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            if let Some(sem_25) = self.sem.as_ref() {
+                match sem_25 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                }
+            }
+            if let Some(scope_26) = self.scope.as_ref() {
+                match scope_26 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            f(".noftz");
+            if let Some(level_cache_hint_27) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_27 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            match &self.vec_16_bit {
+                Vec16Bit::V2 => {
+                    f(".v2");
+                }
+                Vec16Bit::V4 => {
+                    f(".v4");
+                }
+                Vec16Bit::V8 => {
+                    f(".v8");
+                }
+            }
+            match &self.half_word_type {
+                HalfWordType::Bf16 => {
+                    f(".bf16");
+                }
+                HalfWordType::F16 => {
+                    f(".f16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.txt",
+                section: 1,
+                line: 16,
+                rule: "red.op{.space}{.sem}{.scope}.noftz{.level::cache_hint}. vec_16_bit.half_word_type [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for RedOpSpaceSemScopeNoftzLevelCacheHintVec32BitPackedType {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.op {
+                Op::Add => {
+                    f(".add");
+                }
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+            }
+            if let Some(space_28) = self.space.as_ref() {
+                match space_28 {
+                    Space::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Space::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Space::Global => {
+                        f(".global");
+                    }
+                    Space::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            if let Some(sem_29) = self.sem.as_ref() {
+                match sem_29 {
+                    Sem::Relaxed => {
+                        f(".relaxed");
+                    }
+                    Sem::Release => {
+                        f(".release");
+                    }
+                }
+            }
+            if let Some(scope_30) = self.scope.as_ref() {
+                match scope_30 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                    Scope::Cta => {
+                        f(".cta");
+                    }
+                    Scope::Gpu => {
+                        f(".gpu");
+                    }
+                    Scope::Sys => {
+                        f(".sys");
+                    }
+                }
+            }
+            f(".noftz");
+            if let Some(level_cache_hint_31) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_31 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            match &self.vec_32_bit {
+                Vec32Bit::V2 => {
+                    f(".v2");
+                }
+                Vec32Bit::V4 => {
+                    f(".v4");
+                }
+            }
+            match &self.packed_type {
+                PackedType::Bf16x2 => {
+                    f(".bf16x2");
+                }
+                PackedType::F16x2 => {
+                    f(".f16x2");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.txt",
+                section: 1,
+                line: 17,
+                rule: "red.op{.space}{.sem}{.scope}.noftz{.level::cache_hint}.vec_32_bit.packed_type [a], b {, cache-policy};",
+            }
+        }
+    }
+}
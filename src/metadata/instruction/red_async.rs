@@ -0,0 +1,340 @@
+//! Original PTX specification:
+//!
+//! // Increment and Decrement reductions
+//! red.async.sem.scope{.ss}.completion_mechanism.op.type [a], b, [mbar];
+//! .sem  =                 { .relaxed };
+//! .scope =                { .cluster };
+//! .ss   =                 { .shared::cluster };
+//! .op   =                 { .inc, .dec };
+//! .type =                 { .u32 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ------------------------------------------------------------------
+//! // MIN and MAX reductions
+//! red.async.sem.scope{.ss}.completion_mechanism.op.type [a], b, [mbar];
+//! .sem  = { .relaxed };
+//! .scope = { .cluster };
+//! .ss   = { .shared::cluster };
+//! .op   = { .min, .max };
+//! .type = { .u32, .s32 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ------------------------------------------------------------------
+//! // Bitwise AND, OR and XOR reductions
+//! red.async.sem.scope{.ss}.completion_mechanism.op.type [a], b, [mbar];
+//! .sem  = { .relaxed };
+//! .scope = { .cluster };
+//! .ss   = { .shared::cluster };
+//! .op   = { .and, .or, .xor };
+//! .type = { .b32 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ------------------------------------------------------------------
+//! // ADD reductions
+//! red.async.sem.scope{.ss}.completion_mechanism.add.type [a], b, [mbar];
+//! .sem  = { .relaxed };
+//! .scope = { .cluster };
+//! .ss   = { .shared::cluster };
+//! .type = { .u32, .s32, .u64 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! red.async{.mmio}.sem.scope{.ss}.add.type [a], b;
+//! .sem  = { .release };
+//! .scope = { .gpu, .cluster };
+//! .ss   = { .global };
+//! .type = { .u32, .s32, .u64, .s64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_0::*;
+
+    impl Metadata for RedAsyncSemScopeSsCompletionMechanismOpType {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            match &self.sem {
+                Sem::Relaxed => {
+                    f(".relaxed");
+                }
+            }
+            match &self.scope {
+                Scope::Cluster => {
+                    f(".cluster");
+                }
+            }
+            if let Some(ss_0) = self.ss.as_ref() {
+                match ss_0 {
+                    Ss::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierCompleteTxBytes => {
+                    f(".mbarrier::complete_tx::bytes");
+                }
+            }
+            match &self.op {
+                Op::Inc => {
+                    f(".inc");
+                }
+                Op::Dec => {
+                    f(".dec");
+                }
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.async.txt",
+                section: 0,
+                line: 2,
+                rule: "red.async.sem.scope{.ss}.completion_mechanism.op.type [a], b, [mbar];",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_1::*;
+
+    impl Metadata for RedAsyncSemScopeSsCompletionMechanismOpType1 {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            match &self.sem {
+                Sem::Relaxed => {
+                    f(".relaxed");
+                }
+            }
+            match &self.scope {
+                Scope::Cluster => {
+                    f(".cluster");
+                }
+            }
+            if let Some(ss_1) = self.ss.as_ref() {
+                match ss_1 {
+                    Ss::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierCompleteTxBytes => {
+                    f(".mbarrier::complete_tx::bytes");
+                }
+            }
+            match &self.op {
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.async.txt",
+                section: 1,
+                line: 11,
+                rule: "red.async.sem.scope{.ss}.completion_mechanism.op.type [a], b, [mbar];",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_2::*;
+
+    impl Metadata for RedAsyncSemScopeSsCompletionMechanismOpType2 {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            match &self.sem {
+                Sem::Relaxed => {
+                    f(".relaxed");
+                }
+            }
+            match &self.scope {
+                Scope::Cluster => {
+                    f(".cluster");
+                }
+            }
+            if let Some(ss_2) = self.ss.as_ref() {
+                match ss_2 {
+                    Ss::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierCompleteTxBytes => {
+                    f(".mbarrier::complete_tx::bytes");
+                }
+            }
+            match &self.op {
+                Op::And => {
+                    f(".and");
+                }
+                Op::Xor => {
+                    f(".xor");
+                }
+                Op::Or => {
+                    f(".or");
+                }
+            }
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.async.txt",
+                section: 2,
+                line: 20,
+                rule: "red.async.sem.scope{.ss}.completion_mechanism.op.type [a], b, [mbar];",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_3::*;
+
+    impl Metadata for RedAsyncSemScopeSsCompletionMechanismAddType {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            match &self.sem {
+                Sem::Relaxed => {
+                    f(".relaxed");
+                }
+            }
+            match &self.scope {
+                Scope::Cluster => {
+                    f(".cluster");
+                }
+            }
+            if let Some(ss_3) = self.ss.as_ref() {
+                match ss_3 {
+                    Ss::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierCompleteTxBytes => {
+                    f(".mbarrier::complete_tx::bytes");
+                }
+            }
+            f(".add");
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.async.txt",
+                section: 3,
+                line: 29,
+                rule: "red.async.sem.scope{.ss}.completion_mechanism.add.type [a], b, [mbar];",
+            }
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_4::*;
+
+    impl Metadata for RedAsyncMmioSemScopeSsAddType {
+        fn opcode(&self) -> &'static str {
+            "red"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            if self.mmio {
+                f(".mmio");
+            }
+            match &self.sem {
+                Sem::Release => {
+                    f(".release");
+                }
+            }
+            match &self.scope {
+                Scope::Cluster => {
+                    f(".cluster");
+                }
+                Scope::Gpu => {
+                    f(".gpu");
+                }
+            }
+            if let Some(ss_4) = self.ss.as_ref() {
+                match ss_4 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            f(".add");
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "red.async.txt",
+                section: 4,
+                line: 37,
+                rule: "red.async{.mmio}.sem.scope{.ss}.add.type [a], b;",
+            }
+        }
+    }
+}
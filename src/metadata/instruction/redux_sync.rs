@@ -0,0 +1,129 @@
+//! Original PTX specification:
+//!
+//! redux.sync.op.type dst, src, membermask;
+//! .op   = {.add, .min, .max};
+//! .type = {.u32, .s32};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! redux.sync.op.b32 dst, src, membermask;
+//! .op   = {.and, .or, .xor};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! redux.sync.op{.abs}{.NaN}.f32 dst, src, membermask;
+//! .op   = { .min, .max };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::redux_sync::section_0::*;
+
+    impl Metadata for ReduxSyncOpType {
+        fn opcode(&self) -> &'static str {
+            "redux"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            match &self.op {
+                Op::Add => {
+                    f(".add");
+                }
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "redux.sync.txt",
+                section: 0,
+                line: 1,
+                rule: "redux.sync.op.type dst, src, membermask;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::redux_sync::section_1::*;
+
+    impl Metadata for ReduxSyncOpB32 {
+        fn opcode(&self) -> &'static str {
+            "redux"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            match &self.op {
+                Op::And => {
+                    f(".and");
+                }
+                Op::Xor => {
+                    f(".xor");
+                }
+                Op::Or => {
+                    f(".or");
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "redux.sync.txt",
+                section: 1,
+                line: 6,
+                rule: "redux.sync.op.b32 dst, src, membermask;",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::redux_sync::section_2::*;
+
+    impl Metadata for ReduxSyncOpAbsNanF32 {
+        fn opcode(&self) -> &'static str {
+            "redux"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            match &self.op {
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+            }
+            if self.abs {
+                f(".abs");
+            }
+            if self.nan {
+                f(".NaN");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "redux.sync.txt",
+                section: 2,
+                line: 10,
+                rule: "redux.sync.op{.abs}{.NaN}.f32 dst, src, membermask;",
+            }
+        }
+    }
+}
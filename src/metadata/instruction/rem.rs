@@ -0,0 +1,49 @@
+//! Original PTX specification:
+//!
+//! rem.type  d, a, b;
+//! .type = { .u16, .u32, .u64, .s16, .s32, .s64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::rem::section_0::*;
+
+    impl Metadata for RemType {
+        fn opcode(&self) -> &'static str {
+            "rem"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "rem.txt",
+                section: 0,
+                line: 1,
+                rule: "rem.type d, a, b;",
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Original PTX specification:
+//!
+//! ret{.uni};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::ret::section_0::*;
+
+    impl Metadata for RetUni {
+        fn opcode(&self) -> &'static str {
+            "ret"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.uni {
+                f(".uni");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "ret.txt",
+                section: 0,
+                line: 1,
+                rule: "ret{.uni};",
+            }
+        }
+    }
+}
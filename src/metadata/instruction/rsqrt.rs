@@ -0,0 +1,52 @@
+//! Original PTX specification:
+//!
+//! rsqrt.approx{.ftz}.f32  d, a;
+//! rsqrt.approx.f64        d, a;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::rsqrt::section_0::*;
+
+    impl Metadata for RsqrtApproxFtzF32 {
+        fn opcode(&self) -> &'static str {
+            "rsqrt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".approx");
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "rsqrt.txt",
+                section: 0,
+                line: 1,
+                rule: "rsqrt.approx{.ftz}.f32 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for RsqrtApproxF64 {
+        fn opcode(&self) -> &'static str {
+            "rsqrt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".approx");
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "rsqrt.txt",
+                section: 0,
+                line: 2,
+                rule: "rsqrt.approx.f64 d, a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Original PTX specification:
+//!
+//! rsqrt.approx.ftz.f64 d, a;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::rsqrt_approx_ftz_f64::section_0::*;
+
+    impl Metadata for RsqrtApproxFtzF64 {
+        fn opcode(&self) -> &'static str {
+            "rsqrt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".approx");
+            f(".ftz");
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "rsqrt.approx.ftz.f64.txt",
+                section: 0,
+                line: 1,
+                rule: "rsqrt.approx.ftz.f64 d, a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Original PTX specification:
+//!
+//! sad.type  d, a, b, c;
+//! .type = { .u16, .u32, .u64, .s16, .s32, .s64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::sad::section_0::*;
+
+    impl Metadata for SadType {
+        fn opcode(&self) -> &'static str {
+            "sad"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sad.txt",
+                section: 0,
+                line: 1,
+                rule: "sad.type d, a, b, c;",
+            }
+        }
+    }
+}
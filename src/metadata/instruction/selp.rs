@@ -0,0 +1,67 @@
+//! Original PTX specification:
+//!
+//! selp.type d, a, b, c;
+//! .type = { .b16, .b32, .b64,
+//! .u16, .u32, .u64,
+//! .s16, .s32, .s64,
+//! .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::selp::section_0::*;
+
+    impl Metadata for SelpType {
+        fn opcode(&self) -> &'static str {
+            "selp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "selp.txt",
+                section: 0,
+                line: 1,
+                rule: "selp.type d, a, b, c;",
+            }
+        }
+    }
+}
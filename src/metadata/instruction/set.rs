@@ -0,0 +1,1500 @@
+//! Original PTX specification:
+//!
+//! set.CmpOp{.ftz}.dtype.stype         d, a, b;
+//! set.CmpOp.BoolOp{.ftz}.dtype.stype  d, a, b, {!}c;
+//! .CmpOp  = { .eq, .ne, .lt, .le, .gt, .ge, .lo, .ls, .hi, .hs,
+//! .equ, .neu, .ltu, .leu, .gtu, .geu, .num, .nan };
+//! .BoolOp = { .and, .or, .xor };
+//! .dtype  = { .u32, .s32, .f32 };
+//! .stype  = { .b16, .b32, .b64,
+//! .u16, .u32, .u64,
+//! .s16, .s32, .s64,
+//! .f32, .f64 };
+//! -------------------------------------------------------------
+//! set.CmpOp{.ftz}.f16.stype            d, a, b;
+//! set.CmpOp.BoolOp{.ftz}.f16.stype     d, a, b, {!}c;
+//! set.CmpOp.bf16.stype                 d, a, b;
+//! set.CmpOp.BoolOp.bf16.stype          d, a, b, {!}c;
+//! set.CmpOp{.ftz}.dtype.f16            d, a, b;
+//! set.CmpOp.BoolOp{.ftz}.dtype.f16     d, a, b, {!}c;
+//! .dtype  = { .u16, .s16, .u32, .s32};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! set.CmpOp.dtype.bf16                 d, a, b;
+//! set.CmpOp.BoolOp.dtype.bf16          d, a, b, {!}c;
+//! .dtype  = { .u16, .s16, .u32, .s32};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! set.CmpOp{.ftz}.dtype.f16x2          d, a, b;
+//! set.CmpOp.BoolOp{.ftz}.dtype.f16x2   d, a, b, {!}c;
+//! .dtype  = { .f16x2, .u32, .s32};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! set.CmpOp.dtype.bf16x2               d, a, b;
+//! set.CmpOp.BoolOp.dtype.bf16x2        d, a, b, {!}c;
+//! .dtype  = { .bf16x2, .u32, .s32};
+//! .CmpOp  = { .eq, .ne, .lt, .le, .gt, .ge,
+//! .equ, .neu, .ltu, .leu, .gtu, .geu, .num, .nan };
+//! .BoolOp = { .and, .or, .xor };
+//! .stype  = { .b16, .b32, .b64,
+//! .u16, .u32, .u64,
+//! .s16, .s32, .s64,
+//! .f16, .f32, .f64};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::set::section_0::*;
+
+    impl Metadata for SetCmpopFtzDtypeStype {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.stype {
+                Stype::B16 => {
+                    f(".b16");
+                }
+                Stype::B32 => {
+                    f(".b32");
+                }
+                Stype::B64 => {
+                    f(".b64");
+                }
+                Stype::U16 => {
+                    f(".u16");
+                }
+                Stype::U32 => {
+                    f(".u32");
+                }
+                Stype::U64 => {
+                    f(".u64");
+                }
+                Stype::S16 => {
+                    f(".s16");
+                }
+                Stype::S32 => {
+                    f(".s32");
+                }
+                Stype::S64 => {
+                    f(".s64");
+                }
+                Stype::F32 => {
+                    f(".f32");
+                }
+                Stype::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 0,
+                line: 1,
+                rule: "set.CmpOp{.ftz}.dtype.stype d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SetCmpopBoolopFtzDtypeStype {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Xor => {
+                    f(".xor");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.stype {
+                Stype::B16 => {
+                    f(".b16");
+                }
+                Stype::B32 => {
+                    f(".b32");
+                }
+                Stype::B64 => {
+                    f(".b64");
+                }
+                Stype::U16 => {
+                    f(".u16");
+                }
+                Stype::U32 => {
+                    f(".u32");
+                }
+                Stype::U64 => {
+                    f(".u64");
+                }
+                Stype::S16 => {
+                    f(".s16");
+                }
+                Stype::S32 => {
+                    f(".s32");
+                }
+                Stype::S64 => {
+                    f(".s64");
+                }
+                Stype::F32 => {
+                    f(".f32");
+                }
+                Stype::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 0,
+                line: 2,
+                rule: "set.CmpOp.BoolOp{.ftz}.dtype.stype d, a, b, {!}c;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::set::section_1::*;
+
+    impl Metadata for SetCmpopFtzF16Stype {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f16");
+            match &self.stype {
+                Stype::B16 => {
+                    f(".b16");
+                }
+                Stype::B32 => {
+                    f(".b32");
+                }
+                Stype::B64 => {
+                    f(".b64");
+                }
+                Stype::U16 => {
+                    f(".u16");
+                }
+                Stype::U32 => {
+                    f(".u32");
+                }
+                Stype::U64 => {
+                    f(".u64");
+                }
+                Stype::S16 => {
+                    f(".s16");
+                }
+                Stype::S32 => {
+                    f(".s32");
+                }
+                Stype::S64 => {
+                    f(".s64");
+                }
+                Stype::F32 => {
+                    f(".f32");
+                }
+                Stype::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 1,
+                line: 12,
+                rule: "set.CmpOp{.ftz}.f16.stype d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SetCmpopBoolopFtzF16Stype {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Xor => {
+                    f(".xor");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f16");
+            match &self.stype {
+                Stype::B16 => {
+                    f(".b16");
+                }
+                Stype::B32 => {
+                    f(".b32");
+                }
+                Stype::B64 => {
+                    f(".b64");
+                }
+                Stype::U16 => {
+                    f(".u16");
+                }
+                Stype::U32 => {
+                    f(".u32");
+                }
+                Stype::U64 => {
+                    f(".u64");
+                }
+                Stype::S16 => {
+                    f(".s16");
+                }
+                Stype::S32 => {
+                    f(".s32");
+                }
+                Stype::S64 => {
+                    f(".s64");
+                }
+                Stype::F32 => {
+                    f(".f32");
+                }
+                Stype::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 1,
+                line: 13,
+                rule: "set.CmpOp.BoolOp{.ftz}.f16.stype d, a, b, {!}c;",
+            }
+        }
+    }
+
+    impl Metadata for SetCmpopBf16Stype {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            f(".bf16");
+            match &self.stype {
+                Stype::B16 => {
+                    f(".b16");
+                }
+                Stype::B32 => {
+                    f(".b32");
+                }
+                Stype::B64 => {
+                    f(".b64");
+                }
+                Stype::U16 => {
+                    f(".u16");
+                }
+                Stype::U32 => {
+                    f(".u32");
+                }
+                Stype::U64 => {
+                    f(".u64");
+                }
+                Stype::S16 => {
+                    f(".s16");
+                }
+                Stype::S32 => {
+                    f(".s32");
+                }
+                Stype::S64 => {
+                    f(".s64");
+                }
+                Stype::F32 => {
+                    f(".f32");
+                }
+                Stype::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 1,
+                line: 14,
+                rule: "set.CmpOp.bf16.stype d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SetCmpopBoolopBf16Stype {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Xor => {
+                    f(".xor");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            f(".bf16");
+            match &self.stype {
+                Stype::B16 => {
+                    f(".b16");
+                }
+                Stype::B32 => {
+                    f(".b32");
+                }
+                Stype::B64 => {
+                    f(".b64");
+                }
+                Stype::U16 => {
+                    f(".u16");
+                }
+                Stype::U32 => {
+                    f(".u32");
+                }
+                Stype::U64 => {
+                    f(".u64");
+                }
+                Stype::S16 => {
+                    f(".s16");
+                }
+                Stype::S32 => {
+                    f(".s32");
+                }
+                Stype::S64 => {
+                    f(".s64");
+                }
+                Stype::F32 => {
+                    f(".f32");
+                }
+                Stype::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 1,
+                line: 15,
+                rule: "set.CmpOp.BoolOp.bf16.stype d, a, b, {!}c;",
+            }
+        }
+    }
+
+    impl Metadata for SetCmpopFtzDtypeF16 {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            match &self.dtype {
+                Dtype::U16 => {
+                    f(".u16");
+                }
+                Dtype::S16 => {
+                    f(".s16");
+                }
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 1,
+                line: 16,
+                rule: "set.CmpOp{.ftz}.dtype.f16 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SetCmpopBoolopFtzDtypeF16 {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Xor => {
+                    f(".xor");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            match &self.dtype {
+                Dtype::U16 => {
+                    f(".u16");
+                }
+                Dtype::S16 => {
+                    f(".s16");
+                }
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 1,
+                line: 17,
+                rule: "set.CmpOp.BoolOp{.ftz}.dtype.f16 d, a, b, {!}c;",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::set::section_2::*;
+
+    impl Metadata for SetCmpopDtypeBf16 {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            match &self.dtype {
+                Dtype::U16 => {
+                    f(".u16");
+                }
+                Dtype::S16 => {
+                    f(".s16");
+                }
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 2,
+                line: 21,
+                rule: "set.CmpOp.dtype.bf16 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SetCmpopBoolopDtypeBf16 {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Xor => {
+                    f(".xor");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            match &self.dtype {
+                Dtype::U16 => {
+                    f(".u16");
+                }
+                Dtype::S16 => {
+                    f(".s16");
+                }
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 2,
+                line: 22,
+                rule: "set.CmpOp.BoolOp.dtype.bf16 d, a, b, {!}c;",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::set::section_3::*;
+
+    impl Metadata for SetCmpopFtzDtypeF16x2 {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            match &self.dtype {
+                Dtype::F16x2 => {
+                    f(".f16x2");
+                }
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 3,
+                line: 26,
+                rule: "set.CmpOp{.ftz}.dtype.f16x2 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SetCmpopBoolopFtzDtypeF16x2 {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Xor => {
+                    f(".xor");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            match &self.dtype {
+                Dtype::F16x2 => {
+                    f(".f16x2");
+                }
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 3,
+                line: 27,
+                rule: "set.CmpOp.BoolOp{.ftz}.dtype.f16x2 d, a, b, {!}c;",
+            }
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::set::section_4::*;
+
+    impl Metadata for SetCmpopDtypeBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+            }
+            match &self.dtype {
+                Dtype::Bf16x2 => {
+                    f(".bf16x2");
+                }
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 4,
+                line: 31,
+                rule: "set.CmpOp.dtype.bf16x2 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SetCmpopBoolopDtypeBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "set"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+            }
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Xor => {
+                    f(".xor");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            match &self.dtype {
+                Dtype::Bf16x2 => {
+                    f(".bf16x2");
+                }
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "set.txt",
+                section: 4,
+                line: 32,
+                rule: "set.CmpOp.BoolOp.dtype.bf16x2 d, a, b, {!}c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Original PTX specification:
+//!
+//! setmaxnreg.action.sync.aligned.u32 imm-reg-count;
+//! .action = { .inc, .dec };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::setmaxnreg::section_0::*;
+
+    impl Metadata for SetmaxnregActionSyncAlignedU32 {
+        fn opcode(&self) -> &'static str {
+            "setmaxnreg"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.action {
+                Action::Inc => {
+                    f(".inc");
+                }
+                Action::Dec => {
+                    f(".dec");
+                }
+            }
+            f(".sync");
+            f(".aligned");
+            f(".u32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "setmaxnreg.txt",
+                section: 0,
+                line: 1,
+                rule: "setmaxnreg.action.sync.aligned.u32 imm-reg-count;",
+            }
+        }
+    }
+}
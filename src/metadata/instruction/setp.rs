@@ -0,0 +1,807 @@
+//! Original PTX specification:
+//!
+//! setp.CmpOp{.ftz}.type         p{|q}, a, b;
+//! setp.CmpOp.BoolOp{.ftz}.type  p{|q}, a, b, {!}c;
+//! .CmpOp  = { .eq, .ne, .lt, .le, .gt, .ge, .lo, .ls, .hi, .hs, .equ, .neu, .ltu, .leu, .gtu, .geu, .num, .nan };
+//! .BoolOp = { .and, .or, .xor };
+//! .type   = { .b16, .b32, .b64, .u16, .u32, .u64, .s16, .s32, .s64, .f32, .f64 };
+//! --------------------------------------------------------------
+//! setp.CmpOp{.ftz}.f16           p, a, b;
+//! setp.CmpOp.BoolOp{.ftz}.f16    p, a, b, {!}c;
+//! setp.CmpOp{.ftz}.f16x2         p|q, a, b;
+//! setp.CmpOp.BoolOp{.ftz}.f16x2  p|q, a, b, {!}c;
+//! setp.CmpOp.bf16                p, a, b;
+//! setp.CmpOp.BoolOp.bf16         p, a, b, {!}c;
+//! setp.CmpOp.bf16x2              p|q, a, b;
+//! setp.CmpOp.BoolOp.bf16x2       p|q, a, b, {!}c;
+//! .CmpOp  = { .eq, .ne, .lt, .le, .gt, .ge, .equ, .neu, .ltu, .leu, .gtu, .geu, .num, .nan };
+//! .BoolOp = { .and, .or, .xor };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::setp::section_0::*;
+
+    impl Metadata for SetpCmpopFtzType {
+        fn opcode(&self) -> &'static str {
+            "setp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            match &self.type_ {
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "setp.txt",
+                section: 0,
+                line: 1,
+                rule: "setp.CmpOp{.ftz}.type p{|q}, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SetpCmpopBoolopFtzType {
+        fn opcode(&self) -> &'static str {
+            "setp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+                Cmpop::Lo => {
+                    f(".lo");
+                }
+                Cmpop::Ls => {
+                    f(".ls");
+                }
+                Cmpop::Hi => {
+                    f(".hi");
+                }
+                Cmpop::Hs => {
+                    f(".hs");
+                }
+            }
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Xor => {
+                    f(".xor");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            match &self.type_ {
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "setp.txt",
+                section: 0,
+                line: 2,
+                rule: "setp.CmpOp.BoolOp{.ftz}.type p{|q}, a, b, {!}c;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::setp::section_1::*;
+
+    impl Metadata for SetpCmpopFtzF16 {
+        fn opcode(&self) -> &'static str {
+            "setp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "setp.txt",
+                section: 1,
+                line: 7,
+                rule: "setp.CmpOp{.ftz}.f16 p, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SetpCmpopBoolopFtzF16 {
+        fn opcode(&self) -> &'static str {
+            "setp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+            }
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Xor => {
+                    f(".xor");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "setp.txt",
+                section: 1,
+                line: 8,
+                rule: "setp.CmpOp.BoolOp{.ftz}.f16 p, a, b, {!}c;",
+            }
+        }
+    }
+
+    impl Metadata for SetpCmpopFtzF16x2 {
+        fn opcode(&self) -> &'static str {
+            "setp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "setp.txt",
+                section: 1,
+                line: 9,
+                rule: "setp.CmpOp{.ftz}.f16x2 p|q, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SetpCmpopBoolopFtzF16x2 {
+        fn opcode(&self) -> &'static str {
+            "setp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+            }
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Xor => {
+                    f(".xor");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "setp.txt",
+                section: 1,
+                line: 10,
+                rule: "setp.CmpOp.BoolOp{.ftz}.f16x2 p|q, a, b, {!}c;",
+            }
+        }
+    }
+
+    impl Metadata for SetpCmpopBf16 {
+        fn opcode(&self) -> &'static str {
+            "setp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+            }
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "setp.txt",
+                section: 1,
+                line: 11,
+                rule: "setp.CmpOp.bf16 p, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SetpCmpopBoolopBf16 {
+        fn opcode(&self) -> &'static str {
+            "setp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+            }
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Xor => {
+                    f(".xor");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "setp.txt",
+                section: 1,
+                line: 12,
+                rule: "setp.CmpOp.BoolOp.bf16 p, a, b, {!}c;",
+            }
+        }
+    }
+
+    impl Metadata for SetpCmpopBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "setp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+            }
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "setp.txt",
+                section: 1,
+                line: 13,
+                rule: "setp.CmpOp.bf16x2 p|q, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SetpCmpopBoolopBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "setp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.cmpop {
+                Cmpop::Equ => {
+                    f(".equ");
+                }
+                Cmpop::Neu => {
+                    f(".neu");
+                }
+                Cmpop::Ltu => {
+                    f(".ltu");
+                }
+                Cmpop::Leu => {
+                    f(".leu");
+                }
+                Cmpop::Gtu => {
+                    f(".gtu");
+                }
+                Cmpop::Geu => {
+                    f(".geu");
+                }
+                Cmpop::Num => {
+                    f(".num");
+                }
+                Cmpop::Nan => {
+                    f(".nan");
+                }
+                Cmpop::Eq => {
+                    f(".eq");
+                }
+                Cmpop::Ne => {
+                    f(".ne");
+                }
+                Cmpop::Lt => {
+                    f(".lt");
+                }
+                Cmpop::Le => {
+                    f(".le");
+                }
+                Cmpop::Gt => {
+                    f(".gt");
+                }
+                Cmpop::Ge => {
+                    f(".ge");
+                }
+            }
+            match &self.boolop {
+                Boolop::And => {
+                    f(".and");
+                }
+                Boolop::Xor => {
+                    f(".xor");
+                }
+                Boolop::Or => {
+                    f(".or");
+                }
+            }
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "setp.txt",
+                section: 1,
+                line: 14,
+                rule: "setp.CmpOp.BoolOp.bf16x2 p|q, a, b, {!}c;",
+            }
+        }
+    }
+}
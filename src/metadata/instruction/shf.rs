@@ -0,0 +1,66 @@
+//! Original PTX specification:
+//!
+//! shf.l.mode.b32  d, a, b, c;  // left shift
+//! shf.r.mode.b32  d, a, b, c;  // right shift
+//! .mode = { .clamp, .wrap };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::shf::section_0::*;
+
+    impl Metadata for ShfLModeB32 {
+        fn opcode(&self) -> &'static str {
+            "shf"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".l");
+            match &self.mode {
+                Mode::Clamp => {
+                    f(".clamp");
+                }
+                Mode::Wrap => {
+                    f(".wrap");
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "shf.txt",
+                section: 0,
+                line: 1,
+                rule: "shf.l.mode.b32 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for ShfRModeB32 {
+        fn opcode(&self) -> &'static str {
+            "shf"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".r");
+            match &self.mode {
+                Mode::Clamp => {
+                    f(".clamp");
+                }
+                Mode::Wrap => {
+                    f(".wrap");
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "shf.txt",
+                section: 0,
+                line: 2,
+                rule: "shf.r.mode.b32 d, a, b, c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,44 @@
+//! Original PTX specification:
+//!
+//! shfl.mode.b32  d{|p}, a, b, c;
+//! .mode = { .up, .down, .bfly, .idx };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::shfl::section_0::*;
+
+    impl Metadata for ShflModeB32 {
+        fn opcode(&self) -> &'static str {
+            "shfl"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.mode {
+                Mode::Down => {
+                    f(".down");
+                }
+                Mode::Bfly => {
+                    f(".bfly");
+                }
+                Mode::Idx => {
+                    f(".idx");
+                }
+                Mode::Up => {
+                    f(".up");
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "shfl.txt",
+                section: 0,
+                line: 1,
+                rule: "shfl.mode.b32 d{|p}, a, b, c;",
+            }
+        }
+    }
+}
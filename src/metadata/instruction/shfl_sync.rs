@@ -0,0 +1,45 @@
+//! Original PTX specification:
+//!
+//! shfl.sync.mode.b32  d{|p}, a, b, c, membermask;
+//! .mode = { .up, .down, .bfly, .idx };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::shfl_sync::section_0::*;
+
+    impl Metadata for ShflSyncModeB32 {
+        fn opcode(&self) -> &'static str {
+            "shfl"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            match &self.mode {
+                Mode::Down => {
+                    f(".down");
+                }
+                Mode::Bfly => {
+                    f(".bfly");
+                }
+                Mode::Idx => {
+                    f(".idx");
+                }
+                Mode::Up => {
+                    f(".up");
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "shfl.sync.txt",
+                section: 0,
+                line: 1,
+                rule: "shfl.sync.mode.b32 d{|p}, a, b, c, membermask;",
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Original PTX specification:
+//!
+//! shl.type d, a, b;
+//! .type = { .b16, .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::shl::section_0::*;
+
+    impl Metadata for ShlType {
+        fn opcode(&self) -> &'static str {
+            "shl"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "shl.txt",
+                section: 0,
+                line: 1,
+                rule: "shl.type d, a, b;",
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Original PTX specification:
+//!
+//! shr.type d, a, b;
+//! .type = { .b16, .b32, .b64,
+//! .u16, .u32, .u64,
+//! .s16, .s32, .s64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::shr::section_0::*;
+
+    impl Metadata for ShrType {
+        fn opcode(&self) -> &'static str {
+            "shr"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "shr.txt",
+                section: 0,
+                line: 1,
+                rule: "shr.type d, a, b;",
+            }
+        }
+    }
+}
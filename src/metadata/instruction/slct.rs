@@ -0,0 +1,124 @@
+//! Original PTX specification:
+//!
+//! slct.dtype.s32        d, a, b, c;
+//! slct{.ftz}.dtype.f32  d, a, b, c;
+//! .dtype = { .b16, .b32, .b64,
+//! .u16, .u32, .u64,
+//! .s16, .s32, .s64,
+//! .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::slct::section_0::*;
+
+    impl Metadata for SlctDtypeS32 {
+        fn opcode(&self) -> &'static str {
+            "slct"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::B16 => {
+                    f(".b16");
+                }
+                Dtype::B32 => {
+                    f(".b32");
+                }
+                Dtype::B64 => {
+                    f(".b64");
+                }
+                Dtype::U16 => {
+                    f(".u16");
+                }
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::U64 => {
+                    f(".u64");
+                }
+                Dtype::S16 => {
+                    f(".s16");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::S64 => {
+                    f(".s64");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+                Dtype::F64 => {
+                    f(".f64");
+                }
+            }
+            f(".s32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "slct.txt",
+                section: 0,
+                line: 1,
+                rule: "slct.dtype.s32 d, a, b, c;",
+            }
+        }
+    }
+
+    impl Metadata for SlctFtzDtypeF32 {
+        fn opcode(&self) -> &'static str {
+            "slct"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.ftz {
+                f(".ftz");
+            }
+            match &self.dtype {
+                Dtype::B16 => {
+                    f(".b16");
+                }
+                Dtype::B32 => {
+                    f(".b32");
+                }
+                Dtype::B64 => {
+                    f(".b64");
+                }
+                Dtype::U16 => {
+                    f(".u16");
+                }
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::U64 => {
+                    f(".u64");
+                }
+                Dtype::S16 => {
+                    f(".s16");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::S64 => {
+                    f(".s64");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+                Dtype::F64 => {
+                    f(".f64");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "slct.txt",
+                section: 0,
+                line: 2,
+                rule: "slct{.ftz}.dtype.f32 d, a, b, c;",
+            }
+        }
+    }
+}
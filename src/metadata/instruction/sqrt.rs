@@ -0,0 +1,101 @@
+//! Original PTX specification:
+//!
+//! sqrt.approx{.ftz}.f32  d, a; // fast, approximate square root
+//! sqrt.rnd{.ftz}.f32     d, a; // IEEE 754 compliant rounding
+//! sqrt.rnd.f64           d, a; // IEEE 754 compliant rounding
+//! .rnd = { .rn, .rz, .rm, .rp };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::sqrt::section_0::*;
+
+    impl Metadata for SqrtApproxFtzF32 {
+        fn opcode(&self) -> &'static str {
+            "sqrt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".approx");
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sqrt.txt",
+                section: 0,
+                line: 1,
+                rule: "sqrt.approx{.ftz}.f32 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for SqrtRndFtzF32 {
+        fn opcode(&self) -> &'static str {
+            "sqrt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+                Rnd::Rz => {
+                    f(".rz");
+                }
+                Rnd::Rm => {
+                    f(".rm");
+                }
+                Rnd::Rp => {
+                    f(".rp");
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sqrt.txt",
+                section: 0,
+                line: 2,
+                rule: "sqrt.rnd{.ftz}.f32 d, a;",
+            }
+        }
+    }
+
+    impl Metadata for SqrtRndF64 {
+        fn opcode(&self) -> &'static str {
+            "sqrt"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.rnd {
+                Rnd::Rn => {
+                    f(".rn");
+                }
+                Rnd::Rz => {
+                    f(".rz");
+                }
+                Rnd::Rm => {
+                    f(".rm");
+                }
+                Rnd::Rp => {
+                    f(".rp");
+                }
+            }
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sqrt.txt",
+                section: 0,
+                line: 3,
+                rule: "sqrt.rnd.f64 d, a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,785 @@
+//! Original PTX specification:
+//!
+//! st{.weak}{.ss}{.cop}{.level::cache_hint}{.vec}.type   [a], b{, cache-policy};
+//! st{.weak}{.ss}{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.vec}.type [a], b{, cache-policy};
+//! st.volatile{.ss}{.vec}.type                           [a], b;
+//! st.relaxed.scope{.ss}{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.vec}.type [a], b{, cache-policy};
+//! st.release.scope{.ss}{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.vec}.type [a], b{, cache-policy};
+//! st.mmio.relaxed.sys{.global}.type         [a], b;
+//! .ss =                       { .global, .local, .param, .param::func, .shared, .shared::cta, .shared::cluster};
+//! .level1::eviction_priority = { .L1::evict_normal, .L1::evict_unchanged,
+//! .L1::evict_first, .L1::evict_last, .L1::no_allocate };
+//! .level2::eviction_priority = { .L2::evict_normal, .L2::evict_first, .L2::evict_last };
+//! .level::cache_hint =        { .L2::cache_hint };
+//! .cop =                      { .wb, .cg, .cs, .wt };
+//! .sem =                      { .relaxed, .release };
+//! .scope =                    { .cta, .cluster, .gpu, .sys };
+//! .vec =                      { .v2, .v4, .v8 };
+//! .type =                     { .b8, .b16, .b32, .b64, .b128,
+//! .u8, .u16, .u32, .u64,
+//! .s8, .s16, .s32, .s64,
+//! .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::st::section_0::*;
+
+    impl Metadata for StWeakSsCopLevelCacheHintVecType {
+        fn opcode(&self) -> &'static str {
+            "st"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.weak {
+                f(".weak");
+            }
+            if let Some(ss_0) = self.ss.as_ref() {
+                match ss_0 {
+                    Ss::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Ss::ParamFunc => {
+                        f(".param::func");
+                    }
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                    Ss::Local => {
+                        f(".local");
+                    }
+                    Ss::Param => {
+                        f(".param");
+                    }
+                }
+            }
+            if let Some(cop_1) = self.cop.as_ref() {
+                match cop_1 {
+                    Cop::Wb => {
+                        f(".wb");
+                    }
+                    Cop::Cg => {
+                        f(".cg");
+                    }
+                    Cop::Cs => {
+                        f(".cs");
+                    }
+                    Cop::Wt => {
+                        f(".wt");
+                    }
+                }
+            }
+            if let Some(level_cache_hint_2) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_2 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            if let Some(vec_3) = self.vec.as_ref() {
+                match vec_3 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                    Vec::V8 => {
+                        f(".v8");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "st.txt",
+                section: 0,
+                line: 1,
+                rule: "st{.weak}{.ss}{.cop}{.level::cache_hint}{.vec}.type [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for StWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType {
+        fn opcode(&self) -> &'static str {
+            "st"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.weak {
+                f(".weak");
+            }
+            if let Some(ss_4) = self.ss.as_ref() {
+                match ss_4 {
+                    Ss::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Ss::ParamFunc => {
+                        f(".param::func");
+                    }
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                    Ss::Local => {
+                        f(".local");
+                    }
+                    Ss::Param => {
+                        f(".param");
+                    }
+                }
+            }
+            if let Some(level1_eviction_priority_5) = self.level1_eviction_priority.as_ref() {
+                match level1_eviction_priority_5 {
+                    Level1EvictionPriority::L1EvictUnchanged => {
+                        f(".L1::evict_unchanged");
+                    }
+                    Level1EvictionPriority::L1EvictNormal => {
+                        f(".L1::evict_normal");
+                    }
+                    Level1EvictionPriority::L1EvictFirst => {
+                        f(".L1::evict_first");
+                    }
+                    Level1EvictionPriority::L1NoAllocate => {
+                        f(".L1::no_allocate");
+                    }
+                    Level1EvictionPriority::L1EvictLast => {
+                        f(".L1::evict_last");
+                    }
+                }
+            }
+            if let Some(level2_eviction_priority_6) = self.level2_eviction_priority.as_ref() {
+                match level2_eviction_priority_6 {
+                    Level2EvictionPriority::L2EvictNormal => {
+                        f(".L2::evict_normal");
+                    }
+                    Level2EvictionPriority::L2EvictFirst => {
+                        f(".L2::evict_first");
+                    }
+                    Level2EvictionPriority::L2EvictLast => {
+                        f(".L2::evict_last");
+                    }
+                }
+            }
+            if let Some(level_cache_hint_7) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_7 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            if let Some(vec_8) = self.vec.as_ref() {
+                match vec_8 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                    Vec::V8 => {
+                        f(".v8");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "st.txt",
+                section: 0,
+                line: 2,
+                rule: "st{.weak}{.ss}{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.vec}.type [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for StVolatileSsVecType {
+        fn opcode(&self) -> &'static str {
+            "st"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".volatile");
+            if let Some(ss_9) = self.ss.as_ref() {
+                match ss_9 {
+                    Ss::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Ss::ParamFunc => {
+                        f(".param::func");
+                    }
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                    Ss::Local => {
+                        f(".local");
+                    }
+                    Ss::Param => {
+                        f(".param");
+                    }
+                }
+            }
+            if let Some(vec_10) = self.vec.as_ref() {
+                match vec_10 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                    Vec::V8 => {
+                        f(".v8");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "st.txt",
+                section: 0,
+                line: 3,
+                rule: "st.volatile{.ss}{.vec}.type [a], b;",
+            }
+        }
+    }
+
+    impl Metadata
+        for StRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType
+    {
+        fn opcode(&self) -> &'static str {
+            "st"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".relaxed");
+            match &self.scope {
+                Scope::Cluster => {
+                    f(".cluster");
+                }
+                Scope::Cta => {
+                    f(".cta");
+                }
+                Scope::Gpu => {
+                    f(".gpu");
+                }
+                Scope::Sys => {
+                    f(".sys");
+                }
+            }
+            if let Some(ss_11) = self.ss.as_ref() {
+                match ss_11 {
+                    Ss::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Ss::ParamFunc => {
+                        f(".param::func");
+                    }
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                    Ss::Local => {
+                        f(".local");
+                    }
+                    Ss::Param => {
+                        f(".param");
+                    }
+                }
+            }
+            if let Some(level1_eviction_priority_12) = self.level1_eviction_priority.as_ref() {
+                match level1_eviction_priority_12 {
+                    Level1EvictionPriority::L1EvictUnchanged => {
+                        f(".L1::evict_unchanged");
+                    }
+                    Level1EvictionPriority::L1EvictNormal => {
+                        f(".L1::evict_normal");
+                    }
+                    Level1EvictionPriority::L1EvictFirst => {
+                        f(".L1::evict_first");
+                    }
+                    Level1EvictionPriority::L1NoAllocate => {
+                        f(".L1::no_allocate");
+                    }
+                    Level1EvictionPriority::L1EvictLast => {
+                        f(".L1::evict_last");
+                    }
+                }
+            }
+            if let Some(level2_eviction_priority_13) = self.level2_eviction_priority.as_ref() {
+                match level2_eviction_priority_13 {
+                    Level2EvictionPriority::L2EvictNormal => {
+                        f(".L2::evict_normal");
+                    }
+                    Level2EvictionPriority::L2EvictFirst => {
+                        f(".L2::evict_first");
+                    }
+                    Level2EvictionPriority::L2EvictLast => {
+                        f(".L2::evict_last");
+                    }
+                }
+            }
+            if let Some(level_cache_hint_14) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_14 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            if let Some(vec_15) = self.vec.as_ref() {
+                match vec_15 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                    Vec::V8 => {
+                        f(".v8");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "st.txt",
+                section: 0,
+                line: 4,
+                rule: "st.relaxed.scope{.ss}{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.vec}.type [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata
+        for StReleaseScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType
+    {
+        fn opcode(&self) -> &'static str {
+            "st"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".release");
+            match &self.scope {
+                Scope::Cluster => {
+                    f(".cluster");
+                }
+                Scope::Cta => {
+                    f(".cta");
+                }
+                Scope::Gpu => {
+                    f(".gpu");
+                }
+                Scope::Sys => {
+                    f(".sys");
+                }
+            }
+            if let Some(ss_16) = self.ss.as_ref() {
+                match ss_16 {
+                    Ss::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                    Ss::ParamFunc => {
+                        f(".param::func");
+                    }
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                    Ss::Local => {
+                        f(".local");
+                    }
+                    Ss::Param => {
+                        f(".param");
+                    }
+                }
+            }
+            if let Some(level1_eviction_priority_17) = self.level1_eviction_priority.as_ref() {
+                match level1_eviction_priority_17 {
+                    Level1EvictionPriority::L1EvictUnchanged => {
+                        f(".L1::evict_unchanged");
+                    }
+                    Level1EvictionPriority::L1EvictNormal => {
+                        f(".L1::evict_normal");
+                    }
+                    Level1EvictionPriority::L1EvictFirst => {
+                        f(".L1::evict_first");
+                    }
+                    Level1EvictionPriority::L1NoAllocate => {
+                        f(".L1::no_allocate");
+                    }
+                    Level1EvictionPriority::L1EvictLast => {
+                        f(".L1::evict_last");
+                    }
+                }
+            }
+            if let Some(level2_eviction_priority_18) = self.level2_eviction_priority.as_ref() {
+                match level2_eviction_priority_18 {
+                    Level2EvictionPriority::L2EvictNormal => {
+                        f(".L2::evict_normal");
+                    }
+                    Level2EvictionPriority::L2EvictFirst => {
+                        f(".L2::evict_first");
+                    }
+                    Level2EvictionPriority::L2EvictLast => {
+                        f(".L2::evict_last");
+                    }
+                }
+            }
+            if let Some(level_cache_hint_19) = self.level_cache_hint.as_ref() {
+                match level_cache_hint_19 {
+                    LevelCacheHint::L2CacheHint => {
+                        f(".L2::cache_hint");
+                    }
+                }
+            }
+            if let Some(vec_20) = self.vec.as_ref() {
+                match vec_20 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                    Vec::V8 => {
+                        f(".v8");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "st.txt",
+                section: 0,
+                line: 5,
+                rule: "st.release.scope{.ss}{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.vec}.type [a], b{, cache-policy};",
+            }
+        }
+    }
+
+    impl Metadata for StMmioRelaxedSysGlobalType {
+        fn opcode(&self) -> &'static str {
+            "st"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mmio");
+            f(".relaxed");
+            f(".sys");
+            if self.global {
+                f(".global");
+            }
+            match &self.type_ {
+                Type::B128 => {
+                    f(".b128");
+                }
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "st.txt",
+                section: 0,
+                line: 6,
+                rule: "st.mmio.relaxed.sys{.global}.type [a], b;",
+            }
+        }
+    }
+}
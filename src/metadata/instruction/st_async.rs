@@ -0,0 +1,200 @@
+//! Original PTX specification:
+//!
+//! st.async{.sem}{.scope}{.ss}{.completion_mechanism}{.vec}.type [a], b, [mbar];
+//! .sem  =                 { .weak };
+//! .scope =                { .cluster };
+//! .ss   =                 { .shared::cluster };
+//! .type =                 { .b32, .b64,
+//! .u32, .u64,
+//! .s32, .s64,
+//! .f32, .f64 };
+//! .vec  =                 { .v2, .v4 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ---------------------------------------------------------
+//! st.async{.mmio}.sem.scope{.ss}.type [a], b;
+//! .sem =                  { .release };
+//! .scope =                { .gpu, .sys };
+//! .ss =                   { .global };
+//! .type =                 { .b8, .b16, .b32, .b64,
+//! .u8, .u16, .u32, .u64,
+//! .s8, .s16, .s32, .s64,
+//! .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::st_async::section_0::*;
+
+    impl Metadata for StAsyncSemScopeSsCompletionMechanismVecType {
+        fn opcode(&self) -> &'static str {
+            "st"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            if let Some(sem_0) = self.sem.as_ref() {
+                match sem_0 {
+                    Sem::Weak => {
+                        f(".weak");
+                    }
+                }
+            }
+            if let Some(scope_1) = self.scope.as_ref() {
+                match scope_1 {
+                    Scope::Cluster => {
+                        f(".cluster");
+                    }
+                }
+            }
+            if let Some(ss_2) = self.ss.as_ref() {
+                match ss_2 {
+                    Ss::SharedCluster => {
+                        f(".shared::cluster");
+                    }
+                }
+            }
+            if let Some(completion_mechanism_3) = self.completion_mechanism.as_ref() {
+                match completion_mechanism_3 {
+                    CompletionMechanism::MbarrierCompleteTxBytes => {
+                        f(".mbarrier::complete_tx::bytes");
+                    }
+                }
+            }
+            if let Some(vec_4) = self.vec.as_ref() {
+                match vec_4 {
+                    Vec::V2 => {
+                        f(".v2");
+                    }
+                    Vec::V4 => {
+                        f(".v4");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "st.async.txt",
+                section: 0,
+                line: 1,
+                rule: "st.async{.sem}{.scope}{.ss}{.completion_mechanism}{.vec}.type [a], b, [mbar];",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::st_async::section_1::*;
+
+    impl Metadata for StAsyncMmioSemScopeSsType {
+        fn opcode(&self) -> &'static str {
+            "st"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".async");
+            if self.mmio {
+                f(".mmio");
+            }
+            match &self.sem {
+                Sem::Release => {
+                    f(".release");
+                }
+            }
+            match &self.scope {
+                Scope::Gpu => {
+                    f(".gpu");
+                }
+                Scope::Sys => {
+                    f(".sys");
+                }
+            }
+            if let Some(ss_5) = self.ss.as_ref() {
+                match ss_5 {
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+                Type::U8 => {
+                    f(".u8");
+                }
+                Type::S8 => {
+                    f(".s8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "st.async.txt",
+                section: 1,
+                line: 12,
+                rule: "st.async{.mmio}.sem.scope{.ss}.type [a], b;",
+            }
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Original PTX specification:
+//!
+//! st.bulk{.weak}{.shared::cta}  [a], size, initval; // initval must be zero
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::st_bulk::section_0::*;
+
+    impl Metadata for StBulkWeakSharedCta {
+        fn opcode(&self) -> &'static str {
+            "st"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".bulk");
+            if self.weak {
+                f(".weak");
+            }
+            if self.shared_cta {
+                f(".shared::cta");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "st.bulk.txt",
+                section: 0,
+                line: 1,
+                rule: "st.bulk{.weak}{.shared::cta} [a], size, initval;",
+            }
+        }
+    }
+}
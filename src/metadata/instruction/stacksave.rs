@@ -0,0 +1,37 @@
+//! Original PTX specification:
+//!
+//! stacksave.type  d;
+//! .type = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::stacksave::section_0::*;
+
+    impl Metadata for StacksaveType {
+        fn opcode(&self) -> &'static str {
+            "stacksave"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "stacksave.txt",
+                section: 0,
+                line: 1,
+                rule: "stacksave.type d;",
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! Original PTX specification:
+//!
+//! stmatrix.sync.aligned.shape.num{.trans}{.ss}.type [p], r;
+//! .shape  = {.m8n8, .m16n8};
+//! .num    = {.x1, .x2, .x4};
+//! .ss     = {.shared, .shared::cta};
+//! .type   = {.b16, .b8};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::stmatrix::section_0::*;
+
+    impl Metadata for StmatrixSyncAlignedShapeNumTransSsType {
+        fn opcode(&self) -> &'static str {
+            "stmatrix"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M16n8 => {
+                    f(".m16n8");
+                }
+                Shape::M8n8 => {
+                    f(".m8n8");
+                }
+            }
+            match &self.num {
+                Num::X1 => {
+                    f(".x1");
+                }
+                Num::X2 => {
+                    f(".x2");
+                }
+                Num::X4 => {
+                    f(".x4");
+                }
+            }
+            if self.trans {
+                f(".trans");
+            }
+            if let Some(ss_0) = self.ss.as_ref() {
+                match ss_0 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::B16 => {
+                    f(".b16");
+                }
+                Type::B8 => {
+                    f(".b8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "stmatrix.txt",
+                section: 0,
+                line: 1,
+                rule: "stmatrix.sync.aligned.shape.num{.trans}{.ss}.type [p], r;",
+            }
+        }
+    }
+}
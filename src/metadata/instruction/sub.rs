@@ -0,0 +1,361 @@
+//! Original PTX specification:
+//!
+//! sub.type       d, a, b;
+//! sub{.sat}.s32  d, a, b;     // .sat applies only to .s32
+//! .type = { .u16, .u32, .u64,
+//! .s16, .s32, .s64 };
+//! --------------------------------------------
+//! sub{.rnd}{.ftz}{.sat}.f32  d, a, b;
+//! sub{.rnd}{.ftz}.f32x2      d, a, b;
+//! sub{.rnd}.f64              d, a, b;
+//! .rnd = { .rn, .rz, .rm, .rp };
+//! --------------------------------------------
+//! sub{.rnd}{.ftz}{.sat}.f16   d, a, b;
+//! sub{.rnd}{.ftz}{.sat}.f16x2 d, a, b;
+//! sub{.rnd}.bf16   d, a, b;
+//! sub{.rnd}.bf16x2 d, a, b;
+//! .rnd = { .rn };
+//! --------------------------------------------
+//! sub{.rnd}{.sat}.f32.atype  d, a, c;
+//! .atype = { .f16, .bf16};
+//! .rnd   = { .rn, .rz, .rm, .rp };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::sub::section_0::*;
+
+    impl Metadata for SubType {
+        fn opcode(&self) -> &'static str {
+            "sub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.type_ {
+                Type::U16 => {
+                    f(".u16");
+                }
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S16 => {
+                    f(".s16");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sub.txt",
+                section: 0,
+                line: 1,
+                rule: "sub.type d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SubSatS32 {
+        fn opcode(&self) -> &'static str {
+            "sub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.sat {
+                f(".sat");
+            }
+            f(".s32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sub.txt",
+                section: 0,
+                line: 2,
+                rule: "sub{.sat}.s32 d, a, b;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::sub::section_1::*;
+
+    impl Metadata for SubRndFtzSatF32 {
+        fn opcode(&self) -> &'static str {
+            "sub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_0) = self.rnd.as_ref() {
+                match rnd_0 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                    Rnd::Rz => {
+                        f(".rz");
+                    }
+                    Rnd::Rm => {
+                        f(".rm");
+                    }
+                    Rnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sub.txt",
+                section: 1,
+                line: 6,
+                rule: "sub{.rnd}{.ftz}{.sat}.f32 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SubRndFtzF32x2 {
+        fn opcode(&self) -> &'static str {
+            "sub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_1) = self.rnd.as_ref() {
+                match rnd_1 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                    Rnd::Rz => {
+                        f(".rz");
+                    }
+                    Rnd::Rm => {
+                        f(".rm");
+                    }
+                    Rnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            f(".f32x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sub.txt",
+                section: 1,
+                line: 7,
+                rule: "sub{.rnd}{.ftz}.f32x2 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SubRndF64 {
+        fn opcode(&self) -> &'static str {
+            "sub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_2) = self.rnd.as_ref() {
+                match rnd_2 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                    Rnd::Rz => {
+                        f(".rz");
+                    }
+                    Rnd::Rm => {
+                        f(".rm");
+                    }
+                    Rnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sub.txt",
+                section: 1,
+                line: 8,
+                rule: "sub{.rnd}.f64 d, a, b;",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::sub::section_2::*;
+
+    impl Metadata for SubRndFtzSatF16 {
+        fn opcode(&self) -> &'static str {
+            "sub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_3) = self.rnd.as_ref() {
+                match rnd_3 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sub.txt",
+                section: 2,
+                line: 11,
+                rule: "sub{.rnd}{.ftz}{.sat}.f16 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SubRndFtzSatF16x2 {
+        fn opcode(&self) -> &'static str {
+            "sub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_4) = self.rnd.as_ref() {
+                match rnd_4 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                }
+            }
+            if self.ftz {
+                f(".ftz");
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sub.txt",
+                section: 2,
+                line: 12,
+                rule: "sub{.rnd}{.ftz}{.sat}.f16x2 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SubRndBf16 {
+        fn opcode(&self) -> &'static str {
+            "sub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_5) = self.rnd.as_ref() {
+                match rnd_5 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                }
+            }
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sub.txt",
+                section: 2,
+                line: 13,
+                rule: "sub{.rnd}.bf16 d, a, b;",
+            }
+        }
+    }
+
+    impl Metadata for SubRndBf16x2 {
+        fn opcode(&self) -> &'static str {
+            "sub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_6) = self.rnd.as_ref() {
+                match rnd_6 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                }
+            }
+            f(".bf16x2");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sub.txt",
+                section: 2,
+                line: 14,
+                rule: "sub{.rnd}.bf16x2 d, a, b;",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::sub::section_3::*;
+
+    impl Metadata for SubRndSatF32Atype {
+        fn opcode(&self) -> &'static str {
+            "sub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if let Some(rnd_7) = self.rnd.as_ref() {
+                match rnd_7 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                    Rnd::Rz => {
+                        f(".rz");
+                    }
+                    Rnd::Rm => {
+                        f(".rm");
+                    }
+                    Rnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+            f(".f32");
+            match &self.atype {
+                Atype::Bf16 => {
+                    f(".bf16");
+                }
+                Atype::F16 => {
+                    f(".f16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sub.txt",
+                section: 3,
+                line: 17,
+                rule: "sub{.rnd}{.sat}.f32.atype d, a, c;",
+            }
+        }
+    }
+}
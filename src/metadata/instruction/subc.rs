@@ -0,0 +1,46 @@
+//! Original PTX specification:
+//!
+//! subc{.cc}.type  d, a, b;
+//! .type = { .u32, .s32, .u64, .s64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::subc::section_0::*;
+
+    impl Metadata for SubcCcType {
+        fn opcode(&self) -> &'static str {
+            "subc"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            if self.cc {
+                f(".cc");
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+                Type::U64 => {
+                    f(".u64");
+                }
+                Type::S64 => {
+                    f(".s64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "subc.txt",
+                section: 0,
+                line: 1,
+                rule: "subc{.cc}.type d, a, b;",
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+//! Original PTX specification:
+//!
+//! suld.b.geom{.cop}.vec.dtype{.mode}  d, [a, b];  // unformatted
+//!
+//! .geom  = { .1d, .2d, .3d, .a1d, .a2d };
+//! .cop   = { .ca, .cg, .cs, .cv };               // cache operation
+//! .vec   = { none, .v2, .v4 };
+//! .dtype = { .b8 , .b16, .b32, .b64 };
+//! .mode = { .trap, .clamp, .zero };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::suld::section_0::*;
+
+    impl Metadata for SuldBGeomCopVecDtypeMode {
+        fn opcode(&self) -> &'static str {
+            "suld"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".b");
+            match &self.geom {
+                Geom::A1d => {
+                    f(".a1d");
+                }
+                Geom::A2d => {
+                    f(".a2d");
+                }
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            if let Some(cop_0) = self.cop.as_ref() {
+                match cop_0 {
+                    Cop::Ca => {
+                        f(".ca");
+                    }
+                    Cop::Cg => {
+                        f(".cg");
+                    }
+                    Cop::Cs => {
+                        f(".cs");
+                    }
+                    Cop::Cv => {
+                        f(".cv");
+                    }
+                }
+            }
+            match &self.vec {
+                Vec::None => {
+                    f("none");
+                }
+                Vec::V2 => {
+                    f(".v2");
+                }
+                Vec::V4 => {
+                    f(".v4");
+                }
+            }
+            match &self.dtype {
+                Dtype::B16 => {
+                    f(".b16");
+                }
+                Dtype::B32 => {
+                    f(".b32");
+                }
+                Dtype::B64 => {
+                    f(".b64");
+                }
+                Dtype::B8 => {
+                    f(".b8");
+                }
+            }
+            if let Some(mode_1) = self.mode.as_ref() {
+                match mode_1 {
+                    Mode::Clamp => {
+                        f(".clamp");
+                    }
+                    Mode::Trap => {
+                        f(".trap");
+                    }
+                    Mode::Zero => {
+                        f(".zero");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "suld.txt",
+                section: 0,
+                line: 1,
+                rule: "suld.b.geom{.cop}.vec.dtype{.mode} d, [a, b];",
+            }
+        }
+    }
+}
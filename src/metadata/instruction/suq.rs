@@ -0,0 +1,55 @@
+//! Original PTX specification:
+//!
+//! suq.query.b32   d, [a];
+//! .query = { .width, .height, .depth,
+//! .channel_data_type, .channel_order,
+//! .array_size, .memory_layout };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::suq::section_0::*;
+
+    impl Metadata for SuqQueryB32 {
+        fn opcode(&self) -> &'static str {
+            "suq"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.query {
+                Query::ChannelDataType => {
+                    f(".channel_data_type");
+                }
+                Query::ChannelOrder => {
+                    f(".channel_order");
+                }
+                Query::MemoryLayout => {
+                    f(".memory_layout");
+                }
+                Query::ArraySize => {
+                    f(".array_size");
+                }
+                Query::Height => {
+                    f(".height");
+                }
+                Query::Width => {
+                    f(".width");
+                }
+                Query::Depth => {
+                    f(".depth");
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "suq.txt",
+                section: 0,
+                line: 1,
+                rule: "suq.query.b32 d, [a];",
+            }
+        }
+    }
+}
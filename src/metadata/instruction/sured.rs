@@ -0,0 +1,164 @@
+//! Original PTX specification:
+//!
+//! sured.b.op.geom.ctype.mode [a,b],c; // byte addressing
+//! .op    = { .add, .min, .max, .and, .or };
+//! .geom  = { .1d, .2d, .3d };
+//! .ctype = { .u32, .u64, .s32, .b32, .s64 };  // for sured.b
+//! .mode  = { .trap, .clamp, .zero };
+//! ----------------------------------------------------
+//! sured.p.op.geom.ctype.mode [a,b],c; // sample addressing
+//! .op    = { .add, .min, .max, .and, .or };
+//! .geom  = { .1d, .2d, .3d };
+//! .ctype = { .b32, .b64 };                    // for sured.p
+//! .mode  = { .trap, .clamp, .zero };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::sured::section_0::*;
+
+    impl Metadata for SuredBOpGeomCtypeMode {
+        fn opcode(&self) -> &'static str {
+            "sured"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".b");
+            match &self.op {
+                Op::Add => {
+                    f(".add");
+                }
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+                Op::And => {
+                    f(".and");
+                }
+                Op::Or => {
+                    f(".or");
+                }
+            }
+            match &self.geom {
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            match &self.ctype {
+                Ctype::U32 => {
+                    f(".u32");
+                }
+                Ctype::U64 => {
+                    f(".u64");
+                }
+                Ctype::S32 => {
+                    f(".s32");
+                }
+                Ctype::B32 => {
+                    f(".b32");
+                }
+                Ctype::S64 => {
+                    f(".s64");
+                }
+            }
+            match &self.mode {
+                Mode::Clamp => {
+                    f(".clamp");
+                }
+                Mode::Trap => {
+                    f(".trap");
+                }
+                Mode::Zero => {
+                    f(".zero");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sured.txt",
+                section: 0,
+                line: 1,
+                rule: "sured.b.op.geom.ctype.mode [a,b],c;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::sured::section_1::*;
+
+    impl Metadata for SuredPOpGeomCtypeMode {
+        fn opcode(&self) -> &'static str {
+            "sured"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".p");
+            match &self.op {
+                Op::Add => {
+                    f(".add");
+                }
+                Op::Min => {
+                    f(".min");
+                }
+                Op::Max => {
+                    f(".max");
+                }
+                Op::And => {
+                    f(".and");
+                }
+                Op::Or => {
+                    f(".or");
+                }
+            }
+            match &self.geom {
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            match &self.ctype {
+                Ctype::B32 => {
+                    f(".b32");
+                }
+                Ctype::B64 => {
+                    f(".b64");
+                }
+            }
+            match &self.mode {
+                Mode::Clamp => {
+                    f(".clamp");
+                }
+                Mode::Trap => {
+                    f(".trap");
+                }
+                Mode::Zero => {
+                    f(".zero");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sured.txt",
+                section: 1,
+                line: 7,
+                rule: "sured.p.op.geom.ctype.mode [a,b],c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,234 @@
+//! Original PTX specification:
+//!
+//! sust.b.dim{.cop}.vec.ctype{.mode} [a, b], c;  // unformatted
+//! sust.p.dim.vec.b32{.mode}       [a, b], c;  // formatted
+//! sust.b.adim{.cop}.vec.ctype{.mode}   [a, b], c;  // unformatted
+//! .cop   = { .wb, .cg, .cs, .wt };                     // cache operation
+//! .vec   = { none, .v2, .v4 };
+//! .ctype = { .b8 , .b16, .b32, .b64 };
+//! .mode  = { .trap, .clamp, .zero };
+//! .dim   = { .1d, .2d, .3d };
+//! .adim  = { .a1d, .a2d };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::sust::section_0::*;
+
+    impl Metadata for SustBDimCopVecCtypeMode {
+        fn opcode(&self) -> &'static str {
+            "sust"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".b");
+            match &self.dim {
+                Dim::_1d => {
+                    f(".1d");
+                }
+                Dim::_2d => {
+                    f(".2d");
+                }
+                Dim::_3d => {
+                    f(".3d");
+                }
+            }
+            if let Some(cop_0) = self.cop.as_ref() {
+                match cop_0 {
+                    Cop::Wb => {
+                        f(".wb");
+                    }
+                    Cop::Cg => {
+                        f(".cg");
+                    }
+                    Cop::Cs => {
+                        f(".cs");
+                    }
+                    Cop::Wt => {
+                        f(".wt");
+                    }
+                }
+            }
+            match &self.vec {
+                Vec::None => {
+                    f("none");
+                }
+                Vec::V2 => {
+                    f(".v2");
+                }
+                Vec::V4 => {
+                    f(".v4");
+                }
+            }
+            match &self.ctype {
+                Ctype::B16 => {
+                    f(".b16");
+                }
+                Ctype::B32 => {
+                    f(".b32");
+                }
+                Ctype::B64 => {
+                    f(".b64");
+                }
+                Ctype::B8 => {
+                    f(".b8");
+                }
+            }
+            if let Some(mode_1) = self.mode.as_ref() {
+                match mode_1 {
+                    Mode::Clamp => {
+                        f(".clamp");
+                    }
+                    Mode::Trap => {
+                        f(".trap");
+                    }
+                    Mode::Zero => {
+                        f(".zero");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sust.txt",
+                section: 0,
+                line: 1,
+                rule: "sust.b.dim{.cop}.vec.ctype{.mode} [a, b], c;",
+            }
+        }
+    }
+
+    impl Metadata for SustPDimVecB32Mode {
+        fn opcode(&self) -> &'static str {
+            "sust"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".p");
+            match &self.dim {
+                Dim::_1d => {
+                    f(".1d");
+                }
+                Dim::_2d => {
+                    f(".2d");
+                }
+                Dim::_3d => {
+                    f(".3d");
+                }
+            }
+            match &self.vec {
+                Vec::None => {
+                    f("none");
+                }
+                Vec::V2 => {
+                    f(".v2");
+                }
+                Vec::V4 => {
+                    f(".v4");
+                }
+            }
+            f(".b32");
+            if let Some(mode_2) = self.mode.as_ref() {
+                match mode_2 {
+                    Mode::Clamp => {
+                        f(".clamp");
+                    }
+                    Mode::Trap => {
+                        f(".trap");
+                    }
+                    Mode::Zero => {
+                        f(".zero");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sust.txt",
+                section: 0,
+                line: 2,
+                rule: "sust.p.dim.vec.b32{.mode} [a, b], c;",
+            }
+        }
+    }
+
+    impl Metadata for SustBAdimCopVecCtypeMode {
+        fn opcode(&self) -> &'static str {
+            "sust"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".b");
+            match &self.adim {
+                Adim::A1d => {
+                    f(".a1d");
+                }
+                Adim::A2d => {
+                    f(".a2d");
+                }
+            }
+            if let Some(cop_3) = self.cop.as_ref() {
+                match cop_3 {
+                    Cop::Wb => {
+                        f(".wb");
+                    }
+                    Cop::Cg => {
+                        f(".cg");
+                    }
+                    Cop::Cs => {
+                        f(".cs");
+                    }
+                    Cop::Wt => {
+                        f(".wt");
+                    }
+                }
+            }
+            match &self.vec {
+                Vec::None => {
+                    f("none");
+                }
+                Vec::V2 => {
+                    f(".v2");
+                }
+                Vec::V4 => {
+                    f(".v4");
+                }
+            }
+            match &self.ctype {
+                Ctype::B16 => {
+                    f(".b16");
+                }
+                Ctype::B32 => {
+                    f(".b32");
+                }
+                Ctype::B64 => {
+                    f(".b64");
+                }
+                Ctype::B8 => {
+                    f(".b8");
+                }
+            }
+            if let Some(mode_4) = self.mode.as_ref() {
+                match mode_4 {
+                    Mode::Clamp => {
+                        f(".clamp");
+                    }
+                    Mode::Trap => {
+                        f(".trap");
+                    }
+                    Mode::Zero => {
+                        f(".zero");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "sust.txt",
+                section: 0,
+                line: 3,
+                rule: "sust.b.adim{.cop}.vec.ctype{.mode} [a, b], c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,46 @@
+//! Original PTX specification:
+//!
+//! szext.mode.type  d, a, b;
+//! .mode = { .clamp, .wrap };
+//! .type = { .u32, .s32 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::szext::section_0::*;
+
+    impl Metadata for SzextModeType {
+        fn opcode(&self) -> &'static str {
+            "szext"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.mode {
+                Mode::Clamp => {
+                    f(".clamp");
+                }
+                Mode::Wrap => {
+                    f(".wrap");
+                }
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "szext.txt",
+                section: 0,
+                line: 1,
+                rule: "szext.mode.type d, a, b;",
+            }
+        }
+    }
+}
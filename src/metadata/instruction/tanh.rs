@@ -0,0 +1,47 @@
+//! Original PTX specification:
+//!
+//! tanh.approx.type d, a;
+//! .type = {.f16, .f32, .f16x2, .bf16, .bf16x2};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tanh::section_0::*;
+
+    impl Metadata for TanhApproxType {
+        fn opcode(&self) -> &'static str {
+            "tanh"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".approx");
+            match &self.type_ {
+                Type::Bf16x2 => {
+                    f(".bf16x2");
+                }
+                Type::F16x2 => {
+                    f(".f16x2");
+                }
+                Type::Bf16 => {
+                    f(".bf16");
+                }
+                Type::F16 => {
+                    f(".f16");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tanh.txt",
+                section: 0,
+                line: 1,
+                rule: "tanh.approx.type d, a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Original PTX specification:
+//!
+//! tcgen05.alloc.cta_group.sync.aligned{.shared::cta}.b32  [dst], nCols;
+//! tcgen05.dealloc.cta_group.sync.aligned.b32              taddr, nCols;
+//! tcgen05.relinquish_alloc_permit.cta_group.sync.aligned;
+//! .cta_group = { .cta_group::1, .cta_group::2 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_alloc::section_0::*;
+
+    impl Metadata for Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".alloc");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".sync");
+            f(".aligned");
+            if self.shared_cta {
+                f(".shared::cta");
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.alloc.txt",
+                section: 0,
+                line: 1,
+                rule: "tcgen05.alloc.cta_group.sync.aligned{.shared::cta}.b32 [dst], nCols;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05DeallocCtaGroupSyncAlignedB32 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".dealloc");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".sync");
+            f(".aligned");
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.alloc.txt",
+                section: 0,
+                line: 2,
+                rule: "tcgen05.dealloc.cta_group.sync.aligned.b32 taddr, nCols;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05RelinquishAllocPermitCtaGroupSyncAligned {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".relinquish_alloc_permit");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".sync");
+            f(".aligned");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.alloc.txt",
+                section: 0,
+                line: 3,
+                rule: "tcgen05.relinquish_alloc_permit.cta_group.sync.aligned;",
+            }
+        }
+    }
+}
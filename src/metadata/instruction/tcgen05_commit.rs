@@ -0,0 +1,57 @@
+//! Original PTX specification:
+//!
+//! tcgen05.commit.cta_group.completion_mechanism{.shared::cluster}{.multicast}.b64
+//! [mbar] {, ctaMask};
+//! .completion_mechanism = { .mbarrier::arrive::one };
+//! .cta_group            = { .cta_group::1, .cta_group::2 };
+//! .multicast            = { .multicast::cluster };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_commit::section_0::*;
+
+    impl Metadata for Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".commit");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.completion_mechanism {
+                CompletionMechanism::MbarrierArriveOne => {
+                    f(".mbarrier::arrive::one");
+                }
+            }
+            if self.shared_cluster {
+                f(".shared::cluster");
+            }
+            if let Some(multicast_0) = self.multicast.as_ref() {
+                match multicast_0 {
+                    Multicast::MulticastCluster => {
+                        f(".multicast::cluster");
+                    }
+                }
+            }
+            f(".b64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.commit.txt",
+                section: 0,
+                line: 1,
+                rule: "tcgen05.commit.cta_group.completion_mechanism{.shared::cluster}{.multicast}.b64 [mbar] {, ctaMask};",
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! Original PTX specification:
+//!
+//! tcgen05.cp.cta_group.shape{.multicast}{.dst_src_fmt} [taddr], s-desc;
+//! .cta_group = { .cta_group::1, .cta_group::2 };
+//! .dst_src_fmt   = { .b8x16.b6x16_p32, .b8x16.b4x16_p64 };
+//! .shape     = { .128x256b, .4x256b, .128x128b, .64x128b**, .32x128b*** };
+//! .multicast = { .warpx2::02_13** , .warpx2::01_23**, .warpx4*** };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_cp::section_0::*;
+
+    impl Metadata for Tcgen05CpCtaGroupShapeMulticastDstSrcFmt {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".cp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.shape {
+                Shape::_32x128b => {
+                    f(".32x128b***");
+                }
+                Shape::_64x128b => {
+                    f(".64x128b**");
+                }
+                Shape::_128x256b => {
+                    f(".128x256b");
+                }
+                Shape::_128x128b => {
+                    f(".128x128b");
+                }
+                Shape::_4x256b => {
+                    f(".4x256b");
+                }
+            }
+            if let Some(multicast_0) = self.multicast.as_ref() {
+                match multicast_0 {
+                    Multicast::Warpx20213 => {
+                        f(".warpx2::02_13**");
+                    }
+                    Multicast::Warpx20123 => {
+                        f(".warpx2::01_23**");
+                    }
+                    Multicast::Warpx4 => {
+                        f(".warpx4***");
+                    }
+                }
+            }
+            if let Some(dst_src_fmt_1) = self.dst_src_fmt.as_ref() {
+                match dst_src_fmt_1 {
+                    DstSrcFmt::B8x16B6x16P32 => {
+                        f(".b8x16.b6x16_p32");
+                    }
+                    DstSrcFmt::B8x16B4x16P64 => {
+                        f(".b8x16.b4x16_p64");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.cp.txt",
+                section: 0,
+                line: 1,
+                rule: "tcgen05.cp.cta_group.shape{.multicast}{.dst_src_fmt} [taddr], s-desc;",
+            }
+        }
+    }
+}
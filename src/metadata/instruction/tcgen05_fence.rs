@@ -0,0 +1,47 @@
+//! Original PTX specification:
+//!
+//! tcgen05.fence::before_thread_sync ;
+//! tcgen05.fence::after_thread_sync  ;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_fence::section_0::*;
+
+    impl Metadata for Tcgen05FenceBeforeThreadSync {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".fence::before_thread_sync");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.fence.txt",
+                section: 0,
+                line: 1,
+                rule: "tcgen05.fence::before_thread_sync ;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05FenceAfterThreadSync {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".fence::after_thread_sync");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.fence.txt",
+                section: 0,
+                line: 2,
+                rule: "tcgen05.fence::after_thread_sync ;",
+            }
+        }
+    }
+}
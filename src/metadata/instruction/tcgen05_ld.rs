@@ -0,0 +1,419 @@
+//! Original PTX specification:
+//!
+//! // Base load instruction:
+//! tcgen05.ld.sync.aligned.shape1.num{.pack}.b32    r, [taddr];
+//! tcgen05.ld.sync.aligned.shape2.num{.pack}.b32    r, [taddr], immHalfSplitoff;
+//! .shape1 = { .16x64b, .16x128b, .16x256b, .32x32b };
+//! .shape2 = { .16x32bx2 };
+//! .num    = { .x1, .x2, .x4, .x8, .x16, .x32, .x64, .x128 };
+//! .pack   = { .pack::16b };
+//! // Floating point type load along with reduction :
+//! tcgen05.ld.red.sync.aligned.shape3.num.redOp{.abs}{.NaN}.f32 r, redval, [taddr];
+//! tcgen05.ld.red.sync.aligned.shape4.num.redOp{.abs}{.NaN}.f32 r, redval, [taddr], immHalfSplitoff;
+//! // Integer type load along with reduction :
+//! tcgen05.ld.red.sync.aligned.shape3.num.redOp.type r, redval, [taddr];
+//! tcgen05.ld.red.sync.aligned.shape4.num.redOp.type r, redval, [taddr], immHalfSplitoff;
+//! .shape3 = { .32x32b   };
+//! .shape4 = { .16x32bx2 };
+//! .redOp  = { .min, .max };
+//! .type   = { .u32, .s32 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_ld::section_0::*;
+
+    impl Metadata for Tcgen05LdSyncAlignedShape1NumPackB32 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".ld");
+            f(".sync");
+            f(".aligned");
+            match &self.shape1 {
+                Shape1::_16x128b => {
+                    f(".16x128b");
+                }
+                Shape1::_16x256b => {
+                    f(".16x256b");
+                }
+                Shape1::_16x64b => {
+                    f(".16x64b");
+                }
+                Shape1::_32x32b => {
+                    f(".32x32b");
+                }
+            }
+            match &self.num {
+                Num::X128 => {
+                    f(".x128");
+                }
+                Num::X16 => {
+                    f(".x16");
+                }
+                Num::X32 => {
+                    f(".x32");
+                }
+                Num::X64 => {
+                    f(".x64");
+                }
+                Num::X1 => {
+                    f(".x1");
+                }
+                Num::X2 => {
+                    f(".x2");
+                }
+                Num::X4 => {
+                    f(".x4");
+                }
+                Num::X8 => {
+                    f(".x8");
+                }
+            }
+            if let Some(pack_0) = self.pack.as_ref() {
+                match pack_0 {
+                    Pack::Pack16b => {
+                        f(".pack::16b");
+                    }
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.ld.txt",
+                section: 0,
+                line: 2,
+                rule: "tcgen05.ld.sync.aligned.shape1.num{.pack}.b32 r, [taddr];",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05LdSyncAlignedShape2NumPackB32 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".ld");
+            f(".sync");
+            f(".aligned");
+            match &self.shape2 {
+                Shape2::_16x32bx2 => {
+                    f(".16x32bx2");
+                }
+            }
+            match &self.num {
+                Num::X128 => {
+                    f(".x128");
+                }
+                Num::X16 => {
+                    f(".x16");
+                }
+                Num::X32 => {
+                    f(".x32");
+                }
+                Num::X64 => {
+                    f(".x64");
+                }
+                Num::X1 => {
+                    f(".x1");
+                }
+                Num::X2 => {
+                    f(".x2");
+                }
+                Num::X4 => {
+                    f(".x4");
+                }
+                Num::X8 => {
+                    f(".x8");
+                }
+            }
+            if let Some(pack_1) = self.pack.as_ref() {
+                match pack_1 {
+                    Pack::Pack16b => {
+                        f(".pack::16b");
+                    }
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.ld.txt",
+                section: 0,
+                line: 3,
+                rule: "tcgen05.ld.sync.aligned.shape2.num{.pack}.b32 r, [taddr], immHalfSplitoff;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".ld");
+            f(".red");
+            f(".sync");
+            f(".aligned");
+            match &self.shape3 {
+                Shape3::_32x32b => {
+                    f(".32x32b");
+                }
+            }
+            match &self.num {
+                Num::X128 => {
+                    f(".x128");
+                }
+                Num::X16 => {
+                    f(".x16");
+                }
+                Num::X32 => {
+                    f(".x32");
+                }
+                Num::X64 => {
+                    f(".x64");
+                }
+                Num::X1 => {
+                    f(".x1");
+                }
+                Num::X2 => {
+                    f(".x2");
+                }
+                Num::X4 => {
+                    f(".x4");
+                }
+                Num::X8 => {
+                    f(".x8");
+                }
+            }
+            match &self.redop {
+                Redop::Min => {
+                    f(".min");
+                }
+                Redop::Max => {
+                    f(".max");
+                }
+            }
+            if self.abs {
+                f(".abs");
+            }
+            if self.nan {
+                f(".NaN");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.ld.txt",
+                section: 0,
+                line: 9,
+                rule: "tcgen05.ld.red.sync.aligned.shape3.num.redOp{.abs}{.NaN}.f32 r, redval, [taddr];",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".ld");
+            f(".red");
+            f(".sync");
+            f(".aligned");
+            match &self.shape4 {
+                Shape4::_16x32bx2 => {
+                    f(".16x32bx2");
+                }
+            }
+            match &self.num {
+                Num::X128 => {
+                    f(".x128");
+                }
+                Num::X16 => {
+                    f(".x16");
+                }
+                Num::X32 => {
+                    f(".x32");
+                }
+                Num::X64 => {
+                    f(".x64");
+                }
+                Num::X1 => {
+                    f(".x1");
+                }
+                Num::X2 => {
+                    f(".x2");
+                }
+                Num::X4 => {
+                    f(".x4");
+                }
+                Num::X8 => {
+                    f(".x8");
+                }
+            }
+            match &self.redop {
+                Redop::Min => {
+                    f(".min");
+                }
+                Redop::Max => {
+                    f(".max");
+                }
+            }
+            if self.abs {
+                f(".abs");
+            }
+            if self.nan {
+                f(".NaN");
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.ld.txt",
+                section: 0,
+                line: 10,
+                rule: "tcgen05.ld.red.sync.aligned.shape4.num.redOp{.abs}{.NaN}.f32 r, redval, [taddr], immHalfSplitoff;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05LdRedSyncAlignedShape3NumRedopType {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".ld");
+            f(".red");
+            f(".sync");
+            f(".aligned");
+            match &self.shape3 {
+                Shape3::_32x32b => {
+                    f(".32x32b");
+                }
+            }
+            match &self.num {
+                Num::X128 => {
+                    f(".x128");
+                }
+                Num::X16 => {
+                    f(".x16");
+                }
+                Num::X32 => {
+                    f(".x32");
+                }
+                Num::X64 => {
+                    f(".x64");
+                }
+                Num::X1 => {
+                    f(".x1");
+                }
+                Num::X2 => {
+                    f(".x2");
+                }
+                Num::X4 => {
+                    f(".x4");
+                }
+                Num::X8 => {
+                    f(".x8");
+                }
+            }
+            match &self.redop {
+                Redop::Min => {
+                    f(".min");
+                }
+                Redop::Max => {
+                    f(".max");
+                }
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.ld.txt",
+                section: 0,
+                line: 12,
+                rule: "tcgen05.ld.red.sync.aligned.shape3.num.redOp.type r, redval, [taddr];",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05LdRedSyncAlignedShape4NumRedopType {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".ld");
+            f(".red");
+            f(".sync");
+            f(".aligned");
+            match &self.shape4 {
+                Shape4::_16x32bx2 => {
+                    f(".16x32bx2");
+                }
+            }
+            match &self.num {
+                Num::X128 => {
+                    f(".x128");
+                }
+                Num::X16 => {
+                    f(".x16");
+                }
+                Num::X32 => {
+                    f(".x32");
+                }
+                Num::X64 => {
+                    f(".x64");
+                }
+                Num::X1 => {
+                    f(".x1");
+                }
+                Num::X2 => {
+                    f(".x2");
+                }
+                Num::X4 => {
+                    f(".x4");
+                }
+                Num::X8 => {
+                    f(".x8");
+                }
+            }
+            match &self.redop {
+                Redop::Min => {
+                    f(".min");
+                }
+                Redop::Max => {
+                    f(".max");
+                }
+            }
+            match &self.type_ {
+                Type::U32 => {
+                    f(".u32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.ld.txt",
+                section: 0,
+                line: 13,
+                rule: "tcgen05.ld.red.sync.aligned.shape4.num.redOp.type r, redval, [taddr], immHalfSplitoff;",
+            }
+        }
+    }
+}
@@ -0,0 +1,710 @@
+//! Original PTX specification:
+//!
+//! // 1. Floating-point type without block scaling:
+//! tcgen05.mma.cta_group.kind   [d-tmem],  a-desc,  b-desc, idesc
+//! {, disable-output-lane }, enable-input-d {, scale-input-d};
+//! tcgen05.mma.cta_group.kind   [d-tmem], [a-tmem], b-desc, idesc
+//! {, disable-output-lane }, enable-input-d {, scale-input-d};
+//! .kind      = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! .cta_group = { .cta_group::1, .cta_group::2 };
+//! ------------------------------------------------------------------
+//! // 2. Floating-point type with block scaling:
+//! tcgen05.mma.cta_group.kind.block_scale{.scale_vectorsize}
+//! [d-tmem],  a-desc,  b-desc, idesc,
+//! [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! tcgen05.mma.cta_group.kind.block_scale{.scale_vectorsize}
+//! [d-tmem], [a-tmem], b-desc, idesc,
+//! [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! .kind = { .kind::mxf8f6f4, .kind::mxf4, .kind::mxf4nvf4 };
+//! .cta_group      = { .cta_group::1,   .cta_group::2 };
+//! .scale_vectorsize = { .scale_vec::1X, .scale_vec::2X, .scale_vec::4X, .block16, .block32 };
+//! ------------------------------------------------------------------
+//! // 3. Convolution MMA for floating-point type without block scaling:
+//! tcgen05.mma.cta_group.kind.collector_usage [d-tmem],  a-desc,  b-desc, idesc
+//! {, disable-output-lane }, enable-input-d {, scale-input-d};
+//! tcgen05.mma.cta_group.kind{.ashift}.collector_usage [d-tmem], [a-tmem], b-desc, idesc
+//! {, disable-output-lane }, enable-input-d {, scale-input-d};
+//! tcgen05.mma.cta_group.kind.ashift{.collector_usage} [d-tmem], [a-tmem], b-desc, idesc
+//! {, disable-output-lane }, enable-input-d {, scale-input-d};
+//! .kind      = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! .cta_group = { .cta_group::1,   .cta_group::2 };
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+//! ------------------------------------------------------------------
+//! // 4. Activation Stationary MMA for floating-point type with block scaling:
+//! tcgen05.mma.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage
+//! [d-tmem],  a-desc,  b-desc, idesc,
+//! [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! tcgen05.mma.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage
+//! [d-tmem], [a-tmem], b-desc, idesc,
+//! [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! .cta_group       = { .cta_group::1,   .cta_group::2 };
+//! .scale_vectorsize  = { .scale_vec::1X, .scale_vec::2X, .scale_vec::4X, .block16, .block32 };
+//! .kind            = { .kind::mxf8f6f4, .kind::mxf4, .kind::mxf4nvf4 };
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+//! ------------------------------------------------------------------
+//! // 5. Integer type:
+//! tcgen05.mma.cta_group.kind::i8  [d-tmem],  a-desc,  b-desc, idesc
+//! {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.cta_group.kind::i8  [d-tmem], [a-tmem], b-desc, idesc
+//! {, disable-output-lane }, enable-input-d;
+//! .cta_group = { .cta_group::1,   .cta_group::2  };
+//! ------------------------------------------------------------------
+//! // 6. Convolution MMA for integer type:
+//! tcgen05.mma.cta_group.kind::i8.collector_usage          [d-tmem],  a-desc,  b-desc, idesc
+//! {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.cta_group.kind::i8.ashift{.collector_usage} [d-tmem], [a-tmem], b-desc, idesc
+//! {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.cta_group.kind::i8{.ashift}.collector_usage [d-tmem], [a-tmem], b-desc, idesc
+//! {, disable-output-lane }, enable-input-d;
+//! .cta_group       = { .cta_group::1,   .cta_group::2  };
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma::section_0::*;
+
+    impl Metadata for Tcgen05MmaCtaGroupKind {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+                Kind::KindTf32 => {
+                    f(".kind::tf32");
+                }
+                Kind::KindF16 => {
+                    f(".kind::f16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 0,
+                line: 2,
+                rule: "tcgen05.mma.cta_group.kind [d-tmem], a-desc, b-desc, idesc {, disable-output-lane }, enable-input-d {, scale-input-d};",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaCtaGroupKind1 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+                Kind::KindTf32 => {
+                    f(".kind::tf32");
+                }
+                Kind::KindF16 => {
+                    f(".kind::f16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 0,
+                line: 4,
+                rule: "tcgen05.mma.cta_group.kind [d-tmem], [a-tmem], b-desc, idesc {, disable-output-lane }, enable-input-d {, scale-input-d};",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma::section_1::*;
+
+    impl Metadata for Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindMxf8f6f4 => {
+                    f(".kind::mxf8f6f4");
+                }
+                Kind::KindMxf4nvf4 => {
+                    f(".kind::mxf4nvf4");
+                }
+                Kind::KindMxf4 => {
+                    f(".kind::mxf4");
+                }
+            }
+            f(".block_scale");
+            if let Some(scale_vectorsize_0) = self.scale_vectorsize.as_ref() {
+                match scale_vectorsize_0 {
+                    ScaleVectorsize::ScaleVec1x => {
+                        f(".scale_vec::1X");
+                    }
+                    ScaleVectorsize::ScaleVec2x => {
+                        f(".scale_vec::2X");
+                    }
+                    ScaleVectorsize::ScaleVec4x => {
+                        f(".scale_vec::4X");
+                    }
+                    ScaleVectorsize::Block16 => {
+                        f(".block16");
+                    }
+                    ScaleVectorsize::Block32 => {
+                        f(".block32");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 1,
+                line: 10,
+                rule: "tcgen05.mma.cta_group.kind.block_scale{.scale_vectorsize} [d-tmem], a-desc, b-desc, idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindMxf8f6f4 => {
+                    f(".kind::mxf8f6f4");
+                }
+                Kind::KindMxf4nvf4 => {
+                    f(".kind::mxf4nvf4");
+                }
+                Kind::KindMxf4 => {
+                    f(".kind::mxf4");
+                }
+            }
+            f(".block_scale");
+            if let Some(scale_vectorsize_1) = self.scale_vectorsize.as_ref() {
+                match scale_vectorsize_1 {
+                    ScaleVectorsize::ScaleVec1x => {
+                        f(".scale_vec::1X");
+                    }
+                    ScaleVectorsize::ScaleVec2x => {
+                        f(".scale_vec::2X");
+                    }
+                    ScaleVectorsize::ScaleVec4x => {
+                        f(".scale_vec::4X");
+                    }
+                    ScaleVectorsize::Block16 => {
+                        f(".block16");
+                    }
+                    ScaleVectorsize::Block32 => {
+                        f(".block32");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 1,
+                line: 13,
+                rule: "tcgen05.mma.cta_group.kind.block_scale{.scale_vectorsize} [d-tmem], [a-tmem], b-desc, idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma::section_2::*;
+
+    impl Metadata for Tcgen05MmaCtaGroupKindCollectorUsage {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+                Kind::KindTf32 => {
+                    f(".kind::tf32");
+                }
+                Kind::KindF16 => {
+                    f(".kind::f16");
+                }
+            }
+            match &self.collector_usage {
+                CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                    let mut combined = String::from(".collector");
+                    combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                    combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                    f(&combined);
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 2,
+                line: 21,
+                rule: "tcgen05.mma.cta_group.kind.collector_usage [d-tmem], a-desc, b-desc, idesc {, disable-output-lane }, enable-input-d {, scale-input-d};",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaCtaGroupKindAshiftCollectorUsage {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+                Kind::KindTf32 => {
+                    f(".kind::tf32");
+                }
+                Kind::KindF16 => {
+                    f(".kind::f16");
+                }
+            }
+            if self.ashift {
+                f(".ashift");
+            }
+            match &self.collector_usage {
+                CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                    let mut combined = String::from(".collector");
+                    combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                    combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                    f(&combined);
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 2,
+                line: 23,
+                rule: "tcgen05.mma.cta_group.kind{.ashift}.collector_usage [d-tmem], [a-tmem], b-desc, idesc {, disable-output-lane }, enable-input-d {, scale-input-d};",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaCtaGroupKindAshiftCollectorUsage1 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+                Kind::KindTf32 => {
+                    f(".kind::tf32");
+                }
+                Kind::KindF16 => {
+                    f(".kind::f16");
+                }
+            }
+            f(".ashift");
+            if let Some(collector_usage_2) = self.collector_usage.as_ref() {
+                match collector_usage_2 {
+                    CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                        let mut combined = String::from(".collector");
+                        combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                        combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                        f(&combined);
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 2,
+                line: 25,
+                rule: "tcgen05.mma.cta_group.kind.ashift{.collector_usage} [d-tmem], [a-tmem], b-desc, idesc {, disable-output-lane }, enable-input-d {, scale-input-d};",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma::section_3::*;
+
+    impl Metadata for Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindMxf8f6f4 => {
+                    f(".kind::mxf8f6f4");
+                }
+                Kind::KindMxf4nvf4 => {
+                    f(".kind::mxf4nvf4");
+                }
+                Kind::KindMxf4 => {
+                    f(".kind::mxf4");
+                }
+            }
+            f(".block_scale");
+            if let Some(scale_vectorsize_3) = self.scale_vectorsize.as_ref() {
+                match scale_vectorsize_3 {
+                    ScaleVectorsize::ScaleVec1x => {
+                        f(".scale_vec::1X");
+                    }
+                    ScaleVectorsize::ScaleVec2x => {
+                        f(".scale_vec::2X");
+                    }
+                    ScaleVectorsize::ScaleVec4x => {
+                        f(".scale_vec::4X");
+                    }
+                    ScaleVectorsize::Block16 => {
+                        f(".block16");
+                    }
+                    ScaleVectorsize::Block32 => {
+                        f(".block32");
+                    }
+                }
+            }
+            match &self.collector_usage {
+                CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                    let mut combined = String::from(".collector");
+                    combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                    combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                    f(&combined);
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 3,
+                line: 34,
+                rule: "tcgen05.mma.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage [d-tmem], a-desc, b-desc, idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindMxf8f6f4 => {
+                    f(".kind::mxf8f6f4");
+                }
+                Kind::KindMxf4nvf4 => {
+                    f(".kind::mxf4nvf4");
+                }
+                Kind::KindMxf4 => {
+                    f(".kind::mxf4");
+                }
+            }
+            f(".block_scale");
+            if let Some(scale_vectorsize_4) = self.scale_vectorsize.as_ref() {
+                match scale_vectorsize_4 {
+                    ScaleVectorsize::ScaleVec1x => {
+                        f(".scale_vec::1X");
+                    }
+                    ScaleVectorsize::ScaleVec2x => {
+                        f(".scale_vec::2X");
+                    }
+                    ScaleVectorsize::ScaleVec4x => {
+                        f(".scale_vec::4X");
+                    }
+                    ScaleVectorsize::Block16 => {
+                        f(".block16");
+                    }
+                    ScaleVectorsize::Block32 => {
+                        f(".block32");
+                    }
+                }
+            }
+            match &self.collector_usage {
+                CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                    let mut combined = String::from(".collector");
+                    combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                    combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                    f(&combined);
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 3,
+                line: 37,
+                rule: "tcgen05.mma.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage [d-tmem], [a-tmem], b-desc, idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;",
+            }
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma::section_4::*;
+
+    impl Metadata for Tcgen05MmaCtaGroupKindI8 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".kind::i8");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 4,
+                line: 48,
+                rule: "tcgen05.mma.cta_group.kind::i8 [d-tmem], a-desc, b-desc, idesc {, disable-output-lane }, enable-input-d;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaCtaGroupKindI81 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".kind::i8");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 4,
+                line: 50,
+                rule: "tcgen05.mma.cta_group.kind::i8 [d-tmem], [a-tmem], b-desc, idesc {, disable-output-lane }, enable-input-d;",
+            }
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma::section_5::*;
+
+    impl Metadata for Tcgen05MmaCtaGroupKindI8CollectorUsage {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".kind::i8");
+            match &self.collector_usage {
+                CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                    let mut combined = String::from(".collector");
+                    combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                    combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                    f(&combined);
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 5,
+                line: 55,
+                rule: "tcgen05.mma.cta_group.kind::i8.collector_usage [d-tmem], a-desc, b-desc, idesc {, disable-output-lane }, enable-input-d;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".kind::i8");
+            f(".ashift");
+            if let Some(collector_usage_5) = self.collector_usage.as_ref() {
+                match collector_usage_5 {
+                    CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                        let mut combined = String::from(".collector");
+                        combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                        combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                        f(&combined);
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 5,
+                line: 57,
+                rule: "tcgen05.mma.cta_group.kind::i8.ashift{.collector_usage} [d-tmem], [a-tmem], b-desc, idesc {, disable-output-lane }, enable-input-d;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".kind::i8");
+            if self.ashift {
+                f(".ashift");
+            }
+            match &self.collector_usage {
+                CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                    let mut combined = String::from(".collector");
+                    combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                    combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                    f(&combined);
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.txt",
+                section: 5,
+                line: 59,
+                rule: "tcgen05.mma.cta_group.kind::i8{.ashift}.collector_usage [d-tmem], [a-tmem], b-desc, idesc {, disable-output-lane }, enable-input-d;",
+            }
+        }
+    }
+}
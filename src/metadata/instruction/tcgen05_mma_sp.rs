@@ -0,0 +1,706 @@
+//! Original PTX specification:
+//!
+//! // 1. Floating-point type without block scaling:
+//! tcgen05.mma.sp.cta_group.kind  [d-tmem],  a-desc,  b-desc, [sp-meta-tmem] ,  idesc {, disable-output-lane }, enable-input-d{, scale-input-d};
+//! tcgen05.mma.sp.cta_group.kind  [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d{, scale-input-d};
+//! .kind       = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! .cta_group  = { .cta_group::1,  .cta_group::2 };
+//! ------------------------------------------------------------------
+//! // 2. Floating-point type with block scaling:
+//! tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize} [d-tmem],  a-desc,  b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize} [d-tmem], [a-tmem], b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! .scale_vectorsize = { .scale_vec::1X, .scale_vec::2X, .scale_vec::4X, .block16, .block32 };
+//! .cta_group      = { .cta_group::1,  .cta_group::2 };
+//! .kind = { .kind::mxf8f6f4, .kind::mxf4, .kind::mxf4nvf4 };
+//! ------------------------------------------------------------------
+//! // 3. Convolution MMA with floating-point type without block scaling:
+//! tcgen05.mma.sp.cta_group.kind.collector_usage           [d-tmem],  a-desc,  b-desc, [sp-meta-tmem] ,  idesc {, disable-output-lane }, enable-input-d
+//! {, scale-input-d};
+//! tcgen05.mma.sp.cta_group.kind.ashift{.collector_usage}  [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d
+//! {, scale-input-d};
+//! tcgen05.mma.sp.cta_group.kind{.ashift}.collector_usage  [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d
+//! {, scale-input-d};
+//! .kind            = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+//! ------------------------------------------------------------------
+//! // 4. Activation Stationary MMA with floating-point type with block scaling:
+//! tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage [d-tmem],  a-desc,  b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage [d-tmem], [a-tmem], b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! .kind = { .kind::mxf8f6f4, .kind::mxf4, .kind::mxf4nvf4 };
+//! .scale_vectorsize = { .scale_vec::1X, .scale_vec::2X, .scale_vec::4X, .block16, .block32 };
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+//! ------------------------------------------------------------------
+//! // 5. Integer type:
+//! tcgen05.mma.sp.cta_group.kind::i8 [d-tmem],  a-desc,  b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind::i8 [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d;
+//! .cta_group      = { .cta_group::1,  .cta_group::2 };
+//! ------------------------------------------------------------------
+//! // 6. Convolution MMA with Integer type:
+//! tcgen05.mma.sp.cta_group.kind::i8.collector_usage          [d-tmem],  a-desc,  b-desc, [sp-meta-tmem], idesc {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind::i8.ashift{.collector_usage} [d-tmem], [a-tmem], b-desc, [sp-meta-tmem], idesc {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind::i8{.ashift}.collector_usage [d-tmem], [a-tmem], b-desc, [sp-meta-tmem], idesc {, disable-output-lane }, enable-input-d;
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_0::*;
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKind {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+                Kind::KindTf32 => {
+                    f(".kind::tf32");
+                }
+                Kind::KindF16 => {
+                    f(".kind::f16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 0,
+                line: 2,
+                rule: "tcgen05.mma.sp.cta_group.kind [d-tmem], a-desc, b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d{, scale-input-d};",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKind1 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+                Kind::KindTf32 => {
+                    f(".kind::tf32");
+                }
+                Kind::KindF16 => {
+                    f(".kind::f16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 0,
+                line: 3,
+                rule: "tcgen05.mma.sp.cta_group.kind [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d{, scale-input-d};",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_1::*;
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindMxf8f6f4 => {
+                    f(".kind::mxf8f6f4");
+                }
+                Kind::KindMxf4nvf4 => {
+                    f(".kind::mxf4nvf4");
+                }
+                Kind::KindMxf4 => {
+                    f(".kind::mxf4");
+                }
+            }
+            f(".block_scale");
+            if let Some(scale_vectorsize_0) = self.scale_vectorsize.as_ref() {
+                match scale_vectorsize_0 {
+                    ScaleVectorsize::ScaleVec1x => {
+                        f(".scale_vec::1X");
+                    }
+                    ScaleVectorsize::ScaleVec2x => {
+                        f(".scale_vec::2X");
+                    }
+                    ScaleVectorsize::ScaleVec4x => {
+                        f(".scale_vec::4X");
+                    }
+                    ScaleVectorsize::Block16 => {
+                        f(".block16");
+                    }
+                    ScaleVectorsize::Block32 => {
+                        f(".block32");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 1,
+                line: 8,
+                rule: "tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize} [d-tmem], a-desc, b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindMxf8f6f4 => {
+                    f(".kind::mxf8f6f4");
+                }
+                Kind::KindMxf4nvf4 => {
+                    f(".kind::mxf4nvf4");
+                }
+                Kind::KindMxf4 => {
+                    f(".kind::mxf4");
+                }
+            }
+            f(".block_scale");
+            if let Some(scale_vectorsize_1) = self.scale_vectorsize.as_ref() {
+                match scale_vectorsize_1 {
+                    ScaleVectorsize::ScaleVec1x => {
+                        f(".scale_vec::1X");
+                    }
+                    ScaleVectorsize::ScaleVec2x => {
+                        f(".scale_vec::2X");
+                    }
+                    ScaleVectorsize::ScaleVec4x => {
+                        f(".scale_vec::4X");
+                    }
+                    ScaleVectorsize::Block16 => {
+                        f(".block16");
+                    }
+                    ScaleVectorsize::Block32 => {
+                        f(".block32");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 1,
+                line: 9,
+                rule: "tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize} [d-tmem], [a-tmem], b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_2::*;
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKindCollectorUsage {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+                Kind::KindTf32 => {
+                    f(".kind::tf32");
+                }
+                Kind::KindF16 => {
+                    f(".kind::f16");
+                }
+            }
+            match &self.collector_usage {
+                CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                    let mut combined = String::from(".collector");
+                    combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                    combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                    f(&combined);
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 2,
+                line: 15,
+                rule: "tcgen05.mma.sp.cta_group.kind.collector_usage [d-tmem], a-desc, b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d {, scale-input-d};",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+                Kind::KindTf32 => {
+                    f(".kind::tf32");
+                }
+                Kind::KindF16 => {
+                    f(".kind::f16");
+                }
+            }
+            f(".ashift");
+            if let Some(collector_usage_2) = self.collector_usage.as_ref() {
+                match collector_usage_2 {
+                    CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                        let mut combined = String::from(".collector");
+                        combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                        combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                        f(&combined);
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 2,
+                line: 17,
+                rule: "tcgen05.mma.sp.cta_group.kind.ashift{.collector_usage} [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d {, scale-input-d};",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+                Kind::KindTf32 => {
+                    f(".kind::tf32");
+                }
+                Kind::KindF16 => {
+                    f(".kind::f16");
+                }
+            }
+            if self.ashift {
+                f(".ashift");
+            }
+            match &self.collector_usage {
+                CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                    let mut combined = String::from(".collector");
+                    combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                    combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                    f(&combined);
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 2,
+                line: 19,
+                rule: "tcgen05.mma.sp.cta_group.kind{.ashift}.collector_usage [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d {, scale-input-d};",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_3::*;
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindMxf8f6f4 => {
+                    f(".kind::mxf8f6f4");
+                }
+                Kind::KindMxf4nvf4 => {
+                    f(".kind::mxf4nvf4");
+                }
+                Kind::KindMxf4 => {
+                    f(".kind::mxf4");
+                }
+            }
+            f(".block_scale");
+            if let Some(scale_vectorsize_3) = self.scale_vectorsize.as_ref() {
+                match scale_vectorsize_3 {
+                    ScaleVectorsize::ScaleVec1x => {
+                        f(".scale_vec::1X");
+                    }
+                    ScaleVectorsize::ScaleVec2x => {
+                        f(".scale_vec::2X");
+                    }
+                    ScaleVectorsize::ScaleVec4x => {
+                        f(".scale_vec::4X");
+                    }
+                    ScaleVectorsize::Block16 => {
+                        f(".block16");
+                    }
+                    ScaleVectorsize::Block32 => {
+                        f(".block32");
+                    }
+                }
+            }
+            match &self.collector_usage {
+                CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                    let mut combined = String::from(".collector");
+                    combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                    combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                    f(&combined);
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 3,
+                line: 27,
+                rule: "tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage [d-tmem], a-desc, b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            match &self.kind {
+                Kind::KindMxf8f6f4 => {
+                    f(".kind::mxf8f6f4");
+                }
+                Kind::KindMxf4nvf4 => {
+                    f(".kind::mxf4nvf4");
+                }
+                Kind::KindMxf4 => {
+                    f(".kind::mxf4");
+                }
+            }
+            f(".block_scale");
+            if let Some(scale_vectorsize_4) = self.scale_vectorsize.as_ref() {
+                match scale_vectorsize_4 {
+                    ScaleVectorsize::ScaleVec1x => {
+                        f(".scale_vec::1X");
+                    }
+                    ScaleVectorsize::ScaleVec2x => {
+                        f(".scale_vec::2X");
+                    }
+                    ScaleVectorsize::ScaleVec4x => {
+                        f(".scale_vec::4X");
+                    }
+                    ScaleVectorsize::Block16 => {
+                        f(".block16");
+                    }
+                    ScaleVectorsize::Block32 => {
+                        f(".block32");
+                    }
+                }
+            }
+            match &self.collector_usage {
+                CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                    let mut combined = String::from(".collector");
+                    combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                    combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                    f(&combined);
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 3,
+                line: 28,
+                rule: "tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage [d-tmem], [a-tmem], b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;",
+            }
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_4::*;
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKindI8 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".kind::i8");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 4,
+                line: 36,
+                rule: "tcgen05.mma.sp.cta_group.kind::i8 [d-tmem], a-desc, b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKindI81 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".kind::i8");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 4,
+                line: 37,
+                rule: "tcgen05.mma.sp.cta_group.kind::i8 [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d;",
+            }
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_5::*;
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKindI8CollectorUsage {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".kind::i8");
+            match &self.collector_usage {
+                CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                    let mut combined = String::from(".collector");
+                    combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                    combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                    f(&combined);
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 5,
+                line: 41,
+                rule: "tcgen05.mma.sp.cta_group.kind::i8.collector_usage [d-tmem], a-desc, b-desc, [sp-meta-tmem], idesc {, disable-output-lane }, enable-input-d;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".kind::i8");
+            f(".ashift");
+            if let Some(collector_usage_5) = self.collector_usage.as_ref() {
+                match collector_usage_5 {
+                    CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                        let mut combined = String::from(".collector");
+                        combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                        combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                        f(&combined);
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 5,
+                line: 42,
+                rule: "tcgen05.mma.sp.cta_group.kind::i8.ashift{.collector_usage} [d-tmem], [a-tmem], b-desc, [sp-meta-tmem], idesc {, disable-output-lane }, enable-input-d;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sp");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".kind::i8");
+            if self.ashift {
+                f(".ashift");
+            }
+            match &self.collector_usage {
+                CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                    let mut combined = String::from(".collector");
+                    combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                    combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                    f(&combined);
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.sp.txt",
+                section: 5,
+                line: 43,
+                rule: "tcgen05.mma.sp.cta_group.kind::i8{.ashift}.collector_usage [d-tmem], [a-tmem], b-desc, [sp-meta-tmem], idesc {, disable-output-lane }, enable-input-d;",
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//! Original PTX specification:
+//!
+//! // 1. Floating-point type without block scaling:
+//! tcgen05.mma.ws.sp.cta_group::1.kind{.collector_usage} [d-tmem],  a-desc,  b-desc,
+//! [sp-meta-tmem] ,  idesc,
+//! enable-input-d {, zero-column-mask-desc};
+//! tcgen05.mma.ws.sp.cta_group::1.kind{.collector_usage} [d-tmem], [a-tmem], b-desc,
+//! [sp-meta-tmem] , idesc,
+//! enable-input-d {, zero-column-mask-desc};
+//! .kind = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! ------------------------------------------------------------------
+//! // 2. Integer type:
+//! tcgen05.mma.ws.sp.cta_group::1.kind::i8{.collector_usage} [d-tmem], a-desc, b-desc,
+//! [sp-meta-tmem] , idesc,
+//! enable-input-d {, zero-column-mask-desc};
+//! tcgen05.mma.ws.sp.cta_group::1.kind::i8{.collector_usage} [d-tmem], [a-tmem], b-desc,
+//! [sp-meta-tmem] , idesc,
+//! enable-input-d {, zero-column-mask-desc};
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer = { ::b0, ::b1, ::b2, ::b3 };
+//! ::op   = { ::fill, ::use, ::lastuse, ::discard};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_ws_sp::section_0::*;
+
+    impl Metadata for Tcgen05MmaWsSpCtaGroup1KindCollectorUsage {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".ws");
+            f(".sp");
+            f(".cta_group::1");
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+                Kind::KindTf32 => {
+                    f(".kind::tf32");
+                }
+                Kind::KindF16 => {
+                    f(".kind::f16");
+                }
+            }
+            if self.collector_usage {
+                f(".collector_usage");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.ws.sp.txt",
+                section: 0,
+                line: 2,
+                rule: "tcgen05.mma.ws.sp.cta_group::1.kind{.collector_usage} [d-tmem], a-desc, b-desc, [sp-meta-tmem] , idesc, enable-input-d {, zero-column-mask-desc};",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaWsSpCtaGroup1KindCollectorUsage1 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".ws");
+            f(".sp");
+            f(".cta_group::1");
+            match &self.kind {
+                Kind::KindF8f6f4 => {
+                    f(".kind::f8f6f4");
+                }
+                Kind::KindTf32 => {
+                    f(".kind::tf32");
+                }
+                Kind::KindF16 => {
+                    f(".kind::f16");
+                }
+            }
+            if self.collector_usage {
+                f(".collector_usage");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.ws.sp.txt",
+                section: 0,
+                line: 5,
+                rule: "tcgen05.mma.ws.sp.cta_group::1.kind{.collector_usage} [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc, enable-input-d {, zero-column-mask-desc};",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_ws_sp::section_1::*;
+
+    impl Metadata for Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".ws");
+            f(".sp");
+            f(".cta_group::1");
+            f(".kind::i8");
+            if let Some(collector_usage_0) = self.collector_usage.as_ref() {
+                match collector_usage_0 {
+                    CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                        let mut combined = String::from(".collector");
+                        combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                        combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                        f(&combined);
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.ws.sp.txt",
+                section: 1,
+                line: 11,
+                rule: "tcgen05.mma.ws.sp.cta_group::1.kind::i8{.collector_usage} [d-tmem], a-desc, b-desc, [sp-meta-tmem] , idesc, enable-input-d {, zero-column-mask-desc};",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage1 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".ws");
+            f(".sp");
+            f(".cta_group::1");
+            f(".kind::i8");
+            if let Some(collector_usage_1) = self.collector_usage.as_ref() {
+                match collector_usage_1 {
+                    CollectorUsage::CollectorBufferOp(_, n1, n2) => {
+                        let mut combined = String::from(".collector");
+                        combined.push_str(format!("{:?}", n1).trim_start_matches('_'));
+                        combined.push_str(format!("{:?}", n2).trim_start_matches('_'));
+                        f(&combined);
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.mma.ws.sp.txt",
+                section: 1,
+                line: 14,
+                rule: "tcgen05.mma.ws.sp.cta_group::1.kind::i8{.collector_usage} [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc, enable-input-d {, zero-column-mask-desc};",
+            }
+        }
+    }
+}
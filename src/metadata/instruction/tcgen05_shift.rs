@@ -0,0 +1,39 @@
+//! Original PTX specification:
+//!
+//! tcgen05.shift.cta_group.down  [taddr];
+//! .cta_group = { .cta_group::1, .cta_group::2 }
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_shift::section_0::*;
+
+    impl Metadata for Tcgen05ShiftCtaGroupDown {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".shift");
+            match &self.cta_group {
+                CtaGroup::CtaGroup1 => {
+                    f(".cta_group::1");
+                }
+                CtaGroup::CtaGroup2 => {
+                    f(".cta_group::2");
+                }
+            }
+            f(".down");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.shift.txt",
+                section: 0,
+                line: 1,
+                rule: "tcgen05.shift.cta_group.down [taddr];",
+            }
+        }
+    }
+}
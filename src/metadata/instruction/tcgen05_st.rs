@@ -0,0 +1,142 @@
+//! Original PTX specification:
+//!
+//! tcgen05.st.sync.aligned.shape1.num{.unpack}.b32    [taddr], r;
+//! tcgen05.st.sync.aligned.shape2.num{.unpack}.b32    [taddr], immHalfSplitoff, r;
+//! .shape1 = { .16x64b, .16x128b, .16x256b, .32x32b };
+//! .shape2 = { .16x32bx2 };
+//! .num    = { .x1, .x2, .x4, .x8, .x16, .x32, .x64, .x128 };
+//! .unpack = { .unpack::16b };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_st::section_0::*;
+
+    impl Metadata for Tcgen05StSyncAlignedShape1NumUnpackB32 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".st");
+            f(".sync");
+            f(".aligned");
+            match &self.shape1 {
+                Shape1::_16x128b => {
+                    f(".16x128b");
+                }
+                Shape1::_16x256b => {
+                    f(".16x256b");
+                }
+                Shape1::_16x64b => {
+                    f(".16x64b");
+                }
+                Shape1::_32x32b => {
+                    f(".32x32b");
+                }
+            }
+            match &self.num {
+                Num::X128 => {
+                    f(".x128");
+                }
+                Num::X16 => {
+                    f(".x16");
+                }
+                Num::X32 => {
+                    f(".x32");
+                }
+                Num::X64 => {
+                    f(".x64");
+                }
+                Num::X1 => {
+                    f(".x1");
+                }
+                Num::X2 => {
+                    f(".x2");
+                }
+                Num::X4 => {
+                    f(".x4");
+                }
+                Num::X8 => {
+                    f(".x8");
+                }
+            }
+            if let Some(unpack_0) = self.unpack.as_ref() {
+                match unpack_0 {
+                    Unpack::Unpack16b => {
+                        f(".unpack::16b");
+                    }
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.st.txt",
+                section: 0,
+                line: 1,
+                rule: "tcgen05.st.sync.aligned.shape1.num{.unpack}.b32 [taddr], r;",
+            }
+        }
+    }
+
+    impl Metadata for Tcgen05StSyncAlignedShape2NumUnpackB32 {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".st");
+            f(".sync");
+            f(".aligned");
+            match &self.shape2 {
+                Shape2::_16x32bx2 => {
+                    f(".16x32bx2");
+                }
+            }
+            match &self.num {
+                Num::X128 => {
+                    f(".x128");
+                }
+                Num::X16 => {
+                    f(".x16");
+                }
+                Num::X32 => {
+                    f(".x32");
+                }
+                Num::X64 => {
+                    f(".x64");
+                }
+                Num::X1 => {
+                    f(".x1");
+                }
+                Num::X2 => {
+                    f(".x2");
+                }
+                Num::X4 => {
+                    f(".x4");
+                }
+                Num::X8 => {
+                    f(".x8");
+                }
+            }
+            if let Some(unpack_1) = self.unpack.as_ref() {
+                match unpack_1 {
+                    Unpack::Unpack16b => {
+                        f(".unpack::16b");
+                    }
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.st.txt",
+                section: 0,
+                line: 2,
+                rule: "tcgen05.st.sync.aligned.shape2.num{.unpack}.b32 [taddr], immHalfSplitoff, r;",
+            }
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Original PTX specification:
+//!
+//! tcgen05.wait_operation.sync.aligned;
+//! .wait_operation = { .wait::ld, .wait::st }
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_wait::section_0::*;
+
+    impl Metadata for Tcgen05WaitOperationSyncAligned {
+        fn opcode(&self) -> &'static str {
+            "tcgen05"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.wait_operation {
+                WaitOperation::WaitLd => {
+                    f(".wait::ld");
+                }
+                WaitOperation::WaitSt => {
+                    f(".wait::st");
+                }
+            }
+            f(".sync");
+            f(".aligned");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tcgen05.wait.txt",
+                section: 0,
+                line: 1,
+                rule: "tcgen05.wait_operation.sync.aligned;",
+            }
+        }
+    }
+}
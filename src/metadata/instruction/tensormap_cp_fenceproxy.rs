@@ -0,0 +1,50 @@
+//! Original PTX specification:
+//!
+//! tensormap.cp_fenceproxy.cp_qualifiers.fence_qualifiers.sync.aligned  [dst], [src], size;
+//! .cp_qualifiers    = { .global.shared::cta };
+//! .fence_qualifiers = { .to_proxy::from_proxy.release.scope };
+//! .to_proxy::from_proxy  = { .tensormap::generic };
+//! .scope            = { .cta, .cluster, .gpu , .sys };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tensormap_cp_fenceproxy::section_0::*;
+
+    impl Metadata for TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned {
+        fn opcode(&self) -> &'static str {
+            "tensormap"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".cp_fenceproxy");
+            match &self.cp_qualifiers {
+                CpQualifiers::GlobalSharedCta => {
+                    f(".global.shared::cta");
+                }
+            }
+            match &self.fence_qualifiers {
+                FenceQualifiers::ToProxyFromProxyReleaseScope(_, _, _) => {
+                    f(".tensormap::generic");
+                    f(".release");
+                    f(".cluster");
+                    f(".cta");
+                    f(".gpu");
+                    f(".sys");
+                }
+            }
+            f(".sync");
+            f(".aligned");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tensormap.cp_fenceproxy.txt",
+                section: 0,
+                line: 1,
+                rule: "tensormap.cp_fenceproxy.cp_qualifiers.fence_qualifiers.sync.aligned [dst], [src], size;",
+            }
+        }
+    }
+}
@@ -0,0 +1,182 @@
+//! Original PTX specification:
+//!
+//! tensormap.replace.mode.field1{.ss}.b1024.type  [addr], new_val;
+//! tensormap.replace.mode.field2{.ss}.b1024.type  [addr], ord, new_val;
+//! tensormap.replace.mode.field3{.ss}.b1024.type  [addr], new_val;
+//! .mode    = { .tile };
+//! .field1  = { .global_address, .rank };
+//! .field2  = { .box_dim, .global_dim, .global_stride, .element_stride  };
+//! .field3  = { .elemtype,  .interleave_layout, .swizzle_mode, .swizzle_atomicity, .fill_mode };
+//! .ss      = { .global, .shared::cta };
+//! .type    = { .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tensormap_replace::section_0::*;
+
+    impl Metadata for TensormapReplaceModeField1SsB1024Type {
+        fn opcode(&self) -> &'static str {
+            "tensormap"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".replace");
+            match &self.mode {
+                Mode::Tile => {
+                    f(".tile");
+                }
+            }
+            match &self.field1 {
+                Field1::GlobalAddress => {
+                    f(".global_address");
+                }
+                Field1::Rank => {
+                    f(".rank");
+                }
+            }
+            if let Some(ss_0) = self.ss.as_ref() {
+                match ss_0 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            f(".b1024");
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tensormap.replace.txt",
+                section: 0,
+                line: 1,
+                rule: "tensormap.replace.mode.field1{.ss}.b1024.type [addr], new_val;",
+            }
+        }
+    }
+
+    impl Metadata for TensormapReplaceModeField2SsB1024Type {
+        fn opcode(&self) -> &'static str {
+            "tensormap"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".replace");
+            match &self.mode {
+                Mode::Tile => {
+                    f(".tile");
+                }
+            }
+            match &self.field2 {
+                Field2::ElementStride => {
+                    f(".element_stride");
+                }
+                Field2::GlobalStride => {
+                    f(".global_stride");
+                }
+                Field2::GlobalDim => {
+                    f(".global_dim");
+                }
+                Field2::BoxDim => {
+                    f(".box_dim");
+                }
+            }
+            if let Some(ss_1) = self.ss.as_ref() {
+                match ss_1 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            f(".b1024");
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tensormap.replace.txt",
+                section: 0,
+                line: 2,
+                rule: "tensormap.replace.mode.field2{.ss}.b1024.type [addr], ord, new_val;",
+            }
+        }
+    }
+
+    impl Metadata for TensormapReplaceModeField3SsB1024Type {
+        fn opcode(&self) -> &'static str {
+            "tensormap"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".replace");
+            match &self.mode {
+                Mode::Tile => {
+                    f(".tile");
+                }
+            }
+            match &self.field3 {
+                Field3::InterleaveLayout => {
+                    f(".interleave_layout");
+                }
+                Field3::SwizzleAtomicity => {
+                    f(".swizzle_atomicity");
+                }
+                Field3::SwizzleMode => {
+                    f(".swizzle_mode");
+                }
+                Field3::FillMode => {
+                    f(".fill_mode");
+                }
+                Field3::Elemtype => {
+                    f(".elemtype");
+                }
+            }
+            if let Some(ss_2) = self.ss.as_ref() {
+                match ss_2 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                }
+            }
+            f(".b1024");
+            match &self.type_ {
+                Type::B32 => {
+                    f(".b32");
+                }
+                Type::B64 => {
+                    f(".b64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tensormap.replace.txt",
+                section: 0,
+                line: 3,
+                rule: "tensormap.replace.mode.field3{.ss}.b1024.type [addr], new_val;",
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Original PTX specification:
+//!
+//! testp.op.type  p, a;  // result is .pred
+//! .op   = { .finite, .infinite,
+//! .number, .notanumber,
+//! .normal, .subnormal };
+//! .type = { .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::testp::section_0::*;
+
+    impl Metadata for TestpOpType {
+        fn opcode(&self) -> &'static str {
+            "testp"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.op {
+                Op::Notanumber => {
+                    f(".notanumber");
+                }
+                Op::Subnormal => {
+                    f(".subnormal");
+                }
+                Op::Infinite => {
+                    f(".infinite");
+                }
+                Op::Finite => {
+                    f(".finite");
+                }
+                Op::Number => {
+                    f(".number");
+                }
+                Op::Normal => {
+                    f(".normal");
+                }
+            }
+            match &self.type_ {
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "testp.txt",
+                section: 0,
+                line: 1,
+                rule: "testp.op.type p, a;",
+            }
+        }
+    }
+}
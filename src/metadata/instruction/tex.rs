@@ -0,0 +1,647 @@
+//! Original PTX specification:
+//!
+//! tex.geom.v4.dtype.ctype  d{|p}, [a, c] {, e} {, f};
+//! tex.geom.v4.dtype.ctype  d{|p}, [a, b, c] {, e} {, f};  // explicit sampler
+//! tex.geom.v2.f16x2.ctype  d{|p}, [a, c] {, e} {, f};
+//! tex.geom.v2.f16x2.ctype  d{|p}, [a, b, c] {, e} {, f};  // explicit sampler
+//! // mipmaps
+//! tex.base.geom.v4.dtype.ctype   d{|p}, [a, {b,} c] {, e} {, f};
+//! tex.level.geom.v4.dtype.ctype  d{|p}, [a, {b,} c], lod {, e} {, f};
+//! tex.grad.geom.v4.dtype.ctype   d{|p}, [a, {b,} c], dPdx, dPdy {, e} {, f};
+//! tex.base.geom.v2.f16x2.ctype   d{|p}, [a, {b,} c] {, e} {, f};
+//! tex.level.geom.v2.f16x2.ctype  d{|p}, [a, {b,} c], lod {, e} {, f};
+//! tex.grad.geom.v2.f16x2.ctype   d{|p}, [a, {b,} c], dPdx, dPdy {, e} {, f};
+//! .geom  = { .1d, .2d, .3d, .a1d, .a2d, .cube, .acube, .2dms, .a2dms };
+//! .dtype = { .u32, .s32, .f16,  .f32 };
+//! .ctype = {       .s32, .f32 };          // .cube, .acube require .f32
+//! // .2dms, .a2dms require .s32
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tex::section_0::*;
+
+    impl Metadata for TexGeomV4DtypeCtype {
+        fn opcode(&self) -> &'static str {
+            "tex"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.geom {
+                Geom::Acube => {
+                    f(".acube");
+                }
+                Geom::A2dms => {
+                    f(".a2dms");
+                }
+                Geom::Cube => {
+                    f(".cube");
+                }
+                Geom::_2dms => {
+                    f(".2dms");
+                }
+                Geom::A1d => {
+                    f(".a1d");
+                }
+                Geom::A2d => {
+                    f(".a2d");
+                }
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            f(".v4");
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.ctype {
+                Ctype::S32 => {
+                    f(".s32");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tex.txt",
+                section: 0,
+                line: 1,
+                rule: "tex.geom.v4.dtype.ctype d{|p}, [a, c] {, e} {, f};",
+            }
+        }
+    }
+
+    impl Metadata for TexGeomV4DtypeCtype1 {
+        fn opcode(&self) -> &'static str {
+            "tex"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.geom {
+                Geom::Acube => {
+                    f(".acube");
+                }
+                Geom::A2dms => {
+                    f(".a2dms");
+                }
+                Geom::Cube => {
+                    f(".cube");
+                }
+                Geom::_2dms => {
+                    f(".2dms");
+                }
+                Geom::A1d => {
+                    f(".a1d");
+                }
+                Geom::A2d => {
+                    f(".a2d");
+                }
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            f(".v4");
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.ctype {
+                Ctype::S32 => {
+                    f(".s32");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tex.txt",
+                section: 0,
+                line: 2,
+                rule: "tex.geom.v4.dtype.ctype d{|p}, [a, b, c] {, e} {, f};",
+            }
+        }
+    }
+
+    impl Metadata for TexGeomV2F16x2Ctype {
+        fn opcode(&self) -> &'static str {
+            "tex"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.geom {
+                Geom::Acube => {
+                    f(".acube");
+                }
+                Geom::A2dms => {
+                    f(".a2dms");
+                }
+                Geom::Cube => {
+                    f(".cube");
+                }
+                Geom::_2dms => {
+                    f(".2dms");
+                }
+                Geom::A1d => {
+                    f(".a1d");
+                }
+                Geom::A2d => {
+                    f(".a2d");
+                }
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            f(".v2");
+            f(".f16x2");
+            match &self.ctype {
+                Ctype::S32 => {
+                    f(".s32");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tex.txt",
+                section: 0,
+                line: 3,
+                rule: "tex.geom.v2.f16x2.ctype d{|p}, [a, c] {, e} {, f};",
+            }
+        }
+    }
+
+    impl Metadata for TexGeomV2F16x2Ctype1 {
+        fn opcode(&self) -> &'static str {
+            "tex"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.geom {
+                Geom::Acube => {
+                    f(".acube");
+                }
+                Geom::A2dms => {
+                    f(".a2dms");
+                }
+                Geom::Cube => {
+                    f(".cube");
+                }
+                Geom::_2dms => {
+                    f(".2dms");
+                }
+                Geom::A1d => {
+                    f(".a1d");
+                }
+                Geom::A2d => {
+                    f(".a2d");
+                }
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            f(".v2");
+            f(".f16x2");
+            match &self.ctype {
+                Ctype::S32 => {
+                    f(".s32");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tex.txt",
+                section: 0,
+                line: 4,
+                rule: "tex.geom.v2.f16x2.ctype d{|p}, [a, b, c] {, e} {, f};",
+            }
+        }
+    }
+
+    impl Metadata for TexBaseGeomV4DtypeCtype {
+        fn opcode(&self) -> &'static str {
+            "tex"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".base");
+            match &self.geom {
+                Geom::Acube => {
+                    f(".acube");
+                }
+                Geom::A2dms => {
+                    f(".a2dms");
+                }
+                Geom::Cube => {
+                    f(".cube");
+                }
+                Geom::_2dms => {
+                    f(".2dms");
+                }
+                Geom::A1d => {
+                    f(".a1d");
+                }
+                Geom::A2d => {
+                    f(".a2d");
+                }
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            f(".v4");
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.ctype {
+                Ctype::S32 => {
+                    f(".s32");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tex.txt",
+                section: 0,
+                line: 6,
+                rule: "tex.base.geom.v4.dtype.ctype d{|p}, [a, {b,} c] {, e} {, f};",
+            }
+        }
+    }
+
+    impl Metadata for TexLevelGeomV4DtypeCtype {
+        fn opcode(&self) -> &'static str {
+            "tex"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".level");
+            match &self.geom {
+                Geom::Acube => {
+                    f(".acube");
+                }
+                Geom::A2dms => {
+                    f(".a2dms");
+                }
+                Geom::Cube => {
+                    f(".cube");
+                }
+                Geom::_2dms => {
+                    f(".2dms");
+                }
+                Geom::A1d => {
+                    f(".a1d");
+                }
+                Geom::A2d => {
+                    f(".a2d");
+                }
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            f(".v4");
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.ctype {
+                Ctype::S32 => {
+                    f(".s32");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tex.txt",
+                section: 0,
+                line: 7,
+                rule: "tex.level.geom.v4.dtype.ctype d{|p}, [a, {b,} c], lod {, e} {, f};",
+            }
+        }
+    }
+
+    impl Metadata for TexGradGeomV4DtypeCtype {
+        fn opcode(&self) -> &'static str {
+            "tex"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".grad");
+            match &self.geom {
+                Geom::Acube => {
+                    f(".acube");
+                }
+                Geom::A2dms => {
+                    f(".a2dms");
+                }
+                Geom::Cube => {
+                    f(".cube");
+                }
+                Geom::_2dms => {
+                    f(".2dms");
+                }
+                Geom::A1d => {
+                    f(".a1d");
+                }
+                Geom::A2d => {
+                    f(".a2d");
+                }
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            f(".v4");
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.ctype {
+                Ctype::S32 => {
+                    f(".s32");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tex.txt",
+                section: 0,
+                line: 8,
+                rule: "tex.grad.geom.v4.dtype.ctype d{|p}, [a, {b,} c], dPdx, dPdy {, e} {, f};",
+            }
+        }
+    }
+
+    impl Metadata for TexBaseGeomV2F16x2Ctype {
+        fn opcode(&self) -> &'static str {
+            "tex"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".base");
+            match &self.geom {
+                Geom::Acube => {
+                    f(".acube");
+                }
+                Geom::A2dms => {
+                    f(".a2dms");
+                }
+                Geom::Cube => {
+                    f(".cube");
+                }
+                Geom::_2dms => {
+                    f(".2dms");
+                }
+                Geom::A1d => {
+                    f(".a1d");
+                }
+                Geom::A2d => {
+                    f(".a2d");
+                }
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            f(".v2");
+            f(".f16x2");
+            match &self.ctype {
+                Ctype::S32 => {
+                    f(".s32");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tex.txt",
+                section: 0,
+                line: 9,
+                rule: "tex.base.geom.v2.f16x2.ctype d{|p}, [a, {b,} c] {, e} {, f};",
+            }
+        }
+    }
+
+    impl Metadata for TexLevelGeomV2F16x2Ctype {
+        fn opcode(&self) -> &'static str {
+            "tex"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".level");
+            match &self.geom {
+                Geom::Acube => {
+                    f(".acube");
+                }
+                Geom::A2dms => {
+                    f(".a2dms");
+                }
+                Geom::Cube => {
+                    f(".cube");
+                }
+                Geom::_2dms => {
+                    f(".2dms");
+                }
+                Geom::A1d => {
+                    f(".a1d");
+                }
+                Geom::A2d => {
+                    f(".a2d");
+                }
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            f(".v2");
+            f(".f16x2");
+            match &self.ctype {
+                Ctype::S32 => {
+                    f(".s32");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tex.txt",
+                section: 0,
+                line: 10,
+                rule: "tex.level.geom.v2.f16x2.ctype d{|p}, [a, {b,} c], lod {, e} {, f};",
+            }
+        }
+    }
+
+    impl Metadata for TexGradGeomV2F16x2Ctype {
+        fn opcode(&self) -> &'static str {
+            "tex"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".grad");
+            match &self.geom {
+                Geom::Acube => {
+                    f(".acube");
+                }
+                Geom::A2dms => {
+                    f(".a2dms");
+                }
+                Geom::Cube => {
+                    f(".cube");
+                }
+                Geom::_2dms => {
+                    f(".2dms");
+                }
+                Geom::A1d => {
+                    f(".a1d");
+                }
+                Geom::A2d => {
+                    f(".a2d");
+                }
+                Geom::_1d => {
+                    f(".1d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+                Geom::_3d => {
+                    f(".3d");
+                }
+            }
+            f(".v2");
+            f(".f16x2");
+            match &self.ctype {
+                Ctype::S32 => {
+                    f(".s32");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tex.txt",
+                section: 0,
+                line: 11,
+                rule: "tex.grad.geom.v2.f16x2.ctype d{|p}, [a, {b,} c], dPdx, dPdy {, e} {, f};",
+            }
+        }
+    }
+}
@@ -0,0 +1,117 @@
+//! Original PTX specification:
+//!
+//! tld4.comp.2d.v4.dtype.f32    d{|p}, [a, c] {, e} {, f};
+//! tld4.comp.geom.v4.dtype.f32  d{|p}, [a, b, c] {, e} {, f};  // explicit sampler
+//! .comp  = { .r, .g, .b, .a };
+//! .geom  = { .2d, .a2d, .cube, .acube };
+//! .dtype = { .u32, .s32, .f32 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tld4::section_0::*;
+
+    impl Metadata for Tld4Comp2dV4DtypeF32 {
+        fn opcode(&self) -> &'static str {
+            "tld4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.comp {
+                Comp::R => {
+                    f(".r");
+                }
+                Comp::G => {
+                    f(".g");
+                }
+                Comp::B => {
+                    f(".b");
+                }
+                Comp::A => {
+                    f(".a");
+                }
+            }
+            f(".2d");
+            f(".v4");
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tld4.txt",
+                section: 0,
+                line: 1,
+                rule: "tld4.comp.2d.v4.dtype.f32 d{|p}, [a, c] {, e} {, f};",
+            }
+        }
+    }
+
+    impl Metadata for Tld4CompGeomV4DtypeF32 {
+        fn opcode(&self) -> &'static str {
+            "tld4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.comp {
+                Comp::R => {
+                    f(".r");
+                }
+                Comp::G => {
+                    f(".g");
+                }
+                Comp::B => {
+                    f(".b");
+                }
+                Comp::A => {
+                    f(".a");
+                }
+            }
+            match &self.geom {
+                Geom::Acube => {
+                    f(".acube");
+                }
+                Geom::Cube => {
+                    f(".cube");
+                }
+                Geom::A2d => {
+                    f(".a2d");
+                }
+                Geom::_2d => {
+                    f(".2d");
+                }
+            }
+            f(".v4");
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "tld4.txt",
+                section: 0,
+                line: 2,
+                rule: "tld4.comp.geom.v4.dtype.f32 d{|p}, [a, b, c] {, e} {, f};",
+            }
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Original PTX specification:
+//!
+//! trap;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::trap::section_0::*;
+
+    impl Metadata for Trap {
+        fn opcode(&self) -> &'static str {
+            "trap"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {}
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "trap.txt",
+                section: 0,
+                line: 1,
+                rule: "trap;",
+            }
+        }
+    }
+}
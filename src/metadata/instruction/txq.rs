@@ -0,0 +1,130 @@
+//! Original PTX specification:
+//!
+//! txq.tquery.b32         d, [a];       // texture attributes
+//! txq.level.tlquery.b32  d, [a], lod;  // texture attributes
+//! txq.squery.b32         d, [a];       // sampler attributes
+//! .tquery  = { .width, .height, .depth,
+//! .channel_data_type, .channel_order,
+//! .normalized_coords, .array_size,
+//! .num_mipmap_levels, .num_samples};
+//! .tlquery = { .width, .height, .depth };
+//! .squery  = { .force_unnormalized_coords, .filter_mode,
+//! .addr_mode_0, addr_mode_1, addr_mode_2 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::txq::section_0::*;
+
+    impl Metadata for TxqTqueryB32 {
+        fn opcode(&self) -> &'static str {
+            "txq"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.tquery {
+                Tquery::ChannelDataType => {
+                    f(".channel_data_type");
+                }
+                Tquery::NormalizedCoords => {
+                    f(".normalized_coords");
+                }
+                Tquery::NumMipmapLevels => {
+                    f(".num_mipmap_levels");
+                }
+                Tquery::ChannelOrder => {
+                    f(".channel_order");
+                }
+                Tquery::NumSamples => {
+                    f(".num_samples");
+                }
+                Tquery::ArraySize => {
+                    f(".array_size");
+                }
+                Tquery::Height => {
+                    f(".height");
+                }
+                Tquery::Width => {
+                    f(".width");
+                }
+                Tquery::Depth => {
+                    f(".depth");
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "txq.txt",
+                section: 0,
+                line: 1,
+                rule: "txq.tquery.b32 d, [a];",
+            }
+        }
+    }
+
+    impl Metadata for TxqLevelTlqueryB32 {
+        fn opcode(&self) -> &'static str {
+            "txq"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".level");
+            match &self.tlquery {
+                Tlquery::Height => {
+                    f(".height");
+                }
+                Tlquery::Width => {
+                    f(".width");
+                }
+                Tlquery::Depth => {
+                    f(".depth");
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "txq.txt",
+                section: 0,
+                line: 2,
+                rule: "txq.level.tlquery.b32 d, [a], lod;",
+            }
+        }
+    }
+
+    impl Metadata for TxqSqueryB32 {
+        fn opcode(&self) -> &'static str {
+            "txq"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.squery {
+                Squery::ForceUnnormalizedCoords => {
+                    f(".force_unnormalized_coords");
+                }
+                Squery::FilterMode => {
+                    f(".filter_mode");
+                }
+                Squery::AddrMode0 => {
+                    f(".addr_mode_0");
+                }
+                Squery::AddrMode1 => {
+                    f("addr_mode_1");
+                }
+                Squery::AddrMode2 => {
+                    f("addr_mode_2");
+                }
+            }
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "txq.txt",
+                section: 0,
+                line: 3,
+                rule: "txq.squery.b32 d, [a];",
+            }
+        }
+    }
+}
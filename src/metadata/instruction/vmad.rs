@@ -0,0 +1,125 @@
+//! Original PTX specification:
+//!
+//! // 32-bit scalar operation
+//! vmad.dtype.atype.btype{.sat}{.scale}     d, {-}a{.asel}, {-}b{.bsel},
+//! {-}c;
+//! vmad.dtype.atype.btype.po{.sat}{.scale}  d, a{.asel}, b{.bsel}, c;
+//! .dtype = .atype = .btype = { .u32, .s32 };
+//! .asel  = .bsel  = { .b0, .b1, .b2, .b3, .h0, .h1 };
+//! .scale = { .shr7, .shr15 };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::vmad::section_0::*;
+
+    impl Metadata for VmadDtypeAtypeBtypeSatScale {
+        fn opcode(&self) -> &'static str {
+            "vmad"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+            if let Some(scale_0) = self.scale.as_ref() {
+                match scale_0 {
+                    Scale::Shr15 => {
+                        f(".shr15");
+                    }
+                    Scale::Shr7 => {
+                        f(".shr7");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vmad.txt",
+                section: 0,
+                line: 2,
+                rule: "vmad.dtype.atype.btype{.sat}{.scale} d, {-}a{.asel}, {-}b{.bsel}, {-}c;",
+            }
+        }
+    }
+
+    impl Metadata for VmadDtypeAtypeBtypePoSatScale {
+        fn opcode(&self) -> &'static str {
+            "vmad"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".po");
+            if self.sat {
+                f(".sat");
+            }
+            if let Some(scale_1) = self.scale.as_ref() {
+                match scale_1 {
+                    Scale::Shr15 => {
+                        f(".shr15");
+                    }
+                    Scale::Shr7 => {
+                        f(".shr7");
+                    }
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vmad.txt",
+                section: 0,
+                line: 4,
+                rule: "vmad.dtype.atype.btype.po{.sat}{.scale} d, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+}
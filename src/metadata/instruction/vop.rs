@@ -0,0 +1,720 @@
+//! Original PTX specification:
+//!
+//! // 32-bit scalar operation, with optional secondary operation
+//! vop.dtype.atype.btype{.sat}       d, a{.asel}, b{.bsel};
+//! vop.dtype.atype.btype{.sat}.op2   d, a{.asel}, b{.bsel}, c;
+//! // 32-bit scalar operation, with optional data merge
+//! vop.dtype.atype.btype{.sat}  d.dsel, a{.asel}, b{.bsel}, c;
+//! vop   = { vadd, vsub, vabsdiff, vmin, vmax };
+//! .dtype = .atype = .btype = { .u32, .s32 };
+//! .dsel  = .asel  = .bsel  = { .b0, .b1, .b2, .b3, .h0, .h1 };
+//! .op2   = { .add, .min, .max };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::vop::section_0::*;
+
+    impl Metadata for VaddDtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vadd"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 2,
+                rule: "vop.dtype.atype.btype{.sat} d, a{.asel}, b{.bsel};",
+            }
+        }
+    }
+
+    impl Metadata for VsubDtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vsub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 2,
+                rule: "vop.dtype.atype.btype{.sat} d, a{.asel}, b{.bsel};",
+            }
+        }
+    }
+
+    impl Metadata for VabsdiffDtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vabsdiff"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 2,
+                rule: "vop.dtype.atype.btype{.sat} d, a{.asel}, b{.bsel};",
+            }
+        }
+    }
+
+    impl Metadata for VminDtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vmin"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 2,
+                rule: "vop.dtype.atype.btype{.sat} d, a{.asel}, b{.bsel};",
+            }
+        }
+    }
+
+    impl Metadata for VmaxDtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vmax"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 2,
+                rule: "vop.dtype.atype.btype{.sat} d, a{.asel}, b{.bsel};",
+            }
+        }
+    }
+
+    impl Metadata for VaddDtypeAtypeBtypeSatOp2 {
+        fn opcode(&self) -> &'static str {
+            "vadd"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+            match &self.op2 {
+                Op2::Add => {
+                    f(".add");
+                }
+                Op2::Min => {
+                    f(".min");
+                }
+                Op2::Max => {
+                    f(".max");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 3,
+                rule: "vop.dtype.atype.btype{.sat}.op2 d, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VsubDtypeAtypeBtypeSatOp2 {
+        fn opcode(&self) -> &'static str {
+            "vsub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+            match &self.op2 {
+                Op2::Add => {
+                    f(".add");
+                }
+                Op2::Min => {
+                    f(".min");
+                }
+                Op2::Max => {
+                    f(".max");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 3,
+                rule: "vop.dtype.atype.btype{.sat}.op2 d, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VabsdiffDtypeAtypeBtypeSatOp2 {
+        fn opcode(&self) -> &'static str {
+            "vabsdiff"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+            match &self.op2 {
+                Op2::Add => {
+                    f(".add");
+                }
+                Op2::Min => {
+                    f(".min");
+                }
+                Op2::Max => {
+                    f(".max");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 3,
+                rule: "vop.dtype.atype.btype{.sat}.op2 d, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VminDtypeAtypeBtypeSatOp2 {
+        fn opcode(&self) -> &'static str {
+            "vmin"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+            match &self.op2 {
+                Op2::Add => {
+                    f(".add");
+                }
+                Op2::Min => {
+                    f(".min");
+                }
+                Op2::Max => {
+                    f(".max");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 3,
+                rule: "vop.dtype.atype.btype{.sat}.op2 d, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VmaxDtypeAtypeBtypeSatOp2 {
+        fn opcode(&self) -> &'static str {
+            "vmax"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+            match &self.op2 {
+                Op2::Add => {
+                    f(".add");
+                }
+                Op2::Min => {
+                    f(".min");
+                }
+                Op2::Max => {
+                    f(".max");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 3,
+                rule: "vop.dtype.atype.btype{.sat}.op2 d, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VaddDtypeAtypeBtypeSat1 {
+        fn opcode(&self) -> &'static str {
+            "vadd"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 5,
+                rule: "vop.dtype.atype.btype{.sat} d.dsel, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VsubDtypeAtypeBtypeSat1 {
+        fn opcode(&self) -> &'static str {
+            "vsub"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 5,
+                rule: "vop.dtype.atype.btype{.sat} d.dsel, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VabsdiffDtypeAtypeBtypeSat1 {
+        fn opcode(&self) -> &'static str {
+            "vabsdiff"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 5,
+                rule: "vop.dtype.atype.btype{.sat} d.dsel, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VminDtypeAtypeBtypeSat1 {
+        fn opcode(&self) -> &'static str {
+            "vmin"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 5,
+                rule: "vop.dtype.atype.btype{.sat} d.dsel, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VmaxDtypeAtypeBtypeSat1 {
+        fn opcode(&self) -> &'static str {
+            "vmax"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop.txt",
+                section: 0,
+                line: 5,
+                rule: "vop.dtype.atype.btype{.sat} d.dsel, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+}
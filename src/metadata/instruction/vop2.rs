@@ -0,0 +1,525 @@
+//! Original PTX specification:
+//!
+//! // SIMD instruction with secondary SIMD merge operation
+//! vop2.dtype.atype.btype{.sat}  d{.mask}, a{.asel}, b{.bsel}, c;
+//! // SIMD instruction with secondary accumulate operation
+//! vop2.dtype.atype.btype.add  d{.mask}, a{.asel}, b{.bsel}, c;
+//! vop2  = { vadd2, vsub2, vavrg2, vabsdiff2, vmin2, vmax2 };
+//! .dtype = .atype = .btype = { .u32, .s32 };
+//! .mask  = { .h0, .h1, .h10 };  // defaults to .h10
+//! .asel  = .bsel  = { .h00, .h01, .h02, .h03, .h10, .h11, .h12, .h13, .h20, .h21, .h22, .h23, .h30, .h31, .h32, .h33 };
+//! // .asel defaults to .h10
+//! // .bsel defaults to .h32
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::vop2::section_0::*;
+
+    impl Metadata for Vadd2DtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vadd2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop2.txt",
+                section: 0,
+                line: 2,
+                rule: "vop2.dtype.atype.btype{.sat} d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vsub2DtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vsub2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop2.txt",
+                section: 0,
+                line: 2,
+                rule: "vop2.dtype.atype.btype{.sat} d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vavrg2DtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vavrg2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop2.txt",
+                section: 0,
+                line: 2,
+                rule: "vop2.dtype.atype.btype{.sat} d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vabsdiff2DtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vabsdiff2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop2.txt",
+                section: 0,
+                line: 2,
+                rule: "vop2.dtype.atype.btype{.sat} d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vmin2DtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vmin2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop2.txt",
+                section: 0,
+                line: 2,
+                rule: "vop2.dtype.atype.btype{.sat} d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vmax2DtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vmax2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop2.txt",
+                section: 0,
+                line: 2,
+                rule: "vop2.dtype.atype.btype{.sat} d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vadd2DtypeAtypeBtypeAdd {
+        fn opcode(&self) -> &'static str {
+            "vadd2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop2.txt",
+                section: 0,
+                line: 4,
+                rule: "vop2.dtype.atype.btype.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vsub2DtypeAtypeBtypeAdd {
+        fn opcode(&self) -> &'static str {
+            "vsub2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop2.txt",
+                section: 0,
+                line: 4,
+                rule: "vop2.dtype.atype.btype.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vavrg2DtypeAtypeBtypeAdd {
+        fn opcode(&self) -> &'static str {
+            "vavrg2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop2.txt",
+                section: 0,
+                line: 4,
+                rule: "vop2.dtype.atype.btype.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vabsdiff2DtypeAtypeBtypeAdd {
+        fn opcode(&self) -> &'static str {
+            "vabsdiff2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop2.txt",
+                section: 0,
+                line: 4,
+                rule: "vop2.dtype.atype.btype.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vmin2DtypeAtypeBtypeAdd {
+        fn opcode(&self) -> &'static str {
+            "vmin2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop2.txt",
+                section: 0,
+                line: 4,
+                rule: "vop2.dtype.atype.btype.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vmax2DtypeAtypeBtypeAdd {
+        fn opcode(&self) -> &'static str {
+            "vmax2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop2.txt",
+                section: 0,
+                line: 4,
+                rule: "vop2.dtype.atype.btype.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,530 @@
+//! Original PTX specification:
+//!
+//! // SIMD instruction with secondary SIMD merge operation
+//! vop4.dtype.atype.btype{.sat}  d{.mask}, a{.asel}, b{.bsel}, c;
+//! // SIMD instruction with secondary accumulate operation
+//! vop4.dtype.atype.btype.add  d{.mask}, a{.asel}, b{.bsel}, c;
+//! vop4  = { vadd4, vsub4, vavrg4, vabsdiff4, vmin4, vmax4 };
+//! .dtype = .atype = .btype = { .u32, .s32 };
+//! .mask  = { .b0,
+//! .b1, .b10,
+//! .b2, .b20, .b21, .b210,
+//! .b3, .b30, .b31, .b310, .b32, .b320, .b321, .b3210 };
+//! // defaults to .b3210
+//! .asel = .bsel = { .b.n.n.n.n };
+//! .n = { 0, 1, 2, 3, 4, 5, 6, 7};
+//! // .asel defaults to .b3210
+//! // .bsel defaults to .b7654
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::vop4::section_0::*;
+
+    impl Metadata for Vadd4DtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vadd4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop4.txt",
+                section: 0,
+                line: 2,
+                rule: "vop4.dtype.atype.btype{.sat} d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vsub4DtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vsub4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop4.txt",
+                section: 0,
+                line: 2,
+                rule: "vop4.dtype.atype.btype{.sat} d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vavrg4DtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vavrg4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop4.txt",
+                section: 0,
+                line: 2,
+                rule: "vop4.dtype.atype.btype{.sat} d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vabsdiff4DtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vabsdiff4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop4.txt",
+                section: 0,
+                line: 2,
+                rule: "vop4.dtype.atype.btype{.sat} d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vmin4DtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vmin4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop4.txt",
+                section: 0,
+                line: 2,
+                rule: "vop4.dtype.atype.btype{.sat} d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vmax4DtypeAtypeBtypeSat {
+        fn opcode(&self) -> &'static str {
+            "vmax4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            if self.sat {
+                f(".sat");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop4.txt",
+                section: 0,
+                line: 2,
+                rule: "vop4.dtype.atype.btype{.sat} d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vadd4DtypeAtypeBtypeAdd {
+        fn opcode(&self) -> &'static str {
+            "vadd4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop4.txt",
+                section: 0,
+                line: 4,
+                rule: "vop4.dtype.atype.btype.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vsub4DtypeAtypeBtypeAdd {
+        fn opcode(&self) -> &'static str {
+            "vsub4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop4.txt",
+                section: 0,
+                line: 4,
+                rule: "vop4.dtype.atype.btype.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vavrg4DtypeAtypeBtypeAdd {
+        fn opcode(&self) -> &'static str {
+            "vavrg4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop4.txt",
+                section: 0,
+                line: 4,
+                rule: "vop4.dtype.atype.btype.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vabsdiff4DtypeAtypeBtypeAdd {
+        fn opcode(&self) -> &'static str {
+            "vabsdiff4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop4.txt",
+                section: 0,
+                line: 4,
+                rule: "vop4.dtype.atype.btype.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vmin4DtypeAtypeBtypeAdd {
+        fn opcode(&self) -> &'static str {
+            "vmin4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop4.txt",
+                section: 0,
+                line: 4,
+                rule: "vop4.dtype.atype.btype.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vmax4DtypeAtypeBtypeAdd {
+        fn opcode(&self) -> &'static str {
+            "vmax4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vop4.txt",
+                section: 0,
+                line: 4,
+                rule: "vop4.dtype.atype.btype.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Original PTX specification:
+//!
+//! vote.mode.pred  d, {!}a;
+//! vote.ballot.b32 d, {!}a;  // 'ballot' form, returns bitmask
+//! .mode = { .all, .any, .uni };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::vote::section_0::*;
+
+    impl Metadata for VoteModePred {
+        fn opcode(&self) -> &'static str {
+            "vote"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.mode {
+                Mode::All => {
+                    f(".all");
+                }
+                Mode::Any => {
+                    f(".any");
+                }
+                Mode::Uni => {
+                    f(".uni");
+                }
+            }
+            f(".pred");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vote.txt",
+                section: 0,
+                line: 1,
+                rule: "vote.mode.pred d, {!}a;",
+            }
+        }
+    }
+
+    impl Metadata for VoteBallotB32 {
+        fn opcode(&self) -> &'static str {
+            "vote"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".ballot");
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vote.txt",
+                section: 0,
+                line: 2,
+                rule: "vote.ballot.b32 d, {!}a;",
+            }
+        }
+    }
+}
@@ -0,0 +1,62 @@
+//! Original PTX specification:
+//!
+//! vote.sync.mode.pred  d, {!}a, membermask;
+//! vote.sync.ballot.b32 d, {!}a, membermask;  // 'ballot' form, returns bitmask
+//! .mode = { .all, .any, .uni };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::vote_sync::section_0::*;
+
+    impl Metadata for VoteSyncModePred {
+        fn opcode(&self) -> &'static str {
+            "vote"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            match &self.mode {
+                Mode::All => {
+                    f(".all");
+                }
+                Mode::Any => {
+                    f(".any");
+                }
+                Mode::Uni => {
+                    f(".uni");
+                }
+            }
+            f(".pred");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vote.sync.txt",
+                section: 0,
+                line: 1,
+                rule: "vote.sync.mode.pred d, {!}a, membermask;",
+            }
+        }
+    }
+
+    impl Metadata for VoteSyncBallotB32 {
+        fn opcode(&self) -> &'static str {
+            "vote"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".sync");
+            f(".ballot");
+            f(".b32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vote.sync.txt",
+                section: 0,
+                line: 2,
+                rule: "vote.sync.ballot.b32 d, {!}a, membermask;",
+            }
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! Original PTX specification:
+//!
+//! // 32-bit scalar operation, with optional secondary operation
+//! vset.atype.btype.cmp       d, a{.asel}, b{.bsel};
+//! vset.atype.btype.cmp.op2   d, a{.asel}, b{.bsel}, c;
+//! // 32-bit scalar operation, with optional data merge
+//! vset.atype.btype.cmp  d.dsel, a{.asel}, b{.bsel}, c;
+//! .atype = .btype = { .u32, .s32 };
+//! .cmp   = { .eq, .ne, .lt, .le, .gt, .ge };
+//! .dsel  = .asel  = .bsel  = { .b0, .b1, .b2, .b3, .h0, .h1 };
+//! .op2   = { .add, .min, .max };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::vset::section_0::*;
+
+    impl Metadata for VsetAtypeBtypeCmp {
+        fn opcode(&self) -> &'static str {
+            "vset"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.cmp {
+                Cmp::Eq => {
+                    f(".eq");
+                }
+                Cmp::Ne => {
+                    f(".ne");
+                }
+                Cmp::Lt => {
+                    f(".lt");
+                }
+                Cmp::Le => {
+                    f(".le");
+                }
+                Cmp::Gt => {
+                    f(".gt");
+                }
+                Cmp::Ge => {
+                    f(".ge");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vset.txt",
+                section: 0,
+                line: 2,
+                rule: "vset.atype.btype.cmp d, a{.asel}, b{.bsel};",
+            }
+        }
+    }
+
+    impl Metadata for VsetAtypeBtypeCmpOp2 {
+        fn opcode(&self) -> &'static str {
+            "vset"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.cmp {
+                Cmp::Eq => {
+                    f(".eq");
+                }
+                Cmp::Ne => {
+                    f(".ne");
+                }
+                Cmp::Lt => {
+                    f(".lt");
+                }
+                Cmp::Le => {
+                    f(".le");
+                }
+                Cmp::Gt => {
+                    f(".gt");
+                }
+                Cmp::Ge => {
+                    f(".ge");
+                }
+            }
+            match &self.op2 {
+                Op2::Add => {
+                    f(".add");
+                }
+                Op2::Min => {
+                    f(".min");
+                }
+                Op2::Max => {
+                    f(".max");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vset.txt",
+                section: 0,
+                line: 3,
+                rule: "vset.atype.btype.cmp.op2 d, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VsetAtypeBtypeCmp1 {
+        fn opcode(&self) -> &'static str {
+            "vset"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.cmp {
+                Cmp::Eq => {
+                    f(".eq");
+                }
+                Cmp::Ne => {
+                    f(".ne");
+                }
+                Cmp::Lt => {
+                    f(".lt");
+                }
+                Cmp::Le => {
+                    f(".le");
+                }
+                Cmp::Gt => {
+                    f(".gt");
+                }
+                Cmp::Ge => {
+                    f(".ge");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vset.txt",
+                section: 0,
+                line: 5,
+                rule: "vset.atype.btype.cmp d.dsel, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+}
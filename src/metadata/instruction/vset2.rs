@@ -0,0 +1,126 @@
+//! Original PTX specification:
+//!
+//! // SIMD instruction with secondary SIMD merge operation
+//! vset2.atype.btype.cmp  d{.mask}, a{.asel}, b{.bsel}, c;
+//! // SIMD instruction with secondary accumulate operation
+//! vset2.atype.btype.cmp.add  d{.mask}, a{.asel}, b{.bsel}, c;
+//! .atype = .btype = { .u32, .s32 };
+//! .cmp   = { .eq, .ne, .lt, .le, .gt, .ge };
+//! .mask  = { .h0, .h1, .h10 };  // defaults to .h10
+//! .asel  = .bsel  = { .h00, .h01, .h02, .h03, .h10, .h11, .h12, .h13, .h20, .h21, .h22, .h23, .h30, .h31, .h32, .h33 }; // { .hxy, where x,y are from { 0, 1, 2, 3 } };
+//! // .asel defaults to .h10
+//! // .bsel defaults to .h32
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::vset2::section_0::*;
+
+    impl Metadata for Vset2AtypeBtypeCmp {
+        fn opcode(&self) -> &'static str {
+            "vset2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.cmp {
+                Cmp::Eq => {
+                    f(".eq");
+                }
+                Cmp::Ne => {
+                    f(".ne");
+                }
+                Cmp::Lt => {
+                    f(".lt");
+                }
+                Cmp::Le => {
+                    f(".le");
+                }
+                Cmp::Gt => {
+                    f(".gt");
+                }
+                Cmp::Ge => {
+                    f(".ge");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vset2.txt",
+                section: 0,
+                line: 2,
+                rule: "vset2.atype.btype.cmp d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vset2AtypeBtypeCmpAdd {
+        fn opcode(&self) -> &'static str {
+            "vset2"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.cmp {
+                Cmp::Eq => {
+                    f(".eq");
+                }
+                Cmp::Ne => {
+                    f(".ne");
+                }
+                Cmp::Lt => {
+                    f(".lt");
+                }
+                Cmp::Le => {
+                    f(".le");
+                }
+                Cmp::Gt => {
+                    f(".gt");
+                }
+                Cmp::Ge => {
+                    f(".ge");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vset2.txt",
+                section: 0,
+                line: 4,
+                rule: "vset2.atype.btype.cmp.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+}
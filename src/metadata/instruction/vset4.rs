@@ -0,0 +1,138 @@
+//! Original PTX specification:
+//!
+//! // SIMD instruction with secondary SIMD merge operation
+//! vset4.atype.btype.cmp  d{.mask}, a{.asel}, b{.bsel}, c;
+//! // SIMD instruction with secondary accumulate operation
+//! vset4.atype.btype.cmp.add  d{.mask}, a{.asel}, b{.bsel}, c;
+//! .atype = .btype = { .u32, .s32 };
+//! .cmp   = { .eq, .ne, .lt, .le, .gt, .ge };
+//! .mask  = { .b0,
+//! .b1, .b10
+//! .b2, .b20, .b21, .b210,
+//! .b3, .b30, .b31, .b310, .b32, .b320, .b321, .b3210 };
+//! defaults to .b3210
+//! .asel = .bsel = { .b00, .b01, .b02, .b03, .b04, .b05, .b06, .b07,
+//!                   .b10, .b11, .b12, .b13, .b14, .b15, .b16, .b17,
+//!                   .b20, .b21, .b22, .b23, .b24, .b25, .b26, .b27,
+//!                   .b30, .b31, .b32, .b33, .b34, .b35, .b36, .b37,
+//!                   .b40, .b41, .b42, .b43, .b44, .b45, .b46, .b47,
+//!                   .b50, .b51, .b52, .b53, .b54, .b55, .b56, .b57,
+//!                   .b60, .b61, .b62, .b63, .b64, .b65, .b66, .b67,
+//!                   .b70, .b71, .b72, .b73, .b74, .b75, .b76, .b77
+//!                   } //.bxyzw, where x,y,z,w are from { 0, ..., 7 };
+//! // .asel defaults to .b3210
+//! // .bsel defaults to .b7654
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::vset4::section_0::*;
+
+    impl Metadata for Vset4AtypeBtypeCmp {
+        fn opcode(&self) -> &'static str {
+            "vset4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.cmp {
+                Cmp::Eq => {
+                    f(".eq");
+                }
+                Cmp::Ne => {
+                    f(".ne");
+                }
+                Cmp::Lt => {
+                    f(".lt");
+                }
+                Cmp::Le => {
+                    f(".le");
+                }
+                Cmp::Gt => {
+                    f(".gt");
+                }
+                Cmp::Ge => {
+                    f(".ge");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vset4.txt",
+                section: 0,
+                line: 2,
+                rule: "vset4.atype.btype.cmp d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for Vset4AtypeBtypeCmpAdd {
+        fn opcode(&self) -> &'static str {
+            "vset4"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.btype {
+                Btype::U32 => {
+                    f(".u32");
+                }
+                Btype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.cmp {
+                Cmp::Eq => {
+                    f(".eq");
+                }
+                Cmp::Ne => {
+                    f(".ne");
+                }
+                Cmp::Lt => {
+                    f(".lt");
+                }
+                Cmp::Le => {
+                    f(".le");
+                }
+                Cmp::Gt => {
+                    f(".gt");
+                }
+                Cmp::Ge => {
+                    f(".ge");
+                }
+            }
+            f(".add");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vset4.txt",
+                section: 0,
+                line: 4,
+                rule: "vset4.atype.btype.cmp.add d{.mask}, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+}
@@ -0,0 +1,307 @@
+//! Original PTX specification:
+//!
+//! // 32-bit scalar operation, with optional secondary operation
+//! vop.dtype.atype.u32{.sat}.mode       d, a{.asel}, b{.bsel};
+//! vop.dtype.atype.u32{.sat}.mode.op2   d, a{.asel}, b{.bsel}, c;
+//! // 32-bit scalar operation, with optional data merge
+//! vop.dtype.atype.u32{.sat}.mode  d.dsel, a{.asel}, b{.bsel}, c;
+//! vop   = { vshl, vshr };
+//! .dtype = .atype = { .u32, .s32 };
+//! .mode  = { .clamp, .wrap };
+//! .dsel  = .asel  = .bsel  = { .b0, .b1, .b2, .b3, .h0, .h1 };
+//! .op2   = { .add, .min, .max };
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::vsh::section_0::*;
+
+    impl Metadata for VshlDtypeAtypeU32SatMode {
+        fn opcode(&self) -> &'static str {
+            "vshl"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".u32");
+            if self.sat {
+                f(".sat");
+            }
+            match &self.mode {
+                Mode::Clamp => {
+                    f(".clamp");
+                }
+                Mode::Wrap => {
+                    f(".wrap");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vsh.txt",
+                section: 0,
+                line: 2,
+                rule: "vop.dtype.atype.u32{.sat}.mode d, a{.asel}, b{.bsel};",
+            }
+        }
+    }
+
+    impl Metadata for VshrDtypeAtypeU32SatMode {
+        fn opcode(&self) -> &'static str {
+            "vshr"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".u32");
+            if self.sat {
+                f(".sat");
+            }
+            match &self.mode {
+                Mode::Clamp => {
+                    f(".clamp");
+                }
+                Mode::Wrap => {
+                    f(".wrap");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vsh.txt",
+                section: 0,
+                line: 2,
+                rule: "vop.dtype.atype.u32{.sat}.mode d, a{.asel}, b{.bsel};",
+            }
+        }
+    }
+
+    impl Metadata for VshlDtypeAtypeU32SatModeOp2 {
+        fn opcode(&self) -> &'static str {
+            "vshl"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".u32");
+            if self.sat {
+                f(".sat");
+            }
+            match &self.mode {
+                Mode::Clamp => {
+                    f(".clamp");
+                }
+                Mode::Wrap => {
+                    f(".wrap");
+                }
+            }
+            match &self.op2 {
+                Op2::Add => {
+                    f(".add");
+                }
+                Op2::Min => {
+                    f(".min");
+                }
+                Op2::Max => {
+                    f(".max");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vsh.txt",
+                section: 0,
+                line: 3,
+                rule: "vop.dtype.atype.u32{.sat}.mode.op2 d, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VshrDtypeAtypeU32SatModeOp2 {
+        fn opcode(&self) -> &'static str {
+            "vshr"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".u32");
+            if self.sat {
+                f(".sat");
+            }
+            match &self.mode {
+                Mode::Clamp => {
+                    f(".clamp");
+                }
+                Mode::Wrap => {
+                    f(".wrap");
+                }
+            }
+            match &self.op2 {
+                Op2::Add => {
+                    f(".add");
+                }
+                Op2::Min => {
+                    f(".min");
+                }
+                Op2::Max => {
+                    f(".max");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vsh.txt",
+                section: 0,
+                line: 3,
+                rule: "vop.dtype.atype.u32{.sat}.mode.op2 d, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VshlDtypeAtypeU32SatMode1 {
+        fn opcode(&self) -> &'static str {
+            "vshl"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".u32");
+            if self.sat {
+                f(".sat");
+            }
+            match &self.mode {
+                Mode::Clamp => {
+                    f(".clamp");
+                }
+                Mode::Wrap => {
+                    f(".wrap");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vsh.txt",
+                section: 0,
+                line: 5,
+                rule: "vop.dtype.atype.u32{.sat}.mode d.dsel, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+
+    impl Metadata for VshrDtypeAtypeU32SatMode1 {
+        fn opcode(&self) -> &'static str {
+            "vshr"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            match &self.dtype {
+                Dtype::U32 => {
+                    f(".u32");
+                }
+                Dtype::S32 => {
+                    f(".s32");
+                }
+            }
+            match &self.atype {
+                Atype::U32 => {
+                    f(".u32");
+                }
+                Atype::S32 => {
+                    f(".s32");
+                }
+            }
+            f(".u32");
+            if self.sat {
+                f(".sat");
+            }
+            match &self.mode {
+                Mode::Clamp => {
+                    f(".clamp");
+                }
+                Mode::Wrap => {
+                    f(".wrap");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "vsh.txt",
+                section: 0,
+                line: 5,
+                rule: "vop.dtype.atype.u32{.sat}.mode d.dsel, a{.asel}, b{.bsel}, c;",
+            }
+        }
+    }
+}
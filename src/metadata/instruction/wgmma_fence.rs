@@ -0,0 +1,31 @@
+//! Original PTX specification:
+//!
+//! wgmma.fence.sync.aligned;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_fence::section_0::*;
+
+    impl Metadata for WgmmaFenceSyncAligned {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".fence");
+            f(".sync");
+            f(".aligned");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.fence.txt",
+                section: 0,
+                line: 1,
+                rule: "wgmma.fence.sync.aligned;",
+            }
+        }
+    }
+}
@@ -0,0 +1,1488 @@
+//! Original PTX specification:
+//!
+//! // Half precision floating point type:
+//! wgmma.mma_async.sync.aligned.shape.dtype.f16.f16  d, a-desc, b-desc, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;
+//! wgmma.mma_async.sync.aligned.shape.dtype.f16.f16  d, a, b-desc, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;
+//! .shape   = {.m64n8k16, .m64n16k16, .m64n24k16, .m64n32k16,
+//! .m64n40k16, .m64n48k16, .m64n56k16, .m64n64k16,
+//! .m64n72k16, .m64n80k16, .m64n88k16, .m64n96k16,
+//! .m64n104k16, .m64n112k16, .m64n120k16, .m64n128k16,
+//! .m64n136k16, .m64n144k16, .m64n152k16, .m64n160k16,
+//! .m64n168k16, .m64n176k16, .m64n184k16, .m64n192k16,
+//! .m64n200k16, .m64n208k16, .m64n216k16, .m64n224k16,
+//! .m64n232k16, .m64n240k16, .m64n248k16, .m64n256k16};
+//! .dtype   = {.f16, .f32};
+//! ------------------------------------------------------------------
+//! // Alternate floating point type :
+//! // .bf16 floating point type:
+//! wgmma.mma_async.sync.aligned.shape.dtype.bf16.bf16  d, a-desc, b-desc, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;
+//! wgmma.mma_async.sync.aligned.shape.dtype.bf16.bf16  d, a, b-desc, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;
+//! .shape   = {.m64n8k16, .m64n16k16, .m64n24k16, .m64n32k16,
+//! .m64n40k16, .m64n48k16, .m64n56k16, .m64n64k16,
+//! .m64n72k16, .m64n80k16, .m64n88k16, .m64n96k16,
+//! .m64n104k16, .m64n112k16, .m64n120k16, .m64n128k16,
+//! .m64n136k16, .m64n144k16, .m64n152k16, .m64n160k16,
+//! .m64n168k16, .m64n176k16, .m64n184k16, .m64n192k16,
+//! .m64n200k16, .m64n208k16, .m64n216k16, .m64n224k16,
+//! .m64n232k16, .m64n240k16, .m64n248k16, .m64n256k16};
+//! .dtype  = {.f32};
+//! ------------------------------------------------------------------
+//! // .tf32 floating point type:
+//! wgmma.mma_async.sync.aligned.shape.dtype.tf32.tf32  d, a-desc, b-desc, scale-d, imm-scale-a, imm-scale-b;
+//! wgmma.mma_async.sync.aligned.shape.dtype.tf32.tf32  d, a, b-desc, scale-d, imm-scale-a, imm-scale-b;
+//! .shape   = {.m64n8k8, .m64n16k8, .m64n24k8, .m64n32k8,
+//! .m64n40k8, .m64n48k8, .m64n56k8, .m64n64k8,
+//! .m64n72k8, .m64n80k8, .m64n88k8, .m64n96k8,
+//! .m64n104k8, .m64n112k8, .m64n120k8, .m64n128k8,
+//! .m64n136k8, .m64n144k8, .m64n152k8, .m64n160k8,
+//! .m64n168k8, .m64n176k8, .m64n184k8, .m64n192k8,
+//! .m64n200k8, .m64n208k8, .m64n216k8, .m64n224k8,
+//! .m64n232k8, .m64n240k8, .m64n248k8, .m64n256k8};
+//! .dtype  = {.f32};
+//! ------------------------------------------------------------------
+//! // FP8 floating point type
+//! wgmma.mma_async.sync.aligned.shape.dtype.atype.btype  d, a-desc, b-desc, scale-d, imm-scale-a, imm-scale-b;
+//! wgmma.mma_async.sync.aligned.shape.dtype.atype.btype  d, a, b-desc, scale-d, imm-scale-a, imm-scale-b;
+//! .shape   = {.m64n8k32, .m64n16k32, .m64n24k32, .m64n32k32,
+//! .m64n40k32, .m64n48k32, .m64n56k32, .m64n64k32,
+//! .m64n72k32, .m64n80k32, .m64n88k32, .m64n96k32,
+//! .m64n104k32, .m64n112k32, .m64n120k32, .m64n128k32,
+//! .m64n136k32, .m64n144k32, .m64n152k32, .m64n160k32,
+//! .m64n168k32, .m64n176k32, .m64n184k32, .m64n192k32,
+//! .m64n200k32, .m64n208k32, .m64n216k32, .m64n224k32,
+//! .m64n232k32, .m64n240k32, .m64n248k32, .m64n256k32};
+//! .atype  = {.e4m3, .e5m2};
+//! .btype  = {.e4m3, .e5m2};
+//! .dtype  = {.f16, .f32};
+//! ------------------------------------------------------------------
+//! // Integer type:
+//! wgmma.mma_async.sync.aligned.shape{.satfinite}.s32.atype.btype  d, a-desc, b-desc, scale-d;
+//! wgmma.mma_async.sync.aligned.shape{.satfinite}.s32.atype.btype  d, a, b-desc, scale-d;
+//! .shape   = {.m64n8k32, .m64n16k32, .m64n24k32, .m64n32k32,
+//! .m64n48k32, .m64n64k32, .m64n80k32, .m64n96k32,
+//! .m64n112k32, .m64n128k32, .m64n144k32, .m64n160k32,
+//! .m64n176k32, .m64n192k32, .m64n208k32, .m64n224k32};
+//! .atype  = {.s8, .u8};
+//! .btype  = {.s8, .u8};
+//! ------------------------------------------------------------------
+//! // Single bit:
+//! wgmma.mma_async.sync.aligned.shape.s32.b1.b1.op.popc  d, a-desc, b-desc, scale-d;
+//! wgmma.mma_async.sync.aligned.shape.s32.b1.b1.op.popc  d, a, b-desc, scale-d;
+//! .shape   = {.m64n8k256, .m64n16k256, .m64n24k256, .m64n32k256,
+//! .m64n48k256, .m64n64k256, .m64n80k256, .m64n96k256,
+//! .m64n112k256, .m64n128k256, .m64n144k256, .m64n160k256,
+//! .m64n176k256, .m64n192k256, .m64n208k256, .m64n224k256,
+//! .m64n240k256, .m64n256k256};
+//! .op  = {.and};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async::section_0::*;
+
+    impl Metadata for WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k16 => {
+                    f(".m64n104k16");
+                }
+                Shape::M64n112k16 => {
+                    f(".m64n112k16");
+                }
+                Shape::M64n120k16 => {
+                    f(".m64n120k16");
+                }
+                Shape::M64n128k16 => {
+                    f(".m64n128k16");
+                }
+                Shape::M64n136k16 => {
+                    f(".m64n136k16");
+                }
+                Shape::M64n144k16 => {
+                    f(".m64n144k16");
+                }
+                Shape::M64n152k16 => {
+                    f(".m64n152k16");
+                }
+                Shape::M64n160k16 => {
+                    f(".m64n160k16");
+                }
+                Shape::M64n168k16 => {
+                    f(".m64n168k16");
+                }
+                Shape::M64n176k16 => {
+                    f(".m64n176k16");
+                }
+                Shape::M64n184k16 => {
+                    f(".m64n184k16");
+                }
+                Shape::M64n192k16 => {
+                    f(".m64n192k16");
+                }
+                Shape::M64n200k16 => {
+                    f(".m64n200k16");
+                }
+                Shape::M64n208k16 => {
+                    f(".m64n208k16");
+                }
+                Shape::M64n216k16 => {
+                    f(".m64n216k16");
+                }
+                Shape::M64n224k16 => {
+                    f(".m64n224k16");
+                }
+                Shape::M64n232k16 => {
+                    f(".m64n232k16");
+                }
+                Shape::M64n240k16 => {
+                    f(".m64n240k16");
+                }
+                Shape::M64n248k16 => {
+                    f(".m64n248k16");
+                }
+                Shape::M64n256k16 => {
+                    f(".m64n256k16");
+                }
+                Shape::M64n16k16 => {
+                    f(".m64n16k16");
+                }
+                Shape::M64n24k16 => {
+                    f(".m64n24k16");
+                }
+                Shape::M64n32k16 => {
+                    f(".m64n32k16");
+                }
+                Shape::M64n40k16 => {
+                    f(".m64n40k16");
+                }
+                Shape::M64n48k16 => {
+                    f(".m64n48k16");
+                }
+                Shape::M64n56k16 => {
+                    f(".m64n56k16");
+                }
+                Shape::M64n64k16 => {
+                    f(".m64n64k16");
+                }
+                Shape::M64n72k16 => {
+                    f(".m64n72k16");
+                }
+                Shape::M64n80k16 => {
+                    f(".m64n80k16");
+                }
+                Shape::M64n88k16 => {
+                    f(".m64n88k16");
+                }
+                Shape::M64n96k16 => {
+                    f(".m64n96k16");
+                }
+                Shape::M64n8k16 => {
+                    f(".m64n8k16");
+                }
+            }
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".f16");
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.txt",
+                section: 0,
+                line: 2,
+                rule: "wgmma.mma_async.sync.aligned.shape.dtype.f16.f16 d, a-desc, b-desc, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;",
+            }
+        }
+    }
+
+    impl Metadata for WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k16 => {
+                    f(".m64n104k16");
+                }
+                Shape::M64n112k16 => {
+                    f(".m64n112k16");
+                }
+                Shape::M64n120k16 => {
+                    f(".m64n120k16");
+                }
+                Shape::M64n128k16 => {
+                    f(".m64n128k16");
+                }
+                Shape::M64n136k16 => {
+                    f(".m64n136k16");
+                }
+                Shape::M64n144k16 => {
+                    f(".m64n144k16");
+                }
+                Shape::M64n152k16 => {
+                    f(".m64n152k16");
+                }
+                Shape::M64n160k16 => {
+                    f(".m64n160k16");
+                }
+                Shape::M64n168k16 => {
+                    f(".m64n168k16");
+                }
+                Shape::M64n176k16 => {
+                    f(".m64n176k16");
+                }
+                Shape::M64n184k16 => {
+                    f(".m64n184k16");
+                }
+                Shape::M64n192k16 => {
+                    f(".m64n192k16");
+                }
+                Shape::M64n200k16 => {
+                    f(".m64n200k16");
+                }
+                Shape::M64n208k16 => {
+                    f(".m64n208k16");
+                }
+                Shape::M64n216k16 => {
+                    f(".m64n216k16");
+                }
+                Shape::M64n224k16 => {
+                    f(".m64n224k16");
+                }
+                Shape::M64n232k16 => {
+                    f(".m64n232k16");
+                }
+                Shape::M64n240k16 => {
+                    f(".m64n240k16");
+                }
+                Shape::M64n248k16 => {
+                    f(".m64n248k16");
+                }
+                Shape::M64n256k16 => {
+                    f(".m64n256k16");
+                }
+                Shape::M64n16k16 => {
+                    f(".m64n16k16");
+                }
+                Shape::M64n24k16 => {
+                    f(".m64n24k16");
+                }
+                Shape::M64n32k16 => {
+                    f(".m64n32k16");
+                }
+                Shape::M64n40k16 => {
+                    f(".m64n40k16");
+                }
+                Shape::M64n48k16 => {
+                    f(".m64n48k16");
+                }
+                Shape::M64n56k16 => {
+                    f(".m64n56k16");
+                }
+                Shape::M64n64k16 => {
+                    f(".m64n64k16");
+                }
+                Shape::M64n72k16 => {
+                    f(".m64n72k16");
+                }
+                Shape::M64n80k16 => {
+                    f(".m64n80k16");
+                }
+                Shape::M64n88k16 => {
+                    f(".m64n88k16");
+                }
+                Shape::M64n96k16 => {
+                    f(".m64n96k16");
+                }
+                Shape::M64n8k16 => {
+                    f(".m64n8k16");
+                }
+            }
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".f16");
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.txt",
+                section: 0,
+                line: 3,
+                rule: "wgmma.mma_async.sync.aligned.shape.dtype.f16.f16 d, a, b-desc, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async::section_1::*;
+
+    impl Metadata for WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k16 => {
+                    f(".m64n104k16");
+                }
+                Shape::M64n112k16 => {
+                    f(".m64n112k16");
+                }
+                Shape::M64n120k16 => {
+                    f(".m64n120k16");
+                }
+                Shape::M64n128k16 => {
+                    f(".m64n128k16");
+                }
+                Shape::M64n136k16 => {
+                    f(".m64n136k16");
+                }
+                Shape::M64n144k16 => {
+                    f(".m64n144k16");
+                }
+                Shape::M64n152k16 => {
+                    f(".m64n152k16");
+                }
+                Shape::M64n160k16 => {
+                    f(".m64n160k16");
+                }
+                Shape::M64n168k16 => {
+                    f(".m64n168k16");
+                }
+                Shape::M64n176k16 => {
+                    f(".m64n176k16");
+                }
+                Shape::M64n184k16 => {
+                    f(".m64n184k16");
+                }
+                Shape::M64n192k16 => {
+                    f(".m64n192k16");
+                }
+                Shape::M64n200k16 => {
+                    f(".m64n200k16");
+                }
+                Shape::M64n208k16 => {
+                    f(".m64n208k16");
+                }
+                Shape::M64n216k16 => {
+                    f(".m64n216k16");
+                }
+                Shape::M64n224k16 => {
+                    f(".m64n224k16");
+                }
+                Shape::M64n232k16 => {
+                    f(".m64n232k16");
+                }
+                Shape::M64n240k16 => {
+                    f(".m64n240k16");
+                }
+                Shape::M64n248k16 => {
+                    f(".m64n248k16");
+                }
+                Shape::M64n256k16 => {
+                    f(".m64n256k16");
+                }
+                Shape::M64n16k16 => {
+                    f(".m64n16k16");
+                }
+                Shape::M64n24k16 => {
+                    f(".m64n24k16");
+                }
+                Shape::M64n32k16 => {
+                    f(".m64n32k16");
+                }
+                Shape::M64n40k16 => {
+                    f(".m64n40k16");
+                }
+                Shape::M64n48k16 => {
+                    f(".m64n48k16");
+                }
+                Shape::M64n56k16 => {
+                    f(".m64n56k16");
+                }
+                Shape::M64n64k16 => {
+                    f(".m64n64k16");
+                }
+                Shape::M64n72k16 => {
+                    f(".m64n72k16");
+                }
+                Shape::M64n80k16 => {
+                    f(".m64n80k16");
+                }
+                Shape::M64n88k16 => {
+                    f(".m64n88k16");
+                }
+                Shape::M64n96k16 => {
+                    f(".m64n96k16");
+                }
+                Shape::M64n8k16 => {
+                    f(".m64n8k16");
+                }
+            }
+            match &self.dtype {
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".bf16");
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.txt",
+                section: 1,
+                line: 16,
+                rule: "wgmma.mma_async.sync.aligned.shape.dtype.bf16.bf16 d, a-desc, b-desc, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;",
+            }
+        }
+    }
+
+    impl Metadata for WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k16 => {
+                    f(".m64n104k16");
+                }
+                Shape::M64n112k16 => {
+                    f(".m64n112k16");
+                }
+                Shape::M64n120k16 => {
+                    f(".m64n120k16");
+                }
+                Shape::M64n128k16 => {
+                    f(".m64n128k16");
+                }
+                Shape::M64n136k16 => {
+                    f(".m64n136k16");
+                }
+                Shape::M64n144k16 => {
+                    f(".m64n144k16");
+                }
+                Shape::M64n152k16 => {
+                    f(".m64n152k16");
+                }
+                Shape::M64n160k16 => {
+                    f(".m64n160k16");
+                }
+                Shape::M64n168k16 => {
+                    f(".m64n168k16");
+                }
+                Shape::M64n176k16 => {
+                    f(".m64n176k16");
+                }
+                Shape::M64n184k16 => {
+                    f(".m64n184k16");
+                }
+                Shape::M64n192k16 => {
+                    f(".m64n192k16");
+                }
+                Shape::M64n200k16 => {
+                    f(".m64n200k16");
+                }
+                Shape::M64n208k16 => {
+                    f(".m64n208k16");
+                }
+                Shape::M64n216k16 => {
+                    f(".m64n216k16");
+                }
+                Shape::M64n224k16 => {
+                    f(".m64n224k16");
+                }
+                Shape::M64n232k16 => {
+                    f(".m64n232k16");
+                }
+                Shape::M64n240k16 => {
+                    f(".m64n240k16");
+                }
+                Shape::M64n248k16 => {
+                    f(".m64n248k16");
+                }
+                Shape::M64n256k16 => {
+                    f(".m64n256k16");
+                }
+                Shape::M64n16k16 => {
+                    f(".m64n16k16");
+                }
+                Shape::M64n24k16 => {
+                    f(".m64n24k16");
+                }
+                Shape::M64n32k16 => {
+                    f(".m64n32k16");
+                }
+                Shape::M64n40k16 => {
+                    f(".m64n40k16");
+                }
+                Shape::M64n48k16 => {
+                    f(".m64n48k16");
+                }
+                Shape::M64n56k16 => {
+                    f(".m64n56k16");
+                }
+                Shape::M64n64k16 => {
+                    f(".m64n64k16");
+                }
+                Shape::M64n72k16 => {
+                    f(".m64n72k16");
+                }
+                Shape::M64n80k16 => {
+                    f(".m64n80k16");
+                }
+                Shape::M64n88k16 => {
+                    f(".m64n88k16");
+                }
+                Shape::M64n96k16 => {
+                    f(".m64n96k16");
+                }
+                Shape::M64n8k16 => {
+                    f(".m64n8k16");
+                }
+            }
+            match &self.dtype {
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".bf16");
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.txt",
+                section: 1,
+                line: 17,
+                rule: "wgmma.mma_async.sync.aligned.shape.dtype.bf16.bf16 d, a, b-desc, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async::section_2::*;
+
+    impl Metadata for WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k8 => {
+                    f(".m64n104k8");
+                }
+                Shape::M64n112k8 => {
+                    f(".m64n112k8");
+                }
+                Shape::M64n120k8 => {
+                    f(".m64n120k8");
+                }
+                Shape::M64n128k8 => {
+                    f(".m64n128k8");
+                }
+                Shape::M64n136k8 => {
+                    f(".m64n136k8");
+                }
+                Shape::M64n144k8 => {
+                    f(".m64n144k8");
+                }
+                Shape::M64n152k8 => {
+                    f(".m64n152k8");
+                }
+                Shape::M64n160k8 => {
+                    f(".m64n160k8");
+                }
+                Shape::M64n168k8 => {
+                    f(".m64n168k8");
+                }
+                Shape::M64n176k8 => {
+                    f(".m64n176k8");
+                }
+                Shape::M64n184k8 => {
+                    f(".m64n184k8");
+                }
+                Shape::M64n192k8 => {
+                    f(".m64n192k8");
+                }
+                Shape::M64n200k8 => {
+                    f(".m64n200k8");
+                }
+                Shape::M64n208k8 => {
+                    f(".m64n208k8");
+                }
+                Shape::M64n216k8 => {
+                    f(".m64n216k8");
+                }
+                Shape::M64n224k8 => {
+                    f(".m64n224k8");
+                }
+                Shape::M64n232k8 => {
+                    f(".m64n232k8");
+                }
+                Shape::M64n240k8 => {
+                    f(".m64n240k8");
+                }
+                Shape::M64n248k8 => {
+                    f(".m64n248k8");
+                }
+                Shape::M64n256k8 => {
+                    f(".m64n256k8");
+                }
+                Shape::M64n16k8 => {
+                    f(".m64n16k8");
+                }
+                Shape::M64n24k8 => {
+                    f(".m64n24k8");
+                }
+                Shape::M64n32k8 => {
+                    f(".m64n32k8");
+                }
+                Shape::M64n40k8 => {
+                    f(".m64n40k8");
+                }
+                Shape::M64n48k8 => {
+                    f(".m64n48k8");
+                }
+                Shape::M64n56k8 => {
+                    f(".m64n56k8");
+                }
+                Shape::M64n64k8 => {
+                    f(".m64n64k8");
+                }
+                Shape::M64n72k8 => {
+                    f(".m64n72k8");
+                }
+                Shape::M64n80k8 => {
+                    f(".m64n80k8");
+                }
+                Shape::M64n88k8 => {
+                    f(".m64n88k8");
+                }
+                Shape::M64n96k8 => {
+                    f(".m64n96k8");
+                }
+                Shape::M64n8k8 => {
+                    f(".m64n8k8");
+                }
+            }
+            match &self.dtype {
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".tf32");
+            f(".tf32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.txt",
+                section: 2,
+                line: 29,
+                rule: "wgmma.mma_async.sync.aligned.shape.dtype.tf32.tf32 d, a-desc, b-desc, scale-d, imm-scale-a, imm-scale-b;",
+            }
+        }
+    }
+
+    impl Metadata for WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k8 => {
+                    f(".m64n104k8");
+                }
+                Shape::M64n112k8 => {
+                    f(".m64n112k8");
+                }
+                Shape::M64n120k8 => {
+                    f(".m64n120k8");
+                }
+                Shape::M64n128k8 => {
+                    f(".m64n128k8");
+                }
+                Shape::M64n136k8 => {
+                    f(".m64n136k8");
+                }
+                Shape::M64n144k8 => {
+                    f(".m64n144k8");
+                }
+                Shape::M64n152k8 => {
+                    f(".m64n152k8");
+                }
+                Shape::M64n160k8 => {
+                    f(".m64n160k8");
+                }
+                Shape::M64n168k8 => {
+                    f(".m64n168k8");
+                }
+                Shape::M64n176k8 => {
+                    f(".m64n176k8");
+                }
+                Shape::M64n184k8 => {
+                    f(".m64n184k8");
+                }
+                Shape::M64n192k8 => {
+                    f(".m64n192k8");
+                }
+                Shape::M64n200k8 => {
+                    f(".m64n200k8");
+                }
+                Shape::M64n208k8 => {
+                    f(".m64n208k8");
+                }
+                Shape::M64n216k8 => {
+                    f(".m64n216k8");
+                }
+                Shape::M64n224k8 => {
+                    f(".m64n224k8");
+                }
+                Shape::M64n232k8 => {
+                    f(".m64n232k8");
+                }
+                Shape::M64n240k8 => {
+                    f(".m64n240k8");
+                }
+                Shape::M64n248k8 => {
+                    f(".m64n248k8");
+                }
+                Shape::M64n256k8 => {
+                    f(".m64n256k8");
+                }
+                Shape::M64n16k8 => {
+                    f(".m64n16k8");
+                }
+                Shape::M64n24k8 => {
+                    f(".m64n24k8");
+                }
+                Shape::M64n32k8 => {
+                    f(".m64n32k8");
+                }
+                Shape::M64n40k8 => {
+                    f(".m64n40k8");
+                }
+                Shape::M64n48k8 => {
+                    f(".m64n48k8");
+                }
+                Shape::M64n56k8 => {
+                    f(".m64n56k8");
+                }
+                Shape::M64n64k8 => {
+                    f(".m64n64k8");
+                }
+                Shape::M64n72k8 => {
+                    f(".m64n72k8");
+                }
+                Shape::M64n80k8 => {
+                    f(".m64n80k8");
+                }
+                Shape::M64n88k8 => {
+                    f(".m64n88k8");
+                }
+                Shape::M64n96k8 => {
+                    f(".m64n96k8");
+                }
+                Shape::M64n8k8 => {
+                    f(".m64n8k8");
+                }
+            }
+            match &self.dtype {
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".tf32");
+            f(".tf32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.txt",
+                section: 2,
+                line: 30,
+                rule: "wgmma.mma_async.sync.aligned.shape.dtype.tf32.tf32 d, a, b-desc, scale-d, imm-scale-a, imm-scale-b;",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async::section_3::*;
+
+    impl Metadata for WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k32 => {
+                    f(".m64n104k32");
+                }
+                Shape::M64n112k32 => {
+                    f(".m64n112k32");
+                }
+                Shape::M64n120k32 => {
+                    f(".m64n120k32");
+                }
+                Shape::M64n128k32 => {
+                    f(".m64n128k32");
+                }
+                Shape::M64n136k32 => {
+                    f(".m64n136k32");
+                }
+                Shape::M64n144k32 => {
+                    f(".m64n144k32");
+                }
+                Shape::M64n152k32 => {
+                    f(".m64n152k32");
+                }
+                Shape::M64n160k32 => {
+                    f(".m64n160k32");
+                }
+                Shape::M64n168k32 => {
+                    f(".m64n168k32");
+                }
+                Shape::M64n176k32 => {
+                    f(".m64n176k32");
+                }
+                Shape::M64n184k32 => {
+                    f(".m64n184k32");
+                }
+                Shape::M64n192k32 => {
+                    f(".m64n192k32");
+                }
+                Shape::M64n200k32 => {
+                    f(".m64n200k32");
+                }
+                Shape::M64n208k32 => {
+                    f(".m64n208k32");
+                }
+                Shape::M64n216k32 => {
+                    f(".m64n216k32");
+                }
+                Shape::M64n224k32 => {
+                    f(".m64n224k32");
+                }
+                Shape::M64n232k32 => {
+                    f(".m64n232k32");
+                }
+                Shape::M64n240k32 => {
+                    f(".m64n240k32");
+                }
+                Shape::M64n248k32 => {
+                    f(".m64n248k32");
+                }
+                Shape::M64n256k32 => {
+                    f(".m64n256k32");
+                }
+                Shape::M64n16k32 => {
+                    f(".m64n16k32");
+                }
+                Shape::M64n24k32 => {
+                    f(".m64n24k32");
+                }
+                Shape::M64n32k32 => {
+                    f(".m64n32k32");
+                }
+                Shape::M64n40k32 => {
+                    f(".m64n40k32");
+                }
+                Shape::M64n48k32 => {
+                    f(".m64n48k32");
+                }
+                Shape::M64n56k32 => {
+                    f(".m64n56k32");
+                }
+                Shape::M64n64k32 => {
+                    f(".m64n64k32");
+                }
+                Shape::M64n72k32 => {
+                    f(".m64n72k32");
+                }
+                Shape::M64n80k32 => {
+                    f(".m64n80k32");
+                }
+                Shape::M64n88k32 => {
+                    f(".m64n88k32");
+                }
+                Shape::M64n96k32 => {
+                    f(".m64n96k32");
+                }
+                Shape::M64n8k32 => {
+                    f(".m64n8k32");
+                }
+            }
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.atype {
+                Atype::E4m3 => {
+                    f(".e4m3");
+                }
+                Atype::E5m2 => {
+                    f(".e5m2");
+                }
+            }
+            match &self.btype {
+                Btype::E4m3 => {
+                    f(".e4m3");
+                }
+                Btype::E5m2 => {
+                    f(".e5m2");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.txt",
+                section: 3,
+                line: 42,
+                rule: "wgmma.mma_async.sync.aligned.shape.dtype.atype.btype d, a-desc, b-desc, scale-d, imm-scale-a, imm-scale-b;",
+            }
+        }
+    }
+
+    impl Metadata for WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k32 => {
+                    f(".m64n104k32");
+                }
+                Shape::M64n112k32 => {
+                    f(".m64n112k32");
+                }
+                Shape::M64n120k32 => {
+                    f(".m64n120k32");
+                }
+                Shape::M64n128k32 => {
+                    f(".m64n128k32");
+                }
+                Shape::M64n136k32 => {
+                    f(".m64n136k32");
+                }
+                Shape::M64n144k32 => {
+                    f(".m64n144k32");
+                }
+                Shape::M64n152k32 => {
+                    f(".m64n152k32");
+                }
+                Shape::M64n160k32 => {
+                    f(".m64n160k32");
+                }
+                Shape::M64n168k32 => {
+                    f(".m64n168k32");
+                }
+                Shape::M64n176k32 => {
+                    f(".m64n176k32");
+                }
+                Shape::M64n184k32 => {
+                    f(".m64n184k32");
+                }
+                Shape::M64n192k32 => {
+                    f(".m64n192k32");
+                }
+                Shape::M64n200k32 => {
+                    f(".m64n200k32");
+                }
+                Shape::M64n208k32 => {
+                    f(".m64n208k32");
+                }
+                Shape::M64n216k32 => {
+                    f(".m64n216k32");
+                }
+                Shape::M64n224k32 => {
+                    f(".m64n224k32");
+                }
+                Shape::M64n232k32 => {
+                    f(".m64n232k32");
+                }
+                Shape::M64n240k32 => {
+                    f(".m64n240k32");
+                }
+                Shape::M64n248k32 => {
+                    f(".m64n248k32");
+                }
+                Shape::M64n256k32 => {
+                    f(".m64n256k32");
+                }
+                Shape::M64n16k32 => {
+                    f(".m64n16k32");
+                }
+                Shape::M64n24k32 => {
+                    f(".m64n24k32");
+                }
+                Shape::M64n32k32 => {
+                    f(".m64n32k32");
+                }
+                Shape::M64n40k32 => {
+                    f(".m64n40k32");
+                }
+                Shape::M64n48k32 => {
+                    f(".m64n48k32");
+                }
+                Shape::M64n56k32 => {
+                    f(".m64n56k32");
+                }
+                Shape::M64n64k32 => {
+                    f(".m64n64k32");
+                }
+                Shape::M64n72k32 => {
+                    f(".m64n72k32");
+                }
+                Shape::M64n80k32 => {
+                    f(".m64n80k32");
+                }
+                Shape::M64n88k32 => {
+                    f(".m64n88k32");
+                }
+                Shape::M64n96k32 => {
+                    f(".m64n96k32");
+                }
+                Shape::M64n8k32 => {
+                    f(".m64n8k32");
+                }
+            }
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.atype {
+                Atype::E4m3 => {
+                    f(".e4m3");
+                }
+                Atype::E5m2 => {
+                    f(".e5m2");
+                }
+            }
+            match &self.btype {
+                Btype::E4m3 => {
+                    f(".e4m3");
+                }
+                Btype::E5m2 => {
+                    f(".e5m2");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.txt",
+                section: 3,
+                line: 43,
+                rule: "wgmma.mma_async.sync.aligned.shape.dtype.atype.btype d, a, b-desc, scale-d, imm-scale-a, imm-scale-b;",
+            }
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async::section_4::*;
+
+    impl Metadata for WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n112k32 => {
+                    f(".m64n112k32");
+                }
+                Shape::M64n128k32 => {
+                    f(".m64n128k32");
+                }
+                Shape::M64n144k32 => {
+                    f(".m64n144k32");
+                }
+                Shape::M64n160k32 => {
+                    f(".m64n160k32");
+                }
+                Shape::M64n176k32 => {
+                    f(".m64n176k32");
+                }
+                Shape::M64n192k32 => {
+                    f(".m64n192k32");
+                }
+                Shape::M64n208k32 => {
+                    f(".m64n208k32");
+                }
+                Shape::M64n224k32 => {
+                    f(".m64n224k32");
+                }
+                Shape::M64n16k32 => {
+                    f(".m64n16k32");
+                }
+                Shape::M64n24k32 => {
+                    f(".m64n24k32");
+                }
+                Shape::M64n32k32 => {
+                    f(".m64n32k32");
+                }
+                Shape::M64n48k32 => {
+                    f(".m64n48k32");
+                }
+                Shape::M64n64k32 => {
+                    f(".m64n64k32");
+                }
+                Shape::M64n80k32 => {
+                    f(".m64n80k32");
+                }
+                Shape::M64n96k32 => {
+                    f(".m64n96k32");
+                }
+                Shape::M64n8k32 => {
+                    f(".m64n8k32");
+                }
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".s32");
+            match &self.atype {
+                Atype::S8 => {
+                    f(".s8");
+                }
+                Atype::U8 => {
+                    f(".u8");
+                }
+            }
+            match &self.btype {
+                Btype::S8 => {
+                    f(".s8");
+                }
+                Btype::U8 => {
+                    f(".u8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.txt",
+                section: 4,
+                line: 57,
+                rule: "wgmma.mma_async.sync.aligned.shape{.satfinite}.s32.atype.btype d, a-desc, b-desc, scale-d;",
+            }
+        }
+    }
+
+    impl Metadata for WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n112k32 => {
+                    f(".m64n112k32");
+                }
+                Shape::M64n128k32 => {
+                    f(".m64n128k32");
+                }
+                Shape::M64n144k32 => {
+                    f(".m64n144k32");
+                }
+                Shape::M64n160k32 => {
+                    f(".m64n160k32");
+                }
+                Shape::M64n176k32 => {
+                    f(".m64n176k32");
+                }
+                Shape::M64n192k32 => {
+                    f(".m64n192k32");
+                }
+                Shape::M64n208k32 => {
+                    f(".m64n208k32");
+                }
+                Shape::M64n224k32 => {
+                    f(".m64n224k32");
+                }
+                Shape::M64n16k32 => {
+                    f(".m64n16k32");
+                }
+                Shape::M64n24k32 => {
+                    f(".m64n24k32");
+                }
+                Shape::M64n32k32 => {
+                    f(".m64n32k32");
+                }
+                Shape::M64n48k32 => {
+                    f(".m64n48k32");
+                }
+                Shape::M64n64k32 => {
+                    f(".m64n64k32");
+                }
+                Shape::M64n80k32 => {
+                    f(".m64n80k32");
+                }
+                Shape::M64n96k32 => {
+                    f(".m64n96k32");
+                }
+                Shape::M64n8k32 => {
+                    f(".m64n8k32");
+                }
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".s32");
+            match &self.atype {
+                Atype::S8 => {
+                    f(".s8");
+                }
+                Atype::U8 => {
+                    f(".u8");
+                }
+            }
+            match &self.btype {
+                Btype::S8 => {
+                    f(".s8");
+                }
+                Btype::U8 => {
+                    f(".u8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.txt",
+                section: 4,
+                line: 58,
+                rule: "wgmma.mma_async.sync.aligned.shape{.satfinite}.s32.atype.btype d, a, b-desc, scale-d;",
+            }
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async::section_5::*;
+
+    impl Metadata for WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n112k256 => {
+                    f(".m64n112k256");
+                }
+                Shape::M64n128k256 => {
+                    f(".m64n128k256");
+                }
+                Shape::M64n144k256 => {
+                    f(".m64n144k256");
+                }
+                Shape::M64n160k256 => {
+                    f(".m64n160k256");
+                }
+                Shape::M64n176k256 => {
+                    f(".m64n176k256");
+                }
+                Shape::M64n192k256 => {
+                    f(".m64n192k256");
+                }
+                Shape::M64n208k256 => {
+                    f(".m64n208k256");
+                }
+                Shape::M64n224k256 => {
+                    f(".m64n224k256");
+                }
+                Shape::M64n240k256 => {
+                    f(".m64n240k256");
+                }
+                Shape::M64n256k256 => {
+                    f(".m64n256k256");
+                }
+                Shape::M64n16k256 => {
+                    f(".m64n16k256");
+                }
+                Shape::M64n24k256 => {
+                    f(".m64n24k256");
+                }
+                Shape::M64n32k256 => {
+                    f(".m64n32k256");
+                }
+                Shape::M64n48k256 => {
+                    f(".m64n48k256");
+                }
+                Shape::M64n64k256 => {
+                    f(".m64n64k256");
+                }
+                Shape::M64n80k256 => {
+                    f(".m64n80k256");
+                }
+                Shape::M64n96k256 => {
+                    f(".m64n96k256");
+                }
+                Shape::M64n8k256 => {
+                    f(".m64n8k256");
+                }
+            }
+            f(".s32");
+            f(".b1");
+            f(".b1");
+            match &self.op {
+                Op::And => {
+                    f(".and");
+                }
+            }
+            f(".popc");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.txt",
+                section: 5,
+                line: 67,
+                rule: "wgmma.mma_async.sync.aligned.shape.s32.b1.b1.op.popc d, a-desc, b-desc, scale-d;",
+            }
+        }
+    }
+
+    impl Metadata for WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n112k256 => {
+                    f(".m64n112k256");
+                }
+                Shape::M64n128k256 => {
+                    f(".m64n128k256");
+                }
+                Shape::M64n144k256 => {
+                    f(".m64n144k256");
+                }
+                Shape::M64n160k256 => {
+                    f(".m64n160k256");
+                }
+                Shape::M64n176k256 => {
+                    f(".m64n176k256");
+                }
+                Shape::M64n192k256 => {
+                    f(".m64n192k256");
+                }
+                Shape::M64n208k256 => {
+                    f(".m64n208k256");
+                }
+                Shape::M64n224k256 => {
+                    f(".m64n224k256");
+                }
+                Shape::M64n240k256 => {
+                    f(".m64n240k256");
+                }
+                Shape::M64n256k256 => {
+                    f(".m64n256k256");
+                }
+                Shape::M64n16k256 => {
+                    f(".m64n16k256");
+                }
+                Shape::M64n24k256 => {
+                    f(".m64n24k256");
+                }
+                Shape::M64n32k256 => {
+                    f(".m64n32k256");
+                }
+                Shape::M64n48k256 => {
+                    f(".m64n48k256");
+                }
+                Shape::M64n64k256 => {
+                    f(".m64n64k256");
+                }
+                Shape::M64n80k256 => {
+                    f(".m64n80k256");
+                }
+                Shape::M64n96k256 => {
+                    f(".m64n96k256");
+                }
+                Shape::M64n8k256 => {
+                    f(".m64n8k256");
+                }
+            }
+            f(".s32");
+            f(".b1");
+            f(".b1");
+            match &self.op {
+                Op::And => {
+                    f(".and");
+                }
+            }
+            f(".popc");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.txt",
+                section: 5,
+                line: 68,
+                rule: "wgmma.mma_async.sync.aligned.shape.s32.b1.b1.op.popc d, a, b-desc, scale-d;",
+            }
+        }
+    }
+}
@@ -0,0 +1,1328 @@
+//! Original PTX specification:
+//!
+//! // Half precision floating point type:
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.f16.f16  d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.f16.f16  d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;
+//! .shape   = {.m64n8k32, .m64n16k32, .m64n24k32, .m64n32k32,
+//! .m64n40k32, .m64n48k32, .m64n56k32, .m64n64k32,
+//! .m64n72k32, .m64n80k32, .m64n88k32, .m64n96k32,
+//! .m64n104k32, .m64n112k32, .m64n120k32, .m64n128k32,
+//! .m64n136k32, .m64n144k32, .m64n152k32, .m64n160k32,
+//! .m64n168k32, .m64n176k32, .m64n184k32, .m64n192k32,
+//! .m64n200k32, .m64n208k32, .m64n216k32, .m64n224k32,
+//! .m64n232k32, .m64n240k32, .m64n248k32, .m64n256k32};
+//! .dtype   = {.f16, .f32};
+//! ------------------------------------------------------------------
+//! // Alternate floating point type :
+//! // .bf16 floating point type:
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.bf16.bf16  d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.bf16.bf16  d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;
+//! .shape   = {.m64n8k32, .m64n16k32, .m64n24k32, .m64n32k32,
+//! .m64n40k32, .m64n48k32, .m64n56k32, .m64n64k32,
+//! .m64n72k32, .m64n80k32, .m64n88k32, .m64n96k32,
+//! .m64n104k32, .m64n112k32, .m64n120k32, .m64n128k32,
+//! .m64n136k32, .m64n144k32, .m64n152k32, .m64n160k32,
+//! .m64n168k32, .m64n176k32, .m64n184k32, .m64n192k32,
+//! .m64n200k32, .m64n208k32, .m64n216k32, .m64n224k32,
+//! .m64n232k32, .m64n240k32, .m64n248k32, .m64n256k32};
+//! .dtype  = {.f32};
+//! ------------------------------------------------------------------
+//! // .tf32 floating point type:
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.tf32.tf32  d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.tf32.tf32  d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;
+//! .shape   = {.m64n8k16, .m64n16k16, .m64n24k16, .m64n32k16,
+//! .m64n40k16, .m64n48k16, .m64n56k16, .m64n64k16,
+//! .m64n72k16, .m64n80k16, .m64n88k16, .m64n96k16,
+//! .m64n104k16, .m64n112k16, .m64n120k16, .m64n128k16,
+//! .m64n136k16, .m64n144k16, .m64n152k16, .m64n160k16,
+//! .m64n168k16, .m64n176k16, .m64n184k16, .m64n192k16,
+//! .m64n200k16, .m64n208k16, .m64n216k16, .m64n224k16,
+//! .m64n232k16, .m64n240k16, .m64n248k16, .m64n256k16};
+//! .dtype  = {.f32};
+//! ------------------------------------------------------------------
+//! // FP8 floating point type
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.atype.btype  d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.atype.btype  d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;
+//! .shape   = {.m64n8k64, .m64n16k64, .m64n24k64, .m64n32k64,
+//! .m64n40k64, .m64n48k64, .m64n56k64, .m64n64k64,
+//! .m64n72k64, .m64n80k64, .m64n88k64, .m64n96k64,
+//! .m64n104k64, .m64n112k64, .m64n120k64, .m64n128k64,
+//! .m64n136k64, .m64n144k64, .m64n152k64, .m64n160k64,
+//! .m64n168k64, .m64n176k64, .m64n184k64, .m64n192k64,
+//! .m64n200k64, .m64n208k64, .m64n216k64, .m64n224k64,
+//! .m64n232k64, .m64n240k64, .m64n248k64, .m64n256k64};
+//! .atype  = {.e4m3, .e5m2};
+//! .btype  = {.e4m3, .e5m2};
+//! .dtype  = {.f16, .f32};
+//! ------------------------------------------------------------------
+//! // Integer type:
+//! wgmma.mma_async.sp.sync.aligned.shape{.satfinite}.s32.atype.btype  d, a-desc, b-desc, sp-meta, sp-sel, scale-d;
+//! wgmma.mma_async.sp.sync.aligned.shape{.satfinite}.s32.atype.btype  d, a, b-desc, sp-meta, sp-sel, scale-d;
+//! .shape   = {.m64n8k64, .m64n16k64, .m64n24k64, .m64n32k64,
+//! .m64n48k64, .m64n64k64, .m64n80k64, .m64n96k64,
+//! .m64n112k64, .m64n128k64, .m64n144k64, .m64n160k64,
+//! .m64n176k64, .m64n192k64, .m64n208k64, .m64n224k64,
+//! .m64n240k64, .m64n256k64};
+//! .atype  = {.s8, .u8};
+//! .btype  = {.s8, .u8};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_0::*;
+
+    impl Metadata for WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sp");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k32 => {
+                    f(".m64n104k32");
+                }
+                Shape::M64n112k32 => {
+                    f(".m64n112k32");
+                }
+                Shape::M64n120k32 => {
+                    f(".m64n120k32");
+                }
+                Shape::M64n128k32 => {
+                    f(".m64n128k32");
+                }
+                Shape::M64n136k32 => {
+                    f(".m64n136k32");
+                }
+                Shape::M64n144k32 => {
+                    f(".m64n144k32");
+                }
+                Shape::M64n152k32 => {
+                    f(".m64n152k32");
+                }
+                Shape::M64n160k32 => {
+                    f(".m64n160k32");
+                }
+                Shape::M64n168k32 => {
+                    f(".m64n168k32");
+                }
+                Shape::M64n176k32 => {
+                    f(".m64n176k32");
+                }
+                Shape::M64n184k32 => {
+                    f(".m64n184k32");
+                }
+                Shape::M64n192k32 => {
+                    f(".m64n192k32");
+                }
+                Shape::M64n200k32 => {
+                    f(".m64n200k32");
+                }
+                Shape::M64n208k32 => {
+                    f(".m64n208k32");
+                }
+                Shape::M64n216k32 => {
+                    f(".m64n216k32");
+                }
+                Shape::M64n224k32 => {
+                    f(".m64n224k32");
+                }
+                Shape::M64n232k32 => {
+                    f(".m64n232k32");
+                }
+                Shape::M64n240k32 => {
+                    f(".m64n240k32");
+                }
+                Shape::M64n248k32 => {
+                    f(".m64n248k32");
+                }
+                Shape::M64n256k32 => {
+                    f(".m64n256k32");
+                }
+                Shape::M64n16k32 => {
+                    f(".m64n16k32");
+                }
+                Shape::M64n24k32 => {
+                    f(".m64n24k32");
+                }
+                Shape::M64n32k32 => {
+                    f(".m64n32k32");
+                }
+                Shape::M64n40k32 => {
+                    f(".m64n40k32");
+                }
+                Shape::M64n48k32 => {
+                    f(".m64n48k32");
+                }
+                Shape::M64n56k32 => {
+                    f(".m64n56k32");
+                }
+                Shape::M64n64k32 => {
+                    f(".m64n64k32");
+                }
+                Shape::M64n72k32 => {
+                    f(".m64n72k32");
+                }
+                Shape::M64n80k32 => {
+                    f(".m64n80k32");
+                }
+                Shape::M64n88k32 => {
+                    f(".m64n88k32");
+                }
+                Shape::M64n96k32 => {
+                    f(".m64n96k32");
+                }
+                Shape::M64n8k32 => {
+                    f(".m64n8k32");
+                }
+            }
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".f16");
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.sp.txt",
+                section: 0,
+                line: 2,
+                rule: "wgmma.mma_async.sp.sync.aligned.shape.dtype.f16.f16 d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;",
+            }
+        }
+    }
+
+    impl Metadata for WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sp");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k32 => {
+                    f(".m64n104k32");
+                }
+                Shape::M64n112k32 => {
+                    f(".m64n112k32");
+                }
+                Shape::M64n120k32 => {
+                    f(".m64n120k32");
+                }
+                Shape::M64n128k32 => {
+                    f(".m64n128k32");
+                }
+                Shape::M64n136k32 => {
+                    f(".m64n136k32");
+                }
+                Shape::M64n144k32 => {
+                    f(".m64n144k32");
+                }
+                Shape::M64n152k32 => {
+                    f(".m64n152k32");
+                }
+                Shape::M64n160k32 => {
+                    f(".m64n160k32");
+                }
+                Shape::M64n168k32 => {
+                    f(".m64n168k32");
+                }
+                Shape::M64n176k32 => {
+                    f(".m64n176k32");
+                }
+                Shape::M64n184k32 => {
+                    f(".m64n184k32");
+                }
+                Shape::M64n192k32 => {
+                    f(".m64n192k32");
+                }
+                Shape::M64n200k32 => {
+                    f(".m64n200k32");
+                }
+                Shape::M64n208k32 => {
+                    f(".m64n208k32");
+                }
+                Shape::M64n216k32 => {
+                    f(".m64n216k32");
+                }
+                Shape::M64n224k32 => {
+                    f(".m64n224k32");
+                }
+                Shape::M64n232k32 => {
+                    f(".m64n232k32");
+                }
+                Shape::M64n240k32 => {
+                    f(".m64n240k32");
+                }
+                Shape::M64n248k32 => {
+                    f(".m64n248k32");
+                }
+                Shape::M64n256k32 => {
+                    f(".m64n256k32");
+                }
+                Shape::M64n16k32 => {
+                    f(".m64n16k32");
+                }
+                Shape::M64n24k32 => {
+                    f(".m64n24k32");
+                }
+                Shape::M64n32k32 => {
+                    f(".m64n32k32");
+                }
+                Shape::M64n40k32 => {
+                    f(".m64n40k32");
+                }
+                Shape::M64n48k32 => {
+                    f(".m64n48k32");
+                }
+                Shape::M64n56k32 => {
+                    f(".m64n56k32");
+                }
+                Shape::M64n64k32 => {
+                    f(".m64n64k32");
+                }
+                Shape::M64n72k32 => {
+                    f(".m64n72k32");
+                }
+                Shape::M64n80k32 => {
+                    f(".m64n80k32");
+                }
+                Shape::M64n88k32 => {
+                    f(".m64n88k32");
+                }
+                Shape::M64n96k32 => {
+                    f(".m64n96k32");
+                }
+                Shape::M64n8k32 => {
+                    f(".m64n8k32");
+                }
+            }
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".f16");
+            f(".f16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.sp.txt",
+                section: 0,
+                line: 3,
+                rule: "wgmma.mma_async.sp.sync.aligned.shape.dtype.f16.f16 d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_1::*;
+
+    impl Metadata for WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sp");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k32 => {
+                    f(".m64n104k32");
+                }
+                Shape::M64n112k32 => {
+                    f(".m64n112k32");
+                }
+                Shape::M64n120k32 => {
+                    f(".m64n120k32");
+                }
+                Shape::M64n128k32 => {
+                    f(".m64n128k32");
+                }
+                Shape::M64n136k32 => {
+                    f(".m64n136k32");
+                }
+                Shape::M64n144k32 => {
+                    f(".m64n144k32");
+                }
+                Shape::M64n152k32 => {
+                    f(".m64n152k32");
+                }
+                Shape::M64n160k32 => {
+                    f(".m64n160k32");
+                }
+                Shape::M64n168k32 => {
+                    f(".m64n168k32");
+                }
+                Shape::M64n176k32 => {
+                    f(".m64n176k32");
+                }
+                Shape::M64n184k32 => {
+                    f(".m64n184k32");
+                }
+                Shape::M64n192k32 => {
+                    f(".m64n192k32");
+                }
+                Shape::M64n200k32 => {
+                    f(".m64n200k32");
+                }
+                Shape::M64n208k32 => {
+                    f(".m64n208k32");
+                }
+                Shape::M64n216k32 => {
+                    f(".m64n216k32");
+                }
+                Shape::M64n224k32 => {
+                    f(".m64n224k32");
+                }
+                Shape::M64n232k32 => {
+                    f(".m64n232k32");
+                }
+                Shape::M64n240k32 => {
+                    f(".m64n240k32");
+                }
+                Shape::M64n248k32 => {
+                    f(".m64n248k32");
+                }
+                Shape::M64n256k32 => {
+                    f(".m64n256k32");
+                }
+                Shape::M64n16k32 => {
+                    f(".m64n16k32");
+                }
+                Shape::M64n24k32 => {
+                    f(".m64n24k32");
+                }
+                Shape::M64n32k32 => {
+                    f(".m64n32k32");
+                }
+                Shape::M64n40k32 => {
+                    f(".m64n40k32");
+                }
+                Shape::M64n48k32 => {
+                    f(".m64n48k32");
+                }
+                Shape::M64n56k32 => {
+                    f(".m64n56k32");
+                }
+                Shape::M64n64k32 => {
+                    f(".m64n64k32");
+                }
+                Shape::M64n72k32 => {
+                    f(".m64n72k32");
+                }
+                Shape::M64n80k32 => {
+                    f(".m64n80k32");
+                }
+                Shape::M64n88k32 => {
+                    f(".m64n88k32");
+                }
+                Shape::M64n96k32 => {
+                    f(".m64n96k32");
+                }
+                Shape::M64n8k32 => {
+                    f(".m64n8k32");
+                }
+            }
+            match &self.dtype {
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".bf16");
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.sp.txt",
+                section: 1,
+                line: 16,
+                rule: "wgmma.mma_async.sp.sync.aligned.shape.dtype.bf16.bf16 d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;",
+            }
+        }
+    }
+
+    impl Metadata for WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sp");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k32 => {
+                    f(".m64n104k32");
+                }
+                Shape::M64n112k32 => {
+                    f(".m64n112k32");
+                }
+                Shape::M64n120k32 => {
+                    f(".m64n120k32");
+                }
+                Shape::M64n128k32 => {
+                    f(".m64n128k32");
+                }
+                Shape::M64n136k32 => {
+                    f(".m64n136k32");
+                }
+                Shape::M64n144k32 => {
+                    f(".m64n144k32");
+                }
+                Shape::M64n152k32 => {
+                    f(".m64n152k32");
+                }
+                Shape::M64n160k32 => {
+                    f(".m64n160k32");
+                }
+                Shape::M64n168k32 => {
+                    f(".m64n168k32");
+                }
+                Shape::M64n176k32 => {
+                    f(".m64n176k32");
+                }
+                Shape::M64n184k32 => {
+                    f(".m64n184k32");
+                }
+                Shape::M64n192k32 => {
+                    f(".m64n192k32");
+                }
+                Shape::M64n200k32 => {
+                    f(".m64n200k32");
+                }
+                Shape::M64n208k32 => {
+                    f(".m64n208k32");
+                }
+                Shape::M64n216k32 => {
+                    f(".m64n216k32");
+                }
+                Shape::M64n224k32 => {
+                    f(".m64n224k32");
+                }
+                Shape::M64n232k32 => {
+                    f(".m64n232k32");
+                }
+                Shape::M64n240k32 => {
+                    f(".m64n240k32");
+                }
+                Shape::M64n248k32 => {
+                    f(".m64n248k32");
+                }
+                Shape::M64n256k32 => {
+                    f(".m64n256k32");
+                }
+                Shape::M64n16k32 => {
+                    f(".m64n16k32");
+                }
+                Shape::M64n24k32 => {
+                    f(".m64n24k32");
+                }
+                Shape::M64n32k32 => {
+                    f(".m64n32k32");
+                }
+                Shape::M64n40k32 => {
+                    f(".m64n40k32");
+                }
+                Shape::M64n48k32 => {
+                    f(".m64n48k32");
+                }
+                Shape::M64n56k32 => {
+                    f(".m64n56k32");
+                }
+                Shape::M64n64k32 => {
+                    f(".m64n64k32");
+                }
+                Shape::M64n72k32 => {
+                    f(".m64n72k32");
+                }
+                Shape::M64n80k32 => {
+                    f(".m64n80k32");
+                }
+                Shape::M64n88k32 => {
+                    f(".m64n88k32");
+                }
+                Shape::M64n96k32 => {
+                    f(".m64n96k32");
+                }
+                Shape::M64n8k32 => {
+                    f(".m64n8k32");
+                }
+            }
+            match &self.dtype {
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".bf16");
+            f(".bf16");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.sp.txt",
+                section: 1,
+                line: 17,
+                rule: "wgmma.mma_async.sp.sync.aligned.shape.dtype.bf16.bf16 d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_2::*;
+
+    impl Metadata for WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sp");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k16 => {
+                    f(".m64n104k16");
+                }
+                Shape::M64n112k16 => {
+                    f(".m64n112k16");
+                }
+                Shape::M64n120k16 => {
+                    f(".m64n120k16");
+                }
+                Shape::M64n128k16 => {
+                    f(".m64n128k16");
+                }
+                Shape::M64n136k16 => {
+                    f(".m64n136k16");
+                }
+                Shape::M64n144k16 => {
+                    f(".m64n144k16");
+                }
+                Shape::M64n152k16 => {
+                    f(".m64n152k16");
+                }
+                Shape::M64n160k16 => {
+                    f(".m64n160k16");
+                }
+                Shape::M64n168k16 => {
+                    f(".m64n168k16");
+                }
+                Shape::M64n176k16 => {
+                    f(".m64n176k16");
+                }
+                Shape::M64n184k16 => {
+                    f(".m64n184k16");
+                }
+                Shape::M64n192k16 => {
+                    f(".m64n192k16");
+                }
+                Shape::M64n200k16 => {
+                    f(".m64n200k16");
+                }
+                Shape::M64n208k16 => {
+                    f(".m64n208k16");
+                }
+                Shape::M64n216k16 => {
+                    f(".m64n216k16");
+                }
+                Shape::M64n224k16 => {
+                    f(".m64n224k16");
+                }
+                Shape::M64n232k16 => {
+                    f(".m64n232k16");
+                }
+                Shape::M64n240k16 => {
+                    f(".m64n240k16");
+                }
+                Shape::M64n248k16 => {
+                    f(".m64n248k16");
+                }
+                Shape::M64n256k16 => {
+                    f(".m64n256k16");
+                }
+                Shape::M64n16k16 => {
+                    f(".m64n16k16");
+                }
+                Shape::M64n24k16 => {
+                    f(".m64n24k16");
+                }
+                Shape::M64n32k16 => {
+                    f(".m64n32k16");
+                }
+                Shape::M64n40k16 => {
+                    f(".m64n40k16");
+                }
+                Shape::M64n48k16 => {
+                    f(".m64n48k16");
+                }
+                Shape::M64n56k16 => {
+                    f(".m64n56k16");
+                }
+                Shape::M64n64k16 => {
+                    f(".m64n64k16");
+                }
+                Shape::M64n72k16 => {
+                    f(".m64n72k16");
+                }
+                Shape::M64n80k16 => {
+                    f(".m64n80k16");
+                }
+                Shape::M64n88k16 => {
+                    f(".m64n88k16");
+                }
+                Shape::M64n96k16 => {
+                    f(".m64n96k16");
+                }
+                Shape::M64n8k16 => {
+                    f(".m64n8k16");
+                }
+            }
+            match &self.dtype {
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".tf32");
+            f(".tf32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.sp.txt",
+                section: 2,
+                line: 29,
+                rule: "wgmma.mma_async.sp.sync.aligned.shape.dtype.tf32.tf32 d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;",
+            }
+        }
+    }
+
+    impl Metadata for WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sp");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k16 => {
+                    f(".m64n104k16");
+                }
+                Shape::M64n112k16 => {
+                    f(".m64n112k16");
+                }
+                Shape::M64n120k16 => {
+                    f(".m64n120k16");
+                }
+                Shape::M64n128k16 => {
+                    f(".m64n128k16");
+                }
+                Shape::M64n136k16 => {
+                    f(".m64n136k16");
+                }
+                Shape::M64n144k16 => {
+                    f(".m64n144k16");
+                }
+                Shape::M64n152k16 => {
+                    f(".m64n152k16");
+                }
+                Shape::M64n160k16 => {
+                    f(".m64n160k16");
+                }
+                Shape::M64n168k16 => {
+                    f(".m64n168k16");
+                }
+                Shape::M64n176k16 => {
+                    f(".m64n176k16");
+                }
+                Shape::M64n184k16 => {
+                    f(".m64n184k16");
+                }
+                Shape::M64n192k16 => {
+                    f(".m64n192k16");
+                }
+                Shape::M64n200k16 => {
+                    f(".m64n200k16");
+                }
+                Shape::M64n208k16 => {
+                    f(".m64n208k16");
+                }
+                Shape::M64n216k16 => {
+                    f(".m64n216k16");
+                }
+                Shape::M64n224k16 => {
+                    f(".m64n224k16");
+                }
+                Shape::M64n232k16 => {
+                    f(".m64n232k16");
+                }
+                Shape::M64n240k16 => {
+                    f(".m64n240k16");
+                }
+                Shape::M64n248k16 => {
+                    f(".m64n248k16");
+                }
+                Shape::M64n256k16 => {
+                    f(".m64n256k16");
+                }
+                Shape::M64n16k16 => {
+                    f(".m64n16k16");
+                }
+                Shape::M64n24k16 => {
+                    f(".m64n24k16");
+                }
+                Shape::M64n32k16 => {
+                    f(".m64n32k16");
+                }
+                Shape::M64n40k16 => {
+                    f(".m64n40k16");
+                }
+                Shape::M64n48k16 => {
+                    f(".m64n48k16");
+                }
+                Shape::M64n56k16 => {
+                    f(".m64n56k16");
+                }
+                Shape::M64n64k16 => {
+                    f(".m64n64k16");
+                }
+                Shape::M64n72k16 => {
+                    f(".m64n72k16");
+                }
+                Shape::M64n80k16 => {
+                    f(".m64n80k16");
+                }
+                Shape::M64n88k16 => {
+                    f(".m64n88k16");
+                }
+                Shape::M64n96k16 => {
+                    f(".m64n96k16");
+                }
+                Shape::M64n8k16 => {
+                    f(".m64n8k16");
+                }
+            }
+            match &self.dtype {
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            f(".tf32");
+            f(".tf32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.sp.txt",
+                section: 2,
+                line: 30,
+                rule: "wgmma.mma_async.sp.sync.aligned.shape.dtype.tf32.tf32 d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_3::*;
+
+    impl Metadata for WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sp");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k64 => {
+                    f(".m64n104k64");
+                }
+                Shape::M64n112k64 => {
+                    f(".m64n112k64");
+                }
+                Shape::M64n120k64 => {
+                    f(".m64n120k64");
+                }
+                Shape::M64n128k64 => {
+                    f(".m64n128k64");
+                }
+                Shape::M64n136k64 => {
+                    f(".m64n136k64");
+                }
+                Shape::M64n144k64 => {
+                    f(".m64n144k64");
+                }
+                Shape::M64n152k64 => {
+                    f(".m64n152k64");
+                }
+                Shape::M64n160k64 => {
+                    f(".m64n160k64");
+                }
+                Shape::M64n168k64 => {
+                    f(".m64n168k64");
+                }
+                Shape::M64n176k64 => {
+                    f(".m64n176k64");
+                }
+                Shape::M64n184k64 => {
+                    f(".m64n184k64");
+                }
+                Shape::M64n192k64 => {
+                    f(".m64n192k64");
+                }
+                Shape::M64n200k64 => {
+                    f(".m64n200k64");
+                }
+                Shape::M64n208k64 => {
+                    f(".m64n208k64");
+                }
+                Shape::M64n216k64 => {
+                    f(".m64n216k64");
+                }
+                Shape::M64n224k64 => {
+                    f(".m64n224k64");
+                }
+                Shape::M64n232k64 => {
+                    f(".m64n232k64");
+                }
+                Shape::M64n240k64 => {
+                    f(".m64n240k64");
+                }
+                Shape::M64n248k64 => {
+                    f(".m64n248k64");
+                }
+                Shape::M64n256k64 => {
+                    f(".m64n256k64");
+                }
+                Shape::M64n16k64 => {
+                    f(".m64n16k64");
+                }
+                Shape::M64n24k64 => {
+                    f(".m64n24k64");
+                }
+                Shape::M64n32k64 => {
+                    f(".m64n32k64");
+                }
+                Shape::M64n40k64 => {
+                    f(".m64n40k64");
+                }
+                Shape::M64n48k64 => {
+                    f(".m64n48k64");
+                }
+                Shape::M64n56k64 => {
+                    f(".m64n56k64");
+                }
+                Shape::M64n64k64 => {
+                    f(".m64n64k64");
+                }
+                Shape::M64n72k64 => {
+                    f(".m64n72k64");
+                }
+                Shape::M64n80k64 => {
+                    f(".m64n80k64");
+                }
+                Shape::M64n88k64 => {
+                    f(".m64n88k64");
+                }
+                Shape::M64n96k64 => {
+                    f(".m64n96k64");
+                }
+                Shape::M64n8k64 => {
+                    f(".m64n8k64");
+                }
+            }
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.atype {
+                Atype::E4m3 => {
+                    f(".e4m3");
+                }
+                Atype::E5m2 => {
+                    f(".e5m2");
+                }
+            }
+            match &self.btype {
+                Btype::E4m3 => {
+                    f(".e4m3");
+                }
+                Btype::E5m2 => {
+                    f(".e5m2");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.sp.txt",
+                section: 3,
+                line: 42,
+                rule: "wgmma.mma_async.sp.sync.aligned.shape.dtype.atype.btype d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;",
+            }
+        }
+    }
+
+    impl Metadata for WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sp");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n104k64 => {
+                    f(".m64n104k64");
+                }
+                Shape::M64n112k64 => {
+                    f(".m64n112k64");
+                }
+                Shape::M64n120k64 => {
+                    f(".m64n120k64");
+                }
+                Shape::M64n128k64 => {
+                    f(".m64n128k64");
+                }
+                Shape::M64n136k64 => {
+                    f(".m64n136k64");
+                }
+                Shape::M64n144k64 => {
+                    f(".m64n144k64");
+                }
+                Shape::M64n152k64 => {
+                    f(".m64n152k64");
+                }
+                Shape::M64n160k64 => {
+                    f(".m64n160k64");
+                }
+                Shape::M64n168k64 => {
+                    f(".m64n168k64");
+                }
+                Shape::M64n176k64 => {
+                    f(".m64n176k64");
+                }
+                Shape::M64n184k64 => {
+                    f(".m64n184k64");
+                }
+                Shape::M64n192k64 => {
+                    f(".m64n192k64");
+                }
+                Shape::M64n200k64 => {
+                    f(".m64n200k64");
+                }
+                Shape::M64n208k64 => {
+                    f(".m64n208k64");
+                }
+                Shape::M64n216k64 => {
+                    f(".m64n216k64");
+                }
+                Shape::M64n224k64 => {
+                    f(".m64n224k64");
+                }
+                Shape::M64n232k64 => {
+                    f(".m64n232k64");
+                }
+                Shape::M64n240k64 => {
+                    f(".m64n240k64");
+                }
+                Shape::M64n248k64 => {
+                    f(".m64n248k64");
+                }
+                Shape::M64n256k64 => {
+                    f(".m64n256k64");
+                }
+                Shape::M64n16k64 => {
+                    f(".m64n16k64");
+                }
+                Shape::M64n24k64 => {
+                    f(".m64n24k64");
+                }
+                Shape::M64n32k64 => {
+                    f(".m64n32k64");
+                }
+                Shape::M64n40k64 => {
+                    f(".m64n40k64");
+                }
+                Shape::M64n48k64 => {
+                    f(".m64n48k64");
+                }
+                Shape::M64n56k64 => {
+                    f(".m64n56k64");
+                }
+                Shape::M64n64k64 => {
+                    f(".m64n64k64");
+                }
+                Shape::M64n72k64 => {
+                    f(".m64n72k64");
+                }
+                Shape::M64n80k64 => {
+                    f(".m64n80k64");
+                }
+                Shape::M64n88k64 => {
+                    f(".m64n88k64");
+                }
+                Shape::M64n96k64 => {
+                    f(".m64n96k64");
+                }
+                Shape::M64n8k64 => {
+                    f(".m64n8k64");
+                }
+            }
+            match &self.dtype {
+                Dtype::F16 => {
+                    f(".f16");
+                }
+                Dtype::F32 => {
+                    f(".f32");
+                }
+            }
+            match &self.atype {
+                Atype::E4m3 => {
+                    f(".e4m3");
+                }
+                Atype::E5m2 => {
+                    f(".e5m2");
+                }
+            }
+            match &self.btype {
+                Btype::E4m3 => {
+                    f(".e4m3");
+                }
+                Btype::E5m2 => {
+                    f(".e5m2");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.sp.txt",
+                section: 3,
+                line: 43,
+                rule: "wgmma.mma_async.sp.sync.aligned.shape.dtype.atype.btype d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;",
+            }
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_4::*;
+
+    impl Metadata for WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sp");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n112k64 => {
+                    f(".m64n112k64");
+                }
+                Shape::M64n128k64 => {
+                    f(".m64n128k64");
+                }
+                Shape::M64n144k64 => {
+                    f(".m64n144k64");
+                }
+                Shape::M64n160k64 => {
+                    f(".m64n160k64");
+                }
+                Shape::M64n176k64 => {
+                    f(".m64n176k64");
+                }
+                Shape::M64n192k64 => {
+                    f(".m64n192k64");
+                }
+                Shape::M64n208k64 => {
+                    f(".m64n208k64");
+                }
+                Shape::M64n224k64 => {
+                    f(".m64n224k64");
+                }
+                Shape::M64n240k64 => {
+                    f(".m64n240k64");
+                }
+                Shape::M64n256k64 => {
+                    f(".m64n256k64");
+                }
+                Shape::M64n16k64 => {
+                    f(".m64n16k64");
+                }
+                Shape::M64n24k64 => {
+                    f(".m64n24k64");
+                }
+                Shape::M64n32k64 => {
+                    f(".m64n32k64");
+                }
+                Shape::M64n48k64 => {
+                    f(".m64n48k64");
+                }
+                Shape::M64n64k64 => {
+                    f(".m64n64k64");
+                }
+                Shape::M64n80k64 => {
+                    f(".m64n80k64");
+                }
+                Shape::M64n96k64 => {
+                    f(".m64n96k64");
+                }
+                Shape::M64n8k64 => {
+                    f(".m64n8k64");
+                }
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".s32");
+            match &self.atype {
+                Atype::S8 => {
+                    f(".s8");
+                }
+                Atype::U8 => {
+                    f(".u8");
+                }
+            }
+            match &self.btype {
+                Btype::S8 => {
+                    f(".s8");
+                }
+                Btype::U8 => {
+                    f(".u8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.sp.txt",
+                section: 4,
+                line: 57,
+                rule: "wgmma.mma_async.sp.sync.aligned.shape{.satfinite}.s32.atype.btype d, a-desc, b-desc, sp-meta, sp-sel, scale-d;",
+            }
+        }
+    }
+
+    impl Metadata for WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1 {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma_async");
+            f(".sp");
+            f(".sync");
+            f(".aligned");
+            match &self.shape {
+                Shape::M64n112k64 => {
+                    f(".m64n112k64");
+                }
+                Shape::M64n128k64 => {
+                    f(".m64n128k64");
+                }
+                Shape::M64n144k64 => {
+                    f(".m64n144k64");
+                }
+                Shape::M64n160k64 => {
+                    f(".m64n160k64");
+                }
+                Shape::M64n176k64 => {
+                    f(".m64n176k64");
+                }
+                Shape::M64n192k64 => {
+                    f(".m64n192k64");
+                }
+                Shape::M64n208k64 => {
+                    f(".m64n208k64");
+                }
+                Shape::M64n224k64 => {
+                    f(".m64n224k64");
+                }
+                Shape::M64n240k64 => {
+                    f(".m64n240k64");
+                }
+                Shape::M64n256k64 => {
+                    f(".m64n256k64");
+                }
+                Shape::M64n16k64 => {
+                    f(".m64n16k64");
+                }
+                Shape::M64n24k64 => {
+                    f(".m64n24k64");
+                }
+                Shape::M64n32k64 => {
+                    f(".m64n32k64");
+                }
+                Shape::M64n48k64 => {
+                    f(".m64n48k64");
+                }
+                Shape::M64n64k64 => {
+                    f(".m64n64k64");
+                }
+                Shape::M64n80k64 => {
+                    f(".m64n80k64");
+                }
+                Shape::M64n96k64 => {
+                    f(".m64n96k64");
+                }
+                Shape::M64n8k64 => {
+                    f(".m64n8k64");
+                }
+            }
+            if self.satfinite {
+                f(".satfinite");
+            }
+            f(".s32");
+            match &self.atype {
+                Atype::S8 => {
+                    f(".s8");
+                }
+                Atype::U8 => {
+                    f(".u8");
+                }
+            }
+            match &self.btype {
+                Btype::S8 => {
+                    f(".s8");
+                }
+                Btype::U8 => {
+                    f(".u8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.mma_async.sp.txt",
+                section: 4,
+                line: 58,
+                rule: "wgmma.mma_async.sp.sync.aligned.shape{.satfinite}.s32.atype.btype d, a, b-desc, sp-meta, sp-sel, scale-d;",
+            }
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Original PTX specification:
+//!
+//! wgmma.wait_group.sync.aligned N;
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_wait_group::section_0::*;
+
+    impl Metadata for WgmmaWaitGroupSyncAligned {
+        fn opcode(&self) -> &'static str {
+            "wgmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".wait_group");
+            f(".sync");
+            f(".aligned");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wgmma.wait_group.txt",
+                section: 0,
+                line: 1,
+                rule: "wgmma.wait_group.sync.aligned N;",
+            }
+        }
+    }
+}
@@ -0,0 +1,1051 @@
+//! Original PTX specification:
+//!
+//! // Floating point format .f16 loads:
+//! wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k16, .m8n32k16, .m32n8k16};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.f16, .s8, .u8};
+//! .btype  = {.f16, .s8, .u8};
+//! .ctype  = {.f16, .f32, .s32};
+//! ----------------------------------------------------------------
+//! // Alternate floating point format .bf16 loads:
+//! wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k16, .m8n32k16, .m32n8k16};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.bf16 };
+//! .btype  = {.bf16 };
+//! .ctype  = {.f32 };
+//! ----------------------------------------------------------------
+//! // Alternate floating point format .tf32 loads:
+//! wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k8 };
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.tf32 };
+//! .btype  = {.tf32 };
+//! .ctype  = {.f32 };
+//! ----------------------------------------------------------------
+//! // Double precision Floating point .f64 loads:
+//! wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k4 };
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.f64 };
+//! .btype  = {.f64 };
+//! .ctype  = {.f64 };
+//! ----------------------------------------------------------------
+//! // Sub-byte loads:
+//! wmma.load.a.sync.aligned.row.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.col.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k32};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.s4, .u4};
+//! .btype  = {.s4, .u4};
+//! .ctype  = {.s32};
+//! ----------------------------------------------------------------
+//! // Single-bit loads:
+//! wmma.load.a.sync.aligned.row.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.col.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k128};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.b1};
+//! .btype  = {.b1};
+//! .ctype  = {.s32};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_0::*;
+
+    impl Metadata for WmmaLoadASyncAlignedLayoutShapeSsAtype {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".a");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k16 => {
+                    f(".m16n16k16");
+                }
+                Shape::M8n32k16 => {
+                    f(".m8n32k16");
+                }
+                Shape::M32n8k16 => {
+                    f(".m32n8k16");
+                }
+            }
+            if let Some(ss_0) = self.ss.as_ref() {
+                match ss_0 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.atype {
+                Atype::F16 => {
+                    f(".f16");
+                }
+                Atype::S8 => {
+                    f(".s8");
+                }
+                Atype::U8 => {
+                    f(".u8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 0,
+                line: 2,
+                rule: "wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};",
+            }
+        }
+    }
+
+    impl Metadata for WmmaLoadBSyncAlignedLayoutShapeSsBtype {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".b");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k16 => {
+                    f(".m16n16k16");
+                }
+                Shape::M8n32k16 => {
+                    f(".m8n32k16");
+                }
+                Shape::M32n8k16 => {
+                    f(".m32n8k16");
+                }
+            }
+            if let Some(ss_1) = self.ss.as_ref() {
+                match ss_1 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.btype {
+                Btype::F16 => {
+                    f(".f16");
+                }
+                Btype::S8 => {
+                    f(".s8");
+                }
+                Btype::U8 => {
+                    f(".u8");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 0,
+                line: 3,
+                rule: "wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};",
+            }
+        }
+    }
+
+    impl Metadata for WmmaLoadCSyncAlignedLayoutShapeSsCtype {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".c");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k16 => {
+                    f(".m16n16k16");
+                }
+                Shape::M8n32k16 => {
+                    f(".m8n32k16");
+                }
+                Shape::M32n8k16 => {
+                    f(".m32n8k16");
+                }
+            }
+            if let Some(ss_2) = self.ss.as_ref() {
+                match ss_2 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.ctype {
+                Ctype::F16 => {
+                    f(".f16");
+                }
+                Ctype::F32 => {
+                    f(".f32");
+                }
+                Ctype::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 0,
+                line: 4,
+                rule: "wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_1::*;
+
+    impl Metadata for WmmaLoadASyncAlignedLayoutShapeSsAtype1 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".a");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k16 => {
+                    f(".m16n16k16");
+                }
+                Shape::M8n32k16 => {
+                    f(".m8n32k16");
+                }
+                Shape::M32n8k16 => {
+                    f(".m32n8k16");
+                }
+            }
+            if let Some(ss_3) = self.ss.as_ref() {
+                match ss_3 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.atype {
+                Atype::Bf16 => {
+                    f(".bf16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 1,
+                line: 13,
+                rule: "wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};",
+            }
+        }
+    }
+
+    impl Metadata for WmmaLoadBSyncAlignedLayoutShapeSsBtype1 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".b");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k16 => {
+                    f(".m16n16k16");
+                }
+                Shape::M8n32k16 => {
+                    f(".m8n32k16");
+                }
+                Shape::M32n8k16 => {
+                    f(".m32n8k16");
+                }
+            }
+            if let Some(ss_4) = self.ss.as_ref() {
+                match ss_4 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.btype {
+                Btype::Bf16 => {
+                    f(".bf16");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 1,
+                line: 14,
+                rule: "wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};",
+            }
+        }
+    }
+
+    impl Metadata for WmmaLoadCSyncAlignedLayoutShapeSsCtype1 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".c");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k16 => {
+                    f(".m16n16k16");
+                }
+                Shape::M8n32k16 => {
+                    f(".m8n32k16");
+                }
+                Shape::M32n8k16 => {
+                    f(".m32n8k16");
+                }
+            }
+            if let Some(ss_5) = self.ss.as_ref() {
+                match ss_5 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.ctype {
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 1,
+                line: 15,
+                rule: "wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_2::*;
+
+    impl Metadata for WmmaLoadASyncAlignedLayoutShapeSsAtype2 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".a");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k8 => {
+                    f(".m16n16k8");
+                }
+            }
+            if let Some(ss_6) = self.ss.as_ref() {
+                match ss_6 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.atype {
+                Atype::Tf32 => {
+                    f(".tf32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 2,
+                line: 24,
+                rule: "wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};",
+            }
+        }
+    }
+
+    impl Metadata for WmmaLoadBSyncAlignedLayoutShapeSsBtype2 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".b");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k8 => {
+                    f(".m16n16k8");
+                }
+            }
+            if let Some(ss_7) = self.ss.as_ref() {
+                match ss_7 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.btype {
+                Btype::Tf32 => {
+                    f(".tf32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 2,
+                line: 25,
+                rule: "wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};",
+            }
+        }
+    }
+
+    impl Metadata for WmmaLoadCSyncAlignedLayoutShapeSsCtype2 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".c");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k8 => {
+                    f(".m16n16k8");
+                }
+            }
+            if let Some(ss_8) = self.ss.as_ref() {
+                match ss_8 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.ctype {
+                Ctype::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 2,
+                line: 26,
+                rule: "wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_3::*;
+
+    impl Metadata for WmmaLoadASyncAlignedLayoutShapeSsAtype3 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".a");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M8n8k4 => {
+                    f(".m8n8k4");
+                }
+            }
+            if let Some(ss_9) = self.ss.as_ref() {
+                match ss_9 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.atype {
+                Atype::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 3,
+                line: 35,
+                rule: "wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};",
+            }
+        }
+    }
+
+    impl Metadata for WmmaLoadBSyncAlignedLayoutShapeSsBtype3 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".b");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M8n8k4 => {
+                    f(".m8n8k4");
+                }
+            }
+            if let Some(ss_10) = self.ss.as_ref() {
+                match ss_10 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.btype {
+                Btype::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 3,
+                line: 36,
+                rule: "wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};",
+            }
+        }
+    }
+
+    impl Metadata for WmmaLoadCSyncAlignedLayoutShapeSsCtype3 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".c");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M8n8k4 => {
+                    f(".m8n8k4");
+                }
+            }
+            if let Some(ss_11) = self.ss.as_ref() {
+                match ss_11 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.ctype {
+                Ctype::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 3,
+                line: 37,
+                rule: "wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};",
+            }
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_4::*;
+
+    impl Metadata for WmmaLoadASyncAlignedRowShapeSsAtype {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".a");
+            f(".sync");
+            f(".aligned");
+            f(".row");
+            match &self.shape {
+                Shape::M8n8k32 => {
+                    f(".m8n8k32");
+                }
+            }
+            if let Some(ss_12) = self.ss.as_ref() {
+                match ss_12 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.atype {
+                Atype::S4 => {
+                    f(".s4");
+                }
+                Atype::U4 => {
+                    f(".u4");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 4,
+                line: 46,
+                rule: "wmma.load.a.sync.aligned.row.shape{.ss}.atype r, [p] {, stride};",
+            }
+        }
+    }
+
+    impl Metadata for WmmaLoadBSyncAlignedColShapeSsBtype {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".b");
+            f(".sync");
+            f(".aligned");
+            f(".col");
+            match &self.shape {
+                Shape::M8n8k32 => {
+                    f(".m8n8k32");
+                }
+            }
+            if let Some(ss_13) = self.ss.as_ref() {
+                match ss_13 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.btype {
+                Btype::S4 => {
+                    f(".s4");
+                }
+                Btype::U4 => {
+                    f(".u4");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 4,
+                line: 47,
+                rule: "wmma.load.b.sync.aligned.col.shape{.ss}.btype r, [p] {, stride};",
+            }
+        }
+    }
+
+    impl Metadata for WmmaLoadCSyncAlignedLayoutShapeSsCtype4 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".c");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M8n8k32 => {
+                    f(".m8n8k32");
+                }
+            }
+            if let Some(ss_14) = self.ss.as_ref() {
+                match ss_14 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.ctype {
+                Ctype::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 4,
+                line: 48,
+                rule: "wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};",
+            }
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_5::*;
+
+    impl Metadata for WmmaLoadASyncAlignedRowShapeSsAtype1 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".a");
+            f(".sync");
+            f(".aligned");
+            f(".row");
+            match &self.shape {
+                Shape::M8n8k128 => {
+                    f(".m8n8k128");
+                }
+            }
+            if let Some(ss_15) = self.ss.as_ref() {
+                match ss_15 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.atype {
+                Atype::B1 => {
+                    f(".b1");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 5,
+                line: 57,
+                rule: "wmma.load.a.sync.aligned.row.shape{.ss}.atype r, [p] {, stride};",
+            }
+        }
+    }
+
+    impl Metadata for WmmaLoadBSyncAlignedColShapeSsBtype1 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".b");
+            f(".sync");
+            f(".aligned");
+            f(".col");
+            match &self.shape {
+                Shape::M8n8k128 => {
+                    f(".m8n8k128");
+                }
+            }
+            if let Some(ss_16) = self.ss.as_ref() {
+                match ss_16 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.btype {
+                Btype::B1 => {
+                    f(".b1");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 5,
+                line: 58,
+                rule: "wmma.load.b.sync.aligned.col.shape{.ss}.btype r, [p] {, stride};",
+            }
+        }
+    }
+
+    impl Metadata for WmmaLoadCSyncAlignedLayoutShapeSsCtype5 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".load");
+            f(".c");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M8n8k128 => {
+                    f(".m8n8k128");
+                }
+            }
+            if let Some(ss_17) = self.ss.as_ref() {
+                match ss_17 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.ctype {
+                Ctype::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.load.txt",
+                section: 5,
+                line: 59,
+                rule: "wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};",
+            }
+        }
+    }
+}
@@ -0,0 +1,444 @@
+//! Original PTX specification:
+//!
+//! // Floating point (.f16 multiplicands) wmma.mma
+//! wmma.mma.sync.aligned.alayout.blayout.shape.dtype.ctype d, a, b, c;
+//! ----------------------------------------------------------------
+//! // Integer (.u8/.s8 multiplicands) wmma.mma
+//! wmma.mma.sync.aligned.alayout.blayout.shape.s32.atype.btype.s32{.satfinite} d, a, b, c;
+//! .alayout = {.row, .col};
+//! .blayout = {.row, .col};
+//! .shape  =  {.m16n16k16, .m8n32k16, .m32n8k16};
+//! .dtype   = {.f16, .f32};
+//! .atype   = {.s8, .u8};
+//! .btype   = {.s8, .u8};
+//! .ctype   = {.f16, .f32};
+//! ----------------------------------------------------------------
+//! // Floating point format .bf16 wmma.mma:
+//! wmma.mma.sync.aligned.alayout.blayout.shape.f32.atype.btype.f32 d, a, b, c;
+//! .alayout = {.row, .col};
+//! .blayout = {.row, .col};
+//! .shape   = {.m16n16k16, .m8n32k16, .m32n8k16};
+//! .atype   = {.bf16 };
+//! .btype   = {.bf16};
+//! ----------------------------------------------------------------
+//! // Floating point format .tf32 wmma.mma:
+//! wmma.mma.sync.aligned.alayout.blayout.shape.f32.atype.btype.f32 d, a, b, c;
+//! .alayout = {.row, .col};
+//! .blayout = {.row, .col};
+//! .shape   = {.m16n16k8 };
+//! .atype   = {.tf32 };
+//! .btype   = {.tf32};
+//! ----------------------------------------------------------------
+//! // Floating point Double precision wmma.mma:
+//! wmma.mma.sync.aligned.alayout.blayout.shape{.rnd}.f64.f64.f64.f64 d, a, b, c;
+//! .alayout = {.row, .col};
+//! .blayout = {.row, .col};
+//! .shape   = {.m8n8k4 };
+//! .rnd = { .rn, .rz, .rm, .rp };
+//! ----------------------------------------------------------------
+//! // Sub-byte (.u4/.s4 multiplicands) wmma.mma:
+//! wmma.mma.sync.aligned.row.col.shape.s32.atype.btype.s32{.satfinite} d, a, b, c;
+//! .shape  = {.m8n8k32};
+//! .atype  = {.s4, .u4};
+//! .btype  = {.s4, .u4};
+//! ----------------------------------------------------------------
+//! // Single-bit (.b1 multiplicands) wmma.mma:
+//! wmma.mma.op.popc.sync.aligned.row.col.shape.s32.atype.btype.s32 d, a, b, c;
+//! .shape  = {.m8n8k128};
+//! .atype  = {.b1};
+//! .btype  = {.b1};
+//! .op     = {.xor, .and};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wmma_mma::section_0::*;
+
+    impl Metadata for WmmaMmaSyncAlignedAlayoutBlayoutShapeDtypeCtype {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sync");
+            f(".aligned");
+            f(".alayout");
+            f(".blayout");
+            f(".shape");
+            f(".dtype");
+            f(".ctype");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.mma.txt",
+                section: 0,
+                line: 2,
+                rule: "wmma.mma.sync.aligned.alayout.blayout.shape.dtype.ctype d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::wmma_mma::section_1::*;
+
+    impl Metadata for WmmaMmaSyncAlignedAlayoutBlayoutShapeS32AtypeBtypeS32Satfinite {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sync");
+            f(".aligned");
+            match &self.alayout {
+                Alayout::Row => {
+                    f(".row");
+                }
+                Alayout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.blayout {
+                Blayout::Row => {
+                    f(".row");
+                }
+                Blayout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k16 => {
+                    f(".m16n16k16");
+                }
+                Shape::M8n32k16 => {
+                    f(".m8n32k16");
+                }
+                Shape::M32n8k16 => {
+                    f(".m32n8k16");
+                }
+            }
+            f(".s32");
+            match &self.atype {
+                Atype::S8 => {
+                    f(".s8");
+                }
+                Atype::U8 => {
+                    f(".u8");
+                }
+            }
+            match &self.btype {
+                Btype::S8 => {
+                    f(".s8");
+                }
+                Btype::U8 => {
+                    f(".u8");
+                }
+            }
+            f(".s32");
+            if self.satfinite {
+                f(".satfinite");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.mma.txt",
+                section: 1,
+                line: 5,
+                rule: "wmma.mma.sync.aligned.alayout.blayout.shape.s32.atype.btype.s32{.satfinite} d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::wmma_mma::section_2::*;
+
+    impl Metadata for WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF32 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sync");
+            f(".aligned");
+            match &self.alayout {
+                Alayout::Row => {
+                    f(".row");
+                }
+                Alayout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.blayout {
+                Blayout::Row => {
+                    f(".row");
+                }
+                Blayout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k16 => {
+                    f(".m16n16k16");
+                }
+                Shape::M8n32k16 => {
+                    f(".m8n32k16");
+                }
+                Shape::M32n8k16 => {
+                    f(".m32n8k16");
+                }
+            }
+            f(".f32");
+            match &self.atype {
+                Atype::Bf16 => {
+                    f(".bf16");
+                }
+            }
+            match &self.btype {
+                Btype::Bf16 => {
+                    f(".bf16");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.mma.txt",
+                section: 2,
+                line: 15,
+                rule: "wmma.mma.sync.aligned.alayout.blayout.shape.f32.atype.btype.f32 d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::wmma_mma::section_3::*;
+
+    impl Metadata for WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF321 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sync");
+            f(".aligned");
+            match &self.alayout {
+                Alayout::Row => {
+                    f(".row");
+                }
+                Alayout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.blayout {
+                Blayout::Row => {
+                    f(".row");
+                }
+                Blayout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k8 => {
+                    f(".m16n16k8");
+                }
+            }
+            f(".f32");
+            match &self.atype {
+                Atype::Tf32 => {
+                    f(".tf32");
+                }
+            }
+            match &self.btype {
+                Btype::Tf32 => {
+                    f(".tf32");
+                }
+            }
+            f(".f32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.mma.txt",
+                section: 3,
+                line: 23,
+                rule: "wmma.mma.sync.aligned.alayout.blayout.shape.f32.atype.btype.f32 d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::wmma_mma::section_4::*;
+
+    impl Metadata for WmmaMmaSyncAlignedAlayoutBlayoutShapeRndF64F64F64F64 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sync");
+            f(".aligned");
+            match &self.alayout {
+                Alayout::Row => {
+                    f(".row");
+                }
+                Alayout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.blayout {
+                Blayout::Row => {
+                    f(".row");
+                }
+                Blayout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M8n8k4 => {
+                    f(".m8n8k4");
+                }
+            }
+            if let Some(rnd_0) = self.rnd.as_ref() {
+                match rnd_0 {
+                    Rnd::Rn => {
+                        f(".rn");
+                    }
+                    Rnd::Rz => {
+                        f(".rz");
+                    }
+                    Rnd::Rm => {
+                        f(".rm");
+                    }
+                    Rnd::Rp => {
+                        f(".rp");
+                    }
+                }
+            }
+            f(".f64");
+            f(".f64");
+            f(".f64");
+            f(".f64");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.mma.txt",
+                section: 4,
+                line: 31,
+                rule: "wmma.mma.sync.aligned.alayout.blayout.shape{.rnd}.f64.f64.f64.f64 d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::wmma_mma::section_5::*;
+
+    impl Metadata for WmmaMmaSyncAlignedRowColShapeS32AtypeBtypeS32Satfinite {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            f(".sync");
+            f(".aligned");
+            f(".row");
+            f(".col");
+            match &self.shape {
+                Shape::M8n8k32 => {
+                    f(".m8n8k32");
+                }
+            }
+            f(".s32");
+            match &self.atype {
+                Atype::S4 => {
+                    f(".s4");
+                }
+                Atype::U4 => {
+                    f(".u4");
+                }
+            }
+            match &self.btype {
+                Btype::S4 => {
+                    f(".s4");
+                }
+                Btype::U4 => {
+                    f(".u4");
+                }
+            }
+            f(".s32");
+            if self.satfinite {
+                f(".satfinite");
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.mma.txt",
+                section: 5,
+                line: 38,
+                rule: "wmma.mma.sync.aligned.row.col.shape.s32.atype.btype.s32{.satfinite} d, a, b, c;",
+            }
+        }
+    }
+}
+
+pub mod section_6 {
+    use super::*;
+    use crate::r#type::instruction::wmma_mma::section_6::*;
+
+    impl Metadata for WmmaMmaOpPopcSyncAlignedRowColShapeS32AtypeBtypeS32 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".mma");
+            match &self.op {
+                Op::Xor => {
+                    f(".xor");
+                }
+                Op::And => {
+                    f(".and");
+                }
+            }
+            f(".popc");
+            f(".sync");
+            f(".aligned");
+            f(".row");
+            f(".col");
+            match &self.shape {
+                Shape::M8n8k128 => {
+                    f(".m8n8k128");
+                }
+            }
+            f(".s32");
+            match &self.atype {
+                Atype::B1 => {
+                    f(".b1");
+                }
+            }
+            match &self.btype {
+                Btype::B1 => {
+                    f(".b1");
+                }
+            }
+            f(".s32");
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.mma.txt",
+                section: 6,
+                line: 44,
+                rule: "wmma.mma.op.popc.sync.aligned.row.col.shape.s32.atype.btype.s32 d, a, b, c;",
+            }
+        }
+    }
+}
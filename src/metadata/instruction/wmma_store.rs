@@ -0,0 +1,268 @@
+//! Original PTX specification:
+//!
+//! wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k16, .m8n32k16, .m32n8k16};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .type   = {.f16, .f32, .s32};
+//! ----------------------------------------------------------------
+//! wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k32, .m8n8k128};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .type   = {.s32};
+//! ----------------------------------------------------------------
+//! wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k8};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .type   = {.f32};
+//! ----------------------------------------------------------------
+//! wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k4 };
+//! .ss     = {.global, .shared, .shared::cta};
+//! .type   = {.f64};
+
+#![allow(unused)]
+
+use crate::metadata::{Metadata, SpecSection};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wmma_store::section_0::*;
+
+    impl Metadata for WmmaStoreDSyncAlignedLayoutShapeSsType {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".store");
+            f(".d");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k16 => {
+                    f(".m16n16k16");
+                }
+                Shape::M8n32k16 => {
+                    f(".m8n32k16");
+                }
+                Shape::M32n8k16 => {
+                    f(".m32n8k16");
+                }
+            }
+            if let Some(ss_0) = self.ss.as_ref() {
+                match ss_0 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::F16 => {
+                    f(".f16");
+                }
+                Type::F32 => {
+                    f(".f32");
+                }
+                Type::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.store.txt",
+                section: 0,
+                line: 1,
+                rule: "wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};",
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::wmma_store::section_1::*;
+
+    impl Metadata for WmmaStoreDSyncAlignedLayoutShapeSsType1 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".store");
+            f(".d");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M8n8k128 => {
+                    f(".m8n8k128");
+                }
+                Shape::M8n8k32 => {
+                    f(".m8n8k32");
+                }
+            }
+            if let Some(ss_1) = self.ss.as_ref() {
+                match ss_1 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::S32 => {
+                    f(".s32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.store.txt",
+                section: 1,
+                line: 7,
+                rule: "wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};",
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::wmma_store::section_2::*;
+
+    impl Metadata for WmmaStoreDSyncAlignedLayoutShapeSsType2 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".store");
+            f(".d");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M16n16k8 => {
+                    f(".m16n16k8");
+                }
+            }
+            if let Some(ss_2) = self.ss.as_ref() {
+                match ss_2 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::F32 => {
+                    f(".f32");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.store.txt",
+                section: 2,
+                line: 13,
+                rule: "wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};",
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::wmma_store::section_3::*;
+
+    impl Metadata for WmmaStoreDSyncAlignedLayoutShapeSsType3 {
+        fn opcode(&self) -> &'static str {
+            "wmma"
+        }
+        fn for_each_modifier(&self, f: &mut dyn FnMut(&str)) {
+            f(".store");
+            f(".d");
+            f(".sync");
+            f(".aligned");
+            match &self.layout {
+                Layout::Row => {
+                    f(".row");
+                }
+                Layout::Col => {
+                    f(".col");
+                }
+            }
+            match &self.shape {
+                Shape::M8n8k4 => {
+                    f(".m8n8k4");
+                }
+            }
+            if let Some(ss_3) = self.ss.as_ref() {
+                match ss_3 {
+                    Ss::SharedCta => {
+                        f(".shared::cta");
+                    }
+                    Ss::Global => {
+                        f(".global");
+                    }
+                    Ss::Shared => {
+                        f(".shared");
+                    }
+                }
+            }
+            match &self.type_ {
+                Type::F64 => {
+                    f(".f64");
+                }
+            }
+        }
+        fn spec_section(&self) -> SpecSection {
+            SpecSection {
+                file: "wmma.store.txt",
+                section: 3,
+                line: 19,
+                rule: "wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};",
+            }
+        }
+    }
+}
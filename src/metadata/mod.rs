@@ -0,0 +1,316 @@
+//! Opcode and modifier metadata of instructions.
+//!
+//! Every instruction struct and [`Inst`] implement [`Metadata`], which
+//! reports the base opcode, the modifiers as written and the grammar rule the
+//! instruction was parsed with, without unparsing it.
+//!
+//! ```
+//! use ptx_parser::r#type::{FunctionStatement, ModuleDirective};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let module =
+//!         ptx_parser::parse_ptx(".entry k() { ld.global.nc.v4.f32 {%f1, %f2, %f3, %f4}, [%rd1]; }")
+//!             .unwrap();
+//!     let ModuleDirective::EntryFunction { directive, .. } = &module.directives[0] else {
+//!         unreachable!()
+//!     };
+//!     let FunctionStatement::Instruction { instruction, .. } =
+//!         &directive.body.as_ref().unwrap().statements[0]
+//!     else {
+//!         unreachable!()
+//!     };
+//!     assert_eq!(instruction.inst.opcode(), "ld");
+//!     assert_eq!(instruction.inst.mnemonic(), "ld.global.nc.v4.f32");
+//!     assert_eq!(instruction.inst.modifiers(), [".global", ".nc", ".v4", ".f32"]);
+//!     assert_eq!(instruction.inst.spec_section().file, "ld.global.nc.txt");
+//! });
+//! ```
+//!
+//! The implementations for [`Inst`] and the individual instruction structs are
+//! generated from the grammar by `crates/parser-gen` (`generate-metadata`) and
+//! live in `metadata/instruction`.
+//!
+//! [`Inst::requirements`] looks up the minimum PTX ISA version and the
+//! targets an instruction needs. The table is generated by
+//! `crates/parser-gen` (`generate-requirements`) from
+//! `crates/parser-gen/ptx_requirements.txt` and lives in
+//! `metadata/requirements.rs`.
+
+pub(crate) mod instruction;
+pub(crate) mod requirements;
+
+use std::fmt;
+
+use crate::r#type::TargetString;
+use crate::r#type::instruction::Inst;
+
+/// Location of the grammar rule an instruction was parsed with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SpecSection {
+    /// File name under `crates/parser-gen/ptx_syntax`, e.g. `ld.txt`.
+    pub file: &'static str,
+    /// Index of the section within the file; sections are separated by
+    /// `----` lines.
+    pub section: usize,
+    /// 1-based line of the rule within the file.
+    pub line: usize,
+    /// Text of the rule, with whitespace collapsed.
+    pub rule: &'static str,
+}
+
+/// Describes the opcode and modifiers of an instruction.
+///
+/// Implemented for [`Inst`] and every instruction struct; the implementations
+/// are generated from the grammar rule of each instruction.
+pub trait Metadata {
+    /// Base opcode, e.g. `ld` for `ld.global.f32`.
+    fn opcode(&self) -> &'static str;
+
+    /// Call `f` with every modifier in source order, including the leading
+    /// dot, e.g. `.global`.
+    fn for_each_modifier(&self, f: &mut dyn FnMut(&str));
+
+    /// Grammar rule the instruction was parsed with.
+    fn spec_section(&self) -> SpecSection;
+
+    /// Modifiers in source order.
+    fn modifiers(&self) -> Vec<String> {
+        let mut modifiers = Vec::new();
+        self.for_each_modifier(&mut |modifier| modifiers.push(modifier.to_string()));
+        modifiers
+    }
+
+    /// Opcode followed by its modifiers, as written, e.g. `ld.global.f32`.
+    fn mnemonic(&self) -> String {
+        let mut mnemonic = self.opcode().to_string();
+        self.for_each_modifier(&mut |modifier| mnemonic.push_str(modifier));
+        mnemonic
+    }
+}
+
+impl Inst {
+    /// Base opcode. See [`Metadata::opcode`].
+    pub fn opcode(&self) -> &'static str {
+        Metadata::opcode(self)
+    }
+
+    /// Full mnemonic. See [`Metadata::mnemonic`].
+    pub fn mnemonic(&self) -> String {
+        Metadata::mnemonic(self)
+    }
+
+    /// Modifiers in source order. See [`Metadata::modifiers`].
+    pub fn modifiers(&self) -> Vec<String> {
+        Metadata::modifiers(self)
+    }
+
+    /// Grammar rule the instruction was parsed with. See
+    /// [`Metadata::spec_section`].
+    pub fn spec_section(&self) -> SpecSection {
+        Metadata::spec_section(self)
+    }
+
+    /// Requirements that apply to the instruction, in table order.
+    pub fn requirements(&self) -> Vec<&'static Requirement> {
+        let opcode = self.opcode();
+        let modifiers = self.modifiers();
+        Requirement::all()
+            .iter()
+            .filter(|requirement| requirement.matches(opcode, &modifiers))
+            .collect()
+    }
+}
+
+/// A PTX ISA version, as given by the `.version` directive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PtxVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl PtxVersion {
+    pub const fn new(major: u32, minor: u32) -> Self {
+        PtxVersion { major, minor }
+    }
+}
+
+impl fmt::Display for PtxVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Suffix of a target name.
+///
+/// The order is the order of preference when looking for the lowest target
+/// that runs a module: a base target runs on every later architecture, a
+/// family-specific one on the later architectures of its family only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TargetVariant {
+    /// No suffix, e.g. `sm_90`.
+    Base,
+    /// `f` suffix, e.g. `sm_100f`.
+    Family,
+    /// `a` suffix, e.g. `sm_90a`.
+    Arch,
+}
+
+/// A `.target` architecture such as `sm_90a`, ordered by architecture and
+/// then by [`TargetVariant`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Target {
+    /// Architecture number, e.g. `90` for `sm_90a`.
+    pub sm: u32,
+    pub variant: TargetVariant,
+}
+
+impl Target {
+    pub const fn base(sm: u32) -> Self {
+        Target {
+            sm,
+            variant: TargetVariant::Base,
+        }
+    }
+
+    pub const fn family(sm: u32) -> Self {
+        Target {
+            sm,
+            variant: TargetVariant::Family,
+        }
+    }
+
+    pub const fn arch(sm: u32) -> Self {
+        Target {
+            sm,
+            variant: TargetVariant::Arch,
+        }
+    }
+
+    /// Architecture named by a `.target` entry, or `None` for the options
+    /// such as `texmode_unified` and `debug`.
+    pub fn of(target: &TargetString) -> Option<Target> {
+        Some(match target {
+            TargetString::Sm120a { .. } => Target::arch(120),
+            TargetString::Sm120f { .. } => Target::family(120),
+            TargetString::Sm120 { .. } => Target::base(120),
+            TargetString::Sm121a { .. } => Target::arch(121),
+            TargetString::Sm121f { .. } => Target::family(121),
+            TargetString::Sm121 { .. } => Target::base(121),
+            TargetString::Sm110a { .. } => Target::arch(110),
+            TargetString::Sm110f { .. } => Target::family(110),
+            TargetString::Sm110 { .. } => Target::base(110),
+            TargetString::Sm100a { .. } => Target::arch(100),
+            TargetString::Sm100f { .. } => Target::family(100),
+            TargetString::Sm100 { .. } => Target::base(100),
+            TargetString::Sm101a { .. } => Target::arch(101),
+            TargetString::Sm101f { .. } => Target::family(101),
+            TargetString::Sm101 { .. } => Target::base(101),
+            TargetString::Sm103a { .. } => Target::arch(103),
+            TargetString::Sm103f { .. } => Target::family(103),
+            TargetString::Sm103 { .. } => Target::base(103),
+            TargetString::Sm90a { .. } => Target::arch(90),
+            TargetString::Sm90 { .. } => Target::base(90),
+            TargetString::Sm80 { .. } => Target::base(80),
+            TargetString::Sm86 { .. } => Target::base(86),
+            TargetString::Sm87 { .. } => Target::base(87),
+            TargetString::Sm88 { .. } => Target::base(88),
+            TargetString::Sm89 { .. } => Target::base(89),
+            TargetString::Sm70 { .. } => Target::base(70),
+            TargetString::Sm72 { .. } => Target::base(72),
+            TargetString::Sm75 { .. } => Target::base(75),
+            TargetString::Sm60 { .. } => Target::base(60),
+            TargetString::Sm61 { .. } => Target::base(61),
+            TargetString::Sm62 { .. } => Target::base(62),
+            TargetString::Sm50 { .. } => Target::base(50),
+            TargetString::Sm52 { .. } => Target::base(52),
+            TargetString::Sm53 { .. } => Target::base(53),
+            TargetString::Sm30 { .. } => Target::base(30),
+            TargetString::Sm32 { .. } => Target::base(32),
+            TargetString::Sm35 { .. } => Target::base(35),
+            TargetString::Sm37 { .. } => Target::base(37),
+            TargetString::Sm20 { .. } => Target::base(20),
+            TargetString::Sm10 { .. } => Target::base(10),
+            TargetString::Sm11 { .. } => Target::base(11),
+            TargetString::Sm12 { .. } => Target::base(12),
+            TargetString::Sm13 { .. } => Target::base(13),
+            TargetString::TexmodeUnified { .. }
+            | TargetString::TexmodeIndependent { .. }
+            | TargetString::Debug { .. }
+            | TargetString::MapF64ToF32 { .. } => return None,
+        })
+    }
+
+    /// Whether code for this target may use a feature that `required`
+    /// allows.
+    ///
+    /// A base requirement such as `sm_80` is met by `sm_80` and every later
+    /// target. A family requirement such as `sm_100f` is met by the family
+    /// and architecture-specific targets of the same family from `sm_100`
+    /// on. An architecture-specific requirement such as `sm_90a` is met by
+    /// `sm_90a` only.
+    pub fn satisfies(&self, required: &Target) -> bool {
+        match required.variant {
+            TargetVariant::Base => self.sm >= required.sm,
+            TargetVariant::Family => {
+                self.variant != TargetVariant::Base
+                    && self.sm >= required.sm
+                    && self.sm / 10 == required.sm / 10
+            }
+            TargetVariant::Arch => *self == *required,
+        }
+    }
+}
+
+impl fmt::Display for Target {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let suffix = match self.variant {
+            TargetVariant::Base => "",
+            TargetVariant::Family => "f",
+            TargetVariant::Arch => "a",
+        };
+        write!(f, "sm_{}{}", self.sm, suffix)
+    }
+}
+
+/// Minimum PTX ISA version and supported targets of an instruction pattern,
+/// from `crates/parser-gen/ptx_requirements.txt`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Requirement {
+    /// Pattern as written in the table, e.g. `cp.async.bulk` or `*.bf16`.
+    pub pattern: &'static str,
+    /// Opcode of the pattern, or `None` when it applies to every opcode.
+    pub opcode: Option<&'static str>,
+    /// Modifiers that must all be present, in any position.
+    pub modifiers: &'static [&'static str],
+    /// PTX ISA version that introduced the pattern.
+    pub ptx: PtxVersion,
+    /// Targets that support the pattern, any of which will do; empty when
+    /// every target does.
+    pub targets: &'static [Target],
+}
+
+impl Requirement {
+    /// Every requirement in table order.
+    pub fn all() -> &'static [Requirement] {
+        requirements::REQUIREMENTS
+    }
+
+    /// Whether an instruction with `opcode` and `modifiers` matches the
+    /// pattern.
+    pub fn matches(&self, opcode: &str, modifiers: &[String]) -> bool {
+        self.opcode.is_none_or(|required| required == opcode)
+            && self
+                .modifiers
+                .iter()
+                .all(|required| modifiers.iter().any(|modifier| modifier == required))
+    }
+
+    /// Whether code for `target` may use the pattern.
+    pub fn allows(&self, target: &Target) -> bool {
+        self.targets.is_empty()
+            || self
+                .targets
+                .iter()
+                .any(|required| target.satisfies(required))
+    }
+}
@@ -0,0 +1,512 @@
+// Auto-generated module declarations
+// DO NOT EDIT MANUALLY
+#![allow(unused)]
+
+use crate::metadata::{PtxVersion, Requirement, Target};
+
+pub(crate) const REQUIREMENTS: &[Requirement] = &[
+    Requirement {
+        pattern: "cp.async",
+        opcode: Some("cp"),
+        modifiers: &[".async"],
+        ptx: PtxVersion::new(7, 0),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "cp.async.mbarrier.arrive",
+        opcode: Some("cp"),
+        modifiers: &[".async", ".mbarrier", ".arrive"],
+        ptx: PtxVersion::new(7, 0),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "cp.async.bulk",
+        opcode: Some("cp"),
+        modifiers: &[".async", ".bulk"],
+        ptx: PtxVersion::new(8, 0),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "cp.async.bulk.tensor",
+        opcode: Some("cp"),
+        modifiers: &[".async", ".bulk", ".tensor"],
+        ptx: PtxVersion::new(8, 0),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "cp.async.bulk.prefetch",
+        opcode: Some("cp"),
+        modifiers: &[".async", ".bulk", ".prefetch"],
+        ptx: PtxVersion::new(8, 0),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "cp.reduce.async.bulk",
+        opcode: Some("cp"),
+        modifiers: &[".reduce", ".async", ".bulk"],
+        ptx: PtxVersion::new(8, 0),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "st.async",
+        opcode: Some("st"),
+        modifiers: &[".async"],
+        ptx: PtxVersion::new(8, 1),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "red.async",
+        opcode: Some("red"),
+        modifiers: &[".async"],
+        ptx: PtxVersion::new(8, 1),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "st.bulk",
+        opcode: Some("st"),
+        modifiers: &[".bulk"],
+        ptx: PtxVersion::new(8, 6),
+        targets: &[Target::base(100)],
+    },
+    Requirement {
+        pattern: "mbarrier",
+        opcode: Some("mbarrier"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 0),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "mbarrier.try_wait",
+        opcode: Some("mbarrier"),
+        modifiers: &[".try_wait"],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "mbarrier.expect_tx",
+        opcode: Some("mbarrier"),
+        modifiers: &[".expect_tx"],
+        ptx: PtxVersion::new(8, 0),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "mbarrier.complete_tx",
+        opcode: Some("mbarrier"),
+        modifiers: &[".complete_tx"],
+        ptx: PtxVersion::new(8, 0),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "wmma",
+        opcode: Some("wmma"),
+        modifiers: &[],
+        ptx: PtxVersion::new(6, 0),
+        targets: &[Target::base(70)],
+    },
+    Requirement {
+        pattern: "mma",
+        opcode: Some("mma"),
+        modifiers: &[],
+        ptx: PtxVersion::new(6, 4),
+        targets: &[Target::base(70)],
+    },
+    Requirement {
+        pattern: "mma.sp",
+        opcode: Some("mma"),
+        modifiers: &[".sp"],
+        ptx: PtxVersion::new(7, 1),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "mma.sp::ordered_metadata",
+        opcode: Some("mma"),
+        modifiers: &[".sp::ordered_metadata"],
+        ptx: PtxVersion::new(8, 5),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "ldmatrix",
+        opcode: Some("ldmatrix"),
+        modifiers: &[],
+        ptx: PtxVersion::new(6, 5),
+        targets: &[Target::base(75)],
+    },
+    Requirement {
+        pattern: "movmatrix",
+        opcode: Some("movmatrix"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(75)],
+    },
+    Requirement {
+        pattern: "stmatrix",
+        opcode: Some("stmatrix"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "wgmma",
+        opcode: Some("wgmma"),
+        modifiers: &[],
+        ptx: PtxVersion::new(8, 0),
+        targets: &[Target::arch(90)],
+    },
+    Requirement {
+        pattern: "tcgen05",
+        opcode: Some("tcgen05"),
+        modifiers: &[],
+        ptx: PtxVersion::new(8, 6),
+        targets: &[Target::family(100), Target::family(110)],
+    },
+    Requirement {
+        pattern: "match.sync",
+        opcode: Some("match"),
+        modifiers: &[".sync"],
+        ptx: PtxVersion::new(6, 0),
+        targets: &[Target::base(70)],
+    },
+    Requirement {
+        pattern: "redux.sync",
+        opcode: Some("redux"),
+        modifiers: &[".sync"],
+        ptx: PtxVersion::new(7, 0),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "elect.sync",
+        opcode: Some("elect"),
+        modifiers: &[".sync"],
+        ptx: PtxVersion::new(8, 0),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "barrier.cluster",
+        opcode: Some("barrier"),
+        modifiers: &[".cluster"],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "mapa",
+        opcode: Some("mapa"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "getctarank",
+        opcode: Some("getctarank"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "griddepcontrol",
+        opcode: Some("griddepcontrol"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "clusterlaunchcontrol",
+        opcode: Some("clusterlaunchcontrol"),
+        modifiers: &[],
+        ptx: PtxVersion::new(8, 6),
+        targets: &[Target::base(100)],
+    },
+    Requirement {
+        pattern: "setmaxnreg",
+        opcode: Some("setmaxnreg"),
+        modifiers: &[],
+        ptx: PtxVersion::new(8, 0),
+        targets: &[Target::arch(90)],
+    },
+    Requirement {
+        pattern: "multimem",
+        opcode: Some("multimem"),
+        modifiers: &[],
+        ptx: PtxVersion::new(8, 1),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "prefetch.tensormap",
+        opcode: Some("prefetch"),
+        modifiers: &[".tensormap"],
+        ptx: PtxVersion::new(8, 0),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "tensormap.replace",
+        opcode: Some("tensormap"),
+        modifiers: &[".replace"],
+        ptx: PtxVersion::new(8, 3),
+        targets: &[Target::arch(90)],
+    },
+    Requirement {
+        pattern: "tensormap.cp_fenceproxy",
+        opcode: Some("tensormap"),
+        modifiers: &[".cp_fenceproxy"],
+        ptx: PtxVersion::new(8, 3),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "dp4a",
+        opcode: Some("dp4a"),
+        modifiers: &[],
+        ptx: PtxVersion::new(5, 0),
+        targets: &[Target::base(61)],
+    },
+    Requirement {
+        pattern: "dp2a",
+        opcode: Some("dp2a"),
+        modifiers: &[],
+        ptx: PtxVersion::new(5, 0),
+        targets: &[Target::base(61)],
+    },
+    Requirement {
+        pattern: "nanosleep",
+        opcode: Some("nanosleep"),
+        modifiers: &[],
+        ptx: PtxVersion::new(6, 3),
+        targets: &[Target::base(70)],
+    },
+    Requirement {
+        pattern: "tanh",
+        opcode: Some("tanh"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 0),
+        targets: &[Target::base(75)],
+    },
+    Requirement {
+        pattern: "cvt.pack",
+        opcode: Some("cvt"),
+        modifiers: &[".pack"],
+        ptx: PtxVersion::new(7, 1),
+        targets: &[Target::base(72)],
+    },
+    Requirement {
+        pattern: "alloca",
+        opcode: Some("alloca"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 3),
+        targets: &[Target::base(52)],
+    },
+    Requirement {
+        pattern: "stacksave",
+        opcode: Some("stacksave"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 3),
+        targets: &[Target::base(52)],
+    },
+    Requirement {
+        pattern: "stackrestore",
+        opcode: Some("stackrestore"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 3),
+        targets: &[Target::base(52)],
+    },
+    Requirement {
+        pattern: "discard",
+        opcode: Some("discard"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 4),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "applypriority",
+        opcode: Some("applypriority"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 4),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "createpolicy",
+        opcode: Some("createpolicy"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 4),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "szext",
+        opcode: Some("szext"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 6),
+        targets: &[Target::base(70)],
+    },
+    Requirement {
+        pattern: "bmsk",
+        opcode: Some("bmsk"),
+        modifiers: &[],
+        ptx: PtxVersion::new(7, 6),
+        targets: &[Target::base(70)],
+    },
+    Requirement {
+        pattern: "atom.add.f64",
+        opcode: Some("atom"),
+        modifiers: &[".add", ".f64"],
+        ptx: PtxVersion::new(5, 0),
+        targets: &[Target::base(60)],
+    },
+    Requirement {
+        pattern: "red.add.f64",
+        opcode: Some("red"),
+        modifiers: &[".add", ".f64"],
+        ptx: PtxVersion::new(5, 0),
+        targets: &[Target::base(60)],
+    },
+    Requirement {
+        pattern: "*.L2::64B",
+        opcode: None,
+        modifiers: &[".L2::64B"],
+        ptx: PtxVersion::new(7, 4),
+        targets: &[Target::base(75)],
+    },
+    Requirement {
+        pattern: "*.L2::128B",
+        opcode: None,
+        modifiers: &[".L2::128B"],
+        ptx: PtxVersion::new(7, 4),
+        targets: &[Target::base(75)],
+    },
+    Requirement {
+        pattern: "*.L2::256B",
+        opcode: None,
+        modifiers: &[".L2::256B"],
+        ptx: PtxVersion::new(7, 4),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "*.L2::cache_hint",
+        opcode: None,
+        modifiers: &[".L2::cache_hint"],
+        ptx: PtxVersion::new(7, 4),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "*.relaxed",
+        opcode: None,
+        modifiers: &[".relaxed"],
+        ptx: PtxVersion::new(6, 0),
+        targets: &[Target::base(70)],
+    },
+    Requirement {
+        pattern: "*.acquire",
+        opcode: None,
+        modifiers: &[".acquire"],
+        ptx: PtxVersion::new(6, 0),
+        targets: &[Target::base(70)],
+    },
+    Requirement {
+        pattern: "*.release",
+        opcode: None,
+        modifiers: &[".release"],
+        ptx: PtxVersion::new(6, 0),
+        targets: &[Target::base(70)],
+    },
+    Requirement {
+        pattern: "*.cluster",
+        opcode: None,
+        modifiers: &[".cluster"],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "*.shared::cluster",
+        opcode: None,
+        modifiers: &[".shared::cluster"],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "*.bf16",
+        opcode: None,
+        modifiers: &[".bf16"],
+        ptx: PtxVersion::new(7, 0),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "*.bf16x2",
+        opcode: None,
+        modifiers: &[".bf16x2"],
+        ptx: PtxVersion::new(7, 0),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "add.bf16",
+        opcode: Some("add"),
+        modifiers: &[".bf16"],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "sub.bf16",
+        opcode: Some("sub"),
+        modifiers: &[".bf16"],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "mul.bf16",
+        opcode: Some("mul"),
+        modifiers: &[".bf16"],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "*.tf32",
+        opcode: None,
+        modifiers: &[".tf32"],
+        ptx: PtxVersion::new(7, 0),
+        targets: &[Target::base(80)],
+    },
+    Requirement {
+        pattern: "*.e4m3",
+        opcode: None,
+        modifiers: &[".e4m3"],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(89)],
+    },
+    Requirement {
+        pattern: "*.e5m2",
+        opcode: None,
+        modifiers: &[".e5m2"],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(89)],
+    },
+    Requirement {
+        pattern: "*.e4m3x2",
+        opcode: None,
+        modifiers: &[".e4m3x2"],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(89)],
+    },
+    Requirement {
+        pattern: "*.e5m2x2",
+        opcode: None,
+        modifiers: &[".e5m2x2"],
+        ptx: PtxVersion::new(7, 8),
+        targets: &[Target::base(89)],
+    },
+    Requirement {
+        pattern: "*.b128",
+        opcode: None,
+        modifiers: &[".b128"],
+        ptx: PtxVersion::new(8, 3),
+        targets: &[Target::base(70)],
+    },
+    Requirement {
+        pattern: "atom.b128",
+        opcode: Some("atom"),
+        modifiers: &[".b128"],
+        ptx: PtxVersion::new(8, 3),
+        targets: &[Target::base(90)],
+    },
+    Requirement {
+        pattern: "*.mmio",
+        opcode: None,
+        modifiers: &[".mmio"],
+        ptx: PtxVersion::new(8, 2),
+        targets: &[Target::base(70)],
+    },
+];
@@ -0,0 +1,126 @@
+//! Original PTX specification:
+//!
+//! abs.type  d, a;
+//! .type = { .s16, .s32, .s64 };
+//!
+//! abs{.ftz}.f32  d, a;
+//! abs.f64        d, a;
+//!
+//! abs{.ftz}.f16    d, a;
+//! abs{.ftz}.f16x2  d, a;
+//! abs.bf16         d, a;
+//! abs.bf16x2       d, a;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::abs::section_0::*;
+
+    impl Operands for AbsType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+        }
+    }
+
+    impl Operands for AbsFtzF32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+        }
+    }
+
+    impl Operands for AbsF64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+        }
+    }
+
+    impl Operands for AbsFtzF16 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+        }
+    }
+
+    impl Operands for AbsFtzF16x2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+        }
+    }
+
+    impl Operands for AbsBf16 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+        }
+    }
+
+    impl Operands for AbsBf16x2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+        }
+    }
+}
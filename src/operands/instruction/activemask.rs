@@ -0,0 +1,25 @@
+//! Original PTX specification:
+//!
+//! activemask.b32 d;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::activemask::section_0::*;
+
+    impl Operands for ActivemaskB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+        }
+    }
+}
@@ -0,0 +1,216 @@
+//! Original PTX specification:
+//!
+//! add.type       d, a, b;
+//! add{.sat}.s32  d, a, b;     // .sat applies only to .s32
+//! .type = { .u16, .u32, .u64,
+//! .s16, .s32, .s64,
+//! .u16x2, .s16x2 };
+//! -------------------------------------------
+//! add{.rnd}{.ftz}{.sat}.f32  d, a, b;
+//! add{.rnd}{.ftz}.f32x2      d, a, b;
+//! add{.rnd}.f64              d, a, b;
+//! .rnd = { .rn, .rz, .rm, .rp };
+//! --------------------------------------------
+//! add{.rnd}{.ftz}{.sat}.f16   d, a, b;
+//! add{.rnd}{.ftz}{.sat}.f16x2 d, a, b;
+//! add{.rnd}.bf16   d, a, b;
+//! add{.rnd}.bf16x2 d, a, b;
+//! .rnd = { .rn };
+//! --------------------------------------------
+//! add{.rnd}{.sat}.f32.atype  d, a, c;
+//! .atype = { .f16, .bf16};
+//! .rnd   = { .rn, .rz, .rm, .rp };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::add::section_0::*;
+
+    impl Operands for AddType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+
+    impl Operands for AddSatS32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::add::section_1::*;
+
+    impl Operands for AddRndFtzSatF32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+
+    impl Operands for AddRndFtzF32x2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+
+    impl Operands for AddRndF64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::add::section_2::*;
+
+    impl Operands for AddRndFtzSatF16 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+
+    impl Operands for AddRndFtzSatF16x2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+
+    impl Operands for AddRndBf16 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+
+    impl Operands for AddRndBf16x2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::add::section_3::*;
+
+    impl Operands for AddRndSatF32Atype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+}
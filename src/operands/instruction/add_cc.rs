@@ -0,0 +1,30 @@
+//! Original PTX specification:
+//!
+//! add.cc.type  d, a, b;
+//! .type = { .u32, .s32, .u64, .s64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::add_cc::section_0::*;
+
+    impl Operands for AddCcType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+}
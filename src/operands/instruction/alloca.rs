@@ -0,0 +1,50 @@
+//! Original PTX specification:
+//!
+//! alloca.type  ptr, size{, immAlign};
+//! .type = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::alloca::section_0::*;
+
+    impl Operands for AllocaType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "ptr",
+                OperandRole::Destination,
+                &self.ptr,
+            ));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+            if let Some(operand) = &self.immalign {
+                f(OperandRef::general(
+                    "immAlign",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "ptr",
+                OperandRole::Destination,
+                &mut self.ptr,
+            ));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+            if let Some(operand) = &mut self.immalign {
+                f(OperandMut::general(
+                    "immAlign",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
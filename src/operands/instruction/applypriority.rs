@@ -0,0 +1,28 @@
+//! Original PTX specification:
+//!
+//! applypriority{.global}.level::eviction_priority  [a], size;
+//! .level::eviction_priority = { .L2::evict_normal };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::applypriority::section_0::*;
+
+    impl Operands for ApplypriorityGlobalLevelEvictionPriority {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+        }
+    }
+}
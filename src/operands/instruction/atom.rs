@@ -0,0 +1,381 @@
+//! Original PTX specification:
+//!
+//! // Atomic operation with scalar type:
+//! atom{.sem}{.scope}{.space}.op{.level::cache_hint}.type d, [a], b{, cache-policy};
+//! atom{.sem}{.scope}{.space}.op.type d, [a], b, c;
+//! atom{.sem}{.scope}{.space}.cas.b16 d, [a], b, c;
+//! atom{.sem}{.scope}{.space}.cas.b128 d, [a], b, c;
+//! atom{.sem}{.scope}{.space}.exch{.level::cache_hint}.b128 d, [a], b {, cache-policy};
+//! atom{.sem}{.scope}{.space}.add.noftz{.level::cache_hint}.f16     d, [a], b{, cache-policy};
+//! atom{.sem}{.scope}{.space}.add.noftz{.level::cache_hint}.f16x2   d, [a], b{, cache-policy};
+//! atom{.sem}{.scope}{.space}.add.noftz{.level::cache_hint}.bf16    d, [a], b{, cache-policy};
+//! atom{.sem}{.scope}{.space}.add.noftz{.level::cache_hint}.bf16x2  d, [a], b{, cache-policy};
+//! .space =              { .global, .shared, .shared::cta, .shared::cluster};
+//! .sem =                { .relaxed, .acquire, .release, .acq_rel };
+//! .scope =              { .cta, .cluster, .gpu, .sys };
+//! .op =                 { .and, .or, .xor, .cas, .exch, .add, .inc, .dec, .min, .max };
+//! .level::cache_hint =  { .L2::cache_hint };
+//! .type =               { .b32, .b64, .u32, .u64, .s32, .s64, .f32, .f64 };
+//! -------------------------------------------------------------
+//! // Atomic operation with vector type:
+//! atom{.sem}{.scope}{.global}.add{.level::cache_hint}.vec_32_bit.f32                  d, [a], b{, cache-policy};
+//! atom{.sem}{.scope}{.global}.op.noftz{.level::cache_hint}.vec_16_bit.half_word_type  d, [a], b{, cache-policy};
+//! atom{.sem}{.scope}{.global}.op.noftz{.level::cache_hint}.vec_32_bit.packed_type     d, [a], b{, cache-policy};
+//! .sem =               { .relaxed, .acquire, .release, .acq_rel };
+//! .scope =             { .cta, .cluster, .gpu, .sys };
+//! .op =                { .add, .min, .max };
+//! .half_word_type =    { .f16, .bf16 };
+//! .packed_type =       { .f16x2, .bf16x2 };
+//! .vec_16_bit =        { .v2, .v4, .v8 };
+//! .vec_32_bit =        { .v2, .v4 };
+//! .level::cache_hint = { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::atom::section_0::*;
+
+    impl Operands for AtomSemScopeSpaceOpLevelCacheHintType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for AtomSemScopeSpaceOpType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+
+    impl Operands for AtomSemScopeSpaceCasB16 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+
+    impl Operands for AtomSemScopeSpaceCasB128 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+
+    impl Operands for AtomSemScopeSpaceExchLevelCacheHintB128 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for AtomSemScopeSpaceAddNoftzLevelCacheHintF16 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for AtomSemScopeSpaceAddNoftzLevelCacheHintBf16 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::atom::section_1::*;
+
+    impl Operands for AtomSemScopeGlobalAddLevelCacheHintVec32BitF32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
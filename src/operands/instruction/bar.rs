@@ -0,0 +1,164 @@
+//! Original PTX specification:
+//!
+//! barrier{.cta}.sync{.aligned}      a{, b};
+//! barrier{.cta}.arrive{.aligned}    a, b;
+//! barrier{.cta}.red.popc{.aligned}.u32  d, a{, b}, {!}c;
+//! barrier{.cta}.red.op{.aligned}.pred   p, a{, b}, {!}c;
+//! bar{.cta}.sync      a{, b};
+//! bar{.cta}.arrive    a, b;
+//! bar{.cta}.red.popc.u32  d, a{, b}, {!}c;
+//! bar{.cta}.red.op.pred   p, a{, b}, {!}c;
+//! .op = { .and, .or };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bar::section_0::*;
+
+    impl Operands for BarrierCtaSyncAligned {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            if let Some(operand) = &self.b {
+                f(OperandRef::general("b", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            if let Some(operand) = &mut self.b {
+                f(OperandMut::general("b", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for BarrierCtaArriveAligned {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+
+    impl Operands for BarrierCtaRedPopcAlignedU32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            if let Some(operand) = &self.b {
+                f(OperandRef::general("b", OperandRole::Source, operand));
+            }
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            if let Some(operand) = &mut self.b {
+                f(OperandMut::general("b", OperandRole::Source, operand));
+            }
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+
+    impl Operands for BarrierCtaRedOpAlignedPred {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("p", OperandRole::Destination, &self.p));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            if let Some(operand) = &self.b {
+                f(OperandRef::general("b", OperandRole::Source, operand));
+            }
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "p",
+                OperandRole::Destination,
+                &mut self.p,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            if let Some(operand) = &mut self.b {
+                f(OperandMut::general("b", OperandRole::Source, operand));
+            }
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+
+    impl Operands for BarCtaSync {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            if let Some(operand) = &self.b {
+                f(OperandRef::general("b", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            if let Some(operand) = &mut self.b {
+                f(OperandMut::general("b", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for BarCtaArrive {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+
+    impl Operands for BarCtaRedPopcU32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            if let Some(operand) = &self.b {
+                f(OperandRef::general("b", OperandRole::Source, operand));
+            }
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            if let Some(operand) = &mut self.b {
+                f(OperandMut::general("b", OperandRole::Source, operand));
+            }
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+
+    impl Operands for BarCtaRedOpPred {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("p", OperandRole::Destination, &self.p));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            if let Some(operand) = &self.b {
+                f(OperandRef::general("b", OperandRole::Source, operand));
+            }
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "p",
+                OperandRole::Destination,
+                &mut self.p,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            if let Some(operand) = &mut self.b {
+                f(OperandMut::general("b", OperandRole::Source, operand));
+            }
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+}
@@ -0,0 +1,29 @@
+//! Original PTX specification:
+//!
+//! bar.warp.sync      membermask;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bar_warp_sync::section_0::*;
+
+    impl Operands for BarWarpSync {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "membermask",
+                OperandRole::Source,
+                &self.membermask,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "membermask",
+                OperandRole::Source,
+                &mut self.membermask,
+            ));
+        }
+    }
+}
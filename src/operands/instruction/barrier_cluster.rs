@@ -0,0 +1,24 @@
+//! Original PTX specification:
+//!
+//! barrier.cluster.arrive{.sem}{.aligned};
+//! barrier.cluster.wait{.acquire}{.aligned};
+//! .sem = {.release, .relaxed};
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::barrier_cluster::section_0::*;
+
+    impl Operands for BarrierClusterArriveSemAligned {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+
+    impl Operands for BarrierClusterWaitAcquireAligned {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+}
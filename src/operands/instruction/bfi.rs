@@ -0,0 +1,34 @@
+//! Original PTX specification:
+//!
+//! bfi.type  f, a, b, c, d;
+//! .type = { .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bfi::section_0::*;
+
+    impl Operands for BfiType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("f", OperandRole::Destination, &self.f));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("d", OperandRole::Source, &self.d));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "f",
+                OperandRole::Destination,
+                &mut self.f,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("d", OperandRole::Source, &mut self.d));
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Original PTX specification:
+//!
+//! bra{.uni}  tgt;           // tgt is a label
+//! bra{.uni}  tgt;           // unconditional branch
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bra::section_0::*;
+
+    impl Operands for BraUni {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("tgt", OperandRole::Source, &self.tgt));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "tgt",
+                OperandRole::Source,
+                &mut self.tgt,
+            ));
+        }
+    }
+
+    impl Operands for BraUni1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("tgt", OperandRole::Source, &self.tgt));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "tgt",
+                OperandRole::Source,
+                &mut self.tgt,
+            ));
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Original PTX specification:
+//!
+//! brx.idx{.uni} index, tlist;
+//! brx.idx{.uni} index, tlist;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::brx_idx::section_0::*;
+
+    impl Operands for BrxIdxUni {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "index",
+                OperandRole::Source,
+                &self.index,
+            ));
+            f(OperandRef::general(
+                "tlist",
+                OperandRole::Source,
+                &self.tlist,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "index",
+                OperandRole::Source,
+                &mut self.index,
+            ));
+            f(OperandMut::general(
+                "tlist",
+                OperandRole::Source,
+                &mut self.tlist,
+            ));
+        }
+    }
+
+    impl Operands for BrxIdxUni1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "index",
+                OperandRole::Source,
+                &self.index,
+            ));
+            f(OperandRef::general(
+                "tlist",
+                OperandRole::Source,
+                &self.tlist,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "index",
+                OperandRole::Source,
+                &mut self.index,
+            ));
+            f(OperandMut::general(
+                "tlist",
+                OperandRole::Source,
+                &mut self.tlist,
+            ));
+        }
+    }
+}
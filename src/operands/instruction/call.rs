@@ -0,0 +1,314 @@
+//! Original PTX specification:
+//!
+//! // direct call to named function, func is a symbol
+//! call{.uni} (ret-param), func, (param-list);
+//! call{.uni} func, (param-list);
+//! call{.uni} func;
+//! // indirect call via pointer, with full list of call targets
+//! call{.uni} (ret-param), fptr, (param-list), flist;
+//! call{.uni} fptr, (param-list), flist;
+//! call{.uni} fptr, flist;
+//! // indirect call via pointer, with no knowledge of call targets
+//! call{.uni} (ret-param), fptr, (param-list), fproto;
+//! call{.uni} fptr, (param-list), fproto;
+//! call{.uni} fptr, fproto;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::call::section_0::*;
+
+    impl Operands for CallUni {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "ret-param",
+                OperandRole::Destination,
+                &self.ret_param,
+            ));
+            f(OperandRef::general("func", OperandRole::Source, &self.func));
+            for operand in &self.param_list {
+                f(OperandRef::general(
+                    "param_list",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "ret-param",
+                OperandRole::Destination,
+                &mut self.ret_param,
+            ));
+            f(OperandMut::general(
+                "func",
+                OperandRole::Source,
+                &mut self.func,
+            ));
+            for operand in &mut self.param_list {
+                f(OperandMut::general(
+                    "param_list",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for CallUni1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("func", OperandRole::Source, &self.func));
+            for operand in &self.param_list {
+                f(OperandRef::general(
+                    "param_list",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "func",
+                OperandRole::Source,
+                &mut self.func,
+            ));
+            for operand in &mut self.param_list {
+                f(OperandMut::general(
+                    "param_list",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for CallUni2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("func", OperandRole::Source, &self.func));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "func",
+                OperandRole::Source,
+                &mut self.func,
+            ));
+        }
+    }
+
+    impl Operands for CallUni3 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "ret-param",
+                OperandRole::Destination,
+                &self.ret_param,
+            ));
+            f(OperandRef::general("fptr", OperandRole::Source, &self.fptr));
+            for operand in &self.param_list {
+                f(OperandRef::general(
+                    "param_list",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "flist",
+                OperandRole::Source,
+                &self.flist,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "ret-param",
+                OperandRole::Destination,
+                &mut self.ret_param,
+            ));
+            f(OperandMut::general(
+                "fptr",
+                OperandRole::Source,
+                &mut self.fptr,
+            ));
+            for operand in &mut self.param_list {
+                f(OperandMut::general(
+                    "param_list",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "flist",
+                OperandRole::Source,
+                &mut self.flist,
+            ));
+        }
+    }
+
+    impl Operands for CallUni4 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("fptr", OperandRole::Source, &self.fptr));
+            for operand in &self.param_list {
+                f(OperandRef::general(
+                    "param_list",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "flist",
+                OperandRole::Source,
+                &self.flist,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "fptr",
+                OperandRole::Source,
+                &mut self.fptr,
+            ));
+            for operand in &mut self.param_list {
+                f(OperandMut::general(
+                    "param_list",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "flist",
+                OperandRole::Source,
+                &mut self.flist,
+            ));
+        }
+    }
+
+    impl Operands for CallUni5 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("fptr", OperandRole::Source, &self.fptr));
+            f(OperandRef::general(
+                "flist",
+                OperandRole::Source,
+                &self.flist,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "fptr",
+                OperandRole::Source,
+                &mut self.fptr,
+            ));
+            f(OperandMut::general(
+                "flist",
+                OperandRole::Source,
+                &mut self.flist,
+            ));
+        }
+    }
+
+    impl Operands for CallUni6 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "ret-param",
+                OperandRole::Destination,
+                &self.ret_param,
+            ));
+            f(OperandRef::general("fptr", OperandRole::Source, &self.fptr));
+            for operand in &self.param_list {
+                f(OperandRef::general(
+                    "param_list",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "fproto",
+                OperandRole::Source,
+                &self.fproto,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "ret-param",
+                OperandRole::Destination,
+                &mut self.ret_param,
+            ));
+            f(OperandMut::general(
+                "fptr",
+                OperandRole::Source,
+                &mut self.fptr,
+            ));
+            for operand in &mut self.param_list {
+                f(OperandMut::general(
+                    "param_list",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "fproto",
+                OperandRole::Source,
+                &mut self.fproto,
+            ));
+        }
+    }
+
+    impl Operands for CallUni7 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("fptr", OperandRole::Source, &self.fptr));
+            for operand in &self.param_list {
+                f(OperandRef::general(
+                    "param_list",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "fproto",
+                OperandRole::Source,
+                &self.fproto,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "fptr",
+                OperandRole::Source,
+                &mut self.fptr,
+            ));
+            for operand in &mut self.param_list {
+                f(OperandMut::general(
+                    "param_list",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "fproto",
+                OperandRole::Source,
+                &mut self.fproto,
+            ));
+        }
+    }
+
+    impl Operands for CallUni8 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("fptr", OperandRole::Source, &self.fptr));
+            f(OperandRef::general(
+                "fproto",
+                OperandRole::Source,
+                &self.fproto,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "fptr",
+                OperandRole::Source,
+                &mut self.fptr,
+            ));
+            f(OperandMut::general(
+                "fproto",
+                OperandRole::Source,
+                &mut self.fproto,
+            ));
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Original PTX specification:
+//!
+//! clusterlaunchcontrol.query_cancel.is_canceled.pred.b128 pred, try_cancel_response;
+//! clusterlaunchcontrol.query_cancel.get_first_ctaid.v4.b32.b128 {xdim, ydim, zdim, _},  try_cancel_response;
+//! clusterlaunchcontrol.query_cancel{.get_first_ctaid::dimension}.b32.b128 reg, try_cancel_response;
+//! .get_first_ctaid::dimension = { .get_first_ctaid::x, .get_first_ctaid::y, .get_first_ctaid::z };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::clusterlaunchcontrol_query_cancel::section_0::*;
+
+    impl Operands for ClusterlaunchcontrolQueryCancelIsCanceledPredB128 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "pred",
+                OperandRole::Destination,
+                &self.pred2,
+            ));
+            f(OperandRef::general(
+                "try_cancel_response",
+                OperandRole::Source,
+                &self.try_cancel_response,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "pred",
+                OperandRole::Destination,
+                &mut self.pred2,
+            ));
+            f(OperandMut::general(
+                "try_cancel_response",
+                OperandRole::Source,
+                &mut self.try_cancel_response,
+            ));
+        }
+    }
+
+    impl Operands for ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::vector(
+                "xdim",
+                OperandRole::Destination,
+                &self.xdim,
+            ));
+            f(OperandRef::general(
+                "try_cancel_response",
+                OperandRole::Source,
+                &self.try_cancel_response,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::vector(
+                "xdim",
+                OperandRole::Destination,
+                &mut self.xdim,
+            ));
+            f(OperandMut::general(
+                "try_cancel_response",
+                OperandRole::Source,
+                &mut self.try_cancel_response,
+            ));
+        }
+    }
+
+    impl Operands for ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "reg",
+                OperandRole::Destination,
+                &self.reg,
+            ));
+            f(OperandRef::general(
+                "try_cancel_response",
+                OperandRole::Source,
+                &self.try_cancel_response,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "reg",
+                OperandRole::Destination,
+                &mut self.reg,
+            ));
+            f(OperandMut::general(
+                "try_cancel_response",
+                OperandRole::Source,
+                &mut self.try_cancel_response,
+            ));
+        }
+    }
+}
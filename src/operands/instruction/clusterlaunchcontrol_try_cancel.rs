@@ -0,0 +1,43 @@
+//! Original PTX specification:
+//!
+//! clusterlaunchcontrol.try_cancel.async{.space}.completion_mechanism{.multicast::cluster::all}.b128 [addr], [mbar];
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .space = { .shared::cta };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::clusterlaunchcontrol_try_cancel::section_0::*;
+
+    impl Operands
+        for ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128
+    {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Original PTX specification:
+//!
+//! cp.async.ca.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], cp-size{, src-size}{, cache-policy};
+//! cp.async.cg.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], 16{, src-size}{, cache-policy};
+//! cp.async.ca.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], cp-size{, ignore-src}{, cache-policy} ;
+//! cp.async.cg.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], 16{, ignore-src}{, cache-policy} ;
+//! .level::cache_hint =     { .L2::cache_hint };
+//! .level::prefetch_size =  { .L2::64B, .L2::128B, .L2::256B };
+//! cp-size = { 4, 8, 16 };
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async::section_0::*;
+
+    impl Operands for CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("dst", OperandRole::Address, &self.dst));
+            f(OperandRef::address("src", OperandRole::Address, &self.src));
+            if let Some(operand) = &self.src_size {
+                f(OperandRef::general(
+                    "src-size",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dst",
+                OperandRole::Address,
+                &mut self.dst,
+            ));
+            f(OperandMut::address(
+                "src",
+                OperandRole::Address,
+                &mut self.src,
+            ));
+            if let Some(operand) = &mut self.src_size {
+                f(OperandMut::general(
+                    "src-size",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("dst", OperandRole::Address, &self.dst));
+            f(OperandRef::address("src", OperandRole::Address, &self.src));
+            if let Some(operand) = &self.src_size {
+                f(OperandRef::general(
+                    "src-size",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dst",
+                OperandRole::Address,
+                &mut self.dst,
+            ));
+            f(OperandMut::address(
+                "src",
+                OperandRole::Address,
+                &mut self.src,
+            ));
+            if let Some(operand) = &mut self.src_size {
+                f(OperandMut::general(
+                    "src-size",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("dst", OperandRole::Address, &self.dst));
+            f(OperandRef::address("src", OperandRole::Address, &self.src));
+            if let Some(operand) = &self.ignore_src {
+                f(OperandRef::general(
+                    "ignore-src",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dst",
+                OperandRole::Address,
+                &mut self.dst,
+            ));
+            f(OperandMut::address(
+                "src",
+                OperandRole::Address,
+                &mut self.src,
+            ));
+            if let Some(operand) = &mut self.ignore_src {
+                f(OperandMut::general(
+                    "ignore-src",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("dst", OperandRole::Address, &self.dst));
+            f(OperandRef::address("src", OperandRole::Address, &self.src));
+            if let Some(operand) = &self.ignore_src {
+                f(OperandRef::general(
+                    "ignore-src",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dst",
+                OperandRole::Address,
+                &mut self.dst,
+            ));
+            f(OperandMut::address(
+                "src",
+                OperandRole::Address,
+                &mut self.src,
+            ));
+            if let Some(operand) = &mut self.ignore_src {
+                f(OperandMut::general(
+                    "ignore-src",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,277 @@
+//! Original PTX specification:
+//!
+//! // global -> shared::cta
+//! cp.async.bulk.dst.src.completion_mechanism{.level::cache_hint} [dstMem], [srcMem], size, [mbar] {, cache-policy};
+//! .dst =                  { .shared::cta };
+//! .src =                  { .global };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .level::cache_hint =    { .L2::cache_hint };
+//! ----------------------------------------------------------------
+//! // global -> shared::cluster;
+//! cp.async.bulk.dst.src.completion_mechanism{.multicast}{.level::cache_hint} [dstMem], [srcMem], size, [mbar] {, ctaMask} {, cache-policy};
+//! .dst =                  { .shared::cluster };
+//! .src =                  { .global };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .level::cache_hint =    { .L2::cache_hint };
+//! .multicast =            { .multicast::cluster  };
+//! ----------------------------------------------------------------
+//! // shared::cta -> shared::cluster
+//! cp.async.bulk.dst.src.completion_mechanism [dstMem], [srcMem], size, [mbar];
+//! .dst =                  { .shared::cluster };
+//! .src =                  { .shared::cta };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ----------------------------------------------------------------
+//! // shared::cta -> global
+//! cp.async.bulk.dst.src.completion_mechanism{.level::cache_hint}{.cp_mask} [dstMem], [srcMem], size {, cache-policy} {, byteMask};
+//! .dst =                  { .global };
+//! .src =                  { .shared::cta };
+//! .completion_mechanism = { .bulk_group };
+//! .level::cache_hint =    { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk::section_0::*;
+
+    impl Operands for CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "dstMem",
+                OperandRole::Address,
+                &self.dstmem,
+            ));
+            f(OperandRef::address(
+                "srcMem",
+                OperandRole::Address,
+                &self.srcmem,
+            ));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dstMem",
+                OperandRole::Address,
+                &mut self.dstmem,
+            ));
+            f(OperandMut::address(
+                "srcMem",
+                OperandRole::Address,
+                &mut self.srcmem,
+            ));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk::section_1::*;
+
+    impl Operands for CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "dstMem",
+                OperandRole::Address,
+                &self.dstmem,
+            ));
+            f(OperandRef::address(
+                "srcMem",
+                OperandRole::Address,
+                &self.srcmem,
+            ));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+            if let Some(operand) = &self.ctamask {
+                f(OperandRef::general("ctaMask", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dstMem",
+                OperandRole::Address,
+                &mut self.dstmem,
+            ));
+            f(OperandMut::address(
+                "srcMem",
+                OperandRole::Address,
+                &mut self.srcmem,
+            ));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+            if let Some(operand) = &mut self.ctamask {
+                f(OperandMut::general("ctaMask", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk::section_2::*;
+
+    impl Operands for CpAsyncBulkDstSrcCompletionMechanism {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "dstMem",
+                OperandRole::Address,
+                &self.dstmem,
+            ));
+            f(OperandRef::address(
+                "srcMem",
+                OperandRole::Address,
+                &self.srcmem,
+            ));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dstMem",
+                OperandRole::Address,
+                &mut self.dstmem,
+            ));
+            f(OperandMut::address(
+                "srcMem",
+                OperandRole::Address,
+                &mut self.srcmem,
+            ));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk::section_3::*;
+
+    impl Operands for CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "dstMem",
+                OperandRole::Address,
+                &self.dstmem,
+            ));
+            f(OperandRef::address(
+                "srcMem",
+                OperandRole::Address,
+                &self.srcmem,
+            ));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &self.bytemask {
+                f(OperandRef::general(
+                    "byteMask",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dstMem",
+                OperandRole::Address,
+                &mut self.dstmem,
+            ));
+            f(OperandMut::address(
+                "srcMem",
+                OperandRole::Address,
+                &mut self.srcmem,
+            ));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &mut self.bytemask {
+                f(OperandMut::general(
+                    "byteMask",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+//! Original PTX specification:
+//!
+//! cp.async.bulk.prefetch.L2.src{.level::cache_hint}   [srcMem], size {, cache-policy};
+//! .src =                { .global };
+//! .level::cache_hint =  { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_prefetch::section_0::*;
+
+    impl Operands for CpAsyncBulkPrefetchL2SrcLevelCacheHint {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "srcMem",
+                OperandRole::Address,
+                &self.srcmem,
+            ));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "srcMem",
+                OperandRole::Address,
+                &mut self.srcmem,
+            ));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
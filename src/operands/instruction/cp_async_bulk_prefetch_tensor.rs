@@ -0,0 +1,66 @@
+//! Original PTX specification:
+//!
+//! // global -> shared::cluster:
+//! cp.async.bulk.prefetch.tensor.dim.L2.src{.load_mode}{.level::cache_hint} [tensorMap, tensorCoords] {, im2colInfo } {, cache-policy};
+//! .src =                { .global };
+//! .dim =                { .1d, .2d, .3d, .4d, .5d };
+//! .load_mode =          { .tile, .tile::gather4, .im2col, .im2col::w, .im2col::w::128 };
+//! .level::cache_hint =  { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_prefetch_tensor::section_0::*;
+
+    impl Operands for CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            let [first, second] = &self.tensormap.operands;
+            f(OperandRef::general("tensorMap", OperandRole::Source, first));
+            f(OperandRef::general(
+                "tensorCoords",
+                OperandRole::Source,
+                second,
+            ));
+            if let Some(operand) = &self.im2colinfo {
+                f(OperandRef::general(
+                    "im2colInfo",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            let [first, second] = &mut self.tensormap.operands;
+            f(OperandMut::general("tensorMap", OperandRole::Source, first));
+            f(OperandMut::general(
+                "tensorCoords",
+                OperandRole::Source,
+                second,
+            ));
+            if let Some(operand) = &mut self.im2colinfo {
+                f(OperandMut::general(
+                    "im2colInfo",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! Original PTX specification:
+//!
+//! // global -> shared::cta
+//! cp.async.bulk.tensor.dim.dst.src{.load_mode}.completion_mechanism{.cta_group}{.level::cache_hint} [dstMem], [tensorMap, tensorCoords], [mbar]{, im2colInfo} {, cache-policy};
+//! .dst =                  { .shared::cta };
+//! .src =                  { .global };
+//! .dim =                  { .1d, .2d, .3d, .4d, .5d };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .cta_group =            { .cta_group::1, .cta_group::2 };
+//! .load_mode =            { .tile, .tile::gather4, .im2col, .im2col::w, .im2col::w::128 };
+//! .level::cache_hint =    { .L2::cache_hint };
+//! ----------------------------------------------------------------
+//! // global -> shared::cluster
+//! cp.async.bulk.tensor.dim.dst.src{.load_mode}.completion_mechanism{.multicast}{.cta_group}{.level::cache_hint} [dstMem], [tensorMap, tensorCoords], [mbar]{, im2colInfo} {, ctaMask} {, cache-policy};
+//! .dst =                  { .shared::cluster };
+//! .src =                  { .global };
+//! .dim =                  { .1d, .2d, .3d, .4d, .5d };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .cta_group =            { .cta_group::1, .cta_group::2 };
+//! .load_mode =            { .tile, .tile::gather4, .im2col, .im2col::w, .im2col::w::128 };
+//! .level::cache_hint =    { .L2::cache_hint };
+//! .multicast =            { .multicast::cluster  };
+//! ----------------------------------------------------------------
+//! // shared::cta -> global;
+//! cp.async.bulk.tensor.dim.dst.src{.load_mode}.completion_mechanism{.level::cache_hint} [tensorMap, tensorCoords], [srcMem] {, cache-policy};
+//! .dst =                  { .global };
+//! .src =                  { .shared::cta };
+//! .dim =                  { .1d, .2d, .3d, .4d, .5d };
+//! .completion_mechanism = { .bulk_group };
+//! .load_mode =            { .tile, .tile::scatter4, .im2col_no_offs };
+//! .level::cache_hint =    { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_tensor::section_0::*;
+
+    impl Operands for CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "dstMem",
+                OperandRole::Address,
+                &self.dstmem,
+            ));
+            let [first, second] = &self.tensormap.operands;
+            f(OperandRef::general("tensorMap", OperandRole::Source, first));
+            f(OperandRef::general(
+                "tensorCoords",
+                OperandRole::Source,
+                second,
+            ));
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+            if let Some(operand) = &self.im2colinfo {
+                f(OperandRef::general(
+                    "im2colInfo",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dstMem",
+                OperandRole::Address,
+                &mut self.dstmem,
+            ));
+            let [first, second] = &mut self.tensormap.operands;
+            f(OperandMut::general("tensorMap", OperandRole::Source, first));
+            f(OperandMut::general(
+                "tensorCoords",
+                OperandRole::Source,
+                second,
+            ));
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+            if let Some(operand) = &mut self.im2colinfo {
+                f(OperandMut::general(
+                    "im2colInfo",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_tensor::section_1::*;
+
+    impl Operands
+        for CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint
+    {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "dstMem",
+                OperandRole::Address,
+                &self.dstmem,
+            ));
+            let [first, second] = &self.tensormap.operands;
+            f(OperandRef::general("tensorMap", OperandRole::Source, first));
+            f(OperandRef::general(
+                "tensorCoords",
+                OperandRole::Source,
+                second,
+            ));
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+            if let Some(operand) = &self.im2colinfo {
+                f(OperandRef::general(
+                    "im2colInfo",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &self.ctamask {
+                f(OperandRef::general("ctaMask", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dstMem",
+                OperandRole::Address,
+                &mut self.dstmem,
+            ));
+            let [first, second] = &mut self.tensormap.operands;
+            f(OperandMut::general("tensorMap", OperandRole::Source, first));
+            f(OperandMut::general(
+                "tensorCoords",
+                OperandRole::Source,
+                second,
+            ));
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+            if let Some(operand) = &mut self.im2colinfo {
+                f(OperandMut::general(
+                    "im2colInfo",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            if let Some(operand) = &mut self.ctamask {
+                f(OperandMut::general("ctaMask", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_tensor::section_2::*;
+
+    impl Operands for CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            let [first, second] = &self.tensormap.operands;
+            f(OperandRef::general("tensorMap", OperandRole::Source, first));
+            f(OperandRef::general(
+                "tensorCoords",
+                OperandRole::Source,
+                second,
+            ));
+            f(OperandRef::address(
+                "srcMem",
+                OperandRole::Address,
+                &self.srcmem,
+            ));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            let [first, second] = &mut self.tensormap.operands;
+            f(OperandMut::general("tensorMap", OperandRole::Source, first));
+            f(OperandMut::general(
+                "tensorCoords",
+                OperandRole::Source,
+                second,
+            ));
+            f(OperandMut::address(
+                "srcMem",
+                OperandRole::Address,
+                &mut self.srcmem,
+            ));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Original PTX specification:
+//!
+//! cp.async.commit_group ;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_commit_group::section_0::*;
+
+    impl Operands for CpAsyncCommitGroup {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+}
@@ -0,0 +1,30 @@
+//! Original PTX specification:
+//!
+//! cp.async.mbarrier.arrive{.noinc}{.state}.b64 [addr];
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_mbarrier_arrive::section_0::*;
+
+    impl Operands for CpAsyncMbarrierArriveNoincStateB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+        }
+    }
+}
@@ -0,0 +1,27 @@
+//! Original PTX specification:
+//!
+//! cp.async.wait_group N;
+//! cp.async.wait_all ;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_wait_group::section_0::*;
+
+    impl Operands for CpAsyncWaitGroup {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("N", OperandRole::Source, &self.n));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general("N", OperandRole::Source, &mut self.n));
+        }
+    }
+
+    impl Operands for CpAsyncWaitAll {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+}
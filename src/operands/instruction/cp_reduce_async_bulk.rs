@@ -0,0 +1,179 @@
+//! Original PTX specification:
+//!
+//! cp.reduce.async.bulk.dst.src.completion_mechanism.redOp.type [dstMem], [srcMem], size, [mbar];
+//! .dst =                  { .shared::cluster };
+//! .src =                  { .shared::cta };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .redOp=                 { .and, .or, .xor, .add, .inc, .dec, .min, .max };
+//! .type =                 { .b32, .u32, .s32, .b64, .u64 };
+//! ----------------------------------------------------------------
+//! cp.reduce.async.bulk.dst.src.completion_mechanism{.level::cache_hint}.redOp.type [dstMem], [srcMem], size{, cache-policy};
+//! .dst =                  { .global      };
+//! .src =                  { .shared::cta };
+//! ----------------------------------------------------------------
+//! .completion_mechanism = { .bulk_group };
+//! .level::cache_hint    = { .L2::cache_hint };
+//! .redOp=                 { .and, .or, .xor, .add, .inc, .dec, .min, .max };
+//! .type =                 { .f16, .bf16, .b32, .u32, .s32, .b64, .u64, .s64, .f32, .f64 };
+//! ----------------------------------------------------------------
+//! cp.reduce.async.bulk.dst.src.completion_mechanism{.level::cache_hint}.add.noftz.type [dstMem], [srcMem], size{, cache-policy};
+//! .dst  =                 { .global };
+//! .src  =                 { .shared::cta };
+//! .completion_mechanism = { .bulk_group };
+//! .type =                 { .f16, .bf16 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_reduce_async_bulk::section_0::*;
+
+    impl Operands for CpReduceAsyncBulkDstSrcCompletionMechanismRedopType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "dstMem",
+                OperandRole::Address,
+                &self.dstmem,
+            ));
+            f(OperandRef::address(
+                "srcMem",
+                OperandRole::Address,
+                &self.srcmem,
+            ));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dstMem",
+                OperandRole::Address,
+                &mut self.dstmem,
+            ));
+            f(OperandMut::address(
+                "srcMem",
+                OperandRole::Address,
+                &mut self.srcmem,
+            ));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::cp_reduce_async_bulk::section_1::*;
+
+    impl Operands for CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "dstMem",
+                OperandRole::Address,
+                &self.dstmem,
+            ));
+            f(OperandRef::address(
+                "srcMem",
+                OperandRole::Address,
+                &self.srcmem,
+            ));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dstMem",
+                OperandRole::Address,
+                &mut self.dstmem,
+            ));
+            f(OperandMut::address(
+                "srcMem",
+                OperandRole::Address,
+                &mut self.srcmem,
+            ));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::cp_reduce_async_bulk::section_2::*;
+
+    impl Operands for CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "dstMem",
+                OperandRole::Address,
+                &self.dstmem,
+            ));
+            f(OperandRef::address(
+                "srcMem",
+                OperandRole::Address,
+                &self.srcmem,
+            ));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dstMem",
+                OperandRole::Address,
+                &mut self.dstmem,
+            ));
+            f(OperandMut::address(
+                "srcMem",
+                OperandRole::Address,
+                &mut self.srcmem,
+            ));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
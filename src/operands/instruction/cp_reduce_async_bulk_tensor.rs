@@ -0,0 +1,64 @@
+//! Original PTX specification:
+//!
+//! // shared::cta -> global
+//! cp.reduce.async.bulk.tensor.dim.dst.src.redOp{.load_mode}.completion_mechanism{.level::cache_hint} [tensorMap, tensorCoords], [srcMem] {,cache-policy};
+//! .dst =                  { .global };
+//! .src =                  { .shared::cta };
+//! .dim =                  { .1d, .2d, .3d, .4d, .5d };
+//! .completion_mechanism = { .bulk_group };
+//! .load_mode =            { .tile, .im2col_no_offs };
+//! .redOp =                { .add, .min, .max, .inc, .dec, .and, .or, .xor};
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_reduce_async_bulk_tensor::section_0::*;
+
+    impl Operands for CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            let [first, second] = &self.tensormap.operands;
+            f(OperandRef::general("tensorMap", OperandRole::Source, first));
+            f(OperandRef::general(
+                "tensorCoords",
+                OperandRole::Source,
+                second,
+            ));
+            f(OperandRef::address(
+                "srcMem",
+                OperandRole::Address,
+                &self.srcmem,
+            ));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            let [first, second] = &mut self.tensormap.operands;
+            f(OperandMut::general("tensorMap", OperandRole::Source, first));
+            f(OperandMut::general(
+                "tensorCoords",
+                OperandRole::Source,
+                second,
+            ));
+            f(OperandMut::address(
+                "srcMem",
+                OperandRole::Address,
+                &mut self.srcmem,
+            ));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
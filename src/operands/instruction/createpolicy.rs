@@ -0,0 +1,119 @@
+//! Original PTX specification:
+//!
+//! // Range-based policy
+//! createpolicy.range{.global}.level::primary_priority{.level::secondary_priority}.b64
+//! cache-policy, [a], primary-size, total-size;
+//! // Fraction-based policy
+//! createpolicy.fractional.level::primary_priority{.level::secondary_priority}.b64
+//! cache-policy{, fraction};
+//! // Converting the access property from CUDA APIs
+//! createpolicy.cvt.L2.b64            cache-policy, access-property;
+//! .level::primary_priority =   { .L2::evict_last, .L2::evict_normal,
+//! .L2::evict_first, .L2::evict_unchanged };
+//! .level::secondary_priority = { .L2::evict_first, .L2::evict_unchanged };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::createpolicy::section_0::*;
+
+    impl Operands for CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "cache-policy",
+                OperandRole::Destination,
+                &self.cache_policy,
+            ));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general(
+                "primary-size",
+                OperandRole::Source,
+                &self.primary_size,
+            ));
+            f(OperandRef::general(
+                "total-size",
+                OperandRole::Source,
+                &self.total_size,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "cache-policy",
+                OperandRole::Destination,
+                &mut self.cache_policy,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general(
+                "primary-size",
+                OperandRole::Source,
+                &mut self.primary_size,
+            ));
+            f(OperandMut::general(
+                "total-size",
+                OperandRole::Source,
+                &mut self.total_size,
+            ));
+        }
+    }
+
+    impl Operands for CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "cache-policy",
+                OperandRole::Destination,
+                &self.cache_policy,
+            ));
+            if let Some(operand) = &self.fraction {
+                f(OperandRef::general(
+                    "fraction",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "cache-policy",
+                OperandRole::Destination,
+                &mut self.cache_policy,
+            ));
+            if let Some(operand) = &mut self.fraction {
+                f(OperandMut::general(
+                    "fraction",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for CreatepolicyCvtL2B64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "cache-policy",
+                OperandRole::Destination,
+                &self.cache_policy,
+            ));
+            f(OperandRef::general(
+                "access-property",
+                OperandRole::Source,
+                &self.access_property,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "cache-policy",
+                OperandRole::Destination,
+                &mut self.cache_policy,
+            ));
+            f(OperandMut::general(
+                "access-property",
+                OperandRole::Source,
+                &mut self.access_property,
+            ));
+        }
+    }
+}
@@ -0,0 +1,60 @@
+//! Original PTX specification:
+//!
+//! cvt.pack.sat.convertType.abType  d, a, b;
+//! .convertType  = { .u16, .s16 };
+//! .abType       = { .s32 };
+//! ----------------------------------------------------------------
+//! cvt.pack.sat.convertType.abType.cType  d, a, b, c;
+//! .convertType  = { .u2, .s2, .u4, .s4, .u8, .s8 };
+//! .abType       = { .s32 };
+//! .cType        = { .b32 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cvt_pack::section_0::*;
+
+    impl Operands for CvtPackSatConverttypeAbtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::cvt_pack::section_1::*;
+
+    impl Operands for CvtPackSatConverttypeAbtypeCtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+}
@@ -0,0 +1,49 @@
+//! Original PTX specification:
+//!
+//! // convert const, global, local, or shared address to generic address
+//! cvta.space.size  p, a;        // source address in register a
+//! // cvta.space.size  p, var;      // get generic address of var
+//! // cvta.space.size  p, var+imm;  // generic address of var+offset
+//! // convert generic address to const, global, local, or shared address
+//! cvta.to.space.size  p, a;
+//! .space = { .const, .global, .local, .shared, .shared::cta, .shared::cluster, .param, .param::entry };
+//! .size  = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cvta::section_0::*;
+
+    impl Operands for CvtaSpaceSize {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("p", OperandRole::Destination, &self.p));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "p",
+                OperandRole::Destination,
+                &mut self.p,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+        }
+    }
+
+    impl Operands for CvtaToSpaceSize {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("p", OperandRole::Destination, &self.p));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "p",
+                OperandRole::Destination,
+                &mut self.p,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Original PTX specification:
+//!
+//! elect.sync d|p, membermask;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::elect_sync::section_0::*;
+
+    impl Operands for ElectSync {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("p", OperandRole::PredicateOut, &self.p));
+            f(OperandRef::general(
+                "membermask",
+                OperandRole::Source,
+                &self.membermask,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general(
+                "p",
+                OperandRole::PredicateOut,
+                &mut self.p,
+            ));
+            f(OperandMut::general(
+                "membermask",
+                OperandRole::Source,
+                &mut self.membermask,
+            ));
+        }
+    }
+}
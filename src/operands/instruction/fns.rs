@@ -0,0 +1,47 @@
+//! Original PTX specification:
+//!
+//! fns.b32 d, mask, base, offset;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::fns::section_0::*;
+
+    impl Operands for FnsB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("mask", OperandRole::Source, &self.mask));
+            f(OperandRef::general("base", OperandRole::Source, &self.base));
+            f(OperandRef::general(
+                "offset",
+                OperandRole::Source,
+                &self.offset,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general(
+                "mask",
+                OperandRole::Source,
+                &mut self.mask,
+            ));
+            f(OperandMut::general(
+                "base",
+                OperandRole::Source,
+                &mut self.base,
+            ));
+            f(OperandMut::general(
+                "offset",
+                OperandRole::Source,
+                &mut self.offset,
+            ));
+        }
+    }
+}
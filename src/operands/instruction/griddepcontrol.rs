@@ -0,0 +1,18 @@
+//! Original PTX specification:
+//!
+//! griddepcontrol.action;
+//! .action   = { .launch_dependents, .wait };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::griddepcontrol::section_0::*;
+
+    impl Operands for GriddepcontrolAction {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+}
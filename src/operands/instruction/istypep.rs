@@ -0,0 +1,28 @@
+//! Original PTX specification:
+//!
+//! istypep.type   p, a;  // result is .pred
+//! .type = { .texref, .samplerref, .surfref };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::istypep::section_0::*;
+
+    impl Operands for IstypepType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("p", OperandRole::Destination, &self.p));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "p",
+                OperandRole::Destination,
+                &mut self.p,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+        }
+    }
+}
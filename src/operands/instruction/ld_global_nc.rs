@@ -0,0 +1,109 @@
+//! Original PTX specification:
+//!
+//! ld.global{.cop}.nc{.level::cache_hint}{.level::prefetch_size}.type                 d, [a]{, cache-policy};
+//! ld.global{.cop}.nc{.level::cache_hint}{.level::prefetch_size}.vec.type             d, [a]{, cache-policy};
+//! ld.global.nc{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.level::prefetch_size}.type      d, [a]{, cache-policy};
+//! ld.global.nc{.level1::eviction_priority}{.level2::eviction_priority}{.level::cache_hint}{.level::prefetch_size}.vec.type  d, [a]{, cache-policy};
+//! .cop  =                     { .ca, .cg, .cs };     // cache operation
+//! .level1::eviction_priority = { .L1::evict_normal, .L1::evict_unchanged, .L1::evict_first, .L1::evict_last, .L1::no_allocate};
+//! .level2::eviction_priority = {.L2::evict_normal, .L2::evict_first, .L2::evict_last};
+//! .level::cache_hint =        { .L2::cache_hint };
+//! .level::prefetch_size =     { .L2::64B, .L2::128B, .L2::256B };
+//! .vec  =                     { .v2, .v4, .v8 };
+//! .type =                     { .b8, .b16, .b32, .b64, .b128,
+//! .u8, .u16, .u32, .u64,
+//! .s8, .s16, .s32, .s64,
+//! .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::ld_global_nc::section_0::*;
+
+    impl Operands for LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            if let Some(operand) = &self.cache_policy { f(OperandRef::general("cache-policy", OperandRole::Source, operand)); }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general("d", OperandRole::Destination, &mut self.d));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            if let Some(operand) = &mut self.cache_policy { f(OperandMut::general("cache-policy", OperandRole::Source, operand)); }
+        }
+    }
+
+    impl Operands for LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            if let Some(operand) = &self.cache_policy { f(OperandRef::general("cache-policy", OperandRole::Source, operand)); }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general("d", OperandRole::Destination, &mut self.d));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            if let Some(operand) = &mut self.cache_policy { f(OperandMut::general("cache-policy", OperandRole::Source, operand)); }
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Original PTX specification:
+//!
+//! ldmatrix.sync.aligned.shape.num{.trans}{.ss}.type r, [p];
+//! ldmatrix.sync.aligned.m8n16.num{.ss}.dst_fmt.src_fmt        r, [p];
+//! ldmatrix.sync.aligned.m16n16.num.trans{.ss}.dst_fmt.src_fmt r, [p];
+//! .shape   = {.m8n8, .m16n16};
+//! .num     = {.x1, .x2, .x4};
+//! .ss      = {.shared, .shared::cta};
+//! .type    = {.b16, .b8};
+//! .dst_fmt = { .b8x16 };
+//! .src_fmt = { .b6x16_p32, .b4x16_p64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::ldmatrix::section_0::*;
+
+    impl Operands for LdmatrixSyncAlignedShapeNumTransSsType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+        }
+    }
+
+    impl Operands for LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+        }
+    }
+
+    impl Operands for LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+        }
+    }
+}
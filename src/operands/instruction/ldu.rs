@@ -0,0 +1,49 @@
+//! Original PTX specification:
+//!
+//! ldu{.ss}.type      d, [a];       // load from address
+//! ldu{.ss}.vec.type  d, [a];       // vec load from address
+//! .ss   = { .global };             // state space
+//! .vec  = { .v2, .v4 };
+//! .type = { .b8, .b16, .b32, .b64, .b128,
+//! .u8, .u16, .u32, .u64,
+//! .s8, .s16, .s32, .s64,
+//! .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::ldu::section_0::*;
+
+    impl Operands for LduSsType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+        }
+    }
+
+    impl Operands for LduSsVecType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+//! Original PTX specification:
+//!
+//! lop3.b32 d, a, b, c, immLut;
+//! lop3.BoolOp.b32 d|p, a, b, c, immLut, q;
+//! .BoolOp   = { .or , .and };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::lop3::section_0::*;
+
+    impl Operands for Lop3B32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general(
+                "immLut",
+                OperandRole::Source,
+                &self.immlut,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general(
+                "immLut",
+                OperandRole::Source,
+                &mut self.immlut,
+            ));
+        }
+    }
+
+    impl Operands for Lop3BoolopB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("p", OperandRole::PredicateOut, &self.p));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general(
+                "immLut",
+                OperandRole::Source,
+                &self.immlut,
+            ));
+            f(OperandRef::general("q", OperandRole::Source, &self.q));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general(
+                "p",
+                OperandRole::PredicateOut,
+                &mut self.p,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general(
+                "immLut",
+                OperandRole::Source,
+                &mut self.immlut,
+            ));
+            f(OperandMut::general("q", OperandRole::Source, &mut self.q));
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Original PTX specification:
+//!
+//! mapa{.space}.type          d, a, b;
+//! // Maps shared memory address in register a into CTA b.
+//! // mapa.shared::cluster.type  d, a, b;
+//! // Maps shared memory variable into CTA b.
+//! // mapa.shared::cluster.type  d, sh, b;
+//! // Maps shared memory variable into CTA b.
+//! // mapa.shared::cluster.type  d, sh + imm, b;
+//! // Maps generic address in register a into CTA b.
+//! // mapa.type                  d, a, b;
+//! .space = { .shared::cluster };
+//! .type  = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mapa::section_0::*;
+
+    impl Operands for MapaSpaceType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+}
@@ -0,0 +1,70 @@
+//! Original PTX specification:
+//!
+//! match.any.sync.type  d, a, membermask;
+//! match.all.sync.type  d{|p}, a, membermask;
+//! .type = { .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::match_sync::section_0::*;
+
+    impl Operands for MatchAnySyncType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general(
+                "membermask",
+                OperandRole::Source,
+                &self.membermask,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general(
+                "membermask",
+                OperandRole::Source,
+                &mut self.membermask,
+            ));
+        }
+    }
+
+    impl Operands for MatchAllSyncType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general(
+                "membermask",
+                OperandRole::Source,
+                &self.membermask,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general(
+                "membermask",
+                OperandRole::Source,
+                &mut self.membermask,
+            ));
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! Original PTX specification:
+//!
+//! mbarrier.arrive{.sem}{.scope}{.state}.b64           state, [addr]{, count};
+//! mbarrier.arrive{.sem}{.scope}{.shared::cluster}.b64         _, [addr] {,count};
+//! mbarrier.arrive.expect_tx{.sem}{.scope}{.state}.b64 state, [addr], txCount;
+//! mbarrier.arrive.expect_tx{.sem}{.scope}{.shared::cluster}.b64   _, [addr], txCount;
+//! mbarrier.arrive.noComplete{.release}{.cta}{.state}.b64  state, [addr], count;
+//! .sem   = { .release, .relaxed };
+//! .scope = { .cta, .cluster };
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_arrive::section_0::*;
+
+    impl Operands for MbarrierArriveSemScopeStateB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "state",
+                OperandRole::Destination,
+                &self.state2,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            if let Some(operand) = &self.count {
+                f(OperandRef::general("count", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "state",
+                OperandRole::Destination,
+                &mut self.state2,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            if let Some(operand) = &mut self.count {
+                f(OperandMut::general("count", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for MbarrierArriveSemScopeSharedClusterB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "_",
+                OperandRole::Destination,
+                &self.operand,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            if let Some(operand) = &self.count {
+                f(OperandRef::general("count", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "_",
+                OperandRole::Destination,
+                &mut self.operand,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            if let Some(operand) = &mut self.count {
+                f(OperandMut::general("count", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for MbarrierArriveExpectTxSemScopeStateB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "state",
+                OperandRole::Destination,
+                &self.state2,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "txCount",
+                OperandRole::Source,
+                &self.txcount,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "state",
+                OperandRole::Destination,
+                &mut self.state2,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "txCount",
+                OperandRole::Source,
+                &mut self.txcount,
+            ));
+        }
+    }
+
+    impl Operands for MbarrierArriveExpectTxSemScopeSharedClusterB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "_",
+                OperandRole::Destination,
+                &self.operand,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "txCount",
+                OperandRole::Source,
+                &self.txcount,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "_",
+                OperandRole::Destination,
+                &mut self.operand,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "txCount",
+                OperandRole::Source,
+                &mut self.txcount,
+            ));
+        }
+    }
+
+    impl Operands for MbarrierArriveNocompleteReleaseCtaStateB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "state",
+                OperandRole::Destination,
+                &self.state2,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "count",
+                OperandRole::Source,
+                &self.count,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "state",
+                OperandRole::Destination,
+                &mut self.state2,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "count",
+                OperandRole::Source,
+                &mut self.count,
+            ));
+        }
+    }
+}
@@ -0,0 +1,196 @@
+//! Original PTX specification:
+//!
+//! mbarrier.arrive_drop{.sem}{.scope}{.state}.b64 state,           [addr]{, count};
+//! mbarrier.arrive_drop{.sem}{.scope}{.shared::cluster}.b64           _,   [addr] {,count};
+//! mbarrier.arrive_drop.expect_tx{.state}{.sem}{.scope}.b64 state, [addr], tx_count;
+//! mbarrier.arrive_drop.expect_tx{.shared::cluster}{.sem}{.scope}.b64   _, [addr], tx_count;
+//! mbarrier.arrive_drop.noComplete{.release}{.cta}{.state}.b64 state,  [addr], count;
+//! .sem   = { .release, .relaxed };
+//! .scope = { .cta, .cluster };
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_arrive_drop::section_0::*;
+
+    impl Operands for MbarrierArriveDropSemScopeStateB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "state",
+                OperandRole::Destination,
+                &self.state2,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            if let Some(operand) = &self.count {
+                f(OperandRef::general("count", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "state",
+                OperandRole::Destination,
+                &mut self.state2,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            if let Some(operand) = &mut self.count {
+                f(OperandMut::general("count", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for MbarrierArriveDropSemScopeSharedClusterB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "_",
+                OperandRole::Destination,
+                &self.operand,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            if let Some(operand) = &self.count {
+                f(OperandRef::general("count", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "_",
+                OperandRole::Destination,
+                &mut self.operand,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            if let Some(operand) = &mut self.count {
+                f(OperandMut::general("count", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for MbarrierArriveDropExpectTxStateSemScopeB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "state",
+                OperandRole::Destination,
+                &self.state2,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "tx_count",
+                OperandRole::Source,
+                &self.tx_count,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "state",
+                OperandRole::Destination,
+                &mut self.state2,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "tx_count",
+                OperandRole::Source,
+                &mut self.tx_count,
+            ));
+        }
+    }
+
+    impl Operands for MbarrierArriveDropExpectTxSharedClusterSemScopeB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "_",
+                OperandRole::Destination,
+                &self.operand,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "tx_count",
+                OperandRole::Source,
+                &self.tx_count,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "_",
+                OperandRole::Destination,
+                &mut self.operand,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "tx_count",
+                OperandRole::Source,
+                &mut self.tx_count,
+            ));
+        }
+    }
+
+    impl Operands for MbarrierArriveDropNocompleteReleaseCtaStateB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "state",
+                OperandRole::Destination,
+                &self.state2,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "count",
+                OperandRole::Source,
+                &self.count,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "state",
+                OperandRole::Destination,
+                &mut self.state2,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "count",
+                OperandRole::Source,
+                &mut self.count,
+            ));
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Original PTX specification:
+//!
+//! mbarrier.complete_tx{.sem}{.scope}{.space}.b64 [addr], txCount;
+//! .sem   = { .relaxed };
+//! .scope = { .cta, .cluster };
+//! .space = { .shared, .shared::cta, .shared::cluster };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_complete_tx::section_0::*;
+
+    impl Operands for MbarrierCompleteTxSemScopeSpaceB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "txCount",
+                OperandRole::Source,
+                &self.txcount,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "txCount",
+                OperandRole::Source,
+                &mut self.txcount,
+            ));
+        }
+    }
+}
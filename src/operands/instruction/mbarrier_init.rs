@@ -0,0 +1,40 @@
+//! Original PTX specification:
+//!
+//! mbarrier.init{.state}.b64 [addr], count;
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_init::section_0::*;
+
+    impl Operands for MbarrierInitStateB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "count",
+                OperandRole::Source,
+                &self.count,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "count",
+                OperandRole::Source,
+                &mut self.count,
+            ));
+        }
+    }
+}
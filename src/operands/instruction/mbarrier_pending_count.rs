@@ -0,0 +1,39 @@
+//! Original PTX specification:
+//!
+//! mbarrier.pending_count.b64 count, state;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_pending_count::section_0::*;
+
+    impl Operands for MbarrierPendingCountB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "count",
+                OperandRole::Destination,
+                &self.count,
+            ));
+            f(OperandRef::general(
+                "state",
+                OperandRole::Source,
+                &self.state,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "count",
+                OperandRole::Destination,
+                &mut self.count,
+            ));
+            f(OperandMut::general(
+                "state",
+                OperandRole::Source,
+                &mut self.state,
+            ));
+        }
+    }
+}
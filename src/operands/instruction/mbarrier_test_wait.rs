@@ -0,0 +1,194 @@
+//! Original PTX specification:
+//!
+//! mbarrier.test_wait{.sem}{.scope}{.state}.b64        waitComplete, [addr], state;
+//! mbarrier.test_wait.parity{.sem}{.scope}{.state}.b64 waitComplete, [addr], phaseParity;
+//! mbarrier.try_wait{.sem}{.scope}{.state}.b64         waitComplete, [addr], state {, suspendTimeHint};
+//! mbarrier.try_wait.parity{.sem}{.scope}{.state}.b64  waitComplete, [addr], phaseParity {, suspendTimeHint};
+//! .sem   = { .acquire, .relaxed };
+//! .scope = { .cta, .cluster };
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_test_wait::section_0::*;
+
+    impl Operands for MbarrierTestWaitSemScopeStateB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "waitComplete",
+                OperandRole::Destination,
+                &self.waitcomplete,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "state",
+                OperandRole::Source,
+                &self.state2,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "waitComplete",
+                OperandRole::Destination,
+                &mut self.waitcomplete,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "state",
+                OperandRole::Source,
+                &mut self.state2,
+            ));
+        }
+    }
+
+    impl Operands for MbarrierTestWaitParitySemScopeStateB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "waitComplete",
+                OperandRole::Destination,
+                &self.waitcomplete,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "phaseParity",
+                OperandRole::Source,
+                &self.phaseparity,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "waitComplete",
+                OperandRole::Destination,
+                &mut self.waitcomplete,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "phaseParity",
+                OperandRole::Source,
+                &mut self.phaseparity,
+            ));
+        }
+    }
+
+    impl Operands for MbarrierTryWaitSemScopeStateB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "waitComplete",
+                OperandRole::Destination,
+                &self.waitcomplete,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "state",
+                OperandRole::Source,
+                &self.state2,
+            ));
+            if let Some(operand) = &self.suspendtimehint {
+                f(OperandRef::general(
+                    "suspendTimeHint",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "waitComplete",
+                OperandRole::Destination,
+                &mut self.waitcomplete,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "state",
+                OperandRole::Source,
+                &mut self.state2,
+            ));
+            if let Some(operand) = &mut self.suspendtimehint {
+                f(OperandMut::general(
+                    "suspendTimeHint",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for MbarrierTryWaitParitySemScopeStateB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "waitComplete",
+                OperandRole::Destination,
+                &self.waitcomplete,
+            ));
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "phaseParity",
+                OperandRole::Source,
+                &self.phaseparity,
+            ));
+            if let Some(operand) = &self.suspendtimehint {
+                f(OperandRef::general(
+                    "suspendTimeHint",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "waitComplete",
+                OperandRole::Destination,
+                &mut self.waitcomplete,
+            ));
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "phaseParity",
+                OperandRole::Source,
+                &mut self.phaseparity,
+            ));
+            if let Some(operand) = &mut self.suspendtimehint {
+                f(OperandMut::general(
+                    "suspendTimeHint",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Original PTX specification:
+//!
+//! // Thread fence:
+//! fence{.sem}.scope;
+//! // Thread fence (uni-directional):
+//! fence.acquire.sync_restrict::shared::cluster.cluster;
+//! fence.release.sync_restrict::shared::cta.cluster;
+//! // Operation fence (uni-directional):
+//! fence.op_restrict.release.cluster;
+//! // Proxy fence (bi-directional):
+//! fence.proxy.proxykind;
+//! // Proxy fence (uni-directional):
+//! fence.proxy.to_proxykind::from_proxykind.release.scope;
+//! fence.proxy.to_proxykind::from_proxykind.acquire.scope  [addr], size;
+//! fence.proxy.async::generic.acquire.sync_restrict::shared::cluster.cluster;
+//! fence.proxy.async::generic.release.sync_restrict::shared::cta.cluster;
+//! // Old style membar:
+//! membar.level;
+//! membar.proxy.proxykind;
+//! .sem       = { .sc, .acq_rel, .acquire, .release };
+//! .scope     = { .cta, .cluster, .gpu, .sys };
+//! .level     = { .cta, .gl, .sys };
+//! .proxykind = { .alias, .async, .async.global, .async.shared::cta, .async.shared::cluster};
+//! .op_restrict = { .mbarrier_init };
+//! .to_proxykind::from_proxykind = {.tensormap::generic};
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::membar::section_0::*;
+
+    impl Operands for FenceSemScope {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+
+    impl Operands for FenceAcquireSyncRestrictSharedClusterCluster {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+
+    impl Operands for FenceReleaseSyncRestrictSharedCtaCluster {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+
+    impl Operands for FenceOpRestrictReleaseCluster {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+
+    impl Operands for FenceProxyProxykind {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+
+    impl Operands for FenceProxyToProxykindFromProxykindReleaseScope {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+
+    impl Operands for FenceProxyToProxykindFromProxykindAcquireScope {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+        }
+    }
+
+    impl Operands for FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+
+    impl Operands for FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+
+    impl Operands for MembarLevel {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+
+    impl Operands for MembarProxyProxykind {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+}
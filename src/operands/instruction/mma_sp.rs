@@ -0,0 +1,486 @@
+//! Original PTX specification:
+//!
+//! // Half precision floating point type:
+//! mma.spvariant.sync.aligned.m16n8k16.row.col.dtype.f16.f16.ctype  d, a, b, c, e, f;
+//! mma.spvariant.sync.aligned.m16n8k32.row.col.dtype.f16.f16.ctype  d, a, b, c, e, f;
+//! .ctype     = {.f16, .f32};
+//! .dtype     = {.f16, .f32};
+//! .spvariant = {.sp, .sp::ordered_metadata};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! mma.spvariant.sync.aligned.m16n8k16.row.col.f32.bf16.bf16.f32     d, a, b, c, e, f;
+//! mma.spvariant.sync.aligned.m16n8k32.row.col.f32.bf16.bf16.f32     d, a, b, c, e, f;
+//! mma.spvariant.sync.aligned.m16n8k8.row.col.f32.tf32.tf32.f32      d, a, b, c, e, f;
+//! mma.spvariant.sync.aligned.m16n8k16.row.col.f32.tf32.tf32.f32     d, a, b, c, e, f;
+//! mma.spvariant.sync.aligned.m16n8k64.row.col.f32.f8type.f8type.f32 d, a, b, c, e, f;
+//! mma.sp::ordered_metadata.sync.aligned.m16n8k64.row.col.kind.dtype.f8f6f4type.f8f6f4type.ctype d, a, b, c, e, f;
+//! .f8type     = {.e4m3, .e5m2};
+//! .spvariant  = {.sp, .sp::ordered_metadata};
+//! .f8f6f4type = {.e4m3, .e5m2, .e3m2, .e2m3, .e2m1};
+//! .kind       = {.kind::f8f6f4};
+//! .ctype      = {.f16, .f32};
+//! .dtype      = {.f16, .f32};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! // Alternate floating point type with block scaling:
+//! mma.spvariant.sync.aligned.m16n8k128.row.col.kind.block_scale{.scale_vec_size}.f32.e2m1.e2m1.f32.stype d, a, b, c, e, f, scale-a-data, {byte-id-a, thread-id-a}, scale-b-data, {byte-id-b, thread-id-b};
+//! .spvariant      = {.sp::ordered_metadata};
+//! .kind           = {.kind::mxf4};
+//! .scale_vec_size = {.scale_vec::2X};
+//! .stype          = {.ue8m0};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! mma.spvariant.sync.aligned.m16n8k128.row.col.kind.block_scale.scale_vec_size.f32.e2m1.e2m1.f32.stype d, a, b, c, e, f, scale-a-data, {byte-id-a, thread-id-a}, scale-b-data, {byte-id-b, thread-id-b};
+//! .spvariant      = {.sp::ordered_metadata};
+//! .kind           = {.kind::mxf4nvf4};
+//! .scale_vec_size = {.scale_vec::2X, .scale_vec::4X};
+//! .stype          = {.ue8m0, .ue4m3};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! mma.spvariant.sync.aligned.m16n8k64.row.col.kind.block_scale{.scale_vec_size}.f32.f8f6f4type.f8f6f4type.f32.stype d, a, b, c, e, f, scale-a-data, {byte-id-a, thread-id-a}, scale-b-data, {byte-id-b, thread-id-b};
+//! .spvariant      = {.sp::ordered_metadata};
+//! .kind           = {.kind::mxf8f6f4};
+//! .scale_vec_size = {.scale_vec::1X};
+//! .f8f6f4type     = {.e4m3, .e5m2, .e3m2, .e2m3, .e2m1};
+//! .stype          = {.ue8m0};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! // Integer type:
+//! mma.spvariant.sync.aligned.shape.row.col{.satfinite}.s32.atype.btype.s32 d, a, b, c, e, f;
+//! .shape     = {.m16n8k32, .m16n8k64};
+//! .atype     = {.u8, .s8};
+//! .btype     = {.u8, .s8};
+//! .spvariant = {.sp, .sp::ordered_metadata};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! mma.spvariant.sync.aligned.shape.row.col{.satfinite}.s32.atype.btype.s32 d, a, b, c, e, f;
+//! .shape     = {.m16n8k64, .m16n8k128};
+//! .atype     = {.u4, .s4};
+//! .btype     = {.u4, .s4};
+//! .spvariant = {.sp, .sp::ordered_metadata};
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_0::*;
+
+    impl Operands for MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+        }
+    }
+
+    impl Operands for MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_1::*;
+
+    impl Operands for MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+        }
+    }
+
+    impl Operands for MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+        }
+    }
+
+    impl Operands for MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+        }
+    }
+
+    impl Operands for MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+        }
+    }
+
+    impl Operands for MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+        }
+    }
+
+    impl Operands for MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_2::*;
+
+    impl Operands
+        for MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype
+    {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+            f(OperandRef::general(
+                "scale-a-data",
+                OperandRole::Source,
+                &self.scale_a_data,
+            ));
+            f(OperandRef::vector(
+                "byte-id-a",
+                OperandRole::Source,
+                &self.byte_id_a,
+            ));
+            f(OperandRef::general(
+                "scale-b-data",
+                OperandRole::Source,
+                &self.scale_b_data,
+            ));
+            f(OperandRef::vector(
+                "byte-id-b",
+                OperandRole::Source,
+                &self.byte_id_b,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+            f(OperandMut::general(
+                "scale-a-data",
+                OperandRole::Source,
+                &mut self.scale_a_data,
+            ));
+            f(OperandMut::vector(
+                "byte-id-a",
+                OperandRole::Source,
+                &mut self.byte_id_a,
+            ));
+            f(OperandMut::general(
+                "scale-b-data",
+                OperandRole::Source,
+                &mut self.scale_b_data,
+            ));
+            f(OperandMut::vector(
+                "byte-id-b",
+                OperandRole::Source,
+                &mut self.byte_id_b,
+            ));
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_3::*;
+
+    impl Operands
+        for MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1
+    {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+            f(OperandRef::general(
+                "scale-a-data",
+                OperandRole::Source,
+                &self.scale_a_data,
+            ));
+            f(OperandRef::vector(
+                "byte-id-a",
+                OperandRole::Source,
+                &self.byte_id_a,
+            ));
+            f(OperandRef::general(
+                "scale-b-data",
+                OperandRole::Source,
+                &self.scale_b_data,
+            ));
+            f(OperandRef::vector(
+                "byte-id-b",
+                OperandRole::Source,
+                &self.byte_id_b,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+            f(OperandMut::general(
+                "scale-a-data",
+                OperandRole::Source,
+                &mut self.scale_a_data,
+            ));
+            f(OperandMut::vector(
+                "byte-id-a",
+                OperandRole::Source,
+                &mut self.byte_id_a,
+            ));
+            f(OperandMut::general(
+                "scale-b-data",
+                OperandRole::Source,
+                &mut self.scale_b_data,
+            ));
+            f(OperandMut::vector(
+                "byte-id-b",
+                OperandRole::Source,
+                &mut self.byte_id_b,
+            ));
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_4::*;
+
+    impl Operands for MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+            f(OperandRef::general("scale-a-data", OperandRole::Source, &self.scale_a_data));
+            f(OperandRef::vector("byte-id-a", OperandRole::Source, &self.byte_id_a));
+            f(OperandRef::general("scale-b-data", OperandRole::Source, &self.scale_b_data));
+            f(OperandRef::vector("byte-id-b", OperandRole::Source, &self.byte_id_b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general("d", OperandRole::Destination, &mut self.d));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+            f(OperandMut::general("scale-a-data", OperandRole::Source, &mut self.scale_a_data));
+            f(OperandMut::vector("byte-id-a", OperandRole::Source, &mut self.byte_id_a));
+            f(OperandMut::general("scale-b-data", OperandRole::Source, &mut self.scale_b_data));
+            f(OperandMut::vector("byte-id-b", OperandRole::Source, &mut self.byte_id_b));
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_5::*;
+
+    impl Operands for MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+        }
+    }
+}
+
+pub mod section_6 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_6::*;
+
+    impl Operands for MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general("e", OperandRole::Source, &self.e));
+            f(OperandRef::general("f", OperandRole::Source, &self.f));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general("e", OperandRole::Source, &mut self.e));
+            f(OperandMut::general("f", OperandRole::Source, &mut self.f));
+        }
+    }
+}
@@ -0,0 +1,1371 @@
+// Auto-generated module declarations
+// DO NOT EDIT MANUALLY
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, Operands};
+use crate::r#type::instruction::Inst;
+
+pub mod abs;
+pub mod activemask;
+pub mod add;
+pub mod add_cc;
+pub mod addc;
+pub mod alloca;
+pub mod and;
+pub mod applypriority;
+pub mod atom;
+pub mod bar;
+pub mod bar_warp_sync;
+pub mod barrier_cluster;
+pub mod bfe;
+pub mod bfi;
+pub mod bfind;
+pub mod bmsk;
+pub mod bra;
+pub mod brev;
+pub mod brkpt;
+pub mod brx_idx;
+pub mod call;
+pub mod clusterlaunchcontrol_query_cancel;
+pub mod clusterlaunchcontrol_try_cancel;
+pub mod clz;
+pub mod cnot;
+pub mod copysign;
+pub mod cos;
+pub mod cp_async;
+pub mod cp_async_bulk;
+pub mod cp_async_bulk_commit_group;
+pub mod cp_async_bulk_prefetch;
+pub mod cp_async_bulk_prefetch_tensor;
+pub mod cp_async_bulk_tensor;
+pub mod cp_async_bulk_wait_group;
+pub mod cp_async_commit_group;
+pub mod cp_async_mbarrier_arrive;
+pub mod cp_async_wait_group;
+pub mod cp_reduce_async_bulk;
+pub mod cp_reduce_async_bulk_tensor;
+pub mod createpolicy;
+pub mod cvt;
+pub mod cvt_pack;
+pub mod cvta;
+pub mod discard;
+pub mod div;
+pub mod dp2a;
+pub mod dp4a;
+pub mod elect_sync;
+pub mod ex2;
+pub mod exit;
+pub mod fma;
+pub mod fns;
+pub mod getctarank;
+pub mod griddepcontrol;
+pub mod isspacep;
+pub mod istypep;
+pub mod ld;
+pub mod ld_global_nc;
+pub mod ldmatrix;
+pub mod ldu;
+pub mod lg2;
+pub mod lop3;
+pub mod mad;
+pub mod mad24;
+pub mod mad_cc;
+pub mod madc;
+pub mod mapa;
+pub mod match_sync;
+pub mod max;
+pub mod mbarrier_arrive;
+pub mod mbarrier_arrive_drop;
+pub mod mbarrier_complete_tx;
+pub mod mbarrier_expect_tx;
+pub mod mbarrier_init;
+pub mod mbarrier_inval;
+pub mod mbarrier_pending_count;
+pub mod mbarrier_test_wait;
+pub mod membar;
+pub mod min;
+pub mod mma;
+pub mod mma_sp;
+pub mod mov;
+pub mod movmatrix;
+pub mod mul;
+pub mod mul24;
+pub mod multimem_ld_reduce;
+pub mod nanosleep;
+pub mod neg;
+pub mod not;
+pub mod or;
+pub mod pmevent;
+pub mod popc;
+pub mod prefetch;
+pub mod prmt;
+pub mod rcp;
+pub mod rcp_approx_ftz_f64;
+pub mod red;
+pub mod red_async;
+pub mod redux_sync;
+pub mod rem;
+pub mod ret;
+pub mod rsqrt;
+pub mod rsqrt_approx_ftz_f64;
+pub mod sad;
+pub mod selp;
+pub mod set;
+pub mod setmaxnreg;
+pub mod setp;
+pub mod shf;
+pub mod shfl;
+pub mod shfl_sync;
+pub mod shl;
+pub mod shr;
+pub mod sin;
+pub mod slct;
+pub mod sqrt;
+pub mod st;
+pub mod st_async;
+pub mod st_bulk;
+pub mod stackrestore;
+pub mod stacksave;
+pub mod stmatrix;
+pub mod sub;
+pub mod sub_cc;
+pub mod subc;
+pub mod suld;
+pub mod suq;
+pub mod sured;
+pub mod sust;
+pub mod szext;
+pub mod tanh;
+pub mod tcgen05_alloc;
+pub mod tcgen05_commit;
+pub mod tcgen05_cp;
+pub mod tcgen05_fence;
+pub mod tcgen05_ld;
+pub mod tcgen05_mma;
+pub mod tcgen05_mma_sp;
+pub mod tcgen05_mma_ws;
+pub mod tcgen05_mma_ws_sp;
+pub mod tcgen05_shift;
+pub mod tcgen05_st;
+pub mod tcgen05_wait;
+pub mod tensormap_cp_fenceproxy;
+pub mod tensormap_replace;
+pub mod testp;
+pub mod tex;
+pub mod tld4;
+pub mod trap;
+pub mod txq;
+pub mod vmad;
+pub mod vop;
+pub mod vop2;
+pub mod vop4;
+pub mod vote;
+pub mod vote_sync;
+pub mod vset;
+pub mod vset2;
+pub mod vset4;
+pub mod vsh;
+pub mod wgmma_commit_group;
+pub mod wgmma_fence;
+pub mod wgmma_mma_async;
+pub mod wgmma_mma_async_sp;
+pub mod wgmma_wait_group;
+pub mod wmma_load;
+pub mod wmma_mma;
+pub mod wmma_store;
+pub mod xor;
+
+impl Operands for Inst {
+    fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+        match self {
+            Inst::AbsType(value) => value.for_each_operand(f),
+            Inst::AbsFtzF32(value) => value.for_each_operand(f),
+            Inst::AbsF64(value) => value.for_each_operand(f),
+            Inst::AbsFtzF16(value) => value.for_each_operand(f),
+            Inst::AbsFtzF16x2(value) => value.for_each_operand(f),
+            Inst::AbsBf16(value) => value.for_each_operand(f),
+            Inst::AbsBf16x2(value) => value.for_each_operand(f),
+            Inst::ActivemaskB32(value) => value.for_each_operand(f),
+            Inst::AddCcType(value) => value.for_each_operand(f),
+            Inst::AddType(value) => value.for_each_operand(f),
+            Inst::AddSatS32(value) => value.for_each_operand(f),
+            Inst::AddRndFtzSatF32(value) => value.for_each_operand(f),
+            Inst::AddRndFtzF32x2(value) => value.for_each_operand(f),
+            Inst::AddRndF64(value) => value.for_each_operand(f),
+            Inst::AddRndFtzSatF16(value) => value.for_each_operand(f),
+            Inst::AddRndFtzSatF16x2(value) => value.for_each_operand(f),
+            Inst::AddRndBf16(value) => value.for_each_operand(f),
+            Inst::AddRndBf16x2(value) => value.for_each_operand(f),
+            Inst::AddRndSatF32Atype(value) => value.for_each_operand(f),
+            Inst::AddcCcType(value) => value.for_each_operand(f),
+            Inst::AllocaType(value) => value.for_each_operand(f),
+            Inst::AndType(value) => value.for_each_operand(f),
+            Inst::ApplypriorityGlobalLevelEvictionPriority(value) => value.for_each_operand(f),
+            Inst::AtomSemScopeSpaceOpLevelCacheHintType(value) => value.for_each_operand(f),
+            Inst::AtomSemScopeSpaceOpType(value) => value.for_each_operand(f),
+            Inst::AtomSemScopeSpaceCasB16(value) => value.for_each_operand(f),
+            Inst::AtomSemScopeSpaceCasB128(value) => value.for_each_operand(f),
+            Inst::AtomSemScopeSpaceExchLevelCacheHintB128(value) => value.for_each_operand(f),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16(value) => value.for_each_operand(f),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2(value) => value.for_each_operand(f),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16(value) => value.for_each_operand(f),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2(value) => value.for_each_operand(f),
+            Inst::AtomSemScopeGlobalAddLevelCacheHintVec32BitF32(value) => value.for_each_operand(f),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType(value) => value.for_each_operand(f),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType(value) => value.for_each_operand(f),
+            Inst::BarrierCtaSyncAligned(value) => value.for_each_operand(f),
+            Inst::BarrierCtaArriveAligned(value) => value.for_each_operand(f),
+            Inst::BarrierCtaRedPopcAlignedU32(value) => value.for_each_operand(f),
+            Inst::BarrierCtaRedOpAlignedPred(value) => value.for_each_operand(f),
+            Inst::BarCtaSync(value) => value.for_each_operand(f),
+            Inst::BarCtaArrive(value) => value.for_each_operand(f),
+            Inst::BarCtaRedPopcU32(value) => value.for_each_operand(f),
+            Inst::BarCtaRedOpPred(value) => value.for_each_operand(f),
+            Inst::BarWarpSync(value) => value.for_each_operand(f),
+            Inst::BarrierClusterArriveSemAligned(value) => value.for_each_operand(f),
+            Inst::BarrierClusterWaitAcquireAligned(value) => value.for_each_operand(f),
+            Inst::BfeType(value) => value.for_each_operand(f),
+            Inst::BfiType(value) => value.for_each_operand(f),
+            Inst::BfindType(value) => value.for_each_operand(f),
+            Inst::BfindShiftamtType(value) => value.for_each_operand(f),
+            Inst::BmskModeB32(value) => value.for_each_operand(f),
+            Inst::BraUni(value) => value.for_each_operand(f),
+            Inst::BraUni1(value) => value.for_each_operand(f),
+            Inst::BrevType(value) => value.for_each_operand(f),
+            Inst::Brkpt(value) => value.for_each_operand(f),
+            Inst::BrxIdxUni(value) => value.for_each_operand(f),
+            Inst::BrxIdxUni1(value) => value.for_each_operand(f),
+            Inst::CallUni(value) => value.for_each_operand(f),
+            Inst::CallUni1(value) => value.for_each_operand(f),
+            Inst::CallUni2(value) => value.for_each_operand(f),
+            Inst::CallUni3(value) => value.for_each_operand(f),
+            Inst::CallUni4(value) => value.for_each_operand(f),
+            Inst::CallUni5(value) => value.for_each_operand(f),
+            Inst::CallUni6(value) => value.for_each_operand(f),
+            Inst::CallUni7(value) => value.for_each_operand(f),
+            Inst::CallUni8(value) => value.for_each_operand(f),
+            Inst::ClusterlaunchcontrolQueryCancelIsCanceledPredB128(value) => value.for_each_operand(f),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128(value) => value.for_each_operand(f),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128(value) => value.for_each_operand(f),
+            Inst::ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128(value) => value.for_each_operand(f),
+            Inst::ClzType(value) => value.for_each_operand(f),
+            Inst::CnotType(value) => value.for_each_operand(f),
+            Inst::CopysignType(value) => value.for_each_operand(f),
+            Inst::CosApproxFtzF32(value) => value.for_each_operand(f),
+            Inst::CpAsyncBulkCommitGroup(value) => value.for_each_operand(f),
+            Inst::CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint(value) => value.for_each_operand(f),
+            Inst::CpAsyncBulkPrefetchL2SrcLevelCacheHint(value) => value.for_each_operand(f),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint(value) => value.for_each_operand(f),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint(value) => value.for_each_operand(f),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint(value) => value.for_each_operand(f),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint(value) => value.for_each_operand(f),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint(value) => value.for_each_operand(f),
+            Inst::CpAsyncBulkDstSrcCompletionMechanism(value) => value.for_each_operand(f),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask(value) => value.for_each_operand(f),
+            Inst::CpAsyncBulkWaitGroupRead(value) => value.for_each_operand(f),
+            Inst::CpAsyncCommitGroup(value) => value.for_each_operand(f),
+            Inst::CpAsyncMbarrierArriveNoincStateB64(value) => value.for_each_operand(f),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.for_each_operand(f),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.for_each_operand(f),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.for_each_operand(f),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.for_each_operand(f),
+            Inst::CpAsyncWaitGroup(value) => value.for_each_operand(f),
+            Inst::CpAsyncWaitAll(value) => value.for_each_operand(f),
+            Inst::CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint(value) => value.for_each_operand(f),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismRedopType(value) => value.for_each_operand(f),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType(value) => value.for_each_operand(f),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType(value) => value.for_each_operand(f),
+            Inst::CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.for_each_operand(f),
+            Inst::CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.for_each_operand(f),
+            Inst::CreatepolicyCvtL2B64(value) => value.for_each_operand(f),
+            Inst::CvtPackSatConverttypeAbtype(value) => value.for_each_operand(f),
+            Inst::CvtPackSatConverttypeAbtypeCtype(value) => value.for_each_operand(f),
+            Inst::CvtIrndFtzSatDtypeAtype(value) => value.for_each_operand(f),
+            Inst::CvtFrndFtzSatDtypeAtype(value) => value.for_each_operand(f),
+            Inst::CvtFrnd2ReluSatfiniteF16F32(value) => value.for_each_operand(f),
+            Inst::CvtFrnd2ReluSatfiniteF16x2F32(value) => value.for_each_operand(f),
+            Inst::CvtRsReluSatfiniteF16x2F32(value) => value.for_each_operand(f),
+            Inst::CvtFrnd2ReluSatfiniteBf16F32(value) => value.for_each_operand(f),
+            Inst::CvtFrnd2ReluSatfiniteBf16x2F32(value) => value.for_each_operand(f),
+            Inst::CvtRsReluSatfiniteBf16x2F32(value) => value.for_each_operand(f),
+            Inst::CvtRnaSatfiniteTf32F32(value) => value.for_each_operand(f),
+            Inst::CvtFrnd2SatfiniteReluTf32F32(value) => value.for_each_operand(f),
+            Inst::CvtRnSatfiniteReluF8x2typeF32(value) => value.for_each_operand(f),
+            Inst::CvtRnSatfiniteReluF8x2typeF16x2(value) => value.for_each_operand(f),
+            Inst::CvtRnReluF16x2F8x2type(value) => value.for_each_operand(f),
+            Inst::CvtRsReluSatfiniteF8x4typeF32(value) => value.for_each_operand(f),
+            Inst::CvtRnSatfiniteReluF4x2typeF32(value) => value.for_each_operand(f),
+            Inst::CvtRnReluF16x2F4x2type(value) => value.for_each_operand(f),
+            Inst::CvtRsReluSatfiniteF4x4typeF32(value) => value.for_each_operand(f),
+            Inst::CvtRnSatfiniteReluF6x2typeF32(value) => value.for_each_operand(f),
+            Inst::CvtRnReluF16x2F6x2type(value) => value.for_each_operand(f),
+            Inst::CvtRsReluSatfiniteF6x4typeF32(value) => value.for_each_operand(f),
+            Inst::CvtFrnd3SatfiniteUe8m0x2F32(value) => value.for_each_operand(f),
+            Inst::CvtFrnd3SatfiniteUe8m0x2Bf16x2(value) => value.for_each_operand(f),
+            Inst::CvtRnBf16x2Ue8m0x2(value) => value.for_each_operand(f),
+            Inst::CvtaSpaceSize(value) => value.for_each_operand(f),
+            Inst::CvtaToSpaceSize(value) => value.for_each_operand(f),
+            Inst::DiscardGlobalLevel(value) => value.for_each_operand(f),
+            Inst::DivType(value) => value.for_each_operand(f),
+            Inst::DivApproxFtzF32(value) => value.for_each_operand(f),
+            Inst::DivFullFtzF32(value) => value.for_each_operand(f),
+            Inst::DivRndFtzF32(value) => value.for_each_operand(f),
+            Inst::DivRndF64(value) => value.for_each_operand(f),
+            Inst::Dp2aModeAtypeBtype(value) => value.for_each_operand(f),
+            Inst::Dp4aAtypeBtype(value) => value.for_each_operand(f),
+            Inst::ElectSync(value) => value.for_each_operand(f),
+            Inst::Ex2ApproxFtzF32(value) => value.for_each_operand(f),
+            Inst::Ex2ApproxAtype(value) => value.for_each_operand(f),
+            Inst::Ex2ApproxFtzBtype(value) => value.for_each_operand(f),
+            Inst::Exit(value) => value.for_each_operand(f),
+            Inst::FmaRndFtzSatF32(value) => value.for_each_operand(f),
+            Inst::FmaRndFtzF32x2(value) => value.for_each_operand(f),
+            Inst::FmaRndF64(value) => value.for_each_operand(f),
+            Inst::FmaRndFtzSatF16(value) => value.for_each_operand(f),
+            Inst::FmaRndFtzSatF16x2(value) => value.for_each_operand(f),
+            Inst::FmaRndFtzReluF16(value) => value.for_each_operand(f),
+            Inst::FmaRndFtzReluF16x2(value) => value.for_each_operand(f),
+            Inst::FmaRndReluBf16(value) => value.for_each_operand(f),
+            Inst::FmaRndReluBf16x2(value) => value.for_each_operand(f),
+            Inst::FmaRndOobReluType(value) => value.for_each_operand(f),
+            Inst::FmaRndSatF32Abtype(value) => value.for_each_operand(f),
+            Inst::FnsB32(value) => value.for_each_operand(f),
+            Inst::GetctarankSpaceType(value) => value.for_each_operand(f),
+            Inst::GetctarankSharedClusterType(value) => value.for_each_operand(f),
+            Inst::GetctarankType(value) => value.for_each_operand(f),
+            Inst::GriddepcontrolAction(value) => value.for_each_operand(f),
+            Inst::IsspacepSpace(value) => value.for_each_operand(f),
+            Inst::IstypepType(value) => value.for_each_operand(f),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType(value) => value.for_each_operand(f),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_operand(f),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType(value) => value.for_each_operand(f),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_operand(f),
+            Inst::LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_operand(f),
+            Inst::LdWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_operand(f),
+            Inst::LdVolatileSsLevelPrefetchSizeVecType(value) => value.for_each_operand(f),
+            Inst::LdRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_operand(f),
+            Inst::LdAcquireScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_operand(f),
+            Inst::LdMmioRelaxedSysGlobalType(value) => value.for_each_operand(f),
+            Inst::LdmatrixSyncAlignedShapeNumTransSsType(value) => value.for_each_operand(f),
+            Inst::LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt(value) => value.for_each_operand(f),
+            Inst::LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt(value) => value.for_each_operand(f),
+            Inst::LduSsType(value) => value.for_each_operand(f),
+            Inst::LduSsVecType(value) => value.for_each_operand(f),
+            Inst::Lg2ApproxFtzF32(value) => value.for_each_operand(f),
+            Inst::Lop3B32(value) => value.for_each_operand(f),
+            Inst::Lop3BoolopB32(value) => value.for_each_operand(f),
+            Inst::MadHiloCcType(value) => value.for_each_operand(f),
+            Inst::MadModeType(value) => value.for_each_operand(f),
+            Inst::MadHiSatS32(value) => value.for_each_operand(f),
+            Inst::MadFtzSatF32(value) => value.for_each_operand(f),
+            Inst::MadRndFtzSatF32(value) => value.for_each_operand(f),
+            Inst::MadRndF64(value) => value.for_each_operand(f),
+            Inst::Mad24ModeType(value) => value.for_each_operand(f),
+            Inst::Mad24HiSatS32(value) => value.for_each_operand(f),
+            Inst::MadcHiloCcType(value) => value.for_each_operand(f),
+            Inst::MapaSpaceType(value) => value.for_each_operand(f),
+            Inst::MatchAnySyncType(value) => value.for_each_operand(f),
+            Inst::MatchAllSyncType(value) => value.for_each_operand(f),
+            Inst::MaxAtype(value) => value.for_each_operand(f),
+            Inst::MaxReluBtype(value) => value.for_each_operand(f),
+            Inst::MaxFtzNanXorsignAbsF32(value) => value.for_each_operand(f),
+            Inst::MaxFtzNanAbsF32(value) => value.for_each_operand(f),
+            Inst::MaxF64(value) => value.for_each_operand(f),
+            Inst::MaxFtzNanXorsignAbsF16(value) => value.for_each_operand(f),
+            Inst::MaxFtzNanXorsignAbsF16x2(value) => value.for_each_operand(f),
+            Inst::MaxNanXorsignAbsBf16(value) => value.for_each_operand(f),
+            Inst::MaxNanXorsignAbsBf16x2(value) => value.for_each_operand(f),
+            Inst::MbarrierArriveSemScopeStateB64(value) => value.for_each_operand(f),
+            Inst::MbarrierArriveSemScopeSharedClusterB64(value) => value.for_each_operand(f),
+            Inst::MbarrierArriveExpectTxSemScopeStateB64(value) => value.for_each_operand(f),
+            Inst::MbarrierArriveExpectTxSemScopeSharedClusterB64(value) => value.for_each_operand(f),
+            Inst::MbarrierArriveNocompleteReleaseCtaStateB64(value) => value.for_each_operand(f),
+            Inst::MbarrierArriveDropSemScopeStateB64(value) => value.for_each_operand(f),
+            Inst::MbarrierArriveDropSemScopeSharedClusterB64(value) => value.for_each_operand(f),
+            Inst::MbarrierArriveDropExpectTxStateSemScopeB64(value) => value.for_each_operand(f),
+            Inst::MbarrierArriveDropExpectTxSharedClusterSemScopeB64(value) => value.for_each_operand(f),
+            Inst::MbarrierArriveDropNocompleteReleaseCtaStateB64(value) => value.for_each_operand(f),
+            Inst::MbarrierCompleteTxSemScopeSpaceB64(value) => value.for_each_operand(f),
+            Inst::MbarrierExpectTxSemScopeSpaceB64(value) => value.for_each_operand(f),
+            Inst::MbarrierInitStateB64(value) => value.for_each_operand(f),
+            Inst::MbarrierInvalStateB64(value) => value.for_each_operand(f),
+            Inst::MbarrierPendingCountB64(value) => value.for_each_operand(f),
+            Inst::MbarrierTestWaitSemScopeStateB64(value) => value.for_each_operand(f),
+            Inst::MbarrierTestWaitParitySemScopeStateB64(value) => value.for_each_operand(f),
+            Inst::MbarrierTryWaitSemScopeStateB64(value) => value.for_each_operand(f),
+            Inst::MbarrierTryWaitParitySemScopeStateB64(value) => value.for_each_operand(f),
+            Inst::FenceSemScope(value) => value.for_each_operand(f),
+            Inst::FenceAcquireSyncRestrictSharedClusterCluster(value) => value.for_each_operand(f),
+            Inst::FenceReleaseSyncRestrictSharedCtaCluster(value) => value.for_each_operand(f),
+            Inst::FenceOpRestrictReleaseCluster(value) => value.for_each_operand(f),
+            Inst::FenceProxyProxykind(value) => value.for_each_operand(f),
+            Inst::FenceProxyToProxykindFromProxykindReleaseScope(value) => value.for_each_operand(f),
+            Inst::FenceProxyToProxykindFromProxykindAcquireScope(value) => value.for_each_operand(f),
+            Inst::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster(value) => value.for_each_operand(f),
+            Inst::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster(value) => value.for_each_operand(f),
+            Inst::MembarLevel(value) => value.for_each_operand(f),
+            Inst::MembarProxyProxykind(value) => value.for_each_operand(f),
+            Inst::MinAtype(value) => value.for_each_operand(f),
+            Inst::MinReluBtype(value) => value.for_each_operand(f),
+            Inst::MinFtzNanXorsignAbsF32(value) => value.for_each_operand(f),
+            Inst::MinFtzNanAbsF32(value) => value.for_each_operand(f),
+            Inst::MinF64(value) => value.for_each_operand(f),
+            Inst::MinFtzNanXorsignAbsF16(value) => value.for_each_operand(f),
+            Inst::MinFtzNanXorsignAbsF16x2(value) => value.for_each_operand(f),
+            Inst::MinNanXorsignAbsBf16(value) => value.for_each_operand(f),
+            Inst::MinNanXorsignAbsBf16x2(value) => value.for_each_operand(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.for_each_operand(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype(value) => value.for_each_operand(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.for_each_operand(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32(value) => value.for_each_operand(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32(value) => value.for_each_operand(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32(value) => value.for_each_operand(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32(value) => value.for_each_operand(f),
+            Inst::MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.for_each_operand(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.for_each_operand(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.for_each_operand(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.for_each_operand(f),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.for_each_operand(f),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedM8n8k4AlayoutBlayoutDtypeF16F16Ctype(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedM16n8k8RowColDtypeF16F16Ctype(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedM16n8k4RowColF32Tf32Tf32F32(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedM16n8k8RowColF32AtypeBtypeF32(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedShapeRowColDtypeF8typeF8typeCtype(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedM16n8k32RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedM16n8k32RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedShapeRowColF64F64F64F64(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.for_each_operand(f),
+            Inst::MmaSyncAlignedShapeRowColS32B1B1S32BitopPopc(value) => value.for_each_operand(f),
+            Inst::MovType(value) => value.for_each_operand(f),
+            Inst::MovU32(value) => value.for_each_operand(f),
+            Inst::MovU64(value) => value.for_each_operand(f),
+            Inst::MovU321(value) => value.for_each_operand(f),
+            Inst::MovU641(value) => value.for_each_operand(f),
+            Inst::MovType1(value) => value.for_each_operand(f),
+            Inst::MovmatrixSyncAlignedShapeTransType(value) => value.for_each_operand(f),
+            Inst::MulModeType(value) => value.for_each_operand(f),
+            Inst::MulRndFtzSatF32(value) => value.for_each_operand(f),
+            Inst::MulRndFtzF32x2(value) => value.for_each_operand(f),
+            Inst::MulRndF64(value) => value.for_each_operand(f),
+            Inst::MulRndFtzSatF16(value) => value.for_each_operand(f),
+            Inst::MulRndFtzSatF16x2(value) => value.for_each_operand(f),
+            Inst::MulRndBf16(value) => value.for_each_operand(f),
+            Inst::MulRndBf16x2(value) => value.for_each_operand(f),
+            Inst::Mul24ModeType(value) => value.for_each_operand(f),
+            Inst::MultimemLdReduceLdsemScopeSsOpType(value) => value.for_each_operand(f),
+            Inst::MultimemLdReduceWeakSsOpType(value) => value.for_each_operand(f),
+            Inst::MultimemStStsemScopeSsType(value) => value.for_each_operand(f),
+            Inst::MultimemStWeakSsType(value) => value.for_each_operand(f),
+            Inst::MultimemRedRedsemScopeSsOpType(value) => value.for_each_operand(f),
+            Inst::MultimemLdReduceLdsemScopeSsOpAccPrecVecType(value) => value.for_each_operand(f),
+            Inst::MultimemLdReduceWeakSsOpAccPrecVecType(value) => value.for_each_operand(f),
+            Inst::MultimemStStsemScopeSsVecType(value) => value.for_each_operand(f),
+            Inst::MultimemStWeakSsVecType(value) => value.for_each_operand(f),
+            Inst::MultimemRedRedsemScopeSsRedopVecRedtype(value) => value.for_each_operand(f),
+            Inst::NanosleepU32(value) => value.for_each_operand(f),
+            Inst::NegType(value) => value.for_each_operand(f),
+            Inst::NegFtzF32(value) => value.for_each_operand(f),
+            Inst::NegF64(value) => value.for_each_operand(f),
+            Inst::NegFtzF16(value) => value.for_each_operand(f),
+            Inst::NegFtzF16x2(value) => value.for_each_operand(f),
+            Inst::NegBf16(value) => value.for_each_operand(f),
+            Inst::NegBf16x2(value) => value.for_each_operand(f),
+            Inst::NotType(value) => value.for_each_operand(f),
+            Inst::OrType(value) => value.for_each_operand(f),
+            Inst::Pmevent(value) => value.for_each_operand(f),
+            Inst::PmeventMask(value) => value.for_each_operand(f),
+            Inst::PopcType(value) => value.for_each_operand(f),
+            Inst::PrefetchSpaceLevel(value) => value.for_each_operand(f),
+            Inst::PrefetchGlobalLevelEvictionPriority(value) => value.for_each_operand(f),
+            Inst::PrefetchuL1(value) => value.for_each_operand(f),
+            Inst::PrefetchTensormapSpaceTensormap(value) => value.for_each_operand(f),
+            Inst::PrmtB32Mode(value) => value.for_each_operand(f),
+            Inst::RcpApproxFtzF64(value) => value.for_each_operand(f),
+            Inst::RcpApproxFtzF32(value) => value.for_each_operand(f),
+            Inst::RcpRndFtzF32(value) => value.for_each_operand(f),
+            Inst::RcpRndF64(value) => value.for_each_operand(f),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType(value) => value.for_each_operand(f),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType1(value) => value.for_each_operand(f),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType2(value) => value.for_each_operand(f),
+            Inst::RedAsyncSemScopeSsCompletionMechanismAddType(value) => value.for_each_operand(f),
+            Inst::RedAsyncMmioSemScopeSsAddType(value) => value.for_each_operand(f),
+            Inst::RedOpSpaceSemScopeLevelCacheHintType(value) => value.for_each_operand(f),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16(value) => value.for_each_operand(f),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16x2(value) => value.for_each_operand(f),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16(value) => value.for_each_operand(f),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16x2(value) => value.for_each_operand(f),
+            Inst::RedAddSpaceSemScopeLevelCacheHintVec32BitF32(value) => value.for_each_operand(f),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec16BitHalfWordType(value) => value.for_each_operand(f),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec32BitPackedType(value) => value.for_each_operand(f),
+            Inst::ReduxSyncOpType(value) => value.for_each_operand(f),
+            Inst::ReduxSyncOpB32(value) => value.for_each_operand(f),
+            Inst::ReduxSyncOpAbsNanF32(value) => value.for_each_operand(f),
+            Inst::RemType(value) => value.for_each_operand(f),
+            Inst::RetUni(value) => value.for_each_operand(f),
+            Inst::RsqrtApproxFtzF64(value) => value.for_each_operand(f),
+            Inst::RsqrtApproxFtzF32(value) => value.for_each_operand(f),
+            Inst::RsqrtApproxF64(value) => value.for_each_operand(f),
+            Inst::SadType(value) => value.for_each_operand(f),
+            Inst::SelpType(value) => value.for_each_operand(f),
+            Inst::SetCmpopFtzDtypeStype(value) => value.for_each_operand(f),
+            Inst::SetCmpopBoolopFtzDtypeStype(value) => value.for_each_operand(f),
+            Inst::SetCmpopFtzF16Stype(value) => value.for_each_operand(f),
+            Inst::SetCmpopBoolopFtzF16Stype(value) => value.for_each_operand(f),
+            Inst::SetCmpopBf16Stype(value) => value.for_each_operand(f),
+            Inst::SetCmpopBoolopBf16Stype(value) => value.for_each_operand(f),
+            Inst::SetCmpopFtzDtypeF16(value) => value.for_each_operand(f),
+            Inst::SetCmpopBoolopFtzDtypeF16(value) => value.for_each_operand(f),
+            Inst::SetCmpopDtypeBf16(value) => value.for_each_operand(f),
+            Inst::SetCmpopBoolopDtypeBf16(value) => value.for_each_operand(f),
+            Inst::SetCmpopFtzDtypeF16x2(value) => value.for_each_operand(f),
+            Inst::SetCmpopBoolopFtzDtypeF16x2(value) => value.for_each_operand(f),
+            Inst::SetCmpopDtypeBf16x2(value) => value.for_each_operand(f),
+            Inst::SetCmpopBoolopDtypeBf16x2(value) => value.for_each_operand(f),
+            Inst::SetmaxnregActionSyncAlignedU32(value) => value.for_each_operand(f),
+            Inst::SetpCmpopFtzType(value) => value.for_each_operand(f),
+            Inst::SetpCmpopBoolopFtzType(value) => value.for_each_operand(f),
+            Inst::SetpCmpopFtzF16(value) => value.for_each_operand(f),
+            Inst::SetpCmpopBoolopFtzF16(value) => value.for_each_operand(f),
+            Inst::SetpCmpopFtzF16x2(value) => value.for_each_operand(f),
+            Inst::SetpCmpopBoolopFtzF16x2(value) => value.for_each_operand(f),
+            Inst::SetpCmpopBf16(value) => value.for_each_operand(f),
+            Inst::SetpCmpopBoolopBf16(value) => value.for_each_operand(f),
+            Inst::SetpCmpopBf16x2(value) => value.for_each_operand(f),
+            Inst::SetpCmpopBoolopBf16x2(value) => value.for_each_operand(f),
+            Inst::ShfLModeB32(value) => value.for_each_operand(f),
+            Inst::ShfRModeB32(value) => value.for_each_operand(f),
+            Inst::ShflSyncModeB32(value) => value.for_each_operand(f),
+            Inst::ShflModeB32(value) => value.for_each_operand(f),
+            Inst::ShlType(value) => value.for_each_operand(f),
+            Inst::ShrType(value) => value.for_each_operand(f),
+            Inst::SinApproxFtzF32(value) => value.for_each_operand(f),
+            Inst::SlctDtypeS32(value) => value.for_each_operand(f),
+            Inst::SlctFtzDtypeF32(value) => value.for_each_operand(f),
+            Inst::SqrtApproxFtzF32(value) => value.for_each_operand(f),
+            Inst::SqrtRndFtzF32(value) => value.for_each_operand(f),
+            Inst::SqrtRndF64(value) => value.for_each_operand(f),
+            Inst::StAsyncSemScopeSsCompletionMechanismVecType(value) => value.for_each_operand(f),
+            Inst::StAsyncMmioSemScopeSsType(value) => value.for_each_operand(f),
+            Inst::StBulkWeakSharedCta(value) => value.for_each_operand(f),
+            Inst::StWeakSsCopLevelCacheHintVecType(value) => value.for_each_operand(f),
+            Inst::StWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.for_each_operand(f),
+            Inst::StVolatileSsVecType(value) => value.for_each_operand(f),
+            Inst::StRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.for_each_operand(f),
+            Inst::StReleaseScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.for_each_operand(f),
+            Inst::StMmioRelaxedSysGlobalType(value) => value.for_each_operand(f),
+            Inst::StackrestoreType(value) => value.for_each_operand(f),
+            Inst::StacksaveType(value) => value.for_each_operand(f),
+            Inst::StmatrixSyncAlignedShapeNumTransSsType(value) => value.for_each_operand(f),
+            Inst::SubCcType(value) => value.for_each_operand(f),
+            Inst::SubType(value) => value.for_each_operand(f),
+            Inst::SubSatS32(value) => value.for_each_operand(f),
+            Inst::SubRndFtzSatF32(value) => value.for_each_operand(f),
+            Inst::SubRndFtzF32x2(value) => value.for_each_operand(f),
+            Inst::SubRndF64(value) => value.for_each_operand(f),
+            Inst::SubRndFtzSatF16(value) => value.for_each_operand(f),
+            Inst::SubRndFtzSatF16x2(value) => value.for_each_operand(f),
+            Inst::SubRndBf16(value) => value.for_each_operand(f),
+            Inst::SubRndBf16x2(value) => value.for_each_operand(f),
+            Inst::SubRndSatF32Atype(value) => value.for_each_operand(f),
+            Inst::SubcCcType(value) => value.for_each_operand(f),
+            Inst::SuldBGeomCopVecDtypeMode(value) => value.for_each_operand(f),
+            Inst::SuqQueryB32(value) => value.for_each_operand(f),
+            Inst::SuredBOpGeomCtypeMode(value) => value.for_each_operand(f),
+            Inst::SuredPOpGeomCtypeMode(value) => value.for_each_operand(f),
+            Inst::SustBDimCopVecCtypeMode(value) => value.for_each_operand(f),
+            Inst::SustPDimVecB32Mode(value) => value.for_each_operand(f),
+            Inst::SustBAdimCopVecCtypeMode(value) => value.for_each_operand(f),
+            Inst::SzextModeType(value) => value.for_each_operand(f),
+            Inst::TanhApproxType(value) => value.for_each_operand(f),
+            Inst::Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32(value) => value.for_each_operand(f),
+            Inst::Tcgen05DeallocCtaGroupSyncAlignedB32(value) => value.for_each_operand(f),
+            Inst::Tcgen05RelinquishAllocPermitCtaGroupSyncAligned(value) => value.for_each_operand(f),
+            Inst::Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64(value) => value.for_each_operand(f),
+            Inst::Tcgen05CpCtaGroupShapeMulticastDstSrcFmt(value) => value.for_each_operand(f),
+            Inst::Tcgen05FenceBeforeThreadSync(value) => value.for_each_operand(f),
+            Inst::Tcgen05FenceAfterThreadSync(value) => value.for_each_operand(f),
+            Inst::Tcgen05LdSyncAlignedShape1NumPackB32(value) => value.for_each_operand(f),
+            Inst::Tcgen05LdSyncAlignedShape2NumPackB32(value) => value.for_each_operand(f),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32(value) => value.for_each_operand(f),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32(value) => value.for_each_operand(f),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopType(value) => value.for_each_operand(f),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopType(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKind(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKind1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKindCollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI8(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI81(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI8CollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKind(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKind1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKindCollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKindI8(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKindI81(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKindI8CollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage1(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage(value) => value.for_each_operand(f),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1(value) => value.for_each_operand(f),
+            Inst::Tcgen05ShiftCtaGroupDown(value) => value.for_each_operand(f),
+            Inst::Tcgen05StSyncAlignedShape1NumUnpackB32(value) => value.for_each_operand(f),
+            Inst::Tcgen05StSyncAlignedShape2NumUnpackB32(value) => value.for_each_operand(f),
+            Inst::Tcgen05WaitOperationSyncAligned(value) => value.for_each_operand(f),
+            Inst::TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned(value) => value.for_each_operand(f),
+            Inst::TensormapReplaceModeField1SsB1024Type(value) => value.for_each_operand(f),
+            Inst::TensormapReplaceModeField2SsB1024Type(value) => value.for_each_operand(f),
+            Inst::TensormapReplaceModeField3SsB1024Type(value) => value.for_each_operand(f),
+            Inst::TestpOpType(value) => value.for_each_operand(f),
+            Inst::TexGeomV4DtypeCtype(value) => value.for_each_operand(f),
+            Inst::TexGeomV4DtypeCtype1(value) => value.for_each_operand(f),
+            Inst::TexGeomV2F16x2Ctype(value) => value.for_each_operand(f),
+            Inst::TexGeomV2F16x2Ctype1(value) => value.for_each_operand(f),
+            Inst::TexBaseGeomV4DtypeCtype(value) => value.for_each_operand(f),
+            Inst::TexLevelGeomV4DtypeCtype(value) => value.for_each_operand(f),
+            Inst::TexGradGeomV4DtypeCtype(value) => value.for_each_operand(f),
+            Inst::TexBaseGeomV2F16x2Ctype(value) => value.for_each_operand(f),
+            Inst::TexLevelGeomV2F16x2Ctype(value) => value.for_each_operand(f),
+            Inst::TexGradGeomV2F16x2Ctype(value) => value.for_each_operand(f),
+            Inst::Tld4Comp2dV4DtypeF32(value) => value.for_each_operand(f),
+            Inst::Tld4CompGeomV4DtypeF32(value) => value.for_each_operand(f),
+            Inst::Trap(value) => value.for_each_operand(f),
+            Inst::TxqTqueryB32(value) => value.for_each_operand(f),
+            Inst::TxqLevelTlqueryB32(value) => value.for_each_operand(f),
+            Inst::TxqSqueryB32(value) => value.for_each_operand(f),
+            Inst::VmadDtypeAtypeBtypeSatScale(value) => value.for_each_operand(f),
+            Inst::VmadDtypeAtypeBtypePoSatScale(value) => value.for_each_operand(f),
+            Inst::VaddDtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::VsubDtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::VabsdiffDtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::VminDtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::VmaxDtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::VaddDtypeAtypeBtypeSatOp2(value) => value.for_each_operand(f),
+            Inst::VsubDtypeAtypeBtypeSatOp2(value) => value.for_each_operand(f),
+            Inst::VabsdiffDtypeAtypeBtypeSatOp2(value) => value.for_each_operand(f),
+            Inst::VminDtypeAtypeBtypeSatOp2(value) => value.for_each_operand(f),
+            Inst::VmaxDtypeAtypeBtypeSatOp2(value) => value.for_each_operand(f),
+            Inst::VaddDtypeAtypeBtypeSat1(value) => value.for_each_operand(f),
+            Inst::VsubDtypeAtypeBtypeSat1(value) => value.for_each_operand(f),
+            Inst::VabsdiffDtypeAtypeBtypeSat1(value) => value.for_each_operand(f),
+            Inst::VminDtypeAtypeBtypeSat1(value) => value.for_each_operand(f),
+            Inst::VmaxDtypeAtypeBtypeSat1(value) => value.for_each_operand(f),
+            Inst::Vadd2DtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::Vsub2DtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::Vavrg2DtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::Vabsdiff2DtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::Vmin2DtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::Vmax2DtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::Vadd2DtypeAtypeBtypeAdd(value) => value.for_each_operand(f),
+            Inst::Vsub2DtypeAtypeBtypeAdd(value) => value.for_each_operand(f),
+            Inst::Vavrg2DtypeAtypeBtypeAdd(value) => value.for_each_operand(f),
+            Inst::Vabsdiff2DtypeAtypeBtypeAdd(value) => value.for_each_operand(f),
+            Inst::Vmin2DtypeAtypeBtypeAdd(value) => value.for_each_operand(f),
+            Inst::Vmax2DtypeAtypeBtypeAdd(value) => value.for_each_operand(f),
+            Inst::Vadd4DtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::Vsub4DtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::Vavrg4DtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::Vabsdiff4DtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::Vmin4DtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::Vmax4DtypeAtypeBtypeSat(value) => value.for_each_operand(f),
+            Inst::Vadd4DtypeAtypeBtypeAdd(value) => value.for_each_operand(f),
+            Inst::Vsub4DtypeAtypeBtypeAdd(value) => value.for_each_operand(f),
+            Inst::Vavrg4DtypeAtypeBtypeAdd(value) => value.for_each_operand(f),
+            Inst::Vabsdiff4DtypeAtypeBtypeAdd(value) => value.for_each_operand(f),
+            Inst::Vmin4DtypeAtypeBtypeAdd(value) => value.for_each_operand(f),
+            Inst::Vmax4DtypeAtypeBtypeAdd(value) => value.for_each_operand(f),
+            Inst::VoteSyncModePred(value) => value.for_each_operand(f),
+            Inst::VoteSyncBallotB32(value) => value.for_each_operand(f),
+            Inst::VoteModePred(value) => value.for_each_operand(f),
+            Inst::VoteBallotB32(value) => value.for_each_operand(f),
+            Inst::VsetAtypeBtypeCmp(value) => value.for_each_operand(f),
+            Inst::VsetAtypeBtypeCmpOp2(value) => value.for_each_operand(f),
+            Inst::VsetAtypeBtypeCmp1(value) => value.for_each_operand(f),
+            Inst::Vset2AtypeBtypeCmp(value) => value.for_each_operand(f),
+            Inst::Vset2AtypeBtypeCmpAdd(value) => value.for_each_operand(f),
+            Inst::Vset4AtypeBtypeCmp(value) => value.for_each_operand(f),
+            Inst::Vset4AtypeBtypeCmpAdd(value) => value.for_each_operand(f),
+            Inst::VshlDtypeAtypeU32SatMode(value) => value.for_each_operand(f),
+            Inst::VshrDtypeAtypeU32SatMode(value) => value.for_each_operand(f),
+            Inst::VshlDtypeAtypeU32SatModeOp2(value) => value.for_each_operand(f),
+            Inst::VshrDtypeAtypeU32SatModeOp2(value) => value.for_each_operand(f),
+            Inst::VshlDtypeAtypeU32SatMode1(value) => value.for_each_operand(f),
+            Inst::VshrDtypeAtypeU32SatMode1(value) => value.for_each_operand(f),
+            Inst::WgmmaCommitGroupSyncAligned(value) => value.for_each_operand(f),
+            Inst::WgmmaFenceSyncAligned(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc(value) => value.for_each_operand(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1(value) => value.for_each_operand(f),
+            Inst::WgmmaWaitGroupSyncAligned(value) => value.for_each_operand(f),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype(value) => value.for_each_operand(f),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype(value) => value.for_each_operand(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype(value) => value.for_each_operand(f),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype1(value) => value.for_each_operand(f),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype1(value) => value.for_each_operand(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype1(value) => value.for_each_operand(f),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype2(value) => value.for_each_operand(f),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype2(value) => value.for_each_operand(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype2(value) => value.for_each_operand(f),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype3(value) => value.for_each_operand(f),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype3(value) => value.for_each_operand(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype3(value) => value.for_each_operand(f),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype(value) => value.for_each_operand(f),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype(value) => value.for_each_operand(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype4(value) => value.for_each_operand(f),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype1(value) => value.for_each_operand(f),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype1(value) => value.for_each_operand(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype5(value) => value.for_each_operand(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeDtypeCtype(value) => value.for_each_operand(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeS32AtypeBtypeS32Satfinite(value) => value.for_each_operand(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF32(value) => value.for_each_operand(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF321(value) => value.for_each_operand(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeRndF64F64F64F64(value) => value.for_each_operand(f),
+            Inst::WmmaMmaSyncAlignedRowColShapeS32AtypeBtypeS32Satfinite(value) => value.for_each_operand(f),
+            Inst::WmmaMmaOpPopcSyncAlignedRowColShapeS32AtypeBtypeS32(value) => value.for_each_operand(f),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType(value) => value.for_each_operand(f),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType1(value) => value.for_each_operand(f),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType2(value) => value.for_each_operand(f),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType3(value) => value.for_each_operand(f),
+            Inst::XorType(value) => value.for_each_operand(f),
+        }
+    }
+    fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+        match self {
+            Inst::AbsType(value) => value.for_each_operand_mut(f),
+            Inst::AbsFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::AbsF64(value) => value.for_each_operand_mut(f),
+            Inst::AbsFtzF16(value) => value.for_each_operand_mut(f),
+            Inst::AbsFtzF16x2(value) => value.for_each_operand_mut(f),
+            Inst::AbsBf16(value) => value.for_each_operand_mut(f),
+            Inst::AbsBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::ActivemaskB32(value) => value.for_each_operand_mut(f),
+            Inst::AddCcType(value) => value.for_each_operand_mut(f),
+            Inst::AddType(value) => value.for_each_operand_mut(f),
+            Inst::AddSatS32(value) => value.for_each_operand_mut(f),
+            Inst::AddRndFtzSatF32(value) => value.for_each_operand_mut(f),
+            Inst::AddRndFtzF32x2(value) => value.for_each_operand_mut(f),
+            Inst::AddRndF64(value) => value.for_each_operand_mut(f),
+            Inst::AddRndFtzSatF16(value) => value.for_each_operand_mut(f),
+            Inst::AddRndFtzSatF16x2(value) => value.for_each_operand_mut(f),
+            Inst::AddRndBf16(value) => value.for_each_operand_mut(f),
+            Inst::AddRndBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::AddRndSatF32Atype(value) => value.for_each_operand_mut(f),
+            Inst::AddcCcType(value) => value.for_each_operand_mut(f),
+            Inst::AllocaType(value) => value.for_each_operand_mut(f),
+            Inst::AndType(value) => value.for_each_operand_mut(f),
+            Inst::ApplypriorityGlobalLevelEvictionPriority(value) => value.for_each_operand_mut(f),
+            Inst::AtomSemScopeSpaceOpLevelCacheHintType(value) => value.for_each_operand_mut(f),
+            Inst::AtomSemScopeSpaceOpType(value) => value.for_each_operand_mut(f),
+            Inst::AtomSemScopeSpaceCasB16(value) => value.for_each_operand_mut(f),
+            Inst::AtomSemScopeSpaceCasB128(value) => value.for_each_operand_mut(f),
+            Inst::AtomSemScopeSpaceExchLevelCacheHintB128(value) => value.for_each_operand_mut(f),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16(value) => value.for_each_operand_mut(f),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2(value) => value.for_each_operand_mut(f),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16(value) => value.for_each_operand_mut(f),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::AtomSemScopeGlobalAddLevelCacheHintVec32BitF32(value) => value.for_each_operand_mut(f),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType(value) => value.for_each_operand_mut(f),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType(value) => value.for_each_operand_mut(f),
+            Inst::BarrierCtaSyncAligned(value) => value.for_each_operand_mut(f),
+            Inst::BarrierCtaArriveAligned(value) => value.for_each_operand_mut(f),
+            Inst::BarrierCtaRedPopcAlignedU32(value) => value.for_each_operand_mut(f),
+            Inst::BarrierCtaRedOpAlignedPred(value) => value.for_each_operand_mut(f),
+            Inst::BarCtaSync(value) => value.for_each_operand_mut(f),
+            Inst::BarCtaArrive(value) => value.for_each_operand_mut(f),
+            Inst::BarCtaRedPopcU32(value) => value.for_each_operand_mut(f),
+            Inst::BarCtaRedOpPred(value) => value.for_each_operand_mut(f),
+            Inst::BarWarpSync(value) => value.for_each_operand_mut(f),
+            Inst::BarrierClusterArriveSemAligned(value) => value.for_each_operand_mut(f),
+            Inst::BarrierClusterWaitAcquireAligned(value) => value.for_each_operand_mut(f),
+            Inst::BfeType(value) => value.for_each_operand_mut(f),
+            Inst::BfiType(value) => value.for_each_operand_mut(f),
+            Inst::BfindType(value) => value.for_each_operand_mut(f),
+            Inst::BfindShiftamtType(value) => value.for_each_operand_mut(f),
+            Inst::BmskModeB32(value) => value.for_each_operand_mut(f),
+            Inst::BraUni(value) => value.for_each_operand_mut(f),
+            Inst::BraUni1(value) => value.for_each_operand_mut(f),
+            Inst::BrevType(value) => value.for_each_operand_mut(f),
+            Inst::Brkpt(value) => value.for_each_operand_mut(f),
+            Inst::BrxIdxUni(value) => value.for_each_operand_mut(f),
+            Inst::BrxIdxUni1(value) => value.for_each_operand_mut(f),
+            Inst::CallUni(value) => value.for_each_operand_mut(f),
+            Inst::CallUni1(value) => value.for_each_operand_mut(f),
+            Inst::CallUni2(value) => value.for_each_operand_mut(f),
+            Inst::CallUni3(value) => value.for_each_operand_mut(f),
+            Inst::CallUni4(value) => value.for_each_operand_mut(f),
+            Inst::CallUni5(value) => value.for_each_operand_mut(f),
+            Inst::CallUni6(value) => value.for_each_operand_mut(f),
+            Inst::CallUni7(value) => value.for_each_operand_mut(f),
+            Inst::CallUni8(value) => value.for_each_operand_mut(f),
+            Inst::ClusterlaunchcontrolQueryCancelIsCanceledPredB128(value) => value.for_each_operand_mut(f),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128(value) => value.for_each_operand_mut(f),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128(value) => value.for_each_operand_mut(f),
+            Inst::ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128(value) => value.for_each_operand_mut(f),
+            Inst::ClzType(value) => value.for_each_operand_mut(f),
+            Inst::CnotType(value) => value.for_each_operand_mut(f),
+            Inst::CopysignType(value) => value.for_each_operand_mut(f),
+            Inst::CosApproxFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncBulkCommitGroup(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncBulkPrefetchL2SrcLevelCacheHint(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncBulkDstSrcCompletionMechanism(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncBulkWaitGroupRead(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncCommitGroup(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncMbarrierArriveNoincStateB64(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncWaitGroup(value) => value.for_each_operand_mut(f),
+            Inst::CpAsyncWaitAll(value) => value.for_each_operand_mut(f),
+            Inst::CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint(value) => value.for_each_operand_mut(f),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismRedopType(value) => value.for_each_operand_mut(f),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType(value) => value.for_each_operand_mut(f),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType(value) => value.for_each_operand_mut(f),
+            Inst::CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.for_each_operand_mut(f),
+            Inst::CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.for_each_operand_mut(f),
+            Inst::CreatepolicyCvtL2B64(value) => value.for_each_operand_mut(f),
+            Inst::CvtPackSatConverttypeAbtype(value) => value.for_each_operand_mut(f),
+            Inst::CvtPackSatConverttypeAbtypeCtype(value) => value.for_each_operand_mut(f),
+            Inst::CvtIrndFtzSatDtypeAtype(value) => value.for_each_operand_mut(f),
+            Inst::CvtFrndFtzSatDtypeAtype(value) => value.for_each_operand_mut(f),
+            Inst::CvtFrnd2ReluSatfiniteF16F32(value) => value.for_each_operand_mut(f),
+            Inst::CvtFrnd2ReluSatfiniteF16x2F32(value) => value.for_each_operand_mut(f),
+            Inst::CvtRsReluSatfiniteF16x2F32(value) => value.for_each_operand_mut(f),
+            Inst::CvtFrnd2ReluSatfiniteBf16F32(value) => value.for_each_operand_mut(f),
+            Inst::CvtFrnd2ReluSatfiniteBf16x2F32(value) => value.for_each_operand_mut(f),
+            Inst::CvtRsReluSatfiniteBf16x2F32(value) => value.for_each_operand_mut(f),
+            Inst::CvtRnaSatfiniteTf32F32(value) => value.for_each_operand_mut(f),
+            Inst::CvtFrnd2SatfiniteReluTf32F32(value) => value.for_each_operand_mut(f),
+            Inst::CvtRnSatfiniteReluF8x2typeF32(value) => value.for_each_operand_mut(f),
+            Inst::CvtRnSatfiniteReluF8x2typeF16x2(value) => value.for_each_operand_mut(f),
+            Inst::CvtRnReluF16x2F8x2type(value) => value.for_each_operand_mut(f),
+            Inst::CvtRsReluSatfiniteF8x4typeF32(value) => value.for_each_operand_mut(f),
+            Inst::CvtRnSatfiniteReluF4x2typeF32(value) => value.for_each_operand_mut(f),
+            Inst::CvtRnReluF16x2F4x2type(value) => value.for_each_operand_mut(f),
+            Inst::CvtRsReluSatfiniteF4x4typeF32(value) => value.for_each_operand_mut(f),
+            Inst::CvtRnSatfiniteReluF6x2typeF32(value) => value.for_each_operand_mut(f),
+            Inst::CvtRnReluF16x2F6x2type(value) => value.for_each_operand_mut(f),
+            Inst::CvtRsReluSatfiniteF6x4typeF32(value) => value.for_each_operand_mut(f),
+            Inst::CvtFrnd3SatfiniteUe8m0x2F32(value) => value.for_each_operand_mut(f),
+            Inst::CvtFrnd3SatfiniteUe8m0x2Bf16x2(value) => value.for_each_operand_mut(f),
+            Inst::CvtRnBf16x2Ue8m0x2(value) => value.for_each_operand_mut(f),
+            Inst::CvtaSpaceSize(value) => value.for_each_operand_mut(f),
+            Inst::CvtaToSpaceSize(value) => value.for_each_operand_mut(f),
+            Inst::DiscardGlobalLevel(value) => value.for_each_operand_mut(f),
+            Inst::DivType(value) => value.for_each_operand_mut(f),
+            Inst::DivApproxFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::DivFullFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::DivRndFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::DivRndF64(value) => value.for_each_operand_mut(f),
+            Inst::Dp2aModeAtypeBtype(value) => value.for_each_operand_mut(f),
+            Inst::Dp4aAtypeBtype(value) => value.for_each_operand_mut(f),
+            Inst::ElectSync(value) => value.for_each_operand_mut(f),
+            Inst::Ex2ApproxFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::Ex2ApproxAtype(value) => value.for_each_operand_mut(f),
+            Inst::Ex2ApproxFtzBtype(value) => value.for_each_operand_mut(f),
+            Inst::Exit(value) => value.for_each_operand_mut(f),
+            Inst::FmaRndFtzSatF32(value) => value.for_each_operand_mut(f),
+            Inst::FmaRndFtzF32x2(value) => value.for_each_operand_mut(f),
+            Inst::FmaRndF64(value) => value.for_each_operand_mut(f),
+            Inst::FmaRndFtzSatF16(value) => value.for_each_operand_mut(f),
+            Inst::FmaRndFtzSatF16x2(value) => value.for_each_operand_mut(f),
+            Inst::FmaRndFtzReluF16(value) => value.for_each_operand_mut(f),
+            Inst::FmaRndFtzReluF16x2(value) => value.for_each_operand_mut(f),
+            Inst::FmaRndReluBf16(value) => value.for_each_operand_mut(f),
+            Inst::FmaRndReluBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::FmaRndOobReluType(value) => value.for_each_operand_mut(f),
+            Inst::FmaRndSatF32Abtype(value) => value.for_each_operand_mut(f),
+            Inst::FnsB32(value) => value.for_each_operand_mut(f),
+            Inst::GetctarankSpaceType(value) => value.for_each_operand_mut(f),
+            Inst::GetctarankSharedClusterType(value) => value.for_each_operand_mut(f),
+            Inst::GetctarankType(value) => value.for_each_operand_mut(f),
+            Inst::GriddepcontrolAction(value) => value.for_each_operand_mut(f),
+            Inst::IsspacepSpace(value) => value.for_each_operand_mut(f),
+            Inst::IstypepType(value) => value.for_each_operand_mut(f),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType(value) => value.for_each_operand_mut(f),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_operand_mut(f),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType(value) => value.for_each_operand_mut(f),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_operand_mut(f),
+            Inst::LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_operand_mut(f),
+            Inst::LdWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_operand_mut(f),
+            Inst::LdVolatileSsLevelPrefetchSizeVecType(value) => value.for_each_operand_mut(f),
+            Inst::LdRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_operand_mut(f),
+            Inst::LdAcquireScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.for_each_operand_mut(f),
+            Inst::LdMmioRelaxedSysGlobalType(value) => value.for_each_operand_mut(f),
+            Inst::LdmatrixSyncAlignedShapeNumTransSsType(value) => value.for_each_operand_mut(f),
+            Inst::LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt(value) => value.for_each_operand_mut(f),
+            Inst::LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt(value) => value.for_each_operand_mut(f),
+            Inst::LduSsType(value) => value.for_each_operand_mut(f),
+            Inst::LduSsVecType(value) => value.for_each_operand_mut(f),
+            Inst::Lg2ApproxFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::Lop3B32(value) => value.for_each_operand_mut(f),
+            Inst::Lop3BoolopB32(value) => value.for_each_operand_mut(f),
+            Inst::MadHiloCcType(value) => value.for_each_operand_mut(f),
+            Inst::MadModeType(value) => value.for_each_operand_mut(f),
+            Inst::MadHiSatS32(value) => value.for_each_operand_mut(f),
+            Inst::MadFtzSatF32(value) => value.for_each_operand_mut(f),
+            Inst::MadRndFtzSatF32(value) => value.for_each_operand_mut(f),
+            Inst::MadRndF64(value) => value.for_each_operand_mut(f),
+            Inst::Mad24ModeType(value) => value.for_each_operand_mut(f),
+            Inst::Mad24HiSatS32(value) => value.for_each_operand_mut(f),
+            Inst::MadcHiloCcType(value) => value.for_each_operand_mut(f),
+            Inst::MapaSpaceType(value) => value.for_each_operand_mut(f),
+            Inst::MatchAnySyncType(value) => value.for_each_operand_mut(f),
+            Inst::MatchAllSyncType(value) => value.for_each_operand_mut(f),
+            Inst::MaxAtype(value) => value.for_each_operand_mut(f),
+            Inst::MaxReluBtype(value) => value.for_each_operand_mut(f),
+            Inst::MaxFtzNanXorsignAbsF32(value) => value.for_each_operand_mut(f),
+            Inst::MaxFtzNanAbsF32(value) => value.for_each_operand_mut(f),
+            Inst::MaxF64(value) => value.for_each_operand_mut(f),
+            Inst::MaxFtzNanXorsignAbsF16(value) => value.for_each_operand_mut(f),
+            Inst::MaxFtzNanXorsignAbsF16x2(value) => value.for_each_operand_mut(f),
+            Inst::MaxNanXorsignAbsBf16(value) => value.for_each_operand_mut(f),
+            Inst::MaxNanXorsignAbsBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierArriveSemScopeStateB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierArriveSemScopeSharedClusterB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierArriveExpectTxSemScopeStateB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierArriveExpectTxSemScopeSharedClusterB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierArriveNocompleteReleaseCtaStateB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierArriveDropSemScopeStateB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierArriveDropSemScopeSharedClusterB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierArriveDropExpectTxStateSemScopeB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierArriveDropExpectTxSharedClusterSemScopeB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierArriveDropNocompleteReleaseCtaStateB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierCompleteTxSemScopeSpaceB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierExpectTxSemScopeSpaceB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierInitStateB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierInvalStateB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierPendingCountB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierTestWaitSemScopeStateB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierTestWaitParitySemScopeStateB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierTryWaitSemScopeStateB64(value) => value.for_each_operand_mut(f),
+            Inst::MbarrierTryWaitParitySemScopeStateB64(value) => value.for_each_operand_mut(f),
+            Inst::FenceSemScope(value) => value.for_each_operand_mut(f),
+            Inst::FenceAcquireSyncRestrictSharedClusterCluster(value) => value.for_each_operand_mut(f),
+            Inst::FenceReleaseSyncRestrictSharedCtaCluster(value) => value.for_each_operand_mut(f),
+            Inst::FenceOpRestrictReleaseCluster(value) => value.for_each_operand_mut(f),
+            Inst::FenceProxyProxykind(value) => value.for_each_operand_mut(f),
+            Inst::FenceProxyToProxykindFromProxykindReleaseScope(value) => value.for_each_operand_mut(f),
+            Inst::FenceProxyToProxykindFromProxykindAcquireScope(value) => value.for_each_operand_mut(f),
+            Inst::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster(value) => value.for_each_operand_mut(f),
+            Inst::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster(value) => value.for_each_operand_mut(f),
+            Inst::MembarLevel(value) => value.for_each_operand_mut(f),
+            Inst::MembarProxyProxykind(value) => value.for_each_operand_mut(f),
+            Inst::MinAtype(value) => value.for_each_operand_mut(f),
+            Inst::MinReluBtype(value) => value.for_each_operand_mut(f),
+            Inst::MinFtzNanXorsignAbsF32(value) => value.for_each_operand_mut(f),
+            Inst::MinFtzNanAbsF32(value) => value.for_each_operand_mut(f),
+            Inst::MinF64(value) => value.for_each_operand_mut(f),
+            Inst::MinFtzNanXorsignAbsF16(value) => value.for_each_operand_mut(f),
+            Inst::MinFtzNanXorsignAbsF16x2(value) => value.for_each_operand_mut(f),
+            Inst::MinNanXorsignAbsBf16(value) => value.for_each_operand_mut(f),
+            Inst::MinNanXorsignAbsBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.for_each_operand_mut(f),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedM8n8k4AlayoutBlayoutDtypeF16F16Ctype(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedM16n8k8RowColDtypeF16F16Ctype(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedM16n8k4RowColF32Tf32Tf32F32(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedM16n8k8RowColF32AtypeBtypeF32(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedShapeRowColDtypeF8typeF8typeCtype(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedM16n8k32RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedM16n8k32RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedShapeRowColF64F64F64F64(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.for_each_operand_mut(f),
+            Inst::MmaSyncAlignedShapeRowColS32B1B1S32BitopPopc(value) => value.for_each_operand_mut(f),
+            Inst::MovType(value) => value.for_each_operand_mut(f),
+            Inst::MovU32(value) => value.for_each_operand_mut(f),
+            Inst::MovU64(value) => value.for_each_operand_mut(f),
+            Inst::MovU321(value) => value.for_each_operand_mut(f),
+            Inst::MovU641(value) => value.for_each_operand_mut(f),
+            Inst::MovType1(value) => value.for_each_operand_mut(f),
+            Inst::MovmatrixSyncAlignedShapeTransType(value) => value.for_each_operand_mut(f),
+            Inst::MulModeType(value) => value.for_each_operand_mut(f),
+            Inst::MulRndFtzSatF32(value) => value.for_each_operand_mut(f),
+            Inst::MulRndFtzF32x2(value) => value.for_each_operand_mut(f),
+            Inst::MulRndF64(value) => value.for_each_operand_mut(f),
+            Inst::MulRndFtzSatF16(value) => value.for_each_operand_mut(f),
+            Inst::MulRndFtzSatF16x2(value) => value.for_each_operand_mut(f),
+            Inst::MulRndBf16(value) => value.for_each_operand_mut(f),
+            Inst::MulRndBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::Mul24ModeType(value) => value.for_each_operand_mut(f),
+            Inst::MultimemLdReduceLdsemScopeSsOpType(value) => value.for_each_operand_mut(f),
+            Inst::MultimemLdReduceWeakSsOpType(value) => value.for_each_operand_mut(f),
+            Inst::MultimemStStsemScopeSsType(value) => value.for_each_operand_mut(f),
+            Inst::MultimemStWeakSsType(value) => value.for_each_operand_mut(f),
+            Inst::MultimemRedRedsemScopeSsOpType(value) => value.for_each_operand_mut(f),
+            Inst::MultimemLdReduceLdsemScopeSsOpAccPrecVecType(value) => value.for_each_operand_mut(f),
+            Inst::MultimemLdReduceWeakSsOpAccPrecVecType(value) => value.for_each_operand_mut(f),
+            Inst::MultimemStStsemScopeSsVecType(value) => value.for_each_operand_mut(f),
+            Inst::MultimemStWeakSsVecType(value) => value.for_each_operand_mut(f),
+            Inst::MultimemRedRedsemScopeSsRedopVecRedtype(value) => value.for_each_operand_mut(f),
+            Inst::NanosleepU32(value) => value.for_each_operand_mut(f),
+            Inst::NegType(value) => value.for_each_operand_mut(f),
+            Inst::NegFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::NegF64(value) => value.for_each_operand_mut(f),
+            Inst::NegFtzF16(value) => value.for_each_operand_mut(f),
+            Inst::NegFtzF16x2(value) => value.for_each_operand_mut(f),
+            Inst::NegBf16(value) => value.for_each_operand_mut(f),
+            Inst::NegBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::NotType(value) => value.for_each_operand_mut(f),
+            Inst::OrType(value) => value.for_each_operand_mut(f),
+            Inst::Pmevent(value) => value.for_each_operand_mut(f),
+            Inst::PmeventMask(value) => value.for_each_operand_mut(f),
+            Inst::PopcType(value) => value.for_each_operand_mut(f),
+            Inst::PrefetchSpaceLevel(value) => value.for_each_operand_mut(f),
+            Inst::PrefetchGlobalLevelEvictionPriority(value) => value.for_each_operand_mut(f),
+            Inst::PrefetchuL1(value) => value.for_each_operand_mut(f),
+            Inst::PrefetchTensormapSpaceTensormap(value) => value.for_each_operand_mut(f),
+            Inst::PrmtB32Mode(value) => value.for_each_operand_mut(f),
+            Inst::RcpApproxFtzF64(value) => value.for_each_operand_mut(f),
+            Inst::RcpApproxFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::RcpRndFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::RcpRndF64(value) => value.for_each_operand_mut(f),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType(value) => value.for_each_operand_mut(f),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType1(value) => value.for_each_operand_mut(f),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType2(value) => value.for_each_operand_mut(f),
+            Inst::RedAsyncSemScopeSsCompletionMechanismAddType(value) => value.for_each_operand_mut(f),
+            Inst::RedAsyncMmioSemScopeSsAddType(value) => value.for_each_operand_mut(f),
+            Inst::RedOpSpaceSemScopeLevelCacheHintType(value) => value.for_each_operand_mut(f),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16(value) => value.for_each_operand_mut(f),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16x2(value) => value.for_each_operand_mut(f),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16(value) => value.for_each_operand_mut(f),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::RedAddSpaceSemScopeLevelCacheHintVec32BitF32(value) => value.for_each_operand_mut(f),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec16BitHalfWordType(value) => value.for_each_operand_mut(f),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec32BitPackedType(value) => value.for_each_operand_mut(f),
+            Inst::ReduxSyncOpType(value) => value.for_each_operand_mut(f),
+            Inst::ReduxSyncOpB32(value) => value.for_each_operand_mut(f),
+            Inst::ReduxSyncOpAbsNanF32(value) => value.for_each_operand_mut(f),
+            Inst::RemType(value) => value.for_each_operand_mut(f),
+            Inst::RetUni(value) => value.for_each_operand_mut(f),
+            Inst::RsqrtApproxFtzF64(value) => value.for_each_operand_mut(f),
+            Inst::RsqrtApproxFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::RsqrtApproxF64(value) => value.for_each_operand_mut(f),
+            Inst::SadType(value) => value.for_each_operand_mut(f),
+            Inst::SelpType(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopFtzDtypeStype(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopBoolopFtzDtypeStype(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopFtzF16Stype(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopBoolopFtzF16Stype(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopBf16Stype(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopBoolopBf16Stype(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopFtzDtypeF16(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopBoolopFtzDtypeF16(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopDtypeBf16(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopBoolopDtypeBf16(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopFtzDtypeF16x2(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopBoolopFtzDtypeF16x2(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopDtypeBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::SetCmpopBoolopDtypeBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::SetmaxnregActionSyncAlignedU32(value) => value.for_each_operand_mut(f),
+            Inst::SetpCmpopFtzType(value) => value.for_each_operand_mut(f),
+            Inst::SetpCmpopBoolopFtzType(value) => value.for_each_operand_mut(f),
+            Inst::SetpCmpopFtzF16(value) => value.for_each_operand_mut(f),
+            Inst::SetpCmpopBoolopFtzF16(value) => value.for_each_operand_mut(f),
+            Inst::SetpCmpopFtzF16x2(value) => value.for_each_operand_mut(f),
+            Inst::SetpCmpopBoolopFtzF16x2(value) => value.for_each_operand_mut(f),
+            Inst::SetpCmpopBf16(value) => value.for_each_operand_mut(f),
+            Inst::SetpCmpopBoolopBf16(value) => value.for_each_operand_mut(f),
+            Inst::SetpCmpopBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::SetpCmpopBoolopBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::ShfLModeB32(value) => value.for_each_operand_mut(f),
+            Inst::ShfRModeB32(value) => value.for_each_operand_mut(f),
+            Inst::ShflSyncModeB32(value) => value.for_each_operand_mut(f),
+            Inst::ShflModeB32(value) => value.for_each_operand_mut(f),
+            Inst::ShlType(value) => value.for_each_operand_mut(f),
+            Inst::ShrType(value) => value.for_each_operand_mut(f),
+            Inst::SinApproxFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::SlctDtypeS32(value) => value.for_each_operand_mut(f),
+            Inst::SlctFtzDtypeF32(value) => value.for_each_operand_mut(f),
+            Inst::SqrtApproxFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::SqrtRndFtzF32(value) => value.for_each_operand_mut(f),
+            Inst::SqrtRndF64(value) => value.for_each_operand_mut(f),
+            Inst::StAsyncSemScopeSsCompletionMechanismVecType(value) => value.for_each_operand_mut(f),
+            Inst::StAsyncMmioSemScopeSsType(value) => value.for_each_operand_mut(f),
+            Inst::StBulkWeakSharedCta(value) => value.for_each_operand_mut(f),
+            Inst::StWeakSsCopLevelCacheHintVecType(value) => value.for_each_operand_mut(f),
+            Inst::StWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.for_each_operand_mut(f),
+            Inst::StVolatileSsVecType(value) => value.for_each_operand_mut(f),
+            Inst::StRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.for_each_operand_mut(f),
+            Inst::StReleaseScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.for_each_operand_mut(f),
+            Inst::StMmioRelaxedSysGlobalType(value) => value.for_each_operand_mut(f),
+            Inst::StackrestoreType(value) => value.for_each_operand_mut(f),
+            Inst::StacksaveType(value) => value.for_each_operand_mut(f),
+            Inst::StmatrixSyncAlignedShapeNumTransSsType(value) => value.for_each_operand_mut(f),
+            Inst::SubCcType(value) => value.for_each_operand_mut(f),
+            Inst::SubType(value) => value.for_each_operand_mut(f),
+            Inst::SubSatS32(value) => value.for_each_operand_mut(f),
+            Inst::SubRndFtzSatF32(value) => value.for_each_operand_mut(f),
+            Inst::SubRndFtzF32x2(value) => value.for_each_operand_mut(f),
+            Inst::SubRndF64(value) => value.for_each_operand_mut(f),
+            Inst::SubRndFtzSatF16(value) => value.for_each_operand_mut(f),
+            Inst::SubRndFtzSatF16x2(value) => value.for_each_operand_mut(f),
+            Inst::SubRndBf16(value) => value.for_each_operand_mut(f),
+            Inst::SubRndBf16x2(value) => value.for_each_operand_mut(f),
+            Inst::SubRndSatF32Atype(value) => value.for_each_operand_mut(f),
+            Inst::SubcCcType(value) => value.for_each_operand_mut(f),
+            Inst::SuldBGeomCopVecDtypeMode(value) => value.for_each_operand_mut(f),
+            Inst::SuqQueryB32(value) => value.for_each_operand_mut(f),
+            Inst::SuredBOpGeomCtypeMode(value) => value.for_each_operand_mut(f),
+            Inst::SuredPOpGeomCtypeMode(value) => value.for_each_operand_mut(f),
+            Inst::SustBDimCopVecCtypeMode(value) => value.for_each_operand_mut(f),
+            Inst::SustPDimVecB32Mode(value) => value.for_each_operand_mut(f),
+            Inst::SustBAdimCopVecCtypeMode(value) => value.for_each_operand_mut(f),
+            Inst::SzextModeType(value) => value.for_each_operand_mut(f),
+            Inst::TanhApproxType(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05DeallocCtaGroupSyncAlignedB32(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05RelinquishAllocPermitCtaGroupSyncAligned(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05CpCtaGroupShapeMulticastDstSrcFmt(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05FenceBeforeThreadSync(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05FenceAfterThreadSync(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05LdSyncAlignedShape1NumPackB32(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05LdSyncAlignedShape2NumPackB32(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopType(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopType(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKind(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKind1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKindCollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI8(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI81(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI8CollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKind(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKind1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKindCollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKindI8(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKindI81(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKindI8CollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05ShiftCtaGroupDown(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05StSyncAlignedShape1NumUnpackB32(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05StSyncAlignedShape2NumUnpackB32(value) => value.for_each_operand_mut(f),
+            Inst::Tcgen05WaitOperationSyncAligned(value) => value.for_each_operand_mut(f),
+            Inst::TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned(value) => value.for_each_operand_mut(f),
+            Inst::TensormapReplaceModeField1SsB1024Type(value) => value.for_each_operand_mut(f),
+            Inst::TensormapReplaceModeField2SsB1024Type(value) => value.for_each_operand_mut(f),
+            Inst::TensormapReplaceModeField3SsB1024Type(value) => value.for_each_operand_mut(f),
+            Inst::TestpOpType(value) => value.for_each_operand_mut(f),
+            Inst::TexGeomV4DtypeCtype(value) => value.for_each_operand_mut(f),
+            Inst::TexGeomV4DtypeCtype1(value) => value.for_each_operand_mut(f),
+            Inst::TexGeomV2F16x2Ctype(value) => value.for_each_operand_mut(f),
+            Inst::TexGeomV2F16x2Ctype1(value) => value.for_each_operand_mut(f),
+            Inst::TexBaseGeomV4DtypeCtype(value) => value.for_each_operand_mut(f),
+            Inst::TexLevelGeomV4DtypeCtype(value) => value.for_each_operand_mut(f),
+            Inst::TexGradGeomV4DtypeCtype(value) => value.for_each_operand_mut(f),
+            Inst::TexBaseGeomV2F16x2Ctype(value) => value.for_each_operand_mut(f),
+            Inst::TexLevelGeomV2F16x2Ctype(value) => value.for_each_operand_mut(f),
+            Inst::TexGradGeomV2F16x2Ctype(value) => value.for_each_operand_mut(f),
+            Inst::Tld4Comp2dV4DtypeF32(value) => value.for_each_operand_mut(f),
+            Inst::Tld4CompGeomV4DtypeF32(value) => value.for_each_operand_mut(f),
+            Inst::Trap(value) => value.for_each_operand_mut(f),
+            Inst::TxqTqueryB32(value) => value.for_each_operand_mut(f),
+            Inst::TxqLevelTlqueryB32(value) => value.for_each_operand_mut(f),
+            Inst::TxqSqueryB32(value) => value.for_each_operand_mut(f),
+            Inst::VmadDtypeAtypeBtypeSatScale(value) => value.for_each_operand_mut(f),
+            Inst::VmadDtypeAtypeBtypePoSatScale(value) => value.for_each_operand_mut(f),
+            Inst::VaddDtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::VsubDtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::VabsdiffDtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::VminDtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::VmaxDtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::VaddDtypeAtypeBtypeSatOp2(value) => value.for_each_operand_mut(f),
+            Inst::VsubDtypeAtypeBtypeSatOp2(value) => value.for_each_operand_mut(f),
+            Inst::VabsdiffDtypeAtypeBtypeSatOp2(value) => value.for_each_operand_mut(f),
+            Inst::VminDtypeAtypeBtypeSatOp2(value) => value.for_each_operand_mut(f),
+            Inst::VmaxDtypeAtypeBtypeSatOp2(value) => value.for_each_operand_mut(f),
+            Inst::VaddDtypeAtypeBtypeSat1(value) => value.for_each_operand_mut(f),
+            Inst::VsubDtypeAtypeBtypeSat1(value) => value.for_each_operand_mut(f),
+            Inst::VabsdiffDtypeAtypeBtypeSat1(value) => value.for_each_operand_mut(f),
+            Inst::VminDtypeAtypeBtypeSat1(value) => value.for_each_operand_mut(f),
+            Inst::VmaxDtypeAtypeBtypeSat1(value) => value.for_each_operand_mut(f),
+            Inst::Vadd2DtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::Vsub2DtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::Vavrg2DtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::Vabsdiff2DtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::Vmin2DtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::Vmax2DtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::Vadd2DtypeAtypeBtypeAdd(value) => value.for_each_operand_mut(f),
+            Inst::Vsub2DtypeAtypeBtypeAdd(value) => value.for_each_operand_mut(f),
+            Inst::Vavrg2DtypeAtypeBtypeAdd(value) => value.for_each_operand_mut(f),
+            Inst::Vabsdiff2DtypeAtypeBtypeAdd(value) => value.for_each_operand_mut(f),
+            Inst::Vmin2DtypeAtypeBtypeAdd(value) => value.for_each_operand_mut(f),
+            Inst::Vmax2DtypeAtypeBtypeAdd(value) => value.for_each_operand_mut(f),
+            Inst::Vadd4DtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::Vsub4DtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::Vavrg4DtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::Vabsdiff4DtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::Vmin4DtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::Vmax4DtypeAtypeBtypeSat(value) => value.for_each_operand_mut(f),
+            Inst::Vadd4DtypeAtypeBtypeAdd(value) => value.for_each_operand_mut(f),
+            Inst::Vsub4DtypeAtypeBtypeAdd(value) => value.for_each_operand_mut(f),
+            Inst::Vavrg4DtypeAtypeBtypeAdd(value) => value.for_each_operand_mut(f),
+            Inst::Vabsdiff4DtypeAtypeBtypeAdd(value) => value.for_each_operand_mut(f),
+            Inst::Vmin4DtypeAtypeBtypeAdd(value) => value.for_each_operand_mut(f),
+            Inst::Vmax4DtypeAtypeBtypeAdd(value) => value.for_each_operand_mut(f),
+            Inst::VoteSyncModePred(value) => value.for_each_operand_mut(f),
+            Inst::VoteSyncBallotB32(value) => value.for_each_operand_mut(f),
+            Inst::VoteModePred(value) => value.for_each_operand_mut(f),
+            Inst::VoteBallotB32(value) => value.for_each_operand_mut(f),
+            Inst::VsetAtypeBtypeCmp(value) => value.for_each_operand_mut(f),
+            Inst::VsetAtypeBtypeCmpOp2(value) => value.for_each_operand_mut(f),
+            Inst::VsetAtypeBtypeCmp1(value) => value.for_each_operand_mut(f),
+            Inst::Vset2AtypeBtypeCmp(value) => value.for_each_operand_mut(f),
+            Inst::Vset2AtypeBtypeCmpAdd(value) => value.for_each_operand_mut(f),
+            Inst::Vset4AtypeBtypeCmp(value) => value.for_each_operand_mut(f),
+            Inst::Vset4AtypeBtypeCmpAdd(value) => value.for_each_operand_mut(f),
+            Inst::VshlDtypeAtypeU32SatMode(value) => value.for_each_operand_mut(f),
+            Inst::VshrDtypeAtypeU32SatMode(value) => value.for_each_operand_mut(f),
+            Inst::VshlDtypeAtypeU32SatModeOp2(value) => value.for_each_operand_mut(f),
+            Inst::VshrDtypeAtypeU32SatModeOp2(value) => value.for_each_operand_mut(f),
+            Inst::VshlDtypeAtypeU32SatMode1(value) => value.for_each_operand_mut(f),
+            Inst::VshrDtypeAtypeU32SatMode1(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaCommitGroupSyncAligned(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaFenceSyncAligned(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1(value) => value.for_each_operand_mut(f),
+            Inst::WgmmaWaitGroupSyncAligned(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype1(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype1(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype1(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype2(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype2(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype2(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype3(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype3(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype3(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype4(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype1(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype1(value) => value.for_each_operand_mut(f),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype5(value) => value.for_each_operand_mut(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeDtypeCtype(value) => value.for_each_operand_mut(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeS32AtypeBtypeS32Satfinite(value) => value.for_each_operand_mut(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF32(value) => value.for_each_operand_mut(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF321(value) => value.for_each_operand_mut(f),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeRndF64F64F64F64(value) => value.for_each_operand_mut(f),
+            Inst::WmmaMmaSyncAlignedRowColShapeS32AtypeBtypeS32Satfinite(value) => value.for_each_operand_mut(f),
+            Inst::WmmaMmaOpPopcSyncAlignedRowColShapeS32AtypeBtypeS32(value) => value.for_each_operand_mut(f),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType(value) => value.for_each_operand_mut(f),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType1(value) => value.for_each_operand_mut(f),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType2(value) => value.for_each_operand_mut(f),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType3(value) => value.for_each_operand_mut(f),
+            Inst::XorType(value) => value.for_each_operand_mut(f),
+        }
+    }
+}
@@ -0,0 +1,173 @@
+//! Original PTX specification:
+//!
+//! // Integer type:
+//! multimem.ld_reduce{.ldsem}{.scope}{.ss}.op.type      d, [a];
+//! multimem.ld_reduce.weak{.ss}.op.type                 d, [a];
+//! multimem.st{.stsem}{.scope}{.ss}.type                [a], b;
+//! multimem.st.weak{.ss}.type                           [a], b;
+//! multimem.red{.redsem}{.scope}{.ss}.op.type           [a], b;
+//! .ss =       { .global };
+//! .ldsem =    { .relaxed, .acquire };
+//! .stsem =    { .relaxed, .release };
+//! .redsem =   { .relaxed, .release };
+//! .scope =    { .cta, .cluster, .gpu, .sys };
+//! .op  =      { .min, .max, .add, .and, .or, .xor };
+//! .type =     { .b32, .b64,  .u32, .u64, .s32, .s64 };
+//! ------------------------------------------------------------------
+//! // Floating point type:
+//! multimem.ld_reduce{.ldsem}{.scope}{.ss}.op{.acc_prec}{.vec}.type    d, [a];
+//! multimem.ld_reduce.weak{.ss}.op{.acc_prec}{.vec}.type               d, [a];
+//! multimem.st{.stsem}{.scope}{.ss}{.vec}.type                         [a], b;
+//! multimem.st.weak{.ss}{.vec}.type                                    [a], b;
+//! multimem.red{.redsem}{.scope}{.ss}.redop{.vec}.redtype              [a], b;
+//! .ss =       { .global };
+//! .ldsem =    { .relaxed, .acquire };
+//! .stsem =    { .relaxed, .release };
+//! .redsem =   { .relaxed, .release };
+//! .scope =    { .cta, .cluster, .gpu, .sys };
+//! .op  =      { .min, .max, .add };
+//! .redop  =   { .add };
+//! .acc_prec = { .acc::f32, .acc::f16 };
+//! .vec =      { .v2, .v4, .v8 };
+//! .type=      { .f16, .f16x2, .bf16, .bf16x2, .f32, .f64, .e5m2, .e5m2x2, .e5m2x4, .e4m3, .e4m3x2, .e4m3x4 };
+//! .redtype =  { .f16, .f16x2, .bf16, .bf16x2, .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::multimem_ld_reduce::section_0::*;
+
+    impl Operands for MultimemLdReduceLdsemScopeSsOpType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+        }
+    }
+
+    impl Operands for MultimemLdReduceWeakSsOpType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+        }
+    }
+
+    impl Operands for MultimemStStsemScopeSsType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+
+    impl Operands for MultimemStWeakSsType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+
+    impl Operands for MultimemRedRedsemScopeSsOpType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::multimem_ld_reduce::section_1::*;
+
+    impl Operands for MultimemLdReduceLdsemScopeSsOpAccPrecVecType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+        }
+    }
+
+    impl Operands for MultimemLdReduceWeakSsOpAccPrecVecType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+        }
+    }
+
+    impl Operands for MultimemStStsemScopeSsVecType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+
+    impl Operands for MultimemStWeakSsVecType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+
+    impl Operands for MultimemRedRedsemScopeSsRedopVecRedtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Original PTX specification:
+//!
+//! nanosleep.u32 t;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::nanosleep::section_0::*;
+
+    impl Operands for NanosleepU32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("t", OperandRole::Source, &self.t));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general("t", OperandRole::Source, &mut self.t));
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Original PTX specification:
+//!
+//! prefetch{.space}.level                    [a];   // prefetch to data cache
+//! prefetch.global.level::eviction_priority  [a];   // prefetch to data cache
+//! prefetchu.L1  [a];             // prefetch to uniform cache
+//! prefetch{.tensormap_space}.tensormap [a];  // prefetch the tensormap
+//! .space =                    { .global, .local };
+//! .level =                    { .L1, .L2 };
+//! .level::eviction_priority = { .L2::evict_last, .L2::evict_normal };
+//! .tensormap_space =          { .const, .param };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::prefetch::section_0::*;
+
+    impl Operands for PrefetchSpaceLevel {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+        }
+    }
+
+    impl Operands for PrefetchGlobalLevelEvictionPriority {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+        }
+    }
+
+    impl Operands for PrefetchuL1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+        }
+    }
+
+    impl Operands for PrefetchTensormapSpaceTensormap {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+        }
+    }
+}
@@ -0,0 +1,241 @@
+//! Original PTX specification:
+//!
+//! // Reduction operation with scalar type:
+//! red.op{.space}{.sem}{.scope}{.level::cache_hint}.type          [a], b{, cache-policy};
+//! red.add{.space}{.sem}{.scope}.noftz{.level::cache_hint}.f16    [a], b{, cache-policy};
+//! red.add{.space}{.sem}{.scope}.noftz{.level::cache_hint}.f16x2  [a], b{, cache-policy};
+//! red.add{.space}{.sem}{.scope}.noftz{.level::cache_hint}.bf16   [a], b{, cache-policy};
+//! red.add{.space}{.sem}{.scope}.noftz{.level::cache_hint}.bf16x2 [a], b{, cache-policy};
+//! .space =              { .global, .shared, .shared::cta, .shared::cluster};
+//! .sem =                {.relaxed, .release};
+//! .scope =              {.cta, .cluster, .gpu, .sys};
+//! .op =                 { .and, .or, .xor, .add, .inc, .dec, .min, .max };
+//! .level::cache_hint =  { .L2::cache_hint };
+//! .type =               { .b32, .b64, .u32, .u64, .s32, .s64, .f32, .f64 };
+//! ------------------------------------------------------------------
+//! // Reduction operation with vector type:
+//! red.add{.space}{.sem}{.scope}{.level::cache_hint}.vec_32_bit.f32 [a], b{, cache-policy};
+//! red.op{.space}{.sem}{.scope}.noftz{.level::cache_hint}. vec_16_bit.half_word_type [a], b{, cache-policy};
+//! red.op{.space}{.sem}{.scope}.noftz{.level::cache_hint}.vec_32_bit.packed_type [a], b {, cache-policy};
+//! .sem =                { .relaxed, .release };
+//! .scope =              { .cta, .cluster, .gpu, .sys };
+//! .op =                 { .add, .min, .max };
+//! .half_word_type =     { .f16, .bf16 };
+//! .packed_type =        { .f16x2,.bf16x2 };
+//! .vec_16_bit =         { .v2, .v4, .v8 };
+//! .vec_32_bit =         { .v2, .v4 };
+//! .level::cache_hint =  { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::red::section_0::*;
+
+    impl Operands for RedOpSpaceSemScopeLevelCacheHintType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for RedAddSpaceSemScopeNoftzLevelCacheHintF16 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for RedAddSpaceSemScopeNoftzLevelCacheHintF16x2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for RedAddSpaceSemScopeNoftzLevelCacheHintBf16 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for RedAddSpaceSemScopeNoftzLevelCacheHintBf16x2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::red::section_1::*;
+
+    impl Operands for RedAddSpaceSemScopeLevelCacheHintVec32BitF32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for RedOpSpaceSemScopeNoftzLevelCacheHintVec16BitHalfWordType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for RedOpSpaceSemScopeNoftzLevelCacheHintVec32BitPackedType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            if let Some(operand) = &self.cache_policy {
+                f(OperandRef::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            if let Some(operand) = &mut self.cache_policy {
+                f(OperandMut::general(
+                    "cache-policy",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
@@ -0,0 +1,167 @@
+//! Original PTX specification:
+//!
+//! // Increment and Decrement reductions
+//! red.async.sem.scope{.ss}.completion_mechanism.op.type [a], b, [mbar];
+//! .sem  =                 { .relaxed };
+//! .scope =                { .cluster };
+//! .ss   =                 { .shared::cluster };
+//! .op   =                 { .inc, .dec };
+//! .type =                 { .u32 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ------------------------------------------------------------------
+//! // MIN and MAX reductions
+//! red.async.sem.scope{.ss}.completion_mechanism.op.type [a], b, [mbar];
+//! .sem  = { .relaxed };
+//! .scope = { .cluster };
+//! .ss   = { .shared::cluster };
+//! .op   = { .min, .max };
+//! .type = { .u32, .s32 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ------------------------------------------------------------------
+//! // Bitwise AND, OR and XOR reductions
+//! red.async.sem.scope{.ss}.completion_mechanism.op.type [a], b, [mbar];
+//! .sem  = { .relaxed };
+//! .scope = { .cluster };
+//! .ss   = { .shared::cluster };
+//! .op   = { .and, .or, .xor };
+//! .type = { .b32 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ------------------------------------------------------------------
+//! // ADD reductions
+//! red.async.sem.scope{.ss}.completion_mechanism.add.type [a], b, [mbar];
+//! .sem  = { .relaxed };
+//! .scope = { .cluster };
+//! .ss   = { .shared::cluster };
+//! .type = { .u32, .s32, .u64 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! red.async{.mmio}.sem.scope{.ss}.add.type [a], b;
+//! .sem  = { .release };
+//! .scope = { .gpu, .cluster };
+//! .ss   = { .global };
+//! .type = { .u32, .s32, .u64, .s64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_0::*;
+
+    impl Operands for RedAsyncSemScopeSsCompletionMechanismOpType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_1::*;
+
+    impl Operands for RedAsyncSemScopeSsCompletionMechanismOpType1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_2::*;
+
+    impl Operands for RedAsyncSemScopeSsCompletionMechanismOpType2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_3::*;
+
+    impl Operands for RedAsyncSemScopeSsCompletionMechanismAddType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_4::*;
+
+    impl Operands for RedAsyncMmioSemScopeSsAddType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+}
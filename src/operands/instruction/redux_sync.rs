@@ -0,0 +1,131 @@
+//! Original PTX specification:
+//!
+//! redux.sync.op.type dst, src, membermask;
+//! .op   = {.add, .min, .max};
+//! .type = {.u32, .s32};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! redux.sync.op.b32 dst, src, membermask;
+//! .op   = {.and, .or, .xor};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! redux.sync.op{.abs}{.NaN}.f32 dst, src, membermask;
+//! .op   = { .min, .max };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::redux_sync::section_0::*;
+
+    impl Operands for ReduxSyncOpType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "dst",
+                OperandRole::Destination,
+                &self.dst,
+            ));
+            f(OperandRef::general("src", OperandRole::Source, &self.src));
+            f(OperandRef::general(
+                "membermask",
+                OperandRole::Source,
+                &self.membermask,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "dst",
+                OperandRole::Destination,
+                &mut self.dst,
+            ));
+            f(OperandMut::general(
+                "src",
+                OperandRole::Source,
+                &mut self.src,
+            ));
+            f(OperandMut::general(
+                "membermask",
+                OperandRole::Source,
+                &mut self.membermask,
+            ));
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::redux_sync::section_1::*;
+
+    impl Operands for ReduxSyncOpB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "dst",
+                OperandRole::Destination,
+                &self.dst,
+            ));
+            f(OperandRef::general("src", OperandRole::Source, &self.src));
+            f(OperandRef::general(
+                "membermask",
+                OperandRole::Source,
+                &self.membermask,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "dst",
+                OperandRole::Destination,
+                &mut self.dst,
+            ));
+            f(OperandMut::general(
+                "src",
+                OperandRole::Source,
+                &mut self.src,
+            ));
+            f(OperandMut::general(
+                "membermask",
+                OperandRole::Source,
+                &mut self.membermask,
+            ));
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::redux_sync::section_2::*;
+
+    impl Operands for ReduxSyncOpAbsNanF32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "dst",
+                OperandRole::Destination,
+                &self.dst,
+            ));
+            f(OperandRef::general("src", OperandRole::Source, &self.src));
+            f(OperandRef::general(
+                "membermask",
+                OperandRole::Source,
+                &self.membermask,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "dst",
+                OperandRole::Destination,
+                &mut self.dst,
+            ));
+            f(OperandMut::general(
+                "src",
+                OperandRole::Source,
+                &mut self.src,
+            ));
+            f(OperandMut::general(
+                "membermask",
+                OperandRole::Source,
+                &mut self.membermask,
+            ));
+        }
+    }
+}
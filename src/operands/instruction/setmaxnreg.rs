@@ -0,0 +1,30 @@
+//! Original PTX specification:
+//!
+//! setmaxnreg.action.sync.aligned.u32 imm-reg-count;
+//! .action = { .inc, .dec };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::setmaxnreg::section_0::*;
+
+    impl Operands for SetmaxnregActionSyncAlignedU32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "imm-reg-count",
+                OperandRole::Source,
+                &self.imm_reg_count,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "imm-reg-count",
+                OperandRole::Source,
+                &mut self.imm_reg_count,
+            ));
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Original PTX specification:
+//!
+//! shfl.sync.mode.b32  d{|p}, a, b, c, membermask;
+//! .mode = { .up, .down, .bfly, .idx };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::shfl_sync::section_0::*;
+
+    impl Operands for ShflSyncModeB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+            f(OperandRef::general(
+                "membermask",
+                OperandRole::Source,
+                &self.membermask,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+            f(OperandMut::general(
+                "membermask",
+                OperandRole::Source,
+                &mut self.membermask,
+            ));
+        }
+    }
+}
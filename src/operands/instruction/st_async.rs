@@ -0,0 +1,67 @@
+//! Original PTX specification:
+//!
+//! st.async{.sem}{.scope}{.ss}{.completion_mechanism}{.vec}.type [a], b, [mbar];
+//! .sem  =                 { .weak };
+//! .scope =                { .cluster };
+//! .ss   =                 { .shared::cluster };
+//! .type =                 { .b32, .b64,
+//! .u32, .u64,
+//! .s32, .s64,
+//! .f32, .f64 };
+//! .vec  =                 { .v2, .v4 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ---------------------------------------------------------
+//! st.async{.mmio}.sem.scope{.ss}.type [a], b;
+//! .sem =                  { .release };
+//! .scope =                { .gpu, .sys };
+//! .ss =                   { .global };
+//! .type =                 { .b8, .b16, .b32, .b64,
+//! .u8, .u16, .u32, .u64,
+//! .s8, .s16, .s32, .s64,
+//! .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::st_async::section_0::*;
+
+    impl Operands for StAsyncSemScopeSsCompletionMechanismVecType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::st_async::section_1::*;
+
+    impl Operands for StAsyncMmioSemScopeSsType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("b", OperandRole::Source, &self.b));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general("b", OperandRole::Source, &mut self.b));
+        }
+    }
+}
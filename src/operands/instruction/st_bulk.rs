@@ -0,0 +1,37 @@
+//! Original PTX specification:
+//!
+//! st.bulk{.weak}{.shared::cta}  [a], size, initval; // initval must be zero
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::st_bulk::section_0::*;
+
+    impl Operands for StBulkWeakSharedCta {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+            f(OperandRef::general(
+                "initval",
+                OperandRole::Source,
+                &self.initval,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+            f(OperandMut::general(
+                "initval",
+                OperandRole::Source,
+                &mut self.initval,
+            ));
+        }
+    }
+}
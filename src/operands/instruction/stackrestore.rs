@@ -0,0 +1,22 @@
+//! Original PTX specification:
+//!
+//! stackrestore.type  a;
+//! .type = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::stackrestore::section_0::*;
+
+    impl Operands for StackrestoreType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+        }
+    }
+}
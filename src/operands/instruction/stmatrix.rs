@@ -0,0 +1,27 @@
+//! Original PTX specification:
+//!
+//! stmatrix.sync.aligned.shape.num{.trans}{.ss}.type [p], r;
+//! .shape  = {.m8n8, .m16n8};
+//! .num    = {.x1, .x2, .x4};
+//! .ss     = {.shared, .shared::cta};
+//! .type   = {.b16, .b8};
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::stmatrix::section_0::*;
+
+    impl Operands for StmatrixSyncAlignedShapeNumTransSsType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            f(OperandRef::general("r", OperandRole::Source, &self.r));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            f(OperandMut::general("r", OperandRole::Source, &mut self.r));
+        }
+    }
+}
@@ -0,0 +1,37 @@
+//! Original PTX specification:
+//!
+//! suld.b.geom{.cop}.vec.dtype{.mode}  d, [a, b];  // unformatted
+//!
+//! .geom  = { .1d, .2d, .3d, .a1d, .a2d };
+//! .cop   = { .ca, .cg, .cs, .cv };               // cache operation
+//! .vec   = { none, .v2, .v4 };
+//! .dtype = { .b8 , .b16, .b32, .b64 };
+//! .mode = { .trap, .clamp, .zero };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::suld::section_0::*;
+
+    impl Operands for SuldBGeomCopVecDtypeMode {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            let [first, second] = &self.a.operands;
+            f(OperandRef::general("a", OperandRole::Source, first));
+            f(OperandRef::general("b", OperandRole::Source, second));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            let [first, second] = &mut self.a.operands;
+            f(OperandMut::general("a", OperandRole::Source, first));
+            f(OperandMut::general("b", OperandRole::Source, second));
+        }
+    }
+}
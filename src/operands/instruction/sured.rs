@@ -0,0 +1,57 @@
+//! Original PTX specification:
+//!
+//! sured.b.op.geom.ctype.mode [a,b],c; // byte addressing
+//! .op    = { .add, .min, .max, .and, .or };
+//! .geom  = { .1d, .2d, .3d };
+//! .ctype = { .u32, .u64, .s32, .b32, .s64 };  // for sured.b
+//! .mode  = { .trap, .clamp, .zero };
+//! ----------------------------------------------------
+//! sured.p.op.geom.ctype.mode [a,b],c; // sample addressing
+//! .op    = { .add, .min, .max, .and, .or };
+//! .geom  = { .1d, .2d, .3d };
+//! .ctype = { .b32, .b64 };                    // for sured.p
+//! .mode  = { .trap, .clamp, .zero };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::sured::section_0::*;
+
+    impl Operands for SuredBOpGeomCtypeMode {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            let [first, second] = &self.a.operands;
+            f(OperandRef::general("a", OperandRole::Source, first));
+            f(OperandRef::general("b", OperandRole::Source, second));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            let [first, second] = &mut self.a.operands;
+            f(OperandMut::general("a", OperandRole::Source, first));
+            f(OperandMut::general("b", OperandRole::Source, second));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::sured::section_1::*;
+
+    impl Operands for SuredPOpGeomCtypeMode {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            let [first, second] = &self.a.operands;
+            f(OperandRef::general("a", OperandRole::Source, first));
+            f(OperandRef::general("b", OperandRole::Source, second));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            let [first, second] = &mut self.a.operands;
+            f(OperandMut::general("a", OperandRole::Source, first));
+            f(OperandMut::general("b", OperandRole::Source, second));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Original PTX specification:
+//!
+//! sust.b.dim{.cop}.vec.ctype{.mode} [a, b], c;  // unformatted
+//! sust.p.dim.vec.b32{.mode}       [a, b], c;  // formatted
+//! sust.b.adim{.cop}.vec.ctype{.mode}   [a, b], c;  // unformatted
+//! .cop   = { .wb, .cg, .cs, .wt };                     // cache operation
+//! .vec   = { none, .v2, .v4 };
+//! .ctype = { .b8 , .b16, .b32, .b64 };
+//! .mode  = { .trap, .clamp, .zero };
+//! .dim   = { .1d, .2d, .3d };
+//! .adim  = { .a1d, .a2d };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::sust::section_0::*;
+
+    impl Operands for SustBDimCopVecCtypeMode {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            let [first, second] = &self.a.operands;
+            f(OperandRef::general("a", OperandRole::Source, first));
+            f(OperandRef::general("b", OperandRole::Source, second));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            let [first, second] = &mut self.a.operands;
+            f(OperandMut::general("a", OperandRole::Source, first));
+            f(OperandMut::general("b", OperandRole::Source, second));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+
+    impl Operands for SustPDimVecB32Mode {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            let [first, second] = &self.a.operands;
+            f(OperandRef::general("a", OperandRole::Source, first));
+            f(OperandRef::general("b", OperandRole::Source, second));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            let [first, second] = &mut self.a.operands;
+            f(OperandMut::general("a", OperandRole::Source, first));
+            f(OperandMut::general("b", OperandRole::Source, second));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+
+    impl Operands for SustBAdimCopVecCtypeMode {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            let [first, second] = &self.a.operands;
+            f(OperandRef::general("a", OperandRole::Source, first));
+            f(OperandRef::general("b", OperandRole::Source, second));
+            f(OperandRef::general("c", OperandRole::Source, &self.c));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            let [first, second] = &mut self.a.operands;
+            f(OperandMut::general("a", OperandRole::Source, first));
+            f(OperandMut::general("b", OperandRole::Source, second));
+            f(OperandMut::general("c", OperandRole::Source, &mut self.c));
+        }
+    }
+}
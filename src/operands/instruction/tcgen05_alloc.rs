@@ -0,0 +1,70 @@
+//! Original PTX specification:
+//!
+//! tcgen05.alloc.cta_group.sync.aligned{.shared::cta}.b32  [dst], nCols;
+//! tcgen05.dealloc.cta_group.sync.aligned.b32              taddr, nCols;
+//! tcgen05.relinquish_alloc_permit.cta_group.sync.aligned;
+//! .cta_group = { .cta_group::1, .cta_group::2 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_alloc::section_0::*;
+
+    impl Operands for Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("dst", OperandRole::Address, &self.dst));
+            f(OperandRef::general(
+                "nCols",
+                OperandRole::Source,
+                &self.ncols,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dst",
+                OperandRole::Address,
+                &mut self.dst,
+            ));
+            f(OperandMut::general(
+                "nCols",
+                OperandRole::Source,
+                &mut self.ncols,
+            ));
+        }
+    }
+
+    impl Operands for Tcgen05DeallocCtaGroupSyncAlignedB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general(
+                "taddr",
+                OperandRole::Source,
+                &self.taddr,
+            ));
+            f(OperandRef::general(
+                "nCols",
+                OperandRole::Source,
+                &self.ncols,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "taddr",
+                OperandRole::Source,
+                &mut self.taddr,
+            ));
+            f(OperandMut::general(
+                "nCols",
+                OperandRole::Source,
+                &mut self.ncols,
+            ));
+        }
+    }
+
+    impl Operands for Tcgen05RelinquishAllocPermitCtaGroupSyncAligned {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+}
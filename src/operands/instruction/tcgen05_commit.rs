@@ -0,0 +1,39 @@
+//! Original PTX specification:
+//!
+//! tcgen05.commit.cta_group.completion_mechanism{.shared::cluster}{.multicast}.b64
+//! [mbar] {, ctaMask};
+//! .completion_mechanism = { .mbarrier::arrive::one };
+//! .cta_group            = { .cta_group::1, .cta_group::2 };
+//! .multicast            = { .multicast::cluster };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_commit::section_0::*;
+
+    impl Operands for Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "mbar",
+                OperandRole::Address,
+                &self.mbar,
+            ));
+            if let Some(operand) = &self.ctamask {
+                f(OperandRef::general("ctaMask", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "mbar",
+                OperandRole::Address,
+                &mut self.mbar,
+            ));
+            if let Some(operand) = &mut self.ctamask {
+                f(OperandMut::general("ctaMask", OperandRole::Source, operand));
+            }
+        }
+    }
+}
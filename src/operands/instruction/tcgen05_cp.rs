@@ -0,0 +1,43 @@
+//! Original PTX specification:
+//!
+//! tcgen05.cp.cta_group.shape{.multicast}{.dst_src_fmt} [taddr], s-desc;
+//! .cta_group = { .cta_group::1, .cta_group::2 };
+//! .dst_src_fmt   = { .b8x16.b6x16_p32, .b8x16.b4x16_p64 };
+//! .shape     = { .128x256b, .4x256b, .128x128b, .64x128b**, .32x128b*** };
+//! .multicast = { .warpx2::02_13** , .warpx2::01_23**, .warpx4*** };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_cp::section_0::*;
+
+    impl Operands for Tcgen05CpCtaGroupShapeMulticastDstSrcFmt {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "taddr",
+                OperandRole::Address,
+                &self.taddr,
+            ));
+            f(OperandRef::general(
+                "s-desc",
+                OperandRole::Source,
+                &self.s_desc,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "taddr",
+                OperandRole::Address,
+                &mut self.taddr,
+            ));
+            f(OperandMut::general(
+                "s-desc",
+                OperandRole::Source,
+                &mut self.s_desc,
+            ));
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! Original PTX specification:
+//!
+//! tcgen05.fence::before_thread_sync ;
+//! tcgen05.fence::after_thread_sync  ;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_fence::section_0::*;
+
+    impl Operands for Tcgen05FenceBeforeThreadSync {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+
+    impl Operands for Tcgen05FenceAfterThreadSync {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+}
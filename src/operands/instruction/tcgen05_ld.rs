@@ -0,0 +1,236 @@
+//! Original PTX specification:
+//!
+//! // Base load instruction:
+//! tcgen05.ld.sync.aligned.shape1.num{.pack}.b32    r, [taddr];
+//! tcgen05.ld.sync.aligned.shape2.num{.pack}.b32    r, [taddr], immHalfSplitoff;
+//! .shape1 = { .16x64b, .16x128b, .16x256b, .32x32b };
+//! .shape2 = { .16x32bx2 };
+//! .num    = { .x1, .x2, .x4, .x8, .x16, .x32, .x64, .x128 };
+//! .pack   = { .pack::16b };
+//! // Floating point type load along with reduction :
+//! tcgen05.ld.red.sync.aligned.shape3.num.redOp{.abs}{.NaN}.f32 r, redval, [taddr];
+//! tcgen05.ld.red.sync.aligned.shape4.num.redOp{.abs}{.NaN}.f32 r, redval, [taddr], immHalfSplitoff;
+//! // Integer type load along with reduction :
+//! tcgen05.ld.red.sync.aligned.shape3.num.redOp.type r, redval, [taddr];
+//! tcgen05.ld.red.sync.aligned.shape4.num.redOp.type r, redval, [taddr], immHalfSplitoff;
+//! .shape3 = { .32x32b   };
+//! .shape4 = { .16x32bx2 };
+//! .redOp  = { .min, .max };
+//! .type   = { .u32, .s32 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_ld::section_0::*;
+
+    impl Operands for Tcgen05LdSyncAlignedShape1NumPackB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address(
+                "taddr",
+                OperandRole::Address,
+                &self.taddr,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address(
+                "taddr",
+                OperandRole::Address,
+                &mut self.taddr,
+            ));
+        }
+    }
+
+    impl Operands for Tcgen05LdSyncAlignedShape2NumPackB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address(
+                "taddr",
+                OperandRole::Address,
+                &self.taddr,
+            ));
+            f(OperandRef::general(
+                "immHalfSplitoff",
+                OperandRole::Source,
+                &self.immhalfsplitoff,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address(
+                "taddr",
+                OperandRole::Address,
+                &mut self.taddr,
+            ));
+            f(OperandMut::general(
+                "immHalfSplitoff",
+                OperandRole::Source,
+                &mut self.immhalfsplitoff,
+            ));
+        }
+    }
+
+    impl Operands for Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::general(
+                "redval",
+                OperandRole::Source,
+                &self.redval,
+            ));
+            f(OperandRef::address(
+                "taddr",
+                OperandRole::Address,
+                &self.taddr,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::general(
+                "redval",
+                OperandRole::Source,
+                &mut self.redval,
+            ));
+            f(OperandMut::address(
+                "taddr",
+                OperandRole::Address,
+                &mut self.taddr,
+            ));
+        }
+    }
+
+    impl Operands for Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::general(
+                "redval",
+                OperandRole::Source,
+                &self.redval,
+            ));
+            f(OperandRef::address(
+                "taddr",
+                OperandRole::Address,
+                &self.taddr,
+            ));
+            f(OperandRef::general(
+                "immHalfSplitoff",
+                OperandRole::Source,
+                &self.immhalfsplitoff,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::general(
+                "redval",
+                OperandRole::Source,
+                &mut self.redval,
+            ));
+            f(OperandMut::address(
+                "taddr",
+                OperandRole::Address,
+                &mut self.taddr,
+            ));
+            f(OperandMut::general(
+                "immHalfSplitoff",
+                OperandRole::Source,
+                &mut self.immhalfsplitoff,
+            ));
+        }
+    }
+
+    impl Operands for Tcgen05LdRedSyncAlignedShape3NumRedopType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::general(
+                "redval",
+                OperandRole::Source,
+                &self.redval,
+            ));
+            f(OperandRef::address(
+                "taddr",
+                OperandRole::Address,
+                &self.taddr,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::general(
+                "redval",
+                OperandRole::Source,
+                &mut self.redval,
+            ));
+            f(OperandMut::address(
+                "taddr",
+                OperandRole::Address,
+                &mut self.taddr,
+            ));
+        }
+    }
+
+    impl Operands for Tcgen05LdRedSyncAlignedShape4NumRedopType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::general(
+                "redval",
+                OperandRole::Source,
+                &self.redval,
+            ));
+            f(OperandRef::address(
+                "taddr",
+                OperandRole::Address,
+                &self.taddr,
+            ));
+            f(OperandRef::general(
+                "immHalfSplitoff",
+                OperandRole::Source,
+                &self.immhalfsplitoff,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::general(
+                "redval",
+                OperandRole::Source,
+                &mut self.redval,
+            ));
+            f(OperandMut::address(
+                "taddr",
+                OperandRole::Address,
+                &mut self.taddr,
+            ));
+            f(OperandMut::general(
+                "immHalfSplitoff",
+                OperandRole::Source,
+                &mut self.immhalfsplitoff,
+            ));
+        }
+    }
+}
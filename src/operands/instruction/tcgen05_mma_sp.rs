@@ -0,0 +1,1310 @@
+//! Original PTX specification:
+//!
+//! // 1. Floating-point type without block scaling:
+//! tcgen05.mma.sp.cta_group.kind  [d-tmem],  a-desc,  b-desc, [sp-meta-tmem] ,  idesc {, disable-output-lane }, enable-input-d{, scale-input-d};
+//! tcgen05.mma.sp.cta_group.kind  [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d{, scale-input-d};
+//! .kind       = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! .cta_group  = { .cta_group::1,  .cta_group::2 };
+//! ------------------------------------------------------------------
+//! // 2. Floating-point type with block scaling:
+//! tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize} [d-tmem],  a-desc,  b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize} [d-tmem], [a-tmem], b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! .scale_vectorsize = { .scale_vec::1X, .scale_vec::2X, .scale_vec::4X, .block16, .block32 };
+//! .cta_group      = { .cta_group::1,  .cta_group::2 };
+//! .kind = { .kind::mxf8f6f4, .kind::mxf4, .kind::mxf4nvf4 };
+//! ------------------------------------------------------------------
+//! // 3. Convolution MMA with floating-point type without block scaling:
+//! tcgen05.mma.sp.cta_group.kind.collector_usage           [d-tmem],  a-desc,  b-desc, [sp-meta-tmem] ,  idesc {, disable-output-lane }, enable-input-d
+//! {, scale-input-d};
+//! tcgen05.mma.sp.cta_group.kind.ashift{.collector_usage}  [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d
+//! {, scale-input-d};
+//! tcgen05.mma.sp.cta_group.kind{.ashift}.collector_usage  [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d
+//! {, scale-input-d};
+//! .kind            = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+//! ------------------------------------------------------------------
+//! // 4. Activation Stationary MMA with floating-point type with block scaling:
+//! tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage [d-tmem],  a-desc,  b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage [d-tmem], [a-tmem], b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! .kind = { .kind::mxf8f6f4, .kind::mxf4, .kind::mxf4nvf4 };
+//! .scale_vectorsize = { .scale_vec::1X, .scale_vec::2X, .scale_vec::4X, .block16, .block32 };
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+//! ------------------------------------------------------------------
+//! // 5. Integer type:
+//! tcgen05.mma.sp.cta_group.kind::i8 [d-tmem],  a-desc,  b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind::i8 [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d;
+//! .cta_group      = { .cta_group::1,  .cta_group::2 };
+//! ------------------------------------------------------------------
+//! // 6. Convolution MMA with Integer type:
+//! tcgen05.mma.sp.cta_group.kind::i8.collector_usage          [d-tmem],  a-desc,  b-desc, [sp-meta-tmem], idesc {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind::i8.ashift{.collector_usage} [d-tmem], [a-tmem], b-desc, [sp-meta-tmem], idesc {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind::i8{.ashift}.collector_usage [d-tmem], [a-tmem], b-desc, [sp-meta-tmem], idesc {, disable-output-lane }, enable-input-d;
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_0::*;
+
+    impl Operands for Tcgen05MmaSpCtaGroupKind {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            if let Some(operand) = &self.disable_output_lane {
+                f(OperandRef::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+            if let Some(operand) = &self.scale_input_d {
+                f(OperandRef::general(
+                    "scale-input-d",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            if let Some(operand) = &mut self.disable_output_lane {
+                f(OperandMut::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+            if let Some(operand) = &mut self.scale_input_d {
+                f(OperandMut::general(
+                    "scale-input-d",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for Tcgen05MmaSpCtaGroupKind1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::address(
+                "a-tmem",
+                OperandRole::Address,
+                &self.a_tmem,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            if let Some(operand) = &self.disable_output_lane {
+                f(OperandRef::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+            if let Some(operand) = &self.scale_input_d {
+                f(OperandRef::general(
+                    "scale-input-d",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::address(
+                "a-tmem",
+                OperandRole::Address,
+                &mut self.a_tmem,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            if let Some(operand) = &mut self.disable_output_lane {
+                f(OperandMut::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+            if let Some(operand) = &mut self.scale_input_d {
+                f(OperandMut::general(
+                    "scale-input-d",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_1::*;
+
+    impl Operands for Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            f(OperandRef::address(
+                "scale-A-tmem",
+                OperandRole::Address,
+                &self.scale_a_tmem,
+            ));
+            f(OperandRef::address(
+                "scale-B-tmem",
+                OperandRole::Address,
+                &self.scale_b_tmem,
+            ));
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            f(OperandMut::address(
+                "scale-A-tmem",
+                OperandRole::Address,
+                &mut self.scale_a_tmem,
+            ));
+            f(OperandMut::address(
+                "scale-B-tmem",
+                OperandRole::Address,
+                &mut self.scale_b_tmem,
+            ));
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+        }
+    }
+
+    impl Operands for Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::address(
+                "a-tmem",
+                OperandRole::Address,
+                &self.a_tmem,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            f(OperandRef::address(
+                "scale-A-tmem",
+                OperandRole::Address,
+                &self.scale_a_tmem,
+            ));
+            f(OperandRef::address(
+                "scale-B-tmem",
+                OperandRole::Address,
+                &self.scale_b_tmem,
+            ));
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::address(
+                "a-tmem",
+                OperandRole::Address,
+                &mut self.a_tmem,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            f(OperandMut::address(
+                "scale-A-tmem",
+                OperandRole::Address,
+                &mut self.scale_a_tmem,
+            ));
+            f(OperandMut::address(
+                "scale-B-tmem",
+                OperandRole::Address,
+                &mut self.scale_b_tmem,
+            ));
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_2::*;
+
+    impl Operands for Tcgen05MmaSpCtaGroupKindCollectorUsage {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            if let Some(operand) = &self.disable_output_lane {
+                f(OperandRef::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+            if let Some(operand) = &self.scale_input_d {
+                f(OperandRef::general(
+                    "scale-input-d",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            if let Some(operand) = &mut self.disable_output_lane {
+                f(OperandMut::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+            if let Some(operand) = &mut self.scale_input_d {
+                f(OperandMut::general(
+                    "scale-input-d",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::address(
+                "a-tmem",
+                OperandRole::Address,
+                &self.a_tmem,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            if let Some(operand) = &self.disable_output_lane {
+                f(OperandRef::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+            if let Some(operand) = &self.scale_input_d {
+                f(OperandRef::general(
+                    "scale-input-d",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::address(
+                "a-tmem",
+                OperandRole::Address,
+                &mut self.a_tmem,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            if let Some(operand) = &mut self.disable_output_lane {
+                f(OperandMut::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+            if let Some(operand) = &mut self.scale_input_d {
+                f(OperandMut::general(
+                    "scale-input-d",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::address(
+                "a-tmem",
+                OperandRole::Address,
+                &self.a_tmem,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            if let Some(operand) = &self.disable_output_lane {
+                f(OperandRef::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+            if let Some(operand) = &self.scale_input_d {
+                f(OperandRef::general(
+                    "scale-input-d",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::address(
+                "a-tmem",
+                OperandRole::Address,
+                &mut self.a_tmem,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            if let Some(operand) = &mut self.disable_output_lane {
+                f(OperandMut::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+            if let Some(operand) = &mut self.scale_input_d {
+                f(OperandMut::general(
+                    "scale-input-d",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_3::*;
+
+    impl Operands for Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            f(OperandRef::address(
+                "scale-A-tmem",
+                OperandRole::Address,
+                &self.scale_a_tmem,
+            ));
+            f(OperandRef::address(
+                "scale-B-tmem",
+                OperandRole::Address,
+                &self.scale_b_tmem,
+            ));
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            f(OperandMut::address(
+                "scale-A-tmem",
+                OperandRole::Address,
+                &mut self.scale_a_tmem,
+            ));
+            f(OperandMut::address(
+                "scale-B-tmem",
+                OperandRole::Address,
+                &mut self.scale_b_tmem,
+            ));
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+        }
+    }
+
+    impl Operands for Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::address(
+                "a-tmem",
+                OperandRole::Address,
+                &self.a_tmem,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            f(OperandRef::address(
+                "scale-A-tmem",
+                OperandRole::Address,
+                &self.scale_a_tmem,
+            ));
+            f(OperandRef::address(
+                "scale-B-tmem",
+                OperandRole::Address,
+                &self.scale_b_tmem,
+            ));
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::address(
+                "a-tmem",
+                OperandRole::Address,
+                &mut self.a_tmem,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            f(OperandMut::address(
+                "scale-A-tmem",
+                OperandRole::Address,
+                &mut self.scale_a_tmem,
+            ));
+            f(OperandMut::address(
+                "scale-B-tmem",
+                OperandRole::Address,
+                &mut self.scale_b_tmem,
+            ));
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_4::*;
+
+    impl Operands for Tcgen05MmaSpCtaGroupKindI8 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            if let Some(operand) = &self.disable_output_lane {
+                f(OperandRef::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            if let Some(operand) = &mut self.disable_output_lane {
+                f(OperandMut::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+        }
+    }
+
+    impl Operands for Tcgen05MmaSpCtaGroupKindI81 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::address(
+                "a-tmem",
+                OperandRole::Address,
+                &self.a_tmem,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            if let Some(operand) = &self.disable_output_lane {
+                f(OperandRef::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::address(
+                "a-tmem",
+                OperandRole::Address,
+                &mut self.a_tmem,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            if let Some(operand) = &mut self.disable_output_lane {
+                f(OperandMut::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_5::*;
+
+    impl Operands for Tcgen05MmaSpCtaGroupKindI8CollectorUsage {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            if let Some(operand) = &self.disable_output_lane {
+                f(OperandRef::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            if let Some(operand) = &mut self.disable_output_lane {
+                f(OperandMut::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+        }
+    }
+
+    impl Operands for Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::address(
+                "a-tmem",
+                OperandRole::Address,
+                &self.a_tmem,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            if let Some(operand) = &self.disable_output_lane {
+                f(OperandRef::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::address(
+                "a-tmem",
+                OperandRole::Address,
+                &mut self.a_tmem,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            if let Some(operand) = &mut self.disable_output_lane {
+                f(OperandMut::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+        }
+    }
+
+    impl Operands for Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::address(
+                "a-tmem",
+                OperandRole::Address,
+                &self.a_tmem,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &self.sp_meta_tmem,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            if let Some(operand) = &self.disable_output_lane {
+                f(OperandRef::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::address(
+                "a-tmem",
+                OperandRole::Address,
+                &mut self.a_tmem,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::address(
+                "sp-meta-tmem",
+                OperandRole::Address,
+                &mut self.sp_meta_tmem,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            if let Some(operand) = &mut self.disable_output_lane {
+                f(OperandMut::general(
+                    "disable-output-lane",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+        }
+    }
+}
@@ -0,0 +1,315 @@
+//! Original PTX specification:
+//!
+//! // 1. Floating-point type without block scaling:
+//! tcgen05.mma.ws.cta_group::1.kind{.collector_usage}    [d-tmem],  a-desc,  b-desc,  idesc,
+//! enable-input-d {, zero-column-mask-desc };
+//! tcgen05.mma.ws.cta_group::1.kind{.collector_usage}    [d-tmem], [a-tmem], b-desc, idesc,
+//! enable-input-d {, zero-column-mask-desc };
+//! .kind = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! ----------------------------------------------------------------------------------
+//! // 2. Integer type:
+//! tcgen05.mma.ws.cta_group::1.kind::i8{.collector_usage} [d-tmem],  a-desc,  b-desc, idesc,
+//! enable-input-d {, zero-column-mask-desc};
+//! tcgen05.mma.ws.cta_group::1.kind::i8{.collector_usage} [d-tmem], [a-tmem], b-desc, idesc,
+//! enable-input-d {, zero-column-mask-desc};
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer = { ::b0, ::b1, ::b2, ::b3 };
+//! ::op   = { ::fill, ::use, ::lastuse, ::discard};
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_ws::section_0::*;
+
+    impl Operands for Tcgen05MmaWsCtaGroup1KindCollectorUsage {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+            if let Some(operand) = &self.zero_column_mask_desc {
+                f(OperandRef::general(
+                    "zero-column-mask-desc",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+            if let Some(operand) = &mut self.zero_column_mask_desc {
+                f(OperandMut::general(
+                    "zero-column-mask-desc",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for Tcgen05MmaWsCtaGroup1KindCollectorUsage1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::address(
+                "a-tmem",
+                OperandRole::Address,
+                &self.a_tmem,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+            if let Some(operand) = &self.zero_column_mask_desc {
+                f(OperandRef::general(
+                    "zero-column-mask-desc",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::address(
+                "a-tmem",
+                OperandRole::Address,
+                &mut self.a_tmem,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+            if let Some(operand) = &mut self.zero_column_mask_desc {
+                f(OperandMut::general(
+                    "zero-column-mask-desc",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_ws::section_1::*;
+
+    impl Operands for Tcgen05MmaWsCtaGroup1KindI8CollectorUsage {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+            if let Some(operand) = &self.zero_column_mask_desc {
+                f(OperandRef::general(
+                    "zero-column-mask-desc",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+            if let Some(operand) = &mut self.zero_column_mask_desc {
+                f(OperandMut::general(
+                    "zero-column-mask-desc",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+
+    impl Operands for Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "d-tmem",
+                OperandRole::Address,
+                &self.d_tmem,
+            ));
+            f(OperandRef::address(
+                "a-tmem",
+                OperandRole::Address,
+                &self.a_tmem,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "idesc",
+                OperandRole::Source,
+                &self.idesc,
+            ));
+            f(OperandRef::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &self.enable_input_d,
+            ));
+            if let Some(operand) = &self.zero_column_mask_desc {
+                f(OperandRef::general(
+                    "zero-column-mask-desc",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "d-tmem",
+                OperandRole::Address,
+                &mut self.d_tmem,
+            ));
+            f(OperandMut::address(
+                "a-tmem",
+                OperandRole::Address,
+                &mut self.a_tmem,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "idesc",
+                OperandRole::Source,
+                &mut self.idesc,
+            ));
+            f(OperandMut::general(
+                "enable-input-d",
+                OperandRole::Source,
+                &mut self.enable_input_d,
+            ));
+            if let Some(operand) = &mut self.zero_column_mask_desc {
+                f(OperandMut::general(
+                    "zero-column-mask-desc",
+                    OperandRole::Source,
+                    operand,
+                ));
+            }
+        }
+    }
+}
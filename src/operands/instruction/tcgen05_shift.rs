@@ -0,0 +1,30 @@
+//! Original PTX specification:
+//!
+//! tcgen05.shift.cta_group.down  [taddr];
+//! .cta_group = { .cta_group::1, .cta_group::2 }
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_shift::section_0::*;
+
+    impl Operands for Tcgen05ShiftCtaGroupDown {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "taddr",
+                OperandRole::Address,
+                &self.taddr,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "taddr",
+                OperandRole::Address,
+                &mut self.taddr,
+            ));
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Original PTX specification:
+//!
+//! tcgen05.st.sync.aligned.shape1.num{.unpack}.b32    [taddr], r;
+//! tcgen05.st.sync.aligned.shape2.num{.unpack}.b32    [taddr], immHalfSplitoff, r;
+//! .shape1 = { .16x64b, .16x128b, .16x256b, .32x32b };
+//! .shape2 = { .16x32bx2 };
+//! .num    = { .x1, .x2, .x4, .x8, .x16, .x32, .x64, .x128 };
+//! .unpack = { .unpack::16b };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_st::section_0::*;
+
+    impl Operands for Tcgen05StSyncAlignedShape1NumUnpackB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "taddr",
+                OperandRole::Address,
+                &self.taddr,
+            ));
+            f(OperandRef::general("r", OperandRole::Source, &self.r));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "taddr",
+                OperandRole::Address,
+                &mut self.taddr,
+            ));
+            f(OperandMut::general("r", OperandRole::Source, &mut self.r));
+        }
+    }
+
+    impl Operands for Tcgen05StSyncAlignedShape2NumUnpackB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "taddr",
+                OperandRole::Address,
+                &self.taddr,
+            ));
+            f(OperandRef::general(
+                "immHalfSplitoff",
+                OperandRole::Source,
+                &self.immhalfsplitoff,
+            ));
+            f(OperandRef::general("r", OperandRole::Source, &self.r));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "taddr",
+                OperandRole::Address,
+                &mut self.taddr,
+            ));
+            f(OperandMut::general(
+                "immHalfSplitoff",
+                OperandRole::Source,
+                &mut self.immhalfsplitoff,
+            ));
+            f(OperandMut::general("r", OperandRole::Source, &mut self.r));
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Original PTX specification:
+//!
+//! tcgen05.wait_operation.sync.aligned;
+//! .wait_operation = { .wait::ld, .wait::st }
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_wait::section_0::*;
+
+    impl Operands for Tcgen05WaitOperationSyncAligned {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+}
@@ -0,0 +1,41 @@
+//! Original PTX specification:
+//!
+//! tensormap.cp_fenceproxy.cp_qualifiers.fence_qualifiers.sync.aligned  [dst], [src], size;
+//! .cp_qualifiers    = { .global.shared::cta };
+//! .fence_qualifiers = { .to_proxy::from_proxy.release.scope };
+//! .to_proxy::from_proxy  = { .tensormap::generic };
+//! .scope            = { .cta, .cluster, .gpu , .sys };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tensormap_cp_fenceproxy::section_0::*;
+
+    impl Operands for TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("dst", OperandRole::Address, &self.dst));
+            f(OperandRef::address("src", OperandRole::Address, &self.src));
+            f(OperandRef::general("size", OperandRole::Source, &self.size));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "dst",
+                OperandRole::Address,
+                &mut self.dst,
+            ));
+            f(OperandMut::address(
+                "src",
+                OperandRole::Address,
+                &mut self.src,
+            ));
+            f(OperandMut::general(
+                "size",
+                OperandRole::Source,
+                &mut self.size,
+            ));
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Original PTX specification:
+//!
+//! tensormap.replace.mode.field1{.ss}.b1024.type  [addr], new_val;
+//! tensormap.replace.mode.field2{.ss}.b1024.type  [addr], ord, new_val;
+//! tensormap.replace.mode.field3{.ss}.b1024.type  [addr], new_val;
+//! .mode    = { .tile };
+//! .field1  = { .global_address, .rank };
+//! .field2  = { .box_dim, .global_dim, .global_stride, .element_stride  };
+//! .field3  = { .elemtype,  .interleave_layout, .swizzle_mode, .swizzle_atomicity, .fill_mode };
+//! .ss      = { .global, .shared::cta };
+//! .type    = { .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tensormap_replace::section_0::*;
+
+    impl Operands for TensormapReplaceModeField1SsB1024Type {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "new_val",
+                OperandRole::Source,
+                &self.new_val,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "new_val",
+                OperandRole::Source,
+                &mut self.new_val,
+            ));
+        }
+    }
+
+    impl Operands for TensormapReplaceModeField2SsB1024Type {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general("ord", OperandRole::Source, &self.ord));
+            f(OperandRef::general(
+                "new_val",
+                OperandRole::Source,
+                &self.new_val,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "ord",
+                OperandRole::Source,
+                &mut self.ord,
+            ));
+            f(OperandMut::general(
+                "new_val",
+                OperandRole::Source,
+                &mut self.new_val,
+            ));
+        }
+    }
+
+    impl Operands for TensormapReplaceModeField3SsB1024Type {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address(
+                "addr",
+                OperandRole::Address,
+                &self.addr,
+            ));
+            f(OperandRef::general(
+                "new_val",
+                OperandRole::Source,
+                &self.new_val,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address(
+                "addr",
+                OperandRole::Address,
+                &mut self.addr,
+            ));
+            f(OperandMut::general(
+                "new_val",
+                OperandRole::Source,
+                &mut self.new_val,
+            ));
+        }
+    }
+}
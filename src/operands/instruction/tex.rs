@@ -0,0 +1,600 @@
+//! Original PTX specification:
+//!
+//! tex.geom.v4.dtype.ctype  d{|p}, [a, c] {, e} {, f};
+//! tex.geom.v4.dtype.ctype  d{|p}, [a, b, c] {, e} {, f};  // explicit sampler
+//! tex.geom.v2.f16x2.ctype  d{|p}, [a, c] {, e} {, f};
+//! tex.geom.v2.f16x2.ctype  d{|p}, [a, b, c] {, e} {, f};  // explicit sampler
+//! // mipmaps
+//! tex.base.geom.v4.dtype.ctype   d{|p}, [a, {b,} c] {, e} {, f};
+//! tex.level.geom.v4.dtype.ctype  d{|p}, [a, {b,} c], lod {, e} {, f};
+//! tex.grad.geom.v4.dtype.ctype   d{|p}, [a, {b,} c], dPdx, dPdy {, e} {, f};
+//! tex.base.geom.v2.f16x2.ctype   d{|p}, [a, {b,} c] {, e} {, f};
+//! tex.level.geom.v2.f16x2.ctype  d{|p}, [a, {b,} c], lod {, e} {, f};
+//! tex.grad.geom.v2.f16x2.ctype   d{|p}, [a, {b,} c], dPdx, dPdy {, e} {, f};
+//! .geom  = { .1d, .2d, .3d, .a1d, .a2d, .cube, .acube, .2dms, .a2dms };
+//! .dtype = { .u32, .s32, .f16,  .f32 };
+//! .ctype = {       .s32, .f32 };          // .cube, .acube require .f32
+//! // .2dms, .a2dms require .s32
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tex::section_0::*;
+
+    impl Operands for TexGeomV4DtypeCtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            let [first, second] = &self.a.operands;
+            f(OperandRef::general("a", OperandRole::Source, first));
+            f(OperandRef::general("c", OperandRole::Source, second));
+            if let Some(operand) = &self.e {
+                f(OperandRef::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.f {
+                f(OperandRef::general("f", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            let [first, second] = &mut self.a.operands;
+            f(OperandMut::general("a", OperandRole::Source, first));
+            f(OperandMut::general("c", OperandRole::Source, second));
+            if let Some(operand) = &mut self.e {
+                f(OperandMut::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.f {
+                f(OperandMut::general("f", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for TexGeomV4DtypeCtype1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandRef::general(
+                "a",
+                OperandRole::Source,
+                &self.a.handle,
+            ));
+            f(OperandRef::general(
+                "b",
+                OperandRole::Source,
+                &self.a.sampler,
+            ));
+            f(OperandRef::general(
+                "c",
+                OperandRole::Source,
+                &self.a.coords,
+            ));
+            if let Some(operand) = &self.e {
+                f(OperandRef::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.f {
+                f(OperandRef::general("f", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandMut::general(
+                "a",
+                OperandRole::Source,
+                &mut self.a.handle,
+            ));
+            f(OperandMut::general(
+                "b",
+                OperandRole::Source,
+                &mut self.a.sampler,
+            ));
+            f(OperandMut::general(
+                "c",
+                OperandRole::Source,
+                &mut self.a.coords,
+            ));
+            if let Some(operand) = &mut self.e {
+                f(OperandMut::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.f {
+                f(OperandMut::general("f", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for TexGeomV2F16x2Ctype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            let [first, second] = &self.a.operands;
+            f(OperandRef::general("a", OperandRole::Source, first));
+            f(OperandRef::general("c", OperandRole::Source, second));
+            if let Some(operand) = &self.e {
+                f(OperandRef::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.f {
+                f(OperandRef::general("f", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            let [first, second] = &mut self.a.operands;
+            f(OperandMut::general("a", OperandRole::Source, first));
+            f(OperandMut::general("c", OperandRole::Source, second));
+            if let Some(operand) = &mut self.e {
+                f(OperandMut::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.f {
+                f(OperandMut::general("f", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for TexGeomV2F16x2Ctype1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandRef::general(
+                "a",
+                OperandRole::Source,
+                &self.a.handle,
+            ));
+            f(OperandRef::general(
+                "b",
+                OperandRole::Source,
+                &self.a.sampler,
+            ));
+            f(OperandRef::general(
+                "c",
+                OperandRole::Source,
+                &self.a.coords,
+            ));
+            if let Some(operand) = &self.e {
+                f(OperandRef::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.f {
+                f(OperandRef::general("f", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandMut::general(
+                "a",
+                OperandRole::Source,
+                &mut self.a.handle,
+            ));
+            f(OperandMut::general(
+                "b",
+                OperandRole::Source,
+                &mut self.a.sampler,
+            ));
+            f(OperandMut::general(
+                "c",
+                OperandRole::Source,
+                &mut self.a.coords,
+            ));
+            if let Some(operand) = &mut self.e {
+                f(OperandMut::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.f {
+                f(OperandMut::general("f", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for TexBaseGeomV4DtypeCtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandRef::general(
+                "a",
+                OperandRole::Source,
+                &self.a.handle,
+            ));
+            if let Some(operand) = &self.a.sampler {
+                f(OperandRef::general("b", OperandRole::Source, operand));
+            }
+            f(OperandRef::general(
+                "c",
+                OperandRole::Source,
+                &self.a.coords,
+            ));
+            if let Some(operand) = &self.e {
+                f(OperandRef::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.f {
+                f(OperandRef::general("f", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandMut::general(
+                "a",
+                OperandRole::Source,
+                &mut self.a.handle,
+            ));
+            if let Some(operand) = &mut self.a.sampler {
+                f(OperandMut::general("b", OperandRole::Source, operand));
+            }
+            f(OperandMut::general(
+                "c",
+                OperandRole::Source,
+                &mut self.a.coords,
+            ));
+            if let Some(operand) = &mut self.e {
+                f(OperandMut::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.f {
+                f(OperandMut::general("f", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for TexLevelGeomV4DtypeCtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandRef::general(
+                "a",
+                OperandRole::Source,
+                &self.a.handle,
+            ));
+            if let Some(operand) = &self.a.sampler {
+                f(OperandRef::general("b", OperandRole::Source, operand));
+            }
+            f(OperandRef::general(
+                "c",
+                OperandRole::Source,
+                &self.a.coords,
+            ));
+            f(OperandRef::general("lod", OperandRole::Source, &self.lod));
+            if let Some(operand) = &self.e {
+                f(OperandRef::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.f {
+                f(OperandRef::general("f", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandMut::general(
+                "a",
+                OperandRole::Source,
+                &mut self.a.handle,
+            ));
+            if let Some(operand) = &mut self.a.sampler {
+                f(OperandMut::general("b", OperandRole::Source, operand));
+            }
+            f(OperandMut::general(
+                "c",
+                OperandRole::Source,
+                &mut self.a.coords,
+            ));
+            f(OperandMut::general(
+                "lod",
+                OperandRole::Source,
+                &mut self.lod,
+            ));
+            if let Some(operand) = &mut self.e {
+                f(OperandMut::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.f {
+                f(OperandMut::general("f", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for TexGradGeomV4DtypeCtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandRef::general(
+                "a",
+                OperandRole::Source,
+                &self.a.handle,
+            ));
+            if let Some(operand) = &self.a.sampler {
+                f(OperandRef::general("b", OperandRole::Source, operand));
+            }
+            f(OperandRef::general(
+                "c",
+                OperandRole::Source,
+                &self.a.coords,
+            ));
+            f(OperandRef::general("dPdx", OperandRole::Source, &self.dpdx));
+            f(OperandRef::general("dPdy", OperandRole::Source, &self.dpdy));
+            if let Some(operand) = &self.e {
+                f(OperandRef::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.f {
+                f(OperandRef::general("f", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandMut::general(
+                "a",
+                OperandRole::Source,
+                &mut self.a.handle,
+            ));
+            if let Some(operand) = &mut self.a.sampler {
+                f(OperandMut::general("b", OperandRole::Source, operand));
+            }
+            f(OperandMut::general(
+                "c",
+                OperandRole::Source,
+                &mut self.a.coords,
+            ));
+            f(OperandMut::general(
+                "dPdx",
+                OperandRole::Source,
+                &mut self.dpdx,
+            ));
+            f(OperandMut::general(
+                "dPdy",
+                OperandRole::Source,
+                &mut self.dpdy,
+            ));
+            if let Some(operand) = &mut self.e {
+                f(OperandMut::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.f {
+                f(OperandMut::general("f", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for TexBaseGeomV2F16x2Ctype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandRef::general(
+                "a",
+                OperandRole::Source,
+                &self.a.handle,
+            ));
+            if let Some(operand) = &self.a.sampler {
+                f(OperandRef::general("b", OperandRole::Source, operand));
+            }
+            f(OperandRef::general(
+                "c",
+                OperandRole::Source,
+                &self.a.coords,
+            ));
+            if let Some(operand) = &self.e {
+                f(OperandRef::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.f {
+                f(OperandRef::general("f", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandMut::general(
+                "a",
+                OperandRole::Source,
+                &mut self.a.handle,
+            ));
+            if let Some(operand) = &mut self.a.sampler {
+                f(OperandMut::general("b", OperandRole::Source, operand));
+            }
+            f(OperandMut::general(
+                "c",
+                OperandRole::Source,
+                &mut self.a.coords,
+            ));
+            if let Some(operand) = &mut self.e {
+                f(OperandMut::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.f {
+                f(OperandMut::general("f", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for TexLevelGeomV2F16x2Ctype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandRef::general(
+                "a",
+                OperandRole::Source,
+                &self.a.handle,
+            ));
+            if let Some(operand) = &self.a.sampler {
+                f(OperandRef::general("b", OperandRole::Source, operand));
+            }
+            f(OperandRef::general(
+                "c",
+                OperandRole::Source,
+                &self.a.coords,
+            ));
+            f(OperandRef::general("lod", OperandRole::Source, &self.lod));
+            if let Some(operand) = &self.e {
+                f(OperandRef::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.f {
+                f(OperandRef::general("f", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandMut::general(
+                "a",
+                OperandRole::Source,
+                &mut self.a.handle,
+            ));
+            if let Some(operand) = &mut self.a.sampler {
+                f(OperandMut::general("b", OperandRole::Source, operand));
+            }
+            f(OperandMut::general(
+                "c",
+                OperandRole::Source,
+                &mut self.a.coords,
+            ));
+            f(OperandMut::general(
+                "lod",
+                OperandRole::Source,
+                &mut self.lod,
+            ));
+            if let Some(operand) = &mut self.e {
+                f(OperandMut::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.f {
+                f(OperandMut::general("f", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for TexGradGeomV2F16x2Ctype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandRef::general(
+                "a",
+                OperandRole::Source,
+                &self.a.handle,
+            ));
+            if let Some(operand) = &self.a.sampler {
+                f(OperandRef::general("b", OperandRole::Source, operand));
+            }
+            f(OperandRef::general(
+                "c",
+                OperandRole::Source,
+                &self.a.coords,
+            ));
+            f(OperandRef::general("dPdx", OperandRole::Source, &self.dpdx));
+            f(OperandRef::general("dPdy", OperandRole::Source, &self.dpdy));
+            if let Some(operand) = &self.e {
+                f(OperandRef::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.f {
+                f(OperandRef::general("f", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandMut::general(
+                "a",
+                OperandRole::Source,
+                &mut self.a.handle,
+            ));
+            if let Some(operand) = &mut self.a.sampler {
+                f(OperandMut::general("b", OperandRole::Source, operand));
+            }
+            f(OperandMut::general(
+                "c",
+                OperandRole::Source,
+                &mut self.a.coords,
+            ));
+            f(OperandMut::general(
+                "dPdx",
+                OperandRole::Source,
+                &mut self.dpdx,
+            ));
+            f(OperandMut::general(
+                "dPdy",
+                OperandRole::Source,
+                &mut self.dpdy,
+            ));
+            if let Some(operand) = &mut self.e {
+                f(OperandMut::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.f {
+                f(OperandMut::general("f", OperandRole::Source, operand));
+            }
+        }
+    }
+}
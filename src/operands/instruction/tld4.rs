@@ -0,0 +1,114 @@
+//! Original PTX specification:
+//!
+//! tld4.comp.2d.v4.dtype.f32    d{|p}, [a, c] {, e} {, f};
+//! tld4.comp.geom.v4.dtype.f32  d{|p}, [a, b, c] {, e} {, f};  // explicit sampler
+//! .comp  = { .r, .g, .b, .a };
+//! .geom  = { .2d, .a2d, .cube, .acube };
+//! .dtype = { .u32, .s32, .f32 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tld4::section_0::*;
+
+    impl Operands for Tld4Comp2dV4DtypeF32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            let [first, second] = &self.a.operands;
+            f(OperandRef::general("a", OperandRole::Source, first));
+            f(OperandRef::general("c", OperandRole::Source, second));
+            if let Some(operand) = &self.e {
+                f(OperandRef::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.f {
+                f(OperandRef::general("f", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            let [first, second] = &mut self.a.operands;
+            f(OperandMut::general("a", OperandRole::Source, first));
+            f(OperandMut::general("c", OperandRole::Source, second));
+            if let Some(operand) = &mut self.e {
+                f(OperandMut::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.f {
+                f(OperandMut::general("f", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for Tld4CompGeomV4DtypeF32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            if let Some(operand) = &self.p {
+                f(OperandRef::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandRef::general(
+                "a",
+                OperandRole::Source,
+                &self.a.handle,
+            ));
+            f(OperandRef::general(
+                "b",
+                OperandRole::Source,
+                &self.a.sampler,
+            ));
+            f(OperandRef::general(
+                "c",
+                OperandRole::Source,
+                &self.a.coords,
+            ));
+            if let Some(operand) = &self.e {
+                f(OperandRef::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &self.f {
+                f(OperandRef::general("f", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            if let Some(operand) = &mut self.p {
+                f(OperandMut::general("p", OperandRole::PredicateOut, operand));
+            }
+            f(OperandMut::general(
+                "a",
+                OperandRole::Source,
+                &mut self.a.handle,
+            ));
+            f(OperandMut::general(
+                "b",
+                OperandRole::Source,
+                &mut self.a.sampler,
+            ));
+            f(OperandMut::general(
+                "c",
+                OperandRole::Source,
+                &mut self.a.coords,
+            ));
+            if let Some(operand) = &mut self.e {
+                f(OperandMut::general("e", OperandRole::Source, operand));
+            }
+            if let Some(operand) = &mut self.f {
+                f(OperandMut::general("f", OperandRole::Source, operand));
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+//! Original PTX specification:
+//!
+//! txq.tquery.b32         d, [a];       // texture attributes
+//! txq.level.tlquery.b32  d, [a], lod;  // texture attributes
+//! txq.squery.b32         d, [a];       // sampler attributes
+//! .tquery  = { .width, .height, .depth,
+//! .channel_data_type, .channel_order,
+//! .normalized_coords, .array_size,
+//! .num_mipmap_levels, .num_samples};
+//! .tlquery = { .width, .height, .depth };
+//! .squery  = { .force_unnormalized_coords, .filter_mode,
+//! .addr_mode_0, addr_mode_1, addr_mode_2 };
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::txq::section_0::*;
+
+    impl Operands for TxqTqueryB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+        }
+    }
+
+    impl Operands for TxqLevelTlqueryB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+            f(OperandRef::general("lod", OperandRole::Source, &self.lod));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+            f(OperandMut::general(
+                "lod",
+                OperandRole::Source,
+                &mut self.lod,
+            ));
+        }
+    }
+
+    impl Operands for TxqSqueryB32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::Destination, &self.d));
+            f(OperandRef::address("a", OperandRole::Address, &self.a));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::Destination,
+                &mut self.d,
+            ));
+            f(OperandMut::address("a", OperandRole::Address, &mut self.a));
+        }
+    }
+}
@@ -0,0 +1,17 @@
+//! Original PTX specification:
+//!
+//! wgmma.commit_group.sync.aligned;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_commit_group::section_0::*;
+
+    impl Operands for WgmmaCommitGroupSyncAligned {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {}
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {}
+    }
+}
@@ -0,0 +1,906 @@
+//! Original PTX specification:
+//!
+//! // Half precision floating point type:
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.f16.f16  d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.f16.f16  d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;
+//! .shape   = {.m64n8k32, .m64n16k32, .m64n24k32, .m64n32k32,
+//! .m64n40k32, .m64n48k32, .m64n56k32, .m64n64k32,
+//! .m64n72k32, .m64n80k32, .m64n88k32, .m64n96k32,
+//! .m64n104k32, .m64n112k32, .m64n120k32, .m64n128k32,
+//! .m64n136k32, .m64n144k32, .m64n152k32, .m64n160k32,
+//! .m64n168k32, .m64n176k32, .m64n184k32, .m64n192k32,
+//! .m64n200k32, .m64n208k32, .m64n216k32, .m64n224k32,
+//! .m64n232k32, .m64n240k32, .m64n248k32, .m64n256k32};
+//! .dtype   = {.f16, .f32};
+//! ------------------------------------------------------------------
+//! // Alternate floating point type :
+//! // .bf16 floating point type:
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.bf16.bf16  d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.bf16.bf16  d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;
+//! .shape   = {.m64n8k32, .m64n16k32, .m64n24k32, .m64n32k32,
+//! .m64n40k32, .m64n48k32, .m64n56k32, .m64n64k32,
+//! .m64n72k32, .m64n80k32, .m64n88k32, .m64n96k32,
+//! .m64n104k32, .m64n112k32, .m64n120k32, .m64n128k32,
+//! .m64n136k32, .m64n144k32, .m64n152k32, .m64n160k32,
+//! .m64n168k32, .m64n176k32, .m64n184k32, .m64n192k32,
+//! .m64n200k32, .m64n208k32, .m64n216k32, .m64n224k32,
+//! .m64n232k32, .m64n240k32, .m64n248k32, .m64n256k32};
+//! .dtype  = {.f32};
+//! ------------------------------------------------------------------
+//! // .tf32 floating point type:
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.tf32.tf32  d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.tf32.tf32  d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;
+//! .shape   = {.m64n8k16, .m64n16k16, .m64n24k16, .m64n32k16,
+//! .m64n40k16, .m64n48k16, .m64n56k16, .m64n64k16,
+//! .m64n72k16, .m64n80k16, .m64n88k16, .m64n96k16,
+//! .m64n104k16, .m64n112k16, .m64n120k16, .m64n128k16,
+//! .m64n136k16, .m64n144k16, .m64n152k16, .m64n160k16,
+//! .m64n168k16, .m64n176k16, .m64n184k16, .m64n192k16,
+//! .m64n200k16, .m64n208k16, .m64n216k16, .m64n224k16,
+//! .m64n232k16, .m64n240k16, .m64n248k16, .m64n256k16};
+//! .dtype  = {.f32};
+//! ------------------------------------------------------------------
+//! // FP8 floating point type
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.atype.btype  d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.atype.btype  d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;
+//! .shape   = {.m64n8k64, .m64n16k64, .m64n24k64, .m64n32k64,
+//! .m64n40k64, .m64n48k64, .m64n56k64, .m64n64k64,
+//! .m64n72k64, .m64n80k64, .m64n88k64, .m64n96k64,
+//! .m64n104k64, .m64n112k64, .m64n120k64, .m64n128k64,
+//! .m64n136k64, .m64n144k64, .m64n152k64, .m64n160k64,
+//! .m64n168k64, .m64n176k64, .m64n184k64, .m64n192k64,
+//! .m64n200k64, .m64n208k64, .m64n216k64, .m64n224k64,
+//! .m64n232k64, .m64n240k64, .m64n248k64, .m64n256k64};
+//! .atype  = {.e4m3, .e5m2};
+//! .btype  = {.e4m3, .e5m2};
+//! .dtype  = {.f16, .f32};
+//! ------------------------------------------------------------------
+//! // Integer type:
+//! wgmma.mma_async.sp.sync.aligned.shape{.satfinite}.s32.atype.btype  d, a-desc, b-desc, sp-meta, sp-sel, scale-d;
+//! wgmma.mma_async.sp.sync.aligned.shape{.satfinite}.s32.atype.btype  d, a, b-desc, sp-meta, sp-sel, scale-d;
+//! .shape   = {.m64n8k64, .m64n16k64, .m64n24k64, .m64n32k64,
+//! .m64n48k64, .m64n64k64, .m64n80k64, .m64n96k64,
+//! .m64n112k64, .m64n128k64, .m64n144k64, .m64n160k64,
+//! .m64n176k64, .m64n192k64, .m64n208k64, .m64n224k64,
+//! .m64n240k64, .m64n256k64};
+//! .atype  = {.s8, .u8};
+//! .btype  = {.s8, .u8};
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_0::*;
+
+    impl Operands for WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::ReadWrite, &self.d));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "sp-meta",
+                OperandRole::Source,
+                &self.sp_meta,
+            ));
+            f(OperandRef::general(
+                "sp-sel",
+                OperandRole::Source,
+                &self.sp_sel,
+            ));
+            f(OperandRef::general(
+                "scale-d",
+                OperandRole::Source,
+                &self.scale_d,
+            ));
+            f(OperandRef::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &self.imm_scale_a,
+            ));
+            f(OperandRef::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &self.imm_scale_b,
+            ));
+            f(OperandRef::general(
+                "imm-trans-a",
+                OperandRole::Source,
+                &self.imm_trans_a,
+            ));
+            f(OperandRef::general(
+                "imm-trans-b",
+                OperandRole::Source,
+                &self.imm_trans_b,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::ReadWrite,
+                &mut self.d,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "sp-meta",
+                OperandRole::Source,
+                &mut self.sp_meta,
+            ));
+            f(OperandMut::general(
+                "sp-sel",
+                OperandRole::Source,
+                &mut self.sp_sel,
+            ));
+            f(OperandMut::general(
+                "scale-d",
+                OperandRole::Source,
+                &mut self.scale_d,
+            ));
+            f(OperandMut::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &mut self.imm_scale_a,
+            ));
+            f(OperandMut::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &mut self.imm_scale_b,
+            ));
+            f(OperandMut::general(
+                "imm-trans-a",
+                OperandRole::Source,
+                &mut self.imm_trans_a,
+            ));
+            f(OperandMut::general(
+                "imm-trans-b",
+                OperandRole::Source,
+                &mut self.imm_trans_b,
+            ));
+        }
+    }
+
+    impl Operands for WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::ReadWrite, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "sp-meta",
+                OperandRole::Source,
+                &self.sp_meta,
+            ));
+            f(OperandRef::general(
+                "sp-sel",
+                OperandRole::Source,
+                &self.sp_sel,
+            ));
+            f(OperandRef::general(
+                "scale-d",
+                OperandRole::Source,
+                &self.scale_d,
+            ));
+            f(OperandRef::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &self.imm_scale_a,
+            ));
+            f(OperandRef::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &self.imm_scale_b,
+            ));
+            f(OperandRef::general(
+                "imm-trans-b",
+                OperandRole::Source,
+                &self.imm_trans_b,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::ReadWrite,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "sp-meta",
+                OperandRole::Source,
+                &mut self.sp_meta,
+            ));
+            f(OperandMut::general(
+                "sp-sel",
+                OperandRole::Source,
+                &mut self.sp_sel,
+            ));
+            f(OperandMut::general(
+                "scale-d",
+                OperandRole::Source,
+                &mut self.scale_d,
+            ));
+            f(OperandMut::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &mut self.imm_scale_a,
+            ));
+            f(OperandMut::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &mut self.imm_scale_b,
+            ));
+            f(OperandMut::general(
+                "imm-trans-b",
+                OperandRole::Source,
+                &mut self.imm_trans_b,
+            ));
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_1::*;
+
+    impl Operands for WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::ReadWrite, &self.d));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "sp-meta",
+                OperandRole::Source,
+                &self.sp_meta,
+            ));
+            f(OperandRef::general(
+                "sp-sel",
+                OperandRole::Source,
+                &self.sp_sel,
+            ));
+            f(OperandRef::general(
+                "scale-d",
+                OperandRole::Source,
+                &self.scale_d,
+            ));
+            f(OperandRef::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &self.imm_scale_a,
+            ));
+            f(OperandRef::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &self.imm_scale_b,
+            ));
+            f(OperandRef::general(
+                "imm-trans-a",
+                OperandRole::Source,
+                &self.imm_trans_a,
+            ));
+            f(OperandRef::general(
+                "imm-trans-b",
+                OperandRole::Source,
+                &self.imm_trans_b,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::ReadWrite,
+                &mut self.d,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "sp-meta",
+                OperandRole::Source,
+                &mut self.sp_meta,
+            ));
+            f(OperandMut::general(
+                "sp-sel",
+                OperandRole::Source,
+                &mut self.sp_sel,
+            ));
+            f(OperandMut::general(
+                "scale-d",
+                OperandRole::Source,
+                &mut self.scale_d,
+            ));
+            f(OperandMut::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &mut self.imm_scale_a,
+            ));
+            f(OperandMut::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &mut self.imm_scale_b,
+            ));
+            f(OperandMut::general(
+                "imm-trans-a",
+                OperandRole::Source,
+                &mut self.imm_trans_a,
+            ));
+            f(OperandMut::general(
+                "imm-trans-b",
+                OperandRole::Source,
+                &mut self.imm_trans_b,
+            ));
+        }
+    }
+
+    impl Operands for WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::ReadWrite, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "sp-meta",
+                OperandRole::Source,
+                &self.sp_meta,
+            ));
+            f(OperandRef::general(
+                "sp-sel",
+                OperandRole::Source,
+                &self.sp_sel,
+            ));
+            f(OperandRef::general(
+                "scale-d",
+                OperandRole::Source,
+                &self.scale_d,
+            ));
+            f(OperandRef::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &self.imm_scale_a,
+            ));
+            f(OperandRef::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &self.imm_scale_b,
+            ));
+            f(OperandRef::general(
+                "imm-trans-b",
+                OperandRole::Source,
+                &self.imm_trans_b,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::ReadWrite,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "sp-meta",
+                OperandRole::Source,
+                &mut self.sp_meta,
+            ));
+            f(OperandMut::general(
+                "sp-sel",
+                OperandRole::Source,
+                &mut self.sp_sel,
+            ));
+            f(OperandMut::general(
+                "scale-d",
+                OperandRole::Source,
+                &mut self.scale_d,
+            ));
+            f(OperandMut::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &mut self.imm_scale_a,
+            ));
+            f(OperandMut::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &mut self.imm_scale_b,
+            ));
+            f(OperandMut::general(
+                "imm-trans-b",
+                OperandRole::Source,
+                &mut self.imm_trans_b,
+            ));
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_2::*;
+
+    impl Operands for WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::ReadWrite, &self.d));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "sp-meta",
+                OperandRole::Source,
+                &self.sp_meta,
+            ));
+            f(OperandRef::general(
+                "sp-sel",
+                OperandRole::Source,
+                &self.sp_sel,
+            ));
+            f(OperandRef::general(
+                "scale-d",
+                OperandRole::Source,
+                &self.scale_d,
+            ));
+            f(OperandRef::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &self.imm_scale_a,
+            ));
+            f(OperandRef::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &self.imm_scale_b,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::ReadWrite,
+                &mut self.d,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "sp-meta",
+                OperandRole::Source,
+                &mut self.sp_meta,
+            ));
+            f(OperandMut::general(
+                "sp-sel",
+                OperandRole::Source,
+                &mut self.sp_sel,
+            ));
+            f(OperandMut::general(
+                "scale-d",
+                OperandRole::Source,
+                &mut self.scale_d,
+            ));
+            f(OperandMut::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &mut self.imm_scale_a,
+            ));
+            f(OperandMut::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &mut self.imm_scale_b,
+            ));
+        }
+    }
+
+    impl Operands for WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::ReadWrite, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "sp-meta",
+                OperandRole::Source,
+                &self.sp_meta,
+            ));
+            f(OperandRef::general(
+                "sp-sel",
+                OperandRole::Source,
+                &self.sp_sel,
+            ));
+            f(OperandRef::general(
+                "scale-d",
+                OperandRole::Source,
+                &self.scale_d,
+            ));
+            f(OperandRef::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &self.imm_scale_a,
+            ));
+            f(OperandRef::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &self.imm_scale_b,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::ReadWrite,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "sp-meta",
+                OperandRole::Source,
+                &mut self.sp_meta,
+            ));
+            f(OperandMut::general(
+                "sp-sel",
+                OperandRole::Source,
+                &mut self.sp_sel,
+            ));
+            f(OperandMut::general(
+                "scale-d",
+                OperandRole::Source,
+                &mut self.scale_d,
+            ));
+            f(OperandMut::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &mut self.imm_scale_a,
+            ));
+            f(OperandMut::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &mut self.imm_scale_b,
+            ));
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_3::*;
+
+    impl Operands for WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::ReadWrite, &self.d));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "sp-meta",
+                OperandRole::Source,
+                &self.sp_meta,
+            ));
+            f(OperandRef::general(
+                "sp-sel",
+                OperandRole::Source,
+                &self.sp_sel,
+            ));
+            f(OperandRef::general(
+                "scale-d",
+                OperandRole::Source,
+                &self.scale_d,
+            ));
+            f(OperandRef::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &self.imm_scale_a,
+            ));
+            f(OperandRef::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &self.imm_scale_b,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::ReadWrite,
+                &mut self.d,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "sp-meta",
+                OperandRole::Source,
+                &mut self.sp_meta,
+            ));
+            f(OperandMut::general(
+                "sp-sel",
+                OperandRole::Source,
+                &mut self.sp_sel,
+            ));
+            f(OperandMut::general(
+                "scale-d",
+                OperandRole::Source,
+                &mut self.scale_d,
+            ));
+            f(OperandMut::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &mut self.imm_scale_a,
+            ));
+            f(OperandMut::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &mut self.imm_scale_b,
+            ));
+        }
+    }
+
+    impl Operands for WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::ReadWrite, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "sp-meta",
+                OperandRole::Source,
+                &self.sp_meta,
+            ));
+            f(OperandRef::general(
+                "sp-sel",
+                OperandRole::Source,
+                &self.sp_sel,
+            ));
+            f(OperandRef::general(
+                "scale-d",
+                OperandRole::Source,
+                &self.scale_d,
+            ));
+            f(OperandRef::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &self.imm_scale_a,
+            ));
+            f(OperandRef::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &self.imm_scale_b,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::ReadWrite,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "sp-meta",
+                OperandRole::Source,
+                &mut self.sp_meta,
+            ));
+            f(OperandMut::general(
+                "sp-sel",
+                OperandRole::Source,
+                &mut self.sp_sel,
+            ));
+            f(OperandMut::general(
+                "scale-d",
+                OperandRole::Source,
+                &mut self.scale_d,
+            ));
+            f(OperandMut::general(
+                "imm-scale-a",
+                OperandRole::Source,
+                &mut self.imm_scale_a,
+            ));
+            f(OperandMut::general(
+                "imm-scale-b",
+                OperandRole::Source,
+                &mut self.imm_scale_b,
+            ));
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_4::*;
+
+    impl Operands for WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::ReadWrite, &self.d));
+            f(OperandRef::general(
+                "a-desc",
+                OperandRole::Source,
+                &self.a_desc,
+            ));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "sp-meta",
+                OperandRole::Source,
+                &self.sp_meta,
+            ));
+            f(OperandRef::general(
+                "sp-sel",
+                OperandRole::Source,
+                &self.sp_sel,
+            ));
+            f(OperandRef::general(
+                "scale-d",
+                OperandRole::Source,
+                &self.scale_d,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::ReadWrite,
+                &mut self.d,
+            ));
+            f(OperandMut::general(
+                "a-desc",
+                OperandRole::Source,
+                &mut self.a_desc,
+            ));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "sp-meta",
+                OperandRole::Source,
+                &mut self.sp_meta,
+            ));
+            f(OperandMut::general(
+                "sp-sel",
+                OperandRole::Source,
+                &mut self.sp_sel,
+            ));
+            f(OperandMut::general(
+                "scale-d",
+                OperandRole::Source,
+                &mut self.scale_d,
+            ));
+        }
+    }
+
+    impl Operands for WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("d", OperandRole::ReadWrite, &self.d));
+            f(OperandRef::general("a", OperandRole::Source, &self.a));
+            f(OperandRef::general(
+                "b-desc",
+                OperandRole::Source,
+                &self.b_desc,
+            ));
+            f(OperandRef::general(
+                "sp-meta",
+                OperandRole::Source,
+                &self.sp_meta,
+            ));
+            f(OperandRef::general(
+                "sp-sel",
+                OperandRole::Source,
+                &self.sp_sel,
+            ));
+            f(OperandRef::general(
+                "scale-d",
+                OperandRole::Source,
+                &self.scale_d,
+            ));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "d",
+                OperandRole::ReadWrite,
+                &mut self.d,
+            ));
+            f(OperandMut::general("a", OperandRole::Source, &mut self.a));
+            f(OperandMut::general(
+                "b-desc",
+                OperandRole::Source,
+                &mut self.b_desc,
+            ));
+            f(OperandMut::general(
+                "sp-meta",
+                OperandRole::Source,
+                &mut self.sp_meta,
+            ));
+            f(OperandMut::general(
+                "sp-sel",
+                OperandRole::Source,
+                &mut self.sp_sel,
+            ));
+            f(OperandMut::general(
+                "scale-d",
+                OperandRole::Source,
+                &mut self.scale_d,
+            ));
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Original PTX specification:
+//!
+//! wgmma.wait_group.sync.aligned N;
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_wait_group::section_0::*;
+
+    impl Operands for WgmmaWaitGroupSyncAligned {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("N", OperandRole::Source, &self.n));
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general("N", OperandRole::Source, &mut self.n));
+        }
+    }
+}
@@ -0,0 +1,479 @@
+//! Original PTX specification:
+//!
+//! // Floating point format .f16 loads:
+//! wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k16, .m8n32k16, .m32n8k16};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.f16, .s8, .u8};
+//! .btype  = {.f16, .s8, .u8};
+//! .ctype  = {.f16, .f32, .s32};
+//! ----------------------------------------------------------------
+//! // Alternate floating point format .bf16 loads:
+//! wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k16, .m8n32k16, .m32n8k16};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.bf16 };
+//! .btype  = {.bf16 };
+//! .ctype  = {.f32 };
+//! ----------------------------------------------------------------
+//! // Alternate floating point format .tf32 loads:
+//! wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k8 };
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.tf32 };
+//! .btype  = {.tf32 };
+//! .ctype  = {.f32 };
+//! ----------------------------------------------------------------
+//! // Double precision Floating point .f64 loads:
+//! wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k4 };
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.f64 };
+//! .btype  = {.f64 };
+//! .ctype  = {.f64 };
+//! ----------------------------------------------------------------
+//! // Sub-byte loads:
+//! wmma.load.a.sync.aligned.row.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.col.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k32};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.s4, .u4};
+//! .btype  = {.s4, .u4};
+//! .ctype  = {.s32};
+//! ----------------------------------------------------------------
+//! // Single-bit loads:
+//! wmma.load.a.sync.aligned.row.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.col.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k128};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.b1};
+//! .btype  = {.b1};
+//! .ctype  = {.s32};
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_0::*;
+
+    impl Operands for WmmaLoadASyncAlignedLayoutShapeSsAtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for WmmaLoadBSyncAlignedLayoutShapeSsBtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for WmmaLoadCSyncAlignedLayoutShapeSsCtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_1::*;
+
+    impl Operands for WmmaLoadASyncAlignedLayoutShapeSsAtype1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for WmmaLoadBSyncAlignedLayoutShapeSsBtype1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for WmmaLoadCSyncAlignedLayoutShapeSsCtype1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_2::*;
+
+    impl Operands for WmmaLoadASyncAlignedLayoutShapeSsAtype2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for WmmaLoadBSyncAlignedLayoutShapeSsBtype2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for WmmaLoadCSyncAlignedLayoutShapeSsCtype2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_3::*;
+
+    impl Operands for WmmaLoadASyncAlignedLayoutShapeSsAtype3 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for WmmaLoadBSyncAlignedLayoutShapeSsBtype3 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for WmmaLoadCSyncAlignedLayoutShapeSsCtype3 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_4::*;
+
+    impl Operands for WmmaLoadASyncAlignedRowShapeSsAtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for WmmaLoadBSyncAlignedColShapeSsBtype {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for WmmaLoadCSyncAlignedLayoutShapeSsCtype4 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_5::*;
+
+    impl Operands for WmmaLoadASyncAlignedRowShapeSsAtype1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for WmmaLoadBSyncAlignedColShapeSsBtype1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+
+    impl Operands for WmmaLoadCSyncAlignedLayoutShapeSsCtype5 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::general("r", OperandRole::Destination, &self.r));
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::general(
+                "r",
+                OperandRole::Destination,
+                &mut self.r,
+            ));
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+}
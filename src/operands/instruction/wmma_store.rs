@@ -0,0 +1,117 @@
+//! Original PTX specification:
+//!
+//! wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k16, .m8n32k16, .m32n8k16};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .type   = {.f16, .f32, .s32};
+//! ----------------------------------------------------------------
+//! wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k32, .m8n8k128};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .type   = {.s32};
+//! ----------------------------------------------------------------
+//! wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k8};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .type   = {.f32};
+//! ----------------------------------------------------------------
+//! wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k4 };
+//! .ss     = {.global, .shared, .shared::cta};
+//! .type   = {.f64};
+
+#![allow(unused)]
+
+use crate::operands::{OperandMut, OperandRef, OperandRole, Operands};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wmma_store::section_0::*;
+
+    impl Operands for WmmaStoreDSyncAlignedLayoutShapeSsType {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            f(OperandRef::general("r", OperandRole::Source, &self.r));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            f(OperandMut::general("r", OperandRole::Source, &mut self.r));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::wmma_store::section_1::*;
+
+    impl Operands for WmmaStoreDSyncAlignedLayoutShapeSsType1 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            f(OperandRef::general("r", OperandRole::Source, &self.r));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            f(OperandMut::general("r", OperandRole::Source, &mut self.r));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::wmma_store::section_2::*;
+
+    impl Operands for WmmaStoreDSyncAlignedLayoutShapeSsType2 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            f(OperandRef::general("r", OperandRole::Source, &self.r));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            f(OperandMut::general("r", OperandRole::Source, &mut self.r));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::wmma_store::section_3::*;
+
+    impl Operands for WmmaStoreDSyncAlignedLayoutShapeSsType3 {
+        fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>)) {
+            f(OperandRef::address("p", OperandRole::Address, &self.p));
+            f(OperandRef::general("r", OperandRole::Source, &self.r));
+            if let Some(operand) = &self.stride {
+                f(OperandRef::general("stride", OperandRole::Source, operand));
+            }
+        }
+        fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>)) {
+            f(OperandMut::address("p", OperandRole::Address, &mut self.p));
+            f(OperandMut::general("r", OperandRole::Source, &mut self.r));
+            if let Some(operand) = &mut self.stride {
+                f(OperandMut::general("stride", OperandRole::Source, operand));
+            }
+        }
+    }
+}
@@ -0,0 +1,379 @@
+//! Uniform access to instruction operands.
+//!
+//! Every instruction struct and [`Inst`] implement [`Operands`], which lists
+//! the operands of an instruction in source order together with the
+//! [`OperandRole`] they play. [`Operands::defs`] and [`Operands::uses`] narrow
+//! the list down to the operands written and read by the instruction.
+//!
+//! ```
+//! use ptx_parser::operands::OperandRole;
+//! use ptx_parser::r#type::{FunctionStatement, ModuleDirective};
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let module = ptx_parser::parse_ptx(".entry k() { add.s32 %r1, %r2, 4; }").unwrap();
+//!     let ModuleDirective::EntryFunction { directive, .. } = &module.directives[0] else {
+//!         unreachable!()
+//!     };
+//!     let FunctionStatement::Instruction { instruction, .. } =
+//!         &directive.body.as_ref().unwrap().statements[0]
+//!     else {
+//!         unreachable!()
+//!     };
+//!     let roles: Vec<_> = instruction.inst.operands().iter().map(|o| o.role).collect();
+//!     assert_eq!(
+//!         roles,
+//!         [OperandRole::Destination, OperandRole::Source, OperandRole::Immediate]
+//!     );
+//! });
+//! ```
+//!
+//! The implementations for [`Inst`] and the individual instruction structs are
+//! generated from the grammar by `crates/parser-gen` (`generate-operands`) and
+//! live in `operands/instruction`. Roles are derived from the operand layout:
+//! the leading operand is the destination unless the instruction writes
+//! nothing (`bra`, `bar.sync`, ...) or also reads it (the accumulator of
+//! `wgmma.mma_async`), the `p` of `d|p` is a predicate output, bracketed
+//! operands are addresses and the rest are sources.
+
+pub(crate) mod instruction;
+
+use crate::r#type::{
+    AddressBase, AddressOffset, AddressOperand, GeneralOperand, Operand, RegisterOperand,
+    VectorOperand, instruction::Inst,
+};
+
+/// How an instruction uses one of its operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OperandRole {
+    /// Written by the instruction, e.g. `d` in `add d, a, b`.
+    Destination,
+    /// Read and then written by the instruction, e.g. the accumulator `d` of
+    /// `wgmma.mma_async d, a-desc, b-desc, scale-d`.
+    ReadWrite,
+    /// Read by the instruction.
+    Source,
+    /// A memory address `[a]`. The registers it names are read; the memory
+    /// it refers to may be read or written.
+    Address,
+    /// The predicate written next to the destination, e.g. `q` in
+    /// `setp p|q, a, b` or `p` in `shfl.sync d|p, ...`.
+    PredicateOut,
+    /// A source operand given as an immediate value.
+    Immediate,
+}
+
+impl OperandRole {
+    /// Whether operands with this role are written by the instruction.
+    pub fn is_def(self) -> bool {
+        matches!(
+            self,
+            OperandRole::Destination | OperandRole::ReadWrite | OperandRole::PredicateOut
+        )
+    }
+
+    /// Whether operands with this role read registers or symbols.
+    pub fn is_use(self) -> bool {
+        matches!(
+            self,
+            OperandRole::Source | OperandRole::Address | OperandRole::ReadWrite
+        )
+    }
+}
+
+/// Shared reference to the AST node of an operand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperandValue<'a> {
+    General(&'a GeneralOperand),
+    /// A braced operand group such as `{%r1, %r2}` spelled out by the grammar.
+    Vector(&'a VectorOperand),
+    Address(&'a AddressOperand),
+}
+
+/// Mutable reference to the AST node of an operand.
+#[derive(Debug, PartialEq, Eq)]
+pub enum OperandValueMut<'a> {
+    General(&'a mut GeneralOperand),
+    /// A braced operand group such as `{%r1, %r2}` spelled out by the grammar.
+    Vector(&'a mut VectorOperand),
+    Address(&'a mut AddressOperand),
+}
+
+/// An operand of an instruction together with its role.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperandRef<'a> {
+    /// Operand name used by the PTX specification, e.g. `d` or `a`.
+    pub name: &'static str,
+    pub role: OperandRole,
+    pub value: OperandValue<'a>,
+}
+
+/// A mutable operand of an instruction together with its role.
+#[derive(Debug, PartialEq, Eq)]
+pub struct OperandMut<'a> {
+    /// Operand name used by the PTX specification, e.g. `d` or `a`.
+    pub name: &'static str,
+    pub role: OperandRole,
+    pub value: OperandValueMut<'a>,
+}
+
+/// Lists the operands of an instruction.
+///
+/// Implemented for [`Inst`] and every instruction struct; the implementations
+/// are generated so that they follow the operand layout of each instruction.
+pub trait Operands {
+    /// Call `f` with every operand in source order.
+    fn for_each_operand<'a>(&'a self, f: &mut dyn FnMut(OperandRef<'a>));
+
+    /// Call `f` with every operand in source order, by mutable reference.
+    fn for_each_operand_mut<'a>(&'a mut self, f: &mut dyn FnMut(OperandMut<'a>));
+
+    /// All operands in source order.
+    fn operands(&self) -> Vec<OperandRef<'_>> {
+        let mut operands = Vec::new();
+        self.for_each_operand(&mut |operand| operands.push(operand));
+        operands
+    }
+
+    /// All operands in source order, by mutable reference.
+    fn operands_mut(&mut self) -> Vec<OperandMut<'_>> {
+        let mut operands = Vec::new();
+        self.for_each_operand_mut(&mut |operand| operands.push(operand));
+        operands
+    }
+
+    /// Operands written by the instruction, including read-write operands.
+    fn defs(&self) -> Vec<OperandRef<'_>> {
+        let mut operands = self.operands();
+        operands.retain(|operand| operand.role.is_def());
+        operands
+    }
+
+    /// Operands written by the instruction, by mutable reference.
+    fn defs_mut(&mut self) -> Vec<OperandMut<'_>> {
+        let mut operands = self.operands_mut();
+        operands.retain(|operand| operand.role.is_def());
+        operands
+    }
+
+    /// Operands read by the instruction, including address and read-write
+    /// operands. Immediates are left out.
+    fn uses(&self) -> Vec<OperandRef<'_>> {
+        let mut operands = self.operands();
+        operands.retain(|operand| operand.role.is_use());
+        operands
+    }
+
+    /// Operands read by the instruction, by mutable reference.
+    fn uses_mut(&mut self) -> Vec<OperandMut<'_>> {
+        let mut operands = self.operands_mut();
+        operands.retain(|operand| operand.role.is_use());
+        operands
+    }
+}
+
+/// Refine the role of a source operand that is an immediate value.
+fn refine_role(role: OperandRole, operand: &GeneralOperand) -> OperandRole {
+    match (role, operand) {
+        (
+            OperandRole::Source,
+            GeneralOperand::Single {
+                operand: Operand::Immediate { .. },
+                ..
+            },
+        ) => OperandRole::Immediate,
+        _ => role,
+    }
+}
+
+impl<'a> OperandRef<'a> {
+    pub(crate) fn general(
+        name: &'static str,
+        role: OperandRole,
+        operand: &'a GeneralOperand,
+    ) -> Self {
+        OperandRef {
+            name,
+            role: refine_role(role, operand),
+            value: OperandValue::General(operand),
+        }
+    }
+
+    pub(crate) fn vector(
+        name: &'static str,
+        role: OperandRole,
+        operand: &'a VectorOperand,
+    ) -> Self {
+        OperandRef {
+            name,
+            role,
+            value: OperandValue::Vector(operand),
+        }
+    }
+
+    pub(crate) fn address(
+        name: &'static str,
+        role: OperandRole,
+        operand: &'a AddressOperand,
+    ) -> Self {
+        OperandRef {
+            name,
+            role,
+            value: OperandValue::Address(operand),
+        }
+    }
+
+    /// Registers named by the operand, with vector operands expanded to their
+    /// elements and address operands to their base and offset registers.
+    pub fn registers(&self) -> Vec<&'a RegisterOperand> {
+        let mut registers = Vec::new();
+        match self.value {
+            OperandValue::General(GeneralOperand::Single { operand, .. }) => {
+                push_register(operand, &mut registers)
+            }
+            OperandValue::General(GeneralOperand::Vec { operand, .. })
+            | OperandValue::Vector(operand) => {
+                for element in vector_elements(operand) {
+                    push_register(element, &mut registers);
+                }
+            }
+            OperandValue::Address(AddressOperand::Offset { base, offset, .. }) => {
+                if let AddressBase::Register { operand, .. } = base {
+                    registers.push(operand);
+                }
+                if let Some(AddressOffset::Register { operand, .. }) = offset {
+                    registers.push(operand);
+                }
+            }
+            OperandValue::Address(_) => {}
+        }
+        registers
+    }
+}
+
+impl<'a> OperandMut<'a> {
+    pub(crate) fn general(
+        name: &'static str,
+        role: OperandRole,
+        operand: &'a mut GeneralOperand,
+    ) -> Self {
+        OperandMut {
+            name,
+            role: refine_role(role, operand),
+            value: OperandValueMut::General(operand),
+        }
+    }
+
+    pub(crate) fn vector(
+        name: &'static str,
+        role: OperandRole,
+        operand: &'a mut VectorOperand,
+    ) -> Self {
+        OperandMut {
+            name,
+            role,
+            value: OperandValueMut::Vector(operand),
+        }
+    }
+
+    pub(crate) fn address(
+        name: &'static str,
+        role: OperandRole,
+        operand: &'a mut AddressOperand,
+    ) -> Self {
+        OperandMut {
+            name,
+            role,
+            value: OperandValueMut::Address(operand),
+        }
+    }
+
+    /// Mutable counterpart of [`OperandRef::registers`].
+    pub fn into_registers(self) -> Vec<&'a mut RegisterOperand> {
+        let mut registers = Vec::new();
+        match self.value {
+            OperandValueMut::General(GeneralOperand::Single { operand, .. }) => {
+                push_register_mut(operand, &mut registers)
+            }
+            OperandValueMut::General(GeneralOperand::Vec { operand, .. })
+            | OperandValueMut::Vector(operand) => {
+                for element in vector_elements_mut(operand) {
+                    push_register_mut(element, &mut registers);
+                }
+            }
+            OperandValueMut::Address(AddressOperand::Offset { base, offset, .. }) => {
+                if let AddressBase::Register { operand, .. } = base {
+                    registers.push(operand);
+                }
+                if let Some(AddressOffset::Register { operand, .. }) = offset {
+                    registers.push(operand);
+                }
+            }
+            OperandValueMut::Address(_) => {}
+        }
+        registers
+    }
+}
+
+pub(crate) fn vector_elements(vector: &VectorOperand) -> &[Operand] {
+    match vector {
+        VectorOperand::Vector1 { operand, .. } => std::slice::from_ref(operand),
+        VectorOperand::Vector2 { operands, .. } => operands,
+        VectorOperand::Vector3 { operands, .. } => operands,
+        VectorOperand::Vector4 { operands, .. } => operands,
+        VectorOperand::Vector8 { operands, .. } => operands,
+    }
+}
+
+fn vector_elements_mut(vector: &mut VectorOperand) -> &mut [Operand] {
+    match vector {
+        VectorOperand::Vector1 { operand, .. } => std::slice::from_mut(operand),
+        VectorOperand::Vector2 { operands, .. } => operands,
+        VectorOperand::Vector3 { operands, .. } => operands,
+        VectorOperand::Vector4 { operands, .. } => operands,
+        VectorOperand::Vector8 { operands, .. } => operands,
+    }
+}
+
+fn push_register<'a>(operand: &'a Operand, registers: &mut Vec<&'a RegisterOperand>) {
+    if let Operand::Register { operand, .. } = operand {
+        registers.push(operand);
+    }
+}
+
+fn push_register_mut<'a>(operand: &'a mut Operand, registers: &mut Vec<&'a mut RegisterOperand>) {
+    if let Operand::Register { operand, .. } = operand {
+        registers.push(operand);
+    }
+}
+
+impl Inst {
+    /// All operands in source order. See [`Operands::operands`].
+    pub fn operands(&self) -> Vec<OperandRef<'_>> {
+        Operands::operands(self)
+    }
+
+    /// See [`Operands::operands_mut`].
+    pub fn operands_mut(&mut self) -> Vec<OperandMut<'_>> {
+        Operands::operands_mut(self)
+    }
+
+    /// Operands written by the instruction. See [`Operands::defs`].
+    pub fn defs(&self) -> Vec<OperandRef<'_>> {
+        Operands::defs(self)
+    }
+
+    /// See [`Operands::defs_mut`].
+    pub fn defs_mut(&mut self) -> Vec<OperandMut<'_>> {
+        Operands::defs_mut(self)
+    }
+
+    /// Operands read by the instruction. See [`Operands::uses`].
+    pub fn uses(&self) -> Vec<OperandRef<'_>> {
+        Operands::uses(self)
+    }
+
+    /// See [`Operands::uses_mut`].
+    pub fn uses_mut(&mut self) -> Vec<OperandMut<'_>> {
+        Operands::uses_mut(self)
+    }
+}
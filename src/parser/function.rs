@@ -6,6 +6,7 @@ use crate::{
     mapc, ok,
     parser::{
         ParseErrorKind, PtxParseError, PtxParser, PtxTokenStream, Span,
+        recovery::{rbrace_recovering_p, statements_p},
         util::{
             alt, between, colon_p, comma_p, directive_exact_p, directive_p, identifier_p,
             integer_p, langle_p, lbrace_p, lparen_p, many, map, minus_p, optional,
@@ -146,7 +147,7 @@ impl PtxParser for FunctionStatement {
 
         let block_stmt = move |stream: &mut PtxTokenStream| {
             map(
-                between(lbrace_p(), rbrace_p(), many(FunctionStatement::parse())),
+                between(lbrace_p(), rbrace_recovering_p(), statements_p()),
                 |statements, span| c!(FunctionStatement::Block { statements }),
             )(stream)
         };
@@ -664,7 +665,7 @@ impl PtxParser for AliasFunctionDirective {
 impl PtxParser for FunctionBody {
     fn parse() -> impl Fn(&mut PtxTokenStream) -> Result<(Self, Span), PtxParseError> {
         try_map(
-            between(lbrace_p(), rbrace_p(), many(FunctionStatement::parse())),
+            between(lbrace_p(), rbrace_recovering_p(), statements_p()),
             |statements, span| ok!(FunctionBody { statements }),
         )
     }
@@ -3,7 +3,7 @@
 #![allow(unused)]
 
 use crate::lexer::PtxToken;
-use crate::parser::{PtxParseError, PtxParser, PtxTokenStream, Span};
+use crate::parser::{ParseErrorKind, PtxParseError, PtxParser, PtxTokenStream, Span};
 use crate::r#type::instruction::Inst;
 
 pub mod abs;
@@ -194,6 +194,9 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
         ));
     };
 
+    // Error of the candidate format that got furthest before failing
+    let mut closest: Option<(&'static str, PtxParseError)> = None;
+
     // Dispatch based on opcode
     match opcode {
         "abs" => {
@@ -202,55 +205,55 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AbsType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "abs::section_0::AbsType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::abs::section_0::AbsFtzF32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AbsFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "abs::section_0::AbsFtzF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::abs::section_0::AbsF64 as PtxParser>::parse()(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::AbsF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "abs::section_0::AbsF64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::abs::section_0::AbsFtzF16 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AbsFtzF16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "abs::section_0::AbsFtzF16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::abs::section_0::AbsFtzF16x2 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AbsFtzF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "abs::section_0::AbsFtzF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::abs::section_0::AbsBf16 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AbsBf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "abs::section_0::AbsBf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::abs::section_0::AbsBf16x2 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AbsBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "abs::section_0::AbsBf16x2", err),
             }
         }
         "activemask" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::activemask::section_0::ActivemaskB32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::ActivemaskB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "activemask::section_0::ActivemaskB32", err),
             }
         }
         "add" => {
@@ -259,73 +262,83 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AddCcType(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "add_cc::section_0::AddCcType", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::add::section_0::AddType as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AddType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "add::section_0::AddType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::add::section_0::AddSatS32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AddSatS32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "add::section_0::AddSatS32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::add::section_1::AddRndFtzSatF32 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::AddRndFtzSatF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "add::section_1::AddRndFtzSatF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::add::section_1::AddRndFtzF32x2 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AddRndFtzF32x2(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "add::section_1::AddRndFtzF32x2", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::add::section_1::AddRndF64 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AddRndF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "add::section_1::AddRndF64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::add::section_2::AddRndFtzSatF16 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::AddRndFtzSatF16(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "add::section_2::AddRndFtzSatF16", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::add::section_2::AddRndFtzSatF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AddRndFtzSatF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "add::section_2::AddRndFtzSatF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::add::section_2::AddRndBf16 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AddRndBf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "add::section_2::AddRndBf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::add::section_2::AddRndBf16x2 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AddRndBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "add::section_2::AddRndBf16x2", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::add::section_3::AddRndSatF32Atype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AddRndSatF32Atype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "add::section_3::AddRndSatF32Atype", err),
             }
         }
         "addc" => {
@@ -334,7 +347,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AddcCcType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "addc::section_0::AddcCcType", err),
             }
         }
         "alloca" => {
@@ -343,7 +356,9 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AllocaType(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "alloca::section_0::AllocaType", err)
+                }
             }
         }
         "and" => {
@@ -352,191 +367,209 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::AndType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "and::section_0::AndType", err),
             }
         }
         "applypriority" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::applypriority::section_0::ApplypriorityGlobalLevelEvictionPriority as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::ApplypriorityGlobalLevelEvictionPriority(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "applypriority::section_0::ApplypriorityGlobalLevelEvictionPriority", err),
             }
         }
         "atom" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::atom::section_0::AtomSemScopeSpaceOpLevelCacheHintType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AtomSemScopeSpaceOpLevelCacheHintType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "atom::section_0::AtomSemScopeSpaceOpLevelCacheHintType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::atom::section_0::AtomSemScopeSpaceOpType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AtomSemScopeSpaceOpType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "atom::section_0::AtomSemScopeSpaceOpType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::atom::section_0::AtomSemScopeSpaceCasB16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AtomSemScopeSpaceCasB16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "atom::section_0::AtomSemScopeSpaceCasB16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::atom::section_0::AtomSemScopeSpaceCasB128 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AtomSemScopeSpaceCasB128(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "atom::section_0::AtomSemScopeSpaceCasB128", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::atom::section_0::AtomSemScopeSpaceExchLevelCacheHintB128 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AtomSemScopeSpaceExchLevelCacheHintB128(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "atom::section_0::AtomSemScopeSpaceExchLevelCacheHintB128", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::atom::section_0::AtomSemScopeSpaceAddNoftzLevelCacheHintF16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "atom::section_0::AtomSemScopeSpaceAddNoftzLevelCacheHintF16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::atom::section_0::AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "atom::section_0::AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::atom::section_0::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "atom::section_0::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::atom::section_0::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "atom::section_0::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::atom::section_1::AtomSemScopeGlobalAddLevelCacheHintVec32BitF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AtomSemScopeGlobalAddLevelCacheHintVec32BitF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "atom::section_1::AtomSemScopeGlobalAddLevelCacheHintVec32BitF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::atom::section_1::AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "atom::section_1::AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::atom::section_1::AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "atom::section_1::AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType", err),
             }
         }
         "bar" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarrierCtaSyncAligned as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::BarrierCtaSyncAligned(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bar::section_0::BarrierCtaSyncAligned", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarrierCtaArriveAligned as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::BarrierCtaArriveAligned(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bar::section_0::BarrierCtaArriveAligned", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarrierCtaRedPopcAlignedU32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::BarrierCtaRedPopcAlignedU32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bar::section_0::BarrierCtaRedPopcAlignedU32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarrierCtaRedOpAlignedPred as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::BarrierCtaRedOpAlignedPred(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bar::section_0::BarrierCtaRedOpAlignedPred", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarCtaSync as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::BarCtaSync(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bar::section_0::BarCtaSync", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarCtaArrive as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::BarCtaArrive(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "bar::section_0::BarCtaArrive", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarCtaRedPopcU32 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::BarCtaRedPopcU32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "bar::section_0::BarCtaRedPopcU32",
+                    err,
+                ),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarCtaRedOpPred as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::BarCtaRedOpPred(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "bar::section_0::BarCtaRedOpPred", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar_warp_sync::section_0::BarWarpSync as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::BarWarpSync(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bar_warp_sync::section_0::BarWarpSync", err),
             }
         }
         "barrier" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarrierCtaSyncAligned as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::BarrierCtaSyncAligned(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bar::section_0::BarrierCtaSyncAligned", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarrierCtaArriveAligned as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::BarrierCtaArriveAligned(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bar::section_0::BarrierCtaArriveAligned", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarrierCtaRedPopcAlignedU32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::BarrierCtaRedPopcAlignedU32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bar::section_0::BarrierCtaRedPopcAlignedU32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarrierCtaRedOpAlignedPred as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::BarrierCtaRedOpAlignedPred(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bar::section_0::BarrierCtaRedOpAlignedPred", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarCtaSync as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::BarCtaSync(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bar::section_0::BarCtaSync", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarCtaArrive as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::BarCtaArrive(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "bar::section_0::BarCtaArrive", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarCtaRedPopcU32 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::BarCtaRedPopcU32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "bar::section_0::BarCtaRedPopcU32",
+                    err,
+                ),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bar::section_0::BarCtaRedOpPred as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::BarCtaRedOpPred(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "bar::section_0::BarCtaRedOpPred", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::barrier_cluster::section_0::BarrierClusterArriveSemAligned as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::BarrierClusterArriveSemAligned(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "barrier_cluster::section_0::BarrierClusterArriveSemAligned", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::barrier_cluster::section_0::BarrierClusterWaitAcquireAligned as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::BarrierClusterWaitAcquireAligned(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "barrier_cluster::section_0::BarrierClusterWaitAcquireAligned", err),
             }
         }
         "bfe" => {
@@ -545,7 +578,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::BfeType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bfe::section_0::BfeType", err),
             }
         }
         "bfi" => {
@@ -554,7 +587,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::BfiType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bfi::section_0::BfiType", err),
             }
         }
         "bfind" => {
@@ -563,12 +596,12 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::BfindType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bfind::section_0::BfindType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bfind::section_0::BfindShiftamtType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::BfindShiftamtType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bfind::section_0::BfindShiftamtType", err),
             }
         }
         "bmsk" => {
@@ -577,7 +610,9 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::BmskModeB32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "bmsk::section_0::BmskModeB32", err)
+                }
             }
         }
         "bra" => {
@@ -585,14 +620,14 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             match <crate::r#type::instruction::bra::section_0::BraUni as PtxParser>::parse()(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::BraUni(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bra::section_0::BraUni", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::bra::section_0::BraUni1 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::BraUni1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "bra::section_0::BraUni1", err),
             }
         }
         "brev" => {
@@ -601,7 +636,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::BrevType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "brev::section_0::BrevType", err),
             }
         }
         "brkpt" => {
@@ -610,7 +645,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::Brkpt(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "brkpt::section_0::Brkpt", err),
             }
         }
         "brx" => {
@@ -619,14 +654,18 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::BrxIdxUni(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "brx_idx::section_0::BrxIdxUni", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::brx_idx::section_0::BrxIdxUni1 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::BrxIdxUni1(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "brx_idx::section_0::BrxIdxUni1", err)
+                }
             }
         }
         "call" => {
@@ -635,85 +674,85 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::CallUni(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "call::section_0::CallUni", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::call::section_0::CallUni1 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::CallUni1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "call::section_0::CallUni1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::call::section_0::CallUni2 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::CallUni2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "call::section_0::CallUni2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::call::section_0::CallUni3 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::CallUni3(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "call::section_0::CallUni3", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::call::section_0::CallUni4 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::CallUni4(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "call::section_0::CallUni4", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::call::section_0::CallUni5 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::CallUni5(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "call::section_0::CallUni5", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::call::section_0::CallUni6 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::CallUni6(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "call::section_0::CallUni6", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::call::section_0::CallUni7 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::CallUni7(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "call::section_0::CallUni7", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::call::section_0::CallUni8 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::CallUni8(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "call::section_0::CallUni8", err),
             }
         }
         "clusterlaunchcontrol" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::clusterlaunchcontrol_query_cancel::section_0::ClusterlaunchcontrolQueryCancelIsCanceledPredB128 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::ClusterlaunchcontrolQueryCancelIsCanceledPredB128(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "clusterlaunchcontrol_query_cancel::section_0::ClusterlaunchcontrolQueryCancelIsCanceledPredB128", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::clusterlaunchcontrol_query_cancel::section_0::ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "clusterlaunchcontrol_query_cancel::section_0::ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::clusterlaunchcontrol_query_cancel::section_0::ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "clusterlaunchcontrol_query_cancel::section_0::ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::clusterlaunchcontrol_try_cancel::section_0::ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "clusterlaunchcontrol_try_cancel::section_0::ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128", err),
             }
         }
         "clz" => {
@@ -722,7 +761,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::ClzType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "clz::section_0::ClzType", err),
             }
         }
         "cnot" => {
@@ -731,14 +770,14 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::CnotType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cnot::section_0::CnotType", err),
             }
         }
         "copysign" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::copysign::section_0::CopysignType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CopysignType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "copysign::section_0::CopysignType", err),
             }
         }
         "cos" => {
@@ -747,268 +786,270 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::CosApproxFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "cos::section_0::CosApproxFtzF32", err)
+                }
             }
         }
         "cp" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_bulk_commit_group::section_0::CpAsyncBulkCommitGroup as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncBulkCommitGroup(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_bulk_commit_group::section_0::CpAsyncBulkCommitGroup", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_bulk_prefetch_tensor::section_0::CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_bulk_prefetch_tensor::section_0::CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_bulk_prefetch::section_0::CpAsyncBulkPrefetchL2SrcLevelCacheHint as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncBulkPrefetchL2SrcLevelCacheHint(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_bulk_prefetch::section_0::CpAsyncBulkPrefetchL2SrcLevelCacheHint", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_bulk_tensor::section_0::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_bulk_tensor::section_0::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_bulk_tensor::section_1::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_bulk_tensor::section_1::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_bulk_tensor::section_2::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_bulk_tensor::section_2::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_bulk::section_0::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_bulk::section_0::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_bulk::section_1::CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_bulk::section_1::CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_bulk::section_2::CpAsyncBulkDstSrcCompletionMechanism as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncBulkDstSrcCompletionMechanism(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_bulk::section_2::CpAsyncBulkDstSrcCompletionMechanism", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_bulk::section_3::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_bulk::section_3::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_bulk_wait_group::section_0::CpAsyncBulkWaitGroupRead as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncBulkWaitGroupRead(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_bulk_wait_group::section_0::CpAsyncBulkWaitGroupRead", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_commit_group::section_0::CpAsyncCommitGroup as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncCommitGroup(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_commit_group::section_0::CpAsyncCommitGroup", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_mbarrier_arrive::section_0::CpAsyncMbarrierArriveNoincStateB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncMbarrierArriveNoincStateB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_mbarrier_arrive::section_0::CpAsyncMbarrierArriveNoincStateB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async::section_0::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async::section_0::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async::section_0::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async::section_0::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async::section_0::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async::section_0::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async::section_0::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async::section_0::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_wait_group::section_0::CpAsyncWaitGroup as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncWaitGroup(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_wait_group::section_0::CpAsyncWaitGroup", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_async_wait_group::section_0::CpAsyncWaitAll as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpAsyncWaitAll(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_async_wait_group::section_0::CpAsyncWaitAll", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_reduce_async_bulk_tensor::section_0::CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_reduce_async_bulk_tensor::section_0::CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_reduce_async_bulk::section_0::CpReduceAsyncBulkDstSrcCompletionMechanismRedopType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpReduceAsyncBulkDstSrcCompletionMechanismRedopType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_reduce_async_bulk::section_0::CpReduceAsyncBulkDstSrcCompletionMechanismRedopType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_reduce_async_bulk::section_1::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_reduce_async_bulk::section_1::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cp_reduce_async_bulk::section_2::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cp_reduce_async_bulk::section_2::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType", err),
             }
         }
         "createpolicy" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::createpolicy::section_0::CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "createpolicy::section_0::CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::createpolicy::section_0::CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "createpolicy::section_0::CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::createpolicy::section_0::CreatepolicyCvtL2B64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CreatepolicyCvtL2B64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "createpolicy::section_0::CreatepolicyCvtL2B64", err),
             }
         }
         "cvt" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt_pack::section_0::CvtPackSatConverttypeAbtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtPackSatConverttypeAbtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt_pack::section_0::CvtPackSatConverttypeAbtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt_pack::section_1::CvtPackSatConverttypeAbtypeCtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtPackSatConverttypeAbtypeCtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt_pack::section_1::CvtPackSatConverttypeAbtypeCtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtIrndFtzSatDtypeAtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtIrndFtzSatDtypeAtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtIrndFtzSatDtypeAtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtFrndFtzSatDtypeAtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtFrndFtzSatDtypeAtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtFrndFtzSatDtypeAtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtFrnd2ReluSatfiniteF16F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtFrnd2ReluSatfiniteF16F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtFrnd2ReluSatfiniteF16F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtFrnd2ReluSatfiniteF16x2F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtFrnd2ReluSatfiniteF16x2F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtFrnd2ReluSatfiniteF16x2F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRsReluSatfiniteF16x2F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRsReluSatfiniteF16x2F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRsReluSatfiniteF16x2F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtFrnd2ReluSatfiniteBf16F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtFrnd2ReluSatfiniteBf16F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtFrnd2ReluSatfiniteBf16F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtFrnd2ReluSatfiniteBf16x2F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtFrnd2ReluSatfiniteBf16x2F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtFrnd2ReluSatfiniteBf16x2F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRsReluSatfiniteBf16x2F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRsReluSatfiniteBf16x2F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRsReluSatfiniteBf16x2F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRnaSatfiniteTf32F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRnaSatfiniteTf32F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRnaSatfiniteTf32F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtFrnd2SatfiniteReluTf32F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtFrnd2SatfiniteReluTf32F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtFrnd2SatfiniteReluTf32F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRnSatfiniteReluF8x2typeF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRnSatfiniteReluF8x2typeF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRnSatfiniteReluF8x2typeF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRnSatfiniteReluF8x2typeF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRnSatfiniteReluF8x2typeF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRnSatfiniteReluF8x2typeF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRnReluF16x2F8x2type as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRnReluF16x2F8x2type(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRnReluF16x2F8x2type", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRsReluSatfiniteF8x4typeF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRsReluSatfiniteF8x4typeF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRsReluSatfiniteF8x4typeF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRnSatfiniteReluF4x2typeF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRnSatfiniteReluF4x2typeF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRnSatfiniteReluF4x2typeF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRnReluF16x2F4x2type as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRnReluF16x2F4x2type(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRnReluF16x2F4x2type", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRsReluSatfiniteF4x4typeF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRsReluSatfiniteF4x4typeF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRsReluSatfiniteF4x4typeF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRnSatfiniteReluF6x2typeF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRnSatfiniteReluF6x2typeF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRnSatfiniteReluF6x2typeF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRnReluF16x2F6x2type as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRnReluF16x2F6x2type(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRnReluF16x2F6x2type", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRsReluSatfiniteF6x4typeF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRsReluSatfiniteF6x4typeF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRsReluSatfiniteF6x4typeF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtFrnd3SatfiniteUe8m0x2F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtFrnd3SatfiniteUe8m0x2F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtFrnd3SatfiniteUe8m0x2F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtFrnd3SatfiniteUe8m0x2Bf16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtFrnd3SatfiniteUe8m0x2Bf16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtFrnd3SatfiniteUe8m0x2Bf16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvt::section_0::CvtRnBf16x2Ue8m0x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::CvtRnBf16x2Ue8m0x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "cvt::section_0::CvtRnBf16x2Ue8m0x2", err),
             }
         }
         "cvta" => {
@@ -1017,21 +1058,28 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::CvtaSpaceSize(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "cvta::section_0::CvtaSpaceSize", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::cvta::section_0::CvtaToSpaceSize as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::CvtaToSpaceSize(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "cvta::section_0::CvtaToSpaceSize",
+                    err,
+                ),
             }
         }
         "discard" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::discard::section_0::DiscardGlobalLevel as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::DiscardGlobalLevel(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "discard::section_0::DiscardGlobalLevel", err),
             }
         }
         "div" => {
@@ -1040,42 +1088,48 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::DivType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "div::section_0::DivType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::div::section_0::DivApproxFtzF32 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::DivApproxFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "div::section_0::DivApproxFtzF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::div::section_0::DivFullFtzF32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::DivFullFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "div::section_0::DivFullFtzF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::div::section_0::DivRndFtzF32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::DivRndFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "div::section_0::DivRndFtzF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::div::section_0::DivRndF64 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::DivRndF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "div::section_0::DivRndF64", err),
             }
         }
         "dp2a" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::dp2a::section_0::Dp2aModeAtypeBtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Dp2aModeAtypeBtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "dp2a::section_0::Dp2aModeAtypeBtype", err),
             }
         }
         "dp4a" => {
@@ -1084,7 +1138,9 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::Dp4aAtypeBtype(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "dp4a::section_0::Dp4aAtypeBtype", err)
+                }
             }
         }
         "elect" => {
@@ -1093,7 +1149,12 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::ElectSync(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "elect_sync::section_0::ElectSync",
+                    err,
+                ),
             }
         }
         "ex2" => {
@@ -1102,19 +1163,23 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::Ex2ApproxFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "ex2::section_0::Ex2ApproxFtzF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ex2::section_0::Ex2ApproxAtype as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::Ex2ApproxAtype(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "ex2::section_0::Ex2ApproxAtype", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ex2::section_0::Ex2ApproxFtzBtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Ex2ApproxFtzBtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ex2::section_0::Ex2ApproxFtzBtype", err),
             }
         }
         "exit" => {
@@ -1122,7 +1187,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             match <crate::r#type::instruction::exit::section_0::Exit as PtxParser>::parse()(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::Exit(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "exit::section_0::Exit", err),
             }
         }
         "fence" => {
@@ -1131,59 +1196,66 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::FenceSemScope(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "membar::section_0::FenceSemScope",
+                    err,
+                ),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceAcquireSyncRestrictSharedClusterCluster as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceAcquireSyncRestrictSharedClusterCluster(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceAcquireSyncRestrictSharedClusterCluster", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceReleaseSyncRestrictSharedCtaCluster as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceReleaseSyncRestrictSharedCtaCluster(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceReleaseSyncRestrictSharedCtaCluster", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceOpRestrictReleaseCluster as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceOpRestrictReleaseCluster(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceOpRestrictReleaseCluster", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceProxyProxykind as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceProxyProxykind(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceProxyProxykind", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceProxyToProxykindFromProxykindReleaseScope as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceProxyToProxykindFromProxykindReleaseScope(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceProxyToProxykindFromProxykindReleaseScope", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceProxyToProxykindFromProxykindAcquireScope as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceProxyToProxykindFromProxykindAcquireScope(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceProxyToProxykindFromProxykindAcquireScope", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::MembarLevel as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MembarLevel(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "membar::section_0::MembarLevel", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::MembarProxyProxykind as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MembarProxyProxykind(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::MembarProxyProxykind", err),
             }
         }
         "fma" => {
@@ -1192,69 +1264,87 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::FmaRndFtzSatF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "fma::section_0::FmaRndFtzSatF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::fma::section_0::FmaRndFtzF32x2 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::FmaRndFtzF32x2(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "fma::section_0::FmaRndFtzF32x2", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::fma::section_0::FmaRndF64 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::FmaRndF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "fma::section_0::FmaRndF64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::fma::section_1::FmaRndFtzSatF16 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::FmaRndFtzSatF16(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "fma::section_1::FmaRndFtzSatF16", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::fma::section_1::FmaRndFtzSatF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FmaRndFtzSatF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "fma::section_1::FmaRndFtzSatF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::fma::section_1::FmaRndFtzReluF16 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::FmaRndFtzReluF16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "fma::section_1::FmaRndFtzReluF16",
+                    err,
+                ),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::fma::section_1::FmaRndFtzReluF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FmaRndFtzReluF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "fma::section_1::FmaRndFtzReluF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::fma::section_1::FmaRndReluBf16 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::FmaRndReluBf16(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "fma::section_1::FmaRndReluBf16", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::fma::section_1::FmaRndReluBf16x2 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::FmaRndReluBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "fma::section_1::FmaRndReluBf16x2",
+                    err,
+                ),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::fma::section_1::FmaRndOobReluType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FmaRndOobReluType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "fma::section_1::FmaRndOobReluType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::fma::section_2::FmaRndSatF32Abtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FmaRndSatF32Abtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "fma::section_2::FmaRndSatF32Abtype", err),
             }
         }
         "fns" => {
@@ -1262,38 +1352,38 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             match <crate::r#type::instruction::fns::section_0::FnsB32 as PtxParser>::parse()(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::FnsB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "fns::section_0::FnsB32", err),
             }
         }
         "getctarank" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::getctarank::section_0::GetctarankSpaceType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::GetctarankSpaceType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "getctarank::section_0::GetctarankSpaceType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::getctarank::section_0::GetctarankSharedClusterType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::GetctarankSharedClusterType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "getctarank::section_0::GetctarankSharedClusterType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::getctarank::section_0::GetctarankType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::GetctarankType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "getctarank::section_0::GetctarankType", err),
             }
         }
         "griddepcontrol" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::griddepcontrol::section_0::GriddepcontrolAction as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::GriddepcontrolAction(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "griddepcontrol::section_0::GriddepcontrolAction", err),
             }
         }
         "isspacep" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::isspacep::section_0::IsspacepSpace as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::IsspacepSpace(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "isspacep::section_0::IsspacepSpace", err),
             }
         }
         "istypep" => {
@@ -1302,76 +1392,78 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::IstypepType(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "istypep::section_0::IstypepType", err)
+                }
             }
         }
         "ld" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ld_global_nc::section_0::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ld_global_nc::section_0::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ld_global_nc::section_0::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ld_global_nc::section_0::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ld_global_nc::section_0::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ld_global_nc::section_0::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ld_global_nc::section_0::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ld_global_nc::section_0::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ld::section_0::LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ld::section_0::LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ld::section_0::LdWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ld::section_0::LdWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ld::section_0::LdVolatileSsLevelPrefetchSizeVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdVolatileSsLevelPrefetchSizeVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ld::section_0::LdVolatileSsLevelPrefetchSizeVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ld::section_0::LdRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ld::section_0::LdRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ld::section_0::LdAcquireScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdAcquireScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ld::section_0::LdAcquireScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ld::section_0::LdMmioRelaxedSysGlobalType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdMmioRelaxedSysGlobalType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ld::section_0::LdMmioRelaxedSysGlobalType", err),
             }
         }
         "ldmatrix" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ldmatrix::section_0::LdmatrixSyncAlignedShapeNumTransSsType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdmatrixSyncAlignedShapeNumTransSsType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ldmatrix::section_0::LdmatrixSyncAlignedShapeNumTransSsType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ldmatrix::section_0::LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ldmatrix::section_0::LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ldmatrix::section_0::LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ldmatrix::section_0::LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt", err),
             }
         }
         "ldu" => {
@@ -1380,14 +1472,16 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::LduSsType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ldu::section_0::LduSsType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::ldu::section_0::LduSsVecType as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::LduSsVecType(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "ldu::section_0::LduSsVecType", err)
+                }
             }
         }
         "lg2" => {
@@ -1396,7 +1490,9 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::Lg2ApproxFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "lg2::section_0::Lg2ApproxFtzF32", err)
+                }
             }
         }
         "lop3" => {
@@ -1405,14 +1501,16 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::Lop3B32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "lop3::section_0::Lop3B32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::lop3::section_0::Lop3BoolopB32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::Lop3BoolopB32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "lop3::section_0::Lop3BoolopB32", err)
+                }
             }
         }
         "mad" => {
@@ -1421,42 +1519,51 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::MadHiloCcType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "mad_cc::section_0::MadHiloCcType",
+                    err,
+                ),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mad::section_0::MadModeType as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MadModeType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mad::section_0::MadModeType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mad::section_0::MadHiSatS32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MadHiSatS32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mad::section_0::MadHiSatS32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mad::section_0::MadFtzSatF32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MadFtzSatF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "mad::section_0::MadFtzSatF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mad::section_0::MadRndFtzSatF32 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::MadRndFtzSatF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "mad::section_0::MadRndFtzSatF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mad::section_0::MadRndF64 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MadRndF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mad::section_0::MadRndF64", err),
             }
         }
         "mad24" => {
@@ -1465,14 +1572,18 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::Mad24ModeType(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "mad24::section_0::Mad24ModeType", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mad24::section_0::Mad24HiSatS32 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::Mad24HiSatS32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "mad24::section_0::Mad24HiSatS32", err)
+                }
             }
         }
         "madc" => {
@@ -1481,7 +1592,9 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::MadcHiloCcType(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "madc::section_0::MadcHiloCcType", err)
+                }
             }
         }
         "mapa" => {
@@ -1490,19 +1603,21 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MapaSpaceType(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "mapa::section_0::MapaSpaceType", err)
+                }
             }
         }
         "match" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::match_sync::section_0::MatchAnySyncType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MatchAnySyncType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "match_sync::section_0::MatchAnySyncType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::match_sync::section_0::MatchAllSyncType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MatchAllSyncType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "match_sync::section_0::MatchAllSyncType", err),
             }
         }
         "max" => {
@@ -1511,149 +1626,153 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MaxAtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "max::section_0::MaxAtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::max::section_0::MaxReluBtype as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MaxReluBtype(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "max::section_0::MaxReluBtype", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::max::section_0::MaxFtzNanXorsignAbsF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MaxFtzNanXorsignAbsF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "max::section_0::MaxFtzNanXorsignAbsF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::max::section_0::MaxFtzNanAbsF32 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::MaxFtzNanAbsF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "max::section_0::MaxFtzNanAbsF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::max::section_0::MaxF64 as PtxParser>::parse()(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::MaxF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "max::section_0::MaxF64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::max::section_0::MaxFtzNanXorsignAbsF16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MaxFtzNanXorsignAbsF16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "max::section_0::MaxFtzNanXorsignAbsF16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::max::section_0::MaxFtzNanXorsignAbsF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MaxFtzNanXorsignAbsF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "max::section_0::MaxFtzNanXorsignAbsF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::max::section_0::MaxNanXorsignAbsBf16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MaxNanXorsignAbsBf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "max::section_0::MaxNanXorsignAbsBf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::max::section_0::MaxNanXorsignAbsBf16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MaxNanXorsignAbsBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "max::section_0::MaxNanXorsignAbsBf16x2", err),
             }
         }
         "mbarrier" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_arrive::section_0::MbarrierArriveSemScopeStateB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierArriveSemScopeStateB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_arrive::section_0::MbarrierArriveSemScopeStateB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_arrive::section_0::MbarrierArriveSemScopeSharedClusterB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierArriveSemScopeSharedClusterB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_arrive::section_0::MbarrierArriveSemScopeSharedClusterB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_arrive::section_0::MbarrierArriveExpectTxSemScopeStateB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierArriveExpectTxSemScopeStateB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_arrive::section_0::MbarrierArriveExpectTxSemScopeStateB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_arrive::section_0::MbarrierArriveExpectTxSemScopeSharedClusterB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierArriveExpectTxSemScopeSharedClusterB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_arrive::section_0::MbarrierArriveExpectTxSemScopeSharedClusterB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_arrive::section_0::MbarrierArriveNocompleteReleaseCtaStateB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierArriveNocompleteReleaseCtaStateB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_arrive::section_0::MbarrierArriveNocompleteReleaseCtaStateB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_arrive_drop::section_0::MbarrierArriveDropSemScopeStateB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierArriveDropSemScopeStateB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_arrive_drop::section_0::MbarrierArriveDropSemScopeStateB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_arrive_drop::section_0::MbarrierArriveDropSemScopeSharedClusterB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierArriveDropSemScopeSharedClusterB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_arrive_drop::section_0::MbarrierArriveDropSemScopeSharedClusterB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_arrive_drop::section_0::MbarrierArriveDropExpectTxStateSemScopeB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierArriveDropExpectTxStateSemScopeB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_arrive_drop::section_0::MbarrierArriveDropExpectTxStateSemScopeB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_arrive_drop::section_0::MbarrierArriveDropExpectTxSharedClusterSemScopeB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierArriveDropExpectTxSharedClusterSemScopeB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_arrive_drop::section_0::MbarrierArriveDropExpectTxSharedClusterSemScopeB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_arrive_drop::section_0::MbarrierArriveDropNocompleteReleaseCtaStateB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierArriveDropNocompleteReleaseCtaStateB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_arrive_drop::section_0::MbarrierArriveDropNocompleteReleaseCtaStateB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_complete_tx::section_0::MbarrierCompleteTxSemScopeSpaceB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierCompleteTxSemScopeSpaceB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_complete_tx::section_0::MbarrierCompleteTxSemScopeSpaceB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_expect_tx::section_0::MbarrierExpectTxSemScopeSpaceB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierExpectTxSemScopeSpaceB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_expect_tx::section_0::MbarrierExpectTxSemScopeSpaceB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_init::section_0::MbarrierInitStateB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierInitStateB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_init::section_0::MbarrierInitStateB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_inval::section_0::MbarrierInvalStateB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierInvalStateB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_inval::section_0::MbarrierInvalStateB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_pending_count::section_0::MbarrierPendingCountB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierPendingCountB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_pending_count::section_0::MbarrierPendingCountB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_test_wait::section_0::MbarrierTestWaitSemScopeStateB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierTestWaitSemScopeStateB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_test_wait::section_0::MbarrierTestWaitSemScopeStateB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_test_wait::section_0::MbarrierTestWaitParitySemScopeStateB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierTestWaitParitySemScopeStateB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_test_wait::section_0::MbarrierTestWaitParitySemScopeStateB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_test_wait::section_0::MbarrierTryWaitSemScopeStateB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierTryWaitSemScopeStateB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_test_wait::section_0::MbarrierTryWaitSemScopeStateB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mbarrier_test_wait::section_0::MbarrierTryWaitParitySemScopeStateB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MbarrierTryWaitParitySemScopeStateB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mbarrier_test_wait::section_0::MbarrierTryWaitParitySemScopeStateB64", err),
             }
         }
         "membar" => {
@@ -1662,59 +1781,66 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::FenceSemScope(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "membar::section_0::FenceSemScope",
+                    err,
+                ),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceAcquireSyncRestrictSharedClusterCluster as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceAcquireSyncRestrictSharedClusterCluster(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceAcquireSyncRestrictSharedClusterCluster", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceReleaseSyncRestrictSharedCtaCluster as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceReleaseSyncRestrictSharedCtaCluster(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceReleaseSyncRestrictSharedCtaCluster", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceOpRestrictReleaseCluster as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceOpRestrictReleaseCluster(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceOpRestrictReleaseCluster", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceProxyProxykind as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceProxyProxykind(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceProxyProxykind", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceProxyToProxykindFromProxykindReleaseScope as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceProxyToProxykindFromProxykindReleaseScope(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceProxyToProxykindFromProxykindReleaseScope", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceProxyToProxykindFromProxykindAcquireScope as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceProxyToProxykindFromProxykindAcquireScope(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceProxyToProxykindFromProxykindAcquireScope", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::MembarLevel as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MembarLevel(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "membar::section_0::MembarLevel", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::membar::section_0::MembarProxyProxykind as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MembarProxyProxykind(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "membar::section_0::MembarProxyProxykind", err),
             }
         }
         "min" => {
@@ -1723,194 +1849,198 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MinAtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "min::section_0::MinAtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::min::section_0::MinReluBtype as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MinReluBtype(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "min::section_0::MinReluBtype", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::min::section_0::MinFtzNanXorsignAbsF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MinFtzNanXorsignAbsF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "min::section_0::MinFtzNanXorsignAbsF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::min::section_0::MinFtzNanAbsF32 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::MinFtzNanAbsF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "min::section_0::MinFtzNanAbsF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::min::section_0::MinF64 as PtxParser>::parse()(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::MinF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "min::section_0::MinF64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::min::section_0::MinFtzNanXorsignAbsF16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MinFtzNanXorsignAbsF16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "min::section_0::MinFtzNanXorsignAbsF16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::min::section_0::MinFtzNanXorsignAbsF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MinFtzNanXorsignAbsF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "min::section_0::MinFtzNanXorsignAbsF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::min::section_0::MinNanXorsignAbsBf16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MinNanXorsignAbsBf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "min::section_0::MinNanXorsignAbsBf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::min::section_0::MinNanXorsignAbsBf16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MinNanXorsignAbsBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "min::section_0::MinNanXorsignAbsBf16x2", err),
             }
         }
         "mma" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_0::MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_0::MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_0::MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_0::MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_1::MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_1::MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_1::MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_1::MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_1::MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_1::MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_1::MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_1::MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_1::MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_1::MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_1::MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_1::MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_2::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_2::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_3::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_3::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_4::MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_4::MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_5::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_5::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma_sp::section_6::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma_sp::section_6::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_0::MmaSyncAlignedM8n8k4AlayoutBlayoutDtypeF16F16Ctype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedM8n8k4AlayoutBlayoutDtypeF16F16Ctype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_0::MmaSyncAlignedM8n8k4AlayoutBlayoutDtypeF16F16Ctype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_0::MmaSyncAlignedM16n8k8RowColDtypeF16F16Ctype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedM16n8k8RowColDtypeF16F16Ctype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_0::MmaSyncAlignedM16n8k8RowColDtypeF16F16Ctype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_0::MmaSyncAlignedM16n8k16RowColDtypeF16F16Ctype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedM16n8k16RowColDtypeF16F16Ctype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_0::MmaSyncAlignedM16n8k16RowColDtypeF16F16Ctype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_1::MmaSyncAlignedM16n8k4RowColF32Tf32Tf32F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedM16n8k4RowColF32Tf32Tf32F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_1::MmaSyncAlignedM16n8k4RowColF32Tf32Tf32F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_1::MmaSyncAlignedM16n8k8RowColF32AtypeBtypeF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedM16n8k8RowColF32AtypeBtypeF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_1::MmaSyncAlignedM16n8k8RowColF32AtypeBtypeF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_1::MmaSyncAlignedM16n8k16RowColF32Bf16Bf16F32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedM16n8k16RowColF32Bf16Bf16F32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_1::MmaSyncAlignedM16n8k16RowColF32Bf16Bf16F32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_1::MmaSyncAlignedShapeRowColDtypeF8typeF8typeCtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedShapeRowColDtypeF8typeF8typeCtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_1::MmaSyncAlignedShapeRowColDtypeF8typeF8typeCtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_1::MmaSyncAlignedM16n8k32RowColKindDtypeF8f6f4typeF8f6f4typeCtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedM16n8k32RowColKindDtypeF8f6f4typeF8f6f4typeCtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_1::MmaSyncAlignedM16n8k32RowColKindDtypeF8f6f4typeF8f6f4typeCtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_2::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_2::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_3::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_3::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_4::MmaSyncAlignedM16n8k32RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedM16n8k32RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_4::MmaSyncAlignedM16n8k32RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_5::MmaSyncAlignedShapeRowColF64F64F64F64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedShapeRowColF64F64F64F64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_5::MmaSyncAlignedShapeRowColF64F64F64F64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_6::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_6::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_7::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_7::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mma::section_8::MmaSyncAlignedShapeRowColS32B1B1S32BitopPopc as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MmaSyncAlignedShapeRowColS32B1B1S32BitopPopc(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mma::section_8::MmaSyncAlignedShapeRowColS32B1B1S32BitopPopc", err),
             }
         }
         "mov" => {
@@ -1919,47 +2049,47 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MovType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mov::section_0::MovType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mov::section_0::MovU32 as PtxParser>::parse()(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::MovU32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mov::section_0::MovU32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mov::section_0::MovU64 as PtxParser>::parse()(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::MovU64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mov::section_0::MovU64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mov::section_0::MovU321 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MovU321(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mov::section_0::MovU321", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mov::section_0::MovU641 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MovU641(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mov::section_0::MovU641", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mov::section_1::MovType1 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MovType1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mov::section_1::MovType1", err),
             }
         }
         "movmatrix" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::movmatrix::section_0::MovmatrixSyncAlignedShapeTransType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MovmatrixSyncAlignedShapeTransType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "movmatrix::section_0::MovmatrixSyncAlignedShapeTransType", err),
             }
         }
         "mul" => {
@@ -1968,54 +2098,62 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MulModeType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mul::section_0::MulModeType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mul::section_1::MulRndFtzSatF32 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::MulRndFtzSatF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "mul::section_1::MulRndFtzSatF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mul::section_1::MulRndFtzF32x2 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MulRndFtzF32x2(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "mul::section_1::MulRndFtzF32x2", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mul::section_1::MulRndF64 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MulRndF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mul::section_1::MulRndF64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mul::section_2::MulRndFtzSatF16 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::MulRndFtzSatF16(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "mul::section_2::MulRndFtzSatF16", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mul::section_2::MulRndFtzSatF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MulRndFtzSatF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mul::section_2::MulRndFtzSatF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mul::section_2::MulRndBf16 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MulRndBf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "mul::section_2::MulRndBf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::mul::section_2::MulRndBf16x2 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::MulRndBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "mul::section_2::MulRndBf16x2", err)
+                }
             }
         }
         "mul24" => {
@@ -2024,66 +2162,68 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::Mul24ModeType(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "mul24::section_0::Mul24ModeType", err)
+                }
             }
         }
         "multimem" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::multimem_ld_reduce::section_0::MultimemLdReduceLdsemScopeSsOpType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MultimemLdReduceLdsemScopeSsOpType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "multimem_ld_reduce::section_0::MultimemLdReduceLdsemScopeSsOpType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::multimem_ld_reduce::section_0::MultimemLdReduceWeakSsOpType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MultimemLdReduceWeakSsOpType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "multimem_ld_reduce::section_0::MultimemLdReduceWeakSsOpType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::multimem_ld_reduce::section_0::MultimemStStsemScopeSsType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MultimemStStsemScopeSsType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "multimem_ld_reduce::section_0::MultimemStStsemScopeSsType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::multimem_ld_reduce::section_0::MultimemStWeakSsType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MultimemStWeakSsType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "multimem_ld_reduce::section_0::MultimemStWeakSsType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::multimem_ld_reduce::section_0::MultimemRedRedsemScopeSsOpType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MultimemRedRedsemScopeSsOpType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "multimem_ld_reduce::section_0::MultimemRedRedsemScopeSsOpType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::multimem_ld_reduce::section_1::MultimemLdReduceLdsemScopeSsOpAccPrecVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MultimemLdReduceLdsemScopeSsOpAccPrecVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "multimem_ld_reduce::section_1::MultimemLdReduceLdsemScopeSsOpAccPrecVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::multimem_ld_reduce::section_1::MultimemLdReduceWeakSsOpAccPrecVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MultimemLdReduceWeakSsOpAccPrecVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "multimem_ld_reduce::section_1::MultimemLdReduceWeakSsOpAccPrecVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::multimem_ld_reduce::section_1::MultimemStStsemScopeSsVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MultimemStStsemScopeSsVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "multimem_ld_reduce::section_1::MultimemStStsemScopeSsVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::multimem_ld_reduce::section_1::MultimemStWeakSsVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MultimemStWeakSsVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "multimem_ld_reduce::section_1::MultimemStWeakSsVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::multimem_ld_reduce::section_1::MultimemRedRedsemScopeSsRedopVecRedtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::MultimemRedRedsemScopeSsRedopVecRedtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "multimem_ld_reduce::section_1::MultimemRedRedsemScopeSsRedopVecRedtype", err),
             }
         }
         "nanosleep" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::nanosleep::section_0::NanosleepU32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::NanosleepU32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "nanosleep::section_0::NanosleepU32", err),
             }
         }
         "neg" => {
@@ -2092,48 +2232,48 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::NegType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "neg::section_0::NegType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::neg::section_0::NegFtzF32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::NegFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "neg::section_0::NegFtzF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::neg::section_0::NegF64 as PtxParser>::parse()(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::NegF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "neg::section_0::NegF64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::neg::section_0::NegFtzF16 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::NegFtzF16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "neg::section_0::NegFtzF16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::neg::section_0::NegFtzF16x2 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::NegFtzF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "neg::section_0::NegFtzF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::neg::section_0::NegBf16 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::NegBf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "neg::section_0::NegBf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::neg::section_0::NegBf16x2 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::NegBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "neg::section_0::NegBf16x2", err),
             }
         }
         "not" => {
@@ -2142,7 +2282,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::NotType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "not::section_0::NotType", err),
             }
         }
         "or" => {
@@ -2150,7 +2290,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             match <crate::r#type::instruction::or::section_0::OrType as PtxParser>::parse()(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::OrType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "or::section_0::OrType", err),
             }
         }
         "pmevent" => {
@@ -2159,14 +2299,16 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::Pmevent(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "pmevent::section_0::Pmevent", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::pmevent::section_0::PmeventMask as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::PmeventMask(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "pmevent::section_0::PmeventMask", err)
+                }
             }
         }
         "popc" => {
@@ -2175,55 +2317,65 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::PopcType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "popc::section_0::PopcType", err),
             }
         }
         "prefetch" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::prefetch::section_0::PrefetchSpaceLevel as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::PrefetchSpaceLevel(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "prefetch::section_0::PrefetchSpaceLevel", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::prefetch::section_0::PrefetchGlobalLevelEvictionPriority as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::PrefetchGlobalLevelEvictionPriority(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "prefetch::section_0::PrefetchGlobalLevelEvictionPriority", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::prefetch::section_0::PrefetchuL1 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::PrefetchuL1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "prefetch::section_0::PrefetchuL1",
+                    err,
+                ),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::prefetch::section_0::PrefetchTensormapSpaceTensormap as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::PrefetchTensormapSpaceTensormap(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "prefetch::section_0::PrefetchTensormapSpaceTensormap", err),
             }
         }
         "prefetchu" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::prefetch::section_0::PrefetchSpaceLevel as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::PrefetchSpaceLevel(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "prefetch::section_0::PrefetchSpaceLevel", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::prefetch::section_0::PrefetchGlobalLevelEvictionPriority as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::PrefetchGlobalLevelEvictionPriority(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "prefetch::section_0::PrefetchGlobalLevelEvictionPriority", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::prefetch::section_0::PrefetchuL1 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::PrefetchuL1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "prefetch::section_0::PrefetchuL1",
+                    err,
+                ),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::prefetch::section_0::PrefetchTensormapSpaceTensormap as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::PrefetchTensormapSpaceTensormap(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "prefetch::section_0::PrefetchTensormapSpaceTensormap", err),
             }
         }
         "prmt" => {
@@ -2232,119 +2384,125 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::PrmtB32Mode(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "prmt::section_0::PrmtB32Mode", err)
+                }
             }
         }
         "rcp" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::rcp_approx_ftz_f64::section_0::RcpApproxFtzF64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RcpApproxFtzF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "rcp_approx_ftz_f64::section_0::RcpApproxFtzF64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::rcp::section_0::RcpApproxFtzF32 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::RcpApproxFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "rcp::section_0::RcpApproxFtzF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::rcp::section_0::RcpRndFtzF32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::RcpRndFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "rcp::section_0::RcpRndFtzF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::rcp::section_0::RcpRndF64 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::RcpRndF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "rcp::section_0::RcpRndF64", err),
             }
         }
         "red" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red_async::section_0::RedAsyncSemScopeSsCompletionMechanismOpType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedAsyncSemScopeSsCompletionMechanismOpType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red_async::section_0::RedAsyncSemScopeSsCompletionMechanismOpType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red_async::section_1::RedAsyncSemScopeSsCompletionMechanismOpType1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedAsyncSemScopeSsCompletionMechanismOpType1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red_async::section_1::RedAsyncSemScopeSsCompletionMechanismOpType1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red_async::section_2::RedAsyncSemScopeSsCompletionMechanismOpType2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedAsyncSemScopeSsCompletionMechanismOpType2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red_async::section_2::RedAsyncSemScopeSsCompletionMechanismOpType2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red_async::section_3::RedAsyncSemScopeSsCompletionMechanismAddType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedAsyncSemScopeSsCompletionMechanismAddType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red_async::section_3::RedAsyncSemScopeSsCompletionMechanismAddType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red_async::section_4::RedAsyncMmioSemScopeSsAddType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedAsyncMmioSemScopeSsAddType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red_async::section_4::RedAsyncMmioSemScopeSsAddType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red::section_0::RedOpSpaceSemScopeLevelCacheHintType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedOpSpaceSemScopeLevelCacheHintType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red::section_0::RedOpSpaceSemScopeLevelCacheHintType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red::section_0::RedAddSpaceSemScopeNoftzLevelCacheHintF16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red::section_0::RedAddSpaceSemScopeNoftzLevelCacheHintF16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red::section_0::RedAddSpaceSemScopeNoftzLevelCacheHintF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red::section_0::RedAddSpaceSemScopeNoftzLevelCacheHintF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red::section_0::RedAddSpaceSemScopeNoftzLevelCacheHintBf16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red::section_0::RedAddSpaceSemScopeNoftzLevelCacheHintBf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red::section_0::RedAddSpaceSemScopeNoftzLevelCacheHintBf16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red::section_0::RedAddSpaceSemScopeNoftzLevelCacheHintBf16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red::section_1::RedAddSpaceSemScopeLevelCacheHintVec32BitF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedAddSpaceSemScopeLevelCacheHintVec32BitF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red::section_1::RedAddSpaceSemScopeLevelCacheHintVec32BitF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red::section_1::RedOpSpaceSemScopeNoftzLevelCacheHintVec16BitHalfWordType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec16BitHalfWordType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red::section_1::RedOpSpaceSemScopeNoftzLevelCacheHintVec16BitHalfWordType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::red::section_1::RedOpSpaceSemScopeNoftzLevelCacheHintVec32BitPackedType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec32BitPackedType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "red::section_1::RedOpSpaceSemScopeNoftzLevelCacheHintVec32BitPackedType", err),
             }
         }
         "redux" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::redux_sync::section_0::ReduxSyncOpType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::ReduxSyncOpType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "redux_sync::section_0::ReduxSyncOpType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::redux_sync::section_1::ReduxSyncOpB32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::ReduxSyncOpB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "redux_sync::section_1::ReduxSyncOpB32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::redux_sync::section_2::ReduxSyncOpAbsNanF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::ReduxSyncOpAbsNanF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "redux_sync::section_2::ReduxSyncOpAbsNanF32", err),
             }
         }
         "rem" => {
@@ -2353,7 +2511,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::RemType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "rem::section_0::RemType", err),
             }
         }
         "ret" => {
@@ -2361,26 +2519,31 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             match <crate::r#type::instruction::ret::section_0::RetUni as PtxParser>::parse()(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::RetUni(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "ret::section_0::RetUni", err),
             }
         }
         "rsqrt" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::rsqrt_approx_ftz_f64::section_0::RsqrtApproxFtzF64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RsqrtApproxFtzF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "rsqrt_approx_ftz_f64::section_0::RsqrtApproxFtzF64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::rsqrt::section_0::RsqrtApproxFtzF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::RsqrtApproxFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "rsqrt::section_0::RsqrtApproxFtzF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::rsqrt::section_0::RsqrtApproxF64 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::RsqrtApproxF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "rsqrt::section_0::RsqrtApproxF64",
+                    err,
+                ),
             }
         }
         "sad" => {
@@ -2389,7 +2552,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SadType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sad::section_0::SadType", err),
             }
         }
         "selp" => {
@@ -2398,144 +2561,156 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SelpType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "selp::section_0::SelpType", err),
             }
         }
         "set" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_0::SetCmpopFtzDtypeStype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopFtzDtypeStype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_0::SetCmpopFtzDtypeStype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_0::SetCmpopBoolopFtzDtypeStype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopBoolopFtzDtypeStype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_0::SetCmpopBoolopFtzDtypeStype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_1::SetCmpopFtzF16Stype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopFtzF16Stype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_1::SetCmpopFtzF16Stype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_1::SetCmpopBoolopFtzF16Stype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopBoolopFtzF16Stype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_1::SetCmpopBoolopFtzF16Stype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_1::SetCmpopBf16Stype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopBf16Stype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_1::SetCmpopBf16Stype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_1::SetCmpopBoolopBf16Stype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopBoolopBf16Stype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_1::SetCmpopBoolopBf16Stype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_1::SetCmpopFtzDtypeF16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopFtzDtypeF16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_1::SetCmpopFtzDtypeF16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_1::SetCmpopBoolopFtzDtypeF16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopBoolopFtzDtypeF16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_1::SetCmpopBoolopFtzDtypeF16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_2::SetCmpopDtypeBf16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopDtypeBf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_2::SetCmpopDtypeBf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_2::SetCmpopBoolopDtypeBf16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopBoolopDtypeBf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_2::SetCmpopBoolopDtypeBf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_3::SetCmpopFtzDtypeF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopFtzDtypeF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_3::SetCmpopFtzDtypeF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_3::SetCmpopBoolopFtzDtypeF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopBoolopFtzDtypeF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_3::SetCmpopBoolopFtzDtypeF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_4::SetCmpopDtypeBf16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopDtypeBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_4::SetCmpopDtypeBf16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::set::section_4::SetCmpopBoolopDtypeBf16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetCmpopBoolopDtypeBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "set::section_4::SetCmpopBoolopDtypeBf16x2", err),
             }
         }
         "setmaxnreg" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::setmaxnreg::section_0::SetmaxnregActionSyncAlignedU32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetmaxnregActionSyncAlignedU32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "setmaxnreg::section_0::SetmaxnregActionSyncAlignedU32", err),
             }
         }
         "setp" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::setp::section_0::SetpCmpopFtzType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetpCmpopFtzType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "setp::section_0::SetpCmpopFtzType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::setp::section_0::SetpCmpopBoolopFtzType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetpCmpopBoolopFtzType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "setp::section_0::SetpCmpopBoolopFtzType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::setp::section_1::SetpCmpopFtzF16 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::SetpCmpopFtzF16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "setp::section_1::SetpCmpopFtzF16",
+                    err,
+                ),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::setp::section_1::SetpCmpopBoolopFtzF16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetpCmpopBoolopFtzF16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "setp::section_1::SetpCmpopBoolopFtzF16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::setp::section_1::SetpCmpopFtzF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetpCmpopFtzF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "setp::section_1::SetpCmpopFtzF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::setp::section_1::SetpCmpopBoolopFtzF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetpCmpopBoolopFtzF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "setp::section_1::SetpCmpopBoolopFtzF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::setp::section_1::SetpCmpopBf16 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SetpCmpopBf16(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "setp::section_1::SetpCmpopBf16", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::setp::section_1::SetpCmpopBoolopBf16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetpCmpopBoolopBf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "setp::section_1::SetpCmpopBoolopBf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::setp::section_1::SetpCmpopBf16x2 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::SetpCmpopBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "setp::section_1::SetpCmpopBf16x2",
+                    err,
+                ),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::setp::section_1::SetpCmpopBoolopBf16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SetpCmpopBoolopBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "setp::section_1::SetpCmpopBoolopBf16x2", err),
             }
         }
         "shf" => {
@@ -2544,28 +2719,30 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::ShfLModeB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "shf::section_0::ShfLModeB32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::shf::section_0::ShfRModeB32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::ShfRModeB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "shf::section_0::ShfRModeB32", err),
             }
         }
         "shfl" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::shfl_sync::section_0::ShflSyncModeB32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::ShflSyncModeB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "shfl_sync::section_0::ShflSyncModeB32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::shfl::section_0::ShflModeB32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::ShflModeB32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "shfl::section_0::ShflModeB32", err)
+                }
             }
         }
         "shl" => {
@@ -2574,7 +2751,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::ShlType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "shl::section_0::ShlType", err),
             }
         }
         "shr" => {
@@ -2583,7 +2760,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::ShrType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "shr::section_0::ShrType", err),
             }
         }
         "sin" => {
@@ -2592,7 +2769,9 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::SinApproxFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "sin::section_0::SinApproxFtzF32", err)
+                }
             }
         }
         "slct" => {
@@ -2601,103 +2780,112 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SlctDtypeS32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "slct::section_0::SlctDtypeS32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::slct::section_0::SlctFtzDtypeF32 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::SlctFtzDtypeF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(
+                    &mut closest,
+                    opcode,
+                    "slct::section_0::SlctFtzDtypeF32",
+                    err,
+                ),
             }
         }
         "sqrt" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sqrt::section_0::SqrtApproxFtzF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SqrtApproxFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sqrt::section_0::SqrtApproxFtzF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sqrt::section_0::SqrtRndFtzF32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SqrtRndFtzF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "sqrt::section_0::SqrtRndFtzF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sqrt::section_0::SqrtRndF64 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SqrtRndF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sqrt::section_0::SqrtRndF64", err),
             }
         }
         "st" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::st_async::section_0::StAsyncSemScopeSsCompletionMechanismVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::StAsyncSemScopeSsCompletionMechanismVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "st_async::section_0::StAsyncSemScopeSsCompletionMechanismVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::st_async::section_1::StAsyncMmioSemScopeSsType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::StAsyncMmioSemScopeSsType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "st_async::section_1::StAsyncMmioSemScopeSsType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::st_bulk::section_0::StBulkWeakSharedCta as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::StBulkWeakSharedCta(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "st_bulk::section_0::StBulkWeakSharedCta", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::st::section_0::StWeakSsCopLevelCacheHintVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::StWeakSsCopLevelCacheHintVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "st::section_0::StWeakSsCopLevelCacheHintVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::st::section_0::StWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::StWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "st::section_0::StWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::st::section_0::StVolatileSsVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::StVolatileSsVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "st::section_0::StVolatileSsVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::st::section_0::StRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::StRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "st::section_0::StRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::st::section_0::StReleaseScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::StReleaseScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "st::section_0::StReleaseScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::st::section_0::StMmioRelaxedSysGlobalType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::StMmioRelaxedSysGlobalType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "st::section_0::StMmioRelaxedSysGlobalType", err),
             }
         }
         "stackrestore" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::stackrestore::section_0::StackrestoreType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::StackrestoreType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "stackrestore::section_0::StackrestoreType", err),
             }
         }
         "stacksave" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::stacksave::section_0::StacksaveType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::StacksaveType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "stacksave::section_0::StacksaveType", err),
             }
         }
         "stmatrix" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::stmatrix::section_0::StmatrixSyncAlignedShapeNumTransSsType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::StmatrixSyncAlignedShapeNumTransSsType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "stmatrix::section_0::StmatrixSyncAlignedShapeNumTransSsType", err),
             }
         }
         "sub" => {
@@ -2706,73 +2894,83 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SubCcType(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "sub_cc::section_0::SubCcType", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sub::section_0::SubType as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SubType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sub::section_0::SubType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sub::section_0::SubSatS32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SubSatS32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sub::section_0::SubSatS32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sub::section_1::SubRndFtzSatF32 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::SubRndFtzSatF32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "sub::section_1::SubRndFtzSatF32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sub::section_1::SubRndFtzF32x2 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SubRndFtzF32x2(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "sub::section_1::SubRndFtzF32x2", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sub::section_1::SubRndF64 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SubRndF64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sub::section_1::SubRndF64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sub::section_2::SubRndFtzSatF16 as PtxParser>::parse(
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::SubRndFtzSatF16(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "sub::section_2::SubRndFtzSatF16", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sub::section_2::SubRndFtzSatF16x2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SubRndFtzSatF16x2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sub::section_2::SubRndFtzSatF16x2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sub::section_2::SubRndBf16 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SubRndBf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sub::section_2::SubRndBf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sub::section_2::SubRndBf16x2 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SubRndBf16x2(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "sub::section_2::SubRndBf16x2", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sub::section_3::SubRndSatF32Atype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SubRndSatF32Atype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sub::section_3::SubRndSatF32Atype", err),
             }
         }
         "subc" => {
@@ -2781,14 +2979,14 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SubcCcType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "subc::section_0::SubcCcType", err),
             }
         }
         "suld" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::suld::section_0::SuldBGeomCopVecDtypeMode as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SuldBGeomCopVecDtypeMode(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "suld::section_0::SuldBGeomCopVecDtypeMode", err),
             }
         }
         "suq" => {
@@ -2797,36 +2995,36 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::SuqQueryB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "suq::section_0::SuqQueryB32", err),
             }
         }
         "sured" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sured::section_0::SuredBOpGeomCtypeMode as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SuredBOpGeomCtypeMode(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sured::section_0::SuredBOpGeomCtypeMode", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sured::section_1::SuredPOpGeomCtypeMode as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SuredPOpGeomCtypeMode(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sured::section_1::SuredPOpGeomCtypeMode", err),
             }
         }
         "sust" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sust::section_0::SustBDimCopVecCtypeMode as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SustBDimCopVecCtypeMode(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sust::section_0::SustBDimCopVecCtypeMode", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sust::section_0::SustPDimVecB32Mode as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SustPDimVecB32Mode(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sust::section_0::SustPDimVecB32Mode", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::sust::section_0::SustBAdimCopVecCtypeMode as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::SustBAdimCopVecCtypeMode(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "sust::section_0::SustBAdimCopVecCtypeMode", err),
             }
         }
         "szext" => {
@@ -2835,7 +3033,9 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::SzextModeType(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "szext::section_0::SzextModeType", err)
+                }
             }
         }
         "tanh" => {
@@ -2844,296 +3044,298 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             )(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::TanhApproxType(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "tanh::section_0::TanhApproxType", err)
+                }
             }
         }
         "tcgen05" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_alloc::section_0::Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_alloc::section_0::Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_alloc::section_0::Tcgen05DeallocCtaGroupSyncAlignedB32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05DeallocCtaGroupSyncAlignedB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_alloc::section_0::Tcgen05DeallocCtaGroupSyncAlignedB32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_alloc::section_0::Tcgen05RelinquishAllocPermitCtaGroupSyncAligned as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05RelinquishAllocPermitCtaGroupSyncAligned(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_alloc::section_0::Tcgen05RelinquishAllocPermitCtaGroupSyncAligned", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_commit::section_0::Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_commit::section_0::Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_cp::section_0::Tcgen05CpCtaGroupShapeMulticastDstSrcFmt as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05CpCtaGroupShapeMulticastDstSrcFmt(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_cp::section_0::Tcgen05CpCtaGroupShapeMulticastDstSrcFmt", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_fence::section_0::Tcgen05FenceBeforeThreadSync as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05FenceBeforeThreadSync(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_fence::section_0::Tcgen05FenceBeforeThreadSync", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_fence::section_0::Tcgen05FenceAfterThreadSync as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05FenceAfterThreadSync(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_fence::section_0::Tcgen05FenceAfterThreadSync", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_ld::section_0::Tcgen05LdSyncAlignedShape1NumPackB32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05LdSyncAlignedShape1NumPackB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_ld::section_0::Tcgen05LdSyncAlignedShape1NumPackB32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_ld::section_0::Tcgen05LdSyncAlignedShape2NumPackB32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05LdSyncAlignedShape2NumPackB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_ld::section_0::Tcgen05LdSyncAlignedShape2NumPackB32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_ld::section_0::Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_ld::section_0::Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_ld::section_0::Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_ld::section_0::Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_ld::section_0::Tcgen05LdRedSyncAlignedShape3NumRedopType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05LdRedSyncAlignedShape3NumRedopType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_ld::section_0::Tcgen05LdRedSyncAlignedShape3NumRedopType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_ld::section_0::Tcgen05LdRedSyncAlignedShape4NumRedopType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05LdRedSyncAlignedShape4NumRedopType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_ld::section_0::Tcgen05LdRedSyncAlignedShape4NumRedopType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_0::Tcgen05MmaSpCtaGroupKind as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKind(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_0::Tcgen05MmaSpCtaGroupKind", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_0::Tcgen05MmaSpCtaGroupKind1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKind1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_0::Tcgen05MmaSpCtaGroupKind1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_1::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_1::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_1::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_1::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_2::Tcgen05MmaSpCtaGroupKindCollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKindCollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_2::Tcgen05MmaSpCtaGroupKindCollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_2::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_2::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_2::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_2::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_3::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_3::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_3::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_3::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_4::Tcgen05MmaSpCtaGroupKindI8 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKindI8(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_4::Tcgen05MmaSpCtaGroupKindI8", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_4::Tcgen05MmaSpCtaGroupKindI81 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKindI81(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_4::Tcgen05MmaSpCtaGroupKindI81", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_5::Tcgen05MmaSpCtaGroupKindI8CollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKindI8CollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_5::Tcgen05MmaSpCtaGroupKindI8CollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_5::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_5::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_sp::section_5::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_sp::section_5::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_0::Tcgen05MmaCtaGroupKind as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKind(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_0::Tcgen05MmaCtaGroupKind", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_0::Tcgen05MmaCtaGroupKind1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKind1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_0::Tcgen05MmaCtaGroupKind1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_1::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_1::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_1::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_1::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_2::Tcgen05MmaCtaGroupKindCollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKindCollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_2::Tcgen05MmaCtaGroupKindCollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_2::Tcgen05MmaCtaGroupKindAshiftCollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_2::Tcgen05MmaCtaGroupKindAshiftCollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_2::Tcgen05MmaCtaGroupKindAshiftCollectorUsage1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_2::Tcgen05MmaCtaGroupKindAshiftCollectorUsage1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_3::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_3::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_3::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_3::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_4::Tcgen05MmaCtaGroupKindI8 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKindI8(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_4::Tcgen05MmaCtaGroupKindI8", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_4::Tcgen05MmaCtaGroupKindI81 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKindI81(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_4::Tcgen05MmaCtaGroupKindI81", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_5::Tcgen05MmaCtaGroupKindI8CollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKindI8CollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_5::Tcgen05MmaCtaGroupKindI8CollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_5::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_5::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma::section_5::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma::section_5::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_ws_sp::section_0::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_ws_sp::section_0::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_ws_sp::section_0::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_ws_sp::section_0::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_ws_sp::section_1::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_ws_sp::section_1::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_ws_sp::section_1::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_ws_sp::section_1::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_ws::section_0::Tcgen05MmaWsCtaGroup1KindCollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_ws::section_0::Tcgen05MmaWsCtaGroup1KindCollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_ws::section_0::Tcgen05MmaWsCtaGroup1KindCollectorUsage1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_ws::section_0::Tcgen05MmaWsCtaGroup1KindCollectorUsage1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_ws::section_1::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_ws::section_1::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_mma_ws::section_1::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_mma_ws::section_1::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_shift::section_0::Tcgen05ShiftCtaGroupDown as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05ShiftCtaGroupDown(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_shift::section_0::Tcgen05ShiftCtaGroupDown", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_st::section_0::Tcgen05StSyncAlignedShape1NumUnpackB32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05StSyncAlignedShape1NumUnpackB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_st::section_0::Tcgen05StSyncAlignedShape1NumUnpackB32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_st::section_0::Tcgen05StSyncAlignedShape2NumUnpackB32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05StSyncAlignedShape2NumUnpackB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_st::section_0::Tcgen05StSyncAlignedShape2NumUnpackB32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tcgen05_wait::section_0::Tcgen05WaitOperationSyncAligned as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tcgen05WaitOperationSyncAligned(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tcgen05_wait::section_0::Tcgen05WaitOperationSyncAligned", err),
             }
         }
         "tensormap" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tensormap_cp_fenceproxy::section_0::TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tensormap_cp_fenceproxy::section_0::TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tensormap_replace::section_0::TensormapReplaceModeField1SsB1024Type as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TensormapReplaceModeField1SsB1024Type(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tensormap_replace::section_0::TensormapReplaceModeField1SsB1024Type", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tensormap_replace::section_0::TensormapReplaceModeField2SsB1024Type as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TensormapReplaceModeField2SsB1024Type(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tensormap_replace::section_0::TensormapReplaceModeField2SsB1024Type", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tensormap_replace::section_0::TensormapReplaceModeField3SsB1024Type as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TensormapReplaceModeField3SsB1024Type(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tensormap_replace::section_0::TensormapReplaceModeField3SsB1024Type", err),
             }
         }
         "testp" => {
@@ -3142,71 +3344,73 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::TestpOpType(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "testp::section_0::TestpOpType", err)
+                }
             }
         }
         "tex" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tex::section_0::TexGeomV4DtypeCtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TexGeomV4DtypeCtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tex::section_0::TexGeomV4DtypeCtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tex::section_0::TexGeomV4DtypeCtype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TexGeomV4DtypeCtype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tex::section_0::TexGeomV4DtypeCtype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tex::section_0::TexGeomV2F16x2Ctype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TexGeomV2F16x2Ctype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tex::section_0::TexGeomV2F16x2Ctype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tex::section_0::TexGeomV2F16x2Ctype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TexGeomV2F16x2Ctype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tex::section_0::TexGeomV2F16x2Ctype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tex::section_0::TexBaseGeomV4DtypeCtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TexBaseGeomV4DtypeCtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tex::section_0::TexBaseGeomV4DtypeCtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tex::section_0::TexLevelGeomV4DtypeCtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TexLevelGeomV4DtypeCtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tex::section_0::TexLevelGeomV4DtypeCtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tex::section_0::TexGradGeomV4DtypeCtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TexGradGeomV4DtypeCtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tex::section_0::TexGradGeomV4DtypeCtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tex::section_0::TexBaseGeomV2F16x2Ctype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TexBaseGeomV2F16x2Ctype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tex::section_0::TexBaseGeomV2F16x2Ctype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tex::section_0::TexLevelGeomV2F16x2Ctype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TexLevelGeomV2F16x2Ctype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tex::section_0::TexLevelGeomV2F16x2Ctype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tex::section_0::TexGradGeomV2F16x2Ctype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TexGradGeomV2F16x2Ctype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tex::section_0::TexGradGeomV2F16x2Ctype", err),
             }
         }
         "tld4" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tld4::section_0::Tld4Comp2dV4DtypeF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tld4Comp2dV4DtypeF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tld4::section_0::Tld4Comp2dV4DtypeF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::tld4::section_0::Tld4CompGeomV4DtypeF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Tld4CompGeomV4DtypeF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "tld4::section_0::Tld4CompGeomV4DtypeF32", err),
             }
         }
         "trap" => {
@@ -3214,7 +3418,7 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
             match <crate::r#type::instruction::trap::section_0::Trap as PtxParser>::parse()(stream)
             {
                 Ok((inst, _)) => return Ok(Inst::Trap(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "trap::section_0::Trap", err),
             }
         }
         "txq" => {
@@ -3223,1565 +3427,1573 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::TxqTqueryB32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "txq::section_0::TxqTqueryB32", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::txq::section_0::TxqLevelTlqueryB32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::TxqLevelTlqueryB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "txq::section_0::TxqLevelTlqueryB32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::txq::section_0::TxqSqueryB32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::TxqSqueryB32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "txq::section_0::TxqSqueryB32", err)
+                }
             }
         }
         "vabsdiff" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSat1", err),
             }
         }
         "vabsdiff2" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vadd2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vadd2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vsub2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vsub2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vavrg2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vavrg2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vabsdiff2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vabsdiff2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmin2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmin2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmax2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmax2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vadd2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vadd2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vsub2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vsub2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vavrg2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vavrg2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vabsdiff2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vabsdiff2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmin2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmin2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmax2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmax2DtypeAtypeBtypeAdd", err),
             }
         }
         "vabsdiff4" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vadd4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vadd4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vsub4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vsub4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vavrg4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vavrg4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vabsdiff4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vabsdiff4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmin4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmin4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmax4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmax4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vadd4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vadd4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vsub4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vsub4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vavrg4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vavrg4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vabsdiff4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vabsdiff4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmin4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmin4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmax4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmax4DtypeAtypeBtypeAdd", err),
             }
         }
         "vadd" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSat1", err),
             }
         }
         "vadd2" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vadd2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vadd2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vsub2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vsub2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vavrg2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vavrg2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vabsdiff2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vabsdiff2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmin2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmin2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmax2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmax2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vadd2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vadd2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vsub2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vsub2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vavrg2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vavrg2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vabsdiff2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vabsdiff2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmin2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmin2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmax2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmax2DtypeAtypeBtypeAdd", err),
             }
         }
         "vadd4" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vadd4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vadd4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vsub4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vsub4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vavrg4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vavrg4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vabsdiff4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vabsdiff4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmin4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmin4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmax4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmax4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vadd4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vadd4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vsub4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vsub4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vavrg4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vavrg4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vabsdiff4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vabsdiff4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmin4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmin4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmax4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmax4DtypeAtypeBtypeAdd", err),
             }
         }
         "vavrg2" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vadd2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vadd2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vsub2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vsub2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vavrg2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vavrg2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vabsdiff2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vabsdiff2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmin2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmin2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmax2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmax2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vadd2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vadd2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vsub2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vsub2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vavrg2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vavrg2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vabsdiff2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vabsdiff2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmin2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmin2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmax2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmax2DtypeAtypeBtypeAdd", err),
             }
         }
         "vavrg4" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vadd4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vadd4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vsub4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vsub4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vavrg4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vavrg4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vabsdiff4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vabsdiff4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmin4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmin4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmax4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmax4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vadd4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vadd4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vsub4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vsub4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vavrg4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vavrg4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vabsdiff4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vabsdiff4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmin4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmin4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmax4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmax4DtypeAtypeBtypeAdd", err),
             }
         }
         "vmad" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vmad::section_0::VmadDtypeAtypeBtypeSatScale as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmadDtypeAtypeBtypeSatScale(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vmad::section_0::VmadDtypeAtypeBtypeSatScale", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vmad::section_0::VmadDtypeAtypeBtypePoSatScale as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmadDtypeAtypeBtypePoSatScale(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vmad::section_0::VmadDtypeAtypeBtypePoSatScale", err),
             }
         }
         "vmax" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSat1", err),
             }
         }
         "vmax2" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vadd2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vadd2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vsub2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vsub2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vavrg2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vavrg2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vabsdiff2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vabsdiff2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmin2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmin2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmax2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmax2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vadd2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vadd2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vsub2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vsub2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vavrg2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vavrg2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vabsdiff2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vabsdiff2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmin2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmin2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmax2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmax2DtypeAtypeBtypeAdd", err),
             }
         }
         "vmax4" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vadd4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vadd4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vsub4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vsub4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vavrg4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vavrg4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vabsdiff4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vabsdiff4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmin4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmin4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmax4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmax4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vadd4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vadd4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vsub4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vsub4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vavrg4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vavrg4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vabsdiff4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vabsdiff4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmin4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmin4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmax4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmax4DtypeAtypeBtypeAdd", err),
             }
         }
         "vmin" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSat1", err),
             }
         }
         "vmin2" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vadd2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vadd2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vsub2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vsub2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vavrg2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vavrg2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vabsdiff2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vabsdiff2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmin2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmin2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmax2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmax2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vadd2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vadd2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vsub2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vsub2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vavrg2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vavrg2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vabsdiff2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vabsdiff2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmin2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmin2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmax2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmax2DtypeAtypeBtypeAdd", err),
             }
         }
         "vmin4" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vadd4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vadd4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vsub4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vsub4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vavrg4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vavrg4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vabsdiff4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vabsdiff4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmin4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmin4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmax4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmax4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vadd4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vadd4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vsub4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vsub4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vavrg4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vavrg4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vabsdiff4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vabsdiff4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmin4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmin4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmax4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmax4DtypeAtypeBtypeAdd", err),
             }
         }
         "vote" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vote_sync::section_0::VoteSyncModePred as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VoteSyncModePred(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vote_sync::section_0::VoteSyncModePred", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vote_sync::section_0::VoteSyncBallotB32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VoteSyncBallotB32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vote_sync::section_0::VoteSyncBallotB32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vote::section_0::VoteModePred as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::VoteModePred(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "vote::section_0::VoteModePred", err)
+                }
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vote::section_0::VoteBallotB32 as PtxParser>::parse()(
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::VoteBallotB32(inst)),
-                Err(_) => {}
+                Err(err) => {
+                    keep_furthest(&mut closest, opcode, "vote::section_0::VoteBallotB32", err)
+                }
             }
         }
         "vset" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vset::section_0::VsetAtypeBtypeCmp as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsetAtypeBtypeCmp(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vset::section_0::VsetAtypeBtypeCmp", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vset::section_0::VsetAtypeBtypeCmpOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsetAtypeBtypeCmpOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vset::section_0::VsetAtypeBtypeCmpOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vset::section_0::VsetAtypeBtypeCmp1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsetAtypeBtypeCmp1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vset::section_0::VsetAtypeBtypeCmp1", err),
             }
         }
         "vset2" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vset2::section_0::Vset2AtypeBtypeCmp as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vset2AtypeBtypeCmp(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vset2::section_0::Vset2AtypeBtypeCmp", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vset2::section_0::Vset2AtypeBtypeCmpAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vset2AtypeBtypeCmpAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vset2::section_0::Vset2AtypeBtypeCmpAdd", err),
             }
         }
         "vset4" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vset4::section_0::Vset4AtypeBtypeCmp as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vset4AtypeBtypeCmp(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vset4::section_0::Vset4AtypeBtypeCmp", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vset4::section_0::Vset4AtypeBtypeCmpAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vset4AtypeBtypeCmpAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vset4::section_0::Vset4AtypeBtypeCmpAdd", err),
             }
         }
         "vshl" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vsh::section_0::VshlDtypeAtypeU32SatMode as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VshlDtypeAtypeU32SatMode(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vsh::section_0::VshlDtypeAtypeU32SatMode", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vsh::section_0::VshrDtypeAtypeU32SatMode as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VshrDtypeAtypeU32SatMode(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vsh::section_0::VshrDtypeAtypeU32SatMode", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vsh::section_0::VshlDtypeAtypeU32SatModeOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VshlDtypeAtypeU32SatModeOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vsh::section_0::VshlDtypeAtypeU32SatModeOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vsh::section_0::VshrDtypeAtypeU32SatModeOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VshrDtypeAtypeU32SatModeOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vsh::section_0::VshrDtypeAtypeU32SatModeOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vsh::section_0::VshlDtypeAtypeU32SatMode1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VshlDtypeAtypeU32SatMode1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vsh::section_0::VshlDtypeAtypeU32SatMode1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vsh::section_0::VshrDtypeAtypeU32SatMode1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VshrDtypeAtypeU32SatMode1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vsh::section_0::VshrDtypeAtypeU32SatMode1", err),
             }
         }
         "vshr" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vsh::section_0::VshlDtypeAtypeU32SatMode as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VshlDtypeAtypeU32SatMode(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vsh::section_0::VshlDtypeAtypeU32SatMode", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vsh::section_0::VshrDtypeAtypeU32SatMode as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VshrDtypeAtypeU32SatMode(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vsh::section_0::VshrDtypeAtypeU32SatMode", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vsh::section_0::VshlDtypeAtypeU32SatModeOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VshlDtypeAtypeU32SatModeOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vsh::section_0::VshlDtypeAtypeU32SatModeOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vsh::section_0::VshrDtypeAtypeU32SatModeOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VshrDtypeAtypeU32SatModeOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vsh::section_0::VshrDtypeAtypeU32SatModeOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vsh::section_0::VshlDtypeAtypeU32SatMode1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VshlDtypeAtypeU32SatMode1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vsh::section_0::VshlDtypeAtypeU32SatMode1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vsh::section_0::VshrDtypeAtypeU32SatMode1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VshrDtypeAtypeU32SatMode1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vsh::section_0::VshrDtypeAtypeU32SatMode1", err),
             }
         }
         "vsub" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSatOp2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSatOp2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSatOp2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VaddDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VaddDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VaddDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VsubDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VsubDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VsubDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VabsdiffDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VabsdiffDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VabsdiffDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VminDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VminDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VminDtypeAtypeBtypeSat1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop::section_0::VmaxDtypeAtypeBtypeSat1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::VmaxDtypeAtypeBtypeSat1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop::section_0::VmaxDtypeAtypeBtypeSat1", err),
             }
         }
         "vsub2" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vadd2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vadd2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vsub2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vsub2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vavrg2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vavrg2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vabsdiff2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vabsdiff2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmin2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmin2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmax2DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax2DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmax2DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vadd2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vadd2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vsub2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vsub2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vavrg2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vavrg2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vabsdiff2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vabsdiff2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmin2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmin2DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop2::section_0::Vmax2DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax2DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop2::section_0::Vmax2DtypeAtypeBtypeAdd", err),
             }
         }
         "vsub4" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vadd4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vadd4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vsub4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vsub4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vavrg4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vavrg4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vabsdiff4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vabsdiff4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmin4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmin4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmax4DtypeAtypeBtypeSat as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax4DtypeAtypeBtypeSat(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmax4DtypeAtypeBtypeSat", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vadd4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vadd4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vadd4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vsub4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vsub4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vsub4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vavrg4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vavrg4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vavrg4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vabsdiff4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vabsdiff4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vabsdiff4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmin4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmin4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmin4DtypeAtypeBtypeAdd", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::vop4::section_0::Vmax4DtypeAtypeBtypeAdd as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::Vmax4DtypeAtypeBtypeAdd(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "vop4::section_0::Vmax4DtypeAtypeBtypeAdd", err),
             }
         }
         "wgmma" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_commit_group::section_0::WgmmaCommitGroupSyncAligned as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaCommitGroupSyncAligned(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_commit_group::section_0::WgmmaCommitGroupSyncAligned", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_fence::section_0::WgmmaFenceSyncAligned as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaFenceSyncAligned(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_fence::section_0::WgmmaFenceSyncAligned", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async_sp::section_0::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async_sp::section_0::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async_sp::section_0::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async_sp::section_0::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async_sp::section_1::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async_sp::section_1::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async_sp::section_1::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async_sp::section_1::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async_sp::section_2::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async_sp::section_2::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async_sp::section_2::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async_sp::section_2::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async_sp::section_3::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async_sp::section_3::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async_sp::section_3::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async_sp::section_3::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async_sp::section_4::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async_sp::section_4::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async_sp::section_4::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async_sp::section_4::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async::section_0::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async::section_0::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async::section_0::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async::section_0::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async::section_1::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async::section_1::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async::section_1::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async::section_1::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async::section_2::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async::section_2::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async::section_2::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async::section_2::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async::section_3::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async::section_3::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async::section_3::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async::section_3::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async::section_4::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async::section_4::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async::section_4::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async::section_4::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async::section_5::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async::section_5::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_mma_async::section_5::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_mma_async::section_5::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wgmma_wait_group::section_0::WgmmaWaitGroupSyncAligned as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WgmmaWaitGroupSyncAligned(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wgmma_wait_group::section_0::WgmmaWaitGroupSyncAligned", err),
             }
         }
         "wmma" => {
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_0::WmmaLoadASyncAlignedLayoutShapeSsAtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_0::WmmaLoadASyncAlignedLayoutShapeSsAtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_0::WmmaLoadBSyncAlignedLayoutShapeSsBtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_0::WmmaLoadBSyncAlignedLayoutShapeSsBtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_0::WmmaLoadCSyncAlignedLayoutShapeSsCtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_0::WmmaLoadCSyncAlignedLayoutShapeSsCtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_1::WmmaLoadASyncAlignedLayoutShapeSsAtype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_1::WmmaLoadASyncAlignedLayoutShapeSsAtype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_1::WmmaLoadBSyncAlignedLayoutShapeSsBtype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_1::WmmaLoadBSyncAlignedLayoutShapeSsBtype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_1::WmmaLoadCSyncAlignedLayoutShapeSsCtype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_1::WmmaLoadCSyncAlignedLayoutShapeSsCtype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_2::WmmaLoadASyncAlignedLayoutShapeSsAtype2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_2::WmmaLoadASyncAlignedLayoutShapeSsAtype2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_2::WmmaLoadBSyncAlignedLayoutShapeSsBtype2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_2::WmmaLoadBSyncAlignedLayoutShapeSsBtype2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_2::WmmaLoadCSyncAlignedLayoutShapeSsCtype2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_2::WmmaLoadCSyncAlignedLayoutShapeSsCtype2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_3::WmmaLoadASyncAlignedLayoutShapeSsAtype3 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype3(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_3::WmmaLoadASyncAlignedLayoutShapeSsAtype3", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_3::WmmaLoadBSyncAlignedLayoutShapeSsBtype3 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype3(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_3::WmmaLoadBSyncAlignedLayoutShapeSsBtype3", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_3::WmmaLoadCSyncAlignedLayoutShapeSsCtype3 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype3(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_3::WmmaLoadCSyncAlignedLayoutShapeSsCtype3", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_4::WmmaLoadASyncAlignedRowShapeSsAtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadASyncAlignedRowShapeSsAtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_4::WmmaLoadASyncAlignedRowShapeSsAtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_4::WmmaLoadBSyncAlignedColShapeSsBtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadBSyncAlignedColShapeSsBtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_4::WmmaLoadBSyncAlignedColShapeSsBtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_4::WmmaLoadCSyncAlignedLayoutShapeSsCtype4 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype4(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_4::WmmaLoadCSyncAlignedLayoutShapeSsCtype4", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_5::WmmaLoadASyncAlignedRowShapeSsAtype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadASyncAlignedRowShapeSsAtype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_5::WmmaLoadASyncAlignedRowShapeSsAtype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_5::WmmaLoadBSyncAlignedColShapeSsBtype1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadBSyncAlignedColShapeSsBtype1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_5::WmmaLoadBSyncAlignedColShapeSsBtype1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_load::section_5::WmmaLoadCSyncAlignedLayoutShapeSsCtype5 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype5(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_load::section_5::WmmaLoadCSyncAlignedLayoutShapeSsCtype5", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_mma::section_0::WmmaMmaSyncAlignedAlayoutBlayoutShapeDtypeCtype as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeDtypeCtype(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_mma::section_0::WmmaMmaSyncAlignedAlayoutBlayoutShapeDtypeCtype", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_mma::section_1::WmmaMmaSyncAlignedAlayoutBlayoutShapeS32AtypeBtypeS32Satfinite as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeS32AtypeBtypeS32Satfinite(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_mma::section_1::WmmaMmaSyncAlignedAlayoutBlayoutShapeS32AtypeBtypeS32Satfinite", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_mma::section_2::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_mma::section_2::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_mma::section_3::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF321 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF321(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_mma::section_3::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF321", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_mma::section_4::WmmaMmaSyncAlignedAlayoutBlayoutShapeRndF64F64F64F64 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeRndF64F64F64F64(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_mma::section_4::WmmaMmaSyncAlignedAlayoutBlayoutShapeRndF64F64F64F64", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_mma::section_5::WmmaMmaSyncAlignedRowColShapeS32AtypeBtypeS32Satfinite as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaMmaSyncAlignedRowColShapeS32AtypeBtypeS32Satfinite(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_mma::section_5::WmmaMmaSyncAlignedRowColShapeS32AtypeBtypeS32Satfinite", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_mma::section_6::WmmaMmaOpPopcSyncAlignedRowColShapeS32AtypeBtypeS32 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaMmaOpPopcSyncAlignedRowColShapeS32AtypeBtypeS32(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_mma::section_6::WmmaMmaOpPopcSyncAlignedRowColShapeS32AtypeBtypeS32", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_store::section_0::WmmaStoreDSyncAlignedLayoutShapeSsType as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaStoreDSyncAlignedLayoutShapeSsType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_store::section_0::WmmaStoreDSyncAlignedLayoutShapeSsType", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_store::section_1::WmmaStoreDSyncAlignedLayoutShapeSsType1 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaStoreDSyncAlignedLayoutShapeSsType1(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_store::section_1::WmmaStoreDSyncAlignedLayoutShapeSsType1", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_store::section_2::WmmaStoreDSyncAlignedLayoutShapeSsType2 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaStoreDSyncAlignedLayoutShapeSsType2(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_store::section_2::WmmaStoreDSyncAlignedLayoutShapeSsType2", err),
             }
             stream.set_position(start_pos);
             match <crate::r#type::instruction::wmma_store::section_3::WmmaStoreDSyncAlignedLayoutShapeSsType3 as PtxParser>::parse()(stream) {
                 Ok((inst, _)) => return Ok(Inst::WmmaStoreDSyncAlignedLayoutShapeSsType3(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "wmma_store::section_3::WmmaStoreDSyncAlignedLayoutShapeSsType3", err),
             }
         }
         "xor" => {
@@ -4790,13 +5002,23 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
                 stream,
             ) {
                 Ok((inst, _)) => return Ok(Inst::XorType(inst)),
-                Err(_) => {}
+                Err(err) => keep_furthest(&mut closest, opcode, "xor::section_0::XorType", err),
             }
         }
         _ => {}
     }
 
-    // If no parser matched, return error
+    // If no parser matched, report the candidate that got furthest
+    if let Some((candidate, cause)) = closest {
+        return Err(PtxParseError {
+            kind: ParseErrorKind::NoMatchingInstruction {
+                opcode: opcode.to_string(),
+                candidate: candidate.to_string(),
+                cause: Box::new(cause.kind),
+            },
+            span: cause.span,
+        });
+    }
     let span = stream
         .peek()
         .map(|(_, s)| s.clone())
@@ -4808,6 +5030,25 @@ pub(crate) fn parse_instruction_inner(stream: &mut PtxTokenStream) -> Result<Ins
     ))
 }
 
+/// Keep `err` if its candidate got further into the instruction than the current best.
+///
+/// On a tie, a format of the opcode's own module wins over one of a sibling module
+/// such as `ld_global_nc` for `ld`.
+fn keep_furthest(
+    closest: &mut Option<(&'static str, PtxParseError)>,
+    opcode: &str,
+    candidate: &'static str,
+    err: PtxParseError,
+) {
+    let primary = |candidate: &str| candidate.split("::").next() == Some(opcode);
+    if closest.as_ref().is_none_or(|(best_candidate, best)| {
+        err.span.start > best.span.start
+            || (err.span.start == best.span.start && primary(candidate) && !primary(best_candidate))
+    }) {
+        *closest = Some((candidate, err));
+    }
+}
+
 impl PtxParser for Inst {
     fn parse() -> impl Fn(&mut PtxTokenStream) -> Result<(Self, Span), PtxParseError> {
         |stream| stream.try_with_span(|stream| parse_instruction_inner(stream))
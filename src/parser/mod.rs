@@ -1,5 +1,9 @@
-use crate::{LexError, lexer::PtxToken, span};
-use serde::Serialize;
+use crate::{
+    LexError,
+    lexer::{LexErrorKind, PtxToken},
+    span,
+};
+use serde::{Deserialize, Serialize};
 #[cfg(debug_assertions)]
 use stacker;
 use thiserror::Error;
@@ -8,10 +12,11 @@ pub(crate) mod common;
 pub(crate) mod function;
 pub(crate) mod instruction;
 pub(crate) mod module;
+pub(crate) mod recovery;
 pub(crate) mod util;
 pub(crate) mod variable;
 
-#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Serialize)]
+#[derive(Debug, Default, PartialEq, Eq, Copy, Clone, Hash, Serialize, Deserialize)]
 pub struct Span {
     pub start: usize,
     pub end: usize,
@@ -85,14 +90,11 @@ macro_rules! reject_partial_mode {
 /// ```
 macro_rules! no_candidate_match {
     ($self:expr, $candidates:expr) => {{
-        let span = $self
-            .tokens
-            .get($self.index.0)
-            .map_or(span!(0..0), |(_, s)| *s);
+        let (found, span) = $self.found_here();
         $crate::parser::PtxParseError {
             kind: $crate::parser::ParseErrorKind::UnexpectedToken {
                 expected: $candidates.iter().map(|s| s.to_string()).collect(),
-                found: "no match".to_string(),
+                found,
             },
             span,
         }
@@ -127,11 +129,25 @@ pub enum ParseErrorKind {
     InvalidLiteral(String),
     #[error("cannot use token-based methods in partial mode")]
     InvalidModeForTokenMethod,
+    #[error("{kind}: `{text}`")]
+    Lex { kind: LexErrorKind, text: String },
+    /// No instruction format matched `opcode`.
+    ///
+    /// `candidate` is the format (`module::section::Struct`) that got furthest
+    /// before failing, and `cause` is why it failed there.
+    #[error("no matching format for `{opcode}`: closest candidate `{candidate}` failed: {cause}")]
+    NoMatchingInstruction {
+        opcode: String,
+        candidate: String,
+        cause: Box<ParseErrorKind>,
+    },
 }
 
 /// PTX parsing error with location information.
+///
+/// Displays only the message; [`PtxParseError::render`] adds the location.
 #[derive(Debug, Clone, PartialEq, Eq, Error)]
-#[error("parsing error at {span:?}: {kind}")]
+#[error("{kind}")]
 pub struct PtxParseError {
     pub kind: ParseErrorKind,
     pub span: Span,
@@ -140,7 +156,10 @@ pub struct PtxParseError {
 impl From<LexError> for PtxParseError {
     fn from(err: LexError) -> Self {
         PtxParseError {
-            kind: ParseErrorKind::InvalidLiteral("lexing failed".into()),
+            kind: ParseErrorKind::Lex {
+                kind: err.kind,
+                text: err.text,
+            },
             span: err.span,
         }
     }
@@ -157,6 +176,16 @@ pub struct PtxTokenStream<'a> {
     tokens: &'a [(PtxToken, Span)],
     /// Current position (index) in the tokens list
     index: StreamPosition,
+    /// Recovery state, present only when parsing with error recovery enabled
+    recovery: Option<Recovery<'a>>,
+}
+
+/// State kept by a [`PtxTokenStream`] in recovery mode.
+struct Recovery<'a> {
+    /// Source text the tokens were produced from, used to capture raw statements
+    source: &'a str,
+    /// Errors recorded so far, in source order
+    errors: Vec<PtxParseError>,
 }
 
 impl<'a> PtxTokenStream<'a> {
@@ -164,9 +193,82 @@ impl<'a> PtxTokenStream<'a> {
         Self {
             tokens,
             index: (0, None),
+            recovery: None,
         }
     }
 
+    /// Create a stream in recovery mode.
+    ///
+    /// In recovery mode, statement lists and module directive lists do not stop
+    /// at the first failure. Instead the error is recorded, the stream is
+    /// resynchronised at the next `;`, `}` or top-level directive, and the
+    /// skipped source text is kept as a [`FunctionStatement::Error`] node.
+    ///
+    /// [`FunctionStatement::Error`]: crate::r#type::FunctionStatement::Error
+    pub fn new_recovering(tokens: &'a [(PtxToken, Span)], source: &'a str) -> Self {
+        Self {
+            tokens,
+            index: (0, None),
+            recovery: Some(Recovery {
+                source,
+                errors: Vec::new(),
+            }),
+        }
+    }
+
+    /// Returns `true` if the stream was created with [`PtxTokenStream::new_recovering`].
+    pub fn is_recovering(&self) -> bool {
+        self.recovery.is_some()
+    }
+
+    /// Record a recovered error. Does nothing outside recovery mode.
+    pub(crate) fn record_error(&mut self, error: PtxParseError) {
+        if let Some(recovery) = &mut self.recovery {
+            recovery.errors.push(error);
+        }
+    }
+
+    /// Take all errors recorded so far, leaving the stream with none.
+    pub fn take_errors(&mut self) -> Vec<PtxParseError> {
+        self.recovery
+            .as_mut()
+            .map(|recovery| std::mem::take(&mut recovery.errors))
+            .unwrap_or_default()
+    }
+
+    /// Source text covered by `span`, if the stream was given the source.
+    pub(crate) fn source_text(&self, span: Span) -> Option<&'a str> {
+        self.recovery
+            .as_ref()
+            .and_then(|recovery| recovery.source.get(span.start..span.end))
+    }
+
+    /// Text and span of the input at the current position, for error messages.
+    ///
+    /// A `.` is reported together with the identifier after it, so that a
+    /// mismatched modifier reads as `.zz` rather than `.`. In partial mode the
+    /// characters of the token already matched are left out.
+    fn found_here(&self) -> (String, Span) {
+        let Some((token, span)) = self.tokens.get(self.index.0) else {
+            let span = self.tokens.last().map_or(span!(0..0), |(_, s)| *s);
+            return ("end of input".to_string(), span);
+        };
+        let mut text: String = token
+            .as_str()
+            .chars()
+            .skip(self.index.1.unwrap_or(0))
+            .collect();
+        let mut span = *span;
+        if let (PtxToken::Dot, Some((PtxToken::Identifier(name), next))) =
+            (token, self.tokens.get(self.index.0 + 1))
+            && next.start == span.end
+        {
+            text.push_str(name);
+            span.end = next.end;
+        }
+        (text, span)
+    }
+
     /// Peek at the next token without consuming it.
     ///
     /// # Behavior for complete mode
@@ -598,7 +700,8 @@ impl<'a> PtxTokenStream<'a> {
     /// Execute a closure with automatic backtracking and span tracking.
     ///
     /// Saves the current stream position before running `f`. If `f` returns an
-    /// error, the stream position (including partial-mode offsets) is restored.
+    /// error, the stream position (including partial-mode offsets) is restored,
+    /// and in recovery mode any errors recorded by `f` are discarded.
     /// When `f` succeeds, this returns the closure result together with the span
     /// covering the consumed source range.
     pub fn try_with_span<F, R>(&mut self, f: F) -> Result<(R, Span), PtxParseError>
@@ -606,6 +709,10 @@ impl<'a> PtxTokenStream<'a> {
         F: FnOnce(&mut PtxTokenStream) -> Result<R, PtxParseError>,
     {
         let start_pos = self.position();
+        let error_mark = self
+            .recovery
+            .as_ref()
+            .map_or(0, |recovery| recovery.errors.len());
         match f(self) {
             Ok(value) => {
                 let end_pos = self.position();
@@ -615,6 +722,9 @@ impl<'a> PtxTokenStream<'a> {
             }
             Err(err) => {
                 self.set_position(start_pos);
+                if let Some(recovery) = &mut self.recovery {
+                    recovery.errors.truncate(error_mark);
+                }
                 Err(err)
             }
         }
@@ -778,6 +888,99 @@ pub fn parse_ptx(source: &str) -> Result<crate::r#type::module::Module, PtxParse
     }
 }
 
+/// Parse PTX source code, recovering from errors instead of stopping at the first one.
+///
+/// Statements that fail to parse are kept in the returned module as
+/// [`FunctionStatement::Error`] nodes carrying their span and source text, and
+/// parsing resumes at the next `;`, `}` or top-level directive. Module-level
+/// directives that fail to parse are skipped up to the next top-level directive.
+/// Every error encountered is returned alongside the partial module, in source order.
+///
+/// # Example
+///
+/// ```no_run
+/// use ptx_parser::parse_ptx_recovering;
+///
+/// let source = r#"
+///     .version 8.5
+///     .target sm_90
+///     .address_size 64
+///
+///     .entry kernel() {
+///         not_an_instruction %r1;
+///         ret;
+///     }
+/// "#;
+///
+/// let (module, errors) = parse_ptx_recovering(source);
+/// assert_eq!(errors.len(), 1);
+/// println!("Parsed {} directives", module.directives.len());
+/// ```
+///
+/// [`FunctionStatement::Error`]: crate::r#type::FunctionStatement::Error
+pub fn parse_ptx_recovering(source: &str) -> (crate::r#type::module::Module, Vec<PtxParseError>) {
+    #[cfg(debug_assertions)]
+    {
+        // Debug builds can have very deep combinator stacks; force a large stack for parsing.
+        stacker::grow(256 * 1024 * 1024, || parse_ptx_recovering_inner(source))
+    }
+
+    #[cfg(not(debug_assertions))]
+    {
+        parse_ptx_recovering_inner(source)
+    }
+}
+
+/// Parse PTX source code and collect its comments.
+///
+/// Behaves like [`parse_ptx`], and additionally attaches every comment in the
+/// source to the directive or statement it belongs to; see [`crate::Comments`].
+/// Passing both results to [`crate::unparse_with_comments`] reproduces the
+/// module with its comments, such as the `// begin inline asm` markers emitted
+/// by nvcc, in place.
+pub fn parse_ptx_with_comments(
+    source: &str,
+) -> Result<(crate::r#type::module::Module, crate::Comments), PtxParseError> {
+    #[cfg(debug_assertions)]
+    {
+        // Debug builds can have very deep combinator stacks; force a large stack for parsing.
+        stacker::grow(256 * 1024 * 1024, || parse_ptx_with_comments_inner(source))
+    }
+
+    #[cfg(not(debug_assertions))]
+    {
+        parse_ptx_with_comments_inner(source)
+    }
+}
+
+fn parse_ptx_with_comments_inner(
+    source: &str,
+) -> Result<(crate::r#type::module::Module, crate::Comments), PtxParseError> {
+    let module = parse_ptx_inner(source)?;
+    // Parsing succeeded, so the source lexes cleanly.
+    let (tokens, trivia) = crate::tokenize_with_trivia(source)?;
+    let comments = crate::Comments::collect(source, &module, &tokens, &trivia);
+    Ok((module, comments))
+}
+
+fn parse_ptx_recovering_inner(source: &str) -> (crate::r#type::module::Module, Vec<PtxParseError>) {
+    use crate::{PtxTokenStream, tokenize_lossy, r#type::Module};
+
+    let (tokens, lex_errors) = tokenize_lossy(source);
+    let mut stream = PtxTokenStream::new_recovering(&tokens, source);
+    let module = match Module::parse()(&mut stream) {
+        Ok((module, _)) => module,
+        Err(err) => {
+            stream.record_error(err);
+            Module::default()
+        }
+    };
+    let mut errors: Vec<PtxParseError> = lex_errors.into_iter().map(Into::into).collect();
+    errors.extend(stream.take_errors());
+    errors.sort_by_key(|err| err.span.start);
+    (module, errors)
+}
+
 fn parse_ptx_inner(source: &str) -> Result<crate::r#type::module::Module, PtxParseError> {
     use crate::{PtxTokenStream, tokenize, r#type::Module};
 
@@ -785,6 +988,14 @@ fn parse_ptx_inner(source: &str) -> Result<crate::r#type::module::Module, PtxPar
     let mut stream = PtxTokenStream::new(&tokens);
     let (module, _) = Module::parse()(&mut stream)?;
     if !stream.is_at_end() {
+        // `many` stops silently at the first directive it cannot parse; parse
+        // again in recovery mode to report why that directive failed.
+        let mut recovering = PtxTokenStream::new_recovering(&tokens, source);
+        let _ = Module::parse()(&mut recovering);
+        if let Some(err) = recovering.take_errors().into_iter().next() {
+            return Err(err);
+        }
+
         let pos = stream.position();
         let remaining = tokens
             .get(pos.0)
@@ -4,9 +4,10 @@ use crate::{
     mapc, ok,
     parser::{
         ParseErrorKind, PtxParseError, PtxParser, PtxTokenStream, Span,
+        recovery::module_directives_p,
         util::{
-            comma_p, directive_exact_p, identifier_p, many, optional, parse_u32_literal, sep_by,
-            seq, skip_first, skip_semicolon, string_literal_p, try_map, u32_p, u64_p,
+            comma_p, directive_exact_p, identifier_p, optional, parse_u32_literal, sep_by, seq,
+            skip_first, skip_semicolon, string_literal_p, try_map, u32_p, u64_p,
         },
     },
     seq_n,
@@ -18,7 +19,7 @@ use crate::{
 
 impl PtxParser for Module {
     fn parse() -> impl Fn(&mut PtxTokenStream) -> Result<(Self, Span), PtxParseError> {
-        mapc!(many(ModuleDirective::parse()), Module { directives })
+        mapc!(module_directives_p(), Module { directives })
     }
 }
 
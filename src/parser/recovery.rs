@@ -0,0 +1,290 @@
+//! Error recovery for statement lists and module directive lists.
+//!
+//! Outside recovery mode the parsers in this module behave exactly like
+//! `many(...)` over their element parser. In recovery mode (see
+//! [`PtxTokenStream::new_recovering`]) a failing element is recorded on the
+//! stream, skipped up to the next synchronisation point, and parsing continues
+//! with the following element. Elements that contain a [`PtxToken::Error`]
+//! from [`crate::tokenize_lossy`] are skipped without recording a parse error,
+//! since the lexing error is reported instead.
+
+use crate::{
+    lexer::PtxToken,
+    parser::{
+        PtxParseError, PtxParser, PtxTokenStream, Span,
+        util::{many, optional},
+    },
+    r#type::{
+        AliasFunctionDirective, DataLinkage, EntryFunctionDirective, FuncFunctionDirective,
+        FunctionStatement, ModuleDebugDirective, ModuleDirective, ModuleInfoDirectiveKind,
+        ModuleVariableDirective, StatementDirective,
+    },
+    unexpected_token,
+};
+
+/// Directive names that only ever start a module-level directive.
+///
+/// Recovery never skips past one of these when it starts a statement, so a
+/// broken statement cannot swallow the function that follows it. Some of them
+/// are also instruction modifiers, as in `ld.global`, which never resync.
+const TOP_LEVEL_DIRECTIVES: &[&str] = &[
+    "version",
+    "target",
+    "address_size",
+    "entry",
+    "func",
+    "alias",
+    "visible",
+    "extern",
+    "weak",
+    "common",
+    "global",
+    "const",
+    "tex",
+    "file",
+];
+
+/// Parse the statements of a function body or nested block.
+///
+/// In recovery mode the list ends at `}`, at a top-level directive or at the
+/// end of input, and every statement that fails to parse becomes a
+/// [`FunctionStatement::Error`] covering the skipped source text.
+pub(crate) fn statements_p()
+-> impl Fn(&mut PtxTokenStream) -> Result<(Vec<FunctionStatement>, Span), PtxParseError> {
+    let statements = many(FunctionStatement::parse());
+    let statement = FunctionStatement::parse();
+    move |stream| {
+        if !stream.is_recovering() {
+            return statements(stream);
+        }
+
+        stream.try_with_span(|stream| {
+            let mut values = Vec::new();
+            while !at_statement_list_end(stream) {
+                match stream.try_with_span(|stream| statement(stream)) {
+                    Ok(((value, _), _)) => values.push(value),
+                    Err(err) => {
+                        let err = statement_error(stream, err);
+                        let start = stream.position().0;
+                        let ((), span) = stream.try_with_span(|stream| {
+                            skip_statement(stream);
+                            Ok(())
+                        })?;
+                        if !skipped_lex_error(stream, start) {
+                            stream.record_error(err);
+                        }
+                        let text = stream.source_text(span).unwrap_or_default().to_string();
+                        values.push(FunctionStatement::Error { text, span });
+                    }
+                }
+            }
+            Ok(values)
+        })
+    }
+}
+
+/// Parse the closing `}` of a function body or nested block.
+///
+/// In recovery mode a missing brace is recorded as an error and the block is
+/// treated as closed, so that a truncated function still yields its statements.
+pub(crate) fn rbrace_recovering_p()
+-> impl Fn(&mut PtxTokenStream) -> Result<((), Span), PtxParseError> {
+    |stream| {
+        if let Some((_, span)) = stream.consume_if(|token| matches!(token, PtxToken::RBrace)) {
+            return Ok(((), *span));
+        }
+
+        let (found, span) = match stream.peek() {
+            Ok((token, span)) => (format!("{:?}", token), *span),
+            Err(err) => ("EOF".to_string(), err.span),
+        };
+        let err = unexpected_token!(span, &["RBrace"], found);
+        if !stream.is_recovering() {
+            return Err(err);
+        }
+        stream.record_error(err);
+        Ok(((), stream.current_span()))
+    }
+}
+
+/// Parse the directives of a module.
+///
+/// In recovery mode a directive that fails to parse is recorded and skipped up
+/// to the next `;` or `}` at brace depth zero, or the next top-level directive.
+pub(crate) fn module_directives_p()
+-> impl Fn(&mut PtxTokenStream) -> Result<(Vec<ModuleDirective>, Span), PtxParseError> {
+    let directives = many(ModuleDirective::parse());
+    let directive = ModuleDirective::parse();
+    move |stream| {
+        if !stream.is_recovering() {
+            return directives(stream);
+        }
+
+        stream.try_with_span(|stream| {
+            let mut values = Vec::new();
+            while !stream.is_at_end() {
+                match stream.try_with_span(|stream| directive(stream)) {
+                    Ok(((value, _), _)) => values.push(value),
+                    Err(err) => {
+                        let err = module_directive_error(stream, err);
+                        let start = stream.position().0;
+                        skip_module_directive(stream);
+                        if !skipped_lex_error(stream, start) {
+                            stream.record_error(err);
+                        }
+                    }
+                }
+            }
+            Ok(values)
+        })
+    }
+}
+
+/// Name of the directive at the current position (`.name`), if any.
+fn peek_directive_name<'a>(stream: &PtxTokenStream<'a>) -> Option<&'a str> {
+    match (stream.peek(), stream.peek_n(1)) {
+        (Ok((PtxToken::Dot, _)), Ok((PtxToken::Identifier(name), _))) => Some(name.as_str()),
+        _ => None,
+    }
+}
+
+fn at_top_level_directive(stream: &PtxTokenStream) -> bool {
+    peek_directive_name(stream).is_some_and(|name| TOP_LEVEL_DIRECTIVES.contains(&name))
+}
+
+/// Returns `true` if the current token starts a statement: it is the first
+/// token, or follows a `;`, a brace or the `:` of a label.
+fn at_statement_start(stream: &PtxTokenStream) -> bool {
+    match stream.position().0.checked_sub(1) {
+        Some(previous) => matches!(
+            stream.tokens[previous].0,
+            PtxToken::Semicolon | PtxToken::LBrace | PtxToken::RBrace | PtxToken::Colon
+        ),
+        None => true,
+    }
+}
+
+/// Returns `true` at a top-level directive that starts a statement, where
+/// statement recovery stops.
+fn at_resync_point(stream: &PtxTokenStream) -> bool {
+    at_statement_start(stream) && at_top_level_directive(stream)
+}
+
+fn at_statement_list_end(stream: &PtxTokenStream) -> bool {
+    match stream.peek() {
+        Ok((PtxToken::RBrace, _)) => true,
+        Ok(_) => at_resync_point(stream),
+        Err(_) => true,
+    }
+}
+
+/// Returns `true` if the tokens skipped since token index `start` include a
+/// [`PtxToken::Error`], whose lexing error already explains the failure.
+fn skipped_lex_error(stream: &PtxTokenStream, start: usize) -> bool {
+    stream.tokens[start..stream.position().0]
+        .iter()
+        .any(|(token, _)| matches!(token, PtxToken::Error(_)))
+}
+
+/// Skip the remainder of a broken statement.
+///
+/// Stops after a `;` or a balanced `{ ... }` group, and before an unmatched `}`
+/// or a top-level directive starting a statement.
+fn skip_statement(stream: &mut PtxTokenStream) {
+    let mut depth = 0usize;
+    while let Ok((token, _)) = stream.peek() {
+        match token {
+            PtxToken::Semicolon if depth == 0 => {
+                stream.consume().ok();
+                return;
+            }
+            PtxToken::LBrace => depth += 1,
+            PtxToken::RBrace if depth == 0 => return,
+            PtxToken::RBrace => {
+                depth -= 1;
+                if depth == 0 {
+                    stream.consume().ok();
+                    return;
+                }
+            }
+            _ if at_resync_point(stream) => return,
+            _ => {}
+        }
+        stream.consume().ok();
+    }
+}
+
+/// Skip the remainder of a broken module-level directive.
+///
+/// Always consumes at least one token. Stops after a `;` at brace depth zero or
+/// the `}` closing the outermost brace group, and before a top-level directive.
+/// Inside braces only a directive starting a statement counts, so that the
+/// modifiers of instructions such as `ld.global` do not.
+fn skip_module_directive(stream: &mut PtxTokenStream) {
+    let mut depth = 0usize;
+    let mut first = true;
+    while let Ok((token, _)) = stream.peek() {
+        let resync = if depth == 0 {
+            at_top_level_directive(stream)
+        } else {
+            at_resync_point(stream)
+        };
+        if !first && resync {
+            return;
+        }
+        first = false;
+        stream.consume().ok();
+        match token {
+            PtxToken::Semicolon if depth == 0 => return,
+            PtxToken::LBrace => depth += 1,
+            PtxToken::RBrace => {
+                depth = depth.saturating_sub(1);
+                if depth == 0 {
+                    return;
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Pick the most relevant error for a statement that failed to parse.
+///
+/// `FunctionStatement` tries instructions last, so a broken directive would
+/// otherwise be reported as a bad instruction opcode.
+fn statement_error(stream: &mut PtxTokenStream, fallback: PtxParseError) -> PtxParseError {
+    if peek_directive_name(stream).is_none() {
+        return fallback;
+    }
+    stream
+        .try_with_span(|stream| StatementDirective::parse()(stream))
+        .err()
+        .unwrap_or(fallback)
+}
+
+/// Pick the most relevant error for a module directive that failed to parse by
+/// re-running the parser selected by its leading keyword.
+fn module_directive_error(stream: &mut PtxTokenStream, fallback: PtxParseError) -> PtxParseError {
+    let result = stream.try_with_span(|stream| {
+        optional(DataLinkage::parse())(stream)?;
+        let err = match peek_directive_name(stream) {
+            Some("entry") => EntryFunctionDirective::parse()(stream).err(),
+            Some("func") => FuncFunctionDirective::parse()(stream).err(),
+            Some("alias") => AliasFunctionDirective::parse()(stream).err(),
+            Some("version" | "target" | "address_size") => {
+                ModuleInfoDirectiveKind::parse()(stream).err()
+            }
+            Some("global" | "shared" | "const" | "tex") => {
+                ModuleVariableDirective::parse()(stream).err()
+            }
+            Some("file" | "section") => ModuleDebugDirective::parse()(stream).err(),
+            _ => None,
+        };
+        // Always fail so that the stream is rewound and any nested errors dropped.
+        Err::<(), _>(err.unwrap_or_else(|| fallback.clone()))
+    });
+    match result {
+        Ok(_) => fallback,
+        Err(err) => err,
+    }
+}
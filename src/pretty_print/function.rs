@@ -186,6 +186,13 @@ impl TreeDisplay for FunctionStatement {
                 ))?;
                 f.field_vec(true, "statements", statements, source)
             }
+            FunctionStatement::Error { text, span } => {
+                f.root(&format!(
+                    "FunctionStatement::Error [{}]",
+                    f.format_raw(*span, source)
+                ))?;
+                f.field_with_child(true, "text", text, source)
+            }
         }
     }
 }
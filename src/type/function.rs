@@ -3,7 +3,7 @@ use super::variable::{ParameterDirective, VariableDirective};
 use crate::Spanned;
 use crate::parser::Span;
 use crate::r#type::{AttributeDirective, DataType, FunctionSymbol, VariableSymbol};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Alias directive relating one function symbol to another.
 ///
@@ -12,7 +12,7 @@ use serde::Serialize;
 ///
 /// Example:
 /// .alias foo, bar;
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct AliasFunctionDirective {
     pub alias: FunctionSymbol,
     pub target: FunctionSymbol,
@@ -20,7 +20,7 @@ pub struct AliasFunctionDirective {
 }
 
 /// A PTX kernel declared with the `.entry` directive.
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct FuncFunctionDirective {
     /// Example:
     /// .func .attribute(.unified(0xAB, 0xCD)) bar() { ... }
@@ -58,7 +58,7 @@ pub struct FuncFunctionDirective {
 }
 
 /// A PTX device function declared with the `.func` directive.
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct EntryFunctionDirective {
     /// Name of the entry function.
     pub name: FunctionSymbol,
@@ -72,7 +72,7 @@ pub struct EntryFunctionDirective {
 }
 
 /// Directive tokens that may decorate a PTX function header.
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub enum FuncFunctionHeaderDirective {
     /// Syntax:
     /// .noreturn
@@ -101,7 +101,7 @@ pub enum FuncFunctionHeaderDirective {
 }
 
 /// Directive tokens that may decorate a PTX function header.
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub enum EntryFunctionHeaderDirective {
     /// Syntax:
     /// .maxnreg n
@@ -176,7 +176,7 @@ pub enum EntryFunctionHeaderDirective {
 }
 
 /// Statements contained within a PTX function body.
-#[derive(Debug, Clone, Default, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, Default, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct FunctionBody {
     pub statements: Vec<FunctionStatement>,
     pub span: Span,
@@ -184,7 +184,7 @@ pub struct FunctionBody {
 
 /// Nested statement block enclosed in braces.
 /// Executable items that appear within a function body.
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub enum FunctionStatement {
     Label {
         label: Label,
@@ -202,20 +202,28 @@ pub enum FunctionStatement {
         statements: Vec<FunctionStatement>,
         span: Span,
     },
+    /// Statement that failed to parse, kept verbatim.
+    ///
+    /// Only produced by [`crate::parse_ptx_recovering`]; `text` is the source
+    /// text skipped while resynchronising.
+    Error {
+        text: String,
+        span: Span,
+    },
 }
 
 /// Directive that declares a register variable inside a function body.
 ///
 /// Syntax:
 /// .reg .ty name<range>
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct RegisterDirective {
     pub ty: DataType,
     pub registers: Vec<RegisterTarget>,
     pub span: Span,
 }
 
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct RegisterTarget {
     pub name: VariableSymbol,
     pub range: Option<u32>,
@@ -223,7 +231,7 @@ pub struct RegisterTarget {
 }
 
 /// Directive that applies to individual statements.
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub enum StatementDirective {
     Loc {
         directive: LocationDirective,
@@ -284,13 +292,13 @@ pub enum StatementDirective {
 /// .4byte  label
 /// .quad   label
 /// ```
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct DwarfDirective {
     pub kind: DwarfDirectiveKind,
     pub span: Span,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum DwarfDirectiveKind {
     ByteValues(Vec<u8>),
     FourByteValues(Vec<u32>),
@@ -350,20 +358,20 @@ pub enum DwarfDirectiveKind {
 ///     .b8 0
 ///    }
 /// ```
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct SectionDirective {
     pub name: String,
     pub entries: Vec<SectionEntry>,
     pub span: Span,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum SectionEntry {
     Label { label: Label, span: Span },
     Directive(StatementSectionDirectiveLine),
 }
 
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub enum StatementSectionDirectiveLine {
     B8 { values: Vec<i16>, span: Span },
     B16 { values: Vec<i32>, span: Span },
@@ -382,7 +390,7 @@ pub enum StatementSectionDirectiveLine {
 /// Syntax:
 ///     .loc file_index line_number column_position
 ///     .loc file_index line_number column_position,function_name label {+ immediate }, inlined_at file_index2 line_number2 column_position2
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct LocationDirective {
     pub file_index: u32,
     pub line: u32,
@@ -391,7 +399,7 @@ pub struct LocationDirective {
     pub span: Span,
 }
 
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct LocationInlinedAt {
     pub file_index: u32,
     pub line: u32,
@@ -409,13 +417,13 @@ pub struct LocationInlinedAt {
 ///     .pragma "used_bytes_mask mask";
 ///     .pragma "enable_smem_spilling";
 ///     .pragma "frequency n";
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct PragmaDirective {
     pub kind: PragmaDirectiveKind,
     pub span: Span,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PragmaDirectiveKind {
     Nounroll,
     UsedBytesMask { mask: String },
@@ -428,7 +436,7 @@ pub enum PragmaDirectiveKind {
 ///
 /// Syntax:
 ///    .branchtargets label1, label2, label3, ...;
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct BranchTargetsDirective {
     pub labels: Vec<Label>,
     pub span: Span,
@@ -438,7 +446,7 @@ pub struct BranchTargetsDirective {
 ///
 /// Syntax:
 ///     .calltargets func1, func2, func3, ...;
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct CallTargetsDirective {
     pub targets: Vec<FunctionSymbol>,
     pub span: Span,
@@ -455,7 +463,7 @@ pub struct CallTargetsDirective {
 ///     label: .callprototype (ret-param) _ {.abi_preserve N} {.abi_preserve_control N};
 ///     // input, return parameters
 ///     label: .callprototype (ret-param) _ (param-list) {.abi_preserve N} {.abi_preserve_control N};
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub struct CallPrototypeDirective {
     pub return_param: Option<ParameterDirective>,
     pub params: Vec<ParameterDirective>,
@@ -466,7 +474,7 @@ pub struct CallPrototypeDirective {
 }
 
 /// Dimension triplet used by several function header directives.
-#[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
 pub enum FunctionDim {
     X { x: u32, span: Span },
     XY { x: u32, y: u32, span: Span },
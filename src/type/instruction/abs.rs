@@ -19,16 +19,16 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         S16, // .s16
         S32, // .s32
         S64, // .s64
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AbsType {
         pub type_: Type,       // .type
         pub d: GeneralOperand, // d
@@ -36,7 +36,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AbsFtzF32 {
         pub ftz: bool,         // {.ftz}
         pub f32: (),           // .f32
@@ -45,7 +45,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AbsF64 {
         pub f64: (),           // .f64
         pub d: GeneralOperand, // d
@@ -53,7 +53,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AbsFtzF16 {
         pub ftz: bool,         // {.ftz}
         pub f16: (),           // .f16
@@ -62,7 +62,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AbsFtzF16x2 {
         pub ftz: bool,         // {.ftz}
         pub f16x2: (),         // .f16x2
@@ -71,7 +71,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AbsBf16 {
         pub bf16: (),          // .bf16
         pub d: GeneralOperand, // d
@@ -79,7 +79,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AbsBf16x2 {
         pub bf16x2: (),        // .bf16x2
         pub d: GeneralOperand, // d
@@ -10,9 +10,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct ActivemaskB32 {
         pub b32: (),           // .b32
         pub d: GeneralOperand, // d
@@ -29,9 +29,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         U16x2, // .u16x2
         S16x2, // .s16x2
@@ -43,7 +43,7 @@ pub mod section_0 {
         S64,   // .s64
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AddType {
         pub type_: Type,       // .type
         pub d: GeneralOperand, // d
@@ -52,7 +52,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AddSatS32 {
         pub sat: bool,         // {.sat}
         pub s32: (),           // .s32
@@ -68,9 +68,9 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Rnd {
         Rn, // .rn
         Rz, // .rz
@@ -78,7 +78,7 @@ pub mod section_1 {
         Rp, // .rp
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AddRndFtzSatF32 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub ftz: bool,         // {.ftz}
@@ -90,7 +90,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AddRndFtzF32x2 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub ftz: bool,         // {.ftz}
@@ -101,7 +101,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AddRndF64 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub f64: (),           // .f64
@@ -117,14 +117,14 @@ pub mod section_2 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Rnd {
         Rn, // .rn
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AddRndFtzSatF16 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub ftz: bool,         // {.ftz}
@@ -136,7 +136,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AddRndFtzSatF16x2 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub ftz: bool,         // {.ftz}
@@ -148,7 +148,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AddRndBf16 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub bf16: (),          // .bf16
@@ -158,7 +158,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AddRndBf16x2 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub bf16x2: (),        // .bf16x2
@@ -174,9 +174,9 @@ pub mod section_3 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Rnd {
         Rn, // .rn
         Rz, // .rz
@@ -184,13 +184,13 @@ pub mod section_3 {
         Rp, // .rp
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         Bf16, // .bf16
         F16,  // .f16
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AddRndSatF32Atype {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub sat: bool,         // {.sat}
@@ -11,9 +11,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         U32, // .u32
         S32, // .s32
@@ -21,7 +21,7 @@ pub mod section_0 {
         S64, // .s64
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AddCcType {
         pub cc: (),            // .cc
         pub type_: Type,       // .type
@@ -11,14 +11,14 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelEvictionPriority {
         L2EvictNormal, // .L2::evict_normal
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct ApplypriorityGlobalLevelEvictionPriority {
         pub global: bool,                                   // {.global}
         pub level_eviction_priority: LevelEvictionPriority, // .level::eviction_priority
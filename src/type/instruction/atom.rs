@@ -38,9 +38,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Sem {
         Relaxed, // .relaxed
         Acquire, // .acquire
@@ -48,7 +48,7 @@ pub mod section_0 {
         AcqRel,  // .acq_rel
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Scope {
         Cluster, // .cluster
         Cta,     // .cta
@@ -56,7 +56,7 @@ pub mod section_0 {
         Sys,     // .sys
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Space {
         SharedCluster, // .shared::cluster
         SharedCta,     // .shared::cta
@@ -64,7 +64,7 @@ pub mod section_0 {
         Shared,        // .shared
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         Exch, // .exch
         And,  // .and
@@ -78,12 +78,12 @@ pub mod section_0 {
         Or,   // .or
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelCacheHint {
         L2CacheHint, // .L2::cache_hint
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         B32, // .b32
         B64, // .b64
@@ -95,7 +95,7 @@ pub mod section_0 {
         F64, // .f64
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AtomSemScopeSpaceOpLevelCacheHintType {
         pub sem: Option<Sem>,                         // {.sem}
         pub scope: Option<Scope>,                     // {.scope}
@@ -110,7 +110,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AtomSemScopeSpaceOpType {
         pub sem: Option<Sem>,     // {.sem}
         pub scope: Option<Scope>, // {.scope}
@@ -124,7 +124,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AtomSemScopeSpaceCasB16 {
         pub sem: Option<Sem>,     // {.sem}
         pub scope: Option<Scope>, // {.scope}
@@ -138,7 +138,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AtomSemScopeSpaceCasB128 {
         pub sem: Option<Sem>,     // {.sem}
         pub scope: Option<Scope>, // {.scope}
@@ -152,7 +152,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AtomSemScopeSpaceExchLevelCacheHintB128 {
         pub sem: Option<Sem>,                         // {.sem}
         pub scope: Option<Scope>,                     // {.scope}
@@ -167,7 +167,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AtomSemScopeSpaceAddNoftzLevelCacheHintF16 {
         pub sem: Option<Sem>,                         // {.sem}
         pub scope: Option<Scope>,                     // {.scope}
@@ -183,7 +183,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2 {
         pub sem: Option<Sem>,                         // {.sem}
         pub scope: Option<Scope>,                     // {.scope}
@@ -199,7 +199,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AtomSemScopeSpaceAddNoftzLevelCacheHintBf16 {
         pub sem: Option<Sem>,                         // {.sem}
         pub scope: Option<Scope>,                     // {.scope}
@@ -215,7 +215,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2 {
         pub sem: Option<Sem>,                         // {.sem}
         pub scope: Option<Scope>,                     // {.scope}
@@ -237,9 +237,9 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Sem {
         Relaxed, // .relaxed
         Acquire, // .acquire
@@ -247,7 +247,7 @@ pub mod section_1 {
         AcqRel,  // .acq_rel
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Scope {
         Cluster, // .cluster
         Cta,     // .cta
@@ -255,44 +255,44 @@ pub mod section_1 {
         Sys,     // .sys
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelCacheHint {
         L2CacheHint, // .L2::cache_hint
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Vec32Bit {
         V2, // .v2
         V4, // .v4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         Add, // .add
         Min, // .min
         Max, // .max
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Vec16Bit {
         V2, // .v2
         V4, // .v4
         V8, // .v8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum HalfWordType {
         Bf16, // .bf16
         F16,  // .f16
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum PackedType {
         Bf16x2, // .bf16x2
         F16x2,  // .f16x2
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AtomSemScopeGlobalAddLevelCacheHintVec32BitF32 {
         pub sem: Option<Sem>,                         // {.sem}
         pub scope: Option<Scope>,                     // {.scope}
@@ -308,7 +308,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType {
         pub sem: Option<Sem>,                         // {.sem}
         pub scope: Option<Scope>,                     // {.scope}
@@ -325,7 +325,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType {
         pub sem: Option<Sem>,                         // {.sem}
         pub scope: Option<Scope>,                     // {.scope}
@@ -18,15 +18,15 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         And, // .and
         Or,  // .or
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct BarrierCtaSyncAligned {
         pub cta: bool,                 // {.cta}
         pub sync: (),                  // .sync
@@ -36,7 +36,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct BarrierCtaArriveAligned {
         pub cta: bool,         // {.cta}
         pub arrive: (),        // .arrive
@@ -46,7 +46,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct BarrierCtaRedPopcAlignedU32 {
         pub cta: bool,                 // {.cta}
         pub red: (),                   // .red
@@ -61,7 +61,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct BarrierCtaRedOpAlignedPred {
         pub cta: bool,                 // {.cta}
         pub red: (),                   // .red
@@ -76,7 +76,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct BarCtaSync {
         pub cta: bool,                 // {.cta}
         pub sync: (),                  // .sync
@@ -85,7 +85,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct BarCtaArrive {
         pub cta: bool,         // {.cta}
         pub arrive: (),        // .arrive
@@ -94,7 +94,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct BarCtaRedPopcU32 {
         pub cta: bool,                 // {.cta}
         pub red: (),                   // .red
@@ -108,7 +108,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct BarCtaRedOpPred {
         pub cta: bool,                 // {.cta}
         pub red: (),                   // .red
@@ -12,15 +12,15 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Sem {
         Release, // .release
         Relaxed, // .relaxed
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct BarrierClusterArriveSemAligned {
         pub cluster: (),      // .cluster
         pub arrive: (),       // .arrive
@@ -29,7 +29,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct BarrierClusterWaitAcquireAligned {
         pub cluster: (),   // .cluster
         pub wait: (),      // .wait
@@ -21,9 +21,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CallUni {
         pub uni: bool,                       // {.uni}
         pub ret_param: GeneralOperand,       // (ret-param)
@@ -32,7 +32,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CallUni1 {
         pub uni: bool,                       // {.uni}
         pub func: GeneralOperand,            // func
@@ -40,14 +40,14 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CallUni2 {
         pub uni: bool,            // {.uni}
         pub func: GeneralOperand, // func
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CallUni3 {
         pub uni: bool,                       // {.uni}
         pub ret_param: GeneralOperand,       // (ret-param)
@@ -57,7 +57,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CallUni4 {
         pub uni: bool,                       // {.uni}
         pub fptr: GeneralOperand,            // fptr
@@ -66,7 +66,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CallUni5 {
         pub uni: bool,             // {.uni}
         pub fptr: GeneralOperand,  // fptr
@@ -74,7 +74,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CallUni6 {
         pub uni: bool,                       // {.uni}
         pub ret_param: GeneralOperand,       // (ret-param)
@@ -84,7 +84,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CallUni7 {
         pub uni: bool,                       // {.uni}
         pub fptr: GeneralOperand,            // fptr
@@ -93,7 +93,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CallUni8 {
         pub uni: bool,              // {.uni}
         pub fptr: GeneralOperand,   // fptr
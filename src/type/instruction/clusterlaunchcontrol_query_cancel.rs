@@ -13,16 +13,16 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum GetFirstCtaidDimension {
         GetFirstCtaidX, // .get_first_ctaid::x
         GetFirstCtaidY, // .get_first_ctaid::y
         GetFirstCtaidZ, // .get_first_ctaid::z
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct ClusterlaunchcontrolQueryCancelIsCanceledPredB128 {
         pub query_cancel: (),                    // .query_cancel
         pub is_canceled: (),                     // .is_canceled
@@ -33,7 +33,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128 {
         pub query_cancel: (),                    // .query_cancel
         pub get_first_ctaid: (),                 // .get_first_ctaid
@@ -45,7 +45,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128 {
         pub query_cancel: (),                                          // .query_cancel
         pub get_first_ctaid_dimension: Option<GetFirstCtaidDimension>, // {.get_first_ctaid::dimension}
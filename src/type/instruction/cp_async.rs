@@ -17,34 +17,34 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum State {
         SharedCta, // .shared::cta
         Shared,    // .shared
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelCacheHint {
         L2CacheHint, // .L2::cache_hint
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelPrefetchSize {
         L2128b, // .L2::128B
         L2256b, // .L2::256B
         L264b,  // .L2::64B
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CpSize {
         _16, // 16
         _4,  // 4
         _8,  // 8
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize {
         pub async_: (),                                     // .async
         pub ca: (),                                         // .ca
@@ -60,7 +60,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize {
         pub async_: (),                                     // .async
         pub cg: (),                                         // .cg
@@ -76,7 +76,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1 {
         pub async_: (),                                     // .async
         pub ca: (),                                         // .ca
@@ -92,7 +92,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1 {
         pub async_: (),                                     // .async
         pub cg: (),                                         // .cg
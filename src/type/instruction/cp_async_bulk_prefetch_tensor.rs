@@ -15,9 +15,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dim {
         _1d, // .1d
         _2d, // .2d
@@ -26,12 +26,12 @@ pub mod section_0 {
         _5d, // .5d
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Src {
         Global, // .global
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LoadMode {
         Im2colW128,  // .im2col::w::128
         TileGather4, // .tile::gather4
@@ -40,12 +40,12 @@ pub mod section_0 {
         Tile,        // .tile
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelCacheHint {
         L2CacheHint, // .L2::cache_hint
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint {
         pub async_: (),                               // .async
         pub bulk: (),                                 // .bulk
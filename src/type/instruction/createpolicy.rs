@@ -20,9 +20,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelPrimaryPriority {
         L2EvictUnchanged, // .L2::evict_unchanged
         L2EvictNormal,    // .L2::evict_normal
@@ -30,13 +30,13 @@ pub mod section_0 {
         L2EvictLast,      // .L2::evict_last
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelSecondaryPriority {
         L2EvictUnchanged, // .L2::evict_unchanged
         L2EvictFirst,     // .L2::evict_first
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64 {
         pub range: (),                                                // .range
         pub global: bool,                                             // {.global}
@@ -50,7 +50,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64 {
         pub fractional: (),                                           // .fractional
         pub level_primary_priority: LevelPrimaryPriority,             // .level::primary_priority
@@ -61,7 +61,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CreatepolicyCvtL2B64 {
         pub cvt: (),                         // .cvt
         pub l2: (),                          // .L2
@@ -45,9 +45,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Irnd {
         Rni, // .rni
         Rzi, // .rzi
@@ -55,7 +55,7 @@ pub mod section_0 {
         Rpi, // .rpi
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         Bf16, // .bf16
         U16,  // .u16
@@ -71,7 +71,7 @@ pub mod section_0 {
         S8,   // .s8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         Bf16, // .bf16
         U16,  // .u16
@@ -87,7 +87,7 @@ pub mod section_0 {
         S8,   // .s8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Frnd {
         Rn, // .rn
         Rz, // .rz
@@ -95,53 +95,53 @@ pub mod section_0 {
         Rp, // .rp
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Frnd2 {
         Rn, // .rn
         Rz, // .rz
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum F8x2type {
         E4m3x2, // .e4m3x2
         E5m2x2, // .e5m2x2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum F8x4type {
         E4m3x4, // .e4m3x4
         E5m2x4, // .e5m2x4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum F4x2type {
         E2m1x2, // .e2m1x2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum F4x4type {
         E2m1x4, // .e2m1x4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum F6x2type {
         E2m3x2, // .e2m3x2
         E3m2x2, // .e3m2x2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum F6x4type {
         E2m3x4, // .e2m3x4
         E3m2x4, // .e3m2x4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Frnd3 {
         Rz, // .rz
         Rp, // .rp
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtIrndFtzSatDtypeAtype {
         pub irnd: Option<Irnd>, // {.irnd}
         pub ftz: bool,          // {.ftz}
@@ -153,7 +153,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtFrndFtzSatDtypeAtype {
         pub frnd: Option<Frnd>, // {.frnd}
         pub ftz: bool,          // {.ftz}
@@ -165,7 +165,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtFrnd2ReluSatfiniteF16F32 {
         pub frnd2: Frnd2,      // .frnd2
         pub relu: bool,        // {.relu}
@@ -177,7 +177,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtFrnd2ReluSatfiniteF16x2F32 {
         pub frnd2: Frnd2,      // .frnd2
         pub relu: bool,        // {.relu}
@@ -190,7 +190,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRsReluSatfiniteF16x2F32 {
         pub rs: (),                // .rs
         pub relu: bool,            // {.relu}
@@ -204,7 +204,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtFrnd2ReluSatfiniteBf16F32 {
         pub frnd2: Frnd2,      // .frnd2
         pub relu: bool,        // {.relu}
@@ -216,7 +216,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtFrnd2ReluSatfiniteBf16x2F32 {
         pub frnd2: Frnd2,      // .frnd2
         pub relu: bool,        // {.relu}
@@ -229,7 +229,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRsReluSatfiniteBf16x2F32 {
         pub rs: (),                // .rs
         pub relu: bool,            // {.relu}
@@ -243,7 +243,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRnaSatfiniteTf32F32 {
         pub rna: (),           // .rna
         pub satfinite: bool,   // {.satfinite}
@@ -254,7 +254,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtFrnd2SatfiniteReluTf32F32 {
         pub frnd2: Frnd2,      // .frnd2
         pub satfinite: bool,   // {.satfinite}
@@ -266,7 +266,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRnSatfiniteReluF8x2typeF32 {
         pub rn: (),             // .rn
         pub satfinite: (),      // .satfinite
@@ -279,7 +279,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRnSatfiniteReluF8x2typeF16x2 {
         pub rn: (),             // .rn
         pub satfinite: (),      // .satfinite
@@ -291,7 +291,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRnReluF16x2F8x2type {
         pub rn: (),             // .rn
         pub relu: bool,         // {.relu}
@@ -302,7 +302,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRsReluSatfiniteF8x4typeF32 {
         pub rs: (),                // .rs
         pub relu: bool,            // {.relu}
@@ -315,7 +315,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRnSatfiniteReluF4x2typeF32 {
         pub rn: (),             // .rn
         pub satfinite: (),      // .satfinite
@@ -328,7 +328,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRnReluF16x2F4x2type {
         pub rn: (),             // .rn
         pub relu: bool,         // {.relu}
@@ -339,7 +339,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRsReluSatfiniteF4x4typeF32 {
         pub rs: (),                // .rs
         pub relu: bool,            // {.relu}
@@ -352,7 +352,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRnSatfiniteReluF6x2typeF32 {
         pub rn: (),             // .rn
         pub satfinite: (),      // .satfinite
@@ -365,7 +365,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRnReluF16x2F6x2type {
         pub rn: (),             // .rn
         pub relu: bool,         // {.relu}
@@ -376,7 +376,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRsReluSatfiniteF6x4typeF32 {
         pub rs: (),                // .rs
         pub relu: bool,            // {.relu}
@@ -389,7 +389,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtFrnd3SatfiniteUe8m0x2F32 {
         pub frnd3: Frnd3,      // .frnd3
         pub satfinite: bool,   // {.satfinite}
@@ -401,7 +401,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtFrnd3SatfiniteUe8m0x2Bf16x2 {
         pub frnd3: Frnd3,      // .frnd3
         pub satfinite: bool,   // {.satfinite}
@@ -412,7 +412,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtRnBf16x2Ue8m0x2 {
         pub rn: (),            // .rn
         pub bf16x2: (),        // .bf16x2
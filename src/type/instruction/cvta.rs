@@ -17,9 +17,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Space {
         SharedCluster, // .shared::cluster
         ParamEntry,    // .param::entry
@@ -31,13 +31,13 @@ pub mod section_0 {
         Param,         // .param
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Size {
         U32, // .u32
         U64, // .u64
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtaSpaceSize {
         pub space: Space,      // .space
         pub size: Size,        // .size
@@ -46,7 +46,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct CvtaToSpaceSize {
         pub to: (),            // .to
         pub space: Space,      // .space
@@ -15,21 +15,21 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         F16x2, // .f16x2
         F16,   // .f16
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         Bf16x2, // .bf16x2
         Bf16,   // .bf16
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Ex2ApproxFtzF32 {
         pub approx: (),        // .approx
         pub ftz: bool,         // {.ftz}
@@ -39,7 +39,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Ex2ApproxAtype {
         pub approx: (),        // .approx
         pub atype: Atype,      // .atype
@@ -48,7 +48,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Ex2ApproxFtzBtype {
         pub approx: (),        // .approx
         pub ftz: (),           // .ftz
@@ -26,9 +26,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Rnd {
         Rn, // .rn
         Rz, // .rz
@@ -36,7 +36,7 @@ pub mod section_0 {
         Rp, // .rp
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FmaRndFtzSatF32 {
         pub rnd: Rnd,          // .rnd
         pub ftz: bool,         // {.ftz}
@@ -49,7 +49,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FmaRndFtzF32x2 {
         pub rnd: Rnd,          // .rnd
         pub ftz: bool,         // {.ftz}
@@ -61,7 +61,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FmaRndF64 {
         pub rnd: Rnd,          // .rnd
         pub f64: (),           // .f64
@@ -78,14 +78,14 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Rnd {
         Rn, // .rn
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FmaRndFtzSatF16 {
         pub rnd: Rnd,          // .rnd
         pub ftz: bool,         // {.ftz}
@@ -98,7 +98,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FmaRndFtzSatF16x2 {
         pub rnd: Rnd,          // .rnd
         pub ftz: bool,         // {.ftz}
@@ -111,7 +111,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FmaRndFtzReluF16 {
         pub rnd: Rnd,          // .rnd
         pub ftz: bool,         // {.ftz}
@@ -124,7 +124,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FmaRndFtzReluF16x2 {
         pub rnd: Rnd,          // .rnd
         pub ftz: bool,         // {.ftz}
@@ -137,7 +137,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FmaRndReluBf16 {
         pub rnd: Rnd,          // .rnd
         pub relu: bool,        // {.relu}
@@ -149,7 +149,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FmaRndReluBf16x2 {
         pub rnd: Rnd,          // .rnd
         pub relu: bool,        // {.relu}
@@ -161,7 +161,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FmaRndOobReluType {
         pub rnd: Rnd,          // .rnd
         pub oob: (),           // .oob
@@ -180,9 +180,9 @@ pub mod section_2 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Rnd {
         Rn, // .rn
         Rz, // .rz
@@ -190,13 +190,13 @@ pub mod section_2 {
         Rp, // .rp
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Abtype {
         Bf16, // .bf16
         F16,  // .f16
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FmaRndSatF32Abtype {
         pub rnd: Rnd,          // .rnd
         pub sat: bool,         // {.sat}
@@ -11,9 +11,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Space {
         SharedCluster, // .shared::cluster
         ParamEntry,    // .param::entry
@@ -25,7 +25,7 @@ pub mod section_0 {
         Param,         // .param
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct IsspacepSpace {
         pub space: Space,      // .space
         pub p: GeneralOperand, // p
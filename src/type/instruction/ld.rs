@@ -27,9 +27,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ss {
         SharedCluster, // .shared::cluster
         ParamEntry,    // .param::entry
@@ -42,7 +42,7 @@ pub mod section_0 {
         Param,         // .param
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Cop {
         Ca, // .ca
         Cg, // .cg
@@ -51,26 +51,26 @@ pub mod section_0 {
         Cv, // .cv
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelCacheHint {
         L2CacheHint, // .L2::cache_hint
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelPrefetchSize {
         L2128b, // .L2::128B
         L2256b, // .L2::256B
         L264b,  // .L2::64B
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Vec {
         V2, // .v2
         V4, // .v4
         V8, // .v8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         B128, // .b128
         B16,  // .b16
@@ -89,7 +89,7 @@ pub mod section_0 {
         S8,   // .s8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Level1EvictionPriority {
         L1EvictUnchanged, // .L1::evict_unchanged
         L1EvictNormal,    // .L1::evict_normal
@@ -98,14 +98,14 @@ pub mod section_0 {
         L1EvictLast,      // .L1::evict_last
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Level2EvictionPriority {
         L2EvictNormal, // .L2::evict_normal
         L2EvictFirst,  // .L2::evict_first
         L2EvictLast,   // .L2::evict_last
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Scope {
         Cluster, // .cluster
         Cta,     // .cta
@@ -113,7 +113,7 @@ pub mod section_0 {
         Sys,     // .sys
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType {
         pub weak: bool,                                     // {.weak}
         pub ss: Option<Ss>,                                 // {.ss}
@@ -129,7 +129,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType
     {
         pub weak: bool,                                               // {.weak}
@@ -147,7 +147,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdVolatileSsLevelPrefetchSizeVecType {
         pub volatile: (),                                   // .volatile
         pub ss: Option<Ss>,                                 // {.ss}
@@ -159,7 +159,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType
     {
         pub relaxed: (),                                              // .relaxed
@@ -177,7 +177,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdAcquireScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType
     {
         pub acquire: (),                                              // .acquire
@@ -195,7 +195,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdMmioRelaxedSysGlobalType {
         pub mmio: (),          // .mmio
         pub relaxed: (),       // .relaxed
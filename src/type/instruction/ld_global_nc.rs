@@ -23,28 +23,28 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Cop {
         Ca, // .ca
         Cg, // .cg
         Cs, // .cs
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelCacheHint {
         L2CacheHint, // .L2::cache_hint
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelPrefetchSize {
         L2128b, // .L2::128B
         L2256b, // .L2::256B
         L264b,  // .L2::64B
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         B128, // .b128
         B16,  // .b16
@@ -63,14 +63,14 @@ pub mod section_0 {
         S8,   // .s8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Vec {
         V2, // .v2
         V4, // .v4
         V8, // .v8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Level1EvictionPriority {
         L1EvictUnchanged, // .L1::evict_unchanged
         L1EvictNormal,    // .L1::evict_normal
@@ -79,14 +79,14 @@ pub mod section_0 {
         L1EvictLast,      // .L1::evict_last
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Level2EvictionPriority {
         L2EvictNormal, // .L2::evict_normal
         L2EvictFirst,  // .L2::evict_first
         L2EvictLast,   // .L2::evict_last
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType {
         pub global: (),                                     // .global
         pub cop: Option<Cop>,                               // {.cop}
@@ -100,7 +100,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType {
         pub global: (),                                     // .global
         pub cop: Option<Cop>,                               // {.cop}
@@ -115,7 +115,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType
     {
         pub global: (),                                               // .global
@@ -131,7 +131,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType
     {
         pub global: (),                                               // .global
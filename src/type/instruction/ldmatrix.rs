@@ -18,45 +18,45 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M16n16, // .m16n16
         M8n8,   // .m8n8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Num {
         X1, // .x1
         X2, // .x2
         X4, // .x4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ss {
         SharedCta, // .shared::cta
         Shared,    // .shared
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         B16, // .b16
         B8,  // .b8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum DstFmt {
         B8x16, // .b8x16
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum SrcFmt {
         B6x16P32, // .b6x16_p32
         B4x16P64, // .b4x16_p64
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdmatrixSyncAlignedShapeNumTransSsType {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
@@ -70,7 +70,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
@@ -84,7 +84,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
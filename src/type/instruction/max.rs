@@ -23,9 +23,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         U16x2, // .u16x2
         U16,   // .u16
@@ -35,13 +35,13 @@ pub mod section_0 {
         S64,   // .s64
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         S16x2, // .s16x2
         S32,   // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MaxAtype {
         pub atype: Atype,      // .atype
         pub d: GeneralOperand, // d
@@ -50,7 +50,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MaxReluBtype {
         pub relu: bool,        // {.relu}
         pub btype: Btype,      // .btype
@@ -60,7 +60,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MaxFtzNanXorsignAbsF32 {
         pub ftz: bool,         // {.ftz}
         pub nan: bool,         // {.NaN}
@@ -72,7 +72,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MaxFtzNanAbsF32 {
         pub ftz: bool,         // {.ftz}
         pub nan: bool,         // {.NaN}
@@ -85,7 +85,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MaxF64 {
         pub f64: (),           // .f64
         pub d: GeneralOperand, // d
@@ -94,7 +94,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MaxFtzNanXorsignAbsF16 {
         pub ftz: bool,         // {.ftz}
         pub nan: bool,         // {.NaN}
@@ -106,7 +106,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MaxFtzNanXorsignAbsF16x2 {
         pub ftz: bool,         // {.ftz}
         pub nan: bool,         // {.NaN}
@@ -118,7 +118,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MaxNanXorsignAbsBf16 {
         pub nan: bool,         // {.NaN}
         pub xorsign_abs: bool, // {.xorsign.abs}
@@ -129,7 +129,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MaxNanXorsignAbsBf16x2 {
         pub nan: bool,         // {.NaN}
         pub xorsign_abs: bool, // {.xorsign.abs}
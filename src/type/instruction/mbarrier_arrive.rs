@@ -17,27 +17,27 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Sem {
         Release, // .release
         Relaxed, // .relaxed
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Scope {
         Cluster, // .cluster
         Cta,     // .cta
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum State {
         SharedCta, // .shared::cta
         Shared,    // .shared
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MbarrierArriveSemScopeStateB64 {
         pub arrive: (),                    // .arrive
         pub sem: Option<Sem>,              // {.sem}
@@ -50,7 +50,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MbarrierArriveSemScopeSharedClusterB64 {
         pub arrive: (),                    // .arrive
         pub sem: Option<Sem>,              // {.sem}
@@ -63,7 +63,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MbarrierArriveExpectTxSemScopeStateB64 {
         pub arrive: (),              // .arrive
         pub expect_tx: (),           // .expect_tx
@@ -77,7 +77,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MbarrierArriveExpectTxSemScopeSharedClusterB64 {
         pub arrive: (),              // .arrive
         pub expect_tx: (),           // .expect_tx
@@ -91,7 +91,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MbarrierArriveNocompleteReleaseCtaStateB64 {
         pub arrive: (),             // .arrive
         pub nocomplete: (),         // .noComplete
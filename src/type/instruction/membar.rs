@@ -32,9 +32,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Sem {
         AcqRel,  // .acq_rel
         Acquire, // .acquire
@@ -42,7 +42,7 @@ pub mod section_0 {
         Sc,      // .sc
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Scope {
         Cluster, // .cluster
         Cta,     // .cta
@@ -50,12 +50,12 @@ pub mod section_0 {
         Sys,     // .sys
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum OpRestrict {
         MbarrierInit, // .mbarrier_init
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Proxykind {
         AsyncSharedCluster, // .async.shared::cluster
         AsyncSharedCta,     // .async.shared::cta
@@ -64,26 +64,26 @@ pub mod section_0 {
         Async,              // .async
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum ToProxykindFromProxykind {
         TensormapGeneric, // .tensormap::generic
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Level {
         Cta, // .cta
         Sys, // .sys
         Gl,  // .gl
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FenceSemScope {
         pub sem: Option<Sem>, // {.sem}
         pub scope: Scope,     // .scope
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FenceAcquireSyncRestrictSharedClusterCluster {
         pub acquire: (),                      // .acquire
         pub sync_restrict_shared_cluster: (), // .sync_restrict::shared::cluster
@@ -91,7 +91,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FenceReleaseSyncRestrictSharedCtaCluster {
         pub release: (),                  // .release
         pub sync_restrict_shared_cta: (), // .sync_restrict::shared::cta
@@ -99,7 +99,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FenceOpRestrictReleaseCluster {
         pub op_restrict: OpRestrict, // .op_restrict
         pub release: (),             // .release
@@ -107,14 +107,14 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FenceProxyProxykind {
         pub proxy: (),            // .proxy
         pub proxykind: Proxykind, // .proxykind
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FenceProxyToProxykindFromProxykindReleaseScope {
         pub proxy: (),                                             // .proxy
         pub to_proxykind_from_proxykind: ToProxykindFromProxykind, // .to_proxykind::from_proxykind
@@ -123,7 +123,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FenceProxyToProxykindFromProxykindAcquireScope {
         pub proxy: (),                                             // .proxy
         pub to_proxykind_from_proxykind: ToProxykindFromProxykind, // .to_proxykind::from_proxykind
@@ -134,7 +134,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster {
         pub proxy: (),                        // .proxy
         pub async_generic: (),                // .async::generic
@@ -144,7 +144,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster {
         pub proxy: (),                    // .proxy
         pub async_generic: (),            // .async::generic
@@ -154,13 +154,13 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MembarLevel {
         pub level: Level, // .level
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MembarProxyProxykind {
         pub proxy: (),            // .proxy
         pub proxykind: Proxykind, // .proxykind
@@ -22,9 +22,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         U16x2, // .u16x2
         U16,   // .u16
@@ -34,13 +34,13 @@ pub mod section_0 {
         S64,   // .s64
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         S16x2, // .s16x2
         S32,   // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MinAtype {
         pub atype: Atype,      // .atype
         pub d: GeneralOperand, // d
@@ -49,7 +49,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MinReluBtype {
         pub relu: bool,        // {.relu}
         pub btype: Btype,      // .btype
@@ -59,7 +59,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MinFtzNanXorsignAbsF32 {
         pub ftz: bool,         // {.ftz}
         pub nan: bool,         // {.NaN}
@@ -71,7 +71,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MinFtzNanAbsF32 {
         pub ftz: bool,         // {.ftz}
         pub nan: bool,         // {.NaN}
@@ -84,7 +84,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MinF64 {
         pub f64: (),           // .f64
         pub d: GeneralOperand, // d
@@ -93,7 +93,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MinFtzNanXorsignAbsF16 {
         pub ftz: bool,         // {.ftz}
         pub nan: bool,         // {.NaN}
@@ -105,7 +105,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MinFtzNanXorsignAbsF16x2 {
         pub ftz: bool,         // {.ftz}
         pub nan: bool,         // {.NaN}
@@ -117,7 +117,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MinNanXorsignAbsBf16 {
         pub nan: bool,         // {.NaN}
         pub xorsign_abs: bool, // {.xorsign.abs}
@@ -128,7 +128,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MinNanXorsignAbsBf16x2 {
         pub nan: bool,         // {.NaN}
         pub xorsign_abs: bool, // {.xorsign.abs}
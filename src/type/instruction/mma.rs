@@ -77,33 +77,33 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Alayout {
         Row, // .row
         Col, // .col
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Blayout {
         Row, // .row
         Col, // .col
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F16, // .f16
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ctype {
         F16, // .f16
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedM8n8k4AlayoutBlayoutDtypeF16F16Ctype {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
@@ -121,7 +121,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedM16n8k8RowColDtypeF16F16Ctype {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
@@ -139,7 +139,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedM16n8k16RowColDtypeF16F16Ctype {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
@@ -163,50 +163,50 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         Bf16, // .bf16
         Tf32, // .tf32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         Bf16, // .bf16
         Tf32, // .tf32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M16n8k16, // .m16n8k16
         M16n8k32, // .m16n8k32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F16, // .f16
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum F8type {
         E4m3, // .e4m3
         E5m2, // .e5m2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ctype {
         F16, // .f16
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindF8f6f4, // .kind::f8f6f4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum F8f6f4type {
         E4m3, // .e4m3
         E5m2, // .e5m2
@@ -215,7 +215,7 @@ pub mod section_1 {
         E2m1, // .e2m1
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedM16n8k4RowColF32Tf32Tf32F32 {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
@@ -233,7 +233,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedM16n8k8RowColF32AtypeBtypeF32 {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
@@ -251,7 +251,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedM16n8k16RowColF32Bf16Bf16F32 {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
@@ -269,7 +269,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedShapeRowColDtypeF8typeF8typeCtype {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
@@ -287,7 +287,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedM16n8k32RowColKindDtypeF8f6f4typeF8f6f4typeCtype {
         pub sync: (),                // .sync
         pub aligned: (),             // .aligned
@@ -312,24 +312,24 @@ pub mod section_2 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindMxf4, // .kind::mxf4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum ScaleVecSize {
         ScaleVec2x, // .scale_vec::2X
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Stype {
         Ue8m0, // .ue8m0
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype {
         pub sync: (),                             // .sync
         pub aligned: (),                          // .aligned
@@ -361,26 +361,26 @@ pub mod section_3 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindMxf4nvf4, // .kind::mxf4nvf4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum ScaleVecSize {
         ScaleVec2x, // .scale_vec::2X
         ScaleVec4x, // .scale_vec::4X
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Stype {
         Ue8m0, // .ue8m0
         Ue4m3, // .ue4m3
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1 {
         pub sync: (),                     // .sync
         pub aligned: (),                  // .aligned
@@ -412,19 +412,19 @@ pub mod section_4 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindMxf8f6f4, // .kind::mxf8f6f4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum ScaleVecSize {
         ScaleVec1x, // .scale_vec::1X
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum F8f6f4type {
         E4m3, // .e4m3
         E5m2, // .e5m2
@@ -433,12 +433,12 @@ pub mod section_4 {
         E2m1, // .e2m1
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Stype {
         Ue8m0, // .ue8m0
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedM16n8k32RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype {
         pub sync: (),                             // .sync
         pub aligned: (),                          // .aligned
@@ -470,9 +470,9 @@ pub mod section_5 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M16n8k16, // .m16n8k16
         M16n8k4,  // .m16n8k4
@@ -480,7 +480,7 @@ pub mod section_5 {
         M8n84,    // .m8n84
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedShapeRowColF64F64F64F64 {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
@@ -504,28 +504,28 @@ pub mod section_6 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M16n8k16, // .m16n8k16
         M16n8k32, // .m16n8k32
         M8n8k16,  // .m8n8k16
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         U8, // .u8
         S8, // .s8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         U8, // .u8
         S8, // .s8
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32 {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
@@ -550,28 +550,28 @@ pub mod section_7 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M16n8k32, // .m16n8k32
         M16n8k64, // .m16n8k64
         M8n8k32,  // .m8n8k32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         U4, // .u4
         S4, // .s4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         U4, // .u4
         S4, // .s4
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321 {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
@@ -596,22 +596,22 @@ pub mod section_8 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M16n8k128, // .m16n8k128
         M16n8k256, // .m16n8k256
         M8n8k128,  // .m8n8k128
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Bitop {
         Xor, // .xor
         And, // .and
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSyncAlignedShapeRowColS32B1B1S32BitopPopc {
         pub sync: (),          // .sync
         pub aligned: (),       // .aligned
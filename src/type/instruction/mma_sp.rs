@@ -67,27 +67,27 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Spvariant {
         SpOrderedMetadata, // .sp::ordered_metadata
         Sp,                // .sp
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F16, // .f16
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ctype {
         F16, // .f16
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype {
         pub spvariant: Spvariant, // .spvariant
         pub sync: (),             // .sync
@@ -108,7 +108,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype {
         pub spvariant: Spvariant, // .spvariant
         pub sync: (),             // .sync
@@ -135,32 +135,32 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Spvariant {
         SpOrderedMetadata, // .sp::ordered_metadata
         Sp,                // .sp
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum F8type {
         E4m3, // .e4m3
         E5m2, // .e5m2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindF8f6f4, // .kind::f8f6f4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F16, // .f16
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum F8f6f4type {
         E4m3, // .e4m3
         E5m2, // .e5m2
@@ -169,13 +169,13 @@ pub mod section_1 {
         E2m1, // .e2m1
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ctype {
         F16, // .f16
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32 {
         pub spvariant: Spvariant, // .spvariant
         pub sync: (),             // .sync
@@ -196,7 +196,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32 {
         pub spvariant: Spvariant, // .spvariant
         pub sync: (),             // .sync
@@ -217,7 +217,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32 {
         pub spvariant: Spvariant, // .spvariant
         pub sync: (),             // .sync
@@ -238,7 +238,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32 {
         pub spvariant: Spvariant, // .spvariant
         pub sync: (),             // .sync
@@ -259,7 +259,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32 {
         pub spvariant: Spvariant, // .spvariant
         pub sync: (),             // .sync
@@ -280,7 +280,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype {
         pub sp_ordered_metadata: (), // .sp::ordered_metadata
         pub sync: (),                // .sync
@@ -308,29 +308,29 @@ pub mod section_2 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Spvariant {
         SpOrderedMetadata, // .sp::ordered_metadata
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindMxf4, // .kind::mxf4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum ScaleVecSize {
         ScaleVec2x, // .scale_vec::2X
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Stype {
         Ue8m0, // .ue8m0
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype {
         pub spvariant: Spvariant,                 // .spvariant
         pub sync: (),                             // .sync
@@ -365,31 +365,31 @@ pub mod section_3 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Spvariant {
         SpOrderedMetadata, // .sp::ordered_metadata
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindMxf4nvf4, // .kind::mxf4nvf4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum ScaleVecSize {
         ScaleVec2x, // .scale_vec::2X
         ScaleVec4x, // .scale_vec::4X
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Stype {
         Ue8m0, // .ue8m0
         Ue4m3, // .ue4m3
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1 {
         pub spvariant: Spvariant,         // .spvariant
         pub sync: (),                     // .sync
@@ -424,24 +424,24 @@ pub mod section_4 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Spvariant {
         SpOrderedMetadata, // .sp::ordered_metadata
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindMxf8f6f4, // .kind::mxf8f6f4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum ScaleVecSize {
         ScaleVec1x, // .scale_vec::1X
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum F8f6f4type {
         E4m3, // .e4m3
         E5m2, // .e5m2
@@ -450,12 +450,12 @@ pub mod section_4 {
         E2m1, // .e2m1
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Stype {
         Ue8m0, // .ue8m0
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype
     {
         pub spvariant: Spvariant,                 // .spvariant
@@ -491,33 +491,33 @@ pub mod section_5 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Spvariant {
         SpOrderedMetadata, // .sp::ordered_metadata
         Sp,                // .sp
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M16n8k32, // .m16n8k32
         M16n8k64, // .m16n8k64
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         U8, // .u8
         S8, // .s8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         U8, // .u8
         S8, // .s8
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32 {
         pub spvariant: Spvariant, // .spvariant
         pub sync: (),             // .sync
@@ -545,33 +545,33 @@ pub mod section_6 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Spvariant {
         SpOrderedMetadata, // .sp::ordered_metadata
         Sp,                // .sp
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M16n8k128, // .m16n8k128
         M16n8k64,  // .m16n8k64
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         U4, // .u4
         S4, // .s4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         U4, // .u4
         S4, // .s4
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321 {
         pub spvariant: Spvariant, // .spvariant
         pub sync: (),             // .sync
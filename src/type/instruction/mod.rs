@@ -2,7 +2,7 @@
 // DO NOT EDIT MANUALLY
 #![allow(unused)]
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 pub mod abs;
 pub mod activemask;
@@ -175,7 +175,7 @@ pub mod wmma_store;
 pub mod xor;
 
 /// Top-level instruction type encompassing all PTX instructions
-#[derive(Debug, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum Inst {
     AbsType(abs::section_0::AbsType),
     AbsFtzF32(abs::section_0::AbsFtzF32),
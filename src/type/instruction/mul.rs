@@ -24,16 +24,16 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Mode {
         Wide, // .wide
         Hi,   // .hi
         Lo,   // .lo
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         U16, // .u16
         U32, // .u32
@@ -43,7 +43,7 @@ pub mod section_0 {
         S64, // .s64
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MulModeType {
         pub mode: Mode,        // .mode
         pub type_: Type,       // .type
@@ -59,9 +59,9 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Rnd {
         Rn, // .rn
         Rz, // .rz
@@ -69,7 +69,7 @@ pub mod section_1 {
         Rp, // .rp
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MulRndFtzSatF32 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub ftz: bool,         // {.ftz}
@@ -81,7 +81,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MulRndFtzF32x2 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub ftz: bool,         // {.ftz}
@@ -92,7 +92,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MulRndF64 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub f64: (),           // .f64
@@ -108,14 +108,14 @@ pub mod section_2 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Rnd {
         Rn, // .rn
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MulRndFtzSatF16 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub ftz: bool,         // {.ftz}
@@ -127,7 +127,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MulRndFtzSatF16x2 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub ftz: bool,         // {.ftz}
@@ -139,7 +139,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MulRndBf16 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub bf16: (),          // .bf16
@@ -149,7 +149,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MulRndBf16x2 {
         pub rnd: Option<Rnd>,  // {.rnd}
         pub bf16x2: (),        // .bf16x2
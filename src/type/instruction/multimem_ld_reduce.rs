@@ -40,15 +40,15 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ldsem {
         Relaxed, // .relaxed
         Acquire, // .acquire
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Scope {
         Cluster, // .cluster
         Cta,     // .cta
@@ -56,12 +56,12 @@ pub mod section_0 {
         Sys,     // .sys
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ss {
         Global, // .global
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         Min, // .min
         Max, // .max
@@ -71,7 +71,7 @@ pub mod section_0 {
         Or,  // .or
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         B32, // .b32
         B64, // .b64
@@ -81,19 +81,19 @@ pub mod section_0 {
         S64, // .s64
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Stsem {
         Relaxed, // .relaxed
         Release, // .release
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Redsem {
         Relaxed, // .relaxed
         Release, // .release
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MultimemLdReduceLdsemScopeSsOpType {
         pub ld_reduce: (),        // .ld_reduce
         pub ldsem: Option<Ldsem>, // {.ldsem}
@@ -106,7 +106,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MultimemLdReduceWeakSsOpType {
         pub ld_reduce: (),     // .ld_reduce
         pub weak: (),          // .weak
@@ -118,7 +118,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MultimemStStsemScopeSsType {
         pub st: (),               // .st
         pub stsem: Option<Stsem>, // {.stsem}
@@ -130,7 +130,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MultimemStWeakSsType {
         pub st: (),            // .st
         pub weak: (),          // .weak
@@ -141,7 +141,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MultimemRedRedsemScopeSsOpType {
         pub red: (),                // .red
         pub redsem: Option<Redsem>, // {.redsem}
@@ -160,15 +160,15 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ldsem {
         Relaxed, // .relaxed
         Acquire, // .acquire
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Scope {
         Cluster, // .cluster
         Cta,     // .cta
@@ -176,32 +176,32 @@ pub mod section_1 {
         Sys,     // .sys
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ss {
         Global, // .global
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         Min, // .min
         Max, // .max
         Add, // .add
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum AccPrec {
         AccF32, // .acc::f32
         AccF16, // .acc::f16
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Vec {
         V2, // .v2
         V4, // .v4
         V8, // .v8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         Bf16x2, // .bf16x2
         E5m2x2, // .e5m2x2
@@ -217,24 +217,24 @@ pub mod section_1 {
         F64,    // .f64
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Stsem {
         Relaxed, // .relaxed
         Release, // .release
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Redsem {
         Relaxed, // .relaxed
         Release, // .release
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Redop {
         Add, // .add
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Redtype {
         Bf16x2, // .bf16x2
         F16x2,  // .f16x2
@@ -244,7 +244,7 @@ pub mod section_1 {
         F64,    // .f64
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MultimemLdReduceLdsemScopeSsOpAccPrecVecType {
         pub ld_reduce: (),             // .ld_reduce
         pub ldsem: Option<Ldsem>,      // {.ldsem}
@@ -259,7 +259,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MultimemLdReduceWeakSsOpAccPrecVecType {
         pub ld_reduce: (),             // .ld_reduce
         pub weak: (),                  // .weak
@@ -273,7 +273,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MultimemStStsemScopeSsVecType {
         pub st: (),               // .st
         pub stsem: Option<Stsem>, // {.stsem}
@@ -286,7 +286,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MultimemStWeakSsVecType {
         pub st: (),            // .st
         pub weak: (),          // .weak
@@ -298,7 +298,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct MultimemRedRedsemScopeSsRedopVecRedtype {
         pub red: (),                // .red
         pub redsem: Option<Redsem>, // {.redsem}
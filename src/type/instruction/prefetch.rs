@@ -17,33 +17,33 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Space {
         Global, // .global
         Local,  // .local
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Level {
         L1, // .L1
         L2, // .L2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelEvictionPriority {
         L2EvictNormal, // .L2::evict_normal
         L2EvictLast,   // .L2::evict_last
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum TensormapSpace {
         Const, // .const
         Param, // .param
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct PrefetchSpaceLevel {
         pub space: Option<Space>, // {.space}
         pub level: Level,         // .level
@@ -51,7 +51,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct PrefetchGlobalLevelEvictionPriority {
         pub global: (),                                     // .global
         pub level_eviction_priority: LevelEvictionPriority, // .level::eviction_priority
@@ -59,14 +59,14 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct PrefetchuL1 {
         pub l1: (),            // .L1
         pub a: AddressOperand, // [a]
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct PrefetchTensormapSpaceTensormap {
         pub tensormap_space: Option<TensormapSpace>, // {.tensormap_space}
         pub tensormap: (),                           // .tensormap
@@ -11,9 +11,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Mode {
         Rc16, // .rc16
         F4e,  // .f4e
@@ -23,7 +23,7 @@ pub mod section_0 {
         Ecr,  // .ecr
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct PrmtB32Mode {
         pub b32: (),            // .b32
         pub mode: Option<Mode>, // {.mode}
@@ -49,9 +49,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Cmpop {
         Equ, // .equ
         Neu, // .neu
@@ -73,14 +73,14 @@ pub mod section_0 {
         Hs,  // .hs
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         U32, // .u32
         S32, // .s32
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Stype {
         B16, // .b16
         B32, // .b32
@@ -95,14 +95,14 @@ pub mod section_0 {
         F64, // .f64
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Boolop {
         And, // .and
         Xor, // .xor
         Or,  // .or
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopFtzDtypeStype {
         pub cmpop: Cmpop,      // .CmpOp
         pub ftz: bool,         // {.ftz}
@@ -114,7 +114,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopBoolopFtzDtypeStype {
         pub cmpop: Cmpop,      // .CmpOp
         pub boolop: Boolop,    // .BoolOp
@@ -135,9 +135,9 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Cmpop {
         Equ, // .equ
         Neu, // .neu
@@ -159,7 +159,7 @@ pub mod section_1 {
         Hs,  // .hs
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Stype {
         B16, // .b16
         B32, // .b32
@@ -174,14 +174,14 @@ pub mod section_1 {
         F64, // .f64
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Boolop {
         And, // .and
         Xor, // .xor
         Or,  // .or
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         U16, // .u16
         S16, // .s16
@@ -189,7 +189,7 @@ pub mod section_1 {
         S32, // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopFtzF16Stype {
         pub cmpop: Cmpop,      // .CmpOp
         pub ftz: bool,         // {.ftz}
@@ -201,7 +201,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopBoolopFtzF16Stype {
         pub cmpop: Cmpop,      // .CmpOp
         pub boolop: Boolop,    // .BoolOp
@@ -216,7 +216,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopBf16Stype {
         pub cmpop: Cmpop,      // .CmpOp
         pub bf16: (),          // .bf16
@@ -227,7 +227,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopBoolopBf16Stype {
         pub cmpop: Cmpop,      // .CmpOp
         pub boolop: Boolop,    // .BoolOp
@@ -241,7 +241,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopFtzDtypeF16 {
         pub cmpop: Cmpop,      // .CmpOp
         pub ftz: bool,         // {.ftz}
@@ -253,7 +253,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopBoolopFtzDtypeF16 {
         pub cmpop: Cmpop,      // .CmpOp
         pub boolop: Boolop,    // .BoolOp
@@ -274,9 +274,9 @@ pub mod section_2 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Cmpop {
         Equ, // .equ
         Neu, // .neu
@@ -298,7 +298,7 @@ pub mod section_2 {
         Hs,  // .hs
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         U16, // .u16
         S16, // .s16
@@ -306,14 +306,14 @@ pub mod section_2 {
         S32, // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Boolop {
         And, // .and
         Xor, // .xor
         Or,  // .or
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopDtypeBf16 {
         pub cmpop: Cmpop,      // .CmpOp
         pub dtype: Dtype,      // .dtype
@@ -324,7 +324,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopBoolopDtypeBf16 {
         pub cmpop: Cmpop,      // .CmpOp
         pub boolop: Boolop,    // .BoolOp
@@ -344,9 +344,9 @@ pub mod section_3 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Cmpop {
         Equ, // .equ
         Neu, // .neu
@@ -368,21 +368,21 @@ pub mod section_3 {
         Hs,  // .hs
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F16x2, // .f16x2
         U32,   // .u32
         S32,   // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Boolop {
         And, // .and
         Xor, // .xor
         Or,  // .or
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopFtzDtypeF16x2 {
         pub cmpop: Cmpop,      // .CmpOp
         pub ftz: bool,         // {.ftz}
@@ -394,7 +394,7 @@ pub mod section_3 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopBoolopFtzDtypeF16x2 {
         pub cmpop: Cmpop,      // .CmpOp
         pub boolop: Boolop,    // .BoolOp
@@ -415,9 +415,9 @@ pub mod section_4 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Cmpop {
         Equ, // .equ
         Neu, // .neu
@@ -435,21 +435,21 @@ pub mod section_4 {
         Ge,  // .ge
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         Bf16x2, // .bf16x2
         U32,    // .u32
         S32,    // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Boolop {
         And, // .and
         Xor, // .xor
         Or,  // .or
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopDtypeBf16x2 {
         pub cmpop: Cmpop,      // .CmpOp
         pub dtype: Dtype,      // .dtype
@@ -460,7 +460,7 @@ pub mod section_4 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetCmpopBoolopDtypeBf16x2 {
         pub cmpop: Cmpop,      // .CmpOp
         pub boolop: Boolop,    // .BoolOp
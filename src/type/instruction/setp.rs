@@ -25,9 +25,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Cmpop {
         Equ, // .equ
         Neu, // .neu
@@ -49,7 +49,7 @@ pub mod section_0 {
         Hs,  // .hs
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         B16, // .b16
         B32, // .b32
@@ -64,14 +64,14 @@ pub mod section_0 {
         F64, // .f64
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Boolop {
         And, // .and
         Xor, // .xor
         Or,  // .or
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetpCmpopFtzType {
         pub cmpop: Cmpop,              // .CmpOp
         pub ftz: bool,                 // {.ftz}
@@ -83,7 +83,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetpCmpopBoolopFtzType {
         pub cmpop: Cmpop,              // .CmpOp
         pub boolop: Boolop,            // .BoolOp
@@ -104,9 +104,9 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Cmpop {
         Equ, // .equ
         Neu, // .neu
@@ -124,14 +124,14 @@ pub mod section_1 {
         Ge,  // .ge
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Boolop {
         And, // .and
         Xor, // .xor
         Or,  // .or
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetpCmpopFtzF16 {
         pub cmpop: Cmpop,      // .CmpOp
         pub ftz: bool,         // {.ftz}
@@ -142,7 +142,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetpCmpopBoolopFtzF16 {
         pub cmpop: Cmpop,      // .CmpOp
         pub boolop: Boolop,    // .BoolOp
@@ -156,7 +156,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetpCmpopFtzF16x2 {
         pub cmpop: Cmpop,      // .CmpOp
         pub ftz: bool,         // {.ftz}
@@ -168,7 +168,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetpCmpopBoolopFtzF16x2 {
         pub cmpop: Cmpop,      // .CmpOp
         pub boolop: Boolop,    // .BoolOp
@@ -183,7 +183,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetpCmpopBf16 {
         pub cmpop: Cmpop,      // .CmpOp
         pub bf16: (),          // .bf16
@@ -193,7 +193,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetpCmpopBoolopBf16 {
         pub cmpop: Cmpop,      // .CmpOp
         pub boolop: Boolop,    // .BoolOp
@@ -206,7 +206,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetpCmpopBf16x2 {
         pub cmpop: Cmpop,      // .CmpOp
         pub bf16x2: (),        // .bf16x2
@@ -217,7 +217,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SetpCmpopBoolopBf16x2 {
         pub cmpop: Cmpop,      // .CmpOp
         pub boolop: Boolop,    // .BoolOp
@@ -28,9 +28,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ss {
         SharedCluster, // .shared::cluster
         ParamFunc,     // .param::func
@@ -41,7 +41,7 @@ pub mod section_0 {
         Param,         // .param
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Cop {
         Wb, // .wb
         Cg, // .cg
@@ -49,19 +49,19 @@ pub mod section_0 {
         Wt, // .wt
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum LevelCacheHint {
         L2CacheHint, // .L2::cache_hint
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Vec {
         V2, // .v2
         V4, // .v4
         V8, // .v8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         B128, // .b128
         B16,  // .b16
@@ -80,7 +80,7 @@ pub mod section_0 {
         S8,   // .s8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Level1EvictionPriority {
         L1EvictUnchanged, // .L1::evict_unchanged
         L1EvictNormal,    // .L1::evict_normal
@@ -89,14 +89,14 @@ pub mod section_0 {
         L1EvictLast,      // .L1::evict_last
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Level2EvictionPriority {
         L2EvictNormal, // .L2::evict_normal
         L2EvictFirst,  // .L2::evict_first
         L2EvictLast,   // .L2::evict_last
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Scope {
         Cluster, // .cluster
         Cta,     // .cta
@@ -104,7 +104,7 @@ pub mod section_0 {
         Sys,     // .sys
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct StWeakSsCopLevelCacheHintVecType {
         pub weak: bool,                               // {.weak}
         pub ss: Option<Ss>,                           // {.ss}
@@ -118,7 +118,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct StWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType {
         pub weak: bool,                                               // {.weak}
         pub ss: Option<Ss>,                                           // {.ss}
@@ -133,7 +133,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct StVolatileSsVecType {
         pub volatile: (),      // .volatile
         pub ss: Option<Ss>,    // {.ss}
@@ -144,7 +144,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct StRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType {
         pub relaxed: (),                                              // .relaxed
         pub scope: Scope,                                             // .scope
@@ -160,7 +160,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct StReleaseScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType {
         pub release: (),                                              // .release
         pub scope: Scope,                                             // .scope
@@ -176,7 +176,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct StMmioRelaxedSysGlobalType {
         pub mmio: (),          // .mmio
         pub relaxed: (),       // .relaxed
@@ -16,9 +16,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Geom {
         A1d, // .a1d
         A2d, // .a2d
@@ -27,7 +27,7 @@ pub mod section_0 {
         _3d, // .3d
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Cop {
         Ca, // .ca
         Cg, // .cg
@@ -35,14 +35,14 @@ pub mod section_0 {
         Cv, // .cv
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Vec {
         None, // none
         V2,   // .v2
         V4,   // .v4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         B16, // .b16
         B32, // .b32
@@ -50,14 +50,14 @@ pub mod section_0 {
         B8,  // .b8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Mode {
         Clamp, // .clamp
         Trap,  // .trap
         Zero,  // .zero
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SuldBGeomCopVecDtypeMode {
         pub b: (),              // .b
         pub geom: Geom,         // .geom
@@ -13,9 +13,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Query {
         ChannelDataType, // .channel_data_type
         ChannelOrder,    // .channel_order
@@ -26,7 +26,7 @@ pub mod section_0 {
         Depth,           // .depth
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SuqQueryB32 {
         pub query: Query,      // .query
         pub b32: (),           // .b32
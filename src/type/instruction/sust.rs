@@ -18,16 +18,16 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dim {
         _1d, // .1d
         _2d, // .2d
         _3d, // .3d
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Cop {
         Wb, // .wb
         Cg, // .cg
@@ -35,14 +35,14 @@ pub mod section_0 {
         Wt, // .wt
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Vec {
         None, // none
         V2,   // .v2
         V4,   // .v4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ctype {
         B16, // .b16
         B32, // .b32
@@ -50,20 +50,20 @@ pub mod section_0 {
         B8,  // .b8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Mode {
         Clamp, // .clamp
         Trap,  // .trap
         Zero,  // .zero
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Adim {
         A1d, // .a1d
         A2d, // .a2d
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SustBDimCopVecCtypeMode {
         pub b: (),              // .b
         pub dim: Dim,           // .dim
@@ -76,7 +76,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SustPDimVecB32Mode {
         pub p: (),              // .p
         pub dim: Dim,           // .dim
@@ -88,7 +88,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct SustBAdimCopVecCtypeMode {
         pub b: (),              // .b
         pub adim: Adim,         // .adim
@@ -13,15 +13,15 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32 {
         pub alloc: (),             // .alloc
         pub cta_group: CtaGroup,   // .cta_group
@@ -34,7 +34,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05DeallocCtaGroupSyncAlignedB32 {
         pub dealloc: (),           // .dealloc
         pub cta_group: CtaGroup,   // .cta_group
@@ -46,7 +46,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05RelinquishAllocPermitCtaGroupSyncAligned {
         pub relinquish_alloc_permit: (), // .relinquish_alloc_permit
         pub cta_group: CtaGroup,         // .cta_group
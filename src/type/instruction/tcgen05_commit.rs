@@ -14,25 +14,25 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CompletionMechanism {
         MbarrierArriveOne, // .mbarrier::arrive::one
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Multicast {
         MulticastCluster, // .multicast::cluster
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64 {
         pub commit: (),                                // .commit
         pub cta_group: CtaGroup,                       // .cta_group
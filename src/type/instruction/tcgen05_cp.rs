@@ -14,15 +14,15 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         _32x128b,  // .32x128b***
         _64x128b,  // .64x128b**
@@ -31,20 +31,20 @@ pub mod section_0 {
         _4x256b,   // .4x256b
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Multicast {
         Warpx20213, // .warpx2::02_13**
         Warpx20123, // .warpx2::01_23**
         Warpx4,     // .warpx4***
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum DstSrcFmt {
         B8x16B6x16P32, // .b8x16.b6x16_p32
         B8x16B4x16P64, // .b8x16.b4x16_p64
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05CpCtaGroupShapeMulticastDstSrcFmt {
         pub cp: (),                         // .cp
         pub cta_group: CtaGroup,            // .cta_group
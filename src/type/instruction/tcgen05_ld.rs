@@ -26,9 +26,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape1 {
         _16x128b, // .16x128b
         _16x256b, // .16x256b
@@ -36,7 +36,7 @@ pub mod section_0 {
         _32x32b,  // .32x32b
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Num {
         X128, // .x128
         X16,  // .x16
@@ -48,39 +48,39 @@ pub mod section_0 {
         X8,   // .x8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Pack {
         Pack16b, // .pack::16b
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape2 {
         _16x32bx2, // .16x32bx2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape3 {
         _32x32b, // .32x32b
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Redop {
         Min, // .min
         Max, // .max
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape4 {
         _16x32bx2, // .16x32bx2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         U32, // .u32
         S32, // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05LdSyncAlignedShape1NumPackB32 {
         pub ld: (),                // .ld
         pub sync: (),              // .sync
@@ -94,7 +94,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05LdSyncAlignedShape2NumPackB32 {
         pub ld: (),                          // .ld
         pub sync: (),                        // .sync
@@ -109,7 +109,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32 {
         pub ld: (),                 // .ld
         pub red: (),                // .red
@@ -127,7 +127,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32 {
         pub ld: (),                          // .ld
         pub red: (),                         // .red
@@ -146,7 +146,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05LdRedSyncAlignedShape3NumRedopType {
         pub ld: (),                 // .ld
         pub red: (),                // .red
@@ -162,7 +162,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05LdRedSyncAlignedShape4NumRedopType {
         pub ld: (),                          // .ld
         pub red: (),                         // .red
@@ -73,22 +73,22 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindF8f6f4, // .kind::f8f6f4
         KindTf32,   // .kind::tf32
         KindF16,    // .kind::f16
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKind {
         pub mma: (),                                     // .mma
         pub cta_group: CtaGroup,                         // .cta_group
@@ -103,7 +103,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKind1 {
         pub mma: (),                                     // .mma
         pub cta_group: CtaGroup,                         // .cta_group
@@ -124,22 +124,22 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindMxf8f6f4, // .kind::mxf8f6f4
         KindMxf4nvf4, // .kind::mxf4nvf4
         KindMxf4,     // .kind::mxf4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum ScaleVectorsize {
         ScaleVec1x, // .scale_vec::1X
         ScaleVec2x, // .scale_vec::2X
@@ -148,7 +148,7 @@ pub mod section_1 {
         Block32,    // .block32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize {
         pub mma: (),                                   // .mma
         pub cta_group: CtaGroup,                       // .cta_group
@@ -165,7 +165,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1 {
         pub mma: (),                                   // .mma
         pub cta_group: CtaGroup,                       // .cta_group
@@ -188,27 +188,27 @@ pub mod section_2 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindF8f6f4, // .kind::f8f6f4
         KindTf32,   // .kind::tf32
         KindF16,    // .kind::f16
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Buffer {
         A, // ::a
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         Discard, // ::discard*
         Lastuse, // ::lastuse
@@ -216,12 +216,12 @@ pub mod section_2 {
         Use,     // ::use
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CollectorUsage {
         CollectorBufferOp((), Buffer, Op), // .collector::buffer::op
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKindCollectorUsage {
         pub mma: (),                                     // .mma
         pub cta_group: CtaGroup,                         // .cta_group
@@ -237,7 +237,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKindAshiftCollectorUsage {
         pub mma: (),                                     // .mma
         pub cta_group: CtaGroup,                         // .cta_group
@@ -254,7 +254,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKindAshiftCollectorUsage1 {
         pub mma: (),                                     // .mma
         pub cta_group: CtaGroup,                         // .cta_group
@@ -277,22 +277,22 @@ pub mod section_3 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindMxf8f6f4, // .kind::mxf8f6f4
         KindMxf4nvf4, // .kind::mxf4nvf4
         KindMxf4,     // .kind::mxf4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum ScaleVectorsize {
         ScaleVec1x, // .scale_vec::1X
         ScaleVec2x, // .scale_vec::2X
@@ -301,12 +301,12 @@ pub mod section_3 {
         Block32,    // .block32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Buffer {
         A, // ::a
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         Discard, // ::discard*
         Lastuse, // ::lastuse
@@ -314,12 +314,12 @@ pub mod section_3 {
         Use,     // ::use
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CollectorUsage {
         CollectorBufferOp((), Buffer, Op), // .collector::buffer::op
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage {
         pub mma: (),                                   // .mma
         pub cta_group: CtaGroup,                       // .cta_group
@@ -337,7 +337,7 @@ pub mod section_3 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1 {
         pub mma: (),                                   // .mma
         pub cta_group: CtaGroup,                       // .cta_group
@@ -361,15 +361,15 @@ pub mod section_4 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKindI8 {
         pub mma: (),                                     // .mma
         pub cta_group: CtaGroup,                         // .cta_group
@@ -383,7 +383,7 @@ pub mod section_4 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKindI81 {
         pub mma: (),                                     // .mma
         pub cta_group: CtaGroup,                         // .cta_group
@@ -403,20 +403,20 @@ pub mod section_5 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Buffer {
         A, // ::a
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         Discard, // ::discard*
         Lastuse, // ::lastuse
@@ -424,12 +424,12 @@ pub mod section_5 {
         Use,     // ::use
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CollectorUsage {
         CollectorBufferOp((), Buffer, Op), // .collector::buffer::op
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKindI8CollectorUsage {
         pub mma: (),                                     // .mma
         pub cta_group: CtaGroup,                         // .cta_group
@@ -444,7 +444,7 @@ pub mod section_5 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage {
         pub mma: (),                                     // .mma
         pub cta_group: CtaGroup,                         // .cta_group
@@ -460,7 +460,7 @@ pub mod section_5 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1 {
         pub mma: (),                                     // .mma
         pub cta_group: CtaGroup,                         // .cta_group
@@ -55,22 +55,22 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindF8f6f4, // .kind::f8f6f4
         KindTf32,   // .kind::tf32
         KindF16,    // .kind::f16
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKind {
         pub mma: (),                                     // .mma
         pub sp: (),                                      // .sp
@@ -87,7 +87,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKind1 {
         pub mma: (),                                     // .mma
         pub sp: (),                                      // .sp
@@ -110,22 +110,22 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindMxf8f6f4, // .kind::mxf8f6f4
         KindMxf4nvf4, // .kind::mxf4nvf4
         KindMxf4,     // .kind::mxf4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum ScaleVectorsize {
         ScaleVec1x, // .scale_vec::1X
         ScaleVec2x, // .scale_vec::2X
@@ -134,7 +134,7 @@ pub mod section_1 {
         Block32,    // .block32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize {
         pub mma: (),                                   // .mma
         pub sp: (),                                    // .sp
@@ -153,7 +153,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1 {
         pub mma: (),                                   // .mma
         pub sp: (),                                    // .sp
@@ -178,27 +178,27 @@ pub mod section_2 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindF8f6f4, // .kind::f8f6f4
         KindTf32,   // .kind::tf32
         KindF16,    // .kind::f16
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Buffer {
         A, // ::a
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         Discard, // ::discard*
         Lastuse, // ::lastuse
@@ -206,12 +206,12 @@ pub mod section_2 {
         Use,     // ::use
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CollectorUsage {
         CollectorBufferOp((), Buffer, Op), // .collector::buffer::op
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKindCollectorUsage {
         pub mma: (),                                     // .mma
         pub sp: (),                                      // .sp
@@ -229,7 +229,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage {
         pub mma: (),                                     // .mma
         pub sp: (),                                      // .sp
@@ -248,7 +248,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1 {
         pub mma: (),                                     // .mma
         pub sp: (),                                      // .sp
@@ -273,22 +273,22 @@ pub mod section_3 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindMxf8f6f4, // .kind::mxf8f6f4
         KindMxf4nvf4, // .kind::mxf4nvf4
         KindMxf4,     // .kind::mxf4
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum ScaleVectorsize {
         ScaleVec1x, // .scale_vec::1X
         ScaleVec2x, // .scale_vec::2X
@@ -297,12 +297,12 @@ pub mod section_3 {
         Block32,    // .block32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Buffer {
         A, // ::a
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         Discard, // ::discard*
         Lastuse, // ::lastuse
@@ -310,12 +310,12 @@ pub mod section_3 {
         Use,     // ::use
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CollectorUsage {
         CollectorBufferOp((), Buffer, Op), // .collector::buffer::op
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage {
         pub mma: (),                                   // .mma
         pub sp: (),                                    // .sp
@@ -335,7 +335,7 @@ pub mod section_3 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1 {
         pub mma: (),                                   // .mma
         pub sp: (),                                    // .sp
@@ -361,15 +361,15 @@ pub mod section_4 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKindI8 {
         pub mma: (),                                     // .mma
         pub sp: (),                                      // .sp
@@ -385,7 +385,7 @@ pub mod section_4 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKindI81 {
         pub mma: (),                                     // .mma
         pub sp: (),                                      // .sp
@@ -407,20 +407,20 @@ pub mod section_5 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CtaGroup {
         CtaGroup1, // .cta_group::1
         CtaGroup2, // .cta_group::2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Buffer {
         A, // ::a
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         Discard, // ::discard*
         Lastuse, // ::lastuse
@@ -428,12 +428,12 @@ pub mod section_5 {
         Use,     // ::use
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CollectorUsage {
         CollectorBufferOp((), Buffer, Op), // .collector::buffer::op
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKindI8CollectorUsage {
         pub mma: (),                                     // .mma
         pub sp: (),                                      // .sp
@@ -450,7 +450,7 @@ pub mod section_5 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage {
         pub mma: (),                                     // .mma
         pub sp: (),                                      // .sp
@@ -468,7 +468,7 @@ pub mod section_5 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1 {
         pub mma: (),                                     // .mma
         pub sp: (),                                      // .sp
@@ -24,16 +24,16 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindF8f6f4, // .kind::f8f6f4
         KindTf32,   // .kind::tf32
         KindF16,    // .kind::f16
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaWsCtaGroup1KindCollectorUsage {
         pub mma: (),                                       // .mma
         pub ws: (),                                        // .ws
@@ -49,7 +49,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaWsCtaGroup1KindCollectorUsage1 {
         pub mma: (),                                       // .mma
         pub ws: (),                                        // .ws
@@ -71,9 +71,9 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Buffer {
         B0, // ::b0
         B1, // ::b1
@@ -81,7 +81,7 @@ pub mod section_1 {
         B3, // ::b3
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         Lastuse, // ::lastuse
         Discard, // ::discard
@@ -89,12 +89,12 @@ pub mod section_1 {
         Use,     // ::use
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CollectorUsage {
         CollectorBufferOp((), Buffer, Op), // .collector::buffer::op
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaWsCtaGroup1KindI8CollectorUsage {
         pub mma: (),                                       // .mma
         pub ws: (),                                        // .ws
@@ -110,7 +110,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1 {
         pub mma: (),                                       // .mma
         pub ws: (),                                        // .ws
@@ -28,16 +28,16 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Kind {
         KindF8f6f4, // .kind::f8f6f4
         KindTf32,   // .kind::tf32
         KindF16,    // .kind::f16
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaWsSpCtaGroup1KindCollectorUsage {
         pub mma: (),                                       // .mma
         pub ws: (),                                        // .ws
@@ -55,7 +55,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaWsSpCtaGroup1KindCollectorUsage1 {
         pub mma: (),                                       // .mma
         pub ws: (),                                        // .ws
@@ -79,9 +79,9 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Buffer {
         B0, // ::b0
         B1, // ::b1
@@ -89,7 +89,7 @@ pub mod section_1 {
         B3, // ::b3
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         Lastuse, // ::lastuse
         Discard, // ::discard
@@ -97,12 +97,12 @@ pub mod section_1 {
         Use,     // ::use
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CollectorUsage {
         CollectorBufferOp((), Buffer, Op), // .collector::buffer::op
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage {
         pub mma: (),                                       // .mma
         pub ws: (),                                        // .ws
@@ -120,7 +120,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage1 {
         pub mma: (),                                       // .mma
         pub ws: (),                                        // .ws
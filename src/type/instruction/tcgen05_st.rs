@@ -15,9 +15,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape1 {
         _16x128b, // .16x128b
         _16x256b, // .16x256b
@@ -25,7 +25,7 @@ pub mod section_0 {
         _32x32b,  // .32x32b
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Num {
         X128, // .x128
         X16,  // .x16
@@ -37,17 +37,17 @@ pub mod section_0 {
         X8,   // .x8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Unpack {
         Unpack16b, // .unpack::16b
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape2 {
         _16x32bx2, // .16x32bx2
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05StSyncAlignedShape1NumUnpackB32 {
         pub st: (),                 // .st
         pub sync: (),               // .sync
@@ -61,7 +61,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tcgen05StSyncAlignedShape2NumUnpackB32 {
         pub st: (),                          // .st
         pub sync: (),                        // .sync
@@ -14,19 +14,19 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum CpQualifiers {
         GlobalSharedCta, // .global.shared::cta
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum ToProxyFromProxy {
         TensormapGeneric, // .tensormap::generic
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Scope {
         Cluster, // .cluster
         Cta,     // .cta
@@ -34,12 +34,12 @@ pub mod section_0 {
         Sys,     // .sys
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum FenceQualifiers {
         ToProxyFromProxyReleaseScope(ToProxyFromProxy, (), Scope), // .to_proxy::from_proxy.release.scope
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned {
         pub cp_fenceproxy: (),                 // .cp_fenceproxy
         pub cp_qualifiers: CpQualifiers,       // .cp_qualifiers
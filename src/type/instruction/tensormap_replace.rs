@@ -18,32 +18,32 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Mode {
         Tile, // .tile
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Field1 {
         GlobalAddress, // .global_address
         Rank,          // .rank
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ss {
         SharedCta, // .shared::cta
         Global,    // .global
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Type {
         B32, // .b32
         B64, // .b64
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Field2 {
         ElementStride, // .element_stride
         GlobalStride,  // .global_stride
@@ -51,7 +51,7 @@ pub mod section_0 {
         BoxDim,        // .box_dim
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Field3 {
         InterleaveLayout, // .interleave_layout
         SwizzleAtomicity, // .swizzle_atomicity
@@ -60,7 +60,7 @@ pub mod section_0 {
         Elemtype,         // .elemtype
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TensormapReplaceModeField1SsB1024Type {
         pub replace: (),             // .replace
         pub mode: Mode,              // .mode
@@ -73,7 +73,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TensormapReplaceModeField2SsB1024Type {
         pub replace: (),             // .replace
         pub mode: Mode,              // .mode
@@ -87,7 +87,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TensormapReplaceModeField3SsB1024Type {
         pub replace: (),             // .replace
         pub mode: Mode,              // .mode
@@ -24,9 +24,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Geom {
         Acube, // .acube
         A2dms, // .a2dms
@@ -39,7 +39,7 @@ pub mod section_0 {
         _3d,   // .3d
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         U32, // .u32
         S32, // .s32
@@ -47,13 +47,13 @@ pub mod section_0 {
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Ctype {
         S32, // .s32
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TexGeomV4DtypeCtype {
         pub geom: Geom,                // .geom
         pub v4: (),                    // .v4
@@ -67,7 +67,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TexGeomV4DtypeCtype1 {
         pub geom: Geom,                // .geom
         pub v4: (),                    // .v4
@@ -81,7 +81,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TexGeomV2F16x2Ctype {
         pub geom: Geom,                // .geom
         pub v2: (),                    // .v2
@@ -95,7 +95,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TexGeomV2F16x2Ctype1 {
         pub geom: Geom,                // .geom
         pub v2: (),                    // .v2
@@ -109,7 +109,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TexBaseGeomV4DtypeCtype {
         pub base: (),                  // .base
         pub geom: Geom,                // .geom
@@ -124,7 +124,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TexLevelGeomV4DtypeCtype {
         pub level: (),                 // .level
         pub geom: Geom,                // .geom
@@ -140,7 +140,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TexGradGeomV4DtypeCtype {
         pub grad: (),                  // .grad
         pub geom: Geom,                // .geom
@@ -157,7 +157,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TexBaseGeomV2F16x2Ctype {
         pub base: (),                  // .base
         pub geom: Geom,                // .geom
@@ -172,7 +172,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TexLevelGeomV2F16x2Ctype {
         pub level: (),                 // .level
         pub geom: Geom,                // .geom
@@ -188,7 +188,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TexGradGeomV2F16x2Ctype {
         pub grad: (),                  // .grad
         pub geom: Geom,                // .geom
@@ -14,9 +14,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Comp {
         R, // .r
         G, // .g
@@ -24,14 +24,14 @@ pub mod section_0 {
         A, // .a
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         U32, // .u32
         S32, // .s32
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Geom {
         Acube, // .acube
         Cube,  // .cube
@@ -39,7 +39,7 @@ pub mod section_0 {
         _2d,   // .2d
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tld4Comp2dV4DtypeF32 {
         pub comp: Comp,                // .comp
         pub _2d: (),                   // .2d
@@ -54,7 +54,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Tld4CompGeomV4DtypeF32 {
         pub comp: Comp,                // .comp
         pub geom: Geom,                // .geom
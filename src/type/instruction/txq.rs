@@ -19,9 +19,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Tquery {
         ChannelDataType,  // .channel_data_type
         NormalizedCoords, // .normalized_coords
@@ -34,14 +34,14 @@ pub mod section_0 {
         Depth,            // .depth
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Tlquery {
         Height, // .height
         Width,  // .width
         Depth,  // .depth
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Squery {
         ForceUnnormalizedCoords, // .force_unnormalized_coords
         FilterMode,              // .filter_mode
@@ -50,7 +50,7 @@ pub mod section_0 {
         AddrMode2,               // addr_mode_2
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TxqTqueryB32 {
         pub tquery: Tquery,    // .tquery
         pub b32: (),           // .b32
@@ -59,7 +59,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TxqLevelTlqueryB32 {
         pub level: (),           // .level
         pub tlquery: Tlquery,    // .tlquery
@@ -70,7 +70,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct TxqSqueryB32 {
         pub squery: Squery,    // .squery
         pub b32: (),           // .b32
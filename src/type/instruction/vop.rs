@@ -18,27 +18,27 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         U32, // .u32
         S32, // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         U32, // .u32
         S32, // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         U32, // .u32
         S32, // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Asel {
         B0, // .b0
         B1, // .b1
@@ -48,7 +48,7 @@ pub mod section_0 {
         H1, // .h1
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Bsel {
         B0, // .b0
         B1, // .b1
@@ -58,14 +58,14 @@ pub mod section_0 {
         H1, // .h1
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op2 {
         Add, // .add
         Min, // .min
         Max, // .max
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dsel {
         B0, // .b0
         B1, // .b1
@@ -75,7 +75,7 @@ pub mod section_0 {
         H1, // .h1
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VaddDtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -89,7 +89,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VsubDtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -103,7 +103,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VabsdiffDtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -117,7 +117,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VminDtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -131,7 +131,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VmaxDtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -145,7 +145,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VaddDtypeAtypeBtypeSatOp2 {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -161,7 +161,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VsubDtypeAtypeBtypeSatOp2 {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -177,7 +177,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VabsdiffDtypeAtypeBtypeSatOp2 {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -193,7 +193,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VminDtypeAtypeBtypeSatOp2 {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -209,7 +209,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VmaxDtypeAtypeBtypeSatOp2 {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -225,7 +225,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VaddDtypeAtypeBtypeSat1 {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -241,7 +241,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VsubDtypeAtypeBtypeSat1 {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -257,7 +257,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VabsdiffDtypeAtypeBtypeSat1 {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -273,7 +273,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VminDtypeAtypeBtypeSat1 {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -289,7 +289,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct VmaxDtypeAtypeBtypeSat1 {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -19,34 +19,34 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         U32, // .u32
         S32, // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         U32, // .u32
         S32, // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         U32, // .u32
         S32, // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Mask {
         H10, // .h10
         H0,  // .h0
         H1,  // .h1
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Asel {
         H00, // .h00
         H01, // .h01
@@ -66,7 +66,7 @@ pub mod section_0 {
         H33, // .h33
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Bsel {
         H00, // .h00
         H01, // .h01
@@ -86,7 +86,7 @@ pub mod section_0 {
         H33, // .h33
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vadd2DtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -102,7 +102,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vsub2DtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -118,7 +118,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vavrg2DtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -134,7 +134,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vabsdiff2DtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -150,7 +150,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vmin2DtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -166,7 +166,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vmax2DtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -182,7 +182,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vadd2DtypeAtypeBtypeAdd {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -198,7 +198,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vsub2DtypeAtypeBtypeAdd {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -214,7 +214,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vavrg2DtypeAtypeBtypeAdd {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -230,7 +230,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vabsdiff2DtypeAtypeBtypeAdd {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -246,7 +246,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vmin2DtypeAtypeBtypeAdd {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -262,7 +262,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vmax2DtypeAtypeBtypeAdd {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
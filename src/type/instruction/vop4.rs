@@ -24,27 +24,27 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         U32, // .u32
         S32, // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         U32, // .u32
         S32, // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         U32, // .u32
         S32, // .s32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Mask {
         B3210, // .b3210
         B210,  // .b210
@@ -63,7 +63,7 @@ pub mod section_0 {
         B3,    // .b3
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum N {
         _0, // 0
         _1, // 1
@@ -75,17 +75,17 @@ pub mod section_0 {
         _7, // 7
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Asel {
         BNNNN((), N, N, N, N), // .b.n.n.n.n
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Bsel {
         BNNNN((), N, N, N, N), // .b.n.n.n.n
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vadd4DtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -101,7 +101,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vsub4DtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -117,7 +117,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vavrg4DtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -133,7 +133,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vabsdiff4DtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -149,7 +149,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vmin4DtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -165,7 +165,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vmax4DtypeAtypeBtypeSat {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -181,7 +181,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vadd4DtypeAtypeBtypeAdd {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -197,7 +197,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vsub4DtypeAtypeBtypeAdd {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -213,7 +213,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vavrg4DtypeAtypeBtypeAdd {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -229,7 +229,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vabsdiff4DtypeAtypeBtypeAdd {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -245,7 +245,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vmin4DtypeAtypeBtypeAdd {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -261,7 +261,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct Vmax4DtypeAtypeBtypeAdd {
         pub dtype: Dtype,       // .dtype
         pub atype: Atype,       // .atype
@@ -83,9 +83,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M64n104k16, // .m64n104k16
         M64n112k16, // .m64n112k16
@@ -121,13 +121,13 @@ pub mod section_0 {
         M64n8k16,   // .m64n8k16
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F16, // .f16
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16 {
         pub mma_async: (),               // .mma_async
         pub sync: (),                    // .sync
@@ -147,7 +147,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161 {
         pub mma_async: (),               // .mma_async
         pub sync: (),                    // .sync
@@ -172,9 +172,9 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M64n104k16, // .m64n104k16
         M64n112k16, // .m64n112k16
@@ -210,12 +210,12 @@ pub mod section_1 {
         M64n8k16,   // .m64n8k16
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16 {
         pub mma_async: (),               // .mma_async
         pub sync: (),                    // .sync
@@ -235,7 +235,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161 {
         pub mma_async: (),               // .mma_async
         pub sync: (),                    // .sync
@@ -260,9 +260,9 @@ pub mod section_2 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M64n104k8, // .m64n104k8
         M64n112k8, // .m64n112k8
@@ -298,12 +298,12 @@ pub mod section_2 {
         M64n8k8,   // .m64n8k8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32 {
         pub mma_async: (),               // .mma_async
         pub sync: (),                    // .sync
@@ -321,7 +321,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321 {
         pub mma_async: (),               // .mma_async
         pub sync: (),                    // .sync
@@ -345,9 +345,9 @@ pub mod section_3 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M64n104k32, // .m64n104k32
         M64n112k32, // .m64n112k32
@@ -383,25 +383,25 @@ pub mod section_3 {
         M64n8k32,   // .m64n8k32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F16, // .f16
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         E4m3, // .e4m3
         E5m2, // .e5m2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         E4m3, // .e4m3
         E5m2, // .e5m2
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype {
         pub mma_async: (),               // .mma_async
         pub sync: (),                    // .sync
@@ -419,7 +419,7 @@ pub mod section_3 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1 {
         pub mma_async: (),               // .mma_async
         pub sync: (),                    // .sync
@@ -443,9 +443,9 @@ pub mod section_4 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M64n112k32, // .m64n112k32
         M64n128k32, // .m64n128k32
@@ -465,19 +465,19 @@ pub mod section_4 {
         M64n8k32,   // .m64n8k32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         S8, // .s8
         U8, // .u8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         S8, // .s8
         U8, // .u8
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype {
         pub mma_async: (),           // .mma_async
         pub sync: (),                // .sync
@@ -494,7 +494,7 @@ pub mod section_4 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1 {
         pub mma_async: (),           // .mma_async
         pub sync: (),                // .sync
@@ -517,9 +517,9 @@ pub mod section_5 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M64n112k256, // .m64n112k256
         M64n128k256, // .m64n128k256
@@ -541,12 +541,12 @@ pub mod section_5 {
         M64n8k256,   // .m64n8k256
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Op {
         And, // .and
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc {
         pub mma_async: (),           // .mma_async
         pub sync: (),                // .sync
@@ -564,7 +564,7 @@ pub mod section_5 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1 {
         pub mma_async: (),           // .mma_async
         pub sync: (),                // .sync
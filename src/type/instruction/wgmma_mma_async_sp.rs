@@ -74,9 +74,9 @@ pub mod section_0 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M64n104k32, // .m64n104k32
         M64n112k32, // .m64n112k32
@@ -112,13 +112,13 @@ pub mod section_0 {
         M64n8k32,   // .m64n8k32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F16, // .f16
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16 {
         pub mma_async: (),               // .mma_async
         pub sp: (),                      // .sp
@@ -141,7 +141,7 @@ pub mod section_0 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161 {
         pub mma_async: (),               // .mma_async
         pub sp: (),                      // .sp
@@ -169,9 +169,9 @@ pub mod section_1 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M64n104k32, // .m64n104k32
         M64n112k32, // .m64n112k32
@@ -207,12 +207,12 @@ pub mod section_1 {
         M64n8k32,   // .m64n8k32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16 {
         pub mma_async: (),               // .mma_async
         pub sp: (),                      // .sp
@@ -235,7 +235,7 @@ pub mod section_1 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161 {
         pub mma_async: (),               // .mma_async
         pub sp: (),                      // .sp
@@ -263,9 +263,9 @@ pub mod section_2 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M64n104k16, // .m64n104k16
         M64n112k16, // .m64n112k16
@@ -301,12 +301,12 @@ pub mod section_2 {
         M64n8k16,   // .m64n8k16
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32 {
         pub mma_async: (),               // .mma_async
         pub sp: (),                      // .sp
@@ -327,7 +327,7 @@ pub mod section_2 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321 {
         pub mma_async: (),               // .mma_async
         pub sp: (),                      // .sp
@@ -354,9 +354,9 @@ pub mod section_3 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M64n104k64, // .m64n104k64
         M64n112k64, // .m64n112k64
@@ -392,25 +392,25 @@ pub mod section_3 {
         M64n8k64,   // .m64n8k64
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Dtype {
         F16, // .f16
         F32, // .f32
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         E4m3, // .e4m3
         E5m2, // .e5m2
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         E4m3, // .e4m3
         E5m2, // .e5m2
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype {
         pub mma_async: (),               // .mma_async
         pub sp: (),                      // .sp
@@ -431,7 +431,7 @@ pub mod section_3 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1 {
         pub mma_async: (),               // .mma_async
         pub sp: (),                      // .sp
@@ -458,9 +458,9 @@ pub mod section_4 {
     use crate::parser::Span;
     use crate::r#type::common::*;
 
-    use serde::Serialize;
+    use serde::{Deserialize, Serialize};
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Shape {
         M64n112k64, // .m64n112k64
         M64n128k64, // .m64n128k64
@@ -482,19 +482,19 @@ pub mod section_4 {
         M64n8k64,   // .m64n8k64
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Atype {
         S8, // .s8
         U8, // .u8
     }
 
-    #[derive(Debug, Clone, PartialEq, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
     pub enum Btype {
         S8, // .s8
         U8, // .u8
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype {
         pub mma_async: (),           // .mma_async
         pub sp: (),                  // .sp
@@ -514,7 +514,7 @@ pub mod section_4 {
         pub span: Span,
     }
 
-    #[derive(Debug, Clone, PartialEq, Spanned, Serialize)]
+    #[derive(Debug, Clone, PartialEq, Spanned, Serialize, Deserialize)]
     pub struct WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1 {
         pub mma_async: (),           // .mma_async
         pub sp: (),                  // .sp
@@ -2,10 +2,10 @@ use crate::Spanned;
 use crate::parser::Span;
 use crate::r#type::common::{AttributeDirective, DataType};
 use crate::r#type::{FunctionSymbol, Immediate, VariableSymbol};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Module-level declarations that reserve storage in a specific address space.
-#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize, Deserialize)]
 pub enum ModuleVariableDirective {
     /// Deprecated `.tex` variable declaration.
     ///
@@ -30,7 +30,7 @@ pub enum ModuleVariableDirective {
 }
 
 /// Module-scoped variable declaration shared by `.tex`, `.shared`, `.global`, and `.const`.
-#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize, Deserialize)]
 pub struct VariableDirective {
     /// Example:
     /// .global .attribute(.managed) .s32 g;
@@ -53,7 +53,7 @@ pub struct VariableDirective {
 }
 
 /// Qualifiers left on module variable declarations (e.g. `.v4`).
-#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize, Deserialize)]
 pub enum VariableModifier {
     Vector { value: u32, span: Span },
     Alignment { value: u32, span: Span },
@@ -61,7 +61,7 @@ pub enum VariableModifier {
 }
 
 /// Parameters, used in function declarations and calls, e.g., `.param .b32 p`.
-#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize, Deserialize)]
 pub enum ParameterDirective {
     Register {
         ty: DataType,
@@ -80,7 +80,7 @@ pub enum ParameterDirective {
 }
 
 /// Address space qualifiers for parameters.
-#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize, Deserialize)]
 pub enum ParamStateSpace {
     /// .const
     Const { span: Span },
@@ -93,7 +93,7 @@ pub enum ParamStateSpace {
 }
 
 // Values that can appear in global initialiser lists.
-#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize, Deserialize)]
 pub enum InitializerValue {
     NumericLiteral { value: Immediate, span: Span },
     FunctionSymbol { name: FunctionSymbol, span: Span },
@@ -101,7 +101,7 @@ pub enum InitializerValue {
 }
 
 /// Structured representation of a global variable initialiser.
-#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Spanned, Serialize, Deserialize)]
 pub enum GlobalInitializer {
     Scalar {
         value: InitializerValue,
@@ -36,7 +36,9 @@ fn write_token<W: Write>(writer: &mut W, token: &PtxToken) -> fmt::Result {
         | PtxToken::FloatExponent(name)
         | PtxToken::HexFloatSingle(name)
         | PtxToken::HexFloatDouble(name)
-        | PtxToken::Register(name) => writer.write_str(name),
+        | PtxToken::Register(name)
+        | PtxToken::Error(name)
+        | PtxToken::Comment(name) => writer.write_str(name),
         PtxToken::StringLiteral(name) => {
             writer.write_char('"')?;
             writer.write_str(name)?;
@@ -1,6 +1,6 @@
 use crate::unparser::common::push_register;
 use crate::{
-    lexer::PtxToken,
+    lexer::{PtxToken, tokenize_lossy},
     r#type::{function::*, variable::ParameterDirective},
     unparser::*,
 };
@@ -462,6 +462,20 @@ impl PtxUnparser for FunctionStatement {
                 tokens.push(PtxToken::RBrace);
                 push_newline(tokens, spaced);
             }
+            FunctionStatement::Error { text, .. } => {
+                // The text may hold input that does not lex, so keep whatever
+                // tokens it yields, with error tokens for the rest.
+                let (raw, _) = tokenize_lossy(text);
+                let mut previous_end = None;
+                for (token, span) in raw {
+                    if previous_end.is_some_and(|end| end < span.start) {
+                        push_space(tokens, spaced);
+                    }
+                    previous_end = Some(span.end);
+                    tokens.push(token);
+                }
+                push_newline(tokens, spaced);
+            }
         }
     }
 }
@@ -634,12 +648,9 @@ impl PtxUnparser for AliasFunctionDirective {
     }
 }
 
-impl PtxUnparser for EntryFunctionDirective {
-    fn unparse_tokens(&self, tokens: &mut Vec<PtxToken>) {
-        self.unparse_tokens_mode(tokens, false);
-    }
-
-    fn unparse_tokens_mode(&self, tokens: &mut Vec<PtxToken>, spaced: bool) {
+impl EntryFunctionDirective {
+    /// Emit everything before the body or terminating `;`.
+    pub(crate) fn unparse_header(&self, tokens: &mut Vec<PtxToken>, spaced: bool) {
         for directive in &self.directives {
             directive.unparse_tokens_mode(tokens, spaced);
             if spaced {
@@ -652,6 +663,16 @@ impl PtxUnparser for EntryFunctionDirective {
         tokens.push(PtxToken::LParen);
         unparse_param_list(tokens, &self.params, spaced);
         tokens.push(PtxToken::RParen);
+    }
+}
+
+impl PtxUnparser for EntryFunctionDirective {
+    fn unparse_tokens(&self, tokens: &mut Vec<PtxToken>) {
+        self.unparse_tokens_mode(tokens, false);
+    }
+
+    fn unparse_tokens_mode(&self, tokens: &mut Vec<PtxToken>, spaced: bool) {
+        self.unparse_header(tokens, spaced);
         match &self.body {
             Some(body) => body.unparse_tokens_mode(tokens, spaced),
             None => {
@@ -662,12 +683,9 @@ impl PtxUnparser for EntryFunctionDirective {
     }
 }
 
-impl PtxUnparser for FuncFunctionDirective {
-    fn unparse_tokens(&self, tokens: &mut Vec<PtxToken>) {
-        self.unparse_tokens_mode(tokens, false);
-    }
-
-    fn unparse_tokens_mode(&self, tokens: &mut Vec<PtxToken>, spaced: bool) {
+impl FuncFunctionDirective {
+    /// Emit everything before the body or terminating `;`.
+    pub(crate) fn unparse_header(&self, tokens: &mut Vec<PtxToken>, spaced: bool) {
         for attribute in &self.attributes {
             attribute.unparse_tokens_mode(tokens, spaced);
             if spaced {
@@ -697,6 +715,16 @@ impl PtxUnparser for FuncFunctionDirective {
             push_newline(tokens, spaced);
             decl.unparse_tokens_mode(tokens, spaced);
         }
+    }
+}
+
+impl PtxUnparser for FuncFunctionDirective {
+    fn unparse_tokens(&self, tokens: &mut Vec<PtxToken>) {
+        self.unparse_tokens_mode(tokens, false);
+    }
+
+    fn unparse_tokens_mode(&self, tokens: &mut Vec<PtxToken>, spaced: bool) {
+        self.unparse_header(tokens, spaced);
         match &self.body {
             Some(body) => body.unparse_tokens_mode(tokens, spaced),
             None => {
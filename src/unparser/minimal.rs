@@ -0,0 +1,258 @@
+//! Minimal-edit printing: reuse the original text of unchanged nodes.
+
+use std::collections::HashMap;
+
+use crate::{
+    Span,
+    lexer::PtxToken,
+    r#type::{
+        EntryFunctionDirective, FuncFunctionDirective, FunctionBody, FunctionStatement, Module,
+        ModuleDirective,
+    },
+    unlexer::PtxUnlexer,
+    unparser::PtxUnparser,
+};
+
+/// Print `modified` by copying the text of `source` for every node that is
+/// unchanged from `original`, and unparsing only the nodes that were edited.
+///
+/// `original` must be the module parsed from `source`. A directive or
+/// statement of `modified` is matched to the node of `original` with the same
+/// span; if the two are equal, its source text is copied verbatim together
+/// with the whitespace and comments in front of it. Edited functions and
+/// blocks whose header is unchanged keep their header text and are printed
+/// statement by statement; any other edited node is unparsed with
+/// [`PtxUnparser::to_tokens_spaced`]. Nodes without a matching span, such as
+/// newly constructed ones, are placed on a new line with the indentation of
+/// the preceding sibling.
+///
+/// Printing an unmodified module reproduces `source` exactly.
+///
+/// # Example
+///
+/// ```no_run
+/// use ptx_parser::{parse_ptx, unparse_minimal};
+/// use ptx_parser::r#type::ModuleDirective;
+///
+/// let source = ".version 8.5\n.target sm_90\n\n.entry k() {\n\tret; // done\n}\n";
+/// let original = parse_ptx(source).unwrap();
+/// let mut modified = original.clone();
+/// if let ModuleDirective::EntryFunction { directive, .. } = &mut modified.directives[2] {
+///     let body = directive.body.as_mut().unwrap();
+///     let ret = body.statements[0].clone();
+///     body.statements.insert(0, ret);
+/// }
+/// let text = unparse_minimal(source, &original, &modified);
+/// assert!(text.ends_with("\tret; // done\n}\n"));
+/// ```
+pub fn unparse_minimal(source: &str, original: &Module, modified: &Module) -> String {
+    let mut printer = Printer {
+        source,
+        out: String::with_capacity(source.len()),
+    };
+    printer.print_list(&original.directives, &modified.directives, 0, source.len());
+    printer.out
+}
+
+/// A node printed as one item of a sibling list.
+trait Node: PartialEq + PtxUnparser {
+    fn node_span(&self) -> Span;
+
+    /// Print `self` reusing the parts it shares with `original`. Returns
+    /// `false` if nothing can be reused and the node must be unparsed.
+    fn print_changed(&self, original: &Self, printer: &mut Printer) -> bool;
+}
+
+struct Printer<'a> {
+    source: &'a str,
+    out: String,
+}
+
+impl Printer<'_> {
+    fn copy(&mut self, start: usize, end: usize) {
+        self.out.push_str(&self.source[start..end]);
+    }
+
+    /// Print the siblings `modified`, which replace `original` in the source
+    /// range `open_end..close_start`.
+    fn print_list<T: Node>(
+        &mut self,
+        original: &[T],
+        modified: &[T],
+        open_end: usize,
+        close_start: usize,
+    ) {
+        let positions: HashMap<(usize, usize), usize> = original
+            .iter()
+            .enumerate()
+            .map(|(index, node)| {
+                let span = node.node_span();
+                ((span.start, span.end), index)
+            })
+            .collect();
+        // `gaps[index]` is the text in front of `original[index]`, and the last
+        // gap runs up to `close_start`. The part of a gap before its first line
+        // break belongs to the preceding node, so a same-line comment stays with
+        // the node it follows.
+        let source = self.source;
+        let gaps: Vec<(Span, Span)> = (0..=original.len())
+            .map(|index| {
+                let start = match index {
+                    0 => open_end,
+                    _ => original[index - 1].node_span().end,
+                };
+                let end = original
+                    .get(index)
+                    .map_or(close_start, |node| node.node_span().start);
+                let split = source[start..end].find('\n').map_or(end, |at| start + at);
+                (Span::new(start, split), Span::new(split, end))
+            })
+            .collect();
+        let text = |span: Span| &source[span.start..span.end];
+
+        self.copy(gaps[0].0.start, gaps[0].0.end);
+        let mut indent = last_line(text(gaps[0].1));
+        for node in modified {
+            let span = node.node_span();
+            match positions.get(&(span.start, span.end)) {
+                Some(&index) => {
+                    let leading = gaps[index].1;
+                    self.copy(leading.start, leading.end);
+                    indent = last_line(text(leading));
+                    if node == &original[index] {
+                        self.copy(span.start, span.end);
+                    } else if !node.print_changed(&original[index], self) {
+                        self.synthesize(node);
+                    }
+                    let trailing = gaps[index + 1].0;
+                    self.copy(trailing.start, trailing.end);
+                }
+                None => {
+                    if !self.out.is_empty() && !self.out.ends_with('\n') {
+                        self.out.push('\n');
+                    }
+                    self.out.push_str(indent);
+                    self.synthesize(node);
+                }
+            }
+        }
+        let tail = gaps[original.len()].1;
+        self.copy(tail.start, tail.end);
+    }
+
+    fn synthesize<T: PtxUnparser>(&mut self, node: &T) {
+        let mut tokens = node.to_tokens_spaced();
+        while matches!(tokens.last(), Some(PtxToken::Space | PtxToken::Newline)) {
+            tokens.pop();
+        }
+        let _ = PtxUnlexer::write_tokens(&mut self.out, &tokens);
+    }
+
+    /// Print a braced statement list, keeping the original braces and the
+    /// text before the closing brace.
+    fn print_braced(
+        &mut self,
+        original: &[FunctionStatement],
+        modified: &[FunctionStatement],
+        braces: Span,
+    ) {
+        self.copy(braces.start, braces.start + 1);
+        self.print_list(original, modified, braces.start + 1, braces.end - 1);
+        self.copy(braces.end - 1, braces.end);
+    }
+}
+
+/// Indentation of the last line of `gap`, if the gap ends with a line break
+/// followed by whitespace only.
+fn last_line(gap: &str) -> &str {
+    match gap.rfind('\n') {
+        Some(index) if gap[index + 1..].chars().all(char::is_whitespace) => &gap[index + 1..],
+        _ => "",
+    }
+}
+
+impl Node for ModuleDirective {
+    fn node_span(&self) -> Span {
+        self.span()
+    }
+
+    fn print_changed(&self, original: &Self, printer: &mut Printer) -> bool {
+        let (span, body, original_body) = match (self, original) {
+            (
+                ModuleDirective::EntryFunction {
+                    linkage,
+                    directive,
+                    span,
+                },
+                ModuleDirective::EntryFunction {
+                    linkage: original_linkage,
+                    directive: original_directive,
+                    ..
+                },
+            ) if linkage == original_linkage && entry_header_eq(directive, original_directive) => {
+                (span, &directive.body, &original_directive.body)
+            }
+            (
+                ModuleDirective::FuncFunction {
+                    linkage,
+                    directive,
+                    span,
+                },
+                ModuleDirective::FuncFunction {
+                    linkage: original_linkage,
+                    directive: original_directive,
+                    ..
+                },
+            ) if linkage == original_linkage && func_header_eq(directive, original_directive) => {
+                (span, &directive.body, &original_directive.body)
+            }
+            _ => return false,
+        };
+        let (Some(body), Some(original_body)) = (body, original_body) else {
+            return false;
+        };
+        print_body(printer, *span, body, original_body);
+        true
+    }
+}
+
+fn print_body(printer: &mut Printer, span: Span, body: &FunctionBody, original: &FunctionBody) {
+    printer.copy(span.start, original.span.start);
+    printer.print_braced(&original.statements, &body.statements, original.span);
+    printer.copy(original.span.end, span.end);
+}
+
+fn entry_header_eq(a: &EntryFunctionDirective, b: &EntryFunctionDirective) -> bool {
+    a.name == b.name && a.params == b.params && a.directives == b.directives
+}
+
+fn func_header_eq(a: &FuncFunctionDirective, b: &FuncFunctionDirective) -> bool {
+    a.attributes == b.attributes
+        && a.return_param == b.return_param
+        && a.name == b.name
+        && a.params == b.params
+        && a.directives == b.directives
+        && a.pre_body_declarations == b.pre_body_declarations
+}
+
+impl Node for FunctionStatement {
+    fn node_span(&self) -> Span {
+        self.span()
+    }
+
+    fn print_changed(&self, original: &Self, printer: &mut Printer) -> bool {
+        match (self, original) {
+            (
+                FunctionStatement::Block { statements, .. },
+                FunctionStatement::Block {
+                    statements: original_statements,
+                    span,
+                },
+            ) => {
+                printer.print_braced(original_statements, statements, *span);
+                true
+            }
+            _ => false,
+        }
+    }
+}
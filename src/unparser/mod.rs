@@ -1,7 +1,9 @@
 pub(crate) mod common;
 pub(crate) mod function;
 pub(crate) mod instruction;
+pub(crate) mod minimal;
 pub(crate) mod module;
+pub(crate) mod trivia;
 pub(crate) mod variable;
 
 use crate::lexer::PtxToken;
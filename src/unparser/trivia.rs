@@ -0,0 +1,162 @@
+//! Unparsing with the comments of a [`Comments`] table re-inserted.
+
+use crate::{
+    comments::{Comment, Comments},
+    lexer::{PtxToken, TriviaKind},
+    r#type::{FunctionBody, FunctionStatement, Module, ModuleDirective},
+    unparser::{PtxUnparser, push_newline, push_space},
+};
+
+/// Unparse `module`, re-emitting the comments attached to its nodes.
+///
+/// Comments come back in source order next to the directive or statement they
+/// were attached to by [`Comments::collect`]. Line comments are always followed
+/// by a newline so the output stays lexable, even when `spaced` is false.
+///
+/// # Example
+///
+/// ```no_run
+/// use ptx_parser::{PtxUnlexer, parse_ptx_with_comments, unparse_with_comments};
+///
+/// let source = "// begin inline asm\n.version 8.5\n.target sm_90 // target\n";
+/// let (module, comments) = parse_ptx_with_comments(source).unwrap();
+/// let tokens = unparse_with_comments(&module, &comments, true);
+/// let text = PtxUnlexer::to_string(&tokens).unwrap();
+/// assert!(text.starts_with("// begin inline asm\n"));
+/// ```
+pub fn unparse_with_comments(module: &Module, comments: &Comments, spaced: bool) -> Vec<PtxToken> {
+    let mut tokens = Vec::new();
+    let mut broke_line = false;
+    for (index, directive) in module.directives.iter().enumerate() {
+        if index > 0 && !broke_line {
+            push_newline(&mut tokens, spaced);
+        }
+        let span = directive.span();
+        push_leading(&mut tokens, comments.leading(span.start), spaced);
+        unparse_directive(&mut tokens, directive, comments, spaced);
+        broke_line = push_trailing(&mut tokens, comments.trailing(span.end), spaced);
+    }
+    if !broke_line {
+        push_newline(&mut tokens, spaced);
+    }
+    push_leading(&mut tokens, comments.end(), spaced);
+    tokens
+}
+
+fn unparse_directive(
+    tokens: &mut Vec<PtxToken>,
+    directive: &ModuleDirective,
+    comments: &Comments,
+    spaced: bool,
+) {
+    match directive {
+        ModuleDirective::EntryFunction {
+            linkage,
+            directive: function,
+            ..
+        } => {
+            if let Some(link) = linkage {
+                link.unparse_tokens_mode(tokens, spaced);
+                push_space(tokens, spaced);
+            }
+            function.unparse_header(tokens, spaced);
+            unparse_body(tokens, function.body.as_ref(), comments, spaced);
+        }
+        ModuleDirective::FuncFunction {
+            linkage,
+            directive: function,
+            ..
+        } => {
+            if let Some(link) = linkage {
+                link.unparse_tokens_mode(tokens, spaced);
+                push_space(tokens, spaced);
+            }
+            function.unparse_header(tokens, spaced);
+            unparse_body(tokens, function.body.as_ref(), comments, spaced);
+        }
+        _ => directive.unparse_tokens_mode(tokens, spaced),
+    }
+}
+
+fn unparse_body(
+    tokens: &mut Vec<PtxToken>,
+    body: Option<&FunctionBody>,
+    comments: &Comments,
+    spaced: bool,
+) {
+    match body {
+        Some(body) => {
+            tokens.push(PtxToken::LBrace);
+            push_newline(tokens, spaced);
+            unparse_statements(tokens, &body.statements, comments, spaced);
+            push_leading(tokens, comments.leading(body.span.end - 1), spaced);
+            tokens.push(PtxToken::RBrace);
+            push_newline(tokens, spaced);
+        }
+        None => {
+            tokens.push(PtxToken::Semicolon);
+            push_newline(tokens, spaced);
+        }
+    }
+}
+
+fn unparse_statements(
+    tokens: &mut Vec<PtxToken>,
+    statements: &[FunctionStatement],
+    comments: &Comments,
+    spaced: bool,
+) {
+    for statement in statements {
+        let span = statement.span();
+        push_leading(tokens, comments.leading(span.start), spaced);
+        match statement {
+            FunctionStatement::Block { statements, span } => {
+                tokens.push(PtxToken::LBrace);
+                unparse_statements(tokens, statements, comments, spaced);
+                push_leading(tokens, comments.leading(span.end - 1), spaced);
+                tokens.push(PtxToken::RBrace);
+                push_newline(tokens, spaced);
+            }
+            _ => statement.unparse_tokens_mode(tokens, spaced),
+        }
+        push_trailing(tokens, comments.trailing(span.end), spaced);
+    }
+}
+
+/// Emit comments on their own lines before a node.
+fn push_leading(tokens: &mut Vec<PtxToken>, comments: &[Comment], spaced: bool) {
+    for comment in comments {
+        tokens.push(PtxToken::Comment(comment.text.clone()));
+        match comment.kind {
+            TriviaKind::LineComment => tokens.push(PtxToken::Newline),
+            _ => push_newline(tokens, spaced),
+        }
+    }
+}
+
+/// Emit comments after a node, before the newline that ends its line.
+///
+/// Returns whether a line break was added that the node did not end with.
+fn push_trailing(tokens: &mut Vec<PtxToken>, comments: &[Comment], spaced: bool) -> bool {
+    if comments.is_empty() {
+        return false;
+    }
+    let had_newline = matches!(tokens.last(), Some(PtxToken::Newline));
+    if had_newline {
+        tokens.pop();
+    }
+    let mut after_line_comment = false;
+    for comment in comments {
+        if after_line_comment {
+            tokens.push(PtxToken::Newline);
+        } else {
+            push_space(tokens, spaced);
+        }
+        tokens.push(PtxToken::Comment(comment.text.clone()));
+        after_line_comment = comment.kind == TriviaKind::LineComment;
+    }
+    if had_newline || after_line_comment {
+        tokens.push(PtxToken::Newline);
+    }
+    !had_newline && after_line_comment
+}
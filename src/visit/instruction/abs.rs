@@ -0,0 +1,98 @@
+//! Original PTX specification:
+//!
+//! abs.type  d, a;
+//! .type = { .s16, .s32, .s64 };
+//!
+//! abs{.ftz}.f32  d, a;
+//! abs.f64        d, a;
+//!
+//! abs{.ftz}.f16    d, a;
+//! abs{.ftz}.f16x2  d, a;
+//! abs.bf16         d, a;
+//! abs.bf16x2       d, a;
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::abs::section_0::*;
+
+    impl Walk for AbsType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for AbsFtzF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for AbsF64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for AbsFtzF16 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for AbsFtzF16x2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for AbsBf16 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for AbsBf16x2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+}
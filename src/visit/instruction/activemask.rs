@@ -0,0 +1,21 @@
+//! Original PTX specification:
+//!
+//! activemask.b32 d;
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::activemask::section_0::*;
+
+    impl Walk for ActivemaskB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+        }
+    }
+}
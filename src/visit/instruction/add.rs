@@ -0,0 +1,176 @@
+//! Original PTX specification:
+//!
+//! add.type       d, a, b;
+//! add{.sat}.s32  d, a, b;     // .sat applies only to .s32
+//! .type = { .u16, .u32, .u64,
+//! .s16, .s32, .s64,
+//! .u16x2, .s16x2 };
+//! -------------------------------------------
+//! add{.rnd}{.ftz}{.sat}.f32  d, a, b;
+//! add{.rnd}{.ftz}.f32x2      d, a, b;
+//! add{.rnd}.f64              d, a, b;
+//! .rnd = { .rn, .rz, .rm, .rp };
+//! --------------------------------------------
+//! add{.rnd}{.ftz}{.sat}.f16   d, a, b;
+//! add{.rnd}{.ftz}{.sat}.f16x2 d, a, b;
+//! add{.rnd}.bf16   d, a, b;
+//! add{.rnd}.bf16x2 d, a, b;
+//! .rnd = { .rn };
+//! --------------------------------------------
+//! add{.rnd}{.sat}.f32.atype  d, a, c;
+//! .atype = { .f16, .bf16};
+//! .rnd   = { .rn, .rz, .rm, .rp };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::add::section_0::*;
+
+    impl Walk for AddType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for AddSatS32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::add::section_1::*;
+
+    impl Walk for AddRndFtzSatF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for AddRndFtzF32x2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for AddRndF64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::add::section_2::*;
+
+    impl Walk for AddRndFtzSatF16 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for AddRndFtzSatF16x2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for AddRndBf16 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for AddRndBf16x2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::add::section_3::*;
+
+    impl Walk for AddRndSatF32Atype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.c);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.c);
+        }
+    }
+}
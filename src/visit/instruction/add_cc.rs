@@ -0,0 +1,26 @@
+//! Original PTX specification:
+//!
+//! add.cc.type  d, a, b;
+//! .type = { .u32, .s32, .u64, .s64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::add_cc::section_0::*;
+
+    impl Walk for AddCcType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+}
@@ -0,0 +1,30 @@
+//! Original PTX specification:
+//!
+//! alloca.type  ptr, size{, immAlign};
+//! .type = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::alloca::section_0::*;
+
+    impl Walk for AllocaType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.ptr);
+            visitor.visit_general_operand(&self.size);
+            if let Some(operand) = &self.immalign {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.ptr);
+            visitor.visit_general_operand_mut(&mut self.size);
+            if let Some(operand) = &mut self.immalign {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
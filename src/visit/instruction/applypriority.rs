@@ -0,0 +1,24 @@
+//! Original PTX specification:
+//!
+//! applypriority{.global}.level::eviction_priority  [a], size;
+//! .level::eviction_priority = { .L2::evict_normal };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::applypriority::section_0::*;
+
+    impl Walk for ApplypriorityGlobalLevelEvictionPriority {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.size);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.size);
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! Original PTX specification:
+//!
+//! barrier{.cta}.sync{.aligned}      a{, b};
+//! barrier{.cta}.arrive{.aligned}    a, b;
+//! barrier{.cta}.red.popc{.aligned}.u32  d, a{, b}, {!}c;
+//! barrier{.cta}.red.op{.aligned}.pred   p, a{, b}, {!}c;
+//! bar{.cta}.sync      a{, b};
+//! bar{.cta}.arrive    a, b;
+//! bar{.cta}.red.popc.u32  d, a{, b}, {!}c;
+//! bar{.cta}.red.op.pred   p, a{, b}, {!}c;
+//! .op = { .and, .or };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bar::section_0::*;
+
+    impl Walk for BarrierCtaSyncAligned {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.a);
+            if let Some(operand) = &self.b {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.a);
+            if let Some(operand) = &mut self.b {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for BarrierCtaArriveAligned {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for BarrierCtaRedPopcAlignedU32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            if let Some(operand) = &self.b {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.c);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            if let Some(operand) = &mut self.b {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.c);
+        }
+    }
+
+    impl Walk for BarrierCtaRedOpAlignedPred {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.p);
+            visitor.visit_general_operand(&self.a);
+            if let Some(operand) = &self.b {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.c);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.a);
+            if let Some(operand) = &mut self.b {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.c);
+        }
+    }
+
+    impl Walk for BarCtaSync {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.a);
+            if let Some(operand) = &self.b {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.a);
+            if let Some(operand) = &mut self.b {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for BarCtaArrive {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for BarCtaRedPopcU32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            if let Some(operand) = &self.b {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.c);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            if let Some(operand) = &mut self.b {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.c);
+        }
+    }
+
+    impl Walk for BarCtaRedOpPred {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.p);
+            visitor.visit_general_operand(&self.a);
+            if let Some(operand) = &self.b {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.c);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.a);
+            if let Some(operand) = &mut self.b {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.c);
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Original PTX specification:
+//!
+//! bar.warp.sync      membermask;
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bar_warp_sync::section_0::*;
+
+    impl Walk for BarWarpSync {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.membermask);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.membermask);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Original PTX specification:
+//!
+//! barrier.cluster.arrive{.sem}{.aligned};
+//! barrier.cluster.wait{.acquire}{.aligned};
+//! .sem = {.release, .relaxed};
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::barrier_cluster::section_0::*;
+
+    impl Walk for BarrierClusterArriveSemAligned {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+
+    impl Walk for BarrierClusterWaitAcquireAligned {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+}
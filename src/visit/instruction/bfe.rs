@@ -0,0 +1,28 @@
+//! Original PTX specification:
+//!
+//! bfe.type  d, a, b, c;
+//! .type = { .u32, .u64, .s32, .s64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bfe::section_0::*;
+
+    impl Walk for BfeType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+        }
+    }
+}
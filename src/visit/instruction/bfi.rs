@@ -0,0 +1,30 @@
+//! Original PTX specification:
+//!
+//! bfi.type  f, a, b, c, d;
+//! .type = { .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bfi::section_0::*;
+
+    impl Walk for BfiType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.f);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.f);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.d);
+        }
+    }
+}
@@ -0,0 +1,31 @@
+//! Original PTX specification:
+//!
+//! bra{.uni}  tgt;           // tgt is a label
+//! bra{.uni}  tgt;           // unconditional branch
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::bra::section_0::*;
+
+    impl Walk for BraUni {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.tgt);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.tgt);
+        }
+    }
+
+    impl Walk for BraUni1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.tgt);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.tgt);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Original PTX specification:
+//!
+//! brx.idx{.uni} index, tlist;
+//! brx.idx{.uni} index, tlist;
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::brx_idx::section_0::*;
+
+    impl Walk for BrxIdxUni {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.index);
+            visitor.visit_general_operand(&self.tlist);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.index);
+            visitor.visit_general_operand_mut(&mut self.tlist);
+        }
+    }
+
+    impl Walk for BrxIdxUni1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.index);
+            visitor.visit_general_operand(&self.tlist);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.index);
+            visitor.visit_general_operand_mut(&mut self.tlist);
+        }
+    }
+}
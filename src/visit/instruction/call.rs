@@ -0,0 +1,158 @@
+//! Original PTX specification:
+//!
+//! // direct call to named function, func is a symbol
+//! call{.uni} (ret-param), func, (param-list);
+//! call{.uni} func, (param-list);
+//! call{.uni} func;
+//! // indirect call via pointer, with full list of call targets
+//! call{.uni} (ret-param), fptr, (param-list), flist;
+//! call{.uni} fptr, (param-list), flist;
+//! call{.uni} fptr, flist;
+//! // indirect call via pointer, with no knowledge of call targets
+//! call{.uni} (ret-param), fptr, (param-list), fproto;
+//! call{.uni} fptr, (param-list), fproto;
+//! call{.uni} fptr, fproto;
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::call::section_0::*;
+
+    impl Walk for CallUni {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.ret_param);
+            visitor.visit_general_operand(&self.func);
+            for operand in &self.param_list {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.ret_param);
+            visitor.visit_general_operand_mut(&mut self.func);
+            for operand in &mut self.param_list {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for CallUni1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.func);
+            for operand in &self.param_list {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.func);
+            for operand in &mut self.param_list {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for CallUni2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.func);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.func);
+        }
+    }
+
+    impl Walk for CallUni3 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.ret_param);
+            visitor.visit_general_operand(&self.fptr);
+            for operand in &self.param_list {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.flist);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.ret_param);
+            visitor.visit_general_operand_mut(&mut self.fptr);
+            for operand in &mut self.param_list {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.flist);
+        }
+    }
+
+    impl Walk for CallUni4 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.fptr);
+            for operand in &self.param_list {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.flist);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.fptr);
+            for operand in &mut self.param_list {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.flist);
+        }
+    }
+
+    impl Walk for CallUni5 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.fptr);
+            visitor.visit_general_operand(&self.flist);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.fptr);
+            visitor.visit_general_operand_mut(&mut self.flist);
+        }
+    }
+
+    impl Walk for CallUni6 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.ret_param);
+            visitor.visit_general_operand(&self.fptr);
+            for operand in &self.param_list {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.fproto);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.ret_param);
+            visitor.visit_general_operand_mut(&mut self.fptr);
+            for operand in &mut self.param_list {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.fproto);
+        }
+    }
+
+    impl Walk for CallUni7 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.fptr);
+            for operand in &self.param_list {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.fproto);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.fptr);
+            for operand in &mut self.param_list {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.fproto);
+        }
+    }
+
+    impl Walk for CallUni8 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.fptr);
+            visitor.visit_general_operand(&self.fproto);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.fptr);
+            visitor.visit_general_operand_mut(&mut self.fproto);
+        }
+    }
+}
@@ -0,0 +1,48 @@
+//! Original PTX specification:
+//!
+//! clusterlaunchcontrol.query_cancel.is_canceled.pred.b128 pred, try_cancel_response;
+//! clusterlaunchcontrol.query_cancel.get_first_ctaid.v4.b32.b128 {xdim, ydim, zdim, _},  try_cancel_response;
+//! clusterlaunchcontrol.query_cancel{.get_first_ctaid::dimension}.b32.b128 reg, try_cancel_response;
+//! .get_first_ctaid::dimension = { .get_first_ctaid::x, .get_first_ctaid::y, .get_first_ctaid::z };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::clusterlaunchcontrol_query_cancel::section_0::*;
+
+    impl Walk for ClusterlaunchcontrolQueryCancelIsCanceledPredB128 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.pred2);
+            visitor.visit_general_operand(&self.try_cancel_response);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.pred2);
+            visitor.visit_general_operand_mut(&mut self.try_cancel_response);
+        }
+    }
+
+    impl Walk for ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_vector_operand(&self.xdim);
+            visitor.visit_general_operand(&self.try_cancel_response);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_vector_operand_mut(&mut self.xdim);
+            visitor.visit_general_operand_mut(&mut self.try_cancel_response);
+        }
+    }
+
+    impl Walk for ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.reg);
+            visitor.visit_general_operand(&self.try_cancel_response);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.reg);
+            visitor.visit_general_operand_mut(&mut self.try_cancel_response);
+        }
+    }
+}
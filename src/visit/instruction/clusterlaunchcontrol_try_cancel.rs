@@ -0,0 +1,25 @@
+//! Original PTX specification:
+//!
+//! clusterlaunchcontrol.try_cancel.async{.space}.completion_mechanism{.multicast::cluster::all}.b128 [addr], [mbar];
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .space = { .shared::cta };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::clusterlaunchcontrol_try_cancel::section_0::*;
+
+    impl Walk for ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_address_operand(&self.mbar);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_address_operand_mut(&mut self.mbar);
+        }
+    }
+}
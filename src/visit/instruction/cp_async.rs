@@ -0,0 +1,111 @@
+//! Original PTX specification:
+//!
+//! cp.async.ca.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], cp-size{, src-size}{, cache-policy};
+//! cp.async.cg.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], 16{, src-size}{, cache-policy};
+//! cp.async.ca.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], cp-size{, ignore-src}{, cache-policy} ;
+//! cp.async.cg.state.global{.level::cache_hint}{.level::prefetch_size} [dst], [src], 16{, ignore-src}{, cache-policy} ;
+//! .level::cache_hint =     { .L2::cache_hint };
+//! .level::prefetch_size =  { .L2::64B, .L2::128B, .L2::256B };
+//! cp-size = { 4, 8, 16 };
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async::section_0::*;
+
+    impl Walk for CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dst);
+            visitor.visit_address_operand(&self.src);
+            if let Some(operand) = &self.src_size {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dst);
+            visitor.visit_address_operand_mut(&mut self.src);
+            if let Some(operand) = &mut self.src_size {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dst);
+            visitor.visit_address_operand(&self.src);
+            if let Some(operand) = &self.src_size {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dst);
+            visitor.visit_address_operand_mut(&mut self.src);
+            if let Some(operand) = &mut self.src_size {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dst);
+            visitor.visit_address_operand(&self.src);
+            if let Some(operand) = &self.ignore_src {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dst);
+            visitor.visit_address_operand_mut(&mut self.src);
+            if let Some(operand) = &mut self.ignore_src {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dst);
+            visitor.visit_address_operand(&self.src);
+            if let Some(operand) = &self.ignore_src {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dst);
+            visitor.visit_address_operand_mut(&mut self.src);
+            if let Some(operand) = &mut self.ignore_src {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Original PTX specification:
+//!
+//! // global -> shared::cta
+//! cp.async.bulk.dst.src.completion_mechanism{.level::cache_hint} [dstMem], [srcMem], size, [mbar] {, cache-policy};
+//! .dst =                  { .shared::cta };
+//! .src =                  { .global };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .level::cache_hint =    { .L2::cache_hint };
+//! ----------------------------------------------------------------
+//! // global -> shared::cluster;
+//! cp.async.bulk.dst.src.completion_mechanism{.multicast}{.level::cache_hint} [dstMem], [srcMem], size, [mbar] {, ctaMask} {, cache-policy};
+//! .dst =                  { .shared::cluster };
+//! .src =                  { .global };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .level::cache_hint =    { .L2::cache_hint };
+//! .multicast =            { .multicast::cluster  };
+//! ----------------------------------------------------------------
+//! // shared::cta -> shared::cluster
+//! cp.async.bulk.dst.src.completion_mechanism [dstMem], [srcMem], size, [mbar];
+//! .dst =                  { .shared::cluster };
+//! .src =                  { .shared::cta };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ----------------------------------------------------------------
+//! // shared::cta -> global
+//! cp.async.bulk.dst.src.completion_mechanism{.level::cache_hint}{.cp_mask} [dstMem], [srcMem], size {, cache-policy} {, byteMask};
+//! .dst =                  { .global };
+//! .src =                  { .shared::cta };
+//! .completion_mechanism = { .bulk_group };
+//! .level::cache_hint =    { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk::section_0::*;
+
+    impl Walk for CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dstmem);
+            visitor.visit_address_operand(&self.srcmem);
+            visitor.visit_general_operand(&self.size);
+            visitor.visit_address_operand(&self.mbar);
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dstmem);
+            visitor.visit_address_operand_mut(&mut self.srcmem);
+            visitor.visit_general_operand_mut(&mut self.size);
+            visitor.visit_address_operand_mut(&mut self.mbar);
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk::section_1::*;
+
+    impl Walk for CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dstmem);
+            visitor.visit_address_operand(&self.srcmem);
+            visitor.visit_general_operand(&self.size);
+            visitor.visit_address_operand(&self.mbar);
+            if let Some(operand) = &self.ctamask {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dstmem);
+            visitor.visit_address_operand_mut(&mut self.srcmem);
+            visitor.visit_general_operand_mut(&mut self.size);
+            visitor.visit_address_operand_mut(&mut self.mbar);
+            if let Some(operand) = &mut self.ctamask {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk::section_2::*;
+
+    impl Walk for CpAsyncBulkDstSrcCompletionMechanism {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dstmem);
+            visitor.visit_address_operand(&self.srcmem);
+            visitor.visit_general_operand(&self.size);
+            visitor.visit_address_operand(&self.mbar);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dstmem);
+            visitor.visit_address_operand_mut(&mut self.srcmem);
+            visitor.visit_general_operand_mut(&mut self.size);
+            visitor.visit_address_operand_mut(&mut self.mbar);
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk::section_3::*;
+
+    impl Walk for CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dstmem);
+            visitor.visit_address_operand(&self.srcmem);
+            visitor.visit_general_operand(&self.size);
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.bytemask {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dstmem);
+            visitor.visit_address_operand_mut(&mut self.srcmem);
+            visitor.visit_general_operand_mut(&mut self.size);
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.bytemask {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
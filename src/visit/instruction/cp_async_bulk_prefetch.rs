@@ -0,0 +1,31 @@
+//! Original PTX specification:
+//!
+//! cp.async.bulk.prefetch.L2.src{.level::cache_hint}   [srcMem], size {, cache-policy};
+//! .src =                { .global };
+//! .level::cache_hint =  { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_prefetch::section_0::*;
+
+    impl Walk for CpAsyncBulkPrefetchL2SrcLevelCacheHint {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.srcmem);
+            visitor.visit_general_operand(&self.size);
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.srcmem);
+            visitor.visit_general_operand_mut(&mut self.size);
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
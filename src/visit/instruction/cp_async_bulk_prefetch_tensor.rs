@@ -0,0 +1,42 @@
+//! Original PTX specification:
+//!
+//! // global -> shared::cluster:
+//! cp.async.bulk.prefetch.tensor.dim.L2.src{.load_mode}{.level::cache_hint} [tensorMap, tensorCoords] {, im2colInfo } {, cache-policy};
+//! .src =                { .global };
+//! .dim =                { .1d, .2d, .3d, .4d, .5d };
+//! .load_mode =          { .tile, .tile::gather4, .im2col, .im2col::w, .im2col::w::128 };
+//! .level::cache_hint =  { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_prefetch_tensor::section_0::*;
+
+    impl Walk for CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            for operand in &self.tensormap.operands {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.im2colinfo {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            for operand in &mut self.tensormap.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.im2colinfo {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
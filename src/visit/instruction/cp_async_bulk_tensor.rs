@@ -0,0 +1,135 @@
+//! Original PTX specification:
+//!
+//! // global -> shared::cta
+//! cp.async.bulk.tensor.dim.dst.src{.load_mode}.completion_mechanism{.cta_group}{.level::cache_hint} [dstMem], [tensorMap, tensorCoords], [mbar]{, im2colInfo} {, cache-policy};
+//! .dst =                  { .shared::cta };
+//! .src =                  { .global };
+//! .dim =                  { .1d, .2d, .3d, .4d, .5d };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .cta_group =            { .cta_group::1, .cta_group::2 };
+//! .load_mode =            { .tile, .tile::gather4, .im2col, .im2col::w, .im2col::w::128 };
+//! .level::cache_hint =    { .L2::cache_hint };
+//! ----------------------------------------------------------------
+//! // global -> shared::cluster
+//! cp.async.bulk.tensor.dim.dst.src{.load_mode}.completion_mechanism{.multicast}{.cta_group}{.level::cache_hint} [dstMem], [tensorMap, tensorCoords], [mbar]{, im2colInfo} {, ctaMask} {, cache-policy};
+//! .dst =                  { .shared::cluster };
+//! .src =                  { .global };
+//! .dim =                  { .1d, .2d, .3d, .4d, .5d };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .cta_group =            { .cta_group::1, .cta_group::2 };
+//! .load_mode =            { .tile, .tile::gather4, .im2col, .im2col::w, .im2col::w::128 };
+//! .level::cache_hint =    { .L2::cache_hint };
+//! .multicast =            { .multicast::cluster  };
+//! ----------------------------------------------------------------
+//! // shared::cta -> global;
+//! cp.async.bulk.tensor.dim.dst.src{.load_mode}.completion_mechanism{.level::cache_hint} [tensorMap, tensorCoords], [srcMem] {, cache-policy};
+//! .dst =                  { .global };
+//! .src =                  { .shared::cta };
+//! .dim =                  { .1d, .2d, .3d, .4d, .5d };
+//! .completion_mechanism = { .bulk_group };
+//! .load_mode =            { .tile, .tile::scatter4, .im2col_no_offs };
+//! .level::cache_hint =    { .L2::cache_hint };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_tensor::section_0::*;
+
+    impl Walk for CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dstmem);
+            for operand in &self.tensormap.operands {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_address_operand(&self.mbar);
+            if let Some(operand) = &self.im2colinfo {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dstmem);
+            for operand in &mut self.tensormap.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_address_operand_mut(&mut self.mbar);
+            if let Some(operand) = &mut self.im2colinfo {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_tensor::section_1::*;
+
+    impl Walk for CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dstmem);
+            for operand in &self.tensormap.operands {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_address_operand(&self.mbar);
+            if let Some(operand) = &self.im2colinfo {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.ctamask {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dstmem);
+            for operand in &mut self.tensormap.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_address_operand_mut(&mut self.mbar);
+            if let Some(operand) = &mut self.im2colinfo {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.ctamask {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_tensor::section_2::*;
+
+    impl Walk for CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            for operand in &self.tensormap.operands {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_address_operand(&self.srcmem);
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            for operand in &mut self.tensormap.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_address_operand_mut(&mut self.srcmem);
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
@@ -0,0 +1,21 @@
+//! Original PTX specification:
+//!
+//! cp.async.bulk.wait_group{.read} N;
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_bulk_wait_group::section_0::*;
+
+    impl Walk for CpAsyncBulkWaitGroupRead {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.n);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.n);
+        }
+    }
+}
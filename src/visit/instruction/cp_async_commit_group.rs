@@ -0,0 +1,17 @@
+//! Original PTX specification:
+//!
+//! cp.async.commit_group ;
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_commit_group::section_0::*;
+
+    impl Walk for CpAsyncCommitGroup {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+}
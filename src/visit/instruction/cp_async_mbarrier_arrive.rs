@@ -0,0 +1,22 @@
+//! Original PTX specification:
+//!
+//! cp.async.mbarrier.arrive{.noinc}{.state}.b64 [addr];
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_async_mbarrier_arrive::section_0::*;
+
+    impl Walk for CpAsyncMbarrierArriveNoincStateB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.addr);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.addr);
+        }
+    }
+}
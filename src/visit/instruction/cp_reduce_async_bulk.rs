@@ -0,0 +1,95 @@
+//! Original PTX specification:
+//!
+//! cp.reduce.async.bulk.dst.src.completion_mechanism.redOp.type [dstMem], [srcMem], size, [mbar];
+//! .dst =                  { .shared::cluster };
+//! .src =                  { .shared::cta };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! .redOp=                 { .and, .or, .xor, .add, .inc, .dec, .min, .max };
+//! .type =                 { .b32, .u32, .s32, .b64, .u64 };
+//! ----------------------------------------------------------------
+//! cp.reduce.async.bulk.dst.src.completion_mechanism{.level::cache_hint}.redOp.type [dstMem], [srcMem], size{, cache-policy};
+//! .dst =                  { .global      };
+//! .src =                  { .shared::cta };
+//! ----------------------------------------------------------------
+//! .completion_mechanism = { .bulk_group };
+//! .level::cache_hint    = { .L2::cache_hint };
+//! .redOp=                 { .and, .or, .xor, .add, .inc, .dec, .min, .max };
+//! .type =                 { .f16, .bf16, .b32, .u32, .s32, .b64, .u64, .s64, .f32, .f64 };
+//! ----------------------------------------------------------------
+//! cp.reduce.async.bulk.dst.src.completion_mechanism{.level::cache_hint}.add.noftz.type [dstMem], [srcMem], size{, cache-policy};
+//! .dst  =                 { .global };
+//! .src  =                 { .shared::cta };
+//! .completion_mechanism = { .bulk_group };
+//! .type =                 { .f16, .bf16 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_reduce_async_bulk::section_0::*;
+
+    impl Walk for CpReduceAsyncBulkDstSrcCompletionMechanismRedopType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dstmem);
+            visitor.visit_address_operand(&self.srcmem);
+            visitor.visit_general_operand(&self.size);
+            visitor.visit_address_operand(&self.mbar);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dstmem);
+            visitor.visit_address_operand_mut(&mut self.srcmem);
+            visitor.visit_general_operand_mut(&mut self.size);
+            visitor.visit_address_operand_mut(&mut self.mbar);
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::cp_reduce_async_bulk::section_1::*;
+
+    impl Walk for CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dstmem);
+            visitor.visit_address_operand(&self.srcmem);
+            visitor.visit_general_operand(&self.size);
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dstmem);
+            visitor.visit_address_operand_mut(&mut self.srcmem);
+            visitor.visit_general_operand_mut(&mut self.size);
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::cp_reduce_async_bulk::section_2::*;
+
+    impl Walk for CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dstmem);
+            visitor.visit_address_operand(&self.srcmem);
+            visitor.visit_general_operand(&self.size);
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dstmem);
+            visitor.visit_address_operand_mut(&mut self.srcmem);
+            visitor.visit_general_operand_mut(&mut self.size);
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Original PTX specification:
+//!
+//! // shared::cta -> global
+//! cp.reduce.async.bulk.tensor.dim.dst.src.redOp{.load_mode}.completion_mechanism{.level::cache_hint} [tensorMap, tensorCoords], [srcMem] {,cache-policy};
+//! .dst =                  { .global };
+//! .src =                  { .shared::cta };
+//! .dim =                  { .1d, .2d, .3d, .4d, .5d };
+//! .completion_mechanism = { .bulk_group };
+//! .load_mode =            { .tile, .im2col_no_offs };
+//! .redOp =                { .add, .min, .max, .inc, .dec, .and, .or, .xor};
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cp_reduce_async_bulk_tensor::section_0::*;
+
+    impl Walk for CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            for operand in &self.tensormap.operands {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_address_operand(&self.srcmem);
+            if let Some(operand) = &self.cache_policy {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            for operand in &mut self.tensormap.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_address_operand_mut(&mut self.srcmem);
+            if let Some(operand) = &mut self.cache_policy {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
@@ -0,0 +1,63 @@
+//! Original PTX specification:
+//!
+//! // Range-based policy
+//! createpolicy.range{.global}.level::primary_priority{.level::secondary_priority}.b64
+//! cache-policy, [a], primary-size, total-size;
+//! // Fraction-based policy
+//! createpolicy.fractional.level::primary_priority{.level::secondary_priority}.b64
+//! cache-policy{, fraction};
+//! // Converting the access property from CUDA APIs
+//! createpolicy.cvt.L2.b64            cache-policy, access-property;
+//! .level::primary_priority =   { .L2::evict_last, .L2::evict_normal,
+//! .L2::evict_first, .L2::evict_unchanged };
+//! .level::secondary_priority = { .L2::evict_first, .L2::evict_unchanged };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::createpolicy::section_0::*;
+
+    impl Walk for CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.cache_policy);
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.primary_size);
+            visitor.visit_general_operand(&self.total_size);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.cache_policy);
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.primary_size);
+            visitor.visit_general_operand_mut(&mut self.total_size);
+        }
+    }
+
+    impl Walk for CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.cache_policy);
+            if let Some(operand) = &self.fraction {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.cache_policy);
+            if let Some(operand) = &mut self.fraction {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for CreatepolicyCvtL2B64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.cache_policy);
+            visitor.visit_general_operand(&self.access_property);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.cache_policy);
+            visitor.visit_general_operand_mut(&mut self.access_property);
+        }
+    }
+}
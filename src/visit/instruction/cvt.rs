@@ -0,0 +1,326 @@
+//! Original PTX specification:
+//!
+//! cvt{.irnd}{.ftz}{.sat}.dtype.atype         d, a;  // integer rounding
+//! cvt{.frnd}{.ftz}{.sat}.dtype.atype         d, a;  // fp rounding
+//! cvt.frnd2{.relu}{.satfinite}.f16.f32       d, a;
+//! cvt.frnd2{.relu}{.satfinite}.f16x2.f32     d, a, b;
+//! cvt.rs{.relu}{.satfinite}.f16x2.f32        d, a, b, rbits;
+//! cvt.frnd2{.relu}{.satfinite}.bf16.f32      d, a;
+//! cvt.frnd2{.relu}{.satfinite}.bf16x2.f32    d, a, b;
+//! cvt.rs{.relu}{.satfinite}.bf16x2.f32       d, a, b, rbits;
+//! cvt.rna{.satfinite}.tf32.f32               d, a;
+//! cvt.frnd2{.satfinite}{.relu}.tf32.f32      d, a;
+//! cvt.rn.satfinite{.relu}.f8x2type.f32       d, a, b;
+//! cvt.rn.satfinite{.relu}.f8x2type.f16x2     d, a;
+//! cvt.rn{.relu}.f16x2.f8x2type              d, a;
+//! cvt.rs{.relu}.satfinite.f8x4type.f32       d, {a, b, e, f}, rbits;
+//! cvt.rn.satfinite{.relu}.f4x2type.f32       d, a, b;
+//! cvt.rn{.relu}.f16x2.f4x2type               d, a;
+//! cvt.rs{.relu}.satfinite.f4x4type.f32       d, {a, b, e, f}, rbits;
+//! cvt.rn.satfinite{.relu}.f6x2type.f32       d, a, b;
+//! cvt.rn{.relu}.f16x2.f6x2type               d, a;
+//! cvt.rs{.relu}.satfinite.f6x4type.f32       d, {a, b, e, f}, rbits;
+//! cvt.frnd3{.satfinite}.ue8m0x2.f32          d, a, b;
+//! cvt.frnd3{.satfinite}.ue8m0x2.bf16x2       d, a;
+//! cvt.rn.bf16x2.ue8m0x2                      d, a;
+//! .irnd   = { .rni, .rzi, .rmi, .rpi };
+//! .frnd   = { .rn,  .rz,  .rm,  .rp  };
+//! .frnd2  = { .rn,  .rz };
+//! .frnd3  = { .rz,  .rp };
+//! .dtype = .atype = { .u8,   .u16, .u32, .u64,
+//! .s8,   .s16, .s32, .s64,
+//! .bf16, .f16, .f32, .f64 };
+//! .f8x2type = { .e4m3x2, .e5m2x2 };
+//! .f4x2type = { .e2m1x2 };
+//! .f6x2type = { .e2m3x2, .e3m2x2 };
+//! .f4x4type = { .e2m1x4 };
+//! .f8x4type = { .e4m3x4, .e5m2x4 };
+//! .f6x4type = { .e2m3x4, .e3m2x4 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cvt::section_0::*;
+
+    impl Walk for CvtIrndFtzSatDtypeAtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for CvtFrndFtzSatDtypeAtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for CvtFrnd2ReluSatfiniteF16F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for CvtFrnd2ReluSatfiniteF16x2F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for CvtRsReluSatfiniteF16x2F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.rbits);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.rbits);
+        }
+    }
+
+    impl Walk for CvtFrnd2ReluSatfiniteBf16F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for CvtFrnd2ReluSatfiniteBf16x2F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for CvtRsReluSatfiniteBf16x2F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.rbits);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.rbits);
+        }
+    }
+
+    impl Walk for CvtRnaSatfiniteTf32F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for CvtFrnd2SatfiniteReluTf32F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for CvtRnSatfiniteReluF8x2typeF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for CvtRnSatfiniteReluF8x2typeF16x2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for CvtRnReluF16x2F8x2type {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for CvtRsReluSatfiniteF8x4typeF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_vector_operand(&self.a);
+            visitor.visit_general_operand(&self.rbits);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_vector_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.rbits);
+        }
+    }
+
+    impl Walk for CvtRnSatfiniteReluF4x2typeF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for CvtRnReluF16x2F4x2type {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for CvtRsReluSatfiniteF4x4typeF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_vector_operand(&self.a);
+            visitor.visit_general_operand(&self.rbits);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_vector_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.rbits);
+        }
+    }
+
+    impl Walk for CvtRnSatfiniteReluF6x2typeF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for CvtRnReluF16x2F6x2type {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for CvtRsReluSatfiniteF6x4typeF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_vector_operand(&self.a);
+            visitor.visit_general_operand(&self.rbits);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_vector_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.rbits);
+        }
+    }
+
+    impl Walk for CvtFrnd3SatfiniteUe8m0x2F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for CvtFrnd3SatfiniteUe8m0x2Bf16x2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for CvtRnBf16x2Ue8m0x2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Original PTX specification:
+//!
+//! cvt.pack.sat.convertType.abType  d, a, b;
+//! .convertType  = { .u16, .s16 };
+//! .abType       = { .s32 };
+//! ----------------------------------------------------------------
+//! cvt.pack.sat.convertType.abType.cType  d, a, b, c;
+//! .convertType  = { .u2, .s2, .u4, .s4, .u8, .s8 };
+//! .abType       = { .s32 };
+//! .cType        = { .b32 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cvt_pack::section_0::*;
+
+    impl Walk for CvtPackSatConverttypeAbtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::cvt_pack::section_1::*;
+
+    impl Walk for CvtPackSatConverttypeAbtypeCtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Original PTX specification:
+//!
+//! // convert const, global, local, or shared address to generic address
+//! cvta.space.size  p, a;        // source address in register a
+//! // cvta.space.size  p, var;      // get generic address of var
+//! // cvta.space.size  p, var+imm;  // generic address of var+offset
+//! // convert generic address to const, global, local, or shared address
+//! cvta.to.space.size  p, a;
+//! .space = { .const, .global, .local, .shared, .shared::cta, .shared::cluster, .param, .param::entry };
+//! .size  = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::cvta::section_0::*;
+
+    impl Walk for CvtaSpaceSize {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.p);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for CvtaToSpaceSize {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.p);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+}
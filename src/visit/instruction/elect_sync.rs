@@ -0,0 +1,25 @@
+//! Original PTX specification:
+//!
+//! elect.sync d|p, membermask;
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::elect_sync::section_0::*;
+
+    impl Walk for ElectSync {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.p);
+            visitor.visit_general_operand(&self.membermask);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.membermask);
+        }
+    }
+}
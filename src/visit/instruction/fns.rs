@@ -0,0 +1,27 @@
+//! Original PTX specification:
+//!
+//! fns.b32 d, mask, base, offset;
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::fns::section_0::*;
+
+    impl Walk for FnsB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.mask);
+            visitor.visit_general_operand(&self.base);
+            visitor.visit_general_operand(&self.offset);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.mask);
+            visitor.visit_general_operand_mut(&mut self.base);
+            visitor.visit_general_operand_mut(&mut self.offset);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Original PTX specification:
+//!
+//! getctarank{.space}.type d, a;
+//! // Get cta rank from source shared memory address in register a.
+//! getctarank.shared::cluster.type d, a;
+//! // // Get cta rank from shared memory variable.
+//! // getctarank.shared::cluster.type d, var;
+//! // // Get cta rank from shared memory variable+offset.
+//! // getctarank.shared::cluster.type d, var + imm;
+//! // Get cta rank from generic address of shared memory variable in register a.
+//! getctarank.type d, a;
+//! .space = { .shared::cluster };
+//! .type  = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::getctarank::section_0::*;
+
+    impl Walk for GetctarankSpaceType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for GetctarankSharedClusterType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for GetctarankType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Original PTX specification:
+//!
+//! griddepcontrol.action;
+//! .action   = { .launch_dependents, .wait };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::griddepcontrol::section_0::*;
+
+    impl Walk for GriddepcontrolAction {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+}
@@ -0,0 +1,24 @@
+//! Original PTX specification:
+//!
+//! isspacep.space  p, a;    // result is .pred
+//! .space = { .const, .global, .local, .shared, .shared::cta, .shared::cluster, .param, .param::entry };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::isspacep::section_0::*;
+
+    impl Walk for IsspacepSpace {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.p);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Original PTX specification:
+//!
+//! istypep.type   p, a;  // result is .pred
+//! .type = { .texref, .samplerref, .surfref };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::istypep::section_0::*;
+
+    impl Walk for IstypepType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.p);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+}
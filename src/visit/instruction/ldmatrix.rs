@@ -0,0 +1,53 @@
+//! Original PTX specification:
+//!
+//! ldmatrix.sync.aligned.shape.num{.trans}{.ss}.type r, [p];
+//! ldmatrix.sync.aligned.m8n16.num{.ss}.dst_fmt.src_fmt        r, [p];
+//! ldmatrix.sync.aligned.m16n16.num.trans{.ss}.dst_fmt.src_fmt r, [p];
+//! .shape   = {.m8n8, .m16n16};
+//! .num     = {.x1, .x2, .x4};
+//! .ss      = {.shared, .shared::cta};
+//! .type    = {.b16, .b8};
+//! .dst_fmt = { .b8x16 };
+//! .src_fmt = { .b6x16_p32, .b4x16_p64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::ldmatrix::section_0::*;
+
+    impl Walk for LdmatrixSyncAlignedShapeNumTransSsType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+        }
+    }
+
+    impl Walk for LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+        }
+    }
+
+    impl Walk for LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Original PTX specification:
+//!
+//! ldu{.ss}.type      d, [a];       // load from address
+//! ldu{.ss}.vec.type  d, [a];       // vec load from address
+//! .ss   = { .global };             // state space
+//! .vec  = { .v2, .v4 };
+//! .type = { .b8, .b16, .b32, .b64, .b128,
+//! .u8, .u16, .u32, .u64,
+//! .s8, .s16, .s32, .s64,
+//! .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::ldu::section_0::*;
+
+    impl Walk for LduSsType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for LduSsVecType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+//! Original PTX specification:
+//!
+//! lop3.b32 d, a, b, c, immLut;
+//! lop3.BoolOp.b32 d|p, a, b, c, immLut, q;
+//! .BoolOp   = { .or , .and };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::lop3::section_0::*;
+
+    impl Walk for Lop3B32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.immlut);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.immlut);
+        }
+    }
+
+    impl Walk for Lop3BoolopB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.p);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.immlut);
+            visitor.visit_general_operand(&self.q);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.immlut);
+            visitor.visit_general_operand_mut(&mut self.q);
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//! Original PTX specification:
+//!
+//! mapa{.space}.type          d, a, b;
+//! // Maps shared memory address in register a into CTA b.
+//! // mapa.shared::cluster.type  d, a, b;
+//! // Maps shared memory variable into CTA b.
+//! // mapa.shared::cluster.type  d, sh, b;
+//! // Maps shared memory variable into CTA b.
+//! // mapa.shared::cluster.type  d, sh + imm, b;
+//! // Maps generic address in register a into CTA b.
+//! // mapa.type                  d, a, b;
+//! .space = { .shared::cluster };
+//! .type  = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mapa::section_0::*;
+
+    impl Walk for MapaSpaceType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+}
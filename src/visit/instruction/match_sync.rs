@@ -0,0 +1,46 @@
+//! Original PTX specification:
+//!
+//! match.any.sync.type  d, a, membermask;
+//! match.all.sync.type  d{|p}, a, membermask;
+//! .type = { .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::match_sync::section_0::*;
+
+    impl Walk for MatchAnySyncType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.membermask);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.membermask);
+        }
+    }
+
+    impl Walk for MatchAllSyncType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.membermask);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.membermask);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+//! Original PTX specification:
+//!
+//! mbarrier.arrive{.sem}{.scope}{.state}.b64           state, [addr]{, count};
+//! mbarrier.arrive{.sem}{.scope}{.shared::cluster}.b64         _, [addr] {,count};
+//! mbarrier.arrive.expect_tx{.sem}{.scope}{.state}.b64 state, [addr], txCount;
+//! mbarrier.arrive.expect_tx{.sem}{.scope}{.shared::cluster}.b64   _, [addr], txCount;
+//! mbarrier.arrive.noComplete{.release}{.cta}{.state}.b64  state, [addr], count;
+//! .sem   = { .release, .relaxed };
+//! .scope = { .cta, .cluster };
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_arrive::section_0::*;
+
+    impl Walk for MbarrierArriveSemScopeStateB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.state2);
+            visitor.visit_address_operand(&self.addr);
+            if let Some(operand) = &self.count {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.state2);
+            visitor.visit_address_operand_mut(&mut self.addr);
+            if let Some(operand) = &mut self.count {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for MbarrierArriveSemScopeSharedClusterB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.operand);
+            visitor.visit_address_operand(&self.addr);
+            if let Some(operand) = &self.count {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.operand);
+            visitor.visit_address_operand_mut(&mut self.addr);
+            if let Some(operand) = &mut self.count {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for MbarrierArriveExpectTxSemScopeStateB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.state2);
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.txcount);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.state2);
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.txcount);
+        }
+    }
+
+    impl Walk for MbarrierArriveExpectTxSemScopeSharedClusterB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.operand);
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.txcount);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.operand);
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.txcount);
+        }
+    }
+
+    impl Walk for MbarrierArriveNocompleteReleaseCtaStateB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.state2);
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.count);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.state2);
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.count);
+        }
+    }
+}
@@ -0,0 +1,26 @@
+//! Original PTX specification:
+//!
+//! mbarrier.expect_tx{.sem}{.scope}{.space}.b64 [addr], txCount;
+//! .sem   = { .relaxed };
+//! .scope = { .cta, .cluster };
+//! .space = { .shared, .shared::cta, .shared::cluster };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_expect_tx::section_0::*;
+
+    impl Walk for MbarrierExpectTxSemScopeSpaceB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.txcount);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.txcount);
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! Original PTX specification:
+//!
+//! mbarrier.init{.state}.b64 [addr], count;
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_init::section_0::*;
+
+    impl Walk for MbarrierInitStateB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.count);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.count);
+        }
+    }
+}
@@ -0,0 +1,23 @@
+//! Original PTX specification:
+//!
+//! mbarrier.pending_count.b64 count, state;
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_pending_count::section_0::*;
+
+    impl Walk for MbarrierPendingCountB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.count);
+            visitor.visit_general_operand(&self.state);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.count);
+            visitor.visit_general_operand_mut(&mut self.state);
+        }
+    }
+}
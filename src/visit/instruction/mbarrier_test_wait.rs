@@ -0,0 +1,82 @@
+//! Original PTX specification:
+//!
+//! mbarrier.test_wait{.sem}{.scope}{.state}.b64        waitComplete, [addr], state;
+//! mbarrier.test_wait.parity{.sem}{.scope}{.state}.b64 waitComplete, [addr], phaseParity;
+//! mbarrier.try_wait{.sem}{.scope}{.state}.b64         waitComplete, [addr], state {, suspendTimeHint};
+//! mbarrier.try_wait.parity{.sem}{.scope}{.state}.b64  waitComplete, [addr], phaseParity {, suspendTimeHint};
+//! .sem   = { .acquire, .relaxed };
+//! .scope = { .cta, .cluster };
+//! .state = { .shared, .shared::cta}
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mbarrier_test_wait::section_0::*;
+
+    impl Walk for MbarrierTestWaitSemScopeStateB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.waitcomplete);
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.state2);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.waitcomplete);
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.state2);
+        }
+    }
+
+    impl Walk for MbarrierTestWaitParitySemScopeStateB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.waitcomplete);
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.phaseparity);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.waitcomplete);
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.phaseparity);
+        }
+    }
+
+    impl Walk for MbarrierTryWaitSemScopeStateB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.waitcomplete);
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.state2);
+            if let Some(operand) = &self.suspendtimehint {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.waitcomplete);
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.state2);
+            if let Some(operand) = &mut self.suspendtimehint {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for MbarrierTryWaitParitySemScopeStateB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.waitcomplete);
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.phaseparity);
+            if let Some(operand) = &self.suspendtimehint {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.waitcomplete);
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.phaseparity);
+            if let Some(operand) = &mut self.suspendtimehint {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
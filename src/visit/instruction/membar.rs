@@ -0,0 +1,95 @@
+//! Original PTX specification:
+//!
+//! // Thread fence:
+//! fence{.sem}.scope;
+//! // Thread fence (uni-directional):
+//! fence.acquire.sync_restrict::shared::cluster.cluster;
+//! fence.release.sync_restrict::shared::cta.cluster;
+//! // Operation fence (uni-directional):
+//! fence.op_restrict.release.cluster;
+//! // Proxy fence (bi-directional):
+//! fence.proxy.proxykind;
+//! // Proxy fence (uni-directional):
+//! fence.proxy.to_proxykind::from_proxykind.release.scope;
+//! fence.proxy.to_proxykind::from_proxykind.acquire.scope  [addr], size;
+//! fence.proxy.async::generic.acquire.sync_restrict::shared::cluster.cluster;
+//! fence.proxy.async::generic.release.sync_restrict::shared::cta.cluster;
+//! // Old style membar:
+//! membar.level;
+//! membar.proxy.proxykind;
+//! .sem       = { .sc, .acq_rel, .acquire, .release };
+//! .scope     = { .cta, .cluster, .gpu, .sys };
+//! .level     = { .cta, .gl, .sys };
+//! .proxykind = { .alias, .async, .async.global, .async.shared::cta, .async.shared::cluster};
+//! .op_restrict = { .mbarrier_init };
+//! .to_proxykind::from_proxykind = {.tensormap::generic};
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::membar::section_0::*;
+
+    impl Walk for FenceSemScope {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+
+    impl Walk for FenceAcquireSyncRestrictSharedClusterCluster {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+
+    impl Walk for FenceReleaseSyncRestrictSharedCtaCluster {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+
+    impl Walk for FenceOpRestrictReleaseCluster {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+
+    impl Walk for FenceProxyProxykind {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+
+    impl Walk for FenceProxyToProxykindFromProxykindReleaseScope {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+
+    impl Walk for FenceProxyToProxykindFromProxykindAcquireScope {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.size);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.size);
+        }
+    }
+
+    impl Walk for FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+
+    impl Walk for FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+
+    impl Walk for MembarLevel {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+
+    impl Walk for MembarProxyProxykind {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+}
@@ -0,0 +1,370 @@
+//! Original PTX specification:
+//!
+//! // Half precision floating point type:
+//! mma.spvariant.sync.aligned.m16n8k16.row.col.dtype.f16.f16.ctype  d, a, b, c, e, f;
+//! mma.spvariant.sync.aligned.m16n8k32.row.col.dtype.f16.f16.ctype  d, a, b, c, e, f;
+//! .ctype     = {.f16, .f32};
+//! .dtype     = {.f16, .f32};
+//! .spvariant = {.sp, .sp::ordered_metadata};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! mma.spvariant.sync.aligned.m16n8k16.row.col.f32.bf16.bf16.f32     d, a, b, c, e, f;
+//! mma.spvariant.sync.aligned.m16n8k32.row.col.f32.bf16.bf16.f32     d, a, b, c, e, f;
+//! mma.spvariant.sync.aligned.m16n8k8.row.col.f32.tf32.tf32.f32      d, a, b, c, e, f;
+//! mma.spvariant.sync.aligned.m16n8k16.row.col.f32.tf32.tf32.f32     d, a, b, c, e, f;
+//! mma.spvariant.sync.aligned.m16n8k64.row.col.f32.f8type.f8type.f32 d, a, b, c, e, f;
+//! mma.sp::ordered_metadata.sync.aligned.m16n8k64.row.col.kind.dtype.f8f6f4type.f8f6f4type.ctype d, a, b, c, e, f;
+//! .f8type     = {.e4m3, .e5m2};
+//! .spvariant  = {.sp, .sp::ordered_metadata};
+//! .f8f6f4type = {.e4m3, .e5m2, .e3m2, .e2m3, .e2m1};
+//! .kind       = {.kind::f8f6f4};
+//! .ctype      = {.f16, .f32};
+//! .dtype      = {.f16, .f32};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! // Alternate floating point type with block scaling:
+//! mma.spvariant.sync.aligned.m16n8k128.row.col.kind.block_scale{.scale_vec_size}.f32.e2m1.e2m1.f32.stype d, a, b, c, e, f, scale-a-data, {byte-id-a, thread-id-a}, scale-b-data, {byte-id-b, thread-id-b};
+//! .spvariant      = {.sp::ordered_metadata};
+//! .kind           = {.kind::mxf4};
+//! .scale_vec_size = {.scale_vec::2X};
+//! .stype          = {.ue8m0};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! mma.spvariant.sync.aligned.m16n8k128.row.col.kind.block_scale.scale_vec_size.f32.e2m1.e2m1.f32.stype d, a, b, c, e, f, scale-a-data, {byte-id-a, thread-id-a}, scale-b-data, {byte-id-b, thread-id-b};
+//! .spvariant      = {.sp::ordered_metadata};
+//! .kind           = {.kind::mxf4nvf4};
+//! .scale_vec_size = {.scale_vec::2X, .scale_vec::4X};
+//! .stype          = {.ue8m0, .ue4m3};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! mma.spvariant.sync.aligned.m16n8k64.row.col.kind.block_scale{.scale_vec_size}.f32.f8f6f4type.f8f6f4type.f32.stype d, a, b, c, e, f, scale-a-data, {byte-id-a, thread-id-a}, scale-b-data, {byte-id-b, thread-id-b};
+//! .spvariant      = {.sp::ordered_metadata};
+//! .kind           = {.kind::mxf8f6f4};
+//! .scale_vec_size = {.scale_vec::1X};
+//! .f8f6f4type     = {.e4m3, .e5m2, .e3m2, .e2m3, .e2m1};
+//! .stype          = {.ue8m0};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! // Integer type:
+//! mma.spvariant.sync.aligned.shape.row.col{.satfinite}.s32.atype.btype.s32 d, a, b, c, e, f;
+//! .shape     = {.m16n8k32, .m16n8k64};
+//! .atype     = {.u8, .s8};
+//! .btype     = {.u8, .s8};
+//! .spvariant = {.sp, .sp::ordered_metadata};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! mma.spvariant.sync.aligned.shape.row.col{.satfinite}.s32.atype.btype.s32 d, a, b, c, e, f;
+//! .shape     = {.m16n8k64, .m16n8k128};
+//! .atype     = {.u4, .s4};
+//! .btype     = {.u4, .s4};
+//! .spvariant = {.sp, .sp::ordered_metadata};
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_0::*;
+
+    impl Walk for MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+        }
+    }
+
+    impl Walk for MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_1::*;
+
+    impl Walk for MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+        }
+    }
+
+    impl Walk for MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+        }
+    }
+
+    impl Walk for MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+        }
+    }
+
+    impl Walk for MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+        }
+    }
+
+    impl Walk for MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+        }
+    }
+
+    impl Walk for MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_2::*;
+
+    impl Walk for MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+            visitor.visit_general_operand(&self.scale_a_data);
+            visitor.visit_vector_operand(&self.byte_id_a);
+            visitor.visit_general_operand(&self.scale_b_data);
+            visitor.visit_vector_operand(&self.byte_id_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+            visitor.visit_general_operand_mut(&mut self.scale_a_data);
+            visitor.visit_vector_operand_mut(&mut self.byte_id_a);
+            visitor.visit_general_operand_mut(&mut self.scale_b_data);
+            visitor.visit_vector_operand_mut(&mut self.byte_id_b);
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_3::*;
+
+    impl Walk for MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+            visitor.visit_general_operand(&self.scale_a_data);
+            visitor.visit_vector_operand(&self.byte_id_a);
+            visitor.visit_general_operand(&self.scale_b_data);
+            visitor.visit_vector_operand(&self.byte_id_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+            visitor.visit_general_operand_mut(&mut self.scale_a_data);
+            visitor.visit_vector_operand_mut(&mut self.byte_id_a);
+            visitor.visit_general_operand_mut(&mut self.scale_b_data);
+            visitor.visit_vector_operand_mut(&mut self.byte_id_b);
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_4::*;
+
+    impl Walk for MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+            visitor.visit_general_operand(&self.scale_a_data);
+            visitor.visit_vector_operand(&self.byte_id_a);
+            visitor.visit_general_operand(&self.scale_b_data);
+            visitor.visit_vector_operand(&self.byte_id_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+            visitor.visit_general_operand_mut(&mut self.scale_a_data);
+            visitor.visit_vector_operand_mut(&mut self.byte_id_a);
+            visitor.visit_general_operand_mut(&mut self.scale_b_data);
+            visitor.visit_vector_operand_mut(&mut self.byte_id_b);
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_5::*;
+
+    impl Walk for MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+        }
+    }
+}
+
+pub mod section_6 {
+    use super::*;
+    use crate::r#type::instruction::mma_sp::section_6::*;
+
+    impl Walk for MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.e);
+            visitor.visit_general_operand(&self.f);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.e);
+            visitor.visit_general_operand_mut(&mut self.f);
+        }
+    }
+}
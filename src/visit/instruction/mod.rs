@@ -0,0 +1,1371 @@
+// Auto-generated module declarations
+// DO NOT EDIT MANUALLY
+#![allow(unused)]
+
+use crate::r#type::instruction::Inst;
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod abs;
+pub mod activemask;
+pub mod add;
+pub mod add_cc;
+pub mod addc;
+pub mod alloca;
+pub mod and;
+pub mod applypriority;
+pub mod atom;
+pub mod bar;
+pub mod bar_warp_sync;
+pub mod barrier_cluster;
+pub mod bfe;
+pub mod bfi;
+pub mod bfind;
+pub mod bmsk;
+pub mod bra;
+pub mod brev;
+pub mod brkpt;
+pub mod brx_idx;
+pub mod call;
+pub mod clusterlaunchcontrol_query_cancel;
+pub mod clusterlaunchcontrol_try_cancel;
+pub mod clz;
+pub mod cnot;
+pub mod copysign;
+pub mod cos;
+pub mod cp_async;
+pub mod cp_async_bulk;
+pub mod cp_async_bulk_commit_group;
+pub mod cp_async_bulk_prefetch;
+pub mod cp_async_bulk_prefetch_tensor;
+pub mod cp_async_bulk_tensor;
+pub mod cp_async_bulk_wait_group;
+pub mod cp_async_commit_group;
+pub mod cp_async_mbarrier_arrive;
+pub mod cp_async_wait_group;
+pub mod cp_reduce_async_bulk;
+pub mod cp_reduce_async_bulk_tensor;
+pub mod createpolicy;
+pub mod cvt;
+pub mod cvt_pack;
+pub mod cvta;
+pub mod discard;
+pub mod div;
+pub mod dp2a;
+pub mod dp4a;
+pub mod elect_sync;
+pub mod ex2;
+pub mod exit;
+pub mod fma;
+pub mod fns;
+pub mod getctarank;
+pub mod griddepcontrol;
+pub mod isspacep;
+pub mod istypep;
+pub mod ld;
+pub mod ld_global_nc;
+pub mod ldmatrix;
+pub mod ldu;
+pub mod lg2;
+pub mod lop3;
+pub mod mad;
+pub mod mad24;
+pub mod mad_cc;
+pub mod madc;
+pub mod mapa;
+pub mod match_sync;
+pub mod max;
+pub mod mbarrier_arrive;
+pub mod mbarrier_arrive_drop;
+pub mod mbarrier_complete_tx;
+pub mod mbarrier_expect_tx;
+pub mod mbarrier_init;
+pub mod mbarrier_inval;
+pub mod mbarrier_pending_count;
+pub mod mbarrier_test_wait;
+pub mod membar;
+pub mod min;
+pub mod mma;
+pub mod mma_sp;
+pub mod mov;
+pub mod movmatrix;
+pub mod mul;
+pub mod mul24;
+pub mod multimem_ld_reduce;
+pub mod nanosleep;
+pub mod neg;
+pub mod not;
+pub mod or;
+pub mod pmevent;
+pub mod popc;
+pub mod prefetch;
+pub mod prmt;
+pub mod rcp;
+pub mod rcp_approx_ftz_f64;
+pub mod red;
+pub mod red_async;
+pub mod redux_sync;
+pub mod rem;
+pub mod ret;
+pub mod rsqrt;
+pub mod rsqrt_approx_ftz_f64;
+pub mod sad;
+pub mod selp;
+pub mod set;
+pub mod setmaxnreg;
+pub mod setp;
+pub mod shf;
+pub mod shfl;
+pub mod shfl_sync;
+pub mod shl;
+pub mod shr;
+pub mod sin;
+pub mod slct;
+pub mod sqrt;
+pub mod st;
+pub mod st_async;
+pub mod st_bulk;
+pub mod stackrestore;
+pub mod stacksave;
+pub mod stmatrix;
+pub mod sub;
+pub mod sub_cc;
+pub mod subc;
+pub mod suld;
+pub mod suq;
+pub mod sured;
+pub mod sust;
+pub mod szext;
+pub mod tanh;
+pub mod tcgen05_alloc;
+pub mod tcgen05_commit;
+pub mod tcgen05_cp;
+pub mod tcgen05_fence;
+pub mod tcgen05_ld;
+pub mod tcgen05_mma;
+pub mod tcgen05_mma_sp;
+pub mod tcgen05_mma_ws;
+pub mod tcgen05_mma_ws_sp;
+pub mod tcgen05_shift;
+pub mod tcgen05_st;
+pub mod tcgen05_wait;
+pub mod tensormap_cp_fenceproxy;
+pub mod tensormap_replace;
+pub mod testp;
+pub mod tex;
+pub mod tld4;
+pub mod trap;
+pub mod txq;
+pub mod vmad;
+pub mod vop;
+pub mod vop2;
+pub mod vop4;
+pub mod vote;
+pub mod vote_sync;
+pub mod vset;
+pub mod vset2;
+pub mod vset4;
+pub mod vsh;
+pub mod wgmma_commit_group;
+pub mod wgmma_fence;
+pub mod wgmma_mma_async;
+pub mod wgmma_mma_async_sp;
+pub mod wgmma_wait_group;
+pub mod wmma_load;
+pub mod wmma_mma;
+pub mod wmma_store;
+pub mod xor;
+
+impl Walk for Inst {
+    fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+        match self {
+            Inst::AbsType(value) => value.walk(visitor),
+            Inst::AbsFtzF32(value) => value.walk(visitor),
+            Inst::AbsF64(value) => value.walk(visitor),
+            Inst::AbsFtzF16(value) => value.walk(visitor),
+            Inst::AbsFtzF16x2(value) => value.walk(visitor),
+            Inst::AbsBf16(value) => value.walk(visitor),
+            Inst::AbsBf16x2(value) => value.walk(visitor),
+            Inst::ActivemaskB32(value) => value.walk(visitor),
+            Inst::AddCcType(value) => value.walk(visitor),
+            Inst::AddType(value) => value.walk(visitor),
+            Inst::AddSatS32(value) => value.walk(visitor),
+            Inst::AddRndFtzSatF32(value) => value.walk(visitor),
+            Inst::AddRndFtzF32x2(value) => value.walk(visitor),
+            Inst::AddRndF64(value) => value.walk(visitor),
+            Inst::AddRndFtzSatF16(value) => value.walk(visitor),
+            Inst::AddRndFtzSatF16x2(value) => value.walk(visitor),
+            Inst::AddRndBf16(value) => value.walk(visitor),
+            Inst::AddRndBf16x2(value) => value.walk(visitor),
+            Inst::AddRndSatF32Atype(value) => value.walk(visitor),
+            Inst::AddcCcType(value) => value.walk(visitor),
+            Inst::AllocaType(value) => value.walk(visitor),
+            Inst::AndType(value) => value.walk(visitor),
+            Inst::ApplypriorityGlobalLevelEvictionPriority(value) => value.walk(visitor),
+            Inst::AtomSemScopeSpaceOpLevelCacheHintType(value) => value.walk(visitor),
+            Inst::AtomSemScopeSpaceOpType(value) => value.walk(visitor),
+            Inst::AtomSemScopeSpaceCasB16(value) => value.walk(visitor),
+            Inst::AtomSemScopeSpaceCasB128(value) => value.walk(visitor),
+            Inst::AtomSemScopeSpaceExchLevelCacheHintB128(value) => value.walk(visitor),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16(value) => value.walk(visitor),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2(value) => value.walk(visitor),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16(value) => value.walk(visitor),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2(value) => value.walk(visitor),
+            Inst::AtomSemScopeGlobalAddLevelCacheHintVec32BitF32(value) => value.walk(visitor),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType(value) => value.walk(visitor),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType(value) => value.walk(visitor),
+            Inst::BarrierCtaSyncAligned(value) => value.walk(visitor),
+            Inst::BarrierCtaArriveAligned(value) => value.walk(visitor),
+            Inst::BarrierCtaRedPopcAlignedU32(value) => value.walk(visitor),
+            Inst::BarrierCtaRedOpAlignedPred(value) => value.walk(visitor),
+            Inst::BarCtaSync(value) => value.walk(visitor),
+            Inst::BarCtaArrive(value) => value.walk(visitor),
+            Inst::BarCtaRedPopcU32(value) => value.walk(visitor),
+            Inst::BarCtaRedOpPred(value) => value.walk(visitor),
+            Inst::BarWarpSync(value) => value.walk(visitor),
+            Inst::BarrierClusterArriveSemAligned(value) => value.walk(visitor),
+            Inst::BarrierClusterWaitAcquireAligned(value) => value.walk(visitor),
+            Inst::BfeType(value) => value.walk(visitor),
+            Inst::BfiType(value) => value.walk(visitor),
+            Inst::BfindType(value) => value.walk(visitor),
+            Inst::BfindShiftamtType(value) => value.walk(visitor),
+            Inst::BmskModeB32(value) => value.walk(visitor),
+            Inst::BraUni(value) => value.walk(visitor),
+            Inst::BraUni1(value) => value.walk(visitor),
+            Inst::BrevType(value) => value.walk(visitor),
+            Inst::Brkpt(value) => value.walk(visitor),
+            Inst::BrxIdxUni(value) => value.walk(visitor),
+            Inst::BrxIdxUni1(value) => value.walk(visitor),
+            Inst::CallUni(value) => value.walk(visitor),
+            Inst::CallUni1(value) => value.walk(visitor),
+            Inst::CallUni2(value) => value.walk(visitor),
+            Inst::CallUni3(value) => value.walk(visitor),
+            Inst::CallUni4(value) => value.walk(visitor),
+            Inst::CallUni5(value) => value.walk(visitor),
+            Inst::CallUni6(value) => value.walk(visitor),
+            Inst::CallUni7(value) => value.walk(visitor),
+            Inst::CallUni8(value) => value.walk(visitor),
+            Inst::ClusterlaunchcontrolQueryCancelIsCanceledPredB128(value) => value.walk(visitor),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128(value) => value.walk(visitor),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128(value) => value.walk(visitor),
+            Inst::ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128(value) => value.walk(visitor),
+            Inst::ClzType(value) => value.walk(visitor),
+            Inst::CnotType(value) => value.walk(visitor),
+            Inst::CopysignType(value) => value.walk(visitor),
+            Inst::CosApproxFtzF32(value) => value.walk(visitor),
+            Inst::CpAsyncBulkCommitGroup(value) => value.walk(visitor),
+            Inst::CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint(value) => value.walk(visitor),
+            Inst::CpAsyncBulkPrefetchL2SrcLevelCacheHint(value) => value.walk(visitor),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint(value) => value.walk(visitor),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint(value) => value.walk(visitor),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint(value) => value.walk(visitor),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint(value) => value.walk(visitor),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint(value) => value.walk(visitor),
+            Inst::CpAsyncBulkDstSrcCompletionMechanism(value) => value.walk(visitor),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask(value) => value.walk(visitor),
+            Inst::CpAsyncBulkWaitGroupRead(value) => value.walk(visitor),
+            Inst::CpAsyncCommitGroup(value) => value.walk(visitor),
+            Inst::CpAsyncMbarrierArriveNoincStateB64(value) => value.walk(visitor),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.walk(visitor),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.walk(visitor),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.walk(visitor),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.walk(visitor),
+            Inst::CpAsyncWaitGroup(value) => value.walk(visitor),
+            Inst::CpAsyncWaitAll(value) => value.walk(visitor),
+            Inst::CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint(value) => value.walk(visitor),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismRedopType(value) => value.walk(visitor),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType(value) => value.walk(visitor),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType(value) => value.walk(visitor),
+            Inst::CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.walk(visitor),
+            Inst::CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.walk(visitor),
+            Inst::CreatepolicyCvtL2B64(value) => value.walk(visitor),
+            Inst::CvtPackSatConverttypeAbtype(value) => value.walk(visitor),
+            Inst::CvtPackSatConverttypeAbtypeCtype(value) => value.walk(visitor),
+            Inst::CvtIrndFtzSatDtypeAtype(value) => value.walk(visitor),
+            Inst::CvtFrndFtzSatDtypeAtype(value) => value.walk(visitor),
+            Inst::CvtFrnd2ReluSatfiniteF16F32(value) => value.walk(visitor),
+            Inst::CvtFrnd2ReluSatfiniteF16x2F32(value) => value.walk(visitor),
+            Inst::CvtRsReluSatfiniteF16x2F32(value) => value.walk(visitor),
+            Inst::CvtFrnd2ReluSatfiniteBf16F32(value) => value.walk(visitor),
+            Inst::CvtFrnd2ReluSatfiniteBf16x2F32(value) => value.walk(visitor),
+            Inst::CvtRsReluSatfiniteBf16x2F32(value) => value.walk(visitor),
+            Inst::CvtRnaSatfiniteTf32F32(value) => value.walk(visitor),
+            Inst::CvtFrnd2SatfiniteReluTf32F32(value) => value.walk(visitor),
+            Inst::CvtRnSatfiniteReluF8x2typeF32(value) => value.walk(visitor),
+            Inst::CvtRnSatfiniteReluF8x2typeF16x2(value) => value.walk(visitor),
+            Inst::CvtRnReluF16x2F8x2type(value) => value.walk(visitor),
+            Inst::CvtRsReluSatfiniteF8x4typeF32(value) => value.walk(visitor),
+            Inst::CvtRnSatfiniteReluF4x2typeF32(value) => value.walk(visitor),
+            Inst::CvtRnReluF16x2F4x2type(value) => value.walk(visitor),
+            Inst::CvtRsReluSatfiniteF4x4typeF32(value) => value.walk(visitor),
+            Inst::CvtRnSatfiniteReluF6x2typeF32(value) => value.walk(visitor),
+            Inst::CvtRnReluF16x2F6x2type(value) => value.walk(visitor),
+            Inst::CvtRsReluSatfiniteF6x4typeF32(value) => value.walk(visitor),
+            Inst::CvtFrnd3SatfiniteUe8m0x2F32(value) => value.walk(visitor),
+            Inst::CvtFrnd3SatfiniteUe8m0x2Bf16x2(value) => value.walk(visitor),
+            Inst::CvtRnBf16x2Ue8m0x2(value) => value.walk(visitor),
+            Inst::CvtaSpaceSize(value) => value.walk(visitor),
+            Inst::CvtaToSpaceSize(value) => value.walk(visitor),
+            Inst::DiscardGlobalLevel(value) => value.walk(visitor),
+            Inst::DivType(value) => value.walk(visitor),
+            Inst::DivApproxFtzF32(value) => value.walk(visitor),
+            Inst::DivFullFtzF32(value) => value.walk(visitor),
+            Inst::DivRndFtzF32(value) => value.walk(visitor),
+            Inst::DivRndF64(value) => value.walk(visitor),
+            Inst::Dp2aModeAtypeBtype(value) => value.walk(visitor),
+            Inst::Dp4aAtypeBtype(value) => value.walk(visitor),
+            Inst::ElectSync(value) => value.walk(visitor),
+            Inst::Ex2ApproxFtzF32(value) => value.walk(visitor),
+            Inst::Ex2ApproxAtype(value) => value.walk(visitor),
+            Inst::Ex2ApproxFtzBtype(value) => value.walk(visitor),
+            Inst::Exit(value) => value.walk(visitor),
+            Inst::FmaRndFtzSatF32(value) => value.walk(visitor),
+            Inst::FmaRndFtzF32x2(value) => value.walk(visitor),
+            Inst::FmaRndF64(value) => value.walk(visitor),
+            Inst::FmaRndFtzSatF16(value) => value.walk(visitor),
+            Inst::FmaRndFtzSatF16x2(value) => value.walk(visitor),
+            Inst::FmaRndFtzReluF16(value) => value.walk(visitor),
+            Inst::FmaRndFtzReluF16x2(value) => value.walk(visitor),
+            Inst::FmaRndReluBf16(value) => value.walk(visitor),
+            Inst::FmaRndReluBf16x2(value) => value.walk(visitor),
+            Inst::FmaRndOobReluType(value) => value.walk(visitor),
+            Inst::FmaRndSatF32Abtype(value) => value.walk(visitor),
+            Inst::FnsB32(value) => value.walk(visitor),
+            Inst::GetctarankSpaceType(value) => value.walk(visitor),
+            Inst::GetctarankSharedClusterType(value) => value.walk(visitor),
+            Inst::GetctarankType(value) => value.walk(visitor),
+            Inst::GriddepcontrolAction(value) => value.walk(visitor),
+            Inst::IsspacepSpace(value) => value.walk(visitor),
+            Inst::IstypepType(value) => value.walk(visitor),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType(value) => value.walk(visitor),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType(value) => value.walk(visitor),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType(value) => value.walk(visitor),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.walk(visitor),
+            Inst::LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType(value) => value.walk(visitor),
+            Inst::LdWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.walk(visitor),
+            Inst::LdVolatileSsLevelPrefetchSizeVecType(value) => value.walk(visitor),
+            Inst::LdRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.walk(visitor),
+            Inst::LdAcquireScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.walk(visitor),
+            Inst::LdMmioRelaxedSysGlobalType(value) => value.walk(visitor),
+            Inst::LdmatrixSyncAlignedShapeNumTransSsType(value) => value.walk(visitor),
+            Inst::LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt(value) => value.walk(visitor),
+            Inst::LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt(value) => value.walk(visitor),
+            Inst::LduSsType(value) => value.walk(visitor),
+            Inst::LduSsVecType(value) => value.walk(visitor),
+            Inst::Lg2ApproxFtzF32(value) => value.walk(visitor),
+            Inst::Lop3B32(value) => value.walk(visitor),
+            Inst::Lop3BoolopB32(value) => value.walk(visitor),
+            Inst::MadHiloCcType(value) => value.walk(visitor),
+            Inst::MadModeType(value) => value.walk(visitor),
+            Inst::MadHiSatS32(value) => value.walk(visitor),
+            Inst::MadFtzSatF32(value) => value.walk(visitor),
+            Inst::MadRndFtzSatF32(value) => value.walk(visitor),
+            Inst::MadRndF64(value) => value.walk(visitor),
+            Inst::Mad24ModeType(value) => value.walk(visitor),
+            Inst::Mad24HiSatS32(value) => value.walk(visitor),
+            Inst::MadcHiloCcType(value) => value.walk(visitor),
+            Inst::MapaSpaceType(value) => value.walk(visitor),
+            Inst::MatchAnySyncType(value) => value.walk(visitor),
+            Inst::MatchAllSyncType(value) => value.walk(visitor),
+            Inst::MaxAtype(value) => value.walk(visitor),
+            Inst::MaxReluBtype(value) => value.walk(visitor),
+            Inst::MaxFtzNanXorsignAbsF32(value) => value.walk(visitor),
+            Inst::MaxFtzNanAbsF32(value) => value.walk(visitor),
+            Inst::MaxF64(value) => value.walk(visitor),
+            Inst::MaxFtzNanXorsignAbsF16(value) => value.walk(visitor),
+            Inst::MaxFtzNanXorsignAbsF16x2(value) => value.walk(visitor),
+            Inst::MaxNanXorsignAbsBf16(value) => value.walk(visitor),
+            Inst::MaxNanXorsignAbsBf16x2(value) => value.walk(visitor),
+            Inst::MbarrierArriveSemScopeStateB64(value) => value.walk(visitor),
+            Inst::MbarrierArriveSemScopeSharedClusterB64(value) => value.walk(visitor),
+            Inst::MbarrierArriveExpectTxSemScopeStateB64(value) => value.walk(visitor),
+            Inst::MbarrierArriveExpectTxSemScopeSharedClusterB64(value) => value.walk(visitor),
+            Inst::MbarrierArriveNocompleteReleaseCtaStateB64(value) => value.walk(visitor),
+            Inst::MbarrierArriveDropSemScopeStateB64(value) => value.walk(visitor),
+            Inst::MbarrierArriveDropSemScopeSharedClusterB64(value) => value.walk(visitor),
+            Inst::MbarrierArriveDropExpectTxStateSemScopeB64(value) => value.walk(visitor),
+            Inst::MbarrierArriveDropExpectTxSharedClusterSemScopeB64(value) => value.walk(visitor),
+            Inst::MbarrierArriveDropNocompleteReleaseCtaStateB64(value) => value.walk(visitor),
+            Inst::MbarrierCompleteTxSemScopeSpaceB64(value) => value.walk(visitor),
+            Inst::MbarrierExpectTxSemScopeSpaceB64(value) => value.walk(visitor),
+            Inst::MbarrierInitStateB64(value) => value.walk(visitor),
+            Inst::MbarrierInvalStateB64(value) => value.walk(visitor),
+            Inst::MbarrierPendingCountB64(value) => value.walk(visitor),
+            Inst::MbarrierTestWaitSemScopeStateB64(value) => value.walk(visitor),
+            Inst::MbarrierTestWaitParitySemScopeStateB64(value) => value.walk(visitor),
+            Inst::MbarrierTryWaitSemScopeStateB64(value) => value.walk(visitor),
+            Inst::MbarrierTryWaitParitySemScopeStateB64(value) => value.walk(visitor),
+            Inst::FenceSemScope(value) => value.walk(visitor),
+            Inst::FenceAcquireSyncRestrictSharedClusterCluster(value) => value.walk(visitor),
+            Inst::FenceReleaseSyncRestrictSharedCtaCluster(value) => value.walk(visitor),
+            Inst::FenceOpRestrictReleaseCluster(value) => value.walk(visitor),
+            Inst::FenceProxyProxykind(value) => value.walk(visitor),
+            Inst::FenceProxyToProxykindFromProxykindReleaseScope(value) => value.walk(visitor),
+            Inst::FenceProxyToProxykindFromProxykindAcquireScope(value) => value.walk(visitor),
+            Inst::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster(value) => value.walk(visitor),
+            Inst::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster(value) => value.walk(visitor),
+            Inst::MembarLevel(value) => value.walk(visitor),
+            Inst::MembarProxyProxykind(value) => value.walk(visitor),
+            Inst::MinAtype(value) => value.walk(visitor),
+            Inst::MinReluBtype(value) => value.walk(visitor),
+            Inst::MinFtzNanXorsignAbsF32(value) => value.walk(visitor),
+            Inst::MinFtzNanAbsF32(value) => value.walk(visitor),
+            Inst::MinF64(value) => value.walk(visitor),
+            Inst::MinFtzNanXorsignAbsF16(value) => value.walk(visitor),
+            Inst::MinFtzNanXorsignAbsF16x2(value) => value.walk(visitor),
+            Inst::MinNanXorsignAbsBf16(value) => value.walk(visitor),
+            Inst::MinNanXorsignAbsBf16x2(value) => value.walk(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.walk(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype(value) => value.walk(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.walk(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32(value) => value.walk(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32(value) => value.walk(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32(value) => value.walk(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32(value) => value.walk(visitor),
+            Inst::MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.walk(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.walk(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.walk(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.walk(visitor),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.walk(visitor),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedM8n8k4AlayoutBlayoutDtypeF16F16Ctype(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedM16n8k8RowColDtypeF16F16Ctype(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedM16n8k4RowColF32Tf32Tf32F32(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedM16n8k8RowColF32AtypeBtypeF32(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedShapeRowColDtypeF8typeF8typeCtype(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedM16n8k32RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedM16n8k32RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedShapeRowColF64F64F64F64(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.walk(visitor),
+            Inst::MmaSyncAlignedShapeRowColS32B1B1S32BitopPopc(value) => value.walk(visitor),
+            Inst::MovType(value) => value.walk(visitor),
+            Inst::MovU32(value) => value.walk(visitor),
+            Inst::MovU64(value) => value.walk(visitor),
+            Inst::MovU321(value) => value.walk(visitor),
+            Inst::MovU641(value) => value.walk(visitor),
+            Inst::MovType1(value) => value.walk(visitor),
+            Inst::MovmatrixSyncAlignedShapeTransType(value) => value.walk(visitor),
+            Inst::MulModeType(value) => value.walk(visitor),
+            Inst::MulRndFtzSatF32(value) => value.walk(visitor),
+            Inst::MulRndFtzF32x2(value) => value.walk(visitor),
+            Inst::MulRndF64(value) => value.walk(visitor),
+            Inst::MulRndFtzSatF16(value) => value.walk(visitor),
+            Inst::MulRndFtzSatF16x2(value) => value.walk(visitor),
+            Inst::MulRndBf16(value) => value.walk(visitor),
+            Inst::MulRndBf16x2(value) => value.walk(visitor),
+            Inst::Mul24ModeType(value) => value.walk(visitor),
+            Inst::MultimemLdReduceLdsemScopeSsOpType(value) => value.walk(visitor),
+            Inst::MultimemLdReduceWeakSsOpType(value) => value.walk(visitor),
+            Inst::MultimemStStsemScopeSsType(value) => value.walk(visitor),
+            Inst::MultimemStWeakSsType(value) => value.walk(visitor),
+            Inst::MultimemRedRedsemScopeSsOpType(value) => value.walk(visitor),
+            Inst::MultimemLdReduceLdsemScopeSsOpAccPrecVecType(value) => value.walk(visitor),
+            Inst::MultimemLdReduceWeakSsOpAccPrecVecType(value) => value.walk(visitor),
+            Inst::MultimemStStsemScopeSsVecType(value) => value.walk(visitor),
+            Inst::MultimemStWeakSsVecType(value) => value.walk(visitor),
+            Inst::MultimemRedRedsemScopeSsRedopVecRedtype(value) => value.walk(visitor),
+            Inst::NanosleepU32(value) => value.walk(visitor),
+            Inst::NegType(value) => value.walk(visitor),
+            Inst::NegFtzF32(value) => value.walk(visitor),
+            Inst::NegF64(value) => value.walk(visitor),
+            Inst::NegFtzF16(value) => value.walk(visitor),
+            Inst::NegFtzF16x2(value) => value.walk(visitor),
+            Inst::NegBf16(value) => value.walk(visitor),
+            Inst::NegBf16x2(value) => value.walk(visitor),
+            Inst::NotType(value) => value.walk(visitor),
+            Inst::OrType(value) => value.walk(visitor),
+            Inst::Pmevent(value) => value.walk(visitor),
+            Inst::PmeventMask(value) => value.walk(visitor),
+            Inst::PopcType(value) => value.walk(visitor),
+            Inst::PrefetchSpaceLevel(value) => value.walk(visitor),
+            Inst::PrefetchGlobalLevelEvictionPriority(value) => value.walk(visitor),
+            Inst::PrefetchuL1(value) => value.walk(visitor),
+            Inst::PrefetchTensormapSpaceTensormap(value) => value.walk(visitor),
+            Inst::PrmtB32Mode(value) => value.walk(visitor),
+            Inst::RcpApproxFtzF64(value) => value.walk(visitor),
+            Inst::RcpApproxFtzF32(value) => value.walk(visitor),
+            Inst::RcpRndFtzF32(value) => value.walk(visitor),
+            Inst::RcpRndF64(value) => value.walk(visitor),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType(value) => value.walk(visitor),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType1(value) => value.walk(visitor),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType2(value) => value.walk(visitor),
+            Inst::RedAsyncSemScopeSsCompletionMechanismAddType(value) => value.walk(visitor),
+            Inst::RedAsyncMmioSemScopeSsAddType(value) => value.walk(visitor),
+            Inst::RedOpSpaceSemScopeLevelCacheHintType(value) => value.walk(visitor),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16(value) => value.walk(visitor),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16x2(value) => value.walk(visitor),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16(value) => value.walk(visitor),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16x2(value) => value.walk(visitor),
+            Inst::RedAddSpaceSemScopeLevelCacheHintVec32BitF32(value) => value.walk(visitor),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec16BitHalfWordType(value) => value.walk(visitor),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec32BitPackedType(value) => value.walk(visitor),
+            Inst::ReduxSyncOpType(value) => value.walk(visitor),
+            Inst::ReduxSyncOpB32(value) => value.walk(visitor),
+            Inst::ReduxSyncOpAbsNanF32(value) => value.walk(visitor),
+            Inst::RemType(value) => value.walk(visitor),
+            Inst::RetUni(value) => value.walk(visitor),
+            Inst::RsqrtApproxFtzF64(value) => value.walk(visitor),
+            Inst::RsqrtApproxFtzF32(value) => value.walk(visitor),
+            Inst::RsqrtApproxF64(value) => value.walk(visitor),
+            Inst::SadType(value) => value.walk(visitor),
+            Inst::SelpType(value) => value.walk(visitor),
+            Inst::SetCmpopFtzDtypeStype(value) => value.walk(visitor),
+            Inst::SetCmpopBoolopFtzDtypeStype(value) => value.walk(visitor),
+            Inst::SetCmpopFtzF16Stype(value) => value.walk(visitor),
+            Inst::SetCmpopBoolopFtzF16Stype(value) => value.walk(visitor),
+            Inst::SetCmpopBf16Stype(value) => value.walk(visitor),
+            Inst::SetCmpopBoolopBf16Stype(value) => value.walk(visitor),
+            Inst::SetCmpopFtzDtypeF16(value) => value.walk(visitor),
+            Inst::SetCmpopBoolopFtzDtypeF16(value) => value.walk(visitor),
+            Inst::SetCmpopDtypeBf16(value) => value.walk(visitor),
+            Inst::SetCmpopBoolopDtypeBf16(value) => value.walk(visitor),
+            Inst::SetCmpopFtzDtypeF16x2(value) => value.walk(visitor),
+            Inst::SetCmpopBoolopFtzDtypeF16x2(value) => value.walk(visitor),
+            Inst::SetCmpopDtypeBf16x2(value) => value.walk(visitor),
+            Inst::SetCmpopBoolopDtypeBf16x2(value) => value.walk(visitor),
+            Inst::SetmaxnregActionSyncAlignedU32(value) => value.walk(visitor),
+            Inst::SetpCmpopFtzType(value) => value.walk(visitor),
+            Inst::SetpCmpopBoolopFtzType(value) => value.walk(visitor),
+            Inst::SetpCmpopFtzF16(value) => value.walk(visitor),
+            Inst::SetpCmpopBoolopFtzF16(value) => value.walk(visitor),
+            Inst::SetpCmpopFtzF16x2(value) => value.walk(visitor),
+            Inst::SetpCmpopBoolopFtzF16x2(value) => value.walk(visitor),
+            Inst::SetpCmpopBf16(value) => value.walk(visitor),
+            Inst::SetpCmpopBoolopBf16(value) => value.walk(visitor),
+            Inst::SetpCmpopBf16x2(value) => value.walk(visitor),
+            Inst::SetpCmpopBoolopBf16x2(value) => value.walk(visitor),
+            Inst::ShfLModeB32(value) => value.walk(visitor),
+            Inst::ShfRModeB32(value) => value.walk(visitor),
+            Inst::ShflSyncModeB32(value) => value.walk(visitor),
+            Inst::ShflModeB32(value) => value.walk(visitor),
+            Inst::ShlType(value) => value.walk(visitor),
+            Inst::ShrType(value) => value.walk(visitor),
+            Inst::SinApproxFtzF32(value) => value.walk(visitor),
+            Inst::SlctDtypeS32(value) => value.walk(visitor),
+            Inst::SlctFtzDtypeF32(value) => value.walk(visitor),
+            Inst::SqrtApproxFtzF32(value) => value.walk(visitor),
+            Inst::SqrtRndFtzF32(value) => value.walk(visitor),
+            Inst::SqrtRndF64(value) => value.walk(visitor),
+            Inst::StAsyncSemScopeSsCompletionMechanismVecType(value) => value.walk(visitor),
+            Inst::StAsyncMmioSemScopeSsType(value) => value.walk(visitor),
+            Inst::StBulkWeakSharedCta(value) => value.walk(visitor),
+            Inst::StWeakSsCopLevelCacheHintVecType(value) => value.walk(visitor),
+            Inst::StWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.walk(visitor),
+            Inst::StVolatileSsVecType(value) => value.walk(visitor),
+            Inst::StRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.walk(visitor),
+            Inst::StReleaseScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.walk(visitor),
+            Inst::StMmioRelaxedSysGlobalType(value) => value.walk(visitor),
+            Inst::StackrestoreType(value) => value.walk(visitor),
+            Inst::StacksaveType(value) => value.walk(visitor),
+            Inst::StmatrixSyncAlignedShapeNumTransSsType(value) => value.walk(visitor),
+            Inst::SubCcType(value) => value.walk(visitor),
+            Inst::SubType(value) => value.walk(visitor),
+            Inst::SubSatS32(value) => value.walk(visitor),
+            Inst::SubRndFtzSatF32(value) => value.walk(visitor),
+            Inst::SubRndFtzF32x2(value) => value.walk(visitor),
+            Inst::SubRndF64(value) => value.walk(visitor),
+            Inst::SubRndFtzSatF16(value) => value.walk(visitor),
+            Inst::SubRndFtzSatF16x2(value) => value.walk(visitor),
+            Inst::SubRndBf16(value) => value.walk(visitor),
+            Inst::SubRndBf16x2(value) => value.walk(visitor),
+            Inst::SubRndSatF32Atype(value) => value.walk(visitor),
+            Inst::SubcCcType(value) => value.walk(visitor),
+            Inst::SuldBGeomCopVecDtypeMode(value) => value.walk(visitor),
+            Inst::SuqQueryB32(value) => value.walk(visitor),
+            Inst::SuredBOpGeomCtypeMode(value) => value.walk(visitor),
+            Inst::SuredPOpGeomCtypeMode(value) => value.walk(visitor),
+            Inst::SustBDimCopVecCtypeMode(value) => value.walk(visitor),
+            Inst::SustPDimVecB32Mode(value) => value.walk(visitor),
+            Inst::SustBAdimCopVecCtypeMode(value) => value.walk(visitor),
+            Inst::SzextModeType(value) => value.walk(visitor),
+            Inst::TanhApproxType(value) => value.walk(visitor),
+            Inst::Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32(value) => value.walk(visitor),
+            Inst::Tcgen05DeallocCtaGroupSyncAlignedB32(value) => value.walk(visitor),
+            Inst::Tcgen05RelinquishAllocPermitCtaGroupSyncAligned(value) => value.walk(visitor),
+            Inst::Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64(value) => value.walk(visitor),
+            Inst::Tcgen05CpCtaGroupShapeMulticastDstSrcFmt(value) => value.walk(visitor),
+            Inst::Tcgen05FenceBeforeThreadSync(value) => value.walk(visitor),
+            Inst::Tcgen05FenceAfterThreadSync(value) => value.walk(visitor),
+            Inst::Tcgen05LdSyncAlignedShape1NumPackB32(value) => value.walk(visitor),
+            Inst::Tcgen05LdSyncAlignedShape2NumPackB32(value) => value.walk(visitor),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32(value) => value.walk(visitor),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32(value) => value.walk(visitor),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopType(value) => value.walk(visitor),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopType(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKind(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKind1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindCollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindI8(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindI81(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindI8CollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKind(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKind1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKindCollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKindI8(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKindI81(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKindI8CollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage1(value) => value.walk(visitor),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage(value) => value.walk(visitor),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1(value) => value.walk(visitor),
+            Inst::Tcgen05ShiftCtaGroupDown(value) => value.walk(visitor),
+            Inst::Tcgen05StSyncAlignedShape1NumUnpackB32(value) => value.walk(visitor),
+            Inst::Tcgen05StSyncAlignedShape2NumUnpackB32(value) => value.walk(visitor),
+            Inst::Tcgen05WaitOperationSyncAligned(value) => value.walk(visitor),
+            Inst::TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned(value) => value.walk(visitor),
+            Inst::TensormapReplaceModeField1SsB1024Type(value) => value.walk(visitor),
+            Inst::TensormapReplaceModeField2SsB1024Type(value) => value.walk(visitor),
+            Inst::TensormapReplaceModeField3SsB1024Type(value) => value.walk(visitor),
+            Inst::TestpOpType(value) => value.walk(visitor),
+            Inst::TexGeomV4DtypeCtype(value) => value.walk(visitor),
+            Inst::TexGeomV4DtypeCtype1(value) => value.walk(visitor),
+            Inst::TexGeomV2F16x2Ctype(value) => value.walk(visitor),
+            Inst::TexGeomV2F16x2Ctype1(value) => value.walk(visitor),
+            Inst::TexBaseGeomV4DtypeCtype(value) => value.walk(visitor),
+            Inst::TexLevelGeomV4DtypeCtype(value) => value.walk(visitor),
+            Inst::TexGradGeomV4DtypeCtype(value) => value.walk(visitor),
+            Inst::TexBaseGeomV2F16x2Ctype(value) => value.walk(visitor),
+            Inst::TexLevelGeomV2F16x2Ctype(value) => value.walk(visitor),
+            Inst::TexGradGeomV2F16x2Ctype(value) => value.walk(visitor),
+            Inst::Tld4Comp2dV4DtypeF32(value) => value.walk(visitor),
+            Inst::Tld4CompGeomV4DtypeF32(value) => value.walk(visitor),
+            Inst::Trap(value) => value.walk(visitor),
+            Inst::TxqTqueryB32(value) => value.walk(visitor),
+            Inst::TxqLevelTlqueryB32(value) => value.walk(visitor),
+            Inst::TxqSqueryB32(value) => value.walk(visitor),
+            Inst::VmadDtypeAtypeBtypeSatScale(value) => value.walk(visitor),
+            Inst::VmadDtypeAtypeBtypePoSatScale(value) => value.walk(visitor),
+            Inst::VaddDtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::VsubDtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::VabsdiffDtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::VminDtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::VmaxDtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::VaddDtypeAtypeBtypeSatOp2(value) => value.walk(visitor),
+            Inst::VsubDtypeAtypeBtypeSatOp2(value) => value.walk(visitor),
+            Inst::VabsdiffDtypeAtypeBtypeSatOp2(value) => value.walk(visitor),
+            Inst::VminDtypeAtypeBtypeSatOp2(value) => value.walk(visitor),
+            Inst::VmaxDtypeAtypeBtypeSatOp2(value) => value.walk(visitor),
+            Inst::VaddDtypeAtypeBtypeSat1(value) => value.walk(visitor),
+            Inst::VsubDtypeAtypeBtypeSat1(value) => value.walk(visitor),
+            Inst::VabsdiffDtypeAtypeBtypeSat1(value) => value.walk(visitor),
+            Inst::VminDtypeAtypeBtypeSat1(value) => value.walk(visitor),
+            Inst::VmaxDtypeAtypeBtypeSat1(value) => value.walk(visitor),
+            Inst::Vadd2DtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::Vsub2DtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::Vavrg2DtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::Vabsdiff2DtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::Vmin2DtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::Vmax2DtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::Vadd2DtypeAtypeBtypeAdd(value) => value.walk(visitor),
+            Inst::Vsub2DtypeAtypeBtypeAdd(value) => value.walk(visitor),
+            Inst::Vavrg2DtypeAtypeBtypeAdd(value) => value.walk(visitor),
+            Inst::Vabsdiff2DtypeAtypeBtypeAdd(value) => value.walk(visitor),
+            Inst::Vmin2DtypeAtypeBtypeAdd(value) => value.walk(visitor),
+            Inst::Vmax2DtypeAtypeBtypeAdd(value) => value.walk(visitor),
+            Inst::Vadd4DtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::Vsub4DtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::Vavrg4DtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::Vabsdiff4DtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::Vmin4DtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::Vmax4DtypeAtypeBtypeSat(value) => value.walk(visitor),
+            Inst::Vadd4DtypeAtypeBtypeAdd(value) => value.walk(visitor),
+            Inst::Vsub4DtypeAtypeBtypeAdd(value) => value.walk(visitor),
+            Inst::Vavrg4DtypeAtypeBtypeAdd(value) => value.walk(visitor),
+            Inst::Vabsdiff4DtypeAtypeBtypeAdd(value) => value.walk(visitor),
+            Inst::Vmin4DtypeAtypeBtypeAdd(value) => value.walk(visitor),
+            Inst::Vmax4DtypeAtypeBtypeAdd(value) => value.walk(visitor),
+            Inst::VoteSyncModePred(value) => value.walk(visitor),
+            Inst::VoteSyncBallotB32(value) => value.walk(visitor),
+            Inst::VoteModePred(value) => value.walk(visitor),
+            Inst::VoteBallotB32(value) => value.walk(visitor),
+            Inst::VsetAtypeBtypeCmp(value) => value.walk(visitor),
+            Inst::VsetAtypeBtypeCmpOp2(value) => value.walk(visitor),
+            Inst::VsetAtypeBtypeCmp1(value) => value.walk(visitor),
+            Inst::Vset2AtypeBtypeCmp(value) => value.walk(visitor),
+            Inst::Vset2AtypeBtypeCmpAdd(value) => value.walk(visitor),
+            Inst::Vset4AtypeBtypeCmp(value) => value.walk(visitor),
+            Inst::Vset4AtypeBtypeCmpAdd(value) => value.walk(visitor),
+            Inst::VshlDtypeAtypeU32SatMode(value) => value.walk(visitor),
+            Inst::VshrDtypeAtypeU32SatMode(value) => value.walk(visitor),
+            Inst::VshlDtypeAtypeU32SatModeOp2(value) => value.walk(visitor),
+            Inst::VshrDtypeAtypeU32SatModeOp2(value) => value.walk(visitor),
+            Inst::VshlDtypeAtypeU32SatMode1(value) => value.walk(visitor),
+            Inst::VshrDtypeAtypeU32SatMode1(value) => value.walk(visitor),
+            Inst::WgmmaCommitGroupSyncAligned(value) => value.walk(visitor),
+            Inst::WgmmaFenceSyncAligned(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc(value) => value.walk(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1(value) => value.walk(visitor),
+            Inst::WgmmaWaitGroupSyncAligned(value) => value.walk(visitor),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype(value) => value.walk(visitor),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype(value) => value.walk(visitor),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype(value) => value.walk(visitor),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype1(value) => value.walk(visitor),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype1(value) => value.walk(visitor),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype1(value) => value.walk(visitor),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype2(value) => value.walk(visitor),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype2(value) => value.walk(visitor),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype2(value) => value.walk(visitor),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype3(value) => value.walk(visitor),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype3(value) => value.walk(visitor),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype3(value) => value.walk(visitor),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype(value) => value.walk(visitor),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype(value) => value.walk(visitor),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype4(value) => value.walk(visitor),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype1(value) => value.walk(visitor),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype1(value) => value.walk(visitor),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype5(value) => value.walk(visitor),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeDtypeCtype(value) => value.walk(visitor),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeS32AtypeBtypeS32Satfinite(value) => value.walk(visitor),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF32(value) => value.walk(visitor),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF321(value) => value.walk(visitor),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeRndF64F64F64F64(value) => value.walk(visitor),
+            Inst::WmmaMmaSyncAlignedRowColShapeS32AtypeBtypeS32Satfinite(value) => value.walk(visitor),
+            Inst::WmmaMmaOpPopcSyncAlignedRowColShapeS32AtypeBtypeS32(value) => value.walk(visitor),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType(value) => value.walk(visitor),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType1(value) => value.walk(visitor),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType2(value) => value.walk(visitor),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType3(value) => value.walk(visitor),
+            Inst::XorType(value) => value.walk(visitor),
+        }
+    }
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+        match self {
+            Inst::AbsType(value) => value.walk_mut(visitor),
+            Inst::AbsFtzF32(value) => value.walk_mut(visitor),
+            Inst::AbsF64(value) => value.walk_mut(visitor),
+            Inst::AbsFtzF16(value) => value.walk_mut(visitor),
+            Inst::AbsFtzF16x2(value) => value.walk_mut(visitor),
+            Inst::AbsBf16(value) => value.walk_mut(visitor),
+            Inst::AbsBf16x2(value) => value.walk_mut(visitor),
+            Inst::ActivemaskB32(value) => value.walk_mut(visitor),
+            Inst::AddCcType(value) => value.walk_mut(visitor),
+            Inst::AddType(value) => value.walk_mut(visitor),
+            Inst::AddSatS32(value) => value.walk_mut(visitor),
+            Inst::AddRndFtzSatF32(value) => value.walk_mut(visitor),
+            Inst::AddRndFtzF32x2(value) => value.walk_mut(visitor),
+            Inst::AddRndF64(value) => value.walk_mut(visitor),
+            Inst::AddRndFtzSatF16(value) => value.walk_mut(visitor),
+            Inst::AddRndFtzSatF16x2(value) => value.walk_mut(visitor),
+            Inst::AddRndBf16(value) => value.walk_mut(visitor),
+            Inst::AddRndBf16x2(value) => value.walk_mut(visitor),
+            Inst::AddRndSatF32Atype(value) => value.walk_mut(visitor),
+            Inst::AddcCcType(value) => value.walk_mut(visitor),
+            Inst::AllocaType(value) => value.walk_mut(visitor),
+            Inst::AndType(value) => value.walk_mut(visitor),
+            Inst::ApplypriorityGlobalLevelEvictionPriority(value) => value.walk_mut(visitor),
+            Inst::AtomSemScopeSpaceOpLevelCacheHintType(value) => value.walk_mut(visitor),
+            Inst::AtomSemScopeSpaceOpType(value) => value.walk_mut(visitor),
+            Inst::AtomSemScopeSpaceCasB16(value) => value.walk_mut(visitor),
+            Inst::AtomSemScopeSpaceCasB128(value) => value.walk_mut(visitor),
+            Inst::AtomSemScopeSpaceExchLevelCacheHintB128(value) => value.walk_mut(visitor),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16(value) => value.walk_mut(visitor),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintF16x2(value) => value.walk_mut(visitor),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16(value) => value.walk_mut(visitor),
+            Inst::AtomSemScopeSpaceAddNoftzLevelCacheHintBf16x2(value) => value.walk_mut(visitor),
+            Inst::AtomSemScopeGlobalAddLevelCacheHintVec32BitF32(value) => value.walk_mut(visitor),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec16BitHalfWordType(value) => value.walk_mut(visitor),
+            Inst::AtomSemScopeGlobalOpNoftzLevelCacheHintVec32BitPackedType(value) => value.walk_mut(visitor),
+            Inst::BarrierCtaSyncAligned(value) => value.walk_mut(visitor),
+            Inst::BarrierCtaArriveAligned(value) => value.walk_mut(visitor),
+            Inst::BarrierCtaRedPopcAlignedU32(value) => value.walk_mut(visitor),
+            Inst::BarrierCtaRedOpAlignedPred(value) => value.walk_mut(visitor),
+            Inst::BarCtaSync(value) => value.walk_mut(visitor),
+            Inst::BarCtaArrive(value) => value.walk_mut(visitor),
+            Inst::BarCtaRedPopcU32(value) => value.walk_mut(visitor),
+            Inst::BarCtaRedOpPred(value) => value.walk_mut(visitor),
+            Inst::BarWarpSync(value) => value.walk_mut(visitor),
+            Inst::BarrierClusterArriveSemAligned(value) => value.walk_mut(visitor),
+            Inst::BarrierClusterWaitAcquireAligned(value) => value.walk_mut(visitor),
+            Inst::BfeType(value) => value.walk_mut(visitor),
+            Inst::BfiType(value) => value.walk_mut(visitor),
+            Inst::BfindType(value) => value.walk_mut(visitor),
+            Inst::BfindShiftamtType(value) => value.walk_mut(visitor),
+            Inst::BmskModeB32(value) => value.walk_mut(visitor),
+            Inst::BraUni(value) => value.walk_mut(visitor),
+            Inst::BraUni1(value) => value.walk_mut(visitor),
+            Inst::BrevType(value) => value.walk_mut(visitor),
+            Inst::Brkpt(value) => value.walk_mut(visitor),
+            Inst::BrxIdxUni(value) => value.walk_mut(visitor),
+            Inst::BrxIdxUni1(value) => value.walk_mut(visitor),
+            Inst::CallUni(value) => value.walk_mut(visitor),
+            Inst::CallUni1(value) => value.walk_mut(visitor),
+            Inst::CallUni2(value) => value.walk_mut(visitor),
+            Inst::CallUni3(value) => value.walk_mut(visitor),
+            Inst::CallUni4(value) => value.walk_mut(visitor),
+            Inst::CallUni5(value) => value.walk_mut(visitor),
+            Inst::CallUni6(value) => value.walk_mut(visitor),
+            Inst::CallUni7(value) => value.walk_mut(visitor),
+            Inst::CallUni8(value) => value.walk_mut(visitor),
+            Inst::ClusterlaunchcontrolQueryCancelIsCanceledPredB128(value) => value.walk_mut(visitor),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidV4B32B128(value) => value.walk_mut(visitor),
+            Inst::ClusterlaunchcontrolQueryCancelGetFirstCtaidDimensionB32B128(value) => value.walk_mut(visitor),
+            Inst::ClusterlaunchcontrolTryCancelAsyncSpaceCompletionMechanismMulticastClusterAllB128(value) => value.walk_mut(visitor),
+            Inst::ClzType(value) => value.walk_mut(visitor),
+            Inst::CnotType(value) => value.walk_mut(visitor),
+            Inst::CopysignType(value) => value.walk_mut(visitor),
+            Inst::CosApproxFtzF32(value) => value.walk_mut(visitor),
+            Inst::CpAsyncBulkCommitGroup(value) => value.walk_mut(visitor),
+            Inst::CpAsyncBulkPrefetchTensorDimL2SrcLoadModeLevelCacheHint(value) => value.walk_mut(visitor),
+            Inst::CpAsyncBulkPrefetchL2SrcLevelCacheHint(value) => value.walk_mut(visitor),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismCtaGroupLevelCacheHint(value) => value.walk_mut(visitor),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismMulticastCtaGroupLevelCacheHint(value) => value.walk_mut(visitor),
+            Inst::CpAsyncBulkTensorDimDstSrcLoadModeCompletionMechanismLevelCacheHint(value) => value.walk_mut(visitor),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHint(value) => value.walk_mut(visitor),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismMulticastLevelCacheHint(value) => value.walk_mut(visitor),
+            Inst::CpAsyncBulkDstSrcCompletionMechanism(value) => value.walk_mut(visitor),
+            Inst::CpAsyncBulkDstSrcCompletionMechanismLevelCacheHintCpMask(value) => value.walk_mut(visitor),
+            Inst::CpAsyncBulkWaitGroupRead(value) => value.walk_mut(visitor),
+            Inst::CpAsyncCommitGroup(value) => value.walk_mut(visitor),
+            Inst::CpAsyncMbarrierArriveNoincStateB64(value) => value.walk_mut(visitor),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.walk_mut(visitor),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize(value) => value.walk_mut(visitor),
+            Inst::CpAsyncCaStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.walk_mut(visitor),
+            Inst::CpAsyncCgStateGlobalLevelCacheHintLevelPrefetchSize1(value) => value.walk_mut(visitor),
+            Inst::CpAsyncWaitGroup(value) => value.walk_mut(visitor),
+            Inst::CpAsyncWaitAll(value) => value.walk_mut(visitor),
+            Inst::CpReduceAsyncBulkTensorDimDstSrcRedopLoadModeCompletionMechanismLevelCacheHint(value) => value.walk_mut(visitor),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismRedopType(value) => value.walk_mut(visitor),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintRedopType(value) => value.walk_mut(visitor),
+            Inst::CpReduceAsyncBulkDstSrcCompletionMechanismLevelCacheHintAddNoftzType(value) => value.walk_mut(visitor),
+            Inst::CreatepolicyRangeGlobalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.walk_mut(visitor),
+            Inst::CreatepolicyFractionalLevelPrimaryPriorityLevelSecondaryPriorityB64(value) => value.walk_mut(visitor),
+            Inst::CreatepolicyCvtL2B64(value) => value.walk_mut(visitor),
+            Inst::CvtPackSatConverttypeAbtype(value) => value.walk_mut(visitor),
+            Inst::CvtPackSatConverttypeAbtypeCtype(value) => value.walk_mut(visitor),
+            Inst::CvtIrndFtzSatDtypeAtype(value) => value.walk_mut(visitor),
+            Inst::CvtFrndFtzSatDtypeAtype(value) => value.walk_mut(visitor),
+            Inst::CvtFrnd2ReluSatfiniteF16F32(value) => value.walk_mut(visitor),
+            Inst::CvtFrnd2ReluSatfiniteF16x2F32(value) => value.walk_mut(visitor),
+            Inst::CvtRsReluSatfiniteF16x2F32(value) => value.walk_mut(visitor),
+            Inst::CvtFrnd2ReluSatfiniteBf16F32(value) => value.walk_mut(visitor),
+            Inst::CvtFrnd2ReluSatfiniteBf16x2F32(value) => value.walk_mut(visitor),
+            Inst::CvtRsReluSatfiniteBf16x2F32(value) => value.walk_mut(visitor),
+            Inst::CvtRnaSatfiniteTf32F32(value) => value.walk_mut(visitor),
+            Inst::CvtFrnd2SatfiniteReluTf32F32(value) => value.walk_mut(visitor),
+            Inst::CvtRnSatfiniteReluF8x2typeF32(value) => value.walk_mut(visitor),
+            Inst::CvtRnSatfiniteReluF8x2typeF16x2(value) => value.walk_mut(visitor),
+            Inst::CvtRnReluF16x2F8x2type(value) => value.walk_mut(visitor),
+            Inst::CvtRsReluSatfiniteF8x4typeF32(value) => value.walk_mut(visitor),
+            Inst::CvtRnSatfiniteReluF4x2typeF32(value) => value.walk_mut(visitor),
+            Inst::CvtRnReluF16x2F4x2type(value) => value.walk_mut(visitor),
+            Inst::CvtRsReluSatfiniteF4x4typeF32(value) => value.walk_mut(visitor),
+            Inst::CvtRnSatfiniteReluF6x2typeF32(value) => value.walk_mut(visitor),
+            Inst::CvtRnReluF16x2F6x2type(value) => value.walk_mut(visitor),
+            Inst::CvtRsReluSatfiniteF6x4typeF32(value) => value.walk_mut(visitor),
+            Inst::CvtFrnd3SatfiniteUe8m0x2F32(value) => value.walk_mut(visitor),
+            Inst::CvtFrnd3SatfiniteUe8m0x2Bf16x2(value) => value.walk_mut(visitor),
+            Inst::CvtRnBf16x2Ue8m0x2(value) => value.walk_mut(visitor),
+            Inst::CvtaSpaceSize(value) => value.walk_mut(visitor),
+            Inst::CvtaToSpaceSize(value) => value.walk_mut(visitor),
+            Inst::DiscardGlobalLevel(value) => value.walk_mut(visitor),
+            Inst::DivType(value) => value.walk_mut(visitor),
+            Inst::DivApproxFtzF32(value) => value.walk_mut(visitor),
+            Inst::DivFullFtzF32(value) => value.walk_mut(visitor),
+            Inst::DivRndFtzF32(value) => value.walk_mut(visitor),
+            Inst::DivRndF64(value) => value.walk_mut(visitor),
+            Inst::Dp2aModeAtypeBtype(value) => value.walk_mut(visitor),
+            Inst::Dp4aAtypeBtype(value) => value.walk_mut(visitor),
+            Inst::ElectSync(value) => value.walk_mut(visitor),
+            Inst::Ex2ApproxFtzF32(value) => value.walk_mut(visitor),
+            Inst::Ex2ApproxAtype(value) => value.walk_mut(visitor),
+            Inst::Ex2ApproxFtzBtype(value) => value.walk_mut(visitor),
+            Inst::Exit(value) => value.walk_mut(visitor),
+            Inst::FmaRndFtzSatF32(value) => value.walk_mut(visitor),
+            Inst::FmaRndFtzF32x2(value) => value.walk_mut(visitor),
+            Inst::FmaRndF64(value) => value.walk_mut(visitor),
+            Inst::FmaRndFtzSatF16(value) => value.walk_mut(visitor),
+            Inst::FmaRndFtzSatF16x2(value) => value.walk_mut(visitor),
+            Inst::FmaRndFtzReluF16(value) => value.walk_mut(visitor),
+            Inst::FmaRndFtzReluF16x2(value) => value.walk_mut(visitor),
+            Inst::FmaRndReluBf16(value) => value.walk_mut(visitor),
+            Inst::FmaRndReluBf16x2(value) => value.walk_mut(visitor),
+            Inst::FmaRndOobReluType(value) => value.walk_mut(visitor),
+            Inst::FmaRndSatF32Abtype(value) => value.walk_mut(visitor),
+            Inst::FnsB32(value) => value.walk_mut(visitor),
+            Inst::GetctarankSpaceType(value) => value.walk_mut(visitor),
+            Inst::GetctarankSharedClusterType(value) => value.walk_mut(visitor),
+            Inst::GetctarankType(value) => value.walk_mut(visitor),
+            Inst::GriddepcontrolAction(value) => value.walk_mut(visitor),
+            Inst::IsspacepSpace(value) => value.walk_mut(visitor),
+            Inst::IstypepType(value) => value.walk_mut(visitor),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeType(value) => value.walk_mut(visitor),
+            Inst::LdGlobalCopNcLevelCacheHintLevelPrefetchSizeVecType(value) => value.walk_mut(visitor),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeType(value) => value.walk_mut(visitor),
+            Inst::LdGlobalNcLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.walk_mut(visitor),
+            Inst::LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType(value) => value.walk_mut(visitor),
+            Inst::LdWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.walk_mut(visitor),
+            Inst::LdVolatileSsLevelPrefetchSizeVecType(value) => value.walk_mut(visitor),
+            Inst::LdRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.walk_mut(visitor),
+            Inst::LdAcquireScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintLevelPrefetchSizeVecType(value) => value.walk_mut(visitor),
+            Inst::LdMmioRelaxedSysGlobalType(value) => value.walk_mut(visitor),
+            Inst::LdmatrixSyncAlignedShapeNumTransSsType(value) => value.walk_mut(visitor),
+            Inst::LdmatrixSyncAlignedM8n16NumSsDstFmtSrcFmt(value) => value.walk_mut(visitor),
+            Inst::LdmatrixSyncAlignedM16n16NumTransSsDstFmtSrcFmt(value) => value.walk_mut(visitor),
+            Inst::LduSsType(value) => value.walk_mut(visitor),
+            Inst::LduSsVecType(value) => value.walk_mut(visitor),
+            Inst::Lg2ApproxFtzF32(value) => value.walk_mut(visitor),
+            Inst::Lop3B32(value) => value.walk_mut(visitor),
+            Inst::Lop3BoolopB32(value) => value.walk_mut(visitor),
+            Inst::MadHiloCcType(value) => value.walk_mut(visitor),
+            Inst::MadModeType(value) => value.walk_mut(visitor),
+            Inst::MadHiSatS32(value) => value.walk_mut(visitor),
+            Inst::MadFtzSatF32(value) => value.walk_mut(visitor),
+            Inst::MadRndFtzSatF32(value) => value.walk_mut(visitor),
+            Inst::MadRndF64(value) => value.walk_mut(visitor),
+            Inst::Mad24ModeType(value) => value.walk_mut(visitor),
+            Inst::Mad24HiSatS32(value) => value.walk_mut(visitor),
+            Inst::MadcHiloCcType(value) => value.walk_mut(visitor),
+            Inst::MapaSpaceType(value) => value.walk_mut(visitor),
+            Inst::MatchAnySyncType(value) => value.walk_mut(visitor),
+            Inst::MatchAllSyncType(value) => value.walk_mut(visitor),
+            Inst::MaxAtype(value) => value.walk_mut(visitor),
+            Inst::MaxReluBtype(value) => value.walk_mut(visitor),
+            Inst::MaxFtzNanXorsignAbsF32(value) => value.walk_mut(visitor),
+            Inst::MaxFtzNanAbsF32(value) => value.walk_mut(visitor),
+            Inst::MaxF64(value) => value.walk_mut(visitor),
+            Inst::MaxFtzNanXorsignAbsF16(value) => value.walk_mut(visitor),
+            Inst::MaxFtzNanXorsignAbsF16x2(value) => value.walk_mut(visitor),
+            Inst::MaxNanXorsignAbsBf16(value) => value.walk_mut(visitor),
+            Inst::MaxNanXorsignAbsBf16x2(value) => value.walk_mut(visitor),
+            Inst::MbarrierArriveSemScopeStateB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierArriveSemScopeSharedClusterB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierArriveExpectTxSemScopeStateB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierArriveExpectTxSemScopeSharedClusterB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierArriveNocompleteReleaseCtaStateB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierArriveDropSemScopeStateB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierArriveDropSemScopeSharedClusterB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierArriveDropExpectTxStateSemScopeB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierArriveDropExpectTxSharedClusterSemScopeB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierArriveDropNocompleteReleaseCtaStateB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierCompleteTxSemScopeSpaceB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierExpectTxSemScopeSpaceB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierInitStateB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierInvalStateB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierPendingCountB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierTestWaitSemScopeStateB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierTestWaitParitySemScopeStateB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierTryWaitSemScopeStateB64(value) => value.walk_mut(visitor),
+            Inst::MbarrierTryWaitParitySemScopeStateB64(value) => value.walk_mut(visitor),
+            Inst::FenceSemScope(value) => value.walk_mut(visitor),
+            Inst::FenceAcquireSyncRestrictSharedClusterCluster(value) => value.walk_mut(visitor),
+            Inst::FenceReleaseSyncRestrictSharedCtaCluster(value) => value.walk_mut(visitor),
+            Inst::FenceOpRestrictReleaseCluster(value) => value.walk_mut(visitor),
+            Inst::FenceProxyProxykind(value) => value.walk_mut(visitor),
+            Inst::FenceProxyToProxykindFromProxykindReleaseScope(value) => value.walk_mut(visitor),
+            Inst::FenceProxyToProxykindFromProxykindAcquireScope(value) => value.walk_mut(visitor),
+            Inst::FenceProxyAsyncGenericAcquireSyncRestrictSharedClusterCluster(value) => value.walk_mut(visitor),
+            Inst::FenceProxyAsyncGenericReleaseSyncRestrictSharedCtaCluster(value) => value.walk_mut(visitor),
+            Inst::MembarLevel(value) => value.walk_mut(visitor),
+            Inst::MembarProxyProxykind(value) => value.walk_mut(visitor),
+            Inst::MinAtype(value) => value.walk_mut(visitor),
+            Inst::MinReluBtype(value) => value.walk_mut(visitor),
+            Inst::MinFtzNanXorsignAbsF32(value) => value.walk_mut(visitor),
+            Inst::MinFtzNanAbsF32(value) => value.walk_mut(visitor),
+            Inst::MinF64(value) => value.walk_mut(visitor),
+            Inst::MinFtzNanXorsignAbsF16(value) => value.walk_mut(visitor),
+            Inst::MinFtzNanXorsignAbsF16x2(value) => value.walk_mut(visitor),
+            Inst::MinNanXorsignAbsBf16(value) => value.walk_mut(visitor),
+            Inst::MinNanXorsignAbsBf16x2(value) => value.walk_mut(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.walk_mut(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColDtypeF16F16Ctype(value) => value.walk_mut(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.walk_mut(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k32RowColF32Bf16Bf16F32(value) => value.walk_mut(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k8RowColF32Tf32Tf32F32(value) => value.walk_mut(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k16RowColF32Tf32Tf32F32(value) => value.walk_mut(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColF32F8typeF8typeF32(value) => value.walk_mut(visitor),
+            Inst::MmaSpOrderedMetadataSyncAlignedM16n8k64RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.walk_mut(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.walk_mut(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k128RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.walk_mut(visitor),
+            Inst::MmaSpvariantSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.walk_mut(visitor),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.walk_mut(visitor),
+            Inst::MmaSpvariantSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedM8n8k4AlayoutBlayoutDtypeF16F16Ctype(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedM16n8k8RowColDtypeF16F16Ctype(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedM16n8k16RowColDtypeF16F16Ctype(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedM16n8k4RowColF32Tf32Tf32F32(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedM16n8k8RowColF32AtypeBtypeF32(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedM16n8k16RowColF32Bf16Bf16F32(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedShapeRowColDtypeF8typeF8typeCtype(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedM16n8k32RowColKindDtypeF8f6f4typeF8f6f4typeCtype(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedM16n8k64RowColKindBlockScaleScaleVecSizeF32E2m1E2m1F32Stype1(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedM16n8k32RowColKindBlockScaleScaleVecSizeF32F8f6f4typeF8f6f4typeF32Stype(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedShapeRowColF64F64F64F64(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS32(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedShapeRowColSatfiniteS32AtypeBtypeS321(value) => value.walk_mut(visitor),
+            Inst::MmaSyncAlignedShapeRowColS32B1B1S32BitopPopc(value) => value.walk_mut(visitor),
+            Inst::MovType(value) => value.walk_mut(visitor),
+            Inst::MovU32(value) => value.walk_mut(visitor),
+            Inst::MovU64(value) => value.walk_mut(visitor),
+            Inst::MovU321(value) => value.walk_mut(visitor),
+            Inst::MovU641(value) => value.walk_mut(visitor),
+            Inst::MovType1(value) => value.walk_mut(visitor),
+            Inst::MovmatrixSyncAlignedShapeTransType(value) => value.walk_mut(visitor),
+            Inst::MulModeType(value) => value.walk_mut(visitor),
+            Inst::MulRndFtzSatF32(value) => value.walk_mut(visitor),
+            Inst::MulRndFtzF32x2(value) => value.walk_mut(visitor),
+            Inst::MulRndF64(value) => value.walk_mut(visitor),
+            Inst::MulRndFtzSatF16(value) => value.walk_mut(visitor),
+            Inst::MulRndFtzSatF16x2(value) => value.walk_mut(visitor),
+            Inst::MulRndBf16(value) => value.walk_mut(visitor),
+            Inst::MulRndBf16x2(value) => value.walk_mut(visitor),
+            Inst::Mul24ModeType(value) => value.walk_mut(visitor),
+            Inst::MultimemLdReduceLdsemScopeSsOpType(value) => value.walk_mut(visitor),
+            Inst::MultimemLdReduceWeakSsOpType(value) => value.walk_mut(visitor),
+            Inst::MultimemStStsemScopeSsType(value) => value.walk_mut(visitor),
+            Inst::MultimemStWeakSsType(value) => value.walk_mut(visitor),
+            Inst::MultimemRedRedsemScopeSsOpType(value) => value.walk_mut(visitor),
+            Inst::MultimemLdReduceLdsemScopeSsOpAccPrecVecType(value) => value.walk_mut(visitor),
+            Inst::MultimemLdReduceWeakSsOpAccPrecVecType(value) => value.walk_mut(visitor),
+            Inst::MultimemStStsemScopeSsVecType(value) => value.walk_mut(visitor),
+            Inst::MultimemStWeakSsVecType(value) => value.walk_mut(visitor),
+            Inst::MultimemRedRedsemScopeSsRedopVecRedtype(value) => value.walk_mut(visitor),
+            Inst::NanosleepU32(value) => value.walk_mut(visitor),
+            Inst::NegType(value) => value.walk_mut(visitor),
+            Inst::NegFtzF32(value) => value.walk_mut(visitor),
+            Inst::NegF64(value) => value.walk_mut(visitor),
+            Inst::NegFtzF16(value) => value.walk_mut(visitor),
+            Inst::NegFtzF16x2(value) => value.walk_mut(visitor),
+            Inst::NegBf16(value) => value.walk_mut(visitor),
+            Inst::NegBf16x2(value) => value.walk_mut(visitor),
+            Inst::NotType(value) => value.walk_mut(visitor),
+            Inst::OrType(value) => value.walk_mut(visitor),
+            Inst::Pmevent(value) => value.walk_mut(visitor),
+            Inst::PmeventMask(value) => value.walk_mut(visitor),
+            Inst::PopcType(value) => value.walk_mut(visitor),
+            Inst::PrefetchSpaceLevel(value) => value.walk_mut(visitor),
+            Inst::PrefetchGlobalLevelEvictionPriority(value) => value.walk_mut(visitor),
+            Inst::PrefetchuL1(value) => value.walk_mut(visitor),
+            Inst::PrefetchTensormapSpaceTensormap(value) => value.walk_mut(visitor),
+            Inst::PrmtB32Mode(value) => value.walk_mut(visitor),
+            Inst::RcpApproxFtzF64(value) => value.walk_mut(visitor),
+            Inst::RcpApproxFtzF32(value) => value.walk_mut(visitor),
+            Inst::RcpRndFtzF32(value) => value.walk_mut(visitor),
+            Inst::RcpRndF64(value) => value.walk_mut(visitor),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType(value) => value.walk_mut(visitor),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType1(value) => value.walk_mut(visitor),
+            Inst::RedAsyncSemScopeSsCompletionMechanismOpType2(value) => value.walk_mut(visitor),
+            Inst::RedAsyncSemScopeSsCompletionMechanismAddType(value) => value.walk_mut(visitor),
+            Inst::RedAsyncMmioSemScopeSsAddType(value) => value.walk_mut(visitor),
+            Inst::RedOpSpaceSemScopeLevelCacheHintType(value) => value.walk_mut(visitor),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16(value) => value.walk_mut(visitor),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintF16x2(value) => value.walk_mut(visitor),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16(value) => value.walk_mut(visitor),
+            Inst::RedAddSpaceSemScopeNoftzLevelCacheHintBf16x2(value) => value.walk_mut(visitor),
+            Inst::RedAddSpaceSemScopeLevelCacheHintVec32BitF32(value) => value.walk_mut(visitor),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec16BitHalfWordType(value) => value.walk_mut(visitor),
+            Inst::RedOpSpaceSemScopeNoftzLevelCacheHintVec32BitPackedType(value) => value.walk_mut(visitor),
+            Inst::ReduxSyncOpType(value) => value.walk_mut(visitor),
+            Inst::ReduxSyncOpB32(value) => value.walk_mut(visitor),
+            Inst::ReduxSyncOpAbsNanF32(value) => value.walk_mut(visitor),
+            Inst::RemType(value) => value.walk_mut(visitor),
+            Inst::RetUni(value) => value.walk_mut(visitor),
+            Inst::RsqrtApproxFtzF64(value) => value.walk_mut(visitor),
+            Inst::RsqrtApproxFtzF32(value) => value.walk_mut(visitor),
+            Inst::RsqrtApproxF64(value) => value.walk_mut(visitor),
+            Inst::SadType(value) => value.walk_mut(visitor),
+            Inst::SelpType(value) => value.walk_mut(visitor),
+            Inst::SetCmpopFtzDtypeStype(value) => value.walk_mut(visitor),
+            Inst::SetCmpopBoolopFtzDtypeStype(value) => value.walk_mut(visitor),
+            Inst::SetCmpopFtzF16Stype(value) => value.walk_mut(visitor),
+            Inst::SetCmpopBoolopFtzF16Stype(value) => value.walk_mut(visitor),
+            Inst::SetCmpopBf16Stype(value) => value.walk_mut(visitor),
+            Inst::SetCmpopBoolopBf16Stype(value) => value.walk_mut(visitor),
+            Inst::SetCmpopFtzDtypeF16(value) => value.walk_mut(visitor),
+            Inst::SetCmpopBoolopFtzDtypeF16(value) => value.walk_mut(visitor),
+            Inst::SetCmpopDtypeBf16(value) => value.walk_mut(visitor),
+            Inst::SetCmpopBoolopDtypeBf16(value) => value.walk_mut(visitor),
+            Inst::SetCmpopFtzDtypeF16x2(value) => value.walk_mut(visitor),
+            Inst::SetCmpopBoolopFtzDtypeF16x2(value) => value.walk_mut(visitor),
+            Inst::SetCmpopDtypeBf16x2(value) => value.walk_mut(visitor),
+            Inst::SetCmpopBoolopDtypeBf16x2(value) => value.walk_mut(visitor),
+            Inst::SetmaxnregActionSyncAlignedU32(value) => value.walk_mut(visitor),
+            Inst::SetpCmpopFtzType(value) => value.walk_mut(visitor),
+            Inst::SetpCmpopBoolopFtzType(value) => value.walk_mut(visitor),
+            Inst::SetpCmpopFtzF16(value) => value.walk_mut(visitor),
+            Inst::SetpCmpopBoolopFtzF16(value) => value.walk_mut(visitor),
+            Inst::SetpCmpopFtzF16x2(value) => value.walk_mut(visitor),
+            Inst::SetpCmpopBoolopFtzF16x2(value) => value.walk_mut(visitor),
+            Inst::SetpCmpopBf16(value) => value.walk_mut(visitor),
+            Inst::SetpCmpopBoolopBf16(value) => value.walk_mut(visitor),
+            Inst::SetpCmpopBf16x2(value) => value.walk_mut(visitor),
+            Inst::SetpCmpopBoolopBf16x2(value) => value.walk_mut(visitor),
+            Inst::ShfLModeB32(value) => value.walk_mut(visitor),
+            Inst::ShfRModeB32(value) => value.walk_mut(visitor),
+            Inst::ShflSyncModeB32(value) => value.walk_mut(visitor),
+            Inst::ShflModeB32(value) => value.walk_mut(visitor),
+            Inst::ShlType(value) => value.walk_mut(visitor),
+            Inst::ShrType(value) => value.walk_mut(visitor),
+            Inst::SinApproxFtzF32(value) => value.walk_mut(visitor),
+            Inst::SlctDtypeS32(value) => value.walk_mut(visitor),
+            Inst::SlctFtzDtypeF32(value) => value.walk_mut(visitor),
+            Inst::SqrtApproxFtzF32(value) => value.walk_mut(visitor),
+            Inst::SqrtRndFtzF32(value) => value.walk_mut(visitor),
+            Inst::SqrtRndF64(value) => value.walk_mut(visitor),
+            Inst::StAsyncSemScopeSsCompletionMechanismVecType(value) => value.walk_mut(visitor),
+            Inst::StAsyncMmioSemScopeSsType(value) => value.walk_mut(visitor),
+            Inst::StBulkWeakSharedCta(value) => value.walk_mut(visitor),
+            Inst::StWeakSsCopLevelCacheHintVecType(value) => value.walk_mut(visitor),
+            Inst::StWeakSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.walk_mut(visitor),
+            Inst::StVolatileSsVecType(value) => value.walk_mut(visitor),
+            Inst::StRelaxedScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.walk_mut(visitor),
+            Inst::StReleaseScopeSsLevel1EvictionPriorityLevel2EvictionPriorityLevelCacheHintVecType(value) => value.walk_mut(visitor),
+            Inst::StMmioRelaxedSysGlobalType(value) => value.walk_mut(visitor),
+            Inst::StackrestoreType(value) => value.walk_mut(visitor),
+            Inst::StacksaveType(value) => value.walk_mut(visitor),
+            Inst::StmatrixSyncAlignedShapeNumTransSsType(value) => value.walk_mut(visitor),
+            Inst::SubCcType(value) => value.walk_mut(visitor),
+            Inst::SubType(value) => value.walk_mut(visitor),
+            Inst::SubSatS32(value) => value.walk_mut(visitor),
+            Inst::SubRndFtzSatF32(value) => value.walk_mut(visitor),
+            Inst::SubRndFtzF32x2(value) => value.walk_mut(visitor),
+            Inst::SubRndF64(value) => value.walk_mut(visitor),
+            Inst::SubRndFtzSatF16(value) => value.walk_mut(visitor),
+            Inst::SubRndFtzSatF16x2(value) => value.walk_mut(visitor),
+            Inst::SubRndBf16(value) => value.walk_mut(visitor),
+            Inst::SubRndBf16x2(value) => value.walk_mut(visitor),
+            Inst::SubRndSatF32Atype(value) => value.walk_mut(visitor),
+            Inst::SubcCcType(value) => value.walk_mut(visitor),
+            Inst::SuldBGeomCopVecDtypeMode(value) => value.walk_mut(visitor),
+            Inst::SuqQueryB32(value) => value.walk_mut(visitor),
+            Inst::SuredBOpGeomCtypeMode(value) => value.walk_mut(visitor),
+            Inst::SuredPOpGeomCtypeMode(value) => value.walk_mut(visitor),
+            Inst::SustBDimCopVecCtypeMode(value) => value.walk_mut(visitor),
+            Inst::SustPDimVecB32Mode(value) => value.walk_mut(visitor),
+            Inst::SustBAdimCopVecCtypeMode(value) => value.walk_mut(visitor),
+            Inst::SzextModeType(value) => value.walk_mut(visitor),
+            Inst::TanhApproxType(value) => value.walk_mut(visitor),
+            Inst::Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32(value) => value.walk_mut(visitor),
+            Inst::Tcgen05DeallocCtaGroupSyncAlignedB32(value) => value.walk_mut(visitor),
+            Inst::Tcgen05RelinquishAllocPermitCtaGroupSyncAligned(value) => value.walk_mut(visitor),
+            Inst::Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64(value) => value.walk_mut(visitor),
+            Inst::Tcgen05CpCtaGroupShapeMulticastDstSrcFmt(value) => value.walk_mut(visitor),
+            Inst::Tcgen05FenceBeforeThreadSync(value) => value.walk_mut(visitor),
+            Inst::Tcgen05FenceAfterThreadSync(value) => value.walk_mut(visitor),
+            Inst::Tcgen05LdSyncAlignedShape1NumPackB32(value) => value.walk_mut(visitor),
+            Inst::Tcgen05LdSyncAlignedShape2NumPackB32(value) => value.walk_mut(visitor),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32(value) => value.walk_mut(visitor),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32(value) => value.walk_mut(visitor),
+            Inst::Tcgen05LdRedSyncAlignedShape3NumRedopType(value) => value.walk_mut(visitor),
+            Inst::Tcgen05LdRedSyncAlignedShape4NumRedopType(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKind(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKind1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindCollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindI8(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindI81(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindI8CollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKind(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKind1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKindCollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKindAshiftCollectorUsage1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKindI8(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKindI81(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKindI8CollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindCollectorUsage1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaWsSpCtaGroup1KindI8CollectorUsage1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaWsCtaGroup1KindCollectorUsage1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage(value) => value.walk_mut(visitor),
+            Inst::Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1(value) => value.walk_mut(visitor),
+            Inst::Tcgen05ShiftCtaGroupDown(value) => value.walk_mut(visitor),
+            Inst::Tcgen05StSyncAlignedShape1NumUnpackB32(value) => value.walk_mut(visitor),
+            Inst::Tcgen05StSyncAlignedShape2NumUnpackB32(value) => value.walk_mut(visitor),
+            Inst::Tcgen05WaitOperationSyncAligned(value) => value.walk_mut(visitor),
+            Inst::TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned(value) => value.walk_mut(visitor),
+            Inst::TensormapReplaceModeField1SsB1024Type(value) => value.walk_mut(visitor),
+            Inst::TensormapReplaceModeField2SsB1024Type(value) => value.walk_mut(visitor),
+            Inst::TensormapReplaceModeField3SsB1024Type(value) => value.walk_mut(visitor),
+            Inst::TestpOpType(value) => value.walk_mut(visitor),
+            Inst::TexGeomV4DtypeCtype(value) => value.walk_mut(visitor),
+            Inst::TexGeomV4DtypeCtype1(value) => value.walk_mut(visitor),
+            Inst::TexGeomV2F16x2Ctype(value) => value.walk_mut(visitor),
+            Inst::TexGeomV2F16x2Ctype1(value) => value.walk_mut(visitor),
+            Inst::TexBaseGeomV4DtypeCtype(value) => value.walk_mut(visitor),
+            Inst::TexLevelGeomV4DtypeCtype(value) => value.walk_mut(visitor),
+            Inst::TexGradGeomV4DtypeCtype(value) => value.walk_mut(visitor),
+            Inst::TexBaseGeomV2F16x2Ctype(value) => value.walk_mut(visitor),
+            Inst::TexLevelGeomV2F16x2Ctype(value) => value.walk_mut(visitor),
+            Inst::TexGradGeomV2F16x2Ctype(value) => value.walk_mut(visitor),
+            Inst::Tld4Comp2dV4DtypeF32(value) => value.walk_mut(visitor),
+            Inst::Tld4CompGeomV4DtypeF32(value) => value.walk_mut(visitor),
+            Inst::Trap(value) => value.walk_mut(visitor),
+            Inst::TxqTqueryB32(value) => value.walk_mut(visitor),
+            Inst::TxqLevelTlqueryB32(value) => value.walk_mut(visitor),
+            Inst::TxqSqueryB32(value) => value.walk_mut(visitor),
+            Inst::VmadDtypeAtypeBtypeSatScale(value) => value.walk_mut(visitor),
+            Inst::VmadDtypeAtypeBtypePoSatScale(value) => value.walk_mut(visitor),
+            Inst::VaddDtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::VsubDtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::VabsdiffDtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::VminDtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::VmaxDtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::VaddDtypeAtypeBtypeSatOp2(value) => value.walk_mut(visitor),
+            Inst::VsubDtypeAtypeBtypeSatOp2(value) => value.walk_mut(visitor),
+            Inst::VabsdiffDtypeAtypeBtypeSatOp2(value) => value.walk_mut(visitor),
+            Inst::VminDtypeAtypeBtypeSatOp2(value) => value.walk_mut(visitor),
+            Inst::VmaxDtypeAtypeBtypeSatOp2(value) => value.walk_mut(visitor),
+            Inst::VaddDtypeAtypeBtypeSat1(value) => value.walk_mut(visitor),
+            Inst::VsubDtypeAtypeBtypeSat1(value) => value.walk_mut(visitor),
+            Inst::VabsdiffDtypeAtypeBtypeSat1(value) => value.walk_mut(visitor),
+            Inst::VminDtypeAtypeBtypeSat1(value) => value.walk_mut(visitor),
+            Inst::VmaxDtypeAtypeBtypeSat1(value) => value.walk_mut(visitor),
+            Inst::Vadd2DtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::Vsub2DtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::Vavrg2DtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::Vabsdiff2DtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::Vmin2DtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::Vmax2DtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::Vadd2DtypeAtypeBtypeAdd(value) => value.walk_mut(visitor),
+            Inst::Vsub2DtypeAtypeBtypeAdd(value) => value.walk_mut(visitor),
+            Inst::Vavrg2DtypeAtypeBtypeAdd(value) => value.walk_mut(visitor),
+            Inst::Vabsdiff2DtypeAtypeBtypeAdd(value) => value.walk_mut(visitor),
+            Inst::Vmin2DtypeAtypeBtypeAdd(value) => value.walk_mut(visitor),
+            Inst::Vmax2DtypeAtypeBtypeAdd(value) => value.walk_mut(visitor),
+            Inst::Vadd4DtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::Vsub4DtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::Vavrg4DtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::Vabsdiff4DtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::Vmin4DtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::Vmax4DtypeAtypeBtypeSat(value) => value.walk_mut(visitor),
+            Inst::Vadd4DtypeAtypeBtypeAdd(value) => value.walk_mut(visitor),
+            Inst::Vsub4DtypeAtypeBtypeAdd(value) => value.walk_mut(visitor),
+            Inst::Vavrg4DtypeAtypeBtypeAdd(value) => value.walk_mut(visitor),
+            Inst::Vabsdiff4DtypeAtypeBtypeAdd(value) => value.walk_mut(visitor),
+            Inst::Vmin4DtypeAtypeBtypeAdd(value) => value.walk_mut(visitor),
+            Inst::Vmax4DtypeAtypeBtypeAdd(value) => value.walk_mut(visitor),
+            Inst::VoteSyncModePred(value) => value.walk_mut(visitor),
+            Inst::VoteSyncBallotB32(value) => value.walk_mut(visitor),
+            Inst::VoteModePred(value) => value.walk_mut(visitor),
+            Inst::VoteBallotB32(value) => value.walk_mut(visitor),
+            Inst::VsetAtypeBtypeCmp(value) => value.walk_mut(visitor),
+            Inst::VsetAtypeBtypeCmpOp2(value) => value.walk_mut(visitor),
+            Inst::VsetAtypeBtypeCmp1(value) => value.walk_mut(visitor),
+            Inst::Vset2AtypeBtypeCmp(value) => value.walk_mut(visitor),
+            Inst::Vset2AtypeBtypeCmpAdd(value) => value.walk_mut(visitor),
+            Inst::Vset4AtypeBtypeCmp(value) => value.walk_mut(visitor),
+            Inst::Vset4AtypeBtypeCmpAdd(value) => value.walk_mut(visitor),
+            Inst::VshlDtypeAtypeU32SatMode(value) => value.walk_mut(visitor),
+            Inst::VshrDtypeAtypeU32SatMode(value) => value.walk_mut(visitor),
+            Inst::VshlDtypeAtypeU32SatModeOp2(value) => value.walk_mut(visitor),
+            Inst::VshrDtypeAtypeU32SatModeOp2(value) => value.walk_mut(visitor),
+            Inst::VshlDtypeAtypeU32SatMode1(value) => value.walk_mut(visitor),
+            Inst::VshrDtypeAtypeU32SatMode1(value) => value.walk_mut(visitor),
+            Inst::WgmmaCommitGroupSyncAligned(value) => value.walk_mut(visitor),
+            Inst::WgmmaFenceSyncAligned(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc(value) => value.walk_mut(visitor),
+            Inst::WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1(value) => value.walk_mut(visitor),
+            Inst::WgmmaWaitGroupSyncAligned(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype1(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype1(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype1(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype2(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype2(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype2(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadASyncAlignedLayoutShapeSsAtype3(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadBSyncAlignedLayoutShapeSsBtype3(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype3(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype4(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadASyncAlignedRowShapeSsAtype1(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadBSyncAlignedColShapeSsBtype1(value) => value.walk_mut(visitor),
+            Inst::WmmaLoadCSyncAlignedLayoutShapeSsCtype5(value) => value.walk_mut(visitor),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeDtypeCtype(value) => value.walk_mut(visitor),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeS32AtypeBtypeS32Satfinite(value) => value.walk_mut(visitor),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF32(value) => value.walk_mut(visitor),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeF32AtypeBtypeF321(value) => value.walk_mut(visitor),
+            Inst::WmmaMmaSyncAlignedAlayoutBlayoutShapeRndF64F64F64F64(value) => value.walk_mut(visitor),
+            Inst::WmmaMmaSyncAlignedRowColShapeS32AtypeBtypeS32Satfinite(value) => value.walk_mut(visitor),
+            Inst::WmmaMmaOpPopcSyncAlignedRowColShapeS32AtypeBtypeS32(value) => value.walk_mut(visitor),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType(value) => value.walk_mut(visitor),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType1(value) => value.walk_mut(visitor),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType2(value) => value.walk_mut(visitor),
+            Inst::WmmaStoreDSyncAlignedLayoutShapeSsType3(value) => value.walk_mut(visitor),
+            Inst::XorType(value) => value.walk_mut(visitor),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Original PTX specification:
+//!
+//! movmatrix.sync.aligned.shape.trans.type d, a;
+//! .shape  = {.m8n8};
+//! .type   = {.b16};
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::movmatrix::section_0::*;
+
+    impl Walk for MovmatrixSyncAlignedShapeTransType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Original PTX specification:
+//!
+//! // Integer type:
+//! multimem.ld_reduce{.ldsem}{.scope}{.ss}.op.type      d, [a];
+//! multimem.ld_reduce.weak{.ss}.op.type                 d, [a];
+//! multimem.st{.stsem}{.scope}{.ss}.type                [a], b;
+//! multimem.st.weak{.ss}.type                           [a], b;
+//! multimem.red{.redsem}{.scope}{.ss}.op.type           [a], b;
+//! .ss =       { .global };
+//! .ldsem =    { .relaxed, .acquire };
+//! .stsem =    { .relaxed, .release };
+//! .redsem =   { .relaxed, .release };
+//! .scope =    { .cta, .cluster, .gpu, .sys };
+//! .op  =      { .min, .max, .add, .and, .or, .xor };
+//! .type =     { .b32, .b64,  .u32, .u64, .s32, .s64 };
+//! ------------------------------------------------------------------
+//! // Floating point type:
+//! multimem.ld_reduce{.ldsem}{.scope}{.ss}.op{.acc_prec}{.vec}.type    d, [a];
+//! multimem.ld_reduce.weak{.ss}.op{.acc_prec}{.vec}.type               d, [a];
+//! multimem.st{.stsem}{.scope}{.ss}{.vec}.type                         [a], b;
+//! multimem.st.weak{.ss}{.vec}.type                                    [a], b;
+//! multimem.red{.redsem}{.scope}{.ss}.redop{.vec}.redtype              [a], b;
+//! .ss =       { .global };
+//! .ldsem =    { .relaxed, .acquire };
+//! .stsem =    { .relaxed, .release };
+//! .redsem =   { .relaxed, .release };
+//! .scope =    { .cta, .cluster, .gpu, .sys };
+//! .op  =      { .min, .max, .add };
+//! .redop  =   { .add };
+//! .acc_prec = { .acc::f32, .acc::f16 };
+//! .vec =      { .v2, .v4, .v8 };
+//! .type=      { .f16, .f16x2, .bf16, .bf16x2, .f32, .f64, .e5m2, .e5m2x2, .e5m2x4, .e4m3, .e4m3x2, .e4m3x4 };
+//! .redtype =  { .f16, .f16x2, .bf16, .bf16x2, .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::multimem_ld_reduce::section_0::*;
+
+    impl Walk for MultimemLdReduceLdsemScopeSsOpType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for MultimemLdReduceWeakSsOpType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for MultimemStStsemScopeSsType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for MultimemStWeakSsType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for MultimemRedRedsemScopeSsOpType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::multimem_ld_reduce::section_1::*;
+
+    impl Walk for MultimemLdReduceLdsemScopeSsOpAccPrecVecType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for MultimemLdReduceWeakSsOpAccPrecVecType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for MultimemStStsemScopeSsVecType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for MultimemStWeakSsVecType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+
+    impl Walk for MultimemRedRedsemScopeSsRedopVecRedtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+}
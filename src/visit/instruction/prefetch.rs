@@ -0,0 +1,55 @@
+//! Original PTX specification:
+//!
+//! prefetch{.space}.level                    [a];   // prefetch to data cache
+//! prefetch.global.level::eviction_priority  [a];   // prefetch to data cache
+//! prefetchu.L1  [a];             // prefetch to uniform cache
+//! prefetch{.tensormap_space}.tensormap [a];  // prefetch the tensormap
+//! .space =                    { .global, .local };
+//! .level =                    { .L1, .L2 };
+//! .level::eviction_priority = { .L2::evict_last, .L2::evict_normal };
+//! .tensormap_space =          { .const, .param };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::prefetch::section_0::*;
+
+    impl Walk for PrefetchSpaceLevel {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for PrefetchGlobalLevelEvictionPriority {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for PrefetchuL1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for PrefetchTensormapSpaceTensormap {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+}
@@ -0,0 +1,135 @@
+//! Original PTX specification:
+//!
+//! // Increment and Decrement reductions
+//! red.async.sem.scope{.ss}.completion_mechanism.op.type [a], b, [mbar];
+//! .sem  =                 { .relaxed };
+//! .scope =                { .cluster };
+//! .ss   =                 { .shared::cluster };
+//! .op   =                 { .inc, .dec };
+//! .type =                 { .u32 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ------------------------------------------------------------------
+//! // MIN and MAX reductions
+//! red.async.sem.scope{.ss}.completion_mechanism.op.type [a], b, [mbar];
+//! .sem  = { .relaxed };
+//! .scope = { .cluster };
+//! .ss   = { .shared::cluster };
+//! .op   = { .min, .max };
+//! .type = { .u32, .s32 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ------------------------------------------------------------------
+//! // Bitwise AND, OR and XOR reductions
+//! red.async.sem.scope{.ss}.completion_mechanism.op.type [a], b, [mbar];
+//! .sem  = { .relaxed };
+//! .scope = { .cluster };
+//! .ss   = { .shared::cluster };
+//! .op   = { .and, .or, .xor };
+//! .type = { .b32 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ------------------------------------------------------------------
+//! // ADD reductions
+//! red.async.sem.scope{.ss}.completion_mechanism.add.type [a], b, [mbar];
+//! .sem  = { .relaxed };
+//! .scope = { .cluster };
+//! .ss   = { .shared::cluster };
+//! .type = { .u32, .s32, .u64 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! red.async{.mmio}.sem.scope{.ss}.add.type [a], b;
+//! .sem  = { .release };
+//! .scope = { .gpu, .cluster };
+//! .ss   = { .global };
+//! .type = { .u32, .s32, .u64, .s64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_0::*;
+
+    impl Walk for RedAsyncSemScopeSsCompletionMechanismOpType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_address_operand(&self.mbar);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_address_operand_mut(&mut self.mbar);
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_1::*;
+
+    impl Walk for RedAsyncSemScopeSsCompletionMechanismOpType1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_address_operand(&self.mbar);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_address_operand_mut(&mut self.mbar);
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_2::*;
+
+    impl Walk for RedAsyncSemScopeSsCompletionMechanismOpType2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_address_operand(&self.mbar);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_address_operand_mut(&mut self.mbar);
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_3::*;
+
+    impl Walk for RedAsyncSemScopeSsCompletionMechanismAddType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_address_operand(&self.mbar);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_address_operand_mut(&mut self.mbar);
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::red_async::section_4::*;
+
+    impl Walk for RedAsyncMmioSemScopeSsAddType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//! Original PTX specification:
+//!
+//! redux.sync.op.type dst, src, membermask;
+//! .op   = {.add, .min, .max};
+//! .type = {.u32, .s32};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! redux.sync.op.b32 dst, src, membermask;
+//! .op   = {.and, .or, .xor};
+//! ----------------------------------------------------
+//! // Alternate floating point type:
+//! redux.sync.op{.abs}{.NaN}.f32 dst, src, membermask;
+//! .op   = { .min, .max };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::redux_sync::section_0::*;
+
+    impl Walk for ReduxSyncOpType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.dst);
+            visitor.visit_general_operand(&self.src);
+            visitor.visit_general_operand(&self.membermask);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.dst);
+            visitor.visit_general_operand_mut(&mut self.src);
+            visitor.visit_general_operand_mut(&mut self.membermask);
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::redux_sync::section_1::*;
+
+    impl Walk for ReduxSyncOpB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.dst);
+            visitor.visit_general_operand(&self.src);
+            visitor.visit_general_operand(&self.membermask);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.dst);
+            visitor.visit_general_operand_mut(&mut self.src);
+            visitor.visit_general_operand_mut(&mut self.membermask);
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::redux_sync::section_2::*;
+
+    impl Walk for ReduxSyncOpAbsNanF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.dst);
+            visitor.visit_general_operand(&self.src);
+            visitor.visit_general_operand(&self.membermask);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.dst);
+            visitor.visit_general_operand_mut(&mut self.src);
+            visitor.visit_general_operand_mut(&mut self.membermask);
+        }
+    }
+}
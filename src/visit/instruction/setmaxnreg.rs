@@ -0,0 +1,22 @@
+//! Original PTX specification:
+//!
+//! setmaxnreg.action.sync.aligned.u32 imm-reg-count;
+//! .action = { .inc, .dec };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::setmaxnreg::section_0::*;
+
+    impl Walk for SetmaxnregActionSyncAlignedU32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.imm_reg_count);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.imm_reg_count);
+        }
+    }
+}
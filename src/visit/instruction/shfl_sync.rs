@@ -0,0 +1,36 @@
+//! Original PTX specification:
+//!
+//! shfl.sync.mode.b32  d{|p}, a, b, c, membermask;
+//! .mode = { .up, .down, .bfly, .idx };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::shfl_sync::section_0::*;
+
+    impl Walk for ShflSyncModeB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_general_operand(&self.c);
+            visitor.visit_general_operand(&self.membermask);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_general_operand_mut(&mut self.c);
+            visitor.visit_general_operand_mut(&mut self.membermask);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+//! Original PTX specification:
+//!
+//! st.async{.sem}{.scope}{.ss}{.completion_mechanism}{.vec}.type [a], b, [mbar];
+//! .sem  =                 { .weak };
+//! .scope =                { .cluster };
+//! .ss   =                 { .shared::cluster };
+//! .type =                 { .b32, .b64,
+//! .u32, .u64,
+//! .s32, .s64,
+//! .f32, .f64 };
+//! .vec  =                 { .v2, .v4 };
+//! .completion_mechanism = { .mbarrier::complete_tx::bytes };
+//! ---------------------------------------------------------
+//! st.async{.mmio}.sem.scope{.ss}.type [a], b;
+//! .sem =                  { .release };
+//! .scope =                { .gpu, .sys };
+//! .ss =                   { .global };
+//! .type =                 { .b8, .b16, .b32, .b64,
+//! .u8, .u16, .u32, .u64,
+//! .s8, .s16, .s32, .s64,
+//! .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::st_async::section_0::*;
+
+    impl Walk for StAsyncSemScopeSsCompletionMechanismVecType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+            visitor.visit_address_operand(&self.mbar);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+            visitor.visit_address_operand_mut(&mut self.mbar);
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::st_async::section_1::*;
+
+    impl Walk for StAsyncMmioSemScopeSsType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! Original PTX specification:
+//!
+//! st.bulk{.weak}{.shared::cta}  [a], size, initval; // initval must be zero
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::st_bulk::section_0::*;
+
+    impl Walk for StBulkWeakSharedCta {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.size);
+            visitor.visit_general_operand(&self.initval);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.size);
+            visitor.visit_general_operand_mut(&mut self.initval);
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! Original PTX specification:
+//!
+//! stackrestore.type  a;
+//! .type = { .u32, .u64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::stackrestore::section_0::*;
+
+    impl Walk for StackrestoreType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+}
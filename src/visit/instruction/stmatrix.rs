@@ -0,0 +1,27 @@
+//! Original PTX specification:
+//!
+//! stmatrix.sync.aligned.shape.num{.trans}{.ss}.type [p], r;
+//! .shape  = {.m8n8, .m16n8};
+//! .num    = {.x1, .x2, .x4};
+//! .ss     = {.shared, .shared::cta};
+//! .type   = {.b16, .b8};
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::stmatrix::section_0::*;
+
+    impl Walk for StmatrixSyncAlignedShapeNumTransSsType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.p);
+            visitor.visit_general_operand(&self.r);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.r);
+        }
+    }
+}
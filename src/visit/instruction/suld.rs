@@ -0,0 +1,33 @@
+//! Original PTX specification:
+//!
+//! suld.b.geom{.cop}.vec.dtype{.mode}  d, [a, b];  // unformatted
+//!
+//! .geom  = { .1d, .2d, .3d, .a1d, .a2d };
+//! .cop   = { .ca, .cg, .cs, .cv };               // cache operation
+//! .vec   = { none, .v2, .v4 };
+//! .dtype = { .b8 , .b16, .b32, .b64 };
+//! .mode = { .trap, .clamp, .zero };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::suld::section_0::*;
+
+    impl Walk for SuldBGeomCopVecDtypeMode {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            for operand in &self.a.operands {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            for operand in &mut self.a.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
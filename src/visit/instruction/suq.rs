@@ -0,0 +1,26 @@
+//! Original PTX specification:
+//!
+//! suq.query.b32   d, [a];
+//! .query = { .width, .height, .depth,
+//! .channel_data_type, .channel_order,
+//! .array_size, .memory_layout };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::suq::section_0::*;
+
+    impl Walk for SuqQueryB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+//! Original PTX specification:
+//!
+//! sured.b.op.geom.ctype.mode [a,b],c; // byte addressing
+//! .op    = { .add, .min, .max, .and, .or };
+//! .geom  = { .1d, .2d, .3d };
+//! .ctype = { .u32, .u64, .s32, .b32, .s64 };  // for sured.b
+//! .mode  = { .trap, .clamp, .zero };
+//! ----------------------------------------------------
+//! sured.p.op.geom.ctype.mode [a,b],c; // sample addressing
+//! .op    = { .add, .min, .max, .and, .or };
+//! .geom  = { .1d, .2d, .3d };
+//! .ctype = { .b32, .b64 };                    // for sured.p
+//! .mode  = { .trap, .clamp, .zero };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::sured::section_0::*;
+
+    impl Walk for SuredBOpGeomCtypeMode {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            for operand in &self.a.operands {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.c);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            for operand in &mut self.a.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.c);
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::sured::section_1::*;
+
+    impl Walk for SuredPOpGeomCtypeMode {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            for operand in &self.a.operands {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.c);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            for operand in &mut self.a.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.c);
+        }
+    }
+}
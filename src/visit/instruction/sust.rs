@@ -0,0 +1,65 @@
+//! Original PTX specification:
+//!
+//! sust.b.dim{.cop}.vec.ctype{.mode} [a, b], c;  // unformatted
+//! sust.p.dim.vec.b32{.mode}       [a, b], c;  // formatted
+//! sust.b.adim{.cop}.vec.ctype{.mode}   [a, b], c;  // unformatted
+//! .cop   = { .wb, .cg, .cs, .wt };                     // cache operation
+//! .vec   = { none, .v2, .v4 };
+//! .ctype = { .b8 , .b16, .b32, .b64 };
+//! .mode  = { .trap, .clamp, .zero };
+//! .dim   = { .1d, .2d, .3d };
+//! .adim  = { .a1d, .a2d };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::sust::section_0::*;
+
+    impl Walk for SustBDimCopVecCtypeMode {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            for operand in &self.a.operands {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.c);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            for operand in &mut self.a.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.c);
+        }
+    }
+
+    impl Walk for SustPDimVecB32Mode {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            for operand in &self.a.operands {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.c);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            for operand in &mut self.a.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.c);
+        }
+    }
+
+    impl Walk for SustBAdimCopVecCtypeMode {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            for operand in &self.a.operands {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.c);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            for operand in &mut self.a.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.c);
+        }
+    }
+}
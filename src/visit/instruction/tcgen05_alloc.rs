@@ -0,0 +1,42 @@
+//! Original PTX specification:
+//!
+//! tcgen05.alloc.cta_group.sync.aligned{.shared::cta}.b32  [dst], nCols;
+//! tcgen05.dealloc.cta_group.sync.aligned.b32              taddr, nCols;
+//! tcgen05.relinquish_alloc_permit.cta_group.sync.aligned;
+//! .cta_group = { .cta_group::1, .cta_group::2 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_alloc::section_0::*;
+
+    impl Walk for Tcgen05AllocCtaGroupSyncAlignedSharedCtaB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dst);
+            visitor.visit_general_operand(&self.ncols);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dst);
+            visitor.visit_general_operand_mut(&mut self.ncols);
+        }
+    }
+
+    impl Walk for Tcgen05DeallocCtaGroupSyncAlignedB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.taddr);
+            visitor.visit_general_operand(&self.ncols);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.taddr);
+            visitor.visit_general_operand_mut(&mut self.ncols);
+        }
+    }
+
+    impl Walk for Tcgen05RelinquishAllocPermitCtaGroupSyncAligned {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+}
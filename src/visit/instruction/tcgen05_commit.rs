@@ -0,0 +1,31 @@
+//! Original PTX specification:
+//!
+//! tcgen05.commit.cta_group.completion_mechanism{.shared::cluster}{.multicast}.b64
+//! [mbar] {, ctaMask};
+//! .completion_mechanism = { .mbarrier::arrive::one };
+//! .cta_group            = { .cta_group::1, .cta_group::2 };
+//! .multicast            = { .multicast::cluster };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_commit::section_0::*;
+
+    impl Walk for Tcgen05CommitCtaGroupCompletionMechanismSharedClusterMulticastB64 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.mbar);
+            if let Some(operand) = &self.ctamask {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.mbar);
+            if let Some(operand) = &mut self.ctamask {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
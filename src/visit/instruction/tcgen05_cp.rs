@@ -0,0 +1,27 @@
+//! Original PTX specification:
+//!
+//! tcgen05.cp.cta_group.shape{.multicast}{.dst_src_fmt} [taddr], s-desc;
+//! .cta_group = { .cta_group::1, .cta_group::2 };
+//! .dst_src_fmt   = { .b8x16.b6x16_p32, .b8x16.b4x16_p64 };
+//! .shape     = { .128x256b, .4x256b, .128x128b, .64x128b**, .32x128b*** };
+//! .multicast = { .warpx2::02_13** , .warpx2::01_23**, .warpx4*** };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_cp::section_0::*;
+
+    impl Walk for Tcgen05CpCtaGroupShapeMulticastDstSrcFmt {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.taddr);
+            visitor.visit_general_operand(&self.s_desc);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.taddr);
+            visitor.visit_general_operand_mut(&mut self.s_desc);
+        }
+    }
+}
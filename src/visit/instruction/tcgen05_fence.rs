@@ -0,0 +1,23 @@
+//! Original PTX specification:
+//!
+//! tcgen05.fence::before_thread_sync ;
+//! tcgen05.fence::after_thread_sync  ;
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_fence::section_0::*;
+
+    impl Walk for Tcgen05FenceBeforeThreadSync {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+
+    impl Walk for Tcgen05FenceAfterThreadSync {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+}
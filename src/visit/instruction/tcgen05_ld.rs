@@ -0,0 +1,108 @@
+//! Original PTX specification:
+//!
+//! // Base load instruction:
+//! tcgen05.ld.sync.aligned.shape1.num{.pack}.b32    r, [taddr];
+//! tcgen05.ld.sync.aligned.shape2.num{.pack}.b32    r, [taddr], immHalfSplitoff;
+//! .shape1 = { .16x64b, .16x128b, .16x256b, .32x32b };
+//! .shape2 = { .16x32bx2 };
+//! .num    = { .x1, .x2, .x4, .x8, .x16, .x32, .x64, .x128 };
+//! .pack   = { .pack::16b };
+//! // Floating point type load along with reduction :
+//! tcgen05.ld.red.sync.aligned.shape3.num.redOp{.abs}{.NaN}.f32 r, redval, [taddr];
+//! tcgen05.ld.red.sync.aligned.shape4.num.redOp{.abs}{.NaN}.f32 r, redval, [taddr], immHalfSplitoff;
+//! // Integer type load along with reduction :
+//! tcgen05.ld.red.sync.aligned.shape3.num.redOp.type r, redval, [taddr];
+//! tcgen05.ld.red.sync.aligned.shape4.num.redOp.type r, redval, [taddr], immHalfSplitoff;
+//! .shape3 = { .32x32b   };
+//! .shape4 = { .16x32bx2 };
+//! .redOp  = { .min, .max };
+//! .type   = { .u32, .s32 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_ld::section_0::*;
+
+    impl Walk for Tcgen05LdSyncAlignedShape1NumPackB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.taddr);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.taddr);
+        }
+    }
+
+    impl Walk for Tcgen05LdSyncAlignedShape2NumPackB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.taddr);
+            visitor.visit_general_operand(&self.immhalfsplitoff);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.taddr);
+            visitor.visit_general_operand_mut(&mut self.immhalfsplitoff);
+        }
+    }
+
+    impl Walk for Tcgen05LdRedSyncAlignedShape3NumRedopAbsNanF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_general_operand(&self.redval);
+            visitor.visit_address_operand(&self.taddr);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_general_operand_mut(&mut self.redval);
+            visitor.visit_address_operand_mut(&mut self.taddr);
+        }
+    }
+
+    impl Walk for Tcgen05LdRedSyncAlignedShape4NumRedopAbsNanF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_general_operand(&self.redval);
+            visitor.visit_address_operand(&self.taddr);
+            visitor.visit_general_operand(&self.immhalfsplitoff);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_general_operand_mut(&mut self.redval);
+            visitor.visit_address_operand_mut(&mut self.taddr);
+            visitor.visit_general_operand_mut(&mut self.immhalfsplitoff);
+        }
+    }
+
+    impl Walk for Tcgen05LdRedSyncAlignedShape3NumRedopType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_general_operand(&self.redval);
+            visitor.visit_address_operand(&self.taddr);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_general_operand_mut(&mut self.redval);
+            visitor.visit_address_operand_mut(&mut self.taddr);
+        }
+    }
+
+    impl Walk for Tcgen05LdRedSyncAlignedShape4NumRedopType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_general_operand(&self.redval);
+            visitor.visit_address_operand(&self.taddr);
+            visitor.visit_general_operand(&self.immhalfsplitoff);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_general_operand_mut(&mut self.redval);
+            visitor.visit_address_operand_mut(&mut self.taddr);
+            visitor.visit_general_operand_mut(&mut self.immhalfsplitoff);
+        }
+    }
+}
@@ -0,0 +1,444 @@
+//! Original PTX specification:
+//!
+//! // 1. Floating-point type without block scaling:
+//! tcgen05.mma.cta_group.kind   [d-tmem],  a-desc,  b-desc, idesc
+//! {, disable-output-lane }, enable-input-d {, scale-input-d};
+//! tcgen05.mma.cta_group.kind   [d-tmem], [a-tmem], b-desc, idesc
+//! {, disable-output-lane }, enable-input-d {, scale-input-d};
+//! .kind      = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! .cta_group = { .cta_group::1, .cta_group::2 };
+//! ------------------------------------------------------------------
+//! // 2. Floating-point type with block scaling:
+//! tcgen05.mma.cta_group.kind.block_scale{.scale_vectorsize}
+//! [d-tmem],  a-desc,  b-desc, idesc,
+//! [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! tcgen05.mma.cta_group.kind.block_scale{.scale_vectorsize}
+//! [d-tmem], [a-tmem], b-desc, idesc,
+//! [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! .kind = { .kind::mxf8f6f4, .kind::mxf4, .kind::mxf4nvf4 };
+//! .cta_group      = { .cta_group::1,   .cta_group::2 };
+//! .scale_vectorsize = { .scale_vec::1X, .scale_vec::2X, .scale_vec::4X, .block16, .block32 };
+//! ------------------------------------------------------------------
+//! // 3. Convolution MMA for floating-point type without block scaling:
+//! tcgen05.mma.cta_group.kind.collector_usage [d-tmem],  a-desc,  b-desc, idesc
+//! {, disable-output-lane }, enable-input-d {, scale-input-d};
+//! tcgen05.mma.cta_group.kind{.ashift}.collector_usage [d-tmem], [a-tmem], b-desc, idesc
+//! {, disable-output-lane }, enable-input-d {, scale-input-d};
+//! tcgen05.mma.cta_group.kind.ashift{.collector_usage} [d-tmem], [a-tmem], b-desc, idesc
+//! {, disable-output-lane }, enable-input-d {, scale-input-d};
+//! .kind      = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! .cta_group = { .cta_group::1,   .cta_group::2 };
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+//! ------------------------------------------------------------------
+//! // 4. Activation Stationary MMA for floating-point type with block scaling:
+//! tcgen05.mma.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage
+//! [d-tmem],  a-desc,  b-desc, idesc,
+//! [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! tcgen05.mma.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage
+//! [d-tmem], [a-tmem], b-desc, idesc,
+//! [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! .cta_group       = { .cta_group::1,   .cta_group::2 };
+//! .scale_vectorsize  = { .scale_vec::1X, .scale_vec::2X, .scale_vec::4X, .block16, .block32 };
+//! .kind            = { .kind::mxf8f6f4, .kind::mxf4, .kind::mxf4nvf4 };
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+//! ------------------------------------------------------------------
+//! // 5. Integer type:
+//! tcgen05.mma.cta_group.kind::i8  [d-tmem],  a-desc,  b-desc, idesc
+//! {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.cta_group.kind::i8  [d-tmem], [a-tmem], b-desc, idesc
+//! {, disable-output-lane }, enable-input-d;
+//! .cta_group = { .cta_group::1,   .cta_group::2  };
+//! ------------------------------------------------------------------
+//! // 6. Convolution MMA for integer type:
+//! tcgen05.mma.cta_group.kind::i8.collector_usage          [d-tmem],  a-desc,  b-desc, idesc
+//! {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.cta_group.kind::i8.ashift{.collector_usage} [d-tmem], [a-tmem], b-desc, idesc
+//! {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.cta_group.kind::i8{.ashift}.collector_usage [d-tmem], [a-tmem], b-desc, idesc
+//! {, disable-output-lane }, enable-input-d;
+//! .cta_group       = { .cta_group::1,   .cta_group::2  };
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma::section_0::*;
+
+    impl Walk for Tcgen05MmaCtaGroupKind {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.scale_input_d {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.scale_input_d {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for Tcgen05MmaCtaGroupKind1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.scale_input_d {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.scale_input_d {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma::section_1::*;
+
+    impl Walk for Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            visitor.visit_address_operand(&self.scale_a_tmem);
+            visitor.visit_address_operand(&self.scale_b_tmem);
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            visitor.visit_address_operand_mut(&mut self.scale_a_tmem);
+            visitor.visit_address_operand_mut(&mut self.scale_b_tmem);
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+
+    impl Walk for Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsize1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            visitor.visit_address_operand(&self.scale_a_tmem);
+            visitor.visit_address_operand(&self.scale_b_tmem);
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            visitor.visit_address_operand_mut(&mut self.scale_a_tmem);
+            visitor.visit_address_operand_mut(&mut self.scale_b_tmem);
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma::section_2::*;
+
+    impl Walk for Tcgen05MmaCtaGroupKindCollectorUsage {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.scale_input_d {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.scale_input_d {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for Tcgen05MmaCtaGroupKindAshiftCollectorUsage {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.scale_input_d {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.scale_input_d {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for Tcgen05MmaCtaGroupKindAshiftCollectorUsage1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.scale_input_d {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.scale_input_d {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma::section_3::*;
+
+    impl Walk for Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            visitor.visit_address_operand(&self.scale_a_tmem);
+            visitor.visit_address_operand(&self.scale_b_tmem);
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            visitor.visit_address_operand_mut(&mut self.scale_a_tmem);
+            visitor.visit_address_operand_mut(&mut self.scale_b_tmem);
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+
+    impl Walk for Tcgen05MmaCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            visitor.visit_address_operand(&self.scale_a_tmem);
+            visitor.visit_address_operand(&self.scale_b_tmem);
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            visitor.visit_address_operand_mut(&mut self.scale_a_tmem);
+            visitor.visit_address_operand_mut(&mut self.scale_b_tmem);
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma::section_4::*;
+
+    impl Walk for Tcgen05MmaCtaGroupKindI8 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+
+    impl Walk for Tcgen05MmaCtaGroupKindI81 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma::section_5::*;
+
+    impl Walk for Tcgen05MmaCtaGroupKindI8CollectorUsage {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+
+    impl Walk for Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+
+    impl Walk for Tcgen05MmaCtaGroupKindI8AshiftCollectorUsage1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+}
@@ -0,0 +1,454 @@
+//! Original PTX specification:
+//!
+//! // 1. Floating-point type without block scaling:
+//! tcgen05.mma.sp.cta_group.kind  [d-tmem],  a-desc,  b-desc, [sp-meta-tmem] ,  idesc {, disable-output-lane }, enable-input-d{, scale-input-d};
+//! tcgen05.mma.sp.cta_group.kind  [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d{, scale-input-d};
+//! .kind       = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! .cta_group  = { .cta_group::1,  .cta_group::2 };
+//! ------------------------------------------------------------------
+//! // 2. Floating-point type with block scaling:
+//! tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize} [d-tmem],  a-desc,  b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize} [d-tmem], [a-tmem], b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! .scale_vectorsize = { .scale_vec::1X, .scale_vec::2X, .scale_vec::4X, .block16, .block32 };
+//! .cta_group      = { .cta_group::1,  .cta_group::2 };
+//! .kind = { .kind::mxf8f6f4, .kind::mxf4, .kind::mxf4nvf4 };
+//! ------------------------------------------------------------------
+//! // 3. Convolution MMA with floating-point type without block scaling:
+//! tcgen05.mma.sp.cta_group.kind.collector_usage           [d-tmem],  a-desc,  b-desc, [sp-meta-tmem] ,  idesc {, disable-output-lane }, enable-input-d
+//! {, scale-input-d};
+//! tcgen05.mma.sp.cta_group.kind.ashift{.collector_usage}  [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d
+//! {, scale-input-d};
+//! tcgen05.mma.sp.cta_group.kind{.ashift}.collector_usage  [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d
+//! {, scale-input-d};
+//! .kind            = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+//! ------------------------------------------------------------------
+//! // 4. Activation Stationary MMA with floating-point type with block scaling:
+//! tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage [d-tmem],  a-desc,  b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind.block_scale{.scale_vectorsize}.collector_usage [d-tmem], [a-tmem], b-desc , [sp-meta-tmem] , idesc, [scale-A-tmem], [scale-B-tmem], enable-input-d;
+//! .kind = { .kind::mxf8f6f4, .kind::mxf4, .kind::mxf4nvf4 };
+//! .scale_vectorsize = { .scale_vec::1X, .scale_vec::2X, .scale_vec::4X, .block16, .block32 };
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+//! ------------------------------------------------------------------
+//! // 5. Integer type:
+//! tcgen05.mma.sp.cta_group.kind::i8 [d-tmem],  a-desc,  b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind::i8 [d-tmem], [a-tmem], b-desc, [sp-meta-tmem] , idesc {, disable-output-lane }, enable-input-d;
+//! .cta_group      = { .cta_group::1,  .cta_group::2 };
+//! ------------------------------------------------------------------
+//! // 6. Convolution MMA with Integer type:
+//! tcgen05.mma.sp.cta_group.kind::i8.collector_usage          [d-tmem],  a-desc,  b-desc, [sp-meta-tmem], idesc {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind::i8.ashift{.collector_usage} [d-tmem], [a-tmem], b-desc, [sp-meta-tmem], idesc {, disable-output-lane }, enable-input-d;
+//! tcgen05.mma.sp.cta_group.kind::i8{.ashift}.collector_usage [d-tmem], [a-tmem], b-desc, [sp-meta-tmem], idesc {, disable-output-lane }, enable-input-d;
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer         = { ::a };
+//! ::op             = { ::fill, ::use, ::lastuse, ::discard* };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_0::*;
+
+    impl Walk for Tcgen05MmaSpCtaGroupKind {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.scale_input_d {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.scale_input_d {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for Tcgen05MmaSpCtaGroupKind1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.scale_input_d {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.scale_input_d {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_1::*;
+
+    impl Walk for Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            visitor.visit_address_operand(&self.scale_a_tmem);
+            visitor.visit_address_operand(&self.scale_b_tmem);
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            visitor.visit_address_operand_mut(&mut self.scale_a_tmem);
+            visitor.visit_address_operand_mut(&mut self.scale_b_tmem);
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+
+    impl Walk for Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsize1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            visitor.visit_address_operand(&self.scale_a_tmem);
+            visitor.visit_address_operand(&self.scale_b_tmem);
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            visitor.visit_address_operand_mut(&mut self.scale_a_tmem);
+            visitor.visit_address_operand_mut(&mut self.scale_b_tmem);
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_2::*;
+
+    impl Walk for Tcgen05MmaSpCtaGroupKindCollectorUsage {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.scale_input_d {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.scale_input_d {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.scale_input_d {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.scale_input_d {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for Tcgen05MmaSpCtaGroupKindAshiftCollectorUsage1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.scale_input_d {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.scale_input_d {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_3::*;
+
+    impl Walk for Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            visitor.visit_address_operand(&self.scale_a_tmem);
+            visitor.visit_address_operand(&self.scale_b_tmem);
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            visitor.visit_address_operand_mut(&mut self.scale_a_tmem);
+            visitor.visit_address_operand_mut(&mut self.scale_b_tmem);
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+
+    impl Walk for Tcgen05MmaSpCtaGroupKindBlockScaleScaleVectorsizeCollectorUsage1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            visitor.visit_address_operand(&self.scale_a_tmem);
+            visitor.visit_address_operand(&self.scale_b_tmem);
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            visitor.visit_address_operand_mut(&mut self.scale_a_tmem);
+            visitor.visit_address_operand_mut(&mut self.scale_b_tmem);
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_4::*;
+
+    impl Walk for Tcgen05MmaSpCtaGroupKindI8 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+
+    impl Walk for Tcgen05MmaSpCtaGroupKindI81 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_sp::section_5::*;
+
+    impl Walk for Tcgen05MmaSpCtaGroupKindI8CollectorUsage {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+
+    impl Walk for Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+
+    impl Walk for Tcgen05MmaSpCtaGroupKindI8AshiftCollectorUsage1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_address_operand(&self.sp_meta_tmem);
+            visitor.visit_general_operand(&self.idesc);
+            if let Some(operand) = &self.disable_output_lane {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.enable_input_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_address_operand_mut(&mut self.sp_meta_tmem);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            if let Some(operand) = &mut self.disable_output_lane {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Original PTX specification:
+//!
+//! // 1. Floating-point type without block scaling:
+//! tcgen05.mma.ws.cta_group::1.kind{.collector_usage}    [d-tmem],  a-desc,  b-desc,  idesc,
+//! enable-input-d {, zero-column-mask-desc };
+//! tcgen05.mma.ws.cta_group::1.kind{.collector_usage}    [d-tmem], [a-tmem], b-desc, idesc,
+//! enable-input-d {, zero-column-mask-desc };
+//! .kind = { .kind::f16, .kind::tf32, .kind::f8f6f4 };
+//! ----------------------------------------------------------------------------------
+//! // 2. Integer type:
+//! tcgen05.mma.ws.cta_group::1.kind::i8{.collector_usage} [d-tmem],  a-desc,  b-desc, idesc,
+//! enable-input-d {, zero-column-mask-desc};
+//! tcgen05.mma.ws.cta_group::1.kind::i8{.collector_usage} [d-tmem], [a-tmem], b-desc, idesc,
+//! enable-input-d {, zero-column-mask-desc};
+//! .collector_usage = { .collector::buffer::op };
+//! ::buffer = { ::b0, ::b1, ::b2, ::b3 };
+//! ::op   = { ::fill, ::use, ::lastuse, ::discard};
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_ws::section_0::*;
+
+    impl Walk for Tcgen05MmaWsCtaGroup1KindCollectorUsage {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.zero_column_mask_desc {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.zero_column_mask_desc {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for Tcgen05MmaWsCtaGroup1KindCollectorUsage1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.zero_column_mask_desc {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.zero_column_mask_desc {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_mma_ws::section_1::*;
+
+    impl Walk for Tcgen05MmaWsCtaGroup1KindI8CollectorUsage {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.zero_column_mask_desc {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.zero_column_mask_desc {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for Tcgen05MmaWsCtaGroup1KindI8CollectorUsage1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.d_tmem);
+            visitor.visit_address_operand(&self.a_tmem);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.idesc);
+            visitor.visit_general_operand(&self.enable_input_d);
+            if let Some(operand) = &self.zero_column_mask_desc {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.d_tmem);
+            visitor.visit_address_operand_mut(&mut self.a_tmem);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.idesc);
+            visitor.visit_general_operand_mut(&mut self.enable_input_d);
+            if let Some(operand) = &mut self.zero_column_mask_desc {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
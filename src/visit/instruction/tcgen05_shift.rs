@@ -0,0 +1,22 @@
+//! Original PTX specification:
+//!
+//! tcgen05.shift.cta_group.down  [taddr];
+//! .cta_group = { .cta_group::1, .cta_group::2 }
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_shift::section_0::*;
+
+    impl Walk for Tcgen05ShiftCtaGroupDown {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.taddr);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.taddr);
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! Original PTX specification:
+//!
+//! tcgen05.st.sync.aligned.shape1.num{.unpack}.b32    [taddr], r;
+//! tcgen05.st.sync.aligned.shape2.num{.unpack}.b32    [taddr], immHalfSplitoff, r;
+//! .shape1 = { .16x64b, .16x128b, .16x256b, .32x32b };
+//! .shape2 = { .16x32bx2 };
+//! .num    = { .x1, .x2, .x4, .x8, .x16, .x32, .x64, .x128 };
+//! .unpack = { .unpack::16b };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_st::section_0::*;
+
+    impl Walk for Tcgen05StSyncAlignedShape1NumUnpackB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.taddr);
+            visitor.visit_general_operand(&self.r);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.taddr);
+            visitor.visit_general_operand_mut(&mut self.r);
+        }
+    }
+
+    impl Walk for Tcgen05StSyncAlignedShape2NumUnpackB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.taddr);
+            visitor.visit_general_operand(&self.immhalfsplitoff);
+            visitor.visit_general_operand(&self.r);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.taddr);
+            visitor.visit_general_operand_mut(&mut self.immhalfsplitoff);
+            visitor.visit_general_operand_mut(&mut self.r);
+        }
+    }
+}
@@ -0,0 +1,18 @@
+//! Original PTX specification:
+//!
+//! tcgen05.wait_operation.sync.aligned;
+//! .wait_operation = { .wait::ld, .wait::st }
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tcgen05_wait::section_0::*;
+
+    impl Walk for Tcgen05WaitOperationSyncAligned {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+}
@@ -0,0 +1,29 @@
+//! Original PTX specification:
+//!
+//! tensormap.cp_fenceproxy.cp_qualifiers.fence_qualifiers.sync.aligned  [dst], [src], size;
+//! .cp_qualifiers    = { .global.shared::cta };
+//! .fence_qualifiers = { .to_proxy::from_proxy.release.scope };
+//! .to_proxy::from_proxy  = { .tensormap::generic };
+//! .scope            = { .cta, .cluster, .gpu , .sys };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tensormap_cp_fenceproxy::section_0::*;
+
+    impl Walk for TensormapCpFenceproxyCpQualifiersFenceQualifiersSyncAligned {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.dst);
+            visitor.visit_address_operand(&self.src);
+            visitor.visit_general_operand(&self.size);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.dst);
+            visitor.visit_address_operand_mut(&mut self.src);
+            visitor.visit_general_operand_mut(&mut self.size);
+        }
+    }
+}
@@ -0,0 +1,55 @@
+//! Original PTX specification:
+//!
+//! tensormap.replace.mode.field1{.ss}.b1024.type  [addr], new_val;
+//! tensormap.replace.mode.field2{.ss}.b1024.type  [addr], ord, new_val;
+//! tensormap.replace.mode.field3{.ss}.b1024.type  [addr], new_val;
+//! .mode    = { .tile };
+//! .field1  = { .global_address, .rank };
+//! .field2  = { .box_dim, .global_dim, .global_stride, .element_stride  };
+//! .field3  = { .elemtype,  .interleave_layout, .swizzle_mode, .swizzle_atomicity, .fill_mode };
+//! .ss      = { .global, .shared::cta };
+//! .type    = { .b32, .b64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tensormap_replace::section_0::*;
+
+    impl Walk for TensormapReplaceModeField1SsB1024Type {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.new_val);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.new_val);
+        }
+    }
+
+    impl Walk for TensormapReplaceModeField2SsB1024Type {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.ord);
+            visitor.visit_general_operand(&self.new_val);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.ord);
+            visitor.visit_general_operand_mut(&mut self.new_val);
+        }
+    }
+
+    impl Walk for TensormapReplaceModeField3SsB1024Type {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.addr);
+            visitor.visit_general_operand(&self.new_val);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.addr);
+            visitor.visit_general_operand_mut(&mut self.new_val);
+        }
+    }
+}
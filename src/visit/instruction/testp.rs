@@ -0,0 +1,27 @@
+//! Original PTX specification:
+//!
+//! testp.op.type  p, a;  // result is .pred
+//! .op   = { .finite, .infinite,
+//! .number, .notanumber,
+//! .normal, .subnormal };
+//! .type = { .f32, .f64 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::testp::section_0::*;
+
+    impl Walk for TestpOpType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.p);
+            visitor.visit_general_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.a);
+        }
+    }
+}
@@ -0,0 +1,392 @@
+//! Original PTX specification:
+//!
+//! tex.geom.v4.dtype.ctype  d{|p}, [a, c] {, e} {, f};
+//! tex.geom.v4.dtype.ctype  d{|p}, [a, b, c] {, e} {, f};  // explicit sampler
+//! tex.geom.v2.f16x2.ctype  d{|p}, [a, c] {, e} {, f};
+//! tex.geom.v2.f16x2.ctype  d{|p}, [a, b, c] {, e} {, f};  // explicit sampler
+//! // mipmaps
+//! tex.base.geom.v4.dtype.ctype   d{|p}, [a, {b,} c] {, e} {, f};
+//! tex.level.geom.v4.dtype.ctype  d{|p}, [a, {b,} c], lod {, e} {, f};
+//! tex.grad.geom.v4.dtype.ctype   d{|p}, [a, {b,} c], dPdx, dPdy {, e} {, f};
+//! tex.base.geom.v2.f16x2.ctype   d{|p}, [a, {b,} c] {, e} {, f};
+//! tex.level.geom.v2.f16x2.ctype  d{|p}, [a, {b,} c], lod {, e} {, f};
+//! tex.grad.geom.v2.f16x2.ctype   d{|p}, [a, {b,} c], dPdx, dPdy {, e} {, f};
+//! .geom  = { .1d, .2d, .3d, .a1d, .a2d, .cube, .acube, .2dms, .a2dms };
+//! .dtype = { .u32, .s32, .f16,  .f32 };
+//! .ctype = {       .s32, .f32 };          // .cube, .acube require .f32
+//! // .2dms, .a2dms require .s32
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tex::section_0::*;
+
+    impl Walk for TexGeomV4DtypeCtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            for operand in &self.a.operands {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.e {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.f {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            for operand in &mut self.a.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.e {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.f {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for TexGeomV4DtypeCtype1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.handle);
+            visitor.visit_general_operand(&self.a.sampler);
+            visitor.visit_general_operand(&self.a.coords);
+            if let Some(operand) = &self.e {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.f {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.handle);
+            visitor.visit_general_operand_mut(&mut self.a.sampler);
+            visitor.visit_general_operand_mut(&mut self.a.coords);
+            if let Some(operand) = &mut self.e {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.f {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for TexGeomV2F16x2Ctype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            for operand in &self.a.operands {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.e {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.f {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            for operand in &mut self.a.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.e {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.f {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for TexGeomV2F16x2Ctype1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.handle);
+            visitor.visit_general_operand(&self.a.sampler);
+            visitor.visit_general_operand(&self.a.coords);
+            if let Some(operand) = &self.e {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.f {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.handle);
+            visitor.visit_general_operand_mut(&mut self.a.sampler);
+            visitor.visit_general_operand_mut(&mut self.a.coords);
+            if let Some(operand) = &mut self.e {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.f {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for TexBaseGeomV4DtypeCtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.handle);
+            if let Some(operand) = &self.a.sampler {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.coords);
+            if let Some(operand) = &self.e {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.f {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.handle);
+            if let Some(operand) = &mut self.a.sampler {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.coords);
+            if let Some(operand) = &mut self.e {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.f {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for TexLevelGeomV4DtypeCtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.handle);
+            if let Some(operand) = &self.a.sampler {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.coords);
+            visitor.visit_general_operand(&self.lod);
+            if let Some(operand) = &self.e {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.f {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.handle);
+            if let Some(operand) = &mut self.a.sampler {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.coords);
+            visitor.visit_general_operand_mut(&mut self.lod);
+            if let Some(operand) = &mut self.e {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.f {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for TexGradGeomV4DtypeCtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.handle);
+            if let Some(operand) = &self.a.sampler {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.coords);
+            visitor.visit_general_operand(&self.dpdx);
+            visitor.visit_general_operand(&self.dpdy);
+            if let Some(operand) = &self.e {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.f {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.handle);
+            if let Some(operand) = &mut self.a.sampler {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.coords);
+            visitor.visit_general_operand_mut(&mut self.dpdx);
+            visitor.visit_general_operand_mut(&mut self.dpdy);
+            if let Some(operand) = &mut self.e {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.f {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for TexBaseGeomV2F16x2Ctype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.handle);
+            if let Some(operand) = &self.a.sampler {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.coords);
+            if let Some(operand) = &self.e {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.f {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.handle);
+            if let Some(operand) = &mut self.a.sampler {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.coords);
+            if let Some(operand) = &mut self.e {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.f {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for TexLevelGeomV2F16x2Ctype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.handle);
+            if let Some(operand) = &self.a.sampler {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.coords);
+            visitor.visit_general_operand(&self.lod);
+            if let Some(operand) = &self.e {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.f {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.handle);
+            if let Some(operand) = &mut self.a.sampler {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.coords);
+            visitor.visit_general_operand_mut(&mut self.lod);
+            if let Some(operand) = &mut self.e {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.f {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for TexGradGeomV2F16x2Ctype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.handle);
+            if let Some(operand) = &self.a.sampler {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.coords);
+            visitor.visit_general_operand(&self.dpdx);
+            visitor.visit_general_operand(&self.dpdy);
+            if let Some(operand) = &self.e {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.f {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.handle);
+            if let Some(operand) = &mut self.a.sampler {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.coords);
+            visitor.visit_general_operand_mut(&mut self.dpdx);
+            visitor.visit_general_operand_mut(&mut self.dpdy);
+            if let Some(operand) = &mut self.e {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.f {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
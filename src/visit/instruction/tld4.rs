@@ -0,0 +1,82 @@
+//! Original PTX specification:
+//!
+//! tld4.comp.2d.v4.dtype.f32    d{|p}, [a, c] {, e} {, f};
+//! tld4.comp.geom.v4.dtype.f32  d{|p}, [a, b, c] {, e} {, f};  // explicit sampler
+//! .comp  = { .r, .g, .b, .a };
+//! .geom  = { .2d, .a2d, .cube, .acube };
+//! .dtype = { .u32, .s32, .f32 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::tld4::section_0::*;
+
+    impl Walk for Tld4Comp2dV4DtypeF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            for operand in &self.a.operands {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.e {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.f {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            for operand in &mut self.a.operands {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.e {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.f {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for Tld4CompGeomV4DtypeF32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            if let Some(operand) = &self.p {
+                visitor.visit_general_operand(operand);
+            }
+            visitor.visit_general_operand(&self.a.handle);
+            visitor.visit_general_operand(&self.a.sampler);
+            visitor.visit_general_operand(&self.a.coords);
+            if let Some(operand) = &self.e {
+                visitor.visit_general_operand(operand);
+            }
+            if let Some(operand) = &self.f {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            if let Some(operand) = &mut self.p {
+                visitor.visit_general_operand_mut(operand);
+            }
+            visitor.visit_general_operand_mut(&mut self.a.handle);
+            visitor.visit_general_operand_mut(&mut self.a.sampler);
+            visitor.visit_general_operand_mut(&mut self.a.coords);
+            if let Some(operand) = &mut self.e {
+                visitor.visit_general_operand_mut(operand);
+            }
+            if let Some(operand) = &mut self.f {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Original PTX specification:
+//!
+//! txq.tquery.b32         d, [a];       // texture attributes
+//! txq.level.tlquery.b32  d, [a], lod;  // texture attributes
+//! txq.squery.b32         d, [a];       // sampler attributes
+//! .tquery  = { .width, .height, .depth,
+//! .channel_data_type, .channel_order,
+//! .normalized_coords, .array_size,
+//! .num_mipmap_levels, .num_samples};
+//! .tlquery = { .width, .height, .depth };
+//! .squery  = { .force_unnormalized_coords, .filter_mode,
+//! .addr_mode_0, addr_mode_1, addr_mode_2 };
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::txq::section_0::*;
+
+    impl Walk for TxqTqueryB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+
+    impl Walk for TxqLevelTlqueryB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_address_operand(&self.a);
+            visitor.visit_general_operand(&self.lod);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_address_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.lod);
+        }
+    }
+
+    impl Walk for TxqSqueryB32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_address_operand(&self.a);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_address_operand_mut(&mut self.a);
+        }
+    }
+}
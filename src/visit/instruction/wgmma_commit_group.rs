@@ -0,0 +1,17 @@
+//! Original PTX specification:
+//!
+//! wgmma.commit_group.sync.aligned;
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_commit_group::section_0::*;
+
+    impl Walk for WgmmaCommitGroupSyncAligned {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {}
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {}
+    }
+}
@@ -0,0 +1,334 @@
+//! Original PTX specification:
+//!
+//! // Half precision floating point type:
+//! wgmma.mma_async.sync.aligned.shape.dtype.f16.f16  d, a-desc, b-desc, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;
+//! wgmma.mma_async.sync.aligned.shape.dtype.f16.f16  d, a, b-desc, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;
+//! .shape   = {.m64n8k16, .m64n16k16, .m64n24k16, .m64n32k16,
+//! .m64n40k16, .m64n48k16, .m64n56k16, .m64n64k16,
+//! .m64n72k16, .m64n80k16, .m64n88k16, .m64n96k16,
+//! .m64n104k16, .m64n112k16, .m64n120k16, .m64n128k16,
+//! .m64n136k16, .m64n144k16, .m64n152k16, .m64n160k16,
+//! .m64n168k16, .m64n176k16, .m64n184k16, .m64n192k16,
+//! .m64n200k16, .m64n208k16, .m64n216k16, .m64n224k16,
+//! .m64n232k16, .m64n240k16, .m64n248k16, .m64n256k16};
+//! .dtype   = {.f16, .f32};
+//! ------------------------------------------------------------------
+//! // Alternate floating point type :
+//! // .bf16 floating point type:
+//! wgmma.mma_async.sync.aligned.shape.dtype.bf16.bf16  d, a-desc, b-desc, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;
+//! wgmma.mma_async.sync.aligned.shape.dtype.bf16.bf16  d, a, b-desc, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;
+//! .shape   = {.m64n8k16, .m64n16k16, .m64n24k16, .m64n32k16,
+//! .m64n40k16, .m64n48k16, .m64n56k16, .m64n64k16,
+//! .m64n72k16, .m64n80k16, .m64n88k16, .m64n96k16,
+//! .m64n104k16, .m64n112k16, .m64n120k16, .m64n128k16,
+//! .m64n136k16, .m64n144k16, .m64n152k16, .m64n160k16,
+//! .m64n168k16, .m64n176k16, .m64n184k16, .m64n192k16,
+//! .m64n200k16, .m64n208k16, .m64n216k16, .m64n224k16,
+//! .m64n232k16, .m64n240k16, .m64n248k16, .m64n256k16};
+//! .dtype  = {.f32};
+//! ------------------------------------------------------------------
+//! // .tf32 floating point type:
+//! wgmma.mma_async.sync.aligned.shape.dtype.tf32.tf32  d, a-desc, b-desc, scale-d, imm-scale-a, imm-scale-b;
+//! wgmma.mma_async.sync.aligned.shape.dtype.tf32.tf32  d, a, b-desc, scale-d, imm-scale-a, imm-scale-b;
+//! .shape   = {.m64n8k8, .m64n16k8, .m64n24k8, .m64n32k8,
+//! .m64n40k8, .m64n48k8, .m64n56k8, .m64n64k8,
+//! .m64n72k8, .m64n80k8, .m64n88k8, .m64n96k8,
+//! .m64n104k8, .m64n112k8, .m64n120k8, .m64n128k8,
+//! .m64n136k8, .m64n144k8, .m64n152k8, .m64n160k8,
+//! .m64n168k8, .m64n176k8, .m64n184k8, .m64n192k8,
+//! .m64n200k8, .m64n208k8, .m64n216k8, .m64n224k8,
+//! .m64n232k8, .m64n240k8, .m64n248k8, .m64n256k8};
+//! .dtype  = {.f32};
+//! ------------------------------------------------------------------
+//! // FP8 floating point type
+//! wgmma.mma_async.sync.aligned.shape.dtype.atype.btype  d, a-desc, b-desc, scale-d, imm-scale-a, imm-scale-b;
+//! wgmma.mma_async.sync.aligned.shape.dtype.atype.btype  d, a, b-desc, scale-d, imm-scale-a, imm-scale-b;
+//! .shape   = {.m64n8k32, .m64n16k32, .m64n24k32, .m64n32k32,
+//! .m64n40k32, .m64n48k32, .m64n56k32, .m64n64k32,
+//! .m64n72k32, .m64n80k32, .m64n88k32, .m64n96k32,
+//! .m64n104k32, .m64n112k32, .m64n120k32, .m64n128k32,
+//! .m64n136k32, .m64n144k32, .m64n152k32, .m64n160k32,
+//! .m64n168k32, .m64n176k32, .m64n184k32, .m64n192k32,
+//! .m64n200k32, .m64n208k32, .m64n216k32, .m64n224k32,
+//! .m64n232k32, .m64n240k32, .m64n248k32, .m64n256k32};
+//! .atype  = {.e4m3, .e5m2};
+//! .btype  = {.e4m3, .e5m2};
+//! .dtype  = {.f16, .f32};
+//! ------------------------------------------------------------------
+//! // Integer type:
+//! wgmma.mma_async.sync.aligned.shape{.satfinite}.s32.atype.btype  d, a-desc, b-desc, scale-d;
+//! wgmma.mma_async.sync.aligned.shape{.satfinite}.s32.atype.btype  d, a, b-desc, scale-d;
+//! .shape   = {.m64n8k32, .m64n16k32, .m64n24k32, .m64n32k32,
+//! .m64n48k32, .m64n64k32, .m64n80k32, .m64n96k32,
+//! .m64n112k32, .m64n128k32, .m64n144k32, .m64n160k32,
+//! .m64n176k32, .m64n192k32, .m64n208k32, .m64n224k32};
+//! .atype  = {.s8, .u8};
+//! .btype  = {.s8, .u8};
+//! ------------------------------------------------------------------
+//! // Single bit:
+//! wgmma.mma_async.sync.aligned.shape.s32.b1.b1.op.popc  d, a-desc, b-desc, scale-d;
+//! wgmma.mma_async.sync.aligned.shape.s32.b1.b1.op.popc  d, a, b-desc, scale-d;
+//! .shape   = {.m64n8k256, .m64n16k256, .m64n24k256, .m64n32k256,
+//! .m64n48k256, .m64n64k256, .m64n80k256, .m64n96k256,
+//! .m64n112k256, .m64n128k256, .m64n144k256, .m64n160k256,
+//! .m64n176k256, .m64n192k256, .m64n208k256, .m64n224k256,
+//! .m64n240k256, .m64n256k256};
+//! .op  = {.and};
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async::section_0::*;
+
+    impl Walk for WgmmaMmaAsyncSyncAlignedShapeDtypeF16F16 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+            visitor.visit_general_operand(&self.imm_trans_a);
+            visitor.visit_general_operand(&self.imm_trans_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+            visitor.visit_general_operand_mut(&mut self.imm_trans_a);
+            visitor.visit_general_operand_mut(&mut self.imm_trans_b);
+        }
+    }
+
+    impl Walk for WgmmaMmaAsyncSyncAlignedShapeDtypeF16F161 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+            visitor.visit_general_operand(&self.imm_trans_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+            visitor.visit_general_operand_mut(&mut self.imm_trans_b);
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async::section_1::*;
+
+    impl Walk for WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf16 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+            visitor.visit_general_operand(&self.imm_trans_a);
+            visitor.visit_general_operand(&self.imm_trans_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+            visitor.visit_general_operand_mut(&mut self.imm_trans_a);
+            visitor.visit_general_operand_mut(&mut self.imm_trans_b);
+        }
+    }
+
+    impl Walk for WgmmaMmaAsyncSyncAlignedShapeDtypeBf16Bf161 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+            visitor.visit_general_operand(&self.imm_trans_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+            visitor.visit_general_operand_mut(&mut self.imm_trans_b);
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async::section_2::*;
+
+    impl Walk for WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+        }
+    }
+
+    impl Walk for WgmmaMmaAsyncSyncAlignedShapeDtypeTf32Tf321 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async::section_3::*;
+
+    impl Walk for WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+        }
+    }
+
+    impl Walk for WgmmaMmaAsyncSyncAlignedShapeDtypeAtypeBtype1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async::section_4::*;
+
+    impl Walk for WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.scale_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+        }
+    }
+
+    impl Walk for WgmmaMmaAsyncSyncAlignedShapeSatfiniteS32AtypeBtype1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.scale_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async::section_5::*;
+
+    impl Walk for WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.scale_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+        }
+    }
+
+    impl Walk for WgmmaMmaAsyncSyncAlignedShapeS32B1B1OpPopc1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.scale_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+        }
+    }
+}
@@ -0,0 +1,330 @@
+//! Original PTX specification:
+//!
+//! // Half precision floating point type:
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.f16.f16  d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.f16.f16  d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;
+//! .shape   = {.m64n8k32, .m64n16k32, .m64n24k32, .m64n32k32,
+//! .m64n40k32, .m64n48k32, .m64n56k32, .m64n64k32,
+//! .m64n72k32, .m64n80k32, .m64n88k32, .m64n96k32,
+//! .m64n104k32, .m64n112k32, .m64n120k32, .m64n128k32,
+//! .m64n136k32, .m64n144k32, .m64n152k32, .m64n160k32,
+//! .m64n168k32, .m64n176k32, .m64n184k32, .m64n192k32,
+//! .m64n200k32, .m64n208k32, .m64n216k32, .m64n224k32,
+//! .m64n232k32, .m64n240k32, .m64n248k32, .m64n256k32};
+//! .dtype   = {.f16, .f32};
+//! ------------------------------------------------------------------
+//! // Alternate floating point type :
+//! // .bf16 floating point type:
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.bf16.bf16  d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-a, imm-trans-b;
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.bf16.bf16  d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b, imm-trans-b;
+//! .shape   = {.m64n8k32, .m64n16k32, .m64n24k32, .m64n32k32,
+//! .m64n40k32, .m64n48k32, .m64n56k32, .m64n64k32,
+//! .m64n72k32, .m64n80k32, .m64n88k32, .m64n96k32,
+//! .m64n104k32, .m64n112k32, .m64n120k32, .m64n128k32,
+//! .m64n136k32, .m64n144k32, .m64n152k32, .m64n160k32,
+//! .m64n168k32, .m64n176k32, .m64n184k32, .m64n192k32,
+//! .m64n200k32, .m64n208k32, .m64n216k32, .m64n224k32,
+//! .m64n232k32, .m64n240k32, .m64n248k32, .m64n256k32};
+//! .dtype  = {.f32};
+//! ------------------------------------------------------------------
+//! // .tf32 floating point type:
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.tf32.tf32  d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.tf32.tf32  d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;
+//! .shape   = {.m64n8k16, .m64n16k16, .m64n24k16, .m64n32k16,
+//! .m64n40k16, .m64n48k16, .m64n56k16, .m64n64k16,
+//! .m64n72k16, .m64n80k16, .m64n88k16, .m64n96k16,
+//! .m64n104k16, .m64n112k16, .m64n120k16, .m64n128k16,
+//! .m64n136k16, .m64n144k16, .m64n152k16, .m64n160k16,
+//! .m64n168k16, .m64n176k16, .m64n184k16, .m64n192k16,
+//! .m64n200k16, .m64n208k16, .m64n216k16, .m64n224k16,
+//! .m64n232k16, .m64n240k16, .m64n248k16, .m64n256k16};
+//! .dtype  = {.f32};
+//! ------------------------------------------------------------------
+//! // FP8 floating point type
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.atype.btype  d, a-desc, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;
+//! wgmma.mma_async.sp.sync.aligned.shape.dtype.atype.btype  d, a, b-desc, sp-meta, sp-sel, scale-d, imm-scale-a, imm-scale-b;
+//! .shape   = {.m64n8k64, .m64n16k64, .m64n24k64, .m64n32k64,
+//! .m64n40k64, .m64n48k64, .m64n56k64, .m64n64k64,
+//! .m64n72k64, .m64n80k64, .m64n88k64, .m64n96k64,
+//! .m64n104k64, .m64n112k64, .m64n120k64, .m64n128k64,
+//! .m64n136k64, .m64n144k64, .m64n152k64, .m64n160k64,
+//! .m64n168k64, .m64n176k64, .m64n184k64, .m64n192k64,
+//! .m64n200k64, .m64n208k64, .m64n216k64, .m64n224k64,
+//! .m64n232k64, .m64n240k64, .m64n248k64, .m64n256k64};
+//! .atype  = {.e4m3, .e5m2};
+//! .btype  = {.e4m3, .e5m2};
+//! .dtype  = {.f16, .f32};
+//! ------------------------------------------------------------------
+//! // Integer type:
+//! wgmma.mma_async.sp.sync.aligned.shape{.satfinite}.s32.atype.btype  d, a-desc, b-desc, sp-meta, sp-sel, scale-d;
+//! wgmma.mma_async.sp.sync.aligned.shape{.satfinite}.s32.atype.btype  d, a, b-desc, sp-meta, sp-sel, scale-d;
+//! .shape   = {.m64n8k64, .m64n16k64, .m64n24k64, .m64n32k64,
+//! .m64n48k64, .m64n64k64, .m64n80k64, .m64n96k64,
+//! .m64n112k64, .m64n128k64, .m64n144k64, .m64n160k64,
+//! .m64n176k64, .m64n192k64, .m64n208k64, .m64n224k64,
+//! .m64n240k64, .m64n256k64};
+//! .atype  = {.s8, .u8};
+//! .btype  = {.s8, .u8};
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_0::*;
+
+    impl Walk for WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F16 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.sp_meta);
+            visitor.visit_general_operand(&self.sp_sel);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+            visitor.visit_general_operand(&self.imm_trans_a);
+            visitor.visit_general_operand(&self.imm_trans_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.sp_meta);
+            visitor.visit_general_operand_mut(&mut self.sp_sel);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+            visitor.visit_general_operand_mut(&mut self.imm_trans_a);
+            visitor.visit_general_operand_mut(&mut self.imm_trans_b);
+        }
+    }
+
+    impl Walk for WgmmaMmaAsyncSpSyncAlignedShapeDtypeF16F161 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.sp_meta);
+            visitor.visit_general_operand(&self.sp_sel);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+            visitor.visit_general_operand(&self.imm_trans_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.sp_meta);
+            visitor.visit_general_operand_mut(&mut self.sp_sel);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+            visitor.visit_general_operand_mut(&mut self.imm_trans_b);
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_1::*;
+
+    impl Walk for WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf16 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.sp_meta);
+            visitor.visit_general_operand(&self.sp_sel);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+            visitor.visit_general_operand(&self.imm_trans_a);
+            visitor.visit_general_operand(&self.imm_trans_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.sp_meta);
+            visitor.visit_general_operand_mut(&mut self.sp_sel);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+            visitor.visit_general_operand_mut(&mut self.imm_trans_a);
+            visitor.visit_general_operand_mut(&mut self.imm_trans_b);
+        }
+    }
+
+    impl Walk for WgmmaMmaAsyncSpSyncAlignedShapeDtypeBf16Bf161 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.sp_meta);
+            visitor.visit_general_operand(&self.sp_sel);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+            visitor.visit_general_operand(&self.imm_trans_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.sp_meta);
+            visitor.visit_general_operand_mut(&mut self.sp_sel);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+            visitor.visit_general_operand_mut(&mut self.imm_trans_b);
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_2::*;
+
+    impl Walk for WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf32 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.sp_meta);
+            visitor.visit_general_operand(&self.sp_sel);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.sp_meta);
+            visitor.visit_general_operand_mut(&mut self.sp_sel);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+        }
+    }
+
+    impl Walk for WgmmaMmaAsyncSpSyncAlignedShapeDtypeTf32Tf321 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.sp_meta);
+            visitor.visit_general_operand(&self.sp_sel);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.sp_meta);
+            visitor.visit_general_operand_mut(&mut self.sp_sel);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_3::*;
+
+    impl Walk for WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.sp_meta);
+            visitor.visit_general_operand(&self.sp_sel);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.sp_meta);
+            visitor.visit_general_operand_mut(&mut self.sp_sel);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+        }
+    }
+
+    impl Walk for WgmmaMmaAsyncSpSyncAlignedShapeDtypeAtypeBtype1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.sp_meta);
+            visitor.visit_general_operand(&self.sp_sel);
+            visitor.visit_general_operand(&self.scale_d);
+            visitor.visit_general_operand(&self.imm_scale_a);
+            visitor.visit_general_operand(&self.imm_scale_b);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.sp_meta);
+            visitor.visit_general_operand_mut(&mut self.sp_sel);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_a);
+            visitor.visit_general_operand_mut(&mut self.imm_scale_b);
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::wgmma_mma_async_sp::section_4::*;
+
+    impl Walk for WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a_desc);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.sp_meta);
+            visitor.visit_general_operand(&self.sp_sel);
+            visitor.visit_general_operand(&self.scale_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a_desc);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.sp_meta);
+            visitor.visit_general_operand_mut(&mut self.sp_sel);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+        }
+    }
+
+    impl Walk for WgmmaMmaAsyncSpSyncAlignedShapeSatfiniteS32AtypeBtype1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.d);
+            visitor.visit_general_operand(&self.a);
+            visitor.visit_general_operand(&self.b_desc);
+            visitor.visit_general_operand(&self.sp_meta);
+            visitor.visit_general_operand(&self.sp_sel);
+            visitor.visit_general_operand(&self.scale_d);
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.d);
+            visitor.visit_general_operand_mut(&mut self.a);
+            visitor.visit_general_operand_mut(&mut self.b_desc);
+            visitor.visit_general_operand_mut(&mut self.sp_meta);
+            visitor.visit_general_operand_mut(&mut self.sp_sel);
+            visitor.visit_general_operand_mut(&mut self.scale_d);
+        }
+    }
+}
@@ -0,0 +1,407 @@
+//! Original PTX specification:
+//!
+//! // Floating point format .f16 loads:
+//! wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k16, .m8n32k16, .m32n8k16};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.f16, .s8, .u8};
+//! .btype  = {.f16, .s8, .u8};
+//! .ctype  = {.f16, .f32, .s32};
+//! ----------------------------------------------------------------
+//! // Alternate floating point format .bf16 loads:
+//! wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k16, .m8n32k16, .m32n8k16};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.bf16 };
+//! .btype  = {.bf16 };
+//! .ctype  = {.f32 };
+//! ----------------------------------------------------------------
+//! // Alternate floating point format .tf32 loads:
+//! wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k8 };
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.tf32 };
+//! .btype  = {.tf32 };
+//! .ctype  = {.f32 };
+//! ----------------------------------------------------------------
+//! // Double precision Floating point .f64 loads:
+//! wmma.load.a.sync.aligned.layout.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.layout.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k4 };
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.f64 };
+//! .btype  = {.f64 };
+//! .ctype  = {.f64 };
+//! ----------------------------------------------------------------
+//! // Sub-byte loads:
+//! wmma.load.a.sync.aligned.row.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.col.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k32};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.s4, .u4};
+//! .btype  = {.s4, .u4};
+//! .ctype  = {.s32};
+//! ----------------------------------------------------------------
+//! // Single-bit loads:
+//! wmma.load.a.sync.aligned.row.shape{.ss}.atype r, [p] {, stride};
+//! wmma.load.b.sync.aligned.col.shape{.ss}.btype r, [p] {, stride};
+//! wmma.load.c.sync.aligned.layout.shape{.ss}.ctype r, [p] {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k128};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .atype  = {.b1};
+//! .btype  = {.b1};
+//! .ctype  = {.s32};
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_0::*;
+
+    impl Walk for WmmaLoadASyncAlignedLayoutShapeSsAtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for WmmaLoadBSyncAlignedLayoutShapeSsBtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for WmmaLoadCSyncAlignedLayoutShapeSsCtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_1::*;
+
+    impl Walk for WmmaLoadASyncAlignedLayoutShapeSsAtype1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for WmmaLoadBSyncAlignedLayoutShapeSsBtype1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for WmmaLoadCSyncAlignedLayoutShapeSsCtype1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_2::*;
+
+    impl Walk for WmmaLoadASyncAlignedLayoutShapeSsAtype2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for WmmaLoadBSyncAlignedLayoutShapeSsBtype2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for WmmaLoadCSyncAlignedLayoutShapeSsCtype2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_3::*;
+
+    impl Walk for WmmaLoadASyncAlignedLayoutShapeSsAtype3 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for WmmaLoadBSyncAlignedLayoutShapeSsBtype3 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for WmmaLoadCSyncAlignedLayoutShapeSsCtype3 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_4 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_4::*;
+
+    impl Walk for WmmaLoadASyncAlignedRowShapeSsAtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for WmmaLoadBSyncAlignedColShapeSsBtype {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for WmmaLoadCSyncAlignedLayoutShapeSsCtype4 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_5 {
+    use super::*;
+    use crate::r#type::instruction::wmma_load::section_5::*;
+
+    impl Walk for WmmaLoadASyncAlignedRowShapeSsAtype1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for WmmaLoadBSyncAlignedColShapeSsBtype1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+
+    impl Walk for WmmaLoadCSyncAlignedLayoutShapeSsCtype5 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_general_operand(&self.r);
+            visitor.visit_address_operand(&self.p);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_general_operand_mut(&mut self.r);
+            visitor.visit_address_operand_mut(&mut self.p);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
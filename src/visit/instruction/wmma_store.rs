@@ -0,0 +1,117 @@
+//! Original PTX specification:
+//!
+//! wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k16, .m8n32k16, .m32n8k16};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .type   = {.f16, .f32, .s32};
+//! ----------------------------------------------------------------
+//! wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k32, .m8n8k128};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .type   = {.s32};
+//! ----------------------------------------------------------------
+//! wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m16n16k8};
+//! .ss     = {.global, .shared, .shared::cta};
+//! .type   = {.f32};
+//! ----------------------------------------------------------------
+//! wmma.store.d.sync.aligned.layout.shape{.ss}.type [p], r {, stride};
+//! .layout = {.row, .col};
+//! .shape  = {.m8n8k4 };
+//! .ss     = {.global, .shared, .shared::cta};
+//! .type   = {.f64};
+
+#![allow(unused)]
+
+use crate::visit::{Visitor, VisitorMut, Walk};
+
+pub mod section_0 {
+    use super::*;
+    use crate::r#type::instruction::wmma_store::section_0::*;
+
+    impl Walk for WmmaStoreDSyncAlignedLayoutShapeSsType {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.p);
+            visitor.visit_general_operand(&self.r);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.r);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_1 {
+    use super::*;
+    use crate::r#type::instruction::wmma_store::section_1::*;
+
+    impl Walk for WmmaStoreDSyncAlignedLayoutShapeSsType1 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.p);
+            visitor.visit_general_operand(&self.r);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.r);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_2 {
+    use super::*;
+    use crate::r#type::instruction::wmma_store::section_2::*;
+
+    impl Walk for WmmaStoreDSyncAlignedLayoutShapeSsType2 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.p);
+            visitor.visit_general_operand(&self.r);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.r);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
+
+pub mod section_3 {
+    use super::*;
+    use crate::r#type::instruction::wmma_store::section_3::*;
+
+    impl Walk for WmmaStoreDSyncAlignedLayoutShapeSsType3 {
+        fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V) {
+            visitor.visit_address_operand(&self.p);
+            visitor.visit_general_operand(&self.r);
+            if let Some(operand) = &self.stride {
+                visitor.visit_general_operand(operand);
+            }
+        }
+        fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_address_operand_mut(&mut self.p);
+            visitor.visit_general_operand_mut(&mut self.r);
+            if let Some(operand) = &mut self.stride {
+                visitor.visit_general_operand_mut(operand);
+            }
+        }
+    }
+}
@@ -0,0 +1,783 @@
+//! Traversal of the PTX AST.
+//!
+//! [`Visitor`] walks a tree by shared reference and [`VisitorMut`] by mutable
+//! reference. Every `visit_*` method defaults to the matching `walk_*`
+//! function, which visits the node's children in source order; override a
+//! method to act on a node type, and call the `walk_*` function from the
+//! override to keep descending.
+//!
+//! ```
+//! use ptx_parser::r#type::RegisterOperand;
+//! use ptx_parser::visit::Visitor;
+//!
+//! #[derive(Default)]
+//! struct Registers(Vec<String>);
+//!
+//! impl<'ast> Visitor<'ast> for Registers {
+//!     fn visit_register(&mut self, register: &'ast RegisterOperand) {
+//!         self.0.push(register.name.clone());
+//!     }
+//! }
+//!
+//! ptx_parser::run_with_large_stack(|| {
+//!     let module = ptx_parser::parse_ptx(".entry k() { add.s32 %r1, %r2, 4; }").unwrap();
+//!     let mut registers = Registers::default();
+//!     registers.visit_module(&module);
+//!     assert_eq!(registers.0, ["%r1", "%r2"]);
+//! });
+//! ```
+//!
+//! The walkers for [`Inst`] and the individual instruction structs are
+//! generated from the grammar by `crates/parser-gen` (`generate-visitor`) and
+//! live in `visit/instruction`.
+
+pub(crate) mod instruction;
+
+use crate::Span;
+use crate::r#type::{
+    AddressBase, AddressOffset, AddressOperand, AliasFunctionDirective, EntryFunctionDirective,
+    FuncFunctionDirective, FunctionBody, FunctionStatement, FunctionSymbol, GeneralOperand,
+    GlobalInitializer, Immediate, InitializerValue, Instruction, Label, Module, ModuleDirective,
+    ModuleVariableDirective, Operand, ParameterDirective, Predicate, RegisterOperand,
+    StatementDirective, VariableDirective, VariableSymbol, VectorOperand, instruction::Inst,
+};
+
+/// Walks the operands of an instruction.
+///
+/// Implemented for [`Inst`] and every instruction struct; the implementations
+/// are generated so that they follow the operand layout of each instruction.
+pub trait Walk {
+    fn walk<'ast, V: Visitor<'ast> + ?Sized>(&'ast self, visitor: &mut V);
+    fn walk_mut<V: VisitorMut + ?Sized>(&mut self, visitor: &mut V);
+}
+
+/// Read-only traversal of the AST.
+pub trait Visitor<'ast> {
+    fn visit_module(&mut self, module: &'ast Module) {
+        walk_module(self, module)
+    }
+
+    fn visit_module_directive(&mut self, directive: &'ast ModuleDirective) {
+        walk_module_directive(self, directive)
+    }
+
+    fn visit_module_variable(&mut self, variable: &'ast ModuleVariableDirective) {
+        walk_module_variable(self, variable)
+    }
+
+    fn visit_variable_directive(&mut self, variable: &'ast VariableDirective) {
+        walk_variable_directive(self, variable)
+    }
+
+    fn visit_global_initializer(&mut self, initializer: &'ast GlobalInitializer) {
+        walk_global_initializer(self, initializer)
+    }
+
+    fn visit_entry_function(&mut self, function: &'ast EntryFunctionDirective) {
+        walk_entry_function(self, function)
+    }
+
+    fn visit_func_function(&mut self, function: &'ast FuncFunctionDirective) {
+        walk_func_function(self, function)
+    }
+
+    fn visit_alias_function(&mut self, alias: &'ast AliasFunctionDirective) {
+        walk_alias_function(self, alias)
+    }
+
+    fn visit_parameter(&mut self, parameter: &'ast ParameterDirective) {
+        walk_parameter(self, parameter)
+    }
+
+    fn visit_function_body(&mut self, body: &'ast FunctionBody) {
+        walk_function_body(self, body)
+    }
+
+    fn visit_statement(&mut self, statement: &'ast FunctionStatement) {
+        walk_statement(self, statement)
+    }
+
+    fn visit_statement_directive(&mut self, directive: &'ast StatementDirective) {
+        walk_statement_directive(self, directive)
+    }
+
+    fn visit_instruction(&mut self, instruction: &'ast Instruction) {
+        walk_instruction(self, instruction)
+    }
+
+    fn visit_predicate(&mut self, predicate: &'ast Predicate) {
+        walk_predicate(self, predicate)
+    }
+
+    fn visit_inst(&mut self, inst: &'ast Inst) {
+        walk_inst(self, inst)
+    }
+
+    fn visit_general_operand(&mut self, operand: &'ast GeneralOperand) {
+        walk_general_operand(self, operand)
+    }
+
+    fn visit_vector_operand(&mut self, operand: &'ast VectorOperand) {
+        walk_vector_operand(self, operand)
+    }
+
+    fn visit_operand(&mut self, operand: &'ast Operand) {
+        walk_operand(self, operand)
+    }
+
+    fn visit_address_operand(&mut self, operand: &'ast AddressOperand) {
+        walk_address_operand(self, operand)
+    }
+
+    fn visit_register(&mut self, _register: &'ast RegisterOperand) {}
+
+    fn visit_immediate(&mut self, _immediate: &'ast Immediate) {}
+
+    fn visit_function_symbol(&mut self, _symbol: &'ast FunctionSymbol) {}
+
+    fn visit_variable_symbol(&mut self, _symbol: &'ast VariableSymbol) {}
+
+    /// Called for the bare name of an [`Operand::Symbol`] or
+    /// [`Operand::SymbolOffset`], such as a branch target or the function of
+    /// a `call`. `span` covers the whole operand.
+    fn visit_symbol(&mut self, _name: &'ast str, _span: Span) {}
+
+    fn visit_label(&mut self, _label: &'ast Label) {}
+}
+
+pub fn walk_module<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, module: &'ast Module) {
+    for directive in &module.directives {
+        visitor.visit_module_directive(directive);
+    }
+}
+
+pub fn walk_module_directive<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    directive: &'ast ModuleDirective,
+) {
+    match directive {
+        ModuleDirective::ModuleVariable { directive, .. } => {
+            visitor.visit_module_variable(directive)
+        }
+        ModuleDirective::EntryFunction { directive, .. } => visitor.visit_entry_function(directive),
+        ModuleDirective::FuncFunction { directive, .. } => visitor.visit_func_function(directive),
+        ModuleDirective::AliasFunction { directive, .. } => visitor.visit_alias_function(directive),
+        ModuleDirective::ModuleInfo { .. } | ModuleDirective::Debug { .. } => {}
+    }
+}
+
+pub fn walk_module_variable<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    variable: &'ast ModuleVariableDirective,
+) {
+    match variable {
+        ModuleVariableDirective::Tex { directive, .. }
+        | ModuleVariableDirective::Shared { directive, .. }
+        | ModuleVariableDirective::Global { directive, .. }
+        | ModuleVariableDirective::Const { directive, .. } => {
+            visitor.visit_variable_directive(directive)
+        }
+    }
+}
+
+pub fn walk_variable_directive<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    variable: &'ast VariableDirective,
+) {
+    visitor.visit_variable_symbol(&variable.name);
+    if let Some(initializer) = &variable.initializer {
+        visitor.visit_global_initializer(initializer);
+    }
+}
+
+pub fn walk_global_initializer<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    initializer: &'ast GlobalInitializer,
+) {
+    match initializer {
+        GlobalInitializer::Scalar { value, .. } => match value {
+            InitializerValue::NumericLiteral { value, .. } => visitor.visit_immediate(value),
+            InitializerValue::FunctionSymbol { name, .. } => visitor.visit_function_symbol(name),
+            InitializerValue::StringLiteral { .. } => {}
+        },
+        GlobalInitializer::Aggregate { values, .. } => {
+            for value in values {
+                visitor.visit_global_initializer(value);
+            }
+        }
+    }
+}
+
+pub fn walk_entry_function<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    function: &'ast EntryFunctionDirective,
+) {
+    visitor.visit_function_symbol(&function.name);
+    for parameter in &function.params {
+        visitor.visit_parameter(parameter);
+    }
+    if let Some(body) = &function.body {
+        visitor.visit_function_body(body);
+    }
+}
+
+pub fn walk_func_function<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    function: &'ast FuncFunctionDirective,
+) {
+    if let Some(parameter) = &function.return_param {
+        visitor.visit_parameter(parameter);
+    }
+    visitor.visit_function_symbol(&function.name);
+    for parameter in &function.params {
+        visitor.visit_parameter(parameter);
+    }
+    for directive in &function.pre_body_declarations {
+        visitor.visit_statement_directive(directive);
+    }
+    if let Some(body) = &function.body {
+        visitor.visit_function_body(body);
+    }
+}
+
+pub fn walk_alias_function<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    alias: &'ast AliasFunctionDirective,
+) {
+    visitor.visit_function_symbol(&alias.alias);
+    visitor.visit_function_symbol(&alias.target);
+}
+
+pub fn walk_parameter<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    parameter: &'ast ParameterDirective,
+) {
+    match parameter {
+        ParameterDirective::Register { name, .. } | ParameterDirective::Parameter { name, .. } => {
+            visitor.visit_variable_symbol(name)
+        }
+    }
+}
+
+pub fn walk_function_body<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    body: &'ast FunctionBody,
+) {
+    for statement in &body.statements {
+        visitor.visit_statement(statement);
+    }
+}
+
+pub fn walk_statement<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    statement: &'ast FunctionStatement,
+) {
+    match statement {
+        FunctionStatement::Label { label, .. } => visitor.visit_label(label),
+        FunctionStatement::Directive { directive, .. } => {
+            visitor.visit_statement_directive(directive)
+        }
+        FunctionStatement::Instruction { instruction, .. } => {
+            visitor.visit_instruction(instruction)
+        }
+        FunctionStatement::Block { statements, .. } => {
+            for statement in statements {
+                visitor.visit_statement(statement);
+            }
+        }
+        FunctionStatement::Error { .. } => {}
+    }
+}
+
+pub fn walk_statement_directive<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    directive: &'ast StatementDirective,
+) {
+    match directive {
+        StatementDirective::Reg { directive, .. } => {
+            for register in &directive.registers {
+                visitor.visit_variable_symbol(&register.name);
+            }
+        }
+        StatementDirective::Local { directive, .. }
+        | StatementDirective::Param { directive, .. }
+        | StatementDirective::Shared { directive, .. } => {
+            visitor.visit_variable_directive(directive)
+        }
+        StatementDirective::BranchTargets { directive, .. } => {
+            for label in &directive.labels {
+                visitor.visit_label(label);
+            }
+        }
+        StatementDirective::CallTargets { directive, .. } => {
+            for target in &directive.targets {
+                visitor.visit_function_symbol(target);
+            }
+        }
+        StatementDirective::CallPrototype { directive, .. } => {
+            if let Some(parameter) = &directive.return_param {
+                visitor.visit_parameter(parameter);
+            }
+            for parameter in &directive.params {
+                visitor.visit_parameter(parameter);
+            }
+        }
+        StatementDirective::Loc { .. }
+        | StatementDirective::Pragma { .. }
+        | StatementDirective::Section { .. }
+        | StatementDirective::Dwarf { .. } => {}
+    }
+}
+
+pub fn walk_instruction<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    instruction: &'ast Instruction,
+) {
+    if let Some(predicate) = &instruction.predicate {
+        visitor.visit_predicate(predicate);
+    }
+    visitor.visit_inst(&instruction.inst);
+}
+
+pub fn walk_predicate<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    predicate: &'ast Predicate,
+) {
+    visitor.visit_operand(&predicate.operand);
+}
+
+pub fn walk_inst<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, inst: &'ast Inst) {
+    inst.walk(visitor);
+}
+
+pub fn walk_general_operand<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    operand: &'ast GeneralOperand,
+) {
+    match operand {
+        GeneralOperand::Vec { operand, .. } => visitor.visit_vector_operand(operand),
+        GeneralOperand::Single { operand, .. } => visitor.visit_operand(operand),
+    }
+}
+
+pub fn walk_vector_operand<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    operand: &'ast VectorOperand,
+) {
+    let operands: &[Operand] = match operand {
+        VectorOperand::Vector1 { operand, .. } => std::slice::from_ref(operand),
+        VectorOperand::Vector2 { operands, .. } => operands,
+        VectorOperand::Vector3 { operands, .. } => operands,
+        VectorOperand::Vector4 { operands, .. } => operands,
+        VectorOperand::Vector8 { operands, .. } => operands,
+    };
+    for operand in operands {
+        visitor.visit_operand(operand);
+    }
+}
+
+pub fn walk_operand<'ast, V: Visitor<'ast> + ?Sized>(visitor: &mut V, operand: &'ast Operand) {
+    match operand {
+        Operand::Register { operand, .. } => visitor.visit_register(operand),
+        Operand::Immediate { operand, .. } => visitor.visit_immediate(operand),
+        Operand::SymbolOffset {
+            symbol,
+            offset,
+            span,
+        } => {
+            visitor.visit_symbol(symbol, *span);
+            visitor.visit_immediate(offset);
+        }
+        Operand::Symbol { name, span } => visitor.visit_symbol(name, *span),
+    }
+}
+
+pub fn walk_address_operand<'ast, V: Visitor<'ast> + ?Sized>(
+    visitor: &mut V,
+    operand: &'ast AddressOperand,
+) {
+    match operand {
+        AddressOperand::Array { base, index, .. } => {
+            visitor.visit_variable_symbol(base);
+            visitor.visit_immediate(index);
+        }
+        AddressOperand::ImmediateAddress { addr, .. } => visitor.visit_immediate(addr),
+        AddressOperand::Offset { base, offset, .. } => {
+            match base {
+                AddressBase::Register { operand, .. } => visitor.visit_register(operand),
+                AddressBase::Variable { symbol, .. } => visitor.visit_variable_symbol(symbol),
+            }
+            match offset {
+                Some(AddressOffset::Register { operand, .. }) => visitor.visit_register(operand),
+                Some(AddressOffset::Immediate { value, .. }) => visitor.visit_immediate(value),
+                None => {}
+            }
+        }
+    }
+}
+
+/// Mutating traversal of the AST.
+pub trait VisitorMut {
+    fn visit_module_mut(&mut self, module: &mut Module) {
+        walk_module_mut(self, module)
+    }
+
+    fn visit_module_directive_mut(&mut self, directive: &mut ModuleDirective) {
+        walk_module_directive_mut(self, directive)
+    }
+
+    fn visit_module_variable_mut(&mut self, variable: &mut ModuleVariableDirective) {
+        walk_module_variable_mut(self, variable)
+    }
+
+    fn visit_variable_directive_mut(&mut self, variable: &mut VariableDirective) {
+        walk_variable_directive_mut(self, variable)
+    }
+
+    fn visit_global_initializer_mut(&mut self, initializer: &mut GlobalInitializer) {
+        walk_global_initializer_mut(self, initializer)
+    }
+
+    fn visit_entry_function_mut(&mut self, function: &mut EntryFunctionDirective) {
+        walk_entry_function_mut(self, function)
+    }
+
+    fn visit_func_function_mut(&mut self, function: &mut FuncFunctionDirective) {
+        walk_func_function_mut(self, function)
+    }
+
+    fn visit_alias_function_mut(&mut self, alias: &mut AliasFunctionDirective) {
+        walk_alias_function_mut(self, alias)
+    }
+
+    fn visit_parameter_mut(&mut self, parameter: &mut ParameterDirective) {
+        walk_parameter_mut(self, parameter)
+    }
+
+    fn visit_function_body_mut(&mut self, body: &mut FunctionBody) {
+        walk_function_body_mut(self, body)
+    }
+
+    fn visit_statement_mut(&mut self, statement: &mut FunctionStatement) {
+        walk_statement_mut(self, statement)
+    }
+
+    fn visit_statement_directive_mut(&mut self, directive: &mut StatementDirective) {
+        walk_statement_directive_mut(self, directive)
+    }
+
+    fn visit_instruction_mut(&mut self, instruction: &mut Instruction) {
+        walk_instruction_mut(self, instruction)
+    }
+
+    fn visit_predicate_mut(&mut self, predicate: &mut Predicate) {
+        walk_predicate_mut(self, predicate)
+    }
+
+    fn visit_inst_mut(&mut self, inst: &mut Inst) {
+        walk_inst_mut(self, inst)
+    }
+
+    fn visit_general_operand_mut(&mut self, operand: &mut GeneralOperand) {
+        walk_general_operand_mut(self, operand)
+    }
+
+    fn visit_vector_operand_mut(&mut self, operand: &mut VectorOperand) {
+        walk_vector_operand_mut(self, operand)
+    }
+
+    fn visit_operand_mut(&mut self, operand: &mut Operand) {
+        walk_operand_mut(self, operand)
+    }
+
+    fn visit_address_operand_mut(&mut self, operand: &mut AddressOperand) {
+        walk_address_operand_mut(self, operand)
+    }
+
+    fn visit_register_mut(&mut self, _register: &mut RegisterOperand) {}
+
+    fn visit_immediate_mut(&mut self, _immediate: &mut Immediate) {}
+
+    fn visit_function_symbol_mut(&mut self, _symbol: &mut FunctionSymbol) {}
+
+    fn visit_variable_symbol_mut(&mut self, _symbol: &mut VariableSymbol) {}
+
+    fn visit_symbol_mut(&mut self, _name: &mut String, _span: Span) {}
+
+    fn visit_label_mut(&mut self, _label: &mut Label) {}
+}
+
+pub fn walk_module_mut<V: VisitorMut + ?Sized>(visitor: &mut V, module: &mut Module) {
+    for directive in &mut module.directives {
+        visitor.visit_module_directive_mut(directive);
+    }
+}
+
+pub fn walk_module_directive_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    directive: &mut ModuleDirective,
+) {
+    match directive {
+        ModuleDirective::ModuleVariable { directive, .. } => {
+            visitor.visit_module_variable_mut(directive)
+        }
+        ModuleDirective::EntryFunction { directive, .. } => {
+            visitor.visit_entry_function_mut(directive)
+        }
+        ModuleDirective::FuncFunction { directive, .. } => {
+            visitor.visit_func_function_mut(directive)
+        }
+        ModuleDirective::AliasFunction { directive, .. } => {
+            visitor.visit_alias_function_mut(directive)
+        }
+        ModuleDirective::ModuleInfo { .. } | ModuleDirective::Debug { .. } => {}
+    }
+}
+
+pub fn walk_module_variable_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    variable: &mut ModuleVariableDirective,
+) {
+    match variable {
+        ModuleVariableDirective::Tex { directive, .. }
+        | ModuleVariableDirective::Shared { directive, .. }
+        | ModuleVariableDirective::Global { directive, .. }
+        | ModuleVariableDirective::Const { directive, .. } => {
+            visitor.visit_variable_directive_mut(directive)
+        }
+    }
+}
+
+pub fn walk_variable_directive_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    variable: &mut VariableDirective,
+) {
+    visitor.visit_variable_symbol_mut(&mut variable.name);
+    if let Some(initializer) = &mut variable.initializer {
+        visitor.visit_global_initializer_mut(initializer);
+    }
+}
+
+pub fn walk_global_initializer_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    initializer: &mut GlobalInitializer,
+) {
+    match initializer {
+        GlobalInitializer::Scalar { value, .. } => match value {
+            InitializerValue::NumericLiteral { value, .. } => visitor.visit_immediate_mut(value),
+            InitializerValue::FunctionSymbol { name, .. } => {
+                visitor.visit_function_symbol_mut(name)
+            }
+            InitializerValue::StringLiteral { .. } => {}
+        },
+        GlobalInitializer::Aggregate { values, .. } => {
+            for value in values {
+                visitor.visit_global_initializer_mut(value);
+            }
+        }
+    }
+}
+
+pub fn walk_entry_function_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    function: &mut EntryFunctionDirective,
+) {
+    visitor.visit_function_symbol_mut(&mut function.name);
+    for parameter in &mut function.params {
+        visitor.visit_parameter_mut(parameter);
+    }
+    if let Some(body) = &mut function.body {
+        visitor.visit_function_body_mut(body);
+    }
+}
+
+pub fn walk_func_function_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    function: &mut FuncFunctionDirective,
+) {
+    if let Some(parameter) = &mut function.return_param {
+        visitor.visit_parameter_mut(parameter);
+    }
+    visitor.visit_function_symbol_mut(&mut function.name);
+    for parameter in &mut function.params {
+        visitor.visit_parameter_mut(parameter);
+    }
+    for directive in &mut function.pre_body_declarations {
+        visitor.visit_statement_directive_mut(directive);
+    }
+    if let Some(body) = &mut function.body {
+        visitor.visit_function_body_mut(body);
+    }
+}
+
+pub fn walk_alias_function_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    alias: &mut AliasFunctionDirective,
+) {
+    visitor.visit_function_symbol_mut(&mut alias.alias);
+    visitor.visit_function_symbol_mut(&mut alias.target);
+}
+
+pub fn walk_parameter_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    parameter: &mut ParameterDirective,
+) {
+    match parameter {
+        ParameterDirective::Register { name, .. } | ParameterDirective::Parameter { name, .. } => {
+            visitor.visit_variable_symbol_mut(name)
+        }
+    }
+}
+
+pub fn walk_function_body_mut<V: VisitorMut + ?Sized>(visitor: &mut V, body: &mut FunctionBody) {
+    for statement in &mut body.statements {
+        visitor.visit_statement_mut(statement);
+    }
+}
+
+pub fn walk_statement_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    statement: &mut FunctionStatement,
+) {
+    match statement {
+        FunctionStatement::Label { label, .. } => visitor.visit_label_mut(label),
+        FunctionStatement::Directive { directive, .. } => {
+            visitor.visit_statement_directive_mut(directive)
+        }
+        FunctionStatement::Instruction { instruction, .. } => {
+            visitor.visit_instruction_mut(instruction)
+        }
+        FunctionStatement::Block { statements, .. } => {
+            for statement in statements {
+                visitor.visit_statement_mut(statement);
+            }
+        }
+        FunctionStatement::Error { .. } => {}
+    }
+}
+
+pub fn walk_statement_directive_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    directive: &mut StatementDirective,
+) {
+    match directive {
+        StatementDirective::Reg { directive, .. } => {
+            for register in &mut directive.registers {
+                visitor.visit_variable_symbol_mut(&mut register.name);
+            }
+        }
+        StatementDirective::Local { directive, .. }
+        | StatementDirective::Param { directive, .. }
+        | StatementDirective::Shared { directive, .. } => {
+            visitor.visit_variable_directive_mut(directive)
+        }
+        StatementDirective::BranchTargets { directive, .. } => {
+            for label in &mut directive.labels {
+                visitor.visit_label_mut(label);
+            }
+        }
+        StatementDirective::CallTargets { directive, .. } => {
+            for target in &mut directive.targets {
+                visitor.visit_function_symbol_mut(target);
+            }
+        }
+        StatementDirective::CallPrototype { directive, .. } => {
+            if let Some(parameter) = &mut directive.return_param {
+                visitor.visit_parameter_mut(parameter);
+            }
+            for parameter in &mut directive.params {
+                visitor.visit_parameter_mut(parameter);
+            }
+        }
+        StatementDirective::Loc { .. }
+        | StatementDirective::Pragma { .. }
+        | StatementDirective::Section { .. }
+        | StatementDirective::Dwarf { .. } => {}
+    }
+}
+
+pub fn walk_instruction_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    instruction: &mut Instruction,
+) {
+    if let Some(predicate) = &mut instruction.predicate {
+        visitor.visit_predicate_mut(predicate);
+    }
+    visitor.visit_inst_mut(&mut instruction.inst);
+}
+
+pub fn walk_predicate_mut<V: VisitorMut + ?Sized>(visitor: &mut V, predicate: &mut Predicate) {
+    visitor.visit_operand_mut(&mut predicate.operand);
+}
+
+pub fn walk_inst_mut<V: VisitorMut + ?Sized>(visitor: &mut V, inst: &mut Inst) {
+    inst.walk_mut(visitor);
+}
+
+pub fn walk_general_operand_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    operand: &mut GeneralOperand,
+) {
+    match operand {
+        GeneralOperand::Vec { operand, .. } => visitor.visit_vector_operand_mut(operand),
+        GeneralOperand::Single { operand, .. } => visitor.visit_operand_mut(operand),
+    }
+}
+
+pub fn walk_vector_operand_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    operand: &mut VectorOperand,
+) {
+    let operands: &mut [Operand] = match operand {
+        VectorOperand::Vector1 { operand, .. } => std::slice::from_mut(operand),
+        VectorOperand::Vector2 { operands, .. } => operands,
+        VectorOperand::Vector3 { operands, .. } => operands,
+        VectorOperand::Vector4 { operands, .. } => operands,
+        VectorOperand::Vector8 { operands, .. } => operands,
+    };
+    for operand in operands {
+        visitor.visit_operand_mut(operand);
+    }
+}
+
+pub fn walk_operand_mut<V: VisitorMut + ?Sized>(visitor: &mut V, operand: &mut Operand) {
+    match operand {
+        Operand::Register { operand, .. } => visitor.visit_register_mut(operand),
+        Operand::Immediate { operand, .. } => visitor.visit_immediate_mut(operand),
+        Operand::SymbolOffset {
+            symbol,
+            offset,
+            span,
+        } => {
+            visitor.visit_symbol_mut(symbol, *span);
+            visitor.visit_immediate_mut(offset);
+        }
+        Operand::Symbol { name, span } => visitor.visit_symbol_mut(name, *span),
+    }
+}
+
+pub fn walk_address_operand_mut<V: VisitorMut + ?Sized>(
+    visitor: &mut V,
+    operand: &mut AddressOperand,
+) {
+    match operand {
+        AddressOperand::Array { base, index, .. } => {
+            visitor.visit_variable_symbol_mut(base);
+            visitor.visit_immediate_mut(index);
+        }
+        AddressOperand::ImmediateAddress { addr, .. } => visitor.visit_immediate_mut(addr),
+        AddressOperand::Offset { base, offset, .. } => {
+            match base {
+                AddressBase::Register { operand, .. } => visitor.visit_register_mut(operand),
+                AddressBase::Variable { symbol, .. } => visitor.visit_variable_symbol_mut(symbol),
+            }
+            match offset {
+                Some(AddressOffset::Register { operand, .. }) => {
+                    visitor.visit_register_mut(operand)
+                }
+                Some(AddressOffset::Immediate { value, .. }) => visitor.visit_immediate_mut(value),
+                None => {}
+            }
+        }
+    }
+}
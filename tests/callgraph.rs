@@ -0,0 +1,117 @@
+use ptx_parser::analysis::{CallGraph, CallKind, CallOperands, FunctionId};
+use ptx_parser::parse_ptx;
+
+const SOURCE: &str = r#".version 8.5
+.target sm_90
+.address_size 64
+
+.extern .func (.param .b32 r) ext(.param .b32 a);
+.func even(.param .b32 n) { call odd, (n); ret; }
+.func odd(.param .b32 n) { call even, (n); ret; }
+.func (.param .b32 r) inc(.param .b32 a) { ret; }
+.func (.param .b32 r) dec(.param .b32 a) { ret; }
+.func unused() { call even; ret; }
+.alias parity, even;
+.global .u64 table[2] = {inc, dec};
+
+.entry k()
+{
+    .reg .b64 %rd<3>;
+    .param .b32 p;
+    .param .b32 q;
+    call.uni (q), ext, (p);
+    call parity, (p);
+    mov.u64 %rd1, odd;
+    ld.global.u64 %rd2, [table];
+TARGETS: .calltargets even, parity;
+PROTO: .callprototype .param .b32 _ (.param .b32 _);
+    call %rd1, (p), TARGETS;
+    call (q), %rd2, (p), PROTO;
+    call %rd2, UNDECLARED;
+    call missing;
+    ret;
+}
+"#;
+
+#[test]
+fn direct_calls_resolve_aliases_and_report_recursion() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let graph = CallGraph::build(&module);
+        let id = |name: &str| graph.function(name).expect(name);
+        let names = |ids: &[FunctionId]| -> Vec<&str> {
+            ids.iter().map(|&id| graph.node(id).name).collect()
+        };
+
+        assert_eq!(id("parity"), id("even"));
+        assert!(graph.node(id("k")).is_entry());
+        assert!(!graph.node(id("ext")).is_defined());
+        assert!(graph.node(id("missing")).function.is_none());
+
+        assert_eq!(names(graph.callers(id("even"))), ["odd", "unused", "k"]);
+        assert_eq!(names(&graph.recursive_cycles()[0]), ["even", "odd"]);
+        assert_eq!(graph.recursive_cycles().len(), 1);
+        assert!(graph.is_recursive(id("odd")));
+        assert!(!graph.is_recursive(id("k")));
+
+        assert_eq!(names(&graph.unreachable()), ["unused"]);
+        assert_eq!(names(&graph.extern_callees()), ["ext", "missing"]);
+    });
+}
+
+#[test]
+fn indirect_calls_are_bounded_by_targets_and_prototypes() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let graph = CallGraph::build(&module);
+        let names = |ids: &[FunctionId]| -> Vec<&str> {
+            ids.iter().map(|&id| graph.node(id).name).collect()
+        };
+
+        assert_eq!(names(graph.address_taken()), ["odd", "inc", "dec"]);
+        let k = graph.function("k").unwrap();
+        let calls: Vec<(CallKind, Vec<&str>)> = graph
+            .calls_from(k)
+            .map(|site| (site.kind, names(&site.callees)))
+            .collect();
+        assert_eq!(
+            calls,
+            [
+                (CallKind::Direct, vec!["ext"]),
+                (CallKind::Direct, vec!["even"]),
+                (CallKind::CallTargets, vec!["even"]),
+                (CallKind::Prototype, vec!["inc", "dec"]),
+                (CallKind::Unknown, vec!["odd", "inc", "dec"]),
+                (CallKind::Direct, vec!["missing"]),
+            ]
+        );
+
+        let site = &graph.call_sites()[6];
+        assert_eq!(site.path, [12]);
+        let call = CallOperands::of(site.instruction).expect("a call");
+        assert!(call.return_param.is_some());
+        assert_eq!(call.params.len(), 1);
+        assert!(call.targets.is_some());
+    });
+}
+
+#[test]
+fn dot_marks_kernels_externs_and_indirect_calls() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let graph = CallGraph::build(&module);
+        let dot = graph.to_dot();
+
+        let node = |name: &str| format!("f{}", graph.function(name).unwrap().index());
+        assert!(dot.starts_with("digraph callgraph {\n"));
+        assert!(dot.contains(&format!("{} [label=\"k\", shape=box];", node("k"))));
+        assert!(dot.contains(&format!("{} [label=\"ext\", style=dashed];", node("ext"))));
+        assert!(dot.contains(&format!(
+            "{} [label=\"unused\", color=gray];",
+            node("unused")
+        )));
+        assert!(dot.contains(&format!("{} -> {};", node("k"), node("even"))));
+        assert!(dot.contains(&format!("{} -> {} [style=dotted];", node("k"), node("inc"))));
+        assert!(dot.ends_with("}\n"));
+    });
+}
@@ -0,0 +1,251 @@
+mod util;
+
+use ptx_parser::analysis::{CallIssue, Callee, check_calls};
+use ptx_parser::{SourceMap, parse_ptx};
+use util::messages;
+
+const CALLEES: &str = r#".func (.param .b32 r) f(.param .u32 a, .param .align 8 .b8 s[16])
+{
+    ret;
+}
+.func g(.reg .f32 x)
+{
+    ret;
+}
+.alias h, f;
+"#;
+
+/// Source of [`CALLEES`] and a kernel running `body`, with `.param`
+/// variables of several types to pass.
+fn module(body: &str) -> String {
+    format!(
+        r#"{CALLEES}
+.entry k(.param .u64 p)
+{{
+    .reg .b32 %r<2>;
+    .reg .f32 %f<2>;
+    .reg .b64 %rd<2>;
+    .param .b32 ret;
+    .param .b64 ret64;
+    .param .s32 arg;
+    .param .f32 farg;
+    .param .align 8 .b8 pair[16];
+    .param .v2 .b64 wide;
+{body}
+    ret;
+}}
+"#
+    )
+}
+
+fn check(body: &str) -> Vec<String> {
+    let source = module(body);
+    messages(&check_calls(
+        &parse_ptx(&source).expect("module should parse"),
+    ))
+}
+
+#[test]
+fn matching_calls_are_accepted() {
+    ptx_parser::run_with_large_stack(|| {
+        // `.s32` and `.u32` agree, `.b8[16]` takes any 16 bytes and `.b32`
+        // registers fit `.f32` parameters.
+        let body = r#"
+    call (ret), f, (arg, pair);
+    call (ret), f, (arg, wide);
+    call (ret), h, (arg, pair);
+    call g, (%f1);
+    call g, (%r1);
+"#;
+        assert_eq!(check(body), Vec::<String>::new());
+    });
+}
+
+#[test]
+fn argument_counts_must_match() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    call (ret), f, (arg);
+    call g, (%f1, %f1);
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`call` passes 1 argument to `f`, which takes 2",
+                "`call` passes 2 arguments to `g`, which takes 1",
+            ]
+        );
+    });
+}
+
+#[test]
+fn return_values_must_match() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    call f, (arg, pair);
+    call (ret), g, (%f1);
+    call (ret64), f, (arg, pair);
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`f` returns a value, but the call does not receive it",
+                "`g` does not return a value, but the call receives one",
+                "`ret64` is `.b64`, but parameter `r` of `f` is `.b32`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn argument_types_must_match() {
+    ptx_parser::run_with_large_stack(|| {
+        // Integers and floats of the same size do not mix, and neither do
+        // sizes.
+        let body = r#"
+    call (ret), f, (farg, pair);
+    call (ret), f, (p, pair);
+    call g, (%rd1);
+    call (ret), f, (arg, ret64);
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`farg` is `.f32`, but parameter `a` of `f` is `.u32`",
+                "`p` is `.u64`, but parameter `a` of `f` is `.u32`",
+                "`%rd1` is `.b64`, but parameter `x` of `g` is `.f32`",
+                "`ret64` is `.b64`, but parameter `s` of `f` is `.b8[16]`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn aliases_are_checked_against_their_function() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = module("    call (ret), h, (p, pair);\n    call h, (arg, pair);");
+        let issues = check_calls(&parse_ptx(&source).unwrap());
+        assert_eq!(
+            messages(&issues),
+            [
+                "`p` is `.u64`, but parameter `a` of `h` is `.u32`",
+                "`h` returns a value, but the call does not receive it",
+            ]
+        );
+        let CallIssue::Mismatch { callee, .. } = &issues[0] else {
+            panic!("expected a mismatch: {:?}", issues[0]);
+        };
+        assert_eq!(callee, &Callee::Function("h".to_string()));
+    });
+}
+
+#[test]
+fn indirect_calls_are_checked_against_every_target() {
+    ptx_parser::run_with_large_stack(|| {
+        // The `.calltargets` call is checked against both `f` and `g`.
+        let body = r#"
+TARGETS: .calltargets f, g;
+    call (ret), %rd1, (arg, pair), TARGETS;
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`g` does not return a value, but the call receives one",
+                "`call` passes 2 arguments to `g`, which takes 1",
+            ]
+        );
+    });
+}
+
+#[test]
+fn indirect_calls_are_checked_against_their_prototype() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+PROTO: .callprototype _ (.param .b32 _);
+    call %rd1, (arg), PROTO;
+    call %rd1, (p), PROTO;
+    call (ret), %rd1, (arg), PROTO;
+    call %rd1, (arg, arg), PROTO;
+"#;
+        let source = module(body);
+        let issues = check_calls(&parse_ptx(&source).unwrap());
+        assert_eq!(
+            messages(&issues),
+            [
+                "`p` is `.u64`, but parameter `_` of prototype `PROTO` is `.b32`",
+                "prototype `PROTO` does not return a value, but the call receives one",
+                "`call` passes 2 arguments to prototype `PROTO`, which takes 1",
+            ]
+        );
+        let CallIssue::Return { callee, .. } = &issues[1] else {
+            panic!("expected a return issue: {:?}", issues[1]);
+        };
+        assert_eq!(callee, &Callee::Prototype("PROTO".to_string()));
+        assert_eq!(callee.to_string(), "prototype `PROTO`");
+    });
+}
+
+#[test]
+fn arguments_resolve_in_the_innermost_block() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    {
+        .param .f32 arg;
+        call (ret), f, (arg, pair);
+    }
+    call (ret), f, (arg, pair);
+"#;
+        assert_eq!(
+            check(body),
+            ["`arg` is `.f32`, but parameter `a` of `f` is `.u32`"]
+        );
+    });
+}
+
+#[test]
+fn unknown_callees_and_immediates_are_not_checked() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    call missing, (arg);
+    call g, (1);
+    call (ret), f, (undeclared, pair);
+"#;
+        assert_eq!(check(body), Vec::<String>::new());
+    });
+}
+
+#[test]
+fn diagnostics_point_at_the_argument() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#".func (.param .b32 r) f(.param .u32 a)
+{
+    ret;
+}
+.entry k()
+{
+    .param .b32 ret;
+    .param .f32 arg;
+    call (ret), f, (arg);
+    call (ret), f, (arg, arg);
+}
+"#;
+        let issues = check_calls(&parse_ptx(source).unwrap());
+        assert_eq!(issues.len(), 2);
+
+        let mismatch = &issues[0];
+        assert_eq!(&source[mismatch.span().start..mismatch.span().end], "arg");
+        assert_eq!(
+            mismatch
+                .to_diagnostic()
+                .render(&SourceMap::new(source), Some("k.ptx")),
+            "error: `arg` is `.f32`, but parameter `a` of `f` is `.u32`\n --> k.ptx:9:21\n  |\n9 |     call (ret), f, (arg);\n  |                     ^^^ expected `.u32`\n"
+        );
+
+        let count = issues[1].to_diagnostic();
+        assert_eq!(count.label.as_deref(), Some("expected 1 argument"));
+        assert_eq!(
+            &source[count.span.start..count.span.end],
+            "call (ret), f, (arg, arg);"
+        );
+    });
+}
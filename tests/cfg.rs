@@ -0,0 +1,267 @@
+use ptx_parser::analysis::{BlockId, Cfg, Edge, EdgeKind, functions};
+use ptx_parser::parse_ptx;
+use ptx_parser::r#type::FunctionStatement;
+
+const SAMPLES: &[&str] = &[
+    include_str!("sample/hello.ptx"),
+    include_str!("sample/vector_add_scalar.debug.ptx"),
+    include_str!("sample/mini_step64.ptx"),
+    include_str!("sample/matrix_free_device_matrix_vector_01.1.sm_80.ptx"),
+];
+
+const SOURCE: &str = r#".entry k()
+{
+    .reg .b32 %r<4>;
+    .reg .pred %p<2>;
+    setp.eq.u32 %p1, %r1, 0;
+    @%p1 bra.uni DONE;
+    {
+        add.s32 %r1, %r1, 1;
+    }
+LOOP:
+    brx.idx %r2, TARGETS;
+TARGETS: .branchtargets LOOP, DONE, LOOP;
+DONE:
+    @%p1 exit;
+    mov.u32 %r3, %r1;
+}
+"#;
+
+fn edge(target: usize, kind: EdgeKind, uniform: bool) -> Edge {
+    Edge {
+        target: BlockId::new(target),
+        kind,
+        uniform,
+    }
+}
+
+#[test]
+fn blocks_split_at_labels_and_branches() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let body = functions(&module).next().unwrap().body().unwrap();
+        let cfg = Cfg::build(body);
+
+        assert_eq!(cfg.len(), 5);
+        let sizes: Vec<_> = cfg
+            .blocks()
+            .iter()
+            .map(|block| block.instructions().count())
+            .collect();
+        assert_eq!(sizes, [2, 1, 1, 1, 1]);
+        assert_eq!(cfg.block_of_label("LOOP"), Some(BlockId::new(2)));
+        assert_eq!(cfg.block_of_label("DONE"), Some(BlockId::new(3)));
+        assert_eq!(cfg.block_of_label("MISSING"), None);
+
+        // The `add` inside the nested block keeps its path into the body.
+        let nested = &cfg.block(BlockId::new(1)).statements[0];
+        assert_eq!(nested.path, [4, 0]);
+        assert!(matches!(
+            nested.statement,
+            FunctionStatement::Instruction { .. }
+        ));
+        assert_eq!(cfg.block_of_statement(&[4, 0]), Some(BlockId::new(1)));
+        assert_eq!(cfg.block_of_statement(&[4]), None);
+        assert_eq!(
+            cfg.block(BlockId::new(3))
+                .terminator()
+                .map(|inst| inst.inst.opcode()),
+            Some("exit")
+        );
+    });
+}
+
+#[test]
+fn edges_follow_branches_and_branch_targets() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let body = functions(&module).next().unwrap().body().unwrap();
+        let cfg = Cfg::build(body);
+
+        assert_eq!(
+            cfg.block(cfg.entry()).successors,
+            [
+                edge(3, EdgeKind::Branch, true),
+                edge(1, EdgeKind::FallThrough, false),
+            ]
+        );
+        assert_eq!(
+            cfg.block(BlockId::new(1)).successors,
+            [edge(2, EdgeKind::FallThrough, false)]
+        );
+        // Repeated `.branchtargets` labels produce one edge each.
+        assert_eq!(
+            cfg.block(BlockId::new(2)).successors,
+            [
+                edge(2, EdgeKind::Indirect, false),
+                edge(3, EdgeKind::Indirect, false),
+            ]
+        );
+        assert_eq!(
+            cfg.block(BlockId::new(3)).successors,
+            [edge(4, EdgeKind::FallThrough, false)]
+        );
+
+        assert_eq!(
+            cfg.predecessors(BlockId::new(2)),
+            [BlockId::new(1), BlockId::new(2)]
+        );
+        assert_eq!(
+            cfg.predecessors(BlockId::new(3)),
+            [BlockId::new(0), BlockId::new(2)]
+        );
+        assert_eq!(
+            cfg.successors(BlockId::new(2)),
+            [BlockId::new(2), BlockId::new(3)]
+        );
+        // The predicated `exit` and the fall-through off the end both leave.
+        assert_eq!(cfg.exits(), [BlockId::new(3), BlockId::new(4)]);
+    });
+}
+
+#[test]
+fn dot_output_lists_blocks_and_edge_styles() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let body = functions(&module).next().unwrap().body().unwrap();
+        let dot = Cfg::build(body).to_dot("k");
+
+        assert!(dot.starts_with("digraph \"k\" {\n"), "{dot}");
+        assert!(dot.contains("@%p1 bra.uni DONE;\\l"), "{dot}");
+        assert!(dot.contains("LOOP:\\l"), "{dot}");
+        assert!(dot.contains("b0 -> b3 [label=\"uni\"];"), "{dot}");
+        assert!(dot.contains("b0 -> b1 [style=dashed];"), "{dot}");
+        assert!(dot.contains("b2 -> b3 [style=dotted];"), "{dot}");
+        assert!(dot.ends_with("}\n"));
+    });
+}
+
+#[test]
+fn samples_build_consistent_graphs() {
+    ptx_parser::run_with_large_stack(|| {
+        for source in SAMPLES {
+            let module = parse_ptx(source).expect("sample should parse");
+            for function in functions(&module) {
+                let Some(body) = function.body() else {
+                    continue;
+                };
+                let cfg = Cfg::build(body);
+                assert!(!cfg.is_empty());
+                assert!(!cfg.exits().is_empty(), "{} has no exit", function.name());
+                for id in cfg.block_ids() {
+                    for successor in cfg.successors(id) {
+                        assert!(cfg.predecessors(successor).contains(&id));
+                    }
+                }
+            }
+        }
+    });
+}
+
+#[test]
+fn branch_targets_resolve_in_the_enclosing_blocks() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#".entry k()
+{
+    .reg .pred %p1;
+    {
+    L:
+        @%p1 bra L;
+    }
+    {
+    L:
+        @%p1 bra L;
+        bra.uni END;
+    }
+END:
+    ret;
+}
+"#;
+        let module = parse_ptx(source).expect("module should parse");
+        let body = functions(&module).next().unwrap().body().unwrap();
+        let cfg = Cfg::build(body);
+
+        // Each `bra L` loops on the `L` of its own block.
+        assert_eq!(cfg.len(), 4);
+        assert_eq!(
+            cfg.block(BlockId::new(0)).successors,
+            [
+                edge(0, EdgeKind::Branch, false),
+                edge(1, EdgeKind::FallThrough, false),
+            ]
+        );
+        assert_eq!(
+            cfg.block(BlockId::new(1)).successors,
+            [
+                edge(1, EdgeKind::Branch, false),
+                edge(2, EdgeKind::FallThrough, false),
+            ]
+        );
+        // Labels of the body are visible from nested blocks.
+        assert_eq!(
+            cfg.block(BlockId::new(2)).successors,
+            [edge(3, EdgeKind::Branch, true)]
+        );
+        assert!(cfg.unresolved().is_empty());
+        assert_eq!(cfg.block_of_label("L"), Some(BlockId::new(0)));
+    });
+}
+
+#[test]
+fn missing_targets_are_recorded_without_edges() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#".entry k()
+{
+    .reg .b32 %r1;
+    .reg .pred %p1;
+    @%p1 bra MISSING;
+    {
+    INNER:
+        brx.idx %r1, TARGETS;
+    TARGETS: .branchtargets INNER, GONE;
+    }
+    @%p1 brx.idx %r1, TARGETS;
+    bra INNER;
+}
+"#;
+        let module = parse_ptx(source).expect("module should parse");
+        let body = functions(&module).next().unwrap().body().unwrap();
+        let cfg = Cfg::build(body);
+
+        let unresolved: Vec<_> = cfg
+            .unresolved()
+            .iter()
+            .map(|target| {
+                (
+                    target.block.index(),
+                    target.label,
+                    &source[target.span.start..target.span.end],
+                )
+            })
+            .collect();
+        // `TARGETS` and `INNER` are declared in the nested block only.
+        assert_eq!(
+            unresolved,
+            [
+                (0, "MISSING", "MISSING"),
+                (1, "GONE", "GONE"),
+                (2, "TARGETS", "TARGETS"),
+                (3, "INNER", "INNER"),
+            ]
+        );
+        assert_eq!(
+            cfg.block(BlockId::new(0)).successors,
+            [edge(1, EdgeKind::FallThrough, false)]
+        );
+        assert_eq!(
+            cfg.block(BlockId::new(1)).successors,
+            [edge(1, EdgeKind::Indirect, false)]
+        );
+        // The guarded `brx.idx` still falls through.
+        assert_eq!(
+            cfg.block(BlockId::new(2)).successors,
+            [edge(3, EdgeKind::FallThrough, false)]
+        );
+        assert!(cfg.block(BlockId::new(3)).successors.is_empty());
+    });
+}
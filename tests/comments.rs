@@ -0,0 +1,168 @@
+use ptx_parser::{
+    PtxToken, PtxUnlexer, PtxUnparser, TriviaKind, parse_ptx, parse_ptx_with_comments, tokenize,
+    tokenize_with_trivia, unparse_with_comments,
+};
+
+fn rewrite(source: &str) -> String {
+    let (module, comments) = parse_ptx_with_comments(source).expect("module should parse");
+    let tokens = unparse_with_comments(&module, &comments, true);
+    PtxUnlexer::to_string(&tokens).expect("unlex")
+}
+
+fn comment_texts(source: &str) -> Vec<String> {
+    let (_, trivia) = tokenize_with_trivia(source).expect("source should lex");
+    trivia
+        .iter()
+        .flat_map(|entry| entry.leading.iter().chain(&entry.trailing))
+        .filter(|piece| piece.is_comment())
+        .map(|piece| source[piece.span.start..piece.span.end].to_string())
+        .collect()
+}
+
+#[test]
+fn trivia_reconstructs_source_exactly() {
+    let source = "// header\n.version 8.5 /* v */\n\n.target sm_90\t// t\r\n";
+    let (tokens, trivia) = tokenize_with_trivia(source).expect("source should lex");
+    assert_eq!(trivia.len(), tokens.len() + 1);
+    assert_eq!(
+        tokens
+            .iter()
+            .map(|(token, _)| token.clone())
+            .collect::<Vec<_>>(),
+        tokenize(source)
+            .unwrap()
+            .into_iter()
+            .map(|(token, _)| token)
+            .collect::<Vec<_>>()
+    );
+
+    let mut rebuilt = String::new();
+    for (index, entry) in trivia.iter().enumerate() {
+        for piece in &entry.leading {
+            rebuilt.push_str(&source[piece.span.start..piece.span.end]);
+        }
+        if let Some((_, span)) = tokens.get(index) {
+            rebuilt.push_str(&source[span.start..span.end]);
+        }
+        for piece in &entry.trailing {
+            rebuilt.push_str(&source[piece.span.start..piece.span.end]);
+        }
+    }
+    assert_eq!(rebuilt, source);
+
+    let kinds: Vec<_> = trivia
+        .iter()
+        .flat_map(|entry| entry.leading.iter().chain(&entry.trailing))
+        .filter(|piece| piece.is_comment())
+        .map(|piece| piece.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            TriviaKind::LineComment,
+            TriviaKind::BlockComment,
+            TriviaKind::LineComment
+        ]
+    );
+}
+
+#[test]
+fn sample_comments_survive_in_order() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = include_str!("sample/vector_add_scalar.ptx");
+        let (module, comments) = parse_ptx_with_comments(source).expect("module should parse");
+        let text = rewrite(source);
+
+        let collected: Vec<_> = comments
+            .iter()
+            .map(|comment| comment.text.clone())
+            .collect();
+        assert_eq!(collected, comment_texts(source));
+        assert_eq!(comment_texts(&text), comment_texts(source));
+
+        let reparsed = parse_ptx(&text).expect("rewritten module should parse");
+        assert_eq!(reparsed.to_tokens(), module.to_tokens());
+    });
+}
+
+#[test]
+fn comments_stay_next_to_their_nodes() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = "\
+.version 8.5
+.target sm_90 // target
+.entry kernel() {
+    // begin inline asm
+    mov.u32 %r1, %r2; // copy
+    // end inline asm
+    {
+        add.s32 %r1, %r1, 1;
+        // dangling in block
+    }
+    ret;
+    // dangling in body
+}
+// trailer
+";
+        let text = rewrite(source);
+        let expected_order = [
+            "// target",
+            "// begin inline asm",
+            "mov.u32",
+            "// copy",
+            "// end inline asm",
+            "add.s32",
+            "// dangling in block",
+            "ret;",
+            "// dangling in body",
+            "}",
+            "// trailer",
+        ];
+        let mut cursor = 0;
+        for needle in expected_order {
+            let found = text[cursor..]
+                .find(needle)
+                .unwrap_or_else(|| panic!("`{needle}` missing or out of order in:\n{text}"));
+            cursor += found + needle.len();
+        }
+        assert!(text.contains("sm_90 // target\n"), "{text}");
+        assert!(text.contains("// begin inline asm\n"), "{text}");
+        assert!(text.contains("; // copy\n"), "{text}");
+    });
+}
+
+#[test]
+fn comments_inside_a_statement_trail_it() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = ".version 8.5\n.target sm_90\n.entry k() {\n    add.s32 %r1, /* lhs */ %r2, %r3;\n    ret;\n}\n";
+        let (module, comments) = parse_ptx_with_comments(source).expect("module should parse");
+        assert_eq!(comments.len(), 1);
+
+        let text = rewrite(source);
+        assert!(text.contains("%r3; /* lhs */\n"), "{text}");
+        assert_eq!(
+            parse_ptx(&text)
+                .expect("rewritten module should parse")
+                .to_tokens(),
+            module.to_tokens()
+        );
+    });
+}
+
+#[test]
+fn without_comments_output_matches_spaced_unparse() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = include_str!("sample/hello.ptx");
+        let (module, _) = parse_ptx_with_comments(source).expect("module should parse");
+        let empty = ptx_parser::Comments::default();
+        assert_eq!(
+            unparse_with_comments(&module, &empty, true),
+            module.to_tokens_spaced()
+        );
+        assert!(
+            !unparse_with_comments(&module, &empty, false)
+                .iter()
+                .any(|token| matches!(token, PtxToken::Comment(_)))
+        );
+    });
+}
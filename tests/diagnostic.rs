@@ -0,0 +1,121 @@
+use ptx_parser::{Diagnostic, LineCol, SourceMap, Span, parse_ptx};
+
+#[test]
+fn line_col_counts_utf8_bytes_and_utf16_units() {
+    let source = "// héllo 😀\nmov.u32 %r1, %r2;\r\nret;";
+    let map = SourceMap::new(source);
+
+    assert_eq!(map.line_count(), 3);
+    assert_eq!(map.line_text(0), Some("// héllo 😀"));
+    assert_eq!(map.line_text(1), Some("mov.u32 %r1, %r2;"));
+    assert_eq!(map.line_text(2), Some("ret;"));
+    assert_eq!(map.line_text(3), None);
+
+    let end_of_first = source.find('\n').unwrap();
+    assert_eq!(
+        map.line_col(end_of_first),
+        LineCol {
+            line: 0,
+            column: 14
+        }
+    );
+    assert_eq!(
+        map.line_col_utf16(end_of_first),
+        LineCol {
+            line: 0,
+            column: 11
+        }
+    );
+
+    let ret = source.find("ret").unwrap();
+    assert_eq!(map.line_col(ret), LineCol { line: 2, column: 0 });
+    assert_eq!(map.line_col(source.len() + 10).line, 2);
+}
+
+#[test]
+fn offsets_round_trip_through_line_col() {
+    let source = "é😀x\nab";
+    let map = SourceMap::new(source);
+
+    for (offset, _) in source.char_indices() {
+        assert_eq!(map.offset(map.line_col(offset)), Some(offset));
+        assert_eq!(map.offset_utf16(map.line_col_utf16(offset)), Some(offset));
+    }
+    // Inside the surrogate pair of the emoji.
+    assert_eq!(map.offset_utf16(LineCol { line: 0, column: 2 }), None);
+    assert_eq!(map.offset(LineCol { line: 0, column: 3 }), None);
+    assert_eq!(map.offset(LineCol { line: 5, column: 0 }), None);
+}
+
+#[test]
+fn render_underlines_span_with_label_and_notes() {
+    let source = ".entry k() {\n\tmov.u32 %r1 %r2;\n}\n";
+    let start = source.find("%r2").unwrap();
+    let diagnostic = Diagnostic::error("something went wrong", Span::new(start, start + 3))
+        .with_label("here")
+        .with_note("first note");
+
+    let rendered = diagnostic.render(&SourceMap::new(source), Some("kernel.ptx"));
+    let expected = "\
+error: something went wrong
+ --> kernel.ptx:2:14
+  |
+2 |     mov.u32 %r1 %r2;
+  |                 ^^^ here
+  |
+  = note: first note
+";
+    assert_eq!(rendered, expected);
+}
+
+#[test]
+fn render_handles_empty_and_end_of_input_spans() {
+    let source = "ret";
+    let map = SourceMap::new(source);
+
+    let rendered = Diagnostic::error("unexpected end of input", Span::new(3, 3)).render(&map, None);
+    assert!(rendered.contains("--> <input>:1:4"), "{rendered}");
+    assert!(rendered.ends_with("  |    ^\n"), "{rendered}");
+}
+
+#[test]
+fn parse_error_renders_failing_instruction() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = ".version 8.5\n.target sm_90\n.entry k() {\n    mov.u32 %r1 %r2;\n}\n";
+        let err = parse_ptx(source).expect_err("missing comma should not parse");
+
+        let map = SourceMap::new(source);
+        assert_eq!(
+            err.line_col(&map),
+            LineCol {
+                line: 3,
+                column: 16
+            }
+        );
+
+        // The message alone carries no location; `render` adds it.
+        assert!(
+            err.to_string()
+                .starts_with("no matching format for `mov`: closest candidate"),
+            "{err}"
+        );
+
+        let rendered = err.render(source, Some("kernel.ptx"));
+        assert!(
+            rendered.starts_with("error: no matching format for `mov`\n --> kernel.ptx:4:17\n"),
+            "{rendered}"
+        );
+        assert!(
+            rendered.contains("4 |     mov.u32 %r1 %r2;\n"),
+            "{rendered}"
+        );
+        assert!(
+            rendered.contains("^^^ expected `Comma`, found Register(\"%r2\")"),
+            "{rendered}"
+        );
+        assert!(
+            rendered.contains("= note: closest candidate is `mov::section_0::MovType`"),
+            "{rendered}"
+        );
+    });
+}
@@ -0,0 +1,103 @@
+use ptx_parser::analysis::{BlockId, Cfg, DominatorTree, functions};
+use ptx_parser::parse_ptx;
+
+/// b0 branches to b1 or b2, which join at b3; b4 is dead code after `ret`
+/// and b5 returns separately from the diamond's `@%p2` exit.
+const SOURCE: &str = r#".entry k()
+{
+    @%p1 bra ELSE;
+    mov.u32 %r1, 1;
+    bra.uni JOIN;
+ELSE:
+    mov.u32 %r1, 2;
+JOIN:
+    @%p2 bra EARLY;
+    ret;
+DEAD:
+    mov.u32 %r1, 3;
+EARLY:
+    exit;
+}
+"#;
+
+fn ids(indices: &[usize]) -> Vec<BlockId> {
+    indices.iter().map(|&index| BlockId::new(index)).collect()
+}
+
+#[test]
+fn dominators_of_a_diamond() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let cfg = Cfg::build(functions(&module).next().unwrap().body().unwrap());
+        assert_eq!(cfg.len(), 7);
+        let tree = DominatorTree::dominators(&cfg);
+        let [b0, b1, b2, b3, b4, b5, b6] = [0, 1, 2, 3, 4, 5, 6].map(BlockId::new);
+
+        assert_eq!(tree.roots(), [b0]);
+        assert_eq!(tree.immediate(b0), None);
+        assert_eq!(tree.immediate(b1), Some(b0));
+        assert_eq!(tree.immediate(b2), Some(b0));
+        assert_eq!(tree.immediate(b3), Some(b0));
+        assert_eq!(tree.immediate(b4), Some(b3));
+        assert_eq!(tree.immediate(b6), Some(b3));
+        assert_eq!(tree.children(b0), [b1, b2, b3]);
+
+        // The dead block after `ret` is unreachable but still falls into b6.
+        assert!(!tree.contains(b5));
+        assert_eq!(tree.immediate(b5), None);
+        assert!(!tree.dominates(b0, b5));
+
+        assert!(tree.dominates(b0, b6));
+        assert!(tree.dominates(b3, b3));
+        assert!(!tree.strictly_dominates(b3, b3));
+        assert!(!tree.dominates(b1, b3));
+        assert_eq!(tree.ancestors(b6).collect::<Vec<_>>(), [b6, b3, b0]);
+
+        assert_eq!(tree.frontier(b1), [b3]);
+        assert_eq!(tree.frontier(b2), [b3]);
+        assert_eq!(tree.frontier(b0), []);
+    });
+}
+
+#[test]
+fn post_dominators_find_reconvergence_points() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let cfg = Cfg::build(functions(&module).next().unwrap().body().unwrap());
+        let tree = DominatorTree::post_dominators(&cfg);
+        let [b0, b1, b2, b3, b4, b5, b6] = [0, 1, 2, 3, 4, 5, 6].map(BlockId::new);
+
+        assert_eq!(tree.roots(), [b3, b4, b6]);
+        // Both sides of the first branch reconverge at JOIN.
+        assert_eq!(tree.immediate(b0), Some(b3));
+        assert_eq!(tree.immediate(b1), Some(b3));
+        // JOIN leaves through either exit, so it has no single post-dominator.
+        assert_eq!(tree.immediate(b3), None);
+        assert!(tree.contains(b3));
+        assert_eq!(tree.immediate(b5), Some(b6));
+        assert!(tree.dominates(b3, b2));
+
+        // Blocks b4 and b6 are control dependent on the branch ending b3.
+        assert_eq!(tree.frontier(b4), ids(&[3]));
+        assert_eq!(tree.frontier(b6), ids(&[3]));
+        assert_eq!(tree.frontier(b1), ids(&[0]));
+    });
+}
+
+#[test]
+fn infinite_loops_have_no_post_dominators() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = ".entry k() { @%p1 bra SPIN; ret; SPIN: bra.uni SPIN; }";
+        let module = parse_ptx(source).expect("module should parse");
+        let cfg = Cfg::build(functions(&module).next().unwrap().body().unwrap());
+        let spin = cfg.block_of_label("SPIN").unwrap();
+
+        let tree = DominatorTree::post_dominators(&cfg);
+        assert!(!tree.contains(spin));
+        assert!(tree.contains(cfg.entry()));
+
+        let tree = DominatorTree::dominators(&cfg);
+        assert_eq!(tree.immediate(spin), Some(cfg.entry()));
+        assert_eq!(tree.frontier(spin), [spin]);
+    });
+}
@@ -0,0 +1,134 @@
+use ptx_parser::analysis::{
+    FrameReport, LARGE_PARAM_LIMIT, PARAM_LIMIT, functions, module_shared_bytes, param_limit,
+};
+use ptx_parser::{SourceMap, parse_ptx};
+
+const SOURCE: &str = r#".version 7.8
+.target sm_80
+.address_size 64
+
+.extern .shared .align 16 .b8 dynamic[];
+.shared .align 4 .b32 counters[3];
+.shared .align 8 .u64 total;
+
+.func (.param .b32 r) f(.param .b64 a, .param .align 16 .b8 b[20], .reg .b32 c)
+{
+    .local .align 4 .b8 depot[12];
+    .local .align 8 .b64 spill;
+    .reg .b64 %rd<3>;
+    stacksave.u64 %rd1;
+    alloca.u64 %rd2, 64;
+    stackrestore.u64 %rd1;
+    ret;
+}
+
+.visible .entry k(.param .u32 n, .param .align 8 .b8 blob[4096])
+{
+    .shared .align 8 .b8 tile[2][128];
+    .reg .b64 %rd1;
+    {
+        .param .b64 p0;
+        .param .align 16 .b8 p1[20];
+        .param .b32 ret0;
+        call.uni (ret0), f, (p0, p1, 1);
+    }
+    {
+        .param .b32 p0;
+        call f, (p0);
+    }
+    ret;
+}
+"#;
+
+#[test]
+fn parameters_are_laid_out_by_alignment_and_array_size() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let f = FrameReport::of(functions(&module).next().unwrap());
+
+        // `.reg` parameters are passed in registers.
+        let slots: Vec<_> = f
+            .params
+            .iter()
+            .map(|slot| (slot.name, slot.offset, slot.size, slot.align))
+            .collect();
+        assert_eq!(slots, [("a", 0, 8, 8), ("b", 16, 20, 16)]);
+        assert_eq!(f.param_bytes, 36);
+        assert_eq!(f.return_bytes, 4);
+        assert!(!f.is_entry);
+
+        assert_eq!(f.local_bytes, 24);
+        assert_eq!(f.alloca.len(), 1);
+        assert_eq!(f.stacksave.len(), 2);
+        assert!(f.uses_alloca() && f.uses_stacksave());
+    });
+}
+
+#[test]
+fn calls_measure_the_param_variables_in_scope() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let k = FrameReport::of(functions(&module).nth(1).unwrap());
+
+        assert_eq!(k.shared_bytes, 256);
+        assert_eq!(k.local_bytes, 0);
+        assert!(!k.uses_alloca());
+        let calls: Vec<_> = k
+            .calls
+            .iter()
+            .map(|call| {
+                (
+                    call.path.clone(),
+                    call.callee,
+                    call.param_bytes,
+                    call.return_bytes,
+                )
+            })
+            .collect();
+        assert_eq!(
+            calls,
+            [
+                (vec![2, 3], Some("f"), 28, 4),
+                (vec![3, 1], Some("f"), 4, 0)
+            ]
+        );
+
+        // The `.extern` array without a size is dynamic shared memory.
+        assert_eq!(module_shared_bytes(&module), 24);
+    });
+}
+
+#[test]
+fn kernels_above_the_param_limit_are_reported() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let limit = param_limit(&module);
+        assert_eq!(limit, PARAM_LIMIT);
+
+        let f = FrameReport::of(functions(&module).next().unwrap());
+        let k = FrameReport::of(functions(&module).nth(1).unwrap());
+        assert_eq!(k.param_bytes, 4104);
+        assert!(f.to_diagnostic(0).is_none());
+        assert!(k.to_diagnostic(LARGE_PARAM_LIMIT).is_none());
+
+        let diagnostic = k.to_diagnostic(limit).expect("kernel exceeds the limit");
+        let rendered = diagnostic.render(&SourceMap::new(SOURCE), Some("frame.ptx"));
+        assert!(rendered.starts_with(
+            "error: parameters of kernel `k` take 4104 bytes, above the limit of 4096 bytes\n"
+        ));
+        assert!(rendered.contains("= note: `blob` ends at byte 4104\n"));
+        assert!(rendered.contains("raises the limit to 32764 bytes"));
+
+        let newer = parse_ptx(&SOURCE.replacen(".version 7.8", ".version 8.1", 1)).unwrap();
+        assert_eq!(param_limit(&newer), LARGE_PARAM_LIMIT);
+        let older = parse_ptx(
+            &SOURCE.replacen(".version 7.8", ".version 8.1", 1).replacen(
+                "sm_80",
+                "sm_80, sm_61",
+                1,
+            ),
+        )
+        .unwrap();
+        assert_eq!(param_limit(&older), PARAM_LIMIT);
+    });
+}
@@ -0,0 +1,153 @@
+mod util;
+use ptx_parser::r#type::{DataType, Immediate, Operand};
+use ptx_parser::{ImmValue, ImmediateError, Radix, Span, span};
+use util::*;
+
+fn int(value: i128, unsigned: bool, radix: Radix) -> ImmValue {
+    ImmValue::Int {
+        value,
+        unsigned,
+        radix,
+    }
+}
+
+fn decode(source: &str) -> ImmValue {
+    parse::<Immediate>(source)
+        .value()
+        .unwrap_or_else(|err| panic!("{source}: {err}"))
+}
+
+fn operand_value(source: &str) -> ImmValue {
+    match parse::<Operand>(source) {
+        Operand::Immediate { operand, .. } => operand.value().expect("literal should decode"),
+        other => panic!("{source} parsed as {other:?}"),
+    }
+}
+
+#[test]
+fn decodes_integer_literals() {
+    assert_eq!(decode("42"), int(42, false, Radix::Decimal));
+    assert_eq!(decode("0"), int(0, false, Radix::Decimal));
+    assert_eq!(decode("0777U"), int(0o777, true, Radix::Octal));
+    assert_eq!(
+        decode("0x7fffffff"),
+        int(0x7fff_ffff, false, Radix::Hexadecimal)
+    );
+    assert_eq!(decode("0XFFU"), int(255, true, Radix::Hexadecimal));
+    assert_eq!(decode("0b1011"), int(11, false, Radix::Binary));
+    assert_eq!(
+        decode("0xFFFFFFFFFFFFFFFF"),
+        int(u64::MAX as i128, false, Radix::Hexadecimal)
+    );
+    assert_eq!(operand_value("-1"), int(-1, false, Radix::Decimal));
+    assert_eq!(operand_value("-0x10"), int(-16, false, Radix::Hexadecimal));
+}
+
+#[test]
+fn decodes_float_literals_exactly() {
+    assert_eq!(decode("0f3f800000"), ImmValue::F32(1.0));
+    assert_eq!(
+        decode("0F40490FDB"),
+        ImmValue::F32(f32::from_bits(0x4049_0fdb))
+    );
+    assert_eq!(decode("0d3FF0000000000000"), ImmValue::F64(1.0));
+    assert_eq!(decode("0d3FA999999999999A"), ImmValue::F64(0.05));
+    assert_eq!(decode("1.5e10"), ImmValue::F64(1.5e10));
+    assert_eq!(decode("1.5"), ImmValue::F64(1.5));
+    assert_eq!(operand_value("-0f3f800000"), ImmValue::F32(-1.0));
+
+    let ImmValue::F32(nan) = decode("0f7FC00001") else {
+        panic!("expected f32");
+    };
+    assert_eq!(nan.to_bits(), 0x7fc0_0001);
+}
+
+#[test]
+fn rejects_bad_literals() {
+    let too_large = Immediate {
+        value: "0x10000000000000000".into(),
+        span: span!(0..19),
+    };
+    assert!(matches!(
+        too_large.value(),
+        Err(ImmediateError::TooLarge(_))
+    ));
+
+    for literal in ["", "abc", "0x", "1.5U", "0f3f80", "09"] {
+        let immediate = Immediate {
+            value: literal.into(),
+            span: Span::default(),
+        };
+        assert!(
+            matches!(immediate.value(), Err(ImmediateError::Malformed(_))),
+            "{literal:?} should be malformed"
+        );
+    }
+}
+
+#[test]
+fn checks_range_against_operand_type() {
+    let u8_ty = DataType::U8 {
+        span: Span::default(),
+    };
+    let s32 = DataType::S32 {
+        span: Span::default(),
+    };
+    let f32_ty = DataType::F32 {
+        span: Span::default(),
+    };
+    let pred = DataType::Pred {
+        span: Span::default(),
+    };
+
+    assert!(decode("255").fits(&u8_ty));
+    assert!(operand_value("-128").fits(&u8_ty));
+    assert!(!decode("256").fits(&u8_ty));
+    assert!(!operand_value("-129").fits(&u8_ty));
+
+    assert!(decode("0xFFFFFFFF").fits(&s32));
+    assert!(!decode("0x100000000").fits(&s32));
+    assert!(!decode("0f3f800000").fits(&s32));
+
+    assert!(decode("0f3f800000").fits(&f32_ty));
+    assert!(decode("1.5").fits(&f32_ty));
+    assert!(!decode("1e300").fits(&f32_ty));
+
+    assert!(decode("1").fits(&pred));
+    assert!(operand_value("-1").fits(&pred));
+    assert!(!decode("2").fits(&pred));
+
+    let immediate = parse::<Immediate>("300");
+    assert_eq!(
+        immediate.value_for(&u8_ty),
+        Err(ImmediateError::OutOfRange {
+            literal: "300".into(),
+            ty: ".u8"
+        })
+    );
+    assert_eq!(
+        immediate.value_for(&s32),
+        Ok(int(300, false, Radix::Decimal))
+    );
+}
+
+#[test]
+fn from_value_spells_canonically() {
+    let cases = [
+        (int(42, false, Radix::Decimal), "42"),
+        (int(-42, false, Radix::Decimal), "-42"),
+        (int(0o777, true, Radix::Octal), "0777U"),
+        (int(0, false, Radix::Octal), "00"),
+        (int(255, false, Radix::Hexadecimal), "0xFF"),
+        (int(5, true, Radix::Binary), "0b101U"),
+        (ImmValue::F32(1.0), "0f3F800000"),
+        (ImmValue::F32(-2.0), "0fC0000000"),
+        (ImmValue::F64(0.05), "0d3FA999999999999A"),
+    ];
+    for (value, spelling) in cases {
+        let immediate = Immediate::from_value(value);
+        assert_eq!(immediate.value, spelling);
+        assert_eq!(immediate.value().unwrap(), value);
+        assert_eq!(operand_value(spelling), value);
+    }
+}
@@ -1,7 +1,7 @@
 mod util;
 
 use ptx_parser::run_with_large_stack;
-use ptx_parser::{PtxUnparser, r#type::Instruction};
+use ptx_parser::{ParseErrorKind, PtxUnparser, r#type::Instruction};
 use util::{parse_result, tokenize_only};
 
 const DOC_EXAMPLE_INSTRUCTIONS: &[&str] = &[
@@ -368,3 +368,93 @@ fn inner_docs_examples_roundtrip() {
         "no documentation examples failed to parse"
     );
 }
+
+#[test]
+fn unmatched_instruction_reports_closest_candidate() {
+    run_with_large_stack(|| {
+        let source = "mov.u32 %r1 %r2;";
+        let err = parse_result::<Instruction>(source).expect_err("missing comma should not parse");
+
+        assert_eq!(&source[err.span.start..err.span.end], "%r2");
+        match err.kind {
+            ParseErrorKind::NoMatchingInstruction {
+                opcode,
+                candidate,
+                cause,
+            } => {
+                assert_eq!(opcode, "mov");
+                assert_eq!(candidate, "mov::section_0::MovType");
+                assert!(matches!(
+                    *cause,
+                    ParseErrorKind::UnexpectedToken { ref expected, .. } if expected == &["Comma"]
+                ));
+            }
+            other => panic!("unexpected error kind: {other:?}"),
+        }
+    });
+}
+
+#[test]
+fn unmatched_instruction_error_points_past_opcode() {
+    run_with_large_stack(|| {
+        let source = "add.s32 %r1, %r2;";
+        let err =
+            parse_result::<Instruction>(source).expect_err("missing operand should not parse");
+
+        assert_eq!(&source[err.span.start..err.span.end], ";");
+        assert!(
+            err.to_string()
+                .contains("closest candidate `add::section_0::AddType`"),
+            "unexpected message: {err}"
+        );
+    });
+}
+
+#[test]
+fn unmatched_modifier_is_reported_with_the_opcode_format() {
+    run_with_large_stack(|| {
+        // `ld_global_nc` and `add_cc` formats fail at the same modifier, but
+        // the opcode's own formats are named.
+        for (source, opcode, candidate) in [
+            (
+                "ld.global.zz.f32 %f1, [%rd1];",
+                "ld",
+                "ld::section_0::LdWeakSsCopLevelCacheHintLevelPrefetchSizeVecType",
+            ),
+            (
+                "add.zz.s32 %r1, %r2, %r3;",
+                "add",
+                "add::section_0::AddType",
+            ),
+        ] {
+            let err = parse_result::<Instruction>(source).expect_err("unknown modifier");
+
+            assert_eq!(&source[err.span.start..err.span.end], ".zz");
+            let ParseErrorKind::NoMatchingInstruction {
+                opcode: found_opcode,
+                candidate: found_candidate,
+                cause,
+            } = err.kind
+            else {
+                panic!("unexpected error kind: {:?}", err.kind);
+            };
+            assert_eq!(found_opcode, opcode);
+            assert_eq!(found_candidate, candidate);
+            assert!(
+                matches!(*cause, ParseErrorKind::UnexpectedToken { ref found, .. } if found == ".zz"),
+                "unexpected cause: {cause:?}"
+            );
+        }
+    });
+}
+
+#[test]
+fn unknown_opcode_keeps_generic_error() {
+    run_with_large_stack(|| {
+        let err = parse_result::<Instruction>("frobnicate.b32 %r1;")
+            .expect_err("unknown opcode should not parse");
+
+        assert!(matches!(err.kind, ParseErrorKind::UnexpectedToken { .. }));
+        assert_eq!(err.span.start, 0);
+    });
+}
@@ -0,0 +1,257 @@
+mod util;
+
+use ptx_parser::analysis::{LabelIssue, check_labels};
+use ptx_parser::{Severity, SourceMap, parse_ptx};
+use util::messages;
+
+/// Label issues of `body` in a function with a few registers.
+fn check(body: &str) -> Vec<String> {
+    let source = format!(
+        ".func f()\n{{\n    .reg .b32 %r<3>;\n    .reg .b64 %rd<2>;\n    .reg .pred %p<2>;\n{body}\n    ret;\n}}\n"
+    );
+    let module = parse_ptx(&source).expect("module should parse");
+    messages(&check_labels(&module))
+}
+
+#[test]
+fn branches_to_visible_labels_are_accepted() {
+    ptx_parser::run_with_large_stack(|| {
+        // Forward and backward branches, branches out of nested blocks and a
+        // label branching to itself are all fine.
+        let body = r#"
+    @%p1 bra DONE;
+LOOP:
+    @%p1 bra LOOP;
+    {
+        @%p1 bra LOOP;
+        {
+        INNER:
+            @%p1 bra INNER;
+            bra DONE;
+        }
+    }
+DONE:
+"#;
+        assert_eq!(check(body), Vec::<String>::new());
+    });
+}
+
+#[test]
+fn indirect_branches_and_calls_use_their_lists() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    brx.idx %r1, TARGETS;
+TARGETS: .branchtargets A, B, A;
+PROTO: .callprototype _ (.param .b32 p);
+A:
+    call %rd1, (%r1), PROTO;
+B:
+"#;
+        assert_eq!(check(body), Vec::<String>::new());
+    });
+}
+
+#[test]
+fn undefined_labels_are_reported() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    @%p1 bra MISSING;
+    brx.idx %r1, NOLIST;
+    brx.idx %r1, TARGETS;
+TARGETS: .branchtargets DONE, GONE;
+DONE:
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "undefined label `MISSING`",
+                "undefined label `NOLIST`",
+                "`.branchtargets` names undefined label `GONE`",
+            ]
+        );
+
+        // Labels are local to their function.
+        let source = ".func f()\n{\nL:\n    bra L;\n}\n.func g()\n{\n    bra L;\n}\n";
+        let module = parse_ptx(source).expect("module should parse");
+        assert_eq!(messages(&check_labels(&module)), ["undefined label `L`"]);
+    });
+}
+
+#[test]
+fn branches_into_nested_blocks_are_reported() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    @%p1 bra INNER;
+    brx.idx %r1, TARGETS;
+TARGETS: .branchtargets INNER, OUTER;
+    {
+    INNER:
+        ret;
+    }
+    {
+        bra SIBLING;
+    }
+    {
+    SIBLING:
+        bra OUTER;
+    }
+OUTER:
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`INNER` is declared in a block that does not enclose the branch",
+                "`INNER` is declared in a block that does not enclose the branch",
+                "`SIBLING` is declared in a block that does not enclose the branch",
+            ]
+        );
+    });
+}
+
+#[test]
+fn labels_are_unique_per_block() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+L:
+    @%p1 bra L;
+L:
+    {
+    L:
+        @%p1 bra L;
+    }
+    {
+    L:
+        @%p1 bra L;
+    L:
+    }
+"#;
+        // Blocks may reuse the names of labels outside them.
+        assert_eq!(
+            check(body),
+            [
+                "label `L` is already declared in this block",
+                "label `L` is already declared in this block",
+            ]
+        );
+    });
+}
+
+#[test]
+fn brx_needs_a_branchtargets_list() {
+    ptx_parser::run_with_large_stack(|| {
+        // The named label counts as used either way.
+        let body = r#"
+    brx.idx %r1, PLAIN;
+    brx.idx %r1, PROTO;
+PROTO: .callprototype _ (.param .b32 p);
+PLAIN:
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`brx.idx` needs a `.branchtargets` list, but `PLAIN` is a plain label",
+                "`brx.idx` needs a `.branchtargets` list, but `PROTO` is a plain label",
+            ]
+        );
+    });
+}
+
+#[test]
+fn unused_labels_are_warnings() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+UNUSED:
+TABLE: .branchtargets A;
+A:
+$L__tmp0:
+$L__func_begin0:
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "label `UNUSED` is never used",
+                "label `TABLE` is never used"
+            ]
+        );
+
+        let source = ".func f()\n{\nUNUSED:\n    ret;\n}\n";
+        let module = parse_ptx(source).expect("module should parse");
+        let diagnostic = check_labels(&module)[0].to_diagnostic();
+        assert_eq!(diagnostic.severity, Severity::Warning);
+        assert_eq!(diagnostic.label.as_deref(), Some("declared here"));
+        assert_eq!(
+            &source[diagnostic.span.start..diagnostic.span.end],
+            "UNUSED"
+        );
+    });
+}
+
+#[test]
+fn labels_named_in_debug_sections_are_used() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#".func f()
+{
+DEBUG:
+    ret;
+UNUSED:
+    ret;
+}
+.section .debug_info
+{
+.b64 DEBUG
+}
+"#;
+        let module = parse_ptx(source).expect("module should parse");
+        assert_eq!(
+            messages(&check_labels(&module)),
+            ["label `UNUSED` is never used"]
+        );
+    });
+}
+
+#[test]
+fn diagnostics_point_at_the_label() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#".func f()
+{
+    .reg .b32 %r1;
+    brx.idx %r1, L;
+    {
+    INNER:
+        ret;
+    }
+    bra INNER;
+L:
+L:
+}
+"#;
+        let module = parse_ptx(source).expect("module should parse");
+        let issues = check_labels(&module);
+        assert_eq!(issues.len(), 3);
+
+        let plain = issues[0].to_diagnostic();
+        assert_eq!(&source[plain.span.start..plain.span.end], "L");
+        assert_eq!(plain.label.as_deref(), Some("not a `.branchtargets` list"));
+        assert_eq!(
+            plain.notes,
+            ["declare the targets with `L: .branchtargets ...;`"]
+        );
+
+        let LabelIssue::IntoBlock { span, declared, .. } = &issues[1] else {
+            panic!("expected a branch into a block: {:?}", issues[1]);
+        };
+        assert_eq!(&source[span.start..span.end], "INNER");
+        assert!(declared.start < span.start);
+        assert_eq!(
+            issues[1].to_diagnostic().notes,
+            ["a label is only visible in its block and the blocks nested in it"]
+        );
+
+        assert_eq!(
+            issues[2]
+                .to_diagnostic()
+                .render(&SourceMap::new(source), Some("f.ptx")),
+            "error: label `L` is already declared in this block\n  --> f.ptx:11:1\n   |\n11 | L:\n   | ^ declared again here\n"
+        );
+    });
+}
@@ -1,4 +1,4 @@
-use ptx_parser::{PtxToken, tokenize};
+use ptx_parser::{LexErrorKind, PtxToken, Span, tokenize, tokenize_lossy};
 
 // ============================================================================
 // Basic token tests
@@ -724,3 +724,93 @@ fn test_mixed_content() {
     let result = tokenize(source);
     assert!(result.is_ok());
 }
+
+// ============================================================================
+// Error tests
+// ============================================================================
+
+#[test]
+fn test_error_unterminated_block_comment() {
+    let source = "ret; /* never closed\nexit;";
+    let err = tokenize(source).unwrap_err();
+
+    assert_eq!(err.kind, LexErrorKind::UnterminatedBlockComment);
+    assert_eq!(err.span, Span::new(5, source.len()));
+    assert_eq!(err.text, "/* never closed\nexit;");
+}
+
+#[test]
+fn test_error_unterminated_string() {
+    let err = tokenize(".file 1 \"kernel.cu\n.version 8.5").unwrap_err();
+
+    assert_eq!(err.kind, LexErrorKind::UnterminatedString);
+    assert_eq!(err.text, "\"kernel.cu\n.version 8.5");
+}
+
+#[test]
+fn test_error_bad_hex_float_width() {
+    let err = tokenize("mov.f32 %f1, 0f123;").unwrap_err();
+    assert_eq!(
+        err.kind,
+        LexErrorKind::BadHexFloatWidth {
+            expected: 8,
+            found: 3
+        }
+    );
+    assert_eq!(err.text, "0f123");
+
+    let err = tokenize("mov.f64 %fd1, 0d3FF00000000000000;").unwrap_err();
+    assert_eq!(
+        err.kind,
+        LexErrorKind::BadHexFloatWidth {
+            expected: 16,
+            found: 17
+        }
+    );
+}
+
+#[test]
+fn test_error_stray_character() {
+    let err = tokenize("add.s32 %r1, %r2, #3;").unwrap_err();
+
+    assert_eq!(err.kind, LexErrorKind::StrayCharacter);
+    assert_eq!(err.span, Span::new(18, 19));
+    assert_eq!(err.text, "#");
+    assert_eq!(err.to_string(), "unexpected character: `#`");
+}
+
+#[test]
+fn test_tokenize_lossy_continues_after_errors() {
+    let source = "mov.f32 %f1, 0f3F80;\nadd.s32 %r1, %r2, #3;\nret;";
+    let (tokens, errors) = tokenize_lossy(source);
+
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].text, "0f3F80");
+    assert_eq!(errors[1].kind, LexErrorKind::StrayCharacter);
+
+    let error_tokens: Vec<_> = tokens
+        .iter()
+        .filter(|(token, _)| matches!(token, PtxToken::Error(_)))
+        .map(|(token, span)| (token.clone(), *span))
+        .collect();
+    assert_eq!(
+        error_tokens,
+        vec![
+            (PtxToken::Error("0f3F80".into()), errors[0].span),
+            (PtxToken::Error("#".into()), errors[1].span),
+        ]
+    );
+    assert_eq!(
+        tokens.last().map(|(token, _)| token),
+        Some(&PtxToken::Semicolon)
+    );
+}
+
+#[test]
+fn test_tokenize_lossy_matches_tokenize_on_valid_input() {
+    let source = ".version 8.5\n.target sm_90\nmov.f32 %f1, 0f3F800000; // one";
+    let (tokens, errors) = tokenize_lossy(source);
+
+    assert!(errors.is_empty());
+    assert_eq!(tokens, tokenize(source).unwrap());
+}
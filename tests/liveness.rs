@@ -0,0 +1,188 @@
+use ptx_parser::analysis::{
+    BlockId, Cfg, Liveness, Pressure, PressureReport, RegisterTypes, functions,
+};
+use ptx_parser::{Severity, parse_ptx};
+
+const LOOP: &str = r#".entry k(.param .u64 out) .maxnreg 3
+{
+    .reg .b64 %rd<3>;
+    .reg .b32 %r<4>;
+    .reg .pred %p<2>;
+    ld.param.u64 %rd1, [out];
+    mov.u32 %r1, 0;
+    mov.u32 %r2, %tid.x;
+LOOP:
+    add.u32 %r1, %r1, %r2;
+    setp.lt.u32 %p1, %r1, 100;
+    @%p1 bra LOOP;
+    mov.b64 %rd2, {%r1, %r3};
+    @%p1 mov.u32 %r3, 7;
+    st.global.v2.u32 [%rd1], {%r1, %r3};
+    ret;
+}
+"#;
+
+fn live(set: &std::collections::BTreeSet<&str>) -> Vec<String> {
+    set.iter().map(|name| name.to_string()).collect()
+}
+
+#[test]
+fn registers_stay_live_around_loops() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(LOOP).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let cfg = Cfg::build(function.body().unwrap());
+        let liveness = Liveness::compute(&cfg, &RegisterTypes::of(function));
+        let body = BlockId::new(1);
+
+        assert_eq!(cfg.len(), 3);
+        // `%r3` is read in the exit block before any unconditional write.
+        assert_eq!(live(liveness.live_in(cfg.entry())), ["%r3"]);
+        assert_eq!(live(liveness.live_in(body)), ["%r1", "%r2", "%r3", "%rd1"]);
+        // `%p1` is read by the guarded `mov` after the loop.
+        assert_eq!(
+            live(liveness.live_out(body)),
+            ["%p1", "%r1", "%r2", "%r3", "%rd1"]
+        );
+        assert_eq!(
+            live(liveness.live_in(BlockId::new(2))),
+            ["%p1", "%r1", "%r3", "%rd1"]
+        );
+        assert!(liveness.live_out(BlockId::new(2)).is_empty());
+
+        // The guarded `mov` does not end the live range of `%r3`.
+        let points = liveness.points(BlockId::new(2));
+        assert_eq!(points.len(), 3);
+        assert_eq!(points[1].defs, ["%r3"]);
+        assert!(points[1].live_before.contains("%r3"));
+        // Vector and address operands are expanded to their registers.
+        assert_eq!(live(&points[2].live_before), ["%r1", "%r3", "%rd1"]);
+        assert_eq!(live(&points[0].live_after), ["%p1", "%r1", "%r3", "%rd1"]);
+        assert!(points[2].live_after.is_empty());
+    });
+}
+
+#[test]
+fn pressure_counts_pairs_and_predicates() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(LOOP).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let types = RegisterTypes::of(function);
+        assert_eq!(
+            Pressure::of(["%rd1", "%r1", "%p1", "%tid"], &types),
+            Pressure {
+                registers: 3,
+                predicates: 1
+            }
+        );
+
+        let report = PressureReport::of(function).expect("kernel has a body");
+        assert_eq!(report.function, "k");
+        assert_eq!(report.maxnreg, Some(3));
+        assert_eq!(
+            report.max,
+            Pressure {
+                registers: 6,
+                predicates: 1
+            }
+        );
+        let peak = report.peak.as_ref().unwrap();
+        assert_eq!(peak.block, BlockId::new(2));
+        assert_eq!(peak.path, [10]);
+        assert_eq!(peak.live, ["%p1", "%r1", "%r3", "%rd1", "%rd2"]);
+        assert_eq!(
+            &LOOP[peak.span.start..peak.span.end],
+            "mov.b64 %rd2, {%r1, %r3};"
+        );
+        assert_eq!(report.blocks.len(), 3);
+        assert_eq!(report.blocks[1].max.registers, 5);
+        assert!(report.exceeds_maxnreg());
+
+        let diagnostic = report.to_diagnostic().expect("pressure exceeds .maxnreg");
+        assert_eq!(diagnostic.severity, Severity::Warning);
+        assert_eq!(diagnostic.span, peak.span);
+    });
+}
+
+#[test]
+fn reports_skip_prototypes_and_fit_without_maxnreg() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = ".func f(.reg .b32 a);\n.func (.reg .b32 r) g(.reg .b32 a) { add.s32 r, a, 1; add.s32 r, r, a; ret; }\n";
+        let module = parse_ptx(source).expect("module should parse");
+        let mut functions = functions(&module);
+        assert_eq!(PressureReport::of(functions.next().unwrap()), None);
+
+        let function = functions.next().unwrap();
+        let cfg = Cfg::build(function.body().unwrap());
+        // The return register is read by the caller.
+        assert_eq!(
+            live(Liveness::of(function, &cfg).live_out(cfg.entry())),
+            ["r"]
+        );
+
+        let report = PressureReport::of(function).unwrap();
+        assert_eq!(report.max.registers, 2);
+        assert_eq!(report.peak.as_ref().unwrap().live, ["a", "r"]);
+        assert!(!report.exceeds_maxnreg());
+        assert!(report.maxnreg.is_none());
+    });
+}
+
+#[test]
+fn wgmma_accumulators_stay_live_around_the_loop() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#".entry gemm(.param .u64 out)
+{
+    .reg .f32 %f<5>;
+    .reg .b64 %rd<4>;
+    .reg .b32 %r<2>;
+    .reg .pred %p<3>;
+    ld.param.u64 %rd3, [out];
+    mov.f32 %f1, 0f00000000;
+    mov.f32 %f2, 0f00000000;
+    mov.f32 %f3, 0f00000000;
+    mov.f32 %f4, 0f00000000;
+    mov.u32 %r1, 0;
+    setp.ne.u32 %p2, %r1, 0;
+LOOP:
+    wgmma.fence.sync.aligned;
+    wgmma.mma_async.sync.aligned.m64n8k16.f32.f16.f16 {%f1, %f2, %f3, %f4}, %rd1, %rd2, %p2, 1, 1, 0, 0;
+    wgmma.commit_group.sync.aligned;
+    wgmma.wait_group.sync.aligned 0;
+    add.u32 %r1, %r1, 1;
+    setp.lt.u32 %p1, %r1, 8;
+    @%p1 bra LOOP;
+    st.global.v4.f32 [%rd3], {%f1, %f2, %f3, %f4};
+    ret;
+}
+"#;
+        let module = parse_ptx(source).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let cfg = Cfg::build(function.body().unwrap());
+        let liveness = Liveness::compute(&cfg, &RegisterTypes::of(function));
+        let body = BlockId::new(1);
+        let accumulators = ["%f1", "%f2", "%f3", "%f4"];
+
+        assert_eq!(cfg.len(), 3);
+        for set in [liveness.live_in(body), liveness.live_out(body)] {
+            assert!(
+                accumulators.iter().all(|name| set.contains(name)),
+                "{set:?}"
+            );
+        }
+        // The `mov`s before the loop feed the first `wgmma`.
+        let entry_out = liveness.live_out(cfg.entry());
+        assert!(accumulators.iter().all(|name| entry_out.contains(name)));
+
+        let points = liveness.points(body);
+        let wgmma = points
+            .iter()
+            .find(|point| point.defs == accumulators)
+            .expect("wgmma writes the accumulators");
+        assert!(
+            accumulators
+                .iter()
+                .all(|name| wgmma.live_before.contains(name))
+        );
+    });
+}
@@ -0,0 +1,99 @@
+use ptx_parser::analysis::{BlockId, Cfg, DominatorTree, LoopNest, functions};
+use ptx_parser::parse_ptx;
+
+const NESTED: &str = r#".entry k()
+{
+    mov.u32 %r1, 0;
+OUTER:
+    mov.u32 %r2, 0;
+    .pragma "nounroll";
+INNER:
+    add.u32 %r2, %r2, 1;
+    @%p1 bra INNER;
+    add.u32 %r1, %r1, 1;
+    @%p2 bra.uni OUTER;
+    @%p3 bra OUTER;
+    ret;
+}
+"#;
+
+fn loop_nest(cfg: &Cfg) -> LoopNest {
+    LoopNest::build(cfg, &DominatorTree::dominators(cfg))
+}
+
+#[test]
+fn nested_loops_with_merged_back_edges() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(NESTED).expect("module should parse");
+        let cfg = Cfg::build(functions(&module).next().unwrap().body().unwrap());
+        let loops = loop_nest(&cfg);
+        let outer_header = cfg.block_of_label("OUTER").unwrap();
+        let inner_header = cfg.block_of_label("INNER").unwrap();
+
+        assert_eq!(loops.len(), 2);
+        let outer = loops.loop_with_header(outer_header).unwrap();
+        let inner = loops.loop_with_header(inner_header).unwrap();
+        assert_eq!(loops.top_level().collect::<Vec<_>>(), [outer]);
+
+        let outer_loop = loops.get(outer);
+        assert_eq!(outer_loop.latches, [BlockId::new(3), BlockId::new(4)]);
+        assert_eq!(
+            outer_loop.back_edges().collect::<Vec<_>>(),
+            [
+                (BlockId::new(3), outer_header),
+                (BlockId::new(4), outer_header)
+            ]
+        );
+        assert_eq!(
+            outer_loop.blocks,
+            (1..=4).map(BlockId::new).collect::<Vec<_>>()
+        );
+        assert_eq!(outer_loop.children, [inner]);
+        assert_eq!(outer_loop.depth, 1);
+        assert!(!outer_loop.nounroll);
+
+        let inner_loop = loops.get(inner);
+        assert_eq!(inner_loop.blocks, [inner_header]);
+        assert_eq!(inner_loop.latches, [inner_header]);
+        assert_eq!(inner_loop.parent, Some(outer));
+        assert_eq!(inner_loop.depth, 2);
+        assert!(inner_loop.nounroll);
+
+        assert_eq!(loops.innermost(inner_header), Some(inner));
+        assert_eq!(loops.innermost(BlockId::new(3)), Some(outer));
+        assert_eq!(loops.depth(inner_header), 2);
+        assert_eq!(loops.depth(cfg.entry()), 0);
+        assert_eq!(loops.loop_with_header(BlockId::new(3)), None);
+        assert!(outer_loop.contains(inner_header));
+        assert!(!outer_loop.contains(BlockId::new(5)));
+    });
+}
+
+#[test]
+fn sample_loop_uses_nounroll_pragma() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = include_str!("sample/mini_step64.ptx");
+        let module = parse_ptx(source).expect("sample should parse");
+        let function = functions(&module)
+            .find(|function| function.name() == "step64_kernel")
+            .unwrap();
+        let cfg = Cfg::build(function.body().unwrap());
+        let loops = loop_nest(&cfg);
+
+        assert_eq!(loops.len(), 1);
+        let (_, found) = loops.iter().next().unwrap();
+        assert_eq!(Some(found.header), cfg.block_of_label("$L_loop"));
+        assert_eq!(found.blocks.len(), 2);
+        assert!(found.nounroll);
+    });
+}
+
+#[test]
+fn irreducible_cycles_are_not_natural_loops() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = ".entry k() { @%p1 bra B; A: @%p2 bra B; ret; B: bra A; }";
+        let module = parse_ptx(source).expect("module should parse");
+        let cfg = Cfg::build(functions(&module).next().unwrap().body().unwrap());
+        assert!(loop_nest(&cfg).is_empty());
+    });
+}
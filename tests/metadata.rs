@@ -0,0 +1,83 @@
+use ptx_parser::r#type::{FunctionStatement, Module, ModuleDirective, instruction::Inst};
+use ptx_parser::visit::{Visitor, walk_instruction};
+use ptx_parser::{PtxUnlexer, PtxUnparser, parse_ptx};
+
+const SAMPLES: &[&str] = &[
+    include_str!("sample/hello.ptx"),
+    include_str!("sample/vector_add_scalar.ptx"),
+    include_str!("sample/mini_step64.ptx"),
+    include_str!("sample/matrix_free_device_matrix_vector_01.1.sm_80.ptx"),
+];
+
+#[derive(Default)]
+struct Insts<'ast>(Vec<&'ast Inst>);
+
+impl<'ast> Visitor<'ast> for Insts<'ast> {
+    fn visit_instruction(&mut self, instruction: &'ast ptx_parser::r#type::Instruction) {
+        self.0.push(&instruction.inst);
+        walk_instruction(self, instruction);
+    }
+}
+
+fn first_inst(module: &Module) -> &Inst {
+    let ModuleDirective::EntryFunction { directive, .. } = &module.directives[0] else {
+        panic!("first directive should be a kernel");
+    };
+    match &directive.body.as_ref().unwrap().statements[0] {
+        FunctionStatement::Instruction { instruction, .. } => &instruction.inst,
+        _ => panic!("first statement should be an instruction"),
+    }
+}
+
+#[test]
+fn mnemonic_matches_unparsed_text() {
+    ptx_parser::run_with_large_stack(|| {
+        for source in SAMPLES {
+            let module = parse_ptx(source).expect("sample should parse");
+            let mut insts = Insts::default();
+            insts.visit_module(&module);
+            assert!(!insts.0.is_empty());
+            for inst in insts.0 {
+                let text = PtxUnlexer::to_string(&inst.to_tokens_spaced()).expect("unlex");
+                let written = text
+                    .split_whitespace()
+                    .next()
+                    .unwrap()
+                    .trim_end_matches(';');
+                assert_eq!(inst.mnemonic(), written);
+                assert!(written.starts_with(inst.opcode()));
+                assert_eq!(
+                    format!("{}{}", inst.opcode(), inst.modifiers().concat()),
+                    written
+                );
+            }
+        }
+    });
+}
+
+#[test]
+fn spec_section_points_at_rule() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(".entry k() { setp.lt.and.f16x2 %p1|%p2, %r1, %r2, %p3; }").unwrap();
+        let inst = first_inst(&module);
+        assert_eq!(inst.opcode(), "setp");
+        assert_eq!(inst.modifiers(), [".lt", ".and", ".f16x2"]);
+
+        let section = inst.spec_section();
+        assert_eq!(section.file, "setp.txt");
+        assert_eq!(section.section, 1);
+        assert_eq!(
+            section.rule,
+            "setp.CmpOp.BoolOp{.ftz}.f16x2 p|q, a, b, {!}c;"
+        );
+        let spec = include_str!("../crates/parser-gen/ptx_syntax/setp.txt");
+        let line = spec
+            .lines()
+            .nth(section.line - 1)
+            .expect("line should exist");
+        assert_eq!(
+            line.split_whitespace().collect::<Vec<_>>().join(" "),
+            section.rule
+        );
+    });
+}
@@ -0,0 +1,160 @@
+use ptx_parser::r#type::{FunctionBody, FunctionStatement, Module, ModuleDirective};
+use ptx_parser::{parse_ptx, unparse_minimal};
+
+const SAMPLES: &[&str] = &[
+    include_str!("sample/hello.ptx"),
+    include_str!("sample/vector_add_scalar.ptx"),
+    include_str!("sample/vector_add_scalar.debug.ptx"),
+    include_str!("sample/mini_step64.ptx"),
+];
+
+fn body_mut<'a>(module: &'a mut Module, name: &str) -> &'a mut FunctionBody {
+    module
+        .directives
+        .iter_mut()
+        .find_map(|directive| match directive {
+            ModuleDirective::EntryFunction { directive, .. } if directive.name.val == name => {
+                directive.body.as_mut()
+            }
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("entry `{name}` should be present"))
+}
+
+/// Parse a single statement inside a throwaway kernel.
+fn statement(text: &str) -> FunctionStatement {
+    let module = parse_ptx(&format!(".entry tmp() {{\n{text}\n}}\n")).expect("statement parses");
+    let ModuleDirective::EntryFunction { directive, .. } = &module.directives[0] else {
+        unreachable!()
+    };
+    directive.body.as_ref().unwrap().statements[0].clone()
+}
+
+fn changed_lines<'a>(before: &'a str, after: &'a str) -> Vec<(&'a str, &'a str)> {
+    assert_eq!(before.lines().count(), after.lines().count());
+    before
+        .lines()
+        .zip(after.lines())
+        .filter(|(a, b)| a != b)
+        .collect()
+}
+
+#[test]
+fn unmodified_module_prints_source_exactly() {
+    ptx_parser::run_with_large_stack(|| {
+        for source in SAMPLES {
+            let module = parse_ptx(source).expect("sample should parse");
+            assert_eq!(unparse_minimal(source, &module, &module), *source);
+        }
+    });
+}
+
+#[test]
+fn edited_instruction_only_changes_its_own_line() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = include_str!("sample/vector_add_scalar.ptx");
+        let original = parse_ptx(source).expect("sample should parse");
+        let mut modified = original.clone();
+
+        let body = body_mut(&mut modified, "vector_add_scalar");
+        let target = body
+            .statements
+            .iter_mut()
+            .find(|statement| {
+                let span = statement.span();
+                source[span.start..span.end].starts_with("mad.lo.s32")
+            })
+            .expect("mad statement");
+        let FunctionStatement::Instruction { instruction, .. } = target else {
+            panic!("mad should be an instruction");
+        };
+        let FunctionStatement::Instruction {
+            instruction: replacement,
+            ..
+        } = statement("add.s32 %r1, %r3, %r5;")
+        else {
+            panic!("replacement should be an instruction");
+        };
+        *instruction = replacement;
+
+        let text = unparse_minimal(source, &original, &modified);
+        assert_eq!(
+            changed_lines(source, &text),
+            vec![(
+                "\tmad.lo.s32 \t%r1, %r3, %r4, %r5;",
+                "\tadd.s32 %r1, %r3, %r5;"
+            )]
+        );
+        assert_eq!(
+            parse_ptx(&text)
+                .expect("output should parse")
+                .directives
+                .len(),
+            original.directives.len()
+        );
+    });
+}
+
+#[test]
+fn inserted_and_removed_statements_keep_surrounding_text() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = "\
+.version 8.5
+.target sm_90
+
+// kernel
+.entry k() {
+    mov.u32 %r1, %r2; // first
+    add.s32 %r1, %r1, 1;
+    {
+        ret;
+    }
+}
+";
+        let original = parse_ptx(source).expect("module should parse");
+        let mut modified = original.clone();
+        let body = body_mut(&mut modified, "k");
+        body.statements.remove(1);
+        body.statements.insert(1, statement("sub.s32 %r1, %r1, 2;"));
+        let FunctionStatement::Block { statements, .. } = &mut body.statements[2] else {
+            panic!("third statement should be a block");
+        };
+        statements.insert(0, statement("mov.u32 %r3, %r1;"));
+
+        let text = unparse_minimal(source, &original, &modified);
+        let expected = "\
+.version 8.5
+.target sm_90
+
+// kernel
+.entry k() {
+    mov.u32 %r1, %r2; // first
+    sub.s32 %r1, %r1, 2;
+    {
+        mov.u32 %r3, %r1;
+        ret;
+    }
+}
+";
+        assert_eq!(text, expected);
+    });
+}
+
+#[test]
+fn changed_header_reprints_whole_function() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = ".version 8.5\n.target sm_90\n.entry k() {\n    ret; // done\n}\n";
+        let original = parse_ptx(source).expect("module should parse");
+        let mut modified = original.clone();
+        let ModuleDirective::EntryFunction { directive, .. } = &mut modified.directives[2] else {
+            panic!("third directive should be the kernel");
+        };
+        directive.name.val = "renamed".into();
+
+        let text = unparse_minimal(source, &original, &modified);
+        assert!(text.starts_with(".version 8.5\n.target sm_90\n"), "{text}");
+        assert!(text.contains(".entry renamed()"), "{text}");
+        assert!(!text.contains("// done"), "{text}");
+        parse_ptx(&text).expect("output should parse");
+    });
+}
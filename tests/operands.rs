@@ -0,0 +1,188 @@
+use ptx_parser::operands::{OperandRef, OperandRole};
+use ptx_parser::r#type::{FunctionStatement, Module, ModuleDirective, instruction::Inst};
+use ptx_parser::{PtxUnlexer, PtxUnparser, parse_ptx};
+
+/// Instructions of the first kernel in `module`, in source order.
+fn instructions(module: &Module) -> Vec<&Inst> {
+    let ModuleDirective::EntryFunction { directive, .. } = &module.directives[0] else {
+        panic!("first directive should be a kernel");
+    };
+    directive
+        .body
+        .as_ref()
+        .expect("kernel should have a body")
+        .statements
+        .iter()
+        .filter_map(|statement| match statement {
+            FunctionStatement::Instruction { instruction, .. } => Some(&instruction.inst),
+            _ => None,
+        })
+        .collect()
+}
+
+fn summary(operands: &[OperandRef<'_>]) -> Vec<(OperandRole, Vec<String>)> {
+    operands
+        .iter()
+        .map(|operand| {
+            let registers = operand
+                .registers()
+                .iter()
+                .map(|register| register.name.clone())
+                .collect();
+            (operand.role, registers)
+        })
+        .collect()
+}
+
+fn names(operands: &[OperandRef<'_>]) -> Vec<String> {
+    operands
+        .iter()
+        .flat_map(|operand| operand.registers())
+        .map(|register| register.name.clone())
+        .collect()
+}
+
+#[test]
+fn roles_follow_operand_layout() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(
+            r#"
+            .entry kernel() {
+                add.s32 %r1, %r2, 4;
+                ld.global.u32 %r3, [%rd1+8];
+                st.global.u32 [%rd1+%r4], %r3;
+                bra done;
+            done:
+                ret;
+            }
+        "#,
+        )
+        .expect("module should parse");
+        let insts = instructions(&module);
+
+        assert_eq!(
+            summary(&insts[0].operands()),
+            vec![
+                (OperandRole::Destination, vec!["%r1".to_string()]),
+                (OperandRole::Source, vec!["%r2".to_string()]),
+                (OperandRole::Immediate, vec![]),
+            ]
+        );
+        assert_eq!(
+            summary(&insts[1].operands()),
+            vec![
+                (OperandRole::Destination, vec!["%r3".to_string()]),
+                (OperandRole::Address, vec!["%rd1".to_string()]),
+            ]
+        );
+        assert!(insts[2].defs().is_empty());
+        assert_eq!(names(&insts[2].uses()), ["%rd1", "%r4", "%r3"]);
+        assert!(insts[3].defs().is_empty());
+        assert_eq!(insts[3].uses().len(), 1);
+        assert!(insts[4].operands().is_empty());
+    });
+}
+
+#[test]
+fn multi_destination_and_vector_operands() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(
+            r#"
+            .entry kernel() {
+                setp.lt.s32 %p1|%p2, %r1, %r2;
+                setp.eq.s32 %p3, %r1, 0;
+                ld.global.v4.f32 {%f1, %f2, %f3, %f4}, [%rd1];
+                st.global.v2.f32 [%rd2], {%f1, %f2};
+                tex.2d.v4.s32.f32 {%r1, %r2, %r3, %r4}, [tex_a, {%f1, %f2}];
+                call.uni (%r5), foo, (%r1, %r2);
+            }
+        "#,
+        )
+        .expect("module should parse");
+        let insts = instructions(&module);
+
+        let defs = insts[0].defs();
+        assert_eq!(
+            defs.iter().map(|operand| operand.role).collect::<Vec<_>>(),
+            [OperandRole::Destination, OperandRole::PredicateOut]
+        );
+        assert_eq!(defs[1].name, "q");
+        assert_eq!(names(&defs), ["%p1", "%p2"]);
+        assert_eq!(names(&insts[0].uses()), ["%r1", "%r2"]);
+
+        assert_eq!(names(&insts[1].defs()), ["%p3"]);
+        assert_eq!(names(&insts[1].uses()), ["%r1"]);
+
+        assert_eq!(names(&insts[2].defs()), ["%f1", "%f2", "%f3", "%f4"]);
+        assert_eq!(names(&insts[2].uses()), ["%rd1"]);
+
+        assert!(insts[3].defs().is_empty());
+        assert_eq!(names(&insts[3].uses()), ["%rd2", "%f1", "%f2"]);
+
+        assert_eq!(names(&insts[4].defs()), ["%r1", "%r2", "%r3", "%r4"]);
+        assert_eq!(names(&insts[4].uses()), ["%f1", "%f2"]);
+
+        assert_eq!(names(&insts[5].defs()), ["%r5"]);
+        assert_eq!(names(&insts[5].uses()), ["%r1", "%r2"]);
+    });
+}
+
+#[test]
+fn mutable_accessors_rewrite_defs_only() {
+    ptx_parser::run_with_large_stack(|| {
+        let mut module = parse_ptx(
+            r#"
+            .entry kernel() {
+                add.s32 %r1, %r1, 1;
+            }
+        "#,
+        )
+        .expect("module should parse");
+        let ModuleDirective::EntryFunction { directive, .. } = &mut module.directives[0] else {
+            panic!("first directive should be a kernel");
+        };
+        let FunctionStatement::Instruction { instruction, .. } =
+            &mut directive.body.as_mut().unwrap().statements[0]
+        else {
+            panic!("statement should be an instruction");
+        };
+        for operand in instruction.inst.defs_mut() {
+            for register in operand.into_registers() {
+                register.name = "%r9".to_string();
+            }
+        }
+
+        let text = PtxUnlexer::to_string(&module.to_tokens_spaced()).expect("unlex");
+        assert!(text.contains("add.s32 %r9, %r1, 1;"), "{text}");
+    });
+}
+
+#[test]
+fn wgmma_accumulator_is_read_and_written() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(
+            r#"
+            .entry kernel() {
+                wgmma.mma_async.sync.aligned.m64n8k16.f32.f16.f16 {%f1, %f2, %f3, %f4}, %rd1, %rd2, %p1, 1, 1, 0, 0;
+                wgmma.mma_async.sp.sync.aligned.m64n8k32.f32.f16.f16 {%f1, %f2, %f3, %f4}, %rd1, %rd2, %r1, 0, %p1, 1, 1, 0, 0;
+            }
+        "#,
+        )
+        .expect("module should parse");
+        let insts = instructions(&module);
+
+        let defs = insts[0].defs();
+        assert_eq!(defs[0].role, OperandRole::ReadWrite);
+        assert_eq!(names(&defs), ["%f1", "%f2", "%f3", "%f4"]);
+        assert_eq!(
+            names(&insts[0].uses()),
+            ["%f1", "%f2", "%f3", "%f4", "%rd1", "%rd2", "%p1"]
+        );
+
+        assert_eq!(names(&insts[1].defs()), ["%f1", "%f2", "%f3", "%f4"]);
+        assert_eq!(
+            names(&insts[1].uses()),
+            ["%f1", "%f2", "%f3", "%f4", "%rd1", "%rd2", "%r1", "%p1"]
+        );
+    });
+}
@@ -0,0 +1,202 @@
+use std::collections::BTreeSet;
+
+use ptx_parser::analysis::dataflow::{self, Analysis, Direction};
+use ptx_parser::analysis::{BlockId, Cfg, Location, ReachingDefinitions, functions};
+use ptx_parser::{Span, parse_ptx};
+
+const SOURCE: &str = r#".func (.reg .b32 ret) f(.reg .b64 out)
+{
+    .reg .b32 %r<4>;
+    .reg .b64 %rd<3>;
+    .reg .pred %p1;
+    ld.param.u32 %r1, [out];
+    mov.u32 %r2, 0;
+LOOP:
+    add.u32 %r2, %r2, %r1;
+    setp.lt.u32 %p1, %r2, 100;
+    @%p1 mov.u32 %r1, 1;
+    @%p1 bra LOOP;
+    cvt.u64.u32 %rd1, %r2;
+    mov.u32 ret, %r3;
+    ret;
+}
+"#;
+
+fn span_of(needle: &str, nth: usize) -> Span {
+    let start = SOURCE
+        .match_indices(needle)
+        .nth(nth)
+        .unwrap_or_else(|| panic!("`{needle}` #{nth} not found"))
+        .0;
+    Span::new(start, start + needle.len())
+}
+
+#[test]
+fn use_def_chains_follow_loops_and_partial_definitions() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let cfg = Cfg::build(function.body().unwrap());
+        let reaching = ReachingDefinitions::of(function, &cfg);
+        let spans = |ids: &[_]| -> Vec<Span> {
+            ids.iter().map(|&id| reaching.definition(id).span).collect()
+        };
+
+        // `%r1` in the loop comes from the load or the guarded `mov`, which
+        // does not replace the load on the back edge.
+        let r1 = reaching.use_at(span_of("%r1", 1)).expect("use of %r1");
+        assert_eq!(
+            spans(reaching.definitions_of(r1)),
+            [span_of("%r1", 0), span_of("%r1", 2)]
+        );
+        let guarded = reaching.definitions_of(r1)[1];
+        assert!(reaching.definition(guarded).partial);
+        assert_eq!(
+            reaching.definition(guarded).location,
+            Some(Location {
+                block: BlockId::new(1),
+                index: 3
+            })
+        );
+
+        // The sum is defined before the loop and by the `add` inside it.
+        let r2 = reaching.use_at(span_of("%r2", 2)).expect("use of %r2");
+        assert_eq!(
+            spans(reaching.definitions_of(r2)),
+            [span_of("%r2", 0), span_of("%r2", 1)]
+        );
+        let after = reaching.use_at(span_of("%r2", 4)).expect("use after loop");
+        assert_eq!(spans(reaching.definitions_of(after)), [span_of("%r2", 1)]);
+
+        // `%r3` is never written, and the parameter reaches its load.
+        let r3 = reaching.use_at(span_of("%r3", 0)).expect("use of %r3");
+        assert!(reaching.definitions_of(r3).is_empty());
+        let out = reaching.use_at(span_of("out", 1)).expect("use of out");
+        let [param] = reaching.definitions_of(out) else {
+            panic!("one definition should reach");
+        };
+        assert_eq!(reaching.definition(*param).location, None);
+        assert_eq!(reaching.definition(*param).span, span_of("out", 0));
+    });
+}
+
+#[test]
+fn def_use_chains_find_unused_results() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let cfg = Cfg::build(function.body().unwrap());
+        let reaching = ReachingDefinitions::of(function, &cfg);
+
+        let (load, _) = reaching
+            .definitions()
+            .find(|(_, definition)| definition.span == span_of("%r1", 0))
+            .unwrap();
+        let uses: Vec<Span> = reaching
+            .uses_of(load)
+            .iter()
+            .map(|&id| reaching.get_use(id).span)
+            .collect();
+        assert_eq!(uses, [span_of("%r1", 1)]);
+
+        // The caller reads the return register; nothing reads the `cvt` result.
+        let unused: Vec<&str> = reaching
+            .unused()
+            .map(|id| reaching.definition(id).register)
+            .collect();
+        assert_eq!(unused, ["%rd1"]);
+
+        let reaching_exit = reaching.reaching_at(Location {
+            block: BlockId::new(2),
+            index: 2,
+        });
+        assert!(reaching_exit.contains(&load));
+        assert_eq!(reaching.reaching_in(BlockId::new(0)).len(), 2);
+        assert!(reaching.reaching_out(BlockId::new(1)).contains(&load));
+    });
+}
+
+/// Number of instructions on the longest path to each block, capped so that
+/// the lattice stays finite.
+struct Depth;
+
+impl Analysis for Depth {
+    type Fact = BTreeSet<u32>;
+    const DIRECTION: Direction = Direction::Forward;
+
+    fn bottom(&self) -> Self::Fact {
+        BTreeSet::new()
+    }
+
+    fn boundary(&self) -> Self::Fact {
+        BTreeSet::from([0])
+    }
+
+    fn transfer(&self, _block: BlockId, input: &Self::Fact) -> Self::Fact {
+        input.iter().map(|depth| (depth + 1).min(3)).collect()
+    }
+}
+
+#[test]
+fn solver_reaches_a_fixed_point_on_cycles() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let cfg = Cfg::build(function.body().unwrap());
+        let solution = dataflow::solve(&cfg, &Depth);
+
+        assert_eq!(solution.entry(cfg.entry()), &BTreeSet::from([0]));
+        assert_eq!(solution.entry(BlockId::new(1)), &BTreeSet::from([1, 2, 3]));
+        assert_eq!(solution.exit(BlockId::new(2)), &BTreeSet::from([3]));
+    });
+}
+
+#[test]
+fn wgmma_accumulators_are_read_before_they_are_written() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#".entry gemm(.param .u64 out)
+{
+    .reg .f32 %f<3>;
+    .reg .b64 %rd<4>;
+    .reg .b32 %r1;
+    .reg .pred %p<3>;
+    ld.param.u64 %rd3, [out];
+    mov.f32 %f1, 0f00000000;
+    mov.f32 %f2, 0f00000000;
+    mov.u32 %r1, 0;
+    setp.ne.u32 %p2, %r1, 0;
+LOOP:
+    wgmma.mma_async.sync.aligned.m64n8k16.f32.f16.f16 {%f1, %f2}, %rd1, %rd2, %p2, 1, 1, 0, 0;
+    add.u32 %r1, %r1, 1;
+    setp.lt.u32 %p1, %r1, 8;
+    @%p1 bra LOOP;
+    st.global.v2.f32 [%rd3], {%f1, %f2};
+    ret;
+}
+"#;
+        let span_of = |needle: &str, nth: usize| {
+            let start = source.match_indices(needle).nth(nth).unwrap().0;
+            Span::new(start, start + needle.len())
+        };
+        let module = parse_ptx(source).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let cfg = Cfg::build(function.body().unwrap());
+        let reaching = ReachingDefinitions::of(function, &cfg);
+
+        // The `wgmma` accumulates into `%f1`: the initial `mov` and the
+        // previous iteration both reach it.
+        let accumulator = reaching.use_at(span_of("%f1", 1)).expect("use of %f1");
+        let spans: Vec<Span> = reaching
+            .definitions_of(accumulator)
+            .iter()
+            .map(|&id| reaching.definition(id).span)
+            .collect();
+        assert_eq!(spans, [span_of("%f1", 0), span_of("%f1", 1)]);
+
+        let unused: Vec<&str> = reaching
+            .unused()
+            .map(|id| reaching.definition(id).register)
+            .collect();
+        assert!(unused.is_empty(), "{unused:?}");
+    });
+}
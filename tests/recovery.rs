@@ -0,0 +1,261 @@
+use ptx_parser::r#type::{FunctionBody, FunctionStatement, Module, ModuleDirective};
+use ptx_parser::{
+    LexErrorKind, ParseErrorKind, PtxUnlexer, PtxUnparser, parse_ptx, parse_ptx_recovering,
+};
+
+fn entry_body<'a>(module: &'a Module, name: &str) -> &'a FunctionBody {
+    module
+        .directives
+        .iter()
+        .find_map(|directive| match directive {
+            ModuleDirective::EntryFunction { directive, .. } if directive.name.val == name => {
+                directive.body.as_ref()
+            }
+            _ => None,
+        })
+        .unwrap_or_else(|| panic!("entry `{name}` should be present"))
+}
+
+fn error_texts(statements: &[FunctionStatement]) -> Vec<&str> {
+    let mut texts = Vec::new();
+    for statement in statements {
+        match statement {
+            FunctionStatement::Error { text, .. } => texts.push(text.as_str()),
+            FunctionStatement::Block { statements, .. } => texts.extend(error_texts(statements)),
+            _ => {}
+        }
+    }
+    texts
+}
+
+fn instruction_count(statements: &[FunctionStatement]) -> usize {
+    statements
+        .iter()
+        .map(|statement| match statement {
+            FunctionStatement::Instruction { .. } => 1,
+            FunctionStatement::Block { statements, .. } => instruction_count(statements),
+            _ => 0,
+        })
+        .sum()
+}
+
+#[test]
+fn valid_module_has_no_errors() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#"
+            .version 8.5
+            .target sm_90
+            .address_size 64
+
+            .entry kernel() {
+                add.s32 %r1, %r2, %r3;
+                ret;
+            }
+        "#;
+
+        let (module, errors) = parse_ptx_recovering(source);
+        assert!(errors.is_empty(), "unexpected errors: {errors:?}");
+        assert_eq!(module, parse_ptx(source).expect("module should parse"));
+    });
+}
+
+#[test]
+fn bad_instruction_becomes_error_statement() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#"
+            .version 8.5
+            .target sm_90
+            .entry kernel() {
+                add.s32 %r1, %r2, %r3;
+                frobnicate.b32 %r4, %r5;
+                mov.u32 %r6, %r1;
+                ret;
+            }
+        "#;
+
+        assert!(parse_ptx(source).is_err());
+
+        let (module, errors) = parse_ptx_recovering(source);
+        assert_eq!(errors.len(), 1, "errors: {errors:?}");
+
+        let body = entry_body(&module, "kernel");
+        assert_eq!(instruction_count(&body.statements), 3);
+        assert_eq!(
+            error_texts(&body.statements),
+            vec!["frobnicate.b32 %r4, %r5;"]
+        );
+
+        let FunctionStatement::Error { text, span } = &body.statements[1] else {
+            panic!("second statement should be an error node");
+        };
+        assert_eq!(&source[span.start..span.end], text);
+    });
+}
+
+#[test]
+fn state_space_modifiers_do_not_end_recovery() {
+    ptx_parser::run_with_large_stack(|| {
+        // `.global` and `.const` also start module variables, but not in the
+        // middle of an instruction.
+        for broken in [
+            "ld.global.f32 %f1, [%rd1+;",
+            "ld.global.f32 %f1, %f1;",
+            "st.global.f32 [%rd1], ;",
+            "ld.const.u32 %r1, [c+];",
+        ] {
+            let source = format!(
+                ".entry kernel() {{\n    mov.u32 %r1, %r2;\n    {broken}\n    add.s32 %r1, %r2, %r3;\n    st.global.f32 [%rd1], %f1;\n    ret;\n}}\n.entry second() {{\n    ret;\n}}\n"
+            );
+
+            let (module, errors) = parse_ptx_recovering(&source);
+            assert_eq!(errors.len(), 1, "{broken}: {errors:?}");
+
+            let body = entry_body(&module, "kernel");
+            assert_eq!(error_texts(&body.statements), vec![broken]);
+            assert_eq!(instruction_count(&body.statements), 4, "{broken}");
+            entry_body(&module, "second");
+        }
+    });
+}
+
+#[test]
+fn errors_inside_nested_blocks_are_recovered() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#"
+            .version 8.5
+            .target sm_90
+            .entry kernel() {
+                {
+                    .reg .b32 %inner;
+                    mov.u32 %inner, bogus bogus;
+                    add.s32 %r1, %r2, %r3;
+                }
+                not_an_op;
+                ret;
+            }
+        "#;
+
+        let (module, errors) = parse_ptx_recovering(source);
+        assert_eq!(errors.len(), 2, "errors: {errors:?}");
+
+        let body = entry_body(&module, "kernel");
+        assert_eq!(
+            error_texts(&body.statements),
+            vec!["mov.u32 %inner, bogus bogus;", "not_an_op;"]
+        );
+        assert_eq!(instruction_count(&body.statements), 2);
+    });
+}
+
+#[test]
+fn broken_module_directive_is_skipped() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#"
+            .version 8.5
+            .target sm_90
+            .global .b32 broken = ;
+            .entry first(.param .u32 a,, ) {
+                ret;
+            }
+            .entry second() {
+                ret;
+            }
+        "#;
+
+        let (module, errors) = parse_ptx_recovering(source);
+        assert_eq!(errors.len(), 2, "errors: {errors:?}");
+        assert!(errors[0].span.start < errors[1].span.start);
+
+        entry_body(&module, "second");
+        assert_eq!(module.directives.len(), 3);
+    });
+}
+
+#[test]
+fn missing_closing_brace_resynchronises_at_next_function() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#"
+            .version 8.5
+            .target sm_90
+            .entry first() {
+                mov.u32 %r1, %r2;
+
+            .entry second() {
+                ret;
+            }
+        "#;
+
+        let (module, errors) = parse_ptx_recovering(source);
+        assert_eq!(errors.len(), 1, "errors: {errors:?}");
+
+        assert_eq!(
+            instruction_count(&entry_body(&module, "first").statements),
+            1
+        );
+        assert_eq!(
+            instruction_count(&entry_body(&module, "second").statements),
+            1
+        );
+    });
+}
+
+#[test]
+fn error_statements_unparse_to_original_tokens() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = ".entry kernel() {\n    frobnicate.b32 %r4, %r5;\n    ret;\n}\n";
+
+        let (module, errors) = parse_ptx_recovering(source);
+        assert_eq!(errors.len(), 1);
+
+        let text = PtxUnlexer::to_string(&module.to_tokens_spaced()).expect("unlex");
+        assert!(
+            text.contains("frobnicate.b32 %r4, %r5;"),
+            "unparsed text: {text}"
+        );
+        let (reparsed, errors) = parse_ptx_recovering(&text);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            error_texts(&entry_body(&reparsed, "kernel").statements),
+            vec!["frobnicate.b32 %r4, %r5;"]
+        );
+    });
+}
+
+#[test]
+fn lexing_errors_are_reported_once_and_recovered() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#"
+            .version 8.5
+            .target sm_90
+            .entry kernel() {
+                mov.f32 %f1, 0f3F80;
+                add.s32 %r1, %r2, #3;
+                ret;
+            }
+        "#;
+
+        let (module, errors) = parse_ptx_recovering(source);
+        assert_eq!(errors.len(), 2, "errors: {errors:?}");
+        assert!(matches!(
+            errors[0].kind,
+            ParseErrorKind::Lex {
+                kind: LexErrorKind::BadHexFloatWidth { .. },
+                ..
+            }
+        ));
+        assert!(matches!(
+            errors[1].kind,
+            ParseErrorKind::Lex {
+                kind: LexErrorKind::StrayCharacter,
+                ..
+            }
+        ));
+
+        let body = entry_body(&module, "kernel");
+        assert_eq!(
+            error_texts(&body.statements),
+            vec!["mov.f32 %f1, 0f3F80;", "add.s32 %r1, %r2, #3;"]
+        );
+        assert_eq!(instruction_count(&body.statements), 1);
+    });
+}
@@ -0,0 +1,137 @@
+use ptx_parser::analysis::{RegisterTypes, compact_registers, functions};
+use ptx_parser::r#type::{DataType, FunctionStatement, StatementDirective};
+use ptx_parser::{PtxUnlexer, PtxUnparser, Span, parse_ptx};
+
+const SOURCE: &str = r#".func (.reg .b32 ret) f(.reg .b64 a)
+    .reg .f32 %f<3>;
+{
+    .reg .b32 %r<4>, %x;
+    .reg .pred %p<2>;
+    {
+        .reg .b64 %rd<8>;
+        .reg .b32 %r<2>;
+    }
+    ret;
+}
+"#;
+
+fn b32() -> DataType {
+    DataType::B32 {
+        span: Span::default(),
+    }
+}
+
+fn text(directives: &[ptx_parser::r#type::RegisterDirective]) -> Vec<String> {
+    directives
+        .iter()
+        .map(|directive| {
+            PtxUnlexer::to_string(&directive.to_tokens_spaced())
+                .expect("unlex")
+                .trim()
+                .to_string()
+        })
+        .collect()
+}
+
+#[test]
+fn directives_expand_ranges() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let body = functions(&module).next().unwrap().body().unwrap();
+        let FunctionStatement::Directive {
+            directive: StatementDirective::Reg { directive, .. },
+            ..
+        } = &body.statements[0]
+        else {
+            panic!("first statement should be a .reg directive");
+        };
+        assert_eq!(
+            directive.registers().collect::<Vec<_>>(),
+            ["%r0", "%r1", "%r2", "%r3", "%x"]
+        );
+        assert_eq!(directive.registers[1].names().collect::<Vec<_>>(), ["%x"]);
+    });
+}
+
+#[test]
+fn register_types_cover_the_whole_function() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let types = RegisterTypes::of(functions(&module).next().unwrap());
+
+        assert!(matches!(types.get("ret"), Some(DataType::B32 { .. })));
+        assert!(matches!(types.get("a"), Some(DataType::B64 { .. })));
+        assert!(matches!(types.get("%f2"), Some(DataType::F32 { .. })));
+        assert!(matches!(types.get("%r3"), Some(DataType::B32 { .. })));
+        assert!(matches!(types.get("%p1"), Some(DataType::Pred { .. })));
+        assert!(matches!(types.get("%rd7"), Some(DataType::B64 { .. })));
+        assert_eq!(types.get("%rd8"), None);
+        assert_eq!(types.get("%r4"), None);
+        assert_eq!(types.get("%r01"), None);
+
+        // The inner `%r<2>` repeats an earlier declaration and is skipped.
+        assert_eq!(types.len(), 2 + 3 + 5 + 2 + 8);
+        let names: Vec<_> = types.iter().map(|(name, _)| name).collect();
+        assert_eq!(&names[..6], ["ret", "a", "%f0", "%f1", "%f2", "%r0"]);
+    });
+}
+
+#[test]
+fn compacting_registers_round_trips_declarations() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let types = RegisterTypes::of(functions(&module).next().unwrap());
+        let directives = compact_registers(types.iter());
+        assert_eq!(
+            text(&directives),
+            [
+                ".reg .b32 ret, %r<4>, %x;",
+                ".reg .b64 a, %rd<8>;",
+                ".reg .f32 %f<3>;",
+                ".reg .pred %p<2>;",
+            ]
+        );
+    });
+}
+
+#[test]
+fn compacting_keeps_sparse_and_mixed_registers_explicit() {
+    let b64 = DataType::B64 {
+        span: Span::default(),
+    };
+    let registers = [
+        ("%r3", b32()),
+        ("%r1", b32()),
+        ("%r2", b32()),
+        ("%r1", b32()),
+        ("%s2", b32()),
+        ("%s5", b32()),
+        ("%t1", b32()),
+        ("%t2", b64),
+        ("%r", b32()),
+    ];
+    let directives = compact_registers(registers.iter().map(|(name, ty)| (*name, ty)));
+    assert_eq!(
+        text(&directives),
+        [".reg .b32 %r<4>, %s2, %s5, %t1, %r;", ".reg .b64 %t2;"]
+    );
+}
+
+#[test]
+fn ranges_from_one_also_declare_index_zero() {
+    let b64 = DataType::B64 {
+        span: Span::default(),
+    };
+    let registers = [("%r1", b32()), ("%r2", b32())];
+    let directives = compact_registers(registers.iter().map(|(name, ty)| (*name, ty)));
+    assert_eq!(text(&directives), [".reg .b32 %r<3>;"]);
+    assert_eq!(
+        directives[0].registers().collect::<Vec<_>>(),
+        ["%r0", "%r1", "%r2"]
+    );
+
+    // A `%r0` of another type would clash with the range.
+    let registers = [("%r1", b32()), ("%r2", b32()), ("%r0", b64)];
+    let directives = compact_registers(registers.iter().map(|(name, ty)| (*name, ty)));
+    assert_eq!(text(&directives), [".reg .b32 %r1, %r2;", ".reg .b64 %r0;"]);
+}
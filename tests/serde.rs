@@ -0,0 +1,70 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use ptx_parser::r#type::{Instruction, Module};
+use ptx_parser::{PtxUnlexer, PtxUnparser, parse_ptx};
+
+fn sample_paths() -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(Path::new("tests/sample"))
+        .expect("samples directory missing")
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "ptx"))
+        .collect();
+    paths.sort();
+    paths
+}
+
+#[test]
+fn json_round_trips_every_sample() {
+    ptx_parser::run_with_large_stack(|| {
+        let paths = sample_paths();
+        assert!(!paths.is_empty());
+        for path in paths {
+            let source = fs::read_to_string(&path).expect("sample should be readable");
+            let module = parse_ptx(&source)
+                .unwrap_or_else(|err| panic!("{} should parse: {err}", path.display()));
+
+            let json = serde_json::to_string(&module).expect("module should serialize");
+            let restored: Module = serde_json::from_str(&json)
+                .unwrap_or_else(|err| panic!("{} should deserialize: {err}", path.display()));
+            assert!(restored == module, "{} changed in JSON", path.display());
+            assert_eq!(
+                PtxUnlexer::to_string(&restored.to_tokens_spaced()).expect("unlex"),
+                PtxUnlexer::to_string(&module.to_tokens_spaced()).expect("unlex"),
+                "{} unparses differently",
+                path.display()
+            );
+        }
+    });
+}
+
+#[test]
+fn edited_json_unparses_to_edited_ptx() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = ".version 8.5\n.target sm_90\n.entry k() {\n    add.s32 %r1, %r2, 4;\n}\n";
+        let module = parse_ptx(source).expect("module should parse");
+        let json = serde_json::to_string(&module)
+            .expect("module should serialize")
+            .replace("\"%r2\"", "\"%r7\"");
+
+        let restored: Module = serde_json::from_str(&json).expect("module should deserialize");
+        let text = PtxUnlexer::to_string(&restored.to_tokens_spaced()).expect("unlex");
+        assert!(text.contains("add.s32 %r1, %r7, 4;"), "{text}");
+    });
+}
+
+#[test]
+fn instructions_deserialize_on_their_own() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(".entry k() { @!%p1 setp.lt.s32 %p2|%p3, %r1, 4; }")
+            .expect("module should parse");
+        let json = serde_json::to_value(&module).expect("module should serialize");
+        let instruction = &json["directives"][0]["EntryFunction"]["directive"]["body"]["statements"]
+            [0]["Instruction"]["instruction"];
+
+        let restored: Instruction =
+            serde_json::from_value(instruction.clone()).expect("instruction should deserialize");
+        let text = PtxUnlexer::to_string(&restored.to_tokens_spaced()).expect("unlex");
+        assert_eq!(text.trim_end(), "@!%p1 setp.lt.s32 %p2|%p3, %r1, 4;");
+    });
+}
@@ -0,0 +1,266 @@
+mod util;
+
+use ptx_parser::analysis::{
+    Address, AddressSpaces, StateSpace, SymbolTable, check_state_spaces, functions,
+};
+use ptx_parser::{SourceMap, parse_ptx};
+use util::messages;
+
+const VARIABLES: &str = r#".global .align 4 .u32 g[16];
+.const .align 4 .u32 c[4];
+.shared .align 4 .u32 tile[32];
+"#;
+
+/// State space issues of `body` in a kernel that sees the variables of
+/// [`VARIABLES`].
+fn check(body: &str) -> Vec<String> {
+    let source = format!(
+        "{VARIABLES}\n.entry k(.param .u64 p, .param .u32 n)\n{{\n    .reg .b32 %r<6>;\n    .reg .b64 %rd<8>;\n{body}\n    ret;\n}}\n"
+    );
+    let module = parse_ptx(&source).expect("module should parse");
+    messages(&check_state_spaces(&module))
+}
+
+#[test]
+fn addresses_are_traced_through_registers() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = format!(
+            r#"{VARIABLES}
+.entry k(.param .u64 p)
+{{
+    .reg .b32 %r<6>;
+    .reg .b64 %rd<10>;
+    ld.param.u64 %rd1, [p];
+    cvta.to.global.u64 %rd2, %rd1;
+    mul.wide.u32 %rd3, %r1, 4;
+    add.s64 %rd4, %rd2, %rd3;
+    mov.u32 %r3, tile;
+    mad.lo.u32 %r4, %r1, 4, %r3;
+    mov.u64 %rd5, g;
+    cvta.global.u64 %rd6, %rd5;
+    mov.u64 %rd7, %rd6;
+    mov.u64 %rd8, g;
+    @%p1 mov.u64 %rd8, c;
+    ret;
+}}
+"#
+        );
+        let module = parse_ptx(&source).expect("module should parse");
+        let table = SymbolTable::build(&module);
+        let spaces = AddressSpaces::of(functions(&module).next().unwrap(), &table);
+
+        // Loaded values and plain arithmetic are not addresses.
+        assert_eq!(spaces.get("%rd1"), None);
+        assert_eq!(spaces.get("%rd3"), None);
+        assert_eq!(spaces.get("%rd2"), Some(Address::Space(StateSpace::Global)));
+        assert_eq!(spaces.get("%rd4"), Some(Address::Space(StateSpace::Global)));
+        assert_eq!(spaces.get("%r3"), Some(Address::Space(StateSpace::Shared)));
+        assert_eq!(spaces.get("%r4"), Some(Address::Space(StateSpace::Shared)));
+        assert_eq!(spaces.get("%rd5"), Some(Address::Space(StateSpace::Global)));
+        assert_eq!(spaces.get("%rd6"), Some(Address::Generic));
+        assert_eq!(spaces.get("%rd7"), Some(Address::Generic));
+        // Written with addresses in two state spaces.
+        assert_eq!(spaces.get("%rd8"), None);
+    });
+}
+
+#[test]
+fn function_parameters_hold_no_known_address() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#".shared .u32 tile[4];
+.func f(.reg .b64 a)
+{
+    mov.u64 a, tile;
+    ld.global.u32 %r1, [a];
+    ret;
+}
+"#;
+        let module = parse_ptx(source).expect("module should parse");
+        let table = SymbolTable::build(&module);
+        let spaces = AddressSpaces::of(functions(&module).next().unwrap(), &table);
+        assert_eq!(spaces.get("a"), None);
+        assert!(check_state_spaces(&module).is_empty());
+    });
+}
+
+#[test]
+fn variables_are_accessed_in_their_state_space() {
+    ptx_parser::run_with_large_stack(|| {
+        // `.shared::cluster` also reaches `.shared` variables.
+        let body = r#"
+    ld.global.u32 %r1, [g+4];
+    ld.shared.u32 %r1, [tile];
+    ld.shared::cluster.u32 %r1, [tile];
+    ld.const.u32 %r1, [c+4];
+    st.shared.u32 [tile+8], %r1;
+    atom.global.add.u32 %r1, [g], 1;
+"#;
+        assert_eq!(check(body), Vec::<String>::new());
+
+        let body = r#"
+    ld.shared.u32 %r1, [g];
+    ld.global.u32 %r1, [tile+4];
+    ld.global.u32 %r1, [c];
+    red.add.shared.u32 [g], %r1;
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`g` is declared in `.global`, but `ld.shared.u32` accesses `.shared`",
+                "`tile` is declared in `.shared`, but `ld.global.u32` accesses `.global`",
+                "`c` is declared in `.const`, but `ld.global.u32` accesses `.global`",
+                "`g` is declared in `.global`, but `red.add.shared.u32` accesses `.shared`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn constants_and_kernel_parameters_are_read_only() {
+    ptx_parser::run_with_large_stack(|| {
+        // A write in the wrong state space is reported as a write.
+        let body = r#"
+    st.global.u32 [c+4], %r1;
+    st.param.u32 [n], %r1;
+    ld.param.u32 %r1, [n];
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`st.global.u32` writes to `c`, which is read-only",
+                "`st.param.u32` writes to `n`, which is read-only",
+            ]
+        );
+
+        // The parameters of a `.func` may be written.
+        let source = ".func (.param .b32 r) f(.param .b32 a)\n{\n    .reg .b32 %r1;\n    ld.param.b32 %r1, [a];\n    st.param.b32 [r], %r1;\n    st.param.b32 [a], %r1;\n    ret;\n}\n";
+        let module = parse_ptx(source).expect("module should parse");
+        assert!(check_state_spaces(&module).is_empty());
+    });
+}
+
+#[test]
+fn entry_parameters_are_only_read_in_kernels() {
+    ptx_parser::run_with_large_stack(|| {
+        assert_eq!(
+            check("    ld.param::entry.u32 %r1, [n];"),
+            Vec::<String>::new()
+        );
+
+        let source = ".func f(.param .b32 a)\n{\n    .reg .b32 %r1;\n    ld.param::entry.u32 %r1, [a];\n    ld.param::func.u32 %r1, [a];\n    ret;\n}\n";
+        let module = parse_ptx(source).expect("module should parse");
+        assert_eq!(
+            messages(&check_state_spaces(&module)),
+            ["`ld.param::entry.u32` accesses `.param::entry` in `.func` `f`"]
+        );
+    });
+}
+
+#[test]
+fn generic_addressing_needs_a_generic_address() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    mov.u64 %rd1, g;
+    cvta.global.u64 %rd2, %rd1;
+    ld.u32 %r1, [%rd2];
+    st.u32 [%rd2+4], %r1;
+    mov.u32 %r2, tile;
+    ld.u32 %r1, [p];
+    ld.u32 %r1, [%r2];
+    ld.u32 %r1, [%rd1];
+    ld.u32 %r1, [g];
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`ld.u32` uses generic addressing, but `p` is an address in `.param`",
+                "`ld.u32` uses generic addressing, but `%r2` is an address in `.shared`",
+                "`ld.u32` uses generic addressing, but `%rd1` is an address in `.global`",
+                "`ld.u32` uses generic addressing, but `g` is an address in `.global`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn registers_must_hold_an_address_in_the_accessed_space() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    ld.param.u64 %rd1, [p];
+    cvta.to.global.u64 %rd2, %rd1;
+    cvta.global.u64 %rd3, %rd2;
+    ld.global.u32 %r1, [%rd2];
+    ld.global.u32 %r1, [%rd3];
+    atom.shared.add.u32 %r1, [%rd2], 1;
+    prefetch.local.L1 [%rd2];
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`%rd3` holds a generic address, but `ld.global.u32` accesses `.global`",
+                "`%rd2` holds an address in `.global`, but `atom.shared.add.u32` accesses `.shared`",
+                "`%rd2` holds an address in `.global`, but `prefetch.local.L1` accesses `.local`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn untraced_addresses_are_accepted() {
+    ptx_parser::run_with_large_stack(|| {
+        // Loaded pointers and registers holding addresses in two state spaces
+        // could point anywhere.
+        let body = r#"
+    ld.param.u64 %rd1, [p];
+    ld.global.u32 %r1, [%rd1];
+    ld.shared.u32 %r1, [%rd1];
+    ld.u32 %r1, [%rd1];
+    mov.u64 %rd2, g;
+    @%p1 mov.u64 %rd2, tile;
+    ld.shared.u32 %r1, [%rd2];
+    ld.u32 %r1, [undeclared];
+"#;
+        assert_eq!(check(body), Vec::<String>::new());
+    });
+}
+
+#[test]
+fn diagnostics_point_at_the_address_operand() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#".shared .u32 tile[4];
+.entry k(.param .u32 n)
+{
+    .reg .b32 %r<3>;
+    .reg .b64 %rd<3>;
+    st.param.u32 [n], %r1;
+    mov.u32 %r2, tile;
+    ld.u32 %r1, [%r2+4];
+    cvta.shared.u64 %rd1, %rd2;
+    ld.shared.u32 %r1, [%rd1];
+}
+"#;
+        let module = parse_ptx(source).expect("module should parse");
+        let issues = check_state_spaces(&module);
+        assert_eq!(issues.len(), 3);
+
+        assert_eq!(
+            issues[0]
+                .to_diagnostic()
+                .render(&SourceMap::new(source), Some("k.ptx")),
+            "error: `st.param.u32` writes to `n`, which is read-only\n --> k.ptx:6:18\n  |\n6 |     st.param.u32 [n], %r1;\n  |                  ^^^ kernel parameter\n  |\n  = note: kernel parameters can only be read\n"
+        );
+
+        let generic = issues[1].to_diagnostic();
+        assert_eq!(&source[generic.span.start..generic.span.end], "[%r2+4]");
+        assert_eq!(generic.label.as_deref(), Some("expected a generic address"));
+        assert_eq!(generic.notes, ["convert it with `cvta.shared` first"]);
+
+        let register = issues[2].to_diagnostic();
+        assert_eq!(&source[register.span.start..register.span.end], "[%rd1]");
+        assert_eq!(
+            register.label.as_deref(),
+            Some("expected an address in `.shared`")
+        );
+        assert_eq!(register.notes, ["convert it with `cvta.to.shared` first"]);
+    });
+}
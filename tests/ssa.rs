@@ -0,0 +1,230 @@
+use std::collections::HashSet;
+
+use ptx_parser::analysis::{BlockId, Cfg, Ssa, functions};
+use ptx_parser::r#type::{FunctionBody, FunctionStatement, ModuleDirective};
+use ptx_parser::{PtxUnlexer, PtxUnparser, parse_ptx};
+
+const SOURCE: &str = r#".func (.reg .b32 ret) swap(.reg .b32 n)
+{
+    .reg .b32 %r<4>;
+    .reg .pred %p<3>;
+    mov.u32 %r1, 1;
+    mov.u32 %r2, 2;
+LOOP:
+    mov.u32 %r3, %r1;
+    mov.u32 %r1, %r2;
+    mov.u32 %r2, %r3;
+    setp.lt.u32 %p1, %r1, n;
+    @%p1 bra LOOP;
+    setp.eq.u32 %p2, %r2, 0;
+    @%p2 mov.u32 %r1, 0;
+    add.u32 ret, %r1, %r2;
+    ret;
+}
+"#;
+
+fn unparse(body: &FunctionBody) -> String {
+    PtxUnlexer::to_string(&body.to_tokens_spaced()).expect("unlex")
+}
+
+/// Registers written by the instructions of `statements`, including nested
+/// blocks.
+fn written(statements: &[FunctionStatement], out: &mut Vec<String>) {
+    for statement in statements {
+        match statement {
+            FunctionStatement::Instruction { instruction, .. } => out.extend(
+                instruction
+                    .inst
+                    .defs()
+                    .iter()
+                    .flat_map(|operand| operand.registers())
+                    .map(|register| register.name.clone()),
+            ),
+            FunctionStatement::Block { statements, .. } => written(statements, out),
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn phis_join_versions_at_loop_headers() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let cfg = Cfg::build(function.body().unwrap());
+        let ssa = Ssa::build(function).expect("function has a body");
+
+        // `%r3` and the predicates are written before they are read in the
+        // loop, so only `%r1` and `%r2` need phis.
+        let header = cfg.block_of_label("LOOP").unwrap();
+        let phis: Vec<_> = ssa
+            .phis()
+            .iter()
+            .map(|phi| (phi.block, phi.register.as_str(), phi.result.as_str()))
+            .collect();
+        assert_eq!(phis, [(header, "%r1", "%r1_2"), (header, "%r2", "%r2_2")]);
+        let incoming: Vec<_> = ssa.phis()[0]
+            .incoming
+            .iter()
+            .map(|(source, version)| (*source, version.as_str()))
+            .collect();
+        assert_eq!(
+            incoming,
+            [(Some(BlockId::new(0)), "%r1_1"), (Some(header), "%r1_3")]
+        );
+
+        // Every version is written once.
+        let mut writes = Vec::new();
+        written(&ssa.body().statements, &mut writes);
+        let unique: HashSet<&String> = writes.iter().collect();
+        assert_eq!(unique.len(), writes.len());
+        assert!(!writes.contains(&"%r1".to_string()));
+        assert_eq!(ssa.original("%r2_3"), "%r2");
+        assert_eq!(ssa.original("n"), "n");
+        assert_eq!(ssa.versions().count(), ssa.phis().len() + writes.len());
+    });
+}
+
+#[test]
+fn guarded_writes_keep_the_previous_version() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let ssa = Ssa::build(function).expect("function has a body");
+
+        let [guarded] = ssa.guarded() else {
+            panic!("expected one guarded write: {:?}", ssa.guarded());
+        };
+        assert_eq!(guarded.path, [11]);
+        assert_eq!(guarded.result, "%r1_4");
+        assert_eq!(guarded.previous, "%r1_3");
+
+        let text = unparse(&ssa.eliminate_phis().unwrap());
+        assert!(text.contains("mov.b32 %r1_4, %r1_3;\n@%p2_1 mov.u32 %r1_4, 0;"));
+        assert!(text.contains("add.u32 ret, %r1_4, %r2_3;"));
+    });
+}
+
+#[test]
+fn eliminated_phis_give_ptx_that_round_trips() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let ssa = Ssa::build(function).expect("function has a body");
+        let body = ssa.eliminate_phis().unwrap();
+        let text = unparse(&body);
+
+        // The loop entry falls through into the header; the back edge is a
+        // conditional branch and gets a block of its own.
+        assert!(text.contains("mov.b32 %r2_2, %r2_1;\nLOOP:"));
+        assert!(text.contains("@%p1_1 bra $L__ssa_0;"));
+        assert!(text.ends_with(
+            "ret;\n$L__ssa_0:\nmov.b32 %r1_2, %r1_3;\nmov.b32 %r2_2, %r2_3;\nbra LOOP;\n}\n"
+        ));
+        assert!(
+            text.starts_with(
+                "{\n.reg .b32 %r1_<5>, %r2_<4>, %r3_<2>;\n.reg .pred %p1_<2>, %p2_<2>;"
+            )
+        );
+
+        let reparsed = parse_ptx(&format!(".func (.reg .b32 ret) swap(.reg .b32 n)\n{text}"))
+            .expect("eliminated body should parse");
+        let ModuleDirective::FuncFunction { directive, .. } = &reparsed.directives[0] else {
+            panic!("expected a .func");
+        };
+        assert_eq!(unparse(directive.body.as_ref().unwrap()), text);
+    });
+}
+
+#[test]
+fn cyclic_copies_go_through_a_temporary() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(SOURCE).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let mut ssa = Ssa::build(function).expect("function has a body");
+
+        // Propagate the copies of the loop body into the phis, which then
+        // swap their results on the back edge.
+        let phis = ssa.phis_mut();
+        phis[0].incoming[1].1 = "%r2_2".to_string();
+        phis[1].incoming[1].1 = "%r1_2".to_string();
+
+        let text = unparse(&ssa.eliminate_phis().unwrap());
+        assert!(text.contains(
+            "$L__ssa_0:\nmov.b32 %r1_5, %r1_2;\nmov.b32 %r1_2, %r2_2;\nmov.b32 %r2_2, %r1_5;\nbra LOOP;"
+        ));
+        assert!(text.contains(".reg .b32 %r1_<6>,"));
+    });
+}
+
+#[test]
+fn wgmma_accumulators_keep_their_names() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#".entry gemm(.param .u64 a, .param .u64 b)
+{
+    .reg .f32 %f<3>;
+    .reg .b64 %rd<3>;
+    .reg .b32 %r1;
+    .reg .pred %p<3>;
+    ld.param.u64 %rd1, [a];
+    ld.param.u64 %rd2, [b];
+    mov.f32 %f1, 0f00000000;
+    mov.f32 %f2, 0f00000000;
+    mov.u32 %r1, 0;
+    setp.ne.u32 %p2, %r1, 0;
+LOOP:
+    wgmma.mma_async.sync.aligned.m64n8k16.f32.f16.f16 {%f1, %f2}, %rd1, %rd2, %p2, 1, 1, 0, 0;
+    add.u32 %r1, %r1, 1;
+    setp.lt.u32 %p1, %r1, 8;
+    @%p1 bra LOOP;
+    ret;
+}
+"#;
+        let module = parse_ptx(source).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let ssa = Ssa::build(function).expect("function has a body");
+
+        // The accumulator carries its value from one `wgmma` to the next, so
+        // it is left out of renaming; the other operands are still renamed.
+        assert!(ssa.phis().iter().all(|phi| !phi.register.starts_with("%f")));
+        let text = unparse(&ssa.eliminate_phis().unwrap());
+        assert!(!text.contains("%f1_"), "{text}");
+        assert!(text.contains("mov.f32 %f1, 0f00000000;"), "{text}");
+        assert!(
+            text.contains(
+                "wgmma.mma_async.sync.aligned.m64n8k16.f32.f16.f16 {%f1,%f2}, %rd1_1, %rd2_1, %p2_1, 1, 1, 0, 0;"
+            ),
+            "{text}"
+        );
+    });
+}
+
+#[test]
+fn entry_copies_follow_the_declarations() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = ".entry k()\n{\n.reg .b32 %r1;\n.reg .pred %p1;\nLOOP:\nadd.u32 %r1, %r1, 1;\nsetp.lt.u32 %p1, %r1, 10;\n@%p1 bra LOOP;\nret;\n}\n";
+        let module = parse_ptx(source).expect("module should parse");
+        let function = functions(&module).next().unwrap();
+        let ssa = Ssa::build(function).expect("function has a body");
+
+        // The entry block is the loop header, so `%r1` arrives from the
+        // function entry as well as from the back edge.
+        let [phi] = ssa.phis() else {
+            panic!("expected one phi: {:?}", ssa.phis());
+        };
+        assert_eq!(phi.incoming[0], (None, "%r1".to_string()));
+
+        let text = unparse(&ssa.eliminate_phis().unwrap());
+        assert!(
+            text.contains(".reg .b32 %r1;\n.reg .pred %p1;\nmov.b32 %r1_1, %r1;\nLOOP:"),
+            "{text}"
+        );
+
+        let reparsed =
+            parse_ptx(&format!(".entry k()\n{text}")).expect("eliminated body should parse");
+        let ModuleDirective::EntryFunction { directive, .. } = &reparsed.directives[0] else {
+            panic!("expected an .entry");
+        };
+        assert_eq!(unparse(directive.body.as_ref().unwrap()), text);
+    });
+}
@@ -0,0 +1,177 @@
+use ptx_parser::analysis::{
+    Resolution, ScopeKind, StateSpace, SymbolIssue, SymbolKind, SymbolTable,
+};
+use ptx_parser::{SourceMap, Span, parse_ptx};
+
+const SAMPLES: &[&str] = &[
+    include_str!("sample/hello.ptx"),
+    include_str!("sample/vector_add_scalar.debug.ptx"),
+    include_str!("sample/mini_step64.ptx"),
+    include_str!("sample/matrix_free_device_matrix_vector_01.1.sm_80.ptx"),
+];
+
+const NESTED: &str = r#".global .u32 g;
+.entry k()
+{
+    .reg .b32 %r<4>;
+    mov.u32 %r1, 1;
+    {
+        .reg .b32 %r1;
+        .reg .b32 tmp;
+        mov.u32 %r1, %r2;
+    }
+    mov.u32 %r3, tmp;
+    add.u32 %r9, %r0, %tid.x;
+    bra DONE;
+DONE:
+    ld.global.u32 %r0, [g];
+    ret;
+}
+"#;
+
+fn span_of(source: &str, needle: &str, nth: usize) -> Span {
+    let start = source
+        .match_indices(needle)
+        .nth(nth)
+        .unwrap_or_else(|| panic!("`{needle}` #{nth} not found"))
+        .0;
+    Span::new(start, start + needle.len())
+}
+
+#[test]
+fn samples_resolve_without_issues() {
+    ptx_parser::run_with_large_stack(|| {
+        for source in SAMPLES {
+            let module = parse_ptx(source).expect("sample should parse");
+            let table = SymbolTable::build(&module);
+            assert!(!table.references().is_empty());
+            assert_eq!(table.issues(), []);
+        }
+    });
+}
+
+#[test]
+fn nested_blocks_scope_declarations() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(NESTED).expect("module should parse");
+        let table = SymbolTable::build(&module);
+
+        // `%r1` inside the block resolves to the inner declaration, `%r2` to
+        // the outer range.
+        let inner = table
+            .resolve(span_of(NESTED, "%r1", 2))
+            .expect("inner %r1 should resolve");
+        assert_eq!(table.scope(inner.scope).kind, ScopeKind::Block);
+        assert_eq!(inner.span, span_of(NESTED, "%r1", 1));
+        let range = table
+            .resolve(span_of(NESTED, "%r2", 0))
+            .expect("%r2 should resolve");
+        assert!(matches!(
+            range.kind,
+            SymbolKind::Register { range: Some(4), .. }
+        ));
+        assert!(matches!(
+            table.scope(range.scope).kind,
+            ScopeKind::Function { name } if table.name(name) == "k"
+        ));
+        assert_eq!(table.resolve(span_of(NESTED, "%r1", 0)), Some(range));
+
+        let label = table
+            .resolve(span_of(NESTED, "DONE", 0))
+            .expect("forward branch should resolve");
+        assert_eq!(label.kind, SymbolKind::Label);
+        let address = span_of(NESTED, "[g]", 0);
+        let global = table
+            .resolve(Span::new(address.start + 1, address.end - 1))
+            .expect("global should resolve");
+        assert!(matches!(
+            global.kind,
+            SymbolKind::Variable {
+                space: StateSpace::Global,
+                ..
+            }
+        ));
+        assert_eq!(
+            table
+                .reference_at(span_of(NESTED, "%tid.x", 0))
+                .unwrap()
+                .resolution,
+            Resolution::Builtin
+        );
+
+        assert_eq!(
+            table.issues(),
+            [
+                SymbolIssue::Shadowed {
+                    name: "%r1".into(),
+                    span: span_of(NESTED, "%r1", 1),
+                    previous: span_of(NESTED, "%r", 0),
+                },
+                SymbolIssue::Undefined {
+                    name: "tmp".into(),
+                    span: span_of(NESTED, "tmp", 1),
+                },
+                SymbolIssue::Undefined {
+                    name: "%r9".into(),
+                    span: span_of(NESTED, "%r9", 0),
+                },
+            ]
+        );
+    });
+}
+
+#[test]
+fn prototypes_and_redeclarations() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = ".func foo();\n.func foo() { ret; }\n.entry k() { .reg .pred %p; .reg .pred %p; call foo; }\n";
+        let module = parse_ptx(source).expect("module should parse");
+        let table = SymbolTable::build(&module);
+
+        let callee = table
+            .resolve(span_of(source, "foo", 2))
+            .expect("call should resolve");
+        assert_eq!(
+            callee.kind,
+            SymbolKind::Function {
+                entry: false,
+                defined: true
+            }
+        );
+        assert_eq!(callee.span, span_of(source, "foo", 1));
+        let scope = table
+            .function_scope("foo")
+            .expect("foo should have a scope");
+        // Scopes follow source order, so the prototype's scope comes first.
+        assert_eq!(scope.index(), 2);
+
+        assert_eq!(
+            table.issues(),
+            [SymbolIssue::Redeclared {
+                name: "%p".into(),
+                span: span_of(source, "%p", 1),
+                previous: span_of(source, "%p", 0),
+            }]
+        );
+    });
+}
+
+#[test]
+fn issues_render_with_severity() {
+    ptx_parser::run_with_large_stack(|| {
+        let module = parse_ptx(NESTED).expect("module should parse");
+        let table = SymbolTable::build(&module);
+        let map = SourceMap::new(NESTED);
+
+        let shadowed = table.issues()[0].to_diagnostic().render(&map, None);
+        assert!(
+            shadowed.starts_with("warning: `%r1` shadows a declaration in an enclosing scope"),
+            "{shadowed}"
+        );
+        let undefined = table.issues()[1].to_diagnostic().render(&map, None);
+        assert!(
+            undefined.starts_with("error: `tmp` is not declared"),
+            "{undefined}"
+        );
+        assert!(undefined.contains("not found in this scope"), "{undefined}");
+    });
+}
@@ -0,0 +1,292 @@
+mod util;
+
+use ptx_parser::analysis::{TargetIssue, check_targets, min_target, module_targets};
+use ptx_parser::r#type::{FunctionStatement, ModuleDirective};
+use ptx_parser::{PtxVersion, SourceMap, Target, parse_ptx};
+use util::messages;
+
+/// Source of a module with the directives `header` and a kernel running
+/// `body`.
+fn module(header: &str, body: &str) -> String {
+    format!(
+        "{header}\n.entry k()\n{{\n    .reg .b64 %rd<3>;\n    .reg .b32 %r<4>;\n    .reg .f32 %f<3>;\n    .reg .pred %p<2>;\n{body}\n    ret;\n}}\n"
+    )
+}
+
+fn check(header: &str, body: &str) -> Vec<String> {
+    let source = module(header, body);
+    messages(&check_targets(
+        &parse_ptx(&source).expect("module should parse"),
+    ))
+}
+
+#[test]
+fn instructions_allowed_by_version_and_target_are_accepted() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    ld.global.L2::256B.f32 %f1, [%rd1];
+    cp.async.ca.shared.global [%r1], [%rd1], 16;
+    add.s32 %r1, %r2, %r3;
+"#;
+        assert_eq!(
+            check(".version 7.8\n.target sm_80", body),
+            Vec::<String>::new()
+        );
+        // Later versions and targets allow them too.
+        assert_eq!(
+            check(".version 8.5\n.target sm_90a", body),
+            Vec::<String>::new()
+        );
+    });
+}
+
+#[test]
+fn instructions_newer_than_the_version_are_reported() {
+    ptx_parser::run_with_large_stack(|| {
+        let body =
+            "    elect.sync %r1|%p1, 0xffffffff;\n    mapa.shared::cluster.u32 %r1, %r2, %r3;";
+        assert_eq!(
+            check(".version 7.7\n.target sm_90", body),
+            [
+                "`elect.sync` requires PTX ISA 8.0, but the module is version 7.7",
+                "`mapa.shared::cluster.u32` requires PTX ISA 7.8, but the module is version 7.7",
+            ]
+        );
+    });
+}
+
+#[test]
+fn instructions_missing_from_the_target_are_reported() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = "    elect.sync %r1|%p1, 0xffffffff;\n    tanh.approx.f32 %f1, %f2;";
+        assert_eq!(
+            check(".version 8.0\n.target sm_70", body),
+            [
+                "`elect.sync` is not supported on `sm_70`",
+                "`tanh.approx.f32` is not supported on `sm_70`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn architecture_specific_instructions_need_that_exact_architecture() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = "    wgmma.fence.sync.aligned;";
+        assert_eq!(
+            check(".version 8.0\n.target sm_90a", body),
+            Vec::<String>::new()
+        );
+        for target in ["sm_90", "sm_100a"] {
+            assert_eq!(
+                check(&format!(".version 8.0\n.target {target}"), body),
+                [format!(
+                    "`wgmma.fence.sync.aligned` is not supported on `{target}`"
+                )]
+            );
+        }
+    });
+}
+
+#[test]
+fn modifiers_are_checked_on_any_instruction() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    ld.global.L2::128B.f32 %f1, [%rd1];
+    ld.global.L2::256B.f32 %f1, [%rd1];
+"#;
+        assert_eq!(
+            check(".version 7.3\n.target sm_75", body),
+            [
+                "`ld.global.L2::128B.f32` requires PTX ISA 7.4, but the module is version 7.3",
+                "`ld.global.L2::256B.f32` requires PTX ISA 7.4, but the module is version 7.3",
+                "`ld.global.L2::256B.f32` is not supported on `sm_75`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn each_instruction_is_reported_once_per_version_and_target() {
+    ptx_parser::run_with_large_stack(|| {
+        // `cp.async.bulk` also matches `cp.async`; only the stricter
+        // requirement is named.
+        let source = module(
+            ".version 6.5\n.target sm_70",
+            "    cp.async.bulk.shared::cluster.global.mbarrier::complete_tx::bytes [%r1], [%rd1], 16, [%r2];",
+        );
+        let issues = check_targets(&parse_ptx(&source).expect("module should parse"));
+        let patterns: Vec<_> = issues
+            .iter()
+            .map(|issue| match issue {
+                TargetIssue::Version { used, .. } | TargetIssue::Target { used, .. } => {
+                    used.requirement.pattern
+                }
+                TargetIssue::Conflict { .. } => unreachable!(),
+            })
+            .collect();
+        assert_eq!(patterns, ["cp.async.bulk", "cp.async.bulk"]);
+    });
+}
+
+#[test]
+fn every_declared_target_is_checked() {
+    ptx_parser::run_with_large_stack(|| {
+        let header = ".version 8.0\n.target sm_90, texmode_independent, sm_80";
+        let source = module(header, "");
+        let module = parse_ptx(&source).expect("module should parse");
+        // Options are not targets.
+        assert_eq!(
+            module_targets(&module)
+                .iter()
+                .map(|(target, _)| *target)
+                .collect::<Vec<_>>(),
+            [Target::base(90), Target::base(80)]
+        );
+
+        assert_eq!(
+            check(header, "    mapa.shared::cluster.u32 %r1, %r2, %r3;"),
+            ["`mapa.shared::cluster.u32` is not supported on `sm_80`"]
+        );
+    });
+}
+
+#[test]
+fn modules_without_version_or_target_are_not_checked() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = "    wgmma.fence.sync.aligned;";
+        assert_eq!(check("", body), Vec::<String>::new());
+        assert_eq!(
+            check(".target sm_90a", body),
+            Vec::<String>::new(),
+            "no version to check"
+        );
+        assert_eq!(
+            check(".version 8.0", body),
+            Vec::<String>::new(),
+            "no target to check"
+        );
+    });
+}
+
+#[test]
+fn requirements_follow_the_target_variants() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = ".entry k() { tcgen05.fence::before_thread_sync; cvt.rn.bf16.f32 %rs1, %f1; }";
+        let module = parse_ptx(source).expect("module should parse");
+        let ModuleDirective::EntryFunction { directive, .. } = &module.directives[0] else {
+            unreachable!()
+        };
+        let patterns: Vec<Vec<&str>> = directive
+            .body
+            .as_ref()
+            .unwrap()
+            .statements
+            .iter()
+            .map(|statement| {
+                let FunctionStatement::Instruction { instruction, .. } = statement else {
+                    unreachable!()
+                };
+                let requirements = instruction.inst.requirements();
+                requirements.iter().map(|r| r.pattern).collect()
+            })
+            .collect();
+        assert_eq!(patterns, [vec!["tcgen05"], vec!["*.bf16"]]);
+
+        // `sm_100f` covers the later targets of its family, but not the base
+        // targets or other families.
+        let family = Target::family(100);
+        assert!(Target::arch(103).satisfies(&family));
+        assert!(Target::family(100).satisfies(&family));
+        assert!(!Target::base(103).satisfies(&family));
+        assert!(!Target::arch(120).satisfies(&family));
+        assert!(!Target::arch(90).satisfies(&family));
+
+        let arch = Target::arch(90);
+        assert!(arch.satisfies(&Target::base(80)));
+        assert!(!Target::arch(100).satisfies(&arch));
+        assert!(!Target::base(90).satisfies(&arch));
+        assert_eq!(arch.to_string(), "sm_90a");
+        assert!(PtxVersion::new(7, 8) < PtxVersion::new(8, 0));
+    });
+}
+
+#[test]
+fn min_target_names_the_instruction_that_forces_it() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    cp.async.ca.shared.global [%r1], [%rd1], 16;
+    mapa.shared::cluster.u32 %r1, %r2, %r3;
+    wgmma.fence.sync.aligned;
+    elect.sync %r1|%p1, 0xffffffff;
+"#;
+        // The module's own `.version` and `.target` are ignored.
+        let source = module(".version 7.0\n.target sm_50", body);
+        let min = min_target(&parse_ptx(&source).unwrap()).expect("`sm_90a` allows all");
+
+        // `wgmma` is the first instruction that needs PTX ISA 8.0.
+        let (version, used) = min.ptx.unwrap();
+        assert_eq!(version, PtxVersion::new(8, 0));
+        assert_eq!(used.mnemonic, "wgmma.fence.sync.aligned");
+        let (target, used) = min.target.unwrap();
+        assert_eq!(target, Target::arch(90));
+        assert_eq!(used.requirement.pattern, "wgmma");
+
+        // Without it a base target will do.
+        let source = module("", &body.replacen("wgmma.fence.sync.aligned;", "", 1));
+        let min = min_target(&parse_ptx(&source).unwrap()).unwrap();
+        assert_eq!(min.target.unwrap().0, Target::base(90));
+        assert_eq!(min.ptx.unwrap().1.mnemonic, "elect.sync");
+    });
+}
+
+#[test]
+fn min_target_is_empty_without_requirements() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = module(".version 8.0\n.target sm_90", "    add.s32 %r1, %r2, %r3;");
+        let min = min_target(&parse_ptx(&source).unwrap()).unwrap();
+        assert_eq!(min.ptx, None);
+        assert_eq!(min.target, None);
+
+        // A modifier requirement restricts the target like an opcode one.
+        let source = module("", "    cvt.rn.bf16.f32 %r1, %f1;");
+        let min = min_target(&parse_ptx(&source).unwrap()).unwrap();
+        assert_eq!(min.ptx.unwrap().0, PtxVersion::new(7, 0));
+        assert_eq!(min.target.unwrap().0, Target::base(80));
+    });
+}
+
+#[test]
+fn diagnostics_name_the_missing_version_and_targets() {
+    ptx_parser::run_with_large_stack(|| {
+        let source =
+            ".version 7.8\n.target sm_80\n.entry k()\n{\n    wgmma.fence.sync.aligned;\n}\n";
+        let issues = check_targets(&parse_ptx(source).unwrap());
+        assert_eq!(
+            &source[issues[0].span().start..issues[0].span().end],
+            "wgmma.fence.sync.aligned;"
+        );
+
+        let version = issues[0].to_diagnostic();
+        assert_eq!(version.label.as_deref(), Some("requires `.version 8.0`"));
+        assert_eq!(version.notes, ["`wgmma` was introduced in PTX ISA 8.0"]);
+        let target = issues[1].to_diagnostic();
+        assert_eq!(target.label.as_deref(), Some("requires `sm_90a`"));
+        assert_eq!(target.notes, ["`wgmma` needs `sm_90a`"]);
+    });
+}
+
+#[test]
+fn conflicting_instructions_have_no_min_target() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = ".entry k()\n{\n    wgmma.fence.sync.aligned;\n    tcgen05.fence::before_thread_sync;\n}\n";
+        let issue = min_target(&parse_ptx(source).unwrap()).expect_err("no target has both");
+        let rendered = issue
+            .to_diagnostic()
+            .render(&SourceMap::new(source), Some("k.ptx"));
+        assert_eq!(
+            rendered,
+            "error: no target supports both `wgmma.fence.sync.aligned` and `tcgen05.fence::before_thread_sync`\n --> k.ptx:4:5\n  |\n4 |     tcgen05.fence::before_thread_sync;\n  |     ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^ conflicts with `wgmma.fence.sync.aligned`\n  |\n  = note: `wgmma` needs `sm_90a`\n  = note: `tcgen05` needs the `sm_100f` family or the `sm_110f` family\n"
+        );
+    });
+}
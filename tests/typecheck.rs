@@ -0,0 +1,311 @@
+mod util;
+
+use ptx_parser::analysis::{TypeIssue, typecheck};
+use ptx_parser::{SourceMap, parse_ptx};
+use util::messages;
+
+/// Type issues of `body` in a kernel that declares registers of every
+/// common type.
+fn check(body: &str) -> Vec<String> {
+    let source = format!(
+        r#".entry k(.param .u64 p)
+{{
+    .reg .pred %p<3>;
+    .reg .b16 %rs<3>;
+    .reg .b32 %b<3>;
+    .reg .u32 %r<5>;
+    .reg .s64 %rd<4>;
+    .reg .b64 %bd<3>;
+    .reg .f32 %f<5>;
+    .reg .f64 %fd<3>;
+{body}
+    ret;
+}}
+"#
+    );
+    let module = parse_ptx(&source).expect("module should parse");
+    messages(&typecheck(&module))
+}
+
+#[test]
+fn matching_types_are_accepted() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    add.u32 %r1, %r2, %r3;
+    sub.s64 %rd1, %rd2, %rd3;
+    mul.f32 %f1, %f2, %f3;
+    fma.rn.f64 %fd1, %fd2, %fd2, %fd2;
+    setp.lt.f32 %p1, %f1, %f2;
+    selp.u32 %r1, %r2, %r3, %p1;
+    and.pred %p1, %p1, %p2;
+"#;
+        assert_eq!(check(body), Vec::<String>::new());
+    });
+}
+
+#[test]
+fn bit_size_registers_and_instructions_mix_with_any_kind() {
+    ptx_parser::run_with_large_stack(|| {
+        // `.b32` registers serve as integers and floats, and `.b32`
+        // instructions accept float and integer registers alike.
+        let body = r#"
+    add.s32 %b1, %b2, %r1;
+    add.f32 %b1, %b2, %f1;
+    and.b32 %f1, %f2, %r1;
+    mov.b32 %f1, %r1;
+    xor.b64 %bd1, %rd1, %fd1;
+"#;
+        assert_eq!(check(body), Vec::<String>::new());
+    });
+}
+
+#[test]
+fn signed_and_unsigned_integers_are_interchangeable() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    add.s32 %r1, %r2, %r3;
+    add.u64 %rd1, %rd2, %rd3;
+    max.s32 %r1, %r2, %b1;
+"#;
+        assert_eq!(check(body), Vec::<String>::new());
+    });
+}
+
+#[test]
+fn integer_float_and_width_mismatches_are_reported() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    add.s32 %rd1, %r1, %r2;
+    add.s32 %r1, %f2, %r2;
+    add.f32 %f1, %f2, %r3;
+    add.f64 %fd1, %f1, %fd2;
+    mov.b32 %rs1, %r1;
+    add.u32 %r1, %r2, %p1;
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`%rd1` is declared `.s64` but used as `.s32`",
+                "`%f2` is declared `.f32` but used as `.s32`",
+                "`%r3` is declared `.u32` but used as `.f32`",
+                "`%f1` is declared `.f32` but used as `.f64`",
+                "`%rs1` is declared `.b16` but used as `.b32`",
+                "`%p1` is declared `.pred` but used as `.u32`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn predicates_are_only_accepted_where_a_predicate_is_expected() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    setp.eq.u32 %r1, %r2, 0;
+    selp.u32 %r1, %r2, %r3, %r4;
+    @%r1 mov.u32 %r2, %r3;
+    @!%p1 mov.u32 %r2, %r3;
+    mov.pred %p2, -1;
+    mov.pred %p2, 2;
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`%r1` is declared `.u32` but used as `.pred`",
+                "`%r4` is declared `.u32` but used as `.pred`",
+                "`%r1` is declared `.u32` but used as `.pred`",
+                "`2` does not fit in `.pred`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn loads_stores_and_conversions_may_use_wider_registers() {
+    ptx_parser::run_with_large_stack(|| {
+        // Narrow integers move through 32-bit registers, and floats through
+        // wider bit-size registers.
+        let body = r#"
+    ld.param.u64 %rd1, [p];
+    ld.global.u8 %rs1, [%rd1];
+    ld.global.s16 %r1, [%rd1];
+    st.global.u8 [%rd1], %r1;
+    ld.global.f32 %bd1, [%rd1];
+    cvt.u64.u32 %rd2, %r2;
+    cvt.rn.f32.s32 %f3, %r1;
+"#;
+        assert_eq!(check(body), Vec::<String>::new());
+
+        // Floats may not widen into float registers, and nothing narrows.
+        let body = r#"
+    ld.global.f32 %fd1, [%rd1];
+    ld.global.u32 %rs1, [%rd1];
+    st.global.u64 [%rd1], %r1;
+    add.u16 %r1, %r2, %r3;
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`%fd1` is declared `.f64` but used as `.f32`",
+                "`%rs1` is declared `.b16` but used as `.u32`",
+                "`%r1` is declared `.u32` but used as `.u64`",
+                "`%r1` is declared `.u32` but used as `.u16`",
+                "`%r2` is declared `.u32` but used as `.u16`",
+                "`%r3` is declared `.u32` but used as `.u16`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn wide_instructions_double_the_destination() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    mul.wide.u32 %rd1, %r1, %r2;
+    mad.wide.s32 %rd1, %r1, %r2, %rd2;
+    mul.wide.s32 %r1, %r2, %r3;
+    mad.wide.u32 %rd1, %r1, %r2, %r3;
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`%r1` is declared `.u32` but used as `.s64`",
+                "`%r3` is declared `.u32` but used as `.u64`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn immediates_must_fit_the_instruction_type() {
+    ptx_parser::run_with_large_stack(|| {
+        // Integers fit if they are within the signed or unsigned range.
+        let body = r#"
+    add.u32 %r1, %r2, 0xffffffff;
+    add.s32 %r1, %r2, -2147483648;
+    add.u32 %r1, %r2, -1;
+    add.u16 %rs1, %rs2, 65535;
+    add.f32 %f1, %f2, 0f3F800000;
+    add.f32 %f1, %f2, 1;
+    add.f64 %fd1, %fd2, 0d3FF0000000000000;
+"#;
+        assert_eq!(check(body), Vec::<String>::new());
+
+        let body = r#"
+    add.s32 %r1, %r2, 3.5;
+    setp.eq.u32 %p1, %r2, 0x100000000;
+    add.u16 %rs1, %rs2, 65536;
+    add.s32 %r1, %r2, -2147483649;
+    add.f32 %f1, %f2, 0d47F0000000000000;
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "`3.5` does not fit in `.s32`",
+                "`0x100000000` does not fit in `.u32`",
+                "`65536` does not fit in `.u16`",
+                "`-2147483649` does not fit in `.s32`",
+                "`0d47F0000000000000` does not fit in `.f32`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn vector_operands_follow_the_vector_modifier() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    ld.global.v2.f32 {%f1, %f2}, [%rd1];
+    st.global.v4.u32 [%rd1], {%r1, %r2, %r3, %r4};
+    ld.global.v4.f32 {%f1, %f2}, [%rd1];
+    st.global.f32 [%rd1], {%f1, %f2};
+    ld.global.v2.u32 %r1, [%rd1];
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "expected a vector of 4, found a vector of 2",
+                "expected a scalar operand, found a vector of 2",
+                "expected a vector of 2, found a scalar operand",
+            ]
+        );
+    });
+}
+
+#[test]
+fn mov_packs_and_unpacks_braced_operands() {
+    ptx_parser::run_with_large_stack(|| {
+        let body = r#"
+    mov.b64 %rd1, {%r1, %r2};
+    mov.b64 {%r1, %r2}, %bd1;
+    mov.b32 %r1, {%rs1, %rs2};
+    mov.b64 %rd1, {%r1, %r2, %r3};
+    mov.b64 %rd1, {%r1, %rd2};
+"#;
+        assert_eq!(
+            check(body),
+            [
+                "expected a scalar operand, found a vector of 3",
+                "`%rd2` is declared `.s64` but used as `.b32`",
+            ]
+        );
+    });
+}
+
+#[test]
+fn operands_without_rules_are_not_checked() {
+    ptx_parser::run_with_large_stack(|| {
+        // Special registers, symbols, undeclared registers and instructions
+        // without a rule are left alone.
+        let body = r#"
+    mov.u32 %r1, %tid.x;
+    mov.u64 %rd1, p;
+    add.u32 %r1, %undeclared, 1;
+    bar.sync 0;
+    popc.b64 %r1, %bd1;
+"#;
+        assert_eq!(check(body), Vec::<String>::new());
+    });
+}
+
+#[test]
+fn issues_render_at_the_operand() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#".entry k()
+{
+    .reg .b64 %rd1;
+    .reg .f32 %f<3>;
+    add.s32 %rd1, %f2, 3.5;
+    ld.global.v4.f32 {%f1, %f2}, [%rd1];
+}
+"#;
+        let module = parse_ptx(source).expect("module should parse");
+        let issues = typecheck(&module);
+        assert_eq!(issues.len(), 4);
+
+        let register = &issues[0];
+        assert_eq!(&source[register.span().start..register.span().end], "%rd1");
+        let rendered = issues[2]
+            .to_diagnostic()
+            .render(&SourceMap::new(source), Some("k.ptx"));
+        assert_eq!(
+            rendered,
+            "error: `3.5` does not fit in `.s32`\n --> k.ptx:5:24\n  |\n5 |     add.s32 %rd1, %f2, 3.5;\n  |                        ^^^ expected `.s32`\n"
+        );
+
+        let TypeIssue::VectorArity {
+            expected, found, ..
+        } = &issues[3]
+        else {
+            panic!("expected an arity issue: {:?}", issues[3]);
+        };
+        assert_eq!((*expected, *found), (4, 2));
+        assert_eq!(
+            &source[issues[3].span().start..issues[3].span().end],
+            "{%f1, %f2}"
+        );
+        assert_eq!(
+            issues[3].to_diagnostic().label.as_deref(),
+            Some("expected `.v4`")
+        );
+    });
+}
@@ -16,6 +16,11 @@ pub fn tokenize_only(source: &str) -> Vec<ptx_parser::PtxToken> {
     tokens.into_iter().map(|(token, _)| token).collect()
 }
 
+/// Messages of the issues reported by an analysis pass, in order.
+pub fn messages<T: ToString>(issues: &[T]) -> Vec<String> {
+    issues.iter().map(ToString::to_string).collect()
+}
+
 /// Compare two token lists semantically, allowing for equivalent representations.
 /// For example, Register("%ctaid.x") should match Register("%ctaid") + Dot + Identifier("x")
 pub fn tokens_equivalent(left: &[PtxToken], right: &[PtxToken]) -> bool {
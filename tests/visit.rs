@@ -0,0 +1,242 @@
+use ptx_parser::r#type::{FunctionSymbol, Immediate, Label, RegisterOperand, VariableSymbol};
+use ptx_parser::visit::{Visitor, VisitorMut};
+use ptx_parser::{PtxUnlexer, PtxUnparser, Span, parse_ptx};
+
+/// Records every leaf node in visiting order.
+#[derive(Default)]
+struct Leaves {
+    seen: Vec<String>,
+}
+
+impl<'ast> Visitor<'ast> for Leaves {
+    fn visit_register(&mut self, register: &'ast RegisterOperand) {
+        self.seen.push(format!("reg {}", register.name));
+    }
+
+    fn visit_immediate(&mut self, immediate: &'ast Immediate) {
+        self.seen.push(format!("imm {}", immediate.value));
+    }
+
+    fn visit_function_symbol(&mut self, symbol: &'ast FunctionSymbol) {
+        self.seen.push(format!("func {}", symbol.val));
+    }
+
+    fn visit_variable_symbol(&mut self, symbol: &'ast VariableSymbol) {
+        self.seen.push(format!("var {}", symbol.val));
+    }
+
+    fn visit_label(&mut self, label: &'ast Label) {
+        self.seen.push(format!("label {}", label.val));
+    }
+
+    fn visit_symbol(&mut self, name: &'ast str, _span: Span) {
+        self.seen.push(format!("sym {name}"));
+    }
+}
+
+fn leaves(source: &str) -> Vec<String> {
+    let module = parse_ptx(source).expect("module should parse");
+    let mut leaves = Leaves::default();
+    leaves.visit_module(&module);
+    leaves.seen
+}
+
+#[test]
+fn visits_module_nodes_in_source_order() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#"
+            .version 8.5
+            .target sm_90
+            .global .u32 counter = 7;
+            .func (.param .u32 ret) helper(.param .u32 x) {
+                ret;
+            }
+            .entry kernel(.param .u64 out) {
+                .reg .pred %p<2>;
+            start:
+                @%p1 bra start;
+                ld.global.u32 %r1, [%rd1+8];
+                { add.s32 %r2, %r1, 0x10; }
+                ret;
+            }
+        "#;
+
+        assert_eq!(
+            leaves(source),
+            vec![
+                "var counter",
+                "imm 7",
+                "var ret",
+                "func helper",
+                "var x",
+                "func kernel",
+                "var out",
+                "var %p",
+                "label start",
+                "reg %p1",
+                "sym start",
+                "reg %r1",
+                "reg %rd1",
+                "imm 8",
+                "reg %r2",
+                "reg %r1",
+                "imm 0x10",
+            ]
+        );
+    });
+}
+
+#[test]
+fn generated_walkers_cover_grouped_operands() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#"
+            .version 8.5
+            .target sm_90
+            .entry kernel() {
+                ld.global.v2.f32 {%f1, %f2}, [%rd1];
+                tex.2d.v4.s32.f32 {%r1, %r2, %r3, %r4}, [tex_a, {%f1, %f2}];
+                setp.lt.s32 %p1|%p2, %r1, 4;
+                call.uni (%r5), foo, (%r1, %r2);
+            }
+        "#;
+
+        assert_eq!(
+            leaves(source),
+            vec![
+                "func kernel",
+                "reg %f1",
+                "reg %f2",
+                "reg %rd1",
+                "reg %r1",
+                "reg %r2",
+                "reg %r3",
+                "reg %r4",
+                "sym tex_a",
+                "reg %f1",
+                "reg %f2",
+                "reg %p1",
+                "reg %p2",
+                "reg %r1",
+                "imm 4",
+                "reg %r5",
+                "sym foo",
+                "reg %r1",
+                "reg %r2",
+            ]
+        );
+    });
+}
+
+struct RenameRegister<'a> {
+    from: &'a str,
+    to: &'a str,
+}
+
+impl VisitorMut for RenameRegister<'_> {
+    fn visit_register_mut(&mut self, register: &mut RegisterOperand) {
+        if register.name == self.from {
+            register.name = self.to.to_string();
+        }
+    }
+}
+
+#[test]
+fn visitor_mut_rewrites_every_use() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#"
+            .version 8.5
+            .target sm_90
+            .entry kernel() {
+                @%p1 add.s32 %r1, %r1, 1;
+                st.global.u32 [%r1+4], %r2;
+                mov.u32 %r3, %r2;
+            }
+        "#;
+        let mut module = parse_ptx(source).expect("module should parse");
+        RenameRegister {
+            from: "%r1",
+            to: "%r9",
+        }
+        .visit_module_mut(&mut module);
+
+        let text = PtxUnlexer::to_string(&module.to_tokens_spaced()).expect("unlex");
+        assert!(!text.contains("%r1"), "{text}");
+        assert!(text.contains("add.s32 %r9, %r9, 1;"), "{text}");
+        assert!(text.contains("[%r9+4]"), "{text}");
+        assert!(text.contains("mov.u32 %r3, %r2;"), "{text}");
+    });
+}
+
+/// Records each symbol operand together with the source text of its span.
+struct SymbolSpans<'ast> {
+    source: &'ast str,
+    seen: Vec<(&'ast str, &'ast str)>,
+}
+
+impl<'ast> Visitor<'ast> for SymbolSpans<'ast> {
+    fn visit_symbol(&mut self, name: &'ast str, span: Span) {
+        self.seen.push((name, &self.source[span.start..span.end]));
+    }
+}
+
+#[test]
+fn symbol_operands_reach_visit_symbol() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#"
+            .version 8.5
+            .target sm_90
+            .global .u32 table[4];
+            .entry kernel() {
+            loop:
+                mov.u64 %rd1, table+8;
+                bra.uni loop;
+            }
+        "#;
+
+        let module = parse_ptx(source).expect("module should parse");
+        let mut symbols = SymbolSpans {
+            source,
+            seen: Vec::new(),
+        };
+        symbols.visit_module(&module);
+
+        assert_eq!(symbols.seen, [("table", "table+8"), ("loop", "loop")]);
+    });
+}
+
+struct RenameSymbol;
+
+impl VisitorMut for RenameSymbol {
+    fn visit_symbol_mut(&mut self, name: &mut String, _span: Span) {
+        if name == "loop" {
+            *name = "again".to_string();
+        }
+    }
+
+    fn visit_label_mut(&mut self, label: &mut Label) {
+        if label.val == "loop" {
+            label.val = "again".to_string();
+        }
+    }
+}
+
+#[test]
+fn visitor_mut_renames_branch_targets() {
+    ptx_parser::run_with_large_stack(|| {
+        let source = r#"
+            .version 8.5
+            .target sm_90
+            .entry kernel() {
+            loop:
+                @%p1 bra loop;
+                ret;
+            }
+        "#;
+        let mut module = parse_ptx(source).expect("module should parse");
+        RenameSymbol.visit_module_mut(&mut module);
+
+        let text = PtxUnlexer::to_string(&module.to_tokens_spaced()).expect("unlex");
+        assert!(!text.contains("loop"), "{text}");
+        assert!(text.contains("bra again;"), "{text}");
+    });
+}